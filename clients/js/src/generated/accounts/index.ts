@@ -6,6 +6,18 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './denyList';
+export * from './hookAllowlist';
+export * from './mintRegistry';
+export * from './pendingAction';
+export * from './poolAllowlist';
+export * from './poolRegistry';
 export * from './programAuthority';
+export * from './programConfig';
+export * from './rewardCohort';
+export * from './rewardStream';
+export * from './slashProposal';
 export * from './stakeAccount';
 export * from './stakePool';
+export * from './stakePosition';
+export * from './userPoints';
@@ -17,10 +17,16 @@ import {
   getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU8Decoder,
   getU8Encoder,
   type Account,
@@ -36,37 +42,78 @@ import {
   type Option,
   type OptionOrNullable,
 } from '@solana/kit';
-import { getKeyDecoder, getKeyEncoder, type Key, type KeyArgs } from '../types';
+import {
+  getAuthorizedCreatorDecoder,
+  getAuthorizedCreatorEncoder,
+  getClusterProfileDecoder,
+  getClusterProfileEncoder,
+  getKeyDecoder,
+  getKeyEncoder,
+  getRoleHolderDecoder,
+  getRoleHolderEncoder,
+  type AuthorizedCreator,
+  type AuthorizedCreatorArgs,
+  type ClusterProfile,
+  type ClusterProfileArgs,
+  type Key,
+  type KeyArgs,
+  type RoleHolder,
+  type RoleHolderArgs,
+} from '../types';
 
 export type ProgramAuthority = {
   key: Key;
   authority: Address;
-  authorizedCreators: Array<Option<Address>>;
-  creatorCount: number;
   pendingAuthority: Option<Address>;
   bump: number;
+  clusterProfile: ClusterProfile;
+  globalPause: boolean;
+  withdrawalsOnly: boolean;
+  roleHolders: Array<Option<RoleHolder>>;
+  approvalThreshold: number;
+  recoveryAuthority: Option<Address>;
+  recoveryDelay: bigint;
+  recoveryInitiatedAt: Option<bigint>;
+  maxCreators: number;
+  authorizedCreators: Array<AuthorizedCreator>;
 };
 
 export type ProgramAuthorityArgs = {
   key: KeyArgs;
   authority: Address;
-  authorizedCreators: Array<OptionOrNullable<Address>>;
-  creatorCount: number;
   pendingAuthority: OptionOrNullable<Address>;
   bump: number;
+  clusterProfile: ClusterProfileArgs;
+  globalPause: boolean;
+  withdrawalsOnly: boolean;
+  roleHolders: Array<OptionOrNullable<RoleHolderArgs>>;
+  approvalThreshold: number;
+  recoveryAuthority: OptionOrNullable<Address>;
+  recoveryDelay: number | bigint;
+  recoveryInitiatedAt: OptionOrNullable<number | bigint>;
+  maxCreators: number;
+  authorizedCreators: Array<AuthorizedCreatorArgs>;
 };
 
 export function getProgramAuthorityEncoder(): Encoder<ProgramAuthorityArgs> {
   return getStructEncoder([
     ['key', getKeyEncoder()],
     ['authority', getAddressEncoder()],
-    [
-      'authorizedCreators',
-      getArrayEncoder(getOptionEncoder(getAddressEncoder()), { size: 10 }),
-    ],
-    ['creatorCount', getU8Encoder()],
     ['pendingAuthority', getOptionEncoder(getAddressEncoder())],
     ['bump', getU8Encoder()],
+    ['clusterProfile', getClusterProfileEncoder()],
+    ['globalPause', getBooleanEncoder()],
+    ['withdrawalsOnly', getBooleanEncoder()],
+    [
+      'roleHolders',
+      getArrayEncoder(getOptionEncoder(getRoleHolderEncoder()), { size: 8 }),
+    ],
+    ['approvalThreshold', getU8Encoder()],
+    ['recoveryAuthority', getOptionEncoder(getAddressEncoder())],
+    ['recoveryDelay', getI64Encoder()],
+    ['recoveryInitiatedAt', getOptionEncoder(getI64Encoder())],
+    ['maxCreators', getU16Encoder()],
+    ['authorizedCreators', getArrayEncoder(getAuthorizedCreatorEncoder())],
   ]);
 }
 
@@ -74,13 +121,21 @@ export function getProgramAuthorityDecoder(): Decoder<ProgramAuthority> {
   return getStructDecoder([
     ['key', getKeyDecoder()],
     ['authority', getAddressDecoder()],
-    [
-      'authorizedCreators',
-      getArrayDecoder(getOptionDecoder(getAddressDecoder()), { size: 10 }),
-    ],
-    ['creatorCount', getU8Decoder()],
     ['pendingAuthority', getOptionDecoder(getAddressDecoder())],
     ['bump', getU8Decoder()],
+    ['clusterProfile', getClusterProfileDecoder()],
+    ['globalPause', getBooleanDecoder()],
+    ['withdrawalsOnly', getBooleanDecoder()],
+    [
+      'roleHolders',
+      getArrayDecoder(getOptionDecoder(getRoleHolderDecoder()), { size: 8 }),
+    ],
+    ['approvalThreshold', getU8Decoder()],
+    ['recoveryAuthority', getOptionDecoder(getAddressDecoder())],
+    ['recoveryDelay', getI64Decoder()],
+    ['recoveryInitiatedAt', getOptionDecoder(getI64Decoder())],
+    ['maxCreators', getU16Decoder()],
+    ['authorizedCreators', getArrayDecoder(getAuthorizedCreatorDecoder())],
   ]);
 }
 
@@ -13,26 +13,39 @@ import {
   decodeAccount,
   fetchEncodedAccount,
   fetchEncodedAccounts,
+  fixDecoderSize,
+  fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
   getI64Decoder,
   getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
+  type ReadonlyUint8Array,
 } from '@solana/kit';
 import { getKeyDecoder, getKeyEncoder, type Key, type KeyArgs } from '../types';
 
@@ -45,6 +58,24 @@ export type StakeAccount = {
   stakeTimestamp: bigint;
   claimedRewards: bigint;
   bump: number;
+  referrer: Option<Address>;
+  earlyUnstaked: bigint;
+  autoRelock: boolean;
+  carriedRewards: bigint;
+  delegate: Option<Address>;
+  pendingWithdrawAmount: bigint;
+  requestTimestamp: Option<bigint>;
+  lockedRewardRate: bigint;
+  label: ReadonlyUint8Array;
+  pendingOwner: Option<Address>;
+  lockupExtension: bigint;
+  vestingAmount: bigint;
+  vestingStart: bigint;
+  vestingCliff: bigint;
+  vestingDuration: bigint;
+  pendingSlashBps: number;
+  pointsSyncedAt: bigint;
+  rewardCohort: bigint;
 };
 
 export type StakeAccountArgs = {
@@ -56,9 +87,27 @@ export type StakeAccountArgs = {
   stakeTimestamp: number | bigint;
   claimedRewards: number | bigint;
   bump: number;
+  referrer: OptionOrNullable<Address>;
+  earlyUnstaked: number | bigint;
+  autoRelock: boolean;
+  carriedRewards: number | bigint;
+  delegate: OptionOrNullable<Address>;
+  pendingWithdrawAmount: number | bigint;
+  requestTimestamp: OptionOrNullable<number | bigint>;
+  lockedRewardRate: number | bigint;
+  label: ReadonlyUint8Array;
+  pendingOwner: OptionOrNullable<Address>;
+  lockupExtension: number | bigint;
+  vestingAmount: number | bigint;
+  vestingStart: number | bigint;
+  vestingCliff: number | bigint;
+  vestingDuration: number | bigint;
+  pendingSlashBps: number;
+  pointsSyncedAt: number | bigint;
+  rewardCohort: number | bigint;
 };
 
-export function getStakeAccountEncoder(): FixedSizeEncoder<StakeAccountArgs> {
+export function getStakeAccountEncoder(): Encoder<StakeAccountArgs> {
   return getStructEncoder([
     ['key', getKeyEncoder()],
     ['pool', getAddressEncoder()],
@@ -68,10 +117,28 @@ export function getStakeAccountEncoder(): FixedSizeEncoder<StakeAccountArgs> {
     ['stakeTimestamp', getI64Encoder()],
     ['claimedRewards', getU64Encoder()],
     ['bump', getU8Encoder()],
+    ['referrer', getOptionEncoder(getAddressEncoder())],
+    ['earlyUnstaked', getU64Encoder()],
+    ['autoRelock', getBooleanEncoder()],
+    ['carriedRewards', getU64Encoder()],
+    ['delegate', getOptionEncoder(getAddressEncoder())],
+    ['pendingWithdrawAmount', getU64Encoder()],
+    ['requestTimestamp', getOptionEncoder(getI64Encoder())],
+    ['lockedRewardRate', getU64Encoder()],
+    ['label', fixEncoderSize(getBytesEncoder(), 32)],
+    ['pendingOwner', getOptionEncoder(getAddressEncoder())],
+    ['lockupExtension', getI64Encoder()],
+    ['vestingAmount', getU64Encoder()],
+    ['vestingStart', getI64Encoder()],
+    ['vestingCliff', getI64Encoder()],
+    ['vestingDuration', getI64Encoder()],
+    ['pendingSlashBps', getU16Encoder()],
+    ['pointsSyncedAt', getI64Encoder()],
+    ['rewardCohort', getU64Encoder()],
   ]);
 }
 
-export function getStakeAccountDecoder(): FixedSizeDecoder<StakeAccount> {
+export function getStakeAccountDecoder(): Decoder<StakeAccount> {
   return getStructDecoder([
     ['key', getKeyDecoder()],
     ['pool', getAddressDecoder()],
@@ -81,13 +148,28 @@ export function getStakeAccountDecoder(): FixedSizeDecoder<StakeAccount> {
     ['stakeTimestamp', getI64Decoder()],
     ['claimedRewards', getU64Decoder()],
     ['bump', getU8Decoder()],
+    ['referrer', getOptionDecoder(getAddressDecoder())],
+    ['earlyUnstaked', getU64Decoder()],
+    ['autoRelock', getBooleanDecoder()],
+    ['carriedRewards', getU64Decoder()],
+    ['delegate', getOptionDecoder(getAddressDecoder())],
+    ['pendingWithdrawAmount', getU64Decoder()],
+    ['requestTimestamp', getOptionDecoder(getI64Decoder())],
+    ['lockedRewardRate', getU64Decoder()],
+    ['label', fixDecoderSize(getBytesDecoder(), 32)],
+    ['pendingOwner', getOptionDecoder(getAddressDecoder())],
+    ['lockupExtension', getI64Decoder()],
+    ['vestingAmount', getU64Decoder()],
+    ['vestingStart', getI64Decoder()],
+    ['vestingCliff', getI64Decoder()],
+    ['vestingDuration', getI64Decoder()],
+    ['pendingSlashBps', getU16Decoder()],
+    ['pointsSyncedAt', getI64Decoder()],
+    ['rewardCohort', getU64Decoder()],
   ]);
 }
 
-export function getStakeAccountCodec(): FixedSizeCodec<
-  StakeAccountArgs,
-  StakeAccount
-> {
+export function getStakeAccountCodec(): Codec<StakeAccountArgs, StakeAccount> {
   return combineCodec(getStakeAccountEncoder(), getStakeAccountDecoder());
 }
 
@@ -143,7 +225,3 @@ export async function fetchAllMaybeStakeAccount(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeStakeAccount(maybeAccount));
 }
-
-export function getStakeAccountSize(): number {
-  return 98;
-}
@@ -17,16 +17,24 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBooleanDecoder,
   getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getI64Decoder,
   getI64Encoder,
+  getI8Decoder,
+  getI8Encoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -45,7 +53,36 @@ import {
   type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit';
-import { getKeyDecoder, getKeyEncoder, type Key, type KeyArgs } from '../types';
+import {
+  getClaimWindowDecoder,
+  getClaimWindowEncoder,
+  getKeyDecoder,
+  getKeyEncoder,
+  getOutflowLimitDecoder,
+  getOutflowLimitEncoder,
+  getOutflowWindowDecoder,
+  getOutflowWindowEncoder,
+  getRewardRateModeDecoder,
+  getRewardRateModeEncoder,
+  getRewardTierDecoder,
+  getRewardTierEncoder,
+  getWindDownRewardsDecoder,
+  getWindDownRewardsEncoder,
+  type ClaimWindow,
+  type ClaimWindowArgs,
+  type Key,
+  type KeyArgs,
+  type OutflowLimit,
+  type OutflowLimitArgs,
+  type OutflowWindow,
+  type OutflowWindowArgs,
+  type RewardRateMode,
+  type RewardRateModeArgs,
+  type RewardTier,
+  type RewardTierArgs,
+  type WindDownRewards,
+  type WindDownRewardsArgs,
+} from '../types';
 
 export type StakePool = {
   key: Key;
@@ -66,6 +103,52 @@ export type StakePool = {
   pendingRewardRate: Option<bigint>;
   rewardRateChangeTimestamp: Option<bigint>;
   lastRateChange: Option<bigint>;
+  referralBps: number;
+  earlyUnstakeBps: number;
+  stakeVaultBump: Option<number>;
+  rewardVaultBump: Option<number>;
+  feeVault: Address;
+  depositFeeBps: number;
+  withdrawFeeBps: number;
+  feeVaultBump: Option<number>;
+  totalRewardsFunded: bigint;
+  rewardFunders: Array<Option<Address>>;
+  receiptMint: Option<Address>;
+  expiredRewards: bigint;
+  unstakeCooldown: bigint;
+  isRegistered: boolean;
+  rewardRateChangeDelay: number;
+  pendingLockupPeriod: Option<bigint>;
+  pendingEnforceLockup: Option<boolean>;
+  lockupChangeTimestamp: Option<bigint>;
+  minRewardRateBound: Option<bigint>;
+  maxRewardRateBound: Option<bigint>;
+  activeStakeCount: Option<bigint>;
+  rewardTiers: Array<Option<RewardTier>>;
+  bonusEndTs: Option<bigint>;
+  bonusMultiplierBps: number;
+  lockupExtensionRate: bigint;
+  allowlistRequired: boolean;
+  allowlistMerkleRoot: Option<ReadonlyUint8Array>;
+  claimWindow: Option<ClaimWindow>;
+  outflowLimit: Option<OutflowLimit>;
+  outflowWindow: OutflowWindow;
+  poolManager: Option<Address>;
+  keeperBounty: bigint;
+  lastKeeperBountyAt: bigint;
+  maxTotalRewards: Option<bigint>;
+  totalRewardsCommitted: bigint;
+  rewardRateMode: RewardRateMode;
+  rewardDecimalsShift: number;
+  enforceMinRemainingStake: boolean;
+  windDownRewards: WindDownRewards;
+  hookProgram: Option<Address>;
+  pointsRate: bigint;
+  denyListRequired: boolean;
+  allowThirdPartyStake: boolean;
+  eventNonce: bigint;
+  cohortDuration: bigint;
+  openRewardCohorts: number;
   reserved: ReadonlyUint8Array;
 };
 
@@ -88,6 +171,52 @@ export type StakePoolArgs = {
   pendingRewardRate: OptionOrNullable<number | bigint>;
   rewardRateChangeTimestamp: OptionOrNullable<number | bigint>;
   lastRateChange: OptionOrNullable<number | bigint>;
+  referralBps: number;
+  earlyUnstakeBps: number;
+  stakeVaultBump: OptionOrNullable<number>;
+  rewardVaultBump: OptionOrNullable<number>;
+  feeVault: Address;
+  depositFeeBps: number;
+  withdrawFeeBps: number;
+  feeVaultBump: OptionOrNullable<number>;
+  totalRewardsFunded: number | bigint;
+  rewardFunders: Array<OptionOrNullable<Address>>;
+  receiptMint: OptionOrNullable<Address>;
+  expiredRewards: number | bigint;
+  unstakeCooldown: number | bigint;
+  isRegistered: boolean;
+  rewardRateChangeDelay: number;
+  pendingLockupPeriod: OptionOrNullable<number | bigint>;
+  pendingEnforceLockup: OptionOrNullable<boolean>;
+  lockupChangeTimestamp: OptionOrNullable<number | bigint>;
+  minRewardRateBound: OptionOrNullable<number | bigint>;
+  maxRewardRateBound: OptionOrNullable<number | bigint>;
+  activeStakeCount: OptionOrNullable<number | bigint>;
+  rewardTiers: Array<OptionOrNullable<RewardTierArgs>>;
+  bonusEndTs: OptionOrNullable<number | bigint>;
+  bonusMultiplierBps: number;
+  lockupExtensionRate: number | bigint;
+  allowlistRequired: boolean;
+  allowlistMerkleRoot: OptionOrNullable<ReadonlyUint8Array>;
+  claimWindow: OptionOrNullable<ClaimWindowArgs>;
+  outflowLimit: OptionOrNullable<OutflowLimitArgs>;
+  outflowWindow: OutflowWindowArgs;
+  poolManager: OptionOrNullable<Address>;
+  keeperBounty: number | bigint;
+  lastKeeperBountyAt: number | bigint;
+  maxTotalRewards: OptionOrNullable<number | bigint>;
+  totalRewardsCommitted: number | bigint;
+  rewardRateMode: RewardRateModeArgs;
+  rewardDecimalsShift: number;
+  enforceMinRemainingStake: boolean;
+  windDownRewards: WindDownRewardsArgs;
+  hookProgram: OptionOrNullable<Address>;
+  pointsRate: number | bigint;
+  denyListRequired: boolean;
+  allowThirdPartyStake: boolean;
+  eventNonce: number | bigint;
+  cohortDuration: number | bigint;
+  openRewardCohorts: number;
   reserved: ReadonlyUint8Array;
 };
 
@@ -111,7 +240,62 @@ export function getStakePoolEncoder(): Encoder<StakePoolArgs> {
     ['pendingRewardRate', getOptionEncoder(getU64Encoder())],
     ['rewardRateChangeTimestamp', getOptionEncoder(getI64Encoder())],
     ['lastRateChange', getOptionEncoder(getI64Encoder())],
-    ['reserved', fixEncoderSize(getBytesEncoder(), 7)],
+    ['referralBps', getU16Encoder()],
+    ['earlyUnstakeBps', getU16Encoder()],
+    ['stakeVaultBump', getOptionEncoder(getU8Encoder())],
+    ['rewardVaultBump', getOptionEncoder(getU8Encoder())],
+    ['feeVault', getAddressEncoder()],
+    ['depositFeeBps', getU16Encoder()],
+    ['withdrawFeeBps', getU16Encoder()],
+    ['feeVaultBump', getOptionEncoder(getU8Encoder())],
+    ['totalRewardsFunded', getU64Encoder()],
+    [
+      'rewardFunders',
+      getArrayEncoder(getOptionEncoder(getAddressEncoder()), { size: 4 }),
+    ],
+    ['receiptMint', getOptionEncoder(getAddressEncoder())],
+    ['expiredRewards', getU64Encoder()],
+    ['unstakeCooldown', getI64Encoder()],
+    ['isRegistered', getBooleanEncoder()],
+    ['rewardRateChangeDelay', getU32Encoder()],
+    ['pendingLockupPeriod', getOptionEncoder(getI64Encoder())],
+    ['pendingEnforceLockup', getOptionEncoder(getBooleanEncoder())],
+    ['lockupChangeTimestamp', getOptionEncoder(getI64Encoder())],
+    ['minRewardRateBound', getOptionEncoder(getU64Encoder())],
+    ['maxRewardRateBound', getOptionEncoder(getU64Encoder())],
+    ['activeStakeCount', getOptionEncoder(getU64Encoder())],
+    [
+      'rewardTiers',
+      getArrayEncoder(getOptionEncoder(getRewardTierEncoder()), { size: 4 }),
+    ],
+    ['bonusEndTs', getOptionEncoder(getI64Encoder())],
+    ['bonusMultiplierBps', getU16Encoder()],
+    ['lockupExtensionRate', getU64Encoder()],
+    ['allowlistRequired', getBooleanEncoder()],
+    [
+      'allowlistMerkleRoot',
+      getOptionEncoder(fixEncoderSize(getBytesEncoder(), 32)),
+    ],
+    ['claimWindow', getOptionEncoder(getClaimWindowEncoder())],
+    ['outflowLimit', getOptionEncoder(getOutflowLimitEncoder())],
+    ['outflowWindow', getOutflowWindowEncoder()],
+    ['poolManager', getOptionEncoder(getAddressEncoder())],
+    ['keeperBounty', getU64Encoder()],
+    ['lastKeeperBountyAt', getI64Encoder()],
+    ['maxTotalRewards', getOptionEncoder(getU64Encoder())],
+    ['totalRewardsCommitted', getU64Encoder()],
+    ['rewardRateMode', getRewardRateModeEncoder()],
+    ['rewardDecimalsShift', getI8Encoder()],
+    ['enforceMinRemainingStake', getBooleanEncoder()],
+    ['windDownRewards', getWindDownRewardsEncoder()],
+    ['hookProgram', getOptionEncoder(getAddressEncoder())],
+    ['pointsRate', getU64Encoder()],
+    ['denyListRequired', getBooleanEncoder()],
+    ['allowThirdPartyStake', getBooleanEncoder()],
+    ['eventNonce', getU64Encoder()],
+    ['cohortDuration', getI64Encoder()],
+    ['openRewardCohorts', getU32Encoder()],
+    ['reserved', fixEncoderSize(getBytesEncoder(), 2)],
   ]);
 }
 
@@ -135,7 +319,62 @@ export function getStakePoolDecoder(): Decoder<StakePool> {
     ['pendingRewardRate', getOptionDecoder(getU64Decoder())],
     ['rewardRateChangeTimestamp', getOptionDecoder(getI64Decoder())],
     ['lastRateChange', getOptionDecoder(getI64Decoder())],
-    ['reserved', fixDecoderSize(getBytesDecoder(), 7)],
+    ['referralBps', getU16Decoder()],
+    ['earlyUnstakeBps', getU16Decoder()],
+    ['stakeVaultBump', getOptionDecoder(getU8Decoder())],
+    ['rewardVaultBump', getOptionDecoder(getU8Decoder())],
+    ['feeVault', getAddressDecoder()],
+    ['depositFeeBps', getU16Decoder()],
+    ['withdrawFeeBps', getU16Decoder()],
+    ['feeVaultBump', getOptionDecoder(getU8Decoder())],
+    ['totalRewardsFunded', getU64Decoder()],
+    [
+      'rewardFunders',
+      getArrayDecoder(getOptionDecoder(getAddressDecoder()), { size: 4 }),
+    ],
+    ['receiptMint', getOptionDecoder(getAddressDecoder())],
+    ['expiredRewards', getU64Decoder()],
+    ['unstakeCooldown', getI64Decoder()],
+    ['isRegistered', getBooleanDecoder()],
+    ['rewardRateChangeDelay', getU32Decoder()],
+    ['pendingLockupPeriod', getOptionDecoder(getI64Decoder())],
+    ['pendingEnforceLockup', getOptionDecoder(getBooleanDecoder())],
+    ['lockupChangeTimestamp', getOptionDecoder(getI64Decoder())],
+    ['minRewardRateBound', getOptionDecoder(getU64Decoder())],
+    ['maxRewardRateBound', getOptionDecoder(getU64Decoder())],
+    ['activeStakeCount', getOptionDecoder(getU64Decoder())],
+    [
+      'rewardTiers',
+      getArrayDecoder(getOptionDecoder(getRewardTierDecoder()), { size: 4 }),
+    ],
+    ['bonusEndTs', getOptionDecoder(getI64Decoder())],
+    ['bonusMultiplierBps', getU16Decoder()],
+    ['lockupExtensionRate', getU64Decoder()],
+    ['allowlistRequired', getBooleanDecoder()],
+    [
+      'allowlistMerkleRoot',
+      getOptionDecoder(fixDecoderSize(getBytesDecoder(), 32)),
+    ],
+    ['claimWindow', getOptionDecoder(getClaimWindowDecoder())],
+    ['outflowLimit', getOptionDecoder(getOutflowLimitDecoder())],
+    ['outflowWindow', getOutflowWindowDecoder()],
+    ['poolManager', getOptionDecoder(getAddressDecoder())],
+    ['keeperBounty', getU64Decoder()],
+    ['lastKeeperBountyAt', getI64Decoder()],
+    ['maxTotalRewards', getOptionDecoder(getU64Decoder())],
+    ['totalRewardsCommitted', getU64Decoder()],
+    ['rewardRateMode', getRewardRateModeDecoder()],
+    ['rewardDecimalsShift', getI8Decoder()],
+    ['enforceMinRemainingStake', getBooleanDecoder()],
+    ['windDownRewards', getWindDownRewardsDecoder()],
+    ['hookProgram', getOptionDecoder(getAddressDecoder())],
+    ['pointsRate', getU64Decoder()],
+    ['denyListRequired', getBooleanDecoder()],
+    ['allowThirdPartyStake', getBooleanDecoder()],
+    ['eventNonce', getU64Decoder()],
+    ['cohortDuration', getI64Decoder()],
+    ['openRewardCohorts', getU32Decoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 2)],
   ]);
 }
 
@@ -94,92 +94,324 @@ export const STAKE_POOL_ERROR__MAX_AUTHORIZED_CREATORS_REACHED = 0x25; // 37
 export const STAKE_POOL_ERROR__CANNOT_REMOVE_MAIN_AUTHORITY = 0x26; // 38
 /** CreatorNotFound: Creator not found in authorized list */
 export const STAKE_POOL_ERROR__CREATOR_NOT_FOUND = 0x27; // 39
+/** InvalidReferrer: Invalid referrer (self-referral or missing referrer account) */
+export const STAKE_POOL_ERROR__INVALID_REFERRER = 0x28; // 40
+/** InvalidTokenAccountOwner: Token account is not owned by the expected wallet */
+export const STAKE_POOL_ERROR__INVALID_TOKEN_ACCOUNT_OWNER = 0x29; // 41
+/** NotNativeSolPool: Pool stake mint is not native SOL */
+export const STAKE_POOL_ERROR__NOT_NATIVE_SOL_POOL = 0x2a; // 42
+/** AutoRelockDisabled: Auto re-lock is disabled for this stake account */
+export const STAKE_POOL_ERROR__AUTO_RELOCK_DISABLED = 0x2b; // 43
+/** DevOnlyInstruction: Instruction is only available on Devnet-profile deployments */
+export const STAKE_POOL_ERROR__DEV_ONLY_INSTRUCTION = 0x2c; // 44
+/** RewardFunderNotAllowed: Funder is not in the pool's reward funder allowlist */
+export const STAKE_POOL_ERROR__REWARD_FUNDER_NOT_ALLOWED = 0x2d; // 45
+/** DefundExceedsSurplus: Defund amount exceeds the reward vault surplus over owed rewards */
+export const STAKE_POOL_ERROR__DEFUND_EXCEEDS_SURPLUS = 0x2e; // 46
+/** TvlCapExceeded: Stake would exceed the stake mint's total value locked cap */
+export const STAKE_POOL_ERROR__TVL_CAP_EXCEEDED = 0x2f; // 47
+/** PoolNotClosable: Pool must be paused with nothing staked or owed before closing */
+export const STAKE_POOL_ERROR__POOL_NOT_CLOSABLE = 0x30; // 48
+/** ReceiptAccountsRequired: Receipt token accounts are required for this pool */
+export const STAKE_POOL_ERROR__RECEIPT_ACCOUNTS_REQUIRED = 0x31; // 49
+/** RewardsNotExpired: Stake rewards have not reached their expiry yet */
+export const STAKE_POOL_ERROR__REWARDS_NOT_EXPIRED = 0x32; // 50
+/** NoExpiredRewards: No expired rewards to move or claim */
+export const STAKE_POOL_ERROR__NO_EXPIRED_REWARDS = 0x33; // 51
+/** UnstakeCooldownRequired: Pool has an unstake cooldown: use RequestUnstake and WithdrawUnstaked */
+export const STAKE_POOL_ERROR__UNSTAKE_COOLDOWN_REQUIRED = 0x34; // 52
+/** UnstakeCooldownActive: Unstake cooldown has not elapsed yet */
+export const STAKE_POOL_ERROR__UNSTAKE_COOLDOWN_ACTIVE = 0x35; // 53
+/** NoPendingWithdrawal: Stake account has no pending withdrawal */
+export const STAKE_POOL_ERROR__NO_PENDING_WITHDRAWAL = 0x36; // 54
+/** ProtocolPaused: Protocol is globally paused */
+export const STAKE_POOL_ERROR__PROTOCOL_PAUSED = 0x37; // 55
+/** WithdrawalsOnly: Protocol is in withdrawals-only mode */
+export const STAKE_POOL_ERROR__WITHDRAWALS_ONLY = 0x38; // 56
+/** AccountNeedsMigration: Account uses a legacy layout and must be migrated first */
+export const STAKE_POOL_ERROR__ACCOUNT_NEEDS_MIGRATION = 0x39; // 57
+/** PoolRegistryPageFull: Pool registry page is full */
+export const STAKE_POOL_ERROR__POOL_REGISTRY_PAGE_FULL = 0x3a; // 58
+/** CreatorPoolLimitReached: Creator has reached its pool limit */
+export const STAKE_POOL_ERROR__CREATOR_POOL_LIMIT_REACHED = 0x3b; // 59
+/** MaxRoleHoldersReached: Maximum number of role holders reached */
+export const STAKE_POOL_ERROR__MAX_ROLE_HOLDERS_REACHED = 0x3c; // 60
+/** ApprovalRequired: Sensitive pool updates need multi-admin approval */
+export const STAKE_POOL_ERROR__APPROVAL_REQUIRED = 0x3d; // 61
+/** AlreadyApproved: Admin already approved this update */
+export const STAKE_POOL_ERROR__ALREADY_APPROVED = 0x3e; // 62
+/** InsufficientApprovals: Not enough approvals to execute the update */
+export const STAKE_POOL_ERROR__INSUFFICIENT_APPROVALS = 0x3f; // 63
+/** PendingLockupChangeExists: A lockup change is already pending */
+export const STAKE_POOL_ERROR__PENDING_LOCKUP_CHANGE_EXISTS = 0x40; // 64
+/** NoPendingLockupChange: No pending lockup change to finalize */
+export const STAKE_POOL_ERROR__NO_PENDING_LOCKUP_CHANGE = 0x41; // 65
+/** LockupChangeDelayNotElapsed: Lockup change delay has not elapsed */
+export const STAKE_POOL_ERROR__LOCKUP_CHANGE_DELAY_NOT_ELAPSED = 0x42; // 66
+/** RewardRateOutOfBounds: Reward rate outside the pool's committed bounds */
+export const STAKE_POOL_ERROR__REWARD_RATE_OUT_OF_BOUNDS = 0x43; // 67
+/** ClaimExceedsUnclaimedRewards: Claim amount exceeds the unclaimed rewards */
+export const STAKE_POOL_ERROR__CLAIM_EXCEEDS_UNCLAIMED_REWARDS = 0x44; // 68
+/** NoPendingStakeOwner: No owner nominated for the stake account */
+export const STAKE_POOL_ERROR__NO_PENDING_STAKE_OWNER = 0x45; // 69
+/** InvalidPendingStakeOwner: Signer is not the nominated stake owner */
+export const STAKE_POOL_ERROR__INVALID_PENDING_STAKE_OWNER = 0x46; // 70
+/** ReceiptPositionNotTransferable: Positions of pools with receipt tokens cannot change owner */
+export const STAKE_POOL_ERROR__RECEIPT_POSITION_NOT_TRANSFERABLE = 0x47; // 71
+/** LockupExtensionDisabled: The pool offers no bonus for lockup extensions */
+export const STAKE_POOL_ERROR__LOCKUP_EXTENSION_DISABLED = 0x48; // 72
+/** NotAllowlisted: The stake owner is not on the pool's allowlist */
+export const STAKE_POOL_ERROR__NOT_ALLOWLISTED = 0x49; // 73
+/** PoolAllowlistPageFull: The pool allowlist page is full */
+export const STAKE_POOL_ERROR__POOL_ALLOWLIST_PAGE_FULL = 0x4a; // 74
+/** PrincipalNotVested: The stake's principal has not vested yet */
+export const STAKE_POOL_ERROR__PRINCIPAL_NOT_VESTED = 0x4b; // 75
+/** ClaimWindowClosed: Rewards can only be claimed while the pool's claim window is open */
+export const STAKE_POOL_ERROR__CLAIM_WINDOW_CLOSED = 0x4c; // 76
+/** StakeSlashPending: A slash of the stake is pending */
+export const STAKE_POOL_ERROR__STAKE_SLASH_PENDING = 0x4d; // 77
+/** SlashTimelockActive: The slash timelock has not passed yet */
+export const STAKE_POOL_ERROR__SLASH_TIMELOCK_ACTIVE = 0x4e; // 78
+/** NoRecoveryInProgress: No authority recovery is in progress */
+export const STAKE_POOL_ERROR__NO_RECOVERY_IN_PROGRESS = 0x4f; // 79
+/** RecoveryDelayActive: The recovery delay has not passed yet */
+export const STAKE_POOL_ERROR__RECOVERY_DELAY_ACTIVE = 0x50; // 80
+/** InvalidMemo: Memo must be between 1 and MAX_MEMO_LEN bytes */
+export const STAKE_POOL_ERROR__INVALID_MEMO = 0x51; // 81
+/** VaultOrderMismatch: Pool vault passed in the wrong account slot */
+export const STAKE_POOL_ERROR__VAULT_ORDER_MISMATCH = 0x52; // 82
+/** RateLimited: The pool's outflow limit for the current window has been reached */
+export const STAKE_POOL_ERROR__RATE_LIMITED = 0x53; // 83
+/** StakePositionFull: Stake position directory is full */
+export const STAKE_POOL_ERROR__STAKE_POSITION_FULL = 0x54; // 84
+/** RewardsCapExceeded: Pool rewards cap exceeded */
+export const STAKE_POOL_ERROR__REWARDS_CAP_EXCEEDED = 0x55; // 85
+/** IncompleteAudit: Audit does not cover every active stake */
+export const STAKE_POOL_ERROR__INCOMPLETE_AUDIT = 0x56; // 86
+/** RemainingStakeBelowMinimum: Remaining stake below the pool's minimum */
+export const STAKE_POOL_ERROR__REMAINING_STAKE_BELOW_MINIMUM = 0x57; // 87
+/** WindDownNotStarted: Pool wind-down has not started */
+export const STAKE_POOL_ERROR__WIND_DOWN_NOT_STARTED = 0x58; // 88
+/** HookProgramNotAllowed: Hook program not allowed */
+export const STAKE_POOL_ERROR__HOOK_PROGRAM_NOT_ALLOWED = 0x59; // 89
+/** HookProgramRequired: Pool hook program required */
+export const STAKE_POOL_ERROR__HOOK_PROGRAM_REQUIRED = 0x5a; // 90
+/** PointsNotEnabled: Pool points not enabled */
+export const STAKE_POOL_ERROR__POINTS_NOT_ENABLED = 0x5b; // 91
+/** AddressDenied: Address is on the deny list */
+export const STAKE_POOL_ERROR__ADDRESS_DENIED = 0x5c; // 92
+/** DenyListRequired: Pool deny list required */
+export const STAKE_POOL_ERROR__DENY_LIST_REQUIRED = 0x5d; // 93
+/** ThirdPartyStakeDisabled: Third-party stakes not allowed */
+export const STAKE_POOL_ERROR__THIRD_PARTY_STAKE_DISABLED = 0x5e; // 94
+/** StakesNotMergeable: Stake accounts cannot be merged */
+export const STAKE_POOL_ERROR__STAKES_NOT_MERGEABLE = 0x5f; // 95
+/** DeadlineExceeded: Transaction deadline passed */
+export const STAKE_POOL_ERROR__DEADLINE_EXCEEDED = 0x60; // 96
+/** InvalidRewardCohort: Invalid reward cohort */
+export const STAKE_POOL_ERROR__INVALID_REWARD_COHORT = 0x61; // 97
 
 export type StakePoolError =
   | typeof STAKE_POOL_ERROR__ACCOUNT_MISMATCH
+  | typeof STAKE_POOL_ERROR__ACCOUNT_NEEDS_MIGRATION
   | typeof STAKE_POOL_ERROR__ACCOUNT_SIZE_TOO_SMALL
+  | typeof STAKE_POOL_ERROR__ADDRESS_DENIED
+  | typeof STAKE_POOL_ERROR__ALREADY_APPROVED
   | typeof STAKE_POOL_ERROR__AMOUNT_BELOW_MINIMUM
+  | typeof STAKE_POOL_ERROR__APPROVAL_REQUIRED
+  | typeof STAKE_POOL_ERROR__AUTO_RELOCK_DISABLED
   | typeof STAKE_POOL_ERROR__CANNOT_REMOVE_MAIN_AUTHORITY
+  | typeof STAKE_POOL_ERROR__CLAIM_EXCEEDS_UNCLAIMED_REWARDS
+  | typeof STAKE_POOL_ERROR__CLAIM_WINDOW_CLOSED
   | typeof STAKE_POOL_ERROR__CREATOR_ALREADY_AUTHORIZED
   | typeof STAKE_POOL_ERROR__CREATOR_NOT_FOUND
+  | typeof STAKE_POOL_ERROR__CREATOR_POOL_LIMIT_REACHED
   | typeof STAKE_POOL_ERROR__DATA_CORRUPTION
+  | typeof STAKE_POOL_ERROR__DEADLINE_EXCEEDED
+  | typeof STAKE_POOL_ERROR__DEFUND_EXCEEDS_SURPLUS
+  | typeof STAKE_POOL_ERROR__DENY_LIST_REQUIRED
   | typeof STAKE_POOL_ERROR__DESERIALIZATION_ERROR
+  | typeof STAKE_POOL_ERROR__DEV_ONLY_INSTRUCTION
   | typeof STAKE_POOL_ERROR__EXPECTED_EMPTY_ACCOUNT
   | typeof STAKE_POOL_ERROR__EXPECTED_NON_EMPTY_ACCOUNT
   | typeof STAKE_POOL_ERROR__EXPECTED_SIGNER_ACCOUNT
   | typeof STAKE_POOL_ERROR__EXPECTED_WRITABLE_ACCOUNT
+  | typeof STAKE_POOL_ERROR__HOOK_PROGRAM_NOT_ALLOWED
+  | typeof STAKE_POOL_ERROR__HOOK_PROGRAM_REQUIRED
+  | typeof STAKE_POOL_ERROR__INCOMPLETE_AUDIT
+  | typeof STAKE_POOL_ERROR__INSUFFICIENT_APPROVALS
   | typeof STAKE_POOL_ERROR__INSUFFICIENT_REWARDS
   | typeof STAKE_POOL_ERROR__INSUFFICIENT_STAKED_BALANCE
   | typeof STAKE_POOL_ERROR__INVALID_ACCOUNT_DISCRIMINATOR
   | typeof STAKE_POOL_ERROR__INVALID_ACCOUNT_KEY
+  | typeof STAKE_POOL_ERROR__INVALID_MEMO
   | typeof STAKE_POOL_ERROR__INVALID_MINT
   | typeof STAKE_POOL_ERROR__INVALID_PARAMETERS
   | typeof STAKE_POOL_ERROR__INVALID_PDA
   | typeof STAKE_POOL_ERROR__INVALID_PENDING_AUTHORITY
+  | typeof STAKE_POOL_ERROR__INVALID_PENDING_STAKE_OWNER
   | typeof STAKE_POOL_ERROR__INVALID_PROGRAM_OWNER
+  | typeof STAKE_POOL_ERROR__INVALID_REFERRER
+  | typeof STAKE_POOL_ERROR__INVALID_REWARD_COHORT
   | typeof STAKE_POOL_ERROR__INVALID_TIMESTAMP
+  | typeof STAKE_POOL_ERROR__INVALID_TOKEN_ACCOUNT_OWNER
   | typeof STAKE_POOL_ERROR__INVALID_TOKEN_PROGRAM
   | typeof STAKE_POOL_ERROR__INVALID_VAULT_OWNER
+  | typeof STAKE_POOL_ERROR__LOCKUP_CHANGE_DELAY_NOT_ELAPSED
+  | typeof STAKE_POOL_ERROR__LOCKUP_EXTENSION_DISABLED
   | typeof STAKE_POOL_ERROR__LOCKUP_NOT_EXPIRED
   | typeof STAKE_POOL_ERROR__MAX_AUTHORIZED_CREATORS_REACHED
+  | typeof STAKE_POOL_ERROR__MAX_ROLE_HOLDERS_REACHED
   | typeof STAKE_POOL_ERROR__MINT_HAS_FREEZE_AUTHORITY
+  | typeof STAKE_POOL_ERROR__NO_EXPIRED_REWARDS
   | typeof STAKE_POOL_ERROR__NO_PENDING_AUTHORITY
+  | typeof STAKE_POOL_ERROR__NO_PENDING_LOCKUP_CHANGE
   | typeof STAKE_POOL_ERROR__NO_PENDING_REWARD_RATE_CHANGE
+  | typeof STAKE_POOL_ERROR__NO_PENDING_STAKE_OWNER
+  | typeof STAKE_POOL_ERROR__NO_PENDING_WITHDRAWAL
+  | typeof STAKE_POOL_ERROR__NO_RECOVERY_IN_PROGRESS
+  | typeof STAKE_POOL_ERROR__NOT_ALLOWLISTED
+  | typeof STAKE_POOL_ERROR__NOT_NATIVE_SOL_POOL
   | typeof STAKE_POOL_ERROR__NUMERICAL_OVERFLOW
+  | typeof STAKE_POOL_ERROR__PENDING_LOCKUP_CHANGE_EXISTS
   | typeof STAKE_POOL_ERROR__PENDING_REWARD_RATE_CHANGE_EXISTS
+  | typeof STAKE_POOL_ERROR__POINTS_NOT_ENABLED
+  | typeof STAKE_POOL_ERROR__POOL_ALLOWLIST_PAGE_FULL
   | typeof STAKE_POOL_ERROR__POOL_ENDED
+  | typeof STAKE_POOL_ERROR__POOL_NOT_CLOSABLE
   | typeof STAKE_POOL_ERROR__POOL_PARAMETERS_CHANGED
   | typeof STAKE_POOL_ERROR__POOL_PAUSED
+  | typeof STAKE_POOL_ERROR__POOL_REGISTRY_PAGE_FULL
+  | typeof STAKE_POOL_ERROR__PRINCIPAL_NOT_VESTED
+  | typeof STAKE_POOL_ERROR__PROTOCOL_PAUSED
+  | typeof STAKE_POOL_ERROR__RATE_LIMITED
+  | typeof STAKE_POOL_ERROR__RECEIPT_ACCOUNTS_REQUIRED
+  | typeof STAKE_POOL_ERROR__RECEIPT_POSITION_NOT_TRANSFERABLE
+  | typeof STAKE_POOL_ERROR__RECOVERY_DELAY_ACTIVE
+  | typeof STAKE_POOL_ERROR__REMAINING_STAKE_BELOW_MINIMUM
+  | typeof STAKE_POOL_ERROR__REWARD_FUNDER_NOT_ALLOWED
   | typeof STAKE_POOL_ERROR__REWARD_RATE_CHANGE_DELAY_NOT_ELAPSED
+  | typeof STAKE_POOL_ERROR__REWARD_RATE_OUT_OF_BOUNDS
+  | typeof STAKE_POOL_ERROR__REWARDS_CAP_EXCEEDED
+  | typeof STAKE_POOL_ERROR__REWARDS_NOT_EXPIRED
   | typeof STAKE_POOL_ERROR__SERIALIZATION_ERROR
+  | typeof STAKE_POOL_ERROR__SLASH_TIMELOCK_ACTIVE
+  | typeof STAKE_POOL_ERROR__STAKE_POSITION_FULL
+  | typeof STAKE_POOL_ERROR__STAKE_SLASH_PENDING
+  | typeof STAKE_POOL_ERROR__STAKES_NOT_MERGEABLE
+  | typeof STAKE_POOL_ERROR__THIRD_PARTY_STAKE_DISABLED
+  | typeof STAKE_POOL_ERROR__TVL_CAP_EXCEEDED
   | typeof STAKE_POOL_ERROR__UNAUTHORIZED
   | typeof STAKE_POOL_ERROR__UNAUTHORIZED_POOL_CREATOR
   | typeof STAKE_POOL_ERROR__UNEXPECTED_BALANCE_CHANGE
-  | typeof STAKE_POOL_ERROR__UNSAFE_TOKEN_EXTENSION;
+  | typeof STAKE_POOL_ERROR__UNSAFE_TOKEN_EXTENSION
+  | typeof STAKE_POOL_ERROR__UNSTAKE_COOLDOWN_ACTIVE
+  | typeof STAKE_POOL_ERROR__UNSTAKE_COOLDOWN_REQUIRED
+  | typeof STAKE_POOL_ERROR__VAULT_ORDER_MISMATCH
+  | typeof STAKE_POOL_ERROR__WIND_DOWN_NOT_STARTED
+  | typeof STAKE_POOL_ERROR__WITHDRAWALS_ONLY;
 
 let stakePoolErrorMessages: Record<StakePoolError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   stakePoolErrorMessages = {
     [STAKE_POOL_ERROR__ACCOUNT_MISMATCH]: `Account mismatch`,
+    [STAKE_POOL_ERROR__ACCOUNT_NEEDS_MIGRATION]: `Account uses a legacy layout and must be migrated first`,
     [STAKE_POOL_ERROR__ACCOUNT_SIZE_TOO_SMALL]: `Account size too small for serialized data`,
+    [STAKE_POOL_ERROR__ADDRESS_DENIED]: `Address is on the deny list`,
+    [STAKE_POOL_ERROR__ALREADY_APPROVED]: `Admin already approved this update`,
     [STAKE_POOL_ERROR__AMOUNT_BELOW_MINIMUM]: `Amount below minimum stake`,
+    [STAKE_POOL_ERROR__APPROVAL_REQUIRED]: `Sensitive pool updates need multi-admin approval`,
+    [STAKE_POOL_ERROR__AUTO_RELOCK_DISABLED]: `Auto re-lock is disabled for this stake account`,
     [STAKE_POOL_ERROR__CANNOT_REMOVE_MAIN_AUTHORITY]: `Cannot remove main authority from authorized creators`,
+    [STAKE_POOL_ERROR__CLAIM_EXCEEDS_UNCLAIMED_REWARDS]: `Claim amount exceeds the unclaimed rewards`,
+    [STAKE_POOL_ERROR__CLAIM_WINDOW_CLOSED]: `Rewards can only be claimed while the pool's claim window is open`,
     [STAKE_POOL_ERROR__CREATOR_ALREADY_AUTHORIZED]: `Creator already authorized`,
     [STAKE_POOL_ERROR__CREATOR_NOT_FOUND]: `Creator not found in authorized list`,
+    [STAKE_POOL_ERROR__CREATOR_POOL_LIMIT_REACHED]: `Creator has reached its pool limit`,
     [STAKE_POOL_ERROR__DATA_CORRUPTION]: `Data corruption detected (state invariant violated)`,
+    [STAKE_POOL_ERROR__DEADLINE_EXCEEDED]: `Transaction deadline passed`,
+    [STAKE_POOL_ERROR__DEFUND_EXCEEDS_SURPLUS]: `Defund amount exceeds the reward vault surplus over owed rewards`,
+    [STAKE_POOL_ERROR__DENY_LIST_REQUIRED]: `Pool deny list required`,
     [STAKE_POOL_ERROR__DESERIALIZATION_ERROR]: `Error deserializing an account`,
+    [STAKE_POOL_ERROR__DEV_ONLY_INSTRUCTION]: `Instruction is only available on Devnet-profile deployments`,
     [STAKE_POOL_ERROR__EXPECTED_EMPTY_ACCOUNT]: `Expected empty account`,
     [STAKE_POOL_ERROR__EXPECTED_NON_EMPTY_ACCOUNT]: `Expected non empty account`,
     [STAKE_POOL_ERROR__EXPECTED_SIGNER_ACCOUNT]: `Expected signer account`,
     [STAKE_POOL_ERROR__EXPECTED_WRITABLE_ACCOUNT]: `Expected writable account`,
+    [STAKE_POOL_ERROR__HOOK_PROGRAM_NOT_ALLOWED]: `Hook program not allowed`,
+    [STAKE_POOL_ERROR__HOOK_PROGRAM_REQUIRED]: `Pool hook program required`,
+    [STAKE_POOL_ERROR__INCOMPLETE_AUDIT]: `Audit does not cover every active stake`,
+    [STAKE_POOL_ERROR__INSUFFICIENT_APPROVALS]: `Not enough approvals to execute the update`,
     [STAKE_POOL_ERROR__INSUFFICIENT_REWARDS]: `Insufficient rewards in pool`,
     [STAKE_POOL_ERROR__INSUFFICIENT_STAKED_BALANCE]: `Insufficient staked balance`,
     [STAKE_POOL_ERROR__INVALID_ACCOUNT_DISCRIMINATOR]: `Invalid account discriminator`,
     [STAKE_POOL_ERROR__INVALID_ACCOUNT_KEY]: `Invalid account key`,
+    [STAKE_POOL_ERROR__INVALID_MEMO]: `Memo must be between 1 and MAX_MEMO_LEN bytes`,
     [STAKE_POOL_ERROR__INVALID_MINT]: `Invalid mint`,
     [STAKE_POOL_ERROR__INVALID_PARAMETERS]: `Invalid parameters`,
     [STAKE_POOL_ERROR__INVALID_PDA]: `Invalid PDA derivation`,
     [STAKE_POOL_ERROR__INVALID_PENDING_AUTHORITY]: `Invalid pending authority`,
+    [STAKE_POOL_ERROR__INVALID_PENDING_STAKE_OWNER]: `Signer is not the nominated stake owner`,
     [STAKE_POOL_ERROR__INVALID_PROGRAM_OWNER]: `Invalid program owner`,
+    [STAKE_POOL_ERROR__INVALID_REFERRER]: `Invalid referrer (self-referral or missing referrer account)`,
+    [STAKE_POOL_ERROR__INVALID_REWARD_COHORT]: `Invalid reward cohort`,
     [STAKE_POOL_ERROR__INVALID_TIMESTAMP]: `Invalid timestamp`,
+    [STAKE_POOL_ERROR__INVALID_TOKEN_ACCOUNT_OWNER]: `Token account is not owned by the expected wallet`,
     [STAKE_POOL_ERROR__INVALID_TOKEN_PROGRAM]: `Invalid token program`,
     [STAKE_POOL_ERROR__INVALID_VAULT_OWNER]: `Invalid vault owner (vault must be owned by pool PDA)`,
+    [STAKE_POOL_ERROR__LOCKUP_CHANGE_DELAY_NOT_ELAPSED]: `Lockup change delay has not elapsed`,
+    [STAKE_POOL_ERROR__LOCKUP_EXTENSION_DISABLED]: `The pool offers no bonus for lockup extensions`,
     [STAKE_POOL_ERROR__LOCKUP_NOT_EXPIRED]: `Lockup period not expired`,
     [STAKE_POOL_ERROR__MAX_AUTHORIZED_CREATORS_REACHED]: `Maximum number of authorized creators reached`,
+    [STAKE_POOL_ERROR__MAX_ROLE_HOLDERS_REACHED]: `Maximum number of role holders reached`,
     [STAKE_POOL_ERROR__MINT_HAS_FREEZE_AUTHORITY]: `Mint has freeze authority (can lock user funds)`,
+    [STAKE_POOL_ERROR__NO_EXPIRED_REWARDS]: `No expired rewards to move or claim`,
     [STAKE_POOL_ERROR__NO_PENDING_AUTHORITY]: `No pending authority transfer`,
+    [STAKE_POOL_ERROR__NO_PENDING_LOCKUP_CHANGE]: `No pending lockup change to finalize`,
     [STAKE_POOL_ERROR__NO_PENDING_REWARD_RATE_CHANGE]: `No pending reward rate change`,
+    [STAKE_POOL_ERROR__NO_PENDING_STAKE_OWNER]: `No owner nominated for the stake account`,
+    [STAKE_POOL_ERROR__NO_PENDING_WITHDRAWAL]: `Stake account has no pending withdrawal`,
+    [STAKE_POOL_ERROR__NO_RECOVERY_IN_PROGRESS]: `No authority recovery is in progress`,
+    [STAKE_POOL_ERROR__NOT_ALLOWLISTED]: `The stake owner is not on the pool's allowlist`,
+    [STAKE_POOL_ERROR__NOT_NATIVE_SOL_POOL]: `Pool stake mint is not native SOL`,
     [STAKE_POOL_ERROR__NUMERICAL_OVERFLOW]: `Numerical overflow`,
+    [STAKE_POOL_ERROR__PENDING_LOCKUP_CHANGE_EXISTS]: `A lockup change is already pending`,
     [STAKE_POOL_ERROR__PENDING_REWARD_RATE_CHANGE_EXISTS]: `Pending reward rate change already exists`,
+    [STAKE_POOL_ERROR__POINTS_NOT_ENABLED]: `Pool points not enabled`,
+    [STAKE_POOL_ERROR__POOL_ALLOWLIST_PAGE_FULL]: `The pool allowlist page is full`,
     [STAKE_POOL_ERROR__POOL_ENDED]: `Pool has ended (no new stakes allowed)`,
+    [STAKE_POOL_ERROR__POOL_NOT_CLOSABLE]: `Pool must be paused with nothing staked or owed before closing`,
     [STAKE_POOL_ERROR__POOL_PARAMETERS_CHANGED]: `Pool parameters changed (frontrunning protection)`,
     [STAKE_POOL_ERROR__POOL_PAUSED]: `Pool is paused`,
+    [STAKE_POOL_ERROR__POOL_REGISTRY_PAGE_FULL]: `Pool registry page is full`,
+    [STAKE_POOL_ERROR__PRINCIPAL_NOT_VESTED]: `The stake's principal has not vested yet`,
+    [STAKE_POOL_ERROR__PROTOCOL_PAUSED]: `Protocol is globally paused`,
+    [STAKE_POOL_ERROR__RATE_LIMITED]: `The pool's outflow limit for the current window has been reached`,
+    [STAKE_POOL_ERROR__RECEIPT_ACCOUNTS_REQUIRED]: `Receipt token accounts are required for this pool`,
+    [STAKE_POOL_ERROR__RECEIPT_POSITION_NOT_TRANSFERABLE]: `Positions of pools with receipt tokens cannot change owner`,
+    [STAKE_POOL_ERROR__RECOVERY_DELAY_ACTIVE]: `The recovery delay has not passed yet`,
+    [STAKE_POOL_ERROR__REMAINING_STAKE_BELOW_MINIMUM]: `Remaining stake below the pool's minimum`,
+    [STAKE_POOL_ERROR__REWARD_FUNDER_NOT_ALLOWED]: `Funder is not in the pool's reward funder allowlist`,
     [STAKE_POOL_ERROR__REWARD_RATE_CHANGE_DELAY_NOT_ELAPSED]: `Reward rate change delay not elapsed`,
+    [STAKE_POOL_ERROR__REWARD_RATE_OUT_OF_BOUNDS]: `Reward rate outside the pool's committed bounds`,
+    [STAKE_POOL_ERROR__REWARDS_CAP_EXCEEDED]: `Pool rewards cap exceeded`,
+    [STAKE_POOL_ERROR__REWARDS_NOT_EXPIRED]: `Stake rewards have not reached their expiry yet`,
     [STAKE_POOL_ERROR__SERIALIZATION_ERROR]: `Error serializing an account`,
+    [STAKE_POOL_ERROR__SLASH_TIMELOCK_ACTIVE]: `The slash timelock has not passed yet`,
+    [STAKE_POOL_ERROR__STAKE_POSITION_FULL]: `Stake position directory is full`,
+    [STAKE_POOL_ERROR__STAKE_SLASH_PENDING]: `A slash of the stake is pending`,
+    [STAKE_POOL_ERROR__STAKES_NOT_MERGEABLE]: `Stake accounts cannot be merged`,
+    [STAKE_POOL_ERROR__THIRD_PARTY_STAKE_DISABLED]: `Third-party stakes not allowed`,
+    [STAKE_POOL_ERROR__TVL_CAP_EXCEEDED]: `Stake would exceed the stake mint's total value locked cap`,
     [STAKE_POOL_ERROR__UNAUTHORIZED]: `Unauthorized`,
     [STAKE_POOL_ERROR__UNAUTHORIZED_POOL_CREATOR]: `Unauthorized pool creator (only authorized admins can create pools)`,
     [STAKE_POOL_ERROR__UNEXPECTED_BALANCE_CHANGE]: `Unexpected token balance change during transfer`,
     [STAKE_POOL_ERROR__UNSAFE_TOKEN_EXTENSION]: `Unsafe Token-2022 extension detected`,
+    [STAKE_POOL_ERROR__UNSTAKE_COOLDOWN_ACTIVE]: `Unstake cooldown has not elapsed yet`,
+    [STAKE_POOL_ERROR__UNSTAKE_COOLDOWN_REQUIRED]: `Pool has an unstake cooldown: use RequestUnstake and WithdrawUnstaked`,
+    [STAKE_POOL_ERROR__VAULT_ORDER_MISMATCH]: `Pool vault passed in the wrong account slot`,
+    [STAKE_POOL_ERROR__WIND_DOWN_NOT_STARTED]: `Pool wind-down has not started`,
+    [STAKE_POOL_ERROR__WITHDRAWALS_ONLY]: `Protocol is in withdrawals-only mode`,
   };
 }
 
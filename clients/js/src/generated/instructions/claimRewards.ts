@@ -7,21 +7,35 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
+  getI64Decoder,
+  getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
@@ -49,6 +63,15 @@ export type ClaimRewardsInstruction<
     | string
     | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
   TAccountClock extends string | AccountMeta<string> = string,
+  TAccountReferrerRewardAccount extends string | AccountMeta<string> = string,
+  TAccountProgramAuthority extends string | AccountMeta<string> = string,
+  TAccountRecipient extends string | AccountMeta<string> = string,
+  TAccountAssociatedTokenProgram extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends string | AccountMeta<string> = string,
+  TAccountMemoProgram extends string | AccountMeta<string> = string,
+  TAccountDenyList extends string | AccountMeta<string> = string,
+  TAccountRewardCohort extends string | AccountMeta<string> = string,
+  TAccountHookProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -79,26 +102,80 @@ export type ClaimRewardsInstruction<
       TAccountClock extends string
         ? ReadonlyAccount<TAccountClock>
         : TAccountClock,
+      TAccountReferrerRewardAccount extends string
+        ? WritableAccount<TAccountReferrerRewardAccount>
+        : TAccountReferrerRewardAccount,
+      TAccountProgramAuthority extends string
+        ? ReadonlyAccount<TAccountProgramAuthority>
+        : TAccountProgramAuthority,
+      TAccountRecipient extends string
+        ? WritableAccount<TAccountRecipient>
+        : TAccountRecipient,
+      TAccountAssociatedTokenProgram extends string
+        ? ReadonlyAccount<TAccountAssociatedTokenProgram>
+        : TAccountAssociatedTokenProgram,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountMemoProgram extends string
+        ? ReadonlyAccount<TAccountMemoProgram>
+        : TAccountMemoProgram,
+      TAccountDenyList extends string
+        ? ReadonlyAccount<TAccountDenyList>
+        : TAccountDenyList,
+      TAccountRewardCohort extends string
+        ? WritableAccount<TAccountRewardCohort>
+        : TAccountRewardCohort,
+      TAccountHookProgram extends string
+        ? ReadonlyAccount<TAccountHookProgram>
+        : TAccountHookProgram,
       ...TRemainingAccounts,
     ]
   >;
 
-export type ClaimRewardsInstructionData = { discriminator: number };
+export type ClaimRewardsInstructionData = {
+  discriminator: number;
+  amount: Option<bigint>;
+  memo: Option<string>;
+  deadline: Option<bigint>;
+};
 
-export type ClaimRewardsInstructionDataArgs = {};
+export type ClaimRewardsInstructionDataArgs = {
+  amount: OptionOrNullable<number | bigint>;
+  memo: OptionOrNullable<string>;
+  deadline: OptionOrNullable<number | bigint>;
+};
 
-export function getClaimRewardsInstructionDataEncoder(): FixedSizeEncoder<ClaimRewardsInstructionDataArgs> {
+export function getClaimRewardsInstructionDataEncoder(): Encoder<ClaimRewardsInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', getU8Encoder()]]),
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getOptionEncoder(getU64Encoder())],
+      [
+        'memo',
+        getOptionEncoder(
+          addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())
+        ),
+      ],
+      ['deadline', getOptionEncoder(getI64Encoder())],
+    ]),
     (value) => ({ ...value, discriminator: CLAIM_REWARDS_DISCRIMINATOR })
   );
 }
 
-export function getClaimRewardsInstructionDataDecoder(): FixedSizeDecoder<ClaimRewardsInstructionData> {
-  return getStructDecoder([['discriminator', getU8Decoder()]]);
+export function getClaimRewardsInstructionDataDecoder(): Decoder<ClaimRewardsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getOptionDecoder(getU64Decoder())],
+    [
+      'memo',
+      getOptionDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())),
+    ],
+    ['deadline', getOptionDecoder(getI64Decoder())],
+  ]);
 }
 
-export function getClaimRewardsInstructionDataCodec(): FixedSizeCodec<
+export function getClaimRewardsInstructionDataCodec(): Codec<
   ClaimRewardsInstructionDataArgs,
   ClaimRewardsInstructionData
 > {
@@ -117,16 +194,25 @@ export type ClaimRewardsInput<
   TAccountRewardMint extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountClock extends string = string,
+  TAccountReferrerRewardAccount extends string = string,
+  TAccountProgramAuthority extends string = string,
+  TAccountRecipient extends string = string,
+  TAccountAssociatedTokenProgram extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountMemoProgram extends string = string,
+  TAccountDenyList extends string = string,
+  TAccountRewardCohort extends string = string,
+  TAccountHookProgram extends string = string,
 > = {
   /** The stake pool */
   pool: Address<TAccountPool>;
   /** The user's stake account */
   stakeAccount: Address<TAccountStakeAccount>;
-  /** The stake account owner */
+  /** The stake account owner, or its delegate (rewards then go to an owner token account only) */
   owner: TransactionSigner<TAccountOwner>;
-  /** User's reward token account */
+  /** Reward token account owned by the stake owner */
   userRewardAccount: Address<TAccountUserRewardAccount>;
-  /** Pool's reward vault */
+  /** Pool's reward vault, or the stake's cohort vault in pools with reward cohorts */
   rewardVault: Address<TAccountRewardVault>;
   /** The reward token mint */
   rewardMint: Address<TAccountRewardMint>;
@@ -134,6 +220,27 @@ export type ClaimRewardsInput<
   tokenProgram?: Address<TAccountTokenProgram>;
   /** Clock sysvar */
   clock: Address<TAccountClock>;
+  /** Referrer's reward token account (required when the stake has a referrer and the pool pays referrals) */
+  referrerRewardAccount?: Address<TAccountReferrerRewardAccount>;
+  /** The program authority account (global pause switches) */
+  programAuthority: Address<TAccountProgramAuthority>;
+  /** Reward token account to pay instead of user_reward_account (stake owner only) */
+  recipient?: Address<TAccountRecipient>;
+  /** The associated token account program (creates user_reward_account when missing; owner must then sign as a writable payer) */
+  associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
+  /** The system program (required with associated_token_program) */
+  systemProgram?: Address<TAccountSystemProgram>;
+  /** The SPL Memo program (required with a memo) */
+  memoProgram?: Address<TAccountMemoProgram>;
+  /** The global deny list PDA ["deny_list"] (required when the pool checks it) */
+  denyList?: Address<TAccountDenyList>;
+  /** The stake's RewardCohort PDA ["reward_cohort", pool, cohort] (required for stakes of a reward cohort) */
+  rewardCohort?: Address<TAccountRewardCohort>;
+  /** The pool's hook program (required when the pool has one); the hook's own accounts follow */
+  hookProgram?: Address<TAccountHookProgram>;
+  amount: ClaimRewardsInstructionDataArgs['amount'];
+  memo: ClaimRewardsInstructionDataArgs['memo'];
+  deadline: ClaimRewardsInstructionDataArgs['deadline'];
 };
 
 export function getClaimRewardsInstruction<
@@ -145,6 +252,15 @@ export function getClaimRewardsInstruction<
   TAccountRewardMint extends string,
   TAccountTokenProgram extends string,
   TAccountClock extends string,
+  TAccountReferrerRewardAccount extends string,
+  TAccountProgramAuthority extends string,
+  TAccountRecipient extends string,
+  TAccountAssociatedTokenProgram extends string,
+  TAccountSystemProgram extends string,
+  TAccountMemoProgram extends string,
+  TAccountDenyList extends string,
+  TAccountRewardCohort extends string,
+  TAccountHookProgram extends string,
   TProgramAddress extends Address = typeof STAKE_POOL_PROGRAM_ADDRESS,
 >(
   input: ClaimRewardsInput<
@@ -155,7 +271,16 @@ export function getClaimRewardsInstruction<
     TAccountRewardVault,
     TAccountRewardMint,
     TAccountTokenProgram,
-    TAccountClock
+    TAccountClock,
+    TAccountReferrerRewardAccount,
+    TAccountProgramAuthority,
+    TAccountRecipient,
+    TAccountAssociatedTokenProgram,
+    TAccountSystemProgram,
+    TAccountMemoProgram,
+    TAccountDenyList,
+    TAccountRewardCohort,
+    TAccountHookProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): ClaimRewardsInstruction<
@@ -167,7 +292,16 @@ export function getClaimRewardsInstruction<
   TAccountRewardVault,
   TAccountRewardMint,
   TAccountTokenProgram,
-  TAccountClock
+  TAccountClock,
+  TAccountReferrerRewardAccount,
+  TAccountProgramAuthority,
+  TAccountRecipient,
+  TAccountAssociatedTokenProgram,
+  TAccountSystemProgram,
+  TAccountMemoProgram,
+  TAccountDenyList,
+  TAccountRewardCohort,
+  TAccountHookProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? STAKE_POOL_PROGRAM_ADDRESS;
@@ -185,12 +319,33 @@ export function getClaimRewardsInstruction<
     rewardMint: { value: input.rewardMint ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     clock: { value: input.clock ?? null, isWritable: false },
+    referrerRewardAccount: {
+      value: input.referrerRewardAccount ?? null,
+      isWritable: true,
+    },
+    programAuthority: {
+      value: input.programAuthority ?? null,
+      isWritable: false,
+    },
+    recipient: { value: input.recipient ?? null, isWritable: true },
+    associatedTokenProgram: {
+      value: input.associatedTokenProgram ?? null,
+      isWritable: false,
+    },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    memoProgram: { value: input.memoProgram ?? null, isWritable: false },
+    denyList: { value: input.denyList ?? null, isWritable: false },
+    rewardCohort: { value: input.rewardCohort ?? null, isWritable: true },
+    hookProgram: { value: input.hookProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
@@ -208,8 +363,19 @@ export function getClaimRewardsInstruction<
       getAccountMeta(accounts.rewardMint),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.clock),
+      getAccountMeta(accounts.referrerRewardAccount),
+      getAccountMeta(accounts.programAuthority),
+      getAccountMeta(accounts.recipient),
+      getAccountMeta(accounts.associatedTokenProgram),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.memoProgram),
+      getAccountMeta(accounts.denyList),
+      getAccountMeta(accounts.rewardCohort),
+      getAccountMeta(accounts.hookProgram),
     ],
-    data: getClaimRewardsInstructionDataEncoder().encode({}),
+    data: getClaimRewardsInstructionDataEncoder().encode(
+      args as ClaimRewardsInstructionDataArgs
+    ),
     programAddress,
   } as ClaimRewardsInstruction<
     TProgramAddress,
@@ -220,7 +386,16 @@ export function getClaimRewardsInstruction<
     TAccountRewardVault,
     TAccountRewardMint,
     TAccountTokenProgram,
-    TAccountClock
+    TAccountClock,
+    TAccountReferrerRewardAccount,
+    TAccountProgramAuthority,
+    TAccountRecipient,
+    TAccountAssociatedTokenProgram,
+    TAccountSystemProgram,
+    TAccountMemoProgram,
+    TAccountDenyList,
+    TAccountRewardCohort,
+    TAccountHookProgram
   >);
 }
 
@@ -234,11 +409,11 @@ export type ParsedClaimRewardsInstruction<
     pool: TAccountMetas[0];
     /** The user's stake account */
     stakeAccount: TAccountMetas[1];
-    /** The stake account owner */
+    /** The stake account owner, or its delegate (rewards then go to an owner token account only) */
     owner: TAccountMetas[2];
-    /** User's reward token account */
+    /** Reward token account owned by the stake owner */
     userRewardAccount: TAccountMetas[3];
-    /** Pool's reward vault */
+    /** Pool's reward vault, or the stake's cohort vault in pools with reward cohorts */
     rewardVault: TAccountMetas[4];
     /** The reward token mint */
     rewardMint: TAccountMetas[5];
@@ -246,6 +421,24 @@ export type ParsedClaimRewardsInstruction<
     tokenProgram: TAccountMetas[6];
     /** Clock sysvar */
     clock: TAccountMetas[7];
+    /** Referrer's reward token account (required when the stake has a referrer and the pool pays referrals) */
+    referrerRewardAccount?: TAccountMetas[8] | undefined;
+    /** The program authority account (global pause switches) */
+    programAuthority: TAccountMetas[9];
+    /** Reward token account to pay instead of user_reward_account (stake owner only) */
+    recipient?: TAccountMetas[10] | undefined;
+    /** The associated token account program (creates user_reward_account when missing; owner must then sign as a writable payer) */
+    associatedTokenProgram?: TAccountMetas[11] | undefined;
+    /** The system program (required with associated_token_program) */
+    systemProgram?: TAccountMetas[12] | undefined;
+    /** The SPL Memo program (required with a memo) */
+    memoProgram?: TAccountMetas[13] | undefined;
+    /** The global deny list PDA ["deny_list"] (required when the pool checks it) */
+    denyList?: TAccountMetas[14] | undefined;
+    /** The stake's RewardCohort PDA ["reward_cohort", pool, cohort] (required for stakes of a reward cohort) */
+    rewardCohort?: TAccountMetas[15] | undefined;
+    /** The pool's hook program (required when the pool has one); the hook's own accounts follow */
+    hookProgram?: TAccountMetas[16] | undefined;
   };
   data: ClaimRewardsInstructionData;
 };
@@ -258,7 +451,7 @@ export function parseClaimRewardsInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedClaimRewardsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 17) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -268,6 +461,12 @@ export function parseClaimRewardsInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === STAKE_POOL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -279,6 +478,15 @@ export function parseClaimRewardsInstruction<
       rewardMint: getNextAccount(),
       tokenProgram: getNextAccount(),
       clock: getNextAccount(),
+      referrerRewardAccount: getNextOptionalAccount(),
+      programAuthority: getNextAccount(),
+      recipient: getNextOptionalAccount(),
+      associatedTokenProgram: getNextOptionalAccount(),
+      systemProgram: getNextOptionalAccount(),
+      memoProgram: getNextOptionalAccount(),
+      denyList: getNextOptionalAccount(),
+      rewardCohort: getNextOptionalAccount(),
+      hookProgram: getNextOptionalAccount(),
     },
     data: getClaimRewardsInstructionDataDecoder().decode(instruction.data),
   };
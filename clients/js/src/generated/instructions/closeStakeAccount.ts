@@ -41,6 +41,7 @@ export type CloseStakeAccountInstruction<
   TAccountStakeAccount extends string | AccountMeta<string> = string,
   TAccountOwner extends string | AccountMeta<string> = string,
   TAccountReceiver extends string | AccountMeta<string> = string,
+  TAccountStakePosition extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -56,6 +57,9 @@ export type CloseStakeAccountInstruction<
       TAccountReceiver extends string
         ? WritableAccount<TAccountReceiver>
         : TAccountReceiver,
+      TAccountStakePosition extends string
+        ? WritableAccount<TAccountStakePosition>
+        : TAccountStakePosition,
       ...TRemainingAccounts,
     ]
   >;
@@ -89,6 +93,7 @@ export type CloseStakeAccountInput<
   TAccountStakeAccount extends string = string,
   TAccountOwner extends string = string,
   TAccountReceiver extends string = string,
+  TAccountStakePosition extends string = string,
 > = {
   /** The stake account to close */
   stakeAccount: Address<TAccountStakeAccount>;
@@ -96,25 +101,30 @@ export type CloseStakeAccountInput<
   owner: TransactionSigner<TAccountOwner>;
   /** Account to receive the rent lamports */
   receiver: Address<TAccountReceiver>;
+  /** The owner's stake position directory (None: left as is) */
+  stakePosition?: Address<TAccountStakePosition>;
 };
 
 export function getCloseStakeAccountInstruction<
   TAccountStakeAccount extends string,
   TAccountOwner extends string,
   TAccountReceiver extends string,
+  TAccountStakePosition extends string,
   TProgramAddress extends Address = typeof STAKE_POOL_PROGRAM_ADDRESS,
 >(
   input: CloseStakeAccountInput<
     TAccountStakeAccount,
     TAccountOwner,
-    TAccountReceiver
+    TAccountReceiver,
+    TAccountStakePosition
   >,
   config?: { programAddress?: TProgramAddress }
 ): CloseStakeAccountInstruction<
   TProgramAddress,
   TAccountStakeAccount,
   TAccountOwner,
-  TAccountReceiver
+  TAccountReceiver,
+  TAccountStakePosition
 > {
   // Program address.
   const programAddress = config?.programAddress ?? STAKE_POOL_PROGRAM_ADDRESS;
@@ -124,6 +134,7 @@ export function getCloseStakeAccountInstruction<
     stakeAccount: { value: input.stakeAccount ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: false },
     receiver: { value: input.receiver ?? null, isWritable: true },
+    stakePosition: { value: input.stakePosition ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -136,6 +147,7 @@ export function getCloseStakeAccountInstruction<
       getAccountMeta(accounts.stakeAccount),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.receiver),
+      getAccountMeta(accounts.stakePosition),
     ],
     data: getCloseStakeAccountInstructionDataEncoder().encode({}),
     programAddress,
@@ -143,7 +155,8 @@ export function getCloseStakeAccountInstruction<
     TProgramAddress,
     TAccountStakeAccount,
     TAccountOwner,
-    TAccountReceiver
+    TAccountReceiver,
+    TAccountStakePosition
   >);
 }
 
@@ -159,6 +172,8 @@ export type ParsedCloseStakeAccountInstruction<
     owner: TAccountMetas[1];
     /** Account to receive the rent lamports */
     receiver: TAccountMetas[2];
+    /** The owner's stake position directory (None: left as is) */
+    stakePosition?: TAccountMetas[3] | undefined;
   };
   data: CloseStakeAccountInstructionData;
 };
@@ -171,7 +186,7 @@ export function parseCloseStakeAccountInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedCloseStakeAccountInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 4) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -181,12 +196,19 @@ export function parseCloseStakeAccountInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === STAKE_POOL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       stakeAccount: getNextAccount(),
       owner: getNextAccount(),
       receiver: getNextAccount(),
+      stakePosition: getNextOptionalAccount(),
     },
     data: getCloseStakeAccountInstructionDataDecoder().decode(instruction.data),
   };
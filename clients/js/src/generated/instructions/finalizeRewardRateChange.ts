@@ -21,6 +21,7 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type ReadonlyAccount,
   type ReadonlyUint8Array,
   type WritableAccount,
 } from '@solana/kit';
@@ -36,6 +37,8 @@ export function getFinalizeRewardRateChangeDiscriminatorBytes() {
 export type FinalizeRewardRateChangeInstruction<
   TProgram extends string = typeof STAKE_POOL_PROGRAM_ADDRESS,
   TAccountPool extends string | AccountMeta<string> = string,
+  TAccountProgramAuthority extends string | AccountMeta<string> = string,
+  TAccountKeeper extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -44,6 +47,12 @@ export type FinalizeRewardRateChangeInstruction<
       TAccountPool extends string
         ? WritableAccount<TAccountPool>
         : TAccountPool,
+      TAccountProgramAuthority extends string
+        ? ReadonlyAccount<TAccountProgramAuthority>
+        : TAccountProgramAuthority,
+      TAccountKeeper extends string
+        ? WritableAccount<TAccountKeeper>
+        : TAccountKeeper,
       ...TRemainingAccounts,
     ]
   >;
@@ -78,24 +87,46 @@ export function getFinalizeRewardRateChangeInstructionDataCodec(): FixedSizeCode
 
 export type FinalizeRewardRateChangeInput<
   TAccountPool extends string = string,
+  TAccountProgramAuthority extends string = string,
+  TAccountKeeper extends string = string,
 > = {
   /** The stake pool */
   pool: Address<TAccountPool>;
+  /** The program authority account (provides the cluster profile's delay) */
+  programAuthority: Address<TAccountProgramAuthority>;
+  /** Account receiving the pool's keeper bounty (None: no bounty) */
+  keeper?: Address<TAccountKeeper>;
 };
 
 export function getFinalizeRewardRateChangeInstruction<
   TAccountPool extends string,
+  TAccountProgramAuthority extends string,
+  TAccountKeeper extends string,
   TProgramAddress extends Address = typeof STAKE_POOL_PROGRAM_ADDRESS,
 >(
-  input: FinalizeRewardRateChangeInput<TAccountPool>,
+  input: FinalizeRewardRateChangeInput<
+    TAccountPool,
+    TAccountProgramAuthority,
+    TAccountKeeper
+  >,
   config?: { programAddress?: TProgramAddress }
-): FinalizeRewardRateChangeInstruction<TProgramAddress, TAccountPool> {
+): FinalizeRewardRateChangeInstruction<
+  TProgramAddress,
+  TAccountPool,
+  TAccountProgramAuthority,
+  TAccountKeeper
+> {
   // Program address.
   const programAddress = config?.programAddress ?? STAKE_POOL_PROGRAM_ADDRESS;
 
   // Original accounts.
   const originalAccounts = {
     pool: { value: input.pool ?? null, isWritable: true },
+    programAuthority: {
+      value: input.programAuthority ?? null,
+      isWritable: false,
+    },
+    keeper: { value: input.keeper ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -104,10 +135,19 @@ export function getFinalizeRewardRateChangeInstruction<
 
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
-    accounts: [getAccountMeta(accounts.pool)],
+    accounts: [
+      getAccountMeta(accounts.pool),
+      getAccountMeta(accounts.programAuthority),
+      getAccountMeta(accounts.keeper),
+    ],
     data: getFinalizeRewardRateChangeInstructionDataEncoder().encode({}),
     programAddress,
-  } as FinalizeRewardRateChangeInstruction<TProgramAddress, TAccountPool>);
+  } as FinalizeRewardRateChangeInstruction<
+    TProgramAddress,
+    TAccountPool,
+    TAccountProgramAuthority,
+    TAccountKeeper
+  >);
 }
 
 export type ParsedFinalizeRewardRateChangeInstruction<
@@ -118,6 +158,10 @@ export type ParsedFinalizeRewardRateChangeInstruction<
   accounts: {
     /** The stake pool */
     pool: TAccountMetas[0];
+    /** The program authority account (provides the cluster profile's delay) */
+    programAuthority: TAccountMetas[1];
+    /** Account receiving the pool's keeper bounty (None: no bounty) */
+    keeper?: TAccountMetas[2] | undefined;
   };
   data: FinalizeRewardRateChangeInstructionData;
 };
@@ -130,7 +174,7 @@ export function parseFinalizeRewardRateChangeInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedFinalizeRewardRateChangeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 1) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -140,9 +184,19 @@ export function parseFinalizeRewardRateChangeInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === STAKE_POOL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
-    accounts: { pool: getNextAccount() },
+    accounts: {
+      pool: getNextAccount(),
+      programAuthority: getNextAccount(),
+      keeper: getNextOptionalAccount(),
+    },
     data: getFinalizeRewardRateChangeInstructionDataDecoder().decode(
       instruction.data
     ),
@@ -7,17 +7,85 @@
  */
 
 export * from './acceptProgramAuthority';
+export * from './acceptStakeOwnership';
+export * from './approvePoolUpdate';
+export * from './auditPool';
 export * from './cancelAuthorityTransfer';
+export * from './cancelSlash';
 export * from './checkAuthorization';
+export * from './claimExpiredRewards';
 export * from './claimRewards';
+export * from './claimRewardsBatch';
+export * from './clonePool';
+export * from './closePool';
+export * from './closeProgramAuthority';
+export * from './closeRewardStream';
 export * from './closeStakeAccount';
+export * from './completeRecovery';
+export * from './crankRewardStream';
+export * from './createRewardStream';
+export * from './defundCohortRewards';
+export * from './defundRewards';
+export * from './enableReceiptToken';
+export * from './executePoolUpdate';
+export * from './executeSlash';
+export * from './expireRewards';
+export * from './extendLockup';
+export * from './finalizeLockupChange';
 export * from './finalizeRewardRateChange';
+export * from './fundCohortRewards';
+export * from './fundKeeperBounty';
 export * from './fundRewards';
 export * from './getAuthorizedCreators';
+export * from './getPendingRewards';
+export * from './getPoolStats';
+export * from './getStakePosition';
+export * from './getUserPoints';
 export * from './initializePool';
 export * from './initializeProgramAuthority';
+export * from './initializeProgramConfig';
+export * from './initiateRecovery';
 export * from './manageAuthorizedCreators';
+export * from './manageDenyList';
+export * from './manageHookAllowlist';
+export * from './managePoolAllowlist';
+export * from './manageRoles';
+export * from './mergeStakes';
+export * from './migratePool';
+export * from './migrateProgramAuthority';
+export * from './nominateStakeOwner';
+export * from './proposePoolUpdate';
+export * from './proposeSlash';
+export * from './registerPool';
+export * from './relock';
+export * from './requestUnstake';
+export * from './resizePool';
+export * from './setApprovalThreshold';
+export * from './setAutoRelock';
+export * from './setCreatorPoolLimit';
+export * from './setGlobalPause';
+export * from './setMaxCreators';
+export * from './setMintTvlCap';
+export * from './setPoolHook';
+export * from './setRecoveryAuthority';
+export * from './setRewardFunders';
+export * from './setStakeDelegate';
+export * from './setStakeLabel';
+export * from './splitStake';
 export * from './stake';
+export * from './stakeBatch';
+export * from './stakeSol';
+export * from './sweepDust';
+export * from './syncPoints';
+export * from './syncPool';
 export * from './transferProgramAuthority';
 export * from './unstake';
+export * from './unstakeAll';
+export * from './unstakeSol';
 export * from './updatePool';
+export * from './updatePoolsBatch';
+export * from './updateProgramConfig';
+export * from './vestingStake';
+export * from './windDownStake';
+export * from './withdrawFees';
+export * from './withdrawUnstaked';
@@ -8,6 +8,8 @@
 
 import {
   combineCodec,
+  getArrayDecoder,
+  getArrayEncoder,
   getBooleanDecoder,
   getBooleanEncoder,
   getI64Decoder,
@@ -16,6 +18,10 @@ import {
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -40,6 +46,16 @@ import {
 } from '@solana/kit';
 import { STAKE_POOL_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  getRewardRateModeDecoder,
+  getRewardRateModeEncoder,
+  getRewardTierDecoder,
+  getRewardTierEncoder,
+  type RewardRateMode,
+  type RewardRateModeArgs,
+  type RewardTier,
+  type RewardTierArgs,
+} from '../types';
 
 export const INITIALIZE_POOL_DISCRIMINATOR = 0;
 
@@ -65,6 +81,11 @@ export type InitializePoolInstruction<
     | string
     | AccountMeta<string> = 'SysvarRent111111111111111111111111111111111',
   TAccountProgramAuthority extends string | AccountMeta<string> = string,
+  TAccountFeeVault extends string | AccountMeta<string> = string,
+  TAccountMintRegistry extends string | AccountMeta<string> = string,
+  TAccountPoolRegistry extends string | AccountMeta<string> = string,
+  TAccountPreviousPoolRegistry extends string | AccountMeta<string> = string,
+  TAccountProgramConfig extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -99,8 +120,23 @@ export type InitializePoolInstruction<
         ? ReadonlyAccount<TAccountRent>
         : TAccountRent,
       TAccountProgramAuthority extends string
-        ? ReadonlyAccount<TAccountProgramAuthority>
+        ? WritableAccount<TAccountProgramAuthority>
         : TAccountProgramAuthority,
+      TAccountFeeVault extends string
+        ? WritableAccount<TAccountFeeVault>
+        : TAccountFeeVault,
+      TAccountMintRegistry extends string
+        ? WritableAccount<TAccountMintRegistry>
+        : TAccountMintRegistry,
+      TAccountPoolRegistry extends string
+        ? WritableAccount<TAccountPoolRegistry>
+        : TAccountPoolRegistry,
+      TAccountPreviousPoolRegistry extends string
+        ? ReadonlyAccount<TAccountPreviousPoolRegistry>
+        : TAccountPreviousPoolRegistry,
+      TAccountProgramConfig extends string
+        ? ReadonlyAccount<TAccountProgramConfig>
+        : TAccountProgramConfig,
       ...TRemainingAccounts,
     ]
   >;
@@ -113,6 +149,17 @@ export type InitializePoolInstructionData = {
   lockupPeriod: bigint;
   enforceLockup: boolean;
   poolEndDate: Option<bigint>;
+  referralBps: number;
+  earlyUnstakeBps: number;
+  depositFeeBps: number;
+  withdrawFeeBps: number;
+  rewardRateChangeDelay: Option<number>;
+  minRewardRateBound: Option<bigint>;
+  maxRewardRateBound: Option<bigint>;
+  rewardTiers: Array<RewardTier>;
+  bonusEndTs: Option<bigint>;
+  bonusMultiplierBps: number;
+  rewardRateMode: RewardRateMode;
 };
 
 export type InitializePoolInstructionDataArgs = {
@@ -122,6 +169,17 @@ export type InitializePoolInstructionDataArgs = {
   lockupPeriod: number | bigint;
   enforceLockup: boolean;
   poolEndDate: OptionOrNullable<number | bigint>;
+  referralBps: number;
+  earlyUnstakeBps: number;
+  depositFeeBps: number;
+  withdrawFeeBps: number;
+  rewardRateChangeDelay: OptionOrNullable<number>;
+  minRewardRateBound: OptionOrNullable<number | bigint>;
+  maxRewardRateBound: OptionOrNullable<number | bigint>;
+  rewardTiers: Array<RewardTierArgs>;
+  bonusEndTs: OptionOrNullable<number | bigint>;
+  bonusMultiplierBps: number;
+  rewardRateMode: RewardRateModeArgs;
 };
 
 export function getInitializePoolInstructionDataEncoder(): Encoder<InitializePoolInstructionDataArgs> {
@@ -134,6 +192,17 @@ export function getInitializePoolInstructionDataEncoder(): Encoder<InitializePoo
       ['lockupPeriod', getI64Encoder()],
       ['enforceLockup', getBooleanEncoder()],
       ['poolEndDate', getOptionEncoder(getI64Encoder())],
+      ['referralBps', getU16Encoder()],
+      ['earlyUnstakeBps', getU16Encoder()],
+      ['depositFeeBps', getU16Encoder()],
+      ['withdrawFeeBps', getU16Encoder()],
+      ['rewardRateChangeDelay', getOptionEncoder(getU32Encoder())],
+      ['minRewardRateBound', getOptionEncoder(getU64Encoder())],
+      ['maxRewardRateBound', getOptionEncoder(getU64Encoder())],
+      ['rewardTiers', getArrayEncoder(getRewardTierEncoder())],
+      ['bonusEndTs', getOptionEncoder(getI64Encoder())],
+      ['bonusMultiplierBps', getU16Encoder()],
+      ['rewardRateMode', getRewardRateModeEncoder()],
     ]),
     (value) => ({ ...value, discriminator: INITIALIZE_POOL_DISCRIMINATOR })
   );
@@ -148,6 +217,17 @@ export function getInitializePoolInstructionDataDecoder(): Decoder<InitializePoo
     ['lockupPeriod', getI64Decoder()],
     ['enforceLockup', getBooleanDecoder()],
     ['poolEndDate', getOptionDecoder(getI64Decoder())],
+    ['referralBps', getU16Decoder()],
+    ['earlyUnstakeBps', getU16Decoder()],
+    ['depositFeeBps', getU16Decoder()],
+    ['withdrawFeeBps', getU16Decoder()],
+    ['rewardRateChangeDelay', getOptionDecoder(getU32Decoder())],
+    ['minRewardRateBound', getOptionDecoder(getU64Decoder())],
+    ['maxRewardRateBound', getOptionDecoder(getU64Decoder())],
+    ['rewardTiers', getArrayDecoder(getRewardTierDecoder())],
+    ['bonusEndTs', getOptionDecoder(getI64Decoder())],
+    ['bonusMultiplierBps', getU16Decoder()],
+    ['rewardRateMode', getRewardRateModeDecoder()],
   ]);
 }
 
@@ -172,6 +252,11 @@ export type InitializePoolInput<
   TAccountSystemProgram extends string = string,
   TAccountRent extends string = string,
   TAccountProgramAuthority extends string = string,
+  TAccountFeeVault extends string = string,
+  TAccountMintRegistry extends string = string,
+  TAccountPoolRegistry extends string = string,
+  TAccountPreviousPoolRegistry extends string = string,
+  TAccountProgramConfig extends string = string,
 > = {
   /** The stake pool PDA */
   pool: Address<TAccountPool>;
@@ -179,26 +264,47 @@ export type InitializePoolInput<
   stakeMint: Address<TAccountStakeMint>;
   /** The reward token mint */
   rewardMint: Address<TAccountRewardMint>;
-  /** The pool's stake token vault */
+  /** The pool's stake vault PDA ["stake_vault", pool] (created) */
   stakeVault: Address<TAccountStakeVault>;
-  /** The pool's reward token vault */
+  /** The pool's reward vault PDA ["reward_vault", pool] (created) */
   rewardVault: Address<TAccountRewardVault>;
   /** The account paying for rent (must be authorized admin) */
   payer: TransactionSigner<TAccountPayer>;
-  /** The token program */
+  /** The token program owning both mints */
   tokenProgram?: Address<TAccountTokenProgram>;
   /** The system program */
   systemProgram?: Address<TAccountSystemProgram>;
   /** Rent sysvar */
   rent?: Address<TAccountRent>;
-  /** The program authority account (validates creator permission) */
+  /** The program authority account (validates creator permission and counts the creator's pools) */
   programAuthority: Address<TAccountProgramAuthority>;
+  /** The pool's fee vault PDA ["fee_vault", pool] (stake mint, created) collecting deposit and withdrawal fees */
+  feeVault: Address<TAccountFeeVault>;
+  /** The stake mint's registry PDA ["mint_registry", stake_mint] (created with the mint's first pool) */
+  mintRegistry: Address<TAccountMintRegistry>;
+  /** The pool registry page the pool is listed in (latest page, created when the previous one is full) */
+  poolRegistry: Address<TAccountPoolRegistry>;
+  /** The previous registry page (required to create a page after page 0) */
+  previousPoolRegistry?: Address<TAccountPreviousPoolRegistry>;
+  /** The program config PDA ["program_config"] (built-in limits while uninitialized) */
+  programConfig: Address<TAccountProgramConfig>;
   poolId: InitializePoolInstructionDataArgs['poolId'];
   rewardRate: InitializePoolInstructionDataArgs['rewardRate'];
   minStakeAmount: InitializePoolInstructionDataArgs['minStakeAmount'];
   lockupPeriod: InitializePoolInstructionDataArgs['lockupPeriod'];
   enforceLockup: InitializePoolInstructionDataArgs['enforceLockup'];
   poolEndDate: InitializePoolInstructionDataArgs['poolEndDate'];
+  referralBps: InitializePoolInstructionDataArgs['referralBps'];
+  earlyUnstakeBps: InitializePoolInstructionDataArgs['earlyUnstakeBps'];
+  depositFeeBps: InitializePoolInstructionDataArgs['depositFeeBps'];
+  withdrawFeeBps: InitializePoolInstructionDataArgs['withdrawFeeBps'];
+  rewardRateChangeDelay: InitializePoolInstructionDataArgs['rewardRateChangeDelay'];
+  minRewardRateBound: InitializePoolInstructionDataArgs['minRewardRateBound'];
+  maxRewardRateBound: InitializePoolInstructionDataArgs['maxRewardRateBound'];
+  rewardTiers: InitializePoolInstructionDataArgs['rewardTiers'];
+  bonusEndTs: InitializePoolInstructionDataArgs['bonusEndTs'];
+  bonusMultiplierBps: InitializePoolInstructionDataArgs['bonusMultiplierBps'];
+  rewardRateMode: InitializePoolInstructionDataArgs['rewardRateMode'];
 };
 
 export function getInitializePoolInstruction<
@@ -212,6 +318,11 @@ export function getInitializePoolInstruction<
   TAccountSystemProgram extends string,
   TAccountRent extends string,
   TAccountProgramAuthority extends string,
+  TAccountFeeVault extends string,
+  TAccountMintRegistry extends string,
+  TAccountPoolRegistry extends string,
+  TAccountPreviousPoolRegistry extends string,
+  TAccountProgramConfig extends string,
   TProgramAddress extends Address = typeof STAKE_POOL_PROGRAM_ADDRESS,
 >(
   input: InitializePoolInput<
@@ -224,7 +335,12 @@ export function getInitializePoolInstruction<
     TAccountTokenProgram,
     TAccountSystemProgram,
     TAccountRent,
-    TAccountProgramAuthority
+    TAccountProgramAuthority,
+    TAccountFeeVault,
+    TAccountMintRegistry,
+    TAccountPoolRegistry,
+    TAccountPreviousPoolRegistry,
+    TAccountProgramConfig
   >,
   config?: { programAddress?: TProgramAddress }
 ): InitializePoolInstruction<
@@ -238,7 +354,12 @@ export function getInitializePoolInstruction<
   TAccountTokenProgram,
   TAccountSystemProgram,
   TAccountRent,
-  TAccountProgramAuthority
+  TAccountProgramAuthority,
+  TAccountFeeVault,
+  TAccountMintRegistry,
+  TAccountPoolRegistry,
+  TAccountPreviousPoolRegistry,
+  TAccountProgramConfig
 > {
   // Program address.
   const programAddress = config?.programAddress ?? STAKE_POOL_PROGRAM_ADDRESS;
@@ -256,8 +377,16 @@ export function getInitializePoolInstruction<
     rent: { value: input.rent ?? null, isWritable: false },
     programAuthority: {
       value: input.programAuthority ?? null,
+      isWritable: true,
+    },
+    feeVault: { value: input.feeVault ?? null, isWritable: true },
+    mintRegistry: { value: input.mintRegistry ?? null, isWritable: true },
+    poolRegistry: { value: input.poolRegistry ?? null, isWritable: true },
+    previousPoolRegistry: {
+      value: input.previousPoolRegistry ?? null,
       isWritable: false,
     },
+    programConfig: { value: input.programConfig ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -294,6 +423,11 @@ export function getInitializePoolInstruction<
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.rent),
       getAccountMeta(accounts.programAuthority),
+      getAccountMeta(accounts.feeVault),
+      getAccountMeta(accounts.mintRegistry),
+      getAccountMeta(accounts.poolRegistry),
+      getAccountMeta(accounts.previousPoolRegistry),
+      getAccountMeta(accounts.programConfig),
     ],
     data: getInitializePoolInstructionDataEncoder().encode(
       args as InitializePoolInstructionDataArgs
@@ -310,7 +444,12 @@ export function getInitializePoolInstruction<
     TAccountTokenProgram,
     TAccountSystemProgram,
     TAccountRent,
-    TAccountProgramAuthority
+    TAccountProgramAuthority,
+    TAccountFeeVault,
+    TAccountMintRegistry,
+    TAccountPoolRegistry,
+    TAccountPreviousPoolRegistry,
+    TAccountProgramConfig
   >);
 }
 
@@ -326,20 +465,30 @@ export type ParsedInitializePoolInstruction<
     stakeMint: TAccountMetas[1];
     /** The reward token mint */
     rewardMint: TAccountMetas[2];
-    /** The pool's stake token vault */
+    /** The pool's stake vault PDA ["stake_vault", pool] (created) */
     stakeVault: TAccountMetas[3];
-    /** The pool's reward token vault */
+    /** The pool's reward vault PDA ["reward_vault", pool] (created) */
     rewardVault: TAccountMetas[4];
     /** The account paying for rent (must be authorized admin) */
     payer: TAccountMetas[5];
-    /** The token program */
+    /** The token program owning both mints */
     tokenProgram: TAccountMetas[6];
     /** The system program */
     systemProgram: TAccountMetas[7];
     /** Rent sysvar */
     rent: TAccountMetas[8];
-    /** The program authority account (validates creator permission) */
+    /** The program authority account (validates creator permission and counts the creator's pools) */
     programAuthority: TAccountMetas[9];
+    /** The pool's fee vault PDA ["fee_vault", pool] (stake mint, created) collecting deposit and withdrawal fees */
+    feeVault: TAccountMetas[10];
+    /** The stake mint's registry PDA ["mint_registry", stake_mint] (created with the mint's first pool) */
+    mintRegistry: TAccountMetas[11];
+    /** The pool registry page the pool is listed in (latest page, created when the previous one is full) */
+    poolRegistry: TAccountMetas[12];
+    /** The previous registry page (required to create a page after page 0) */
+    previousPoolRegistry?: TAccountMetas[13] | undefined;
+    /** The program config PDA ["program_config"] (built-in limits while uninitialized) */
+    programConfig: TAccountMetas[14];
   };
   data: InitializePoolInstructionData;
 };
@@ -352,7 +501,7 @@ export function parseInitializePoolInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedInitializePoolInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 15) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -362,6 +511,12 @@ export function parseInitializePoolInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === STAKE_POOL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -375,6 +530,11 @@ export function parseInitializePoolInstruction<
       systemProgram: getNextAccount(),
       rent: getNextAccount(),
       programAuthority: getNextAccount(),
+      feeVault: getNextAccount(),
+      mintRegistry: getNextAccount(),
+      poolRegistry: getNextAccount(),
+      previousPoolRegistry: getNextOptionalAccount(),
+      programConfig: getNextAccount(),
     },
     data: getInitializePoolInstructionDataDecoder().decode(instruction.data),
   };
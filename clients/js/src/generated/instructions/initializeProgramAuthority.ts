@@ -31,6 +31,12 @@ import {
 } from '@solana/kit';
 import { STAKE_POOL_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  getClusterProfileDecoder,
+  getClusterProfileEncoder,
+  type ClusterProfile,
+  type ClusterProfileArgs,
+} from '../types';
 
 export const INITIALIZE_PROGRAM_AUTHORITY_DISCRIMINATOR = 8;
 
@@ -71,13 +77,19 @@ export type InitializeProgramAuthorityInstruction<
 
 export type InitializeProgramAuthorityInstructionData = {
   discriminator: number;
+  clusterProfile: ClusterProfile;
 };
 
-export type InitializeProgramAuthorityInstructionDataArgs = {};
+export type InitializeProgramAuthorityInstructionDataArgs = {
+  clusterProfile: ClusterProfileArgs;
+};
 
 export function getInitializeProgramAuthorityInstructionDataEncoder(): FixedSizeEncoder<InitializeProgramAuthorityInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', getU8Encoder()]]),
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['clusterProfile', getClusterProfileEncoder()],
+    ]),
     (value) => ({
       ...value,
       discriminator: INITIALIZE_PROGRAM_AUTHORITY_DISCRIMINATOR,
@@ -86,7 +98,10 @@ export function getInitializeProgramAuthorityInstructionDataEncoder(): FixedSize
 }
 
 export function getInitializeProgramAuthorityInstructionDataDecoder(): FixedSizeDecoder<InitializeProgramAuthorityInstructionData> {
-  return getStructDecoder([['discriminator', getU8Decoder()]]);
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['clusterProfile', getClusterProfileDecoder()],
+  ]);
 }
 
 export function getInitializeProgramAuthorityInstructionDataCodec(): FixedSizeCodec<
@@ -113,6 +128,7 @@ export type InitializeProgramAuthorityInput<
   payer: TransactionSigner<TAccountPayer>;
   /** The system program */
   systemProgram?: Address<TAccountSystemProgram>;
+  clusterProfile: InitializeProgramAuthorityInstructionDataArgs['clusterProfile'];
 };
 
 export function getInitializeProgramAuthorityInstruction<
@@ -157,6 +173,9 @@ export function getInitializeProgramAuthorityInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -171,7 +190,9 @@ export function getInitializeProgramAuthorityInstruction<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.systemProgram),
     ],
-    data: getInitializeProgramAuthorityInstructionDataEncoder().encode({}),
+    data: getInitializeProgramAuthorityInstructionDataEncoder().encode(
+      args as InitializeProgramAuthorityInstructionDataArgs
+    ),
     programAddress,
   } as InitializeProgramAuthorityInstruction<
     TProgramAddress,
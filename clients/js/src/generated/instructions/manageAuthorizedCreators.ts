@@ -26,10 +26,12 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/kit';
 import { STAKE_POOL_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -44,6 +46,8 @@ export type ManageAuthorizedCreatorsInstruction<
   TProgram extends string = typeof STAKE_POOL_PROGRAM_ADDRESS,
   TAccountProgramAuthority extends string | AccountMeta<string> = string,
   TAccountAuthority extends string | AccountMeta<string> = string,
+  TAccountPayer extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -56,6 +60,13 @@ export type ManageAuthorizedCreatorsInstruction<
         ? ReadonlySignerAccount<TAccountAuthority> &
             AccountSignerMeta<TAccountAuthority>
         : TAccountAuthority,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            AccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -106,11 +117,17 @@ export function getManageAuthorizedCreatorsInstructionDataCodec(): Codec<
 export type ManageAuthorizedCreatorsInput<
   TAccountProgramAuthority extends string = string,
   TAccountAuthority extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   /** The program authority PDA */
   programAuthority: Address<TAccountProgramAuthority>;
   /** The program authority signer */
   authority: TransactionSigner<TAccountAuthority>;
+  /** Pays rent when the program authority grows (required when the creator list outgrows the account) */
+  payer?: TransactionSigner<TAccountPayer>;
+  /** The system program (required with payer) */
+  systemProgram?: Address<TAccountSystemProgram>;
   add: ManageAuthorizedCreatorsInstructionDataArgs['add'];
   remove: ManageAuthorizedCreatorsInstructionDataArgs['remove'];
 };
@@ -118,17 +135,23 @@ export type ManageAuthorizedCreatorsInput<
 export function getManageAuthorizedCreatorsInstruction<
   TAccountProgramAuthority extends string,
   TAccountAuthority extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof STAKE_POOL_PROGRAM_ADDRESS,
 >(
   input: ManageAuthorizedCreatorsInput<
     TAccountProgramAuthority,
-    TAccountAuthority
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): ManageAuthorizedCreatorsInstruction<
   TProgramAddress,
   TAccountProgramAuthority,
-  TAccountAuthority
+  TAccountAuthority,
+  TAccountPayer,
+  TAccountSystemProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? STAKE_POOL_PROGRAM_ADDRESS;
@@ -140,6 +163,8 @@ export function getManageAuthorizedCreatorsInstruction<
       isWritable: true,
     },
     authority: { value: input.authority ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -154,6 +179,8 @@ export function getManageAuthorizedCreatorsInstruction<
     accounts: [
       getAccountMeta(accounts.programAuthority),
       getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
     ],
     data: getManageAuthorizedCreatorsInstructionDataEncoder().encode(
       args as ManageAuthorizedCreatorsInstructionDataArgs
@@ -162,7 +189,9 @@ export function getManageAuthorizedCreatorsInstruction<
   } as ManageAuthorizedCreatorsInstruction<
     TProgramAddress,
     TAccountProgramAuthority,
-    TAccountAuthority
+    TAccountAuthority,
+    TAccountPayer,
+    TAccountSystemProgram
   >);
 }
 
@@ -176,6 +205,10 @@ export type ParsedManageAuthorizedCreatorsInstruction<
     programAuthority: TAccountMetas[0];
     /** The program authority signer */
     authority: TAccountMetas[1];
+    /** Pays rent when the program authority grows (required when the creator list outgrows the account) */
+    payer?: TAccountMetas[2] | undefined;
+    /** The system program (required with payer) */
+    systemProgram?: TAccountMetas[3] | undefined;
   };
   data: ManageAuthorizedCreatorsInstructionData;
 };
@@ -188,7 +221,7 @@ export function parseManageAuthorizedCreatorsInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedManageAuthorizedCreatorsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 4) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -198,11 +231,19 @@ export function parseManageAuthorizedCreatorsInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === STAKE_POOL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       programAuthority: getNextAccount(),
       authority: getNextAccount(),
+      payer: getNextOptionalAccount(),
+      systemProgram: getNextOptionalAccount(),
     },
     data: getManageAuthorizedCreatorsInstructionDataDecoder().decode(
       instruction.data
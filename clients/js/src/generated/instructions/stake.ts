@@ -7,17 +7,33 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
   getI64Decoder,
   getI64Encoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
   getU8Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -62,6 +78,19 @@ export type StakeInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountFeeVault extends string | AccountMeta<string> = string,
+  TAccountMintRegistry extends string | AccountMeta<string> = string,
+  TAccountReceiptMint extends string | AccountMeta<string> = string,
+  TAccountUserReceiptAccount extends string | AccountMeta<string> = string,
+  TAccountReceiptTokenProgram extends string | AccountMeta<string> = string,
+  TAccountProgramAuthority extends string | AccountMeta<string> = string,
+  TAccountAllowlist extends string | AccountMeta<string> = string,
+  TAccountMemoProgram extends string | AccountMeta<string> = string,
+  TAccountStakePosition extends string | AccountMeta<string> = string,
+  TAccountFunder extends string | AccountMeta<string> = string,
+  TAccountDenyList extends string | AccountMeta<string> = string,
+  TAccountRewardCohort extends string | AccountMeta<string> = string,
+  TAccountHookProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -74,8 +103,7 @@ export type StakeInstruction<
         ? WritableAccount<TAccountStakeAccount>
         : TAccountStakeAccount,
       TAccountOwner extends string
-        ? ReadonlySignerAccount<TAccountOwner> &
-            AccountSignerMeta<TAccountOwner>
+        ? ReadonlyAccount<TAccountOwner>
         : TAccountOwner,
       TAccountUserTokenAccount extends string
         ? WritableAccount<TAccountUserTokenAccount>
@@ -99,6 +127,46 @@ export type StakeInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountFeeVault extends string
+        ? WritableAccount<TAccountFeeVault>
+        : TAccountFeeVault,
+      TAccountMintRegistry extends string
+        ? WritableAccount<TAccountMintRegistry>
+        : TAccountMintRegistry,
+      TAccountReceiptMint extends string
+        ? WritableAccount<TAccountReceiptMint>
+        : TAccountReceiptMint,
+      TAccountUserReceiptAccount extends string
+        ? WritableAccount<TAccountUserReceiptAccount>
+        : TAccountUserReceiptAccount,
+      TAccountReceiptTokenProgram extends string
+        ? ReadonlyAccount<TAccountReceiptTokenProgram>
+        : TAccountReceiptTokenProgram,
+      TAccountProgramAuthority extends string
+        ? ReadonlyAccount<TAccountProgramAuthority>
+        : TAccountProgramAuthority,
+      TAccountAllowlist extends string
+        ? ReadonlyAccount<TAccountAllowlist>
+        : TAccountAllowlist,
+      TAccountMemoProgram extends string
+        ? ReadonlyAccount<TAccountMemoProgram>
+        : TAccountMemoProgram,
+      TAccountStakePosition extends string
+        ? WritableAccount<TAccountStakePosition>
+        : TAccountStakePosition,
+      TAccountFunder extends string
+        ? ReadonlySignerAccount<TAccountFunder> &
+            AccountSignerMeta<TAccountFunder>
+        : TAccountFunder,
+      TAccountDenyList extends string
+        ? ReadonlyAccount<TAccountDenyList>
+        : TAccountDenyList,
+      TAccountRewardCohort extends string
+        ? WritableAccount<TAccountRewardCohort>
+        : TAccountRewardCohort,
+      TAccountHookProgram extends string
+        ? ReadonlyAccount<TAccountHookProgram>
+        : TAccountHookProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -106,16 +174,28 @@ export type StakeInstruction<
 export type StakeInstructionData = {
   discriminator: number;
   amount: bigint;
-  index: bigint;
+  index: Option<bigint>;
   expectedRewardRate: Option<bigint>;
   expectedLockupPeriod: Option<bigint>;
+  referrer: Option<Address>;
+  autoRelock: boolean;
+  label: Option<ReadonlyUint8Array>;
+  allowlistProof: Option<Array<ReadonlyUint8Array>>;
+  memo: Option<string>;
+  deadline: Option<bigint>;
 };
 
 export type StakeInstructionDataArgs = {
   amount: number | bigint;
-  index: number | bigint;
+  index: OptionOrNullable<number | bigint>;
   expectedRewardRate: OptionOrNullable<number | bigint>;
   expectedLockupPeriod: OptionOrNullable<number | bigint>;
+  referrer: OptionOrNullable<Address>;
+  autoRelock: boolean;
+  label: OptionOrNullable<ReadonlyUint8Array>;
+  allowlistProof: OptionOrNullable<Array<ReadonlyUint8Array>>;
+  memo: OptionOrNullable<string>;
+  deadline: OptionOrNullable<number | bigint>;
 };
 
 export function getStakeInstructionDataEncoder(): Encoder<StakeInstructionDataArgs> {
@@ -123,9 +203,25 @@ export function getStakeInstructionDataEncoder(): Encoder<StakeInstructionDataAr
     getStructEncoder([
       ['discriminator', getU8Encoder()],
       ['amount', getU64Encoder()],
-      ['index', getU64Encoder()],
+      ['index', getOptionEncoder(getU64Encoder())],
       ['expectedRewardRate', getOptionEncoder(getU64Encoder())],
       ['expectedLockupPeriod', getOptionEncoder(getI64Encoder())],
+      ['referrer', getOptionEncoder(getAddressEncoder())],
+      ['autoRelock', getBooleanEncoder()],
+      ['label', getOptionEncoder(fixEncoderSize(getBytesEncoder(), 32))],
+      [
+        'allowlistProof',
+        getOptionEncoder(
+          getArrayEncoder(fixEncoderSize(getBytesEncoder(), 32))
+        ),
+      ],
+      [
+        'memo',
+        getOptionEncoder(
+          addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())
+        ),
+      ],
+      ['deadline', getOptionEncoder(getI64Encoder())],
     ]),
     (value) => ({ ...value, discriminator: STAKE_DISCRIMINATOR })
   );
@@ -135,9 +231,21 @@ export function getStakeInstructionDataDecoder(): Decoder<StakeInstructionData>
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
     ['amount', getU64Decoder()],
-    ['index', getU64Decoder()],
+    ['index', getOptionDecoder(getU64Decoder())],
     ['expectedRewardRate', getOptionDecoder(getU64Decoder())],
     ['expectedLockupPeriod', getOptionDecoder(getI64Decoder())],
+    ['referrer', getOptionDecoder(getAddressDecoder())],
+    ['autoRelock', getBooleanDecoder()],
+    ['label', getOptionDecoder(fixDecoderSize(getBytesDecoder(), 32))],
+    [
+      'allowlistProof',
+      getOptionDecoder(getArrayDecoder(fixDecoderSize(getBytesDecoder(), 32))),
+    ],
+    [
+      'memo',
+      getOptionDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())),
+    ],
+    ['deadline', getOptionDecoder(getI64Decoder())],
   ]);
 }
 
@@ -162,18 +270,31 @@ export type StakeInput<
   TAccountTokenProgram extends string = string,
   TAccountPayer extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountFeeVault extends string = string,
+  TAccountMintRegistry extends string = string,
+  TAccountReceiptMint extends string = string,
+  TAccountUserReceiptAccount extends string = string,
+  TAccountReceiptTokenProgram extends string = string,
+  TAccountProgramAuthority extends string = string,
+  TAccountAllowlist extends string = string,
+  TAccountMemoProgram extends string = string,
+  TAccountStakePosition extends string = string,
+  TAccountFunder extends string = string,
+  TAccountDenyList extends string = string,
+  TAccountRewardCohort extends string = string,
+  TAccountHookProgram extends string = string,
 > = {
   /** The stake pool */
   pool: Address<TAccountPool>;
   /** The stake account PDA (will be created) */
   stakeAccount: Address<TAccountStakeAccount>;
-  /** The stake account owner */
-  owner: TransactionSigner<TAccountOwner>;
-  /** User's token account */
+  /** The stake account owner (signs unless a funder stakes for it) */
+  owner: Address<TAccountOwner> | TransactionSigner<TAccountOwner>;
+  /** User's token account (the funder's when a funder stakes) */
   userTokenAccount: Address<TAccountUserTokenAccount>;
   /** Pool's stake vault */
   stakeVault: Address<TAccountStakeVault>;
-  /** Pool's reward vault (for checking available rewards) */
+  /** Pool's reward vault, or the cohort vault in pools with reward cohorts (for checking available rewards) */
   rewardVault: Address<TAccountRewardVault>;
   /** The token mint being staked */
   stakeMint: Address<TAccountStakeMint>;
@@ -183,10 +304,42 @@ export type StakeInput<
   payer: TransactionSigner<TAccountPayer>;
   /** The system program */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** Pool's fee vault */
+  feeVault: Address<TAccountFeeVault>;
+  /** The stake mint's registry (enforces the TVL cap) */
+  mintRegistry: Address<TAccountMintRegistry>;
+  /** Pool's receipt mint (required when the pool tokenizes positions) */
+  receiptMint?: Address<TAccountReceiptMint>;
+  /** Owner's receipt token account to mint into (required when the pool tokenizes positions) */
+  userReceiptAccount?: Address<TAccountUserReceiptAccount>;
+  /** The Token-2022 program (required when the pool tokenizes positions) */
+  receiptTokenProgram?: Address<TAccountReceiptTokenProgram>;
+  /** The program authority account (global pause switches) */
+  programAuthority: Address<TAccountProgramAuthority>;
+  /** Pool allowlist page listing the owner (required when the pool requires allowlisting and no merkle proof is given) */
+  allowlist?: Address<TAccountAllowlist>;
+  /** The SPL Memo program (required with a memo) */
+  memoProgram?: Address<TAccountMemoProgram>;
+  /** The owner's stake position directory, created when empty (None: the index is not recorded) */
+  stakePosition?: Address<TAccountStakePosition>;
+  /** Authority of user_token_account staking for the owner (pools with allow_third_party_stake) */
+  funder?: TransactionSigner<TAccountFunder>;
+  /** The global deny list PDA ["deny_list"] (required when the pool checks it) */
+  denyList?: Address<TAccountDenyList>;
+  /** The current cohort's RewardCohort PDA ["reward_cohort", pool, cohort] (required when the pool has reward cohorts) */
+  rewardCohort?: Address<TAccountRewardCohort>;
+  /** The pool's hook program (required when the pool has one); the hook's own accounts follow */
+  hookProgram?: Address<TAccountHookProgram>;
   amount: StakeInstructionDataArgs['amount'];
   index: StakeInstructionDataArgs['index'];
   expectedRewardRate: StakeInstructionDataArgs['expectedRewardRate'];
   expectedLockupPeriod: StakeInstructionDataArgs['expectedLockupPeriod'];
+  referrer: StakeInstructionDataArgs['referrer'];
+  autoRelock: StakeInstructionDataArgs['autoRelock'];
+  label: StakeInstructionDataArgs['label'];
+  allowlistProof: StakeInstructionDataArgs['allowlistProof'];
+  memo: StakeInstructionDataArgs['memo'];
+  deadline: StakeInstructionDataArgs['deadline'];
 };
 
 export function getStakeInstruction<
@@ -200,6 +353,19 @@ export function getStakeInstruction<
   TAccountTokenProgram extends string,
   TAccountPayer extends string,
   TAccountSystemProgram extends string,
+  TAccountFeeVault extends string,
+  TAccountMintRegistry extends string,
+  TAccountReceiptMint extends string,
+  TAccountUserReceiptAccount extends string,
+  TAccountReceiptTokenProgram extends string,
+  TAccountProgramAuthority extends string,
+  TAccountAllowlist extends string,
+  TAccountMemoProgram extends string,
+  TAccountStakePosition extends string,
+  TAccountFunder extends string,
+  TAccountDenyList extends string,
+  TAccountRewardCohort extends string,
+  TAccountHookProgram extends string,
   TProgramAddress extends Address = typeof STAKE_POOL_PROGRAM_ADDRESS,
 >(
   input: StakeInput<
@@ -212,21 +378,49 @@ export function getStakeInstruction<
     TAccountStakeMint,
     TAccountTokenProgram,
     TAccountPayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountFeeVault,
+    TAccountMintRegistry,
+    TAccountReceiptMint,
+    TAccountUserReceiptAccount,
+    TAccountReceiptTokenProgram,
+    TAccountProgramAuthority,
+    TAccountAllowlist,
+    TAccountMemoProgram,
+    TAccountStakePosition,
+    TAccountFunder,
+    TAccountDenyList,
+    TAccountRewardCohort,
+    TAccountHookProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): StakeInstruction<
   TProgramAddress,
   TAccountPool,
   TAccountStakeAccount,
-  TAccountOwner,
+  (typeof input)['owner'] extends TransactionSigner<TAccountOwner>
+    ? ReadonlySignerAccount<TAccountOwner> & AccountSignerMeta<TAccountOwner>
+    : TAccountOwner,
   TAccountUserTokenAccount,
   TAccountStakeVault,
   TAccountRewardVault,
   TAccountStakeMint,
   TAccountTokenProgram,
   TAccountPayer,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountFeeVault,
+  TAccountMintRegistry,
+  TAccountReceiptMint,
+  TAccountUserReceiptAccount,
+  TAccountReceiptTokenProgram,
+  TAccountProgramAuthority,
+  TAccountAllowlist,
+  TAccountMemoProgram,
+  TAccountStakePosition,
+  TAccountFunder,
+  TAccountDenyList,
+  TAccountRewardCohort,
+  TAccountHookProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? STAKE_POOL_PROGRAM_ADDRESS;
@@ -246,6 +440,28 @@ export function getStakeInstruction<
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     payer: { value: input.payer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    feeVault: { value: input.feeVault ?? null, isWritable: true },
+    mintRegistry: { value: input.mintRegistry ?? null, isWritable: true },
+    receiptMint: { value: input.receiptMint ?? null, isWritable: true },
+    userReceiptAccount: {
+      value: input.userReceiptAccount ?? null,
+      isWritable: true,
+    },
+    receiptTokenProgram: {
+      value: input.receiptTokenProgram ?? null,
+      isWritable: false,
+    },
+    programAuthority: {
+      value: input.programAuthority ?? null,
+      isWritable: false,
+    },
+    allowlist: { value: input.allowlist ?? null, isWritable: false },
+    memoProgram: { value: input.memoProgram ?? null, isWritable: false },
+    stakePosition: { value: input.stakePosition ?? null, isWritable: true },
+    funder: { value: input.funder ?? null, isWritable: false },
+    denyList: { value: input.denyList ?? null, isWritable: false },
+    rewardCohort: { value: input.rewardCohort ?? null, isWritable: true },
+    hookProgram: { value: input.hookProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -278,6 +494,19 @@ export function getStakeInstruction<
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.feeVault),
+      getAccountMeta(accounts.mintRegistry),
+      getAccountMeta(accounts.receiptMint),
+      getAccountMeta(accounts.userReceiptAccount),
+      getAccountMeta(accounts.receiptTokenProgram),
+      getAccountMeta(accounts.programAuthority),
+      getAccountMeta(accounts.allowlist),
+      getAccountMeta(accounts.memoProgram),
+      getAccountMeta(accounts.stakePosition),
+      getAccountMeta(accounts.funder),
+      getAccountMeta(accounts.denyList),
+      getAccountMeta(accounts.rewardCohort),
+      getAccountMeta(accounts.hookProgram),
     ],
     data: getStakeInstructionDataEncoder().encode(
       args as StakeInstructionDataArgs
@@ -287,14 +516,29 @@ export function getStakeInstruction<
     TProgramAddress,
     TAccountPool,
     TAccountStakeAccount,
-    TAccountOwner,
+    (typeof input)['owner'] extends TransactionSigner<TAccountOwner>
+      ? ReadonlySignerAccount<TAccountOwner> & AccountSignerMeta<TAccountOwner>
+      : TAccountOwner,
     TAccountUserTokenAccount,
     TAccountStakeVault,
     TAccountRewardVault,
     TAccountStakeMint,
     TAccountTokenProgram,
     TAccountPayer,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountFeeVault,
+    TAccountMintRegistry,
+    TAccountReceiptMint,
+    TAccountUserReceiptAccount,
+    TAccountReceiptTokenProgram,
+    TAccountProgramAuthority,
+    TAccountAllowlist,
+    TAccountMemoProgram,
+    TAccountStakePosition,
+    TAccountFunder,
+    TAccountDenyList,
+    TAccountRewardCohort,
+    TAccountHookProgram
   >);
 }
 
@@ -308,13 +552,13 @@ export type ParsedStakeInstruction<
     pool: TAccountMetas[0];
     /** The stake account PDA (will be created) */
     stakeAccount: TAccountMetas[1];
-    /** The stake account owner */
+    /** The stake account owner (signs unless a funder stakes for it) */
     owner: TAccountMetas[2];
-    /** User's token account */
+    /** User's token account (the funder's when a funder stakes) */
     userTokenAccount: TAccountMetas[3];
     /** Pool's stake vault */
     stakeVault: TAccountMetas[4];
-    /** Pool's reward vault (for checking available rewards) */
+    /** Pool's reward vault, or the cohort vault in pools with reward cohorts (for checking available rewards) */
     rewardVault: TAccountMetas[5];
     /** The token mint being staked */
     stakeMint: TAccountMetas[6];
@@ -324,6 +568,32 @@ export type ParsedStakeInstruction<
     payer: TAccountMetas[8];
     /** The system program */
     systemProgram: TAccountMetas[9];
+    /** Pool's fee vault */
+    feeVault: TAccountMetas[10];
+    /** The stake mint's registry (enforces the TVL cap) */
+    mintRegistry: TAccountMetas[11];
+    /** Pool's receipt mint (required when the pool tokenizes positions) */
+    receiptMint?: TAccountMetas[12] | undefined;
+    /** Owner's receipt token account to mint into (required when the pool tokenizes positions) */
+    userReceiptAccount?: TAccountMetas[13] | undefined;
+    /** The Token-2022 program (required when the pool tokenizes positions) */
+    receiptTokenProgram?: TAccountMetas[14] | undefined;
+    /** The program authority account (global pause switches) */
+    programAuthority: TAccountMetas[15];
+    /** Pool allowlist page listing the owner (required when the pool requires allowlisting and no merkle proof is given) */
+    allowlist?: TAccountMetas[16] | undefined;
+    /** The SPL Memo program (required with a memo) */
+    memoProgram?: TAccountMetas[17] | undefined;
+    /** The owner's stake position directory, created when empty (None: the index is not recorded) */
+    stakePosition?: TAccountMetas[18] | undefined;
+    /** Authority of user_token_account staking for the owner (pools with allow_third_party_stake) */
+    funder?: TAccountMetas[19] | undefined;
+    /** The global deny list PDA ["deny_list"] (required when the pool checks it) */
+    denyList?: TAccountMetas[20] | undefined;
+    /** The current cohort's RewardCohort PDA ["reward_cohort", pool, cohort] (required when the pool has reward cohorts) */
+    rewardCohort?: TAccountMetas[21] | undefined;
+    /** The pool's hook program (required when the pool has one); the hook's own accounts follow */
+    hookProgram?: TAccountMetas[22] | undefined;
   };
   data: StakeInstructionData;
 };
@@ -336,7 +606,7 @@ export function parseStakeInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedStakeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 23) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -346,6 +616,12 @@ export function parseStakeInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === STAKE_POOL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -359,6 +635,19 @@ export function parseStakeInstruction<
       tokenProgram: getNextAccount(),
       payer: getNextAccount(),
       systemProgram: getNextAccount(),
+      feeVault: getNextAccount(),
+      mintRegistry: getNextAccount(),
+      receiptMint: getNextOptionalAccount(),
+      userReceiptAccount: getNextOptionalAccount(),
+      receiptTokenProgram: getNextOptionalAccount(),
+      programAuthority: getNextAccount(),
+      allowlist: getNextOptionalAccount(),
+      memoProgram: getNextOptionalAccount(),
+      stakePosition: getNextOptionalAccount(),
+      funder: getNextOptionalAccount(),
+      denyList: getNextOptionalAccount(),
+      rewardCohort: getNextOptionalAccount(),
+      hookProgram: getNextOptionalAccount(),
     },
     data: getStakeInstructionDataDecoder().decode(instruction.data),
   };
@@ -7,15 +7,23 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
+  getI64Decoder,
+  getI64Encoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
   getU8Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -55,6 +63,15 @@ export type UnstakeInstruction<
     | string
     | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
   TAccountClock extends string | AccountMeta<string> = string,
+  TAccountFeeVault extends string | AccountMeta<string> = string,
+  TAccountMintRegistry extends string | AccountMeta<string> = string,
+  TAccountReceiptMint extends string | AccountMeta<string> = string,
+  TAccountUserReceiptAccount extends string | AccountMeta<string> = string,
+  TAccountReceiptTokenProgram extends string | AccountMeta<string> = string,
+  TAccountProgramAuthority extends string | AccountMeta<string> = string,
+  TAccountMemoProgram extends string | AccountMeta<string> = string,
+  TAccountDenyList extends string | AccountMeta<string> = string,
+  TAccountHookProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -85,6 +102,33 @@ export type UnstakeInstruction<
       TAccountClock extends string
         ? ReadonlyAccount<TAccountClock>
         : TAccountClock,
+      TAccountFeeVault extends string
+        ? WritableAccount<TAccountFeeVault>
+        : TAccountFeeVault,
+      TAccountMintRegistry extends string
+        ? WritableAccount<TAccountMintRegistry>
+        : TAccountMintRegistry,
+      TAccountReceiptMint extends string
+        ? WritableAccount<TAccountReceiptMint>
+        : TAccountReceiptMint,
+      TAccountUserReceiptAccount extends string
+        ? WritableAccount<TAccountUserReceiptAccount>
+        : TAccountUserReceiptAccount,
+      TAccountReceiptTokenProgram extends string
+        ? ReadonlyAccount<TAccountReceiptTokenProgram>
+        : TAccountReceiptTokenProgram,
+      TAccountProgramAuthority extends string
+        ? ReadonlyAccount<TAccountProgramAuthority>
+        : TAccountProgramAuthority,
+      TAccountMemoProgram extends string
+        ? ReadonlyAccount<TAccountMemoProgram>
+        : TAccountMemoProgram,
+      TAccountDenyList extends string
+        ? ReadonlyAccount<TAccountDenyList>
+        : TAccountDenyList,
+      TAccountHookProgram extends string
+        ? ReadonlyAccount<TAccountHookProgram>
+        : TAccountHookProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -93,11 +137,15 @@ export type UnstakeInstructionData = {
   discriminator: number;
   amount: bigint;
   expectedRewardRate: Option<bigint>;
+  memo: Option<string>;
+  deadline: Option<bigint>;
 };
 
 export type UnstakeInstructionDataArgs = {
   amount: number | bigint;
   expectedRewardRate: OptionOrNullable<number | bigint>;
+  memo: OptionOrNullable<string>;
+  deadline: OptionOrNullable<number | bigint>;
 };
 
 export function getUnstakeInstructionDataEncoder(): Encoder<UnstakeInstructionDataArgs> {
@@ -106,6 +154,13 @@ export function getUnstakeInstructionDataEncoder(): Encoder<UnstakeInstructionDa
       ['discriminator', getU8Encoder()],
       ['amount', getU64Encoder()],
       ['expectedRewardRate', getOptionEncoder(getU64Encoder())],
+      [
+        'memo',
+        getOptionEncoder(
+          addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())
+        ),
+      ],
+      ['deadline', getOptionEncoder(getI64Encoder())],
     ]),
     (value) => ({ ...value, discriminator: UNSTAKE_DISCRIMINATOR })
   );
@@ -116,6 +171,11 @@ export function getUnstakeInstructionDataDecoder(): Decoder<UnstakeInstructionDa
     ['discriminator', getU8Decoder()],
     ['amount', getU64Decoder()],
     ['expectedRewardRate', getOptionDecoder(getU64Decoder())],
+    [
+      'memo',
+      getOptionDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())),
+    ],
+    ['deadline', getOptionDecoder(getI64Decoder())],
   ]);
 }
 
@@ -138,6 +198,15 @@ export type UnstakeInput<
   TAccountStakeMint extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountClock extends string = string,
+  TAccountFeeVault extends string = string,
+  TAccountMintRegistry extends string = string,
+  TAccountReceiptMint extends string = string,
+  TAccountUserReceiptAccount extends string = string,
+  TAccountReceiptTokenProgram extends string = string,
+  TAccountProgramAuthority extends string = string,
+  TAccountMemoProgram extends string = string,
+  TAccountDenyList extends string = string,
+  TAccountHookProgram extends string = string,
 > = {
   /** The stake pool */
   pool: Address<TAccountPool>;
@@ -155,8 +224,28 @@ export type UnstakeInput<
   tokenProgram?: Address<TAccountTokenProgram>;
   /** Clock sysvar */
   clock: Address<TAccountClock>;
+  /** Pool's fee vault */
+  feeVault: Address<TAccountFeeVault>;
+  /** The stake mint's registry */
+  mintRegistry: Address<TAccountMintRegistry>;
+  /** Pool's receipt mint (required when the pool tokenizes positions) */
+  receiptMint?: Address<TAccountReceiptMint>;
+  /** Owner's receipt token account to burn from (required when the pool tokenizes positions) */
+  userReceiptAccount?: Address<TAccountUserReceiptAccount>;
+  /** The Token-2022 program (required when the pool tokenizes positions) */
+  receiptTokenProgram?: Address<TAccountReceiptTokenProgram>;
+  /** The program authority account (global pause switches) */
+  programAuthority: Address<TAccountProgramAuthority>;
+  /** The SPL Memo program (required with a memo) */
+  memoProgram?: Address<TAccountMemoProgram>;
+  /** The global deny list PDA ["deny_list"] (required when the pool checks it) */
+  denyList?: Address<TAccountDenyList>;
+  /** The pool's hook program (required when the pool has one); the hook's own accounts follow */
+  hookProgram?: Address<TAccountHookProgram>;
   amount: UnstakeInstructionDataArgs['amount'];
   expectedRewardRate: UnstakeInstructionDataArgs['expectedRewardRate'];
+  memo: UnstakeInstructionDataArgs['memo'];
+  deadline: UnstakeInstructionDataArgs['deadline'];
 };
 
 export function getUnstakeInstruction<
@@ -168,6 +257,15 @@ export function getUnstakeInstruction<
   TAccountStakeMint extends string,
   TAccountTokenProgram extends string,
   TAccountClock extends string,
+  TAccountFeeVault extends string,
+  TAccountMintRegistry extends string,
+  TAccountReceiptMint extends string,
+  TAccountUserReceiptAccount extends string,
+  TAccountReceiptTokenProgram extends string,
+  TAccountProgramAuthority extends string,
+  TAccountMemoProgram extends string,
+  TAccountDenyList extends string,
+  TAccountHookProgram extends string,
   TProgramAddress extends Address = typeof STAKE_POOL_PROGRAM_ADDRESS,
 >(
   input: UnstakeInput<
@@ -178,7 +276,16 @@ export function getUnstakeInstruction<
     TAccountStakeVault,
     TAccountStakeMint,
     TAccountTokenProgram,
-    TAccountClock
+    TAccountClock,
+    TAccountFeeVault,
+    TAccountMintRegistry,
+    TAccountReceiptMint,
+    TAccountUserReceiptAccount,
+    TAccountReceiptTokenProgram,
+    TAccountProgramAuthority,
+    TAccountMemoProgram,
+    TAccountDenyList,
+    TAccountHookProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): UnstakeInstruction<
@@ -190,7 +297,16 @@ export function getUnstakeInstruction<
   TAccountStakeVault,
   TAccountStakeMint,
   TAccountTokenProgram,
-  TAccountClock
+  TAccountClock,
+  TAccountFeeVault,
+  TAccountMintRegistry,
+  TAccountReceiptMint,
+  TAccountUserReceiptAccount,
+  TAccountReceiptTokenProgram,
+  TAccountProgramAuthority,
+  TAccountMemoProgram,
+  TAccountDenyList,
+  TAccountHookProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? STAKE_POOL_PROGRAM_ADDRESS;
@@ -208,6 +324,24 @@ export function getUnstakeInstruction<
     stakeMint: { value: input.stakeMint ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     clock: { value: input.clock ?? null, isWritable: false },
+    feeVault: { value: input.feeVault ?? null, isWritable: true },
+    mintRegistry: { value: input.mintRegistry ?? null, isWritable: true },
+    receiptMint: { value: input.receiptMint ?? null, isWritable: true },
+    userReceiptAccount: {
+      value: input.userReceiptAccount ?? null,
+      isWritable: true,
+    },
+    receiptTokenProgram: {
+      value: input.receiptTokenProgram ?? null,
+      isWritable: false,
+    },
+    programAuthority: {
+      value: input.programAuthority ?? null,
+      isWritable: false,
+    },
+    memoProgram: { value: input.memoProgram ?? null, isWritable: false },
+    denyList: { value: input.denyList ?? null, isWritable: false },
+    hookProgram: { value: input.hookProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -234,6 +368,15 @@ export function getUnstakeInstruction<
       getAccountMeta(accounts.stakeMint),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.clock),
+      getAccountMeta(accounts.feeVault),
+      getAccountMeta(accounts.mintRegistry),
+      getAccountMeta(accounts.receiptMint),
+      getAccountMeta(accounts.userReceiptAccount),
+      getAccountMeta(accounts.receiptTokenProgram),
+      getAccountMeta(accounts.programAuthority),
+      getAccountMeta(accounts.memoProgram),
+      getAccountMeta(accounts.denyList),
+      getAccountMeta(accounts.hookProgram),
     ],
     data: getUnstakeInstructionDataEncoder().encode(
       args as UnstakeInstructionDataArgs
@@ -248,7 +391,16 @@ export function getUnstakeInstruction<
     TAccountStakeVault,
     TAccountStakeMint,
     TAccountTokenProgram,
-    TAccountClock
+    TAccountClock,
+    TAccountFeeVault,
+    TAccountMintRegistry,
+    TAccountReceiptMint,
+    TAccountUserReceiptAccount,
+    TAccountReceiptTokenProgram,
+    TAccountProgramAuthority,
+    TAccountMemoProgram,
+    TAccountDenyList,
+    TAccountHookProgram
   >);
 }
 
@@ -274,6 +426,24 @@ export type ParsedUnstakeInstruction<
     tokenProgram: TAccountMetas[6];
     /** Clock sysvar */
     clock: TAccountMetas[7];
+    /** Pool's fee vault */
+    feeVault: TAccountMetas[8];
+    /** The stake mint's registry */
+    mintRegistry: TAccountMetas[9];
+    /** Pool's receipt mint (required when the pool tokenizes positions) */
+    receiptMint?: TAccountMetas[10] | undefined;
+    /** Owner's receipt token account to burn from (required when the pool tokenizes positions) */
+    userReceiptAccount?: TAccountMetas[11] | undefined;
+    /** The Token-2022 program (required when the pool tokenizes positions) */
+    receiptTokenProgram?: TAccountMetas[12] | undefined;
+    /** The program authority account (global pause switches) */
+    programAuthority: TAccountMetas[13];
+    /** The SPL Memo program (required with a memo) */
+    memoProgram?: TAccountMetas[14] | undefined;
+    /** The global deny list PDA ["deny_list"] (required when the pool checks it) */
+    denyList?: TAccountMetas[15] | undefined;
+    /** The pool's hook program (required when the pool has one); the hook's own accounts follow */
+    hookProgram?: TAccountMetas[16] | undefined;
   };
   data: UnstakeInstructionData;
 };
@@ -286,7 +456,7 @@ export function parseUnstakeInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUnstakeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 17) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -296,6 +466,12 @@ export function parseUnstakeInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === STAKE_POOL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -307,6 +483,15 @@ export function parseUnstakeInstruction<
       stakeMint: getNextAccount(),
       tokenProgram: getNextAccount(),
       clock: getNextAccount(),
+      feeVault: getNextAccount(),
+      mintRegistry: getNextAccount(),
+      receiptMint: getNextOptionalAccount(),
+      userReceiptAccount: getNextOptionalAccount(),
+      receiptTokenProgram: getNextOptionalAccount(),
+      programAuthority: getNextAccount(),
+      memoProgram: getNextOptionalAccount(),
+      denyList: getNextOptionalAccount(),
+      hookProgram: getNextOptionalAccount(),
     },
     data: getUnstakeInstructionDataDecoder().decode(instruction.data),
   };
@@ -8,14 +8,22 @@
 
 import {
   combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getAddressDecoder,
+  getAddressEncoder,
   getBooleanDecoder,
   getBooleanEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
   getI64Decoder,
   getI64Encoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -40,6 +48,20 @@ import {
 } from '@solana/kit';
 import { STAKE_POOL_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  getClaimWindowDecoder,
+  getClaimWindowEncoder,
+  getOutflowLimitDecoder,
+  getOutflowLimitEncoder,
+  getWindDownRewardsDecoder,
+  getWindDownRewardsEncoder,
+  type ClaimWindow,
+  type ClaimWindowArgs,
+  type OutflowLimit,
+  type OutflowLimitArgs,
+  type WindDownRewards,
+  type WindDownRewardsArgs,
+} from '../types';
 
 export const UPDATE_POOL_DISCRIMINATOR = 4;
 
@@ -52,6 +74,7 @@ export type UpdatePoolInstruction<
   TAccountPool extends string | AccountMeta<string> = string,
   TAccountAdmin extends string | AccountMeta<string> = string,
   TAccountProgramAuthority extends string | AccountMeta<string> = string,
+  TAccountProgramConfig extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -67,6 +90,9 @@ export type UpdatePoolInstruction<
       TAccountProgramAuthority extends string
         ? ReadonlyAccount<TAccountProgramAuthority>
         : TAccountProgramAuthority,
+      TAccountProgramConfig extends string
+        ? ReadonlyAccount<TAccountProgramConfig>
+        : TAccountProgramConfig,
       ...TRemainingAccounts,
     ]
   >;
@@ -79,6 +105,26 @@ export type UpdatePoolInstructionData = {
   isPaused: Option<boolean>;
   enforceLockup: Option<boolean>;
   poolEndDate: Option<Option<bigint>>;
+  referralBps: Option<number>;
+  earlyUnstakeBps: Option<number>;
+  depositFeeBps: Option<number>;
+  withdrawFeeBps: Option<number>;
+  unstakeCooldown: Option<bigint>;
+  bonusEndTs: Option<Option<bigint>>;
+  bonusMultiplierBps: Option<number>;
+  lockupExtensionRate: Option<bigint>;
+  allowlistMerkleRoot: Option<Option<ReadonlyUint8Array>>;
+  claimWindow: Option<Option<ClaimWindow>>;
+  outflowLimit: Option<Option<OutflowLimit>>;
+  poolManager: Option<Option<Address>>;
+  keeperBounty: Option<bigint>;
+  maxTotalRewards: Option<Option<bigint>>;
+  enforceMinRemainingStake: Option<boolean>;
+  windDownRewards: Option<WindDownRewards>;
+  pointsRate: Option<bigint>;
+  denyListRequired: Option<boolean>;
+  allowThirdPartyStake: Option<boolean>;
+  cohortDuration: Option<bigint>;
 };
 
 export type UpdatePoolInstructionDataArgs = {
@@ -88,6 +134,26 @@ export type UpdatePoolInstructionDataArgs = {
   isPaused: OptionOrNullable<boolean>;
   enforceLockup: OptionOrNullable<boolean>;
   poolEndDate: OptionOrNullable<OptionOrNullable<number | bigint>>;
+  referralBps: OptionOrNullable<number>;
+  earlyUnstakeBps: OptionOrNullable<number>;
+  depositFeeBps: OptionOrNullable<number>;
+  withdrawFeeBps: OptionOrNullable<number>;
+  unstakeCooldown: OptionOrNullable<number | bigint>;
+  bonusEndTs: OptionOrNullable<OptionOrNullable<number | bigint>>;
+  bonusMultiplierBps: OptionOrNullable<number>;
+  lockupExtensionRate: OptionOrNullable<number | bigint>;
+  allowlistMerkleRoot: OptionOrNullable<OptionOrNullable<ReadonlyUint8Array>>;
+  claimWindow: OptionOrNullable<OptionOrNullable<ClaimWindowArgs>>;
+  outflowLimit: OptionOrNullable<OptionOrNullable<OutflowLimitArgs>>;
+  poolManager: OptionOrNullable<OptionOrNullable<Address>>;
+  keeperBounty: OptionOrNullable<number | bigint>;
+  maxTotalRewards: OptionOrNullable<OptionOrNullable<number | bigint>>;
+  enforceMinRemainingStake: OptionOrNullable<boolean>;
+  windDownRewards: OptionOrNullable<WindDownRewardsArgs>;
+  pointsRate: OptionOrNullable<number | bigint>;
+  denyListRequired: OptionOrNullable<boolean>;
+  allowThirdPartyStake: OptionOrNullable<boolean>;
+  cohortDuration: OptionOrNullable<number | bigint>;
 };
 
 export function getUpdatePoolInstructionDataEncoder(): Encoder<UpdatePoolInstructionDataArgs> {
@@ -100,6 +166,37 @@ export function getUpdatePoolInstructionDataEncoder(): Encoder<UpdatePoolInstruc
       ['isPaused', getOptionEncoder(getBooleanEncoder())],
       ['enforceLockup', getOptionEncoder(getBooleanEncoder())],
       ['poolEndDate', getOptionEncoder(getOptionEncoder(getI64Encoder()))],
+      ['referralBps', getOptionEncoder(getU16Encoder())],
+      ['earlyUnstakeBps', getOptionEncoder(getU16Encoder())],
+      ['depositFeeBps', getOptionEncoder(getU16Encoder())],
+      ['withdrawFeeBps', getOptionEncoder(getU16Encoder())],
+      ['unstakeCooldown', getOptionEncoder(getI64Encoder())],
+      ['bonusEndTs', getOptionEncoder(getOptionEncoder(getI64Encoder()))],
+      ['bonusMultiplierBps', getOptionEncoder(getU16Encoder())],
+      ['lockupExtensionRate', getOptionEncoder(getU64Encoder())],
+      [
+        'allowlistMerkleRoot',
+        getOptionEncoder(
+          getOptionEncoder(fixEncoderSize(getBytesEncoder(), 32))
+        ),
+      ],
+      [
+        'claimWindow',
+        getOptionEncoder(getOptionEncoder(getClaimWindowEncoder())),
+      ],
+      [
+        'outflowLimit',
+        getOptionEncoder(getOptionEncoder(getOutflowLimitEncoder())),
+      ],
+      ['poolManager', getOptionEncoder(getOptionEncoder(getAddressEncoder()))],
+      ['keeperBounty', getOptionEncoder(getU64Encoder())],
+      ['maxTotalRewards', getOptionEncoder(getOptionEncoder(getU64Encoder()))],
+      ['enforceMinRemainingStake', getOptionEncoder(getBooleanEncoder())],
+      ['windDownRewards', getOptionEncoder(getWindDownRewardsEncoder())],
+      ['pointsRate', getOptionEncoder(getU64Encoder())],
+      ['denyListRequired', getOptionEncoder(getBooleanEncoder())],
+      ['allowThirdPartyStake', getOptionEncoder(getBooleanEncoder())],
+      ['cohortDuration', getOptionEncoder(getI64Encoder())],
     ]),
     (value) => ({ ...value, discriminator: UPDATE_POOL_DISCRIMINATOR })
   );
@@ -114,6 +211,35 @@ export function getUpdatePoolInstructionDataDecoder(): Decoder<UpdatePoolInstruc
     ['isPaused', getOptionDecoder(getBooleanDecoder())],
     ['enforceLockup', getOptionDecoder(getBooleanDecoder())],
     ['poolEndDate', getOptionDecoder(getOptionDecoder(getI64Decoder()))],
+    ['referralBps', getOptionDecoder(getU16Decoder())],
+    ['earlyUnstakeBps', getOptionDecoder(getU16Decoder())],
+    ['depositFeeBps', getOptionDecoder(getU16Decoder())],
+    ['withdrawFeeBps', getOptionDecoder(getU16Decoder())],
+    ['unstakeCooldown', getOptionDecoder(getI64Decoder())],
+    ['bonusEndTs', getOptionDecoder(getOptionDecoder(getI64Decoder()))],
+    ['bonusMultiplierBps', getOptionDecoder(getU16Decoder())],
+    ['lockupExtensionRate', getOptionDecoder(getU64Decoder())],
+    [
+      'allowlistMerkleRoot',
+      getOptionDecoder(getOptionDecoder(fixDecoderSize(getBytesDecoder(), 32))),
+    ],
+    [
+      'claimWindow',
+      getOptionDecoder(getOptionDecoder(getClaimWindowDecoder())),
+    ],
+    [
+      'outflowLimit',
+      getOptionDecoder(getOptionDecoder(getOutflowLimitDecoder())),
+    ],
+    ['poolManager', getOptionDecoder(getOptionDecoder(getAddressDecoder()))],
+    ['keeperBounty', getOptionDecoder(getU64Decoder())],
+    ['maxTotalRewards', getOptionDecoder(getOptionDecoder(getU64Decoder()))],
+    ['enforceMinRemainingStake', getOptionDecoder(getBooleanDecoder())],
+    ['windDownRewards', getOptionDecoder(getWindDownRewardsDecoder())],
+    ['pointsRate', getOptionDecoder(getU64Decoder())],
+    ['denyListRequired', getOptionDecoder(getBooleanDecoder())],
+    ['allowThirdPartyStake', getOptionDecoder(getBooleanDecoder())],
+    ['cohortDuration', getOptionDecoder(getI64Decoder())],
   ]);
 }
 
@@ -131,34 +257,64 @@ export type UpdatePoolInput<
   TAccountPool extends string = string,
   TAccountAdmin extends string = string,
   TAccountProgramAuthority extends string = string,
+  TAccountProgramConfig extends string = string,
 > = {
   /** The stake pool */
   pool: Address<TAccountPool>;
-  /** The global admin (authorized in ProgramAuthority) */
+  /** The global admin or a role holder allowed to change the updated parameters */
   admin: TransactionSigner<TAccountAdmin>;
   /** The program authority account (validates admin permission) */
   programAuthority: Address<TAccountProgramAuthority>;
+  /** The program config PDA ["program_config"] (built-in limits while uninitialized) */
+  programConfig: Address<TAccountProgramConfig>;
   rewardRate: UpdatePoolInstructionDataArgs['rewardRate'];
   minStakeAmount: UpdatePoolInstructionDataArgs['minStakeAmount'];
   lockupPeriod: UpdatePoolInstructionDataArgs['lockupPeriod'];
   isPaused: UpdatePoolInstructionDataArgs['isPaused'];
   enforceLockup: UpdatePoolInstructionDataArgs['enforceLockup'];
   poolEndDate: UpdatePoolInstructionDataArgs['poolEndDate'];
+  referralBps: UpdatePoolInstructionDataArgs['referralBps'];
+  earlyUnstakeBps: UpdatePoolInstructionDataArgs['earlyUnstakeBps'];
+  depositFeeBps: UpdatePoolInstructionDataArgs['depositFeeBps'];
+  withdrawFeeBps: UpdatePoolInstructionDataArgs['withdrawFeeBps'];
+  unstakeCooldown: UpdatePoolInstructionDataArgs['unstakeCooldown'];
+  bonusEndTs: UpdatePoolInstructionDataArgs['bonusEndTs'];
+  bonusMultiplierBps: UpdatePoolInstructionDataArgs['bonusMultiplierBps'];
+  lockupExtensionRate: UpdatePoolInstructionDataArgs['lockupExtensionRate'];
+  allowlistMerkleRoot: UpdatePoolInstructionDataArgs['allowlistMerkleRoot'];
+  claimWindow: UpdatePoolInstructionDataArgs['claimWindow'];
+  outflowLimit: UpdatePoolInstructionDataArgs['outflowLimit'];
+  poolManager: UpdatePoolInstructionDataArgs['poolManager'];
+  keeperBounty: UpdatePoolInstructionDataArgs['keeperBounty'];
+  maxTotalRewards: UpdatePoolInstructionDataArgs['maxTotalRewards'];
+  enforceMinRemainingStake: UpdatePoolInstructionDataArgs['enforceMinRemainingStake'];
+  windDownRewards: UpdatePoolInstructionDataArgs['windDownRewards'];
+  pointsRate: UpdatePoolInstructionDataArgs['pointsRate'];
+  denyListRequired: UpdatePoolInstructionDataArgs['denyListRequired'];
+  allowThirdPartyStake: UpdatePoolInstructionDataArgs['allowThirdPartyStake'];
+  cohortDuration: UpdatePoolInstructionDataArgs['cohortDuration'];
 };
 
 export function getUpdatePoolInstruction<
   TAccountPool extends string,
   TAccountAdmin extends string,
   TAccountProgramAuthority extends string,
+  TAccountProgramConfig extends string,
   TProgramAddress extends Address = typeof STAKE_POOL_PROGRAM_ADDRESS,
 >(
-  input: UpdatePoolInput<TAccountPool, TAccountAdmin, TAccountProgramAuthority>,
+  input: UpdatePoolInput<
+    TAccountPool,
+    TAccountAdmin,
+    TAccountProgramAuthority,
+    TAccountProgramConfig
+  >,
   config?: { programAddress?: TProgramAddress }
 ): UpdatePoolInstruction<
   TProgramAddress,
   TAccountPool,
   TAccountAdmin,
-  TAccountProgramAuthority
+  TAccountProgramAuthority,
+  TAccountProgramConfig
 > {
   // Program address.
   const programAddress = config?.programAddress ?? STAKE_POOL_PROGRAM_ADDRESS;
@@ -171,6 +327,7 @@ export function getUpdatePoolInstruction<
       value: input.programAuthority ?? null,
       isWritable: false,
     },
+    programConfig: { value: input.programConfig ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -186,6 +343,7 @@ export function getUpdatePoolInstruction<
       getAccountMeta(accounts.pool),
       getAccountMeta(accounts.admin),
       getAccountMeta(accounts.programAuthority),
+      getAccountMeta(accounts.programConfig),
     ],
     data: getUpdatePoolInstructionDataEncoder().encode(
       args as UpdatePoolInstructionDataArgs
@@ -195,7 +353,8 @@ export function getUpdatePoolInstruction<
     TProgramAddress,
     TAccountPool,
     TAccountAdmin,
-    TAccountProgramAuthority
+    TAccountProgramAuthority,
+    TAccountProgramConfig
   >);
 }
 
@@ -207,10 +366,12 @@ export type ParsedUpdatePoolInstruction<
   accounts: {
     /** The stake pool */
     pool: TAccountMetas[0];
-    /** The global admin (authorized in ProgramAuthority) */
+    /** The global admin or a role holder allowed to change the updated parameters */
     admin: TAccountMetas[1];
     /** The program authority account (validates admin permission) */
     programAuthority: TAccountMetas[2];
+    /** The program config PDA ["program_config"] (built-in limits while uninitialized) */
+    programConfig: TAccountMetas[3];
   };
   data: UpdatePoolInstructionData;
 };
@@ -223,7 +384,7 @@ export function parseUpdatePoolInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUpdatePoolInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 4) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -239,6 +400,7 @@ export function parseUpdatePoolInstruction<
       pool: getNextAccount(),
       admin: getNextAccount(),
       programAuthority: getNextAccount(),
+      programConfig: getNextAccount(),
     },
     data: getUpdatePoolInstructionDataDecoder().decode(instruction.data),
   };
@@ -14,20 +14,88 @@ import {
 } from '@solana/kit';
 import {
   type ParsedAcceptProgramAuthorityInstruction,
+  type ParsedAcceptStakeOwnershipInstruction,
+  type ParsedApprovePoolUpdateInstruction,
+  type ParsedAuditPoolInstruction,
   type ParsedCancelAuthorityTransferInstruction,
+  type ParsedCancelSlashInstruction,
   type ParsedCheckAuthorizationInstruction,
+  type ParsedClaimExpiredRewardsInstruction,
+  type ParsedClaimRewardsBatchInstruction,
   type ParsedClaimRewardsInstruction,
+  type ParsedClonePoolInstruction,
+  type ParsedClosePoolInstruction,
+  type ParsedCloseProgramAuthorityInstruction,
+  type ParsedCloseRewardStreamInstruction,
   type ParsedCloseStakeAccountInstruction,
+  type ParsedCompleteRecoveryInstruction,
+  type ParsedCrankRewardStreamInstruction,
+  type ParsedCreateRewardStreamInstruction,
+  type ParsedDefundCohortRewardsInstruction,
+  type ParsedDefundRewardsInstruction,
+  type ParsedEnableReceiptTokenInstruction,
+  type ParsedExecutePoolUpdateInstruction,
+  type ParsedExecuteSlashInstruction,
+  type ParsedExpireRewardsInstruction,
+  type ParsedExtendLockupInstruction,
+  type ParsedFinalizeLockupChangeInstruction,
   type ParsedFinalizeRewardRateChangeInstruction,
+  type ParsedFundCohortRewardsInstruction,
+  type ParsedFundKeeperBountyInstruction,
   type ParsedFundRewardsInstruction,
   type ParsedGetAuthorizedCreatorsInstruction,
+  type ParsedGetPendingRewardsInstruction,
+  type ParsedGetPoolStatsInstruction,
+  type ParsedGetStakePositionInstruction,
+  type ParsedGetUserPointsInstruction,
   type ParsedInitializePoolInstruction,
   type ParsedInitializeProgramAuthorityInstruction,
+  type ParsedInitializeProgramConfigInstruction,
+  type ParsedInitiateRecoveryInstruction,
   type ParsedManageAuthorizedCreatorsInstruction,
+  type ParsedManageDenyListInstruction,
+  type ParsedManageHookAllowlistInstruction,
+  type ParsedManagePoolAllowlistInstruction,
+  type ParsedManageRolesInstruction,
+  type ParsedMergeStakesInstruction,
+  type ParsedMigratePoolInstruction,
+  type ParsedMigrateProgramAuthorityInstruction,
+  type ParsedNominateStakeOwnerInstruction,
+  type ParsedProposePoolUpdateInstruction,
+  type ParsedProposeSlashInstruction,
+  type ParsedRegisterPoolInstruction,
+  type ParsedRelockInstruction,
+  type ParsedRequestUnstakeInstruction,
+  type ParsedResizePoolInstruction,
+  type ParsedSetApprovalThresholdInstruction,
+  type ParsedSetAutoRelockInstruction,
+  type ParsedSetCreatorPoolLimitInstruction,
+  type ParsedSetGlobalPauseInstruction,
+  type ParsedSetMaxCreatorsInstruction,
+  type ParsedSetMintTvlCapInstruction,
+  type ParsedSetPoolHookInstruction,
+  type ParsedSetRecoveryAuthorityInstruction,
+  type ParsedSetRewardFundersInstruction,
+  type ParsedSetStakeDelegateInstruction,
+  type ParsedSetStakeLabelInstruction,
+  type ParsedSplitStakeInstruction,
+  type ParsedStakeBatchInstruction,
   type ParsedStakeInstruction,
+  type ParsedStakeSolInstruction,
+  type ParsedSweepDustInstruction,
+  type ParsedSyncPointsInstruction,
+  type ParsedSyncPoolInstruction,
   type ParsedTransferProgramAuthorityInstruction,
+  type ParsedUnstakeAllInstruction,
   type ParsedUnstakeInstruction,
+  type ParsedUnstakeSolInstruction,
   type ParsedUpdatePoolInstruction,
+  type ParsedUpdatePoolsBatchInstruction,
+  type ParsedUpdateProgramConfigInstruction,
+  type ParsedVestingStakeInstruction,
+  type ParsedWindDownStakeInstruction,
+  type ParsedWithdrawFeesInstruction,
+  type ParsedWithdrawUnstakedInstruction,
 } from '../instructions';
 
 export const STAKE_POOL_PROGRAM_ADDRESS =
@@ -37,6 +105,18 @@ export enum StakePoolAccount {
   StakePool,
   StakeAccount,
   ProgramAuthority,
+  MintRegistry,
+  PoolRegistry,
+  PendingAction,
+  PoolAllowlist,
+  SlashProposal,
+  ProgramConfig,
+  RewardStream,
+  StakePosition,
+  HookAllowlist,
+  UserPoints,
+  DenyList,
+  RewardCohort,
 }
 
 export enum StakePoolInstruction {
@@ -55,6 +135,74 @@ export enum StakePoolInstruction {
   CancelAuthorityTransfer,
   GetAuthorizedCreators,
   CheckAuthorization,
+  WithdrawFees,
+  UpdatePoolsBatch,
+  StakeSol,
+  UnstakeSol,
+  SetAutoRelock,
+  Relock,
+  SyncPool,
+  CloseProgramAuthority,
+  SetRewardFunders,
+  DefundRewards,
+  SetMintTvlCap,
+  ClosePool,
+  SetStakeDelegate,
+  EnableReceiptToken,
+  ExpireRewards,
+  ClaimExpiredRewards,
+  RequestUnstake,
+  WithdrawUnstaked,
+  SetGlobalPause,
+  MigratePool,
+  StakeBatch,
+  ClaimRewardsBatch,
+  GetPendingRewards,
+  RegisterPool,
+  SetCreatorPoolLimit,
+  ManageRoles,
+  SetApprovalThreshold,
+  ProposePoolUpdate,
+  ApprovePoolUpdate,
+  ExecutePoolUpdate,
+  FinalizeLockupChange,
+  SetStakeLabel,
+  NominateStakeOwner,
+  AcceptStakeOwnership,
+  SweepDust,
+  GetPoolStats,
+  ExtendLockup,
+  ManagePoolAllowlist,
+  VestingStake,
+  ProposeSlash,
+  ExecuteSlash,
+  CancelSlash,
+  SetRecoveryAuthority,
+  InitiateRecovery,
+  CompleteRecovery,
+  InitializeProgramConfig,
+  UpdateProgramConfig,
+  SetMaxCreators,
+  MigrateProgramAuthority,
+  ClonePool,
+  CreateRewardStream,
+  CrankRewardStream,
+  CloseRewardStream,
+  FundKeeperBounty,
+  ResizePool,
+  GetStakePosition,
+  AuditPool,
+  WindDownStake,
+  ManageHookAllowlist,
+  SetPoolHook,
+  SyncPoints,
+  GetUserPoints,
+  ManageDenyList,
+  SplitStake,
+  MergeStakes,
+  FundCohortRewards,
+  DefundCohortRewards,
+  UnstakeAll,
 }
 
 export function identifyStakePoolInstruction(
@@ -106,6 +254,210 @@ export function identifyStakePoolInstruction(
   if (containsBytes(data, getU8Encoder().encode(14), 0)) {
     return StakePoolInstruction.CheckAuthorization;
   }
+  if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+    return StakePoolInstruction.WithdrawFees;
+  }
+  if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+    return StakePoolInstruction.UpdatePoolsBatch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+    return StakePoolInstruction.StakeSol;
+  }
+  if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+    return StakePoolInstruction.UnstakeSol;
+  }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return StakePoolInstruction.SetAutoRelock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+    return StakePoolInstruction.Relock;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return StakePoolInstruction.SyncPool;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return StakePoolInstruction.CloseProgramAuthority;
+  }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return StakePoolInstruction.SetRewardFunders;
+  }
+  if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+    return StakePoolInstruction.DefundRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+    return StakePoolInstruction.SetMintTvlCap;
+  }
+  if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+    return StakePoolInstruction.ClosePool;
+  }
+  if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+    return StakePoolInstruction.SetStakeDelegate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(28), 0)) {
+    return StakePoolInstruction.EnableReceiptToken;
+  }
+  if (containsBytes(data, getU8Encoder().encode(29), 0)) {
+    return StakePoolInstruction.ExpireRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(30), 0)) {
+    return StakePoolInstruction.ClaimExpiredRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(31), 0)) {
+    return StakePoolInstruction.RequestUnstake;
+  }
+  if (containsBytes(data, getU8Encoder().encode(32), 0)) {
+    return StakePoolInstruction.WithdrawUnstaked;
+  }
+  if (containsBytes(data, getU8Encoder().encode(33), 0)) {
+    return StakePoolInstruction.SetGlobalPause;
+  }
+  if (containsBytes(data, getU8Encoder().encode(34), 0)) {
+    return StakePoolInstruction.MigratePool;
+  }
+  if (containsBytes(data, getU8Encoder().encode(35), 0)) {
+    return StakePoolInstruction.StakeBatch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(36), 0)) {
+    return StakePoolInstruction.ClaimRewardsBatch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(37), 0)) {
+    return StakePoolInstruction.GetPendingRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(38), 0)) {
+    return StakePoolInstruction.RegisterPool;
+  }
+  if (containsBytes(data, getU8Encoder().encode(39), 0)) {
+    return StakePoolInstruction.SetCreatorPoolLimit;
+  }
+  if (containsBytes(data, getU8Encoder().encode(40), 0)) {
+    return StakePoolInstruction.ManageRoles;
+  }
+  if (containsBytes(data, getU8Encoder().encode(41), 0)) {
+    return StakePoolInstruction.SetApprovalThreshold;
+  }
+  if (containsBytes(data, getU8Encoder().encode(42), 0)) {
+    return StakePoolInstruction.ProposePoolUpdate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(43), 0)) {
+    return StakePoolInstruction.ApprovePoolUpdate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(44), 0)) {
+    return StakePoolInstruction.ExecutePoolUpdate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(45), 0)) {
+    return StakePoolInstruction.FinalizeLockupChange;
+  }
+  if (containsBytes(data, getU8Encoder().encode(46), 0)) {
+    return StakePoolInstruction.SetStakeLabel;
+  }
+  if (containsBytes(data, getU8Encoder().encode(47), 0)) {
+    return StakePoolInstruction.NominateStakeOwner;
+  }
+  if (containsBytes(data, getU8Encoder().encode(48), 0)) {
+    return StakePoolInstruction.AcceptStakeOwnership;
+  }
+  if (containsBytes(data, getU8Encoder().encode(49), 0)) {
+    return StakePoolInstruction.SweepDust;
+  }
+  if (containsBytes(data, getU8Encoder().encode(50), 0)) {
+    return StakePoolInstruction.GetPoolStats;
+  }
+  if (containsBytes(data, getU8Encoder().encode(51), 0)) {
+    return StakePoolInstruction.ExtendLockup;
+  }
+  if (containsBytes(data, getU8Encoder().encode(52), 0)) {
+    return StakePoolInstruction.ManagePoolAllowlist;
+  }
+  if (containsBytes(data, getU8Encoder().encode(53), 0)) {
+    return StakePoolInstruction.VestingStake;
+  }
+  if (containsBytes(data, getU8Encoder().encode(54), 0)) {
+    return StakePoolInstruction.ProposeSlash;
+  }
+  if (containsBytes(data, getU8Encoder().encode(55), 0)) {
+    return StakePoolInstruction.ExecuteSlash;
+  }
+  if (containsBytes(data, getU8Encoder().encode(56), 0)) {
+    return StakePoolInstruction.CancelSlash;
+  }
+  if (containsBytes(data, getU8Encoder().encode(57), 0)) {
+    return StakePoolInstruction.SetRecoveryAuthority;
+  }
+  if (containsBytes(data, getU8Encoder().encode(58), 0)) {
+    return StakePoolInstruction.InitiateRecovery;
+  }
+  if (containsBytes(data, getU8Encoder().encode(59), 0)) {
+    return StakePoolInstruction.CompleteRecovery;
+  }
+  if (containsBytes(data, getU8Encoder().encode(60), 0)) {
+    return StakePoolInstruction.InitializeProgramConfig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(61), 0)) {
+    return StakePoolInstruction.UpdateProgramConfig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(62), 0)) {
+    return StakePoolInstruction.SetMaxCreators;
+  }
+  if (containsBytes(data, getU8Encoder().encode(63), 0)) {
+    return StakePoolInstruction.MigrateProgramAuthority;
+  }
+  if (containsBytes(data, getU8Encoder().encode(64), 0)) {
+    return StakePoolInstruction.ClonePool;
+  }
+  if (containsBytes(data, getU8Encoder().encode(65), 0)) {
+    return StakePoolInstruction.CreateRewardStream;
+  }
+  if (containsBytes(data, getU8Encoder().encode(66), 0)) {
+    return StakePoolInstruction.CrankRewardStream;
+  }
+  if (containsBytes(data, getU8Encoder().encode(67), 0)) {
+    return StakePoolInstruction.CloseRewardStream;
+  }
+  if (containsBytes(data, getU8Encoder().encode(68), 0)) {
+    return StakePoolInstruction.FundKeeperBounty;
+  }
+  if (containsBytes(data, getU8Encoder().encode(69), 0)) {
+    return StakePoolInstruction.ResizePool;
+  }
+  if (containsBytes(data, getU8Encoder().encode(70), 0)) {
+    return StakePoolInstruction.GetStakePosition;
+  }
+  if (containsBytes(data, getU8Encoder().encode(71), 0)) {
+    return StakePoolInstruction.AuditPool;
+  }
+  if (containsBytes(data, getU8Encoder().encode(72), 0)) {
+    return StakePoolInstruction.WindDownStake;
+  }
+  if (containsBytes(data, getU8Encoder().encode(73), 0)) {
+    return StakePoolInstruction.ManageHookAllowlist;
+  }
+  if (containsBytes(data, getU8Encoder().encode(74), 0)) {
+    return StakePoolInstruction.SetPoolHook;
+  }
+  if (containsBytes(data, getU8Encoder().encode(75), 0)) {
+    return StakePoolInstruction.SyncPoints;
+  }
+  if (containsBytes(data, getU8Encoder().encode(76), 0)) {
+    return StakePoolInstruction.GetUserPoints;
+  }
+  if (containsBytes(data, getU8Encoder().encode(77), 0)) {
+    return StakePoolInstruction.ManageDenyList;
+  }
+  if (containsBytes(data, getU8Encoder().encode(78), 0)) {
+    return StakePoolInstruction.SplitStake;
+  }
+  if (containsBytes(data, getU8Encoder().encode(79), 0)) {
+    return StakePoolInstruction.MergeStakes;
+  }
+  if (containsBytes(data, getU8Encoder().encode(80), 0)) {
+    return StakePoolInstruction.FundCohortRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(81), 0)) {
+    return StakePoolInstruction.DefundCohortRewards;
+  }
+  if (containsBytes(data, getU8Encoder().encode(82), 0)) {
+    return StakePoolInstruction.UnstakeAll;
+  }
   throw new Error(
     'The provided instruction could not be identified as a stakePool instruction.'
   );
@@ -158,4 +510,208 @@ export type ParsedStakePoolInstruction<
     } & ParsedGetAuthorizedCreatorsInstruction<TProgram>)
   | ({
       instructionType: StakePoolInstruction.CheckAuthorization;
-    } & ParsedCheckAuthorizationInstruction<TProgram>);
+    } & ParsedCheckAuthorizationInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.WithdrawFees;
+    } & ParsedWithdrawFeesInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.UpdatePoolsBatch;
+    } & ParsedUpdatePoolsBatchInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.StakeSol;
+    } & ParsedStakeSolInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.UnstakeSol;
+    } & ParsedUnstakeSolInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.SetAutoRelock;
+    } & ParsedSetAutoRelockInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.Relock;
+    } & ParsedRelockInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.SyncPool;
+    } & ParsedSyncPoolInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.CloseProgramAuthority;
+    } & ParsedCloseProgramAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.SetRewardFunders;
+    } & ParsedSetRewardFundersInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.DefundRewards;
+    } & ParsedDefundRewardsInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.SetMintTvlCap;
+    } & ParsedSetMintTvlCapInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.ClosePool;
+    } & ParsedClosePoolInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.SetStakeDelegate;
+    } & ParsedSetStakeDelegateInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.EnableReceiptToken;
+    } & ParsedEnableReceiptTokenInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.ExpireRewards;
+    } & ParsedExpireRewardsInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.ClaimExpiredRewards;
+    } & ParsedClaimExpiredRewardsInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.RequestUnstake;
+    } & ParsedRequestUnstakeInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.WithdrawUnstaked;
+    } & ParsedWithdrawUnstakedInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.SetGlobalPause;
+    } & ParsedSetGlobalPauseInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.MigratePool;
+    } & ParsedMigratePoolInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.StakeBatch;
+    } & ParsedStakeBatchInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.ClaimRewardsBatch;
+    } & ParsedClaimRewardsBatchInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.GetPendingRewards;
+    } & ParsedGetPendingRewardsInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.RegisterPool;
+    } & ParsedRegisterPoolInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.SetCreatorPoolLimit;
+    } & ParsedSetCreatorPoolLimitInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.ManageRoles;
+    } & ParsedManageRolesInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.SetApprovalThreshold;
+    } & ParsedSetApprovalThresholdInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.ProposePoolUpdate;
+    } & ParsedProposePoolUpdateInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.ApprovePoolUpdate;
+    } & ParsedApprovePoolUpdateInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.ExecutePoolUpdate;
+    } & ParsedExecutePoolUpdateInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.FinalizeLockupChange;
+    } & ParsedFinalizeLockupChangeInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.SetStakeLabel;
+    } & ParsedSetStakeLabelInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.NominateStakeOwner;
+    } & ParsedNominateStakeOwnerInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.AcceptStakeOwnership;
+    } & ParsedAcceptStakeOwnershipInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.SweepDust;
+    } & ParsedSweepDustInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.GetPoolStats;
+    } & ParsedGetPoolStatsInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.ExtendLockup;
+    } & ParsedExtendLockupInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.ManagePoolAllowlist;
+    } & ParsedManagePoolAllowlistInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.VestingStake;
+    } & ParsedVestingStakeInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.ProposeSlash;
+    } & ParsedProposeSlashInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.ExecuteSlash;
+    } & ParsedExecuteSlashInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.CancelSlash;
+    } & ParsedCancelSlashInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.SetRecoveryAuthority;
+    } & ParsedSetRecoveryAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.InitiateRecovery;
+    } & ParsedInitiateRecoveryInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.CompleteRecovery;
+    } & ParsedCompleteRecoveryInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.InitializeProgramConfig;
+    } & ParsedInitializeProgramConfigInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.UpdateProgramConfig;
+    } & ParsedUpdateProgramConfigInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.SetMaxCreators;
+    } & ParsedSetMaxCreatorsInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.MigrateProgramAuthority;
+    } & ParsedMigrateProgramAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.ClonePool;
+    } & ParsedClonePoolInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.CreateRewardStream;
+    } & ParsedCreateRewardStreamInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.CrankRewardStream;
+    } & ParsedCrankRewardStreamInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.CloseRewardStream;
+    } & ParsedCloseRewardStreamInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.FundKeeperBounty;
+    } & ParsedFundKeeperBountyInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.ResizePool;
+    } & ParsedResizePoolInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.GetStakePosition;
+    } & ParsedGetStakePositionInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.AuditPool;
+    } & ParsedAuditPoolInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.WindDownStake;
+    } & ParsedWindDownStakeInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.ManageHookAllowlist;
+    } & ParsedManageHookAllowlistInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.SetPoolHook;
+    } & ParsedSetPoolHookInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.SyncPoints;
+    } & ParsedSyncPointsInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.GetUserPoints;
+    } & ParsedGetUserPointsInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.ManageDenyList;
+    } & ParsedManageDenyListInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.SplitStake;
+    } & ParsedSplitStakeInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.MergeStakes;
+    } & ParsedMergeStakesInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.FundCohortRewards;
+    } & ParsedFundCohortRewardsInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.DefundCohortRewards;
+    } & ParsedDefundCohortRewardsInstruction<TProgram>)
+  | ({
+      instructionType: StakePoolInstruction.UnstakeAll;
+    } & ParsedUnstakeAllInstruction<TProgram>);
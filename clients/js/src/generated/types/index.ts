@@ -6,4 +6,18 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './authorizedCreator';
+export * from './claimWindow';
+export * from './clusterProfile';
+export * from './contextValue';
 export * from './key';
+export * from './outflowLimit';
+export * from './outflowWindow';
+export * from './poolParameter';
+export * from './poolRegistryEntry';
+export * from './rewardRateMode';
+export * from './rewardTier';
+export * from './roleHolder';
+export * from './sensitivePoolUpdate';
+export * from './stakeClaim';
+export * from './windDownRewards';
@@ -17,9 +17,18 @@ import {
 
 export enum Key {
   Uninitialized,
-  StakePool,
-  StakeAccount,
-  ProgramAuthority,
+  StakePoolV1,
+  StakeAccountV1,
+  ProgramAuthorityV1,
+  MintRegistry,
+  StakePoolV2,
+  StakeAccountV2,
+  PoolRegistry,
+  PendingAction,
+  PoolAllowlist,
+  SlashProposal,
+  ProgramConfig,
+  ProgramAuthorityV2,
 }
 
 export type KeyArgs = Key;
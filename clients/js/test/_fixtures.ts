@@ -0,0 +1,222 @@
+import {
+  ClusterProfile,
+  Key,
+  RewardRateMode,
+  WindDownRewards,
+  type InitializePoolInstructionDataArgs,
+  type ProgramAuthorityArgs,
+  type StakeAccountArgs,
+  type StakeInstructionDataArgs,
+  type StakePoolArgs,
+  type UnstakeInstructionDataArgs,
+  type UpdatePoolInstructionDataArgs,
+} from '../src';
+import { address, none } from '@solana/kit';
+
+// Account and instruction data values with every optional field unset.
+// Tests override the fields they exercise so the fixtures track the
+// generated layouts.
+
+export const stakePoolFixture = (
+  overrides: Partial<StakePoolArgs> = {}
+): StakePoolArgs => ({
+  key: Key.StakePoolV2,
+  stakeMint: address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
+  rewardMint: address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
+  poolId: 0n,
+  stakeVault: address('11111111111111111111111111111111'),
+  rewardVault: address('11111111111111111111111111111111'),
+  totalStaked: 0n,
+  totalRewardsOwed: 0n,
+  rewardRate: 100_000_000n,
+  minStakeAmount: 1_000_000n,
+  lockupPeriod: 86400n,
+  isPaused: false,
+  enforceLockup: false,
+  bump: 255,
+  poolEndDate: none(),
+  pendingRewardRate: none(),
+  rewardRateChangeTimestamp: none(),
+  lastRateChange: none(),
+  referralBps: 0,
+  earlyUnstakeBps: 0,
+  stakeVaultBump: none(),
+  rewardVaultBump: none(),
+  feeVault: address('11111111111111111111111111111111'),
+  depositFeeBps: 0,
+  withdrawFeeBps: 0,
+  feeVaultBump: none(),
+  totalRewardsFunded: 0n,
+  rewardFunders: [none(), none(), none(), none()],
+  receiptMint: none(),
+  expiredRewards: 0n,
+  unstakeCooldown: 0n,
+  isRegistered: false,
+  rewardRateChangeDelay: 604800,
+  pendingLockupPeriod: none(),
+  pendingEnforceLockup: none(),
+  lockupChangeTimestamp: none(),
+  minRewardRateBound: none(),
+  maxRewardRateBound: none(),
+  activeStakeCount: none(),
+  rewardTiers: [none(), none(), none(), none()],
+  bonusEndTs: none(),
+  bonusMultiplierBps: 0,
+  lockupExtensionRate: 0n,
+  allowlistRequired: false,
+  allowlistMerkleRoot: none(),
+  claimWindow: none(),
+  outflowLimit: none(),
+  outflowWindow: { start: 0n, totalStaked: 0n, unstaked: 0n, claimed: 0n },
+  poolManager: none(),
+  keeperBounty: 0n,
+  lastKeeperBountyAt: 0n,
+  maxTotalRewards: none(),
+  totalRewardsCommitted: 0n,
+  rewardRateMode: RewardRateMode.PerLockup,
+  rewardDecimalsShift: 0,
+  enforceMinRemainingStake: false,
+  windDownRewards: WindDownRewards.Expire,
+  hookProgram: none(),
+  pointsRate: 0n,
+  denyListRequired: false,
+  allowThirdPartyStake: false,
+  eventNonce: 0n,
+  cohortDuration: 0n,
+  openRewardCohorts: 0,
+  reserved: new Uint8Array(2),
+  ...overrides,
+});
+
+export const stakeAccountFixture = (
+  overrides: Partial<StakeAccountArgs> = {}
+): StakeAccountArgs => ({
+  key: Key.StakeAccountV2,
+  pool: address('11111111111111111111111111111111'),
+  owner: address('11111111111111111111111111111111'),
+  index: 0n,
+  amountStaked: 0n,
+  stakeTimestamp: 1234567890n,
+  claimedRewards: 0n,
+  bump: 255,
+  referrer: none(),
+  earlyUnstaked: 0n,
+  autoRelock: false,
+  carriedRewards: 0n,
+  delegate: none(),
+  pendingWithdrawAmount: 0n,
+  requestTimestamp: none(),
+  lockedRewardRate: 0n,
+  label: new Uint8Array(32),
+  pendingOwner: none(),
+  lockupExtension: 0n,
+  vestingAmount: 0n,
+  vestingStart: 0n,
+  vestingCliff: 0n,
+  vestingDuration: 0n,
+  pendingSlashBps: 0,
+  pointsSyncedAt: 0n,
+  rewardCohort: 0n,
+  ...overrides,
+});
+
+export const programAuthorityFixture = (
+  overrides: Partial<ProgramAuthorityArgs> = {}
+): ProgramAuthorityArgs => ({
+  key: Key.ProgramAuthorityV2,
+  authority: address('11111111111111111111111111111111'),
+  pendingAuthority: none(),
+  bump: 255,
+  clusterProfile: ClusterProfile.Mainnet,
+  globalPause: false,
+  withdrawalsOnly: false,
+  roleHolders: Array.from({ length: 8 }, () => none()),
+  approvalThreshold: 0,
+  recoveryAuthority: none(),
+  recoveryDelay: 0n,
+  recoveryInitiatedAt: none(),
+  maxCreators: 10,
+  authorizedCreators: [],
+  ...overrides,
+});
+
+export const initializePoolDataFixture = (
+  overrides: Partial<InitializePoolInstructionDataArgs> = {}
+): InitializePoolInstructionDataArgs => ({
+  poolId: 0n,
+  rewardRate: 100_000_000n,
+  minStakeAmount: 1_000_000n,
+  lockupPeriod: 86400n,
+  enforceLockup: false,
+  poolEndDate: null,
+  referralBps: 0,
+  earlyUnstakeBps: 0,
+  depositFeeBps: 0,
+  withdrawFeeBps: 0,
+  rewardRateChangeDelay: null,
+  minRewardRateBound: null,
+  maxRewardRateBound: null,
+  rewardTiers: [],
+  bonusEndTs: null,
+  bonusMultiplierBps: 0,
+  rewardRateMode: RewardRateMode.PerLockup,
+  ...overrides,
+});
+
+export const stakeDataFixture = (
+  overrides: Partial<StakeInstructionDataArgs> = {}
+): StakeInstructionDataArgs => ({
+  amount: 0n,
+  index: null,
+  expectedRewardRate: null,
+  expectedLockupPeriod: null,
+  referrer: null,
+  autoRelock: false,
+  label: null,
+  allowlistProof: null,
+  memo: null,
+  deadline: null,
+  ...overrides,
+});
+
+export const unstakeDataFixture = (
+  overrides: Partial<UnstakeInstructionDataArgs> = {}
+): UnstakeInstructionDataArgs => ({
+  amount: 0n,
+  expectedRewardRate: null,
+  memo: null,
+  deadline: null,
+  ...overrides,
+});
+
+export const updatePoolDataFixture = (
+  overrides: Partial<UpdatePoolInstructionDataArgs> = {}
+): UpdatePoolInstructionDataArgs => ({
+  rewardRate: null,
+  minStakeAmount: null,
+  lockupPeriod: null,
+  isPaused: null,
+  enforceLockup: null,
+  poolEndDate: null,
+  referralBps: null,
+  earlyUnstakeBps: null,
+  depositFeeBps: null,
+  withdrawFeeBps: null,
+  unstakeCooldown: null,
+  bonusEndTs: null,
+  bonusMultiplierBps: null,
+  lockupExtensionRate: null,
+  allowlistMerkleRoot: null,
+  claimWindow: null,
+  outflowLimit: null,
+  poolManager: null,
+  keeperBounty: null,
+  maxTotalRewards: null,
+  enforceMinRemainingStake: null,
+  windDownRewards: null,
+  pointsRate: null,
+  denyListRequired: null,
+  allowThirdPartyStake: null,
+  cohortDuration: null,
+  ...overrides,
+});
@@ -1,79 +1,31 @@
 import test from 'ava';
 import { getStakePoolCodec, getStakeAccountCodec } from '../src';
-import { Key } from '../src';
-import { address } from '@solana/kit';
+import { stakeAccountFixture, stakePoolFixture } from './_fixtures';
 
 test('StakePool codec encodes and decodes correctly', (t) => {
   const codec = getStakePoolCodec();
 
-  const stakePool = {
-    key: Key.StakePool,
-    authority: address('11111111111111111111111111111111'),
-    stakeMint: address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
-    rewardMint: address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
-    poolId: 0n,
-    stakeVault: address('11111111111111111111111111111111'),
-    rewardVault: address('11111111111111111111111111111111'),
+  const stakePool = stakePoolFixture({
     totalStaked: 1_000_000_000n,
-    totalRewardsOwed: 0n,
     rewardRate: 100_000_000n, // 10% (100_000_000 / 1e9)
     minStakeAmount: 1_000_000n,
     lockupPeriod: 86400n, // 1 day in seconds
-    isPaused: false,
-    enforceLockup: false,
-    bump: 255,
-    poolEndDate: null,
-    pendingRewardRate: null,
-    rewardRateChangeTimestamp: null,
-    lastRateChange: null,
-    reserved: new Uint8Array(7),
-  };
+  });
 
   const encoded = codec.encode(stakePool);
   const decoded = codec.decode(encoded);
 
   // Compare all fields
-  t.is(decoded.key, stakePool.key);
-  // Note: authority field removed - now managed globally via ProgramAuthority
-  t.is(decoded.stakeMint, stakePool.stakeMint);
-  t.is(decoded.rewardMint, stakePool.rewardMint);
-  t.is(decoded.stakeVault, stakePool.stakeVault);
-  t.is(decoded.rewardVault, stakePool.rewardVault);
-  t.is(decoded.totalStaked, stakePool.totalStaked);
-  t.is(decoded.rewardRate, stakePool.rewardRate);
-  t.is(decoded.minStakeAmount, stakePool.minStakeAmount);
-  t.is(decoded.lockupPeriod, stakePool.lockupPeriod);
-  t.is(decoded.isPaused, stakePool.isPaused);
-  t.is(decoded.bump, stakePool.bump);
-  // Note: pendingAuthority field removed - now managed globally via ProgramAuthority
+  t.deepEqual(decoded, stakePool);
 });
 
 test('StakePool has correct reward_rate field', (t) => {
   const codec = getStakePoolCodec();
 
-  const stakePool = {
-    key: Key.StakePool,
-    authority: address('11111111111111111111111111111111'),
-    stakeMint: address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
-    rewardMint: address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
-    poolId: 0n,
-    stakeVault: address('11111111111111111111111111111111'),
-    rewardVault: address('11111111111111111111111111111111'),
-    totalStaked: 0n,
-    totalRewardsOwed: 0n,
+  const stakePool = stakePoolFixture({
     rewardRate: 250_000_000n, // 25% reward rate
-    minStakeAmount: 1_000_000n,
     lockupPeriod: 604800n, // 7 days
-    isPaused: false,
-    enforceLockup: false,
-    bump: 255,
-    pendingAuthority: null,
-    poolEndDate: null,
-    pendingRewardRate: null,
-    rewardRateChangeTimestamp: null,
-    lastRateChange: null,
-    reserved: new Uint8Array(7),
-  };
+  });
 
   const encoded = codec.encode(stakePool);
   const decoded = codec.decode(encoded);
@@ -84,16 +36,7 @@ test('StakePool has correct reward_rate field', (t) => {
 test('StakeAccount codec encodes and decodes correctly', (t) => {
   const codec = getStakeAccountCodec();
 
-  const stakeAccount = {
-    key: Key.StakeAccount,
-    pool: address('11111111111111111111111111111111'),
-    owner: address('11111111111111111111111111111111'),
-    index: 0n,
-    amountStaked: 10_000_000n,
-    stakeTimestamp: 1234567890n,
-    claimedRewards: 0n,
-    bump: 255,
-  };
+  const stakeAccount = stakeAccountFixture({ amountStaked: 10_000_000n });
 
   const encoded = codec.encode(stakeAccount);
   const decoded = codec.decode(encoded);
@@ -104,16 +47,10 @@ test('StakeAccount codec encodes and decodes correctly', (t) => {
 test('StakeAccount has claimedRewards field', (t) => {
   const codec = getStakeAccountCodec();
 
-  const stakeAccount = {
-    key: Key.StakeAccount,
-    pool: address('11111111111111111111111111111111'),
-    owner: address('11111111111111111111111111111111'),
-    index: 0n,
+  const stakeAccount = stakeAccountFixture({
     amountStaked: 100_000_000n,
-    stakeTimestamp: 1234567890n,
     claimedRewards: 10_000_000n, // Already claimed 10 tokens
-    bump: 255,
-  };
+  });
 
   const encoded = codec.encode(stakeAccount);
   const decoded = codec.decode(encoded);
@@ -124,16 +61,7 @@ test('StakeAccount has claimedRewards field', (t) => {
 test('StakeAccount does not have deprecated reward fields', (t) => {
   const codec = getStakeAccountCodec();
 
-  const stakeAccount = {
-    key: Key.StakeAccount,
-    pool: address('11111111111111111111111111111111'),
-    owner: address('11111111111111111111111111111111'),
-    index: 0n,
-    amountStaked: 100_000_000n,
-    stakeTimestamp: 1234567890n,
-    claimedRewards: 0n,
-    bump: 255,
-  };
+  const stakeAccount = stakeAccountFixture({ amountStaked: 100_000_000n });
 
   const encoded = codec.encode(stakeAccount);
   const decoded = codec.decode(encoded);
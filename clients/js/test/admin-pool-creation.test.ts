@@ -3,14 +3,21 @@ import {
   getProgramAuthorityCodec,
   getInitializeProgramAuthorityInstructionDataCodec,
   getManageAuthorizedCreatorsInstructionDataCodec,
-  Key,
+  ClusterProfile,
   STAKE_POOL_ERROR__UNAUTHORIZED_POOL_CREATOR,
   STAKE_POOL_ERROR__CREATOR_ALREADY_AUTHORIZED,
   STAKE_POOL_ERROR__MAX_AUTHORIZED_CREATORS_REACHED,
   STAKE_POOL_ERROR__CANNOT_REMOVE_MAIN_AUTHORITY,
   STAKE_POOL_ERROR__CREATOR_NOT_FOUND,
 } from '../src';
-import { address, type Address } from '@solana/kit';
+import { address, none, some, type Address } from '@solana/kit';
+import { programAuthorityFixture } from './_fixtures';
+
+const creator = (creatorAddress: Address, poolLimit: number | null = null) => ({
+  creator: creatorAddress,
+  poolsCreated: 0,
+  poolLimit: poolLimit === null ? none() : some(poolLimit),
+});
 
 // ============================================================================
 // ProgramAuthority Account Tests
@@ -19,65 +26,44 @@ import { address, type Address } from '@solana/kit';
 test('ProgramAuthority codec encodes and decodes correctly', (t) => {
   const codec = getProgramAuthorityCodec();
 
-  const programAuthority = {
-    key: Key.ProgramAuthority,
-    authority: address('11111111111111111111111111111111'),
+  const programAuthority = programAuthorityFixture({
     authorizedCreators: [
-      address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
-      address('TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'),
-      null,
-      null,
-      null,
-      null,
-      null,
-      null,
-      null,
-      null,
-    ] as Array<Address | null>,
-    creatorCount: 2,
-    pendingAuthority: null,
-    bump: 255,
-  };
+      creator(address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA')),
+      creator(address('TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'), 3),
+    ],
+  });
 
   const encoded = codec.encode(programAuthority);
   const decoded = codec.decode(encoded);
 
-  t.is(decoded.key, Key.ProgramAuthority);
+  t.is(decoded.key, programAuthority.key);
   t.is(decoded.authority, programAuthority.authority);
-  t.is(decoded.creatorCount, 2);
+  t.is(decoded.maxCreators, 10);
   t.is(decoded.bump, 255);
 
-  // Check authorized creators - decode returns Option type
+  // Check authorized creators - the pool limit decodes as an Option type
+  t.is(decoded.authorizedCreators.length, 2);
   t.deepEqual(decoded.authorizedCreators[0], {
-    __option: 'Some',
-    value: programAuthority.authorizedCreators[0],
+    creator: address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
+    poolsCreated: 0,
+    poolLimit: { __option: 'None' },
   });
   t.deepEqual(decoded.authorizedCreators[1], {
-    __option: 'Some',
-    value: programAuthority.authorizedCreators[1],
+    creator: address('TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'),
+    poolsCreated: 0,
+    poolLimit: { __option: 'Some', value: 3 },
   });
-  t.deepEqual(decoded.authorizedCreators[2], { __option: 'None' });
 });
 
 test('ProgramAuthority codec handles empty creators list', (t) => {
   const codec = getProgramAuthorityCodec();
 
-  const programAuthority = {
-    key: Key.ProgramAuthority,
-    authority: address('11111111111111111111111111111111'),
-    authorizedCreators: Array(10).fill(null),
-    creatorCount: 0,
-    pendingAuthority: null,
-    bump: 255,
-  };
+  const programAuthority = programAuthorityFixture();
 
   const encoded = codec.encode(programAuthority);
   const decoded = codec.decode(encoded);
 
-  t.is(decoded.creatorCount, 0);
-  decoded.authorizedCreators.forEach((creator) => {
-    t.deepEqual(creator, { __option: 'None' });
-  });
+  t.is(decoded.authorizedCreators.length, 0);
 });
 
 test('ProgramAuthority codec handles max creators', (t) => {
@@ -85,75 +71,69 @@ test('ProgramAuthority codec handles max creators', (t) => {
 
   // Use simple valid addresses (all same address for testing purposes)
   const creators = Array.from({ length: 10 }, () =>
-    address('11111111111111111111111111111111')
+    creator(address('11111111111111111111111111111111'))
   );
 
-  const programAuthority = {
-    key: Key.ProgramAuthority,
-    authority: address('11111111111111111111111111111111'),
+  const programAuthority = programAuthorityFixture({
     authorizedCreators: creators,
-    creatorCount: 10,
-    pendingAuthority: null,
-    bump: 255,
-  };
+  });
 
   const encoded = codec.encode(programAuthority);
   const decoded = codec.decode(encoded);
 
-  t.is(decoded.creatorCount, 10);
-  decoded.authorizedCreators.forEach((creator, idx) => {
-    t.deepEqual(creator, { __option: 'Some', value: creators[idx] });
+  t.is(decoded.authorizedCreators.length, 10);
+  decoded.authorizedCreators.forEach((decodedCreator, idx) => {
+    t.is(decodedCreator.creator, creators[idx].creator);
   });
 });
 
 test('ProgramAuthority has correct size', (t) => {
   const codec = getProgramAuthorityCodec();
 
-  // Test empty creators list (all null)
-  const emptyAuthority = {
-    key: Key.ProgramAuthority,
-    authority: address('11111111111111111111111111111111'),
-    authorizedCreators: Array(10).fill(null),
-    creatorCount: 0,
-    pendingAuthority: null,
-    bump: 255,
-  };
+  // Test empty creators list
+  const emptyAuthority = programAuthorityFixture();
 
   const encodedEmpty = codec.encode(emptyAuthority);
-  // Size: 1 (key) + 32 (authority) + 10 (10 * 1 byte for Option::None) + 1 (count) + 1 (pending=None) + 1 (bump) = 46 bytes
+  // Size: 1 (key) + 32 (authority) + 1 (pending=None) + 1 (bump) + 1 (cluster) + 2 (pause flags)
+  //   + 8 (8 * 1 byte for role Option::None) + 1 (threshold) + 1 (recovery=None) + 8 (delay)
+  //   + 1 (initiated=None) + 2 (max creators) + 4 (vec length) = 63 bytes
   // Note: Borsh encodes Option::None as 1 byte (0x00), Option::Some as 1 byte (0x01) + value
-  // IMPORTANT: On-chain, the ProgramAuthority account is always allocated with the maximum size (398 bytes, as per ProgramAuthority::LEN),
-  // regardless of how many creators are actually populated. This test only verifies the serialized size for the empty case.
-  t.is(encodedEmpty.length, 46);
-
-  // Test full creators list (all Some)
-  const fullAuthority = {
-    key: Key.ProgramAuthority,
-    authority: address('11111111111111111111111111111111'),
-    authorizedCreators: Array(10).fill(
-      address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA')
+  // IMPORTANT: On-chain the account also starts with an 8-byte discriminator and is allocated
+  // with room for `maxCreators` creators (ProgramAuthority::space). This test only verifies the
+  // serialized size of the codec fields.
+  t.is(encodedEmpty.length, 63);
+
+  // Test full creators list without pool limits
+  const fullAuthority = programAuthorityFixture({
+    authorizedCreators: Array.from({ length: 10 }, () =>
+      creator(address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'))
     ),
-    creatorCount: 10,
-    pendingAuthority: null,
-    bump: 255,
-  };
+  });
 
   const encodedFull = codec.encode(fullAuthority);
-  // Size with pending=None: 1 (key) + 32 (authority) + 330 (10 * 33 for Option::Some + Pubkey) + 1 (count) + 1 (pending=None) + 1 (bump) = 366 bytes
-  // Note: Each Option::Some(Pubkey) is 1 byte discriminator + 32 bytes pubkey = 33 bytes
-  t.is(encodedFull.length, 366);
-
-  // Test maximal case: pending_authority = Some(Pubkey)
-  // This is what ProgramAuthority::LEN (398 bytes) represents on-chain
-  const maxAuthority = {
-    ...fullAuthority,
-    pendingAuthority: address('22222222222222222222222222222222222222222222'),
-  };
+  // Each creator without a limit is 32 (pubkey) + 2 (pools created) + 1 (limit=None) = 35 bytes
+  t.is(encodedFull.length, 63 + 10 * 35);
+
+  // Test maximal case: every optional field set and every creator limited
+  // This is ProgramAuthority::LEN without its 8-byte discriminator
+  const maxAuthority = programAuthorityFixture({
+    pendingAuthority: some(
+      address('22222222222222222222222222222222222222222222')
+    ),
+    roleHolders: Array.from({ length: 8 }, () =>
+      some({ holder: address('11111111111111111111111111111111'), roles: 1 })
+    ),
+    recoveryAuthority: some(address('11111111111111111111111111111111')),
+    recoveryInitiatedAt: some(1700000000n),
+    authorizedCreators: Array.from({ length: 10 }, () =>
+      creator(address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'), 5)
+    ),
+  });
 
   const encodedMax = codec.encode(maxAuthority);
-  // Size with pending=Some: 1 + 32 + 330 + 1 + 33 (Some + Pubkey) + 1 = 398 bytes
-  // This matches ProgramAuthority::LEN constant used for on-chain rent calculations
-  t.is(encodedMax.length, 398);
+  // Size: 1 + 32 + 33 (Some + Pubkey) + 1 + 1 + 2 + 272 (8 * 34) + 1 + 33 + 8 + 9 + 2 + 4 = 399 bytes
+  // plus 10 * 37 bytes for limited creators, matching ProgramAuthority::LEN - 8 = 769 bytes
+  t.is(encodedMax.length, 769);
 });
 
 // ============================================================================
@@ -163,15 +143,16 @@ test('ProgramAuthority has correct size', (t) => {
 test('InitializeProgramAuthority instruction data codec', (t) => {
   const codec = getInitializeProgramAuthorityInstructionDataCodec();
 
-  const data = {};
+  const data = { clusterProfile: ClusterProfile.Devnet };
 
   const encoded = codec.encode(data);
   const decoded = codec.decode(encoded);
 
   // Should have discriminator
-  t.is(encoded.length, 1); // Just the discriminator byte
+  t.is(encoded.length, 2); // Discriminator and cluster profile bytes
   t.is(decoded.discriminator, 8); // InitializeProgramAuthority discriminator (updated after refactor)
-  t.is(Object.keys(decoded).length, 1); // Only the discriminator field
+  t.is(decoded.clusterProfile, ClusterProfile.Devnet);
+  t.is(Object.keys(decoded).length, 2); // Only the discriminator and cluster profile fields
 });
 
 // ============================================================================
@@ -291,44 +272,25 @@ test('ProgramAuthority round-trip with various states', (t) => {
   const testCases = [
     {
       name: 'No creators',
-      data: {
-        key: Key.ProgramAuthority,
-        authority: address('11111111111111111111111111111111'),
-        authorizedCreators: Array(10).fill(null),
-        creatorCount: 0,
-        pendingAuthority: null,
-        bump: 255,
-      },
+      data: programAuthorityFixture(),
     },
     {
       name: 'One creator',
-      data: {
-        key: Key.ProgramAuthority,
-        authority: address('11111111111111111111111111111111'),
+      data: programAuthorityFixture({
         authorizedCreators: [
-          address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
-          ...Array(9).fill(null),
+          creator(address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA')),
         ],
-        creatorCount: 1,
-        pendingAuthority: null,
-        bump: 255,
-      },
+      }),
     },
     {
-      name: 'Multiple creators with gaps',
-      data: {
-        key: Key.ProgramAuthority,
-        authority: address('11111111111111111111111111111111'),
+      name: 'Multiple creators with limits',
+      data: programAuthorityFixture({
+        clusterProfile: ClusterProfile.Devnet,
         authorizedCreators: [
-          address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
-          null,
-          address('TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb'),
-          ...Array(7).fill(null),
+          creator(address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'), 2),
+          creator(address('TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb')),
         ],
-        creatorCount: 2,
-        pendingAuthority: null,
-        bump: 255,
-      },
+      }),
     },
   ];
 
@@ -339,28 +301,18 @@ test('ProgramAuthority round-trip with various states', (t) => {
     t.is(decoded.key, data.key, `${name}: key should match`);
     t.is(decoded.authority, data.authority, `${name}: authority should match`);
     t.is(
-      decoded.creatorCount,
-      data.creatorCount,
-      `${name}: count should match`
+      decoded.clusterProfile,
+      data.clusterProfile,
+      `${name}: cluster profile should match`
     );
     t.is(decoded.bump, data.bump, `${name}: bump should match`);
 
-    // Check creators individually since decoded will have Option type
-    data.authorizedCreators.forEach((creator, idx) => {
-      if (creator === null) {
-        t.deepEqual(
-          decoded.authorizedCreators[idx],
-          { __option: 'None' },
-          `${name}: creator ${idx} should be None`
-        );
-      } else {
-        t.deepEqual(
-          decoded.authorizedCreators[idx],
-          { __option: 'Some', value: creator },
-          `${name}: creator ${idx} should match`
-        );
-      }
-    });
+    // Creators round-trip with their pool limits as Option types
+    t.deepEqual(
+      decoded.authorizedCreators,
+      data.authorizedCreators,
+      `${name}: creators should match`
+    );
   });
 });
 
@@ -370,10 +322,10 @@ test('ProgramAuthority round-trip with various states', (t) => {
 
 test('InitializeProgramAuthority instruction has minimal size', (t) => {
   const codec = getInitializeProgramAuthorityInstructionDataCodec();
-  const encoded = codec.encode({});
+  const encoded = codec.encode({ clusterProfile: ClusterProfile.Mainnet });
 
-  // Should only have discriminator (1 byte)
-  t.is(encoded.length, 1);
+  // Should only have discriminator and cluster profile (1 byte each)
+  t.is(encoded.length, 2);
 });
 
 test('ManageAuthorizedCreators instruction size scales with operations', (t) => {
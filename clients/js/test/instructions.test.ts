@@ -7,18 +7,20 @@ import {
   getFundRewardsInstructionDataCodec,
 } from '../src';
 import { none, some } from '@solana/kit';
+import {
+  initializePoolDataFixture,
+  stakeDataFixture,
+  unstakeDataFixture,
+  updatePoolDataFixture,
+} from './_fixtures';
 
 test('initializePool instruction data codec with reward_rate', (t) => {
   const codec = getInitializePoolInstructionDataCodec();
 
-  const data = {
-    poolId: 0n,
+  const data = initializePoolDataFixture({
     rewardRate: 100_000_000n, // 10%
-    minStakeAmount: 1_000_000n,
     lockupPeriod: 86400n, // 1 day
-    enforceLockup: false,
-    poolEndDate: null,
-  };
+  });
 
   const encoded = codec.encode(data);
   const decoded = codec.decode(encoded);
@@ -42,14 +44,9 @@ test('initializePool with different reward_rate values', (t) => {
   ];
 
   rewardRates.forEach(({ rate, description }) => {
-    const data = {
-      poolId: 0n,
+    const data = initializePoolDataFixture({
       rewardRate: rate,
-      minStakeAmount: 1_000_000n,
-      lockupPeriod: 86400n,
-      enforceLockup: false,
-      poolEndDate: null,
-    };
+    });
 
     const encoded = codec.encode(data);
     const decoded = codec.decode(encoded);
@@ -65,18 +62,16 @@ test('initializePool with different reward_rate values', (t) => {
 test('stake instruction data codec', (t) => {
   const codec = getStakeInstructionDataCodec();
 
-  const data = {
+  const data = stakeDataFixture({
     amount: 100_000_000n,
     index: 0n,
-    expectedRewardRate: null,
-    expectedLockupPeriod: null,
-  };
+  });
 
   const encoded = codec.encode(data);
   const decoded = codec.decode(encoded);
 
   t.is(decoded.amount, 100_000_000n);
-  t.is(decoded.index, 0n);
+  t.deepEqual(decoded.index, some(0n));
 });
 
 test('stake with various amounts', (t) => {
@@ -90,28 +85,25 @@ test('stake with various amounts', (t) => {
   ];
 
   amounts.forEach((amount, index) => {
-    const data = {
+    const data = stakeDataFixture({
       amount,
       index: BigInt(index),
-      expectedRewardRate: null,
-      expectedLockupPeriod: null,
-    };
+    });
 
     const encoded = codec.encode(data);
     const decoded = codec.decode(encoded);
 
     t.is(decoded.amount, amount);
-    t.is(decoded.index, BigInt(index));
+    t.deepEqual(decoded.index, some(BigInt(index)));
   });
 });
 
 test('unstake instruction data codec', (t) => {
   const codec = getUnstakeInstructionDataCodec();
 
-  const data = {
+  const data = unstakeDataFixture({
     amount: 50_000_000n,
-    expectedRewardRate: null,
-  };
+  });
 
   const encoded = codec.encode(data);
   const decoded = codec.decode(encoded);
@@ -123,10 +115,9 @@ test('unstake full vs partial amounts', (t) => {
   const codec = getUnstakeInstructionDataCodec();
 
   // Full unstake
-  const fullUnstakeData = {
+  const fullUnstakeData = unstakeDataFixture({
     amount: 100_000_000n,
-    expectedRewardRate: null,
-  };
+  });
 
   const fullEncoded = codec.encode(fullUnstakeData);
   const fullDecoded = codec.decode(fullEncoded);
@@ -134,10 +125,9 @@ test('unstake full vs partial amounts', (t) => {
   t.is(fullDecoded.amount, 100_000_000n);
 
   // Partial unstake
-  const partialUnstakeData = {
+  const partialUnstakeData = unstakeDataFixture({
     amount: 50_000_000n,
-    expectedRewardRate: null,
-  };
+  });
 
   const partialEncoded = codec.encode(partialUnstakeData);
   const partialDecoded = codec.decode(partialEncoded);
@@ -161,14 +151,12 @@ test('fundRewards instruction data codec', (t) => {
 test('updatePool instruction data codec with reward_rate', (t) => {
   const codec = getUpdatePoolInstructionDataCodec();
 
-  const data = {
+  const data = updatePoolDataFixture({
     rewardRate: some(150_000_000n), // 15%
     minStakeAmount: some(2_000_000n),
     lockupPeriod: some(172800n), // 2 days
     isPaused: some(false),
-    enforceLockup: null,
-    poolEndDate: null,
-  };
+  });
 
   const encoded = codec.encode(data);
   const decoded = codec.decode(encoded);
@@ -183,14 +171,9 @@ test('updatePool can toggle pause state', (t) => {
   const codec = getUpdatePoolInstructionDataCodec();
 
   // Paused
-  const pausedData = {
-    rewardRate: null,
-    minStakeAmount: null,
-    lockupPeriod: null,
+  const pausedData = updatePoolDataFixture({
     isPaused: some(true),
-    enforceLockup: null,
-    poolEndDate: null,
-  };
+  });
 
   const pausedEncoded = codec.encode(pausedData);
   const pausedDecoded = codec.decode(pausedEncoded);
@@ -198,14 +181,9 @@ test('updatePool can toggle pause state', (t) => {
   t.deepEqual(pausedDecoded.isPaused, some(true));
 
   // Unpaused
-  const unpausedData = {
-    rewardRate: null,
-    minStakeAmount: null,
-    lockupPeriod: null,
+  const unpausedData = updatePoolDataFixture({
     isPaused: some(false),
-    enforceLockup: null,
-    poolEndDate: null,
-  };
+  });
 
   const unpausedEncoded = codec.encode(unpausedData);
   const unpausedDecoded = codec.decode(unpausedEncoded);
@@ -223,14 +201,10 @@ test('updatePool with different reward_rate values', (t) => {
   ];
 
   testCases.forEach(({ rewardRate, lockupPeriod }) => {
-    const data = {
+    const data = updatePoolDataFixture({
       rewardRate: some(rewardRate),
-      minStakeAmount: null,
       lockupPeriod: some(lockupPeriod),
-      isPaused: null,
-      enforceLockup: null,
-      poolEndDate: null,
-    };
+    });
 
     const encoded = codec.encode(data);
     const decoded = codec.decode(encoded);
@@ -244,14 +218,9 @@ test('updatePool with partial updates', (t) => {
   const codec = getUpdatePoolInstructionDataCodec();
 
   // Only update reward_rate
-  const onlyRewardRate = {
+  const onlyRewardRate = updatePoolDataFixture({
     rewardRate: some(300_000_000n),
-    minStakeAmount: null,
-    lockupPeriod: null,
-    isPaused: null,
-    enforceLockup: null,
-    poolEndDate: null,
-  };
+  });
 
   const encoded1 = codec.encode(onlyRewardRate);
   const decoded1 = codec.decode(encoded1);
@@ -262,14 +231,9 @@ test('updatePool with partial updates', (t) => {
   t.deepEqual(decoded1.isPaused, none());
 
   // Only update lockup_period
-  const onlyLockup = {
-    rewardRate: null,
-    minStakeAmount: null,
+  const onlyLockup = updatePoolDataFixture({
     lockupPeriod: some(259200n),
-    isPaused: null,
-    enforceLockup: null,
-    poolEndDate: null,
-  };
+  });
 
   const encoded2 = codec.encode(onlyLockup);
   const decoded2 = codec.decode(encoded2);
@@ -282,14 +246,10 @@ test('initializePool with enforceLockup flag', (t) => {
   const codec = getInitializePoolInstructionDataCodec();
 
   // Test with enforceLockup = true
-  const strictData = {
-    poolId: 0n,
+  const strictData = initializePoolDataFixture({
     rewardRate: 100_000_000n,
-    minStakeAmount: 1_000_000n,
-    lockupPeriod: 86400n,
     enforceLockup: true,
-    poolEndDate: null,
-  };
+  });
 
   const strictEncoded = codec.encode(strictData);
   const strictDecoded = codec.decode(strictEncoded);
@@ -297,14 +257,9 @@ test('initializePool with enforceLockup flag', (t) => {
   t.is(strictDecoded.enforceLockup, true, 'enforceLockup should be true');
 
   // Test with enforceLockup = false
-  const flexibleData = {
-    poolId: 0n,
+  const flexibleData = initializePoolDataFixture({
     rewardRate: 100_000_000n,
-    minStakeAmount: 1_000_000n,
-    lockupPeriod: 86400n,
-    enforceLockup: false,
-    poolEndDate: null,
-  };
+  });
 
   const flexibleEncoded = codec.encode(flexibleData);
   const flexibleDecoded = codec.decode(flexibleEncoded);
@@ -316,14 +271,9 @@ test('updatePool can toggle enforceLockup', (t) => {
   const codec = getUpdatePoolInstructionDataCodec();
 
   // Enable enforceLockup
-  const enableData = {
-    rewardRate: null,
-    minStakeAmount: null,
-    lockupPeriod: null,
-    isPaused: null,
+  const enableData = updatePoolDataFixture({
     enforceLockup: some(true),
-    poolEndDate: null,
-  };
+  });
 
   const enableEncoded = codec.encode(enableData);
   const enableDecoded = codec.decode(enableEncoded);
@@ -335,14 +285,9 @@ test('updatePool can toggle enforceLockup', (t) => {
   );
 
   // Disable enforceLockup
-  const disableData = {
-    rewardRate: null,
-    minStakeAmount: null,
-    lockupPeriod: null,
-    isPaused: null,
+  const disableData = updatePoolDataFixture({
     enforceLockup: some(false),
-    poolEndDate: null,
-  };
+  });
 
   const disableEncoded = codec.encode(disableData);
   const disableDecoded = codec.decode(disableEncoded);
@@ -354,14 +299,9 @@ test('updatePool can toggle enforceLockup', (t) => {
   );
 
   // Leave enforceLockup unchanged
-  const noChangeData = {
+  const noChangeData = updatePoolDataFixture({
     rewardRate: some(200_000_000n),
-    minStakeAmount: null,
-    lockupPeriod: null,
-    isPaused: null,
-    enforceLockup: null,
-    poolEndDate: null,
-  };
+  });
 
   const noChangeEncoded = codec.encode(noChangeData);
   const noChangeDecoded = codec.decode(noChangeEncoded);
@@ -377,14 +317,12 @@ test('instruction data codecs preserve exact values', (t) => {
   // Test that bigint values are preserved exactly through encode/decode
 
   const initPoolCodec = getInitializePoolInstructionDataCodec();
-  const initPoolData = {
-    poolId: 0n,
+  const initPoolData = initializePoolDataFixture({
     rewardRate: 123_456_789n,
     minStakeAmount: 987_654_321n,
     lockupPeriod: 555_555n,
     enforceLockup: true,
-    poolEndDate: null,
-  };
+  });
 
   const initPoolEncoded = initPoolCodec.encode(initPoolData);
   const initPoolDecoded = initPoolCodec.decode(initPoolEncoded);
@@ -396,16 +334,14 @@ test('instruction data codecs preserve exact values', (t) => {
   t.is(initPoolDecoded.enforceLockup, true);
 
   const stakeCodec = getStakeInstructionDataCodec();
-  const stakeData = {
+  const stakeData = stakeDataFixture({
     amount: 9_876_543_210n,
     index: 42n,
-    expectedRewardRate: null,
-    expectedLockupPeriod: null,
-  };
+  });
 
   const stakeEncoded = stakeCodec.encode(stakeData);
   const stakeDecoded = stakeCodec.decode(stakeEncoded);
 
   t.is(stakeDecoded.amount, 9_876_543_210n);
-  t.is(stakeDecoded.index, 42n);
+  t.deepEqual(stakeDecoded.index, some(42n));
 });
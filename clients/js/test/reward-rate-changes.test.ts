@@ -9,7 +9,8 @@ import {
   FINALIZE_REWARD_RATE_CHANGE_DISCRIMINATOR,
   getStakePoolCodec,
 } from '../src';
-import { address, some } from '@solana/kit';
+import { some } from '@solana/kit';
+import { stakePoolFixture } from './_fixtures';
 
 test('RewardRateChangeDelayNotElapsed error code is correct', (t) => {
   t.is(STAKE_POOL_ERROR__REWARD_RATE_CHANGE_DELAY_NOT_ELAPSED, 0x1d); // 29
@@ -73,29 +74,10 @@ test('StakePool has pendingRewardRate field', (t) => {
   const codec = getStakePoolCodec();
 
   // Verify codec accepts pendingRewardRate
-  const stakePool = {
-    key: 1,
-    authority: address('11111111111111111111111111111111'),
-    stakeMint: address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
-    rewardMint: address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
-    poolId: 0n,
-    stakeVault: address('11111111111111111111111111111111'),
-    rewardVault: address('11111111111111111111111111111111'),
-    totalStaked: 0n,
-    totalRewardsOwed: 0n,
-    rewardRate: 100_000_000n,
-    minStakeAmount: 1_000_000n,
-    lockupPeriod: 86400n,
-    isPaused: false,
-    enforceLockup: false,
-    bump: 255,
-    pendingAuthority: null,
-    poolEndDate: null,
+  const stakePool = stakePoolFixture({
     pendingRewardRate: some(150_000_000n),
     rewardRateChangeTimestamp: some(1700000000n),
-    lastRateChange: null,
-    reserved: new Uint8Array(7),
-  };
+  });
 
   // Should not throw
   const encoded = codec.encode(stakePool);
@@ -108,29 +90,10 @@ test('StakePool has rewardRateChangeTimestamp field', (t) => {
   const currentTime = 1700000000n;
   const proposalTime = currentTime;
 
-  const stakePool = {
-    key: 1,
-    authority: address('11111111111111111111111111111111'),
-    stakeMint: address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
-    rewardMint: address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
-    poolId: 0n,
-    stakeVault: address('11111111111111111111111111111111'),
-    rewardVault: address('11111111111111111111111111111111'),
-    totalStaked: 0n,
-    totalRewardsOwed: 0n,
-    rewardRate: 100_000_000n,
-    minStakeAmount: 1_000_000n,
-    lockupPeriod: 86400n,
-    isPaused: false,
-    enforceLockup: false,
-    bump: 255,
-    pendingAuthority: null,
-    poolEndDate: null,
+  const stakePool = stakePoolFixture({
     pendingRewardRate: some(150_000_000n), // Must be Some when timestamp is Some
     rewardRateChangeTimestamp: some(proposalTime),
-    lastRateChange: null,
-    reserved: new Uint8Array(7),
-  };
+  });
 
   const encoded = codec.encode(stakePool);
   const decoded = codec.decode(encoded);
@@ -160,29 +123,12 @@ test('StakePool pending fields work together', (t) => {
   const currentTime = 1700000000n;
   const proposalTime = currentTime; // When the change was proposed
 
-  const stakePool = {
-    key: 1,
-    authority: address('11111111111111111111111111111111'),
-    stakeMint: address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
-    rewardMint: address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
-    poolId: 0n,
-    stakeVault: address('11111111111111111111111111111111'),
-    rewardVault: address('11111111111111111111111111111111'),
+  const stakePool = stakePoolFixture({
     totalStaked: 1_000_000_000n,
-    totalRewardsOwed: 0n,
     rewardRate: 100_000_000n, // Current: 10%
-    minStakeAmount: 1_000_000n,
-    lockupPeriod: 86400n,
-    isPaused: false,
-    enforceLockup: false,
-    bump: 255,
-    pendingAuthority: null,
-    poolEndDate: null,
     pendingRewardRate: some(200_000_000n), // Pending: 20%
     rewardRateChangeTimestamp: some(proposalTime),
-    lastRateChange: null,
-    reserved: new Uint8Array(7),
-  };
+  });
 
   const encoded = codec.encode(stakePool);
   const decoded = codec.decode(encoded);
@@ -202,29 +148,7 @@ test('StakePool pending fields work together', (t) => {
 test('StakePool with no pending changes', (t) => {
   const codec = getStakePoolCodec();
 
-  const stakePool = {
-    key: 1,
-    authority: address('11111111111111111111111111111111'),
-    stakeMint: address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
-    rewardMint: address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
-    poolId: 0n,
-    stakeVault: address('11111111111111111111111111111111'),
-    rewardVault: address('11111111111111111111111111111111'),
-    totalStaked: 0n,
-    totalRewardsOwed: 0n,
-    rewardRate: 100_000_000n,
-    minStakeAmount: 1_000_000n,
-    lockupPeriod: 86400n,
-    isPaused: false,
-    enforceLockup: false,
-    bump: 255,
-    pendingAuthority: null,
-    poolEndDate: null,
-    pendingRewardRate: null,
-    rewardRateChangeTimestamp: null,
-    lastRateChange: null,
-    reserved: new Uint8Array(7),
-  };
+  const stakePool = stakePoolFixture();
 
   const encoded = codec.encode(stakePool);
   const decoded = codec.decode(encoded);
@@ -256,29 +180,11 @@ test('Cancellation mechanism - proposing current rate', (t) => {
   const currentRate = 100_000_000n;
 
   // Pool with pending change
-  const poolBeforeCancellation = {
-    key: 1,
-    authority: address('11111111111111111111111111111111'),
-    stakeMint: address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
-    rewardMint: address('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
-    poolId: 0n,
-    stakeVault: address('11111111111111111111111111111111'),
-    rewardVault: address('11111111111111111111111111111111'),
-    totalStaked: 0n,
-    totalRewardsOwed: 0n,
+  const poolBeforeCancellation = stakePoolFixture({
     rewardRate: currentRate,
-    minStakeAmount: 1_000_000n,
-    lockupPeriod: 86400n,
-    isPaused: false,
-    enforceLockup: false,
-    bump: 255,
-    pendingAuthority: null,
-    poolEndDate: null,
     pendingRewardRate: some(200_000_000n),
     rewardRateChangeTimestamp: some(1700000000n),
-    lastRateChange: null,
-    reserved: new Uint8Array(7),
-  };
+  });
 
   // After proposing current rate (simulated state after on-chain cancellation)
   const poolAfterCancellation = {
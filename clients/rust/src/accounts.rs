@@ -0,0 +1,235 @@
+//! `getProgramAccounts` filters for the program's accounts
+//!
+//! Pools and stake accounts start with a fixed-size prefix (8-byte
+//! discriminator, key, pubkeys, ids), so the fields indexers search by sit at
+//! stable byte offsets ahead of any Borsh `Option`. The offsets below are those
+//! of the current layout; the filter builders match current accounts only, so
+//! accounts in the V1 layout or written before the discriminator are selected
+//! once MigratePool has rewritten them. They do not filter on the data size,
+//! so accounts grown by a later layout change stay selected.
+//!
+//! `AccountFilter` mirrors the RPC filter shapes (`dataSize` and `memcmp`) so
+//! it maps one-to-one onto whichever RPC client is in use.
+//!
+//! `resolve` goes the other way: from a pool, owner and stake index to every
+//! account a position's instructions take, in the order the program expects.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use your_wallet_stake_pool::{
+    spl_associated_token_account_client::address::get_associated_token_address_with_program_id,
+    spl_token_2022,
+    state::{Key, DISCRIMINATOR_LEN},
+};
+
+use crate::{
+    instruction::{self, StakeArgs},
+    PoolKeys,
+};
+
+/// Offset of the 8-byte account discriminator in pools and stake accounts
+pub const DISCRIMINATOR_OFFSET: usize = 0;
+
+/// Offset of the account `Key` in pools and stake accounts, right after the discriminator
+pub const KEY_OFFSET: usize = DISCRIMINATOR_OFFSET + DISCRIMINATOR_LEN;
+
+/// Field offsets of `StakeAccount`
+pub mod stake_account {
+    /// The pool the stake belongs to
+    pub const POOL: usize = super::KEY_OFFSET + 1;
+    /// The wallet owning the stake
+    pub const OWNER: usize = POOL + 32;
+    /// The stake's index (u64, little-endian)
+    pub const INDEX: usize = OWNER + 32;
+}
+
+/// Field offsets of `StakePool`
+pub mod stake_pool {
+    /// The token mint being staked
+    pub const STAKE_MINT: usize = super::KEY_OFFSET + 1;
+    /// The token mint rewards are paid in
+    pub const REWARD_MINT: usize = STAKE_MINT + 32;
+    /// The pool id (u64, little-endian)
+    pub const POOL_ID: usize = REWARD_MINT + 32;
+}
+
+/// A `getProgramAccounts` filter
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountFilter {
+    /// Account data length equals the given size
+    DataSize(u64),
+    /// Account data at `offset` starts with `bytes`
+    Memcmp { offset: usize, bytes: Vec<u8> },
+}
+
+impl AccountFilter {
+    fn memcmp(offset: usize, bytes: &[u8]) -> Self {
+        Self::Memcmp {
+            offset,
+            bytes: bytes.to_vec(),
+        }
+    }
+
+    /// Whether account `data` passes the filter, as the RPC node would evaluate it
+    pub fn matches(&self, data: &[u8]) -> bool {
+        match self {
+            Self::DataSize(size) => data.len() as u64 == *size,
+            Self::Memcmp { offset, bytes } => data
+                .get(*offset..)
+                .is_some_and(|data| data.starts_with(bytes)),
+        }
+    }
+
+    /// The memcmp bytes base64-encoded, for the RPC `"encoding": "base64"` form
+    pub fn encoded_bytes(&self) -> Option<String> {
+        match self {
+            Self::DataSize(_) => None,
+            Self::Memcmp { bytes, .. } => Some(STANDARD.encode(bytes)),
+        }
+    }
+}
+
+/// The discriminator and key an account of type `key` starts with
+fn account_prefix(key: Key) -> Vec<u8> {
+    let mut prefix = key.discriminator().unwrap_or_default().to_vec();
+    prefix.push(key as u8);
+    prefix
+}
+
+fn stake_account_filters() -> Vec<AccountFilter> {
+    vec![AccountFilter::memcmp(
+        DISCRIMINATOR_OFFSET,
+        &account_prefix(Key::StakeAccountV2),
+    )]
+}
+
+fn stake_pool_filters() -> Vec<AccountFilter> {
+    vec![AccountFilter::memcmp(
+        DISCRIMINATOR_OFFSET,
+        &account_prefix(Key::StakePoolV2),
+    )]
+}
+
+/// Filters selecting every stake account owned by `owner`, across all pools
+pub fn stake_accounts_by_owner(owner: &Pubkey) -> Vec<AccountFilter> {
+    let mut filters = stake_account_filters();
+    filters.push(AccountFilter::memcmp(stake_account::OWNER, owner.as_ref()));
+    filters
+}
+
+/// Filters selecting every stake account of `pool`
+pub fn stake_accounts_by_pool(pool: &Pubkey) -> Vec<AccountFilter> {
+    let mut filters = stake_account_filters();
+    filters.push(AccountFilter::memcmp(stake_account::POOL, pool.as_ref()));
+    filters
+}
+
+/// Filters selecting the stake accounts `owner` holds in `pool`
+///
+/// Pool and owner are adjacent, so this is a single 64-byte memcmp.
+pub fn stake_accounts_by_pool_and_owner(pool: &Pubkey, owner: &Pubkey) -> Vec<AccountFilter> {
+    let mut filters = stake_account_filters();
+    filters.push(AccountFilter::memcmp(
+        stake_account::POOL,
+        &[pool.as_ref(), owner.as_ref()].concat(),
+    ));
+    filters
+}
+
+/// Filters selecting every pool
+pub fn pools() -> Vec<AccountFilter> {
+    stake_pool_filters()
+}
+
+/// Filters selecting every pool staking `stake_mint`
+pub fn pools_by_stake_mint(stake_mint: &Pubkey) -> Vec<AccountFilter> {
+    let mut filters = stake_pool_filters();
+    filters.push(AccountFilter::memcmp(
+        stake_pool::STAKE_MINT,
+        stake_mint.as_ref(),
+    ));
+    filters
+}
+
+/// Accounts of `owner`'s stake `index`, resolved by `resolve`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedAccounts {
+    pub keys: PoolKeys,
+    pub owner: Pubkey,
+    pub index: u64,
+    pub stake_account: Pubkey,
+    /// Owner's associated token account for the stake mint
+    pub stake_token_account: Pubkey,
+    /// Owner's associated token account for the reward mint
+    pub reward_token_account: Pubkey,
+    /// Owner's associated receipt token account, if the pool tokenizes positions
+    pub receipt_account: Option<Pubkey>,
+}
+
+/// Resolve the accounts of `owner`'s stake `index` in the pool at `keys`
+///
+/// Vaults come from `keys` (see `PoolKeys::from_state`), PDAs are derived and
+/// the owner's token accounts are their associated token accounts.
+pub fn resolve(keys: &PoolKeys, owner: &Pubkey, index: u64) -> ResolvedAccounts {
+    let receipt_account = keys.receipt_mint.map(|receipt_mint| {
+        get_associated_token_address_with_program_id(owner, &receipt_mint, &spl_token_2022::ID)
+    });
+
+    ResolvedAccounts {
+        keys: keys.clone(),
+        owner: *owner,
+        index,
+        stake_account: instruction::stake_account_address(&keys.pool, owner, index),
+        stake_token_account: get_associated_token_address_with_program_id(
+            owner,
+            &keys.stake_mint,
+            &keys.token_program,
+        ),
+        reward_token_account: instruction::associated_reward_account(keys, owner),
+        receipt_account,
+    }
+}
+
+impl ResolvedAccounts {
+    /// Ordered account metas of `Stake` opening the position, paid for by `payer`
+    pub fn stake_metas(&self, payer: &Pubkey) -> Vec<AccountMeta> {
+        let args = StakeArgs {
+            receipt_account: self.receipt_account,
+            ..StakeArgs::new(0, self.index)
+        };
+        instruction::stake(
+            &self.keys,
+            &self.owner,
+            &self.stake_token_account,
+            payer,
+            args,
+        )
+        .accounts
+    }
+
+    /// Ordered account metas of `Unstake` from the position
+    pub fn unstake_metas(&self) -> Vec<AccountMeta> {
+        instruction::unstake(
+            &self.keys,
+            &self.owner,
+            self.index,
+            &self.stake_token_account,
+            0,
+            None,
+            self.receipt_account.as_ref(),
+        )
+        .accounts
+    }
+
+    /// Ordered account metas of `ClaimRewards` of the position
+    pub fn claim_rewards_metas(&self) -> Vec<AccountMeta> {
+        instruction::claim_rewards(
+            &self.keys,
+            &self.owner,
+            self.index,
+            &self.reward_token_account,
+            None,
+        )
+        .accounts
+    }
+}
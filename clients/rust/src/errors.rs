@@ -0,0 +1,31 @@
+//! Decoding of the program's error codes
+//!
+//! The program fails with `ProgramError::Custom(code)`, which RPC nodes report
+//! as `InstructionError::Custom(code)` and log as `custom program error: 0x..`.
+//! Codes and `StakePoolError::to_str` names are stable across releases, so
+//! integrators can key messages on either.
+//!
+//! Custom codes are only unique per program: the same number raised by the
+//! token program means something else. `from_logs` therefore only decodes
+//! the failure line of this program.
+
+use crate::ID;
+
+pub use your_wallet_stake_pool::error::StakePoolError;
+
+/// The stake pool error behind a custom error code of this program
+pub fn from_code(code: u32) -> Option<StakePoolError> {
+    StakePoolError::from_code(code)
+}
+
+/// The stake pool error a failed transaction ended with, read from its logs
+///
+/// Looks for the runtime's `Program <id> failed: custom program error: 0x..`
+/// line of this program, so failures raised by other programs are ignored.
+pub fn from_logs<S: AsRef<str>>(logs: &[S]) -> Option<StakePoolError> {
+    let failed = format!("Program {} failed: custom program error: 0x", ID);
+    logs.iter().rev().find_map(|line| {
+        let code = line.as_ref().strip_prefix(&failed)?;
+        from_code(u32::from_str_radix(code.trim(), 16).ok()?)
+    })
+}
@@ -0,0 +1,75 @@
+//! Decoding of the events the program logs
+//!
+//! The program publishes each event as a `Program data:` log line holding a
+//! base64, Borsh-encoded `StakePoolEvent`. `parse_logs` extracts the events of
+//! this program from a transaction's log messages, skipping data logged by
+//! other programs it invokes or is invoked by.
+//!
+//! Failing instructions log an `ErrorContext` event before returning their
+//! error; `error_context` picks it out of a failed transaction's logs.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_program::pubkey::Pubkey;
+
+use crate::ID;
+
+pub use your_wallet_stake_pool::events::*;
+
+const PROGRAM_DATA: &str = "Program data: ";
+
+/// Decode the base64 payload of a `Program data:` log line
+pub fn decode_event(base64: &str) -> Option<StakePoolEvent> {
+    let data = STANDARD.decode(base64.trim()).ok()?;
+    StakePoolEvent::decode(&data)
+}
+
+/// Events logged by this program, in log order
+///
+/// Tracks the invocation stack from the runtime's `invoke` / `success` /
+/// `failed` lines so only data logged while this program is executing is
+/// decoded. Lines that are not valid events are skipped.
+pub fn parse_logs<S: AsRef<str>>(logs: &[S]) -> Vec<StakePoolEvent> {
+    let mut stack: Vec<Pubkey> = Vec::new();
+    let mut events = Vec::new();
+
+    for line in logs {
+        let line = line.as_ref();
+        if let Some(data) = line.strip_prefix(PROGRAM_DATA) {
+            if stack.last() == Some(&ID) {
+                events.extend(decode_event(data));
+            }
+            continue;
+        }
+
+        let Some(rest) = line.strip_prefix("Program ") else {
+            continue;
+        };
+        let mut words = rest.split_whitespace();
+        let Some(program) = words.next().and_then(|id| id.parse::<Pubkey>().ok()) else {
+            continue;
+        };
+        match words.next() {
+            Some("invoke") => stack.push(program),
+            Some("success") | Some("failed:") => {
+                stack.pop();
+            }
+            _ => {}
+        }
+    }
+
+    events
+}
+
+/// The context logged by the failed check of a failed transaction, if any
+///
+/// The runtime keeps the logs of failed transactions (and simulations), so
+/// this is the last `ErrorContext` event this program logged.
+pub fn error_context<S: AsRef<str>>(logs: &[S]) -> Option<ErrorContextEvent> {
+    parse_logs(logs)
+        .into_iter()
+        .rev()
+        .find_map(|event| match event {
+            StakePoolEvent::ErrorContext(context) => Some(context),
+            _ => None,
+        })
+}
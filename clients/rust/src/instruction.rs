@@ -0,0 +1,2453 @@
+//! Instruction builders mirroring every `StakePoolInstruction` variant
+//!
+//! Account order matches the `#[account(...)]` attributes on the program's
+//! instruction enum. Optional accounts that are not provided are passed as the
+//! program ID, which is how the program's account parser reads `None`.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    sysvar,
+};
+use your_wallet_stake_pool::{
+    instruction::StakePoolInstruction,
+    spl_associated_token_account_client::address::get_associated_token_address_with_program_id,
+    state::{
+        ClaimWindow, ClusterProfile, DenyList, HookAllowlist, MintRegistry, OutflowLimit,
+        PendingAction, PoolAllowlist, PoolRegistry, ProgramAuthority, ProgramConfig, RewardCohort,
+        RewardRateMode, RewardStream, RewardTier, SensitivePoolUpdate, SlashProposal, StakeAccount,
+        StakePool, StakePosition, UserPoints, WindDownRewards,
+    },
+    ID,
+};
+
+use crate::PoolKeys;
+
+const SYSTEM_PROGRAM_ID: Pubkey = solana_program::system_program::ID;
+
+fn build(accounts: Vec<AccountMeta>, data: StakePoolInstruction) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts,
+        data: data
+            .try_to_vec()
+            .expect("StakePoolInstruction serialization is infallible"),
+    }
+}
+
+/// Address of the stake account for `owner`'s deposit `index` in `pool`
+pub fn stake_account_address(pool: &Pubkey, owner: &Pubkey, index: u64) -> Pubkey {
+    StakeAccount::find_pda(pool, owner, index).0
+}
+
+/// Address of `owner`'s stake position directory in `pool`
+pub fn stake_position_address(pool: &Pubkey, owner: &Pubkey) -> Pubkey {
+    StakePosition::find_pda(pool, owner).0
+}
+
+/// Stake position account of stake instructions (the program's ID when not tracked)
+fn stake_position_meta(pool: &Pubkey, owner: &Pubkey, track_position: bool) -> AccountMeta {
+    let stake_position = if track_position {
+        stake_position_address(pool, owner)
+    } else {
+        ID
+    };
+    AccountMeta::new(stake_position, false)
+}
+
+/// Address of the global program authority account
+pub fn program_authority_address() -> Pubkey {
+    ProgramAuthority::find_pda().0
+}
+
+/// Address of the program config account
+pub fn program_config_address() -> Pubkey {
+    ProgramConfig::find_pda().0
+}
+
+/// Address of the registry shared by every pool of `stake_mint`
+pub fn mint_registry_address(stake_mint: &Pubkey) -> Pubkey {
+    MintRegistry::find_pda(stake_mint).0
+}
+
+/// Address of page `page` of the pool registry
+pub fn pool_registry_address(page: u32) -> Pubkey {
+    PoolRegistry::find_pda(page).0
+}
+
+/// Address of page `page` of `pool`'s staker allowlist
+pub fn pool_allowlist_address(pool: &Pubkey, page: u32) -> Pubkey {
+    PoolAllowlist::find_pda(pool, page).0
+}
+
+/// Allowlist account of stake instructions (the program's ID when not given)
+fn allowlist_meta(pool: &Pubkey, allowlist_page: Option<u32>) -> AccountMeta {
+    let allowlist = match allowlist_page {
+        Some(page) => pool_allowlist_address(pool, page),
+        None => ID,
+    };
+    AccountMeta::new_readonly(allowlist, false)
+}
+
+/// Address of the pending pool update `action_id` of `pool`
+pub fn pending_action_address(pool: &Pubkey, action_id: u64) -> Pubkey {
+    PendingAction::find_pda(pool, action_id).0
+}
+
+/// Address of the slash proposal of `stake_account`
+pub fn slash_proposal_address(stake_account: &Pubkey) -> Pubkey {
+    SlashProposal::find_pda(stake_account).0
+}
+
+/// Address of `funder`'s reward stream into `pool`
+pub fn reward_stream_address(pool: &Pubkey, funder: &Pubkey) -> Pubkey {
+    RewardStream::find_pda(pool, funder).0
+}
+
+/// Registry page accounts for listing a pool in `page`
+///
+/// `page` is the latest registry page, or the next one when the latest is full.
+fn pool_registry_metas(page: u32) -> [AccountMeta; 2] {
+    let previous = match page.checked_sub(1) {
+        Some(previous) => pool_registry_address(previous),
+        None => ID,
+    };
+    [
+        AccountMeta::new(pool_registry_address(page), false),
+        AccountMeta::new_readonly(previous, false),
+    ]
+}
+
+/// Receipt accounts of stake and unstake instructions
+///
+/// Passed only when the pool tokenizes positions (`keys.receipt_mint`) and the
+/// owner's receipt token account is known; otherwise all three are omitted.
+fn receipt_metas(keys: &PoolKeys, receipt_account: Option<&Pubkey>) -> [AccountMeta; 3] {
+    match (keys.receipt_mint, receipt_account) {
+        (Some(receipt_mint), Some(receipt_account)) => [
+            AccountMeta::new(receipt_mint, false),
+            AccountMeta::new(*receipt_account, false),
+            AccountMeta::new_readonly(your_wallet_stake_pool::spl_token_2022::ID, false),
+        ],
+        _ => [
+            AccountMeta::new_readonly(ID, false),
+            AccountMeta::new_readonly(ID, false),
+            AccountMeta::new_readonly(ID, false),
+        ],
+    }
+}
+
+/// Keeper account of permissionless cranks (the program's ID when not given)
+fn keeper_meta(keeper: Option<&Pubkey>) -> AccountMeta {
+    match keeper {
+        Some(keeper) => AccountMeta::new(*keeper, false),
+        None => AccountMeta::new_readonly(ID, false),
+    }
+}
+
+/// Hook program of the pool (the program's ID when the pool has none)
+fn hook_program_meta(keys: &PoolKeys) -> AccountMeta {
+    AccountMeta::new_readonly(keys.hook_program.unwrap_or(ID), false)
+}
+
+/// Address of the allowlist of programs pools may call as hooks
+pub fn hook_allowlist_address() -> Pubkey {
+    HookAllowlist::find_pda().0
+}
+
+/// Address of the global deny list
+pub fn deny_list_address() -> Pubkey {
+    DenyList::find_pda().0
+}
+
+/// Global deny list (the program's ID when the pool does not check it)
+fn deny_list_meta(keys: &PoolKeys) -> AccountMeta {
+    let deny_list = match keys.deny_list_required {
+        true => deny_list_address(),
+        false => ID,
+    };
+    AccountMeta::new_readonly(deny_list, false)
+}
+
+/// Address of reward cohort `cohort` of `pool`
+pub fn reward_cohort_address(pool: &Pubkey, cohort: u64) -> Pubkey {
+    RewardCohort::find_pda(pool, cohort).0
+}
+
+/// Address of the reward vault of reward cohort `cohort` of `pool`
+pub fn cohort_vault_address(pool: &Pubkey, cohort: u64) -> Pubkey {
+    RewardCohort::find_vault_pda(&reward_cohort_address(pool, cohort)).0
+}
+
+/// Address of `owner`'s points in `pool`
+pub fn user_points_address(pool: &Pubkey, owner: &Pubkey) -> Pubkey {
+    UserPoints::find_pda(pool, owner).0
+}
+
+/// The SPL Memo program when a memo is attached, absent otherwise
+fn memo_program_meta(memo: Option<&String>) -> AccountMeta {
+    let memo_program = match memo {
+        Some(_) => your_wallet_stake_pool::spl_memo::ID,
+        None => ID,
+    };
+    AccountMeta::new_readonly(memo_program, false)
+}
+
+/// Parameters of a new pool (see `StakePoolInstruction::InitializePool`)
+#[derive(Clone, Debug, Default)]
+pub struct InitializePoolArgs {
+    pub pool_id: u64,
+    pub reward_rate: u64,
+    pub min_stake_amount: u64,
+    pub lockup_period: i64,
+    pub enforce_lockup: bool,
+    pub pool_end_date: Option<i64>,
+    pub referral_bps: u16,
+    pub early_unstake_bps: u16,
+    pub deposit_fee_bps: u16,
+    pub withdraw_fee_bps: u16,
+    /// Pool registry page to list the pool in (see `pool_registry_address`)
+    pub registry_page: u32,
+    /// Reward rate time-lock in seconds (None uses the deployment's default)
+    pub reward_rate_change_delay: Option<u32>,
+    /// Lowest reward rate future updates may propose (None: no floor)
+    pub min_reward_rate_bound: Option<u64>,
+    /// Highest reward rate future updates may propose (None: MAX_REWARD_RATE)
+    pub max_reward_rate_bound: Option<u64>,
+    /// Stake size tiers boosting the reward rate of large stakes (at most 4, by ascending min amount)
+    pub reward_tiers: Vec<RewardTier>,
+    /// End of the early-bird bonus window (None: no bonus)
+    pub bonus_end_ts: Option<i64>,
+    /// Reward rate multiplier (basis points) of stakes created in the bonus window
+    pub bonus_multiplier_bps: u16,
+    /// Whether `reward_rate` is paid per lockup round (default) or annualized
+    pub reward_rate_mode: RewardRateMode,
+}
+
+/// Campaign of a pool cloned from another (see `StakePoolInstruction::ClonePool`)
+#[derive(Clone, Debug, Default)]
+pub struct ClonePoolArgs {
+    pub pool_id: u64,
+    pub pool_end_date: Option<i64>,
+    /// End of the early-bird bonus window, at the source pool's multiplier (None: no bonus)
+    pub bonus_end_ts: Option<i64>,
+    /// Pool registry page to list the pool in (see `pool_registry_address`)
+    pub registry_page: u32,
+}
+
+/// Parameter changes for `update_pool` / `update_pools_batch` (None leaves a parameter unchanged)
+#[derive(Clone, Debug, Default)]
+pub struct UpdatePoolArgs {
+    pub reward_rate: Option<u64>,
+    pub min_stake_amount: Option<u64>,
+    pub lockup_period: Option<i64>,
+    pub is_paused: Option<bool>,
+    pub enforce_lockup: Option<bool>,
+    pub pool_end_date: Option<Option<i64>>,
+    pub referral_bps: Option<u16>,
+    pub early_unstake_bps: Option<u16>,
+    pub deposit_fee_bps: Option<u16>,
+    pub withdraw_fee_bps: Option<u16>,
+    /// Seconds between `request_unstake` and `withdraw_unstaked` (0 disables the cooldown)
+    pub unstake_cooldown: Option<i64>,
+    /// End of the early-bird bonus window (Some(None) removes the bonus)
+    pub bonus_end_ts: Option<Option<i64>>,
+    /// Reward rate multiplier (basis points) of stakes created in the bonus window
+    pub bonus_multiplier_bps: Option<u16>,
+    /// Bonus reward rate per year of `extend_lockup` extension (0 disables extensions)
+    pub lockup_extension_rate: Option<u64>,
+    /// Root of the merkle allowlist of stake owners (Some(None) removes it, see `allowlist_merkle_root`)
+    pub allowlist_merkle_root: Option<Option<[u8; 32]>>,
+    /// Recurring window rewards can be claimed in (Some(None) lifts the restriction)
+    pub claim_window: Option<Option<ClaimWindow>>,
+    /// Caps on the unstakes and claims of any 24h window (Some(None) lifts them)
+    pub outflow_limit: Option<Option<OutflowLimit>>,
+    /// Manager who may pause and fund this pool only (Some(None) removes it)
+    pub pool_manager: Option<Option<Pubkey>>,
+    /// Lamports paid per finalize, sync or stream crank, from the pool's escrow (Some(0) disables it)
+    pub keeper_bounty: Option<u64>,
+    /// Cap on the rewards the pool commits over its life (Some(None) lifts it)
+    pub max_total_rewards: Option<Option<u64>>,
+    /// Whether partial unstakes must leave at least min_stake_amount staked
+    pub enforce_min_remaining_stake: Option<bool>,
+    /// What WindDownStake does with the unclaimed rewards of the stakes it returns
+    pub wind_down_rewards: Option<WindDownRewards>,
+    /// Points accrued per staked base unit per second, scaled by 1e9 (0 disables points)
+    pub points_rate: Option<u64>,
+    /// Whether stakes, unstakes and claims check the global deny list
+    pub deny_list_required: Option<bool>,
+    /// Whether Stake accepts funders staking for owners who do not sign
+    pub allow_third_party_stake: Option<bool>,
+    /// Length in seconds of the pool's reward cohorts (0 turns them off; empty pools only)
+    pub cohort_duration: Option<i64>,
+}
+
+/// Parameters of a stake deposit (see `StakePoolInstruction::Stake`)
+#[derive(Clone, Debug, Default)]
+pub struct StakeArgs {
+    pub amount: u64,
+    pub index: u64,
+    /// Frontrunning protection: fail if the pool's reward rate differs
+    pub expected_reward_rate: Option<u64>,
+    /// Frontrunning protection: fail if the pool's lockup period differs
+    pub expected_lockup_period: Option<i64>,
+    pub referrer: Option<Pubkey>,
+    /// Let the permissionless Relock crank restart the lockup once it expires
+    pub auto_relock: bool,
+    /// Owner's receipt token account (required when the pool tokenizes positions)
+    pub receipt_account: Option<Pubkey>,
+    /// Label stored on the new stake account (see `stake_label`)
+    pub label: Option<[u8; 32]>,
+    /// Allowlist page listing the owner (required when the pool requires allowlisting)
+    pub allowlist_page: Option<u32>,
+    /// Merkle proof of the owner against the pool's allowlist root (see `allowlist_merkle_proof`)
+    pub allowlist_proof: Option<Vec<[u8; 32]>>,
+    /// Memo attached via the SPL Memo program, signed by the owner (`stake` only)
+    pub memo: Option<String>,
+    /// Record the index in the owner's stake position directory (see `get_stake_position`)
+    pub track_position: bool,
+    /// Let the program take the directory's next index (`stake` only, implies
+    /// `track_position`); `index` must be that next index, which locates the stake account
+    pub auto_index: bool,
+    /// Funder signing for `user_token_account` instead of the owner, who then does not
+    /// sign (`stake` only, pools with allow_third_party_stake)
+    pub funder: Option<Pubkey>,
+    /// Unix timestamp after which the stake is rejected (`stake` only, see `with_deadline`)
+    pub deadline: Option<i64>,
+}
+
+impl StakeArgs {
+    pub fn new(amount: u64, index: u64) -> Self {
+        Self {
+            amount,
+            index,
+            ..Self::default()
+        }
+    }
+}
+
+/// Encode `label` as a zero-padded stake account label (None if longer than 32 bytes)
+pub fn stake_label(label: &str) -> Option<[u8; 32]> {
+    let bytes = label.as_bytes();
+    let mut encoded = [0; 32];
+    encoded.get_mut(..bytes.len())?.copy_from_slice(bytes);
+    Some(encoded)
+}
+
+/// Initialize a new stake pool at `keys.pool`
+///
+/// `payer` must be an authorized pool creator and pays for the vaults, which the
+/// program creates at their PDAs: build `keys` with `PoolKeys::with_pda_vaults`.
+pub fn initialize_pool(keys: &PoolKeys, payer: &Pubkey, args: InitializePoolArgs) -> Instruction {
+    build(
+        pool_creation_metas(keys, payer, args.registry_page),
+        StakePoolInstruction::InitializePool {
+            pool_id: args.pool_id,
+            reward_rate: args.reward_rate,
+            min_stake_amount: args.min_stake_amount,
+            lockup_period: args.lockup_period,
+            enforce_lockup: args.enforce_lockup,
+            pool_end_date: args.pool_end_date,
+            referral_bps: args.referral_bps,
+            early_unstake_bps: args.early_unstake_bps,
+            deposit_fee_bps: args.deposit_fee_bps,
+            withdraw_fee_bps: args.withdraw_fee_bps,
+            reward_rate_change_delay: args.reward_rate_change_delay,
+            min_reward_rate_bound: args.min_reward_rate_bound,
+            max_reward_rate_bound: args.max_reward_rate_bound,
+            reward_tiers: args.reward_tiers,
+            bonus_end_ts: args.bonus_end_ts,
+            bonus_multiplier_bps: args.bonus_multiplier_bps,
+            reward_rate_mode: args.reward_rate_mode,
+        },
+    )
+}
+
+/// Create a new pool at `keys.pool` with the settings of `source_pool`
+///
+/// `keys` are the new pool's (same mints as the source, see `PoolKeys::with_pda_vaults`);
+/// only the new pool's id and campaign dates are given in `args`.
+pub fn clone_pool(
+    source_pool: &Pubkey,
+    keys: &PoolKeys,
+    payer: &Pubkey,
+    args: ClonePoolArgs,
+) -> Instruction {
+    let mut accounts = pool_creation_metas(keys, payer, args.registry_page);
+    accounts.push(AccountMeta::new_readonly(*source_pool, false));
+
+    build(
+        accounts,
+        StakePoolInstruction::ClonePool {
+            pool_id: args.pool_id,
+            pool_end_date: args.pool_end_date,
+            bonus_end_ts: args.bonus_end_ts,
+        },
+    )
+}
+
+/// Accounts of `initialize_pool`, which `clone_pool` starts with
+fn pool_creation_metas(keys: &PoolKeys, payer: &Pubkey, registry_page: u32) -> Vec<AccountMeta> {
+    let mut accounts = vec![
+        AccountMeta::new(keys.pool, false),
+        AccountMeta::new_readonly(keys.stake_mint, false),
+        AccountMeta::new_readonly(keys.reward_mint, false),
+        AccountMeta::new(keys.stake_vault, false),
+        AccountMeta::new(keys.reward_vault, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(keys.token_program, false),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new(program_authority_address(), false),
+        AccountMeta::new(keys.fee_vault, false),
+        AccountMeta::new(mint_registry_address(&keys.stake_mint), false),
+    ];
+    accounts.extend(pool_registry_metas(registry_page));
+    accounts.push(AccountMeta::new_readonly(program_config_address(), false));
+    accounts
+}
+
+/// Stake tokens from `user_token_account` into a new stake account at `args.index`
+pub fn stake(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    user_token_account: &Pubkey,
+    payer: &Pubkey,
+    args: StakeArgs,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(keys.pool, false),
+        AccountMeta::new(stake_account_address(&keys.pool, owner, args.index), false),
+        AccountMeta::new_readonly(*owner, args.funder.is_none()),
+        AccountMeta::new(*user_token_account, false),
+        AccountMeta::new(keys.stake_vault, false),
+        AccountMeta::new_readonly(keys.reward_vault, false),
+        AccountMeta::new_readonly(keys.stake_mint, false),
+        AccountMeta::new_readonly(keys.token_program, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        AccountMeta::new(keys.fee_vault, false),
+        AccountMeta::new(mint_registry_address(&keys.stake_mint), false),
+    ];
+    accounts.extend(receipt_metas(keys, args.receipt_account.as_ref()));
+    accounts.push(AccountMeta::new_readonly(
+        program_authority_address(),
+        false,
+    ));
+    accounts.push(allowlist_meta(&keys.pool, args.allowlist_page));
+    accounts.push(memo_program_meta(args.memo.as_ref()));
+    accounts.push(stake_position_meta(
+        &keys.pool,
+        owner,
+        args.track_position || args.auto_index,
+    ));
+    accounts.push(match args.funder {
+        Some(funder) => AccountMeta::new_readonly(funder, true),
+        None => AccountMeta::new_readonly(ID, false),
+    });
+    accounts.push(deny_list_meta(keys));
+    accounts.push(AccountMeta::new_readonly(ID, false));
+    accounts.push(hook_program_meta(keys));
+
+    build(
+        accounts,
+        StakePoolInstruction::Stake {
+            amount: args.amount,
+            index: (!args.auto_index).then_some(args.index),
+            expected_reward_rate: args.expected_reward_rate,
+            expected_lockup_period: args.expected_lockup_period,
+            referrer: args.referrer,
+            auto_relock: args.auto_relock,
+            label: args.label,
+            allowlist_proof: args.allowlist_proof,
+            memo: args.memo,
+            deadline: args.deadline,
+        },
+    )
+}
+
+/// Parameters of a batch of stake deposits (see `StakePoolInstruction::StakeBatch`)
+#[derive(Clone, Debug, Default)]
+pub struct StakeBatchArgs {
+    /// Deposit of each new stake account, in index order
+    pub amounts: Vec<u64>,
+    /// Index of the first stake account created
+    pub start_index: u64,
+    /// Frontrunning protection: fail if the pool's reward rate differs
+    pub expected_reward_rate: Option<u64>,
+    /// Frontrunning protection: fail if the pool's lockup period differs
+    pub expected_lockup_period: Option<i64>,
+    /// Owner's receipt token account (required when the pool tokenizes positions)
+    pub receipt_account: Option<Pubkey>,
+    /// Allowlist page listing the owner (required when the pool requires allowlisting)
+    pub allowlist_page: Option<u32>,
+    /// Merkle proof of the owner against the pool's allowlist root (see `allowlist_merkle_proof`)
+    pub allowlist_proof: Option<Vec<[u8; 32]>>,
+    /// Record the indices in the owner's stake position directory (see `get_stake_position`)
+    pub track_position: bool,
+}
+
+/// Stake `args.amounts` from `user_token_account` into new stake accounts
+/// at consecutive indices from `args.start_index`
+pub fn stake_batch(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    user_token_account: &Pubkey,
+    payer: &Pubkey,
+    args: StakeBatchArgs,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(keys.pool, false),
+        AccountMeta::new_readonly(*owner, true),
+        AccountMeta::new(*user_token_account, false),
+        AccountMeta::new(keys.stake_vault, false),
+        AccountMeta::new_readonly(keys.reward_vault, false),
+        AccountMeta::new_readonly(keys.stake_mint, false),
+        AccountMeta::new_readonly(keys.token_program, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        AccountMeta::new(keys.fee_vault, false),
+        AccountMeta::new(mint_registry_address(&keys.stake_mint), false),
+    ];
+    accounts.extend(receipt_metas(keys, args.receipt_account.as_ref()));
+    accounts.push(AccountMeta::new_readonly(
+        program_authority_address(),
+        false,
+    ));
+    accounts.push(allowlist_meta(&keys.pool, args.allowlist_page));
+    accounts.push(stake_position_meta(&keys.pool, owner, args.track_position));
+    accounts.extend(
+        (args.start_index..)
+            .take(args.amounts.len())
+            .map(|index| AccountMeta::new(stake_account_address(&keys.pool, owner, index), false)),
+    );
+
+    build(
+        accounts,
+        StakePoolInstruction::StakeBatch {
+            amounts: args.amounts,
+            start_index: args.start_index,
+            expected_reward_rate: args.expected_reward_rate,
+            expected_lockup_period: args.expected_lockup_period,
+            allowlist_proof: args.allowlist_proof,
+        },
+    )
+}
+
+/// Unstake `amount` from stake account `index` into `user_token_account`
+///
+/// `receipt_account` is required when the pool tokenizes positions.
+pub fn unstake(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    index: u64,
+    user_token_account: &Pubkey,
+    amount: u64,
+    expected_reward_rate: Option<u64>,
+    receipt_account: Option<&Pubkey>,
+) -> Instruction {
+    build_unstake(
+        keys,
+        owner,
+        index,
+        user_token_account,
+        amount,
+        expected_reward_rate,
+        receipt_account,
+        None,
+    )
+}
+
+/// `unstake`, attaching `memo` via the SPL Memo program
+#[allow(clippy::too_many_arguments)]
+pub fn unstake_with_memo(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    index: u64,
+    user_token_account: &Pubkey,
+    amount: u64,
+    expected_reward_rate: Option<u64>,
+    receipt_account: Option<&Pubkey>,
+    memo: &str,
+) -> Instruction {
+    build_unstake(
+        keys,
+        owner,
+        index,
+        user_token_account,
+        amount,
+        expected_reward_rate,
+        receipt_account,
+        Some(memo.to_string()),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn build_unstake(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    index: u64,
+    user_token_account: &Pubkey,
+    amount: u64,
+    expected_reward_rate: Option<u64>,
+    receipt_account: Option<&Pubkey>,
+    memo: Option<String>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(keys.pool, false),
+        AccountMeta::new(stake_account_address(&keys.pool, owner, index), false),
+        AccountMeta::new_readonly(*owner, true),
+        AccountMeta::new(*user_token_account, false),
+        AccountMeta::new(keys.stake_vault, false),
+        AccountMeta::new_readonly(keys.stake_mint, false),
+        AccountMeta::new_readonly(keys.token_program, false),
+        AccountMeta::new_readonly(sysvar::clock::ID, false),
+        AccountMeta::new(keys.fee_vault, false),
+        AccountMeta::new(mint_registry_address(&keys.stake_mint), false),
+    ];
+    accounts.extend(receipt_metas(keys, receipt_account));
+    accounts.push(AccountMeta::new_readonly(
+        program_authority_address(),
+        false,
+    ));
+    accounts.push(memo_program_meta(memo.as_ref()));
+    accounts.push(deny_list_meta(keys));
+    accounts.push(hook_program_meta(keys));
+
+    build(
+        accounts,
+        StakePoolInstruction::Unstake {
+            amount,
+            expected_reward_rate,
+            memo,
+            deadline: None,
+        },
+    )
+}
+
+/// Claim the rewards of stake account `index`, unstake its whole principal and
+/// close it in one instruction, returning its rent to `owner`
+///
+/// Principal goes to `user_token_account` and rewards to `user_reward_account`,
+/// both owned by `owner`. `referrer_reward_account` and `receipt_account` are
+/// required as for `claim_rewards` and `unstake`.
+#[allow(clippy::too_many_arguments)]
+pub fn unstake_all(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    index: u64,
+    user_token_account: &Pubkey,
+    user_reward_account: &Pubkey,
+    referrer_reward_account: Option<&Pubkey>,
+    expected_reward_rate: Option<u64>,
+    receipt_account: Option<&Pubkey>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(keys.pool, false),
+        AccountMeta::new(stake_account_address(&keys.pool, owner, index), false),
+        AccountMeta::new(*owner, true),
+        AccountMeta::new(*user_token_account, false),
+        AccountMeta::new(keys.stake_vault, false),
+        AccountMeta::new_readonly(keys.stake_mint, false),
+        AccountMeta::new_readonly(keys.token_program, false),
+        AccountMeta::new_readonly(sysvar::clock::ID, false),
+        AccountMeta::new(keys.fee_vault, false),
+        AccountMeta::new(mint_registry_address(&keys.stake_mint), false),
+        AccountMeta::new(*user_reward_account, false),
+        AccountMeta::new(keys.reward_vault, false),
+        AccountMeta::new_readonly(keys.reward_mint, false),
+        AccountMeta::new_readonly(program_authority_address(), false),
+    ];
+    accounts.extend(receipt_metas(keys, receipt_account));
+    accounts.push(match referrer_reward_account {
+        Some(account) => AccountMeta::new(*account, false),
+        None => AccountMeta::new_readonly(ID, false),
+    });
+    accounts.push(stake_position_meta(&keys.pool, owner, true));
+    accounts.push(memo_program_meta(None));
+    accounts.push(deny_list_meta(keys));
+    accounts.push(AccountMeta::new_readonly(ID, false));
+    accounts.push(hook_program_meta(keys));
+
+    build(
+        accounts,
+        StakePoolInstruction::UnstakeAll {
+            expected_reward_rate,
+            memo: None,
+            deadline: None,
+        },
+    )
+}
+
+/// Claim rewards of stake account `index` into `user_reward_account`
+///
+/// `user_reward_account` must be owned by `owner`. `referrer_reward_account` is
+/// required when the stake has a referrer and the pool pays referrals.
+pub fn claim_rewards(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    index: u64,
+    user_reward_account: &Pubkey,
+    referrer_reward_account: Option<&Pubkey>,
+) -> Instruction {
+    claim_rewards_signed_by(
+        keys,
+        owner,
+        owner,
+        index,
+        user_reward_account,
+        referrer_reward_account,
+        None,
+        None,
+        false,
+        None,
+    )
+}
+
+/// `claim_rewards`, attaching `memo` via the SPL Memo program
+pub fn claim_rewards_with_memo(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    index: u64,
+    user_reward_account: &Pubkey,
+    referrer_reward_account: Option<&Pubkey>,
+    memo: &str,
+) -> Instruction {
+    claim_rewards_signed_by(
+        keys,
+        owner,
+        owner,
+        index,
+        user_reward_account,
+        referrer_reward_account,
+        None,
+        None,
+        false,
+        Some(memo.to_string()),
+    )
+}
+
+/// Claim rewards of stake account `index` into `owner`'s associated reward token account
+///
+/// The account is created during the claim when missing, paid for by `owner`,
+/// so wallets need no separate transaction to set it up.
+pub fn claim_rewards_to_associated_account(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    index: u64,
+    referrer_reward_account: Option<&Pubkey>,
+) -> Instruction {
+    claim_rewards_signed_by(
+        keys,
+        owner,
+        owner,
+        index,
+        &associated_reward_account(keys, owner),
+        referrer_reward_account,
+        None,
+        None,
+        true,
+        None,
+    )
+}
+
+/// `owner`'s associated token account for the pool's reward mint
+pub fn associated_reward_account(keys: &PoolKeys, owner: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, &keys.reward_mint, &keys.token_program)
+}
+
+/// Claim `amount` of the unclaimed rewards of stake account `index`
+///
+/// The remaining rewards stay claimable later.
+pub fn claim_partial_rewards(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    index: u64,
+    user_reward_account: &Pubkey,
+    amount: u64,
+    referrer_reward_account: Option<&Pubkey>,
+) -> Instruction {
+    claim_rewards_signed_by(
+        keys,
+        owner,
+        owner,
+        index,
+        user_reward_account,
+        referrer_reward_account,
+        None,
+        Some(amount),
+        false,
+        None,
+    )
+}
+
+/// Claim rewards of stake account `index` into `recipient`, any reward token account
+///
+/// Lets the owner send rewards elsewhere, e.g. to a cold wallet's token account.
+/// `user_reward_account` is passed but not paid.
+pub fn claim_rewards_to(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    index: u64,
+    user_reward_account: &Pubkey,
+    recipient: &Pubkey,
+    referrer_reward_account: Option<&Pubkey>,
+) -> Instruction {
+    claim_rewards_signed_by(
+        keys,
+        owner,
+        owner,
+        index,
+        user_reward_account,
+        referrer_reward_account,
+        Some(recipient),
+        None,
+        false,
+        None,
+    )
+}
+
+/// Claim rewards of `owner`'s stake account `index` as its delegate
+///
+/// `owner_reward_account` must be a reward token account owned by `owner`.
+pub fn claim_rewards_as_delegate(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    delegate: &Pubkey,
+    index: u64,
+    owner_reward_account: &Pubkey,
+    referrer_reward_account: Option<&Pubkey>,
+) -> Instruction {
+    claim_rewards_signed_by(
+        keys,
+        owner,
+        delegate,
+        index,
+        owner_reward_account,
+        referrer_reward_account,
+        None,
+        None,
+        false,
+        None,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn claim_rewards_signed_by(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    signer: &Pubkey,
+    index: u64,
+    user_reward_account: &Pubkey,
+    referrer_reward_account: Option<&Pubkey>,
+    recipient: Option<&Pubkey>,
+    amount: Option<u64>,
+    create_user_reward_account: bool,
+    memo: Option<String>,
+) -> Instruction {
+    let referrer_reward_account = match referrer_reward_account {
+        Some(account) => AccountMeta::new(*account, false),
+        None => AccountMeta::new_readonly(ID, false),
+    };
+    let recipient = match recipient {
+        Some(account) => AccountMeta::new(*account, false),
+        None => AccountMeta::new_readonly(ID, false),
+    };
+    // The owner pays for the associated token account when it is created
+    let (signer, associated_token_program, system_program) = if create_user_reward_account {
+        (
+            AccountMeta::new(*signer, true),
+            your_wallet_stake_pool::spl_associated_token_account_client::program::ID,
+            SYSTEM_PROGRAM_ID,
+        )
+    } else {
+        (AccountMeta::new_readonly(*signer, true), ID, ID)
+    };
+
+    build(
+        vec![
+            AccountMeta::new(keys.pool, false),
+            AccountMeta::new(stake_account_address(&keys.pool, owner, index), false),
+            signer,
+            AccountMeta::new(*user_reward_account, false),
+            AccountMeta::new(keys.reward_vault, false),
+            AccountMeta::new_readonly(keys.reward_mint, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(sysvar::clock::ID, false),
+            referrer_reward_account,
+            AccountMeta::new_readonly(program_authority_address(), false),
+            recipient,
+            AccountMeta::new_readonly(associated_token_program, false),
+            AccountMeta::new_readonly(system_program, false),
+            memo_program_meta(memo.as_ref()),
+            deny_list_meta(keys),
+            AccountMeta::new_readonly(ID, false),
+            hook_program_meta(keys),
+        ],
+        StakePoolInstruction::ClaimRewards {
+            amount,
+            memo,
+            deadline: None,
+        },
+    )
+}
+
+/// Make a built Stake, Unstake, ClaimRewards or UnstakeAll instruction fail once `deadline`
+/// (a Unix timestamp) has passed, e.g. `with_deadline(claim_rewards(..), now + 60)`
+///
+/// Protects against a transaction landing late, after a parameter change or the
+/// pool's end. Returns None for any other instruction.
+pub fn with_deadline(mut instruction: Instruction, deadline: i64) -> Option<Instruction> {
+    if instruction.program_id != ID {
+        return None;
+    }
+    let mut data = StakePoolInstruction::try_from_slice(&instruction.data).ok()?;
+    match &mut data {
+        StakePoolInstruction::Stake { deadline: slot, .. }
+        | StakePoolInstruction::Unstake { deadline: slot, .. }
+        | StakePoolInstruction::ClaimRewards { deadline: slot, .. }
+        | StakePoolInstruction::UnstakeAll { deadline: slot, .. } => *slot = Some(deadline),
+        _ => return None,
+    }
+    instruction.data = data
+        .try_to_vec()
+        .expect("StakePoolInstruction serialization is infallible");
+    Some(instruction)
+}
+
+/// Point a built Stake, ClaimRewards or UnstakeAll instruction at reward cohort `cohort`,
+/// e.g. `with_reward_cohort(stake(..), pool.reward_cohort_at(now))`
+///
+/// Pools with reward cohorts pay a stake's rewards from the vault of the cohort
+/// it was opened in (`StakeAccount::reward_cohort`). Returns None for any other
+/// instruction, or for cohort 0.
+pub fn with_reward_cohort(mut instruction: Instruction, cohort: u64) -> Option<Instruction> {
+    if instruction.program_id != ID || cohort == 0 {
+        return None;
+    }
+    let (reward_vault_index, cohort_index) =
+        match StakePoolInstruction::try_from_slice(&instruction.data).ok()? {
+            StakePoolInstruction::Stake { .. } => (5, 21),
+            StakePoolInstruction::ClaimRewards { .. } => (4, 15),
+            StakePoolInstruction::UnstakeAll { .. } => (11, 21),
+            _ => return None,
+        };
+    let pool = instruction.accounts.first()?.pubkey;
+    let reward_vault = instruction.accounts.get_mut(reward_vault_index)?;
+    reward_vault.pubkey = cohort_vault_address(&pool, cohort);
+    *instruction.accounts.get_mut(cohort_index)? =
+        AccountMeta::new(reward_cohort_address(&pool, cohort), false);
+    Some(instruction)
+}
+
+/// Claim the rewards of `owner`'s stake accounts at `indices` in one transfer
+///
+/// Stakes that pay a referral share must be claimed with `claim_rewards` instead.
+pub fn claim_rewards_batch(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    indices: &[u64],
+    user_reward_account: &Pubkey,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(keys.pool, false),
+        AccountMeta::new_readonly(*owner, true),
+        AccountMeta::new(*user_reward_account, false),
+        AccountMeta::new(keys.reward_vault, false),
+        AccountMeta::new_readonly(keys.reward_mint, false),
+        AccountMeta::new_readonly(keys.token_program, false),
+        AccountMeta::new_readonly(sysvar::clock::ID, false),
+        AccountMeta::new_readonly(program_authority_address(), false),
+    ];
+    accounts.extend(
+        indices
+            .iter()
+            .map(|&index| AccountMeta::new(stake_account_address(&keys.pool, owner, index), false)),
+    );
+
+    build(accounts, StakePoolInstruction::ClaimRewardsBatch)
+}
+
+/// Update pool settings (global admin, or a pauser / rate admin for is_paused / reward_rate)
+pub fn update_pool(pool: &Pubkey, admin: &Pubkey, args: UpdatePoolArgs) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(*pool, false),
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            AccountMeta::new_readonly(program_config_address(), false),
+        ],
+        StakePoolInstruction::UpdatePool {
+            reward_rate: args.reward_rate,
+            min_stake_amount: args.min_stake_amount,
+            lockup_period: args.lockup_period,
+            is_paused: args.is_paused,
+            enforce_lockup: args.enforce_lockup,
+            pool_end_date: args.pool_end_date,
+            referral_bps: args.referral_bps,
+            early_unstake_bps: args.early_unstake_bps,
+            deposit_fee_bps: args.deposit_fee_bps,
+            withdraw_fee_bps: args.withdraw_fee_bps,
+            unstake_cooldown: args.unstake_cooldown,
+            bonus_end_ts: args.bonus_end_ts,
+            bonus_multiplier_bps: args.bonus_multiplier_bps,
+            lockup_extension_rate: args.lockup_extension_rate,
+            allowlist_merkle_root: args.allowlist_merkle_root,
+            claim_window: args.claim_window,
+            outflow_limit: args.outflow_limit,
+            pool_manager: args.pool_manager,
+            keeper_bounty: args.keeper_bounty,
+            max_total_rewards: args.max_total_rewards,
+            enforce_min_remaining_stake: args.enforce_min_remaining_stake,
+            wind_down_rewards: args.wind_down_rewards,
+            points_rate: args.points_rate,
+            deny_list_required: args.deny_list_required,
+            allow_third_party_stake: args.allow_third_party_stake,
+            cohort_duration: args.cohort_duration,
+        },
+    )
+}
+
+/// Fund the pool's reward vault from `funder_token_account`
+pub fn fund_rewards(
+    keys: &PoolKeys,
+    funder: &Pubkey,
+    funder_token_account: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(keys.pool, false),
+            AccountMeta::new_readonly(*funder, true),
+            AccountMeta::new(*funder_token_account, false),
+            AccountMeta::new(keys.reward_vault, false),
+            AccountMeta::new_readonly(keys.reward_mint, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+        ],
+        StakePoolInstruction::FundRewards { amount },
+    )
+}
+
+/// Add `lamports` from `funder` to the pool's keeper bounty escrow
+pub fn fund_keeper_bounty(pool: &Pubkey, funder: &Pubkey, lamports: u64) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(*pool, false),
+            AccountMeta::new(*funder, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        StakePoolInstruction::FundKeeperBounty { lamports },
+    )
+}
+
+/// Stream `rate_per_second` reward tokens from `funder_token_account` into the
+/// pool until `end_ts`
+pub fn create_reward_stream(
+    keys: &PoolKeys,
+    funder: &Pubkey,
+    funder_token_account: &Pubkey,
+    rate_per_second: u64,
+    end_ts: i64,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new_readonly(keys.pool, false),
+            AccountMeta::new(reward_stream_address(&keys.pool, funder), false),
+            AccountMeta::new(*funder, true),
+            AccountMeta::new(*funder_token_account, false),
+            AccountMeta::new_readonly(keys.reward_mint, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        StakePoolInstruction::CreateRewardStream {
+            rate_per_second,
+            end_ts,
+        },
+    )
+}
+
+/// Move what `funder`'s stream accrued into the reward vault (permissionless),
+/// paying the pool's keeper bounty to `keeper`
+pub fn crank_reward_stream(
+    keys: &PoolKeys,
+    funder: &Pubkey,
+    funder_token_account: &Pubkey,
+    keeper: Option<&Pubkey>,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(keys.pool, false),
+            AccountMeta::new(reward_stream_address(&keys.pool, funder), false),
+            AccountMeta::new(*funder_token_account, false),
+            AccountMeta::new(keys.reward_vault, false),
+            AccountMeta::new_readonly(keys.reward_mint, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            keeper_meta(keeper),
+        ],
+        StakePoolInstruction::CrankRewardStream,
+    )
+}
+
+/// Close `funder`'s stream into `keys.pool`, revoking its delegation
+pub fn close_reward_stream(
+    keys: &PoolKeys,
+    funder: &Pubkey,
+    funder_token_account: &Pubkey,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(reward_stream_address(&keys.pool, funder), false),
+            AccountMeta::new(*funder, true),
+            AccountMeta::new(*funder_token_account, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+        ],
+        StakePoolInstruction::CloseRewardStream,
+    )
+}
+
+/// Close an empty stake account and send its rent to `receiver`
+///
+/// The index is also removed from the owner's stake position directory; the
+/// program skips that while the directory does not exist.
+pub fn close_stake_account(
+    pool: &Pubkey,
+    owner: &Pubkey,
+    index: u64,
+    receiver: &Pubkey,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(stake_account_address(pool, owner, index), false),
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new(*receiver, false),
+            stake_position_meta(pool, owner, true),
+        ],
+        StakePoolInstruction::CloseStakeAccount,
+    )
+}
+
+/// Apply a pending reward rate change once its delay has elapsed (permissionless),
+/// paying the pool's keeper bounty to `keeper`
+pub fn finalize_reward_rate_change(pool: &Pubkey, keeper: Option<&Pubkey>) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(*pool, false),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            keeper_meta(keeper),
+        ],
+        StakePoolInstruction::FinalizeRewardRateChange,
+    )
+}
+
+/// Apply a pending lockup change once its delay has elapsed (permissionless),
+/// paying the pool's keeper bounty to `keeper`
+pub fn finalize_lockup_change(pool: &Pubkey, keeper: Option<&Pubkey>) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(*pool, false),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            keeper_meta(keeper),
+        ],
+        StakePoolInstruction::FinalizeLockupChange,
+    )
+}
+
+/// Create the global program authority account for the given cluster profile
+pub fn initialize_program_authority(
+    initial_authority: &Pubkey,
+    payer: &Pubkey,
+    cluster_profile: ClusterProfile,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(program_authority_address(), false),
+            AccountMeta::new_readonly(*initial_authority, true),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        StakePoolInstruction::InitializeProgramAuthority { cluster_profile },
+    )
+}
+
+/// Add and remove authorized pool creators; the authority pays rent if the account grows
+pub fn manage_authorized_creators(
+    authority: &Pubkey,
+    add: Vec<Pubkey>,
+    remove: Vec<Pubkey>,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(program_authority_address(), false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        StakePoolInstruction::ManageAuthorizedCreators { add, remove },
+    )
+}
+
+/// Cap the number of pools `creator` may create (`None` removes the cap)
+pub fn set_creator_pool_limit(
+    authority: &Pubkey,
+    creator: &Pubkey,
+    pool_limit: Option<u16>,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(program_authority_address(), false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        StakePoolInstruction::SetCreatorPoolLimit {
+            creator: *creator,
+            pool_limit,
+        },
+    )
+}
+
+/// Set how many authorized creators the program authority may hold
+pub fn set_max_creators(authority: &Pubkey, max_creators: u16) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(program_authority_address(), false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        StakePoolInstruction::SetMaxCreators { max_creators },
+    )
+}
+
+/// Grant `holder` exactly the roles in `roles` (see `Role::mask`); 0 revokes every role
+pub fn manage_roles(authority: &Pubkey, holder: &Pubkey, roles: u8) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(program_authority_address(), false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        StakePoolInstruction::ManageRoles {
+            holder: *holder,
+            roles,
+        },
+    )
+}
+
+/// Nominate `new_authority` as the next program authority
+pub fn transfer_program_authority(
+    current_authority: &Pubkey,
+    new_authority: &Pubkey,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(program_authority_address(), false),
+            AccountMeta::new_readonly(*current_authority, true),
+            AccountMeta::new_readonly(*new_authority, false),
+        ],
+        StakePoolInstruction::TransferProgramAuthority,
+    )
+}
+
+/// Accept a pending program authority transfer
+pub fn accept_program_authority(pending_authority: &Pubkey) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(program_authority_address(), false),
+            AccountMeta::new_readonly(*pending_authority, true),
+        ],
+        StakePoolInstruction::AcceptProgramAuthority,
+    )
+}
+
+/// Cancel a pending program authority transfer and any recovery in progress
+pub fn cancel_authority_transfer(current_authority: &Pubkey) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(program_authority_address(), false),
+            AccountMeta::new_readonly(*current_authority, true),
+        ],
+        StakePoolInstruction::CancelAuthorityTransfer,
+    )
+}
+
+/// Set or remove the key that can take over a lost program authority after
+/// `recovery_delay` seconds
+pub fn set_recovery_authority(
+    authority: &Pubkey,
+    recovery_authority: Option<Pubkey>,
+    recovery_delay: i64,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(program_authority_address(), false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        StakePoolInstruction::SetRecoveryAuthority {
+            recovery_authority,
+            recovery_delay,
+        },
+    )
+}
+
+/// Start a takeover of the program authority by `recovery_authority`
+pub fn initiate_recovery(recovery_authority: &Pubkey) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(program_authority_address(), false),
+            AccountMeta::new_readonly(*recovery_authority, true),
+        ],
+        StakePoolInstruction::InitiateRecovery,
+    )
+}
+
+/// Complete a takeover of the program authority once the recovery delay has passed
+pub fn complete_recovery(recovery_authority: &Pubkey) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(program_authority_address(), false),
+            AccountMeta::new_readonly(*recovery_authority, true),
+        ],
+        StakePoolInstruction::CompleteRecovery,
+    )
+}
+
+/// Create the program config with the given feature flags and pool limits
+pub fn initialize_program_config(
+    authority: &Pubkey,
+    payer: &Pubkey,
+    feature_flags: u64,
+    max_reward_rate: u64,
+    min_lockup_period: i64,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(program_config_address(), false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        StakePoolInstruction::InitializeProgramConfig {
+            feature_flags,
+            max_reward_rate,
+            min_lockup_period,
+        },
+    )
+}
+
+/// Change the program config fields that are `Some`
+pub fn update_program_config(
+    authority: &Pubkey,
+    feature_flags: Option<u64>,
+    max_reward_rate: Option<u64>,
+    min_lockup_period: Option<i64>,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(program_config_address(), false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+        ],
+        StakePoolInstruction::UpdateProgramConfig {
+            feature_flags,
+            max_reward_rate,
+            min_lockup_period,
+        },
+    )
+}
+
+/// Query the authorized creators (for simulateTransaction)
+pub fn get_authorized_creators() -> Instruction {
+    build(
+        vec![AccountMeta::new_readonly(
+            program_authority_address(),
+            false,
+        )],
+        StakePoolInstruction::GetAuthorizedCreators,
+    )
+}
+
+/// Check whether `address` may create pools (for simulateTransaction)
+pub fn check_authorization(address: &Pubkey) -> Instruction {
+    build(
+        vec![AccountMeta::new_readonly(
+            program_authority_address(),
+            false,
+        )],
+        StakePoolInstruction::CheckAuthorization { address: *address },
+    )
+}
+
+/// Query the pending rewards of `owner`'s stake account `index` (for simulateTransaction)
+///
+/// The simulation's return data decodes as a `PendingRewards`.
+pub fn get_pending_rewards(keys: &PoolKeys, owner: &Pubkey, index: u64) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new_readonly(keys.pool, false),
+            AccountMeta::new_readonly(stake_account_address(&keys.pool, owner, index), false),
+        ],
+        StakePoolInstruction::GetPendingRewards,
+    )
+}
+
+/// Query `owner`'s stake indices in the pool (for simulateTransaction)
+///
+/// The simulation's return data decodes as a `StakePositions`.
+pub fn get_stake_position(keys: &PoolKeys, owner: &Pubkey) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new_readonly(keys.pool, false),
+            AccountMeta::new_readonly(*owner, false),
+            AccountMeta::new_readonly(stake_position_address(&keys.pool, owner), false),
+        ],
+        StakePoolInstruction::GetStakePosition,
+    )
+}
+
+/// Audit the pool's `total_rewards_owed` against `stake_accounts` (permissionless)
+///
+/// With `repair_admin`, that global admin resets the counter to the recomputed
+/// sum, which requires `stake_accounts` to hold every active stake of the pool.
+/// The return data decodes as a `PoolAudit`.
+pub fn audit_pool(
+    pool: &Pubkey,
+    stake_accounts: &[Pubkey],
+    repair_admin: Option<&Pubkey>,
+) -> Instruction {
+    let mut accounts = match repair_admin {
+        Some(admin) => vec![
+            AccountMeta::new(*pool, false),
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+        ],
+        None => vec![
+            AccountMeta::new(*pool, false),
+            AccountMeta::new_readonly(ID, false),
+            AccountMeta::new_readonly(ID, false),
+        ],
+    };
+    accounts.extend(
+        stake_accounts
+            .iter()
+            .map(|stake_account| AccountMeta::new_readonly(*stake_account, false)),
+    );
+
+    build(
+        accounts,
+        StakePoolInstruction::AuditPool {
+            repair: repair_admin.is_some(),
+        },
+    )
+}
+
+/// Return stake account `index` of `owner` to its owner and close it once the
+/// pool's wind-down has started (permissionless)
+///
+/// The principal goes to the owner's associated token account for the stake
+/// mint, which must exist; create it first if needed. Under the pool's
+/// `WindDownRewards::Pay` policy the earned rewards go to the owner's
+/// associated reward account, which must exist as well.
+pub fn wind_down_stake(keys: &PoolKeys, owner: &Pubkey, index: u64) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(keys.pool, false),
+            AccountMeta::new(stake_account_address(&keys.pool, owner, index), false),
+            AccountMeta::new(*owner, false),
+            AccountMeta::new(
+                get_associated_token_address_with_program_id(
+                    owner,
+                    &keys.stake_mint,
+                    &keys.token_program,
+                ),
+                false,
+            ),
+            AccountMeta::new(keys.stake_vault, false),
+            AccountMeta::new_readonly(keys.stake_mint, false),
+            AccountMeta::new(keys.reward_vault, false),
+            AccountMeta::new_readonly(keys.reward_mint, false),
+            AccountMeta::new(associated_reward_account(keys, owner), false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new(mint_registry_address(&keys.stake_mint), false),
+            stake_position_meta(&keys.pool, owner, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+        ],
+        StakePoolInstruction::WindDownStake,
+    )
+}
+
+/// Query the pool's health (for simulateTransaction)
+///
+/// The simulation's return data decodes as a `PoolStats`.
+pub fn get_pool_stats(keys: &PoolKeys) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new_readonly(keys.pool, false),
+            AccountMeta::new_readonly(keys.stake_vault, false),
+            AccountMeta::new_readonly(keys.reward_vault, false),
+        ],
+        StakePoolInstruction::GetPoolStats,
+    )
+}
+
+/// List a pool created before the pool registry existed in registry page `page`
+pub fn register_pool(pool: &Pubkey, payer: &Pubkey, page: u32) -> Instruction {
+    let mut accounts = vec![AccountMeta::new(*pool, false)];
+    accounts.extend(pool_registry_metas(page));
+    accounts.push(AccountMeta::new(*payer, true));
+    accounts.push(AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false));
+
+    build(accounts, StakePoolInstruction::RegisterPool)
+}
+
+/// Withdraw collected fees from the pool's fee vault to `destination` (global admin or treasurer)
+pub fn withdraw_fees(
+    keys: &PoolKeys,
+    admin: &Pubkey,
+    destination: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new_readonly(keys.pool, false),
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            AccountMeta::new(keys.fee_vault, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(keys.stake_mint, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+        ],
+        StakePoolInstruction::WithdrawFees { amount },
+    )
+}
+
+/// Sweep the stake vault balance above total_staked to `treasury` (global admin or treasurer)
+pub fn sweep_dust(keys: &PoolKeys, admin: &Pubkey, treasury: &Pubkey) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new_readonly(keys.pool, false),
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            AccountMeta::new(keys.stake_vault, false),
+            AccountMeta::new(*treasury, false),
+            AccountMeta::new_readonly(keys.stake_mint, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+        ],
+        StakePoolInstruction::SweepDust,
+    )
+}
+
+/// Apply the same parameter changes to every pool in `pools` (global admin, or a pauser / rate admin)
+pub fn update_pools_batch(pools: &[Pubkey], admin: &Pubkey, args: UpdatePoolArgs) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(program_authority_address(), false),
+        AccountMeta::new_readonly(program_config_address(), false),
+    ];
+    accounts.extend(pools.iter().map(|pool| AccountMeta::new(*pool, false)));
+
+    build(
+        accounts,
+        StakePoolInstruction::UpdatePoolsBatch {
+            reward_rate: args.reward_rate,
+            min_stake_amount: args.min_stake_amount,
+            lockup_period: args.lockup_period,
+            is_paused: args.is_paused,
+            enforce_lockup: args.enforce_lockup,
+            pool_end_date: args.pool_end_date,
+            referral_bps: args.referral_bps,
+            early_unstake_bps: args.early_unstake_bps,
+            deposit_fee_bps: args.deposit_fee_bps,
+            withdraw_fee_bps: args.withdraw_fee_bps,
+            unstake_cooldown: args.unstake_cooldown,
+            bonus_end_ts: args.bonus_end_ts,
+            bonus_multiplier_bps: args.bonus_multiplier_bps,
+            lockup_extension_rate: args.lockup_extension_rate,
+            allowlist_merkle_root: args.allowlist_merkle_root,
+            claim_window: args.claim_window,
+            outflow_limit: args.outflow_limit,
+            pool_manager: args.pool_manager,
+            keeper_bounty: args.keeper_bounty,
+            max_total_rewards: args.max_total_rewards,
+            enforce_min_remaining_stake: args.enforce_min_remaining_stake,
+            wind_down_rewards: args.wind_down_rewards,
+            points_rate: args.points_rate,
+            deny_list_required: args.deny_list_required,
+            allow_third_party_stake: args.allow_third_party_stake,
+            cohort_duration: args.cohort_duration,
+        },
+    )
+}
+
+/// Stake `args.amount` lamports into a native SOL pool
+pub fn stake_sol(keys: &PoolKeys, owner: &Pubkey, payer: &Pubkey, args: StakeArgs) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(keys.pool, false),
+        AccountMeta::new(stake_account_address(&keys.pool, owner, args.index), false),
+        AccountMeta::new(*owner, true),
+        AccountMeta::new(keys.stake_vault, false),
+        AccountMeta::new_readonly(keys.reward_vault, false),
+        AccountMeta::new_readonly(keys.stake_mint, false),
+        AccountMeta::new_readonly(keys.token_program, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        AccountMeta::new(keys.fee_vault, false),
+        AccountMeta::new(mint_registry_address(&keys.stake_mint), false),
+    ];
+    accounts.extend(receipt_metas(keys, args.receipt_account.as_ref()));
+    accounts.push(AccountMeta::new_readonly(
+        program_authority_address(),
+        false,
+    ));
+    accounts.push(allowlist_meta(&keys.pool, args.allowlist_page));
+    accounts.push(stake_position_meta(&keys.pool, owner, args.track_position));
+
+    build(
+        accounts,
+        StakePoolInstruction::StakeSol {
+            amount: args.amount,
+            index: args.index,
+            expected_reward_rate: args.expected_reward_rate,
+            expected_lockup_period: args.expected_lockup_period,
+            referrer: args.referrer,
+            auto_relock: args.auto_relock,
+            label: args.label,
+            allowlist_proof: args.allowlist_proof,
+        },
+    )
+}
+
+/// Unstake `amount` lamports from stake account `index` of a native SOL pool
+///
+/// `receipt_account` is required when the pool tokenizes positions.
+pub fn unstake_sol(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    index: u64,
+    amount: u64,
+    expected_reward_rate: Option<u64>,
+    receipt_account: Option<&Pubkey>,
+) -> Instruction {
+    let stake_account = stake_account_address(&keys.pool, owner, index);
+    let (unwrap_account, _) = StakeAccount::find_unwrap_pda(&stake_account);
+
+    let mut accounts = vec![
+        AccountMeta::new(keys.pool, false),
+        AccountMeta::new(stake_account, false),
+        AccountMeta::new(*owner, true),
+        AccountMeta::new(keys.stake_vault, false),
+        AccountMeta::new_readonly(keys.stake_mint, false),
+        AccountMeta::new_readonly(keys.token_program, false),
+        AccountMeta::new_readonly(sysvar::clock::ID, false),
+        AccountMeta::new(keys.fee_vault, false),
+        AccountMeta::new(unwrap_account, false),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        AccountMeta::new(mint_registry_address(&keys.stake_mint), false),
+    ];
+    accounts.extend(receipt_metas(keys, receipt_account));
+    accounts.push(AccountMeta::new_readonly(
+        program_authority_address(),
+        false,
+    ));
+
+    build(
+        accounts,
+        StakePoolInstruction::UnstakeSol {
+            amount,
+            expected_reward_rate,
+        },
+    )
+}
+
+/// Enable or disable auto re-lock on stake account `index`
+pub fn set_auto_relock(
+    pool: &Pubkey,
+    owner: &Pubkey,
+    index: u64,
+    auto_relock: bool,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(stake_account_address(pool, owner, index), false),
+            AccountMeta::new_readonly(*owner, true),
+        ],
+        StakePoolInstruction::SetAutoRelock { auto_relock },
+    )
+}
+
+/// Restart the expired lockup of `stake_account` (permissionless crank)
+pub fn relock(keys: &PoolKeys, stake_account: &Pubkey) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(keys.pool, false),
+            AccountMeta::new(*stake_account, false),
+            AccountMeta::new_readonly(keys.reward_vault, false),
+        ],
+        StakePoolInstruction::Relock,
+    )
+}
+
+/// Extend the lockup of stake account `index` by `additional_seconds` for the pool's
+/// lockup extension bonus
+pub fn extend_lockup(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    index: u64,
+    additional_seconds: i64,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(keys.pool, false),
+            AccountMeta::new(stake_account_address(&keys.pool, owner, index), false),
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new_readonly(keys.reward_vault, false),
+        ],
+        StakePoolInstruction::ExtendLockup { additional_seconds },
+    )
+}
+
+/// Edit page `page` of the pool's staker allowlist (global admin only)
+///
+/// `allowlist_required` toggles enforcement (None leaves it unchanged). `payer`
+/// funds the page when it does not exist yet.
+pub fn manage_pool_allowlist(
+    pool: &Pubkey,
+    admin: &Pubkey,
+    payer: &Pubkey,
+    page: u32,
+    add: Vec<Pubkey>,
+    remove: Vec<Pubkey>,
+    allowlist_required: Option<bool>,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(*pool, false),
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            AccountMeta::new(pool_allowlist_address(pool, page), false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        StakePoolInstruction::ManagePoolAllowlist {
+            page,
+            add,
+            remove,
+            allowlist_required,
+        },
+    )
+}
+
+/// Edit the allowlist of programs pools may call as hooks (global admin only)
+///
+/// `payer` funds the allowlist when it does not exist yet.
+pub fn manage_hook_allowlist(
+    admin: &Pubkey,
+    payer: &Pubkey,
+    add: Vec<Pubkey>,
+    remove: Vec<Pubkey>,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(hook_allowlist_address(), false),
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        StakePoolInstruction::ManageHookAllowlist { add, remove },
+    )
+}
+
+/// Edit the global deny list (global admin only)
+///
+/// `payer` funds the deny list when it does not exist yet. `allow_listed_withdrawals`
+/// sets whether listed owners may still withdraw principal to their own token
+/// accounts (None leaves it unchanged).
+pub fn manage_deny_list(
+    admin: &Pubkey,
+    payer: &Pubkey,
+    add: Vec<Pubkey>,
+    remove: Vec<Pubkey>,
+    allow_listed_withdrawals: Option<bool>,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(deny_list_address(), false),
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        StakePoolInstruction::ManageDenyList {
+            add,
+            remove,
+            allow_listed_withdrawals,
+        },
+    )
+}
+
+/// Move `amount` of `owner`'s stake account `index` into a new stake account `new_index`
+///
+/// `owner` signs and pays rent for the new stake account, whose index is also
+/// recorded in the owner's stake position directory if it exists.
+pub fn split_stake(
+    pool: &Pubkey,
+    owner: &Pubkey,
+    index: u64,
+    new_index: u64,
+    amount: u64,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(*pool, false),
+            AccountMeta::new(stake_account_address(pool, owner, index), false),
+            AccountMeta::new(stake_account_address(pool, owner, new_index), false),
+            AccountMeta::new(*owner, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            stake_position_meta(pool, owner, true),
+        ],
+        StakePoolInstruction::SplitStake { amount, new_index },
+    )
+}
+
+/// Merge `owner`'s stake account `source_index` into stake account `index`
+///
+/// The source account is closed and its rent returned to `owner`; its index is
+/// released from the owner's stake position directory if it exists.
+pub fn merge_stakes(pool: &Pubkey, owner: &Pubkey, index: u64, source_index: u64) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(*pool, false),
+            AccountMeta::new(stake_account_address(pool, owner, index), false),
+            AccountMeta::new(stake_account_address(pool, owner, source_index), false),
+            AccountMeta::new(*owner, true),
+            stake_position_meta(pool, owner, true),
+        ],
+        StakePoolInstruction::MergeStakes,
+    )
+}
+
+/// Set the program `pool` calls after stakes, unstakes and claims, or detach it
+/// with None (global admin only)
+pub fn set_pool_hook(pool: &Pubkey, admin: &Pubkey, hook_program: Option<Pubkey>) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(*pool, false),
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            AccountMeta::new_readonly(hook_allowlist_address(), false),
+        ],
+        StakePoolInstruction::SetPoolHook { hook_program },
+    )
+}
+
+/// Credit the points of `owner`'s stake `index` to their UserPoints (permissionless)
+///
+/// `payer` pays the rent of the owner's UserPoints on their first sync.
+pub fn sync_points(pool: &Pubkey, owner: &Pubkey, index: u64, payer: &Pubkey) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new_readonly(*pool, false),
+            AccountMeta::new(stake_account_address(pool, owner, index), false),
+            AccountMeta::new(user_points_address(pool, owner), false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        StakePoolInstruction::SyncPoints,
+    )
+}
+
+/// Query `owner`'s points in `pool`, including what their stakes at `indices`
+/// accrued since their last sync (for simulateTransaction)
+///
+/// The simulation's return data decodes as a `PointsBalance`.
+pub fn get_user_points(pool: &Pubkey, owner: &Pubkey, indices: &[u64]) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*pool, false),
+        AccountMeta::new_readonly(*owner, false),
+        AccountMeta::new_readonly(user_points_address(pool, owner), false),
+    ];
+    accounts.extend(
+        indices.iter().map(|&index| {
+            AccountMeta::new_readonly(stake_account_address(pool, owner, index), false)
+        }),
+    );
+
+    build(accounts, StakePoolInstruction::GetUserPoints)
+}
+
+/// Parameters of a vesting stake grant (see `StakePoolInstruction::VestingStake`)
+#[derive(Clone, Debug, Default)]
+pub struct VestingStakeArgs {
+    pub amount: u64,
+    /// Index of the beneficiary's new stake account
+    pub index: u64,
+    /// Seconds before any principal vests
+    pub vesting_cliff: i64,
+    /// Seconds until all principal has vested
+    pub vesting_duration: i64,
+    /// Record the index in the beneficiary's stake position directory
+    pub track_position: bool,
+}
+
+/// Stake from `admin_token_account` into a new stake account of `beneficiary`
+/// whose principal vests over `args.vesting_duration` (global admin only)
+pub fn vesting_stake(
+    keys: &PoolKeys,
+    beneficiary: &Pubkey,
+    admin: &Pubkey,
+    admin_token_account: &Pubkey,
+    payer: &Pubkey,
+    args: VestingStakeArgs,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(keys.pool, false),
+            AccountMeta::new(
+                stake_account_address(&keys.pool, beneficiary, args.index),
+                false,
+            ),
+            AccountMeta::new_readonly(*beneficiary, false),
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new(*admin_token_account, false),
+            AccountMeta::new(keys.stake_vault, false),
+            AccountMeta::new_readonly(keys.reward_vault, false),
+            AccountMeta::new_readonly(keys.stake_mint, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new(keys.fee_vault, false),
+            AccountMeta::new(mint_registry_address(&keys.stake_mint), false),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            stake_position_meta(&keys.pool, beneficiary, args.track_position),
+        ],
+        StakePoolInstruction::VestingStake {
+            amount: args.amount,
+            index: args.index,
+            vesting_cliff: args.vesting_cliff,
+            vesting_duration: args.vesting_duration,
+        },
+    )
+}
+
+/// Propose slashing `slash_bps` of `stake_account`'s principal into `treasury`,
+/// or the pool's reward vault when `None`; the admin pays the proposal's rent
+pub fn propose_slash(
+    pool: &Pubkey,
+    stake_account: &Pubkey,
+    admin: &Pubkey,
+    slash_bps: u16,
+    treasury: Option<Pubkey>,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new_readonly(*pool, false),
+            AccountMeta::new(*stake_account, false),
+            AccountMeta::new(slash_proposal_address(stake_account), false),
+            AccountMeta::new(*admin, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        StakePoolInstruction::ProposeSlash {
+            slash_bps,
+            treasury,
+        },
+    )
+}
+
+/// Execute the slash proposed for `stake_account`, sending the slashed tokens to
+/// the proposal's `treasury` (the reward vault when `None`) and its rent to `proposer`
+pub fn execute_slash(
+    keys: &PoolKeys,
+    stake_account: &Pubkey,
+    executor: &Pubkey,
+    proposer: &Pubkey,
+    treasury: Option<&Pubkey>,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(keys.pool, false),
+            AccountMeta::new(*stake_account, false),
+            AccountMeta::new(slash_proposal_address(stake_account), false),
+            AccountMeta::new_readonly(*executor, true),
+            AccountMeta::new(*proposer, false),
+            AccountMeta::new(keys.stake_vault, false),
+            AccountMeta::new(*treasury.unwrap_or(&keys.reward_vault), false),
+            AccountMeta::new_readonly(keys.stake_mint, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new(mint_registry_address(&keys.stake_mint), false),
+            AccountMeta::new_readonly(program_authority_address(), false),
+        ],
+        StakePoolInstruction::ExecuteSlash,
+    )
+}
+
+/// Cancel the slash proposed for `stake_account`, returning its rent to `proposer`
+pub fn cancel_slash(stake_account: &Pubkey, admin: &Pubkey, proposer: &Pubkey) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(*stake_account, false),
+            AccountMeta::new(slash_proposal_address(stake_account), false),
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new(*proposer, false),
+            AccountMeta::new_readonly(program_authority_address(), false),
+        ],
+        StakePoolInstruction::CancelSlash,
+    )
+}
+
+/// Check the pool's vault balances against its recorded totals (permissionless crank),
+/// paying the pool's keeper bounty to `keeper`
+pub fn sync_pool(keys: &PoolKeys, keeper: Option<&Pubkey>) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(keys.pool, false),
+            AccountMeta::new_readonly(keys.stake_vault, false),
+            AccountMeta::new_readonly(keys.reward_vault, false),
+            keeper_meta(keeper),
+        ],
+        StakePoolInstruction::SyncPool,
+    )
+}
+
+/// Close the program authority and send its rent to `receiver` (Devnet profile only)
+pub fn close_program_authority(authority: &Pubkey, receiver: &Pubkey) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(program_authority_address(), false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(*receiver, false),
+        ],
+        StakePoolInstruction::CloseProgramAuthority,
+    )
+}
+
+/// Replace the pool's reward funder allowlist; empty opens funding to anyone (global admin only)
+pub fn set_reward_funders(pool: &Pubkey, admin: &Pubkey, funders: Vec<Pubkey>) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(*pool, false),
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+        ],
+        StakePoolInstruction::SetRewardFunders { funders },
+    )
+}
+
+/// Withdraw un-owed reward vault surplus to `destination` (global admin or treasurer)
+pub fn defund_rewards(
+    keys: &PoolKeys,
+    admin: &Pubkey,
+    destination: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(keys.pool, false),
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            AccountMeta::new(keys.reward_vault, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(keys.reward_mint, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+        ],
+        StakePoolInstruction::DefundRewards { amount },
+    )
+}
+
+/// Fund the vault of reward cohort `cohort` from `funder_token_account`
+///
+/// The first funding of a cohort creates its accounts, paid for by `funder`.
+pub fn fund_cohort_rewards(
+    keys: &PoolKeys,
+    funder: &Pubkey,
+    funder_token_account: &Pubkey,
+    cohort: u64,
+    amount: u64,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(keys.pool, false),
+            AccountMeta::new(reward_cohort_address(&keys.pool, cohort), false),
+            AccountMeta::new(cohort_vault_address(&keys.pool, cohort), false),
+            AccountMeta::new(*funder, true),
+            AccountMeta::new(*funder_token_account, false),
+            AccountMeta::new_readonly(keys.reward_mint, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        StakePoolInstruction::FundCohortRewards { cohort, amount },
+    )
+}
+
+/// Withdraw un-owed tokens of reward cohort `cohort` to `destination` (global admin or treasurer)
+pub fn defund_cohort_rewards(
+    keys: &PoolKeys,
+    admin: &Pubkey,
+    destination: &Pubkey,
+    cohort: u64,
+    amount: u64,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(keys.pool, false),
+            AccountMeta::new(reward_cohort_address(&keys.pool, cohort), false),
+            AccountMeta::new(cohort_vault_address(&keys.pool, cohort), false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(keys.reward_mint, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new(*admin, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+        ],
+        StakePoolInstruction::DefundCohortRewards { amount },
+    )
+}
+
+/// Set the TVL cap of `stake_mint` across all its pools; None removes it (global admin only)
+pub fn set_mint_tvl_cap(stake_mint: &Pubkey, admin: &Pubkey, tvl_cap: Option<u64>) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(mint_registry_address(stake_mint), false),
+            AccountMeta::new_readonly(*stake_mint, false),
+            AccountMeta::new(*admin, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        StakePoolInstruction::SetMintTvlCap { tvl_cap },
+    )
+}
+
+/// Close a paused, empty pool, sweeping residual vault tokens to the receivers (global admin only)
+///
+/// `stake_receiver` takes the stake and fee vault leftovers and must hold the
+/// stake mint; `reward_receiver` must hold the reward mint.
+pub fn close_pool(
+    keys: &PoolKeys,
+    admin: &Pubkey,
+    stake_receiver: &Pubkey,
+    reward_receiver: &Pubkey,
+    rent_receiver: &Pubkey,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(keys.pool, false),
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            AccountMeta::new(keys.stake_vault, false),
+            AccountMeta::new(keys.reward_vault, false),
+            AccountMeta::new(keys.fee_vault, false),
+            AccountMeta::new_readonly(keys.stake_mint, false),
+            AccountMeta::new_readonly(keys.reward_mint, false),
+            AccountMeta::new(*stake_receiver, false),
+            AccountMeta::new(*reward_receiver, false),
+            AccountMeta::new(*rent_receiver, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+        ],
+        StakePoolInstruction::ClosePool,
+    )
+}
+
+/// Set or clear the delegate allowed to claim rewards of stake account `index`
+pub fn set_stake_delegate(
+    pool: &Pubkey,
+    owner: &Pubkey,
+    index: u64,
+    delegate: Option<Pubkey>,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(stake_account_address(pool, owner, index), false),
+            AccountMeta::new_readonly(*owner, true),
+        ],
+        StakePoolInstruction::SetStakeDelegate { delegate },
+    )
+}
+
+/// Set (or clear, with all zeros) the label of stake account `index`
+pub fn set_stake_label(pool: &Pubkey, owner: &Pubkey, index: u64, label: [u8; 32]) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(stake_account_address(pool, owner, index), false),
+            AccountMeta::new_readonly(*owner, true),
+        ],
+        StakePoolInstruction::SetStakeLabel { label },
+    )
+}
+
+/// Nominate `new_owner` to take over stake account `index` (None cancels the nomination)
+pub fn nominate_stake_owner(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    index: u64,
+    new_owner: Option<&Pubkey>,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(stake_account_address(&keys.pool, owner, index), false),
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new_readonly(keys.pool, false),
+            deny_list_meta(keys),
+        ],
+        StakePoolInstruction::NominateStakeOwner {
+            new_owner: new_owner.copied(),
+        },
+    )
+}
+
+/// Accept `previous_owner`'s stake account `index` as `new_owner`'s stake account `new_index`
+///
+/// `new_owner` signs and pays rent for the new stake account and, if it does not
+/// exist yet, its stake position directory, which records `new_index`. The
+/// previous account's rent goes back to `previous_owner`, whose directory
+/// releases `index` if it exists.
+pub fn accept_stake_ownership(
+    keys: &PoolKeys,
+    previous_owner: &Pubkey,
+    index: u64,
+    new_owner: &Pubkey,
+    new_index: u64,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new_readonly(keys.pool, false),
+            AccountMeta::new(
+                stake_account_address(&keys.pool, previous_owner, index),
+                false,
+            ),
+            AccountMeta::new(
+                stake_account_address(&keys.pool, new_owner, new_index),
+                false,
+            ),
+            AccountMeta::new(*new_owner, true),
+            AccountMeta::new(*previous_owner, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            stake_position_meta(&keys.pool, previous_owner, true),
+            stake_position_meta(&keys.pool, new_owner, true),
+            deny_list_meta(keys),
+        ],
+        StakePoolInstruction::AcceptStakeOwnership { new_index },
+    )
+}
+
+/// Create the pool's receipt mint so positions are tokenized (global admin only)
+pub fn enable_receipt_token(keys: &PoolKeys, admin: &Pubkey, transferable: bool) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(keys.pool, false),
+            AccountMeta::new(*admin, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            AccountMeta::new(StakePool::find_receipt_mint_pda(&keys.pool).0, false),
+            AccountMeta::new_readonly(keys.stake_mint, false),
+            AccountMeta::new_readonly(your_wallet_stake_pool::spl_token_2022::ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        StakePoolInstruction::EnableReceiptToken { transferable },
+    )
+}
+
+/// Move the unclaimed rewards of an abandoned stake account to the pool's expired rewards
+/// (permissionless crank)
+pub fn expire_rewards(keys: &PoolKeys, stake_account: &Pubkey) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(keys.pool, false),
+            AccountMeta::new(*stake_account, false),
+        ],
+        StakePoolInstruction::ExpireRewards,
+    )
+}
+
+/// Withdraw the pool's expired rewards into `destination` (global admin only)
+pub fn claim_expired_rewards(keys: &PoolKeys, admin: &Pubkey, destination: &Pubkey) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(keys.pool, false),
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            AccountMeta::new(keys.reward_vault, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(keys.reward_mint, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+        ],
+        StakePoolInstruction::ClaimExpiredRewards,
+    )
+}
+
+/// Start the unstake cooldown for `amount` of stake account `index`
+///
+/// `receipt_account` is required when the pool tokenizes positions.
+pub fn request_unstake(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    index: u64,
+    amount: u64,
+    expected_reward_rate: Option<u64>,
+    receipt_account: Option<&Pubkey>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(keys.pool, false),
+        AccountMeta::new(stake_account_address(&keys.pool, owner, index), false),
+        AccountMeta::new_readonly(*owner, true),
+    ];
+    accounts.extend(receipt_metas(keys, receipt_account));
+    accounts.push(AccountMeta::new_readonly(
+        program_authority_address(),
+        false,
+    ));
+
+    build(
+        accounts,
+        StakePoolInstruction::RequestUnstake {
+            amount,
+            expected_reward_rate,
+        },
+    )
+}
+
+/// Withdraw the pending unstake of stake account `index` into `user_token_account`
+/// once the pool's cooldown has passed
+pub fn withdraw_unstaked(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    index: u64,
+    user_token_account: &Pubkey,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(keys.pool, false),
+            AccountMeta::new(stake_account_address(&keys.pool, owner, index), false),
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new(*user_token_account, false),
+            AccountMeta::new(keys.stake_vault, false),
+            AccountMeta::new_readonly(keys.stake_mint, false),
+            AccountMeta::new_readonly(keys.token_program, false),
+            AccountMeta::new(keys.fee_vault, false),
+            AccountMeta::new(mint_registry_address(&keys.stake_mint), false),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            deny_list_meta(keys),
+        ],
+        StakePoolInstruction::WithdrawUnstaked,
+    )
+}
+
+/// Set the protocol-wide pause switches (global admin or pauser)
+pub fn set_global_pause(admin: &Pubkey, global_pause: bool, withdrawals_only: bool) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(program_authority_address(), false),
+            AccountMeta::new_readonly(*admin, true),
+        ],
+        StakePoolInstruction::SetGlobalPause {
+            global_pause,
+            withdrawals_only,
+        },
+    )
+}
+
+/// Upgrade a V1 pool and the given V1 stake accounts to the current layout (global admin only)
+///
+/// `keys.fee_vault` is recorded as the pool's fee vault. Stake accounts can be
+/// spread over several calls; accounts that are already migrated are skipped.
+pub fn migrate_pool(keys: &PoolKeys, admin: &Pubkey, stake_accounts: &[Pubkey]) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(keys.pool, false),
+        AccountMeta::new(*admin, true),
+        AccountMeta::new_readonly(program_authority_address(), false),
+        AccountMeta::new_readonly(keys.fee_vault, false),
+        AccountMeta::new(mint_registry_address(&keys.stake_mint), false),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+    ];
+    accounts.extend(
+        stake_accounts
+            .iter()
+            .map(|stake_account| AccountMeta::new(*stake_account, false)),
+    );
+
+    build(accounts, StakePoolInstruction::MigratePool)
+}
+
+/// Migrate a program authority holding the fixed creator array to the creator list
+pub fn migrate_program_authority(authority: &Pubkey, payer: &Pubkey) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(program_authority_address(), false),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        StakePoolInstruction::MigrateProgramAuthority,
+    )
+}
+
+/// Grow `pool`'s account to `new_len` bytes ahead of a larger layout, `admin` paying the rent
+pub fn resize_pool(pool: &Pubkey, admin: &Pubkey, new_len: u32) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(*pool, false),
+            AccountMeta::new(*admin, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        StakePoolInstruction::ResizePool { new_len },
+    )
+}
+
+/// Set how many global admins must approve reward rate, pause and end date changes
+/// (0 or 1 disables the approval flow)
+pub fn set_approval_threshold(authority: &Pubkey, threshold: u8) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(program_authority_address(), false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        StakePoolInstruction::SetApprovalThreshold { threshold },
+    )
+}
+
+/// Propose `update` to `pool` as pending action `action_id`; the proposer pays its rent
+pub fn propose_pool_update(
+    pool: &Pubkey,
+    proposer: &Pubkey,
+    action_id: u64,
+    update: SensitivePoolUpdate,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new_readonly(*pool, false),
+            AccountMeta::new(pending_action_address(pool, action_id), false),
+            AccountMeta::new(*proposer, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        StakePoolInstruction::ProposePoolUpdate { action_id, update },
+    )
+}
+
+/// Approve pending action `action_id` of `pool`
+pub fn approve_pool_update(pool: &Pubkey, approver: &Pubkey, action_id: u64) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(pending_action_address(pool, action_id), false),
+            AccountMeta::new_readonly(*approver, true),
+            AccountMeta::new_readonly(program_authority_address(), false),
+        ],
+        StakePoolInstruction::ApprovePoolUpdate,
+    )
+}
+
+/// Apply pending action `action_id` of `pool`, returning its rent to `proposer`
+pub fn execute_pool_update(
+    pool: &Pubkey,
+    executor: &Pubkey,
+    proposer: &Pubkey,
+    action_id: u64,
+) -> Instruction {
+    build(
+        vec![
+            AccountMeta::new(*pool, false),
+            AccountMeta::new(pending_action_address(pool, action_id), false),
+            AccountMeta::new_readonly(*executor, true),
+            AccountMeta::new(*proposer, false),
+            AccountMeta::new_readonly(program_authority_address(), false),
+            AccountMeta::new_readonly(program_config_address(), false),
+        ],
+        StakePoolInstruction::ExecutePoolUpdate,
+    )
+}
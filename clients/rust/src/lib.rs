@@ -0,0 +1,138 @@
+//! Rust client for the YourWallet stake pool program
+//!
+//! Provides typed builders for every `StakePoolInstruction` variant. Builders
+//! derive program PDAs (stake accounts, program authority, unwrap accounts)
+//! internally and return instructions with correctly ordered account metas.
+//! The `accounts` module builds `getProgramAccounts` filters for finding pools
+//! and stake accounts and resolves the ordered accounts of a stake position,
+//! `return_data` decodes what view instructions return
+//! from `simulateTransaction`, `errors` maps failure codes to `StakePoolError`
+//! and `merkle` builds allowlist roots and proofs. With the `test-utils`
+//! feature, `test_utils` sets up pools and accounts in LiteSVM.
+//!
+//! # Example
+//! ```ignore
+//! use your_wallet_stake_pool_client::{instruction, PoolKeys};
+//!
+//! let keys = PoolKeys::with_pda_vaults(stake_mint, reward_mint, 0, spl_token_2022::ID);
+//! let ix = instruction::stake(
+//!     &keys,
+//!     &owner,
+//!     &user_token_account,
+//!     &payer,
+//!     instruction::StakeArgs::new(1_000_000, 0),
+//! );
+//! ```
+
+pub mod accounts;
+pub mod errors;
+pub mod events;
+pub mod instruction;
+pub mod merkle;
+pub mod return_data;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+
+use solana_program::pubkey::Pubkey;
+use your_wallet_stake_pool::state::StakePool;
+
+pub use your_wallet_stake_pool::{self as program, ID};
+
+/// Addresses of a pool and the accounts it was initialized with
+///
+/// Every pool instruction needs the same handful of addresses; build this once
+/// per pool and pass it to the instruction builders.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PoolKeys {
+    pub pool: Pubkey,
+    pub stake_mint: Pubkey,
+    pub reward_mint: Pubkey,
+    pub stake_vault: Pubkey,
+    pub reward_vault: Pubkey,
+    pub fee_vault: Pubkey,
+    /// Token program owning the stake mint (Token or Token-2022)
+    pub token_program: Pubkey,
+    /// Receipt mint of a pool that tokenizes positions (see `EnableReceiptToken`)
+    pub receipt_mint: Option<Pubkey>,
+    /// Hook program of a pool that calls one after stakes, unstakes and claims (see `SetPoolHook`)
+    pub hook_program: Option<Pubkey>,
+    /// Whether the pool checks the global deny list (see `deny_list_required`)
+    pub deny_list_required: bool,
+}
+
+impl PoolKeys {
+    /// Keys of a pool whose vaults live at their program-derived addresses
+    ///
+    /// Set `receipt_mint` with `with_receipt_mint` if the pool tokenizes positions.
+    pub fn with_pda_vaults(
+        stake_mint: Pubkey,
+        reward_mint: Pubkey,
+        pool_id: u64,
+        token_program: Pubkey,
+    ) -> Self {
+        let (pool, _) = StakePool::find_pda(&stake_mint, pool_id);
+        let (stake_vault, _) = StakePool::find_stake_vault_pda(&pool);
+        let (reward_vault, _) = StakePool::find_reward_vault_pda(&pool);
+        let (fee_vault, _) = StakePool::find_fee_vault_pda(&pool);
+
+        Self {
+            pool,
+            stake_mint,
+            reward_mint,
+            stake_vault,
+            reward_vault,
+            fee_vault,
+            token_program,
+            receipt_mint: None,
+            hook_program: None,
+            deny_list_required: false,
+        }
+    }
+
+    /// The same keys for a pool that tokenizes positions with its receipt mint PDA
+    pub fn with_receipt_mint(self) -> Self {
+        let (receipt_mint, _) = StakePool::find_receipt_mint_pda(&self.pool);
+        Self {
+            receipt_mint: Some(receipt_mint),
+            ..self
+        }
+    }
+
+    /// The same keys for a pool that calls `hook_program` after stakes, unstakes and claims
+    ///
+    /// Accounts the hook itself needs are appended to the built `stake`, `unstake`
+    /// and `claim_rewards` instructions by the caller.
+    pub fn with_hook_program(self, hook_program: Pubkey) -> Self {
+        Self {
+            hook_program: Some(hook_program),
+            ..self
+        }
+    }
+
+    /// The same keys for a pool checking the global deny list
+    ///
+    /// The built `stake`, `unstake`, `withdraw_unstaked` and `claim_rewards`
+    /// instructions then pass the deny list PDA.
+    pub fn with_deny_list(self) -> Self {
+        Self {
+            deny_list_required: true,
+            ..self
+        }
+    }
+
+    /// Keys of an existing pool, read from its deserialized account data
+    pub fn from_state(pool: Pubkey, state: &StakePool, token_program: Pubkey) -> Self {
+        Self {
+            pool,
+            stake_mint: state.stake_mint,
+            reward_mint: state.reward_mint,
+            stake_vault: state.stake_vault,
+            reward_vault: state.reward_vault,
+            fee_vault: state.fee_vault,
+            token_program,
+            receipt_mint: state.receipt_mint,
+            hook_program: state.hook_program,
+            deny_list_required: state.deny_list_required,
+        }
+    }
+}
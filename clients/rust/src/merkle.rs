@@ -0,0 +1,52 @@
+//! Merkle allowlist trees
+//!
+//! Builds the root a pool stores in `allowlist_merkle_root` and the proofs
+//! stakers pass as `allowlist_proof`, hashing like the program's `merkle`
+//! module. Leaves are sorted and deduplicated, and a node left without a
+//! sibling is promoted to the next level unchanged.
+
+use solana_program::pubkey::Pubkey;
+use your_wallet_stake_pool::merkle::{leaf_hash, node_hash};
+
+/// Levels of the tree over `owners`, from the sorted leaves up to the root
+fn tree_levels(owners: &[Pubkey]) -> Vec<Vec<[u8; 32]>> {
+    let mut leaves: Vec<[u8; 32]> = owners.iter().map(leaf_hash).collect();
+    leaves.sort_unstable();
+    leaves.dedup();
+
+    let mut levels = vec![leaves];
+    while levels.last().is_some_and(|level| level.len() > 1) {
+        let next = levels
+            .last()
+            .unwrap()
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => node_hash(a, b),
+                [a] => *a,
+                _ => unreachable!(),
+            })
+            .collect();
+        levels.push(next);
+    }
+    levels
+}
+
+/// Root of the allowlist tree over `owners`, None when empty
+pub fn allowlist_merkle_root(owners: &[Pubkey]) -> Option<[u8; 32]> {
+    tree_levels(owners).last()?.first().copied()
+}
+
+/// Proof that `owner` is in the allowlist tree over `owners`, None when it is not
+pub fn allowlist_merkle_proof(owners: &[Pubkey], owner: &Pubkey) -> Option<Vec<[u8; 32]>> {
+    let levels = tree_levels(owners);
+    let mut position = levels[0].binary_search(&leaf_hash(owner)).ok()?;
+
+    let mut proof = Vec::new();
+    for level in &levels[..levels.len() - 1] {
+        if let Some(sibling) = level.get(position ^ 1) {
+            proof.push(*sibling);
+        }
+        position /= 2;
+    }
+    Some(proof)
+}
@@ -0,0 +1,43 @@
+//! Decoding of the data view instructions return
+//!
+//! `GetAuthorizedCreators`, `CheckAuthorization`, `GetPendingRewards`,
+//! `GetPoolStats`, `GetStakePosition`, `GetUserPoints`, `AuditPool` and `ClaimRewards` publish a Borsh-encoded response with `set_return_data`.
+//! Simulation results report it as `returnData: { programId, data: [base64,
+//! "base64"] }`, with trailing zero bytes stripped by the runtime; the decoders
+//! here restore them before deserializing.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use borsh::BorshDeserialize;
+use solana_program::{program::MAX_RETURN_DATA, pubkey::Pubkey};
+
+use crate::ID;
+
+pub use your_wallet_stake_pool::return_data::{
+    AuthorizationCheck, AuthorizedCreators, ClaimRewardsResult, PendingRewards, PointsBalance,
+    PoolAudit, PoolStats, StakePositions,
+};
+
+/// Decode return data set by this program
+///
+/// Returns `None` if `program_id` is another program (the last program to set
+/// return data wins) or the bytes are not a `T`.
+pub fn decode_return_data<T: BorshDeserialize>(program_id: &Pubkey, data: &[u8]) -> Option<T> {
+    if program_id != &ID || data.len() > MAX_RETURN_DATA {
+        return None;
+    }
+
+    // Put back the trailing zeros the runtime stripped; unread padding is ignored
+    let mut padded = data.to_vec();
+    padded.resize(MAX_RETURN_DATA, 0);
+    T::deserialize(&mut padded.as_slice()).ok()
+}
+
+/// Decode the `returnData` of a simulation result (program id and base64 data as reported by RPC)
+pub fn decode_simulation_return_data<T: BorshDeserialize>(
+    program_id: &str,
+    base64: &str,
+) -> Option<T> {
+    let program_id = program_id.parse::<Pubkey>().ok()?;
+    let data = STANDARD.decode(base64.trim()).ok()?;
+    decode_return_data(&program_id, &data)
+}
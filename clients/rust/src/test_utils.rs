@@ -0,0 +1,344 @@
+//! LiteSVM harness for testing against the stake pool program (`test-utils` feature)
+//!
+//! Integrators exercising the program from their own LiteSVM tests, typically
+//! to check CPIs into it, need the same setup the program's tests use: the
+//! program loaded, a program authority, mints, funded token accounts, a pool
+//! with its vaults and a controllable clock. The helpers here build those with
+//! the crate's instruction builders and panic with the transaction logs when a
+//! setup step fails.
+//!
+//! LiteSVM ships Token-2022, so only the program binary has to be provided
+//! (`cargo build-sbf` writes it to `target/deploy/your_wallet_stake_pool.so`).
+//!
+//! # Example
+//! ```ignore
+//! use your_wallet_stake_pool_client::{instruction, test_utils};
+//!
+//! let mut svm = LiteSVM::new();
+//! test_utils::add_program(&mut svm, "target/deploy/your_wallet_stake_pool.so");
+//! let authority = test_utils::funded_keypair(&mut svm);
+//! let keys = test_utils::setup_pool(&mut svm, &authority, Default::default());
+//! test_utils::fund_rewards(&mut svm, &authority, &keys, 1_000_000_000);
+//! test_utils::warp_forward(&mut svm, 86_400);
+//! ```
+
+#![allow(deprecated)]
+
+use std::path::Path;
+
+use litesvm::{
+    types::{FailedTransactionMetadata, TransactionMetadata},
+    LiteSVM,
+};
+use solana_program::{clock::Clock, program_pack::Pack, pubkey::Pubkey};
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+use spl_token_2022::{
+    instruction as token_instruction,
+    state::{Account as TokenAccount, Mint},
+};
+use your_wallet_stake_pool::state::{ClusterProfile, StakeAccount, StakePool};
+
+use crate::errors::{self, StakePoolError};
+use crate::instruction::{self, InitializePoolArgs};
+use crate::{PoolKeys, ID};
+
+/// Lamports `funded_keypair` airdrops
+pub const AIRDROP_LAMPORTS: u64 = 10_000_000_000;
+
+/// Decimals of the mints `setup_pool` creates
+pub const MINT_DECIMALS: u8 = 6;
+
+/// Load the program binary at `path` into `svm` under the program ID
+pub fn add_program(svm: &mut LiteSVM, path: impl AsRef<Path>) {
+    let path = path.as_ref();
+    svm.add_program_from_file(ID, path).unwrap_or_else(|e| {
+        panic!(
+            "Failed to load the stake pool program from {}: {:?}. Build it with `cargo build-sbf` first.",
+            path.display(),
+            e
+        )
+    });
+}
+
+/// A new keypair holding `AIRDROP_LAMPORTS`
+pub fn funded_keypair(svm: &mut LiteSVM) -> Keypair {
+    let keypair = Keypair::new();
+    svm.airdrop(&keypair.pubkey(), AIRDROP_LAMPORTS)
+        .unwrap_or_else(|e| panic!("Airdrop failed: {:?}", e.err));
+    keypair
+}
+
+/// Send `instructions` paid by `payer`, returning the failure for tests that expect one
+///
+/// The blockhash is expired afterwards so identical transactions can be sent again.
+#[allow(clippy::result_large_err)]
+pub fn try_send(
+    svm: &mut LiteSVM,
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+) -> Result<TransactionMetadata, FailedTransactionMetadata> {
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&payer.pubkey()),
+        &all_signers,
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx);
+    svm.expire_blockhash();
+    result
+}
+
+/// Send `instructions` paid by `payer`, panicking with the logs if they fail
+pub fn send(
+    svm: &mut LiteSVM,
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+) -> TransactionMetadata {
+    try_send(svm, instructions, payer, signers)
+        .unwrap_or_else(|e| panic!("Transaction failed: {:?}\n{:#?}", e.err, e.meta.logs))
+}
+
+/// Send `instructions` expecting them to fail, returning the stake pool error they failed with
+///
+/// None if the failure did not come from this program (a token program error, say).
+pub fn send_expecting_error(
+    svm: &mut LiteSVM,
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+) -> Option<StakePoolError> {
+    match try_send(svm, instructions, payer, signers) {
+        Ok(meta) => panic!("Transaction unexpectedly succeeded\n{:#?}", meta.logs),
+        Err(failure) => errors::from_logs(&failure.meta.logs),
+    }
+}
+
+// ============================================================================
+// Clock
+// ============================================================================
+
+/// The clock's current unix timestamp
+pub fn now(svm: &LiteSVM) -> i64 {
+    svm.get_sysvar::<Clock>().unix_timestamp
+}
+
+/// Set the clock's unix timestamp (lockups, cooldowns and time-locks read it)
+pub fn warp_to(svm: &mut LiteSVM, unix_timestamp: i64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = unix_timestamp;
+    svm.set_sysvar(&clock);
+}
+
+/// Move the clock's unix timestamp `seconds` ahead
+pub fn warp_forward(svm: &mut LiteSVM, seconds: i64) {
+    warp_to(svm, now(svm) + seconds);
+}
+
+/// Unix timestamp at which a stake account's lockup completes
+///
+/// Reads the lockup from the pool, so extensions and relocks are accounted for.
+pub fn lockup_end(svm: &LiteSVM, stake_account: &Pubkey) -> i64 {
+    let stake = load_stake_account(svm, stake_account);
+    let pool = load_stake_pool(svm, &stake.pool);
+    pool.stake_maturity(&stake)
+        .unwrap_or_else(|e| panic!("Invalid lockup of stake account {}: {}", stake_account, e))
+}
+
+/// Set the clock to the moment a stake account's lockup completes
+pub fn warp_past_lockup(svm: &mut LiteSVM, stake_account: &Pubkey) {
+    let end = lockup_end(svm, stake_account);
+    warp_to(svm, end);
+}
+
+// ============================================================================
+// Tokens
+// ============================================================================
+
+/// Create a Token-2022 mint with `authority` as its mint authority
+pub fn create_mint(svm: &mut LiteSVM, authority: &Keypair, decimals: u8) -> Pubkey {
+    let mint = Keypair::new();
+    let rent = svm.minimum_balance_for_rent_exemption(Mint::LEN);
+    let instructions = [
+        system_instruction::create_account(
+            &authority.pubkey(),
+            &mint.pubkey(),
+            rent,
+            Mint::LEN as u64,
+            &spl_token_2022::id(),
+        ),
+        token_instruction::initialize_mint(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            &authority.pubkey(),
+            None,
+            decimals,
+        )
+        .unwrap(),
+    ];
+    send(svm, &instructions, authority, &[&mint]);
+    mint.pubkey()
+}
+
+/// Create a token account of `mint` for `owner` holding `amount` newly minted tokens
+///
+/// `mint_authority` pays for the account and mints the tokens.
+pub fn create_token_account(
+    svm: &mut LiteSVM,
+    mint_authority: &Keypair,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let token_account = Keypair::new();
+    let rent = svm.minimum_balance_for_rent_exemption(TokenAccount::LEN);
+    let mut instructions = vec![
+        system_instruction::create_account(
+            &mint_authority.pubkey(),
+            &token_account.pubkey(),
+            rent,
+            TokenAccount::LEN as u64,
+            &spl_token_2022::id(),
+        ),
+        token_instruction::initialize_account(
+            &spl_token_2022::id(),
+            &token_account.pubkey(),
+            mint,
+            owner,
+        )
+        .unwrap(),
+    ];
+    if amount > 0 {
+        instructions.push(
+            token_instruction::mint_to(
+                &spl_token_2022::id(),
+                mint,
+                &token_account.pubkey(),
+                &mint_authority.pubkey(),
+                &[],
+                amount,
+            )
+            .unwrap(),
+        );
+    }
+    send(svm, &instructions, mint_authority, &[&token_account]);
+    token_account.pubkey()
+}
+
+/// Balance of a token account (0 if it does not exist)
+pub fn token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    svm.get_account(token_account)
+        .and_then(|account| TokenAccount::unpack_from_slice(&account.data).ok())
+        .map_or(0, |account| account.amount)
+}
+
+// ============================================================================
+// Program Setup
+// ============================================================================
+
+/// Initialize the program authority with `authority` as the main authority
+///
+/// Every pool creation needs it; `authority` is always an authorized creator.
+pub fn initialize_program_authority(
+    svm: &mut LiteSVM,
+    authority: &Keypair,
+    cluster_profile: ClusterProfile,
+) -> Pubkey {
+    send(
+        svm,
+        &[instruction::initialize_program_authority(
+            &authority.pubkey(),
+            &authority.pubkey(),
+            cluster_profile,
+        )],
+        authority,
+        &[],
+    );
+    instruction::program_authority_address()
+}
+
+/// Create a pool on new Token-2022 stake and reward mints, both minted by `authority`
+///
+/// Initializes the program authority (Devnet profile, so time-locks stay short)
+/// if it does not exist yet; `authority` must be its main authority or an
+/// authorized creator otherwise. The pool's vaults live at their PDAs.
+pub fn setup_pool(svm: &mut LiteSVM, authority: &Keypair, args: InitializePoolArgs) -> PoolKeys {
+    if svm
+        .get_account(&instruction::program_authority_address())
+        .is_none()
+    {
+        initialize_program_authority(svm, authority, ClusterProfile::Devnet);
+    }
+
+    let stake_mint = create_mint(svm, authority, MINT_DECIMALS);
+    let reward_mint = create_mint(svm, authority, MINT_DECIMALS);
+    let keys =
+        PoolKeys::with_pda_vaults(stake_mint, reward_mint, args.pool_id, spl_token_2022::id());
+    send(
+        svm,
+        &[instruction::initialize_pool(
+            &keys,
+            &authority.pubkey(),
+            args,
+        )],
+        authority,
+        &[],
+    );
+    keys
+}
+
+/// Mint `amount` reward tokens to `mint_authority` and fund the pool's reward vault with them
+pub fn fund_rewards(svm: &mut LiteSVM, mint_authority: &Keypair, keys: &PoolKeys, amount: u64) {
+    let funder_account = create_token_account(
+        svm,
+        mint_authority,
+        &keys.reward_mint,
+        &mint_authority.pubkey(),
+        amount,
+    );
+    send(
+        svm,
+        &[instruction::fund_rewards(
+            keys,
+            &mint_authority.pubkey(),
+            &funder_account,
+            amount,
+        )],
+        mint_authority,
+        &[],
+    );
+}
+
+// ============================================================================
+// Account Reads
+// ============================================================================
+
+/// Read a pool account, panicking if it is missing or not a pool
+pub fn load_stake_pool(svm: &LiteSVM, pool: &Pubkey) -> StakePool {
+    let account = svm
+        .get_account(pool)
+        .unwrap_or_else(|| panic!("Pool account {} does not exist", pool));
+    StakePool::from_account_data(&account.data)
+        .unwrap_or_else(|e| panic!("Failed to deserialize pool {}: {}", pool, e))
+}
+
+/// Read a stake account, panicking if it is missing or not a stake account
+pub fn load_stake_account(svm: &LiteSVM, stake_account: &Pubkey) -> StakeAccount {
+    let account = svm
+        .get_account(stake_account)
+        .unwrap_or_else(|| panic!("Stake account {} does not exist", stake_account));
+    StakeAccount::from_account_data(&account.data).unwrap_or_else(|e| {
+        panic!(
+            "Failed to deserialize stake account {}: {}",
+            stake_account, e
+        )
+    })
+}
@@ -0,0 +1,289 @@
+// ============================================================================
+// Account Filter Tests
+// ============================================================================
+// The published offsets must match the Borsh layouts of StakeAccount and
+// StakePool, and the filter builders must select exactly the intended accounts.
+// `resolve` must give the same account lists as the instruction builders.
+
+use solana_program::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    spl_token_2022,
+    state::{
+        Key, OutflowWindow, RewardRateMode, StakeAccount, StakePool, WindDownRewards,
+        DISCRIMINATOR_LEN,
+    },
+};
+use your_wallet_stake_pool_client::{
+    accounts::{self, stake_account, stake_pool, AccountFilter},
+    instruction::{self, StakeArgs},
+    PoolKeys,
+};
+
+fn stake_account_data(pool: Pubkey, owner: Pubkey, index: u64) -> Vec<u8> {
+    let stake = StakeAccount {
+        key: Key::StakeAccountV2,
+        pool,
+        owner,
+        index,
+        amount_staked: 1_000_000,
+        stake_timestamp: 1_700_000_000,
+        claimed_rewards: 0,
+        bump: 255,
+        referrer: None,
+        early_unstaked: 0,
+        auto_relock: false,
+        carried_rewards: 0,
+        delegate: None,
+        pending_withdraw_amount: 0,
+        request_timestamp: None,
+        locked_reward_rate: 100_000_000,
+        label: [0; StakeAccount::LABEL_LEN],
+        pending_owner: None,
+        lockup_extension: 0,
+        vesting_amount: 0,
+        vesting_start: 0,
+        vesting_cliff: 0,
+        vesting_duration: 0,
+        pending_slash_bps: 0,
+        points_synced_at: 0,
+        reward_cohort: 0,
+    };
+    // Accounts are allocated at LEN; unused Option space is zero-padded
+    let mut data = stake.to_account_data().unwrap();
+    data.resize(StakeAccount::LEN, 0);
+    data
+}
+
+fn pool_data(stake_mint: Pubkey, pool_id: u64) -> Vec<u8> {
+    let pool = StakePool {
+        key: Key::StakePoolV2,
+        stake_mint,
+        reward_mint: Pubkey::new_unique(),
+        pool_id,
+        stake_vault: Pubkey::new_unique(),
+        reward_vault: Pubkey::new_unique(),
+        total_staked: 0,
+        total_rewards_owed: 0,
+        reward_rate: 100_000_000,
+        min_stake_amount: 0,
+        lockup_period: 86400,
+        is_paused: false,
+        enforce_lockup: false,
+        bump: 255,
+        pool_end_date: None,
+        pending_reward_rate: None,
+        reward_rate_change_timestamp: None,
+        last_rate_change: None,
+        referral_bps: 0,
+        early_unstake_bps: 0,
+        stake_vault_bump: None,
+        reward_vault_bump: None,
+        fee_vault: Pubkey::new_unique(),
+        deposit_fee_bps: 0,
+        withdraw_fee_bps: 0,
+        fee_vault_bump: None,
+        total_rewards_funded: 0,
+        reward_funders: [None; StakePool::MAX_REWARD_FUNDERS],
+        receipt_mint: None,
+        expired_rewards: 0,
+        unstake_cooldown: 0,
+        is_registered: true,
+        reward_rate_change_delay: 0,
+        pending_lockup_period: None,
+        pending_enforce_lockup: None,
+        lockup_change_timestamp: None,
+        min_reward_rate_bound: None,
+        max_reward_rate_bound: None,
+        active_stake_count: None,
+        reward_tiers: [None; StakePool::MAX_REWARD_TIERS],
+        bonus_end_ts: None,
+        bonus_multiplier_bps: 0,
+        lockup_extension_rate: 0,
+        allowlist_required: false,
+        allowlist_merkle_root: None,
+        claim_window: None,
+        outflow_limit: None,
+        outflow_window: OutflowWindow::default(),
+        pool_manager: None,
+        keeper_bounty: 0,
+        last_keeper_bounty_at: 0,
+        max_total_rewards: None,
+        total_rewards_committed: 0,
+        reward_rate_mode: RewardRateMode::PerLockup,
+        reward_decimals_shift: 0,
+        enforce_min_remaining_stake: false,
+        wind_down_rewards: WindDownRewards::Expire,
+        hook_program: None,
+        points_rate: 0,
+        deny_list_required: false,
+        allow_third_party_stake: false,
+        event_nonce: 0,
+        cohort_duration: 0,
+        open_reward_cohorts: 0,
+        _reserved: [0; 2],
+    };
+    let mut data = pool.to_account_data().unwrap();
+    data.resize(StakePool::LEN, 0);
+    data
+}
+
+fn matches_all(filters: &[AccountFilter], data: &[u8]) -> bool {
+    filters.iter().all(|filter| filter.matches(data))
+}
+
+#[test]
+fn test_offsets_match_borsh_layout() {
+    let pool = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let data = stake_account_data(pool, owner, 7);
+
+    assert_eq!(&data[stake_account::POOL..][..32], pool.as_ref());
+    assert_eq!(&data[stake_account::OWNER..][..32], owner.as_ref());
+    assert_eq!(&data[stake_account::INDEX..][..8], &7u64.to_le_bytes());
+
+    let stake_mint = Pubkey::new_unique();
+    let data = pool_data(stake_mint, 3);
+
+    assert_eq!(&data[stake_pool::STAKE_MINT..][..32], stake_mint.as_ref());
+    assert_eq!(&data[stake_pool::POOL_ID..][..8], &3u64.to_le_bytes());
+}
+
+#[test]
+fn test_stake_account_filters_select_owner_and_pool() {
+    let pool = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mine = stake_account_data(pool, owner, 0);
+    let other_owner = stake_account_data(pool, Pubkey::new_unique(), 0);
+    let other_pool = stake_account_data(Pubkey::new_unique(), owner, 0);
+
+    let by_owner = accounts::stake_accounts_by_owner(&owner);
+    assert!(matches_all(&by_owner, &mine));
+    assert!(matches_all(&by_owner, &other_pool));
+    assert!(!matches_all(&by_owner, &other_owner));
+
+    let by_pool = accounts::stake_accounts_by_pool(&pool);
+    assert!(matches_all(&by_pool, &mine));
+    assert!(matches_all(&by_pool, &other_owner));
+    assert!(!matches_all(&by_pool, &other_pool));
+
+    let by_both = accounts::stake_accounts_by_pool_and_owner(&pool, &owner);
+    assert!(matches_all(&by_both, &mine));
+    assert!(!matches_all(&by_both, &other_owner));
+    assert!(!matches_all(&by_both, &other_pool));
+}
+
+#[test]
+fn test_pool_filters_select_stake_mint() {
+    let stake_mint = Pubkey::new_unique();
+    let pool = pool_data(stake_mint, 0);
+    let other = pool_data(Pubkey::new_unique(), 0);
+
+    let by_mint = accounts::pools_by_stake_mint(&stake_mint);
+    assert!(matches_all(&by_mint, &pool));
+    assert!(!matches_all(&by_mint, &other));
+    assert!(matches_all(&accounts::pools(), &other));
+}
+
+#[test]
+fn test_filters_reject_other_account_types() {
+    let key = Pubkey::new_unique();
+    let stake = stake_account_data(key, key, 0);
+    let pool = pool_data(key, 0);
+
+    // A pool's stake_mint sits where a stake account's pool does
+    assert!(!matches_all(&accounts::stake_accounts_by_pool(&key), &pool));
+    assert!(!matches_all(&accounts::pools_by_stake_mint(&key), &stake));
+
+    // Accounts written before the discriminator are not selected until migrated
+    let unprefixed = stake[DISCRIMINATOR_LEN..].to_vec();
+    assert!(!matches_all(
+        &accounts::stake_accounts_by_pool(&key),
+        &unprefixed
+    ));
+
+    // Neither are legacy V1 accounts
+    let mut legacy = unprefixed.clone();
+    legacy[0] = Key::StakeAccountV1 as u8;
+    assert!(!matches_all(
+        &accounts::stake_accounts_by_pool(&key),
+        &legacy
+    ));
+
+    // Accounts grown by a later layout change are still selected
+    let mut grown = stake.clone();
+    grown.resize(StakeAccount::LEN + 32, 0);
+    assert!(matches_all(&accounts::stake_accounts_by_pool(&key), &grown));
+}
+
+#[test]
+fn test_memcmp_bytes_are_base64_encoded() {
+    let owner = Pubkey::new_unique();
+    let filters = accounts::stake_accounts_by_owner(&owner);
+
+    assert_eq!(filters.len(), 2);
+    assert_eq!(filters[0].encoded_bytes().as_deref(), Some("UJ5DfDK9wP8G"));
+    assert_eq!(AccountFilter::DataSize(1).encoded_bytes(), None);
+    assert_eq!(
+        filters[1],
+        AccountFilter::Memcmp {
+            offset: stake_account::OWNER,
+            bytes: owner.to_bytes().to_vec(),
+        }
+    );
+}
+
+#[test]
+fn test_resolve_matches_the_instruction_builders() {
+    let keys = PoolKeys::with_pda_vaults(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        0,
+        spl_token_2022::ID,
+    )
+    .with_receipt_mint();
+    let owner = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+
+    let resolved = accounts::resolve(&keys, &owner, 2);
+    let receipt_account = resolved.receipt_account.expect("pool tokenizes positions");
+
+    assert_eq!(
+        resolved.stake_account,
+        instruction::stake_account_address(&keys.pool, &owner, 2)
+    );
+    assert_eq!(
+        resolved.stake_metas(&payer),
+        instruction::stake(
+            &keys,
+            &owner,
+            &resolved.stake_token_account,
+            &payer,
+            StakeArgs {
+                receipt_account: Some(receipt_account),
+                ..StakeArgs::new(1_000_000, 2)
+            },
+        )
+        .accounts
+    );
+    assert_eq!(
+        resolved.unstake_metas(),
+        instruction::unstake(
+            &keys,
+            &owner,
+            2,
+            &resolved.stake_token_account,
+            1_000_000,
+            None,
+            Some(&receipt_account),
+        )
+        .accounts
+    );
+    assert_eq!(
+        resolved.claim_rewards_metas(),
+        instruction::claim_rewards(&keys, &owner, 2, &resolved.reward_token_account, None).accounts
+    );
+    assert_eq!(
+        resolved.reward_token_account,
+        instruction::associated_reward_account(&keys, &owner)
+    );
+}
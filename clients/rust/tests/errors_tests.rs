@@ -0,0 +1,51 @@
+// ============================================================================
+// Error Decoding Tests
+// ============================================================================
+// Failure codes must decode to the same StakePoolError the program raised, and
+// only this program's failure line may be decoded from transaction logs.
+
+use solana_program::pubkey::Pubkey;
+use your_wallet_stake_pool_client::{
+    errors::{self, StakePoolError},
+    ID,
+};
+
+#[test]
+fn test_codes_decode_to_stake_pool_errors() {
+    assert_eq!(
+        errors::from_code(12),
+        Some(StakePoolError::AmountBelowMinimum)
+    );
+    assert_eq!(
+        errors::from_code(12).unwrap().to_str(),
+        "AmountBelowMinimum"
+    );
+    assert_eq!(errors::from_code(u32::MAX), None);
+}
+
+#[test]
+fn test_from_logs_reads_only_this_programs_failure() {
+    let token_program = Pubkey::new_unique();
+    let logs = vec![
+        format!("Program {} invoke [1]", ID),
+        format!("Program {} invoke [2]", token_program),
+        // Custom(1) of the token program is not SerializationError
+        format!(
+            "Program {} failed: custom program error: 0x1",
+            token_program
+        ),
+        format!("Program {} failed: custom program error: 0x1", ID),
+    ];
+    assert_eq!(
+        errors::from_logs(&logs[..3]),
+        None,
+        "the token program's code must not be decoded"
+    );
+    assert_eq!(
+        errors::from_logs(&logs),
+        Some(StakePoolError::SerializationError)
+    );
+
+    let logs = [format!("Program {} failed: custom program error: 0x53", ID)];
+    assert_eq!(errors::from_logs(&logs), Some(StakePoolError::RateLimited));
+}
@@ -0,0 +1,101 @@
+// ============================================================================
+// Event Log Parsing Tests
+// ============================================================================
+// parse_logs must only decode `Program data:` lines logged while the stake
+// pool program is the executing frame, including across nested CPIs.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_program::pubkey::Pubkey;
+use your_wallet_stake_pool_client::{
+    events::{
+        decode_event, error_context, parse_logs, ContextValue, ErrorContextEvent,
+        RewardsFundedEvent, StakeEvent, StakePoolEvent,
+    },
+    program::error::StakePoolError,
+    ID,
+};
+
+fn data_line(event: &StakePoolEvent) -> String {
+    format!(
+        "Program data: {}",
+        STANDARD.encode(borsh::to_vec(event).unwrap())
+    )
+}
+
+#[test]
+fn test_parse_logs_keeps_only_stake_pool_frames() {
+    let token_program = Pubkey::new_unique();
+    let caller = Pubkey::new_unique();
+    let stake = StakePoolEvent::Stake(StakeEvent {
+        pool: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        index: 0,
+        amount: 1_000_000,
+        fee: 0,
+        active_stake_count: Some(1),
+        event_nonce: 1,
+    });
+    let funded = StakePoolEvent::RewardsFunded(RewardsFundedEvent {
+        pool: Pubkey::new_unique(),
+        funder: Pubkey::new_unique(),
+        amount: 5_000,
+    });
+
+    let logs = vec![
+        format!("Program {} invoke [1]", caller),
+        // Data logged by the outer program is not ours, even if it decodes
+        data_line(&funded),
+        format!("Program {} invoke [2]", ID),
+        "Program log: Instruction: Stake".to_string(),
+        format!("Program {} invoke [3]", token_program),
+        data_line(&funded),
+        format!("Program {} success", token_program),
+        data_line(&stake),
+        format!("Program {} consumed 12345 of 200000 compute units", ID),
+        format!("Program {} success", ID),
+        data_line(&funded),
+        format!("Program {} success", caller),
+        format!("Program {} invoke [1]", ID),
+        "Program data: not-base64!".to_string(),
+        data_line(&funded),
+        format!("Program {} failed: custom program error: 0x1", ID),
+        data_line(&stake),
+    ];
+
+    assert_eq!(parse_logs(&logs), vec![stake, funded]);
+}
+
+#[test]
+fn test_decode_event_rejects_foreign_data() {
+    assert_eq!(decode_event("not-base64!"), None);
+    assert_eq!(decode_event(&STANDARD.encode([0xff, 1, 2])), None);
+}
+
+#[test]
+fn test_error_context_is_read_from_failed_transaction_logs() {
+    let context = ErrorContextEvent {
+        instruction: "Stake".to_string(),
+        error: StakePoolError::AmountBelowMinimum as u32,
+        field: "amount".to_string(),
+        expected: ContextValue::Unsigned(1_000),
+        actual: ContextValue::Unsigned(999),
+    };
+
+    let logs = vec![
+        format!("Program {} invoke [1]", ID),
+        "Program log: Stake amount 999 below minimum 1000".to_string(),
+        data_line(&StakePoolEvent::ErrorContext(context.clone())),
+        "Program log: Stake failed: StakePoolError::AmountBelowMinimum".to_string(),
+        format!("Program {} failed: custom program error: 0xc", ID),
+    ];
+
+    let decoded = error_context(&logs).unwrap();
+    assert_eq!(decoded, context);
+    assert_eq!(
+        decoded.stake_pool_error(),
+        Some(StakePoolError::AmountBelowMinimum)
+    );
+
+    // Successful transactions carry no context
+    assert_eq!(error_context(&logs[..1]), None);
+}
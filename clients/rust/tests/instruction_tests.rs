@@ -0,0 +1,1968 @@
+// ============================================================================
+// Instruction Builder Tests
+// ============================================================================
+// Builders must produce account lists the program's ShankContext parsers accept,
+// with PDAs in the slots the program expects.
+
+use borsh::BorshDeserialize;
+use solana_program::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use your_wallet_stake_pool::{
+    instruction::{accounts::*, StakePoolInstruction},
+    state::{
+        DenyList, HookAllowlist, MintRegistry, PendingAction, PoolAllowlist, PoolRegistry,
+        ProgramAuthority, ProgramConfig, RewardCohort, RewardStream, Role, SensitivePoolUpdate,
+        SlashProposal, StakeAccount, StakePool, StakePosition, UserPoints,
+    },
+};
+use your_wallet_stake_pool_client::{instruction, PoolKeys, ID};
+
+fn keys() -> PoolKeys {
+    PoolKeys::with_pda_vaults(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        3,
+        Pubkey::new_unique(),
+    )
+}
+
+/// Backing storage for `AccountInfo`s built from an instruction's metas
+struct Accounts {
+    keys: Vec<Pubkey>,
+    signer: Vec<bool>,
+    writable: Vec<bool>,
+    lamports: Vec<u64>,
+    data: Vec<Vec<u8>>,
+    owner: Pubkey,
+}
+
+impl Accounts {
+    fn from_instruction(ix: &Instruction) -> Self {
+        Self {
+            keys: ix.accounts.iter().map(|m| m.pubkey).collect(),
+            signer: ix.accounts.iter().map(|m| m.is_signer).collect(),
+            writable: ix.accounts.iter().map(|m| m.is_writable).collect(),
+            lamports: vec![0; ix.accounts.len()],
+            data: vec![Vec::new(); ix.accounts.len()],
+            owner: Pubkey::new_unique(),
+        }
+    }
+
+    fn infos(&mut self) -> Vec<AccountInfo<'_>> {
+        let owner = &self.owner;
+        self.keys
+            .iter()
+            .zip(self.lamports.iter_mut())
+            .zip(self.data.iter_mut())
+            .enumerate()
+            .map(|(i, ((key, lamports), data))| {
+                AccountInfo::new(
+                    key,
+                    self.signer[i],
+                    self.writable[i],
+                    lamports,
+                    data,
+                    owner,
+                    false,
+                    0,
+                )
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_pool_keys_derive_vault_pdas() {
+    let keys = keys();
+    let (pool, _) = StakePool::find_pda(&keys.stake_mint, 3);
+
+    assert_eq!(keys.pool, pool);
+    assert_eq!(keys.stake_vault, StakePool::find_stake_vault_pda(&pool).0);
+    assert_eq!(keys.reward_vault, StakePool::find_reward_vault_pda(&pool).0);
+    assert_eq!(keys.fee_vault, StakePool::find_fee_vault_pda(&pool).0);
+}
+
+#[test]
+fn test_stake_accounts_parse_in_program_order() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+    let user_token_account = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let referrer = Pubkey::new_unique();
+
+    let ix = instruction::stake(
+        &keys,
+        &owner,
+        &user_token_account,
+        &payer,
+        instruction::StakeArgs {
+            referrer: Some(referrer),
+            ..instruction::StakeArgs::new(500, 7)
+        },
+    );
+    assert_eq!(ix.program_id, ID);
+
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = StakeAccounts::context(&infos).unwrap();
+
+    let (stake_account, _) = StakeAccount::find_pda(&keys.pool, &owner, 7);
+    assert_eq!(ctx.accounts.pool.key, &keys.pool);
+    assert_eq!(ctx.accounts.stake_account.key, &stake_account);
+    assert!(ctx.accounts.owner.is_signer);
+    assert_eq!(ctx.accounts.user_token_account.key, &user_token_account);
+    assert_eq!(ctx.accounts.stake_vault.key, &keys.stake_vault);
+    assert_eq!(ctx.accounts.payer.key, &payer);
+    assert_eq!(ctx.accounts.fee_vault.key, &keys.fee_vault);
+    assert_eq!(
+        ctx.accounts.mint_registry.key,
+        &MintRegistry::find_pda(&keys.stake_mint).0
+    );
+    assert!(ctx.accounts.allowlist.is_none());
+    assert!(ctx.accounts.stake_position.is_none());
+
+    match StakePoolInstruction::try_from_slice(&ix.data).unwrap() {
+        StakePoolInstruction::Stake {
+            amount,
+            index,
+            referrer: parsed_referrer,
+            ..
+        } => {
+            assert_eq!(amount, 500);
+            assert_eq!(index, Some(7));
+            assert_eq!(parsed_referrer, Some(referrer));
+        }
+        _ => panic!("expected Stake instruction"),
+    }
+}
+
+#[test]
+fn test_claim_rewards_without_referrer_passes_none() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+    let user_reward_account = Pubkey::new_unique();
+
+    let ix = instruction::claim_rewards(&keys, &owner, 0, &user_reward_account, None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ClaimRewardsAccounts::context(&infos).unwrap();
+
+    assert!(ctx.accounts.referrer_reward_account.is_none());
+    assert_eq!(ctx.accounts.reward_vault.key, &keys.reward_vault);
+
+    let referrer_reward_account = Pubkey::new_unique();
+    let ix = instruction::claim_rewards(
+        &keys,
+        &owner,
+        0,
+        &user_reward_account,
+        Some(&referrer_reward_account),
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ClaimRewardsAccounts::context(&infos).unwrap();
+
+    assert_eq!(
+        ctx.accounts.referrer_reward_account.map(|a| a.key),
+        Some(&referrer_reward_account)
+    );
+}
+
+#[test]
+fn test_claim_partial_rewards_encodes_amount() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+    let user_reward_account = Pubkey::new_unique();
+
+    let ix = instruction::claim_partial_rewards(&keys, &owner, 0, &user_reward_account, 250, None);
+    match StakePoolInstruction::try_from_slice(&ix.data).unwrap() {
+        StakePoolInstruction::ClaimRewards { amount, .. } => assert_eq!(amount, Some(250)),
+        _ => panic!("expected ClaimRewards instruction"),
+    }
+
+    let ix = instruction::claim_rewards(&keys, &owner, 0, &user_reward_account, None);
+    match StakePoolInstruction::try_from_slice(&ix.data).unwrap() {
+        StakePoolInstruction::ClaimRewards { amount, .. } => assert_eq!(amount, None),
+        _ => panic!("expected ClaimRewards instruction"),
+    }
+}
+
+#[test]
+fn test_memo_passes_the_memo_program() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+    let memo_program = your_wallet_stake_pool::spl_memo::ID;
+
+    let ix = instruction::claim_rewards_with_memo(
+        &keys,
+        &owner,
+        0,
+        &Pubkey::new_unique(),
+        None,
+        "Q3 compliance claim",
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ClaimRewardsAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.memo_program.unwrap().key, &memo_program);
+    match StakePoolInstruction::try_from_slice(&ix.data).unwrap() {
+        StakePoolInstruction::ClaimRewards { memo, .. } => {
+            assert_eq!(memo.as_deref(), Some("Q3 compliance claim"))
+        }
+        _ => panic!("expected ClaimRewards instruction"),
+    }
+
+    let ix = instruction::unstake_with_memo(
+        &keys,
+        &owner,
+        0,
+        &Pubkey::new_unique(),
+        10,
+        None,
+        None,
+        "withdrawal #42",
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = UnstakeAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.memo_program.unwrap().key, &memo_program);
+
+    // Without a memo the memo program is absent
+    let args = instruction::StakeArgs::new(10, 0);
+    let ix = instruction::stake(&keys, &owner, &Pubkey::new_unique(), &owner, args);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = StakeAccounts::context(&infos).unwrap();
+    assert!(ctx.accounts.memo_program.is_none());
+}
+
+#[test]
+fn test_claim_rewards_to_associated_account_creates_owner_ata() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+
+    let ix = instruction::claim_rewards_to_associated_account(&keys, &owner, 1, None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ClaimRewardsAccounts::context(&infos).unwrap();
+
+    let ata_program = your_wallet_stake_pool::spl_associated_token_account_client::program::ID;
+    let (ata, _) = Pubkey::find_program_address(
+        &[
+            owner.as_ref(),
+            keys.token_program.as_ref(),
+            keys.reward_mint.as_ref(),
+        ],
+        &ata_program,
+    );
+    assert_eq!(ctx.accounts.user_reward_account.key, &ata);
+    assert_eq!(instruction::associated_reward_account(&keys, &owner), ata);
+    assert!(ctx.accounts.owner.is_signer);
+    assert!(ctx.accounts.owner.is_writable);
+    assert_eq!(
+        ctx.accounts.associated_token_program.map(|a| a.key),
+        Some(&ata_program)
+    );
+    assert_eq!(
+        ctx.accounts.system_program.map(|a| a.key),
+        Some(&solana_program::system_program::ID)
+    );
+
+    // Plain claims leave the owner read-only and skip the creation
+    let ix = instruction::claim_rewards(&keys, &owner, 1, &ata, None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ClaimRewardsAccounts::context(&infos).unwrap();
+    assert!(!ctx.accounts.owner.is_writable);
+    assert!(ctx.accounts.associated_token_program.is_none());
+    assert!(ctx.accounts.system_program.is_none());
+}
+
+#[test]
+fn test_claim_rewards_to_passes_recipient() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+    let user_reward_account = Pubkey::new_unique();
+
+    let ix = instruction::claim_rewards(&keys, &owner, 0, &user_reward_account, None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ClaimRewardsAccounts::context(&infos).unwrap();
+    assert!(ctx.accounts.recipient.is_none());
+
+    let recipient = Pubkey::new_unique();
+    let ix =
+        instruction::claim_rewards_to(&keys, &owner, 0, &user_reward_account, &recipient, None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ClaimRewardsAccounts::context(&infos).unwrap();
+
+    let recipient_info = ctx.accounts.recipient.unwrap();
+    assert_eq!(recipient_info.key, &recipient);
+    assert!(recipient_info.is_writable);
+    assert!(ctx.accounts.referrer_reward_account.is_none());
+}
+
+#[test]
+fn test_claim_rewards_as_delegate_signs_with_delegate() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+    let owner_reward_account = Pubkey::new_unique();
+
+    let ix = instruction::claim_rewards_as_delegate(
+        &keys,
+        &owner,
+        &delegate,
+        4,
+        &owner_reward_account,
+        None,
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ClaimRewardsAccounts::context(&infos).unwrap();
+
+    let (stake_account, _) = StakeAccount::find_pda(&keys.pool, &owner, 4);
+    assert_eq!(ctx.accounts.stake_account.key, &stake_account);
+    assert_eq!(ctx.accounts.owner.key, &delegate);
+    assert!(ctx.accounts.owner.is_signer);
+    assert_eq!(ctx.accounts.user_reward_account.key, &owner_reward_account);
+}
+
+#[test]
+fn test_unstake_sol_derives_unwrap_account() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+
+    let ix = instruction::unstake_sol(&keys, &owner, 2, 1_000, None, None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = UnstakeSolAccounts::context(&infos).unwrap();
+
+    let (stake_account, _) = StakeAccount::find_pda(&keys.pool, &owner, 2);
+    let (unwrap_account, _) = StakeAccount::find_unwrap_pda(&stake_account);
+    assert_eq!(ctx.accounts.stake_account.key, &stake_account);
+    assert_eq!(ctx.accounts.unwrap_account.key, &unwrap_account);
+    assert!(ctx.accounts.owner.is_writable);
+}
+
+#[test]
+fn test_update_pools_batch_appends_pools() {
+    let admin = Pubkey::new_unique();
+    let pools: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+
+    let ix = instruction::update_pools_batch(
+        &pools,
+        &admin,
+        instruction::UpdatePoolArgs {
+            is_paused: Some(true),
+            ..Default::default()
+        },
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = UpdatePoolsBatchAccounts::context(&infos).unwrap();
+
+    assert_eq!(ctx.accounts.admin.key, &admin);
+    assert_eq!(
+        ctx.accounts.program_authority.key,
+        &ProgramAuthority::find_pda().0
+    );
+    assert_eq!(
+        ctx.accounts.program_config.key,
+        &ProgramConfig::find_pda().0
+    );
+    let parsed: Vec<Pubkey> = ctx.remaining_accounts.iter().map(|a| *a.key).collect();
+    assert_eq!(parsed, pools);
+    assert!(ctx.remaining_accounts.iter().all(|a| a.is_writable));
+}
+
+#[test]
+fn test_sync_pool_accounts_parse_in_program_order() {
+    let keys = keys();
+
+    let ix = instruction::sync_pool(&keys, None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = SyncPoolAccounts::context(&infos).unwrap();
+
+    assert_eq!(ctx.accounts.pool.key, &keys.pool);
+    assert!(ctx.accounts.pool.is_writable);
+    assert_eq!(ctx.accounts.stake_vault.key, &keys.stake_vault);
+    assert_eq!(ctx.accounts.reward_vault.key, &keys.reward_vault);
+    assert!(ctx.accounts.keeper.is_none());
+    assert!(infos.iter().all(|a| !a.is_signer));
+}
+
+#[test]
+fn test_finalize_reward_rate_change_passes_program_authority() {
+    let pool = Pubkey::new_unique();
+
+    let ix = instruction::finalize_reward_rate_change(&pool, None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = FinalizeRewardRateChangeAccounts::context(&infos).unwrap();
+
+    assert_eq!(ctx.accounts.pool.key, &pool);
+    assert_eq!(
+        ctx.accounts.program_authority.key,
+        &ProgramAuthority::find_pda().0
+    );
+}
+
+#[test]
+fn test_finalize_lockup_change_passes_program_authority() {
+    let pool = Pubkey::new_unique();
+
+    let keeper = Pubkey::new_unique();
+
+    let ix = instruction::finalize_lockup_change(&pool, Some(&keeper));
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = FinalizeLockupChangeAccounts::context(&infos).unwrap();
+
+    assert_eq!(ctx.accounts.pool.key, &pool);
+    assert!(ctx.accounts.pool.is_writable);
+    let keeper_info = ctx.accounts.keeper.unwrap();
+    assert_eq!(keeper_info.key, &keeper);
+    assert!(keeper_info.is_writable && !keeper_info.is_signer);
+    assert_eq!(
+        ctx.accounts.program_authority.key,
+        &ProgramAuthority::find_pda().0
+    );
+}
+
+#[test]
+fn test_defund_rewards_accounts_parse_in_program_order() {
+    let keys = keys();
+    let admin = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let ix = instruction::defund_rewards(&keys, &admin, &destination, 42);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = DefundRewardsAccounts::context(&infos).unwrap();
+
+    assert!(ctx.accounts.pool.is_writable);
+    assert!(ctx.accounts.admin.is_signer);
+    assert_eq!(ctx.accounts.reward_vault.key, &keys.reward_vault);
+    assert_eq!(ctx.accounts.destination.key, &destination);
+    assert_eq!(ctx.accounts.reward_mint.key, &keys.reward_mint);
+}
+
+#[test]
+fn test_set_mint_tvl_cap_derives_mint_registry() {
+    let stake_mint = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+
+    let ix = instruction::set_mint_tvl_cap(&stake_mint, &admin, Some(1_000));
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = SetMintTvlCapAccounts::context(&infos).unwrap();
+
+    assert_eq!(
+        ctx.accounts.mint_registry.key,
+        &MintRegistry::find_pda(&stake_mint).0
+    );
+    assert!(ctx.accounts.mint_registry.is_writable);
+    assert_eq!(ctx.accounts.stake_mint.key, &stake_mint);
+    assert!(ctx.accounts.admin.is_signer);
+    assert_eq!(
+        ctx.accounts.program_authority.key,
+        &ProgramAuthority::find_pda().0
+    );
+}
+
+#[test]
+fn test_close_pool_accounts_parse_in_program_order() {
+    let keys = keys();
+    let admin = Pubkey::new_unique();
+    let stake_receiver = Pubkey::new_unique();
+    let reward_receiver = Pubkey::new_unique();
+    let rent_receiver = Pubkey::new_unique();
+
+    let ix = instruction::close_pool(
+        &keys,
+        &admin,
+        &stake_receiver,
+        &reward_receiver,
+        &rent_receiver,
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ClosePoolAccounts::context(&infos).unwrap();
+
+    assert!(ctx.accounts.pool.is_writable);
+    assert!(ctx.accounts.admin.is_signer);
+    assert_eq!(ctx.accounts.fee_vault.key, &keys.fee_vault);
+    assert_eq!(ctx.accounts.stake_receiver.key, &stake_receiver);
+    assert_eq!(ctx.accounts.reward_receiver.key, &reward_receiver);
+    assert_eq!(ctx.accounts.rent_receiver.key, &rent_receiver);
+    assert_eq!(ctx.accounts.token_program.key, &keys.token_program);
+}
+
+#[test]
+fn test_receipt_accounts_only_filled_for_tokenized_pools() {
+    let owner = Pubkey::new_unique();
+    let user_token_account = Pubkey::new_unique();
+
+    let ix = instruction::stake(
+        &keys(),
+        &owner,
+        &user_token_account,
+        &owner,
+        instruction::StakeArgs::new(500, 0),
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = StakeAccounts::context(&infos).unwrap();
+    assert!(ctx.accounts.receipt_mint.is_none());
+    assert!(ctx.accounts.user_receipt_account.is_none());
+    assert!(ctx.accounts.receipt_token_program.is_none());
+
+    let keys = keys().with_receipt_mint();
+    let receipt_account = Pubkey::new_unique();
+    let ix = instruction::unstake(
+        &keys,
+        &owner,
+        0,
+        &user_token_account,
+        500,
+        None,
+        Some(&receipt_account),
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = UnstakeAccounts::context(&infos).unwrap();
+    assert_eq!(
+        ctx.accounts.receipt_mint.unwrap().key,
+        &StakePool::find_receipt_mint_pda(&keys.pool).0
+    );
+    assert_eq!(
+        ctx.accounts.user_receipt_account.unwrap().key,
+        &receipt_account
+    );
+    assert!(ctx.accounts.receipt_mint.unwrap().is_writable);
+}
+
+#[test]
+fn test_enable_receipt_token_accounts_parse_in_program_order() {
+    let keys = keys();
+    let admin = Pubkey::new_unique();
+
+    let ix = instruction::enable_receipt_token(&keys, &admin, false);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = EnableReceiptTokenAccounts::context(&infos).unwrap();
+
+    assert!(ctx.accounts.pool.is_writable);
+    assert!(ctx.accounts.admin.is_signer);
+    assert_eq!(
+        ctx.accounts.receipt_mint.key,
+        &StakePool::find_receipt_mint_pda(&keys.pool).0
+    );
+    assert_eq!(ctx.accounts.stake_mint.key, &keys.stake_mint);
+
+    match StakePoolInstruction::try_from_slice(&ix.data).unwrap() {
+        StakePoolInstruction::EnableReceiptToken { transferable } => assert!(!transferable),
+        _ => panic!("expected EnableReceiptToken instruction"),
+    }
+}
+
+#[test]
+fn test_reward_expiry_accounts_parse_in_program_order() {
+    let keys = keys();
+    let stake_account = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let ix = instruction::expire_rewards(&keys, &stake_account);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ExpireRewardsAccounts::context(&infos).unwrap();
+    assert!(ctx.accounts.pool.is_writable);
+    assert_eq!(ctx.accounts.stake_account.key, &stake_account);
+    assert!(!ctx.accounts.stake_account.is_signer);
+
+    let ix = instruction::claim_expired_rewards(&keys, &admin, &destination);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ClaimExpiredRewardsAccounts::context(&infos).unwrap();
+    assert!(ctx.accounts.admin.is_signer);
+    assert_eq!(ctx.accounts.reward_vault.key, &keys.reward_vault);
+    assert_eq!(ctx.accounts.destination.key, &destination);
+    assert_eq!(ctx.accounts.token_program.key, &keys.token_program);
+}
+
+#[test]
+fn test_unstake_cooldown_accounts_parse_in_program_order() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+    let user_token_account = Pubkey::new_unique();
+    let stake_account = instruction::stake_account_address(&keys.pool, &owner, 2);
+
+    let ix = instruction::request_unstake(&keys, &owner, 2, 5_000, None, None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = RequestUnstakeAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.stake_account.key, &stake_account);
+    assert!(ctx.accounts.owner.is_signer);
+    assert!(ctx.accounts.receipt_mint.is_none());
+
+    let ix = instruction::withdraw_unstaked(&keys, &owner, 2, &user_token_account);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = WithdrawUnstakedAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.stake_account.key, &stake_account);
+    assert_eq!(ctx.accounts.user_token_account.key, &user_token_account);
+    assert_eq!(ctx.accounts.fee_vault.key, &keys.fee_vault);
+    assert_eq!(
+        ctx.accounts.mint_registry.key,
+        &instruction::mint_registry_address(&keys.stake_mint)
+    );
+}
+
+#[test]
+fn test_user_operations_pass_program_authority_for_global_pause() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+    let token_account = Pubkey::new_unique();
+    let program_authority = ProgramAuthority::find_pda().0;
+
+    let ix = instruction::stake(
+        &keys,
+        &owner,
+        &token_account,
+        &owner,
+        instruction::StakeArgs::new(500, 0),
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = StakeAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.program_authority.key, &program_authority);
+
+    let ix = instruction::unstake_sol(&keys, &owner, 0, 500, None, None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = UnstakeSolAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.program_authority.key, &program_authority);
+
+    let ix = instruction::claim_rewards(&keys, &owner, 0, &token_account, None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ClaimRewardsAccounts::context(&infos).unwrap();
+    assert!(ctx.accounts.referrer_reward_account.is_none());
+    assert_eq!(ctx.accounts.program_authority.key, &program_authority);
+
+    let ix = instruction::withdraw_unstaked(&keys, &owner, 0, &token_account);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = WithdrawUnstakedAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.program_authority.key, &program_authority);
+
+    let admin = Pubkey::new_unique();
+    let ix = instruction::set_global_pause(&admin, false, true);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = SetGlobalPauseAccounts::context(&infos).unwrap();
+    assert!(ctx.accounts.program_authority.is_writable);
+    assert!(ctx.accounts.admin.is_signer);
+    match StakePoolInstruction::try_from_slice(&ix.data).unwrap() {
+        StakePoolInstruction::SetGlobalPause {
+            global_pause,
+            withdrawals_only,
+        } => assert!(!global_pause && withdrawals_only),
+        _ => panic!("expected SetGlobalPause instruction"),
+    }
+}
+
+#[test]
+fn test_migrate_pool_appends_stake_accounts() {
+    let keys = keys();
+    let admin = Pubkey::new_unique();
+    let stake_accounts: Vec<Pubkey> = (0..2).map(|_| Pubkey::new_unique()).collect();
+
+    let ix = instruction::migrate_pool(&keys, &admin, &stake_accounts);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = MigratePoolAccounts::context(&infos).unwrap();
+
+    assert!(ctx.accounts.pool.is_writable);
+    assert!(ctx.accounts.admin.is_signer && ctx.accounts.admin.is_writable);
+    assert_eq!(ctx.accounts.fee_vault.key, &keys.fee_vault);
+    assert_eq!(
+        ctx.accounts.mint_registry.key,
+        &MintRegistry::find_pda(&keys.stake_mint).0
+    );
+    let parsed: Vec<Pubkey> = ctx.remaining_accounts.iter().map(|a| *a.key).collect();
+    assert_eq!(parsed, stake_accounts);
+    assert!(ctx.remaining_accounts.iter().all(|a| a.is_writable));
+}
+
+#[test]
+fn test_stake_batch_appends_consecutive_stake_accounts() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+    let user_token_account = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+
+    let ix = instruction::stake_batch(
+        &keys,
+        &owner,
+        &user_token_account,
+        &payer,
+        instruction::StakeBatchArgs {
+            amounts: vec![100, 200, 300],
+            start_index: 4,
+            allowlist_page: Some(2),
+            ..instruction::StakeBatchArgs::default()
+        },
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = StakeBatchAccounts::context(&infos).unwrap();
+
+    // The allowlist page comes before the stake accounts
+    assert_eq!(
+        ctx.accounts.allowlist.unwrap().key,
+        &PoolAllowlist::find_pda(&keys.pool, 2).0
+    );
+
+    assert!(ctx.accounts.owner.is_signer);
+    assert_eq!(ctx.accounts.user_token_account.key, &user_token_account);
+    assert_eq!(ctx.accounts.payer.key, &payer);
+    assert_eq!(
+        ctx.accounts.program_authority.key,
+        &ProgramAuthority::find_pda().0
+    );
+    let parsed: Vec<Pubkey> = ctx.remaining_accounts.iter().map(|a| *a.key).collect();
+    let expected: Vec<Pubkey> = (4..7)
+        .map(|index| StakeAccount::find_pda(&keys.pool, &owner, index).0)
+        .collect();
+    assert_eq!(parsed, expected);
+    assert!(ctx.remaining_accounts.iter().all(|a| a.is_writable));
+
+    match StakePoolInstruction::try_from_slice(&ix.data).unwrap() {
+        StakePoolInstruction::StakeBatch {
+            amounts,
+            start_index,
+            ..
+        } => {
+            assert_eq!(amounts, vec![100, 200, 300]);
+            assert_eq!(start_index, 4);
+        }
+        _ => panic!("expected StakeBatch instruction"),
+    }
+}
+
+#[test]
+fn test_claim_rewards_batch_appends_stake_accounts_by_index() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+    let user_reward_account = Pubkey::new_unique();
+
+    let ix = instruction::claim_rewards_batch(&keys, &owner, &[0, 2, 5], &user_reward_account);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ClaimRewardsBatchAccounts::context(&infos).unwrap();
+
+    assert!(ctx.accounts.owner.is_signer);
+    assert_eq!(ctx.accounts.user_reward_account.key, &user_reward_account);
+    assert_eq!(ctx.accounts.reward_vault.key, &keys.reward_vault);
+    assert_eq!(
+        ctx.accounts.program_authority.key,
+        &ProgramAuthority::find_pda().0
+    );
+    let parsed: Vec<Pubkey> = ctx.remaining_accounts.iter().map(|a| *a.key).collect();
+    let expected: Vec<Pubkey> = [0, 2, 5]
+        .iter()
+        .map(|&index| StakeAccount::find_pda(&keys.pool, &owner, index).0)
+        .collect();
+    assert_eq!(parsed, expected);
+    assert!(ctx.remaining_accounts.iter().all(|a| a.is_writable));
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::ClaimRewardsBatch
+    ));
+}
+
+#[test]
+fn test_get_pending_rewards_is_read_only() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+
+    let ix = instruction::get_pending_rewards(&keys, &owner, 4);
+    assert!(ix.accounts.iter().all(|m| !m.is_writable && !m.is_signer));
+
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = GetPendingRewardsAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.pool.key, &keys.pool);
+    assert_eq!(
+        ctx.accounts.stake_account.key,
+        &StakeAccount::find_pda(&keys.pool, &owner, 4).0
+    );
+}
+
+#[test]
+fn test_stake_position_is_tracked_on_request_and_released_on_close() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let position = StakePosition::find_pda(&keys.pool, &owner).0;
+    assert_eq!(
+        instruction::stake_position_address(&keys.pool, &owner),
+        position
+    );
+
+    let ix = instruction::stake_sol(
+        &keys,
+        &owner,
+        &payer,
+        instruction::StakeArgs {
+            track_position: true,
+            ..instruction::StakeArgs::new(500, 0)
+        },
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = StakeSolAccounts::context(&infos).unwrap();
+    let stake_position = ctx.accounts.stake_position.unwrap();
+    assert_eq!(stake_position.key, &position);
+    assert!(stake_position.is_writable);
+
+    let ix = instruction::close_stake_account(&keys.pool, &owner, 0, &owner);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = CloseStakeAccountAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.stake_position.unwrap().key, &position);
+
+    let ix = instruction::get_stake_position(&keys, &owner);
+    assert!(ix.accounts.iter().all(|m| !m.is_writable && !m.is_signer));
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = GetStakePositionAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.pool.key, &keys.pool);
+    assert_eq!(ctx.accounts.owner.key, &owner);
+    assert_eq!(ctx.accounts.stake_position.key, &position);
+}
+
+#[test]
+fn test_auto_index_stake_sends_no_index_and_passes_the_directory() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+
+    // `index` is the directory's next index, read with get_stake_position
+    let ix = instruction::stake(
+        &keys,
+        &owner,
+        &Pubkey::new_unique(),
+        &owner,
+        instruction::StakeArgs {
+            auto_index: true,
+            ..instruction::StakeArgs::new(500, 4)
+        },
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = StakeAccounts::context(&infos).unwrap();
+
+    assert_eq!(
+        ctx.accounts.stake_account.key,
+        &StakeAccount::find_pda(&keys.pool, &owner, 4).0
+    );
+    assert_eq!(
+        ctx.accounts.stake_position.unwrap().key,
+        &StakePosition::find_pda(&keys.pool, &owner).0
+    );
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::Stake { index: None, .. }
+    ));
+}
+
+#[test]
+fn test_funded_stake_is_signed_by_the_funder_only() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+    let funder = Pubkey::new_unique();
+
+    let ix = instruction::stake(
+        &keys,
+        &owner,
+        &Pubkey::new_unique(),
+        &funder,
+        instruction::StakeArgs {
+            funder: Some(funder),
+            ..instruction::StakeArgs::new(500, 0)
+        },
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = StakeAccounts::context(&infos).unwrap();
+
+    assert!(!ctx.accounts.owner.is_signer);
+    assert_eq!(
+        ctx.accounts.stake_account.key,
+        &StakeAccount::find_pda(&keys.pool, &owner, 0).0
+    );
+    let funder_info = ctx.accounts.funder.unwrap();
+    assert_eq!(funder_info.key, &funder);
+    assert!(funder_info.is_signer);
+}
+
+#[test]
+fn test_get_pool_stats_is_read_only() {
+    let keys = keys();
+
+    let ix = instruction::get_pool_stats(&keys);
+    assert!(ix.accounts.iter().all(|m| !m.is_writable && !m.is_signer));
+
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = GetPoolStatsAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.pool.key, &keys.pool);
+    assert_eq!(ctx.accounts.stake_vault.key, &keys.stake_vault);
+    assert_eq!(ctx.accounts.reward_vault.key, &keys.reward_vault);
+}
+
+#[test]
+fn test_pool_registry_pages_in_program_order() {
+    let keys = keys();
+    let payer = Pubkey::new_unique();
+
+    // The first page has no previous page
+    let ix =
+        instruction::initialize_pool(&keys, &payer, instruction::InitializePoolArgs::default());
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = InitializePoolAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.pool_registry.key, &PoolRegistry::find_pda(0).0);
+    assert!(ctx.accounts.pool_registry.is_writable);
+    assert!(ctx.accounts.previous_pool_registry.is_none());
+
+    // Later pages pass the page before them
+    let ix = instruction::register_pool(&keys.pool, &payer, 2);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = RegisterPoolAccounts::context(&infos).unwrap();
+    assert!(ctx.accounts.pool.is_writable);
+    assert_eq!(ctx.accounts.pool_registry.key, &PoolRegistry::find_pda(2).0);
+    assert_eq!(
+        ctx.accounts.previous_pool_registry.unwrap().key,
+        &PoolRegistry::find_pda(1).0
+    );
+    assert!(ctx.accounts.payer.is_signer);
+}
+
+#[test]
+fn test_creator_pool_limits_write_program_authority() {
+    let keys = keys();
+    let creator = Pubkey::new_unique();
+
+    // Pool creation counts the creator's pools in the program authority
+    let ix =
+        instruction::initialize_pool(&keys, &creator, instruction::InitializePoolArgs::default());
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = InitializePoolAccounts::context(&infos).unwrap();
+    assert!(ctx.accounts.program_authority.is_writable);
+
+    let authority = Pubkey::new_unique();
+    let ix = instruction::set_creator_pool_limit(&authority, &creator, Some(3));
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = SetCreatorPoolLimitAccounts::context(&infos).unwrap();
+    assert_eq!(
+        ctx.accounts.program_authority.key,
+        &instruction::program_authority_address()
+    );
+    assert!(ctx.accounts.program_authority.is_writable);
+    assert!(ctx.accounts.authority.is_signer);
+    match StakePoolInstruction::try_from_slice(&ix.data).unwrap() {
+        StakePoolInstruction::SetCreatorPoolLimit {
+            creator: limited,
+            pool_limit,
+        } => assert_eq!((limited, pool_limit), (creator, Some(3))),
+        _ => panic!("expected SetCreatorPoolLimit instruction"),
+    }
+}
+
+#[test]
+fn test_manage_roles_passes_role_mask() {
+    let authority = Pubkey::new_unique();
+    let holder = Pubkey::new_unique();
+    let roles = Role::Pauser.mask() | Role::Treasurer.mask();
+
+    let ix = instruction::manage_roles(&authority, &holder, roles);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ManageRolesAccounts::context(&infos).unwrap();
+    assert!(ctx.accounts.program_authority.is_writable);
+    assert!(ctx.accounts.authority.is_signer);
+    match StakePoolInstruction::try_from_slice(&ix.data).unwrap() {
+        StakePoolInstruction::ManageRoles {
+            holder: granted,
+            roles: mask,
+        } => assert_eq!((granted, mask), (holder, roles)),
+        _ => panic!("expected ManageRoles instruction"),
+    }
+}
+
+#[test]
+fn test_pool_update_approval_flow_uses_one_pending_action() {
+    let keys = keys();
+    let proposer = Pubkey::new_unique();
+    let approver = Pubkey::new_unique();
+    let pending_action = instruction::pending_action_address(&keys.pool, 4);
+    assert_eq!(pending_action, PendingAction::find_pda(&keys.pool, 4).0);
+
+    let update = SensitivePoolUpdate {
+        is_paused: Some(true),
+        ..Default::default()
+    };
+    let ix = instruction::propose_pool_update(&keys.pool, &proposer, 4, update);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ProposePoolUpdateAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.pending_action.key, &pending_action);
+    assert!(ctx.accounts.proposer.is_signer && ctx.accounts.proposer.is_writable);
+
+    let ix = instruction::approve_pool_update(&keys.pool, &approver, 4);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ApprovePoolUpdateAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.pending_action.key, &pending_action);
+    assert!(ctx.accounts.approver.is_signer);
+
+    let ix = instruction::execute_pool_update(&keys.pool, &approver, &proposer, 4);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ExecutePoolUpdateAccounts::context(&infos).unwrap();
+    assert!(ctx.accounts.pool.is_writable);
+    assert_eq!(ctx.accounts.pending_action.key, &pending_action);
+    assert_eq!(ctx.accounts.proposer.key, &proposer);
+    assert!(ctx.accounts.proposer.is_writable);
+}
+
+#[test]
+fn test_stake_labels_are_zero_padded() {
+    let label = instruction::stake_label("treasury-Q3").unwrap();
+    assert_eq!(&label[..11], b"treasury-Q3");
+    assert!(label[11..].iter().all(|byte| *byte == 0));
+
+    assert!(instruction::stake_label(&"x".repeat(StakeAccount::LABEL_LEN)).is_some());
+    assert_eq!(
+        instruction::stake_label(&"x".repeat(StakeAccount::LABEL_LEN + 1)),
+        None
+    );
+}
+
+#[test]
+fn test_set_stake_label_targets_the_owners_stake_account() {
+    let pool = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let label = instruction::stake_label("treasury-Q3").unwrap();
+
+    let ix = instruction::set_stake_label(&pool, &owner, 2, label);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = SetStakeLabelAccounts::context(&infos).unwrap();
+
+    assert_eq!(
+        ctx.accounts.stake_account.key,
+        &StakeAccount::find_pda(&pool, &owner, 2).0
+    );
+    assert!(ctx.accounts.stake_account.is_writable);
+    assert!(ctx.accounts.owner.is_signer);
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::SetStakeLabel { label: parsed } if parsed == label
+    ));
+}
+
+#[test]
+fn test_accept_stake_ownership_derives_both_stake_accounts() {
+    let keys = keys();
+    let pool = keys.pool;
+    let previous_owner = Pubkey::new_unique();
+    let new_owner = Pubkey::new_unique();
+
+    let ix = instruction::accept_stake_ownership(&keys, &previous_owner, 2, &new_owner, 0);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = AcceptStakeOwnershipAccounts::context(&infos).unwrap();
+
+    assert_eq!(
+        ctx.accounts.stake_account.key,
+        &StakeAccount::find_pda(&pool, &previous_owner, 2).0
+    );
+    assert_eq!(
+        ctx.accounts.new_stake_account.key,
+        &StakeAccount::find_pda(&pool, &new_owner, 0).0
+    );
+    assert!(ctx.accounts.new_owner.is_signer);
+    assert!(ctx.accounts.new_owner.is_writable);
+    assert_eq!(ctx.accounts.previous_owner.key, &previous_owner);
+    assert!(ctx.accounts.previous_owner.is_writable);
+    assert_eq!(
+        ctx.accounts.previous_stake_position.unwrap().key,
+        &StakePosition::find_pda(&pool, &previous_owner).0
+    );
+    let new_stake_position = ctx.accounts.new_stake_position.unwrap();
+    assert_eq!(
+        new_stake_position.key,
+        &StakePosition::find_pda(&pool, &new_owner).0
+    );
+    assert!(new_stake_position.is_writable);
+    assert!(ctx.accounts.deny_list.is_none());
+}
+
+#[test]
+fn test_sweep_dust_moves_stake_vault_to_treasury() {
+    let keys = keys();
+    let admin = Pubkey::new_unique();
+    let treasury = Pubkey::new_unique();
+
+    let ix = instruction::sweep_dust(&keys, &admin, &treasury);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = SweepDustAccounts::context(&infos).unwrap();
+
+    assert_eq!(ctx.accounts.pool.key, &keys.pool);
+    assert!(ctx.accounts.admin.is_signer);
+    assert_eq!(ctx.accounts.stake_vault.key, &keys.stake_vault);
+    assert!(ctx.accounts.stake_vault.is_writable);
+    assert_eq!(ctx.accounts.treasury.key, &treasury);
+    assert!(ctx.accounts.treasury.is_writable);
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::SweepDust
+    ));
+}
+
+#[test]
+fn test_extend_lockup_targets_the_owners_stake_account() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+
+    let ix = instruction::extend_lockup(&keys, &owner, 2, 86_400);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ExtendLockupAccounts::context(&infos).unwrap();
+
+    assert!(ctx.accounts.pool.is_writable);
+    assert_eq!(
+        ctx.accounts.stake_account.key,
+        &StakeAccount::find_pda(&keys.pool, &owner, 2).0
+    );
+    assert!(ctx.accounts.stake_account.is_writable);
+    assert!(ctx.accounts.owner.is_signer);
+    assert_eq!(ctx.accounts.reward_vault.key, &keys.reward_vault);
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::ExtendLockup {
+            additional_seconds: 86_400
+        }
+    ));
+}
+
+#[test]
+fn test_manage_pool_allowlist_targets_the_pools_page() {
+    let pool = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let member = Pubkey::new_unique();
+
+    let ix = instruction::manage_pool_allowlist(
+        &pool,
+        &admin,
+        &payer,
+        3,
+        vec![member],
+        vec![],
+        Some(true),
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ManagePoolAllowlistAccounts::context(&infos).unwrap();
+
+    assert!(ctx.accounts.pool.is_writable);
+    assert!(ctx.accounts.admin.is_signer);
+    assert_eq!(
+        ctx.accounts.allowlist.key,
+        &PoolAllowlist::find_pda(&pool, 3).0
+    );
+    assert!(ctx.accounts.allowlist.is_writable);
+    assert!(ctx.accounts.payer.is_signer);
+    match StakePoolInstruction::try_from_slice(&ix.data).unwrap() {
+        StakePoolInstruction::ManagePoolAllowlist {
+            page,
+            add,
+            remove,
+            allowlist_required,
+        } => {
+            assert_eq!(page, 3);
+            assert_eq!(add, vec![member]);
+            assert!(remove.is_empty());
+            assert_eq!(allowlist_required, Some(true));
+        }
+        _ => panic!("expected ManagePoolAllowlist instruction"),
+    }
+}
+
+#[test]
+fn test_vesting_stake_creates_the_beneficiarys_stake_account() {
+    let keys = keys();
+    let beneficiary = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let admin_token_account = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+
+    let ix = instruction::vesting_stake(
+        &keys,
+        &beneficiary,
+        &admin,
+        &admin_token_account,
+        &payer,
+        instruction::VestingStakeArgs {
+            amount: 1_000,
+            index: 2,
+            vesting_cliff: 100,
+            vesting_duration: 400,
+            track_position: true,
+        },
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = VestingStakeAccounts::context(&infos).unwrap();
+
+    assert_eq!(
+        ctx.accounts.stake_account.key,
+        &StakeAccount::find_pda(&keys.pool, &beneficiary, 2).0
+    );
+    assert!(!ctx.accounts.beneficiary.is_signer);
+    assert!(ctx.accounts.admin.is_signer);
+    assert!(ctx.accounts.admin_token_account.is_writable);
+    assert_eq!(
+        ctx.accounts.program_authority.key,
+        &ProgramAuthority::find_pda().0
+    );
+    // The directory is the beneficiary's, not the admin's
+    assert_eq!(
+        ctx.accounts.stake_position.unwrap().key,
+        &StakePosition::find_pda(&keys.pool, &beneficiary).0
+    );
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::VestingStake {
+            amount: 1_000,
+            index: 2,
+            vesting_cliff: 100,
+            vesting_duration: 400,
+        }
+    ));
+}
+
+#[test]
+fn test_execute_slash_sends_to_the_treasury_or_reward_vault() {
+    let keys = keys();
+    let stake_account = StakeAccount::find_pda(&keys.pool, &Pubkey::new_unique(), 0).0;
+    let executor = Pubkey::new_unique();
+    let proposer = Pubkey::new_unique();
+    let treasury = Pubkey::new_unique();
+
+    let ix = instruction::execute_slash(&keys, &stake_account, &executor, &proposer, None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ExecuteSlashAccounts::context(&infos).unwrap();
+
+    assert_eq!(
+        ctx.accounts.slash_proposal.key,
+        &SlashProposal::find_pda(&stake_account).0
+    );
+    assert!(ctx.accounts.executor.is_signer);
+    assert!(ctx.accounts.proposer.is_writable);
+    assert_eq!(ctx.accounts.destination.key, &keys.reward_vault);
+    assert_eq!(
+        ctx.accounts.mint_registry.key,
+        &MintRegistry::find_pda(&keys.stake_mint).0
+    );
+
+    let ix =
+        instruction::execute_slash(&keys, &stake_account, &executor, &proposer, Some(&treasury));
+    assert_eq!(ix.accounts[6].pubkey, treasury);
+    assert!(ix.accounts[6].is_writable);
+}
+
+#[test]
+fn test_initialize_program_config_creates_the_config_pda() {
+    let authority = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+
+    let ix =
+        instruction::initialize_program_config(&authority, &payer, 0b101, 500_000_000, 604_800);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = InitializeProgramConfigAccounts::context(&infos).unwrap();
+
+    assert_eq!(
+        ctx.accounts.program_config.key,
+        &ProgramConfig::find_pda().0
+    );
+    assert!(ctx.accounts.program_config.is_writable);
+    assert!(ctx.accounts.authority.is_signer);
+    assert!(ctx.accounts.payer.is_signer && ctx.accounts.payer.is_writable);
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::InitializeProgramConfig {
+            feature_flags: 0b101,
+            max_reward_rate: 500_000_000,
+            min_lockup_period: 604_800,
+        }
+    ));
+}
+
+#[test]
+fn test_manage_authorized_creators_can_grow_program_authority() {
+    let authority = Pubkey::new_unique();
+
+    let ix =
+        instruction::manage_authorized_creators(&authority, vec![Pubkey::new_unique()], vec![]);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ManageAuthorizedCreatorsAccounts::context(&infos).unwrap();
+
+    assert!(ctx.accounts.program_authority.is_writable);
+    // The authority pays when the creator list outgrows the account
+    let payer = ctx.accounts.payer.unwrap();
+    assert_eq!(payer.key, &authority);
+    assert!(payer.is_signer && payer.is_writable);
+    assert!(ctx.accounts.system_program.is_some());
+
+    let ix = instruction::migrate_program_authority(&authority, &authority);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = MigrateProgramAuthorityAccounts::context(&infos).unwrap();
+    assert_eq!(
+        ctx.accounts.program_authority.key,
+        &ProgramAuthority::find_pda().0
+    );
+    assert!(ctx.accounts.payer.is_signer && ctx.accounts.payer.is_writable);
+
+    let ix = instruction::set_max_creators(&authority, 25);
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::SetMaxCreators { max_creators: 25 }
+    ));
+}
+
+#[test]
+fn test_clone_pool_appends_the_source_pool() {
+    let keys = keys();
+    let source = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+
+    let ix = instruction::clone_pool(
+        &source,
+        &keys,
+        &payer,
+        instruction::ClonePoolArgs {
+            pool_id: 4,
+            pool_end_date: Some(1_800_000_000),
+            bonus_end_ts: None,
+            registry_page: 1,
+        },
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ClonePoolAccounts::context(&infos).unwrap();
+
+    assert_eq!(ctx.accounts.source_pool.key, &source);
+    assert!(!ctx.accounts.source_pool.is_writable);
+    assert_eq!(ctx.accounts.pool.key, &keys.pool);
+    assert_eq!(ctx.accounts.fee_vault.key, &keys.fee_vault);
+    assert!(ctx.accounts.payer.is_signer && ctx.accounts.payer.is_writable);
+    assert_eq!(ctx.accounts.pool_registry.key, &PoolRegistry::find_pda(1).0);
+    assert_eq!(
+        ctx.accounts.previous_pool_registry.unwrap().key,
+        &PoolRegistry::find_pda(0).0
+    );
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::ClonePool {
+            pool_id: 4,
+            pool_end_date: Some(1_800_000_000),
+            bonus_end_ts: None,
+        }
+    ));
+}
+
+#[test]
+fn test_reward_stream_instructions_use_the_stream_pda() {
+    let keys = keys();
+    let funder = Pubkey::new_unique();
+    let funder_token_account = Pubkey::new_unique();
+    let stream = RewardStream::find_pda(&keys.pool, &funder).0;
+
+    let ix =
+        instruction::create_reward_stream(&keys, &funder, &funder_token_account, 50, 1_800_000_000);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = CreateRewardStreamAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.reward_stream.key, &stream);
+    assert!(ctx.accounts.funder.is_signer && ctx.accounts.funder.is_writable);
+    assert_eq!(ctx.accounts.reward_mint.key, &keys.reward_mint);
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::CreateRewardStream {
+            rate_per_second: 50,
+            end_ts: 1_800_000_000,
+        }
+    ));
+
+    let ix = instruction::crank_reward_stream(&keys, &funder, &funder_token_account, None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = CrankRewardStreamAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.reward_stream.key, &stream);
+    assert_eq!(ctx.accounts.funder_token_account.key, &funder_token_account);
+    assert_eq!(ctx.accounts.reward_vault.key, &keys.reward_vault);
+    assert!(ix.accounts.iter().all(|meta| !meta.is_signer));
+
+    let ix = instruction::close_reward_stream(&keys, &funder, &funder_token_account);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = CloseRewardStreamAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.reward_stream.key, &stream);
+    assert!(ctx.accounts.funder.is_signer);
+}
+
+#[test]
+fn test_fund_keeper_bounty_is_signed_by_the_funder() {
+    let pool = Pubkey::new_unique();
+    let funder = Pubkey::new_unique();
+
+    let ix = instruction::fund_keeper_bounty(&pool, &funder, 1_000_000);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = FundKeeperBountyAccounts::context(&infos).unwrap();
+
+    assert_eq!(ctx.accounts.pool.key, &pool);
+    assert!(ctx.accounts.pool.is_writable);
+    assert!(ctx.accounts.funder.is_signer && ctx.accounts.funder.is_writable);
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::FundKeeperBounty {
+            lamports: 1_000_000
+        }
+    ));
+}
+
+#[test]
+fn test_resize_pool_is_paid_by_the_admin() {
+    let pool = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+
+    let ix = instruction::resize_pool(&pool, &admin, 2_048);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ResizePoolAccounts::context(&infos).unwrap();
+
+    assert_eq!(ctx.accounts.pool.key, &pool);
+    assert!(ctx.accounts.pool.is_writable);
+    assert!(ctx.accounts.admin.is_signer && ctx.accounts.admin.is_writable);
+    assert_eq!(
+        ctx.accounts.program_authority.key,
+        &instruction::program_authority_address()
+    );
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::ResizePool { new_len: 2_048 }
+    ));
+}
+
+#[test]
+fn test_audit_pool_passes_stakes_as_remaining_accounts() {
+    let pool = Pubkey::new_unique();
+    let stakes = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let ix = instruction::audit_pool(&pool, &stakes, None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = AuditPoolAccounts::context(&infos).unwrap();
+    assert!(ctx.accounts.admin.is_none());
+    assert!(ctx.accounts.program_authority.is_none());
+    let remaining: Vec<_> = ctx.remaining_accounts.iter().map(|a| *a.key).collect();
+    assert_eq!(remaining, stakes);
+    assert!(ctx.remaining_accounts.iter().all(|a| !a.is_writable));
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::AuditPool { repair: false }
+    ));
+
+    let admin = Pubkey::new_unique();
+    let ix = instruction::audit_pool(&pool, &stakes, Some(&admin));
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = AuditPoolAccounts::context(&infos).unwrap();
+    assert!(ctx.accounts.pool.is_writable);
+    assert!(ctx.accounts.admin.unwrap().is_signer);
+    assert_eq!(
+        ctx.accounts.program_authority.unwrap().key,
+        &ProgramAuthority::find_pda().0
+    );
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::AuditPool { repair: true }
+    ));
+}
+
+#[test]
+fn test_wind_down_stake_pays_to_owner_associated_accounts() {
+    use your_wallet_stake_pool::spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
+
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+
+    let ix = instruction::wind_down_stake(&keys, &owner, 4);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = WindDownStakeAccounts::context(&infos).unwrap();
+    assert_eq!(
+        ctx.accounts.stake_account.key,
+        &StakeAccount::find_pda(&keys.pool, &owner, 4).0
+    );
+    assert!(!ctx.accounts.owner.is_signer);
+    assert!(ctx.accounts.owner.is_writable);
+    assert_eq!(
+        ctx.accounts.owner_token_account.key,
+        &get_associated_token_address_with_program_id(
+            &owner,
+            &keys.stake_mint,
+            &keys.token_program,
+        )
+    );
+    assert_eq!(
+        ctx.accounts.owner_reward_account.unwrap().key,
+        &instruction::associated_reward_account(&keys, &owner)
+    );
+    assert_eq!(
+        ctx.accounts.mint_registry.key,
+        &MintRegistry::find_pda(&keys.stake_mint).0
+    );
+    assert_eq!(
+        ctx.accounts.stake_position.unwrap().key,
+        &StakePosition::find_pda(&keys.pool, &owner).0
+    );
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::WindDownStake
+    ));
+}
+
+#[test]
+fn test_hooked_pool_instructions_pass_the_hook_and_its_accounts() {
+    let hook_program = Pubkey::new_unique();
+    let hook_account = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    let ix = instruction::stake(
+        &keys(),
+        &owner,
+        &Pubkey::new_unique(),
+        &owner,
+        instruction::StakeArgs::new(500, 0),
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = StakeAccounts::context(&infos).unwrap();
+    assert!(ctx.accounts.hook_program.is_none());
+
+    let keys = keys().with_hook_program(hook_program);
+
+    let mut ix = instruction::unstake(&keys, &owner, 1, &Pubkey::new_unique(), 500, None, None);
+    ix.accounts.push(AccountMeta::new(hook_account, false));
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = UnstakeAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.hook_program.unwrap().key, &hook_program);
+    assert_eq!(ctx.remaining_accounts.len(), 1);
+    assert_eq!(ctx.remaining_accounts[0].key, &hook_account);
+
+    let ix = instruction::claim_rewards(&keys, &owner, 1, &Pubkey::new_unique(), None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ClaimRewardsAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.hook_program.unwrap().key, &hook_program);
+
+    let ix = instruction::set_pool_hook(&keys.pool, &owner, Some(hook_program));
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = SetPoolHookAccounts::context(&infos).unwrap();
+    assert!(ctx.accounts.pool.is_writable);
+    assert!(ctx.accounts.admin.is_signer);
+    assert_eq!(
+        ctx.accounts.hook_allowlist.unwrap().key,
+        &HookAllowlist::find_pda().0
+    );
+}
+
+#[test]
+fn test_points_instructions_target_the_owners_user_points() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let user_points = UserPoints::find_pda(&keys.pool, &owner).0;
+
+    let ix = instruction::sync_points(&keys.pool, &owner, 2, &payer);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = SyncPointsAccounts::context(&infos).unwrap();
+    assert_eq!(
+        ctx.accounts.stake_account.key,
+        &StakeAccount::find_pda(&keys.pool, &owner, 2).0
+    );
+    assert!(ctx.accounts.stake_account.is_writable);
+    assert_eq!(ctx.accounts.user_points.key, &user_points);
+    assert!(ctx.accounts.payer.is_signer);
+
+    let ix = instruction::get_user_points(&keys.pool, &owner, &[0, 3]);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = GetUserPointsAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.user_points.key, &user_points);
+    assert_eq!(ctx.remaining_accounts.len(), 2);
+    assert_eq!(
+        ctx.remaining_accounts[1].key,
+        &StakeAccount::find_pda(&keys.pool, &owner, 3).0
+    );
+    assert!(ix
+        .accounts
+        .iter()
+        .all(|meta| !meta.is_writable && !meta.is_signer));
+}
+
+#[test]
+fn test_deny_list_pools_pass_the_deny_list() {
+    let owner = Pubkey::new_unique();
+    let hook_program = Pubkey::new_unique();
+    let deny_list = DenyList::find_pda().0;
+
+    let ix = instruction::withdraw_unstaked(&keys(), &owner, 0, &Pubkey::new_unique());
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = WithdrawUnstakedAccounts::context(&infos).unwrap();
+    assert!(ctx.accounts.deny_list.is_none());
+
+    let keys = keys().with_deny_list().with_hook_program(hook_program);
+
+    let ix = instruction::stake(
+        &keys,
+        &owner,
+        &Pubkey::new_unique(),
+        &owner,
+        instruction::StakeArgs::new(500, 0),
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = StakeAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.deny_list.unwrap().key, &deny_list);
+    assert_eq!(ctx.accounts.hook_program.unwrap().key, &hook_program);
+
+    let ix = instruction::unstake(&keys, &owner, 1, &Pubkey::new_unique(), 500, None, None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = UnstakeAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.deny_list.unwrap().key, &deny_list);
+
+    let ix = instruction::claim_rewards(&keys, &owner, 1, &Pubkey::new_unique(), None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ClaimRewardsAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.deny_list.unwrap().key, &deny_list);
+
+    let ix = instruction::withdraw_unstaked(&keys, &owner, 0, &Pubkey::new_unique());
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = WithdrawUnstakedAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.deny_list.unwrap().key, &deny_list);
+
+    let new_owner = Pubkey::new_unique();
+    let ix = instruction::nominate_stake_owner(&keys, &owner, 1, Some(&new_owner));
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = NominateStakeOwnerAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.pool.key, &keys.pool);
+    assert_eq!(ctx.accounts.deny_list.unwrap().key, &deny_list);
+
+    let ix = instruction::accept_stake_ownership(&keys, &owner, 1, &new_owner, 0);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = AcceptStakeOwnershipAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.deny_list.unwrap().key, &deny_list);
+
+    let ix = instruction::manage_deny_list(&owner, &owner, vec![hook_program], vec![], Some(false));
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ManageDenyListAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.deny_list.key, &deny_list);
+    assert!(ctx.accounts.deny_list.is_writable);
+    assert!(ctx.accounts.admin.is_signer);
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::ManageDenyList {
+            allow_listed_withdrawals: Some(false),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_split_stake_derives_both_stake_accounts() {
+    let pool = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    let ix = instruction::split_stake(&pool, &owner, 0, 3, 400);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = SplitStakeAccounts::context(&infos).unwrap();
+
+    assert_eq!(
+        ctx.accounts.stake_account.key,
+        &StakeAccount::find_pda(&pool, &owner, 0).0
+    );
+    assert_eq!(
+        ctx.accounts.new_stake_account.key,
+        &StakeAccount::find_pda(&pool, &owner, 3).0
+    );
+    assert!(ctx.accounts.new_stake_account.is_writable);
+    assert!(ctx.accounts.owner.is_signer);
+    assert_eq!(
+        ctx.accounts.stake_position.unwrap().key,
+        &StakePosition::find_pda(&pool, &owner).0
+    );
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::SplitStake {
+            amount: 400,
+            new_index: 3
+        }
+    ));
+}
+
+#[test]
+fn test_merge_stakes_closes_the_source_to_the_owner() {
+    let pool = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+
+    let ix = instruction::merge_stakes(&pool, &owner, 0, 3);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = MergeStakesAccounts::context(&infos).unwrap();
+
+    assert_eq!(
+        ctx.accounts.stake_account.key,
+        &StakeAccount::find_pda(&pool, &owner, 0).0
+    );
+    assert_eq!(
+        ctx.accounts.source_stake_account.key,
+        &StakeAccount::find_pda(&pool, &owner, 3).0
+    );
+    assert!(ctx.accounts.source_stake_account.is_writable);
+    assert!(ctx.accounts.owner.is_signer && ctx.accounts.owner.is_writable);
+    assert_eq!(
+        ctx.accounts.stake_position.unwrap().key,
+        &StakePosition::find_pda(&pool, &owner).0
+    );
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::MergeStakes
+    ));
+}
+
+#[test]
+fn test_with_deadline_sets_the_deadline_of_user_instructions() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+
+    let ix = instruction::unstake(&keys, &owner, 1, &account, 500, None, None);
+    let accounts = ix.accounts.clone();
+    let ix = instruction::with_deadline(ix, 1_700_000_060).unwrap();
+    assert_eq!(ix.accounts, accounts);
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::Unstake {
+            amount: 500,
+            deadline: Some(1_700_000_060),
+            ..
+        }
+    ));
+
+    let ix = instruction::claim_rewards(&keys, &owner, 1, &account, None);
+    let ix = instruction::with_deadline(ix, 1_700_000_060).unwrap();
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::ClaimRewards {
+            deadline: Some(1_700_000_060),
+            ..
+        }
+    ));
+
+    // Other instructions have no deadline
+    let ix = instruction::fund_rewards(&keys, &owner, &account, 100);
+    assert!(instruction::with_deadline(ix, 1_700_000_060).is_none());
+}
+
+#[test]
+fn test_with_reward_cohort_passes_the_cohort_and_its_vault() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+    let (reward_cohort, _) = RewardCohort::find_pda(&keys.pool, 4);
+    let (cohort_vault, _) = RewardCohort::find_vault_pda(&reward_cohort);
+
+    let ix = instruction::stake(
+        &keys,
+        &owner,
+        &account,
+        &owner,
+        instruction::StakeArgs {
+            amount: 1_000,
+            ..Default::default()
+        },
+    );
+    let ix = instruction::with_reward_cohort(ix, 4).unwrap();
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = StakeAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.reward_vault.key, &cohort_vault);
+    let cohort_account = ctx.accounts.reward_cohort.unwrap();
+    assert_eq!(cohort_account.key, &reward_cohort);
+    assert!(cohort_account.is_writable);
+
+    let ix = instruction::claim_rewards(&keys, &owner, 1, &account, None);
+    let ix = instruction::with_reward_cohort(ix, 4).unwrap();
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ClaimRewardsAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.reward_vault.key, &cohort_vault);
+    assert!(ctx.accounts.reward_vault.is_writable);
+    assert_eq!(ctx.accounts.reward_cohort.unwrap().key, &reward_cohort);
+
+    // Without a cohort, the pool's reward vault pays
+    let ix = instruction::claim_rewards(&keys, &owner, 1, &account, None);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = ClaimRewardsAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.reward_vault.key, &keys.reward_vault);
+    assert!(ctx.accounts.reward_cohort.is_none());
+
+    let ix = instruction::claim_rewards(&keys, &owner, 1, &account, None);
+    assert!(instruction::with_reward_cohort(ix, 0).is_none());
+    let ix = instruction::fund_rewards(&keys, &owner, &account, 100);
+    assert!(instruction::with_reward_cohort(ix, 4).is_none());
+}
+
+#[test]
+fn test_cohort_funding_targets_the_cohort_pdas() {
+    let keys = keys();
+    let funder = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+    let (reward_cohort, _) = RewardCohort::find_pda(&keys.pool, 2);
+    let (cohort_vault, _) = RewardCohort::find_vault_pda(&reward_cohort);
+
+    let ix = instruction::fund_cohort_rewards(&keys, &funder, &account, 2, 5_000);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = FundCohortRewardsAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.reward_cohort.key, &reward_cohort);
+    assert_eq!(ctx.accounts.cohort_vault.key, &cohort_vault);
+    assert!(ctx.accounts.funder.is_signer && ctx.accounts.funder.is_writable);
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::FundCohortRewards {
+            cohort: 2,
+            amount: 5_000
+        }
+    ));
+
+    let ix = instruction::defund_cohort_rewards(&keys, &funder, &account, 2, 700);
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = DefundCohortRewardsAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.reward_cohort.key, &reward_cohort);
+    assert_eq!(ctx.accounts.cohort_vault.key, &cohort_vault);
+    assert_eq!(ctx.accounts.destination.key, &account);
+    assert!(ctx.accounts.admin.is_signer && ctx.accounts.admin.is_writable);
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::DefundCohortRewards { amount: 700 }
+    ));
+}
+
+#[test]
+fn test_unstake_all_accounts_parse_in_program_order() {
+    let keys = keys();
+    let owner = Pubkey::new_unique();
+    let token_account = Pubkey::new_unique();
+    let reward_account = Pubkey::new_unique();
+    let (reward_cohort, _) = RewardCohort::find_pda(&keys.pool, 3);
+    let (cohort_vault, _) = RewardCohort::find_vault_pda(&reward_cohort);
+
+    let ix = instruction::unstake_all(
+        &keys,
+        &owner,
+        2,
+        &token_account,
+        &reward_account,
+        None,
+        Some(100),
+        None,
+    );
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = UnstakeAllAccounts::context(&infos).unwrap();
+    assert_eq!(
+        ctx.accounts.stake_account.key,
+        &instruction::stake_account_address(&keys.pool, &owner, 2)
+    );
+    // The owner receives the closed stake account's rent
+    assert!(ctx.accounts.owner.is_signer && ctx.accounts.owner.is_writable);
+    assert_eq!(ctx.accounts.user_token_account.key, &token_account);
+    assert_eq!(ctx.accounts.user_reward_account.key, &reward_account);
+    assert_eq!(ctx.accounts.reward_vault.key, &keys.reward_vault);
+    assert_eq!(
+        ctx.accounts.stake_position.unwrap().key,
+        &StakePosition::find_pda(&keys.pool, &owner).0
+    );
+    assert!(ctx.accounts.referrer_reward_account.is_none());
+    assert!(ctx.accounts.reward_cohort.is_none());
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::UnstakeAll {
+            expected_reward_rate: Some(100),
+            memo: None,
+            deadline: None,
+        }
+    ));
+
+    let ix = instruction::with_deadline(ix, 1_700_000_000).unwrap();
+    let ix = instruction::with_reward_cohort(ix, 3).unwrap();
+    let mut storage = Accounts::from_instruction(&ix);
+    let infos = storage.infos();
+    let ctx = UnstakeAllAccounts::context(&infos).unwrap();
+    assert_eq!(ctx.accounts.reward_vault.key, &cohort_vault);
+    assert_eq!(ctx.accounts.reward_cohort.unwrap().key, &reward_cohort);
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&ix.data).unwrap(),
+        StakePoolInstruction::UnstakeAll {
+            deadline: Some(1_700_000_000),
+            ..
+        }
+    ));
+}
@@ -0,0 +1,58 @@
+// ============================================================================
+// Merkle Allowlist Tests
+// ============================================================================
+// The client builds allowlist roots and proofs the program verifies. Every
+// listed owner must get a proof against the root, whatever the tree size.
+
+use solana_program::pubkey::Pubkey;
+use your_wallet_stake_pool::merkle::{leaf_hash, verify_proof};
+use your_wallet_stake_pool_client::merkle::{allowlist_merkle_proof, allowlist_merkle_root};
+
+fn owners(count: usize) -> Vec<Pubkey> {
+    (0..count).map(|_| Pubkey::new_unique()).collect()
+}
+
+#[test]
+fn test_every_owner_proves_membership() {
+    for count in [1, 2, 3, 5, 8, 13, 100] {
+        let owners = owners(count);
+        let root = allowlist_merkle_root(&owners).unwrap();
+
+        for owner in &owners {
+            let proof = allowlist_merkle_proof(&owners, owner).unwrap();
+            assert!(verify_proof(&root, owner, &proof), "{count} owners");
+            // Proofs grow with the log of the list size
+            assert!(proof.len() <= count.next_power_of_two().trailing_zeros() as usize);
+        }
+    }
+}
+
+#[test]
+fn test_single_owner_root_is_its_leaf() {
+    let owner = Pubkey::new_unique();
+
+    assert_eq!(allowlist_merkle_root(&[owner]), Some(leaf_hash(&owner)));
+    assert_eq!(allowlist_merkle_proof(&[owner], &owner), Some(vec![]));
+}
+
+#[test]
+fn test_root_ignores_owner_order_and_duplicates() {
+    let owners = owners(6);
+    let mut shuffled = owners.clone();
+    shuffled.reverse();
+    shuffled.push(owners[2]);
+
+    assert_eq!(
+        allowlist_merkle_root(&owners),
+        allowlist_merkle_root(&shuffled)
+    );
+}
+
+#[test]
+fn test_unlisted_owners_get_no_proof() {
+    let owners = owners(4);
+
+    assert_eq!(allowlist_merkle_root(&[]), None);
+    assert_eq!(allowlist_merkle_proof(&owners, &Pubkey::new_unique()), None);
+    assert_eq!(allowlist_merkle_proof(&[], &owners[0]), None);
+}
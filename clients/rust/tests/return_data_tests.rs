@@ -0,0 +1,100 @@
+// ============================================================================
+// Return Data Decoding Tests
+// ============================================================================
+// View instructions publish Borsh responses with set_return_data. Simulation
+// results strip trailing zero bytes, so decoding must restore them.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_program::pubkey::Pubkey;
+use your_wallet_stake_pool_client::{
+    return_data::{
+        decode_return_data, decode_simulation_return_data, AuthorizationCheck, AuthorizedCreators,
+        ClaimRewardsResult, PendingRewards, PoolStats,
+    },
+    ID,
+};
+
+/// Return data as the runtime reports it: trailing zeros stripped
+fn reported(value: &impl borsh::BorshSerialize) -> Vec<u8> {
+    let mut data = borsh::to_vec(value).unwrap();
+    while data.last() == Some(&0) {
+        data.pop();
+    }
+    data
+}
+
+#[test]
+fn test_decodes_responses_with_stripped_trailing_zeros() {
+    let pending = PendingRewards {
+        current_time: 1_700_000_000,
+        claimable: 0,
+        referral_share: 0,
+        matures_at: 1_700_086_400,
+        projected_rewards: 100_000,
+        reward_rate: 0,
+    };
+    let data = reported(&pending);
+    assert!(data.len() < borsh::to_vec(&pending).unwrap().len());
+    assert_eq!(decode_return_data(&ID, &data), Some(pending));
+
+    let check = AuthorizationCheck {
+        address: Pubkey::new_unique(),
+        authorized: false,
+    };
+    assert_eq!(decode_return_data(&ID, &reported(&check)), Some(check));
+
+    // An unpaused pool's stats end with a zero byte
+    let stats = PoolStats {
+        total_staked: 1_000_000,
+        stake_vault_balance: 1_000_000,
+        total_rewards_owed: 100_000,
+        reward_vault_balance: 50_000,
+        stake_coverage_bps: Some(10_000),
+        reward_coverage_bps: Some(5_000),
+        solvent: false,
+        active_stake_count: Some(3),
+        is_paused: false,
+    };
+    assert_eq!(decode_return_data(&ID, &reported(&stats)), Some(stats));
+
+    // An all-zero response is reported as empty data
+    let claimed = ClaimRewardsResult::Claimed {
+        amount: 0,
+        received: 0,
+    };
+    assert!(reported(&claimed).is_empty());
+    assert_eq!(decode_return_data(&ID, &[]), Some(claimed));
+}
+
+#[test]
+fn test_decodes_simulation_return_data() {
+    let creators = AuthorizedCreators {
+        authority: Pubkey::new_unique(),
+        creators: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+        pending_authority: None,
+    };
+    let base64 = STANDARD.encode(reported(&creators));
+
+    assert_eq!(
+        decode_simulation_return_data(&ID.to_string(), &base64),
+        Some(creators)
+    );
+}
+
+#[test]
+fn test_ignores_other_programs_return_data() {
+    let check = AuthorizationCheck {
+        address: Pubkey::new_unique(),
+        authorized: true,
+    };
+    let data = borsh::to_vec(&check).unwrap();
+
+    assert_eq!(
+        decode_return_data::<AuthorizationCheck>(&Pubkey::new_unique(), &data),
+        None
+    );
+    assert_eq!(
+        decode_simulation_return_data::<AuthorizationCheck>("not a pubkey", "AA=="),
+        None
+    );
+}
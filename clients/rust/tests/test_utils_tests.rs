@@ -0,0 +1,41 @@
+// ============================================================================
+// Test Utilities Tests (`test-utils` feature)
+// ============================================================================
+// The LiteSVM helpers integrators build their fixtures with. These run on the
+// Token-2022 program LiteSVM ships, so they need no program binary.
+
+#![cfg(feature = "test-utils")]
+
+use litesvm::LiteSVM;
+use solana_sdk::signature::Signer;
+use your_wallet_stake_pool_client::test_utils;
+
+#[test]
+fn test_created_token_account_holds_the_minted_amount() {
+    let mut svm = LiteSVM::new();
+    let authority = test_utils::funded_keypair(&mut svm);
+    let owner = test_utils::funded_keypair(&mut svm);
+
+    let mint = test_utils::create_mint(&mut svm, &authority, test_utils::MINT_DECIMALS);
+    let funded =
+        test_utils::create_token_account(&mut svm, &authority, &mint, &owner.pubkey(), 5_000);
+    let empty = test_utils::create_token_account(&mut svm, &authority, &mint, &owner.pubkey(), 0);
+
+    assert_eq!(test_utils::token_balance(&svm, &funded), 5_000);
+    assert_eq!(test_utils::token_balance(&svm, &empty), 0);
+    assert_eq!(
+        svm.get_account(&funded).unwrap().owner,
+        spl_token_2022::id()
+    );
+}
+
+#[test]
+fn test_warps_move_the_clock() {
+    let mut svm = LiteSVM::new();
+
+    test_utils::warp_to(&mut svm, 1_700_000_000);
+    assert_eq!(test_utils::now(&svm), 1_700_000_000);
+
+    test_utils::warp_forward(&mut svm, 86_400);
+    assert_eq!(test_utils::now(&svm), 1_700_086_400);
+}
@@ -1,4 +1,7 @@
-use crate::{error::StakePoolError, state::Key};
+use crate::{
+    error::StakePoolError,
+    state::{Key, PoolVault, StakePool},
+};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey,
@@ -160,16 +163,99 @@ pub fn assert_same_pubkeys(
     }
 }
 
+/// Assert that the given account is the pool vault recorded on the pool.
+///
+/// The stored pubkey is always compared. When the pool recorded a vault bump
+/// (program-derived vault), the address is also re-derived from
+/// `[seed_prefix, pool, bump]` so corruption of the stored vault field is detected.
+pub fn assert_pool_vault(
+    account_name: &str,
+    account: &AccountInfo,
+    pool: &Pubkey,
+    expected: &Pubkey,
+    seed_prefix: &[u8],
+    bump: Option<u8>,
+) -> ProgramResult {
+    assert_same_pubkeys(account_name, account, expected)?;
+
+    if let Some(bump) = bump {
+        assert_pda_with_bump(
+            account_name,
+            account,
+            &crate::ID,
+            &[seed_prefix, pool.as_ref(), &[bump]],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Assert that the given account is `vault` of the pool, resolved from the pool's state.
+///
+/// Passing another of the pool's vaults in the slot (a misordered account list)
+/// fails with `VaultOrderMismatch` naming the vault that was passed, instead
+/// of a bare `AccountMismatch`.
+pub fn assert_vault(
+    account_name: &str,
+    account: &AccountInfo,
+    pool: &Pubkey,
+    pool_data: &StakePool,
+    vault: PoolVault,
+) -> ProgramResult {
+    let (expected, bump) = pool_data.vault(vault);
+    if *account.key != expected {
+        if let Some(passed) = PoolVault::ALL
+            .into_iter()
+            .find(|other| pool_data.vault(*other).0 == *account.key)
+        {
+            msg!(
+                "Account \"{}\" [{}] is the pool's {}, expected its {} [{}]. Check the account order",
+                account_name,
+                account.key,
+                passed.name(),
+                vault.name(),
+                expected,
+            );
+            return Err(StakePoolError::VaultOrderMismatch.into());
+        }
+    }
+
+    assert_pool_vault(account_name, account, pool, &expected, vault.seed(), bump)
+}
+
 /// Assert that the given account has the expected account key.
+///
+/// Types with an 8-byte discriminator must start with it, followed by the key.
+/// Accounts still in the previous layout of the expected type, or written
+/// before the type got its discriminator, fail with `AccountNeedsMigration` so
+/// callers know to run MigratePool or MigrateProgramAuthority first.
 pub fn assert_account_key(account_name: &str, account: &AccountInfo, key: Key) -> ProgramResult {
+    let discriminator = key.discriminator();
+    let legacy_number = key.legacy_version().map(|legacy| legacy as u8);
     let key_number = key as u8;
-    if account.data_len() <= 1 || account.try_borrow_data()?[0] != key_number {
+    let data = account.try_borrow_data()?;
+    let key_offset = discriminator.map_or(0, |discriminator| discriminator.len());
+    let first_byte = data.first().copied();
+    if data.len() > 1
+        && (first_byte == legacy_number
+            || (discriminator.is_some() && first_byte == Some(key_number)))
+    {
+        msg!(
+            "Account \"{}\" [{}] uses a legacy layout, migrate it first",
+            account_name,
+            account.key
+        );
+        Err(StakePoolError::AccountNeedsMigration.into())
+    } else if data.len() <= key_offset + 1
+        || discriminator.is_some_and(|discriminator| !data.starts_with(&discriminator))
+        || data[key_offset] != key_number
+    {
         msg!(
             "Account \"{}\" [{}] expected account key [{}], got [{}]",
             account_name,
             account.key,
             key_number,
-            account.try_borrow_data()?[0]
+            data.get(key_offset).copied().unwrap_or_default()
         );
         Err(StakePoolError::InvalidAccountKey.into())
     } else {
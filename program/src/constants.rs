@@ -1,8 +1,177 @@
+use solana_program::{pubkey, pubkey::Pubkey};
+
 /// Maximum allowed reward rate (1000% when scaled by REWARD_SCALE)
-/// This prevents misconfiguration of reward rates that could drain the reward vault
+/// This prevents misconfiguration of reward rates that could drain the reward vault.
+/// Deployments may lower it through `ProgramConfig::max_reward_rate`, never raise it.
 pub const MAX_REWARD_RATE: u64 = 1_000_000_000_000; // 1000% * 1e9
 
+/// Minimum lockup period enforced during pool initialization (1 day = 86400 seconds).
+///
+/// Deployments may raise it through `ProgramConfig::min_lockup_period`, never lower it.
+///
+/// **Security Rationale [H-02]:**
+/// Prevents reward vault drain attacks where attackers could:
+/// 1. Set lockup to 1 second
+/// 2. Stake tokens
+/// 3. Wait 1 second
+/// 4. Claim full rewards instantly
+/// 5. Drain the reward vault
+///
+/// **Business Rationale:**
+/// Ensures meaningful staking commitment and prevents gaming of reward mechanics.
+/// Can be adjusted per deployment requirements (7 days, 30 days, etc.)
+///
+/// **Current Value**: 86400 seconds (1 day)
+pub const MIN_LOCKUP_PERIOD: i64 = 86400;
+
 /// Scale factor for reward rate calculations (1e9)
 /// Reward rates are stored as scaled integers to maintain precision
 /// Example: 100_000_000 = 10% reward rate (100_000_000 / 1_000_000_000 = 0.10)
 pub const REWARD_SCALE: u128 = 1_000_000_000;
+
+/// Scale factor of pool points rates (1e9)
+/// Example: 1_000_000_000 = 1 point per staked base unit per second
+pub const POINTS_SCALE: u128 = 1_000_000_000;
+
+/// Basis points denominator (10_000 bps = 100%)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Maximum share of claimed rewards that can be routed to a referrer (20%)
+pub const MAX_REFERRAL_BPS: u16 = 2_000;
+
+/// Maximum deposit or withdrawal fee a pool can charge (10%)
+pub const MAX_FEE_BPS: u16 = 1_000;
+
+/// Highest reward multiplier a stake size tier can grant (3x, in basis points)
+/// Bounds how far a tier can raise a large stake's rate above the pool's reward_rate.
+pub const MAX_REWARD_MULTIPLIER_BPS: u16 = 30_000;
+
+/// Time delay before a reward rate change can be finalized (7 days = 604800 seconds).
+///
+/// Default for pools created without their own delay; pools may choose one between
+/// `MIN_REWARD_RATE_CHANGE_DELAY` and `MAX_REWARD_RATE_CHANGE_DELAY` at initialization.
+///
+/// **Security [L-01]:**
+/// Provides users notice to unstake if they disagree with new rate.
+/// Prevents centralized surprise changes to reward rates.
+///
+/// **Design Rationale:**
+/// - 7 days balances user protection vs operational flexibility
+/// - Industry standard for time-locked governance operations
+/// - Sufficient time for users to monitor and react to changes
+/// - Aligns with common DeFi governance timelock periods
+///
+/// **Cooldown Enforcement:**
+/// After finalization, another 7-day cooldown is enforced before
+/// proposing a new rate change (prevents authority from chaining
+/// rapid rate changes to bypass the time-lock).
+///
+/// **Current Value**: 604800 seconds (7 days)
+pub const REWARD_RATE_CHANGE_DELAY: i64 = 604800;
+
+/// Shortest reward rate change delay a pool can be created with on Mainnet (24 hours)
+/// Pools choose their own delay at initialization (see `StakePool::reward_rate_change_delay`);
+/// this floor keeps even short campaign pools giving stakers a day to react.
+pub const MIN_REWARD_RATE_CHANGE_DELAY: i64 = 86400;
+
+/// Longest reward rate change delay a pool can be created with (30 days)
+pub const MAX_REWARD_RATE_CHANGE_DELAY: i64 = 2_592_000;
+
+/// Reward rate change delay and cooldown on Devnet-profile deployments (1 hour)
+/// Short enough to exercise the full propose/finalize flow during testing.
+pub const DEVNET_REWARD_RATE_CHANGE_DELAY: i64 = 3600;
+
+/// Time after lockup completion (2 years) after which a stake's unclaimed rewards
+/// may be moved to the pool's expired rewards, claimable by governance
+pub const REWARD_EXPIRY_PERIOD: i64 = 63_072_000;
+
+/// Time after a pool's end date (90 days) after which anyone may return its
+/// remaining stakes to their owners with WindDownStake
+pub const WIND_DOWN_DELAY: i64 = 7_776_000;
+
+/// Longest unstake cooldown a pool can be configured with (30 days)
+/// Bounds how long an admin can hold requested withdrawals in the vault.
+pub const MAX_UNSTAKE_COOLDOWN: i64 = 2_592_000;
+
+/// Seconds in a (365-day) year, the period lockup extension bonus rates are expressed over
+pub const SECONDS_PER_YEAR: i64 = 31_536_000;
+
+/// Largest difference between a pool's reward and stake mint decimals
+/// Reward rates are scaled by 10^difference between the two mints' units; beyond
+/// 18 the scaled rates no longer fit the u128 reward math.
+pub const MAX_DECIMALS_SHIFT: u8 = 18;
+
+/// Longest total extension ExtendLockup may add to a stake's lockup round (4 years)
+pub const MAX_LOCKUP_EXTENSION: i64 = 126_144_000;
+
+/// Longest vesting schedule VestingStake may grant (10 years)
+pub const MAX_VESTING_DURATION: i64 = 315_360_000;
+
+/// Delay between ProposeSlash and the earliest ExecuteSlash (48 hours)
+/// Gives the staker and other admins time to react to a proposed slash.
+pub const SLASH_TIMELOCK: i64 = 172_800;
+
+/// Shortest delay a recovery authority's takeover can be configured with (7 days)
+/// Leaves the current authority time to notice and cancel a rogue takeover.
+pub const MIN_RECOVERY_DELAY: i64 = 604_800;
+
+/// Longest delay a recovery authority's takeover can be configured with (1 year)
+pub const MAX_RECOVERY_DELAY: i64 = 31_536_000;
+
+/// Most stake accounts a single StakeBatch instruction can create
+/// Keeps the instruction within the compute and transaction size limits.
+pub const MAX_STAKE_BATCH: usize = 5;
+
+/// Most sibling hashes an allowlist merkle proof may carry
+/// Enough for trees of 2^24 owners while bounding the hashing a stake pays for.
+pub const MAX_MERKLE_PROOF_LEN: usize = 24;
+
+/// Longest memo, in bytes, Stake, Unstake and ClaimRewards attach via the SPL Memo program
+pub const MAX_MEMO_LEN: usize = 256;
+
+/// Length of the windows a pool's outflow limit caps unstakes and claims over (24 hours)
+pub const OUTFLOW_WINDOW: i64 = 86_400;
+
+/// Largest keeper bounty a pool can pay per crank (0.01 SOL)
+pub const MAX_KEEPER_BOUNTY: u64 = 10_000_000;
+
+/// Shortest time between two keeper bounty payouts of a pool (1 hour), so
+/// repeatable cranks such as SyncPool cannot drain the escrow
+pub const KEEPER_BOUNTY_INTERVAL: i64 = 3_600;
+
+/// Largest size ResizePool grows a pool account to (10 KiB), bounding the rent
+/// an admin can commit and the data every pool load reads
+pub const MAX_POOL_ACCOUNT_LEN: usize = 10_240;
+
+/// Version of this program build (major, minor, patch), recorded in ProgramConfig
+pub const PROGRAM_VERSION: [u16; 3] = parse_version(env!("CARGO_PKG_VERSION"));
+
+/// Parse a "major.minor.patch" version, ignoring any pre-release suffix
+const fn parse_version(version: &str) -> [u16; 3] {
+    let bytes = version.as_bytes();
+    let mut parts = [0u16; 3];
+    let mut part = 0;
+    let mut i = 0;
+    while i < bytes.len() && part < 3 {
+        match bytes[i] {
+            b'.' => part += 1,
+            digit @ b'0'..=b'9' => parts[part] = parts[part] * 10 + (digit - b'0') as u16,
+            _ => break,
+        }
+        i += 1;
+    }
+    parts
+}
+
+/// Wrapped SOL mint of the SPL Token program
+/// Pools staking this mint (or Token-2022's native mint) accept lamports via StakeSol
+pub const NATIVE_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
+
+/// Shortest cohort a pool can split its stakers into by stake time (1 day)
+/// Bounds the number of cohort vaults a pool can accumulate.
+pub const MIN_REWARD_COHORT_DURATION: i64 = 86_400;
+
+/// Longest reward cohort (365 days)
+/// Keeps every stake since the pool's creation out of cohort 0, which stands for
+/// the pool's own reward vault.
+pub const MAX_REWARD_COHORT_DURATION: i64 = 365 * 86_400;
@@ -0,0 +1,307 @@
+//! Cross-program invocation helpers
+//!
+//! Programs that stake on behalf of their users (vaults, DAOs, aggregators)
+//! can call into the pool without assembling instructions by hand. Each helper
+//! takes a [`CpiContext`] holding this program's account, the instruction's
+//! accounts as the ShankContext struct the processor parses them into, and the
+//! seeds of any PDA signing for the caller. It builds the instruction with the
+//! signer and writable flags the processor expects and invokes it.
+//!
+//! Optional accounts left as `None` are passed as this program's account,
+//! which the processor reads as absent. Instructions returning data (such as
+//! `ClaimRewards`) publish it as usual; read it with
+//! `solana_program::program::get_return_data` after the call.
+//!
+//! Only compiled with the `no-entrypoint` feature, which programs depending on
+//! this crate enable anyway.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+    pubkey::Pubkey,
+};
+
+use crate::error::StakePoolError;
+use crate::instruction::accounts::{
+    ClaimRewardsAccounts, FundRewardsAccounts, StakeAccounts, UnstakeAccounts,
+};
+use crate::instruction::StakePoolInstruction;
+
+/// Accounts and signer seeds of a call into the stake pool program
+pub struct CpiContext<'a, 'b, T> {
+    /// The stake pool program's account
+    pub program: &'a AccountInfo<'a>,
+    /// The instruction's accounts
+    pub accounts: T,
+    /// Seeds of the PDAs signing for the calling program
+    pub signer_seeds: &'b [&'b [&'b [u8]]],
+    /// Accounts passed after the instruction's own, e.g. those of a pool's hook program
+    pub remaining_accounts: &'a [AccountInfo<'a>],
+}
+
+impl<'a, 'b, T> CpiContext<'a, 'b, T> {
+    /// A call whose signers all signed the outer transaction
+    pub fn new(program: &'a AccountInfo<'a>, accounts: T) -> Self {
+        Self {
+            program,
+            accounts,
+            signer_seeds: &[],
+            remaining_accounts: &[],
+        }
+    }
+
+    /// A call signed by the calling program's PDAs
+    pub fn new_with_signer(
+        program: &'a AccountInfo<'a>,
+        accounts: T,
+        signer_seeds: &'b [&'b [&'b [u8]]],
+    ) -> Self {
+        Self {
+            program,
+            accounts,
+            signer_seeds,
+            remaining_accounts: &[],
+        }
+    }
+
+    /// The same call, passing `remaining_accounts` after the instruction's own
+    pub fn with_remaining_accounts(self, remaining_accounts: &'a [AccountInfo<'a>]) -> Self {
+        Self {
+            remaining_accounts,
+            ..self
+        }
+    }
+}
+
+/// Account metas and infos of the instruction being built, in account order
+struct CpiAccounts<'a> {
+    program: &'a AccountInfo<'a>,
+    metas: Vec<AccountMeta>,
+    infos: Vec<AccountInfo<'a>>,
+}
+
+impl<'a> CpiAccounts<'a> {
+    fn new(program: &'a AccountInfo<'a>) -> Self {
+        Self {
+            program,
+            metas: Vec::new(),
+            infos: vec![program.clone()],
+        }
+    }
+
+    fn add(mut self, account: &AccountInfo<'a>, is_writable: bool, is_signer: bool) -> Self {
+        self.metas.push(AccountMeta {
+            pubkey: *account.key,
+            is_signer,
+            is_writable,
+        });
+        self.infos.push(account.clone());
+        self
+    }
+
+    /// Add an optional account, passing the program's account when absent
+    fn add_optional(self, account: Option<&AccountInfo<'a>>, is_writable: bool) -> Self {
+        match account {
+            Some(account) => self.add(account, is_writable, false),
+            None => {
+                let program = self.program;
+                self.add(program, false, false)
+            }
+        }
+    }
+
+    /// Add an optional signer, passing the program's account when absent
+    fn add_optional_signer(self, account: Option<&AccountInfo<'a>>) -> Self {
+        match account {
+            Some(account) => self.add(account, false, true),
+            None => self.add_optional(None, false),
+        }
+    }
+
+    /// Add accounts after the instruction's own, keeping their writable flag
+    fn add_remaining(mut self, accounts: &[AccountInfo<'a>]) -> Self {
+        for account in accounts {
+            self = self.add(account, account.is_writable, false);
+        }
+        self
+    }
+
+    fn invoke(
+        self,
+        instruction: &StakePoolInstruction,
+        signer_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        let data = borsh::to_vec(instruction).map_err(|_| StakePoolError::SerializationError)?;
+        let instruction = Instruction {
+            program_id: crate::ID,
+            accounts: self.metas,
+            data,
+        };
+        invoke_signed(&instruction, &self.infos, signer_seeds)
+    }
+}
+
+/// Stake `amount` into a new stake account at `index`
+/// (None: the next index of the owner's stake position directory)
+///
+/// With a `funder`, the funder signs for its token account and the owner does not sign.
+#[allow(clippy::too_many_arguments)]
+pub fn stake<'a>(
+    ctx: CpiContext<'a, '_, StakeAccounts<'a>>,
+    amount: u64,
+    index: Option<u64>,
+    expected_reward_rate: Option<u64>,
+    expected_lockup_period: Option<i64>,
+    referrer: Option<Pubkey>,
+    auto_relock: bool,
+    label: Option<[u8; 32]>,
+    allowlist_proof: Option<Vec<[u8; 32]>>,
+    memo: Option<String>,
+    deadline: Option<i64>,
+) -> ProgramResult {
+    let accounts = ctx.accounts;
+    CpiAccounts::new(ctx.program)
+        .add(accounts.pool, true, false)
+        .add(accounts.stake_account, true, false)
+        .add(accounts.owner, false, accounts.funder.is_none())
+        .add(accounts.user_token_account, true, false)
+        .add(accounts.stake_vault, true, false)
+        .add(accounts.reward_vault, false, false)
+        .add(accounts.stake_mint, false, false)
+        .add(accounts.token_program, false, false)
+        .add(accounts.payer, true, true)
+        .add(accounts.system_program, false, false)
+        .add(accounts.fee_vault, true, false)
+        .add(accounts.mint_registry, true, false)
+        .add_optional(accounts.receipt_mint, true)
+        .add_optional(accounts.user_receipt_account, true)
+        .add_optional(accounts.receipt_token_program, false)
+        .add(accounts.program_authority, false, false)
+        .add_optional(accounts.allowlist, false)
+        .add_optional(accounts.memo_program, false)
+        .add_optional(accounts.stake_position, true)
+        .add_optional_signer(accounts.funder)
+        .add_optional(accounts.deny_list, false)
+        .add_optional(accounts.reward_cohort, true)
+        .add_optional(accounts.hook_program, false)
+        .add_remaining(ctx.remaining_accounts)
+        .invoke(
+            &StakePoolInstruction::Stake {
+                amount,
+                index,
+                expected_reward_rate,
+                expected_lockup_period,
+                referrer,
+                auto_relock,
+                label,
+                allowlist_proof,
+                memo,
+                deadline,
+            },
+            ctx.signer_seeds,
+        )
+}
+
+/// Unstake `amount` from a stake account
+pub fn unstake<'a>(
+    ctx: CpiContext<'a, '_, UnstakeAccounts<'a>>,
+    amount: u64,
+    expected_reward_rate: Option<u64>,
+    memo: Option<String>,
+    deadline: Option<i64>,
+) -> ProgramResult {
+    let accounts = ctx.accounts;
+    CpiAccounts::new(ctx.program)
+        .add(accounts.pool, true, false)
+        .add(accounts.stake_account, true, false)
+        .add(accounts.owner, false, true)
+        .add(accounts.user_token_account, true, false)
+        .add(accounts.stake_vault, true, false)
+        .add(accounts.stake_mint, false, false)
+        .add(accounts.token_program, false, false)
+        .add(accounts.clock, false, false)
+        .add(accounts.fee_vault, true, false)
+        .add(accounts.mint_registry, true, false)
+        .add_optional(accounts.receipt_mint, true)
+        .add_optional(accounts.user_receipt_account, true)
+        .add_optional(accounts.receipt_token_program, false)
+        .add(accounts.program_authority, false, false)
+        .add_optional(accounts.memo_program, false)
+        .add_optional(accounts.deny_list, false)
+        .add_optional(accounts.hook_program, false)
+        .add_remaining(ctx.remaining_accounts)
+        .invoke(
+            &StakePoolInstruction::Unstake {
+                amount,
+                expected_reward_rate,
+                memo,
+                deadline,
+            },
+            ctx.signer_seeds,
+        )
+}
+
+/// Claim `amount` of a stake account's rewards (all unclaimed rewards when None)
+///
+/// The owner is passed writable when `associated_token_program` is given, since
+/// it then pays for the reward account it creates.
+pub fn claim_rewards<'a>(
+    ctx: CpiContext<'a, '_, ClaimRewardsAccounts<'a>>,
+    amount: Option<u64>,
+    memo: Option<String>,
+    deadline: Option<i64>,
+) -> ProgramResult {
+    let accounts = ctx.accounts;
+    CpiAccounts::new(ctx.program)
+        .add(accounts.pool, true, false)
+        .add(accounts.stake_account, true, false)
+        .add(
+            accounts.owner,
+            accounts.associated_token_program.is_some(),
+            true,
+        )
+        .add(accounts.user_reward_account, true, false)
+        .add(accounts.reward_vault, true, false)
+        .add(accounts.reward_mint, false, false)
+        .add(accounts.token_program, false, false)
+        .add(accounts.clock, false, false)
+        .add_optional(accounts.referrer_reward_account, true)
+        .add(accounts.program_authority, false, false)
+        .add_optional(accounts.recipient, true)
+        .add_optional(accounts.associated_token_program, false)
+        .add_optional(accounts.system_program, false)
+        .add_optional(accounts.memo_program, false)
+        .add_optional(accounts.deny_list, false)
+        .add_optional(accounts.reward_cohort, true)
+        .add_optional(accounts.hook_program, false)
+        .add_remaining(ctx.remaining_accounts)
+        .invoke(
+            &StakePoolInstruction::ClaimRewards {
+                amount,
+                memo,
+                deadline,
+            },
+            ctx.signer_seeds,
+        )
+}
+
+/// Fund the pool's reward vault with `amount` reward tokens
+pub fn fund_rewards<'a>(
+    ctx: CpiContext<'a, '_, FundRewardsAccounts<'a>>,
+    amount: u64,
+) -> ProgramResult {
+    let accounts = ctx.accounts;
+    CpiAccounts::new(ctx.program)
+        .add(accounts.pool, true, false)
+        .add(accounts.funder, false, true)
+        .add(accounts.funder_token_account, true, false)
+        .add(accounts.reward_vault, true, false)
+        .add(accounts.reward_mint, false, false)
+        .add(accounts.token_program, false, false)
+        .invoke(
+            &StakePoolInstruction::FundRewards { amount },
+            ctx.signer_seeds,
+        )
+}
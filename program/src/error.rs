@@ -4,6 +4,12 @@ use num_derive::FromPrimitive;
 use solana_program::program_error::ProgramError;
 use thiserror::Error;
 
+use crate::events::{ContextValue, ErrorContextEvent, StakePoolEvent};
+
+/// Errors returned by the program as `ProgramError::Custom(code)`
+///
+/// Codes and names are part of the program's interface: variants are only ever
+/// appended, so a code keeps its meaning across releases.
 #[derive(Error, Clone, Debug, Eq, PartialEq, FromPrimitive)]
 pub enum StakePoolError {
     /// 0 - Error deserializing an account
@@ -126,6 +132,314 @@ pub enum StakePoolError {
     /// 39 - Creator not found in authorized list
     #[error("Creator not found in authorized list")]
     CreatorNotFound,
+    /// 40 - Invalid referrer (self-referral or missing referrer account)
+    #[error("Invalid referrer (self-referral or missing referrer account)")]
+    InvalidReferrer,
+    /// 41 - Token account is not owned by the expected wallet
+    #[error("Token account is not owned by the expected wallet")]
+    InvalidTokenAccountOwner,
+    /// 42 - Pool stake mint is not native SOL
+    #[error("Pool stake mint is not native SOL")]
+    NotNativeSolPool,
+    /// 43 - Auto re-lock is disabled for this stake account
+    #[error("Auto re-lock is disabled for this stake account")]
+    AutoRelockDisabled,
+    /// 44 - Instruction is only available on Devnet-profile deployments
+    #[error("Instruction is only available on Devnet-profile deployments")]
+    DevOnlyInstruction,
+    /// 45 - Funder is not in the pool's reward funder allowlist
+    #[error("Funder is not in the pool's reward funder allowlist")]
+    RewardFunderNotAllowed,
+    /// 46 - Defund amount exceeds the reward vault surplus over owed rewards
+    #[error("Defund amount exceeds the reward vault surplus over owed rewards")]
+    DefundExceedsSurplus,
+    /// 47 - Stake would exceed the stake mint's total value locked cap
+    #[error("Stake would exceed the stake mint's total value locked cap")]
+    TvlCapExceeded,
+    /// 48 - Pool must be paused with nothing staked or owed before closing
+    #[error("Pool must be paused with nothing staked or owed before closing")]
+    PoolNotClosable,
+    /// 49 - Receipt token accounts are required for this pool
+    #[error("Receipt token accounts are required for this pool")]
+    ReceiptAccountsRequired,
+    /// 50 - Stake rewards have not reached their expiry yet
+    #[error("Stake rewards have not reached their expiry yet")]
+    RewardsNotExpired,
+    /// 51 - No expired rewards to move or claim
+    #[error("No expired rewards to move or claim")]
+    NoExpiredRewards,
+    /// 52 - Pool has an unstake cooldown: use RequestUnstake and WithdrawUnstaked
+    #[error("Pool has an unstake cooldown: use RequestUnstake and WithdrawUnstaked")]
+    UnstakeCooldownRequired,
+    /// 53 - Unstake cooldown has not elapsed yet
+    #[error("Unstake cooldown has not elapsed yet")]
+    UnstakeCooldownActive,
+    /// 54 - Stake account has no pending withdrawal
+    #[error("Stake account has no pending withdrawal")]
+    NoPendingWithdrawal,
+    /// 55 - Protocol is globally paused
+    #[error("Protocol is globally paused")]
+    ProtocolPaused,
+    /// 56 - Protocol is in withdrawals-only mode
+    #[error("Protocol is in withdrawals-only mode")]
+    WithdrawalsOnly,
+    /// 57 - Account uses a legacy layout and must be migrated first
+    #[error("Account uses a legacy layout and must be migrated first")]
+    AccountNeedsMigration,
+    /// 58 - Pool registry page is full
+    #[error("Pool registry page is full")]
+    PoolRegistryPageFull,
+    /// 59 - Creator has reached its pool limit
+    #[error("Creator has reached its pool limit")]
+    CreatorPoolLimitReached,
+    /// 60 - Maximum number of role holders reached
+    #[error("Maximum number of role holders reached")]
+    MaxRoleHoldersReached,
+    /// 61 - Sensitive pool updates need multi-admin approval
+    #[error("Sensitive pool updates need multi-admin approval")]
+    ApprovalRequired,
+    /// 62 - Admin already approved this update
+    #[error("Admin already approved this update")]
+    AlreadyApproved,
+    /// 63 - Not enough approvals to execute the update
+    #[error("Not enough approvals to execute the update")]
+    InsufficientApprovals,
+    /// 64 - A lockup change is already pending
+    #[error("A lockup change is already pending")]
+    PendingLockupChangeExists,
+    /// 65 - No pending lockup change to finalize
+    #[error("No pending lockup change to finalize")]
+    NoPendingLockupChange,
+    /// 66 - Lockup change delay has not elapsed
+    #[error("Lockup change delay has not elapsed")]
+    LockupChangeDelayNotElapsed,
+    /// 67 - Reward rate outside the pool's committed bounds
+    #[error("Reward rate outside the pool's committed bounds")]
+    RewardRateOutOfBounds,
+    /// 68 - Claim amount exceeds the unclaimed rewards
+    #[error("Claim amount exceeds the unclaimed rewards")]
+    ClaimExceedsUnclaimedRewards,
+    /// 69 - No owner nominated for the stake account
+    #[error("No owner nominated for the stake account")]
+    NoPendingStakeOwner,
+    /// 70 - Signer is not the nominated stake owner
+    #[error("Signer is not the nominated stake owner")]
+    InvalidPendingStakeOwner,
+    /// 71 - Positions of pools with receipt tokens cannot change owner
+    #[error("Positions of pools with receipt tokens cannot change owner")]
+    ReceiptPositionNotTransferable,
+    /// 72 - The pool offers no bonus for lockup extensions
+    #[error("The pool offers no bonus for lockup extensions")]
+    LockupExtensionDisabled,
+    /// 73 - The stake owner is not on the pool's allowlist
+    #[error("The stake owner is not on the pool's allowlist")]
+    NotAllowlisted,
+    /// 74 - The pool allowlist page is full
+    #[error("The pool allowlist page is full")]
+    PoolAllowlistPageFull,
+    /// 75 - The stake's principal has not vested yet
+    #[error("The stake's principal has not vested yet")]
+    PrincipalNotVested,
+    /// 76 - Rewards can only be claimed while the pool's claim window is open
+    #[error("Rewards can only be claimed while the pool's claim window is open")]
+    ClaimWindowClosed,
+    /// 77 - A slash of the stake is pending
+    #[error("A slash of the stake is pending")]
+    StakeSlashPending,
+    /// 78 - The slash timelock has not passed yet
+    #[error("The slash timelock has not passed yet")]
+    SlashTimelockActive,
+    /// 79 - No authority recovery is in progress
+    #[error("No authority recovery is in progress")]
+    NoRecoveryInProgress,
+    /// 80 - The recovery delay has not passed yet
+    #[error("The recovery delay has not passed yet")]
+    RecoveryDelayActive,
+    /// 81 - Memo is empty or too long
+    #[error("Memo must be between 1 and MAX_MEMO_LEN bytes")]
+    InvalidMemo,
+    /// 82 - One of the pool's vaults was passed in another vault's slot
+    #[error("Pool vault passed in the wrong account slot")]
+    VaultOrderMismatch,
+    /// 83 - The pool's outflow limit for the current window has been reached
+    #[error("The pool's outflow limit for the current window has been reached")]
+    RateLimited,
+    /// 84 - The owner's stake position directory has no room for another index
+    #[error("Stake position directory is full")]
+    StakePositionFull,
+    /// 85 - The rewards would take the pool past its rewards cap
+    #[error("Pool rewards cap exceeded")]
+    RewardsCapExceeded,
+    /// 86 - The audited stake accounts do not cover every active stake of the pool
+    #[error("Audit does not cover every active stake")]
+    IncompleteAudit,
+    /// 87 - A partial unstake would leave less than the pool's minimum stake
+    #[error("Remaining stake below the pool's minimum")]
+    RemainingStakeBelowMinimum,
+    /// 88 - The pool has no end date or its wind-down delay has not passed
+    #[error("Pool wind-down has not started")]
+    WindDownNotStarted,
+    /// 89 - The hook program is not on the hook allowlist, or cannot be listed
+    #[error("Hook program not allowed")]
+    HookProgramNotAllowed,
+    /// 90 - The pool has a hook program and the instruction cannot call it
+    #[error("Pool hook program required")]
+    HookProgramRequired,
+    /// 91 - The pool does not accrue points
+    #[error("Pool points not enabled")]
+    PointsNotEnabled,
+    /// 92 - The address is on the deny list
+    #[error("Address is on the deny list")]
+    AddressDenied,
+    /// 93 - The pool checks the deny list and the instruction did not pass it
+    #[error("Pool deny list required")]
+    DenyListRequired,
+    /// 94 - A funder staked for another owner in a pool without allow_third_party_stake
+    #[error("Third-party stakes not allowed")]
+    ThirdPartyStakeDisabled,
+    /// 95 - The stake accounts differ in a way MergeStakes cannot combine
+    #[error("Stake accounts cannot be merged")]
+    StakesNotMergeable,
+    /// 96 - The transaction executed after the deadline its signer set
+    #[error("Transaction deadline passed")]
+    DeadlineExceeded,
+    /// 97 - The reward cohort account or vault does not match the stake's cohort
+    #[error("Invalid reward cohort")]
+    InvalidRewardCohort,
+}
+
+impl StakePoolError {
+    /// Decode the code of a `ProgramError::Custom` raised by this program
+    pub fn from_code(code: u32) -> Option<Self> {
+        num_traits::FromPrimitive::from_u32(code)
+    }
+
+    /// Variant name, stable across releases like the code itself
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            Self::DeserializationError => "DeserializationError",
+            Self::SerializationError => "SerializationError",
+            Self::InvalidProgramOwner => "InvalidProgramOwner",
+            Self::InvalidPda => "InvalidPda",
+            Self::ExpectedEmptyAccount => "ExpectedEmptyAccount",
+            Self::ExpectedNonEmptyAccount => "ExpectedNonEmptyAccount",
+            Self::ExpectedSignerAccount => "ExpectedSignerAccount",
+            Self::ExpectedWritableAccount => "ExpectedWritableAccount",
+            Self::AccountMismatch => "AccountMismatch",
+            Self::InvalidAccountKey => "InvalidAccountKey",
+            Self::NumericalOverflow => "NumericalOverflow",
+            Self::PoolPaused => "PoolPaused",
+            Self::AmountBelowMinimum => "AmountBelowMinimum",
+            Self::InsufficientStakedBalance => "InsufficientStakedBalance",
+            Self::LockupNotExpired => "LockupNotExpired",
+            Self::InsufficientRewards => "InsufficientRewards",
+            Self::Unauthorized => "Unauthorized",
+            Self::InvalidTokenProgram => "InvalidTokenProgram",
+            Self::InvalidMint => "InvalidMint",
+            Self::InvalidAccountDiscriminator => "InvalidAccountDiscriminator",
+            Self::PoolParametersChanged => "PoolParametersChanged",
+            Self::NoPendingAuthority => "NoPendingAuthority",
+            Self::InvalidPendingAuthority => "InvalidPendingAuthority",
+            Self::PoolEnded => "PoolEnded",
+            Self::InvalidParameters => "InvalidParameters",
+            Self::InvalidVaultOwner => "InvalidVaultOwner",
+            Self::UnsafeTokenExtension => "UnsafeTokenExtension",
+            Self::UnexpectedBalanceChange => "UnexpectedBalanceChange",
+            Self::MintHasFreezeAuthority => "MintHasFreezeAuthority",
+            Self::RewardRateChangeDelayNotElapsed => "RewardRateChangeDelayNotElapsed",
+            Self::NoPendingRewardRateChange => "NoPendingRewardRateChange",
+            Self::PendingRewardRateChangeExists => "PendingRewardRateChangeExists",
+            Self::InvalidTimestamp => "InvalidTimestamp",
+            Self::DataCorruption => "DataCorruption",
+            Self::AccountSizeTooSmall => "AccountSizeTooSmall",
+            Self::UnauthorizedPoolCreator => "UnauthorizedPoolCreator",
+            Self::CreatorAlreadyAuthorized => "CreatorAlreadyAuthorized",
+            Self::MaxAuthorizedCreatorsReached => "MaxAuthorizedCreatorsReached",
+            Self::CannotRemoveMainAuthority => "CannotRemoveMainAuthority",
+            Self::CreatorNotFound => "CreatorNotFound",
+            Self::InvalidReferrer => "InvalidReferrer",
+            Self::InvalidTokenAccountOwner => "InvalidTokenAccountOwner",
+            Self::NotNativeSolPool => "NotNativeSolPool",
+            Self::AutoRelockDisabled => "AutoRelockDisabled",
+            Self::DevOnlyInstruction => "DevOnlyInstruction",
+            Self::RewardFunderNotAllowed => "RewardFunderNotAllowed",
+            Self::DefundExceedsSurplus => "DefundExceedsSurplus",
+            Self::TvlCapExceeded => "TvlCapExceeded",
+            Self::PoolNotClosable => "PoolNotClosable",
+            Self::ReceiptAccountsRequired => "ReceiptAccountsRequired",
+            Self::RewardsNotExpired => "RewardsNotExpired",
+            Self::NoExpiredRewards => "NoExpiredRewards",
+            Self::UnstakeCooldownRequired => "UnstakeCooldownRequired",
+            Self::UnstakeCooldownActive => "UnstakeCooldownActive",
+            Self::NoPendingWithdrawal => "NoPendingWithdrawal",
+            Self::ProtocolPaused => "ProtocolPaused",
+            Self::WithdrawalsOnly => "WithdrawalsOnly",
+            Self::AccountNeedsMigration => "AccountNeedsMigration",
+            Self::PoolRegistryPageFull => "PoolRegistryPageFull",
+            Self::CreatorPoolLimitReached => "CreatorPoolLimitReached",
+            Self::MaxRoleHoldersReached => "MaxRoleHoldersReached",
+            Self::ApprovalRequired => "ApprovalRequired",
+            Self::AlreadyApproved => "AlreadyApproved",
+            Self::InsufficientApprovals => "InsufficientApprovals",
+            Self::PendingLockupChangeExists => "PendingLockupChangeExists",
+            Self::NoPendingLockupChange => "NoPendingLockupChange",
+            Self::LockupChangeDelayNotElapsed => "LockupChangeDelayNotElapsed",
+            Self::RewardRateOutOfBounds => "RewardRateOutOfBounds",
+            Self::ClaimExceedsUnclaimedRewards => "ClaimExceedsUnclaimedRewards",
+            Self::NoPendingStakeOwner => "NoPendingStakeOwner",
+            Self::InvalidPendingStakeOwner => "InvalidPendingStakeOwner",
+            Self::ReceiptPositionNotTransferable => "ReceiptPositionNotTransferable",
+            Self::LockupExtensionDisabled => "LockupExtensionDisabled",
+            Self::NotAllowlisted => "NotAllowlisted",
+            Self::PoolAllowlistPageFull => "PoolAllowlistPageFull",
+            Self::PrincipalNotVested => "PrincipalNotVested",
+            Self::ClaimWindowClosed => "ClaimWindowClosed",
+            Self::StakeSlashPending => "StakeSlashPending",
+            Self::SlashTimelockActive => "SlashTimelockActive",
+            Self::NoRecoveryInProgress => "NoRecoveryInProgress",
+            Self::RecoveryDelayActive => "RecoveryDelayActive",
+            Self::InvalidMemo => "InvalidMemo",
+            Self::VaultOrderMismatch => "VaultOrderMismatch",
+            Self::RateLimited => "RateLimited",
+            Self::StakePositionFull => "StakePositionFull",
+            Self::RewardsCapExceeded => "RewardsCapExceeded",
+            Self::IncompleteAudit => "IncompleteAudit",
+            Self::RemainingStakeBelowMinimum => "RemainingStakeBelowMinimum",
+            Self::WindDownNotStarted => "WindDownNotStarted",
+            Self::HookProgramNotAllowed => "HookProgramNotAllowed",
+            Self::HookProgramRequired => "HookProgramRequired",
+            Self::PointsNotEnabled => "PointsNotEnabled",
+            Self::AddressDenied => "AddressDenied",
+            Self::DenyListRequired => "DenyListRequired",
+            Self::ThirdPartyStakeDisabled => "ThirdPartyStakeDisabled",
+            Self::StakesNotMergeable => "StakesNotMergeable",
+            Self::DeadlineExceeded => "DeadlineExceeded",
+            Self::InvalidRewardCohort => "InvalidRewardCohort",
+        }
+    }
+
+    /// Log an `ErrorContext` event for the failed check of `field`, then return the error
+    ///
+    /// The runtime keeps the logs of failed transactions, so clients can decode
+    /// what was expected and what was found instead of parsing `msg!` text.
+    pub fn with_context(
+        self,
+        instruction: &str,
+        field: &str,
+        expected: impl Into<ContextValue>,
+        actual: impl Into<ContextValue>,
+    ) -> ProgramError {
+        let event = StakePoolEvent::ErrorContext(ErrorContextEvent {
+            instruction: instruction.to_string(),
+            error: self.clone() as u32,
+            field: field.to_string(),
+            expected: expected.into(),
+            actual: actual.into(),
+        });
+        // The context is best effort: failing to log it must not mask the error
+        let _ = event.emit();
+        self.into()
+    }
 }
 
 impl From<StakePoolError> for ProgramError {
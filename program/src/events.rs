@@ -0,0 +1,973 @@
+//! Borsh-encoded events logged for off-chain indexers
+//!
+//! Every event is published with a single `sol_log_data` call whose only field
+//! is a Borsh-serialized `StakePoolEvent`. Borsh encodes the enum variant as a
+//! one-byte discriminator followed by the event struct, so indexers decode the
+//! base64 `Program data:` log line with the same layout.
+//!
+//! Discriminators are the variant positions: new events are appended at the
+//! end and existing variants are never reordered or removed.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{entrypoint::ProgramResult, log::sol_log_data, pubkey::Pubkey};
+
+use crate::error::StakePoolError;
+use crate::state::{
+    ClaimWindow, ClusterProfile, OutflowLimit, RewardRateMode, SensitivePoolUpdate, WindDownRewards,
+};
+
+/// Every event the program emits, tagged by a one-byte discriminator
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum StakePoolEvent {
+    /// 0
+    PoolInitialized(PoolInitializedEvent),
+    /// 1
+    Stake(StakeEvent),
+    /// 2
+    Unstake(UnstakeEvent),
+    /// 3
+    Claim(ClaimEvent),
+    /// 4
+    ReferralPayout(ReferralPayoutEvent),
+    /// 5
+    RewardsFunded(RewardsFundedEvent),
+    /// 6
+    RewardsDefunded(VaultWithdrawalEvent),
+    /// 7
+    FeesWithdrawn(VaultWithdrawalEvent),
+    /// 8
+    PoolSolvencyChecked(PoolSolvencyCheckedEvent),
+    /// 9
+    PoolClosed(PoolClosedEvent),
+    /// 10
+    AutoRelockUpdated(AutoRelockUpdatedEvent),
+    /// 11
+    Relock(RelockEvent),
+    /// 12
+    ReceiptTokenEnabled(ReceiptTokenEnabledEvent),
+    /// 13
+    RewardsExpired(RewardsExpiredEvent),
+    /// 14
+    ExpiredRewardsClaimed(VaultWithdrawalEvent),
+    /// 15
+    StakeDelegateUpdated(StakeDelegateUpdatedEvent),
+    /// 16
+    ProgramAuthorityInitialized(ProgramAuthorityInitializedEvent),
+    /// 17
+    AuthorizedCreatorAdded(AuthorizedCreatorEvent),
+    /// 18
+    AuthorizedCreatorRemoved(AuthorizedCreatorEvent),
+    /// 19
+    PoolUpdated(PoolUpdatedEvent),
+    /// 20
+    PoolBatchUpdated(PoolAdminEvent),
+    /// 21
+    PoolBatchUpdateFailed(PoolBatchUpdateFailedEvent),
+    /// 22
+    RewardRateProposed(RewardRateProposedEvent),
+    /// 23
+    RewardRateProposalCancelled(PoolAdminEvent),
+    /// 24
+    RewardRateFinalized(RewardRateFinalizedEvent),
+    /// 25
+    ProgramAuthorityNominated(AuthorityChangeEvent),
+    /// 26
+    ProgramAuthorityTransferred(AuthorityChangeEvent),
+    /// 27
+    ProgramAuthorityTransferCancelled(AuthorityChangeEvent),
+    /// 28
+    ProgramAuthorityClosed(ProgramAuthorityClosedEvent),
+    /// 29
+    RewardFundersUpdated(RewardFundersUpdatedEvent),
+    /// 30
+    MintTvlCapUpdated(MintTvlCapUpdatedEvent),
+    /// 31
+    UnstakeRequested(UnstakeRequestedEvent),
+    /// 32
+    UnstakeWithdrawn(UnstakeWithdrawnEvent),
+    /// 33
+    GlobalPauseUpdated(GlobalPauseUpdatedEvent),
+    /// 34
+    PoolMigrated(PoolMigratedEvent),
+    /// 35
+    ClaimBatch(ClaimBatchEvent),
+    /// 36
+    PoolRegistered(PoolRegisteredEvent),
+    /// 37
+    CreatorPoolLimitUpdated(CreatorPoolLimitUpdatedEvent),
+    /// 38
+    RolesUpdated(RolesUpdatedEvent),
+    /// 39
+    ApprovalThresholdUpdated(ApprovalThresholdUpdatedEvent),
+    /// 40
+    PoolUpdateProposed(PoolUpdateProposedEvent),
+    /// 41
+    PoolUpdateApproved(PoolUpdateApprovedEvent),
+    /// 42
+    PoolUpdateExecuted(PoolUpdateExecutedEvent),
+    /// 43
+    LockupChangeProposed(LockupChangeProposedEvent),
+    /// 44
+    LockupChangeCancelled(PoolAdminEvent),
+    /// 45
+    LockupChangeFinalized(LockupChangeFinalizedEvent),
+    /// 46
+    StakeLabelUpdated(StakeLabelUpdatedEvent),
+    /// 47
+    StakeOwnerNominated(StakeOwnerNominatedEvent),
+    /// 48
+    StakeOwnershipTransferred(StakeOwnershipTransferredEvent),
+    /// 49
+    DustSwept(VaultWithdrawalEvent),
+    /// 50
+    LockupExtended(LockupExtendedEvent),
+    /// 51
+    PoolAllowlistUpdated(PoolAllowlistUpdatedEvent),
+    /// 52
+    VestingStakeCreated(VestingStakeCreatedEvent),
+    /// 53
+    SlashProposed(SlashProposedEvent),
+    /// 54
+    SlashExecuted(SlashExecutedEvent),
+    /// 55
+    SlashCancelled(SlashCancelledEvent),
+    /// 56
+    RecoveryAuthorityUpdated(RecoveryAuthorityUpdatedEvent),
+    /// 57
+    RecoveryInitiated(RecoveryInitiatedEvent),
+    /// 58
+    RecoveryCompleted(AuthorityChangeEvent),
+    /// 59
+    RecoveryCancelled(AuthorityChangeEvent),
+    /// 60
+    ProgramConfigUpdated(ProgramConfigUpdatedEvent),
+    /// 61
+    MaxCreatorsUpdated(MaxCreatorsUpdatedEvent),
+    /// 62
+    ProgramAuthorityMigrated(ProgramAuthorityMigratedEvent),
+    /// 63
+    PoolCloned(PoolClonedEvent),
+    /// 64
+    RewardStreamCreated(RewardStreamCreatedEvent),
+    /// 65
+    RewardStreamed(RewardStreamedEvent),
+    /// 66
+    RewardStreamClosed(RewardStreamClosedEvent),
+    /// 67
+    KeeperBountyPaid(KeeperBountyPaidEvent),
+    /// 68
+    KeeperBountyFunded(KeeperBountyFundedEvent),
+    /// 69
+    PoolResized(PoolResizedEvent),
+    /// 70 - Logged by failing instructions (see `StakePoolError::with_context`)
+    ErrorContext(ErrorContextEvent),
+    /// 71
+    PoolRewardsCapReached(PoolRewardsCapReachedEvent),
+    /// 72
+    PoolAudited(PoolAuditedEvent),
+    /// 73
+    StakeWoundDown(StakeWoundDownEvent),
+    /// 74
+    HookAllowlistUpdated(HookAllowlistUpdatedEvent),
+    /// 75
+    PointsSynced(PointsSyncedEvent),
+    /// 76
+    DenyListUpdated(DenyListUpdatedEvent),
+    /// 77
+    StakeSplit(StakeSplitEvent),
+    /// 78
+    StakesMerged(StakesMergedEvent),
+    /// 79
+    CohortRewardsFunded(CohortRewardsFundedEvent),
+    /// 80
+    CohortRewardsDefunded(CohortRewardsDefundedEvent),
+}
+
+impl StakePoolEvent {
+    /// Serialize and log the event
+    pub fn emit(&self) -> ProgramResult {
+        let data = borsh::to_vec(self).map_err(|_| StakePoolError::SerializationError)?;
+        sol_log_data(&[&data]);
+        Ok(())
+    }
+
+    /// Decode the payload of one `Program data:` log line (after base64 decoding)
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        Self::try_from_slice(data).ok()
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolInitializedEvent {
+    pub pool: Pubkey,
+    pub payer: Pubkey,
+    pub pool_id: u64,
+    pub reward_rate: u64,
+    /// Whether `reward_rate` is paid per lockup round or annualized
+    pub reward_rate_mode: RewardRateMode,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolClonedEvent {
+    pub pool: Pubkey,
+    /// The pool whose settings the new pool was created with
+    pub source_pool: Pubkey,
+    pub payer: Pubkey,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StakeEvent {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub index: u64,
+    /// Amount added to the position (received after transfer fees, net of the deposit fee)
+    pub amount: u64,
+    /// Deposit fee received by the fee vault
+    pub fee: u64,
+    /// The pool's stake accounts holding stake after this stake (None when the pool keeps no count)
+    pub active_stake_count: Option<u64>,
+    /// The pool's `event_nonce` after this event: consecutive per pool, so indexers detect gaps
+    pub event_nonce: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UnstakeEvent {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    /// Amount received by the owner
+    pub amount: u64,
+    /// Withdrawal fee received by the fee vault
+    pub fee: u64,
+    /// Rewards released from total_rewards_owed by this unstake
+    pub forfeited_rewards: u64,
+    /// The pool's stake accounts holding stake after this unstake (None when the pool keeps no count)
+    pub active_stake_count: Option<u64>,
+    /// The pool's `event_nonce` after this event: consecutive per pool, so indexers detect gaps
+    pub event_nonce: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ClaimEvent {
+    pub pool: Pubkey,
+    pub stake_account: Pubkey,
+    /// The stake owner or its delegate
+    pub claimant: Pubkey,
+    /// Token account the rewards were paid to
+    pub recipient: Pubkey,
+    /// Rewards deducted from total_rewards_owed
+    pub amount: u64,
+    /// Rewards received after transfer fees
+    pub received: u64,
+    /// The pool's `event_nonce` after this event: consecutive per pool, so indexers detect gaps
+    pub event_nonce: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReferralPayoutEvent {
+    pub pool: Pubkey,
+    pub stake_account: Pubkey,
+    pub referrer: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RewardsFundedEvent {
+    pub pool: Pubkey,
+    pub funder: Pubkey,
+    /// Amount received by the reward vault
+    pub amount: u64,
+}
+
+/// Tokens moved out of a pool vault by an admin
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VaultWithdrawalEvent {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolSolvencyCheckedEvent {
+    pub pool: Pubkey,
+    pub stake_vault_balance: u64,
+    pub total_staked: u64,
+    pub reward_vault_balance: u64,
+    pub total_rewards_owed: u64,
+    pub solvent: bool,
+    pub paused: bool,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolClosedEvent {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+    pub stake_swept: u64,
+    pub reward_swept: u64,
+    pub fee_swept: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AutoRelockUpdatedEvent {
+    pub stake_account: Pubkey,
+    pub auto_relock: bool,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RelockEvent {
+    pub pool: Pubkey,
+    pub stake_account: Pubkey,
+    /// Start of the new lockup round
+    pub stake_timestamp: i64,
+    pub round_rewards: u64,
+    pub carried_rewards: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct LockupExtendedEvent {
+    pub pool: Pubkey,
+    pub stake_account: Pubkey,
+    pub owner: Pubkey,
+    pub additional_seconds: i64,
+    /// New maturity of the stake's current round
+    pub matures_at: i64,
+    /// Reward reserved for the extension
+    pub bonus_rewards: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReceiptTokenEnabledEvent {
+    pub pool: Pubkey,
+    pub receipt_mint: Pubkey,
+    pub transferable: bool,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RewardsExpiredEvent {
+    pub pool: Pubkey,
+    pub stake_account: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StakeDelegateUpdatedEvent {
+    pub stake_account: Pubkey,
+    pub delegate: Option<Pubkey>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StakeLabelUpdatedEvent {
+    pub stake_account: Pubkey,
+    /// All zeros when the label was cleared
+    pub label: [u8; 32],
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StakeOwnerNominatedEvent {
+    pub stake_account: Pubkey,
+    pub owner: Pubkey,
+    /// None when the nomination was cancelled
+    pub pending_owner: Option<Pubkey>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StakeOwnershipTransferredEvent {
+    pub pool: Pubkey,
+    /// The closed stake account of the previous owner
+    pub previous_stake_account: Pubkey,
+    /// The stake account now holding the position
+    pub stake_account: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProgramAuthorityInitializedEvent {
+    pub authority: Pubkey,
+    pub cluster_profile: ClusterProfile,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AuthorizedCreatorEvent {
+    pub creator: Pubkey,
+    pub authority: Pubkey,
+}
+
+/// One pool parameter changed by UpdatePool or UpdatePoolsBatch
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolUpdatedEvent {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+    pub parameter: PoolParameter,
+    /// The pool's `event_nonce` after this event: consecutive per pool, so indexers detect gaps
+    pub event_nonce: u64,
+}
+
+/// New value of an updated pool parameter
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum PoolParameter {
+    MinStakeAmount(u64),
+    /// No longer emitted: lockup changes are time-locked (see `LockupChangeProposed`)
+    LockupPeriod(i64),
+    Paused(bool),
+    /// No longer emitted: lockup changes are time-locked (see `LockupChangeProposed`)
+    EnforceLockup(bool),
+    /// None when the end date was removed
+    PoolEndDate(Option<i64>),
+    ReferralBps(u16),
+    EarlyUnstakeBps(u16),
+    DepositFeeBps(u16),
+    WithdrawFeeBps(u16),
+    UnstakeCooldown(i64),
+    /// Early-bird bonus window end (None when removed) and multiplier
+    BonusWindow {
+        end_ts: Option<i64>,
+        multiplier_bps: u16,
+    },
+    /// Bonus reward rate per year of lockup extension (0 when disabled)
+    LockupExtensionRate(u64),
+    /// Root of the merkle allowlist of stake owners (None when removed)
+    AllowlistMerkleRoot(Option<[u8; 32]>),
+    /// Recurring claim window (None when claims are always open)
+    ClaimWindow(Option<ClaimWindow>),
+    /// Caps on the unstakes and claims of any outflow window (None when lifted)
+    OutflowLimit(Option<OutflowLimit>),
+    /// Manager of the pool (None when removed)
+    PoolManager(Option<Pubkey>),
+    /// Lamports paid per crank (0 when disabled)
+    KeeperBounty(u64),
+    /// Cap on the rewards the pool commits (None when lifted)
+    MaxTotalRewards(Option<u64>),
+    /// Whether partial unstakes must leave at least the minimum stake
+    EnforceMinRemainingStake(bool),
+    /// What WindDownStake does with unclaimed rewards
+    WindDownRewards(WindDownRewards),
+    /// Program called after stakes, unstakes and claims (None when detached)
+    HookProgram(Option<Pubkey>),
+    /// Points accrued per staked base unit per second, scaled by 1e9 (0 when disabled)
+    PointsRate(u64),
+    /// Whether stakes, unstakes and claims check the global deny list
+    DenyListRequired(bool),
+    /// Whether Stake accepts funders staking for owners who do not sign
+    AllowThirdPartyStake(bool),
+    /// Length in seconds of the pool's reward cohorts (0 when turned off)
+    CohortDuration(i64),
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolBatchUpdateFailedEvent {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+    /// `u64::from(ProgramError)` of the skipped pool's error
+    pub error: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RewardRateProposedEvent {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+    pub current_rate: u64,
+    pub proposed_rate: u64,
+}
+
+/// Lockup settings proposed through UpdatePool; `None` leaves a setting unchanged
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct LockupChangeProposedEvent {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+    pub current_lockup_period: i64,
+    pub proposed_lockup_period: Option<i64>,
+    pub current_enforce_lockup: bool,
+    pub proposed_enforce_lockup: Option<bool>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct LockupChangeFinalizedEvent {
+    pub pool: Pubkey,
+    pub lockup_period: i64,
+    pub enforce_lockup: bool,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolAdminEvent {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RewardRateFinalizedEvent {
+    pub pool: Pubkey,
+    pub old_rate: u64,
+    pub new_rate: u64,
+}
+
+/// Nomination, acceptance or cancellation of a program authority transfer or recovery
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AuthorityChangeEvent {
+    pub current_authority: Pubkey,
+    /// The nominated (or cancelled) authority or recovery authority, or the new
+    /// authority once transferred
+    pub new_authority: Pubkey,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RecoveryAuthorityUpdatedEvent {
+    pub authority: Pubkey,
+    pub recovery_authority: Option<Pubkey>,
+    pub recovery_delay: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RecoveryInitiatedEvent {
+    pub authority: Pubkey,
+    pub recovery_authority: Pubkey,
+    /// Time from which CompleteRecovery can hand over the authority
+    pub completes_at: i64,
+}
+
+/// The program config as initialized or updated
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProgramConfigUpdatedEvent {
+    pub authority: Pubkey,
+    pub version: [u16; 3],
+    pub feature_flags: u64,
+    pub max_reward_rate: u64,
+    pub min_lockup_period: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MaxCreatorsUpdatedEvent {
+    pub authority: Pubkey,
+    /// Most authorized creators the program authority may hold
+    pub max_creators: u16,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProgramAuthorityMigratedEvent {
+    pub authority: Pubkey,
+    /// Authorized creators carried over from the legacy layout
+    pub creator_count: u16,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProgramAuthorityClosedEvent {
+    pub authority: Pubkey,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RewardFundersUpdatedEvent {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+    /// Empty when funding is open to anyone
+    pub funders: Vec<Pubkey>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolAllowlistUpdatedEvent {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+    pub page: u32,
+    pub added: Vec<Pubkey>,
+    pub removed: Vec<Pubkey>,
+    /// Owners listed on the page after the update
+    pub page_members: u32,
+    pub allowlist_required: bool,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VestingStakeCreatedEvent {
+    pub pool: Pubkey,
+    pub stake_account: Pubkey,
+    pub beneficiary: Pubkey,
+    pub admin: Pubkey,
+    /// Principal granted, after transfer and deposit fees
+    pub vesting_amount: u64,
+    pub vesting_start: i64,
+    pub vesting_cliff: i64,
+    pub vesting_duration: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SlashProposedEvent {
+    pub pool: Pubkey,
+    pub stake_account: Pubkey,
+    pub owner: Pubkey,
+    pub proposer: Pubkey,
+    pub slash_bps: u16,
+    /// Recipient of the slashed tokens (None for the pool's reward vault)
+    pub treasury: Option<Pubkey>,
+    pub executable_at: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SlashExecutedEvent {
+    pub pool: Pubkey,
+    pub stake_account: Pubkey,
+    pub executor: Pubkey,
+    /// Token account the slashed tokens were sent to
+    pub destination: Pubkey,
+    /// Principal removed from the stake (staked and pending withdrawal)
+    pub slashed_amount: u64,
+    /// Amount the destination received, net of transfer fees
+    pub received_amount: u64,
+    pub forfeited_rewards: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SlashCancelledEvent {
+    pub pool: Pubkey,
+    pub stake_account: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RewardStreamCreatedEvent {
+    pub pool: Pubkey,
+    pub reward_stream: Pubkey,
+    pub funder: Pubkey,
+    pub rate_per_second: u64,
+    pub end_ts: i64,
+    /// Total amount the stream delivers (before transfer fees)
+    pub total_amount: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RewardStreamedEvent {
+    pub pool: Pubkey,
+    pub reward_stream: Pubkey,
+    /// Amount accrued since the last crank
+    pub amount: u64,
+    /// Amount the reward vault received after transfer fees
+    pub received: u64,
+    /// Timestamp the stream has now been cranked up to
+    pub streamed_until: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RewardStreamClosedEvent {
+    pub pool: Pubkey,
+    pub reward_stream: Pubkey,
+    pub funder: Pubkey,
+    /// Total amount the stream delivered (before transfer fees)
+    pub total_streamed: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct KeeperBountyPaidEvent {
+    pub pool: Pubkey,
+    pub keeper: Pubkey,
+    /// Lamports paid out of the pool's escrow
+    pub lamports: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct KeeperBountyFundedEvent {
+    pub pool: Pubkey,
+    pub funder: Pubkey,
+    pub lamports: u64,
+    /// Escrow balance after the funding
+    pub escrow: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolResizedEvent {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+    pub old_len: u32,
+    pub new_len: u32,
+}
+
+/// Logged by the commitment that exhausts the pool's rewards cap, after which
+/// the pool rejects new stakes as if it had ended
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolRewardsCapReachedEvent {
+    pub pool: Pubkey,
+    pub max_total_rewards: u64,
+    pub total_rewards_committed: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolAuditedEvent {
+    pub pool: Pubkey,
+    pub audited_stakes: u64,
+    pub recomputed_rewards_owed: u64,
+    pub total_rewards_owed: u64,
+    pub repaired: bool,
+}
+
+/// Logged when WindDownStake returns an abandoned stake of an ended pool and closes it
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StakeWoundDownEvent {
+    pub pool: Pubkey,
+    pub stake_account: Pubkey,
+    pub owner: Pubkey,
+    /// Principal returned to the owner's token account
+    pub principal: u64,
+    /// Principal received after transfer fees
+    pub received: u64,
+    /// Earned rewards paid to the owner
+    pub rewards_paid: u64,
+    /// Earned rewards moved to the pool's expired rewards
+    pub rewards_expired: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct HookAllowlistUpdatedEvent {
+    pub admin: Pubkey,
+    pub added: Vec<Pubkey>,
+    pub removed: Vec<Pubkey>,
+    /// Programs listed after the update
+    pub programs: u32,
+}
+
+/// Logged when SyncPoints credits a stake's points to its owner
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PointsSyncedEvent {
+    pub pool: Pubkey,
+    pub stake_account: Pubkey,
+    pub owner: Pubkey,
+    /// Points the stake accrued since its last sync
+    pub points: u128,
+    /// The owner's points in the pool after the sync
+    pub total_points: u128,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DenyListUpdatedEvent {
+    pub admin: Pubkey,
+    pub added: Vec<Pubkey>,
+    pub removed: Vec<Pubkey>,
+    /// Addresses listed after the update
+    pub addresses: u32,
+    /// Whether listed owners may still withdraw principal to their own token accounts
+    pub allow_listed_withdrawals: bool,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StakeSplitEvent {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub stake_account: Pubkey,
+    /// The stake account created with the split off principal
+    pub new_stake_account: Pubkey,
+    /// Principal moved into the new stake account
+    pub amount: u64,
+    /// Principal left in the original stake account
+    pub remaining: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StakesMergedEvent {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    /// The stake account that received the principal
+    pub stake_account: Pubkey,
+    /// The stake account merged in and closed
+    pub source_stake_account: Pubkey,
+    /// Principal moved from the source stake account
+    pub amount: u64,
+    /// Principal of the merged stake account
+    pub amount_staked: u64,
+    /// Start of the merged stake's round (the later of the two)
+    pub stake_timestamp: i64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CohortRewardsFundedEvent {
+    pub pool: Pubkey,
+    pub funder: Pubkey,
+    pub cohort: u64,
+    /// Amount received by the cohort vault
+    pub amount: u64,
+    /// Whether this funding created the cohort's account and vault
+    pub created: bool,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CohortRewardsDefundedEvent {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+    pub destination: Pubkey,
+    pub cohort: u64,
+    pub amount: u64,
+    /// Whether the emptied cohort's account and vault were closed
+    pub closed: bool,
+}
+
+/// A value compared by a failed check
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContextValue {
+    /// Amounts, rates and other unsigned values
+    Unsigned(u64),
+    /// Timestamps and durations
+    Signed(i64),
+    Pubkey(Pubkey),
+}
+
+impl From<u64> for ContextValue {
+    fn from(value: u64) -> Self {
+        Self::Unsigned(value)
+    }
+}
+
+impl From<i64> for ContextValue {
+    fn from(value: i64) -> Self {
+        Self::Signed(value)
+    }
+}
+
+impl From<Pubkey> for ContextValue {
+    fn from(value: Pubkey) -> Self {
+        Self::Pubkey(value)
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ErrorContextEvent {
+    /// Name of the failing instruction
+    pub instruction: String,
+    /// The `StakePoolError` code the instruction fails with
+    pub error: u32,
+    /// The argument, account or pool field that failed the check
+    pub field: String,
+    pub expected: ContextValue,
+    pub actual: ContextValue,
+}
+
+impl ErrorContextEvent {
+    /// The error the instruction failed with
+    pub fn stake_pool_error(&self) -> Option<StakePoolError> {
+        StakePoolError::from_code(self.error)
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MintTvlCapUpdatedEvent {
+    pub stake_mint: Pubkey,
+    pub admin: Pubkey,
+    pub tvl_cap: Option<u64>,
+    pub total_staked: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UnstakeRequestedEvent {
+    pub pool: Pubkey,
+    pub stake_account: Pubkey,
+    pub amount: u64,
+    /// Total awaiting WithdrawUnstaked, including earlier requests
+    pub pending_amount: u64,
+    pub forfeited_rewards: u64,
+    pub withdrawable_at: i64,
+    /// The pool's stake accounts holding stake after this request (None when the pool keeps no count)
+    pub active_stake_count: Option<u64>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UnstakeWithdrawnEvent {
+    pub pool: Pubkey,
+    pub stake_account: Pubkey,
+    pub owner: Pubkey,
+    /// Amount received by the owner
+    pub amount: u64,
+    /// Withdrawal fee received by the fee vault
+    pub fee: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GlobalPauseUpdatedEvent {
+    pub admin: Pubkey,
+    pub global_pause: bool,
+    pub withdrawals_only: bool,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolMigratedEvent {
+    pub pool: Pubkey,
+    pub admin: Pubkey,
+    /// Whether the pool itself was upgraded (false when it already was)
+    pub pool_migrated: bool,
+    /// Stake accounts upgraded by this instruction
+    pub stake_accounts_migrated: u32,
+}
+
+/// Rewards claimed from one stake account by ClaimRewardsBatch
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StakeClaim {
+    pub stake_account: Pubkey,
+    /// Rewards deducted from total_rewards_owed
+    pub amount: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ClaimBatchEvent {
+    pub pool: Pubkey,
+    /// The stake owner or its delegate
+    pub claimant: Pubkey,
+    /// Stake accounts that had rewards to claim, in account order
+    pub claims: Vec<StakeClaim>,
+    /// Total rewards deducted from total_rewards_owed
+    pub amount: u64,
+    /// Total rewards received after transfer fees
+    pub received: u64,
+    /// The pool's `event_nonce` after this event: consecutive per pool, so indexers detect gaps
+    pub event_nonce: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolRegisteredEvent {
+    pub pool: Pubkey,
+    /// Registry page the pool was listed in
+    pub page: u32,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreatorPoolLimitUpdatedEvent {
+    pub creator: Pubkey,
+    pub authority: Pubkey,
+    /// Maximum pools the creator may create (None = unlimited)
+    pub pool_limit: Option<u16>,
+    /// Pools the creator has created so far
+    pub pools_created: u16,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RolesUpdatedEvent {
+    pub holder: Pubkey,
+    pub authority: Pubkey,
+    /// Bit mask of roles now held (0 when every role was revoked)
+    pub roles: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ApprovalThresholdUpdatedEvent {
+    pub authority: Pubkey,
+    /// Approvals sensitive pool updates now need (0 or 1 = no approval flow)
+    pub threshold: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolUpdateProposedEvent {
+    pub pool: Pubkey,
+    pub pending_action: Pubkey,
+    pub proposer: Pubkey,
+    pub action_id: u64,
+    pub update: SensitivePoolUpdate,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolUpdateApprovedEvent {
+    pub pool: Pubkey,
+    pub pending_action: Pubkey,
+    pub approver: Pubkey,
+    /// Approvals recorded so far, the proposer's included
+    pub approvals: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolUpdateExecutedEvent {
+    pub pool: Pubkey,
+    pub pending_action: Pubkey,
+    pub executor: Pubkey,
+    /// Approvals counted at execution
+    pub approvals: u8,
+}
@@ -0,0 +1,75 @@
+//! Interface of the hook programs pools call after stakes, unstakes and claims
+//!
+//! A pool whose `hook_program` is set invokes that program once the state of a
+//! successful `Stake`, `Unstake` or `ClaimRewards` has been saved, e.g. so a
+//! loyalty-points program can credit the owner. The instruction data is
+//! `HOOK_DISCRIMINATOR` followed by a Borsh-encoded `StakePoolHookInstruction`,
+//! and the accounts are:
+//!
+//! 0. `[]` The stake pool
+//! 1. `[]` The stake account
+//! 2. `[]` The stake owner
+//! 3. .. The accounts passed after the instruction's own accounts, in order,
+//!    writable as passed but never as signers
+//!
+//! Hooks only get read access to the pool's accounts and no signature: the
+//! owner's signature does not reach the hook, so it cannot move the owner's
+//! tokens. The hook cannot re-enter the stake pool either, since the runtime
+//! rejects a callee invoking a program already on the call stack and the pool
+//! program itself can never be allowlisted.
+//!
+//! Hooks run within the calling transaction's compute budget. The CPI costs
+//! about 1,000 compute units on top of whatever the hook consumes, so callers
+//! of hooked pools should raise the transaction's limit with a
+//! `ComputeBudgetInstruction::set_compute_unit_limit`. A failing hook (or one
+//! running out of compute) fails the whole instruction: admins detach a broken
+//! hook with SetPoolHook so unstakes keep working.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+
+use crate::error::StakePoolError;
+
+/// First 8 bytes of `sha256("your-wallet-stake-pool:hook")`, prefixing the hook's
+/// instruction data so hooks can tell pool callbacks apart from their own instructions
+pub const HOOK_DISCRIMINATOR: [u8; 8] = [0xc5, 0x17, 0x49, 0x9a, 0x5e, 0xcb, 0x38, 0x1e];
+
+/// Operation a hook is called after
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum StakePoolHookInstruction {
+    /// A new stake account was created
+    AfterStake {
+        /// Amount staked after fees
+        amount: u64,
+        /// Index of the new stake account
+        index: u64,
+    },
+    /// Principal was withdrawn from a stake account
+    AfterUnstake {
+        /// Amount received by the owner
+        amount: u64,
+        /// Principal left in the stake account
+        remaining: u64,
+    },
+    /// Rewards of a stake account were claimed
+    AfterClaim {
+        /// Rewards committed by the claim (before transfer fees)
+        amount: u64,
+    },
+}
+
+impl StakePoolHookInstruction {
+    /// Instruction data of the hook call: the discriminator and the Borsh-encoded operation
+    pub fn data(&self) -> Result<Vec<u8>, ProgramError> {
+        let mut data = HOOK_DISCRIMINATOR.to_vec();
+        self.serialize(&mut data)
+            .map_err(|_| StakePoolError::SerializationError)?;
+        Ok(data)
+    }
+
+    /// Decode hook instruction data, None if it is not a stake pool callback
+    pub fn unpack(data: &[u8]) -> Option<Self> {
+        let payload = data.strip_prefix(&HOOK_DISCRIMINATOR)?;
+        Self::try_from_slice(payload).ok()
+    }
+}
@@ -1,21 +1,34 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use shank::{ShankContext, ShankInstruction};
-use solana_program::pubkey::Pubkey;
+use solana_program::{entrypoint::ProgramResult, msg, pubkey::Pubkey};
+
+use crate::constants::{MAX_MEMO_LEN, MAX_MERKLE_PROOF_LEN, MAX_STAKE_BATCH};
+use crate::error::StakePoolError;
+use crate::state::{
+    ClaimWindow, ClusterProfile, DenyList, HookAllowlist, OutflowLimit, PoolAllowlist,
+    ProgramAuthority, RewardRateMode, RewardTier, SensitivePoolUpdate, StakePool, WindDownRewards,
+};
 
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, ShankContext, ShankInstruction)]
 #[rustfmt::skip]
 pub enum StakePoolInstruction {
     /// Initialize a new stake pool
+    /// The stake, reward and fee vaults are created by the program at their PDAs.
     #[account(0, writable, name="pool", desc = "The stake pool PDA")]
     #[account(1, name="stake_mint", desc = "The token mint being staked")]
     #[account(2, name="reward_mint", desc = "The reward token mint")]
-    #[account(3, writable, name="stake_vault", desc = "The pool's stake token vault")]
-    #[account(4, writable, name="reward_vault", desc = "The pool's reward token vault")]
+    #[account(3, writable, name="stake_vault", desc = "The pool's stake vault PDA [\"stake_vault\", pool] (created)")]
+    #[account(4, writable, name="reward_vault", desc = "The pool's reward vault PDA [\"reward_vault\", pool] (created)")]
     #[account(5, writable, signer, name="payer", desc = "The account paying for rent (must be authorized admin)")]
-    #[account(6, name="token_program", desc = "The token program")]
+    #[account(6, name="token_program", desc = "The token program owning both mints")]
     #[account(7, name="system_program", desc = "The system program")]
     #[account(8, name="rent", desc = "Rent sysvar")]
-    #[account(9, name="program_authority", desc = "The program authority account (validates creator permission)")]
+    #[account(9, writable, name="program_authority", desc = "The program authority account (validates creator permission and counts the creator's pools)")]
+    #[account(10, writable, name="fee_vault", desc = "The pool's fee vault PDA [\"fee_vault\", pool] (stake mint, created) collecting deposit and withdrawal fees")]
+    #[account(11, writable, name="mint_registry", desc = "The stake mint's registry PDA [\"mint_registry\", stake_mint] (created with the mint's first pool)")]
+    #[account(12, writable, name="pool_registry", desc = "The pool registry page the pool is listed in (latest page, created when the previous one is full)")]
+    #[account(13, optional, name="previous_pool_registry", desc = "The previous registry page (required to create a page after page 0)")]
+    #[account(14, name="program_config", desc = "The program config PDA [\"program_config\"] (built-in limits while uninitialized)")]
     InitializePool {
         /// Unique identifier to allow multiple pools for same authority + stake_mint (typically 0 for first pool, 1 for second, etc.)
         pool_id: u64,
@@ -26,28 +39,85 @@ pub enum StakePoolInstruction {
         enforce_lockup: bool,
         /// Optional pool end date (Unix timestamp). If set, no new stakes allowed after this time.
         pool_end_date: Option<i64>,
+        /// Share of each claim (basis points) paid to the stake's referrer. 0 disables referrals.
+        referral_bps: u16,
+        /// Share of a position (basis points) that may be unstaked before lockup completion,
+        /// even when enforce_lockup is set. Only the withdrawn part's rewards are forfeited.
+        early_unstake_bps: u16,
+        /// Fee (basis points) taken from each stake deposit and sent to the fee vault
+        deposit_fee_bps: u16,
+        /// Fee (basis points) taken from each unstake and sent to the fee vault
+        withdraw_fee_bps: u16,
+        /// Seconds a proposed reward rate change waits before finalization, also the
+        /// cooldown between changes (at least 24h on Mainnet, at most 30 days).
+        /// None uses the deployment's default (7 days on Mainnet).
+        reward_rate_change_delay: Option<u32>,
+        /// Lowest reward rate UpdatePool may ever propose (None: no floor)
+        min_reward_rate_bound: Option<u64>,
+        /// Highest reward rate UpdatePool may ever propose (None: MAX_REWARD_RATE)
+        max_reward_rate_bound: Option<u64>,
+        /// Up to 4 stake size tiers boosting the reward rate of large stakes, by ascending min amount
+        reward_tiers: Vec<RewardTier>,
+        /// End of the early-bird bonus window (None: no bonus)
+        bonus_end_ts: Option<i64>,
+        /// Reward rate multiplier (basis points) of stakes created before bonus_end_ts
+        bonus_multiplier_bps: u16,
+        /// Whether reward_rate is paid per lockup round or is an annual rate prorated to the lockup
+        reward_rate_mode: RewardRateMode,
     },
 
     /// Stake tokens into the pool (creates a new stake account for this deposit)
     /// Each stake account has independent lockup period and reward tracking
     /// Multiple deposits create separate accounts (index 0, 1, 2, etc.)
+    /// Without an index the stake takes the next index of the owner's stake position
+    /// directory, which must then be passed; stake_account must be the PDA at that index.
+    /// In pools with allow_third_party_stake, a funder may stake from its own token
+    /// account for an owner who does not sign (gift and custodial stakes).
+    /// In pools with reward cohorts, reward_vault is the vault of the current cohort,
+    /// which reserves the stake's rewards.
     #[account(0, writable, name="pool", desc = "The stake pool")]
     #[account(1, writable, name="stake_account", desc = "The stake account PDA (will be created)")]
-    #[account(2, signer, name="owner", desc = "The stake account owner")]
-    #[account(3, writable, name="user_token_account", desc = "User's token account")]
+    #[account(2, optional_signer, name="owner", desc = "The stake account owner (signs unless a funder stakes for it)")]
+    #[account(3, writable, name="user_token_account", desc = "User's token account (the funder's when a funder stakes)")]
     #[account(4, writable, name="stake_vault", desc = "Pool's stake vault")]
-    #[account(5, name="reward_vault", desc = "Pool's reward vault (for checking available rewards)")]
+    #[account(5, name="reward_vault", desc = "Pool's reward vault, or the cohort vault in pools with reward cohorts (for checking available rewards)")]
     #[account(6, name="stake_mint", desc = "The token mint being staked")]
     #[account(7, name="token_program", desc = "The token program (Token or Token-2022)")]
     #[account(8, writable, signer, name="payer", desc = "The account paying for rent")]
     #[account(9, name="system_program", desc = "The system program")]
+    #[account(10, writable, name="fee_vault", desc = "Pool's fee vault")]
+    #[account(11, writable, name="mint_registry", desc = "The stake mint's registry (enforces the TVL cap)")]
+    #[account(12, optional, writable, name="receipt_mint", desc = "Pool's receipt mint (required when the pool tokenizes positions)")]
+    #[account(13, optional, writable, name="user_receipt_account", desc = "Owner's receipt token account to mint into (required when the pool tokenizes positions)")]
+    #[account(14, optional, name="receipt_token_program", desc = "The Token-2022 program (required when the pool tokenizes positions)")]
+    #[account(15, name="program_authority", desc = "The program authority account (global pause switches)")]
+    #[account(16, optional, name="allowlist", desc = "Pool allowlist page listing the owner (required when the pool requires allowlisting and no merkle proof is given)")]
+    #[account(17, optional, name="memo_program", desc = "The SPL Memo program (required with a memo)")]
+    #[account(18, optional, writable, name="stake_position", desc = "The owner's stake position directory, created when empty (None: the index is not recorded)")]
+    #[account(19, optional, signer, name="funder", desc = "Authority of user_token_account staking for the owner (pools with allow_third_party_stake)")]
+    #[account(20, optional, name="deny_list", desc = "The global deny list PDA [\"deny_list\"] (required when the pool checks it)")]
+    #[account(21, optional, writable, name="reward_cohort", desc = "The current cohort's RewardCohort PDA [\"reward_cohort\", pool, cohort] (required when the pool has reward cohorts)")]
+    #[account(22, optional, name="hook_program", desc = "The pool's hook program (required when the pool has one); the hook's own accounts follow")]
     Stake {
         amount: u64,
-        index: u64,
+        /// Index of the new stake account (None: the directory's next index)
+        index: Option<u64>,
         /// Frontrunning protection: expected reward rate (optional)
         expected_reward_rate: Option<u64>,
         /// Frontrunning protection: expected lockup period (optional)
         expected_lockup_period: Option<i64>,
+        /// Optional referrer credited with the pool's referral share on every claim
+        referrer: Option<Pubkey>,
+        /// Let the permissionless Relock crank restart the lockup once it expires
+        auto_relock: bool,
+        /// Optional owner label for the position (zero-padded, see SetStakeLabel)
+        label: Option<[u8; 32]>,
+        /// Merkle proof that the owner is on the pool's allowlist root (see `merkle`)
+        allowlist_proof: Option<Vec<[u8; 32]>>,
+        /// Memo written via the SPL Memo program, signed by the owner or funder (at most MAX_MEMO_LEN bytes)
+        memo: Option<String>,
+        /// Unix timestamp after which the stake is rejected (None: no deadline)
+        deadline: Option<i64>,
     },
 
     /// Unstake tokens from the pool
@@ -59,39 +129,118 @@ pub enum StakePoolInstruction {
     #[account(5, name="stake_mint", desc = "The token mint being staked")]
     #[account(6, name="token_program", desc = "The token program")]
     #[account(7, name="clock", desc = "Clock sysvar")]
+    #[account(8, writable, name="fee_vault", desc = "Pool's fee vault")]
+    #[account(9, writable, name="mint_registry", desc = "The stake mint's registry")]
+    #[account(10, optional, writable, name="receipt_mint", desc = "Pool's receipt mint (required when the pool tokenizes positions)")]
+    #[account(11, optional, writable, name="user_receipt_account", desc = "Owner's receipt token account to burn from (required when the pool tokenizes positions)")]
+    #[account(12, optional, name="receipt_token_program", desc = "The Token-2022 program (required when the pool tokenizes positions)")]
+    #[account(13, name="program_authority", desc = "The program authority account (global pause switches)")]
+    #[account(14, optional, name="memo_program", desc = "The SPL Memo program (required with a memo)")]
+    #[account(15, optional, name="deny_list", desc = "The global deny list PDA [\"deny_list\"] (required when the pool checks it)")]
+    #[account(16, optional, name="hook_program", desc = "The pool's hook program (required when the pool has one); the hook's own accounts follow")]
     Unstake {
         amount: u64,
         /// Frontrunning protection: expected reward rate (optional)
         expected_reward_rate: Option<u64>,
+        /// Memo written via the SPL Memo program, signed by the owner (at most MAX_MEMO_LEN bytes)
+        memo: Option<String>,
+        /// Unix timestamp after which the unstake is rejected (None: no deadline)
+        deadline: Option<i64>,
     },
 
     /// Claim rewards
+    /// Returns a `ClaimRewardsResult` via return data. Claiming before the lockup
+    /// completes succeeds without a transfer and reports `matures_at` and `projected_rewards`.
+    /// Rewards go to user_reward_account, which must be owned by the stake owner, unless
+    /// the owner passes a recipient token account to pay instead. `amount` claims only part
+    /// of the unclaimed rewards, leaving the rest claimable later. Passing the ATA and system
+    /// programs creates user_reward_account, the owner's associated token account, when missing.
+    /// Stakes of a reward cohort are paid from their cohort vault, passed as reward_vault.
     #[account(0, writable, name="pool", desc = "The stake pool")]
     #[account(1, writable, name="stake_account", desc = "The user's stake account")]
-    #[account(2, signer, name="owner", desc = "The stake account owner")]
-    #[account(3, writable, name="user_reward_account", desc = "User's reward token account")]
-    #[account(4, writable, name="reward_vault", desc = "Pool's reward vault")]
+    #[account(2, signer, name="owner", desc = "The stake account owner, or its delegate (rewards then go to an owner token account only)")]
+    #[account(3, writable, name="user_reward_account", desc = "Reward token account owned by the stake owner")]
+    #[account(4, writable, name="reward_vault", desc = "Pool's reward vault, or the stake's cohort vault in pools with reward cohorts")]
     #[account(5, name="reward_mint", desc = "The reward token mint")]
     #[account(6, name="token_program", desc = "The token program")]
     #[account(7, name="clock", desc = "Clock sysvar")]
-    ClaimRewards,
+    #[account(8, optional, writable, name="referrer_reward_account", desc = "Referrer's reward token account (required when the stake has a referrer and the pool pays referrals)")]
+    #[account(9, name="program_authority", desc = "The program authority account (global pause switches)")]
+    #[account(10, optional, writable, name="recipient", desc = "Reward token account to pay instead of user_reward_account (stake owner only)")]
+    #[account(11, optional, name="associated_token_program", desc = "The associated token account program (creates user_reward_account when missing; owner must then sign as a writable payer)")]
+    #[account(12, optional, name="system_program", desc = "The system program (required with associated_token_program)")]
+    #[account(13, optional, name="memo_program", desc = "The SPL Memo program (required with a memo)")]
+    #[account(14, optional, name="deny_list", desc = "The global deny list PDA [\"deny_list\"] (required when the pool checks it)")]
+    #[account(15, optional, writable, name="reward_cohort", desc = "The stake's RewardCohort PDA [\"reward_cohort\", pool, cohort] (required for stakes of a reward cohort)")]
+    #[account(16, optional, name="hook_program", desc = "The pool's hook program (required when the pool has one); the hook's own accounts follow")]
+    ClaimRewards {
+        /// Rewards to claim (all unclaimed rewards when None)
+        amount: Option<u64>,
+        /// Memo written via the SPL Memo program, signed by the claimer (at most MAX_MEMO_LEN bytes)
+        memo: Option<String>,
+        /// Unix timestamp after which the claim is rejected (None: no deadline)
+        deadline: Option<i64>,
+    },
 
-    /// Update pool settings (global admin only)
+    /// Update pool settings (global admin only; pausers may change is_paused and
+    /// rate admins reward_rate)
     #[account(0, writable, name="pool", desc = "The stake pool")]
-    #[account(1, signer, name="admin", desc = "The global admin (authorized in ProgramAuthority)")]
+    #[account(1, signer, name="admin", desc = "The global admin or a role holder allowed to change the updated parameters")]
     #[account(2, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(3, name="program_config", desc = "The program config PDA [\"program_config\"] (built-in limits while uninitialized)")]
     UpdatePool {
         reward_rate: Option<u64>,
         min_stake_amount: Option<u64>,
+        /// Proposed lockup period, applied by FinalizeLockupChange after the pool's delay
         lockup_period: Option<i64>,
         is_paused: Option<bool>,
-        /// Whether to enforce lockup period (prevent early withdrawals)
+        /// Whether to enforce lockup period (prevent early withdrawals), applied like lockup_period
         enforce_lockup: Option<bool>,
         /// Optional pool end date (Unix timestamp). Set to extend/shorten pool duration.
         pool_end_date: Option<Option<i64>>,
+        /// Share of each claim (basis points) paid to referrers
+        referral_bps: Option<u16>,
+        /// Share of a position (basis points) that may be unstaked before lockup completion
+        early_unstake_bps: Option<u16>,
+        /// Fee (basis points) taken from each stake deposit
+        deposit_fee_bps: Option<u16>,
+        /// Fee (basis points) taken from each unstake
+        withdraw_fee_bps: Option<u16>,
+        /// Seconds between RequestUnstake and WithdrawUnstaked (0 restores the one-step Unstake)
+        unstake_cooldown: Option<i64>,
+        /// End of the early-bird bonus window (Some(None) removes the bonus)
+        bonus_end_ts: Option<Option<i64>>,
+        /// Reward rate multiplier (basis points) of stakes created in the bonus window
+        bonus_multiplier_bps: Option<u16>,
+        /// Bonus reward rate per year of ExtendLockup extension (0 disables ExtendLockup)
+        lockup_extension_rate: Option<u64>,
+        /// Root of the merkle tree of allowed stake owners (Some(None) removes it)
+        allowlist_merkle_root: Option<Option<[u8; 32]>>,
+        /// Recurring window rewards can be claimed in (Some(None) lifts the restriction)
+        claim_window: Option<Option<ClaimWindow>>,
+        /// Caps on the unstakes and claims of any 24h window (Some(None) lifts them)
+        outflow_limit: Option<Option<OutflowLimit>>,
+        /// Manager who may pause and fund this pool only (Some(None) removes it)
+        pool_manager: Option<Option<Pubkey>>,
+        /// Lamports paid per finalize, sync or stream crank, from the pool's escrow (Some(0) disables it)
+        keeper_bounty: Option<u64>,
+        /// Cap on the rewards the pool commits over its life (Some(None) lifts it)
+        max_total_rewards: Option<Option<u64>>,
+        /// Whether partial unstakes must leave at least min_stake_amount staked
+        enforce_min_remaining_stake: Option<bool>,
+        /// What WindDownStake does with the unclaimed rewards of the stakes it returns
+        wind_down_rewards: Option<WindDownRewards>,
+        /// Points accrued per staked base unit per second, scaled by 1e9 (0 disables points)
+        points_rate: Option<u64>,
+        /// Whether stakes, unstakes and claims check the global deny list
+        deny_list_required: Option<bool>,
+        /// Whether Stake accepts funders staking for owners who do not sign
+        allow_third_party_stake: Option<bool>,
+        /// Length in seconds of the pool's reward cohorts (0 turns them off; empty pools only)
+        cohort_duration: Option<i64>,
     },
 
-    /// Fund the reward pool (anyone can fund)
+    /// Fund the reward pool (anyone, or only the pool's reward funders when an allowlist is set)
     #[account(0, writable, name="pool", desc = "The stake pool")]
     #[account(1, signer, name="funder", desc = "The account funding rewards")]
     #[account(2, writable, name="funder_token_account", desc = "Funder's reward token account")]
@@ -104,27 +253,38 @@ pub enum StakePoolInstruction {
     #[account(0, writable, name="stake_account", desc = "The stake account to close")]
     #[account(1, signer, name="owner", desc = "The stake account owner")]
     #[account(2, writable, name="receiver", desc = "Account to receive the rent lamports")]
+    #[account(3, optional, writable, name="stake_position", desc = "The owner's stake position directory (None: left as is)")]
     CloseStakeAccount,
 
     /// Finalize a pending reward rate change after the delay period
     /// This completes the two-step process for changing reward rates.
     /// After authority proposes a rate change via UpdatePool, anyone can
-    /// call this after 7 days to apply the change.
+    /// call this after 7 days (1 hour on Devnet-profile deployments) to apply the change.
     #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, name="program_authority", desc = "The program authority account (provides the cluster profile's delay)")]
+    #[account(2, optional, writable, name="keeper", desc = "Account receiving the pool's keeper bounty (None: no bounty)")]
     FinalizeRewardRateChange,
 
     /// Initialize the program authority (one-time setup)
-    /// This creates the global authority account that controls who can create pools
+    /// This creates the global authority account that controls who can create pools.
+    /// Repeating it with the same authority and cluster profile succeeds without changes;
+    /// an authority in an older layout is upgraded with MigrateProgramAuthority instead.
     #[account(0, writable, name="program_authority", desc = "The program authority PDA to create")]
     #[account(1, signer, name="initial_authority", desc = "The initial authority who will control authorized creators")]
     #[account(2, writable, signer, name="payer", desc = "The account paying for rent")]
     #[account(3, name="system_program", desc = "The system program")]
-    InitializeProgramAuthority,
+    InitializeProgramAuthority {
+        /// Cluster the deployment serves. Devnet enables dev-only instructions and shorter timelocks.
+        cluster_profile: ClusterProfile,
+    },
 
     /// Manage authorized pool creators (add or remove)
     /// Only the program authority can call this
+    /// The program authority grows when the creator list no longer fits; the payer funds the rent.
     #[account(0, writable, name="program_authority", desc = "The program authority PDA")]
     #[account(1, signer, name="authority", desc = "The program authority signer")]
+    #[account(2, optional, writable, signer, name="payer", desc = "Pays rent when the program authority grows (required when the creator list outgrows the account)")]
+    #[account(3, optional, name="system_program", desc = "The system program (required with payer)")]
     ManageAuthorizedCreators {
         /// Addresses to add to authorized creators list
         add: Vec<Pubkey>,
@@ -146,22 +306,1133 @@ pub enum StakePoolInstruction {
     #[account(1, signer, name="pending_authority", desc = "The pending authority accepting the transfer")]
     AcceptProgramAuthority,
 
-    /// Cancel a pending authority transfer
+    /// Cancel a pending authority transfer and any authority recovery in progress
     /// Only the current authority can call this
     #[account(0, writable, name="program_authority", desc = "The program authority PDA")]
     #[account(1, signer, name="current_authority", desc = "The current program authority")]
     CancelAuthorityTransfer,
 
     /// Get all authorized creators (for off-chain queries via simulateTransaction)
-    /// Returns the ProgramAuthority account data which can be deserialized client-side
+    /// Returns an `AuthorizedCreators` via return data
     #[account(0, name="program_authority", desc = "The program authority PDA")]
     GetAuthorizedCreators,
 
-    /// Check if an address is authorized to create pools
-    /// Returns success if authorized, error if not (for off-chain queries via simulateTransaction)
+    /// Check if an address is authorized to create pools (for off-chain queries via simulateTransaction)
+    /// Returns an `AuthorizationCheck` via return data
     #[account(0, name="program_authority", desc = "The program authority PDA")]
     CheckAuthorization {
         /// The address to check
         address: Pubkey,
     },
+
+    /// Withdraw collected deposit and withdrawal fees from the pool's fee vault (global admin or treasurer)
+    #[account(0, name="pool", desc = "The stake pool")]
+    #[account(1, signer, name="admin", desc = "The global admin or a treasurer")]
+    #[account(2, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(3, writable, name="fee_vault", desc = "Pool's fee vault")]
+    #[account(4, writable, name="destination", desc = "Token account receiving the fees")]
+    #[account(5, name="stake_mint", desc = "The token mint being staked")]
+    #[account(6, name="token_program", desc = "The token program")]
+    WithdrawFees { amount: u64 },
+
+    /// Apply the same parameter changes to many pools (global admin only; pausers may
+    /// change is_paused and rate admins reward_rate)
+    /// Pools are passed as writable remaining accounts. Each pool is updated independently:
+    /// a pool that fails validation is skipped with a `PoolBatchUpdateFailed` event while
+    /// the others are still updated (`PoolBatchUpdated` event).
+    #[account(0, signer, name="admin", desc = "The global admin or a role holder allowed to change the updated parameters")]
+    #[account(1, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(2, name="program_config", desc = "The program config PDA [\"program_config\"] (built-in limits while uninitialized)")]
+    UpdatePoolsBatch {
+        reward_rate: Option<u64>,
+        min_stake_amount: Option<u64>,
+        lockup_period: Option<i64>,
+        is_paused: Option<bool>,
+        enforce_lockup: Option<bool>,
+        pool_end_date: Option<Option<i64>>,
+        referral_bps: Option<u16>,
+        early_unstake_bps: Option<u16>,
+        deposit_fee_bps: Option<u16>,
+        withdraw_fee_bps: Option<u16>,
+        unstake_cooldown: Option<i64>,
+        /// End of the early-bird bonus window (Some(None) removes the bonus)
+        bonus_end_ts: Option<Option<i64>>,
+        /// Reward rate multiplier (basis points) of stakes created in the bonus window
+        bonus_multiplier_bps: Option<u16>,
+        /// Bonus reward rate per year of ExtendLockup extension (0 disables ExtendLockup)
+        lockup_extension_rate: Option<u64>,
+        /// Root of the merkle tree of allowed stake owners (Some(None) removes it)
+        allowlist_merkle_root: Option<Option<[u8; 32]>>,
+        /// Recurring window rewards can be claimed in (Some(None) lifts the restriction)
+        claim_window: Option<Option<ClaimWindow>>,
+        /// Caps on the unstakes and claims of any 24h window (Some(None) lifts them)
+        outflow_limit: Option<Option<OutflowLimit>>,
+        /// Manager who may pause and fund this pool only (Some(None) removes it)
+        pool_manager: Option<Option<Pubkey>>,
+        /// Lamports paid per finalize, sync or stream crank, from the pool's escrow (Some(0) disables it)
+        keeper_bounty: Option<u64>,
+        /// Cap on the rewards the pool commits over its life (Some(None) lifts it)
+        max_total_rewards: Option<Option<u64>>,
+        /// Whether partial unstakes must leave at least min_stake_amount staked
+        enforce_min_remaining_stake: Option<bool>,
+        /// What WindDownStake does with the unclaimed rewards of the stakes it returns
+        wind_down_rewards: Option<WindDownRewards>,
+        /// Points accrued per staked base unit per second, scaled by 1e9 (0 disables points)
+        points_rate: Option<u64>,
+        /// Whether stakes, unstakes and claims check the global deny list
+        deny_list_required: Option<bool>,
+        /// Whether Stake accepts funders staking for owners who do not sign
+        allow_third_party_stake: Option<bool>,
+        /// Length in seconds of the pool's reward cohorts (0 turns them off; empty pools only)
+        cohort_duration: Option<i64>,
+    },
+
+    /// Stake lamports into a native SOL pool (stake mint is wrapped SOL)
+    /// The lamports are wrapped into the wSOL stake vault; no user token account is needed.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="stake_account", desc = "The stake account PDA (will be created)")]
+    #[account(2, writable, signer, name="owner", desc = "The stake account owner (lamports are taken from this account)")]
+    #[account(3, writable, name="stake_vault", desc = "Pool's wSOL stake vault")]
+    #[account(4, name="reward_vault", desc = "Pool's reward vault (for checking available rewards)")]
+    #[account(5, name="stake_mint", desc = "The native mint")]
+    #[account(6, name="token_program", desc = "The token program (Token or Token-2022)")]
+    #[account(7, writable, signer, name="payer", desc = "The account paying for rent")]
+    #[account(8, name="system_program", desc = "The system program")]
+    #[account(9, writable, name="fee_vault", desc = "Pool's fee vault")]
+    #[account(10, writable, name="mint_registry", desc = "The stake mint's registry (enforces the TVL cap)")]
+    #[account(11, optional, writable, name="receipt_mint", desc = "Pool's receipt mint (required when the pool tokenizes positions)")]
+    #[account(12, optional, writable, name="user_receipt_account", desc = "Owner's receipt token account to mint into (required when the pool tokenizes positions)")]
+    #[account(13, optional, name="receipt_token_program", desc = "The Token-2022 program (required when the pool tokenizes positions)")]
+    #[account(14, name="program_authority", desc = "The program authority account (global pause switches)")]
+    #[account(15, optional, name="allowlist", desc = "Pool allowlist page listing the owner (required when the pool requires allowlisting and no merkle proof is given)")]
+    #[account(16, optional, writable, name="stake_position", desc = "The owner's stake position directory, created when empty (None: the index is not recorded)")]
+    StakeSol {
+        /// Lamports to stake
+        amount: u64,
+        index: u64,
+        /// Frontrunning protection: expected reward rate (optional)
+        expected_reward_rate: Option<u64>,
+        /// Frontrunning protection: expected lockup period (optional)
+        expected_lockup_period: Option<i64>,
+        /// Optional referrer credited with the pool's referral share on every claim
+        referrer: Option<Pubkey>,
+        /// Let the permissionless Relock crank restart the lockup once it expires
+        auto_relock: bool,
+        /// Optional owner label for the position (zero-padded, see SetStakeLabel)
+        label: Option<[u8; 32]>,
+        /// Merkle proof that the owner is on the pool's allowlist root (see `merkle`)
+        allowlist_proof: Option<Vec<[u8; 32]>>,
+    },
+
+    /// Unstake from a native SOL pool, unwrapping to the owner's system account
+    /// A temporary wSOL account is created at the unwrap PDA and closed within the instruction.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="stake_account", desc = "The user's stake account")]
+    #[account(2, writable, signer, name="owner", desc = "The stake account owner (receives the lamports)")]
+    #[account(3, writable, name="stake_vault", desc = "Pool's wSOL stake vault")]
+    #[account(4, name="stake_mint", desc = "The native mint")]
+    #[account(5, name="token_program", desc = "The token program")]
+    #[account(6, name="clock", desc = "Clock sysvar")]
+    #[account(7, writable, name="fee_vault", desc = "Pool's fee vault")]
+    #[account(8, writable, name="unwrap_account", desc = "Temporary wSOL account PDA [\"unwrap\", stake_account]")]
+    #[account(9, name="system_program", desc = "The system program")]
+    #[account(10, writable, name="mint_registry", desc = "The stake mint's registry")]
+    #[account(11, optional, writable, name="receipt_mint", desc = "Pool's receipt mint (required when the pool tokenizes positions)")]
+    #[account(12, optional, writable, name="user_receipt_account", desc = "Owner's receipt token account to burn from (required when the pool tokenizes positions)")]
+    #[account(13, optional, name="receipt_token_program", desc = "The Token-2022 program (required when the pool tokenizes positions)")]
+    #[account(14, name="program_authority", desc = "The program authority account (global pause switches)")]
+    UnstakeSol {
+        /// Lamports to unstake
+        amount: u64,
+        /// Frontrunning protection: expected reward rate (optional)
+        expected_reward_rate: Option<u64>,
+    },
+
+    /// Enable or disable auto re-lock on a stake account (owner only)
+    #[account(0, writable, name="stake_account", desc = "The user's stake account")]
+    #[account(1, signer, name="owner", desc = "The stake account owner")]
+    SetAutoRelock {
+        auto_relock: bool,
+    },
+
+    /// Restart the expired lockup of a stake account with auto re-lock enabled (permissionless crank)
+    /// Unclaimed rewards of the completed round are carried over and the new round's reward is reserved.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="stake_account", desc = "The stake account to re-lock")]
+    #[account(2, name="reward_vault", desc = "Pool's reward vault (for the solvency check)")]
+    Relock,
+
+    /// Re-read the pool's vault balances and check them against its recorded totals (permissionless crank)
+    /// An insolvent pool is paused until an admin unpauses it.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, name="stake_vault", desc = "Pool's stake vault")]
+    #[account(2, name="reward_vault", desc = "Pool's reward vault")]
+    #[account(3, optional, writable, name="keeper", desc = "Account receiving the pool's keeper bounty (None: no bounty)")]
+    SyncPool,
+
+    /// Close the program authority and recover rent (Devnet-profile deployments only)
+    /// Allows a devnet deployment to be re-initialized from scratch.
+    #[account(0, writable, name="program_authority", desc = "The program authority PDA to close")]
+    #[account(1, signer, name="authority", desc = "The current program authority")]
+    #[account(2, writable, name="receiver", desc = "Account to receive the rent lamports")]
+    CloseProgramAuthority,
+
+    /// Replace the pool's reward funder allowlist (global admin only)
+    /// An empty list lets anyone fund rewards.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, signer, name="admin", desc = "The global admin (authorized in ProgramAuthority)")]
+    #[account(2, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    SetRewardFunders {
+        funders: Vec<Pubkey>,
+    },
+
+    /// Withdraw reward tokens not owed to stakers from the reward vault (global admin or treasurer)
+    /// At most the vault balance minus total_rewards_owed and expired_rewards can be withdrawn.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, signer, name="admin", desc = "The global admin or a treasurer")]
+    #[account(2, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(3, writable, name="reward_vault", desc = "Pool's reward vault")]
+    #[account(4, writable, name="destination", desc = "Token account receiving the surplus")]
+    #[account(5, name="reward_mint", desc = "The reward token mint")]
+    #[account(6, name="token_program", desc = "The token program")]
+    DefundRewards { amount: u64 },
+
+    /// Set the total value locked cap of a stake mint across all its pools (global admin only)
+    /// Creates the mint registry if the mint has no pool yet. None removes the cap.
+    #[account(0, writable, name="mint_registry", desc = "The stake mint's registry PDA [\"mint_registry\", stake_mint]")]
+    #[account(1, name="stake_mint", desc = "The stake mint")]
+    #[account(2, writable, signer, name="admin", desc = "The global admin (pays rent if the registry is created)")]
+    #[account(3, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(4, name="system_program", desc = "The system program")]
+    SetMintTvlCap { tvl_cap: Option<u64> },
+
+    /// Decommission a pool and recover its rent (global admin only)
+    /// Second step after pausing the pool: requires total_staked and total_rewards_owed to be zero.
+    /// Residual vault tokens are swept to the receivers, then the vaults and the pool are closed.
+    #[account(0, writable, name="pool", desc = "The stake pool to close")]
+    #[account(1, signer, name="admin", desc = "The global admin (authorized in ProgramAuthority)")]
+    #[account(2, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(3, writable, name="stake_vault", desc = "Pool's stake vault")]
+    #[account(4, writable, name="reward_vault", desc = "Pool's reward vault")]
+    #[account(5, writable, name="fee_vault", desc = "Pool's fee vault")]
+    #[account(6, name="stake_mint", desc = "The token mint being staked")]
+    #[account(7, name="reward_mint", desc = "The reward token mint")]
+    #[account(8, writable, name="stake_receiver", desc = "Token account (stake mint) receiving residual stake and fee vault tokens")]
+    #[account(9, writable, name="reward_receiver", desc = "Token account (reward mint) receiving residual reward vault tokens")]
+    #[account(10, writable, name="rent_receiver", desc = "Account to receive the rent lamports of the vaults and the pool")]
+    #[account(11, name="token_program", desc = "The token program")]
+    ClosePool,
+
+    /// Set or clear the delegate allowed to claim a stake account's rewards (owner only)
+    /// The delegate can only claim into a token account owned by the stake owner.
+    #[account(0, writable, name="stake_account", desc = "The user's stake account")]
+    #[account(1, signer, name="owner", desc = "The stake account owner")]
+    SetStakeDelegate {
+        delegate: Option<Pubkey>,
+    },
+
+    /// Tokenize the pool's positions with a receipt mint (global admin only)
+    /// Creates the Token-2022 receipt mint PDA ["receipt_mint", pool] with the pool as mint
+    /// authority. Stakes then mint receipt tokens 1:1 and unstakes burn them.
+    /// Only allowed while nothing is staked, so every position is backed by receipts.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, signer, name="admin", desc = "The global admin (pays rent for the receipt mint)")]
+    #[account(2, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(3, writable, name="receipt_mint", desc = "The receipt mint PDA (will be created)")]
+    #[account(4, name="stake_mint", desc = "The token mint being staked (receipt decimals match it)")]
+    #[account(5, name="receipt_token_program", desc = "The Token-2022 program")]
+    #[account(6, name="system_program", desc = "The system program")]
+    EnableReceiptToken {
+        /// Whether receipt tokens can be transferred. When false the mint carries the
+        /// Token-2022 NonTransferable extension.
+        transferable: bool,
+    },
+
+    /// Move the unclaimed rewards of an abandoned stake to the pool's expired rewards (permissionless crank)
+    /// Allowed once REWARD_EXPIRY_PERIOD has passed since the stake's lockup completed.
+    /// The owner keeps the principal but can no longer claim the expired rewards.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="stake_account", desc = "The abandoned stake account")]
+    ExpireRewards,
+
+    /// Withdraw the pool's expired rewards from the reward vault (global admin only)
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, signer, name="admin", desc = "The global admin (authorized in ProgramAuthority)")]
+    #[account(2, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(3, writable, name="reward_vault", desc = "Pool's reward vault")]
+    #[account(4, writable, name="destination", desc = "Token account receiving the expired rewards")]
+    #[account(5, name="reward_mint", desc = "The reward token mint")]
+    #[account(6, name="token_program", desc = "The token program")]
+    ClaimExpiredRewards,
+
+    /// Start the unstake cooldown for part of a position (owner only)
+    /// The amount leaves the position (forfeiting its unclaimed rewards like Unstake) and
+    /// becomes withdrawable with WithdrawUnstaked once the pool's unstake_cooldown has passed.
+    /// A new request adds to the pending amount and restarts the cooldown.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="stake_account", desc = "The user's stake account")]
+    #[account(2, signer, name="owner", desc = "The stake account owner")]
+    #[account(3, optional, writable, name="receipt_mint", desc = "Pool's receipt mint (required when the pool tokenizes positions)")]
+    #[account(4, optional, writable, name="user_receipt_account", desc = "Owner's receipt token account to burn from (required when the pool tokenizes positions)")]
+    #[account(5, optional, name="receipt_token_program", desc = "The Token-2022 program (required when the pool tokenizes positions)")]
+    #[account(6, name="program_authority", desc = "The program authority account (global pause switches)")]
+    RequestUnstake {
+        amount: u64,
+        /// Frontrunning protection: expected reward rate (optional)
+        expected_reward_rate: Option<u64>,
+    },
+
+    /// Withdraw the pending amount of a RequestUnstake after the cooldown (owner only)
+    /// The pool's withdraw_fee_bps is taken at withdrawal time.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="stake_account", desc = "The user's stake account")]
+    #[account(2, signer, name="owner", desc = "The stake account owner")]
+    #[account(3, writable, name="user_token_account", desc = "User's token account")]
+    #[account(4, writable, name="stake_vault", desc = "Pool's stake vault")]
+    #[account(5, name="stake_mint", desc = "The token mint being staked")]
+    #[account(6, name="token_program", desc = "The token program")]
+    #[account(7, writable, name="fee_vault", desc = "Pool's fee vault")]
+    #[account(8, writable, name="mint_registry", desc = "The stake mint's registry")]
+    #[account(9, name="program_authority", desc = "The program authority account (global pause switches)")]
+    #[account(10, optional, name="deny_list", desc = "The global deny list PDA [\"deny_list\"] (required when the pool checks it)")]
+    WithdrawUnstaked,
+
+    /// Set the protocol-wide pause switches (global admin or pauser)
+    /// global_pause halts stake, unstake and claim operations in every pool; withdrawals_only
+    /// blocks stakes and claims but keeps unstakes and withdrawals open.
+    #[account(0, writable, name="program_authority", desc = "The program authority PDA")]
+    #[account(1, signer, name="admin", desc = "The global admin or a pauser")]
+    SetGlobalPause {
+        global_pause: bool,
+        withdrawals_only: bool,
+    },
+
+    /// Upgrade a pool and its stake accounts from the V1 layout (global admin only)
+    /// Grows each account to the current size and rewrites it with a V2 discriminator.
+    /// Stake accounts of the pool are passed as writable remaining accounts, so large
+    /// pools can be migrated over several transactions. Accounts already migrated are
+    /// skipped; fee_vault and mint_registry are only used while the pool is still V1.
+    /// V2 pools and stake accounts allocated before their layout last grew are grown to the
+    /// current size, and those written before the 8-byte discriminator get it.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, signer, name="admin", desc = "The global admin (pays rent for the larger accounts)")]
+    #[account(2, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(3, name="fee_vault", desc = "Pool-owned stake mint token account to record as the pool's fee vault")]
+    #[account(4, writable, name="mint_registry", desc = "The stake mint's registry (created if missing, records the pool's stake)")]
+    #[account(5, name="system_program", desc = "The system program")]
+    MigratePool,
+
+    /// Stake several deposits at once, creating one stake account per amount
+    /// Stake account PDAs for indices start_index, start_index + 1, ... are passed as
+    /// writable remaining accounts, one per amount (at most MAX_STAKE_BATCH).
+    /// The pool is loaded, checked and saved once for the whole batch.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, signer, name="owner", desc = "The stake accounts owner")]
+    #[account(2, writable, name="user_token_account", desc = "User's token account")]
+    #[account(3, writable, name="stake_vault", desc = "Pool's stake vault")]
+    #[account(4, name="reward_vault", desc = "Pool's reward vault (for checking available rewards)")]
+    #[account(5, name="stake_mint", desc = "The token mint being staked")]
+    #[account(6, name="token_program", desc = "The token program (Token or Token-2022)")]
+    #[account(7, writable, signer, name="payer", desc = "The account paying for rent")]
+    #[account(8, name="system_program", desc = "The system program")]
+    #[account(9, writable, name="fee_vault", desc = "Pool's fee vault")]
+    #[account(10, writable, name="mint_registry", desc = "The stake mint's registry (enforces the TVL cap)")]
+    #[account(11, optional, writable, name="receipt_mint", desc = "Pool's receipt mint (required when the pool tokenizes positions)")]
+    #[account(12, optional, writable, name="user_receipt_account", desc = "Owner's receipt token account to mint into (required when the pool tokenizes positions)")]
+    #[account(13, optional, name="receipt_token_program", desc = "The Token-2022 program (required when the pool tokenizes positions)")]
+    #[account(14, name="program_authority", desc = "The program authority account (global pause switches)")]
+    #[account(15, optional, name="allowlist", desc = "Pool allowlist page listing the owner (required when the pool requires allowlisting and no merkle proof is given)")]
+    #[account(16, optional, writable, name="stake_position", desc = "The owner's stake position directory, created when empty (None: the index is not recorded)")]
+    StakeBatch {
+        /// Deposit of each new stake account, in index order
+        amounts: Vec<u64>,
+        /// Index of the first stake account created
+        start_index: u64,
+        /// Frontrunning protection: expected reward rate (optional)
+        expected_reward_rate: Option<u64>,
+        /// Frontrunning protection: expected lockup period (optional)
+        expected_lockup_period: Option<i64>,
+        /// Merkle proof that the owner is on the pool's allowlist root (see `merkle`)
+        allowlist_proof: Option<Vec<[u8; 32]>>,
+    },
+
+    /// Claim the rewards of several stake accounts of one pool in a single transfer
+    /// Stake accounts are passed as writable remaining accounts; each must be claimable
+    /// by the signer. Stakes not yet matured are skipped, stakes paying a referral share
+    /// must use ClaimRewards. Returns a `ClaimRewardsResult` with the totals via return data.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, signer, name="owner", desc = "The stake accounts owner, or their delegate (rewards then go to an owner token account only)")]
+    #[account(2, writable, name="user_reward_account", desc = "Owner's reward token account")]
+    #[account(3, writable, name="reward_vault", desc = "Pool's reward vault")]
+    #[account(4, name="reward_mint", desc = "The reward token mint")]
+    #[account(5, name="token_program", desc = "The token program")]
+    #[account(6, name="clock", desc = "Clock sysvar")]
+    #[account(7, name="program_authority", desc = "The program authority account (global pause switches)")]
+    ClaimRewardsBatch,
+
+    /// Get the pending rewards of a stake account (for off-chain queries via simulateTransaction)
+    /// Returns a `PendingRewards` via return data, computed at the current clock with the
+    /// same math ClaimRewards uses.
+    #[account(0, name="pool", desc = "The stake pool")]
+    #[account(1, name="stake_account", desc = "The stake account")]
+    GetPendingRewards,
+
+    /// List a pool created before the pool registry existed (permissionless)
+    /// Fails if the pool is already registered.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="pool_registry", desc = "The pool registry page the pool is listed in (latest page, created when the previous one is full)")]
+    #[account(2, optional, name="previous_pool_registry", desc = "The previous registry page (required to create a page after page 0)")]
+    #[account(3, writable, signer, name="payer", desc = "The account paying for rent of a new registry page")]
+    #[account(4, name="system_program", desc = "The system program")]
+    RegisterPool,
+
+    /// Set the maximum number of pools an authorized creator may create
+    /// Only the program authority can call this. None removes the limit; a limit below
+    /// the creator's current pool count blocks further pools without touching existing ones.
+    #[account(0, writable, name="program_authority", desc = "The program authority PDA")]
+    #[account(1, signer, name="authority", desc = "The program authority signer")]
+    SetCreatorPoolLimit {
+        /// The authorized creator to limit
+        creator: Pubkey,
+        /// Maximum pools the creator may create (None = unlimited)
+        pool_limit: Option<u16>,
+    },
+
+    /// Grant or revoke scoped roles (program authority only)
+    /// Pausers can pause and unpause pools and flip the global pause switches, rate admins
+    /// can propose reward rate changes, treasurers can defund rewards and withdraw fees.
+    /// Global admins hold every role.
+    #[account(0, writable, name="program_authority", desc = "The program authority PDA")]
+    #[account(1, signer, name="authority", desc = "The program authority signer")]
+    ManageRoles {
+        /// The address whose roles are set
+        holder: Pubkey,
+        /// Bit mask of roles to hold (bit 0 pauser, bit 1 rate admin, bit 2 treasurer; 0 revokes all)
+        roles: u8,
+    },
+
+    /// Set how many global admins must approve sensitive pool updates (program authority only)
+    /// Above 1, reward rate, pause and end date changes are rejected by UpdatePool and
+    /// UpdatePoolsBatch and go through ProposePoolUpdate / ApprovePoolUpdate / ExecutePoolUpdate.
+    #[account(0, writable, name="program_authority", desc = "The program authority PDA")]
+    #[account(1, signer, name="authority", desc = "The program authority signer")]
+    SetApprovalThreshold {
+        /// Approvals required (0 or 1 disables the approval flow, at most the number of global admins)
+        threshold: u8,
+    },
+
+    /// Propose a sensitive pool update for multi-admin approval (global admin only)
+    /// Creates a pending action PDA ["pending_action", pool, action_id] carrying the
+    /// proposer's approval.
+    #[account(0, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="pending_action", desc = "The pending action PDA")]
+    #[account(2, writable, signer, name="proposer", desc = "The proposing global admin (pays rent)")]
+    #[account(3, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(4, name="system_program", desc = "The system program")]
+    ProposePoolUpdate {
+        /// Proposer-chosen id of the pending action
+        action_id: u64,
+        /// The proposed parameter changes
+        update: SensitivePoolUpdate,
+    },
+
+    /// Approve a pending pool update (global admin only, once per admin)
+    #[account(0, writable, name="pending_action", desc = "The pending action PDA")]
+    #[account(1, signer, name="approver", desc = "The approving global admin")]
+    #[account(2, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    ApprovePoolUpdate,
+
+    /// Apply a pending pool update once enough admins approved it (global admin only)
+    /// Approvals of addresses no longer global admins are not counted. The pending action
+    /// is closed and its rent returned to the proposer.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="pending_action", desc = "The pending action PDA")]
+    #[account(2, signer, name="executor", desc = "The executing global admin")]
+    #[account(3, writable, name="proposer", desc = "The proposer, receiving the pending action's rent")]
+    #[account(4, name="program_authority", desc = "The program authority account (validates admin permission and threshold)")]
+    #[account(5, name="program_config", desc = "The program config PDA [\"program_config\"] (built-in limits while uninitialized)")]
+    ExecutePoolUpdate,
+
+    /// Finalize a pending lockup_period / enforce_lockup change after the pool's delay (permissionless)
+    /// UpdatePool only proposes lockup changes, since they affect existing stakes.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, name="program_authority", desc = "The program authority account (provides the cluster profile's delay)")]
+    #[account(2, optional, writable, name="keeper", desc = "Account receiving the pool's keeper bounty (None: no bounty)")]
+    FinalizeLockupChange,
+
+    /// Set or clear the owner's label of a stake account (owner only)
+    #[account(0, writable, name="stake_account", desc = "The user's stake account")]
+    #[account(1, signer, name="owner", desc = "The stake account owner")]
+    SetStakeLabel {
+        /// Zero-padded label, e.g. UTF-8 "treasury-Q3" (all zeros clears the label)
+        label: [u8; 32],
+    },
+
+    /// Nominate a wallet to take over a stake position, or cancel the nomination (owner only)
+    /// Pools checking the deny list refuse nominations from or to a listed wallet.
+    #[account(0, writable, name="stake_account", desc = "The stake account to transfer")]
+    #[account(1, signer, name="owner", desc = "The stake account owner")]
+    #[account(2, name="pool", desc = "The stake account's pool")]
+    #[account(3, optional, name="deny_list", desc = "The global deny list PDA [\"deny_list\"] (required when the pool checks it)")]
+    NominateStakeOwner {
+        /// Wallet allowed to accept the position (None cancels the nomination)
+        new_owner: Option<Pubkey>,
+    },
+
+    /// Accept a nominated stake position (nominated owner only)
+    /// The position moves into the new owner's stake account at `new_index` with its
+    /// amounts, timestamps and rewards; the previous stake account is closed. Pools
+    /// checking the deny list refuse it when either owner is listed.
+    #[account(0, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="stake_account", desc = "The previous owner's stake account (closed)")]
+    #[account(2, writable, name="new_stake_account", desc = "The new owner's stake account PDA (created)")]
+    #[account(3, writable, signer, name="new_owner", desc = "The nominated owner (pays rent for the new stake account)")]
+    #[account(4, writable, name="previous_owner", desc = "The previous owner (receives the closed account's rent)")]
+    #[account(5, name="system_program", desc = "The system program")]
+    #[account(6, optional, writable, name="previous_stake_position", desc = "The previous owner's stake position directory, releasing the index (None: left as is)")]
+    #[account(7, optional, writable, name="new_stake_position", desc = "The new owner's stake position directory, recording new_index; created when empty (None: the index is not recorded)")]
+    #[account(8, optional, name="deny_list", desc = "The global deny list PDA [\"deny_list\"] (required when the pool checks it)")]
+    AcceptStakeOwnership {
+        /// Stake index of the position under the new owner
+        new_index: u64,
+    },
+
+    /// Sweep stake vault tokens not backing total_staked to a treasury account (global admin or treasurer)
+    /// Moves the stake vault balance minus total_staked (transfer fee dust, stray deposits),
+    /// leaving the vault holding exactly total_staked. Reward vault surplus is withdrawn with DefundRewards.
+    #[account(0, name="pool", desc = "The stake pool")]
+    #[account(1, signer, name="admin", desc = "The global admin or a treasurer")]
+    #[account(2, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(3, writable, name="stake_vault", desc = "Pool's stake vault")]
+    #[account(4, writable, name="treasury", desc = "Token account (stake mint) receiving the dust")]
+    #[account(5, name="stake_mint", desc = "The token mint being staked")]
+    #[account(6, name="token_program", desc = "The token program")]
+    SweepDust,
+
+    /// Get the pool's health (for off-chain queries via simulateTransaction)
+    /// Returns a `PoolStats` via return data: recorded totals, live vault balances,
+    /// coverage ratios, the active stake count and the paused state.
+    #[account(0, name="pool", desc = "The stake pool")]
+    #[account(1, name="stake_vault", desc = "Pool's stake vault")]
+    #[account(2, name="reward_vault", desc = "Pool's reward vault")]
+    GetPoolStats,
+
+    /// Extend the lockup of a stake account's current round in exchange for bonus rewards (owner only)
+    /// The stake locks in the pool's lockup_extension_rate pro rata to the extension on top of
+    /// its reward rate; the bonus is reserved against the reward vault and paid at maturity.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="stake_account", desc = "The stake account to extend")]
+    #[account(2, signer, name="owner", desc = "The stake account owner")]
+    #[account(3, name="reward_vault", desc = "Pool's reward vault (for the solvency check)")]
+    ExtendLockup {
+        /// Seconds added to the round's lockup
+        additional_seconds: i64,
+    },
+
+    /// Edit a page of the pool's staker allowlist and toggle its enforcement (global admin only)
+    /// Creates the page PDA ["pool_allowlist", pool, page] if it does not exist yet. While
+    /// allowlist_required is set, Stake, StakeSol and StakeBatch only accept listed owners.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, signer, name="admin", desc = "The global admin (authorized in ProgramAuthority)")]
+    #[account(2, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(3, writable, name="allowlist", desc = "The allowlist page PDA (created if missing)")]
+    #[account(4, writable, signer, name="payer", desc = "The account paying for rent of a new page")]
+    #[account(5, name="system_program", desc = "The system program")]
+    ManagePoolAllowlist {
+        /// Page to edit
+        page: u32,
+        /// Owners to add to the page
+        add: Vec<Pubkey>,
+        /// Owners to remove from the page
+        remove: Vec<Pubkey>,
+        /// Whether staking requires an allowlist entry (None leaves it unchanged)
+        allowlist_required: Option<bool>,
+    },
+
+    /// Stake on behalf of a beneficiary with vesting principal (global admin only)
+    /// The admin funds a new stake account of the beneficiary at `index`. Rewards accrue
+    /// as for any stake, but its principal vests linearly over `vesting_duration` seconds
+    /// after a `vesting_cliff`, and only vested principal can be unstaked. Not available
+    /// in pools that tokenize positions.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="stake_account", desc = "The beneficiary's stake account PDA (will be created)")]
+    #[account(2, name="beneficiary", desc = "The owner of the new stake account")]
+    #[account(3, signer, name="admin", desc = "The global admin (authorized in ProgramAuthority)")]
+    #[account(4, writable, name="admin_token_account", desc = "Admin's token account funding the stake")]
+    #[account(5, writable, name="stake_vault", desc = "Pool's stake vault")]
+    #[account(6, name="reward_vault", desc = "Pool's reward vault (for checking available rewards)")]
+    #[account(7, name="stake_mint", desc = "The token mint being staked")]
+    #[account(8, name="token_program", desc = "The token program (Token or Token-2022)")]
+    #[account(9, writable, signer, name="payer", desc = "The account paying for rent")]
+    #[account(10, name="system_program", desc = "The system program")]
+    #[account(11, writable, name="fee_vault", desc = "Pool's fee vault")]
+    #[account(12, writable, name="mint_registry", desc = "The stake mint's registry (enforces the TVL cap)")]
+    #[account(13, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(14, optional, writable, name="stake_position", desc = "The beneficiary's stake position directory, created when empty (None: the index is not recorded)")]
+    VestingStake {
+        amount: u64,
+        index: u64,
+        /// Seconds before any principal vests
+        vesting_cliff: i64,
+        /// Seconds until all principal has vested
+        vesting_duration: i64,
+    },
+
+    /// Propose slashing a share of a stake account's principal (global admin only)
+    /// Creates the slash proposal PDA ["slash_proposal", stake_account], executable after
+    /// a 48 hour timelock, and freezes the stake's principal until the slash is executed
+    /// or cancelled. Not available in pools that tokenize positions.
+    #[account(0, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="stake_account", desc = "The stake account to slash")]
+    #[account(2, writable, name="slash_proposal", desc = "The slash proposal PDA (will be created)")]
+    #[account(3, writable, signer, name="admin", desc = "The proposing global admin (pays rent)")]
+    #[account(4, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(5, name="system_program", desc = "The system program")]
+    ProposeSlash {
+        /// Share of the principal (staked and pending withdrawal) to slash, in basis points
+        slash_bps: u16,
+        /// Stake-mint token account receiving the slashed tokens (None for the reward
+        /// vault, which requires the pool to pay rewards in the stake mint)
+        treasury: Option<Pubkey>,
+    },
+
+    /// Execute a slash proposal once its timelock has passed (global admin only)
+    /// The slashed principal leaves total_staked and its unclaimed rewards are forfeited.
+    /// Tokens slashed into the reward vault count as funded rewards. The proposal is
+    /// closed and its rent returned to the proposer.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="stake_account", desc = "The slashed stake account")]
+    #[account(2, writable, name="slash_proposal", desc = "The slash proposal PDA")]
+    #[account(3, signer, name="executor", desc = "The executing global admin")]
+    #[account(4, writable, name="proposer", desc = "The proposer, receiving the proposal's rent")]
+    #[account(5, writable, name="stake_vault", desc = "Pool's stake vault")]
+    #[account(6, writable, name="destination", desc = "The proposal's treasury, or the pool's reward vault")]
+    #[account(7, name="stake_mint", desc = "The token mint being staked")]
+    #[account(8, name="token_program", desc = "The token program (Token or Token-2022)")]
+    #[account(9, writable, name="mint_registry", desc = "The stake mint's registry")]
+    #[account(10, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    ExecuteSlash,
+
+    /// Drop a slash proposal, unfreezing the stake (global admin only)
+    /// The proposal is closed and its rent returned to the proposer.
+    #[account(0, writable, name="stake_account", desc = "The stake account proposed for slashing")]
+    #[account(1, writable, name="slash_proposal", desc = "The slash proposal PDA")]
+    #[account(2, signer, name="admin", desc = "The cancelling global admin")]
+    #[account(3, writable, name="proposer", desc = "The proposer, receiving the proposal's rent")]
+    #[account(4, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    CancelSlash,
+
+    /// Set or remove the key that can recover a lost program authority (program authority only)
+    /// Cancels any recovery in progress.
+    #[account(0, writable, name="program_authority", desc = "The program authority PDA")]
+    #[account(1, signer, name="authority", desc = "The program authority signer")]
+    SetRecoveryAuthority {
+        /// The recovery key (None removes recovery)
+        recovery_authority: Option<Pubkey>,
+        /// Seconds a takeover takes to complete (7 days to 1 year, ignored when removing)
+        recovery_delay: i64,
+    },
+
+    /// Start taking over the program authority (recovery authority only)
+    /// The takeover completes with CompleteRecovery after the recovery delay unless the
+    /// current authority cancels it with CancelAuthorityTransfer.
+    #[account(0, writable, name="program_authority", desc = "The program authority PDA")]
+    #[account(1, signer, name="recovery_authority", desc = "The recovery authority")]
+    InitiateRecovery,
+
+    /// Become the program authority once the recovery delay has passed (recovery authority only)
+    #[account(0, writable, name="program_authority", desc = "The program authority PDA")]
+    #[account(1, signer, name="recovery_authority", desc = "The recovery authority")]
+    CompleteRecovery,
+
+    /// Create the program config with the current program version (program authority only)
+    /// Until it exists, pools are validated against the built-in limits.
+    #[account(0, writable, name="program_config", desc = "The program config PDA [\"program_config\"] (created)")]
+    #[account(1, signer, name="authority", desc = "The program authority signer")]
+    #[account(2, name="program_authority", desc = "The program authority account")]
+    #[account(3, writable, signer, name="payer", desc = "The account paying for rent")]
+    #[account(4, name="system_program", desc = "The system program")]
+    InitializeProgramConfig {
+        /// Enabled feature flags, one bit per feature
+        feature_flags: u64,
+        /// Highest reward rate pools may be created or updated with (at most MAX_REWARD_RATE)
+        max_reward_rate: u64,
+        /// Shortest lockup period pools may be created with (at least 1 day)
+        min_lockup_period: i64,
+    },
+
+    /// Change the program config and record the current program version (program authority only)
+    #[account(0, writable, name="program_config", desc = "The program config PDA")]
+    #[account(1, signer, name="authority", desc = "The program authority signer")]
+    #[account(2, name="program_authority", desc = "The program authority account")]
+    UpdateProgramConfig {
+        feature_flags: Option<u64>,
+        max_reward_rate: Option<u64>,
+        min_lockup_period: Option<i64>,
+    },
+
+    /// Set how many authorized creators the program authority may hold (program authority only)
+    /// May not drop below the current creator count or exceed ProgramAuthority::MAX_CREATORS.
+    #[account(0, writable, name="program_authority", desc = "The program authority PDA")]
+    #[account(1, signer, name="authority", desc = "The program authority signer")]
+    SetMaxCreators {
+        max_creators: u16,
+    },
+
+    /// Migrate the program authority from the fixed creator array to the growable creator list,
+    /// or add the 8-byte discriminator to one written before it (program authority only).
+    /// The account is resized to the new layout.
+    #[account(0, writable, name="program_authority", desc = "The program authority PDA")]
+    #[account(1, signer, name="authority", desc = "The program authority signer")]
+    #[account(2, writable, signer, name="payer", desc = "Pays rent if the account has to grow")]
+    #[account(3, name="system_program", desc = "The system program")]
+    MigrateProgramAuthority,
+
+    /// Create a new pool with the settings of an existing pool of the same mints, e.g. for
+    /// recurring campaigns (authorized pool creators). Accounts are those of InitializePool
+    /// followed by the source pool; the new pool's vaults are created at their PDAs.
+    #[account(0, writable, name="pool", desc = "The new stake pool PDA")]
+    #[account(1, name="stake_mint", desc = "The token mint being staked (the source pool's)")]
+    #[account(2, name="reward_mint", desc = "The reward token mint (the source pool's)")]
+    #[account(3, writable, name="stake_vault", desc = "The new pool's stake vault PDA [\"stake_vault\", pool] (created)")]
+    #[account(4, writable, name="reward_vault", desc = "The new pool's reward vault PDA [\"reward_vault\", pool] (created)")]
+    #[account(5, writable, signer, name="payer", desc = "The account paying for rent (must be authorized admin)")]
+    #[account(6, name="token_program", desc = "The token program owning both mints")]
+    #[account(7, name="system_program", desc = "The system program")]
+    #[account(8, name="rent", desc = "Rent sysvar")]
+    #[account(9, writable, name="program_authority", desc = "The program authority account (validates creator permission and counts the creator's pools)")]
+    #[account(10, writable, name="fee_vault", desc = "The new pool's fee vault PDA [\"fee_vault\", pool] (stake mint, created)")]
+    #[account(11, writable, name="mint_registry", desc = "The stake mint's registry PDA [\"mint_registry\", stake_mint]")]
+    #[account(12, writable, name="pool_registry", desc = "The pool registry page the pool is listed in (latest page, created when the previous one is full)")]
+    #[account(13, optional, name="previous_pool_registry", desc = "The previous registry page (required to create a page after page 0)")]
+    #[account(14, name="program_config", desc = "The program config PDA [\"program_config\"] (built-in limits while uninitialized)")]
+    #[account(15, name="source_pool", desc = "The pool whose settings are copied")]
+    ClonePool {
+        /// Identifier of the new pool (see InitializePool)
+        pool_id: u64,
+        /// End date of the new pool (None: no end date)
+        pool_end_date: Option<i64>,
+        /// End of the new pool's early-bird bonus window, at the source's multiplier (None: no bonus)
+        bonus_end_ts: Option<i64>,
+    },
+
+    /// Stream rewards into a pool at a fixed rate until `end_ts` (same funders as FundRewards)
+    /// The funder's token account delegates the whole stream to the stream PDA; nothing
+    /// moves until CrankRewardStream.
+    #[account(0, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="reward_stream", desc = "The reward stream PDA [\"reward_stream\", pool, funder]")]
+    #[account(2, writable, signer, name="funder", desc = "The funder (pays for the stream account)")]
+    #[account(3, writable, name="funder_token_account", desc = "Funder's reward token account the stream draws from")]
+    #[account(4, name="reward_mint", desc = "The reward token mint")]
+    #[account(5, name="token_program", desc = "The token program")]
+    #[account(6, name="system_program", desc = "The system program")]
+    CreateRewardStream {
+        /// Reward tokens streamed per second
+        rate_per_second: u64,
+        /// Unix timestamp at which the stream stops
+        end_ts: i64,
+    },
+
+    /// Move the rewards a stream accrued since its last crank into the reward vault (permissionless)
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="reward_stream", desc = "The reward stream PDA")]
+    #[account(2, writable, name="funder_token_account", desc = "The stream's funder token account")]
+    #[account(3, writable, name="reward_vault", desc = "Pool's reward vault")]
+    #[account(4, name="reward_mint", desc = "The reward token mint")]
+    #[account(5, name="token_program", desc = "The token program")]
+    #[account(6, optional, writable, name="keeper", desc = "Account receiving the pool's keeper bounty (None: no bounty)")]
+    CrankRewardStream,
+
+    /// Stop a reward stream, revoking its delegation (stream funder only)
+    /// Rewards accrued but not yet cranked are dropped. The rent is returned to the funder.
+    #[account(0, writable, name="reward_stream", desc = "The reward stream PDA")]
+    #[account(1, writable, signer, name="funder", desc = "The stream's funder")]
+    #[account(2, writable, name="funder_token_account", desc = "The stream's funder token account")]
+    #[account(3, name="token_program", desc = "The token program")]
+    CloseRewardStream,
+
+    /// Add lamports to the pool's keeper bounty escrow (anyone)
+    /// The escrow is the pool account's balance above its rent-exempt minimum; ClosePool
+    /// returns what is left of it with the pool's rent.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, signer, name="funder", desc = "The account funding the escrow")]
+    #[account(2, name="system_program", desc = "The system program")]
+    FundKeeperBounty { lamports: u64 },
+
+    /// Grow a pool account to `new_len` bytes ahead of a larger layout (global admin only)
+    /// The added bytes are zeroed, so fields added there later load as their zero value
+    /// and the upgrade needs no MigratePool. Pools still needing migration are rejected;
+    /// `new_len` must exceed the current size and stay within MAX_POOL_ACCOUNT_LEN.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, signer, name="admin", desc = "The global admin (pays rent for the larger account)")]
+    #[account(2, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(3, name="system_program", desc = "The system program")]
+    ResizePool { new_len: u32 },
+
+    /// Get an owner's stake indices in a pool (for off-chain queries via simulateTransaction)
+    /// Returns a `StakePositions` via return data: the open indices recorded in the owner's
+    /// stake position directory and `next_index`, the index to stake at next. An empty
+    /// directory account returns no indices.
+    #[account(0, name="pool", desc = "The stake pool")]
+    #[account(1, name="owner", desc = "The stake accounts owner")]
+    #[account(2, name="stake_position", desc = "The owner's stake position directory PDA")]
+    GetStakePosition,
+
+    /// Recompute the rewards reserved for the stake accounts passed as remaining accounts
+    /// and compare their sum with the pool's `total_rewards_owed` (permissionless)
+    /// Returns a `PoolAudit` via return data. With `repair`, a global admin resets
+    /// `total_rewards_owed` to the recomputed sum, which requires every active stake.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, optional, signer, name="admin", desc = "Global admin repairing the counter (required with repair)")]
+    #[account(2, optional, name="program_authority", desc = "The program authority account (required with repair)")]
+    AuditPool {
+        /// Reset `total_rewards_owed` to the recomputed sum
+        repair: bool,
+    },
+
+    /// Return an abandoned stake of an ended pool to its owner and close it (permissionless)
+    /// Allowed WIND_DOWN_DELAY after the pool's end date. The principal goes to the owner's
+    /// associated token account, the stake account's rent to the owner, and its earned
+    /// rewards are paid or expired according to the pool's `wind_down_rewards`.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="stake_account", desc = "The abandoned stake account")]
+    #[account(2, writable, name="owner", desc = "The stake account owner (receives the rent)")]
+    #[account(3, writable, name="owner_token_account", desc = "Owner's associated token account for the stake mint")]
+    #[account(4, writable, name="stake_vault", desc = "Pool's stake vault")]
+    #[account(5, name="stake_mint", desc = "The token mint being staked")]
+    #[account(6, writable, name="reward_vault", desc = "Pool's reward vault")]
+    #[account(7, name="reward_mint", desc = "The reward token mint")]
+    #[account(8, optional, writable, name="owner_reward_account", desc = "Owner's associated token account for the reward mint (required when rewards are paid)")]
+    #[account(9, name="token_program", desc = "The token program")]
+    #[account(10, writable, name="mint_registry", desc = "The stake mint's registry")]
+    #[account(11, optional, writable, name="stake_position", desc = "The owner's stake position directory (None: left as is)")]
+    #[account(12, name="program_authority", desc = "The program authority account (global pause switches)")]
+    WindDownStake,
+
+    /// Edit the allowlist of programs pools may call as hooks (global admin only)
+    /// Creates the allowlist PDA ["hook_allowlist"] if it does not exist yet. The stake pool
+    /// program itself cannot be listed.
+    #[account(0, writable, name="hook_allowlist", desc = "The hook allowlist PDA (created if missing)")]
+    #[account(1, signer, name="admin", desc = "The global admin (authorized in ProgramAuthority)")]
+    #[account(2, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(3, writable, signer, name="payer", desc = "The account paying for rent of a new allowlist")]
+    #[account(4, name="system_program", desc = "The system program")]
+    ManageHookAllowlist {
+        /// Programs to add to the allowlist
+        add: Vec<Pubkey>,
+        /// Programs to remove from the allowlist
+        remove: Vec<Pubkey>,
+    },
+
+    /// Set or detach the program a pool calls after stakes, unstakes and claims (global admin only)
+    /// The program must be on the hook allowlist. Stake, Unstake and ClaimRewards of a hooked
+    /// pool must then pass it; StakeSol, StakeBatch, UnstakeSol and ClaimRewardsBatch are rejected.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, signer, name="admin", desc = "The global admin (authorized in ProgramAuthority)")]
+    #[account(2, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(3, optional, name="hook_allowlist", desc = "The hook allowlist PDA (required to set a hook)")]
+    SetPoolHook {
+        /// Hook program to call (None detaches the pool's hook)
+        hook_program: Option<Pubkey>,
+    },
+
+    /// Credit the points a stake accrued since its last sync to its owner (permissionless)
+    /// Points accrue on the stake's principal at the pool's `points_rate`. Creates the
+    /// owner's UserPoints PDA ["user_points", pool, owner] if it does not exist yet.
+    #[account(0, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="stake_account", desc = "The stake account")]
+    #[account(2, writable, name="user_points", desc = "The stake owner's UserPoints PDA (created if missing)")]
+    #[account(3, writable, signer, name="payer", desc = "The account paying for rent of a new UserPoints")]
+    #[account(4, name="system_program", desc = "The system program")]
+    SyncPoints,
+
+    /// Return an owner's points in a pool (read-only, for simulateTransaction)
+    /// Returns a `PointsBalance` via return data: the synced points, plus those the stake
+    /// accounts passed as remaining accounts accrued since their last sync.
+    #[account(0, name="pool", desc = "The stake pool")]
+    #[account(1, name="owner", desc = "The stake accounts owner")]
+    #[account(2, name="user_points", desc = "The owner's UserPoints PDA (may not exist yet)")]
+    GetUserPoints,
+
+    /// Edit the global deny list (global admin only)
+    /// Creates the deny list PDA ["deny_list"] if it does not exist yet. Pools with
+    /// deny_list_required reject stakes, claims and unstakes involving a listed address;
+    /// with allow_listed_withdrawals, listed owners may still withdraw principal to
+    /// their own token accounts.
+    #[account(0, writable, name="deny_list", desc = "The deny list PDA (created if missing)")]
+    #[account(1, signer, name="admin", desc = "The global admin (authorized in ProgramAuthority)")]
+    #[account(2, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    #[account(3, writable, signer, name="payer", desc = "The account paying for rent of a new deny list")]
+    #[account(4, name="system_program", desc = "The system program")]
+    ManageDenyList {
+        /// Addresses to add to the deny list
+        add: Vec<Pubkey>,
+        /// Addresses to remove from the deny list
+        remove: Vec<Pubkey>,
+        /// Whether listed owners may still withdraw principal to themselves (None: unchanged)
+        allow_listed_withdrawals: Option<bool>,
+    },
+
+    /// Move part of a stake into a new stake account of the same owner (owner only)
+    /// The new position at `new_index` keeps the stake's timestamp, locked rate and lockup
+    /// progress; claimed rewards are split in proportion to the principal. Both parts must
+    /// hold at least the pool's min_stake_amount.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="stake_account", desc = "The stake account to split")]
+    #[account(2, writable, name="new_stake_account", desc = "The owner's stake account PDA at new_index (created)")]
+    #[account(3, writable, signer, name="owner", desc = "The stake account owner (pays rent for the new stake account)")]
+    #[account(4, name="system_program", desc = "The system program")]
+    #[account(5, optional, writable, name="stake_position", desc = "The owner's stake position directory, recording new_index (None: left as is)")]
+    SplitStake {
+        /// Principal to move into the new stake account
+        amount: u64,
+        /// Stake index of the new stake account
+        new_index: u64,
+    },
+
+    /// Merge a stake account into another of the same owner and pool (owner only)
+    /// The merged stake keeps the later of the two stake timestamps, so neither part
+    /// matures earlier than it would have; both must share the locked reward rate and
+    /// lockup extension. The source stake account is closed and its rent returned.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="stake_account", desc = "The stake account receiving the principal")]
+    #[account(2, writable, name="source_stake_account", desc = "The stake account merged in (closed)")]
+    #[account(3, writable, signer, name="owner", desc = "The stake accounts' owner (receives the closed account's rent)")]
+    #[account(4, optional, writable, name="stake_position", desc = "The owner's stake position directory, releasing the source index (None: left as is)")]
+    MergeStakes,
+
+    /// Fund the reward vault of one cohort of a pool with reward cohorts (anyone, or only
+    /// the pool's reward funders when an allowlist is set)
+    /// The cohort's RewardCohort account and vault are created by its first funding.
+    /// Only stakes opened during the cohort can draw on the funded rewards.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="reward_cohort", desc = "The cohort's RewardCohort PDA [\"reward_cohort\", pool, cohort] (created when empty)")]
+    #[account(2, writable, name="cohort_vault", desc = "The cohort's reward vault PDA [\"cohort_vault\", reward_cohort] (created when empty)")]
+    #[account(3, writable, signer, name="funder", desc = "The account funding rewards (pays rent when the cohort is created)")]
+    #[account(4, writable, name="funder_token_account", desc = "Funder's reward token account")]
+    #[account(5, name="reward_mint", desc = "The reward token mint")]
+    #[account(6, name="token_program", desc = "The token program")]
+    #[account(7, name="system_program", desc = "The system program")]
+    FundCohortRewards {
+        /// Cohort to fund (stake timestamp / the pool's cohort_duration)
+        cohort: u64,
+        amount: u64,
+    },
+
+    /// Withdraw reward tokens not owed to stakers from a cohort vault (global admin or treasurer)
+    /// Rewards the cohort's stakes forfeited stay reserved until the pool owes nothing.
+    /// Withdrawing the last tokens once the pool owes nothing closes the cohort and its vault.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="reward_cohort", desc = "The cohort's RewardCohort PDA")]
+    #[account(2, writable, name="cohort_vault", desc = "The cohort's reward vault")]
+    #[account(3, writable, name="destination", desc = "Token account receiving the surplus")]
+    #[account(4, name="reward_mint", desc = "The reward token mint")]
+    #[account(5, name="token_program", desc = "The token program")]
+    #[account(6, writable, signer, name="admin", desc = "The global admin or a treasurer (receives the rent of a closed cohort)")]
+    #[account(7, name="program_authority", desc = "The program authority account (validates admin permission)")]
+    DefundCohortRewards { amount: u64 },
+
+    /// Close out a stake account in one instruction: claim its claimable rewards, unstake
+    /// its whole principal and close it, returning its rent to the owner
+    /// Runs the checks and emits the events of ClaimRewards, Unstake and CloseStakeAccount,
+    /// and fails where any of them would. Rewards not claimable yet are forfeited by the
+    /// unstake as usual. Pools with an unstake cooldown use RequestUnstake instead.
+    #[account(0, writable, name="pool", desc = "The stake pool")]
+    #[account(1, writable, name="stake_account", desc = "The user's stake account (closed)")]
+    #[account(2, writable, signer, name="owner", desc = "The stake account owner (receives the stake account's rent)")]
+    #[account(3, writable, name="user_token_account", desc = "User's token account receiving the principal")]
+    #[account(4, writable, name="stake_vault", desc = "Pool's stake vault")]
+    #[account(5, name="stake_mint", desc = "The token mint being staked")]
+    #[account(6, name="token_program", desc = "The token program owning both mints")]
+    #[account(7, name="clock", desc = "Clock sysvar")]
+    #[account(8, writable, name="fee_vault", desc = "Pool's fee vault")]
+    #[account(9, writable, name="mint_registry", desc = "The stake mint's registry")]
+    #[account(10, writable, name="user_reward_account", desc = "Reward token account owned by the stake owner")]
+    #[account(11, writable, name="reward_vault", desc = "Pool's reward vault, or the stake's cohort vault in pools with reward cohorts")]
+    #[account(12, name="reward_mint", desc = "The reward token mint")]
+    #[account(13, name="program_authority", desc = "The program authority account (global pause switches)")]
+    #[account(14, optional, writable, name="receipt_mint", desc = "Pool's receipt mint (required when the pool tokenizes positions)")]
+    #[account(15, optional, writable, name="user_receipt_account", desc = "Owner's receipt token account to burn from (required when the pool tokenizes positions)")]
+    #[account(16, optional, name="receipt_token_program", desc = "The Token-2022 program (required when the pool tokenizes positions)")]
+    #[account(17, optional, writable, name="referrer_reward_account", desc = "Referrer's reward token account (required when the stake has a referrer and the pool pays referrals)")]
+    #[account(18, optional, writable, name="stake_position", desc = "The owner's stake position directory (None: left as is)")]
+    #[account(19, optional, name="memo_program", desc = "The SPL Memo program (required with a memo)")]
+    #[account(20, optional, name="deny_list", desc = "The global deny list PDA [\"deny_list\"] (required when the pool checks it)")]
+    #[account(21, optional, writable, name="reward_cohort", desc = "The stake's RewardCohort PDA [\"reward_cohort\", pool, cohort] (required for stakes of a reward cohort)")]
+    #[account(22, optional, name="hook_program", desc = "The pool's hook program (required when the pool has one); the hook's own accounts follow")]
+    UnstakeAll {
+        /// Frontrunning protection: expected reward rate (optional)
+        expected_reward_rate: Option<u64>,
+        /// Memo written via the SPL Memo program, signed by the owner (at most MAX_MEMO_LEN bytes)
+        memo: Option<String>,
+        /// Unix timestamp after which the instruction is rejected (None: no deadline)
+        deadline: Option<i64>,
+    },
+}
+
+/// Fail when the `field` argument holds more than `max` elements (or bytes)
+fn check_len(field: &str, len: usize, max: usize) -> ProgramResult {
+    if len > max {
+        msg!("{} too long: {} entries. Maximum: {}", field, len, max);
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+    Ok(())
+}
+
+fn check_proof_len(allowlist_proof: &Option<Vec<[u8; 32]>>) -> ProgramResult {
+    let len = allowlist_proof.as_ref().map_or(0, Vec::len);
+    check_len("allowlist_proof", len, MAX_MERKLE_PROOF_LEN)
+}
+
+fn check_memo_len(memo: &Option<String>) -> ProgramResult {
+    let len = memo.as_ref().map_or(0, String::len);
+    check_len("memo", len, MAX_MEMO_LEN)
+}
+
+impl StakePoolInstruction {
+    /// Reject variable-length arguments above the most any processor accepts
+    ///
+    /// Runs before dispatch, so oversized payloads fail before any account is
+    /// read. Processors still validate the contents (and lower bounds) themselves.
+    pub fn check_lengths(&self) -> ProgramResult {
+        match self {
+            Self::InitializePool { reward_tiers, .. } => check_len(
+                "reward_tiers",
+                reward_tiers.len(),
+                StakePool::MAX_REWARD_TIERS,
+            ),
+            Self::Stake {
+                allowlist_proof,
+                memo,
+                ..
+            } => {
+                check_proof_len(allowlist_proof)?;
+                check_memo_len(memo)
+            }
+            Self::Unstake { memo, .. }
+            | Self::ClaimRewards { memo, .. }
+            | Self::UnstakeAll { memo, .. } => check_memo_len(memo),
+            Self::StakeSol {
+                allowlist_proof, ..
+            } => check_proof_len(allowlist_proof),
+            Self::StakeBatch {
+                amounts,
+                allowlist_proof,
+                ..
+            } => {
+                check_len("amounts", amounts.len(), MAX_STAKE_BATCH)?;
+                check_proof_len(allowlist_proof)
+            }
+            Self::ManageAuthorizedCreators { add, remove } => {
+                let max = ProgramAuthority::MAX_CREATORS as usize;
+                check_len("add", add.len(), max)?;
+                check_len("remove", remove.len(), max)
+            }
+            Self::SetRewardFunders { funders } => {
+                check_len("funders", funders.len(), StakePool::MAX_REWARD_FUNDERS)
+            }
+            Self::ManagePoolAllowlist { add, remove, .. } => {
+                check_len("add", add.len(), PoolAllowlist::PAGE_CAPACITY)?;
+                check_len("remove", remove.len(), PoolAllowlist::PAGE_CAPACITY)
+            }
+            Self::ManageHookAllowlist { add, remove } => {
+                check_len("add", add.len(), HookAllowlist::CAPACITY)?;
+                check_len("remove", remove.len(), HookAllowlist::CAPACITY)
+            }
+            Self::ManageDenyList { add, remove, .. } => {
+                check_len("add", add.len(), DenyList::CAPACITY)?;
+                check_len("remove", remove.len(), DenyList::CAPACITY)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Variant name, used to tag the dispatcher's log lines
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::InitializePool { .. } => "InitializePool",
+            Self::Stake { .. } => "Stake",
+            Self::Unstake { .. } => "Unstake",
+            Self::ClaimRewards { .. } => "ClaimRewards",
+            Self::UpdatePool { .. } => "UpdatePool",
+            Self::FundRewards { .. } => "FundRewards",
+            Self::CloseStakeAccount => "CloseStakeAccount",
+            Self::FinalizeRewardRateChange => "FinalizeRewardRateChange",
+            Self::InitializeProgramAuthority { .. } => "InitializeProgramAuthority",
+            Self::ManageAuthorizedCreators { .. } => "ManageAuthorizedCreators",
+            Self::TransferProgramAuthority => "TransferProgramAuthority",
+            Self::AcceptProgramAuthority => "AcceptProgramAuthority",
+            Self::CancelAuthorityTransfer => "CancelAuthorityTransfer",
+            Self::GetAuthorizedCreators => "GetAuthorizedCreators",
+            Self::CheckAuthorization { .. } => "CheckAuthorization",
+            Self::WithdrawFees { .. } => "WithdrawFees",
+            Self::UpdatePoolsBatch { .. } => "UpdatePoolsBatch",
+            Self::StakeSol { .. } => "StakeSol",
+            Self::UnstakeSol { .. } => "UnstakeSol",
+            Self::SetAutoRelock { .. } => "SetAutoRelock",
+            Self::Relock => "Relock",
+            Self::SyncPool => "SyncPool",
+            Self::CloseProgramAuthority => "CloseProgramAuthority",
+            Self::SetRewardFunders { .. } => "SetRewardFunders",
+            Self::DefundRewards { .. } => "DefundRewards",
+            Self::SetMintTvlCap { .. } => "SetMintTvlCap",
+            Self::ClosePool => "ClosePool",
+            Self::SetStakeDelegate { .. } => "SetStakeDelegate",
+            Self::EnableReceiptToken { .. } => "EnableReceiptToken",
+            Self::ExpireRewards => "ExpireRewards",
+            Self::ClaimExpiredRewards => "ClaimExpiredRewards",
+            Self::RequestUnstake { .. } => "RequestUnstake",
+            Self::WithdrawUnstaked => "WithdrawUnstaked",
+            Self::SetGlobalPause { .. } => "SetGlobalPause",
+            Self::MigratePool => "MigratePool",
+            Self::StakeBatch { .. } => "StakeBatch",
+            Self::ClaimRewardsBatch => "ClaimRewardsBatch",
+            Self::GetPendingRewards => "GetPendingRewards",
+            Self::RegisterPool => "RegisterPool",
+            Self::SetCreatorPoolLimit { .. } => "SetCreatorPoolLimit",
+            Self::ManageRoles { .. } => "ManageRoles",
+            Self::SetApprovalThreshold { .. } => "SetApprovalThreshold",
+            Self::ProposePoolUpdate { .. } => "ProposePoolUpdate",
+            Self::ApprovePoolUpdate => "ApprovePoolUpdate",
+            Self::ExecutePoolUpdate => "ExecutePoolUpdate",
+            Self::FinalizeLockupChange => "FinalizeLockupChange",
+            Self::SetStakeLabel { .. } => "SetStakeLabel",
+            Self::NominateStakeOwner { .. } => "NominateStakeOwner",
+            Self::AcceptStakeOwnership { .. } => "AcceptStakeOwnership",
+            Self::SweepDust => "SweepDust",
+            Self::GetPoolStats => "GetPoolStats",
+            Self::ExtendLockup { .. } => "ExtendLockup",
+            Self::ManagePoolAllowlist { .. } => "ManagePoolAllowlist",
+            Self::VestingStake { .. } => "VestingStake",
+            Self::ProposeSlash { .. } => "ProposeSlash",
+            Self::ExecuteSlash => "ExecuteSlash",
+            Self::CancelSlash => "CancelSlash",
+            Self::SetRecoveryAuthority { .. } => "SetRecoveryAuthority",
+            Self::InitiateRecovery => "InitiateRecovery",
+            Self::CompleteRecovery => "CompleteRecovery",
+            Self::InitializeProgramConfig { .. } => "InitializeProgramConfig",
+            Self::UpdateProgramConfig { .. } => "UpdateProgramConfig",
+            Self::SetMaxCreators { .. } => "SetMaxCreators",
+            Self::MigrateProgramAuthority => "MigrateProgramAuthority",
+            Self::ClonePool { .. } => "ClonePool",
+            Self::CreateRewardStream { .. } => "CreateRewardStream",
+            Self::CrankRewardStream => "CrankRewardStream",
+            Self::CloseRewardStream => "CloseRewardStream",
+            Self::FundKeeperBounty { .. } => "FundKeeperBounty",
+            Self::ResizePool { .. } => "ResizePool",
+            Self::GetStakePosition => "GetStakePosition",
+            Self::AuditPool { .. } => "AuditPool",
+            Self::WindDownStake => "WindDownStake",
+            Self::ManageHookAllowlist { .. } => "ManageHookAllowlist",
+            Self::SetPoolHook { .. } => "SetPoolHook",
+            Self::SyncPoints => "SyncPoints",
+            Self::GetUserPoints => "GetUserPoints",
+            Self::ManageDenyList { .. } => "ManageDenyList",
+            Self::SplitStake { .. } => "SplitStake",
+            Self::MergeStakes => "MergeStakes",
+            Self::FundCohortRewards { .. } => "FundCohortRewards",
+            Self::DefundCohortRewards { .. } => "DefundCohortRewards",
+            Self::UnstakeAll { .. } => "UnstakeAll",
+        }
+    }
 }
@@ -1,16 +1,31 @@
 pub mod assertions;
 pub mod constants;
+#[cfg(feature = "no-entrypoint")]
+pub mod cpi;
 pub mod entrypoint;
 pub mod error;
+pub mod events;
+pub mod hook;
 pub mod instruction;
+pub mod merkle;
 pub mod processor;
+pub mod return_data;
 pub mod state;
 pub mod utils;
 
 pub use solana_program;
+pub use spl_associated_token_account_client;
+pub use spl_memo;
+pub use spl_token_2022;
 
 solana_program::declare_id!("8PtjrGvKNeZt2vCmRkSPGjss7TAFhvxux2N8r67UMKBx");
 
+/// The program IDL (Anchor format as written by shank, plus events), shipped with the crate
+///
+/// Generated by `pnpm generate:idls`; `program/tests/idl_tests.rs` keeps it in
+/// sync with the program source.
+pub const IDL_JSON: &str = include_str!("../idl.json");
+
 #[cfg(not(feature = "no-entrypoint"))]
 use solana_security_txt::security_txt;
 
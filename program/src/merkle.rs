@@ -0,0 +1,37 @@
+//! Merkle allowlists
+//!
+//! Pools with more stakers than allowlist pages comfortably hold store the root
+//! of a merkle tree of allowed owners instead (`StakePool::allowlist_merkle_root`).
+//! Stakers prove membership with the sibling hashes on the path from their leaf
+//! to the root, so proofs grow with the log of the list size.
+//!
+//! Hashes are SHA-256. A leaf is `hash(0x00 || owner)` and a parent node
+//! `hash(0x01 || min(a, b) || max(a, b))`. The prefixes keep an inner node from
+//! being passed off as a leaf, and sorting each pair lets proofs omit the side
+//! each sibling is on. Off-chain tree builders must follow the same rules.
+
+use solana_program::{hash::hashv, pubkey::Pubkey};
+
+/// Prefix of leaf hashes
+pub const LEAF_PREFIX: u8 = 0;
+/// Prefix of inner node hashes
+pub const NODE_PREFIX: u8 = 1;
+
+/// Leaf of `owner` in an allowlist tree
+pub fn leaf_hash(owner: &Pubkey) -> [u8; 32] {
+    hashv(&[&[LEAF_PREFIX], owner.as_ref()]).to_bytes()
+}
+
+/// Parent of two nodes, independent of their order
+pub fn node_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (low, high) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[&[NODE_PREFIX], low, high]).to_bytes()
+}
+
+/// Whether `proof` shows that `owner` is a leaf of the tree with `root`
+pub fn verify_proof(root: &[u8; 32], owner: &Pubkey, proof: &[[u8; 32]]) -> bool {
+    let computed = proof
+        .iter()
+        .fold(leaf_hash(owner), |node, sibling| node_hash(&node, sibling));
+    &computed == root
+}
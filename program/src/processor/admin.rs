@@ -9,23 +9,45 @@
 //! - A single `ProgramAuthority` account controls who can create and manage pools
 //! - No per-pool authorities - all pools are managed by authorized global admins
 //! - Authority can be transferred via a two-step process (nominate + accept)
+//! - Scoped roles (pauser, rate admin, treasurer) grant single operational
+//!   permissions without full global admin rights
 
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
-    log::sol_log_data,
     msg,
+    program_error::ProgramError,
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
 
 use crate::assertions::*;
-use crate::constants::MAX_REWARD_RATE;
+use crate::constants::{
+    BPS_DENOMINATOR, MAX_FEE_BPS, MAX_KEEPER_BOUNTY, MAX_REFERRAL_BPS, MAX_REWARD_RATE,
+    MAX_UNSTAKE_COOLDOWN,
+};
 use crate::error::StakePoolError;
+use crate::events::{
+    AuthorityChangeEvent, AuthorizedCreatorEvent, CreatorPoolLimitUpdatedEvent,
+    DenyListUpdatedEvent, GlobalPauseUpdatedEvent, HookAllowlistUpdatedEvent,
+    LockupChangeFinalizedEvent, LockupChangeProposedEvent, MaxCreatorsUpdatedEvent,
+    MintTvlCapUpdatedEvent, PoolAdminEvent, PoolAllowlistUpdatedEvent, PoolBatchUpdateFailedEvent,
+    PoolParameter, PoolUpdatedEvent, ProgramAuthorityClosedEvent, ProgramAuthorityInitializedEvent,
+    RewardFundersUpdatedEvent, RewardRateFinalizedEvent, RewardRateProposedEvent,
+    RolesUpdatedEvent, StakePoolEvent,
+};
 use crate::instruction::accounts::*;
-use crate::processor::helpers::{validate_current_timestamp, validate_stored_timestamp};
-use crate::state::{Key, ProgramAuthority, StakePool};
-use crate::utils::create_account;
+use crate::processor::helpers::{
+    emit_keeper_bounty_paid, load_hook_allowlist, load_or_create_deny_list,
+    load_or_create_hook_allowlist, load_or_create_mint_registry, load_or_create_pool_allowlist,
+    load_program_config, pay_keeper_bounty, validate_current_timestamp, validate_stored_timestamp,
+};
+use crate::return_data::{self, AuthorizationCheck, AuthorizedCreators};
+use crate::state::{
+    ClaimWindow, ClusterProfile, Key, OutflowLimit, ProgramAuthority, ProgramConfig, Role,
+    StakePool, WindDownRewards,
+};
+use crate::utils::{close_account, create_account, realloc_account};
 
 //
 // ============================================================================
@@ -36,22 +58,29 @@ use crate::utils::create_account;
 /// Initialize the program authority account (one-time setup)
 ///
 /// This creates a global ProgramAuthority account that controls who can create stake pools.
-/// Should only be called once during program deployment.
+/// Calling it again with the same authority and cluster profile is a no-op.
 ///
 /// # Security
 /// - Only the initial authority can manage the authorized creators list
 /// - The ProgramAuthority PDA is deterministic (derived from "program_authority" seed)
-/// - Cannot be reinitialized once created
+/// - Cannot be reinitialized once created: a repeated call must match the existing
+///   authority and cluster profile, and changes nothing
 ///
 /// # Arguments
 /// * `accounts` - Required accounts for program authority initialization
+/// * `cluster_profile` - Cluster the deployment serves (Devnet enables dev-only
+///   instructions and shorter timelocks)
 ///
 /// # Errors
 /// Returns error if:
-/// - Program authority account already exists
+/// - Program authority account already exists with another authority or cluster profile
+/// - Program authority account uses an older layout (upgrade it with MigrateProgramAuthority)
 /// - Account creation fails
 /// - Signer validation fails
-pub fn initialize_program_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+pub fn initialize_program_authority<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    cluster_profile: ClusterProfile,
+) -> ProgramResult {
     let ctx = InitializeProgramAuthorityAccounts::context(accounts)?;
 
     // Derive the expected program authority PDA
@@ -71,7 +100,30 @@ pub fn initialize_program_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> Prog
     )?;
     assert_signer("initial_authority", ctx.accounts.initial_authority)?;
     assert_signer("payer", ctx.accounts.payer)?;
-    assert_empty("program_authority", ctx.accounts.program_authority)?;
+
+    // Repeating the setup succeeds without changes. Older layouts fail with
+    // AccountNeedsMigration: MigrateProgramAuthority upgrades them in place.
+    if !ctx.accounts.program_authority.data_is_empty() {
+        assert_account_key(
+            "program_authority",
+            ctx.accounts.program_authority,
+            Key::ProgramAuthorityV2,
+        )?;
+        assert_program_owner(
+            "program_authority",
+            ctx.accounts.program_authority,
+            &crate::ID,
+        )?;
+        let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+        program_authority
+            .check_reinitialization(ctx.accounts.initial_authority.key, cluster_profile)?;
+        msg!(
+            "Program authority already initialized with authority: {} ({:?} profile)",
+            program_authority.authority,
+            program_authority.cluster_profile
+        );
+        return Ok(());
+    }
     assert_writable("program_authority", ctx.accounts.program_authority)?;
     assert_writable("payer", ctx.accounts.payer)?;
 
@@ -91,28 +143,36 @@ pub fn initialize_program_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> Prog
 
     // Initialize program authority data
     let program_authority_data = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority: *ctx.accounts.initial_authority.key,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
         pending_authority: None,
         bump,
+        cluster_profile,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
+        authorized_creators: Vec::new(),
     };
 
     program_authority_data.save(ctx.accounts.program_authority)?;
 
     msg!(
-        "Program authority initialized with authority: {}",
-        ctx.accounts.initial_authority.key
+        "Program authority initialized with authority: {} ({:?} profile)",
+        ctx.accounts.initial_authority.key,
+        cluster_profile
     );
 
     // Log event for off-chain indexing
-    sol_log_data(&[
-        b"ProgramAuthorityInitialized",
-        ctx.accounts.initial_authority.key.as_ref(),
-    ]);
-
-    Ok(())
+    StakePoolEvent::ProgramAuthorityInitialized(ProgramAuthorityInitializedEvent {
+        authority: *ctx.accounts.initial_authority.key,
+        cluster_profile,
+    })
+    .emit()
 }
 
 /// Manage authorized pool creators (add or remove)
@@ -123,7 +183,8 @@ pub fn initialize_program_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> Prog
 /// # Security
 /// - Only the main authority can manage the list
 /// - Cannot remove the main authority itself
-/// - Maximum of 10 authorized creators
+/// - At most `max_creators` authorized creators (see `set_max_creators`)
+/// - Grows the program authority when the list outgrows it (payer required)
 /// - Validates all operations before applying changes
 ///
 /// # Arguments
@@ -135,6 +196,7 @@ pub fn initialize_program_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> Prog
 /// Returns error if:
 /// - Caller is not the program authority
 /// - Maximum creators limit reached
+/// - The list outgrows the account and no payer was provided
 /// - Creator already exists (when adding)
 /// - Creator not found (when removing)
 /// - Attempting to remove main authority
@@ -146,7 +208,7 @@ pub fn manage_authorized_creators<'a>(
     let ctx = ManageAuthorizedCreatorsAccounts::context(accounts)?;
 
     // DoS Protection: Limit vector sizes to prevent excessive computation
-    if add.len() > ProgramAuthority::MAX_CREATORS {
+    if add.len() > ProgramAuthority::MAX_CREATORS as usize {
         msg!(
             "Too many creators to add: {}. Maximum: {}",
             add.len(),
@@ -154,7 +216,7 @@ pub fn manage_authorized_creators<'a>(
         );
         return Err(StakePoolError::InvalidParameters.into());
     }
-    if remove.len() > ProgramAuthority::MAX_CREATORS {
+    if remove.len() > ProgramAuthority::MAX_CREATORS as usize {
         msg!(
             "Too many creators to remove: {}. Maximum: {}",
             remove.len(),
@@ -164,6 +226,16 @@ pub fn manage_authorized_creators<'a>(
     }
 
     // Load and validate program authority
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    assert_program_owner(
+        "program_authority",
+        ctx.accounts.program_authority,
+        &crate::ID,
+    )?;
     let mut program_authority_data = ProgramAuthority::load(ctx.accounts.program_authority)?;
 
     // Guards
@@ -185,11 +257,11 @@ pub fn manage_authorized_creators<'a>(
         msg!("Removed authorized creator: {}", creator);
 
         // Log event for off-chain indexing
-        sol_log_data(&[
-            b"AuthorizedCreatorRemoved",
-            creator.as_ref(),
-            ctx.accounts.authority.key.as_ref(),
-        ]);
+        StakePoolEvent::AuthorizedCreatorRemoved(AuthorizedCreatorEvent {
+            creator: *creator,
+            authority: *ctx.accounts.authority.key,
+        })
+        .emit()?;
     }
 
     // Add new creators
@@ -198,11 +270,30 @@ pub fn manage_authorized_creators<'a>(
         msg!("Added authorized creator: {}", creator);
 
         // Log event for off-chain indexing
-        sol_log_data(&[
-            b"AuthorizedCreatorAdded",
-            creator.as_ref(),
-            ctx.accounts.authority.key.as_ref(),
-        ]);
+        StakePoolEvent::AuthorizedCreatorAdded(AuthorizedCreatorEvent {
+            creator: *creator,
+            authority: *ctx.accounts.authority.key,
+        })
+        .emit()?;
+    }
+
+    // Grow the account when the creator list no longer fits
+    let required_len = program_authority_data.required_len();
+    if required_len > ctx.accounts.program_authority.data_len() {
+        let (Some(payer), Some(system_program)) = (ctx.accounts.payer, ctx.accounts.system_program)
+        else {
+            msg!("A payer and the system program are required to grow the program authority");
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        assert_signer("payer", payer)?;
+        assert_writable("payer", payer)?;
+        realloc_account(
+            ctx.accounts.program_authority,
+            payer,
+            system_program,
+            required_len,
+            false,
+        )?;
     }
 
     // Save updated state
@@ -210,38 +301,210 @@ pub fn manage_authorized_creators<'a>(
 
     msg!(
         "Authorized creators updated. Current count: {}",
-        program_authority_data.creator_count
+        program_authority_data.creator_count()
     );
 
     Ok(())
 }
 
-//
-// ============================================================================
-// POOL MANAGEMENT
-// ============================================================================
-//
+/// Set the maximum number of pools an authorized creator may create
+///
+/// Bounds the damage a compromised creator key can do: once the creator has
+/// created `pool_limit` pools, `initialize_pool` rejects further ones with
+/// `CreatorPoolLimitReached`.
+///
+/// # Arguments
+/// * `creator` - The authorized creator to limit
+/// * `pool_limit` - Maximum pools the creator may create (None = unlimited)
+///
+/// # Errors
+/// Returns error if:
+/// - Caller is not the program authority
+/// - Creator is not in the authorized creators list
+/// - Creator is the main authority (which is never limited)
+pub fn set_creator_pool_limit<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    creator: Pubkey,
+    pool_limit: Option<u16>,
+) -> ProgramResult {
+    let ctx = SetCreatorPoolLimitAccounts::context(accounts)?;
+
+    // Load and validate program authority
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    assert_program_owner(
+        "program_authority",
+        ctx.accounts.program_authority,
+        &crate::ID,
+    )?;
+    let mut program_authority_data = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("authority", ctx.accounts.authority)?;
+    assert_writable("program_authority", ctx.accounts.program_authority)?;
+
+    // Verify the signer is the program authority
+    if ctx.accounts.authority.key != &program_authority_data.authority {
+        msg!(
+            "Unauthorized: {} is not the program authority",
+            ctx.accounts.authority.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    let pools_created = program_authority_data.set_creator_pool_limit(&creator, pool_limit)?;
 
-/// Time delay before a reward rate change can be finalized (7 days = 604800 seconds).
+    msg!(
+        "Pool limit of creator {} set to {:?} ({} pools created)",
+        creator,
+        pool_limit,
+        pools_created
+    );
+
+    // Save state first to ensure persistence before emitting event
+    program_authority_data.save(ctx.accounts.program_authority)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::CreatorPoolLimitUpdated(CreatorPoolLimitUpdatedEvent {
+        creator,
+        authority: *ctx.accounts.authority.key,
+        pool_limit,
+        pools_created,
+    })
+    .emit()
+}
+
+/// Set how many authorized creators the program authority may hold
+///
+/// Raising the cap does not allocate space: ManageAuthorizedCreators grows the
+/// account when the list outgrows it.
 ///
-/// **Security [L-01]:**
-/// Provides users notice to unstake if they disagree with new rate.
-/// Prevents centralized surprise changes to reward rates.
+/// # Errors
+/// Returns error if:
+/// - Caller is not the program authority
+/// - The cap is below the current creator count or above `ProgramAuthority::MAX_CREATORS`
+pub fn set_max_creators<'a>(accounts: &'a [AccountInfo<'a>], max_creators: u16) -> ProgramResult {
+    let ctx = SetMaxCreatorsAccounts::context(accounts)?;
+
+    // Load and validate program authority
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    assert_program_owner(
+        "program_authority",
+        ctx.accounts.program_authority,
+        &crate::ID,
+    )?;
+    let mut program_authority_data = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("authority", ctx.accounts.authority)?;
+    assert_writable("program_authority", ctx.accounts.program_authority)?;
+
+    // Verify the signer is the program authority
+    if ctx.accounts.authority.key != &program_authority_data.authority {
+        msg!(
+            "Unauthorized: {} is not the program authority",
+            ctx.accounts.authority.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    program_authority_data.set_max_creators(max_creators)?;
+
+    msg!(
+        "Max authorized creators set to {} ({} authorized)",
+        max_creators,
+        program_authority_data.creator_count()
+    );
+
+    // Save state first to ensure persistence before emitting event
+    program_authority_data.save(ctx.accounts.program_authority)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::MaxCreatorsUpdated(MaxCreatorsUpdatedEvent {
+        authority: *ctx.accounts.authority.key,
+        max_creators,
+    })
+    .emit()
+}
+
+/// Grant or revoke scoped roles
 ///
-/// **Design Rationale:**
-/// - 7 days balances user protection vs operational flexibility
-/// - Industry standard for time-locked governance operations
-/// - Sufficient time for users to monitor and react to changes
-/// - Aligns with common DeFi governance timelock periods
+/// Roles let operational keys act without full global admin rights: a pauser
+/// can only pause, a rate admin can only propose reward rates and a treasurer
+/// can only move surplus funds.
 ///
-/// **Cooldown Enforcement:**
-/// After finalization, another 7-day cooldown is enforced before
-/// proposing a new rate change (prevents authority from chaining
-/// rapid rate changes to bypass the time-lock).
+/// # Arguments
+/// * `holder` - The address whose roles are set
+/// * `roles` - Bit mask of roles to hold (see `Role::mask`); 0 revokes every role
 ///
-/// **Current Value**: 604800 seconds (7 days)
-const REWARD_RATE_CHANGE_DELAY: i64 = 604800;
+/// # Errors
+/// Returns error if:
+/// - Caller is not the program authority
+/// - The mask has unknown role bits, or the holder is the main authority
+/// - Every role holder slot is taken
+pub fn manage_roles<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    holder: Pubkey,
+    roles: u8,
+) -> ProgramResult {
+    let ctx = ManageRolesAccounts::context(accounts)?;
+
+    // Load and validate program authority
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    assert_program_owner(
+        "program_authority",
+        ctx.accounts.program_authority,
+        &crate::ID,
+    )?;
+    let mut program_authority_data = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("authority", ctx.accounts.authority)?;
+    assert_writable("program_authority", ctx.accounts.program_authority)?;
+
+    // Verify the signer is the program authority
+    if ctx.accounts.authority.key != &program_authority_data.authority {
+        msg!(
+            "Unauthorized: {} is not the program authority",
+            ctx.accounts.authority.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
 
+    program_authority_data.set_roles(holder, roles)?;
+
+    msg!("Roles of {} set to {:#05b}", holder, roles);
+
+    // Save state first to ensure persistence before emitting event
+    program_authority_data.save(ctx.accounts.program_authority)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::RolesUpdated(RolesUpdatedEvent {
+        holder,
+        authority: *ctx.accounts.authority.key,
+        roles,
+    })
+    .emit()
+}
+
+//
+// ============================================================================
+// POOL MANAGEMENT
+// ============================================================================
+//
+
+#[allow(clippy::too_many_arguments)]
 pub fn update_pool<'a>(
     accounts: &'a [AccountInfo<'a>],
     reward_rate: Option<u64>,
@@ -250,12 +513,32 @@ pub fn update_pool<'a>(
     is_paused: Option<bool>,
     enforce_lockup: Option<bool>,
     pool_end_date: Option<Option<i64>>,
+    referral_bps: Option<u16>,
+    early_unstake_bps: Option<u16>,
+    deposit_fee_bps: Option<u16>,
+    withdraw_fee_bps: Option<u16>,
+    unstake_cooldown: Option<i64>,
+    bonus_end_ts: Option<Option<i64>>,
+    bonus_multiplier_bps: Option<u16>,
+    lockup_extension_rate: Option<u64>,
+    allowlist_merkle_root: Option<Option<[u8; 32]>>,
+    claim_window: Option<Option<ClaimWindow>>,
+    outflow_limit: Option<Option<OutflowLimit>>,
+    pool_manager: Option<Option<Pubkey>>,
+    keeper_bounty: Option<u64>,
+    max_total_rewards: Option<Option<u64>>,
+    enforce_min_remaining_stake: Option<bool>,
+    wind_down_rewards: Option<WindDownRewards>,
+    points_rate: Option<u64>,
+    deny_list_required: Option<bool>,
+    allow_third_party_stake: Option<bool>,
+    cohort_duration: Option<i64>,
 ) -> ProgramResult {
     // Parse accounts using ShankContext-generated struct
     let ctx = UpdatePoolAccounts::context(accounts)?;
 
     // Verify pool account discriminator before loading (Type Cosplay protection)
-    assert_account_key("pool", ctx.accounts.pool, Key::StakePool)?;
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
 
     // Verify program ownership
     assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
@@ -267,34 +550,310 @@ pub fn update_pool<'a>(
     assert_account_key(
         "program_authority",
         ctx.accounts.program_authority,
-        Key::ProgramAuthority,
+        Key::ProgramAuthorityV2,
     )?;
     let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+    let config = load_program_config(ctx.accounts.program_config)?;
 
     // Guards
     assert_signer("admin", ctx.accounts.admin)?;
     assert_writable("pool", ctx.accounts.pool)?;
 
-    // Verify the signer is authorized as a global admin
-    if !program_authority.is_authorized(ctx.accounts.admin.key) {
-        msg!(
-            "Unauthorized: {} is not a global admin",
-            ctx.accounts.admin.key
-        );
-        return Err(StakePoolError::Unauthorized.into());
+    let update = PoolUpdate {
+        reward_rate,
+        min_stake_amount,
+        lockup_period,
+        is_paused,
+        enforce_lockup,
+        pool_end_date,
+        referral_bps,
+        early_unstake_bps,
+        deposit_fee_bps,
+        withdraw_fee_bps,
+        unstake_cooldown,
+        bonus_end_ts,
+        bonus_multiplier_bps,
+        lockup_extension_rate,
+        allowlist_merkle_root,
+        claim_window,
+        outflow_limit,
+        pool_manager,
+        keeper_bounty,
+        max_total_rewards,
+        enforce_min_remaining_stake,
+        wind_down_rewards,
+        points_rate,
+        deny_list_required,
+        allow_third_party_stake,
+        cohort_duration,
+    };
+
+    // Verify the signer is a global admin, the pool's manager or holds the roles the update needs
+    authorize_pool_update(
+        &program_authority,
+        ctx.accounts.admin.key,
+        &update,
+        pool_data.pool_manager.as_ref(),
+    )?;
+
+    // Get current time once for efficiency (Clock is a sysvar that shouldn't change during transaction)
+    let current_time = Clock::get()?.unix_timestamp;
+    validate_current_timestamp(current_time)?;
+
+    apply_pool_update(
+        ctx.accounts.pool,
+        ctx.accounts.admin,
+        &mut pool_data,
+        &update,
+        current_time,
+        program_authority.cluster_profile,
+        &config,
+    )?;
+
+    pool_data.save(ctx.accounts.pool)
+}
+
+/// Apply the same parameter changes to every pool passed as a remaining account
+///
+/// Intended for incident response (e.g. pausing every pool at once). Each pool is
+/// validated, updated and saved independently; a pool that fails is skipped and
+/// reported with a `PoolBatchUpdateFailed` event carrying the error code, so one
+/// misconfigured pool cannot block the rest of the batch. Parameter events logged
+/// for a pool before its failure are superseded by that failure event.
+pub fn update_pools_batch<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    update: PoolUpdate,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = UpdatePoolsBatchAccounts::context(accounts)?;
+
+    // Load program authority to verify admin permissions
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    assert_program_owner(
+        "program_authority",
+        ctx.accounts.program_authority,
+        &crate::ID,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+    let config = load_program_config(ctx.accounts.program_config)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+
+    // Verify the signer is a global admin or holds the roles the update needs
+    // (pool managers only manage their own pool through UpdatePool)
+    authorize_pool_update(&program_authority, ctx.accounts.admin.key, &update, None)?;
+
+    if ctx.remaining_accounts.is_empty() {
+        msg!("No pools provided. Pass pools as remaining accounts.");
+        return Err(StakePoolError::InvalidParameters.into());
     }
 
     // Get current time once for efficiency (Clock is a sysvar that shouldn't change during transaction)
     let current_time = Clock::get()?.unix_timestamp;
     validate_current_timestamp(current_time)?;
 
-    // Update fields
-    if let Some(rate) = reward_rate {
-        if rate > MAX_REWARD_RATE {
+    let mut updated: u32 = 0;
+    for pool in ctx.remaining_accounts {
+        let result = assert_account_key("pool", pool, Key::StakePoolV2)
+            .and_then(|_| assert_program_owner("pool", pool, &crate::ID))
+            .and_then(|_| assert_writable("pool", pool))
+            .and_then(|_| StakePool::load(pool))
+            .and_then(|mut pool_data| {
+                apply_pool_update(
+                    pool,
+                    ctx.accounts.admin,
+                    &mut pool_data,
+                    &update,
+                    current_time,
+                    program_authority.cluster_profile,
+                    &config,
+                )?;
+                pool_data.save(pool)
+            });
+
+        match result {
+            Ok(()) => {
+                updated = updated.saturating_add(1);
+                StakePoolEvent::PoolBatchUpdated(PoolAdminEvent {
+                    pool: *pool.key,
+                    admin: *ctx.accounts.admin.key,
+                })
+                .emit()?;
+            }
+            Err(error) => {
+                msg!("Pool {} skipped: {}", pool.key, error);
+                StakePoolEvent::PoolBatchUpdateFailed(PoolBatchUpdateFailedEvent {
+                    pool: *pool.key,
+                    admin: *ctx.accounts.admin.key,
+                    error: u64::from(error),
+                })
+                .emit()?;
+            }
+        }
+    }
+
+    msg!(
+        "Batch update complete: {} of {} pools updated",
+        updated,
+        ctx.remaining_accounts.len()
+    );
+
+    Ok(())
+}
+
+/// Parameter changes shared by `update_pool` and `update_pools_batch`
+/// (None leaves the parameter unchanged)
+#[derive(Default)]
+pub struct PoolUpdate {
+    pub reward_rate: Option<u64>,
+    pub min_stake_amount: Option<u64>,
+    pub lockup_period: Option<i64>,
+    pub is_paused: Option<bool>,
+    pub enforce_lockup: Option<bool>,
+    pub pool_end_date: Option<Option<i64>>,
+    pub referral_bps: Option<u16>,
+    pub early_unstake_bps: Option<u16>,
+    pub deposit_fee_bps: Option<u16>,
+    pub withdraw_fee_bps: Option<u16>,
+    pub unstake_cooldown: Option<i64>,
+    pub bonus_end_ts: Option<Option<i64>>,
+    pub bonus_multiplier_bps: Option<u16>,
+    pub lockup_extension_rate: Option<u64>,
+    pub allowlist_merkle_root: Option<Option<[u8; 32]>>,
+    pub claim_window: Option<Option<ClaimWindow>>,
+    pub outflow_limit: Option<Option<OutflowLimit>>,
+    pub pool_manager: Option<Option<Pubkey>>,
+    pub keeper_bounty: Option<u64>,
+    pub max_total_rewards: Option<Option<u64>>,
+    pub enforce_min_remaining_stake: Option<bool>,
+    pub wind_down_rewards: Option<WindDownRewards>,
+    pub points_rate: Option<u64>,
+    pub deny_list_required: Option<bool>,
+    pub allow_third_party_stake: Option<bool>,
+    pub cohort_duration: Option<i64>,
+}
+
+impl PoolUpdate {
+    /// Whether the update changes a parameter subject to multi-admin approval
+    pub fn is_sensitive(&self) -> bool {
+        self.reward_rate.is_some() || self.is_paused.is_some() || self.pool_end_date.is_some()
+    }
+
+    /// Whether a pool manager may apply the update: it only pauses or unpauses the pool
+    pub fn is_manager_update(&self) -> bool {
+        self.required_roles() == Some(Role::Pauser.mask())
+    }
+
+    /// Roles that together cover every parameter the update changes
+    ///
+    /// None when the update changes a parameter reserved to global admins.
+    pub fn required_roles(&self) -> Option<u8> {
+        let admin_only = self.min_stake_amount.is_some()
+            || self.lockup_period.is_some()
+            || self.enforce_lockup.is_some()
+            || self.pool_end_date.is_some()
+            || self.referral_bps.is_some()
+            || self.early_unstake_bps.is_some()
+            || self.deposit_fee_bps.is_some()
+            || self.withdraw_fee_bps.is_some()
+            || self.unstake_cooldown.is_some()
+            || self.bonus_end_ts.is_some()
+            || self.bonus_multiplier_bps.is_some()
+            || self.lockup_extension_rate.is_some()
+            || self.allowlist_merkle_root.is_some()
+            || self.claim_window.is_some()
+            || self.outflow_limit.is_some()
+            || self.pool_manager.is_some()
+            || self.keeper_bounty.is_some()
+            || self.max_total_rewards.is_some()
+            || self.enforce_min_remaining_stake.is_some()
+            || self.wind_down_rewards.is_some()
+            || self.points_rate.is_some()
+            || self.deny_list_required.is_some()
+            || self.allow_third_party_stake.is_some()
+            || self.cohort_duration.is_some();
+        if admin_only {
+            return None;
+        }
+
+        let mut roles = 0;
+        if self.is_paused.is_some() {
+            roles |= Role::Pauser.mask();
+        }
+        if self.reward_rate.is_some() {
+            roles |= Role::RateAdmin.mask();
+        }
+        Some(roles)
+    }
+}
+
+/// Verify `admin` may apply `update`: a global admin, a holder of every role it needs,
+/// or the pool's `pool_manager` when the update only pauses or unpauses the pool
+///
+/// Once an approval threshold is set, sensitive changes are only applied by
+/// ExecutePoolUpdate.
+fn authorize_pool_update(
+    program_authority: &ProgramAuthority,
+    admin: &Pubkey,
+    update: &PoolUpdate,
+    pool_manager: Option<&Pubkey>,
+) -> ProgramResult {
+    if program_authority.requires_approval() && update.is_sensitive() {
+        msg!(
+            "Reward rate, pause and end date changes need {} admin approvals; use ProposePoolUpdate",
+            program_authority.approval_threshold
+        );
+        return Err(StakePoolError::ApprovalRequired.into());
+    }
+
+    if program_authority.is_authorized(admin) {
+        return Ok(());
+    }
+
+    match update.required_roles() {
+        Some(roles) if roles != 0 && program_authority.has_roles(admin, roles) => Ok(()),
+        _ if pool_manager == Some(admin) && update.is_manager_update() => Ok(()),
+        _ => {
+            msg!(
+                "Unauthorized: {} is not a global admin or pool manager, or lacks a role this update needs",
+                admin
+            );
+            Err(StakePoolError::Unauthorized.into())
+        }
+    }
+}
+
+/// Validate and apply `update` to an already loaded and authorized pool
+///
+/// Emits a parameter event per changed field. The caller is responsible for
+/// saving `pool_data` once this returns successfully. `cluster_profile` supplies
+/// the reward rate time-lock of pools without their own (see `StakePool::rate_change_delay`)
+/// and `config` the highest reward rate.
+pub(super) fn apply_pool_update(
+    pool: &AccountInfo,
+    admin: &AccountInfo,
+    pool_data: &mut StakePool,
+    update: &PoolUpdate,
+    current_time: i64,
+    cluster_profile: ClusterProfile,
+    config: &ProgramConfig,
+) -> ProgramResult {
+    let rate_change_delay = pool_data.rate_change_delay(cluster_profile);
+
+    if let Some(rate) = update.reward_rate {
+        if rate > config.max_reward_rate {
             msg!("Reward rate too high: {}", rate);
             return Err(StakePoolError::InvalidParameters.into());
         }
 
+        // Stakers were promised this envelope when the pool was created
+        pool_data.validate_reward_rate_bounds(rate)?;
+
         // Special case: If proposing the current active rate, cancel any pending change
         // This allows authority to revert/cancel unwanted proposals
         // Note: pending_reward_rate should never equal current_rate immediately after proposing
@@ -310,11 +869,11 @@ pub fn update_pool<'a>(
                 );
 
                 // Emit event for off-chain indexing
-                sol_log_data(&[
-                    b"RewardRateProposalCancelled",
-                    ctx.accounts.pool.key.as_ref(),
-                    ctx.accounts.admin.key.as_ref(),
-                ]);
+                StakePoolEvent::RewardRateProposalCancelled(PoolAdminEvent {
+                    pool: *pool.key,
+                    admin: *admin.key,
+                })
+                .emit()?;
             } else {
                 msg!(
                     "Reward rate unchanged: {}. No pending change to cancel.",
@@ -342,8 +901,8 @@ pub fn update_pool<'a>(
                     .checked_sub(last_change)
                     .ok_or(StakePoolError::NumericalOverflow)?;
 
-                if time_since_last_change < REWARD_RATE_CHANGE_DELAY {
-                    let remaining = REWARD_RATE_CHANGE_DELAY
+                if time_since_last_change < rate_change_delay {
+                    let remaining = rate_change_delay
                         .checked_sub(time_since_last_change)
                         .unwrap_or(0);
                     msg!(
@@ -356,7 +915,7 @@ pub fn update_pool<'a>(
 
             // Validate timestamp arithmetic before modifying state
             let finalization_time = current_time
-                .checked_add(REWARD_RATE_CHANGE_DELAY)
+                .checked_add(rate_change_delay)
                 .ok_or_else(|| {
                     msg!("Error: Reward rate change finalization time overflowed. Invalid timestamp.");
                     StakePoolError::InvalidParameters
@@ -368,83 +927,89 @@ pub fn update_pool<'a>(
             pool_data.reward_rate_change_timestamp = Some(current_time);
 
             msg!(
-                "Reward rate change proposed: {} -> {}. Will take effect after {} (7 days from now)",
+                "Reward rate change proposed: {} -> {}. Will take effect after {} ({} seconds from now)",
                 pool_data.reward_rate,
                 rate,
-                finalization_time
+                finalization_time,
+                rate_change_delay
             );
 
             // Emit event for off-chain indexing
-            sol_log_data(&[
-                b"RewardRateProposed",
-                ctx.accounts.pool.key.as_ref(),
-                ctx.accounts.admin.key.as_ref(),
-                &pool_data.reward_rate.to_le_bytes(),
-                &rate.to_le_bytes(),
-            ]);
+            StakePoolEvent::RewardRateProposed(RewardRateProposedEvent {
+                pool: *pool.key,
+                admin: *admin.key,
+                current_rate: pool_data.reward_rate,
+                proposed_rate: rate,
+            })
+            .emit()?;
         }
     }
-    if let Some(min_amount) = min_stake_amount {
+    if let Some(min_amount) = update.min_stake_amount {
         pool_data.min_stake_amount = min_amount;
         msg!("Min stake amount updated to: {}", min_amount);
 
         // Emit event
-        sol_log_data(&[
-            b"PoolParameterUpdated",
-            ctx.accounts.pool.key.as_ref(),
-            ctx.accounts.admin.key.as_ref(),
-            b"min_stake_amount",
-            &min_amount.to_le_bytes(),
-        ]);
-    }
-    if let Some(lockup) = lockup_period {
-        if lockup < 0 {
-            msg!("Lockup period cannot be negative: {}", lockup);
-            return Err(StakePoolError::InvalidParameters.into());
-        }
-        pool_data.lockup_period = lockup;
-        msg!("Lockup period updated to: {}", lockup);
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::MinStakeAmount(min_amount),
+        )?;
+    }
+    if update.lockup_period.is_some() || update.enforce_lockup.is_some() {
+        // Lockup changes affect existing stakes, so like reward rate changes they
+        // only take effect after the pool's delay (see finalize_lockup_change)
+        let had_pending_change = pool_data.has_pending_lockup_change();
+        if pool_data.propose_lockup_change(
+            update.lockup_period,
+            update.enforce_lockup,
+            current_time,
+        )? {
+            msg!(
+                "Lockup change proposed: lockup_period={:?}, enforce_lockup={:?}. Will take effect after {} seconds",
+                pool_data.pending_lockup_period,
+                pool_data.pending_enforce_lockup,
+                rate_change_delay
+            );
 
-        // Emit event
-        sol_log_data(&[
-            b"PoolParameterUpdated",
-            ctx.accounts.pool.key.as_ref(),
-            ctx.accounts.admin.key.as_ref(),
-            b"lockup_period",
-            &lockup.to_le_bytes(),
-        ]);
-    }
-    if let Some(paused) = is_paused {
+            // Emit event for off-chain indexing
+            StakePoolEvent::LockupChangeProposed(LockupChangeProposedEvent {
+                pool: *pool.key,
+                admin: *admin.key,
+                current_lockup_period: pool_data.lockup_period,
+                proposed_lockup_period: pool_data.pending_lockup_period,
+                current_enforce_lockup: pool_data.enforce_lockup,
+                proposed_enforce_lockup: pool_data.pending_enforce_lockup,
+            })
+            .emit()?;
+        } else if had_pending_change {
+            msg!("Pending lockup change cancelled. Keeping current lockup settings");
+
+            // Emit event for off-chain indexing
+            StakePoolEvent::LockupChangeCancelled(PoolAdminEvent {
+                pool: *pool.key,
+                admin: *admin.key,
+            })
+            .emit()?;
+        } else {
+            msg!("Lockup settings unchanged. No pending change to cancel.");
+        }
+    }
+    if let Some(paused) = update.is_paused {
         let status_change = if paused { "PAUSED" } else { "UNPAUSED" };
-        msg!("Pool {} {}", ctx.accounts.pool.key, status_change);
+        msg!("Pool {} {}", pool.key, status_change);
 
         // Emit event for off-chain indexing
-        sol_log_data(&[
-            if paused {
-                b"PoolPaused"
-            } else {
-                b"PoolUnpaused"
-            },
-            ctx.accounts.pool.key.as_ref(),
-            ctx.accounts.admin.key.as_ref(),
-        ]);
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::Paused(paused),
+        )?;
 
         pool_data.is_paused = paused;
     }
-    if let Some(enforce) = enforce_lockup {
-        pool_data.enforce_lockup = enforce;
-        msg!("Enforce lockup updated to: {}", enforce);
-
-        // Emit event
-        sol_log_data(&[
-            b"PoolParameterUpdated",
-            ctx.accounts.pool.key.as_ref(),
-            ctx.accounts.admin.key.as_ref(),
-            b"enforce_lockup",
-            &[if enforce { 1u8 } else { 0u8 }],
-        ]);
-    }
-    if let Some(end_date) = pool_end_date {
+    if let Some(end_date) = update.pool_end_date {
         // Prevent extending pool after end date has passed
         if let Some(existing_end) = pool_data.pool_end_date {
             if current_time >= existing_end {
@@ -466,25 +1031,369 @@ pub fn update_pool<'a>(
         msg!("Pool end date updated to: {:?}", end_date);
 
         // Emit event
-        if let Some(timestamp) = end_date {
-            sol_log_data(&[
-                b"PoolParameterUpdated",
-                ctx.accounts.pool.key.as_ref(),
-                ctx.accounts.admin.key.as_ref(),
-                b"pool_end_date",
-                &timestamp.to_le_bytes(),
-            ]);
-        } else {
-            sol_log_data(&[
-                b"PoolParameterUpdated",
-                ctx.accounts.pool.key.as_ref(),
-                ctx.accounts.admin.key.as_ref(),
-                b"pool_end_date_removed",
-            ]);
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::PoolEndDate(end_date),
+        )?;
+    }
+
+    if let Some(bps) = update.referral_bps {
+        if bps > MAX_REFERRAL_BPS {
+            msg!(
+                "Referral share too high: {} bps. Maximum: {} bps",
+                bps,
+                MAX_REFERRAL_BPS
+            );
+            return Err(StakePoolError::InvalidParameters.into());
         }
+        pool_data.referral_bps = bps;
+        msg!("Referral share updated to: {} bps", bps);
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::ReferralBps(bps),
+        )?;
     }
 
-    pool_data.save(ctx.accounts.pool)
+    if let Some(bps) = update.early_unstake_bps {
+        if bps as u64 > BPS_DENOMINATOR {
+            msg!(
+                "Early unstake allowance too high: {} bps. Maximum: {} bps",
+                bps,
+                BPS_DENOMINATOR
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        pool_data.early_unstake_bps = bps;
+        msg!("Early unstake allowance updated to: {} bps", bps);
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::EarlyUnstakeBps(bps),
+        )?;
+    }
+
+    if let Some(bps) = update.deposit_fee_bps {
+        if bps > MAX_FEE_BPS {
+            msg!(
+                "Deposit fee too high: {} bps. Maximum: {} bps",
+                bps,
+                MAX_FEE_BPS
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        pool_data.deposit_fee_bps = bps;
+        msg!("Deposit fee updated to: {} bps", bps);
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::DepositFeeBps(bps),
+        )?;
+    }
+
+    if let Some(bps) = update.withdraw_fee_bps {
+        if bps > MAX_FEE_BPS {
+            msg!(
+                "Withdraw fee too high: {} bps. Maximum: {} bps",
+                bps,
+                MAX_FEE_BPS
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        pool_data.withdraw_fee_bps = bps;
+        msg!("Withdraw fee updated to: {} bps", bps);
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::WithdrawFeeBps(bps),
+        )?;
+    }
+
+    if let Some(cooldown) = update.unstake_cooldown {
+        if !(0..=MAX_UNSTAKE_COOLDOWN).contains(&cooldown) {
+            msg!(
+                "Unstake cooldown out of range: {} seconds. Maximum: {} seconds",
+                cooldown,
+                MAX_UNSTAKE_COOLDOWN
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        pool_data.unstake_cooldown = cooldown;
+        msg!("Unstake cooldown updated to: {} seconds", cooldown);
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::UnstakeCooldown(cooldown),
+        )?;
+    }
+
+    if update.bonus_end_ts.is_some() || update.bonus_multiplier_bps.is_some() {
+        // Only stakes created while the window is open lock in the bonus, so
+        // changing it never affects existing stakes
+        let end_ts = update.bonus_end_ts.unwrap_or(pool_data.bonus_end_ts);
+        let multiplier_bps = update
+            .bonus_multiplier_bps
+            .unwrap_or(pool_data.bonus_multiplier_bps);
+        pool_data.set_bonus_window(end_ts, multiplier_bps, current_time)?;
+        msg!(
+            "Bonus window updated: end {:?}, multiplier {} bps",
+            pool_data.bonus_end_ts,
+            pool_data.bonus_multiplier_bps
+        );
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::BonusWindow {
+                end_ts: pool_data.bonus_end_ts,
+                multiplier_bps: pool_data.bonus_multiplier_bps,
+            },
+        )?;
+    }
+
+    if let Some(rate) = update.lockup_extension_rate {
+        if rate > config.max_reward_rate {
+            msg!(
+                "Lockup extension rate too high: {}. Maximum: {}",
+                rate,
+                config.max_reward_rate
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        // Extensions already made keep the bonus rate they locked in
+        pool_data.lockup_extension_rate = rate;
+        msg!("Lockup extension rate updated to: {}", rate);
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::LockupExtensionRate(rate),
+        )?;
+    }
+
+    if let Some(root) = update.allowlist_merkle_root {
+        pool_data.allowlist_merkle_root = root;
+        msg!("Allowlist merkle root updated to: {:?}", root);
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::AllowlistMerkleRoot(root),
+        )?;
+    }
+
+    if let Some(window) = update.claim_window {
+        if let Some(window) = &window {
+            window.validate()?;
+        }
+        pool_data.claim_window = window;
+        msg!("Claim window updated to: {:?}", window);
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::ClaimWindow(window),
+        )?;
+    }
+
+    if let Some(limit) = update.outflow_limit {
+        if let Some(limit) = &limit {
+            limit.validate()?;
+        }
+        pool_data.outflow_limit = limit;
+        msg!("Outflow limit updated to: {:?}", limit);
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::OutflowLimit(limit),
+        )?;
+    }
+
+    if let Some(manager) = update.pool_manager {
+        pool_data.pool_manager = manager;
+        msg!("Pool manager updated to: {:?}", manager);
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::PoolManager(manager),
+        )?;
+    }
+
+    if let Some(bounty) = update.keeper_bounty {
+        if bounty > MAX_KEEPER_BOUNTY {
+            msg!(
+                "Keeper bounty too high: {} lamports. Maximum: {} lamports",
+                bounty,
+                MAX_KEEPER_BOUNTY
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        pool_data.keeper_bounty = bounty;
+        msg!("Keeper bounty updated to: {} lamports", bounty);
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::KeeperBounty(bounty),
+        )?;
+    }
+
+    if let Some(cap) = update.max_total_rewards {
+        if let Some(cap) = cap {
+            if cap < pool_data.total_rewards_committed {
+                msg!(
+                    "Rewards cap below committed rewards: {}. Committed: {}",
+                    cap,
+                    pool_data.total_rewards_committed
+                );
+                return Err(StakePoolError::InvalidParameters.into());
+            }
+        }
+        pool_data.max_total_rewards = cap;
+        msg!("Max total rewards updated to: {:?}", cap);
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::MaxTotalRewards(cap),
+        )?;
+    }
+
+    if let Some(enforce) = update.enforce_min_remaining_stake {
+        pool_data.enforce_min_remaining_stake = enforce;
+        msg!(
+            "Minimum remaining stake enforcement updated to: {}",
+            enforce
+        );
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::EnforceMinRemainingStake(enforce),
+        )?;
+    }
+
+    if let Some(policy) = update.wind_down_rewards {
+        pool_data.wind_down_rewards = policy;
+        msg!("Wind-down rewards policy updated to: {:?}", policy);
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::WindDownRewards(policy),
+        )?;
+    }
+
+    if let Some(rate) = update.points_rate {
+        pool_data.points_rate = rate;
+        msg!("Points rate updated to: {}", rate);
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::PointsRate(rate),
+        )?;
+    }
+
+    if let Some(required) = update.deny_list_required {
+        pool_data.deny_list_required = required;
+        msg!("Deny list requirement updated to: {}", required);
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::DenyListRequired(required),
+        )?;
+    }
+
+    if let Some(allow) = update.allow_third_party_stake {
+        pool_data.allow_third_party_stake = allow;
+        msg!("Third-party stakes allowed: {}", allow);
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::AllowThirdPartyStake(allow),
+        )?;
+    }
+
+    if let Some(duration) = update.cohort_duration {
+        pool_data.set_cohort_duration(duration)?;
+        msg!("Reward cohort duration updated to: {} seconds", duration);
+
+        // Emit event
+        emit_pool_updated(
+            pool,
+            admin,
+            pool_data.next_event_nonce()?,
+            PoolParameter::CohortDuration(duration),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Log a `PoolUpdated` event for one changed pool parameter
+///
+/// `event_nonce` comes from `StakePool::next_event_nonce` on the pool being updated.
+fn emit_pool_updated(
+    pool: &AccountInfo,
+    admin: &AccountInfo,
+    event_nonce: u64,
+    parameter: PoolParameter,
+) -> ProgramResult {
+    StakePoolEvent::PoolUpdated(PoolUpdatedEvent {
+        pool: *pool.key,
+        admin: *admin.key,
+        parameter,
+        event_nonce,
+    })
+    .emit()
 }
 
 /// Transfer the global program authority to a new admin (two-step process: step 1)
@@ -495,7 +1404,7 @@ pub fn transfer_program_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> Progra
     assert_account_key(
         "program_authority",
         ctx.accounts.program_authority,
-        Key::ProgramAuthority,
+        Key::ProgramAuthorityV2,
     )?;
     assert_program_owner(
         "program_authority",
@@ -533,13 +1442,11 @@ pub fn transfer_program_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> Progra
     program_authority.save(ctx.accounts.program_authority)?;
 
     // Emit event
-    sol_log_data(&[
-        b"ProgramAuthorityNominated",
-        ctx.accounts.current_authority.key.as_ref(),
-        ctx.accounts.new_authority.key.as_ref(),
-    ]);
-
-    Ok(())
+    StakePoolEvent::ProgramAuthorityNominated(AuthorityChangeEvent {
+        current_authority: *ctx.accounts.current_authority.key,
+        new_authority: *ctx.accounts.new_authority.key,
+    })
+    .emit()
 }
 
 /// Accept the transfer of program authority (two-step process: step 2)
@@ -550,7 +1457,7 @@ pub fn accept_program_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramR
     assert_account_key(
         "program_authority",
         ctx.accounts.program_authority,
-        Key::ProgramAuthority,
+        Key::ProgramAuthorityV2,
     )?;
     assert_program_owner(
         "program_authority",
@@ -595,13 +1502,11 @@ pub fn accept_program_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramR
     program_authority.save(ctx.accounts.program_authority)?;
 
     // Emit event
-    sol_log_data(&[
-        b"ProgramAuthorityTransferred",
-        old_authority.as_ref(),
-        program_authority.authority.as_ref(),
-    ]);
-
-    Ok(())
+    StakePoolEvent::ProgramAuthorityTransferred(AuthorityChangeEvent {
+        current_authority: old_authority,
+        new_authority: program_authority.authority,
+    })
+    .emit()
 }
 
 /// Finalize a pending reward rate change after the delay period has elapsed
@@ -623,8 +1528,8 @@ pub fn accept_program_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramR
 ///   NoPendingRewardRateChange error since pending fields are cleared atomically
 /// - **No Race Conditions**: Single atomic update in pool_data.save() prevents any
 ///   race conditions between concurrent finalization attempts
-/// - **Time-Lock Enforcement**: Cannot be called until exactly REWARD_RATE_CHANGE_DELAY
-///   seconds have elapsed, regardless of who calls it
+/// - **Time-Lock Enforcement**: Cannot be called until the cluster profile's delay
+///   (REWARD_RATE_CHANGE_DELAY on Mainnet) has elapsed, regardless of who calls it
 /// - **Rate Validation**: Pending rate is re-validated during finalization (defense-in-depth)
 ///
 /// ## Attack Surface:
@@ -645,12 +1550,17 @@ pub fn accept_program_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramR
 ///
 /// The new authority inherits full control over the pending change and can choose to either
 /// let it finalize (by waiting) or cancel it (by reproposing the current rate).
+///
+/// ## Existing Stakes
+/// The new rate only applies to stakes and relock rounds started after finalization.
+/// Existing rounds keep their `locked_reward_rate`, so what they reserved in
+/// `total_rewards_owed` stays exact and no recomputation is needed.
 pub fn finalize_reward_rate_change<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
     // Parse accounts using ShankContext-generated struct
     let ctx = FinalizeRewardRateChangeAccounts::context(accounts)?;
 
     // Verify pool account discriminator before loading (Type Cosplay protection)
-    assert_account_key("pool", ctx.accounts.pool, Key::StakePool)?;
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
 
     // Verify program ownership
     assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
@@ -658,6 +1568,20 @@ pub fn finalize_reward_rate_change<'a>(accounts: &'a [AccountInfo<'a>]) -> Progr
     // Load pool
     let mut pool_data = StakePool::load(ctx.accounts.pool)?;
 
+    // Load program authority for the deployment's time-lock
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    assert_program_owner(
+        "program_authority",
+        ctx.accounts.program_authority,
+        &crate::ID,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+    let rate_change_delay = pool_data.rate_change_delay(program_authority.cluster_profile);
+
     // Guards
     assert_writable("pool", ctx.accounts.pool)?;
 
@@ -690,16 +1614,14 @@ pub fn finalize_reward_rate_change<'a>(accounts: &'a [AccountInfo<'a>]) -> Progr
         .checked_sub(change_timestamp)
         .ok_or(StakePoolError::NumericalOverflow)?;
 
-    // Require at least REWARD_RATE_CHANGE_DELAY seconds to have passed
-    // Using < (not <=) means we need time_elapsed >= REWARD_RATE_CHANGE_DELAY
+    // Require at least rate_change_delay seconds to have passed
+    // Using < (not <=) means we need time_elapsed >= rate_change_delay
     // Example: If delay is 604800 seconds (7 days), and change_timestamp = 1000000,
     //          finalization is allowed when current_time >= 1604800 (exactly 7 days later)
-    if time_elapsed < REWARD_RATE_CHANGE_DELAY {
+    if time_elapsed < rate_change_delay {
         msg!(
             "Reward rate change delay not elapsed. Time remaining: {} seconds",
-            REWARD_RATE_CHANGE_DELAY
-                .checked_sub(time_elapsed)
-                .unwrap_or(0)
+            rate_change_delay.checked_sub(time_elapsed).unwrap_or(0)
         );
         return Err(StakePoolError::RewardRateChangeDelayNotElapsed.into());
     }
@@ -724,27 +1646,98 @@ pub fn finalize_reward_rate_change<'a>(accounts: &'a [AccountInfo<'a>]) -> Progr
         pool_data.reward_rate
     );
 
+    let bounty = pay_keeper_bounty(
+        ctx.accounts.pool,
+        &mut pool_data,
+        ctx.accounts.keeper,
+        current_time,
+    )?;
+
     // Save state first to ensure persistence before emitting event
     pool_data.save(ctx.accounts.pool)?;
 
     // Emit event for off-chain indexing after successful state save
-    sol_log_data(&[
-        b"RewardRateFinalized",
-        ctx.accounts.pool.key.as_ref(),
-        &old_rate.to_le_bytes(),
-        &pool_data.reward_rate.to_le_bytes(),
-    ]);
+    StakePoolEvent::RewardRateFinalized(RewardRateFinalizedEvent {
+        pool: *ctx.accounts.pool.key,
+        old_rate,
+        new_rate: pool_data.reward_rate,
+    })
+    .emit()?;
+    emit_keeper_bounty_paid(ctx.accounts.pool, ctx.accounts.keeper, bounty)
+}
 
-    Ok(())
+/// Finalize a pending lockup_period / enforce_lockup change after the delay period
+///
+/// Lockup changes apply to existing stakes, so UpdatePool only records them as
+/// pending. Like reward rate changes they wait the pool's rate change delay,
+/// giving stakers notice to exit, and anyone can finalize them afterwards.
+///
+/// # Errors
+/// Returns error if:
+/// - No lockup change is pending
+/// - The pool's delay has not elapsed since the change was proposed
+pub fn finalize_lockup_change<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = FinalizeLockupChangeAccounts::context(accounts)?;
+
+    // Verify pool account discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+
+    // Load program authority for the deployment's time-lock
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    assert_program_owner(
+        "program_authority",
+        ctx.accounts.program_authority,
+        &crate::ID,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_writable("pool", ctx.accounts.pool)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    validate_current_timestamp(current_time)?;
+
+    pool_data.finalize_lockup_change(
+        current_time,
+        pool_data.rate_change_delay(program_authority.cluster_profile),
+    )?;
+
+    msg!(
+        "Lockup change finalized: lockup_period={}, enforce_lockup={}",
+        pool_data.lockup_period,
+        pool_data.enforce_lockup
+    );
+
+    let bounty = pay_keeper_bounty(
+        ctx.accounts.pool,
+        &mut pool_data,
+        ctx.accounts.keeper,
+        current_time,
+    )?;
+
+    // Save state first to ensure persistence before emitting event
+    pool_data.save(ctx.accounts.pool)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::LockupChangeFinalized(LockupChangeFinalizedEvent {
+        pool: *ctx.accounts.pool.key,
+        lockup_period: pool_data.lockup_period,
+        enforce_lockup: pool_data.enforce_lockup,
+    })
+    .emit()?;
+    emit_keeper_bounty_paid(ctx.accounts.pool, ctx.accounts.keeper, bounty)
 }
 
 /// Get all authorized creators (view function for off-chain queries)
 ///
-/// This is a read-only operation that returns the ProgramAuthority account data.
+/// Publishes an `AuthorizedCreators` as return data.
 /// Intended to be called via simulateTransaction for off-chain queries.
-///
-/// # Returns
-/// Always returns Ok(()) - the account data can be deserialized client-side
 pub fn get_authorized_creators<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
     let ctx = GetAuthorizedCreatorsAccounts::context(accounts)?;
 
@@ -752,7 +1745,7 @@ pub fn get_authorized_creators<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramRe
     assert_account_key(
         "program_authority",
         ctx.accounts.program_authority,
-        Key::ProgramAuthority,
+        Key::ProgramAuthorityV2,
     )?;
     assert_program_owner(
         "program_authority",
@@ -760,18 +1753,26 @@ pub fn get_authorized_creators<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramRe
         &crate::ID,
     )?;
 
-    // Load to verify account is valid
-    let _program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    let creators: Vec<Pubkey> = program_authority
+        .authorized_creators
+        .iter()
+        .map(|entry| entry.creator)
+        .collect();
+    msg!("{} authorized creators", creators.len());
 
-    // Return success - caller can deserialize the account data
-    msg!("ProgramAuthority account verified");
-    Ok(())
+    return_data::set(&AuthorizedCreators {
+        authority: program_authority.authority,
+        creators,
+        pending_authority: program_authority.pending_authority,
+    })
 }
 
 /// Check if an address is authorized (view function for off-chain queries)
 ///
-/// Returns Ok(()) if the address is authorized to create pools.
-/// Returns Unauthorized error if not authorized.
+/// Publishes an `AuthorizationCheck` as return data; an unauthorized address
+/// is an answer, not an error.
 /// Intended to be called via simulateTransaction for off-chain queries.
 ///
 /// # Arguments
@@ -784,7 +1785,7 @@ pub fn check_authorization<'a>(accounts: &'a [AccountInfo<'a>], address: Pubkey)
     assert_account_key(
         "program_authority",
         ctx.accounts.program_authority,
-        Key::ProgramAuthority,
+        Key::ProgramAuthorityV2,
     )?;
     assert_program_owner(
         "program_authority",
@@ -795,25 +1796,29 @@ pub fn check_authorization<'a>(accounts: &'a [AccountInfo<'a>], address: Pubkey)
     // Load program authority
     let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
 
-    // Check if address is authorized
-    if !program_authority.is_authorized(&address) {
+    let authorized = program_authority.is_authorized(&address);
+    if authorized {
+        msg!("Address {} is authorized", address);
+    } else {
         msg!("Address {} is not authorized", address);
-        return Err(StakePoolError::Unauthorized.into());
     }
 
-    msg!("Address {} is authorized", address);
-    Ok(())
+    return_data::set(&AuthorizationCheck {
+        address,
+        authorized,
+    })
 }
 
 /// Cancel a pending authority transfer
 ///
 /// Allows the current authority to cancel a pending transfer before it's accepted.
 /// This provides flexibility if the authority changes their mind or nominates
-/// the wrong address.
+/// the wrong address. A takeover initiated by the recovery authority is
+/// cancelled as well, which is how a live authority stops a rogue recovery.
 ///
 /// # Security
 /// - Only current authority can cancel
-/// - Returns error if no pending transfer exists
+/// - Returns error if neither a transfer nor a recovery is pending
 pub fn cancel_authority_transfer<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
     let ctx = CancelAuthorityTransferAccounts::context(accounts)?;
 
@@ -821,7 +1826,7 @@ pub fn cancel_authority_transfer<'a>(accounts: &'a [AccountInfo<'a>]) -> Program
     assert_account_key(
         "program_authority",
         ctx.accounts.program_authority,
-        Key::ProgramAuthority,
+        Key::ProgramAuthorityV2,
     )?;
     assert_program_owner(
         "program_authority",
@@ -845,28 +1850,598 @@ pub fn cancel_authority_transfer<'a>(accounts: &'a [AccountInfo<'a>]) -> Program
         return Err(StakePoolError::Unauthorized.into());
     }
 
-    // Verify there is a pending authority to cancel
-    let pending = program_authority
-        .pending_authority
-        .ok_or(StakePoolError::NoPendingAuthority)?;
-
-    // Clear pending authority
-    program_authority.pending_authority = None;
+    // Verify there is a pending transfer or recovery to cancel
+    let pending = program_authority.pending_authority.take();
+    let recovery = program_authority
+        .recovery_initiated_at
+        .take()
+        .and(program_authority.recovery_authority);
+    if pending.is_none() && recovery.is_none() {
+        return Err(StakePoolError::NoPendingAuthority.into());
+    }
 
-    msg!(
-        "Authority transfer cancelled. Pending authority {} removed.",
-        pending
-    );
+    if let Some(pending) = pending {
+        msg!(
+            "Authority transfer cancelled. Pending authority {} removed.",
+            pending
+        );
+    }
+    if let Some(recovery) = recovery {
+        msg!("Authority recovery by {} cancelled", recovery);
+    }
 
     // Save state
     program_authority.save(ctx.accounts.program_authority)?;
 
+    // Emit events
+    if let Some(pending) = pending {
+        StakePoolEvent::ProgramAuthorityTransferCancelled(AuthorityChangeEvent {
+            current_authority: *ctx.accounts.current_authority.key,
+            new_authority: pending,
+        })
+        .emit()?;
+    }
+    if let Some(recovery) = recovery {
+        StakePoolEvent::RecoveryCancelled(AuthorityChangeEvent {
+            current_authority: *ctx.accounts.current_authority.key,
+            new_authority: recovery,
+        })
+        .emit()?;
+    }
+    Ok(())
+}
+
+/// Close the program authority account (Devnet-profile deployments only)
+///
+/// Lets a devnet deployment be reset and re-initialized (e.g. with a new
+/// authority or profile) without redeploying the program. Rejected with
+/// `DevOnlyInstruction` on Mainnet-profile deployments.
+///
+/// # Security
+/// - Only the current authority can close the account
+/// - Account data is zeroed before the lamports are returned
+pub fn close_program_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = CloseProgramAuthorityAccounts::context(accounts)?;
+
+    // Verify program authority account
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    assert_program_owner(
+        "program_authority",
+        ctx.accounts.program_authority,
+        &crate::ID,
+    )?;
+
+    // Load program authority
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("authority", ctx.accounts.authority)?;
+    assert_writable("program_authority", ctx.accounts.program_authority)?;
+    assert_writable("receiver", ctx.accounts.receiver)?;
+
+    if !program_authority.cluster_profile.allows_dev_instructions() {
+        msg!(
+            "CloseProgramAuthority is disabled for the {:?} profile",
+            program_authority.cluster_profile
+        );
+        return Err(StakePoolError::DevOnlyInstruction.into());
+    }
+
+    // Verify signer is current authority
+    if ctx.accounts.authority.key != &program_authority.authority {
+        msg!(
+            "Unauthorized: {} is not the current authority",
+            ctx.accounts.authority.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    close_account(ctx.accounts.program_authority, ctx.accounts.receiver)?;
+
+    msg!("Program authority closed by {}", ctx.accounts.authority.key);
+
     // Emit event
-    sol_log_data(&[
-        b"ProgramAuthorityTransferCancelled",
-        ctx.accounts.current_authority.key.as_ref(),
-        pending.as_ref(),
-    ]);
+    StakePoolEvent::ProgramAuthorityClosed(ProgramAuthorityClosedEvent {
+        authority: *ctx.accounts.authority.key,
+    })
+    .emit()
+}
 
-    Ok(())
+/// Replace a pool's reward funder allowlist (global admin only)
+///
+/// While the list is non-empty only the listed addresses can call `fund_rewards`,
+/// so third parties cannot mix their tokens into a pool's reward accounting.
+/// An empty list restores permissionless funding.
+pub fn set_reward_funders<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    funders: Vec<Pubkey>,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = SetRewardFundersAccounts::context(accounts)?;
+
+    // Verify pool account discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // Load pool
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+
+    // Load program authority to verify admin permissions
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+
+    // Verify the signer is authorized as a global admin
+    if !program_authority.is_authorized(ctx.accounts.admin.key) {
+        msg!(
+            "Unauthorized: {} is not a global admin",
+            ctx.accounts.admin.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    pool_data.set_reward_funders(&funders)?;
+
+    msg!(
+        "Reward funders updated: {} allowed ({})",
+        funders.len(),
+        if funders.is_empty() {
+            "open funding"
+        } else {
+            "allowlist"
+        }
+    );
+
+    // Save state first to ensure persistence before emitting event
+    pool_data.save(ctx.accounts.pool)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::RewardFundersUpdated(RewardFundersUpdatedEvent {
+        pool: *ctx.accounts.pool.key,
+        admin: *ctx.accounts.admin.key,
+        funders,
+    })
+    .emit()
+}
+
+/// Edit a page of a pool's staker allowlist and toggle its enforcement (global admin only)
+///
+/// Removals are applied before additions; owners already in the wanted state are
+/// skipped. Creates the page if missing, paid by `payer`. Enforcement only gates
+/// new stakes: positions of owners removed from the list are left untouched.
+pub fn manage_pool_allowlist<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    page: u32,
+    add: Vec<Pubkey>,
+    remove: Vec<Pubkey>,
+    allowlist_required: Option<bool>,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = ManagePoolAllowlistAccounts::context(accounts)?;
+
+    // Verify pool account discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // Load pool
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+
+    // Load program authority to verify admin permissions
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_signer("payer", ctx.accounts.payer)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("allowlist", ctx.accounts.allowlist)?;
+    assert_writable("payer", ctx.accounts.payer)?;
+
+    // Verify the signer is authorized as a global admin
+    if !program_authority.is_authorized(ctx.accounts.admin.key) {
+        msg!(
+            "Unauthorized: {} is not a global admin",
+            ctx.accounts.admin.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    let mut allowlist = load_or_create_pool_allowlist(
+        ctx.accounts.allowlist,
+        ctx.accounts.pool.key,
+        page,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )?;
+    allowlist.update(&add, &remove)?;
+
+    if let Some(required) = allowlist_required {
+        pool_data.allowlist_required = required;
+    }
+
+    msg!(
+        "Pool allowlist page {} updated: {} added, {} removed, {} listed (allowlist {})",
+        page,
+        add.len(),
+        remove.len(),
+        allowlist.members.len(),
+        if pool_data.allowlist_required {
+            "required"
+        } else {
+            "not required"
+        }
+    );
+
+    // Save state first to ensure persistence before emitting event
+    allowlist.save(ctx.accounts.allowlist)?;
+    pool_data.save(ctx.accounts.pool)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::PoolAllowlistUpdated(PoolAllowlistUpdatedEvent {
+        pool: *ctx.accounts.pool.key,
+        admin: *ctx.accounts.admin.key,
+        page,
+        added: add,
+        removed: remove,
+        page_members: allowlist.members.len() as u32,
+        allowlist_required: pool_data.allowlist_required,
+    })
+    .emit()
+}
+
+/// Edit the allowlist of programs pools may call as hooks (global admin only)
+///
+/// Removals are applied before additions; programs already in the wanted state
+/// are skipped. Creates the allowlist if missing, paid by `payer`. Pools keep
+/// calling a removed program until an admin detaches it with `set_pool_hook`.
+pub fn manage_hook_allowlist<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    add: Vec<Pubkey>,
+    remove: Vec<Pubkey>,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = ManageHookAllowlistAccounts::context(accounts)?;
+
+    // Load program authority to verify admin permissions
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_signer("payer", ctx.accounts.payer)?;
+    assert_writable("hook_allowlist", ctx.accounts.hook_allowlist)?;
+    assert_writable("payer", ctx.accounts.payer)?;
+
+    // Verify the signer is authorized as a global admin
+    if !program_authority.is_authorized(ctx.accounts.admin.key) {
+        msg!(
+            "Unauthorized: {} is not a global admin",
+            ctx.accounts.admin.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    let mut allowlist = load_or_create_hook_allowlist(
+        ctx.accounts.hook_allowlist,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )?;
+    allowlist.update(&add, &remove)?;
+
+    msg!(
+        "Hook allowlist updated: {} added, {} removed, {} listed",
+        add.len(),
+        remove.len(),
+        allowlist.programs.len()
+    );
+
+    // Save state first to ensure persistence before emitting event
+    allowlist.save(ctx.accounts.hook_allowlist)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::HookAllowlistUpdated(HookAllowlistUpdatedEvent {
+        admin: *ctx.accounts.admin.key,
+        added: add,
+        removed: remove,
+        programs: allowlist.programs.len() as u32,
+    })
+    .emit()
+}
+
+/// Set or detach the program a pool calls after stakes, unstakes and claims (global admin only)
+///
+/// Setting a hook requires the program to be on the hook allowlist; detaching
+/// never does, so a broken hook can always be removed.
+pub fn set_pool_hook<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    hook_program: Option<Pubkey>,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = SetPoolHookAccounts::context(accounts)?;
+
+    // Verify pool account discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // Load pool
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+
+    // Load program authority to verify admin permissions
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+
+    // Verify the signer is authorized as a global admin
+    if !program_authority.is_authorized(ctx.accounts.admin.key) {
+        msg!(
+            "Unauthorized: {} is not a global admin",
+            ctx.accounts.admin.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    if let Some(program) = hook_program {
+        let allowlist_account = ctx.accounts.hook_allowlist.ok_or_else(|| {
+            msg!("hook_allowlist is required to set a hook program");
+            ProgramError::NotEnoughAccountKeys
+        })?;
+        let allowlist = load_hook_allowlist(allowlist_account)?;
+        if !allowlist.contains(&program) {
+            msg!("Program {} is not on the hook allowlist", program);
+            return Err(StakePoolError::HookProgramNotAllowed.into());
+        }
+    }
+
+    pool_data.hook_program = hook_program;
+
+    match hook_program {
+        Some(program) => msg!(
+            "Pool {} calls hook program {}",
+            ctx.accounts.pool.key,
+            program
+        ),
+        None => msg!("Pool {} hook detached", ctx.accounts.pool.key),
+    }
+
+    // Save state first to ensure persistence before emitting event
+    let event_nonce = pool_data.next_event_nonce()?;
+    pool_data.save(ctx.accounts.pool)?;
+
+    // Emit event for off-chain indexing after successful state save
+    emit_pool_updated(
+        ctx.accounts.pool,
+        ctx.accounts.admin,
+        event_nonce,
+        PoolParameter::HookProgram(hook_program),
+    )
+}
+
+/// Edit the global deny list (global admin only)
+///
+/// Removals are applied before additions; addresses already in the wanted state
+/// are skipped. Creates the deny list if missing, paid by `payer`, and optionally
+/// sets whether listed owners may still withdraw principal to themselves.
+pub fn manage_deny_list<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    add: Vec<Pubkey>,
+    remove: Vec<Pubkey>,
+    allow_listed_withdrawals: Option<bool>,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = ManageDenyListAccounts::context(accounts)?;
+
+    // Load program authority to verify admin permissions
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_signer("payer", ctx.accounts.payer)?;
+    assert_writable("deny_list", ctx.accounts.deny_list)?;
+    assert_writable("payer", ctx.accounts.payer)?;
+
+    // Verify the signer is authorized as a global admin
+    if !program_authority.is_authorized(ctx.accounts.admin.key) {
+        msg!(
+            "Unauthorized: {} is not a global admin",
+            ctx.accounts.admin.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    let mut deny_list = load_or_create_deny_list(
+        ctx.accounts.deny_list,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )?;
+    deny_list.update(&add, &remove)?;
+    if let Some(allow) = allow_listed_withdrawals {
+        deny_list.allow_listed_withdrawals = allow;
+    }
+
+    msg!(
+        "Deny list updated: {} added, {} removed, {} listed, listed withdrawals allowed: {}",
+        add.len(),
+        remove.len(),
+        deny_list.addresses.len(),
+        deny_list.allow_listed_withdrawals
+    );
+
+    // Save state first to ensure persistence before emitting event
+    deny_list.save(ctx.accounts.deny_list)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::DenyListUpdated(DenyListUpdatedEvent {
+        admin: *ctx.accounts.admin.key,
+        added: add,
+        removed: remove,
+        addresses: deny_list.addresses.len() as u32,
+        allow_listed_withdrawals: deny_list.allow_listed_withdrawals,
+    })
+    .emit()
+}
+
+/// Set the total value locked cap of a stake mint (global admin only)
+///
+/// The cap bounds the sum staked across every pool of the mint and is checked
+/// by `stake` and `stake_sol`. Lowering it below the current total only blocks
+/// new stakes; existing positions are never forced out. Creates the mint's
+/// registry when no pool of the mint exists yet.
+pub fn set_mint_tvl_cap<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    tvl_cap: Option<u64>,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = SetMintTvlCapAccounts::context(accounts)?;
+
+    // Load program authority to verify admin permissions
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_writable("admin", ctx.accounts.admin)?;
+    assert_writable("mint_registry", ctx.accounts.mint_registry)?;
+
+    // Verify the signer is authorized as a global admin
+    if !program_authority.is_authorized(ctx.accounts.admin.key) {
+        msg!(
+            "Unauthorized: {} is not a global admin",
+            ctx.accounts.admin.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    let mut mint_registry = load_or_create_mint_registry(
+        ctx.accounts.mint_registry,
+        ctx.accounts.stake_mint.key,
+        ctx.accounts.admin,
+        ctx.accounts.system_program,
+    )?;
+    mint_registry.tvl_cap = tvl_cap;
+
+    match tvl_cap {
+        Some(cap) => msg!(
+            "TVL cap for mint {} set to {} (currently staked: {})",
+            ctx.accounts.stake_mint.key,
+            cap,
+            mint_registry.total_staked
+        ),
+        None => msg!("TVL cap for mint {} removed", ctx.accounts.stake_mint.key),
+    }
+
+    // Save state first to ensure persistence before emitting event
+    mint_registry.save(ctx.accounts.mint_registry)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::MintTvlCapUpdated(MintTvlCapUpdatedEvent {
+        stake_mint: *ctx.accounts.stake_mint.key,
+        admin: *ctx.accounts.admin.key,
+        tvl_cap,
+        total_staked: mint_registry.total_staked,
+    })
+    .emit()
+}
+
+/// Set the protocol-wide pause switches on the program authority (global admin or pauser)
+///
+/// `global_pause` halts stake, unstake and claim operations in every pool.
+/// `withdrawals_only` keeps unstakes and withdrawals open while blocking new
+/// stakes and claims. Per-pool `is_paused` flags are left untouched.
+pub fn set_global_pause<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    global_pause: bool,
+    withdrawals_only: bool,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = SetGlobalPauseAccounts::context(accounts)?;
+
+    // Verify program authority account
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    assert_program_owner(
+        "program_authority",
+        ctx.accounts.program_authority,
+        &crate::ID,
+    )?;
+
+    // Load program authority
+    let mut program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_writable("program_authority", ctx.accounts.program_authority)?;
+
+    // Verify the signer is a global admin or a pauser
+    if !program_authority.has_role(ctx.accounts.admin.key, Role::Pauser) {
+        msg!(
+            "Unauthorized: {} is not a global admin or pauser",
+            ctx.accounts.admin.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    program_authority.global_pause = global_pause;
+    program_authority.withdrawals_only = withdrawals_only;
+
+    msg!(
+        "Global pause set to {}, withdrawals-only set to {} by {}",
+        global_pause,
+        withdrawals_only,
+        ctx.accounts.admin.key
+    );
+
+    // Save state first to ensure persistence before emitting event
+    program_authority.save(ctx.accounts.program_authority)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::GlobalPauseUpdated(GlobalPauseUpdatedEvent {
+        admin: *ctx.accounts.admin.key,
+        global_pause,
+        withdrawals_only,
+    })
+    .emit()
 }
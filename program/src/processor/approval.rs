@@ -0,0 +1,310 @@
+//! Multi-admin approval of sensitive pool updates
+//!
+//! Once the program authority sets an approval threshold above 1, reward rate,
+//! pause and end date changes can no longer be applied by a single admin
+//! through UpdatePool. A global admin proposes the change into a pending action
+//! PDA, other admins approve it, and any admin executes it once the approvals
+//! reach the threshold. Executing applies the change with the same validation
+//! as UpdatePool and closes the pending action.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+use crate::assertions::*;
+use crate::error::StakePoolError;
+use crate::events::{
+    ApprovalThresholdUpdatedEvent, PoolUpdateApprovedEvent, PoolUpdateExecutedEvent,
+    PoolUpdateProposedEvent, StakePoolEvent,
+};
+use crate::instruction::accounts::*;
+use crate::state::{Key, PendingAction, ProgramAuthority, SensitivePoolUpdate, StakePool};
+use crate::utils::{close_account, create_account};
+
+use super::admin::{apply_pool_update, PoolUpdate};
+use super::helpers::{load_program_config, validate_current_timestamp};
+
+/// Load the program authority after verifying its discriminator and owner
+pub(super) fn load_program_authority(
+    account: &AccountInfo,
+) -> Result<ProgramAuthority, ProgramError> {
+    assert_account_key("program_authority", account, Key::ProgramAuthorityV2)?;
+    assert_program_owner("program_authority", account, &crate::ID)?;
+    ProgramAuthority::load(account)
+}
+
+/// Fail unless `admin` is a global admin
+pub(super) fn assert_global_admin(
+    program_authority: &ProgramAuthority,
+    admin: &Pubkey,
+) -> ProgramResult {
+    if !program_authority.is_authorized(admin) {
+        msg!("Unauthorized: {} is not a global admin", admin);
+        return Err(StakePoolError::Unauthorized.into());
+    }
+    Ok(())
+}
+
+/// Load a pending action after verifying its discriminator and owner
+fn load_pending_action(account: &AccountInfo) -> Result<PendingAction, ProgramError> {
+    assert_account_key("pending_action", account, Key::PendingAction)?;
+    assert_program_owner("pending_action", account, &crate::ID)?;
+    assert_writable("pending_action", account)?;
+    PendingAction::load(account)
+}
+
+/// Set how many global admins must approve sensitive pool updates (program authority only)
+///
+/// # Errors
+/// Returns error if:
+/// - Caller is not the program authority
+/// - The threshold exceeds the number of global admins
+pub fn set_approval_threshold<'a>(accounts: &'a [AccountInfo<'a>], threshold: u8) -> ProgramResult {
+    let ctx = SetApprovalThresholdAccounts::context(accounts)?;
+
+    let mut program_authority = load_program_authority(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("authority", ctx.accounts.authority)?;
+    assert_writable("program_authority", ctx.accounts.program_authority)?;
+
+    // Verify the signer is the program authority
+    if ctx.accounts.authority.key != &program_authority.authority {
+        msg!(
+            "Unauthorized: {} is not the program authority",
+            ctx.accounts.authority.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    program_authority.set_approval_threshold(threshold)?;
+
+    msg!("Approval threshold set to {}", threshold);
+
+    // Save state first to ensure persistence before emitting event
+    program_authority.save(ctx.accounts.program_authority)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::ApprovalThresholdUpdated(ApprovalThresholdUpdatedEvent {
+        authority: *ctx.accounts.authority.key,
+        threshold,
+    })
+    .emit()
+}
+
+/// Propose a sensitive pool update, recording the proposer's approval (global admin only)
+///
+/// # Errors
+/// Returns error if:
+/// - The proposer is not a global admin
+/// - The update changes nothing
+/// - The pending action is not the ["pending_action", pool, action_id] PDA or already exists
+pub fn propose_pool_update<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    action_id: u64,
+    update: SensitivePoolUpdate,
+) -> ProgramResult {
+    let ctx = ProposePoolUpdateAccounts::context(accounts)?;
+
+    // Verify the pool
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    let program_authority = load_program_authority(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("proposer", ctx.accounts.proposer)?;
+    assert_writable("proposer", ctx.accounts.proposer)?;
+    assert_writable("pending_action", ctx.accounts.pending_action)?;
+    assert_empty("pending_action", ctx.accounts.pending_action)?;
+
+    assert_global_admin(&program_authority, ctx.accounts.proposer.key)?;
+
+    if update.is_empty() {
+        msg!("The proposed update changes nothing");
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    let pool_key = ctx.accounts.pool.key;
+    let (pending_action_key, bump) = PendingAction::find_pda(pool_key, action_id);
+    assert_same_pubkeys(
+        "pending_action",
+        ctx.accounts.pending_action,
+        &pending_action_key,
+    )?;
+
+    let mut seeds_with_bump = PendingAction::seeds(pool_key, action_id);
+    seeds_with_bump.push(vec![bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    create_account(
+        ctx.accounts.pending_action,
+        ctx.accounts.proposer,
+        ctx.accounts.system_program,
+        PendingAction::LEN,
+        &crate::ID,
+        Some(&[&seeds_refs]),
+    )?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    validate_current_timestamp(current_time)?;
+
+    let mut approvals = [None; PendingAction::MAX_APPROVALS];
+    approvals[0] = Some(*ctx.accounts.proposer.key);
+    let pending_action = PendingAction {
+        key: Key::PendingAction,
+        pool: *pool_key,
+        proposer: *ctx.accounts.proposer.key,
+        action_id,
+        update,
+        approvals,
+        created_at: current_time,
+        bump,
+    };
+
+    msg!(
+        "Pool update {} proposed for pool {} by {}",
+        action_id,
+        pool_key,
+        ctx.accounts.proposer.key
+    );
+
+    // Save state first to ensure persistence before emitting event
+    pending_action.save(ctx.accounts.pending_action)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::PoolUpdateProposed(PoolUpdateProposedEvent {
+        pool: *pool_key,
+        pending_action: pending_action_key,
+        proposer: *ctx.accounts.proposer.key,
+        action_id,
+        update,
+    })
+    .emit()
+}
+
+/// Approve a pending pool update (global admin only, once per admin)
+///
+/// # Errors
+/// Returns error if:
+/// - The approver is not a global admin
+/// - The approver already approved the update
+pub fn approve_pool_update<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = ApprovePoolUpdateAccounts::context(accounts)?;
+
+    let mut pending_action = load_pending_action(ctx.accounts.pending_action)?;
+    let program_authority = load_program_authority(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("approver", ctx.accounts.approver)?;
+    assert_global_admin(&program_authority, ctx.accounts.approver.key)?;
+
+    let approvals = pending_action.approve(ctx.accounts.approver.key)?;
+
+    msg!(
+        "Pool update {} approved by {} ({} approvals)",
+        pending_action.action_id,
+        ctx.accounts.approver.key,
+        approvals
+    );
+
+    // Save state first to ensure persistence before emitting event
+    pending_action.save(ctx.accounts.pending_action)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::PoolUpdateApproved(PoolUpdateApprovedEvent {
+        pool: pending_action.pool,
+        pending_action: *ctx.accounts.pending_action.key,
+        approver: *ctx.accounts.approver.key,
+        approvals: approvals as u8,
+    })
+    .emit()
+}
+
+/// Apply a pending pool update once enough global admins approved it
+///
+/// Only approvals of addresses that are still global admins count. The update
+/// is validated like an UpdatePool call, then the pending action is closed and
+/// its rent returned to the proposer.
+///
+/// # Errors
+/// Returns error if:
+/// - The executor is not a global admin
+/// - The pending action belongs to another pool or proposer
+/// - The approvals do not reach the threshold
+/// - The update fails UpdatePool validation
+pub fn execute_pool_update<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = ExecutePoolUpdateAccounts::context(accounts)?;
+
+    // Verify pool account discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+
+    let pending_action = load_pending_action(ctx.accounts.pending_action)?;
+    let program_authority = load_program_authority(ctx.accounts.program_authority)?;
+    let config = load_program_config(ctx.accounts.program_config)?;
+
+    // Guards
+    assert_signer("executor", ctx.accounts.executor)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("proposer", ctx.accounts.proposer)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &pending_action.pool)?;
+    assert_same_pubkeys("proposer", ctx.accounts.proposer, &pending_action.proposer)?;
+    assert_global_admin(&program_authority, ctx.accounts.executor.key)?;
+
+    let approvals = pending_action.valid_approvals(&program_authority);
+    let required = program_authority.approval_threshold.max(1) as usize;
+    if approvals < required {
+        msg!(
+            "Pool update {} has {} of {} required approvals",
+            pending_action.action_id,
+            approvals,
+            required
+        );
+        return Err(StakePoolError::InsufficientApprovals.into());
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    validate_current_timestamp(current_time)?;
+
+    let update = PoolUpdate {
+        reward_rate: pending_action.update.reward_rate,
+        is_paused: pending_action.update.is_paused,
+        pool_end_date: pending_action.update.pool_end_date,
+        ..Default::default()
+    };
+    apply_pool_update(
+        ctx.accounts.pool,
+        ctx.accounts.executor,
+        &mut pool_data,
+        &update,
+        current_time,
+        program_authority.cluster_profile,
+        &config,
+    )?;
+
+    // Save state first to ensure persistence before emitting event
+    pool_data.save(ctx.accounts.pool)?;
+    close_account(ctx.accounts.pending_action, ctx.accounts.proposer)?;
+
+    msg!(
+        "Pool update {} executed with {} approvals",
+        pending_action.action_id,
+        approvals
+    );
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::PoolUpdateExecuted(PoolUpdateExecutedEvent {
+        pool: *ctx.accounts.pool.key,
+        pending_action: *ctx.accounts.pending_action.key,
+        executor: *ctx.accounts.executor.key,
+        approvals: approvals as u8,
+    })
+    .emit()
+}
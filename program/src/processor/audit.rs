@@ -0,0 +1,132 @@
+//! Reward accounting audit
+//!
+//! `total_rewards_owed` is only ever adjusted incrementally, so a past bug or
+//! a migration can leave it out of step with the stake accounts it reserves
+//! rewards for. `audit_pool` recomputes the sum from the stake accounts passed
+//! as remaining accounts and reports the difference; a global admin can reset
+//! the counter once every active stake is covered.
+
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::assertions::*;
+use crate::error::StakePoolError;
+use crate::events::{PoolAuditedEvent, StakePoolEvent};
+use crate::instruction::accounts::*;
+use crate::return_data;
+use crate::state::{Key, ProgramAuthority, StakeAccount, StakePool};
+
+/// Compare the pool's `total_rewards_owed` with the unclaimed rewards of the
+/// stake accounts passed as remaining accounts
+///
+/// Never changes the pool without `repair`. A repair needs a global admin and
+/// an audit covering every active stake, so a partial list cannot shrink the
+/// counter below what the missing stakes are owed.
+pub fn audit_pool<'a>(accounts: &'a [AccountInfo<'a>], repair: bool) -> ProgramResult {
+    let ctx = AuditPoolAccounts::context(accounts)?;
+
+    // Verify pool discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // Load pool
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+
+    let mut stakes = Vec::with_capacity(ctx.remaining_accounts.len());
+    for (position, stake_account) in ctx.remaining_accounts.iter().enumerate() {
+        // A repeated account would be counted twice
+        if ctx.remaining_accounts[..position]
+            .iter()
+            .any(|previous| previous.key == stake_account.key)
+        {
+            msg!("Stake account {} is listed twice", stake_account.key);
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+
+        // Verify stake account discriminator before loading (Type Cosplay protection)
+        assert_account_key("stake_account", stake_account, Key::StakeAccountV2)?;
+        assert_program_owner("stake_account", stake_account, &crate::ID)?;
+
+        let stake_account_data = StakeAccount::load(stake_account)?;
+        assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
+        stakes.push(stake_account_data);
+    }
+
+    let mut audit = pool_data.audit(&stakes)?;
+    if audit.recomputed_rewards_owed == audit.total_rewards_owed {
+        msg!(
+            "Pool {} rewards owed match: {} across {} stake accounts",
+            ctx.accounts.pool.key,
+            audit.total_rewards_owed,
+            audit.audited_stakes
+        );
+    } else {
+        msg!(
+            "Pool {} rewards owed discrepancy! Recorded: {}, Recomputed: {} across {} stake accounts (complete: {})",
+            ctx.accounts.pool.key,
+            audit.total_rewards_owed,
+            audit.recomputed_rewards_owed,
+            audit.audited_stakes,
+            audit.complete
+        );
+    }
+
+    if repair {
+        let (Some(admin), Some(program_authority)) =
+            (ctx.accounts.admin, ctx.accounts.program_authority)
+        else {
+            msg!("An admin and the program authority are required to repair the pool");
+            return Err(StakePoolError::Unauthorized.into());
+        };
+
+        // Guards
+        assert_signer("admin", admin)?;
+        assert_writable("pool", ctx.accounts.pool)?;
+        assert_account_key(
+            "program_authority",
+            program_authority,
+            Key::ProgramAuthorityV2,
+        )?;
+        assert_program_owner("program_authority", program_authority, &crate::ID)?;
+
+        // Verify the signer is a global admin
+        let program_authority_data = ProgramAuthority::load(program_authority)?;
+        if !program_authority_data.is_authorized(admin.key) {
+            msg!("Unauthorized: {} is not a global admin", admin.key);
+            return Err(StakePoolError::Unauthorized.into());
+        }
+
+        if !audit.complete {
+            msg!(
+                "Cannot repair pool {}: audited stakes do not match its {:?} active stakes",
+                ctx.accounts.pool.key,
+                pool_data.active_stake_count
+            );
+            return Err(StakePoolError::IncompleteAudit.into());
+        }
+
+        pool_data.total_rewards_owed = audit.recomputed_rewards_owed;
+        audit.repaired = true;
+        msg!(
+            "Pool {} total rewards owed reset to {}",
+            ctx.accounts.pool.key,
+            audit.recomputed_rewards_owed
+        );
+
+        // Save state first to ensure persistence before emitting event
+        pool_data.save(ctx.accounts.pool)?;
+    }
+
+    // Emit event for off-chain indexing after any state save
+    StakePoolEvent::PoolAudited(PoolAuditedEvent {
+        pool: *ctx.accounts.pool.key,
+        audited_stakes: audit.audited_stakes,
+        recomputed_rewards_owed: audit.recomputed_rewards_owed,
+        total_rewards_owed: audit.total_rewards_owed,
+        repaired: audit.repaired,
+    })
+    .emit()?;
+
+    return_data::set(&audit)
+}
@@ -1,10 +1,16 @@
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
+    program_error::ProgramError,
+};
 
 use crate::assertions::*;
 use crate::error::StakePoolError;
+use crate::events::{PoolClosedEvent, StakePoolEvent};
 use crate::instruction::accounts::*;
-use crate::state::{Key, StakeAccount};
-use crate::utils::close_account;
+use crate::state::{Key, PoolVault, ProgramAuthority, StakeAccount, StakePool};
+use crate::utils::{close_account, transfer_tokens_with_fee};
+
+use super::helpers::{get_token_account_balance, load_stake_position, verify_token_account};
 
 pub fn close_stake_account<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
     // Parse accounts using ShankContext-generated struct
@@ -14,7 +20,7 @@ pub fn close_stake_account<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult
     assert_account_key(
         "stake_account",
         ctx.accounts.stake_account,
-        Key::StakeAccount,
+        Key::StakeAccountV2,
     )?;
 
     // Verify program ownership
@@ -29,6 +35,24 @@ pub fn close_stake_account<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult
     assert_writable("receiver", ctx.accounts.receiver)?;
     assert_same_pubkeys("owner", ctx.accounts.owner, &stake_account_data.owner)?;
 
+    close_empty_stake_account(
+        ctx.accounts.stake_account,
+        &stake_account_data,
+        ctx.accounts.receiver,
+        ctx.accounts.stake_position,
+    )
+}
+
+/// Close a stake account holding no principal and release its index from the
+/// owner's stake position directory, if given
+///
+/// Used by `close_stake_account` and `unstake_all`, after their owner checks.
+pub(super) fn close_empty_stake_account<'a>(
+    stake_account: &'a AccountInfo<'a>,
+    stake_account_data: &StakeAccount,
+    receiver: &'a AccountInfo<'a>,
+    stake_position: Option<&'a AccountInfo<'a>>,
+) -> ProgramResult {
     // Ensure stake account is empty (no staked amount)
     if stake_account_data.amount_staked != 0 {
         msg!(
@@ -38,14 +62,238 @@ pub fn close_stake_account<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult
         return Err(StakePoolError::ExpectedEmptyAccount.into());
     }
 
+    // A requested unstake still has to be withdrawn
+    if stake_account_data.pending_withdraw_amount != 0 {
+        msg!(
+            "Cannot close stake account with a pending withdrawal. Pending: {}",
+            stake_account_data.pending_withdraw_amount
+        );
+        return Err(StakePoolError::ExpectedEmptyAccount.into());
+    }
+
+    // Remove the index from the owner's stake position directory, if it has one
+    if let Some(position_account) = stake_position {
+        if !position_account.data_is_empty() {
+            let mut position = load_stake_position(
+                position_account,
+                &stake_account_data.pool,
+                &stake_account_data.owner,
+            )?;
+            position.release(stake_account_data.index);
+            position.save(position_account)?;
+        }
+    }
+
     // Close the account and recover rent
-    close_account(ctx.accounts.stake_account, ctx.accounts.receiver)?;
+    close_account(stake_account, receiver)?;
 
     msg!(
         "Closed stake account {} and returned rent to {}",
-        ctx.accounts.stake_account.key,
-        ctx.accounts.receiver.key
+        stake_account.key,
+        receiver.key
     );
 
     Ok(())
 }
+
+/// Close a paused, empty pool together with its vaults (global admin only)
+///
+/// Whatever the vaults still hold (donations, rounding dust, uncollected fees)
+/// is swept to the receivers first, since token accounts only close at a zero
+/// balance. The vaults' and the pool's rent goes to `rent_receiver`.
+pub fn close_pool<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = ClosePoolAccounts::context(accounts)?;
+
+    // Verify pool account discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // Load pool
+    let pool_data = StakePool::load(ctx.accounts.pool)?;
+
+    // Load program authority to verify admin permissions
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("stake_vault", ctx.accounts.stake_vault)?;
+    assert_writable("reward_vault", ctx.accounts.reward_vault)?;
+    assert_writable("fee_vault", ctx.accounts.fee_vault)?;
+    assert_writable("stake_receiver", ctx.accounts.stake_receiver)?;
+    assert_writable("reward_receiver", ctx.accounts.reward_receiver)?;
+    assert_writable("rent_receiver", ctx.accounts.rent_receiver)?;
+    assert_vault(
+        "stake_vault",
+        ctx.accounts.stake_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Stake,
+    )?;
+    assert_vault(
+        "reward_vault",
+        ctx.accounts.reward_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Reward,
+    )?;
+    assert_vault(
+        "fee_vault",
+        ctx.accounts.fee_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Fee,
+    )?;
+    assert_same_pubkeys("stake_mint", ctx.accounts.stake_mint, &pool_data.stake_mint)?;
+    assert_same_pubkeys(
+        "reward_mint",
+        ctx.accounts.reward_mint,
+        &pool_data.reward_mint,
+    )?;
+
+    // Verify the signer is authorized as a global admin
+    if !program_authority.is_authorized(ctx.accounts.admin.key) {
+        msg!(
+            "Unauthorized: {} is not a global admin",
+            ctx.accounts.admin.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    // Verify token accounts belong to the pool's mints
+    verify_token_account(ctx.accounts.stake_vault, &pool_data.stake_mint, None, None)?;
+    verify_token_account(ctx.accounts.fee_vault, &pool_data.stake_mint, None, None)?;
+    verify_token_account(
+        ctx.accounts.reward_vault,
+        &pool_data.reward_mint,
+        None,
+        None,
+    )?;
+    verify_token_account(
+        ctx.accounts.stake_receiver,
+        &pool_data.stake_mint,
+        None,
+        None,
+    )?;
+    verify_token_account(
+        ctx.accounts.reward_receiver,
+        &pool_data.reward_mint,
+        None,
+        None,
+    )?;
+
+    if !pool_data.is_closable() {
+        msg!(
+            "Cannot close pool {}: paused={}, total_staked={}, total_rewards_owed={}",
+            ctx.accounts.pool.key,
+            pool_data.is_paused,
+            pool_data.total_staked,
+            pool_data.total_rewards_owed
+        );
+        return Err(StakePoolError::PoolNotClosable.into());
+    }
+
+    // Sweep and close the vaults (with pool PDA signer)
+    let pool_seeds = StakePool::seeds(&pool_data.stake_mint, pool_data.pool_id);
+    let mut seeds_with_bump = pool_seeds.clone();
+    seeds_with_bump.push(vec![pool_data.bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    let vaults = [
+        (
+            ctx.accounts.stake_vault,
+            ctx.accounts.stake_mint,
+            ctx.accounts.stake_receiver,
+        ),
+        (
+            ctx.accounts.reward_vault,
+            ctx.accounts.reward_mint,
+            ctx.accounts.reward_receiver,
+        ),
+        (
+            ctx.accounts.fee_vault,
+            ctx.accounts.stake_mint,
+            ctx.accounts.stake_receiver,
+        ),
+    ];
+    let mut swept = [0u64; 3];
+    for (i, (vault, mint, receiver)) in vaults.into_iter().enumerate() {
+        swept[i] = sweep_and_close_vault(
+            vault,
+            mint,
+            receiver,
+            ctx.accounts.rent_receiver,
+            ctx.accounts.pool,
+            ctx.accounts.token_program,
+            &seeds_refs,
+        )?;
+    }
+
+    // Close the pool account last; the vault closures above need it as signer
+    close_account(ctx.accounts.pool, ctx.accounts.rent_receiver)?;
+
+    msg!(
+        "Closed pool {}: swept {} stake, {} reward and {} fee tokens",
+        ctx.accounts.pool.key,
+        swept[0],
+        swept[1],
+        swept[2]
+    );
+
+    // Emit event for off-chain indexing after the pool is closed
+    StakePoolEvent::PoolClosed(PoolClosedEvent {
+        pool: *ctx.accounts.pool.key,
+        admin: *ctx.accounts.admin.key,
+        stake_swept: swept[0],
+        reward_swept: swept[1],
+        fee_swept: swept[2],
+    })
+    .emit()
+}
+
+/// Transfer a vault's whole balance to `receiver`, then close the vault into
+/// `rent_receiver`. Returns the amount swept out of the vault.
+pub(super) fn sweep_and_close_vault<'a>(
+    vault: &'a AccountInfo<'a>,
+    mint: &'a AccountInfo<'a>,
+    receiver: &'a AccountInfo<'a>,
+    rent_receiver: &'a AccountInfo<'a>,
+    pool: &'a AccountInfo<'a>,
+    token_program: &'a AccountInfo<'a>,
+    pool_signer_seeds: &[&[u8]],
+) -> Result<u64, ProgramError> {
+    let balance = get_token_account_balance(vault)?;
+    if balance > 0 {
+        transfer_tokens_with_fee(
+            vault,
+            receiver,
+            mint,
+            pool,
+            token_program,
+            balance,
+            &[pool_signer_seeds],
+        )?;
+    }
+
+    invoke_signed(
+        &spl_token_2022::instruction::close_account(
+            token_program.key,
+            vault.key,
+            rent_receiver.key,
+            pool.key,
+            &[],
+        )?,
+        &[vault.clone(), rent_receiver.clone(), pool.clone()],
+        &[pool_signer_seeds],
+    )?;
+
+    Ok(balance)
+}
@@ -0,0 +1,436 @@
+//! Reward cohorts: one reward vault per cohort of stakers
+//!
+//! Pools with a `cohort_duration` group their stakes by stake time. Each cohort
+//! has its own `RewardCohort` account and token vault, owned by the pool PDA:
+//! Stake reserves a new stake's rewards in the vault of the cohort it is opened
+//! in and ClaimRewards pays them from there, so one cohort's funding never pays
+//! another cohort's rewards. `fund_cohort_rewards` creates a cohort with its
+//! first funding; `defund_cohort_rewards` withdraws its surplus and closes it
+//! once the pool owes nothing and the vault is emptied.
+
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::assertions::*;
+use crate::error::StakePoolError;
+use crate::events::{CohortRewardsDefundedEvent, CohortRewardsFundedEvent, StakePoolEvent};
+use crate::instruction::accounts::*;
+use crate::state::{Key, ProgramAuthority, RewardCohort, Role, StakePool};
+use crate::utils::{close_account, create_account, transfer_tokens_with_fee};
+
+use super::close::sweep_and_close_vault;
+use super::helpers::{
+    create_pool_vault, get_token_account_balance, verify_reward_token_accounts,
+    verify_token_account,
+};
+
+/// Load a pool's reward cohort and verify it and its vault sit at their PDAs
+pub(super) fn load_reward_cohort(
+    cohort_account: &AccountInfo,
+    cohort_vault: &AccountInfo,
+    pool: &Pubkey,
+) -> Result<RewardCohort, ProgramError> {
+    // Verify discriminator and ownership before loading (Type Cosplay protection)
+    assert_account_key("reward_cohort", cohort_account, Key::RewardCohort)?;
+    assert_program_owner("reward_cohort", cohort_account, &crate::ID)?;
+
+    let reward_cohort = RewardCohort::load(cohort_account)?;
+    if reward_cohort.pool != *pool {
+        msg!(
+            "Reward cohort {} belongs to pool {}, not {}",
+            cohort_account.key,
+            reward_cohort.pool,
+            pool
+        );
+        return Err(StakePoolError::InvalidRewardCohort.into());
+    }
+    assert_pda_with_bump(
+        "reward_cohort",
+        cohort_account,
+        &crate::ID,
+        &[
+            b"reward_cohort",
+            pool.as_ref(),
+            &reward_cohort.cohort.to_le_bytes(),
+            &[reward_cohort.bump],
+        ],
+    )?;
+    assert_pda_with_bump(
+        "cohort_vault",
+        cohort_vault,
+        &crate::ID,
+        &[
+            b"cohort_vault",
+            cohort_account.key.as_ref(),
+            &[reward_cohort.vault_bump],
+        ],
+    )?;
+    Ok(reward_cohort)
+}
+
+/// Load the reward cohort `cohort` a stake draws its rewards from
+///
+/// Fails with `InvalidRewardCohort` when the account is missing or belongs to
+/// another cohort.
+pub(super) fn load_stake_cohort(
+    cohort_account: Option<&AccountInfo>,
+    cohort_vault: &AccountInfo,
+    pool: &Pubkey,
+    cohort: u64,
+) -> Result<RewardCohort, ProgramError> {
+    let Some(cohort_account) = cohort_account else {
+        msg!(
+            "Stakes of reward cohort {} need its reward_cohort account",
+            cohort
+        );
+        return Err(StakePoolError::InvalidRewardCohort.into());
+    };
+    assert_writable("reward_cohort", cohort_account)?;
+    let reward_cohort = load_reward_cohort(cohort_account, cohort_vault, pool)?;
+    if reward_cohort.cohort != cohort {
+        msg!(
+            "Reward cohort {} was passed for a stake of cohort {}",
+            reward_cohort.cohort,
+            cohort
+        );
+        return Err(StakePoolError::InvalidRewardCohort.into());
+    }
+    Ok(reward_cohort)
+}
+
+/// Fund the vault of reward cohort `cohort`, creating the cohort on its first funding
+///
+/// Anyone may fund, or only the pool's reward funders when an allowlist is set.
+/// The funder pays the rent of a created cohort account and vault. The received
+/// amount is recorded in the cohort's and the pool's `total_rewards_funded`.
+///
+/// # Errors
+/// Returns error if:
+/// - The pool has no reward cohorts, or `cohort` is 0
+/// - The funder is not in the pool's reward funder allowlist
+/// - The cohort accounts are not at their PDAs
+pub fn fund_cohort_rewards<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    cohort: u64,
+    amount: u64,
+) -> ProgramResult {
+    // Validate amount
+    if amount == 0 {
+        msg!("Fund amount must be greater than zero");
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    // Parse accounts using ShankContext-generated struct
+    let ctx = FundCohortRewardsAccounts::context(accounts)?;
+
+    // Verify pool account discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // Load pool
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+
+    // Guards
+    assert_signer("funder", ctx.accounts.funder)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("reward_cohort", ctx.accounts.reward_cohort)?;
+    assert_writable("cohort_vault", ctx.accounts.cohort_vault)?;
+    assert_writable("funder", ctx.accounts.funder)?;
+    assert_writable("funder_token_account", ctx.accounts.funder_token_account)?;
+    assert_same_pubkeys(
+        "reward_mint",
+        ctx.accounts.reward_mint,
+        &pool_data.reward_mint,
+    )?;
+
+    if pool_data.cohort_duration == 0 || cohort == 0 {
+        msg!(
+            "Pool {} has no reward cohort {}",
+            ctx.accounts.pool.key,
+            cohort
+        );
+        return Err(StakePoolError::InvalidRewardCohort.into());
+    }
+
+    if !pool_data.is_reward_funder_allowed(ctx.accounts.funder.key) {
+        msg!(
+            "Funder {} is not in the pool's reward funder allowlist",
+            ctx.accounts.funder.key
+        );
+        return Err(StakePoolError::RewardFunderNotAllowed.into());
+    }
+
+    let created = ctx.accounts.reward_cohort.data_is_empty();
+    let mut reward_cohort = if created {
+        let (cohort_key, bump) = RewardCohort::find_pda(ctx.accounts.pool.key, cohort);
+        assert_same_pubkeys("reward_cohort", ctx.accounts.reward_cohort, &cohort_key)?;
+        let (vault_key, vault_bump) = RewardCohort::find_vault_pda(&cohort_key);
+        assert_same_pubkeys("cohort_vault", ctx.accounts.cohort_vault, &vault_key)?;
+
+        let mut seeds_with_bump = RewardCohort::seeds(ctx.accounts.pool.key, cohort);
+        seeds_with_bump.push(vec![bump]);
+        let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+        create_account(
+            ctx.accounts.reward_cohort,
+            ctx.accounts.funder,
+            ctx.accounts.system_program,
+            RewardCohort::LEN,
+            &crate::ID,
+            Some(&[&seeds_refs]),
+        )?;
+
+        let mut vault_seeds_with_bump = RewardCohort::vault_seeds(&cohort_key);
+        vault_seeds_with_bump.push(vec![vault_bump]);
+        let vault_seeds_refs: Vec<&[u8]> =
+            vault_seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+        create_pool_vault(
+            ctx.accounts.cohort_vault,
+            ctx.accounts.reward_mint,
+            ctx.accounts.pool.key,
+            ctx.accounts.funder,
+            ctx.accounts.system_program,
+            ctx.accounts.token_program,
+            &vault_seeds_refs,
+        )?;
+
+        pool_data.open_reward_cohorts = pool_data
+            .open_reward_cohorts
+            .checked_add(1)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        msg!(
+            "Reward cohort {} created for pool {}",
+            cohort,
+            ctx.accounts.pool.key
+        );
+
+        RewardCohort {
+            key: Key::RewardCohort,
+            pool: *ctx.accounts.pool.key,
+            cohort,
+            rewards_funded: 0,
+            rewards_owed: 0,
+            bump,
+            vault_bump,
+        }
+    } else {
+        let reward_cohort = load_reward_cohort(
+            ctx.accounts.reward_cohort,
+            ctx.accounts.cohort_vault,
+            ctx.accounts.pool.key,
+        )?;
+        if reward_cohort.cohort != cohort {
+            msg!(
+                "Reward cohort {} was passed to fund cohort {}",
+                reward_cohort.cohort,
+                cohort
+            );
+            return Err(StakePoolError::InvalidRewardCohort.into());
+        }
+        reward_cohort
+    };
+
+    // Verify token accounts belong to correct mints
+    verify_reward_token_accounts(
+        ctx.accounts.funder_token_account,
+        ctx.accounts.cohort_vault,
+        &pool_data.reward_mint,
+    )?;
+
+    let actual_amount = transfer_tokens_with_fee(
+        ctx.accounts.funder_token_account,
+        ctx.accounts.cohort_vault,
+        ctx.accounts.reward_mint,
+        ctx.accounts.funder,
+        ctx.accounts.token_program,
+        amount,
+        &[],
+    )?;
+
+    reward_cohort.rewards_funded = reward_cohort
+        .rewards_funded
+        .checked_add(actual_amount)
+        .ok_or(StakePoolError::NumericalOverflow)?;
+    pool_data.total_rewards_funded = pool_data
+        .total_rewards_funded
+        .checked_add(actual_amount)
+        .ok_or(StakePoolError::NumericalOverflow)?;
+
+    msg!(
+        "Funded reward cohort {} with {} reward tokens (cohort funded: {})",
+        cohort,
+        actual_amount,
+        reward_cohort.rewards_funded
+    );
+
+    // Save state first to ensure persistence before emitting event
+    reward_cohort.save(ctx.accounts.reward_cohort)?;
+    pool_data.save(ctx.accounts.pool)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::CohortRewardsFunded(CohortRewardsFundedEvent {
+        pool: *ctx.accounts.pool.key,
+        funder: *ctx.accounts.funder.key,
+        cohort,
+        amount: actual_amount,
+        created,
+    })
+    .emit()
+}
+
+/// Withdraw un-owed reward tokens from a cohort vault (global admin or treasurer)
+///
+/// Only the surplus over the cohort's `rewards_owed` can leave the vault, or
+/// the whole balance once the pool owes nothing. Emptying the vault then closes
+/// the cohort account and vault, returning their rent to the admin. The
+/// withdrawn amount is deducted from the cohort's and the pool's `total_rewards_funded`.
+///
+/// # Errors
+/// Returns error if:
+/// - The signer is neither a global admin nor a treasurer
+/// - The cohort accounts are not the pool's
+/// - `amount` exceeds the cohort vault's surplus
+pub fn defund_cohort_rewards<'a>(accounts: &'a [AccountInfo<'a>], amount: u64) -> ProgramResult {
+    // Validate amount
+    if amount == 0 {
+        msg!("Defund amount must be greater than zero");
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    // Parse accounts using ShankContext-generated struct
+    let ctx = DefundCohortRewardsAccounts::context(accounts)?;
+
+    // Verify pool account discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // Load pool and cohort
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+    let mut reward_cohort = load_reward_cohort(
+        ctx.accounts.reward_cohort,
+        ctx.accounts.cohort_vault,
+        ctx.accounts.pool.key,
+    )?;
+
+    // Load program authority to verify admin permissions
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_writable("admin", ctx.accounts.admin)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("reward_cohort", ctx.accounts.reward_cohort)?;
+    assert_writable("cohort_vault", ctx.accounts.cohort_vault)?;
+    assert_writable("destination", ctx.accounts.destination)?;
+    assert_same_pubkeys(
+        "reward_mint",
+        ctx.accounts.reward_mint,
+        &pool_data.reward_mint,
+    )?;
+
+    // Verify the signer is a global admin or a treasurer
+    if !program_authority.has_role(ctx.accounts.admin.key, Role::Treasurer) {
+        msg!(
+            "Unauthorized: {} is not a global admin or treasurer",
+            ctx.accounts.admin.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    // Verify token accounts belong to the reward mint
+    verify_token_account(
+        ctx.accounts.cohort_vault,
+        &pool_data.reward_mint,
+        None,
+        None,
+    )?;
+    verify_token_account(ctx.accounts.destination, &pool_data.reward_mint, None, None)?;
+
+    let vault_balance = get_token_account_balance(ctx.accounts.cohort_vault)?;
+    let pool_owes_nothing = pool_data.total_rewards_owed == 0;
+    let surplus = reward_cohort.surplus(vault_balance, pool_owes_nothing);
+    if amount > surplus {
+        msg!(
+            "Defund exceeds surplus. Requested: {}, Surplus: {} (balance: {}, owed: {})",
+            amount,
+            surplus,
+            vault_balance,
+            reward_cohort.rewards_owed
+        );
+        return Err(StakePoolError::DefundExceedsSurplus.into());
+    }
+
+    // Transfer surplus (with pool PDA signer)
+    let pool_seeds = StakePool::seeds(&pool_data.stake_mint, pool_data.pool_id);
+    let mut seeds_with_bump = pool_seeds.clone();
+    seeds_with_bump.push(vec![pool_data.bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    // No stake holds a reservation once the pool owes nothing
+    if pool_owes_nothing {
+        reward_cohort.rewards_owed = 0;
+    }
+    let closed = pool_owes_nothing && amount == vault_balance;
+    if closed {
+        sweep_and_close_vault(
+            ctx.accounts.cohort_vault,
+            ctx.accounts.reward_mint,
+            ctx.accounts.destination,
+            ctx.accounts.admin,
+            ctx.accounts.pool,
+            ctx.accounts.token_program,
+            &seeds_refs,
+        )?;
+        close_account(ctx.accounts.reward_cohort, ctx.accounts.admin)?;
+        pool_data.open_reward_cohorts = pool_data.open_reward_cohorts.saturating_sub(1);
+    } else {
+        transfer_tokens_with_fee(
+            ctx.accounts.cohort_vault,
+            ctx.accounts.destination,
+            ctx.accounts.reward_mint,
+            ctx.accounts.pool,
+            ctx.accounts.token_program,
+            amount,
+            &[&seeds_refs],
+        )?;
+    }
+
+    // The vault is debited the full amount, transfer fees are borne by the destination
+    reward_cohort.rewards_funded = reward_cohort.rewards_funded.saturating_sub(amount);
+    pool_data.total_rewards_funded = pool_data.total_rewards_funded.saturating_sub(amount);
+
+    msg!(
+        "Defunded {} reward tokens of cohort {} to {}{}",
+        amount,
+        reward_cohort.cohort,
+        ctx.accounts.destination.key,
+        if closed { " and closed the cohort" } else { "" }
+    );
+
+    // Save state first to ensure persistence before emitting event
+    if !closed {
+        reward_cohort.save(ctx.accounts.reward_cohort)?;
+    }
+    pool_data.save(ctx.accounts.pool)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::CohortRewardsDefunded(CohortRewardsDefundedEvent {
+        pool: *ctx.accounts.pool.key,
+        admin: *ctx.accounts.admin.key,
+        destination: *ctx.accounts.destination.key,
+        cohort: reward_cohort.cohort,
+        amount,
+        closed,
+    })
+    .emit()
+}
@@ -0,0 +1,162 @@
+//! Deployment-wide program configuration
+//!
+//! The ProgramConfig PDA records the program version and enabled feature flags
+//! for clients, and holds the reward rate and lockup limits InitializePool and
+//! pool updates validate against, so a deployment can tighten them without
+//! redeploying the program.
+
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
+
+use crate::assertions::*;
+use crate::constants::PROGRAM_VERSION;
+use crate::error::StakePoolError;
+use crate::events::{ProgramConfigUpdatedEvent, StakePoolEvent};
+use crate::instruction::accounts::*;
+use crate::state::{Key, ProgramAuthority, ProgramConfig};
+use crate::utils::create_account;
+
+use super::approval::load_program_authority;
+
+/// Fail unless `authority` is the program authority
+fn assert_program_authority(
+    program_authority: &ProgramAuthority,
+    authority: &AccountInfo,
+) -> ProgramResult {
+    if authority.key != &program_authority.authority {
+        msg!(
+            "Unauthorized: {} is not the program authority",
+            authority.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+    Ok(())
+}
+
+fn emit_config_updated(authority: &Pubkey, config: &ProgramConfig) -> ProgramResult {
+    StakePoolEvent::ProgramConfigUpdated(ProgramConfigUpdatedEvent {
+        authority: *authority,
+        version: config.version,
+        feature_flags: config.feature_flags,
+        max_reward_rate: config.max_reward_rate,
+        min_lockup_period: config.min_lockup_period,
+    })
+    .emit()
+}
+
+/// Create the program config (program authority only)
+///
+/// # Errors
+/// Returns error if:
+/// - Caller is not the program authority
+/// - The config account is not the config PDA or already exists
+/// - The limits loosen MAX_REWARD_RATE or MIN_LOCKUP_PERIOD
+pub fn initialize_program_config<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    feature_flags: u64,
+    max_reward_rate: u64,
+    min_lockup_period: i64,
+) -> ProgramResult {
+    let ctx = InitializeProgramConfigAccounts::context(accounts)?;
+
+    let program_authority = load_program_authority(ctx.accounts.program_authority)?;
+    let (config_key, bump) = ProgramConfig::find_pda();
+
+    // Guards
+    assert_same_pubkeys("program_config", ctx.accounts.program_config, &config_key)?;
+    assert_signer("authority", ctx.accounts.authority)?;
+    assert_signer("payer", ctx.accounts.payer)?;
+    assert_empty("program_config", ctx.accounts.program_config)?;
+    assert_writable("program_config", ctx.accounts.program_config)?;
+    assert_writable("payer", ctx.accounts.payer)?;
+    assert_program_authority(&program_authority, ctx.accounts.authority)?;
+
+    let mut config = ProgramConfig {
+        key: Key::ProgramConfig,
+        feature_flags,
+        bump,
+        ..ProgramConfig::default()
+    };
+    config.set_limits(max_reward_rate, min_lockup_period)?;
+
+    let mut seeds_with_bump = ProgramConfig::seeds();
+    seeds_with_bump.push(vec![bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    create_account(
+        ctx.accounts.program_config,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ProgramConfig::LEN,
+        &crate::ID,
+        Some(&[&seeds_refs]),
+    )?;
+
+    msg!(
+        "Program config initialized: version {:?}, flags {:#x}, max reward rate {}, min lockup {}",
+        config.version,
+        config.feature_flags,
+        config.max_reward_rate,
+        config.min_lockup_period
+    );
+
+    // Save state first to ensure persistence before emitting event
+    config.save(ctx.accounts.program_config)?;
+
+    // Emit event for off-chain indexing after successful state save
+    emit_config_updated(ctx.accounts.authority.key, &config)
+}
+
+/// Change the program config and record the current program version (program authority only)
+///
+/// # Errors
+/// Returns error if:
+/// - Caller is not the program authority
+/// - The config account is not the initialized config PDA
+/// - The limits loosen MAX_REWARD_RATE or MIN_LOCKUP_PERIOD
+pub fn update_program_config<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    feature_flags: Option<u64>,
+    max_reward_rate: Option<u64>,
+    min_lockup_period: Option<i64>,
+) -> ProgramResult {
+    let ctx = UpdateProgramConfigAccounts::context(accounts)?;
+
+    let program_authority = load_program_authority(ctx.accounts.program_authority)?;
+
+    // Verify discriminator and ownership before loading (Type Cosplay protection)
+    assert_account_key(
+        "program_config",
+        ctx.accounts.program_config,
+        Key::ProgramConfig,
+    )?;
+    assert_program_owner("program_config", ctx.accounts.program_config, &crate::ID)?;
+    let mut config = ProgramConfig::load(ctx.accounts.program_config)?;
+
+    // Guards
+    assert_signer("authority", ctx.accounts.authority)?;
+    assert_writable("program_config", ctx.accounts.program_config)?;
+    assert_program_authority(&program_authority, ctx.accounts.authority)?;
+
+    if let Some(feature_flags) = feature_flags {
+        config.feature_flags = feature_flags;
+    }
+    config.set_limits(
+        max_reward_rate.unwrap_or(config.max_reward_rate),
+        min_lockup_period.unwrap_or(config.min_lockup_period),
+    )?;
+    config.version = PROGRAM_VERSION;
+
+    msg!(
+        "Program config updated: version {:?}, flags {:#x}, max reward rate {}, min lockup {}",
+        config.version,
+        config.feature_flags,
+        config.max_reward_rate,
+        config.min_lockup_period
+    );
+
+    // Save state first to ensure persistence before emitting event
+    config.save(ctx.accounts.program_config)?;
+
+    // Emit event for off-chain indexing after successful state save
+    emit_config_updated(ctx.accounts.authority.key, &config)
+}
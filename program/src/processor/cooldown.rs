@@ -0,0 +1,256 @@
+//! Two-step unstaking for pools with an unstake cooldown
+//!
+//! When a pool's `unstake_cooldown` is non-zero, `Unstake` is disabled and
+//! principal leaves in two steps: `request_unstake` moves it out of the position
+//! into the stake account's pending withdrawal (it stops earning rewards right
+//! away), and `withdraw_unstaked` pays it out once the cooldown has passed. The
+//! pending amount stays in the stake vault and in `total_staked` until then.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+use crate::assertions::*;
+use crate::error::StakePoolError;
+use crate::events::{StakePoolEvent, UnstakeRequestedEvent, UnstakeWithdrawnEvent};
+use crate::instruction::accounts::*;
+use crate::state::{Key, PoolVault, StakeAccount, StakePool, UserOperation};
+use crate::utils::transfer_tokens_with_fee;
+
+use super::helpers::{
+    check_global_pause, get_token_account_owner, load_mint_registry, resolve_deny_list,
+    validate_current_timestamp, verify_stake_token_accounts, verify_token_account,
+};
+use super::receipt::ReceiptAccounts;
+
+/// Start the unstake cooldown for `amount` of a stake account's principal (owner only)
+pub fn request_unstake<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    amount: u64,
+    expected_reward_rate: Option<u64>,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = RequestUnstakeAccounts::context(accounts)?;
+
+    // Validate amount
+    if amount == 0 {
+        msg!("Unstake amount must be greater than zero");
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    // Verify account discriminators before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+
+    // Load accounts
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+
+    // Frontrunning protection: Verify expected reward rate if provided
+    if let Some(expected_rate) = expected_reward_rate {
+        if pool_data.reward_rate != expected_rate {
+            msg!(
+                "Reward rate mismatch: expected {}, got {}",
+                expected_rate,
+                pool_data.reward_rate
+            );
+            return Err(StakePoolError::PoolParametersChanged.into());
+        }
+    }
+
+    // Guards
+    assert_signer("owner", ctx.accounts.owner)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("stake_account", ctx.accounts.stake_account)?;
+    assert_same_pubkeys("owner", ctx.accounts.owner, &stake_account_data.owner)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
+    check_global_pause(ctx.accounts.program_authority, UserOperation::Withdrawal)?;
+    let receipt = ReceiptAccounts::resolve(
+        &pool_data,
+        ctx.accounts.receipt_mint,
+        ctx.accounts.user_receipt_account,
+        ctx.accounts.receipt_token_program,
+        ctx.accounts.owner.key,
+    )?;
+
+    let clock = Clock::get()?;
+    validate_current_timestamp(clock.unix_timestamp)?;
+
+    let forfeited_rewards =
+        pool_data.request_unstake(&mut stake_account_data, amount, clock.unix_timestamp)?;
+    let withdrawable_at = clock
+        .unix_timestamp
+        .checked_add(pool_data.unstake_cooldown)
+        .ok_or(StakePoolError::NumericalOverflow)?;
+
+    // Receipts are burned when the principal leaves the position, so they cannot
+    // be transferred away while the withdrawal is pending
+    if let Some(receipt) = &receipt {
+        receipt.burn(ctx.accounts.owner, amount)?;
+    }
+
+    msg!(
+        "Requested unstake of {} tokens (pending: {}), forfeited {} reward tokens, withdrawable at {}",
+        amount,
+        stake_account_data.pending_withdraw_amount,
+        forfeited_rewards,
+        withdrawable_at
+    );
+
+    // Save state first to ensure persistence before emitting event
+    pool_data.save(ctx.accounts.pool)?;
+    stake_account_data.save(ctx.accounts.stake_account)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::UnstakeRequested(UnstakeRequestedEvent {
+        pool: *ctx.accounts.pool.key,
+        stake_account: *ctx.accounts.stake_account.key,
+        amount,
+        pending_amount: stake_account_data.pending_withdraw_amount,
+        forfeited_rewards,
+        withdrawable_at,
+        active_stake_count: pool_data.active_stake_count,
+    })
+    .emit()
+}
+
+/// Pay out a stake account's pending withdrawal after the cooldown (owner only)
+pub fn withdraw_unstaked<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = WithdrawUnstakedAccounts::context(accounts)?;
+
+    // Verify account discriminators before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+
+    // Load accounts
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+
+    // Guards
+    assert_signer("owner", ctx.accounts.owner)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("stake_account", ctx.accounts.stake_account)?;
+    assert_writable("user_token_account", ctx.accounts.user_token_account)?;
+    assert_writable("stake_vault", ctx.accounts.stake_vault)?;
+    assert_writable("fee_vault", ctx.accounts.fee_vault)?;
+    assert_writable("mint_registry", ctx.accounts.mint_registry)?;
+    assert_same_pubkeys("owner", ctx.accounts.owner, &stake_account_data.owner)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
+    assert_vault(
+        "stake_vault",
+        ctx.accounts.stake_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Stake,
+    )?;
+    assert_vault(
+        "fee_vault",
+        ctx.accounts.fee_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Fee,
+    )?;
+    assert_same_pubkeys("stake_mint", ctx.accounts.stake_mint, &pool_data.stake_mint)?;
+    check_global_pause(ctx.accounts.program_authority, UserOperation::Withdrawal)?;
+
+    // Verify token accounts belong to correct mints
+    verify_stake_token_accounts(
+        ctx.accounts.user_token_account,
+        ctx.accounts.stake_vault,
+        &pool_data.stake_mint,
+    )?;
+    verify_token_account(ctx.accounts.fee_vault, &pool_data.stake_mint, None, None)?;
+    let mut mint_registry = load_mint_registry(ctx.accounts.mint_registry, &pool_data.stake_mint)?;
+    if let Some(deny_list) = resolve_deny_list(&pool_data, ctx.accounts.deny_list)? {
+        deny_list.check_withdrawal(
+            &stake_account_data.owner,
+            &get_token_account_owner(ctx.accounts.user_token_account)?,
+        )?;
+    }
+
+    let clock = Clock::get()?;
+    validate_current_timestamp(clock.unix_timestamp)?;
+
+    let amount =
+        pool_data.complete_unstake_request(&mut stake_account_data, clock.unix_timestamp)?;
+    pool_data.record_unstake_outflow(amount, clock.unix_timestamp)?;
+    mint_registry.record_unstake(amount);
+
+    // Transfer tokens (with PDA signer)
+    let pool_seeds = StakePool::seeds(&pool_data.stake_mint, pool_data.pool_id);
+    let mut seeds_with_bump = pool_seeds.clone();
+    seeds_with_bump.push(vec![pool_data.bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    // Withdrawal fee is routed to the fee vault; the owner receives the rest
+    let withdraw_fee = pool_data.withdraw_fee(amount)?;
+    let owner_amount = amount
+        .checked_sub(withdraw_fee)
+        .ok_or(StakePoolError::NumericalOverflow)?;
+
+    let fee_received = if withdraw_fee > 0 {
+        transfer_tokens_with_fee(
+            ctx.accounts.stake_vault,
+            ctx.accounts.fee_vault,
+            ctx.accounts.stake_mint,
+            ctx.accounts.pool,
+            ctx.accounts.token_program,
+            withdraw_fee,
+            &[&seeds_refs],
+        )?
+    } else {
+        0
+    };
+
+    let actual_amount = transfer_tokens_with_fee(
+        ctx.accounts.stake_vault,
+        ctx.accounts.user_token_account,
+        ctx.accounts.stake_mint,
+        ctx.accounts.pool,
+        ctx.accounts.token_program,
+        owner_amount,
+        &[&seeds_refs],
+    )?;
+
+    msg!(
+        "Withdrew {} unstaked tokens (actual: {}, withdraw fee: {})",
+        amount,
+        actual_amount,
+        withdraw_fee
+    );
+
+    // Save state first to ensure persistence before emitting event
+    pool_data.save(ctx.accounts.pool)?;
+    stake_account_data.save(ctx.accounts.stake_account)?;
+    mint_registry.save(ctx.accounts.mint_registry)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::UnstakeWithdrawn(UnstakeWithdrawnEvent {
+        pool: *ctx.accounts.pool.key,
+        stake_account: *ctx.accounts.stake_account.key,
+        owner: *ctx.accounts.owner.key,
+        amount: actual_amount,
+        fee: fee_received,
+    })
+    .emit()
+}
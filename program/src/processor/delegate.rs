@@ -0,0 +1,61 @@
+//! Stake account delegates
+//!
+//! An owner may name one delegate per stake account, typically a custodial
+//! wallet or an automation bot. The delegate can call `claim_rewards` for the
+//! stake account, but only into a reward token account owned by the owner.
+
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
+
+use crate::assertions::*;
+use crate::events::{StakeDelegateUpdatedEvent, StakePoolEvent};
+use crate::instruction::accounts::*;
+use crate::state::{Key, StakeAccount};
+
+/// Set or clear the delegate of a stake account (owner only)
+pub fn set_stake_delegate<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    delegate: Option<Pubkey>,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = SetStakeDelegateAccounts::context(accounts)?;
+
+    // Verify stake account discriminator before loading (Type Cosplay protection)
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+
+    // Verify program ownership
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+
+    // Load stake account
+    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+
+    // Guards
+    assert_signer("owner", ctx.accounts.owner)?;
+    assert_writable("stake_account", ctx.accounts.stake_account)?;
+    assert_same_pubkeys("owner", ctx.accounts.owner, &stake_account_data.owner)?;
+
+    stake_account_data.delegate = delegate;
+    stake_account_data.save(ctx.accounts.stake_account)?;
+
+    match delegate {
+        Some(delegate) => msg!(
+            "Delegate of stake account {} set to {}",
+            ctx.accounts.stake_account.key,
+            delegate
+        ),
+        None => msg!(
+            "Delegate of stake account {} cleared",
+            ctx.accounts.stake_account.key
+        ),
+    }
+
+    // Emit event for off-chain indexing
+    StakePoolEvent::StakeDelegateUpdated(StakeDelegateUpdatedEvent {
+        stake_account: *ctx.accounts.stake_account.key,
+        delegate,
+    })
+    .emit()
+}
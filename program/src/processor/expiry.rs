@@ -0,0 +1,193 @@
+//! Expiry of rewards left unclaimed on abandoned stake accounts
+//!
+//! Rewards stay reserved in `total_rewards_owed` until their stake is claimed or
+//! unstaked. When an owner never comes back, anyone may call `expire_rewards`
+//! once `REWARD_EXPIRY_PERIOD` has passed since the lockup completed: the
+//! reservation moves to the pool's `expired_rewards`, which governance withdraws
+//! with `claim_expired_rewards`. The owner's principal is never touched.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+use crate::assertions::*;
+use crate::error::StakePoolError;
+use crate::events::{RewardsExpiredEvent, StakePoolEvent, VaultWithdrawalEvent};
+use crate::instruction::accounts::*;
+use crate::state::{Key, PoolVault, ProgramAuthority, StakeAccount, StakePool};
+use crate::utils::transfer_tokens_with_fee;
+
+use super::helpers::{get_token_account_balance, validate_current_timestamp, verify_token_account};
+
+/// Move the unclaimed rewards of an abandoned stake account to the pool's expired rewards
+///
+/// Permissionless: the expiry period is the authorization.
+pub fn expire_rewards<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = ExpireRewardsAccounts::context(accounts)?;
+
+    // Verify account discriminators before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+
+    // Load accounts
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+
+    // Guards
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("stake_account", ctx.accounts.stake_account)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
+
+    let clock = Clock::get()?;
+    validate_current_timestamp(clock.unix_timestamp)?;
+
+    let expired = pool_data.expire_stake_rewards(&mut stake_account_data, clock.unix_timestamp)?;
+
+    msg!(
+        "Expired {} unclaimed reward tokens of stake account {} (pool expired total: {})",
+        expired,
+        ctx.accounts.stake_account.key,
+        pool_data.expired_rewards
+    );
+
+    // Save state first to ensure persistence before emitting event
+    pool_data.save(ctx.accounts.pool)?;
+    stake_account_data.save(ctx.accounts.stake_account)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::RewardsExpired(RewardsExpiredEvent {
+        pool: *ctx.accounts.pool.key,
+        stake_account: *ctx.accounts.stake_account.key,
+        amount: expired,
+    })
+    .emit()
+}
+
+/// Withdraw all of the pool's expired rewards from the reward vault (global admin only)
+///
+/// The withdrawn amount is deducted from `total_rewards_funded`, like a defund.
+pub fn claim_expired_rewards<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = ClaimExpiredRewardsAccounts::context(accounts)?;
+
+    // Verify pool account discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // Load pool
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+
+    // Load program authority to verify admin permissions
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("reward_vault", ctx.accounts.reward_vault)?;
+    assert_writable("destination", ctx.accounts.destination)?;
+    assert_vault(
+        "reward_vault",
+        ctx.accounts.reward_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Reward,
+    )?;
+    assert_same_pubkeys(
+        "reward_mint",
+        ctx.accounts.reward_mint,
+        &pool_data.reward_mint,
+    )?;
+
+    // Verify the signer is authorized as a global admin
+    if !program_authority.is_authorized(ctx.accounts.admin.key) {
+        msg!(
+            "Unauthorized: {} is not a global admin",
+            ctx.accounts.admin.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    // Verify token accounts belong to the reward mint
+    verify_token_account(
+        ctx.accounts.reward_vault,
+        &pool_data.reward_mint,
+        None,
+        None,
+    )?;
+    verify_token_account(ctx.accounts.destination, &pool_data.reward_mint, None, None)?;
+
+    let amount = pool_data.expired_rewards;
+    if amount == 0 {
+        msg!("Pool has no expired rewards to claim");
+        return Err(StakePoolError::NoExpiredRewards.into());
+    }
+
+    // Expired rewards left total_rewards_owed, so the vault must still hold them on top of it
+    let reward_vault_balance = get_token_account_balance(ctx.accounts.reward_vault)?;
+    if reward_vault_balance.saturating_sub(pool_data.reward_vault_owed()) < amount {
+        msg!(
+            "Reward vault cannot cover expired rewards. Expired: {}, Balance: {}, Owed: {}",
+            amount,
+            reward_vault_balance,
+            pool_data.reward_vault_owed()
+        );
+        return Err(StakePoolError::InsufficientRewards.into());
+    }
+
+    // Transfer expired rewards (with pool PDA signer)
+    let pool_seeds = StakePool::seeds(&pool_data.stake_mint, pool_data.pool_id);
+    let mut seeds_with_bump = pool_seeds.clone();
+    seeds_with_bump.push(vec![pool_data.bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    let actual_amount = transfer_tokens_with_fee(
+        ctx.accounts.reward_vault,
+        ctx.accounts.destination,
+        ctx.accounts.reward_mint,
+        ctx.accounts.pool,
+        ctx.accounts.token_program,
+        amount,
+        &[&seeds_refs],
+    )?;
+
+    pool_data.expired_rewards = 0;
+    pool_data.total_rewards_funded = pool_data.total_rewards_funded.saturating_sub(amount);
+
+    msg!(
+        "Claimed {} expired reward tokens (actual: {}) to {}",
+        amount,
+        actual_amount,
+        ctx.accounts.destination.key
+    );
+
+    // Save state first to ensure persistence before emitting event
+    pool_data.save(ctx.accounts.pool)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::ExpiredRewardsClaimed(VaultWithdrawalEvent {
+        pool: *ctx.accounts.pool.key,
+        admin: *ctx.accounts.admin.key,
+        destination: *ctx.accounts.destination.key,
+        amount,
+    })
+    .emit()
+}
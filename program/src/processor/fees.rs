@@ -0,0 +1,229 @@
+//! Deposit and withdrawal fee collection
+//!
+//! `stake` and `unstake` route the pool's `deposit_fee_bps` / `withdraw_fee_bps`
+//! share of each transfer into the pool's fee vault. Global admins sweep the
+//! collected fees out of the fee vault with `withdraw_fees`.
+//!
+//! Tokens that reach the stake vault without being staked (rounding left by
+//! Token-2022 transfer fees, stray deposits) are not part of `total_staked`.
+//! `sweep_dust` moves them to a treasury account so the vault balance matches
+//! `total_staked` exactly.
+
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::assertions::*;
+use crate::error::StakePoolError;
+use crate::events::{StakePoolEvent, VaultWithdrawalEvent};
+use crate::instruction::accounts::*;
+use crate::state::{Key, PoolVault, ProgramAuthority, Role, StakePool};
+use crate::utils::transfer_tokens_with_fee;
+
+use super::helpers::{get_token_account_balance, verify_token_account};
+
+/// Withdraw collected fees from the pool's fee vault (global admin or treasurer)
+///
+/// # Arguments
+/// * `accounts` - Accounts required for the instruction
+/// * `amount` - Amount of stake tokens to withdraw from the fee vault
+///
+/// # Errors
+/// Returns error if:
+/// - The signer is neither a global admin nor a treasurer
+/// - The fee vault does not match the pool's fee vault
+/// - The fee vault balance is lower than `amount`
+pub fn withdraw_fees<'a>(accounts: &'a [AccountInfo<'a>], amount: u64) -> ProgramResult {
+    // Validate amount
+    if amount == 0 {
+        msg!("Withdraw amount must be greater than zero");
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    // Parse accounts using ShankContext-generated struct
+    let ctx = WithdrawFeesAccounts::context(accounts)?;
+
+    // Verify pool account discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // Load pool
+    let pool_data = StakePool::load(ctx.accounts.pool)?;
+
+    // Load program authority to verify admin permissions
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_writable("fee_vault", ctx.accounts.fee_vault)?;
+    assert_writable("destination", ctx.accounts.destination)?;
+    assert_vault(
+        "fee_vault",
+        ctx.accounts.fee_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Fee,
+    )?;
+    assert_same_pubkeys("stake_mint", ctx.accounts.stake_mint, &pool_data.stake_mint)?;
+
+    // Verify the signer is a global admin or a treasurer
+    if !program_authority.has_role(ctx.accounts.admin.key, Role::Treasurer) {
+        msg!(
+            "Unauthorized: {} is not a global admin or treasurer",
+            ctx.accounts.admin.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    // Verify token accounts belong to the stake mint
+    verify_token_account(ctx.accounts.fee_vault, &pool_data.stake_mint, None, None)?;
+    verify_token_account(ctx.accounts.destination, &pool_data.stake_mint, None, None)?;
+
+    let fee_vault_balance = get_token_account_balance(ctx.accounts.fee_vault)?;
+    if fee_vault_balance < amount {
+        msg!(
+            "Insufficient fees collected. Requested: {}, Available: {}",
+            amount,
+            fee_vault_balance
+        );
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    // Transfer fees (with pool PDA signer)
+    let pool_seeds = StakePool::seeds(&pool_data.stake_mint, pool_data.pool_id);
+    let mut seeds_with_bump = pool_seeds.clone();
+    seeds_with_bump.push(vec![pool_data.bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    let actual_amount = transfer_tokens_with_fee(
+        ctx.accounts.fee_vault,
+        ctx.accounts.destination,
+        ctx.accounts.stake_mint,
+        ctx.accounts.pool,
+        ctx.accounts.token_program,
+        amount,
+        &[&seeds_refs],
+    )?;
+
+    msg!(
+        "Withdrew {} fee tokens (actual: {}) to {}",
+        amount,
+        actual_amount,
+        ctx.accounts.destination.key
+    );
+
+    // Emit event for off-chain indexing
+    // Note: withdraw_fees doesn't modify pool state, so event can be emitted immediately
+    StakePoolEvent::FeesWithdrawn(VaultWithdrawalEvent {
+        pool: *ctx.accounts.pool.key,
+        admin: *ctx.accounts.admin.key,
+        destination: *ctx.accounts.destination.key,
+        amount: actual_amount,
+    })
+    .emit()
+}
+
+/// Move the stake vault balance above `total_staked` to a treasury account (global admin or treasurer)
+///
+/// # Errors
+/// Returns error if:
+/// - The signer is neither a global admin nor a treasurer
+/// - The stake vault does not match the pool's stake vault
+/// - The stake vault holds no more than `total_staked`
+pub fn sweep_dust<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = SweepDustAccounts::context(accounts)?;
+
+    // Verify pool account discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // Load pool
+    let pool_data = StakePool::load(ctx.accounts.pool)?;
+
+    // Load program authority to verify admin permissions
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_writable("stake_vault", ctx.accounts.stake_vault)?;
+    assert_writable("treasury", ctx.accounts.treasury)?;
+    assert_vault(
+        "stake_vault",
+        ctx.accounts.stake_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Stake,
+    )?;
+    assert_same_pubkeys("stake_mint", ctx.accounts.stake_mint, &pool_data.stake_mint)?;
+
+    // Verify the signer is a global admin or a treasurer
+    if !program_authority.has_role(ctx.accounts.admin.key, Role::Treasurer) {
+        msg!(
+            "Unauthorized: {} is not a global admin or treasurer",
+            ctx.accounts.admin.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    // Verify token accounts belong to the stake mint
+    verify_token_account(ctx.accounts.stake_vault, &pool_data.stake_mint, None, None)?;
+    verify_token_account(ctx.accounts.treasury, &pool_data.stake_mint, None, None)?;
+
+    let stake_vault_balance = get_token_account_balance(ctx.accounts.stake_vault)?;
+    let dust = pool_data.stake_surplus(stake_vault_balance);
+    if dust == 0 {
+        msg!(
+            "No dust to sweep. Stake vault: {}, Total staked: {}",
+            stake_vault_balance,
+            pool_data.total_staked
+        );
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    // Transfer the dust (with pool PDA signer)
+    let pool_seeds = StakePool::seeds(&pool_data.stake_mint, pool_data.pool_id);
+    let mut seeds_with_bump = pool_seeds.clone();
+    seeds_with_bump.push(vec![pool_data.bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    let actual_amount = transfer_tokens_with_fee(
+        ctx.accounts.stake_vault,
+        ctx.accounts.treasury,
+        ctx.accounts.stake_mint,
+        ctx.accounts.pool,
+        ctx.accounts.token_program,
+        dust,
+        &[&seeds_refs],
+    )?;
+
+    msg!(
+        "Swept {} dust tokens (actual: {}) to {}; stake vault now holds total_staked={}",
+        dust,
+        actual_amount,
+        ctx.accounts.treasury.key,
+        pool_data.total_staked
+    );
+
+    // Emit event for off-chain indexing
+    // Note: sweep_dust doesn't modify pool state, so event can be emitted immediately
+    StakePoolEvent::DustSwept(VaultWithdrawalEvent {
+        pool: *ctx.accounts.pool.key,
+        admin: *ctx.accounts.admin.key,
+        destination: *ctx.accounts.treasury.key,
+        amount: actual_amount,
+    })
+    .emit()
+}
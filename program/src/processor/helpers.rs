@@ -1,10 +1,30 @@
-use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke,
+    program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+use spl_associated_token_account_client::{
+    address::get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
 use spl_token_2022::{
     extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    instruction::initialize_account3,
     state::{Account as TokenAccount, Mint},
 };
 
+use crate::assertions::{
+    assert_account_key, assert_pda_with_bump, assert_program_owner, assert_same_pubkeys,
+    assert_writable,
+};
+use crate::constants::{MAX_MEMO_LEN, MAX_MERKLE_PROOF_LEN, NATIVE_MINT};
 use crate::error::StakePoolError;
+use crate::events::{KeeperBountyPaidEvent, PoolRewardsCapReachedEvent, StakePoolEvent};
+use crate::merkle;
+use crate::state::{
+    DenyList, HookAllowlist, Key, MintRegistry, PoolAllowlist, PoolRegistry, PoolRegistryEntry,
+    ProgramAuthority, ProgramConfig, StakePool, StakePosition, UserOperation, UserPoints,
+};
+use crate::utils::{create_account, transfer_lamports_from_pdas};
 
 /// Minimum valid Unix timestamp (Jan 1, 2021)
 /// Timestamps before this indicate clock misconfiguration for this modern blockchain
@@ -22,6 +42,38 @@ pub fn validate_current_timestamp(timestamp: i64) -> Result<(), ProgramError> {
     Ok(())
 }
 
+/// Fails once `current_time` is past `deadline`, a Unix timestamp set by the signer
+///
+/// Bounds how long a transaction may linger before landing, so it cannot execute
+/// against a parameter change or pool end that happened in the meantime. Nothing
+/// is checked without a deadline.
+///
+/// # Errors
+/// Returns DeadlineExceeded, with an `ErrorContext` event, after the deadline
+pub fn check_deadline(
+    instruction: &str,
+    deadline: Option<i64>,
+    current_time: i64,
+) -> Result<(), ProgramError> {
+    let Some(deadline) = deadline else {
+        return Ok(());
+    };
+    if current_time > deadline {
+        msg!(
+            "Transaction deadline {} passed (current time: {})",
+            deadline,
+            current_time
+        );
+        return Err(StakePoolError::DeadlineExceeded.with_context(
+            instruction,
+            "deadline",
+            deadline,
+            current_time,
+        ));
+    }
+    Ok(())
+}
+
 /// Validates that a stored timestamp is reasonable and not in the future
 ///
 /// This checks for both data corruption (stored timestamp is too old) and
@@ -99,8 +151,20 @@ pub fn validate_future_allowed_timestamp(timestamp: i64) -> Result<(), ProgramEr
 /// 4. **DefaultAccountState (Frozen)**: Accounts could be created in frozen state,
 ///    preventing any token movement.
 ///
-/// Note: TransferFeeConfig is now properly supported as transfer_tokens_with_fee()
-/// correctly determines the actual transferred amount after fees via balance checking.
+/// 5. **ConfidentialTransferMint / ConfidentialTransferFeeConfig**: Confidential
+///    transfers move tokens through encrypted pending and available balances that
+///    the public `amount` of a token account does not include. Vault balance checks,
+///    `total_staked` and reward funding all read the public amount, so confidential
+///    deposits and withdrawals would go unaccounted. The protocol only supports
+///    public balances, so these mints are rejected.
+///
+/// 6. **InterestBearingConfig**: Interest accrues only in the UI amount, while the
+///    raw amounts the protocol stakes, pays and records stay fixed. Reward rates and
+///    caps configured in UI terms would silently diverge from what stakers see, so
+///    these mints are rejected rather than given scaled-amount accounting.
+///
+/// Note: TransferFeeConfig is supported as transfer_tokens_with_fee() computes the
+/// epoch fee and confirms the amount received via balance checking.
 ///
 /// # Arguments
 /// * `mint_account` - The mint account to validate (can be Token or Token-2022)
@@ -129,6 +193,11 @@ pub fn validate_token_extensions(
         ExtensionType::MintCloseAuthority,
         ExtensionType::DefaultAccountState,
         ExtensionType::NonTransferable, // Tokens that can never be transferred would lock user funds
+        // Encrypted balances are invisible to the vault accounting
+        ExtensionType::ConfidentialTransferMint,
+        ExtensionType::ConfidentialTransferFeeConfig,
+        // Raw amounts diverge from the displayed, interest-scaled amounts
+        ExtensionType::InterestBearingConfig,
     ];
 
     // Check if any dangerous extensions are present
@@ -158,8 +227,8 @@ pub fn validate_token_extensions(
 /// * `token_account` - The token account to verify (vault or user account)
 /// * `expected_mint` - The expected mint pubkey
 /// * `mint_account` - Optional mint account to validate for dangerous Token-2022 extensions.
-///                     Should be provided during pool initialization to ensure safety.
-///                     Can be None for runtime operations after the pool is already validated.
+///   Should be provided during pool initialization to ensure safety.
+///   Can be None for runtime operations after the pool is already validated.
 /// * `mint_name` - Optional name for error messages (required if mint_account is Some)
 ///
 /// # Returns
@@ -233,6 +302,55 @@ pub fn verify_vault_ownership(
     Ok(())
 }
 
+/// Verify that a user-supplied token account belongs to the expected wallet
+///
+/// Used for destination accounts that receive tokens on behalf of a specific
+/// wallet (e.g. a referrer), so rewards cannot be redirected to an arbitrary account.
+///
+/// # Arguments
+/// * `token_account` - The token account to validate
+/// * `expected_owner` - The wallet that must own the token account
+/// * `account_name` - Name for error messaging (e.g., "referrer_reward_account")
+pub fn verify_token_account_owner(
+    token_account: &AccountInfo,
+    expected_owner: &Pubkey,
+    account_name: &str,
+) -> Result<(), ProgramError> {
+    let account_data = token_account.try_borrow_data()?;
+
+    // Support both Token and Token-2022
+    let account = StateWithExtensions::<TokenAccount>::unpack(&account_data)
+        .map_err(|_| StakePoolError::InvalidTokenProgram)?;
+
+    if &account.base.owner != expected_owner {
+        msg!(
+            "{} owner mismatch. Expected: {}, Got: {}",
+            account_name,
+            expected_owner,
+            account.base.owner
+        );
+        return Err(StakePoolError::InvalidTokenAccountOwner.into());
+    }
+
+    Ok(())
+}
+
+/// Get the owner of a token account
+pub fn get_token_account_owner(token_account: &AccountInfo) -> Result<Pubkey, ProgramError> {
+    let account_data = token_account.try_borrow_data()?;
+
+    // Support both Token and Token-2022
+    let account = StateWithExtensions::<TokenAccount>::unpack(&account_data)
+        .map_err(|_| StakePoolError::InvalidTokenProgram)?;
+
+    Ok(account.base.owner)
+}
+
+/// Whether `mint` is wrapped SOL (SPL Token or Token-2022 native mint)
+pub fn is_native_mint(mint: &Pubkey) -> bool {
+    mint == &NATIVE_MINT || mint == &spl_token_2022::native_mint::ID
+}
+
 /// Get the balance of a token account
 pub fn get_token_account_balance(token_account: &AccountInfo) -> Result<u64, ProgramError> {
     let account_data = token_account.try_borrow_data()?;
@@ -241,6 +359,88 @@ pub fn get_token_account_balance(token_account: &AccountInfo) -> Result<u64, Pro
     Ok(account.base.amount)
 }
 
+/// Lamports the pool account holds above its rent-exempt minimum
+pub fn keeper_bounty_escrow(pool: &AccountInfo) -> Result<u64, ProgramError> {
+    let rent_exempt = Rent::get()?.minimum_balance(pool.data_len());
+    Ok(pool.lamports().saturating_sub(rent_exempt))
+}
+
+/// Pay the pool's keeper bounty for a crank at `current_time` to `keeper`
+///
+/// Returns the lamports paid, 0 without a keeper account or while no bounty is
+/// due (see `StakePool::keeper_bounty_due`). The caller saves `pool_data`, which
+/// records the payout time, and emits `KeeperBountyPaid` when something was paid.
+pub fn pay_keeper_bounty<'a>(
+    pool: &AccountInfo<'a>,
+    pool_data: &mut StakePool,
+    keeper: Option<&AccountInfo<'a>>,
+    current_time: i64,
+) -> Result<u64, ProgramError> {
+    let Some(keeper) = keeper else {
+        return Ok(0);
+    };
+    assert_writable("keeper", keeper)?;
+
+    let bounty = pool_data.keeper_bounty_due(keeper_bounty_escrow(pool)?, current_time);
+    if bounty == 0 {
+        return Ok(0);
+    }
+
+    pool_data.last_keeper_bounty_at = current_time;
+    transfer_lamports_from_pdas(pool, keeper, bounty)?;
+    msg!("Paid keeper {} a bounty of {} lamports", keeper.key, bounty);
+    Ok(bounty)
+}
+
+/// Emit `KeeperBountyPaid` for a bounty `pay_keeper_bounty` paid, if any
+pub fn emit_keeper_bounty_paid(
+    pool: &AccountInfo,
+    keeper: Option<&AccountInfo>,
+    lamports: u64,
+) -> ProgramResult {
+    match keeper {
+        Some(keeper) if lamports > 0 => StakePoolEvent::KeeperBountyPaid(KeeperBountyPaidEvent {
+            pool: *pool.key,
+            keeper: *keeper.key,
+            lamports,
+        })
+        .emit(),
+        _ => Ok(()),
+    }
+}
+
+/// Emit `PoolRewardsCapReached` if the commitment just saved exhausted the pool's rewards cap
+///
+/// Only called after `check_not_ended` passed, so the cap was not yet reached before.
+pub fn emit_if_rewards_cap_reached(
+    pool: &AccountInfo,
+    pool_data: &StakePool,
+    current_time: i64,
+) -> ProgramResult {
+    match pool_data.max_total_rewards {
+        Some(cap) if pool_data.is_rewards_cap_reached(current_time)? => {
+            msg!("Pool {} reached its rewards cap of {}", pool.key, cap);
+            StakePoolEvent::PoolRewardsCapReached(PoolRewardsCapReachedEvent {
+                pool: *pool.key,
+                max_total_rewards: cap,
+                total_rewards_committed: pool_data.total_rewards_committed,
+            })
+            .emit()
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Get the delegate of a token account, if any
+pub fn get_token_account_delegate(
+    token_account: &AccountInfo,
+) -> Result<Option<Pubkey>, ProgramError> {
+    let account_data = token_account.try_borrow_data()?;
+    let account = StateWithExtensions::<TokenAccount>::unpack(&account_data)
+        .map_err(|_| StakePoolError::InvalidTokenProgram)?;
+    Ok(account.base.delegate.into())
+}
+
 /// Validates both user token account and pool vault belong to the expected stake mint
 /// This reduces code duplication across stake/unstake operations
 ///
@@ -321,6 +521,114 @@ pub fn verify_pool_vaults_at_init(
     Ok(())
 }
 
+/// Create a pool vault as a `mint` token account owned by the pool PDA
+///
+/// The vault lives at a program-derived address (`vault_seeds` are its signer
+/// seeds), so a pool can never be initialized with a token account someone else
+/// controls. It is allocated with the account extensions the mint requires, e.g.
+/// `TransferFeeAmount` for Token-2022 mints with a transfer fee.
+///
+/// # Errors
+/// Returns InvalidTokenProgram if the mint is not owned by `token_program` or
+/// `token_program` is neither Token nor Token-2022.
+pub fn create_pool_vault<'a>(
+    vault: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    pool: &Pubkey,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    vault_seeds: &[&[u8]],
+) -> ProgramResult {
+    if mint.owner != token_program.key
+        || spl_token_2022::check_spl_token_program_account(token_program.key).is_err()
+    {
+        msg!(
+            "Mint {} is not owned by token program {}",
+            mint.key,
+            token_program.key
+        );
+        return Err(StakePoolError::InvalidTokenProgram.into());
+    }
+
+    let account_len = {
+        let mint_data = mint.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<Mint>::unpack(&mint_data)
+            .map_err(|_| StakePoolError::InvalidTokenProgram)?;
+        let account_extensions =
+            ExtensionType::get_required_init_account_extensions(&mint_state.get_extension_types()?);
+        ExtensionType::try_calculate_account_len::<TokenAccount>(&account_extensions)?
+    };
+
+    create_account(
+        vault,
+        payer,
+        system_program,
+        account_len,
+        token_program.key,
+        Some(&[vault_seeds]),
+    )?;
+    invoke(
+        &initialize_account3(token_program.key, vault.key, mint.key, pool)?,
+        &[vault.clone(), mint.clone()],
+    )
+}
+
+/// Create `wallet`'s associated token account for `mint` unless it already exists
+///
+/// `token_account` must be the associated token account address. The ATA
+/// program creates it, funded by `payer`.
+#[allow(clippy::too_many_arguments)]
+pub fn create_associated_token_account_if_missing<'a>(
+    token_account: &AccountInfo<'a>,
+    wallet: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    associated_token_program: &AccountInfo<'a>,
+    account_name: &str,
+) -> ProgramResult {
+    assert_same_pubkeys(
+        "associated_token_program",
+        associated_token_program,
+        &spl_associated_token_account_client::program::ID,
+    )?;
+    assert_same_pubkeys(
+        "system_program",
+        system_program,
+        &solana_program::system_program::ID,
+    )?;
+    assert_same_pubkeys(
+        account_name,
+        token_account,
+        &get_associated_token_address_with_program_id(wallet.key, mint.key, token_program.key),
+    )?;
+
+    if !token_account.data_is_empty() {
+        return Ok(());
+    }
+
+    msg!("Creating associated token account {}", token_account.key);
+    invoke(
+        &create_associated_token_account_idempotent(
+            payer.key,
+            wallet.key,
+            mint.key,
+            token_program.key,
+        ),
+        &[
+            payer.clone(),
+            token_account.clone(),
+            wallet.clone(),
+            mint.clone(),
+            system_program.clone(),
+            token_program.clone(),
+            associated_token_program.clone(),
+        ],
+    )
+}
+
 /// Validates that a mint does not have a freeze authority set.
 ///
 /// # Security [M-03]
@@ -368,3 +676,664 @@ pub fn validate_no_freeze_authority(
 
     Ok(())
 }
+
+/// Decimals of a Token or Token-2022 mint
+pub fn mint_decimals(mint_account: &AccountInfo) -> Result<u8, ProgramError> {
+    let account_data = mint_account.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&account_data)
+        .map_err(|_| StakePoolError::InvalidTokenProgram)?;
+    Ok(mint.base.decimals)
+}
+
+/// Load the registry of `stake_mint`, creating it (uncapped) if it does not exist yet
+///
+/// `payer` funds the rent of a new registry. The address is verified against
+/// the `["mint_registry", stake_mint]` PDA either way, re-derived from the stored
+/// bump when the registry exists.
+pub fn load_or_create_mint_registry<'a>(
+    registry_account: &AccountInfo<'a>,
+    stake_mint: &Pubkey,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> Result<MintRegistry, ProgramError> {
+    if !registry_account.data_is_empty() {
+        let registry = load_mint_registry(registry_account, stake_mint)?;
+        assert_pda_with_bump(
+            "mint_registry",
+            registry_account,
+            &crate::ID,
+            &[b"mint_registry", stake_mint.as_ref(), &[registry.bump]],
+        )?;
+        return Ok(registry);
+    }
+
+    let (registry_key, bump) = MintRegistry::find_pda(stake_mint);
+    assert_same_pubkeys("mint_registry", registry_account, &registry_key)?;
+
+    let mut seeds_with_bump = MintRegistry::seeds(stake_mint);
+    seeds_with_bump.push(vec![bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    create_account(
+        registry_account,
+        payer,
+        system_program,
+        MintRegistry::LEN,
+        &crate::ID,
+        Some(&[&seeds_refs]),
+    )?;
+
+    msg!("Mint registry created for {}", stake_mint);
+
+    Ok(MintRegistry {
+        key: Key::MintRegistry,
+        stake_mint: *stake_mint,
+        total_staked: 0,
+        tvl_cap: None,
+        bump,
+    })
+}
+
+/// Verify `pool` sits at its PDA, re-derived from the bump stored on the pool
+///
+/// A single `create_program_address` hash, where `find_program_address` may
+/// try several bumps before it finds the pool's.
+pub fn assert_pool_address(pool: &AccountInfo, pool_data: &StakePool) -> ProgramResult {
+    assert_pda_with_bump(
+        "pool",
+        pool,
+        &crate::ID,
+        &[
+            b"stake_pool",
+            pool_data.stake_mint.as_ref(),
+            &pool_data.pool_id.to_le_bytes(),
+            &[pool_data.bump],
+        ],
+    )
+}
+
+/// Load an existing mint registry and verify it tracks `stake_mint`
+pub fn load_mint_registry(
+    registry_account: &AccountInfo,
+    stake_mint: &Pubkey,
+) -> Result<MintRegistry, ProgramError> {
+    // Verify discriminator and ownership before loading (Type Cosplay protection)
+    assert_account_key("mint_registry", registry_account, Key::MintRegistry)?;
+    assert_program_owner("mint_registry", registry_account, &crate::ID)?;
+
+    let registry = MintRegistry::load(registry_account)?;
+
+    // Registries only exist at their mint's PDA, so the stored mint identifies the account
+    if &registry.stake_mint != stake_mint {
+        msg!(
+            "Mint registry tracks {}, expected {}",
+            registry.stake_mint,
+            stake_mint
+        );
+        return Err(StakePoolError::InvalidMint.into());
+    }
+
+    Ok(registry)
+}
+
+/// Load `owner`'s stake position directory in `pool` and verify it sits at its PDA
+pub fn load_stake_position(
+    position_account: &AccountInfo,
+    pool: &Pubkey,
+    owner: &Pubkey,
+) -> Result<StakePosition, ProgramError> {
+    // Verify discriminator and ownership before loading (Type Cosplay protection)
+    assert_account_key("stake_position", position_account, Key::StakePosition)?;
+    assert_program_owner("stake_position", position_account, &crate::ID)?;
+
+    let position = StakePosition::load(position_account)?;
+    assert_pda_with_bump(
+        "stake_position",
+        position_account,
+        &crate::ID,
+        &[
+            b"stake_position",
+            pool.as_ref(),
+            owner.as_ref(),
+            &[position.bump],
+        ],
+    )?;
+    Ok(position)
+}
+
+/// Load `owner`'s stake position directory in `pool`, creating it at its PDA
+/// (paid by `payer`) if the account is empty
+pub fn load_or_create_stake_position<'a>(
+    position_account: &AccountInfo<'a>,
+    pool: &Pubkey,
+    owner: &Pubkey,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> Result<StakePosition, ProgramError> {
+    if !position_account.data_is_empty() {
+        return load_stake_position(position_account, pool, owner);
+    }
+
+    let (position_key, bump) = StakePosition::find_pda(pool, owner);
+    assert_same_pubkeys("stake_position", position_account, &position_key)?;
+
+    let mut seeds_with_bump = StakePosition::seeds(pool, owner);
+    seeds_with_bump.push(vec![bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    create_account(
+        position_account,
+        payer,
+        system_program,
+        StakePosition::LEN,
+        &crate::ID,
+        Some(&[&seeds_refs]),
+    )?;
+
+    msg!("Stake position created for {} in pool {}", owner, pool);
+
+    Ok(StakePosition {
+        key: Key::StakePosition,
+        pool: *pool,
+        owner: *owner,
+        next_index: 0,
+        bump,
+        indices: Vec::new(),
+    })
+}
+
+/// Load `owner`'s points in `pool` and verify they sit at their PDA
+pub fn load_user_points(
+    points_account: &AccountInfo,
+    pool: &Pubkey,
+    owner: &Pubkey,
+) -> Result<UserPoints, ProgramError> {
+    // Verify discriminator and ownership before loading (Type Cosplay protection)
+    assert_account_key("user_points", points_account, Key::UserPoints)?;
+    assert_program_owner("user_points", points_account, &crate::ID)?;
+
+    let points = UserPoints::load(points_account)?;
+    assert_pda_with_bump(
+        "user_points",
+        points_account,
+        &crate::ID,
+        &[
+            b"user_points",
+            pool.as_ref(),
+            owner.as_ref(),
+            &[points.bump],
+        ],
+    )?;
+    Ok(points)
+}
+
+/// Load `owner`'s points in `pool`, creating their account at its PDA (paid by
+/// `payer`) if the account is empty
+pub fn load_or_create_user_points<'a>(
+    points_account: &AccountInfo<'a>,
+    pool: &Pubkey,
+    owner: &Pubkey,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> Result<UserPoints, ProgramError> {
+    if !points_account.data_is_empty() {
+        return load_user_points(points_account, pool, owner);
+    }
+
+    let (points_key, bump) = UserPoints::find_pda(pool, owner);
+    assert_same_pubkeys("user_points", points_account, &points_key)?;
+
+    let mut seeds_with_bump = UserPoints::seeds(pool, owner);
+    seeds_with_bump.push(vec![bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    create_account(
+        points_account,
+        payer,
+        system_program,
+        UserPoints::LEN,
+        &crate::ID,
+        Some(&[&seeds_refs]),
+    )?;
+
+    msg!("User points created for {} in pool {}", owner, pool);
+
+    Ok(UserPoints {
+        key: Key::UserPoints,
+        pool: *pool,
+        owner: *owner,
+        points: 0,
+        last_synced_at: 0,
+        bump,
+    })
+}
+
+/// Load an existing pool registry page and verify it sits at its page's PDA
+fn load_pool_registry(
+    account_name: &str,
+    registry_account: &AccountInfo,
+) -> Result<PoolRegistry, ProgramError> {
+    // Verify discriminator and ownership before loading (Type Cosplay protection)
+    assert_account_key(account_name, registry_account, Key::PoolRegistry)?;
+    assert_program_owner(account_name, registry_account, &crate::ID)?;
+
+    let registry = PoolRegistry::load(registry_account)?;
+    assert_pda_with_bump(
+        account_name,
+        registry_account,
+        &crate::ID,
+        &[
+            b"pool_registry",
+            &registry.page.to_le_bytes(),
+            &[registry.bump],
+        ],
+    )?;
+
+    Ok(registry)
+}
+
+/// Append a pool to the pool registry page `registry_account`
+///
+/// An existing page must have room. A missing page is created when it is the
+/// next one: page 0 when `previous_registry` is None, otherwise the page after
+/// `previous_registry`, which must be full.
+pub fn append_to_pool_registry<'a>(
+    registry_account: &AccountInfo<'a>,
+    previous_registry: Option<&AccountInfo<'a>>,
+    entry: PoolRegistryEntry,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> Result<u32, ProgramError> {
+    assert_writable("pool_registry", registry_account)?;
+
+    let mut registry = if !registry_account.data_is_empty() {
+        load_pool_registry("pool_registry", registry_account)?
+    } else {
+        let page = match previous_registry {
+            None => 0,
+            Some(previous_account) => {
+                let previous = load_pool_registry("previous_pool_registry", previous_account)?;
+                if !previous.is_full() {
+                    msg!(
+                        "Pool registry page {} still has room; register the pool there",
+                        previous.page
+                    );
+                    return Err(StakePoolError::InvalidParameters.into());
+                }
+                previous
+                    .page
+                    .checked_add(1)
+                    .ok_or(StakePoolError::NumericalOverflow)?
+            }
+        };
+
+        let (registry_key, bump) = PoolRegistry::find_pda(page);
+        assert_same_pubkeys("pool_registry", registry_account, &registry_key)?;
+
+        let mut seeds_with_bump = PoolRegistry::seeds(page);
+        seeds_with_bump.push(vec![bump]);
+        let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+        create_account(
+            registry_account,
+            payer,
+            system_program,
+            PoolRegistry::LEN,
+            &crate::ID,
+            Some(&[&seeds_refs]),
+        )?;
+
+        msg!("Pool registry page {} created", page);
+
+        PoolRegistry {
+            key: Key::PoolRegistry,
+            page,
+            bump,
+            pools: Vec::new(),
+        }
+    };
+
+    msg!(
+        "Pool {} listed in registry page {}",
+        entry.pool,
+        registry.page
+    );
+    registry.push(entry)?;
+    registry.save(registry_account)?;
+
+    Ok(registry.page)
+}
+
+/// Load a page of `pool`'s staker allowlist, creating it at its PDA if missing
+pub fn load_or_create_pool_allowlist<'a>(
+    allowlist_account: &AccountInfo<'a>,
+    pool: &Pubkey,
+    page: u32,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> Result<PoolAllowlist, ProgramError> {
+    if !allowlist_account.data_is_empty() {
+        let allowlist = load_pool_allowlist(allowlist_account, pool)?;
+        if allowlist.page != page {
+            msg!(
+                "Allowlist account holds page {}, expected page {}",
+                allowlist.page,
+                page
+            );
+            return Err(StakePoolError::InvalidPda.into());
+        }
+        return Ok(allowlist);
+    }
+
+    let (allowlist_key, bump) = PoolAllowlist::find_pda(pool, page);
+    assert_same_pubkeys("allowlist", allowlist_account, &allowlist_key)?;
+
+    let mut seeds_with_bump = PoolAllowlist::seeds(pool, page);
+    seeds_with_bump.push(vec![bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    create_account(
+        allowlist_account,
+        payer,
+        system_program,
+        PoolAllowlist::LEN,
+        &crate::ID,
+        Some(&[&seeds_refs]),
+    )?;
+
+    msg!("Pool allowlist page {} created for {}", page, pool);
+
+    Ok(PoolAllowlist {
+        key: Key::PoolAllowlist,
+        pool: *pool,
+        page,
+        bump,
+        members: Vec::new(),
+    })
+}
+
+/// Load an existing page of `pool`'s staker allowlist
+pub fn load_pool_allowlist(
+    allowlist_account: &AccountInfo,
+    pool: &Pubkey,
+) -> Result<PoolAllowlist, ProgramError> {
+    // Verify discriminator and ownership before loading (Type Cosplay protection)
+    assert_account_key("allowlist", allowlist_account, Key::PoolAllowlist)?;
+    assert_program_owner("allowlist", allowlist_account, &crate::ID)?;
+
+    // Pages only exist at their pool's PDA, so this also ties the page to `pool`
+    let allowlist = PoolAllowlist::load(allowlist_account)?;
+    assert_pda_with_bump(
+        "allowlist",
+        allowlist_account,
+        &crate::ID,
+        &[
+            b"pool_allowlist",
+            pool.as_ref(),
+            &allowlist.page.to_le_bytes(),
+            &[allowlist.bump],
+        ],
+    )?;
+
+    Ok(allowlist)
+}
+
+/// Load the hook allowlist, creating it at its PDA when the account is empty
+pub fn load_or_create_hook_allowlist<'a>(
+    allowlist_account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> Result<HookAllowlist, ProgramError> {
+    if !allowlist_account.data_is_empty() {
+        return load_hook_allowlist(allowlist_account);
+    }
+
+    let (allowlist_key, bump) = HookAllowlist::find_pda();
+    assert_same_pubkeys("hook_allowlist", allowlist_account, &allowlist_key)?;
+
+    let mut seeds_with_bump = HookAllowlist::seeds();
+    seeds_with_bump.push(vec![bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    create_account(
+        allowlist_account,
+        payer,
+        system_program,
+        HookAllowlist::LEN,
+        &crate::ID,
+        Some(&[&seeds_refs]),
+    )?;
+
+    msg!("Hook allowlist created");
+
+    Ok(HookAllowlist {
+        key: Key::HookAllowlist,
+        bump,
+        programs: Vec::new(),
+    })
+}
+
+/// Load the existing hook allowlist
+pub fn load_hook_allowlist(allowlist_account: &AccountInfo) -> Result<HookAllowlist, ProgramError> {
+    // Verify discriminator and ownership before loading (Type Cosplay protection)
+    assert_account_key("hook_allowlist", allowlist_account, Key::HookAllowlist)?;
+    assert_program_owner("hook_allowlist", allowlist_account, &crate::ID)?;
+
+    let allowlist = HookAllowlist::load(allowlist_account)?;
+    assert_pda_with_bump(
+        "hook_allowlist",
+        allowlist_account,
+        &crate::ID,
+        &[b"hook_allowlist", &[allowlist.bump]],
+    )?;
+
+    Ok(allowlist)
+}
+
+/// Load the deny list, creating its account at its PDA (paid by `payer`) if empty
+pub fn load_or_create_deny_list<'a>(
+    deny_list_account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> Result<DenyList, ProgramError> {
+    if !deny_list_account.data_is_empty() {
+        return load_deny_list(deny_list_account);
+    }
+
+    let (deny_list_key, bump) = DenyList::find_pda();
+    assert_same_pubkeys("deny_list", deny_list_account, &deny_list_key)?;
+
+    let mut seeds_with_bump = DenyList::seeds();
+    seeds_with_bump.push(vec![bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    create_account(
+        deny_list_account,
+        payer,
+        system_program,
+        DenyList::LEN,
+        &crate::ID,
+        Some(&[&seeds_refs]),
+    )?;
+
+    msg!("Deny list created");
+
+    Ok(DenyList::empty(bump))
+}
+
+/// Load the existing deny list
+pub fn load_deny_list(deny_list_account: &AccountInfo) -> Result<DenyList, ProgramError> {
+    // Verify discriminator and ownership before loading (Type Cosplay protection)
+    assert_account_key("deny_list", deny_list_account, Key::DenyList)?;
+    assert_program_owner("deny_list", deny_list_account, &crate::ID)?;
+
+    let deny_list = DenyList::load(deny_list_account)?;
+    assert_pda_with_bump(
+        "deny_list",
+        deny_list_account,
+        &crate::ID,
+        &[b"deny_list", &[deny_list.bump]],
+    )?;
+    Ok(deny_list)
+}
+
+/// The deny list a pool's stakes, unstakes, claims and ownership transfers are checked against
+///
+/// None for pools without `deny_list_required` (the account is ignored). Otherwise
+/// the deny list PDA is required; nothing is denied before its account exists.
+pub fn resolve_deny_list(
+    pool_data: &StakePool,
+    deny_list: Option<&AccountInfo>,
+) -> Result<Option<DenyList>, ProgramError> {
+    if !pool_data.deny_list_required {
+        return Ok(None);
+    }
+
+    let Some(deny_list_account) = deny_list else {
+        msg!("Pool checks the deny list: pass the deny_list account (batches and SOL variants cannot)");
+        return Err(StakePoolError::DenyListRequired.into());
+    };
+    if deny_list_account.data_is_empty() {
+        let (deny_list_key, bump) = DenyList::find_pda();
+        assert_same_pubkeys("deny_list", deny_list_account, &deny_list_key)?;
+        return Ok(Some(DenyList::empty(bump)));
+    }
+    load_deny_list(deny_list_account).map(Some)
+}
+
+/// Fail unless `owner` may stake into the pool
+///
+/// Pools without `allowlist_required` accept anyone. Otherwise `allowlist` must
+/// be one of the pool's allowlist pages and list the owner.
+pub fn check_pool_allowlist(
+    pool_data: &StakePool,
+    pool: &Pubkey,
+    allowlist: Option<&AccountInfo>,
+    proof: Option<&[[u8; 32]]>,
+    owner: &Pubkey,
+) -> ProgramResult {
+    if !pool_data.requires_allowlist() {
+        return Ok(());
+    }
+
+    if let (Some(root), Some(proof)) = (pool_data.allowlist_merkle_root, proof) {
+        if proof.len() > MAX_MERKLE_PROOF_LEN {
+            msg!(
+                "Allowlist proof has {} hashes, at most {} are allowed",
+                proof.len(),
+                MAX_MERKLE_PROOF_LEN
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        if !merkle::verify_proof(&root, owner, proof) {
+            msg!(
+                "Proof does not show {} on the allowlist of pool {}",
+                owner,
+                pool
+            );
+            return Err(StakePoolError::NotAllowlisted.into());
+        }
+        return Ok(());
+    }
+
+    let Some(allowlist_account) = allowlist else {
+        msg!(
+            "Pool {} requires a merkle proof or the allowlist page listing the owner",
+            pool
+        );
+        return Err(StakePoolError::NotAllowlisted.into());
+    };
+    let allowlist = load_pool_allowlist(allowlist_account, pool)?;
+    if !allowlist.contains(owner) {
+        msg!(
+            "{} is not listed on allowlist page {} of pool {}",
+            owner,
+            allowlist.page,
+            pool
+        );
+        return Err(StakePoolError::NotAllowlisted.into());
+    }
+    Ok(())
+}
+
+/// Load the program authority and fail if its global pause switches block `operation`
+pub fn check_global_pause(
+    program_authority: &AccountInfo,
+    operation: UserOperation,
+) -> Result<(), ProgramError> {
+    // Verify discriminator and ownership before loading (Type Cosplay protection)
+    assert_account_key(
+        "program_authority",
+        program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    assert_program_owner("program_authority", program_authority, &crate::ID)?;
+
+    let authority = ProgramAuthority::load(program_authority)?;
+    assert_pda_with_bump(
+        "program_authority",
+        program_authority,
+        &crate::ID,
+        &[b"program_authority", &[authority.bump]],
+    )?;
+
+    authority.check_not_paused(operation)
+}
+
+/// Load the program config, or the built-in limits while it is not initialized
+///
+/// An empty account is only accepted at the config PDA, so the configured limits
+/// cannot be sidestepped by passing some other uninitialized account.
+pub fn load_program_config(config_account: &AccountInfo) -> Result<ProgramConfig, ProgramError> {
+    if config_account.data_is_empty() {
+        let (config_key, _) = ProgramConfig::find_pda();
+        assert_same_pubkeys("program_config", config_account, &config_key)?;
+        return Ok(ProgramConfig::default());
+    }
+
+    // Verify discriminator and ownership before loading (Type Cosplay protection)
+    assert_account_key("program_config", config_account, Key::ProgramConfig)?;
+    assert_program_owner("program_config", config_account, &crate::ID)?;
+
+    let config = ProgramConfig::load(config_account)?;
+    assert_pda_with_bump(
+        "program_config",
+        config_account,
+        &crate::ID,
+        &[b"program_config", &[config.bump]],
+    )?;
+
+    Ok(config)
+}
+
+/// Attach `memo` to the transaction via the SPL Memo program, signed by `signer`
+///
+/// Gives explorers and auditors a human-readable record of the operation.
+/// Nothing is written when `memo` is None.
+pub fn write_memo<'a>(
+    memo: Option<&str>,
+    memo_program: Option<&AccountInfo<'a>>,
+    signer: &AccountInfo<'a>,
+) -> ProgramResult {
+    let Some(memo) = memo else {
+        return Ok(());
+    };
+    if memo.is_empty() || memo.len() > MAX_MEMO_LEN {
+        msg!(
+            "Memo must be between 1 and {} bytes, got {}",
+            MAX_MEMO_LEN,
+            memo.len()
+        );
+        return Err(StakePoolError::InvalidMemo.into());
+    }
+    let Some(memo_program) = memo_program else {
+        msg!("The memo program is required to attach a memo");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    assert_same_pubkeys("memo_program", memo_program, &spl_memo::ID)?;
+
+    invoke(
+        &spl_memo::build_memo(memo.as_bytes(), &[signer.key]),
+        &[signer.clone(), memo_program.clone()],
+    )
+}
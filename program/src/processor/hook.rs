@@ -0,0 +1,90 @@
+//! Calls into a pool's hook program (see `crate::hook`)
+//!
+//! `stake`, `unstake` and `claim_rewards` resolve the hook of the pool up front
+//! and call it once their state is saved. The other entry points that stake,
+//! unstake or claim on the owner's behalf (the SOL variants and the batches)
+//! cannot pass a hook, so they reject hooked pools rather than skip the hook.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+};
+
+use crate::assertions::*;
+use crate::error::StakePoolError;
+use crate::hook::StakePoolHookInstruction;
+use crate::state::StakePool;
+
+/// Hook program of a pool and the accounts forwarded to it
+pub(super) struct PoolHook<'a> {
+    program: &'a AccountInfo<'a>,
+    accounts: &'a [AccountInfo<'a>],
+}
+
+impl<'a> PoolHook<'a> {
+    /// Resolve the optional hook program account of an instruction
+    ///
+    /// Returns None for pools without a hook (the accounts are ignored). For
+    /// hooked pools the program account is required and must be the pool's hook.
+    pub(super) fn resolve(
+        pool_data: &StakePool,
+        program: Option<&'a AccountInfo<'a>>,
+        accounts: &'a [AccountInfo<'a>],
+    ) -> Result<Option<Self>, ProgramError> {
+        let Some(hook_program) = pool_data.hook_program else {
+            return Ok(None);
+        };
+
+        let Some(program) = program else {
+            msg!(
+                "Pool calls hook program {}: pass it to Stake, Unstake or ClaimRewards",
+                hook_program
+            );
+            return Err(StakePoolError::HookProgramRequired.into());
+        };
+        assert_same_pubkeys("hook_program", program, &hook_program)?;
+
+        Ok(Some(Self { program, accounts }))
+    }
+
+    /// Invoke the hook after `operation` on `stake_account`
+    ///
+    /// The pool's accounts are passed read-only and nothing is passed as a
+    /// signer, so the hook can observe the operation but not act on its behalf.
+    pub(super) fn call(
+        &self,
+        operation: StakePoolHookInstruction,
+        pool: &'a AccountInfo<'a>,
+        stake_account: &'a AccountInfo<'a>,
+        owner: &'a AccountInfo<'a>,
+    ) -> ProgramResult {
+        let mut metas = vec![
+            AccountMeta::new_readonly(*pool.key, false),
+            AccountMeta::new_readonly(*stake_account.key, false),
+            AccountMeta::new_readonly(*owner.key, false),
+        ];
+        let mut infos = vec![pool.clone(), stake_account.clone(), owner.clone()];
+        for account in self.accounts {
+            metas.push(if account.is_writable {
+                AccountMeta::new(*account.key, false)
+            } else {
+                AccountMeta::new_readonly(*account.key, false)
+            });
+            infos.push(account.clone());
+        }
+        infos.push(self.program.clone());
+
+        invoke(
+            &Instruction {
+                program_id: *self.program.key,
+                accounts: metas,
+                data: operation.data()?,
+            },
+            &infos,
+        )
+    }
+}
@@ -1,46 +1,33 @@
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
-    log::sol_log_data,
     msg,
     sysvar::{clock::Clock, Sysvar},
 };
 
 use crate::assertions::*;
-use crate::constants::MAX_REWARD_RATE;
+use crate::constants::{BPS_DENOMINATOR, MAX_FEE_BPS, MAX_REFERRAL_BPS};
 use crate::error::StakePoolError;
+use crate::events::{PoolClonedEvent, PoolInitializedEvent, PoolRegisteredEvent, StakePoolEvent};
 use crate::instruction::accounts::*;
-use crate::state::{Key, ProgramAuthority, StakePool};
+use crate::state::{
+    Key, OutflowWindow, PoolRegistryEntry, ProgramAuthority, RewardRateMode, RewardTier, StakePool,
+    WindDownRewards,
+};
 use crate::utils::create_account;
 use solana_program::pubkey::Pubkey;
 
 use super::helpers::{
-    validate_current_timestamp, validate_no_freeze_authority, verify_pool_vaults_at_init,
-    verify_vault_ownership,
+    append_to_pool_registry, assert_pool_address, create_pool_vault, load_or_create_mint_registry,
+    load_program_config, mint_decimals, validate_current_timestamp, validate_no_freeze_authority,
+    verify_pool_vaults_at_init, verify_token_account,
 };
 
-/// Minimum lockup period enforced during pool initialization (1 day = 86400 seconds).
-///
-/// **Security Rationale [H-02]:**
-/// Prevents reward vault drain attacks where attackers could:
-/// 1. Set lockup to 1 second
-/// 2. Stake tokens
-/// 3. Wait 1 second
-/// 4. Claim full rewards instantly
-/// 5. Drain the reward vault
-///
-/// **Business Rationale:**
-/// Ensures meaningful staking commitment and prevents gaming of reward mechanics.
-/// Can be adjusted per deployment requirements (7 days, 30 days, etc.)
-///
-/// **Current Value**: 86400 seconds (1 day)
-const MIN_LOCKUP_PERIOD: i64 = 86400;
-
 /// Initialize a new staking pool with the provided parameters.
 ///
 /// # Security
 /// This function includes critical security validations:
-/// - [H-01] Validates vault token accounts are owned by the pool PDA (not by arbitrary users)
+/// - [H-01] Creates the vault token accounts itself at their PDAs, owned by the pool PDA
 /// - [H-02] Enforces minimum lockup period to prevent reward drain attacks
 /// - Prevents attackers from passing malicious token accounts they control
 /// - Ensures only the pool program can authorize transfers from vaults
@@ -53,14 +40,28 @@ const MIN_LOCKUP_PERIOD: i64 = 86400;
 /// * `lockup_period` - Time in seconds before rewards are earned (minimum 1 day)
 /// * `enforce_lockup` - Whether to prevent early unstaking
 /// * `pool_end_date` - Optional timestamp after which no new stakes allowed
+/// * `referral_bps` - Share of each claim paid to the stake's referrer (max 20%)
+/// * `early_unstake_bps` - Share of a position that may be unstaked before lockup completion
+/// * `deposit_fee_bps` - Fee taken from each stake deposit (max 10%)
+/// * `withdraw_fee_bps` - Fee taken from each unstake (max 10%)
+/// * `reward_rate_change_delay` - Reward rate time-lock in seconds (None: deployment default)
+/// * `min_reward_rate_bound` / `max_reward_rate_bound` - Reward rate range future updates must stay within
+/// * `reward_tiers` - Stake size tiers boosting the reward rate (see `StakePool::set_reward_tiers`)
+/// * `bonus_end_ts` / `bonus_multiplier_bps` - Optional early-bird bonus window (see `StakePool::set_bonus_window`)
+/// * `reward_rate_mode` - Whether `reward_rate` is per lockup round or annualized (see `RewardRateMode`)
 ///
 /// # Errors
 /// Returns error if:
-/// - Parameters are invalid (reward rate too high, lockup below minimum, past end date)
+/// - Parameters are invalid (reward rate above or lockup below the program config's
+///   limits, past end date, misordered reward tiers, a multiplier above MAX_REWARD_MULTIPLIER_BPS or a past
+///   bonus window end)
 /// - Pool account doesn't match expected PDA derivation
+/// - The program config account is not the config PDA
 /// - Required signers are missing
-/// - Vault accounts are not owned by the pool PDA (CRITICAL SECURITY CHECK)
+/// - Vault accounts are not the pool's vault PDAs or already exist
+/// - A mint is not owned by the token program
 /// - Account creation fails
+#[allow(clippy::too_many_arguments)]
 pub fn initialize_pool<'a>(
     accounts: &'a [AccountInfo<'a>],
     pool_id: u64,
@@ -69,13 +70,232 @@ pub fn initialize_pool<'a>(
     lockup_period: i64,
     enforce_lockup: bool,
     pool_end_date: Option<i64>,
+    referral_bps: u16,
+    early_unstake_bps: u16,
+    deposit_fee_bps: u16,
+    withdraw_fee_bps: u16,
+    reward_rate_change_delay: Option<u32>,
+    min_reward_rate_bound: Option<u64>,
+    max_reward_rate_bound: Option<u64>,
+    reward_tiers: Vec<RewardTier>,
+    bonus_end_ts: Option<i64>,
+    bonus_multiplier_bps: u16,
+    reward_rate_mode: RewardRateMode,
+) -> ProgramResult {
+    // Use ShankContext to parse accounts
+    let ctx = InitializePoolAccounts::context(accounts)?;
+
+    create_pool(
+        PoolCreationAccounts {
+            pool: ctx.accounts.pool,
+            stake_mint: ctx.accounts.stake_mint,
+            reward_mint: ctx.accounts.reward_mint,
+            stake_vault: ctx.accounts.stake_vault,
+            reward_vault: ctx.accounts.reward_vault,
+            payer: ctx.accounts.payer,
+            token_program: ctx.accounts.token_program,
+            system_program: ctx.accounts.system_program,
+            program_authority: ctx.accounts.program_authority,
+            fee_vault: ctx.accounts.fee_vault,
+            mint_registry: ctx.accounts.mint_registry,
+            pool_registry: ctx.accounts.pool_registry,
+            previous_pool_registry: ctx.accounts.previous_pool_registry,
+            program_config: ctx.accounts.program_config,
+        },
+        PoolParams {
+            pool_id,
+            reward_rate,
+            min_stake_amount,
+            lockup_period,
+            enforce_lockup,
+            pool_end_date,
+            referral_bps,
+            early_unstake_bps,
+            deposit_fee_bps,
+            withdraw_fee_bps,
+            reward_rate_change_delay,
+            min_reward_rate_bound,
+            max_reward_rate_bound,
+            reward_tiers,
+            bonus_end_ts,
+            bonus_multiplier_bps,
+            reward_rate_mode,
+        },
+        None,
+    )
+}
+
+/// Accounts shared by `initialize_pool` and `clone_pool`
+struct PoolCreationAccounts<'a> {
+    pool: &'a AccountInfo<'a>,
+    stake_mint: &'a AccountInfo<'a>,
+    reward_mint: &'a AccountInfo<'a>,
+    stake_vault: &'a AccountInfo<'a>,
+    reward_vault: &'a AccountInfo<'a>,
+    payer: &'a AccountInfo<'a>,
+    token_program: &'a AccountInfo<'a>,
+    system_program: &'a AccountInfo<'a>,
+    program_authority: &'a AccountInfo<'a>,
+    fee_vault: &'a AccountInfo<'a>,
+    mint_registry: &'a AccountInfo<'a>,
+    pool_registry: &'a AccountInfo<'a>,
+    previous_pool_registry: Option<&'a AccountInfo<'a>>,
+    program_config: &'a AccountInfo<'a>,
+}
+
+/// Parameters a new pool is created with (see `StakePoolInstruction::InitializePool`)
+struct PoolParams {
+    pool_id: u64,
+    reward_rate: u64,
+    min_stake_amount: u64,
+    lockup_period: i64,
+    enforce_lockup: bool,
+    pool_end_date: Option<i64>,
+    referral_bps: u16,
+    early_unstake_bps: u16,
+    deposit_fee_bps: u16,
+    withdraw_fee_bps: u16,
+    reward_rate_change_delay: Option<u32>,
+    min_reward_rate_bound: Option<u64>,
+    max_reward_rate_bound: Option<u64>,
+    reward_tiers: Vec<RewardTier>,
+    bonus_end_ts: Option<i64>,
+    bonus_multiplier_bps: u16,
+    reward_rate_mode: RewardRateMode,
+}
+
+impl PoolParams {
+    /// Parameters of a clone of `source`, with its own id and campaign dates
+    ///
+    /// The source's current reward rate is used (not a pending change) and its
+    /// bonus multiplier only applies when the clone has a bonus window.
+    fn cloned_from(
+        source: &StakePool,
+        pool_id: u64,
+        pool_end_date: Option<i64>,
+        bonus_end_ts: Option<i64>,
+    ) -> Self {
+        Self {
+            pool_id,
+            reward_rate: source.reward_rate,
+            min_stake_amount: source.min_stake_amount,
+            lockup_period: source.lockup_period,
+            enforce_lockup: source.enforce_lockup,
+            pool_end_date,
+            referral_bps: source.referral_bps,
+            early_unstake_bps: source.early_unstake_bps,
+            deposit_fee_bps: source.deposit_fee_bps,
+            withdraw_fee_bps: source.withdraw_fee_bps,
+            reward_rate_change_delay: match source.reward_rate_change_delay {
+                0 => None,
+                delay => Some(delay),
+            },
+            min_reward_rate_bound: source.min_reward_rate_bound,
+            max_reward_rate_bound: source.max_reward_rate_bound,
+            reward_tiers: source.reward_tiers.iter().flatten().copied().collect(),
+            bonus_end_ts,
+            bonus_multiplier_bps: match bonus_end_ts {
+                Some(_) => source.bonus_multiplier_bps,
+                None => 0,
+            },
+            reward_rate_mode: source.reward_rate_mode,
+        }
+    }
+}
+
+/// Create and initialize a pool (see `initialize_pool`)
+///
+/// A cloned pool also takes the settings `StakePool::copy_settings_from` copies
+/// from its `source` pool.
+fn create_pool<'a>(
+    accounts: PoolCreationAccounts<'a>,
+    params: PoolParams,
+    source: Option<(&Pubkey, &StakePool)>,
 ) -> ProgramResult {
+    let PoolParams {
+        pool_id,
+        reward_rate,
+        min_stake_amount,
+        lockup_period,
+        enforce_lockup,
+        pool_end_date,
+        referral_bps,
+        early_unstake_bps,
+        deposit_fee_bps,
+        withdraw_fee_bps,
+        reward_rate_change_delay,
+        min_reward_rate_bound,
+        max_reward_rate_bound,
+        reward_tiers,
+        bonus_end_ts,
+        bonus_multiplier_bps,
+        reward_rate_mode,
+    } = params;
+
+    // Limits tuned by the program config, or the built-in ones
+    let config = load_program_config(accounts.program_config)?;
+
     // Validate parameters
-    if reward_rate > MAX_REWARD_RATE {
+    if reward_rate > config.max_reward_rate {
         msg!("Reward rate too high: {}", reward_rate);
         return Err(StakePoolError::InvalidParameters.into());
     }
 
+    // The committed bounds must form a valid range that holds the initial rate
+    if let Some(max_bound) = max_reward_rate_bound {
+        if max_bound > config.max_reward_rate
+            || min_reward_rate_bound.is_some_and(|min| min > max_bound)
+        {
+            msg!(
+                "Invalid reward rate bounds: min {:?}, max {}",
+                min_reward_rate_bound,
+                max_bound
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+    }
+    if !StakePool::reward_rate_within_bounds(
+        reward_rate,
+        min_reward_rate_bound,
+        max_reward_rate_bound,
+    ) {
+        msg!(
+            "Reward rate {} outside the committed bounds (min: {:?}, max: {:?})",
+            reward_rate,
+            min_reward_rate_bound,
+            max_reward_rate_bound
+        );
+        return Err(StakePoolError::RewardRateOutOfBounds.into());
+    }
+
+    if referral_bps > MAX_REFERRAL_BPS {
+        msg!(
+            "Referral share too high: {} bps. Maximum: {} bps",
+            referral_bps,
+            MAX_REFERRAL_BPS
+        );
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    if early_unstake_bps as u64 > BPS_DENOMINATOR {
+        msg!(
+            "Early unstake allowance too high: {} bps. Maximum: {} bps",
+            early_unstake_bps,
+            BPS_DENOMINATOR
+        );
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    if deposit_fee_bps > MAX_FEE_BPS || withdraw_fee_bps > MAX_FEE_BPS {
+        msg!(
+            "Fee too high: deposit {} bps, withdraw {} bps. Maximum: {} bps",
+            deposit_fee_bps,
+            withdraw_fee_bps,
+            MAX_FEE_BPS
+        );
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
     // [H-02] Security Fix: Enforce minimum lockup period
     // Without this check, admins could set lockup to 1 second, allowing users to:
     // 1. Stake tokens
@@ -83,11 +303,11 @@ pub fn initialize_pool<'a>(
     // 3. Claim full rewards instantly
     // 4. Drain the reward vault
     // The minimum lockup ensures rewards are earned over a meaningful timeframe.
-    if lockup_period < MIN_LOCKUP_PERIOD {
+    if lockup_period < config.min_lockup_period {
         msg!(
-            "Lockup period too short: {} seconds. Minimum required: {} seconds (1 day)",
+            "Lockup period too short: {} seconds. Minimum required: {} seconds",
             lockup_period,
-            MIN_LOCKUP_PERIOD
+            config.min_lockup_period
         );
         return Err(StakePoolError::InvalidParameters.into());
     }
@@ -105,9 +325,6 @@ pub fn initialize_pool<'a>(
         }
     }
 
-    // Use ShankContext to parse accounts
-    let ctx = InitializePoolAccounts::context(accounts)?;
-
     // [Q-01] Security Fix: Validate pool creator is authorized
     // Only addresses in the ProgramAuthority's authorized_creators list (or the main authority) can create pools.
     // This prevents spam/scam pools and maintains quality control.
@@ -117,32 +334,59 @@ pub fn initialize_pool<'a>(
     // Authorization is checked via ProgramAuthority.is_authorized(), which verifies:
     // 1. The payer is the main program authority, OR
     // 2. The payer is in the authorized_creators list (max 10 additional addresses)
-    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+    let mut program_authority = ProgramAuthority::load(accounts.program_authority)?;
 
-    if !program_authority.is_authorized(ctx.accounts.payer.key) {
+    if !program_authority.is_authorized(accounts.payer.key) {
         msg!(
             "Unauthorized pool creator: {}. Only authorized admins can create pools.",
-            ctx.accounts.payer.key
+            accounts.payer.key
         );
         return Err(StakePoolError::UnauthorizedPoolCreator.into());
     }
 
+    // The delay's floor depends on the deployment's cluster profile
+    if let Some(delay) = reward_rate_change_delay {
+        program_authority
+            .cluster_profile
+            .validate_reward_rate_change_delay(delay as i64)?;
+    }
+
+    // Count the pool against the creator's pool limit (the main authority is not limited)
+    let counts_against_limit = accounts.payer.key != &program_authority.authority;
+    if counts_against_limit {
+        assert_writable("program_authority", accounts.program_authority)?;
+        program_authority.record_pool_created(accounts.payer.key)?;
+    }
+
     // Guards
     // Derive the expected pool PDA from stake_mint and pool_id
     // This ensures the provided pool account matches the pool_id parameter
-    let pool_seeds = StakePool::seeds(ctx.accounts.stake_mint.key, pool_id);
+    let pool_seeds = StakePool::seeds(accounts.stake_mint.key, pool_id);
     let pool_seeds_refs: Vec<&[u8]> = pool_seeds.iter().map(|s| s.as_slice()).collect();
     let (pool_key, bump) = Pubkey::find_program_address(&pool_seeds_refs, &crate::ID);
 
     // Validate that the provided pool address matches the expected PDA
     // This prevents initialization with wrong pool_id
-    assert_same_pubkeys("pool", ctx.accounts.pool, &pool_key)?;
-    assert_signer("payer", ctx.accounts.payer)?;
-    assert_empty("pool", ctx.accounts.pool)?;
-    assert_writable("pool", ctx.accounts.pool)?;
-    assert_writable("stake_vault", ctx.accounts.stake_vault)?;
-    assert_writable("reward_vault", ctx.accounts.reward_vault)?;
-    assert_writable("payer", ctx.accounts.payer)?;
+    assert_same_pubkeys("pool", accounts.pool, &pool_key)?;
+    assert_signer("payer", accounts.payer)?;
+    assert_empty("pool", accounts.pool)?;
+    assert_writable("pool", accounts.pool)?;
+    assert_writable("stake_vault", accounts.stake_vault)?;
+    assert_writable("reward_vault", accounts.reward_vault)?;
+    assert_writable("fee_vault", accounts.fee_vault)?;
+    assert_writable("payer", accounts.payer)?;
+    assert_writable("mint_registry", accounts.mint_registry)?;
+    assert_empty("stake_vault", accounts.stake_vault)?;
+    assert_empty("reward_vault", accounts.reward_vault)?;
+    assert_empty("fee_vault", accounts.fee_vault)?;
+
+    // The vaults must be the pool's vault PDAs
+    let (stake_vault_key, stake_vault_bump) = StakePool::find_stake_vault_pda(&pool_key);
+    let (reward_vault_key, reward_vault_bump) = StakePool::find_reward_vault_pda(&pool_key);
+    let (fee_vault_key, fee_vault_bump) = StakePool::find_fee_vault_pda(&pool_key);
+    assert_same_pubkeys("stake_vault", accounts.stake_vault, &stake_vault_key)?;
+    assert_same_pubkeys("reward_vault", accounts.reward_vault, &reward_vault_key)?;
+    assert_same_pubkeys("fee_vault", accounts.fee_vault, &fee_vault_key)?;
 
     // [M-03] Security Fix: Validate mints don't have freeze authority
     // The freeze_authority allows freezing token accounts, which would lock user funds permanently.
@@ -151,33 +395,62 @@ pub fn initialize_pool<'a>(
     // 1. Freeze any user's stake account after they deposit
     // 2. Prevent unstaking and token transfers
     // 3. Cause permanent loss of user funds
-    validate_no_freeze_authority(ctx.accounts.stake_mint, "stake_mint")?;
-    validate_no_freeze_authority(ctx.accounts.reward_mint, "reward_mint")?;
+    validate_no_freeze_authority(accounts.stake_mint, "stake_mint")?;
+    validate_no_freeze_authority(accounts.reward_mint, "reward_mint")?;
+
+    // Rates mean the same in whole tokens whatever the mints' decimals
+    let stake_decimals = mint_decimals(accounts.stake_mint)?;
+    let reward_decimals = mint_decimals(accounts.reward_mint)?;
+    let reward_decimals_shift = StakePool::decimals_shift(stake_decimals, reward_decimals)?;
+
+    // CRITICAL SECURITY FIX [H-01]: The program creates the vaults itself
+    // Vaults used to be token accounts created by the client, so every pool had to
+    // prove that an attacker had not passed token accounts they control (funds sent
+    // to such a vault could be stolen, and vault addresses cannot be changed later).
+    // Creating them at the pool's vault PDAs, owned by the pool PDA, removes that
+    // class of wrong-vault attacks: only the pool program can authorize transfers.
+    let vaults = [
+        (
+            accounts.stake_vault,
+            accounts.stake_mint,
+            StakePool::STAKE_VAULT_SEED,
+            stake_vault_bump,
+        ),
+        (
+            accounts.reward_vault,
+            accounts.reward_mint,
+            StakePool::REWARD_VAULT_SEED,
+            reward_vault_bump,
+        ),
+        (
+            accounts.fee_vault,
+            accounts.stake_mint,
+            StakePool::FEE_VAULT_SEED,
+            fee_vault_bump,
+        ),
+    ];
+    for (vault, mint, seed, vault_bump) in vaults {
+        create_pool_vault(
+            vault,
+            mint,
+            &pool_key,
+            accounts.payer,
+            accounts.system_program,
+            accounts.token_program,
+            &[seed, pool_key.as_ref(), &[vault_bump]],
+        )?;
+    }
 
     // Verify token accounts have correct mints and validate Token-2022 extensions
     verify_pool_vaults_at_init(
-        ctx.accounts.stake_vault,
-        ctx.accounts.reward_vault,
-        ctx.accounts.stake_mint,
-        ctx.accounts.reward_mint,
-        ctx.accounts.stake_mint.key,
-        ctx.accounts.reward_mint.key,
+        accounts.stake_vault,
+        accounts.reward_vault,
+        accounts.stake_mint,
+        accounts.reward_mint,
+        accounts.stake_mint.key,
+        accounts.reward_mint.key,
     )?;
-
-    // CRITICAL SECURITY FIX [H-01]: Verify vault ownership
-    // This prevents an attacker from passing token accounts they control as pool vaults.
-    // Without this check, an attacker could:
-    // 1. Create token accounts they own
-    // 2. Pass them as stake_vault and reward_vault during pool initialization
-    // 3. Steal all user deposits since funds would be sent to attacker's accounts
-    // 4. Lock the system permanently since vault addresses cannot be changed
-    //
-    // The pool PDA must own both vaults to ensure:
-    // - Only the pool program can authorize transfers from vaults
-    // - User funds are protected by program logic
-    // - No external parties can drain the vaults
-    verify_vault_ownership(ctx.accounts.stake_vault, &pool_key, "stake_vault")?;
-    verify_vault_ownership(ctx.accounts.reward_vault, &pool_key, "reward_vault")?;
+    verify_token_account(accounts.fee_vault, accounts.stake_mint.key, None, None)?;
 
     // Create pool account
     let mut seeds_with_bump = pool_seeds.clone();
@@ -185,22 +458,22 @@ pub fn initialize_pool<'a>(
     let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
 
     create_account(
-        ctx.accounts.pool,
-        ctx.accounts.payer,
-        ctx.accounts.system_program,
+        accounts.pool,
+        accounts.payer,
+        accounts.system_program,
         StakePool::LEN,
         &crate::ID,
         Some(&[&seeds_refs]),
     )?;
 
     // Initialize pool
-    let pool_data = StakePool {
-        key: Key::StakePool,
-        stake_mint: *ctx.accounts.stake_mint.key,
-        reward_mint: *ctx.accounts.reward_mint.key,
+    let mut pool_data = StakePool {
+        key: Key::StakePoolV2,
+        stake_mint: *accounts.stake_mint.key,
+        reward_mint: *accounts.reward_mint.key,
         pool_id,
-        stake_vault: *ctx.accounts.stake_vault.key,
-        reward_vault: *ctx.accounts.reward_vault.key,
+        stake_vault: *accounts.stake_vault.key,
+        reward_vault: *accounts.reward_vault.key,
         total_staked: 0,
         total_rewards_owed: 0,
         reward_rate,
@@ -213,28 +486,242 @@ pub fn initialize_pool<'a>(
         pending_reward_rate: None,
         reward_rate_change_timestamp: None,
         last_rate_change: None,
-        _reserved: [0; 7],
+        referral_bps,
+        early_unstake_bps,
+        // Recorded so every later instruction can re-derive the vaults (see assert_vault)
+        stake_vault_bump: Some(stake_vault_bump),
+        reward_vault_bump: Some(reward_vault_bump),
+        fee_vault: *accounts.fee_vault.key,
+        deposit_fee_bps,
+        withdraw_fee_bps,
+        fee_vault_bump: Some(fee_vault_bump),
+        total_rewards_funded: 0,
+        reward_funders: [None; StakePool::MAX_REWARD_FUNDERS],
+        receipt_mint: None,
+        expired_rewards: 0,
+        unstake_cooldown: 0,
+        is_registered: true,
+        reward_rate_change_delay: reward_rate_change_delay.unwrap_or(0),
+        pending_lockup_period: None,
+        pending_enforce_lockup: None,
+        lockup_change_timestamp: None,
+        min_reward_rate_bound,
+        max_reward_rate_bound,
+        active_stake_count: Some(0),
+        reward_tiers: [None; StakePool::MAX_REWARD_TIERS],
+        bonus_end_ts: None,
+        bonus_multiplier_bps: 0,
+        lockup_extension_rate: 0,
+        allowlist_required: false,
+        allowlist_merkle_root: None,
+        claim_window: None,
+        outflow_limit: None,
+        outflow_window: OutflowWindow::default(),
+        pool_manager: None,
+        keeper_bounty: 0,
+        last_keeper_bounty_at: 0,
+        max_total_rewards: None,
+        total_rewards_committed: 0,
+        reward_rate_mode,
+        reward_decimals_shift,
+        enforce_min_remaining_stake: false,
+        wind_down_rewards: WindDownRewards::Expire,
+        hook_program: None,
+        points_rate: 0,
+        deny_list_required: false,
+        allow_third_party_stake: false,
+        event_nonce: 0,
+        cohort_duration: 0,
+        open_reward_cohorts: 0,
+        _reserved: [0; 2],
     };
+    pool_data.set_reward_tiers(&reward_tiers)?;
+    pool_data.set_bonus_window(
+        bonus_end_ts,
+        bonus_multiplier_bps,
+        Clock::get()?.unix_timestamp,
+    )?;
+    if let Some((_, source_data)) = source {
+        pool_data.copy_settings_from(source_data);
+    }
 
     msg!(
-        "Pool initialized: pool_id={}, reward_rate={}, lockup_period={}, min_stake_amount={}",
+        "Pool initialized: pool_id={}, reward_rate={} ({:?}), lockup_period={}, min_stake_amount={}",
         pool_id,
         reward_rate,
+        reward_rate_mode,
         lockup_period,
         min_stake_amount
     );
 
+    // Every pool of the stake mint shares one registry, created with the first pool
+    let mint_registry = load_or_create_mint_registry(
+        accounts.mint_registry,
+        accounts.stake_mint.key,
+        accounts.payer,
+        accounts.system_program,
+    )?;
+
+    // List the pool so wallets can enumerate pools without scanning the program
+    append_to_pool_registry(
+        accounts.pool_registry,
+        accounts.previous_pool_registry,
+        PoolRegistryEntry {
+            pool: pool_key,
+            stake_mint: pool_data.stake_mint,
+            pool_id,
+        },
+        accounts.payer,
+        accounts.system_program,
+    )?;
+
+    // Save state first to ensure persistence before emitting event
+    pool_data.save(accounts.pool)?;
+    mint_registry.save(accounts.mint_registry)?;
+    if counts_against_limit {
+        program_authority.save(accounts.program_authority)?;
+    }
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::PoolInitialized(PoolInitializedEvent {
+        pool: *accounts.pool.key,
+        payer: *accounts.payer.key,
+        pool_id,
+        reward_rate,
+        reward_rate_mode,
+    })
+    .emit()?;
+
+    match source {
+        Some((source_pool, _)) => StakePoolEvent::PoolCloned(PoolClonedEvent {
+            pool: *accounts.pool.key,
+            source_pool: *source_pool,
+            payer: *accounts.payer.key,
+        })
+        .emit(),
+        None => Ok(()),
+    }
+}
+
+/// Create a new pool (`pool_id`) with the settings of an existing pool of the same mints
+///
+/// For recurring campaigns: the new pool and its vaults are created in one
+/// instruction, as by InitializePool, with the source pool's current parameters
+/// (see `PoolParams::cloned_from`) and the settings `StakePool::copy_settings_from`
+/// copies. Only the campaign dates are given anew. The payer must be an authorized
+/// pool creator.
+///
+/// # Errors
+/// Returns error if:
+/// - The source pool is not a pool of this program or has other mints
+/// - Any InitializePool check fails, e.g. the source's parameters are no longer
+///   within the program config's limits or a date is in the past
+pub fn clone_pool<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    pool_id: u64,
+    pool_end_date: Option<i64>,
+    bonus_end_ts: Option<i64>,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = ClonePoolAccounts::context(accounts)?;
+
+    // Verify source pool discriminator before loading (Type Cosplay protection)
+    assert_account_key("source_pool", ctx.accounts.source_pool, Key::StakePoolV2)?;
+
+    // Verify program ownership
+    assert_program_owner("source_pool", ctx.accounts.source_pool, &crate::ID)?;
+
+    // Load source pool
+    let source_data = StakePool::load(ctx.accounts.source_pool)?;
+
+    // Guards
+    assert_same_pubkeys(
+        "stake_mint",
+        ctx.accounts.stake_mint,
+        &source_data.stake_mint,
+    )?;
+    assert_same_pubkeys(
+        "reward_mint",
+        ctx.accounts.reward_mint,
+        &source_data.reward_mint,
+    )?;
+
+    msg!(
+        "Cloning pool {} into pool_id={}",
+        ctx.accounts.source_pool.key,
+        pool_id
+    );
+
+    create_pool(
+        PoolCreationAccounts {
+            pool: ctx.accounts.pool,
+            stake_mint: ctx.accounts.stake_mint,
+            reward_mint: ctx.accounts.reward_mint,
+            stake_vault: ctx.accounts.stake_vault,
+            reward_vault: ctx.accounts.reward_vault,
+            payer: ctx.accounts.payer,
+            token_program: ctx.accounts.token_program,
+            system_program: ctx.accounts.system_program,
+            program_authority: ctx.accounts.program_authority,
+            fee_vault: ctx.accounts.fee_vault,
+            mint_registry: ctx.accounts.mint_registry,
+            pool_registry: ctx.accounts.pool_registry,
+            previous_pool_registry: ctx.accounts.previous_pool_registry,
+            program_config: ctx.accounts.program_config,
+        },
+        PoolParams::cloned_from(&source_data, pool_id, pool_end_date, bonus_end_ts),
+        Some((ctx.accounts.source_pool.key, &source_data)),
+    )
+}
+
+/// List a pool created before the pool registry existed
+///
+/// Permissionless: the payer only funds a new registry page when one is needed.
+/// `is_registered` keeps a pool from being listed twice.
+pub fn register_pool<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = RegisterPoolAccounts::context(accounts)?;
+
+    // Verify pool account discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // Load pool
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+
+    // Guards
+    assert_signer("payer", ctx.accounts.payer)?;
+    assert_writable("payer", ctx.accounts.payer)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_pool_address(ctx.accounts.pool, &pool_data)?;
+
+    if pool_data.is_registered {
+        msg!("Pool {} is already registered", ctx.accounts.pool.key);
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    let page = append_to_pool_registry(
+        ctx.accounts.pool_registry,
+        ctx.accounts.previous_pool_registry,
+        PoolRegistryEntry {
+            pool: *ctx.accounts.pool.key,
+            stake_mint: pool_data.stake_mint,
+            pool_id: pool_data.pool_id,
+        },
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )?;
+    pool_data.is_registered = true;
+
     // Save state first to ensure persistence before emitting event
     pool_data.save(ctx.accounts.pool)?;
 
     // Emit event for off-chain indexing after successful state save
-    sol_log_data(&[
-        b"InitializePool",
-        ctx.accounts.pool.key.as_ref(),
-        ctx.accounts.payer.key.as_ref(),
-        &pool_id.to_le_bytes(),
-        &reward_rate.to_le_bytes(),
-    ]);
-
-    Ok(())
+    StakePoolEvent::PoolRegistered(PoolRegisteredEvent {
+        pool: *ctx.accounts.pool.key,
+        page,
+    })
+    .emit()
 }
@@ -0,0 +1,66 @@
+//! Keeper bounty escrow
+//!
+//! Permissionless cranks (FinalizeRewardRateChange, FinalizeLockupChange,
+//! SyncPool and CrankRewardStream) pay the pool's `keeper_bounty` to an
+//! optional keeper account. The bounty comes out of the pool account's lamports
+//! above its rent-exempt minimum, which anyone can top up here. Payouts are
+//! capped at MAX_KEEPER_BOUNTY and at most one per KEEPER_BOUNTY_INTERVAL, so
+//! spamming a repeatable crank cannot drain the escrow.
+
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::assertions::*;
+use crate::error::StakePoolError;
+use crate::events::{KeeperBountyFundedEvent, StakePoolEvent};
+use crate::instruction::accounts::*;
+use crate::state::Key;
+use crate::utils::transfer_lamports;
+
+use super::helpers::keeper_bounty_escrow;
+
+/// Add `lamports` to the pool's keeper bounty escrow (anyone)
+///
+/// # Errors
+/// Returns error if:
+/// - `lamports` is zero
+/// - The pool is not a stake pool of this program
+pub fn fund_keeper_bounty<'a>(accounts: &'a [AccountInfo<'a>], lamports: u64) -> ProgramResult {
+    if lamports == 0 {
+        msg!("Fund amount must be greater than zero");
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    let ctx = FundKeeperBountyAccounts::context(accounts)?;
+
+    // Verify pool account discriminator (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // Guards
+    assert_signer("funder", ctx.accounts.funder)?;
+    assert_writable("funder", ctx.accounts.funder)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_same_pubkeys(
+        "system_program",
+        ctx.accounts.system_program,
+        &solana_program::system_program::ID,
+    )?;
+
+    transfer_lamports(ctx.accounts.funder, ctx.accounts.pool, lamports, None)?;
+
+    let escrow = keeper_bounty_escrow(ctx.accounts.pool)?;
+    msg!(
+        "Funded keeper bounty of pool {} with {} lamports (escrow: {})",
+        ctx.accounts.pool.key,
+        lamports,
+        escrow
+    );
+
+    StakePoolEvent::KeeperBountyFunded(KeeperBountyFundedEvent {
+        pool: *ctx.accounts.pool.key,
+        funder: *ctx.accounts.funder.key,
+        lamports,
+        escrow,
+    })
+    .emit()
+}
@@ -0,0 +1,54 @@
+//! Stake account labels
+//!
+//! Owners may tag a position with a short label (e.g. "treasury-Q3") so that
+//! wallets and indexers can display it. The label is opaque to the program: it
+//! is stored as given, set at stake time or later with `set_stake_label`.
+
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::assertions::*;
+use crate::events::{StakeLabelUpdatedEvent, StakePoolEvent};
+use crate::instruction::accounts::*;
+use crate::state::{Key, StakeAccount};
+
+/// Set or clear the label of a stake account (owner only)
+pub fn set_stake_label<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    label: [u8; StakeAccount::LABEL_LEN],
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = SetStakeLabelAccounts::context(accounts)?;
+
+    // Verify stake account discriminator before loading (Type Cosplay protection)
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+
+    // Verify program ownership
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+
+    // Load stake account
+    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+
+    // Guards
+    assert_signer("owner", ctx.accounts.owner)?;
+    assert_writable("stake_account", ctx.accounts.stake_account)?;
+    assert_same_pubkeys("owner", ctx.accounts.owner, &stake_account_data.owner)?;
+
+    stake_account_data.label = label;
+    stake_account_data.save(ctx.accounts.stake_account)?;
+
+    msg!(
+        "Label of stake account {} updated",
+        ctx.accounts.stake_account.key
+    );
+
+    // Emit event for off-chain indexing
+    StakePoolEvent::StakeLabelUpdated(StakeLabelUpdatedEvent {
+        stake_account: *ctx.accounts.stake_account.key,
+        label,
+    })
+    .emit()
+}
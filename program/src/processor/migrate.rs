@@ -0,0 +1,330 @@
+//! Upgrade of accounts written in the first deployed layout
+//!
+//! Pools and stake accounts created by the first deployed program version carry
+//! the `StakePoolV1` / `StakeAccountV1` discriminators. `StakePool::load` and
+//! `StakeAccount::load` can read them, but every other instruction rejects them
+//! with `AccountNeedsMigration`. `migrate_pool` grows them to the current size
+//! and rewrites them with the V2 discriminators, filling in what the V1 layout
+//! did not record: the fee vault, the vault bumps and the mint registry entry.
+//! V2 pools and stake accounts allocated before their type last grew are grown
+//! to the current size.
+//!
+//! Pools, stake accounts and the program authority now start with an 8-byte
+//! discriminator ahead of their key. V2 accounts written before it was added
+//! start with the key; `migrate_pool` and `migrate_program_authority` grow
+//! them and rewrite them with the discriminator.
+//!
+//! A program authority still holding the fixed creator array carries the
+//! `ProgramAuthorityV1` discriminator; `migrate_program_authority` rewrites it
+//! with the growable creator list.
+//!
+//! `resize_pool` grows current pools ahead of a layout change instead: fields
+//! appended in the zeroed space load as their zero value, so such a release
+//! needs no migration.
+
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::assertions::*;
+use crate::constants::MAX_POOL_ACCOUNT_LEN;
+use crate::error::StakePoolError;
+use crate::events::{
+    PoolMigratedEvent, PoolResizedEvent, ProgramAuthorityMigratedEvent, StakePoolEvent,
+};
+use crate::instruction::accounts::*;
+use crate::state::{Key, ProgramAuthority, StakeAccount, StakePool};
+use crate::utils::realloc_account;
+
+use super::helpers::{
+    assert_pool_address, load_or_create_mint_registry, verify_token_account, verify_vault_ownership,
+};
+
+/// Migrate a V1 pool and the V1 stake accounts passed as remaining accounts (global admin only)
+pub fn migrate_pool<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = MigratePoolAccounts::context(accounts)?;
+
+    // Load program authority to verify admin permissions
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    assert_program_owner(
+        "program_authority",
+        ctx.accounts.program_authority,
+        &crate::ID,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_writable("admin", ctx.accounts.admin)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // Verify the signer is authorized as a global admin
+    if !program_authority.is_authorized(ctx.accounts.admin.key) {
+        msg!(
+            "Unauthorized: {} is not a global admin",
+            ctx.accounts.admin.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    // Load the pool in either layout (V1 pools are upgraded in memory)
+    let pool_migrated = StakePool::is_legacy_layout(ctx.accounts.pool);
+    let pool_lacks_discriminator = StakePool::lacks_discriminator(ctx.accounts.pool);
+    if !pool_migrated && !pool_lacks_discriminator {
+        assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    }
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+    assert_pool_address(ctx.accounts.pool, &pool_data)?;
+
+    if pool_migrated {
+        assert_writable("mint_registry", ctx.accounts.mint_registry)?;
+
+        // V1 pools had no fee vault; it is validated like at pool initialization
+        verify_token_account(ctx.accounts.fee_vault, &pool_data.stake_mint, None, None)?;
+        verify_vault_ownership(ctx.accounts.fee_vault, ctx.accounts.pool.key, "fee_vault")?;
+        pool_data.fee_vault = *ctx.accounts.fee_vault.key;
+
+        // Record vault bumps for vaults at their program-derived addresses
+        let pool_key = ctx.accounts.pool.key;
+        let (stake_vault_pda, stake_vault_bump) = StakePool::find_stake_vault_pda(pool_key);
+        let (reward_vault_pda, reward_vault_bump) = StakePool::find_reward_vault_pda(pool_key);
+        let (fee_vault_pda, fee_vault_bump) = StakePool::find_fee_vault_pda(pool_key);
+        pool_data.stake_vault_bump =
+            (pool_data.stake_vault == stake_vault_pda).then_some(stake_vault_bump);
+        pool_data.reward_vault_bump =
+            (pool_data.reward_vault == reward_vault_pda).then_some(reward_vault_bump);
+        pool_data.fee_vault_bump = (pool_data.fee_vault == fee_vault_pda).then_some(fee_vault_bump);
+
+        // V1 stakes predate the registry, so the pool's stake is recorded now
+        let mut mint_registry = load_or_create_mint_registry(
+            ctx.accounts.mint_registry,
+            &pool_data.stake_mint,
+            ctx.accounts.admin,
+            ctx.accounts.system_program,
+        )?;
+        mint_registry.record_stake(pool_data.total_staked)?;
+
+        realloc_account(
+            ctx.accounts.pool,
+            ctx.accounts.admin,
+            ctx.accounts.system_program,
+            StakePool::LEN,
+            false,
+        )?;
+        pool_data.save(ctx.accounts.pool)?;
+        mint_registry.save(ctx.accounts.mint_registry)?;
+
+        msg!(
+            "Pool migrated to V2: total_staked={}, fee_vault={}",
+            pool_data.total_staked,
+            pool_data.fee_vault
+        );
+    } else if pool_lacks_discriminator || ctx.accounts.pool.data_len() < StakePool::LEN {
+        // The missing tail was read as zeros, so the pool is saved unchanged
+        // (behind the discriminator if it had none)
+        realloc_account(
+            ctx.accounts.pool,
+            ctx.accounts.admin,
+            ctx.accounts.system_program,
+            StakePool::LEN,
+            false,
+        )?;
+        pool_data.save(ctx.accounts.pool)?;
+
+        msg!("Pool grown to {} bytes", StakePool::LEN);
+    }
+
+    let mut stake_accounts_migrated: u32 = 0;
+    for stake_account in ctx.remaining_accounts {
+        assert_program_owner("stake_account", stake_account, &crate::ID)?;
+        let legacy = StakeAccount::is_legacy_layout(stake_account);
+        if !legacy && !StakeAccount::lacks_discriminator(stake_account) {
+            assert_account_key("stake_account", stake_account, Key::StakeAccountV2)?;
+            if stake_account.data_len() >= StakeAccount::LEN {
+                continue;
+            }
+        }
+
+        assert_writable("stake_account", stake_account)?;
+        let mut stake_account_data = StakeAccount::load(stake_account)?;
+        assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
+
+        // V1 rewards followed the pool's current rate, which is locked in from now on
+        if legacy {
+            stake_account_data.locked_reward_rate = pool_data.reward_rate;
+        }
+
+        realloc_account(
+            stake_account,
+            ctx.accounts.admin,
+            ctx.accounts.system_program,
+            StakeAccount::LEN,
+            false,
+        )?;
+        stake_account_data.save(stake_account)?;
+
+        stake_accounts_migrated = stake_accounts_migrated
+            .checked_add(1)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+    }
+
+    msg!(
+        "Migrated {} stake accounts of pool {}",
+        stake_accounts_migrated,
+        ctx.accounts.pool.key
+    );
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::PoolMigrated(PoolMigratedEvent {
+        pool: *ctx.accounts.pool.key,
+        admin: *ctx.accounts.admin.key,
+        pool_migrated,
+        stake_accounts_migrated,
+    })
+    .emit()
+}
+
+/// Migrate a program authority holding the fixed creator array or lacking the
+/// discriminator (program authority only)
+///
+/// # Errors
+/// Returns error if:
+/// - Caller is not the program authority
+/// - The program authority is already in the current layout
+pub fn migrate_program_authority<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = MigrateProgramAuthorityAccounts::context(accounts)?;
+
+    assert_program_owner(
+        "program_authority",
+        ctx.accounts.program_authority,
+        &crate::ID,
+    )?;
+    if !ProgramAuthority::is_legacy_layout(ctx.accounts.program_authority)
+        && !ProgramAuthority::lacks_discriminator(ctx.accounts.program_authority)
+    {
+        msg!("Program authority already uses the current layout");
+        return Err(StakePoolError::InvalidAccountKey.into());
+    }
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("authority", ctx.accounts.authority)?;
+    assert_signer("payer", ctx.accounts.payer)?;
+    assert_writable("program_authority", ctx.accounts.program_authority)?;
+    assert_writable("payer", ctx.accounts.payer)?;
+
+    // Verify the signer is the program authority
+    if ctx.accounts.authority.key != &program_authority.authority {
+        msg!(
+            "Unauthorized: {} is not the program authority",
+            ctx.accounts.authority.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    // Accounts written before the type last grew may be too small for the list
+    let required_len = program_authority.required_len();
+    if required_len > ctx.accounts.program_authority.data_len() {
+        realloc_account(
+            ctx.accounts.program_authority,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+            required_len,
+            false,
+        )?;
+    }
+
+    // Bounded by MAX_CREATORS, checked on load
+    let creator_count = program_authority.creator_count() as u16;
+    msg!(
+        "Migrated program authority with {} authorized creators",
+        creator_count
+    );
+
+    // Save state first to ensure persistence before emitting event
+    program_authority.save(ctx.accounts.program_authority)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::ProgramAuthorityMigrated(ProgramAuthorityMigratedEvent {
+        authority: program_authority.authority,
+        creator_count,
+    })
+    .emit()
+}
+
+/// Grow a current pool account to `new_len` bytes (global admin only)
+///
+/// # Errors
+/// Returns error if:
+/// - Caller is not a global admin
+/// - The pool still needs MigratePool
+/// - `new_len` does not exceed the current size or exceeds MAX_POOL_ACCOUNT_LEN
+pub fn resize_pool<'a>(accounts: &'a [AccountInfo<'a>], new_len: u32) -> ProgramResult {
+    let ctx = ResizePoolAccounts::context(accounts)?;
+
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    assert_program_owner(
+        "program_authority",
+        ctx.accounts.program_authority,
+        &crate::ID,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    let pool_data = StakePool::load(ctx.accounts.pool)?;
+    assert_pool_address(ctx.accounts.pool, &pool_data)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_writable("admin", ctx.accounts.admin)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+
+    if !program_authority.is_authorized(ctx.accounts.admin.key) {
+        msg!(
+            "Unauthorized: {} is not a global admin",
+            ctx.accounts.admin.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    let old_len = ctx.accounts.pool.data_len();
+    let new_len_bytes = new_len as usize;
+    if new_len_bytes <= old_len || new_len_bytes > MAX_POOL_ACCOUNT_LEN {
+        msg!(
+            "New pool size {} must exceed the current {} bytes and be at most {}",
+            new_len,
+            old_len,
+            MAX_POOL_ACCOUNT_LEN
+        );
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    // The pool's data is unchanged; the added bytes are zeroed by the resize
+    realloc_account(
+        ctx.accounts.pool,
+        ctx.accounts.admin,
+        ctx.accounts.system_program,
+        new_len_bytes,
+        false,
+    )?;
+
+    msg!("Pool grown from {} to {} bytes", old_len, new_len);
+
+    // Bounded by MAX_POOL_ACCOUNT_LEN
+    StakePoolEvent::PoolResized(PoolResizedEvent {
+        pool: *ctx.accounts.pool.key,
+        admin: *ctx.accounts.admin.key,
+        old_len: old_len as u32,
+        new_len,
+    })
+    .emit()
+}
@@ -4,25 +4,78 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
+use crate::error::StakePoolError;
 use crate::instruction::StakePoolInstruction;
 
 mod admin;
+mod approval;
+mod audit;
 mod close;
+mod cohort;
+mod config;
+mod cooldown;
+mod delegate;
+mod expiry;
+mod fees;
 pub mod helpers;
+mod hook;
 mod initialize;
+mod keeper;
+mod label;
+mod migrate;
+mod points;
+mod position;
+mod receipt;
+mod recovery;
+mod relock;
 mod rewards;
+mod slash;
+mod split;
 mod stake;
+mod stream;
+mod sync;
+mod transfer;
+mod wind_down;
 
 // Re-export handler functions
 pub use admin::{
     accept_program_authority, cancel_authority_transfer, check_authorization,
-    finalize_reward_rate_change, get_authorized_creators, initialize_program_authority,
-    manage_authorized_creators, transfer_program_authority, update_pool,
+    close_program_authority, finalize_lockup_change, finalize_reward_rate_change,
+    get_authorized_creators, initialize_program_authority, manage_authorized_creators,
+    manage_deny_list, manage_hook_allowlist, manage_pool_allowlist, manage_roles,
+    set_creator_pool_limit, set_global_pause, set_max_creators, set_mint_tvl_cap, set_pool_hook,
+    set_reward_funders, transfer_program_authority, update_pool, update_pools_batch, PoolUpdate,
 };
-pub use close::close_stake_account;
-pub use initialize::initialize_pool;
-pub use rewards::{claim_rewards, fund_rewards};
-pub use stake::{stake, unstake};
+pub use approval::{
+    approve_pool_update, execute_pool_update, propose_pool_update, set_approval_threshold,
+};
+pub use audit::audit_pool;
+pub use close::{close_pool, close_stake_account};
+pub use cohort::{defund_cohort_rewards, fund_cohort_rewards};
+pub use config::{initialize_program_config, update_program_config};
+pub use cooldown::{request_unstake, withdraw_unstaked};
+pub use delegate::set_stake_delegate;
+pub use expiry::{claim_expired_rewards, expire_rewards};
+pub use fees::{sweep_dust, withdraw_fees};
+pub use initialize::{clone_pool, initialize_pool, register_pool};
+pub use keeper::fund_keeper_bounty;
+pub use label::set_stake_label;
+pub use migrate::{migrate_pool, migrate_program_authority, resize_pool};
+pub use points::{get_user_points, sync_points};
+pub use position::get_stake_position;
+pub use receipt::enable_receipt_token;
+pub use recovery::{complete_recovery, initiate_recovery, set_recovery_authority};
+pub use relock::{extend_lockup, relock, set_auto_relock};
+pub use rewards::{
+    claim_rewards, claim_rewards_batch, defund_rewards, fund_rewards, get_pending_rewards,
+};
+pub use slash::{cancel_slash, execute_slash, propose_slash};
+pub use split::{merge_stakes, split_stake};
+pub use stake::{stake, stake_batch, stake_sol, unstake, unstake_all, unstake_sol, vesting_stake};
+pub use stream::{close_reward_stream, crank_reward_stream, create_reward_stream};
+pub use sync::{get_pool_stats, sync_pool};
+pub use transfer::{accept_stake_ownership, nominate_stake_owner};
+pub use wind_down::wind_down_stake;
 
 pub fn process_instruction<'a>(
     _program_id: &Pubkey,
@@ -36,12 +89,58 @@ pub fn process_instruction<'a>(
     }
 
     // Deserialize instruction with explicit error handling
-    let instruction: StakePoolInstruction = StakePoolInstruction::try_from_slice(instruction_data)
-        .map_err(|_| {
-            msg!("Failed to deserialize instruction");
-            ProgramError::InvalidInstructionData
-        })?;
+    let mut data = instruction_data;
+    let instruction = StakePoolInstruction::deserialize(&mut data).map_err(|_| {
+        msg!("Failed to deserialize instruction");
+        ProgramError::InvalidInstructionData
+    })?;
+
+    // Trailing bytes mean the client encoded a different layout than the program reads
+    if !data.is_empty() {
+        msg!("Instruction data has {} trailing bytes", data.len());
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let name = instruction.name();
+    msg!("Instruction: {}", name);
+
+    instruction
+        .check_lengths()
+        .and_then(|()| dispatch(accounts, instruction))
+        .inspect_err(|error| log_error(name, error))
+}
+
+/// Log which instruction failed and which program the error belongs to
+///
+/// Custom error codes share one number space across programs: Custom(1) is
+/// `SerializationError` here but `InsufficientFunds` in the token program. A
+/// failing CPI aborts the transaction inside the callee (the runtime logs the
+/// callee's program id with its code), so a Custom code that reaches this
+/// point was raised by this program and is decoded as a `StakePoolError`.
+fn log_error(instruction: &str, error: &ProgramError) {
+    match error {
+        ProgramError::Custom(code) => match StakePoolError::from_code(*code) {
+            Some(stake_pool_error) => msg!(
+                "{} failed: StakePoolError::{:?} (custom program error: {:#x}): {}",
+                instruction,
+                stake_pool_error,
+                code,
+                stake_pool_error
+            ),
+            None => msg!(
+                "{} failed: custom program error {:#x} is not a StakePoolError",
+                instruction,
+                code
+            ),
+        },
+        error => msg!("{} failed: {}", instruction, error),
+    }
+}
 
+fn dispatch<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    instruction: StakePoolInstruction,
+) -> ProgramResult {
     match instruction {
         StakePoolInstruction::InitializePool {
             pool_id,
@@ -50,102 +149,381 @@ pub fn process_instruction<'a>(
             lockup_period,
             enforce_lockup,
             pool_end_date,
-        } => {
-            msg!("Instruction: InitializePool");
-            initialize_pool(
-                accounts,
-                pool_id,
-                reward_rate,
-                min_stake_amount,
-                lockup_period,
-                enforce_lockup,
-                pool_end_date,
-            )
-        }
+            referral_bps,
+            early_unstake_bps,
+            deposit_fee_bps,
+            withdraw_fee_bps,
+            reward_rate_change_delay,
+            min_reward_rate_bound,
+            max_reward_rate_bound,
+            reward_tiers,
+            bonus_end_ts,
+            bonus_multiplier_bps,
+            reward_rate_mode,
+        } => initialize_pool(
+            accounts,
+            pool_id,
+            reward_rate,
+            min_stake_amount,
+            lockup_period,
+            enforce_lockup,
+            pool_end_date,
+            referral_bps,
+            early_unstake_bps,
+            deposit_fee_bps,
+            withdraw_fee_bps,
+            reward_rate_change_delay,
+            min_reward_rate_bound,
+            max_reward_rate_bound,
+            reward_tiers,
+            bonus_end_ts,
+            bonus_multiplier_bps,
+            reward_rate_mode,
+        ),
         StakePoolInstruction::Stake {
             amount,
             index,
             expected_reward_rate,
             expected_lockup_period,
-        } => {
-            msg!("Instruction: Stake");
-            stake(
-                accounts,
-                amount,
-                index,
-                expected_reward_rate,
-                expected_lockup_period,
-            )
-        }
+            referrer,
+            auto_relock,
+            label,
+            allowlist_proof,
+            memo,
+            deadline,
+        } => stake(
+            accounts,
+            amount,
+            index,
+            expected_reward_rate,
+            expected_lockup_period,
+            referrer,
+            auto_relock,
+            label,
+            allowlist_proof,
+            memo,
+            deadline,
+        ),
         StakePoolInstruction::Unstake {
             amount,
             expected_reward_rate,
-        } => {
-            msg!("Instruction: Unstake");
-            unstake(accounts, amount, expected_reward_rate)
+            memo,
+            deadline,
+        } => unstake(accounts, amount, expected_reward_rate, memo, deadline),
+        StakePoolInstruction::ClaimRewards {
+            amount,
+            memo,
+            deadline,
+        } => claim_rewards(accounts, amount, memo, deadline),
+        StakePoolInstruction::UpdatePool {
+            reward_rate,
+            min_stake_amount,
+            lockup_period,
+            is_paused,
+            enforce_lockup,
+            pool_end_date,
+            referral_bps,
+            early_unstake_bps,
+            deposit_fee_bps,
+            withdraw_fee_bps,
+            unstake_cooldown,
+            bonus_end_ts,
+            bonus_multiplier_bps,
+            lockup_extension_rate,
+            allowlist_merkle_root,
+            claim_window,
+            outflow_limit,
+            pool_manager,
+            keeper_bounty,
+            max_total_rewards,
+            enforce_min_remaining_stake,
+            wind_down_rewards,
+            points_rate,
+            deny_list_required,
+            allow_third_party_stake,
+            cohort_duration,
+        } => update_pool(
+            accounts,
+            reward_rate,
+            min_stake_amount,
+            lockup_period,
+            is_paused,
+            enforce_lockup,
+            pool_end_date,
+            referral_bps,
+            early_unstake_bps,
+            deposit_fee_bps,
+            withdraw_fee_bps,
+            unstake_cooldown,
+            bonus_end_ts,
+            bonus_multiplier_bps,
+            lockup_extension_rate,
+            allowlist_merkle_root,
+            claim_window,
+            outflow_limit,
+            pool_manager,
+            keeper_bounty,
+            max_total_rewards,
+            enforce_min_remaining_stake,
+            wind_down_rewards,
+            points_rate,
+            deny_list_required,
+            allow_third_party_stake,
+            cohort_duration,
+        ),
+        StakePoolInstruction::FundRewards { amount } => fund_rewards(accounts, amount),
+        StakePoolInstruction::CloseStakeAccount => close_stake_account(accounts),
+        StakePoolInstruction::FinalizeRewardRateChange => finalize_reward_rate_change(accounts),
+        StakePoolInstruction::InitializeProgramAuthority { cluster_profile } => {
+            initialize_program_authority(accounts, cluster_profile)
+        }
+        StakePoolInstruction::ManageAuthorizedCreators { add, remove } => {
+            manage_authorized_creators(accounts, add, remove)
         }
-        StakePoolInstruction::ClaimRewards => {
-            msg!("Instruction: ClaimRewards");
-            claim_rewards(accounts)
+        StakePoolInstruction::TransferProgramAuthority => transfer_program_authority(accounts),
+        StakePoolInstruction::AcceptProgramAuthority => accept_program_authority(accounts),
+        StakePoolInstruction::GetAuthorizedCreators => get_authorized_creators(accounts),
+        StakePoolInstruction::CheckAuthorization { address } => {
+            check_authorization(accounts, address)
         }
-        StakePoolInstruction::UpdatePool {
+        StakePoolInstruction::CancelAuthorityTransfer => cancel_authority_transfer(accounts),
+        StakePoolInstruction::WithdrawFees { amount } => withdraw_fees(accounts, amount),
+        StakePoolInstruction::UpdatePoolsBatch {
             reward_rate,
             min_stake_amount,
             lockup_period,
             is_paused,
             enforce_lockup,
             pool_end_date,
-        } => {
-            msg!("Instruction: UpdatePool");
-            update_pool(
-                accounts,
+            referral_bps,
+            early_unstake_bps,
+            deposit_fee_bps,
+            withdraw_fee_bps,
+            unstake_cooldown,
+            bonus_end_ts,
+            bonus_multiplier_bps,
+            lockup_extension_rate,
+            allowlist_merkle_root,
+            claim_window,
+            outflow_limit,
+            pool_manager,
+            keeper_bounty,
+            max_total_rewards,
+            enforce_min_remaining_stake,
+            wind_down_rewards,
+            points_rate,
+            deny_list_required,
+            allow_third_party_stake,
+            cohort_duration,
+        } => update_pools_batch(
+            accounts,
+            PoolUpdate {
                 reward_rate,
                 min_stake_amount,
                 lockup_period,
                 is_paused,
                 enforce_lockup,
                 pool_end_date,
-            )
+                referral_bps,
+                early_unstake_bps,
+                deposit_fee_bps,
+                withdraw_fee_bps,
+                unstake_cooldown,
+                bonus_end_ts,
+                bonus_multiplier_bps,
+                lockup_extension_rate,
+                allowlist_merkle_root,
+                claim_window,
+                outflow_limit,
+                pool_manager,
+                keeper_bounty,
+                max_total_rewards,
+                enforce_min_remaining_stake,
+                wind_down_rewards,
+                points_rate,
+                deny_list_required,
+                allow_third_party_stake,
+                cohort_duration,
+            },
+        ),
+        StakePoolInstruction::StakeSol {
+            amount,
+            index,
+            expected_reward_rate,
+            expected_lockup_period,
+            referrer,
+            auto_relock,
+            label,
+            allowlist_proof,
+        } => stake_sol(
+            accounts,
+            amount,
+            index,
+            expected_reward_rate,
+            expected_lockup_period,
+            referrer,
+            auto_relock,
+            label,
+            allowlist_proof,
+        ),
+        StakePoolInstruction::UnstakeSol {
+            amount,
+            expected_reward_rate,
+        } => unstake_sol(accounts, amount, expected_reward_rate),
+        StakePoolInstruction::SetAutoRelock { auto_relock } => {
+            set_auto_relock(accounts, auto_relock)
         }
-        StakePoolInstruction::FundRewards { amount } => {
-            msg!("Instruction: FundRewards");
-            fund_rewards(accounts, amount)
+        StakePoolInstruction::Relock => relock(accounts),
+        StakePoolInstruction::SyncPool => sync_pool(accounts),
+        StakePoolInstruction::CloseProgramAuthority => close_program_authority(accounts),
+        StakePoolInstruction::SetRewardFunders { funders } => set_reward_funders(accounts, funders),
+        StakePoolInstruction::DefundRewards { amount } => defund_rewards(accounts, amount),
+        StakePoolInstruction::SetMintTvlCap { tvl_cap } => set_mint_tvl_cap(accounts, tvl_cap),
+        StakePoolInstruction::ClosePool => close_pool(accounts),
+        StakePoolInstruction::SetStakeDelegate { delegate } => {
+            set_stake_delegate(accounts, delegate)
         }
-        StakePoolInstruction::CloseStakeAccount => {
-            msg!("Instruction: CloseStakeAccount");
-            close_stake_account(accounts)
+        StakePoolInstruction::EnableReceiptToken { transferable } => {
+            enable_receipt_token(accounts, transferable)
         }
-        StakePoolInstruction::FinalizeRewardRateChange => {
-            msg!("Instruction: FinalizeRewardRateChange");
-            finalize_reward_rate_change(accounts)
+        StakePoolInstruction::ExpireRewards => expire_rewards(accounts),
+        StakePoolInstruction::ClaimExpiredRewards => claim_expired_rewards(accounts),
+        StakePoolInstruction::RequestUnstake {
+            amount,
+            expected_reward_rate,
+        } => request_unstake(accounts, amount, expected_reward_rate),
+        StakePoolInstruction::WithdrawUnstaked => withdraw_unstaked(accounts),
+        StakePoolInstruction::SetGlobalPause {
+            global_pause,
+            withdrawals_only,
+        } => set_global_pause(accounts, global_pause, withdrawals_only),
+        StakePoolInstruction::MigratePool => migrate_pool(accounts),
+        StakePoolInstruction::StakeBatch {
+            amounts,
+            start_index,
+            expected_reward_rate,
+            expected_lockup_period,
+            allowlist_proof,
+        } => stake_batch(
+            accounts,
+            amounts,
+            start_index,
+            expected_reward_rate,
+            expected_lockup_period,
+            allowlist_proof,
+        ),
+        StakePoolInstruction::ClaimRewardsBatch => claim_rewards_batch(accounts),
+        StakePoolInstruction::GetPendingRewards => get_pending_rewards(accounts),
+        StakePoolInstruction::RegisterPool => register_pool(accounts),
+        StakePoolInstruction::SetCreatorPoolLimit {
+            creator,
+            pool_limit,
+        } => set_creator_pool_limit(accounts, creator, pool_limit),
+        StakePoolInstruction::ManageRoles { holder, roles } => {
+            manage_roles(accounts, holder, roles)
         }
-        StakePoolInstruction::InitializeProgramAuthority => {
-            msg!("Instruction: InitializeProgramAuthority");
-            initialize_program_authority(accounts)
+        StakePoolInstruction::SetApprovalThreshold { threshold } => {
+            set_approval_threshold(accounts, threshold)
         }
-        StakePoolInstruction::ManageAuthorizedCreators { add, remove } => {
-            msg!("Instruction: ManageAuthorizedCreators");
-            manage_authorized_creators(accounts, add, remove)
+        StakePoolInstruction::ProposePoolUpdate { action_id, update } => {
+            propose_pool_update(accounts, action_id, update)
         }
-        StakePoolInstruction::TransferProgramAuthority => {
-            msg!("Instruction: TransferProgramAuthority");
-            transfer_program_authority(accounts)
+        StakePoolInstruction::ApprovePoolUpdate => approve_pool_update(accounts),
+        StakePoolInstruction::ExecutePoolUpdate => execute_pool_update(accounts),
+        StakePoolInstruction::FinalizeLockupChange => finalize_lockup_change(accounts),
+        StakePoolInstruction::SetStakeLabel { label } => set_stake_label(accounts, label),
+        StakePoolInstruction::NominateStakeOwner { new_owner } => {
+            nominate_stake_owner(accounts, new_owner)
         }
-        StakePoolInstruction::AcceptProgramAuthority => {
-            msg!("Instruction: AcceptProgramAuthority");
-            accept_program_authority(accounts)
+        StakePoolInstruction::AcceptStakeOwnership { new_index } => {
+            accept_stake_ownership(accounts, new_index)
         }
-        StakePoolInstruction::GetAuthorizedCreators => {
-            msg!("Instruction: GetAuthorizedCreators");
-            get_authorized_creators(accounts)
+        StakePoolInstruction::SweepDust => sweep_dust(accounts),
+        StakePoolInstruction::GetPoolStats => get_pool_stats(accounts),
+        StakePoolInstruction::ExtendLockup { additional_seconds } => {
+            extend_lockup(accounts, additional_seconds)
         }
-        StakePoolInstruction::CheckAuthorization { address } => {
-            msg!("Instruction: CheckAuthorization");
-            check_authorization(accounts, address)
+        StakePoolInstruction::ManagePoolAllowlist {
+            page,
+            add,
+            remove,
+            allowlist_required,
+        } => manage_pool_allowlist(accounts, page, add, remove, allowlist_required),
+        StakePoolInstruction::VestingStake {
+            amount,
+            index,
+            vesting_cliff,
+            vesting_duration,
+        } => vesting_stake(accounts, amount, index, vesting_cliff, vesting_duration),
+        StakePoolInstruction::ProposeSlash {
+            slash_bps,
+            treasury,
+        } => propose_slash(accounts, slash_bps, treasury),
+        StakePoolInstruction::ExecuteSlash => execute_slash(accounts),
+        StakePoolInstruction::CancelSlash => cancel_slash(accounts),
+        StakePoolInstruction::SetRecoveryAuthority {
+            recovery_authority,
+            recovery_delay,
+        } => set_recovery_authority(accounts, recovery_authority, recovery_delay),
+        StakePoolInstruction::InitiateRecovery => initiate_recovery(accounts),
+        StakePoolInstruction::CompleteRecovery => complete_recovery(accounts),
+        StakePoolInstruction::InitializeProgramConfig {
+            feature_flags,
+            max_reward_rate,
+            min_lockup_period,
+        } => initialize_program_config(accounts, feature_flags, max_reward_rate, min_lockup_period),
+        StakePoolInstruction::UpdateProgramConfig {
+            feature_flags,
+            max_reward_rate,
+            min_lockup_period,
+        } => update_program_config(accounts, feature_flags, max_reward_rate, min_lockup_period),
+        StakePoolInstruction::SetMaxCreators { max_creators } => {
+            set_max_creators(accounts, max_creators)
+        }
+        StakePoolInstruction::MigrateProgramAuthority => migrate_program_authority(accounts),
+        StakePoolInstruction::ClonePool {
+            pool_id,
+            pool_end_date,
+            bonus_end_ts,
+        } => clone_pool(accounts, pool_id, pool_end_date, bonus_end_ts),
+        StakePoolInstruction::CreateRewardStream {
+            rate_per_second,
+            end_ts,
+        } => create_reward_stream(accounts, rate_per_second, end_ts),
+        StakePoolInstruction::CrankRewardStream => crank_reward_stream(accounts),
+        StakePoolInstruction::CloseRewardStream => close_reward_stream(accounts),
+        StakePoolInstruction::FundKeeperBounty { lamports } => {
+            fund_keeper_bounty(accounts, lamports)
         }
-        StakePoolInstruction::CancelAuthorityTransfer => {
-            msg!("Instruction: CancelAuthorityTransfer");
-            cancel_authority_transfer(accounts)
+        StakePoolInstruction::ResizePool { new_len } => resize_pool(accounts, new_len),
+        StakePoolInstruction::GetStakePosition => get_stake_position(accounts),
+        StakePoolInstruction::AuditPool { repair } => audit_pool(accounts, repair),
+        StakePoolInstruction::WindDownStake => wind_down_stake(accounts),
+        StakePoolInstruction::ManageHookAllowlist { add, remove } => {
+            manage_hook_allowlist(accounts, add, remove)
         }
+        StakePoolInstruction::SetPoolHook { hook_program } => set_pool_hook(accounts, hook_program),
+        StakePoolInstruction::SyncPoints => sync_points(accounts),
+        StakePoolInstruction::GetUserPoints => get_user_points(accounts),
+        StakePoolInstruction::ManageDenyList {
+            add,
+            remove,
+            allow_listed_withdrawals,
+        } => manage_deny_list(accounts, add, remove, allow_listed_withdrawals),
+        StakePoolInstruction::SplitStake { amount, new_index } => {
+            split_stake(accounts, amount, new_index)
+        }
+        StakePoolInstruction::MergeStakes => merge_stakes(accounts),
+        StakePoolInstruction::FundCohortRewards { cohort, amount } => {
+            fund_cohort_rewards(accounts, cohort, amount)
+        }
+        StakePoolInstruction::DefundCohortRewards { amount } => {
+            defund_cohort_rewards(accounts, amount)
+        }
+        StakePoolInstruction::UnstakeAll {
+            expected_reward_rate,
+            memo,
+            deadline,
+        } => unstake_all(accounts, expected_reward_rate, memo, deadline),
     }
 }
@@ -0,0 +1,157 @@
+//! Loyalty points
+//!
+//! Pools with a `points_rate` let every stake accrue points per staked base
+//! unit per second, e.g. to weight an airdrop. Accrual is lazy: the
+//! permissionless `sync_points` crank credits what a stake earned since its
+//! last sync to the owner's `UserPoints`, and `get_user_points` reports the
+//! credited points together with what the stakes passed to it would add. No
+//! token moves, and points cannot be transferred.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+use crate::assertions::*;
+use crate::error::StakePoolError;
+use crate::events::{PointsSyncedEvent, StakePoolEvent};
+use crate::instruction::accounts::*;
+use crate::return_data::{self, PointsBalance};
+use crate::state::{Key, StakeAccount, StakePool, UserPoints};
+
+use super::helpers::{load_or_create_user_points, load_user_points, validate_current_timestamp};
+
+/// Credit the points a stake accrued since its last sync to its owner's UserPoints
+///
+/// Permissionless: points only ever go to the stake's owner, and syncing early
+/// cannot lower them since accrual is linear in time.
+pub fn sync_points<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = SyncPointsAccounts::context(accounts)?;
+
+    // Verify account discriminators before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+
+    // Load accounts
+    let pool_data = StakePool::load(ctx.accounts.pool)?;
+    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+
+    // Guards
+    assert_writable("stake_account", ctx.accounts.stake_account)?;
+    assert_writable("user_points", ctx.accounts.user_points)?;
+    assert_signer("payer", ctx.accounts.payer)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
+    if pool_data.points_rate == 0 {
+        msg!("Pool {} does not accrue points", ctx.accounts.pool.key);
+        return Err(StakePoolError::PointsNotEnabled.into());
+    }
+
+    let clock = Clock::get()?;
+    validate_current_timestamp(clock.unix_timestamp)?;
+
+    let mut user_points = load_or_create_user_points(
+        ctx.accounts.user_points,
+        ctx.accounts.pool.key,
+        &stake_account_data.owner,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )?;
+
+    let points = pool_data.accrue_points(&mut stake_account_data, clock.unix_timestamp)?;
+    user_points.credit(points, clock.unix_timestamp)?;
+
+    stake_account_data.save(ctx.accounts.stake_account)?;
+    user_points.save(ctx.accounts.user_points)?;
+
+    msg!(
+        "Synced {} points of stake {} to {} (total: {})",
+        points,
+        ctx.accounts.stake_account.key,
+        stake_account_data.owner,
+        user_points.points
+    );
+
+    StakePoolEvent::PointsSynced(PointsSyncedEvent {
+        pool: *ctx.accounts.pool.key,
+        stake_account: *ctx.accounts.stake_account.key,
+        owner: stake_account_data.owner,
+        points,
+        total_points: user_points.points,
+    })
+    .emit()
+}
+
+/// Return an owner's synced points and those the stake accounts passed as
+/// remaining accounts accrued since their last sync
+pub fn get_user_points<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = GetUserPointsAccounts::context(accounts)?;
+
+    // Verify pool account discriminator and ownership (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    let pool_data = StakePool::load(ctx.accounts.pool)?;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // An owner never synced has no UserPoints yet
+    let synced_points = if ctx.accounts.user_points.data_is_empty() {
+        let (points_key, _) = UserPoints::find_pda(ctx.accounts.pool.key, ctx.accounts.owner.key);
+        assert_same_pubkeys("user_points", ctx.accounts.user_points, &points_key)?;
+        0
+    } else {
+        load_user_points(
+            ctx.accounts.user_points,
+            ctx.accounts.pool.key,
+            ctx.accounts.owner.key,
+        )?
+        .points
+    };
+
+    let mut pending_points: u128 = 0;
+    for (position, stake_account) in ctx.remaining_accounts.iter().enumerate() {
+        // A repeated account would be counted twice
+        if ctx.remaining_accounts[..position]
+            .iter()
+            .any(|previous| previous.key == stake_account.key)
+        {
+            msg!("Stake account {} is listed twice", stake_account.key);
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+
+        // Verify stake account discriminator before loading (Type Cosplay protection)
+        assert_account_key("stake_account", stake_account, Key::StakeAccountV2)?;
+        assert_program_owner("stake_account", stake_account, &crate::ID)?;
+
+        let mut stake_account_data = StakeAccount::load(stake_account)?;
+        assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
+        assert_same_pubkeys("owner", ctx.accounts.owner, &stake_account_data.owner)?;
+
+        pending_points = pending_points
+            .checked_add(pool_data.accrue_points(&mut stake_account_data, current_time)?)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+    }
+
+    msg!(
+        "Points of {}: {} synced, {} pending",
+        ctx.accounts.owner.key,
+        synced_points,
+        pending_points
+    );
+
+    return_data::set(&PointsBalance {
+        current_time,
+        synced_points,
+        pending_points,
+    })
+}
@@ -0,0 +1,49 @@
+//! Stake position directories
+//!
+//! Stake accounts live at ["stake_account", pool, owner, index], so finding a
+//! free index used to take trial PDA lookups. Stakes that pass the owner's
+//! `StakePosition` record their index there (and CloseStakeAccount removes
+//! it); `get_stake_position` hands the directory back to clients.
+
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+
+use crate::assertions::*;
+use crate::instruction::accounts::*;
+use crate::return_data::{self, StakePositions};
+use crate::state::{Key, StakePosition};
+
+use super::helpers::load_stake_position;
+
+pub fn get_stake_position<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = GetStakePositionAccounts::context(accounts)?;
+
+    // Verify pool account discriminator and ownership (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // An owner who never staked through the directory has none yet
+    if ctx.accounts.stake_position.data_is_empty() {
+        let (position_key, _) =
+            StakePosition::find_pda(ctx.accounts.pool.key, ctx.accounts.owner.key);
+        assert_same_pubkeys("stake_position", ctx.accounts.stake_position, &position_key)?;
+        msg!("No stake position recorded for {}", ctx.accounts.owner.key);
+        return return_data::set(&StakePositions::default());
+    }
+
+    let position = load_stake_position(
+        ctx.accounts.stake_position,
+        ctx.accounts.pool.key,
+        ctx.accounts.owner.key,
+    )?;
+
+    msg!(
+        "Stake position: {} open stakes, next index {}",
+        position.indices.len(),
+        position.next_index
+    );
+
+    return_data::set(&StakePositions {
+        next_index: position.next_index,
+        indices: position.indices,
+    })
+}
@@ -0,0 +1,256 @@
+//! Receipt tokens for tokenized positions
+//!
+//! A pool can opt into tokenization with `enable_receipt_token`, which creates
+//! a Token-2022 receipt mint at `["receipt_mint", pool]` with the pool PDA as
+//! mint authority. From then on `stake` mints receipt tokens 1:1 with the
+//! amount added to the position and `unstake` burns the amount withdrawn, so
+//! the receipt supply always equals the pool's `total_staked`. Receipts are
+//! non-transferable unless the admin chose otherwise.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use spl_token_2022::{
+    extension::{ExtensionType, StateWithExtensions},
+    instruction::{burn, initialize_mint2, initialize_non_transferable_mint, mint_to},
+    state::Mint,
+};
+
+use crate::assertions::*;
+use crate::error::StakePoolError;
+use crate::events::{ReceiptTokenEnabledEvent, StakePoolEvent};
+use crate::instruction::accounts::*;
+use crate::state::{Key, ProgramAuthority, StakePool};
+use crate::utils::create_account;
+
+use super::helpers::{verify_token_account, verify_token_account_owner};
+
+/// Receipt accounts of a tokenized pool, validated against the pool
+pub(super) struct ReceiptAccounts<'a> {
+    mint: &'a AccountInfo<'a>,
+    user_account: &'a AccountInfo<'a>,
+    token_program: &'a AccountInfo<'a>,
+}
+
+impl<'a> ReceiptAccounts<'a> {
+    /// Resolve the optional receipt accounts of a stake or unstake instruction
+    ///
+    /// Returns None for pools without a receipt mint (the accounts are ignored).
+    /// For tokenized pools all three accounts are required and the user account
+    /// must be a receipt token account owned by `owner`.
+    pub(super) fn resolve(
+        pool_data: &StakePool,
+        mint: Option<&'a AccountInfo<'a>>,
+        user_account: Option<&'a AccountInfo<'a>>,
+        token_program: Option<&'a AccountInfo<'a>>,
+        owner: &Pubkey,
+    ) -> Result<Option<Self>, ProgramError> {
+        let Some(receipt_mint) = pool_data.receipt_mint else {
+            return Ok(None);
+        };
+
+        let (Some(mint), Some(user_account), Some(token_program)) =
+            (mint, user_account, token_program)
+        else {
+            msg!(
+                "Pool tokenizes positions with receipt mint {}; receipt accounts are required",
+                receipt_mint
+            );
+            return Err(StakePoolError::ReceiptAccountsRequired.into());
+        };
+
+        assert_same_pubkeys("receipt_mint", mint, &receipt_mint)?;
+        assert_same_pubkeys("receipt_token_program", token_program, &spl_token_2022::ID)?;
+        assert_writable("receipt_mint", mint)?;
+        assert_writable("user_receipt_account", user_account)?;
+        verify_token_account(user_account, &receipt_mint, None, None)?;
+        verify_token_account_owner(user_account, owner, "user_receipt_account")?;
+
+        Ok(Some(Self {
+            mint,
+            user_account,
+            token_program,
+        }))
+    }
+
+    /// Mint `amount` receipt tokens to the user (signed by the pool PDA)
+    pub(super) fn mint(
+        &self,
+        pool: &'a AccountInfo<'a>,
+        amount: u64,
+        pool_signer_seeds: &[&[u8]],
+    ) -> ProgramResult {
+        invoke_signed(
+            &mint_to(
+                self.token_program.key,
+                self.mint.key,
+                self.user_account.key,
+                pool.key,
+                &[],
+                amount,
+            )?,
+            &[self.mint.clone(), self.user_account.clone(), pool.clone()],
+            &[pool_signer_seeds],
+        )
+    }
+
+    /// Burn `amount` receipt tokens from the user (signed by the owner)
+    pub(super) fn burn(&self, owner: &'a AccountInfo<'a>, amount: u64) -> ProgramResult {
+        invoke(
+            &burn(
+                self.token_program.key,
+                self.user_account.key,
+                self.mint.key,
+                owner.key,
+                &[],
+                amount,
+            )?,
+            &[self.user_account.clone(), self.mint.clone(), owner.clone()],
+        )
+    }
+}
+
+/// Create the pool's receipt mint and start tokenizing positions (global admin only)
+pub fn enable_receipt_token<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    transferable: bool,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = EnableReceiptTokenAccounts::context(accounts)?;
+
+    // Verify pool account discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // Load pool
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+
+    // Load program authority to verify admin permissions
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("admin", ctx.accounts.admin)?;
+    assert_writable("receipt_mint", ctx.accounts.receipt_mint)?;
+    assert_empty("receipt_mint", ctx.accounts.receipt_mint)?;
+    assert_same_pubkeys("stake_mint", ctx.accounts.stake_mint, &pool_data.stake_mint)?;
+    assert_same_pubkeys(
+        "receipt_token_program",
+        ctx.accounts.receipt_token_program,
+        &spl_token_2022::ID,
+    )?;
+
+    // Verify the signer is authorized as a global admin
+    if !program_authority.is_authorized(ctx.accounts.admin.key) {
+        msg!(
+            "Unauthorized: {} is not a global admin",
+            ctx.accounts.admin.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    if let Some(receipt_mint) = pool_data.receipt_mint {
+        msg!("Pool already tokenizes positions with {}", receipt_mint);
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    // Positions opened before tokenization would have no receipts to burn
+    if pool_data.total_staked != 0 {
+        msg!(
+            "Receipt tokens can only be enabled while nothing is staked. Total staked: {}",
+            pool_data.total_staked
+        );
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    let (receipt_mint_key, receipt_mint_bump) =
+        StakePool::find_receipt_mint_pda(ctx.accounts.pool.key);
+    assert_same_pubkeys("receipt_mint", ctx.accounts.receipt_mint, &receipt_mint_key)?;
+
+    let decimals = {
+        let mint_data = ctx.accounts.stake_mint.try_borrow_data()?;
+        StateWithExtensions::<Mint>::unpack(&mint_data)?
+            .base
+            .decimals
+    };
+
+    let extensions: &[ExtensionType] = if transferable {
+        &[]
+    } else {
+        &[ExtensionType::NonTransferable]
+    };
+    let mint_len = ExtensionType::try_calculate_account_len::<Mint>(extensions)?;
+
+    let receipt_mint_bump = [receipt_mint_bump];
+    let receipt_mint_seeds: &[&[u8]] = &[
+        StakePool::RECEIPT_MINT_SEED,
+        ctx.accounts.pool.key.as_ref(),
+        &receipt_mint_bump,
+    ];
+
+    create_account(
+        ctx.accounts.receipt_mint,
+        ctx.accounts.admin,
+        ctx.accounts.system_program,
+        mint_len,
+        &spl_token_2022::ID,
+        Some(&[receipt_mint_seeds]),
+    )?;
+
+    // Extensions must be initialized before the mint itself
+    if !transferable {
+        invoke(
+            &initialize_non_transferable_mint(
+                ctx.accounts.receipt_token_program.key,
+                ctx.accounts.receipt_mint.key,
+            )?,
+            std::slice::from_ref(ctx.accounts.receipt_mint),
+        )?;
+    }
+    invoke(
+        &initialize_mint2(
+            ctx.accounts.receipt_token_program.key,
+            ctx.accounts.receipt_mint.key,
+            ctx.accounts.pool.key,
+            None,
+            decimals,
+        )?,
+        std::slice::from_ref(ctx.accounts.receipt_mint),
+    )?;
+
+    pool_data.receipt_mint = Some(receipt_mint_key);
+
+    msg!(
+        "Receipt token enabled: mint {} ({})",
+        receipt_mint_key,
+        if transferable {
+            "transferable"
+        } else {
+            "non-transferable"
+        }
+    );
+
+    // Save state first to ensure persistence before emitting event
+    pool_data.save(ctx.accounts.pool)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::ReceiptTokenEnabled(ReceiptTokenEnabledEvent {
+        pool: *ctx.accounts.pool.key,
+        receipt_mint: receipt_mint_key,
+        transferable,
+    })
+    .emit()
+}
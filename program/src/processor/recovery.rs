@@ -0,0 +1,155 @@
+//! Recovery of a lost program authority
+//!
+//! The program authority can name a recovery authority with a recovery delay.
+//! If the authority key is lost, the recovery key initiates a takeover and,
+//! once the delay has passed, completes it and becomes the authority. While
+//! the delay runs, a live authority stops the takeover with
+//! CancelAuthorityTransfer, or by replacing the recovery key.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+use crate::assertions::*;
+use crate::error::StakePoolError;
+use crate::events::{
+    AuthorityChangeEvent, RecoveryAuthorityUpdatedEvent, RecoveryInitiatedEvent, StakePoolEvent,
+};
+use crate::instruction::accounts::*;
+
+use super::approval::load_program_authority;
+use super::helpers::validate_current_timestamp;
+
+/// Set or remove the recovery authority and its delay (program authority only)
+///
+/// # Errors
+/// Returns error if:
+/// - Caller is not the program authority
+/// - The recovery authority is the authority itself
+/// - The delay is outside MIN_RECOVERY_DELAY..=MAX_RECOVERY_DELAY
+pub fn set_recovery_authority<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    recovery_authority: Option<Pubkey>,
+    recovery_delay: i64,
+) -> ProgramResult {
+    let ctx = SetRecoveryAuthorityAccounts::context(accounts)?;
+
+    let mut program_authority = load_program_authority(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("authority", ctx.accounts.authority)?;
+    assert_writable("program_authority", ctx.accounts.program_authority)?;
+
+    // Verify the signer is the program authority
+    if ctx.accounts.authority.key != &program_authority.authority {
+        msg!(
+            "Unauthorized: {} is not the program authority",
+            ctx.accounts.authority.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    program_authority.set_recovery(recovery_authority, recovery_delay)?;
+
+    match recovery_authority {
+        Some(recovery_authority) => msg!(
+            "Recovery authority set to {} with a {} second delay",
+            recovery_authority,
+            program_authority.recovery_delay
+        ),
+        None => msg!("Recovery authority removed"),
+    }
+
+    // Save state first to ensure persistence before emitting event
+    program_authority.save(ctx.accounts.program_authority)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::RecoveryAuthorityUpdated(RecoveryAuthorityUpdatedEvent {
+        authority: program_authority.authority,
+        recovery_authority,
+        recovery_delay: program_authority.recovery_delay,
+    })
+    .emit()
+}
+
+/// Start a takeover of the program authority (recovery authority only)
+///
+/// # Errors
+/// Returns error if:
+/// - Caller is not the recovery authority
+/// - A recovery is already in progress
+pub fn initiate_recovery<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = InitiateRecoveryAccounts::context(accounts)?;
+
+    let mut program_authority = load_program_authority(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("recovery_authority", ctx.accounts.recovery_authority)?;
+    assert_writable("program_authority", ctx.accounts.program_authority)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    validate_current_timestamp(current_time)?;
+
+    let completes_at =
+        program_authority.initiate_recovery(ctx.accounts.recovery_authority.key, current_time)?;
+
+    msg!(
+        "Authority recovery initiated by {}. Completes at: {}",
+        ctx.accounts.recovery_authority.key,
+        completes_at
+    );
+
+    // Save state first to ensure persistence before emitting event
+    program_authority.save(ctx.accounts.program_authority)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::RecoveryInitiated(RecoveryInitiatedEvent {
+        authority: program_authority.authority,
+        recovery_authority: *ctx.accounts.recovery_authority.key,
+        completes_at,
+    })
+    .emit()
+}
+
+/// Hand the program authority to the recovery authority once the delay has passed
+///
+/// # Errors
+/// Returns error if:
+/// - Caller is not the recovery authority
+/// - No recovery is in progress
+/// - The recovery delay has not passed
+pub fn complete_recovery<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = CompleteRecoveryAccounts::context(accounts)?;
+
+    let mut program_authority = load_program_authority(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("recovery_authority", ctx.accounts.recovery_authority)?;
+    assert_writable("program_authority", ctx.accounts.program_authority)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    validate_current_timestamp(current_time)?;
+
+    let previous_authority =
+        program_authority.complete_recovery(ctx.accounts.recovery_authority.key, current_time)?;
+
+    msg!(
+        "Authority recovery complete. Old: {}, New: {}",
+        previous_authority,
+        program_authority.authority
+    );
+
+    // Save state first to ensure persistence before emitting event
+    program_authority.save(ctx.accounts.program_authority)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::RecoveryCompleted(AuthorityChangeEvent {
+        current_authority: previous_authority,
+        new_authority: program_authority.authority,
+    })
+    .emit()
+}
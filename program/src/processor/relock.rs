@@ -0,0 +1,251 @@
+//! Auto re-lock of matured stake accounts
+//!
+//! Owners opt a stake account into auto re-lock at stake time or with
+//! `set_auto_relock`. Once its lockup expires, anyone may call `relock` to start
+//! another lockup round: unclaimed rewards of the completed round are carried
+//! over and the new round's reward is reserved against the reward vault.
+//!
+//! Owners may also lengthen a round that has not matured yet with
+//! `extend_lockup`, locking in the pool's lockup extension bonus.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+use crate::assertions::*;
+use crate::error::StakePoolError;
+use crate::events::{AutoRelockUpdatedEvent, LockupExtendedEvent, RelockEvent, StakePoolEvent};
+use crate::instruction::accounts::*;
+use crate::state::{Key, PoolVault, StakeAccount, StakePool};
+
+use super::helpers::{
+    emit_if_rewards_cap_reached, get_token_account_balance, validate_current_timestamp,
+    verify_token_account,
+};
+
+/// Enable or disable auto re-lock on a stake account (owner only)
+pub fn set_auto_relock<'a>(accounts: &'a [AccountInfo<'a>], auto_relock: bool) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = SetAutoRelockAccounts::context(accounts)?;
+
+    // Verify stake account discriminator before loading (Type Cosplay protection)
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+
+    // Verify program ownership
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+
+    // Load stake account
+    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+
+    // Guards
+    assert_signer("owner", ctx.accounts.owner)?;
+    assert_writable("stake_account", ctx.accounts.stake_account)?;
+    assert_same_pubkeys("owner", ctx.accounts.owner, &stake_account_data.owner)?;
+
+    stake_account_data.auto_relock = auto_relock;
+    stake_account_data.save(ctx.accounts.stake_account)?;
+
+    msg!(
+        "Auto re-lock {} for stake account {}",
+        if auto_relock { "enabled" } else { "disabled" },
+        ctx.accounts.stake_account.key
+    );
+
+    StakePoolEvent::AutoRelockUpdated(AutoRelockUpdatedEvent {
+        stake_account: *ctx.accounts.stake_account.key,
+        auto_relock,
+    })
+    .emit()
+}
+
+/// Restart the expired lockup of a stake account with auto re-lock enabled
+///
+/// Permissionless: the stake account's opt-in is the authorization. Relocking
+/// reserves another full round of rewards, so it is refused when the pool is
+/// paused, has ended, or the reward vault cannot cover the new total owed.
+pub fn relock<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = RelockAccounts::context(accounts)?;
+
+    // Verify account discriminators before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+
+    // Load accounts
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+
+    // Guards
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("stake_account", ctx.accounts.stake_account)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
+    assert_vault(
+        "reward_vault",
+        ctx.accounts.reward_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Reward,
+    )?;
+    verify_token_account(
+        ctx.accounts.reward_vault,
+        &pool_data.reward_mint,
+        None,
+        None,
+    )?;
+
+    if pool_data.is_paused {
+        msg!(
+            "Pool {} is currently paused. Re-locking is disabled.",
+            ctx.accounts.pool.key
+        );
+        return Err(StakePoolError::PoolPaused.into());
+    }
+
+    let clock = Clock::get()?;
+    validate_current_timestamp(clock.unix_timestamp)?;
+
+    // A new lockup round is a new commitment, which ended pools no longer accept
+    pool_data.check_not_ended(clock.unix_timestamp)?;
+
+    let round_rewards = pool_data.relock_stake(&mut stake_account_data, clock.unix_timestamp)?;
+
+    // The new round's reward must be covered on top of everything already owed
+    let reward_vault_balance = get_token_account_balance(ctx.accounts.reward_vault)?;
+    pool_data.verify_solvency(reward_vault_balance)?;
+
+    msg!(
+        "Re-locked stake account {} until {}, reserved {} reward tokens (carried over: {})",
+        ctx.accounts.stake_account.key,
+        pool_data.stake_maturity(&stake_account_data)?,
+        round_rewards,
+        stake_account_data.carried_rewards
+    );
+
+    // Save state first to ensure persistence before emitting event
+    pool_data.save(ctx.accounts.pool)?;
+    stake_account_data.save(ctx.accounts.stake_account)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::Relock(RelockEvent {
+        pool: *ctx.accounts.pool.key,
+        stake_account: *ctx.accounts.stake_account.key,
+        stake_timestamp: stake_account_data.stake_timestamp,
+        round_rewards,
+        carried_rewards: stake_account_data.carried_rewards,
+    })
+    .emit()?;
+    emit_if_rewards_cap_reached(ctx.accounts.pool, &pool_data, clock.unix_timestamp)
+}
+
+/// Extend the lockup of a stake account's current round by `additional_seconds` (owner only)
+///
+/// The stake locks in the pool's lockup extension bonus, reserved like any other
+/// round reward, so it is refused when the pool is paused, has ended, or the
+/// reward vault cannot cover the new total owed.
+pub fn extend_lockup<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    additional_seconds: i64,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = ExtendLockupAccounts::context(accounts)?;
+
+    // Verify account discriminators before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+
+    // Load accounts
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+
+    // Guards
+    assert_signer("owner", ctx.accounts.owner)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("stake_account", ctx.accounts.stake_account)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
+    assert_same_pubkeys("owner", ctx.accounts.owner, &stake_account_data.owner)?;
+    assert_vault(
+        "reward_vault",
+        ctx.accounts.reward_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Reward,
+    )?;
+    verify_token_account(
+        ctx.accounts.reward_vault,
+        &pool_data.reward_mint,
+        None,
+        None,
+    )?;
+
+    if pool_data.is_paused {
+        msg!(
+            "Pool {} is currently paused. Lockup extensions are disabled.",
+            ctx.accounts.pool.key
+        );
+        return Err(StakePoolError::PoolPaused.into());
+    }
+
+    let clock = Clock::get()?;
+    validate_current_timestamp(clock.unix_timestamp)?;
+
+    // Ended pools accept no new commitments
+    pool_data.check_not_ended(clock.unix_timestamp)?;
+
+    let bonus_rewards = pool_data.extend_lockup(
+        &mut stake_account_data,
+        additional_seconds,
+        clock.unix_timestamp,
+    )?;
+
+    // The bonus must be covered on top of everything already owed
+    let reward_vault_balance = get_token_account_balance(ctx.accounts.reward_vault)?;
+    pool_data.verify_solvency(reward_vault_balance)?;
+
+    let matures_at = pool_data.stake_maturity(&stake_account_data)?;
+    msg!(
+        "Extended lockup of stake account {} by {} seconds until {}, reserved {} bonus reward tokens",
+        ctx.accounts.stake_account.key,
+        additional_seconds,
+        matures_at,
+        bonus_rewards
+    );
+
+    // Save state first to ensure persistence before emitting event
+    pool_data.save(ctx.accounts.pool)?;
+    stake_account_data.save(ctx.accounts.stake_account)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::LockupExtended(LockupExtendedEvent {
+        pool: *ctx.accounts.pool.key,
+        stake_account: *ctx.accounts.stake_account.key,
+        owner: *ctx.accounts.owner.key,
+        additional_seconds,
+        matures_at,
+        bonus_rewards,
+    })
+    .emit()?;
+    emit_if_rewards_cap_reached(ctx.accounts.pool, &pool_data, clock.unix_timestamp)
+}
@@ -1,59 +1,462 @@
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
-    log::sol_log_data,
     msg,
+    program_error::ProgramError,
     sysvar::{clock::Clock, Sysvar},
 };
 
 use crate::assertions::*;
 use crate::error::StakePoolError;
+use crate::events::{
+    ClaimBatchEvent, ClaimEvent, ReferralPayoutEvent, RewardsFundedEvent, StakeClaim,
+    StakePoolEvent, VaultWithdrawalEvent,
+};
+use crate::hook::StakePoolHookInstruction;
 use crate::instruction::accounts::*;
-use crate::state::{Key, StakeAccount, StakePool};
+use crate::return_data::{self, ClaimRewardsResult};
+use crate::state::{
+    Key, PoolVault, ProgramAuthority, Role, StakeAccount, StakePool, UserOperation,
+};
 use crate::utils::transfer_tokens_with_fee;
 
+use super::cohort::load_stake_cohort;
 use super::helpers::{
-    get_token_account_balance, validate_current_timestamp, verify_reward_token_accounts,
+    check_deadline, check_global_pause, create_associated_token_account_if_missing,
+    get_token_account_balance, get_token_account_owner, resolve_deny_list,
+    validate_current_timestamp, verify_reward_token_accounts, verify_token_account,
+    verify_token_account_owner, write_memo,
 };
+use super::hook::PoolHook;
+
+/// Accounts shared by `claim_rewards` and `unstake_all`
+pub(super) struct ClaimAccountInfos<'a> {
+    pub(super) pool: &'a AccountInfo<'a>,
+    pub(super) stake_account: &'a AccountInfo<'a>,
+    pub(super) owner: &'a AccountInfo<'a>,
+    pub(super) user_reward_account: &'a AccountInfo<'a>,
+    pub(super) reward_vault: &'a AccountInfo<'a>,
+    pub(super) reward_mint: &'a AccountInfo<'a>,
+    pub(super) token_program: &'a AccountInfo<'a>,
+    pub(super) clock: &'a AccountInfo<'a>,
+    pub(super) referrer_reward_account: Option<&'a AccountInfo<'a>>,
+    pub(super) program_authority: &'a AccountInfo<'a>,
+    pub(super) recipient: Option<&'a AccountInfo<'a>>,
+    pub(super) associated_token_program: Option<&'a AccountInfo<'a>>,
+    pub(super) system_program: Option<&'a AccountInfo<'a>>,
+    pub(super) deny_list: Option<&'a AccountInfo<'a>>,
+    pub(super) reward_cohort: Option<&'a AccountInfo<'a>>,
+    pub(super) hook_program: Option<&'a AccountInfo<'a>>,
+    pub(super) hook_accounts: &'a [AccountInfo<'a>],
+}
 
-pub fn claim_rewards<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+pub fn claim_rewards<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    amount: Option<u64>,
+    memo: Option<String>,
+    deadline: Option<i64>,
+) -> ProgramResult {
     // Parse accounts using ShankContext-generated struct
     let ctx = ClaimRewardsAccounts::context(accounts)?;
 
+    check_deadline("ClaimRewards", deadline, Clock::get()?.unix_timestamp)?;
+
+    write_memo(
+        memo.as_deref(),
+        ctx.accounts.memo_program,
+        ctx.accounts.owner,
+    )?;
+
+    let result = process_claim(
+        ClaimAccountInfos {
+            pool: ctx.accounts.pool,
+            stake_account: ctx.accounts.stake_account,
+            owner: ctx.accounts.owner,
+            user_reward_account: ctx.accounts.user_reward_account,
+            reward_vault: ctx.accounts.reward_vault,
+            reward_mint: ctx.accounts.reward_mint,
+            token_program: ctx.accounts.token_program,
+            clock: ctx.accounts.clock,
+            referrer_reward_account: ctx.accounts.referrer_reward_account,
+            program_authority: ctx.accounts.program_authority,
+            recipient: ctx.accounts.recipient,
+            associated_token_program: ctx.accounts.associated_token_program,
+            system_program: ctx.accounts.system_program,
+            deny_list: ctx.accounts.deny_list,
+            reward_cohort: ctx.accounts.reward_cohort,
+            hook_program: ctx.accounts.hook_program,
+            hook_accounts: ctx.remaining_accounts,
+        },
+        amount,
+    )?;
+
+    // Set after the hook ran, since the hook's own return data would replace it
+    return_data::set(&result)
+}
+
+/// Pay `amount` of a stake account's unclaimed rewards (all of them when None)
+///
+/// Claiming before maturity is not an error: the returned result tells the
+/// caller when the stake matures instead.
+pub(super) fn process_claim<'a>(
+    accounts: ClaimAccountInfos<'a>,
+    amount: Option<u64>,
+) -> Result<ClaimRewardsResult, ProgramError> {
     // Verify account discriminators before loading (Type Cosplay protection)
-    assert_account_key("pool", ctx.accounts.pool, Key::StakePool)?;
-    assert_account_key(
-        "stake_account",
-        ctx.accounts.stake_account,
-        Key::StakeAccount,
+    assert_account_key("pool", accounts.pool, Key::StakePoolV2)?;
+    assert_account_key("stake_account", accounts.stake_account, Key::StakeAccountV2)?;
+
+    // Verify program ownership
+    assert_program_owner("pool", accounts.pool, &crate::ID)?;
+    assert_program_owner("stake_account", accounts.stake_account, &crate::ID)?;
+
+    // Load accounts
+    let mut pool_data = StakePool::load(accounts.pool)?;
+    let mut stake_account_data = StakeAccount::load(accounts.stake_account)?;
+
+    // Guards
+    assert_signer("owner", accounts.owner)?;
+    assert_writable("pool", accounts.pool)?;
+    assert_writable("stake_account", accounts.stake_account)?;
+    assert_writable("user_reward_account", accounts.user_reward_account)?;
+    assert_writable("reward_vault", accounts.reward_vault)?;
+    if !stake_account_data.can_claim(accounts.owner.key) {
+        msg!(
+            "{} is neither the owner nor the delegate of stake account {}",
+            accounts.owner.key,
+            accounts.stake_account.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+    assert_same_pubkeys("pool", accounts.pool, &stake_account_data.pool)?;
+    // Stakes of a reward cohort are paid from the cohort's vault
+    let mut reward_cohort = match stake_account_data.reward_cohort {
+        0 => {
+            assert_vault(
+                "reward_vault",
+                accounts.reward_vault,
+                accounts.pool.key,
+                &pool_data,
+                PoolVault::Reward,
+            )?;
+            None
+        }
+        cohort => Some(load_stake_cohort(
+            accounts.reward_cohort,
+            accounts.reward_vault,
+            accounts.pool.key,
+            cohort,
+        )?),
+    };
+    assert_same_pubkeys("reward_mint", accounts.reward_mint, &pool_data.reward_mint)?;
+    check_global_pause(accounts.program_authority, UserOperation::Claim)?;
+    let hook = PoolHook::resolve(&pool_data, accounts.hook_program, accounts.hook_accounts)?;
+
+    // Wallets may claim without creating the owner's reward token account first:
+    // the owner pays for the associated token account, created here when missing
+    if let Some(associated_token_program) = accounts.associated_token_program {
+        if accounts.owner.key != &stake_account_data.owner {
+            msg!("Only the stake owner can create the reward token account during a claim");
+            return Err(StakePoolError::Unauthorized.into());
+        }
+        let system_program = accounts.system_program.ok_or_else(|| {
+            msg!("system_program is required to create the reward token account");
+            ProgramError::NotEnoughAccountKeys
+        })?;
+        assert_writable("owner", accounts.owner)?;
+        create_associated_token_account_if_missing(
+            accounts.user_reward_account,
+            accounts.owner,
+            accounts.reward_mint,
+            accounts.owner,
+            system_program,
+            accounts.token_program,
+            associated_token_program,
+            "user_reward_account",
+        )?;
+    }
+
+    // Rewards are paid to a token account of the stake owner, unless the owner
+    // designates another recipient (e.g. a cold wallet). A delegate claims on the
+    // owner's behalf and can never redirect rewards.
+    let destination = match accounts.recipient {
+        Some(recipient) => {
+            if accounts.owner.key != &stake_account_data.owner {
+                msg!("Only the stake owner can designate a reward recipient");
+                return Err(StakePoolError::Unauthorized.into());
+            }
+            assert_writable("recipient", recipient)?;
+            if recipient.key == accounts.reward_vault.key {
+                msg!("Recipient cannot be the pool's reward vault");
+                return Err(StakePoolError::InvalidParameters.into());
+            }
+            msg!("Paying rewards to designated recipient {}", recipient.key);
+            recipient
+        }
+        None => {
+            verify_token_account_owner(
+                accounts.user_reward_account,
+                &stake_account_data.owner,
+                "user_reward_account",
+            )?;
+            accounts.user_reward_account
+        }
+    };
+
+    // Verify token accounts belong to correct mints
+    verify_reward_token_accounts(destination, accounts.reward_vault, &pool_data.reward_mint)?;
+    if let Some(deny_list) = resolve_deny_list(&pool_data, accounts.deny_list)? {
+        deny_list.check("Stake owner", &stake_account_data.owner)?;
+        deny_list.check("Destination owner", &get_token_account_owner(destination)?)?;
+    }
+
+    // Get current time
+    let clock = Clock::from_account_info(accounts.clock)?;
+    validate_current_timestamp(clock.unix_timestamp)?;
+    pool_data.check_claim_window(clock.unix_timestamp)?;
+
+    // Calculate total rewards based on stake duration and reward rate
+    // Rewards are only given if lockup period is complete (plus rewards carried over by Relock)
+    let total_rewards = pool_data.stake_rewards(&stake_account_data, clock.unix_timestamp)?;
+
+    // Calculate unclaimed rewards (total - already claimed)
+    let unclaimed_rewards = total_rewards
+        .checked_sub(stake_account_data.claimed_rewards)
+        .ok_or(StakePoolError::NumericalOverflow)?;
+
+    if unclaimed_rewards == 0 {
+        // Claiming before maturity is not an error: tell the caller when the stake
+        // matures and how much it will be able to claim so wallets can show a countdown
+        let matures_at = pool_data.stake_maturity(&stake_account_data)?;
+        let result = if stake_account_data.amount_staked > 0 && clock.unix_timestamp < matures_at {
+            let projected_rewards = pool_data
+                .stake_rewards(&stake_account_data, matures_at)?
+                .checked_sub(stake_account_data.claimed_rewards)
+                .ok_or(StakePoolError::NumericalOverflow)?;
+            msg!(
+                "Lockup period not complete. Matures at: {}, projected rewards: {}",
+                matures_at,
+                projected_rewards
+            );
+            ClaimRewardsResult::LockupPending {
+                matures_at,
+                projected_rewards,
+                reward_rate: stake_account_data.locked_reward_rate,
+            }
+        } else {
+            msg!("No rewards to claim - no stake or rewards already claimed");
+            ClaimRewardsResult::NothingToClaim
+        };
+        return Ok(result);
+    }
+
+    // A partial claim leaves the remaining rewards claimable later
+    let claim_amount = StakeAccount::claim_amount(unclaimed_rewards, amount)?;
+
+    // Check reward vault has sufficient balance
+    let reward_vault_balance = get_token_account_balance(accounts.reward_vault)?;
+    if reward_vault_balance < claim_amount {
+        msg!(
+            "Insufficient rewards in vault. Required: {}, Available: {}",
+            claim_amount,
+            reward_vault_balance
+        );
+        return Err(StakePoolError::InsufficientRewards.into());
+    }
+
+    // Count the claim against the pool's outflow limit
+    pool_data.record_claim_outflow(claim_amount, clock.unix_timestamp)?;
+
+    // Referral share is carved out of the claimed amount (never paid on top of it),
+    // so the referrer and the owner together receive exactly what the protocol owes
+    let referral_payout = match stake_account_data.referrer {
+        Some(referrer) if pool_data.referral_bps > 0 => {
+            let referral_amount = pool_data.referral_share(claim_amount)?;
+
+            let referrer_account = accounts.referrer_reward_account.ok_or_else(|| {
+                msg!(
+                    "Stake has referrer {} but no referrer_reward_account was provided",
+                    referrer
+                );
+                StakePoolError::InvalidReferrer
+            })?;
+            assert_writable("referrer_reward_account", referrer_account)?;
+            verify_token_account(referrer_account, &pool_data.reward_mint, None, None)?;
+            verify_token_account_owner(referrer_account, &referrer, "referrer_reward_account")?;
+
+            Some((referrer, referrer_account, referral_amount))
+        }
+        _ => None,
+    };
+    let referral_amount = referral_payout.map_or(0, |(_, _, amount)| amount);
+    let owner_amount = claim_amount
+        .checked_sub(referral_amount)
+        .ok_or(StakePoolError::NumericalOverflow)?;
+
+    // Transfer rewards (with PDA signer)
+    let pool_seeds = StakePool::seeds(&pool_data.stake_mint, pool_data.pool_id);
+    let mut seeds_with_bump = pool_seeds.clone();
+    seeds_with_bump.push(vec![pool_data.bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    // Transfer rewards (with PDA signer)
+    // Capture actual amount transferred in case of transfer fees
+    let actual_amount = transfer_tokens_with_fee(
+        accounts.reward_vault,
+        destination,
+        accounts.reward_mint,
+        accounts.pool,
+        accounts.token_program,
+        owner_amount,
+        &[&seeds_refs],
     )?;
 
+    if let Some((referrer, referrer_account, referral_amount)) = referral_payout {
+        if referral_amount > 0 {
+            let referral_received = transfer_tokens_with_fee(
+                accounts.reward_vault,
+                referrer_account,
+                accounts.reward_mint,
+                accounts.pool,
+                accounts.token_program,
+                referral_amount,
+                &[&seeds_refs],
+            )?;
+
+            msg!(
+                "Paid {} reward tokens to referrer {} (actual: {})",
+                referral_amount,
+                referrer,
+                referral_received
+            );
+
+            StakePoolEvent::ReferralPayout(ReferralPayoutEvent {
+                pool: *accounts.pool.key,
+                stake_account: *accounts.stake_account.key,
+                referrer,
+                amount: referral_amount,
+            })
+            .emit()?;
+        }
+    }
+
+    // Update claimed rewards tracking with the COMMITTED amount (claim_amount)
+    // NOT the actual amount received after fees.
+    //
+    // Rationale: The protocol committed to pay 'claim_amount' tokens to the user.
+    // If transfer fees apply, the user receives less (actual_amount < claim_amount),
+    // but this is a cost borne by the user, not a reason to allow repeated claims.
+    // Recording the full committed amount ensures:
+    // - User cannot claim the same reward multiple times
+    // - total_rewards calculation remains consistent across claims
+    // - Transfer fees are properly accounted for as user cost
+    stake_account_data.claimed_rewards = stake_account_data
+        .claimed_rewards
+        .checked_add(claim_amount)
+        .ok_or(StakePoolError::NumericalOverflow)?;
+
+    // Update pool's total rewards owed by the full committed amount
+    pool_data.total_rewards_owed = pool_data
+        .total_rewards_owed
+        .checked_sub(claim_amount)
+        .ok_or(StakePoolError::NumericalOverflow)?;
+
+    msg!(
+        "Claimed {} reward tokens (actual received after fees), total committed: {}",
+        actual_amount,
+        stake_account_data.claimed_rewards
+    );
+
+    // Save updated accounts first to ensure persistence before emitting event
+    let event_nonce = pool_data.next_event_nonce()?;
+    pool_data.save(accounts.pool)?;
+    stake_account_data.save(accounts.stake_account)?;
+    if let (Some(reward_cohort), Some(cohort_account)) =
+        (reward_cohort.as_mut(), accounts.reward_cohort)
+    {
+        reward_cohort.release(claim_amount);
+        reward_cohort.save(cohort_account)?;
+    }
+
+    // Call the pool's hook after the state is saved
+    if let Some(hook) = &hook {
+        hook.call(
+            StakePoolHookInstruction::AfterClaim {
+                amount: claim_amount,
+            },
+            accounts.pool,
+            accounts.stake_account,
+            accounts.owner,
+        )?;
+    }
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::Claim(ClaimEvent {
+        pool: *accounts.pool.key,
+        stake_account: *accounts.stake_account.key,
+        claimant: *accounts.owner.key,
+        recipient: *destination.key,
+        amount: claim_amount,
+        received: actual_amount,
+        event_nonce,
+    })
+    .emit()?;
+
+    Ok(ClaimRewardsResult::Claimed {
+        amount: claim_amount,
+        received: actual_amount,
+    })
+}
+
+/// Claim the rewards of several stake accounts of one pool with a single transfer
+///
+/// Stake accounts are passed as writable remaining accounts. Stakes with nothing
+/// to claim yet are skipped; stakes that owe a referral share must be claimed
+/// with `claim_rewards`, which pays the referrer.
+pub fn claim_rewards_batch<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = ClaimRewardsBatchAccounts::context(accounts)?;
+
+    if ctx.remaining_accounts.is_empty() {
+        msg!("No stake accounts provided");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // Verify pool account discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+
     // Verify program ownership
     assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
-    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
 
-    // Load accounts
+    // Load pool
     let mut pool_data = StakePool::load(ctx.accounts.pool)?;
-    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
 
     // Guards
     assert_signer("owner", ctx.accounts.owner)?;
     assert_writable("pool", ctx.accounts.pool)?;
-    assert_writable("stake_account", ctx.accounts.stake_account)?;
     assert_writable("user_reward_account", ctx.accounts.user_reward_account)?;
     assert_writable("reward_vault", ctx.accounts.reward_vault)?;
-    assert_same_pubkeys("owner", ctx.accounts.owner, &stake_account_data.owner)?;
-    assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
-    assert_same_pubkeys(
+    assert_vault(
         "reward_vault",
         ctx.accounts.reward_vault,
-        &pool_data.reward_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Reward,
     )?;
     assert_same_pubkeys(
         "reward_mint",
         ctx.accounts.reward_mint,
         &pool_data.reward_mint,
     )?;
+    check_global_pause(ctx.accounts.program_authority, UserOperation::Claim)?;
+    // Hooked pools are claimed with claim_rewards, which calls the hook
+    PoolHook::resolve(&pool_data, None, &[])?;
+    if pool_data.cohort_duration > 0 {
+        msg!("Pools with reward cohorts are claimed with claim_rewards");
+        return Err(StakePoolError::InvalidRewardCohort.into());
+    }
+    // Deny-listed pools are claimed with claim_rewards, which checks each owner
+    resolve_deny_list(&pool_data, None)?;
 
     // Verify token accounts belong to correct mints
     verify_reward_token_accounts(
@@ -65,43 +468,105 @@ pub fn claim_rewards<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
     // Get current time
     let clock = Clock::from_account_info(ctx.accounts.clock)?;
     validate_current_timestamp(clock.unix_timestamp)?;
+    pool_data.check_claim_window(clock.unix_timestamp)?;
+
+    let mut claims = Vec::with_capacity(ctx.remaining_accounts.len());
+    let mut total_unclaimed: u64 = 0;
+    for (position, stake_account) in ctx.remaining_accounts.iter().enumerate() {
+        // A repeated account would be paid twice from a single load
+        if ctx.remaining_accounts[..position]
+            .iter()
+            .any(|previous| previous.key == stake_account.key)
+        {
+            msg!("Stake account {} is listed twice", stake_account.key);
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+
+        // Verify stake account discriminator before loading (Type Cosplay protection)
+        assert_account_key("stake_account", stake_account, Key::StakeAccountV2)?;
+        assert_program_owner("stake_account", stake_account, &crate::ID)?;
+        assert_writable("stake_account", stake_account)?;
+
+        let mut stake_account_data = StakeAccount::load(stake_account)?;
+        if !stake_account_data.can_claim(ctx.accounts.owner.key) {
+            msg!(
+                "{} is neither the owner nor the delegate of stake account {}",
+                ctx.accounts.owner.key,
+                stake_account.key
+            );
+            return Err(StakePoolError::Unauthorized.into());
+        }
+        assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
+
+        // A delegate claims on the owner's behalf, never to an account of its own
+        if ctx.accounts.owner.key != &stake_account_data.owner {
+            verify_token_account_owner(
+                ctx.accounts.user_reward_account,
+                &stake_account_data.owner,
+                "user_reward_account",
+            )?;
+        }
+
+        let unclaimed_rewards = pool_data
+            .stake_rewards(&stake_account_data, clock.unix_timestamp)?
+            .checked_sub(stake_account_data.claimed_rewards)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        if unclaimed_rewards == 0 {
+            continue;
+        }
+
+        if let Some(referrer) = stake_account_data.referrer {
+            if pool_data.referral_bps > 0 {
+                msg!(
+                    "Stake account {} pays referrer {}; claim it with ClaimRewards",
+                    stake_account.key,
+                    referrer
+                );
+                return Err(StakePoolError::InvalidReferrer.into());
+            }
+        }
+
+        // Record the committed amount, as claim_rewards does
+        stake_account_data.claimed_rewards = stake_account_data
+            .claimed_rewards
+            .checked_add(unclaimed_rewards)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        stake_account_data.save(stake_account)?;
+
+        total_unclaimed = total_unclaimed
+            .checked_add(unclaimed_rewards)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        claims.push(StakeClaim {
+            stake_account: *stake_account.key,
+            amount: unclaimed_rewards,
+        });
+    }
 
-    // Calculate total rewards based on stake duration and reward rate
-    // Rewards are only given if lockup period is complete
-    let total_rewards = pool_data.calculate_rewards(
-        stake_account_data.amount_staked,
-        stake_account_data.stake_timestamp,
-        clock.unix_timestamp,
-    )?;
-
-    // Calculate unclaimed rewards (total - already claimed)
-    let unclaimed_rewards = total_rewards
-        .checked_sub(stake_account_data.claimed_rewards)
-        .ok_or(StakePoolError::NumericalOverflow)?;
-
-    if unclaimed_rewards == 0 {
-        msg!("No rewards to claim - lockup period not complete, no stake, or rewards already claimed");
-        return Ok(());
+    if total_unclaimed == 0 {
+        msg!("No rewards to claim in any of the stake accounts");
+        return return_data::set(&ClaimRewardsResult::NothingToClaim);
     }
 
     // Check reward vault has sufficient balance
     let reward_vault_balance = get_token_account_balance(ctx.accounts.reward_vault)?;
-    if reward_vault_balance < unclaimed_rewards {
+    if reward_vault_balance < total_unclaimed {
         msg!(
             "Insufficient rewards in vault. Required: {}, Available: {}",
-            unclaimed_rewards,
+            total_unclaimed,
             reward_vault_balance
         );
         return Err(StakePoolError::InsufficientRewards.into());
     }
 
+    // Count the claims against the pool's outflow limit
+    pool_data.record_claim_outflow(total_unclaimed, clock.unix_timestamp)?;
+
     // Transfer rewards (with PDA signer)
     let pool_seeds = StakePool::seeds(&pool_data.stake_mint, pool_data.pool_id);
     let mut seeds_with_bump = pool_seeds.clone();
     seeds_with_bump.push(vec![pool_data.bump]);
     let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
 
-    // Transfer rewards (with PDA signer)
     // Capture actual amount transferred in case of transfer fees
     let actual_amount = transfer_tokens_with_fee(
         ctx.accounts.reward_vault,
@@ -109,51 +574,80 @@ pub fn claim_rewards<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
         ctx.accounts.reward_mint,
         ctx.accounts.pool,
         ctx.accounts.token_program,
-        unclaimed_rewards,
+        total_unclaimed,
         &[&seeds_refs],
     )?;
 
-    // Update claimed rewards tracking with the COMMITTED amount (unclaimed_rewards)
-    // NOT the actual amount received after fees.
-    //
-    // Rationale: The protocol committed to pay 'unclaimed_rewards' tokens to the user.
-    // If transfer fees apply, the user receives less (actual_amount < unclaimed_rewards),
-    // but this is a cost borne by the user, not a reason to allow repeated claims.
-    // Recording the full committed amount ensures:
-    // - User cannot claim the same reward multiple times
-    // - total_rewards calculation remains consistent across claims
-    // - Transfer fees are properly accounted for as user cost
-    stake_account_data.claimed_rewards = stake_account_data
-        .claimed_rewards
-        .checked_add(unclaimed_rewards)
-        .ok_or(StakePoolError::NumericalOverflow)?;
-
     // Update pool's total rewards owed by the full committed amount
     pool_data.total_rewards_owed = pool_data
         .total_rewards_owed
-        .checked_sub(unclaimed_rewards)
+        .checked_sub(total_unclaimed)
         .ok_or(StakePoolError::NumericalOverflow)?;
 
     msg!(
-        "Claimed {} reward tokens (actual received after fees), total committed: {}",
-        actual_amount,
-        stake_account_data.claimed_rewards
+        "Claimed {} reward tokens from {} stake accounts (actual received after fees: {})",
+        total_unclaimed,
+        claims.len(),
+        actual_amount
     );
 
-    // Save updated accounts first to ensure persistence before emitting event
+    // Save state first to ensure persistence before emitting event
+    let event_nonce = pool_data.next_event_nonce()?;
     pool_data.save(ctx.accounts.pool)?;
-    stake_account_data.save(ctx.accounts.stake_account)?;
 
     // Emit event for off-chain indexing after successful state save
-    sol_log_data(&[
-        b"ClaimRewards",
-        ctx.accounts.pool.key.as_ref(),
-        ctx.accounts.owner.key.as_ref(),
-        &unclaimed_rewards.to_le_bytes(),
-        &actual_amount.to_le_bytes(),
-    ]);
-
-    Ok(())
+    StakePoolEvent::ClaimBatch(ClaimBatchEvent {
+        pool: *ctx.accounts.pool.key,
+        claimant: *ctx.accounts.owner.key,
+        claims,
+        amount: total_unclaimed,
+        received: actual_amount,
+        event_nonce,
+    })
+    .emit()?;
+
+    return_data::set(&ClaimRewardsResult::Claimed {
+        amount: total_unclaimed,
+        received: actual_amount,
+    })
+}
+
+/// Get the pending rewards of a stake account (view function for off-chain queries)
+///
+/// Publishes a `PendingRewards` as return data. Intended to be called via
+/// simulateTransaction so frontends read the program's own numbers instead of
+/// re-implementing the reward math.
+pub fn get_pending_rewards<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = GetPendingRewardsAccounts::context(accounts)?;
+
+    // Verify account discriminators before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+
+    // Load accounts
+    let pool_data = StakePool::load(ctx.accounts.pool)?;
+    let stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
+
+    let clock = Clock::get()?;
+    let pending = pool_data.pending_rewards(&stake_account_data, clock.unix_timestamp)?;
+
+    msg!(
+        "Pending rewards: claimable {}, projected {} at {}",
+        pending.claimable,
+        pending.projected_rewards,
+        pending.matures_at
+    );
+
+    return_data::set(&pending)
 }
 
 pub fn fund_rewards<'a>(accounts: &'a [AccountInfo<'a>], amount: u64) -> ProgramResult {
@@ -167,22 +661,25 @@ pub fn fund_rewards<'a>(accounts: &'a [AccountInfo<'a>], amount: u64) -> Program
     let ctx = FundRewardsAccounts::context(accounts)?;
 
     // Verify pool account discriminator before loading (Type Cosplay protection)
-    assert_account_key("pool", ctx.accounts.pool, Key::StakePool)?;
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
 
     // Verify program ownership
     assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
 
     // Load pool
-    let pool_data = StakePool::load(ctx.accounts.pool)?;
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
 
     // Guards
     assert_signer("funder", ctx.accounts.funder)?;
+    assert_writable("pool", ctx.accounts.pool)?;
     assert_writable("funder_token_account", ctx.accounts.funder_token_account)?;
     assert_writable("reward_vault", ctx.accounts.reward_vault)?;
-    assert_same_pubkeys(
+    assert_vault(
         "reward_vault",
         ctx.accounts.reward_vault,
-        &pool_data.reward_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Reward,
     )?;
     assert_same_pubkeys(
         "reward_mint",
@@ -190,6 +687,14 @@ pub fn fund_rewards<'a>(accounts: &'a [AccountInfo<'a>], amount: u64) -> Program
         &pool_data.reward_mint,
     )?;
 
+    if !pool_data.is_reward_funder_allowed(ctx.accounts.funder.key) {
+        msg!(
+            "Funder {} is not in the pool's reward funder allowlist",
+            ctx.accounts.funder.key
+        );
+        return Err(StakePoolError::RewardFunderNotAllowed.into());
+    }
+
     // Verify token accounts belong to correct mints
     verify_reward_token_accounts(
         ctx.accounts.funder_token_account,
@@ -198,7 +703,8 @@ pub fn fund_rewards<'a>(accounts: &'a [AccountInfo<'a>], amount: u64) -> Program
     )?;
 
     // Transfer reward tokens to pool
-    // NOTE: Captures actual_amount for accurate logging, but does NOT update pool state.
+    // NOTE: actual_amount is recorded in total_rewards_funded only; it does NOT
+    // change total_rewards_owed.
     //
     // Design Rationale:
     // - total_rewards_owed tracks COMMITTED rewards (via stake operations)
@@ -228,16 +734,151 @@ pub fn fund_rewards<'a>(accounts: &'a [AccountInfo<'a>], amount: u64) -> Program
         &[],
     )?;
 
-    msg!("Funded pool with {} reward tokens", actual_amount);
+    pool_data.total_rewards_funded = pool_data
+        .total_rewards_funded
+        .checked_add(actual_amount)
+        .ok_or(StakePoolError::NumericalOverflow)?;
+
+    msg!(
+        "Funded pool with {} reward tokens (total funded: {})",
+        actual_amount,
+        pool_data.total_rewards_funded
+    );
+
+    // Save state first to ensure persistence before emitting event
+    pool_data.save(ctx.accounts.pool)?;
 
-    // Emit event for off-chain indexing
-    // Note: fund_rewards doesn't modify pool state, so event can be emitted immediately
-    sol_log_data(&[
-        b"FundRewards",
-        ctx.accounts.pool.key.as_ref(),
-        ctx.accounts.funder.key.as_ref(),
-        &actual_amount.to_le_bytes(),
-    ]);
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::RewardsFunded(RewardsFundedEvent {
+        pool: *ctx.accounts.pool.key,
+        funder: *ctx.accounts.funder.key,
+        amount: actual_amount,
+    })
+    .emit()
+}
 
-    Ok(())
+/// Withdraw un-owed reward tokens from the reward vault (global admin or treasurer)
+///
+/// Only the surplus over `total_rewards_owed` can leave the vault, so rewards
+/// already committed to stakers stay fully backed. The withdrawn amount is
+/// deducted from `total_rewards_funded`.
+///
+/// # Errors
+/// Returns error if:
+/// - The signer is neither a global admin nor a treasurer
+/// - The reward vault does not match the pool's reward vault
+/// - `amount` exceeds the vault balance minus `total_rewards_owed`
+pub fn defund_rewards<'a>(accounts: &'a [AccountInfo<'a>], amount: u64) -> ProgramResult {
+    // Validate amount
+    if amount == 0 {
+        msg!("Defund amount must be greater than zero");
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    // Parse accounts using ShankContext-generated struct
+    let ctx = DefundRewardsAccounts::context(accounts)?;
+
+    // Verify pool account discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // Load pool
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+
+    // Load program authority to verify admin permissions
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("reward_vault", ctx.accounts.reward_vault)?;
+    assert_writable("destination", ctx.accounts.destination)?;
+    assert_vault(
+        "reward_vault",
+        ctx.accounts.reward_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Reward,
+    )?;
+    assert_same_pubkeys(
+        "reward_mint",
+        ctx.accounts.reward_mint,
+        &pool_data.reward_mint,
+    )?;
+
+    // Verify the signer is a global admin or a treasurer
+    if !program_authority.has_role(ctx.accounts.admin.key, Role::Treasurer) {
+        msg!(
+            "Unauthorized: {} is not a global admin or treasurer",
+            ctx.accounts.admin.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    // Verify token accounts belong to the reward mint
+    verify_token_account(
+        ctx.accounts.reward_vault,
+        &pool_data.reward_mint,
+        None,
+        None,
+    )?;
+    verify_token_account(ctx.accounts.destination, &pool_data.reward_mint, None, None)?;
+
+    let reward_vault_balance = get_token_account_balance(ctx.accounts.reward_vault)?;
+    let surplus = pool_data.reward_surplus(reward_vault_balance);
+    if amount > surplus {
+        msg!(
+            "Defund exceeds surplus. Requested: {}, Surplus: {} (balance: {}, owed: {})",
+            amount,
+            surplus,
+            reward_vault_balance,
+            pool_data.total_rewards_owed
+        );
+        return Err(StakePoolError::DefundExceedsSurplus.into());
+    }
+
+    // Transfer surplus (with pool PDA signer)
+    let pool_seeds = StakePool::seeds(&pool_data.stake_mint, pool_data.pool_id);
+    let mut seeds_with_bump = pool_seeds.clone();
+    seeds_with_bump.push(vec![pool_data.bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    let actual_amount = transfer_tokens_with_fee(
+        ctx.accounts.reward_vault,
+        ctx.accounts.destination,
+        ctx.accounts.reward_mint,
+        ctx.accounts.pool,
+        ctx.accounts.token_program,
+        amount,
+        &[&seeds_refs],
+    )?;
+
+    // The vault is debited the full amount, transfer fees are borne by the destination
+    pool_data.total_rewards_funded = pool_data.total_rewards_funded.saturating_sub(amount);
+
+    msg!(
+        "Defunded {} reward tokens (actual: {}) to {}",
+        amount,
+        actual_amount,
+        ctx.accounts.destination.key
+    );
+
+    // Save state first to ensure persistence before emitting event
+    pool_data.save(ctx.accounts.pool)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::RewardsDefunded(VaultWithdrawalEvent {
+        pool: *ctx.accounts.pool.key,
+        admin: *ctx.accounts.admin.key,
+        destination: *ctx.accounts.destination.key,
+        amount,
+    })
+    .emit()
 }
@@ -0,0 +1,357 @@
+//! Admin-initiated slashing of misbehaving stakers
+//!
+//! A global admin proposes slashing a share of one stake account's principal
+//! into a slash proposal PDA. The proposal freezes the stake (no unstake,
+//! withdrawal or ownership transfer) and becomes executable once
+//! SLASH_TIMELOCK has passed, leaving the staker and other admins time to
+//! react. Executing moves the slashed tokens out of the stake vault to the
+//! proposal's treasury or the pool's reward vault, releases the slashed part's
+//! unclaimed rewards and closes the proposal. An admin can cancel it instead.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+use crate::assertions::*;
+use crate::constants::BPS_DENOMINATOR;
+use crate::error::StakePoolError;
+use crate::events::{SlashCancelledEvent, SlashExecutedEvent, SlashProposedEvent, StakePoolEvent};
+use crate::instruction::accounts::*;
+use crate::state::{Key, PoolVault, SlashProposal, StakeAccount, StakePool};
+use crate::utils::{close_account, create_account, transfer_tokens_with_fee};
+
+use super::approval::{assert_global_admin, load_program_authority};
+use super::helpers::{load_mint_registry, validate_current_timestamp, verify_token_account};
+
+/// Load a slash proposal after verifying its discriminator and owner
+fn load_slash_proposal(account: &AccountInfo) -> Result<SlashProposal, ProgramError> {
+    assert_account_key("slash_proposal", account, Key::SlashProposal)?;
+    assert_program_owner("slash_proposal", account, &crate::ID)?;
+    assert_writable("slash_proposal", account)?;
+    SlashProposal::load(account)
+}
+
+/// Propose slashing `slash_bps` of a stake's principal (global admin only)
+///
+/// # Errors
+/// Returns error if:
+/// - The admin is not a global admin
+/// - `slash_bps` is zero or above 100%
+/// - The stake is empty or already has a slash pending
+/// - The pool tokenizes positions
+/// - No treasury is given and the pool pays rewards in another mint
+/// - The proposal is not the ["slash_proposal", stake_account] PDA
+pub fn propose_slash<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    slash_bps: u16,
+    treasury: Option<Pubkey>,
+) -> ProgramResult {
+    let ctx = ProposeSlashAccounts::context(accounts)?;
+
+    // Verify account discriminators before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+
+    let pool_data = StakePool::load(ctx.accounts.pool)?;
+    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+    let program_authority = load_program_authority(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_writable("admin", ctx.accounts.admin)?;
+    assert_writable("stake_account", ctx.accounts.stake_account)?;
+    assert_writable("slash_proposal", ctx.accounts.slash_proposal)?;
+    assert_empty("slash_proposal", ctx.accounts.slash_proposal)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
+    assert_global_admin(&program_authority, ctx.accounts.admin.key)?;
+
+    if slash_bps == 0 || slash_bps as u64 > BPS_DENOMINATOR {
+        msg!(
+            "Invalid slash: {} bps. Must be between 1 and {}",
+            slash_bps,
+            BPS_DENOMINATOR
+        );
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+    stake_account_data.check_no_pending_slash()?;
+    if stake_account_data.amount_staked == 0 && stake_account_data.pending_withdraw_amount == 0 {
+        msg!("Stake account holds no principal to slash");
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    // Receipts would outlive the principal they represent
+    if pool_data.receipt_mint.is_some() {
+        msg!(
+            "Pool {} tokenizes positions and cannot slash stakes",
+            ctx.accounts.pool.key
+        );
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    // Slashed stake tokens can only fund rewards paid in the same mint
+    if treasury.is_none() && pool_data.stake_mint != pool_data.reward_mint {
+        msg!("Pool pays rewards in another mint; slash to a treasury instead");
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    let stake_account_key = ctx.accounts.stake_account.key;
+    let (slash_proposal_key, bump) = SlashProposal::find_pda(stake_account_key);
+    assert_same_pubkeys(
+        "slash_proposal",
+        ctx.accounts.slash_proposal,
+        &slash_proposal_key,
+    )?;
+
+    let mut seeds_with_bump = SlashProposal::seeds(stake_account_key);
+    seeds_with_bump.push(vec![bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    create_account(
+        ctx.accounts.slash_proposal,
+        ctx.accounts.admin,
+        ctx.accounts.system_program,
+        SlashProposal::LEN,
+        &crate::ID,
+        Some(&[&seeds_refs]),
+    )?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    validate_current_timestamp(current_time)?;
+
+    let proposal = SlashProposal {
+        key: Key::SlashProposal,
+        pool: *ctx.accounts.pool.key,
+        stake_account: *stake_account_key,
+        proposer: *ctx.accounts.admin.key,
+        slash_bps,
+        treasury,
+        executable_at: SlashProposal::executable_after(current_time)?,
+        bump,
+    };
+    stake_account_data.pending_slash_bps = slash_bps;
+
+    msg!(
+        "Slash of {} bps proposed for stake account {}, executable at {}",
+        slash_bps,
+        stake_account_key,
+        proposal.executable_at
+    );
+
+    // Save state first to ensure persistence before emitting event
+    proposal.save(ctx.accounts.slash_proposal)?;
+    stake_account_data.save(ctx.accounts.stake_account)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::SlashProposed(SlashProposedEvent {
+        pool: proposal.pool,
+        stake_account: proposal.stake_account,
+        owner: stake_account_data.owner,
+        proposer: proposal.proposer,
+        slash_bps,
+        treasury,
+        executable_at: proposal.executable_at,
+    })
+    .emit()
+}
+
+/// Execute a slash proposal once its timelock has passed (global admin only)
+///
+/// The slashed principal leaves `total_staked` and the mint registry, the
+/// slashed part's unclaimed rewards leave `total_rewards_owed`, and tokens sent
+/// to the reward vault are recorded as funded rewards. The proposal is closed
+/// and its rent returned to the proposer.
+///
+/// # Errors
+/// Returns error if:
+/// - The executor is not a global admin
+/// - The proposal belongs to another stake account or proposer
+/// - The timelock has not passed
+/// - The destination is not the proposal's treasury or the pool's reward vault
+pub fn execute_slash<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = ExecuteSlashAccounts::context(accounts)?;
+
+    // Verify account discriminators before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+    let proposal = load_slash_proposal(ctx.accounts.slash_proposal)?;
+    let program_authority = load_program_authority(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("executor", ctx.accounts.executor)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("stake_account", ctx.accounts.stake_account)?;
+    assert_writable("proposer", ctx.accounts.proposer)?;
+    assert_writable("stake_vault", ctx.accounts.stake_vault)?;
+    assert_writable("destination", ctx.accounts.destination)?;
+    assert_writable("mint_registry", ctx.accounts.mint_registry)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &proposal.pool)?;
+    assert_same_pubkeys(
+        "stake_account",
+        ctx.accounts.stake_account,
+        &proposal.stake_account,
+    )?;
+    assert_same_pubkeys("proposer", ctx.accounts.proposer, &proposal.proposer)?;
+    assert_global_admin(&program_authority, ctx.accounts.executor.key)?;
+    assert_vault(
+        "stake_vault",
+        ctx.accounts.stake_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Stake,
+    )?;
+    assert_same_pubkeys("stake_mint", ctx.accounts.stake_mint, &pool_data.stake_mint)?;
+    match proposal.treasury {
+        Some(treasury) => assert_same_pubkeys("destination", ctx.accounts.destination, &treasury)?,
+        None => assert_vault(
+            "destination",
+            ctx.accounts.destination,
+            ctx.accounts.pool.key,
+            &pool_data,
+            PoolVault::Reward,
+        )?,
+    }
+    verify_token_account(ctx.accounts.stake_vault, &pool_data.stake_mint, None, None)?;
+    verify_token_account(ctx.accounts.destination, &pool_data.stake_mint, None, None)?;
+    let mut mint_registry = load_mint_registry(ctx.accounts.mint_registry, &pool_data.stake_mint)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    validate_current_timestamp(current_time)?;
+    proposal.check_executable(current_time)?;
+
+    stake_account_data.pending_slash_bps = 0;
+    let (slashed_amount, forfeited_rewards) =
+        pool_data.slash_stake(&mut stake_account_data, proposal.slash_bps)?;
+    mint_registry.record_unstake(slashed_amount);
+
+    let received_amount = if slashed_amount > 0 {
+        // Transfer tokens (with PDA signer)
+        let pool_seeds = StakePool::seeds(&pool_data.stake_mint, pool_data.pool_id);
+        let mut seeds_with_bump = pool_seeds.clone();
+        seeds_with_bump.push(vec![pool_data.bump]);
+        let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+        transfer_tokens_with_fee(
+            ctx.accounts.stake_vault,
+            ctx.accounts.destination,
+            ctx.accounts.stake_mint,
+            ctx.accounts.pool,
+            ctx.accounts.token_program,
+            slashed_amount,
+            &[&seeds_refs],
+        )?
+    } else {
+        0
+    };
+
+    // Slashed tokens in the reward vault back future rewards like a funding
+    if proposal.treasury.is_none() {
+        pool_data.total_rewards_funded = pool_data
+            .total_rewards_funded
+            .checked_add(received_amount)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+    }
+
+    msg!(
+        "Slashed {} tokens of stake account {} (received: {}), forfeited {} reward tokens",
+        slashed_amount,
+        ctx.accounts.stake_account.key,
+        received_amount,
+        forfeited_rewards
+    );
+
+    // Save state first to ensure persistence before emitting event
+    pool_data.save(ctx.accounts.pool)?;
+    stake_account_data.save(ctx.accounts.stake_account)?;
+    mint_registry.save(ctx.accounts.mint_registry)?;
+    close_account(ctx.accounts.slash_proposal, ctx.accounts.proposer)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::SlashExecuted(SlashExecutedEvent {
+        pool: *ctx.accounts.pool.key,
+        stake_account: *ctx.accounts.stake_account.key,
+        executor: *ctx.accounts.executor.key,
+        destination: *ctx.accounts.destination.key,
+        slashed_amount,
+        received_amount,
+        forfeited_rewards,
+    })
+    .emit()
+}
+
+/// Cancel a slash proposal, unfreezing the stake (global admin only)
+///
+/// # Errors
+/// Returns error if:
+/// - The admin is not a global admin
+/// - The proposal belongs to another stake account or proposer
+pub fn cancel_slash<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = CancelSlashAccounts::context(accounts)?;
+
+    // Verify stake account discriminator before loading (Type Cosplay protection)
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+
+    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+    let proposal = load_slash_proposal(ctx.accounts.slash_proposal)?;
+    let program_authority = load_program_authority(ctx.accounts.program_authority)?;
+
+    // Guards
+    assert_signer("admin", ctx.accounts.admin)?;
+    assert_writable("stake_account", ctx.accounts.stake_account)?;
+    assert_writable("proposer", ctx.accounts.proposer)?;
+    assert_same_pubkeys(
+        "stake_account",
+        ctx.accounts.stake_account,
+        &proposal.stake_account,
+    )?;
+    assert_same_pubkeys("proposer", ctx.accounts.proposer, &proposal.proposer)?;
+    assert_global_admin(&program_authority, ctx.accounts.admin.key)?;
+
+    stake_account_data.pending_slash_bps = 0;
+
+    msg!(
+        "Slash of {} bps cancelled for stake account {}",
+        proposal.slash_bps,
+        ctx.accounts.stake_account.key
+    );
+
+    // Save state first to ensure persistence before emitting event
+    stake_account_data.save(ctx.accounts.stake_account)?;
+    close_account(ctx.accounts.slash_proposal, ctx.accounts.proposer)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::SlashCancelled(SlashCancelledEvent {
+        pool: proposal.pool,
+        stake_account: proposal.stake_account,
+        admin: *ctx.accounts.admin.key,
+    })
+    .emit()
+}
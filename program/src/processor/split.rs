@@ -0,0 +1,213 @@
+//! Stake splits and merges
+//!
+//! An owner exiting or transferring part of a position splits it first:
+//! `split_stake` moves part of the principal into a new stake account of the
+//! same owner with the same round, so the remainder keeps its lockup progress
+//! while the new position is unstaked or handed over on its own.
+//! `merge_stakes` goes the other way, folding one stake account into another
+//! to cut down on accounts and per-account claims.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+use crate::assertions::*;
+use crate::error::StakePoolError;
+use crate::events::{StakePoolEvent, StakeSplitEvent, StakesMergedEvent};
+use crate::instruction::accounts::*;
+use crate::state::{Key, StakeAccount, StakePool};
+use crate::utils::{close_account, create_account};
+
+use super::helpers::{load_stake_position, validate_current_timestamp};
+
+/// Move `amount` of a stake's principal into the owner's stake account at `new_index` (owner only)
+pub fn split_stake<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    amount: u64,
+    new_index: u64,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = SplitStakeAccounts::context(accounts)?;
+
+    // Verify account discriminators before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+
+    // Load accounts
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+
+    // Guards
+    assert_signer("owner", ctx.accounts.owner)?;
+    assert_writable("owner", ctx.accounts.owner)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("stake_account", ctx.accounts.stake_account)?;
+    assert_writable("new_stake_account", ctx.accounts.new_stake_account)?;
+    assert_empty("new_stake_account", ctx.accounts.new_stake_account)?;
+    assert_same_pubkeys("owner", ctx.accounts.owner, &stake_account_data.owner)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
+
+    let pool_key = ctx.accounts.pool.key;
+    let owner = ctx.accounts.owner.key;
+    let (new_stake_account_key, bump) = StakeAccount::find_pda(pool_key, owner, new_index);
+    assert_same_pubkeys(
+        "new_stake_account",
+        ctx.accounts.new_stake_account,
+        &new_stake_account_key,
+    )?;
+
+    let new_stake_account_data =
+        pool_data.split_stake(&mut stake_account_data, amount, new_index, bump)?;
+
+    let mut seeds_with_bump = StakeAccount::seeds(pool_key, owner, new_index);
+    seeds_with_bump.push(vec![bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    create_account(
+        ctx.accounts.new_stake_account,
+        ctx.accounts.owner,
+        ctx.accounts.system_program,
+        StakeAccount::LEN,
+        &crate::ID,
+        Some(&[&seeds_refs]),
+    )?;
+
+    // Record the new index in the owner's stake position directory, if it has one
+    if let Some(position_account) = ctx.accounts.stake_position {
+        if !position_account.data_is_empty() {
+            let mut position = load_stake_position(position_account, pool_key, owner)?;
+            position.record(new_index)?;
+            position.save(position_account)?;
+        }
+    }
+
+    // Save state first to ensure persistence before emitting event
+    pool_data.save(ctx.accounts.pool)?;
+    stake_account_data.save(ctx.accounts.stake_account)?;
+    new_stake_account_data.save(ctx.accounts.new_stake_account)?;
+
+    msg!(
+        "Split {} from stake account {} into {} ({} remaining)",
+        amount,
+        ctx.accounts.stake_account.key,
+        new_stake_account_key,
+        stake_account_data.amount_staked
+    );
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::StakeSplit(StakeSplitEvent {
+        pool: *pool_key,
+        owner: *owner,
+        stake_account: *ctx.accounts.stake_account.key,
+        new_stake_account: new_stake_account_key,
+        amount,
+        remaining: stake_account_data.amount_staked,
+    })
+    .emit()
+}
+
+/// Fold the owner's `source_stake_account` into `stake_account` and close it (owner only)
+///
+/// # Errors
+/// Returns error if:
+/// - The two accounts are the same or belong to different owners or pools
+/// - The stakes were locked at different reward rates or lockup extensions
+/// - The source has a pending withdrawal or vesting grant, or either a pending slash
+/// - The earlier stake matured while the later one is still locked
+pub fn merge_stakes<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = MergeStakesAccounts::context(accounts)?;
+
+    // Verify account discriminators before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+    assert_account_key(
+        "source_stake_account",
+        ctx.accounts.source_stake_account,
+        Key::StakeAccountV2,
+    )?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+    assert_program_owner(
+        "source_stake_account",
+        ctx.accounts.source_stake_account,
+        &crate::ID,
+    )?;
+
+    // Loading the same account twice would count its stake twice
+    if ctx.accounts.stake_account.key == ctx.accounts.source_stake_account.key {
+        msg!("A stake account cannot be merged into itself");
+        return Err(StakePoolError::StakesNotMergeable.into());
+    }
+
+    // Load accounts
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+    let source_data = StakeAccount::load(ctx.accounts.source_stake_account)?;
+
+    // Guards
+    assert_signer("owner", ctx.accounts.owner)?;
+    assert_writable("owner", ctx.accounts.owner)?;
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("stake_account", ctx.accounts.stake_account)?;
+    assert_writable("source_stake_account", ctx.accounts.source_stake_account)?;
+    assert_same_pubkeys("owner", ctx.accounts.owner, &stake_account_data.owner)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
+
+    let clock = Clock::get()?;
+    validate_current_timestamp(clock.unix_timestamp)?;
+
+    pool_data.merge_stakes(&mut stake_account_data, &source_data, clock.unix_timestamp)?;
+
+    // Remove the source index from the owner's stake position directory, if it has one
+    if let Some(position_account) = ctx.accounts.stake_position {
+        if !position_account.data_is_empty() {
+            let mut position =
+                load_stake_position(position_account, ctx.accounts.pool.key, &source_data.owner)?;
+            position.release(source_data.index);
+            position.save(position_account)?;
+        }
+    }
+
+    // Save the merged stake before closing the source account
+    pool_data.save(ctx.accounts.pool)?;
+    stake_account_data.save(ctx.accounts.stake_account)?;
+    close_account(ctx.accounts.source_stake_account, ctx.accounts.owner)?;
+
+    msg!(
+        "Merged stake account {} into {} ({} staked since {})",
+        ctx.accounts.source_stake_account.key,
+        ctx.accounts.stake_account.key,
+        stake_account_data.amount_staked,
+        stake_account_data.stake_timestamp
+    );
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::StakesMerged(StakesMergedEvent {
+        pool: *ctx.accounts.pool.key,
+        owner: stake_account_data.owner,
+        stake_account: *ctx.accounts.stake_account.key,
+        source_stake_account: *ctx.accounts.source_stake_account.key,
+        amount: source_data.amount_staked,
+        amount_staked: stake_account_data.amount_staked,
+        stake_timestamp: stake_account_data.stake_timestamp,
+    })
+    .emit()
+}
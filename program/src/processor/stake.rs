@@ -1,47 +1,781 @@
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
-    log::sol_log_data,
     msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    program_pack::Pack,
     pubkey::Pubkey,
     sysvar::{clock::Clock, Sysvar},
 };
+use spl_token_2022::{
+    instruction::{close_account, initialize_account3},
+    state::Account as TokenAccount,
+};
 
 use crate::assertions::*;
-use crate::constants::REWARD_SCALE;
+use crate::constants::MAX_STAKE_BATCH;
 use crate::error::StakePoolError;
+use crate::events::{StakeEvent, StakePoolEvent, UnstakeEvent, VestingStakeCreatedEvent};
+use crate::hook::StakePoolHookInstruction;
 use crate::instruction::accounts::*;
-use crate::state::{Key, StakeAccount, StakePool};
-use crate::utils::{create_account, transfer_tokens_with_fee};
+use crate::state::{Key, PoolVault, ProgramAuthority, StakeAccount, StakePool, UserOperation};
+use crate::utils::{create_account, transfer_tokens_with_fee, wrap_sol};
 
+use super::close::close_empty_stake_account;
+use super::cohort::load_stake_cohort;
 use super::helpers::{
-    get_token_account_balance, validate_current_timestamp, verify_stake_token_accounts,
+    check_deadline, check_global_pause, check_pool_allowlist, emit_if_rewards_cap_reached,
+    get_token_account_balance, get_token_account_owner, is_native_mint, load_mint_registry,
+    load_or_create_stake_position, resolve_deny_list, validate_current_timestamp,
+    verify_stake_token_accounts, verify_token_account, write_memo,
 };
+use super::hook::PoolHook;
+use super::receipt::ReceiptAccounts;
+use super::rewards::{process_claim, ClaimAccountInfos};
+
+/// Accounts shared by `stake`, `stake_sol` and `stake_batch`
+struct StakeAccountInfos<'a> {
+    pool: &'a AccountInfo<'a>,
+    owner: &'a AccountInfo<'a>,
+    stake_vault: &'a AccountInfo<'a>,
+    reward_vault: &'a AccountInfo<'a>,
+    stake_mint: &'a AccountInfo<'a>,
+    token_program: &'a AccountInfo<'a>,
+    payer: &'a AccountInfo<'a>,
+    system_program: &'a AccountInfo<'a>,
+    fee_vault: &'a AccountInfo<'a>,
+    mint_registry: &'a AccountInfo<'a>,
+    receipt_mint: Option<&'a AccountInfo<'a>>,
+    user_receipt_account: Option<&'a AccountInfo<'a>>,
+    receipt_token_program: Option<&'a AccountInfo<'a>>,
+    program_authority: &'a AccountInfo<'a>,
+    allowlist: Option<&'a AccountInfo<'a>>,
+    stake_position: Option<&'a AccountInfo<'a>>,
+    deny_list: Option<&'a AccountInfo<'a>>,
+    reward_cohort: Option<&'a AccountInfo<'a>>,
+    hook_program: Option<&'a AccountInfo<'a>>,
+    hook_accounts: &'a [AccountInfo<'a>],
+}
+
+/// Where the staked tokens come from
+#[derive(Clone, Copy)]
+enum StakeFunding<'a> {
+    /// SPL tokens from the owner's token account
+    Token(&'a AccountInfo<'a>),
+    /// SPL tokens from a funder's token account, staked for an owner who does not sign
+    ThirdParty {
+        funder: &'a AccountInfo<'a>,
+        funder_token_account: &'a AccountInfo<'a>,
+    },
+    /// Lamports from the owner's system account, wrapped into the wSOL vault
+    Lamports,
+    /// SPL tokens from a global admin's token account, granted with vesting principal
+    Grant {
+        admin: &'a AccountInfo<'a>,
+        admin_token_account: &'a AccountInfo<'a>,
+        vesting_cliff: i64,
+        vesting_duration: i64,
+    },
+}
+
+impl<'a> StakeFunding<'a> {
+    /// Move `amount` from the owner into the pool token account `to`
+    /// and return the amount actually received
+    fn deposit(
+        &self,
+        accounts: &StakeAccountInfos<'a>,
+        to: &'a AccountInfo<'a>,
+        amount: u64,
+    ) -> Result<u64, ProgramError> {
+        match *self {
+            StakeFunding::Token(user_token_account) => transfer_tokens_with_fee(
+                user_token_account,
+                to,
+                accounts.stake_mint,
+                accounts.owner,
+                accounts.token_program,
+                amount,
+                &[],
+            ),
+            StakeFunding::ThirdParty {
+                funder,
+                funder_token_account,
+            } => transfer_tokens_with_fee(
+                funder_token_account,
+                to,
+                accounts.stake_mint,
+                funder,
+                accounts.token_program,
+                amount,
+                &[],
+            ),
+            StakeFunding::Lamports => {
+                wrap_sol(accounts.owner, to, accounts.token_program, amount)?;
+                Ok(amount)
+            }
+            StakeFunding::Grant {
+                admin,
+                admin_token_account,
+                ..
+            } => transfer_tokens_with_fee(
+                admin_token_account,
+                to,
+                accounts.stake_mint,
+                admin,
+                accounts.token_program,
+                amount,
+                &[],
+            ),
+        }
+    }
+}
+
+/// Accounts shared by `unstake` and `unstake_sol`
+struct UnstakeAccountInfos<'a> {
+    pool: &'a AccountInfo<'a>,
+    stake_account: &'a AccountInfo<'a>,
+    owner: &'a AccountInfo<'a>,
+    stake_vault: &'a AccountInfo<'a>,
+    stake_mint: &'a AccountInfo<'a>,
+    token_program: &'a AccountInfo<'a>,
+    clock: &'a AccountInfo<'a>,
+    fee_vault: &'a AccountInfo<'a>,
+    mint_registry: &'a AccountInfo<'a>,
+    receipt_mint: Option<&'a AccountInfo<'a>>,
+    user_receipt_account: Option<&'a AccountInfo<'a>>,
+    receipt_token_program: Option<&'a AccountInfo<'a>>,
+    program_authority: &'a AccountInfo<'a>,
+    deny_list: Option<&'a AccountInfo<'a>>,
+    hook_program: Option<&'a AccountInfo<'a>>,
+    hook_accounts: &'a [AccountInfo<'a>],
+}
+
+/// Where the unstaked tokens go
+#[derive(Clone, Copy)]
+enum UnstakePayout<'a> {
+    /// SPL tokens to the owner's token account
+    Token(&'a AccountInfo<'a>),
+    /// Lamports to the owner's system account, unwrapped through a temporary
+    /// wSOL account at the stake account's unwrap PDA
+    Lamports {
+        unwrap_account: &'a AccountInfo<'a>,
+        system_program: &'a AccountInfo<'a>,
+    },
+}
+
+impl<'a> UnstakePayout<'a> {
+    /// Move `amount` out of the stake vault to the owner and return the amount received
+    fn withdraw(
+        &self,
+        accounts: &UnstakeAccountInfos<'a>,
+        amount: u64,
+        pool_signer_seeds: &[&[u8]],
+    ) -> Result<u64, ProgramError> {
+        match *self {
+            UnstakePayout::Token(user_token_account) => transfer_tokens_with_fee(
+                accounts.stake_vault,
+                user_token_account,
+                accounts.stake_mint,
+                accounts.pool,
+                accounts.token_program,
+                amount,
+                &[pool_signer_seeds],
+            ),
+            UnstakePayout::Lamports {
+                unwrap_account,
+                system_program,
+            } => {
+                let (unwrap_key, unwrap_bump) =
+                    StakeAccount::find_unwrap_pda(accounts.stake_account.key);
+                assert_same_pubkeys("unwrap_account", unwrap_account, &unwrap_key)?;
+
+                let unwrap_bump = [unwrap_bump];
+                let unwrap_seeds: &[&[u8]] = &[
+                    StakeAccount::UNWRAP_SEED,
+                    accounts.stake_account.key.as_ref(),
+                    &unwrap_bump,
+                ];
+
+                // Temporary wSOL account owned by the pool; the owner fronts its rent
+                // and gets it back when the account is closed below
+                create_account(
+                    unwrap_account,
+                    accounts.owner,
+                    system_program,
+                    TokenAccount::LEN,
+                    accounts.token_program.key,
+                    Some(&[unwrap_seeds]),
+                )?;
+                invoke(
+                    &initialize_account3(
+                        accounts.token_program.key,
+                        unwrap_account.key,
+                        accounts.stake_mint.key,
+                        accounts.pool.key,
+                    )?,
+                    &[unwrap_account.clone(), accounts.stake_mint.clone()],
+                )?;
+
+                let received = transfer_tokens_with_fee(
+                    accounts.stake_vault,
+                    unwrap_account,
+                    accounts.stake_mint,
+                    accounts.pool,
+                    accounts.token_program,
+                    amount,
+                    &[pool_signer_seeds],
+                )?;
+
+                // Closing a native account releases the wrapped lamports with its rent
+                invoke_signed(
+                    &close_account(
+                        accounts.token_program.key,
+                        unwrap_account.key,
+                        accounts.owner.key,
+                        accounts.pool.key,
+                        &[],
+                    )?,
+                    &[
+                        unwrap_account.clone(),
+                        accounts.owner.clone(),
+                        accounts.pool.clone(),
+                    ],
+                    &[pool_signer_seeds],
+                )?;
 
+                Ok(received)
+            }
+        }
+    }
+}
+
+/// Reject native SOL instructions on pools whose stake mint is not wrapped SOL
+fn assert_native_sol_pool(pool_data: &StakePool) -> ProgramResult {
+    if !is_native_mint(&pool_data.stake_mint) {
+        msg!("Pool stake mint {} is not native SOL", pool_data.stake_mint);
+        return Err(StakePoolError::NotNativeSolPool.into());
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn stake<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    amount: u64,
+    index: Option<u64>,
+    expected_reward_rate: Option<u64>,
+    expected_lockup_period: Option<i64>,
+    referrer: Option<Pubkey>,
+    auto_relock: bool,
+    label: Option<[u8; StakeAccount::LABEL_LEN]>,
+    allowlist_proof: Option<Vec<[u8; 32]>>,
+    memo: Option<String>,
+    deadline: Option<i64>,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = StakeAccounts::context(accounts)?;
+
+    check_deadline("Stake", deadline, Clock::get()?.unix_timestamp)?;
+
+    // A funder stakes from its own token account and signs the memo instead of the owner
+    let funding = match ctx.accounts.funder {
+        Some(funder) => StakeFunding::ThirdParty {
+            funder,
+            funder_token_account: ctx.accounts.user_token_account,
+        },
+        None => StakeFunding::Token(ctx.accounts.user_token_account),
+    };
+    write_memo(
+        memo.as_deref(),
+        ctx.accounts.memo_program,
+        ctx.accounts.funder.unwrap_or(ctx.accounts.owner),
+    )?;
+
+    process_stake(
+        "Stake",
+        StakeAccountInfos {
+            pool: ctx.accounts.pool,
+            owner: ctx.accounts.owner,
+            stake_vault: ctx.accounts.stake_vault,
+            reward_vault: ctx.accounts.reward_vault,
+            stake_mint: ctx.accounts.stake_mint,
+            token_program: ctx.accounts.token_program,
+            payer: ctx.accounts.payer,
+            system_program: ctx.accounts.system_program,
+            fee_vault: ctx.accounts.fee_vault,
+            mint_registry: ctx.accounts.mint_registry,
+            receipt_mint: ctx.accounts.receipt_mint,
+            user_receipt_account: ctx.accounts.user_receipt_account,
+            receipt_token_program: ctx.accounts.receipt_token_program,
+            program_authority: ctx.accounts.program_authority,
+            allowlist: ctx.accounts.allowlist,
+            stake_position: ctx.accounts.stake_position,
+            deny_list: ctx.accounts.deny_list,
+            reward_cohort: ctx.accounts.reward_cohort,
+            hook_program: ctx.accounts.hook_program,
+            hook_accounts: ctx.remaining_accounts,
+        },
+        funding,
+        &[NewStake {
+            stake_account: ctx.accounts.stake_account,
+            index,
+            amount,
+        }],
+        expected_reward_rate,
+        expected_lockup_period,
+        referrer,
+        auto_relock,
+        label,
+        allowlist_proof.as_deref(),
+    )
+}
+
+/// Stake lamports into a native SOL pool
+///
+/// The lamports are transferred from the owner's system account into the
+/// wSOL stake vault and synced, so the stake is accounted exactly like a
+/// wSOL token deposit.
+#[allow(clippy::too_many_arguments)]
+pub fn stake_sol<'a>(
     accounts: &'a [AccountInfo<'a>],
     amount: u64,
     index: u64,
     expected_reward_rate: Option<u64>,
     expected_lockup_period: Option<i64>,
+    referrer: Option<Pubkey>,
+    auto_relock: bool,
+    label: Option<[u8; StakeAccount::LABEL_LEN]>,
+    allowlist_proof: Option<Vec<[u8; 32]>>,
 ) -> ProgramResult {
-    // Validate amount
-    if amount == 0 {
-        msg!("Stake amount must be greater than zero");
+    // Parse accounts using ShankContext-generated struct
+    let ctx = StakeSolAccounts::context(accounts)?;
+
+    process_stake(
+        "StakeSol",
+        StakeAccountInfos {
+            pool: ctx.accounts.pool,
+            owner: ctx.accounts.owner,
+            stake_vault: ctx.accounts.stake_vault,
+            reward_vault: ctx.accounts.reward_vault,
+            stake_mint: ctx.accounts.stake_mint,
+            token_program: ctx.accounts.token_program,
+            payer: ctx.accounts.payer,
+            system_program: ctx.accounts.system_program,
+            fee_vault: ctx.accounts.fee_vault,
+            mint_registry: ctx.accounts.mint_registry,
+            receipt_mint: ctx.accounts.receipt_mint,
+            user_receipt_account: ctx.accounts.user_receipt_account,
+            receipt_token_program: ctx.accounts.receipt_token_program,
+            program_authority: ctx.accounts.program_authority,
+            allowlist: ctx.accounts.allowlist,
+            stake_position: ctx.accounts.stake_position,
+            deny_list: None,
+            reward_cohort: None,
+            hook_program: None,
+            hook_accounts: &[],
+        },
+        StakeFunding::Lamports,
+        &[NewStake {
+            stake_account: ctx.accounts.stake_account,
+            index: Some(index),
+            amount,
+        }],
+        expected_reward_rate,
+        expected_lockup_period,
+        referrer,
+        auto_relock,
+        label,
+        allowlist_proof.as_deref(),
+    )
+}
+
+/// Stake several deposits in one instruction, one new stake account per amount
+///
+/// Stake account `i` of the batch is the owner's PDA at index `start_index + i`.
+/// Lets programmatic depositors open several positions while paying for the
+/// pool load, checks and save only once.
+pub fn stake_batch<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    amounts: Vec<u64>,
+    start_index: u64,
+    expected_reward_rate: Option<u64>,
+    expected_lockup_period: Option<i64>,
+    allowlist_proof: Option<Vec<[u8; 32]>>,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = StakeBatchAccounts::context(accounts)?;
+
+    if amounts.is_empty() || amounts.len() > MAX_STAKE_BATCH {
+        msg!(
+            "Stake batch must contain between 1 and {} stakes, got {}",
+            MAX_STAKE_BATCH,
+            amounts.len()
+        );
         return Err(StakePoolError::InvalidParameters.into());
     }
+    if ctx.remaining_accounts.len() != amounts.len() {
+        msg!(
+            "Expected {} stake accounts, got {}",
+            amounts.len(),
+            ctx.remaining_accounts.len()
+        );
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let mut stakes = Vec::with_capacity(amounts.len());
+    let mut index = start_index;
+    for (stake_account, amount) in ctx.remaining_accounts.iter().zip(amounts) {
+        stakes.push(NewStake {
+            stake_account,
+            index: Some(index),
+            amount,
+        });
+        index = index
+            .checked_add(1)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+    }
+
+    process_stake(
+        "StakeBatch",
+        StakeAccountInfos {
+            pool: ctx.accounts.pool,
+            owner: ctx.accounts.owner,
+            stake_vault: ctx.accounts.stake_vault,
+            reward_vault: ctx.accounts.reward_vault,
+            stake_mint: ctx.accounts.stake_mint,
+            token_program: ctx.accounts.token_program,
+            payer: ctx.accounts.payer,
+            system_program: ctx.accounts.system_program,
+            fee_vault: ctx.accounts.fee_vault,
+            mint_registry: ctx.accounts.mint_registry,
+            receipt_mint: ctx.accounts.receipt_mint,
+            user_receipt_account: ctx.accounts.user_receipt_account,
+            receipt_token_program: ctx.accounts.receipt_token_program,
+            program_authority: ctx.accounts.program_authority,
+            allowlist: ctx.accounts.allowlist,
+            stake_position: ctx.accounts.stake_position,
+            deny_list: None,
+            reward_cohort: None,
+            hook_program: None,
+            hook_accounts: &[],
+        },
+        StakeFunding::Token(ctx.accounts.user_token_account),
+        &stakes,
+        expected_reward_rate,
+        expected_lockup_period,
+        None,
+        false,
+        None,
+        allowlist_proof.as_deref(),
+    )
+}
+
+pub fn unstake<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    amount: u64,
+    expected_reward_rate: Option<u64>,
+    memo: Option<String>,
+    deadline: Option<i64>,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = UnstakeAccounts::context(accounts)?;
+
+    check_deadline("Unstake", deadline, Clock::get()?.unix_timestamp)?;
+
+    write_memo(
+        memo.as_deref(),
+        ctx.accounts.memo_program,
+        ctx.accounts.owner,
+    )?;
+
+    process_unstake(
+        "Unstake",
+        UnstakeAccountInfos {
+            pool: ctx.accounts.pool,
+            stake_account: ctx.accounts.stake_account,
+            owner: ctx.accounts.owner,
+            stake_vault: ctx.accounts.stake_vault,
+            stake_mint: ctx.accounts.stake_mint,
+            token_program: ctx.accounts.token_program,
+            clock: ctx.accounts.clock,
+            fee_vault: ctx.accounts.fee_vault,
+            mint_registry: ctx.accounts.mint_registry,
+            receipt_mint: ctx.accounts.receipt_mint,
+            user_receipt_account: ctx.accounts.user_receipt_account,
+            receipt_token_program: ctx.accounts.receipt_token_program,
+            program_authority: ctx.accounts.program_authority,
+            deny_list: ctx.accounts.deny_list,
+            hook_program: ctx.accounts.hook_program,
+            hook_accounts: ctx.remaining_accounts,
+        },
+        UnstakePayout::Token(ctx.accounts.user_token_account),
+        amount,
+        expected_reward_rate,
+    )
+}
+
+/// Unstake from a native SOL pool, paying out lamports to the owner's system account
+pub fn unstake_sol<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    amount: u64,
+    expected_reward_rate: Option<u64>,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = UnstakeSolAccounts::context(accounts)?;
+
+    process_unstake(
+        "UnstakeSol",
+        UnstakeAccountInfos {
+            pool: ctx.accounts.pool,
+            stake_account: ctx.accounts.stake_account,
+            owner: ctx.accounts.owner,
+            stake_vault: ctx.accounts.stake_vault,
+            stake_mint: ctx.accounts.stake_mint,
+            token_program: ctx.accounts.token_program,
+            clock: ctx.accounts.clock,
+            fee_vault: ctx.accounts.fee_vault,
+            mint_registry: ctx.accounts.mint_registry,
+            receipt_mint: ctx.accounts.receipt_mint,
+            user_receipt_account: ctx.accounts.user_receipt_account,
+            receipt_token_program: ctx.accounts.receipt_token_program,
+            program_authority: ctx.accounts.program_authority,
+            deny_list: None,
+            hook_program: None,
+            hook_accounts: &[],
+        },
+        UnstakePayout::Lamports {
+            unwrap_account: ctx.accounts.unwrap_account,
+            system_program: ctx.accounts.system_program,
+        },
+        amount,
+        expected_reward_rate,
+    )
+}
+
+/// Claim a stake account's claimable rewards, unstake its whole principal and close it,
+/// returning its rent to the owner
+///
+/// Composes the claim, unstake and close cores, so each step keeps its own checks and
+/// events. The claim runs first since the full unstake forfeits unclaimed rewards.
+pub fn unstake_all<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    expected_reward_rate: Option<u64>,
+    memo: Option<String>,
+    deadline: Option<i64>,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = UnstakeAllAccounts::context(accounts)?;
+
+    check_deadline("UnstakeAll", deadline, Clock::get()?.unix_timestamp)?;
+
+    write_memo(
+        memo.as_deref(),
+        ctx.accounts.memo_program,
+        ctx.accounts.owner,
+    )?;
+
+    // The owner receives the closed stake account's rent
+    assert_writable("owner", ctx.accounts.owner)?;
+
+    process_claim(
+        ClaimAccountInfos {
+            pool: ctx.accounts.pool,
+            stake_account: ctx.accounts.stake_account,
+            owner: ctx.accounts.owner,
+            user_reward_account: ctx.accounts.user_reward_account,
+            reward_vault: ctx.accounts.reward_vault,
+            reward_mint: ctx.accounts.reward_mint,
+            token_program: ctx.accounts.token_program,
+            clock: ctx.accounts.clock,
+            referrer_reward_account: ctx.accounts.referrer_reward_account,
+            program_authority: ctx.accounts.program_authority,
+            recipient: None,
+            associated_token_program: None,
+            system_program: None,
+            deny_list: ctx.accounts.deny_list,
+            reward_cohort: ctx.accounts.reward_cohort,
+            hook_program: ctx.accounts.hook_program,
+            hook_accounts: ctx.remaining_accounts,
+        },
+        None,
+    )?;
+
+    // The claim verified the stake account's key and program owner
+    let amount = StakeAccount::load(ctx.accounts.stake_account)?.amount_staked;
+    if amount > 0 {
+        process_unstake(
+            "UnstakeAll",
+            UnstakeAccountInfos {
+                pool: ctx.accounts.pool,
+                stake_account: ctx.accounts.stake_account,
+                owner: ctx.accounts.owner,
+                stake_vault: ctx.accounts.stake_vault,
+                stake_mint: ctx.accounts.stake_mint,
+                token_program: ctx.accounts.token_program,
+                clock: ctx.accounts.clock,
+                fee_vault: ctx.accounts.fee_vault,
+                mint_registry: ctx.accounts.mint_registry,
+                receipt_mint: ctx.accounts.receipt_mint,
+                user_receipt_account: ctx.accounts.user_receipt_account,
+                receipt_token_program: ctx.accounts.receipt_token_program,
+                program_authority: ctx.accounts.program_authority,
+                deny_list: ctx.accounts.deny_list,
+                hook_program: ctx.accounts.hook_program,
+                hook_accounts: ctx.remaining_accounts,
+            },
+            UnstakePayout::Token(ctx.accounts.user_token_account),
+            amount,
+            expected_reward_rate,
+        )?;
+    }
+
+    // A delegate may claim, but only the owner closes the account
+    let stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+    assert_same_pubkeys("owner", ctx.accounts.owner, &stake_account_data.owner)?;
 
+    close_empty_stake_account(
+        ctx.accounts.stake_account,
+        &stake_account_data,
+        ctx.accounts.owner,
+        ctx.accounts.stake_position,
+    )
+}
+
+/// Stake `amount` from a global admin into a new stake account of a beneficiary
+///
+/// The principal vests linearly over `vesting_duration` seconds after a
+/// `vesting_cliff`; `check_unstake_lockup` keeps the unvested part staked.
+pub fn vesting_stake<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    amount: u64,
+    index: u64,
+    vesting_cliff: i64,
+    vesting_duration: i64,
+) -> ProgramResult {
     // Parse accounts using ShankContext-generated struct
-    let ctx = StakeAccounts::context(accounts)?;
+    let ctx = VestingStakeAccounts::context(accounts)?;
+
+    StakeAccount::validate_vesting_schedule(vesting_cliff, vesting_duration)?;
+
+    // Load program authority to verify admin permissions
+    assert_account_key(
+        "program_authority",
+        ctx.accounts.program_authority,
+        Key::ProgramAuthorityV2,
+    )?;
+    let program_authority = ProgramAuthority::load(ctx.accounts.program_authority)?;
+    if !program_authority.is_authorized(ctx.accounts.admin.key) {
+        msg!(
+            "Unauthorized: {} is not a global admin",
+            ctx.accounts.admin.key
+        );
+        return Err(StakePoolError::Unauthorized.into());
+    }
+
+    process_stake(
+        "VestingStake",
+        StakeAccountInfos {
+            pool: ctx.accounts.pool,
+            owner: ctx.accounts.beneficiary,
+            stake_vault: ctx.accounts.stake_vault,
+            reward_vault: ctx.accounts.reward_vault,
+            stake_mint: ctx.accounts.stake_mint,
+            token_program: ctx.accounts.token_program,
+            payer: ctx.accounts.payer,
+            system_program: ctx.accounts.system_program,
+            fee_vault: ctx.accounts.fee_vault,
+            mint_registry: ctx.accounts.mint_registry,
+            receipt_mint: None,
+            user_receipt_account: None,
+            receipt_token_program: None,
+            program_authority: ctx.accounts.program_authority,
+            allowlist: None,
+            stake_position: ctx.accounts.stake_position,
+            deny_list: None,
+            reward_cohort: None,
+            hook_program: None,
+            hook_accounts: &[],
+        },
+        StakeFunding::Grant {
+            admin: ctx.accounts.admin,
+            admin_token_account: ctx.accounts.admin_token_account,
+            vesting_cliff,
+            vesting_duration,
+        },
+        &[NewStake {
+            stake_account: ctx.accounts.stake_account,
+            index: Some(index),
+            amount,
+        }],
+        None,
+        None,
+        None,
+        false,
+        None,
+        None,
+    )?;
+
+    // Emit event for off-chain indexing after the stake is saved
+    let stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+    StakePoolEvent::VestingStakeCreated(VestingStakeCreatedEvent {
+        pool: *ctx.accounts.pool.key,
+        stake_account: *ctx.accounts.stake_account.key,
+        beneficiary: *ctx.accounts.beneficiary.key,
+        admin: *ctx.accounts.admin.key,
+        vesting_amount: stake_account_data.vesting_amount,
+        vesting_start: stake_account_data.vesting_start,
+        vesting_cliff,
+        vesting_duration,
+    })
+    .emit()
+}
+
+/// A stake account created by `process_stake`
+struct NewStake<'a> {
+    stake_account: &'a AccountInfo<'a>,
+    /// None: the next index of the owner's stake position directory
+    index: Option<u64>,
+    amount: u64,
+}
+
+/// Stake a deposit into each of `stakes`, all under the same owner and pool
+///
+/// The pool, registry and global checks run once; solvency and the mint cap
+/// are checked against the sum of the deposits.
+#[allow(clippy::too_many_arguments)]
+fn process_stake<'a>(
+    instruction: &str,
+    accounts: StakeAccountInfos<'a>,
+    funding: StakeFunding<'a>,
+    stakes: &[NewStake<'a>],
+    expected_reward_rate: Option<u64>,
+    expected_lockup_period: Option<i64>,
+    referrer: Option<Pubkey>,
+    auto_relock: bool,
+    label: Option<[u8; StakeAccount::LABEL_LEN]>,
+    allowlist_proof: Option<&[[u8; 32]]>,
+) -> ProgramResult {
+    // Validate amounts
+    if stakes.iter().any(|stake| stake.amount == 0) {
+        msg!("Stake amount must be greater than zero");
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    // Self-referral would let users recover the referral share for themselves
+    if referrer.as_ref() == Some(accounts.owner.key) {
+        msg!("Owner cannot refer their own stake");
+        return Err(StakePoolError::InvalidReferrer.into());
+    }
 
     // Verify pool account discriminator before loading (Type Cosplay protection)
-    assert_account_key("pool", ctx.accounts.pool, Key::StakePool)?;
+    assert_account_key("pool", accounts.pool, Key::StakePoolV2)?;
 
     // Verify program ownership
-    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    assert_program_owner("pool", accounts.pool, &crate::ID)?;
 
     // Load pool
-    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+    let mut pool_data = StakePool::load(accounts.pool)?;
 
     // Frontrunning protection: Verify expected pool parameters if provided
     if let Some(expected_rate) = expected_reward_rate {
@@ -51,7 +785,12 @@ pub fn stake<'a>(
                 expected_rate,
                 pool_data.reward_rate
             );
-            return Err(StakePoolError::PoolParametersChanged.into());
+            return Err(StakePoolError::PoolParametersChanged.with_context(
+                instruction,
+                "reward_rate",
+                expected_rate,
+                pool_data.reward_rate,
+            ));
         }
     }
 
@@ -62,84 +801,222 @@ pub fn stake<'a>(
                 expected_lockup,
                 pool_data.lockup_period
             );
-            return Err(StakePoolError::PoolParametersChanged.into());
+            return Err(StakePoolError::PoolParametersChanged.with_context(
+                instruction,
+                "lockup_period",
+                expected_lockup,
+                pool_data.lockup_period,
+            ));
         }
     }
 
     // Guards
-    assert_signer("owner", ctx.accounts.owner)?;
-    assert_signer("payer", ctx.accounts.payer)?;
-    assert_empty("stake_account", ctx.accounts.stake_account)?;
-    assert_writable("pool", ctx.accounts.pool)?;
-    assert_writable("stake_account", ctx.accounts.stake_account)?;
-    assert_writable("user_token_account", ctx.accounts.user_token_account)?;
-    assert_writable("stake_vault", ctx.accounts.stake_vault)?;
-    assert_writable("payer", ctx.accounts.payer)?;
-    assert_same_pubkeys(
+    match funding {
+        // Grants are signed by the funding admin on the beneficiary's behalf
+        StakeFunding::Grant { admin, .. } => assert_signer("admin", admin)?,
+        StakeFunding::ThirdParty { funder, .. } => {
+            assert_signer("funder", funder)?;
+            if !pool_data.allow_third_party_stake {
+                msg!(
+                    "Pool {} does not accept stakes on behalf of other owners",
+                    accounts.pool.key
+                );
+                return Err(StakePoolError::ThirdPartyStakeDisabled.into());
+            }
+            msg!("Funder {} stakes for {}", funder.key, accounts.owner.key);
+        }
+        _ => assert_signer("owner", accounts.owner)?,
+    }
+    assert_signer("payer", accounts.payer)?;
+    for stake in stakes {
+        assert_empty("stake_account", stake.stake_account)?;
+        assert_writable("stake_account", stake.stake_account)?;
+    }
+    assert_writable("pool", accounts.pool)?;
+    assert_writable("stake_vault", accounts.stake_vault)?;
+    assert_writable("payer", accounts.payer)?;
+    assert_writable("fee_vault", accounts.fee_vault)?;
+    assert_writable("mint_registry", accounts.mint_registry)?;
+    assert_vault(
         "stake_vault",
-        ctx.accounts.stake_vault,
-        &pool_data.stake_vault,
+        accounts.stake_vault,
+        accounts.pool.key,
+        &pool_data,
+        PoolVault::Stake,
     )?;
-    assert_same_pubkeys(
-        "reward_vault",
-        ctx.accounts.reward_vault,
-        &pool_data.reward_vault,
+    assert_vault(
+        "fee_vault",
+        accounts.fee_vault,
+        accounts.pool.key,
+        &pool_data,
+        PoolVault::Fee,
     )?;
-    assert_same_pubkeys("stake_mint", ctx.accounts.stake_mint, &pool_data.stake_mint)?;
+    assert_same_pubkeys("stake_mint", accounts.stake_mint, &pool_data.stake_mint)?;
 
     // Verify token accounts belong to correct mints
-    verify_stake_token_accounts(
-        ctx.accounts.user_token_account,
-        ctx.accounts.stake_vault,
-        &pool_data.stake_mint,
+    match funding {
+        StakeFunding::Token(user_token_account)
+        | StakeFunding::ThirdParty {
+            funder_token_account: user_token_account,
+            ..
+        } => {
+            assert_writable("user_token_account", user_token_account)?;
+            verify_stake_token_accounts(
+                user_token_account,
+                accounts.stake_vault,
+                &pool_data.stake_mint,
+            )?;
+        }
+        StakeFunding::Lamports => {
+            assert_native_sol_pool(&pool_data)?;
+            assert_writable("owner", accounts.owner)?;
+            verify_token_account(accounts.stake_vault, &pool_data.stake_mint, None, None)?;
+        }
+        StakeFunding::Grant {
+            admin_token_account,
+            ..
+        } => {
+            // Receipts would make the unvested principal transferable
+            if pool_data.receipt_mint.is_some() {
+                msg!(
+                    "Pool {} tokenizes positions and cannot hold vesting stakes",
+                    accounts.pool.key
+                );
+                return Err(StakePoolError::InvalidParameters.into());
+            }
+            assert_writable("admin_token_account", admin_token_account)?;
+            verify_stake_token_accounts(
+                admin_token_account,
+                accounts.stake_vault,
+                &pool_data.stake_mint,
+            )?;
+        }
+    }
+    verify_token_account(accounts.fee_vault, &pool_data.stake_mint, None, None)?;
+    let receipt = ReceiptAccounts::resolve(
+        &pool_data,
+        accounts.receipt_mint,
+        accounts.user_receipt_account,
+        accounts.receipt_token_program,
+        accounts.owner.key,
     )?;
+    // The allowlist gates self-service staking; admins may grant to anyone
+    if !matches!(funding, StakeFunding::Grant { .. }) {
+        check_pool_allowlist(
+            &pool_data,
+            accounts.pool.key,
+            accounts.allowlist,
+            allowlist_proof,
+            accounts.owner.key,
+        )?;
+    }
+    // Admin grants are not the owner's own stakes and do not call the hook
+    let hook = match funding {
+        StakeFunding::Grant { .. } => None,
+        _ => PoolHook::resolve(&pool_data, accounts.hook_program, accounts.hook_accounts)?,
+    };
+    // Like the allowlist, the deny list gates self-service staking only
+    if !matches!(funding, StakeFunding::Grant { .. }) {
+        if let Some(deny_list) = resolve_deny_list(&pool_data, accounts.deny_list)? {
+            deny_list.check("Owner", accounts.owner.key)?;
+            if let StakeFunding::ThirdParty { funder, .. } = funding {
+                deny_list.check("Funder", funder.key)?;
+            }
+            if let Some(referrer) = &referrer {
+                deny_list.check("Referrer", referrer)?;
+            }
+        }
+    }
 
     if pool_data.is_paused {
         msg!(
             "Pool {} is currently paused. Staking is disabled.",
-            ctx.accounts.pool.key
+            accounts.pool.key
         );
         return Err(StakePoolError::PoolPaused.into());
     }
+    check_global_pause(accounts.program_authority, UserOperation::Deposit)?;
 
     // Get current time once for efficiency and reuse throughout function
     let clock = Clock::get()?;
     validate_current_timestamp(clock.unix_timestamp)?;
 
-    // Check if pool has ended
-    if let Some(end_date) = pool_data.pool_end_date {
-        if clock.unix_timestamp >= end_date {
+    // Check if pool has ended (end date passed or rewards cap exhausted)
+    pool_data.check_not_ended(clock.unix_timestamp)?;
+
+    // Split each deposit into its fee and staked amount
+    let mut splits = Vec::with_capacity(stakes.len());
+    let mut total_net_amount: u64 = 0;
+    let mut total_expected_rewards: u64 = 0;
+    for stake in stakes {
+        if stake.amount < pool_data.min_stake_amount {
             msg!(
-                "Pool has ended. End date: {}, Current time: {}",
-                end_date,
-                clock.unix_timestamp
+                "Stake amount {} below minimum {}. Pool requires at least {} tokens.",
+                stake.amount,
+                pool_data.min_stake_amount,
+                pool_data.min_stake_amount
             );
-            return Err(StakePoolError::PoolEnded.into());
+            return Err(StakePoolError::AmountBelowMinimum.with_context(
+                instruction,
+                "amount",
+                pool_data.min_stake_amount,
+                stake.amount,
+            ));
         }
-    }
 
-    if amount < pool_data.min_stake_amount {
-        msg!(
-            "Stake amount {} below minimum {}. Pool requires at least {} tokens.",
-            amount,
-            pool_data.min_stake_amount,
-            pool_data.min_stake_amount
-        );
-        return Err(StakePoolError::AmountBelowMinimum.into());
+        // Deposit fee is routed to the fee vault; only the net amount is staked
+        let deposit_fee = pool_data.deposit_fee(stake.amount)?;
+        let net_amount = stake
+            .amount
+            .checked_sub(deposit_fee)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        splits.push((deposit_fee, net_amount));
+
+        total_net_amount = total_net_amount
+            .checked_add(net_amount)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        total_expected_rewards = total_expected_rewards
+            .checked_add(pool_data.expected_rewards(net_amount, clock.unix_timestamp)?)
+            .ok_or(StakePoolError::NumericalOverflow)?;
     }
 
-    // Calculate expected rewards for this stake
-    let expected_rewards = (amount as u128)
-        .checked_mul(pool_data.reward_rate as u128)
-        .ok_or(StakePoolError::NumericalOverflow)?
-        .checked_div(REWARD_SCALE)
-        .ok_or(StakePoolError::NumericalOverflow)? as u64;
+    // Bound the program's total exposure to the stake mint across all its pools
+    let mut mint_registry = load_mint_registry(accounts.mint_registry, &pool_data.stake_mint)?;
+    mint_registry.check_capacity(total_net_amount)?;
 
-    // Check if reward vault has sufficient balance to cover total rewards owed plus this new stake
-    let reward_vault_balance = get_token_account_balance(ctx.accounts.reward_vault)?;
-    let total_required = pool_data
-        .total_rewards_owed
-        .checked_add(expected_rewards)
+    // Pools with reward cohorts reserve the new stakes' rewards in the current cohort's vault
+    let cohort = pool_data.reward_cohort_at(clock.unix_timestamp);
+    let mut reward_cohort = match cohort {
+        0 => {
+            assert_vault(
+                "reward_vault",
+                accounts.reward_vault,
+                accounts.pool.key,
+                &pool_data,
+                PoolVault::Reward,
+            )?;
+            None
+        }
+        cohort => Some(load_stake_cohort(
+            accounts.reward_cohort,
+            accounts.reward_vault,
+            accounts.pool.key,
+            cohort,
+        )?),
+    };
+    if reward_cohort.is_some() && auto_relock {
+        msg!("Stakes of reward cohorts cannot be relocked automatically");
+        return Err(StakePoolError::InvalidRewardCohort.into());
+    }
+
+    // Check if reward vault has sufficient balance to cover total rewards owed plus the new stakes
+    let reward_vault_balance = get_token_account_balance(accounts.reward_vault)?;
+    let already_owed = match &reward_cohort {
+        Some(reward_cohort) => reward_cohort.rewards_owed,
+        None => pool_data.total_rewards_owed,
+    };
+    let total_required = already_owed
+        .checked_add(total_expected_rewards)
         .ok_or(StakePoolError::NumericalOverflow)?;
 
     if reward_vault_balance < total_required {
@@ -147,107 +1024,224 @@ pub fn stake<'a>(
             "Insufficient rewards in pool. Required (total): {}, Available: {}, Already owed: {}, New stake needs: {}",
             total_required,
             reward_vault_balance,
-            pool_data.total_rewards_owed,
-            expected_rewards
+            already_owed,
+            total_expected_rewards
         );
-        return Err(StakePoolError::InsufficientRewards.into());
+        return Err(StakePoolError::InsufficientRewards.with_context(
+            instruction,
+            "reward_vault",
+            total_required,
+            reward_vault_balance,
+        ));
     }
 
-    // Verify stake account PDA
-    let stake_account_seeds =
-        StakeAccount::seeds(ctx.accounts.pool.key, ctx.accounts.owner.key, index);
-    let stake_seeds_refs: Vec<&[u8]> = stake_account_seeds.iter().map(|s| s.as_slice()).collect();
-    let (stake_account_key, bump) = Pubkey::find_program_address(&stake_seeds_refs, &crate::ID);
+    // The new stakes' rewards must fit under the pool's rewards cap
+    if let Some(headroom) = pool_data.rewards_headroom() {
+        if total_expected_rewards > headroom {
+            msg!(
+                "Rewards cap exceeded. New stake needs: {}, Remaining under cap: {}",
+                total_expected_rewards,
+                headroom
+            );
+            return Err(StakePoolError::RewardsCapExceeded.with_context(
+                instruction,
+                "max_total_rewards",
+                headroom,
+                total_expected_rewards,
+            ));
+        }
+    }
 
-    assert_same_pubkeys(
-        "stake_account",
-        ctx.accounts.stake_account,
-        &stake_account_key,
-    )?;
+    if let Some(reward_cohort) = reward_cohort.as_mut() {
+        reward_cohort.reserve(total_expected_rewards, reward_vault_balance)?;
+    }
 
-    // Create the new stake account
-    let mut seeds_with_bump = stake_account_seeds.clone();
-    seeds_with_bump.push(vec![bump]);
-    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+    // The owner's stake position directory, if given, records the new indices
+    let mut stake_position = match accounts.stake_position {
+        Some(position_account) => Some(load_or_create_stake_position(
+            position_account,
+            accounts.pool.key,
+            accounts.owner.key,
+            accounts.payer,
+            accounts.system_program,
+        )?),
+        None => None,
+    };
 
-    // Diagnostic logging: print payer and target account lamports and pubkeys
-    msg!(
-        "Stake() - creating stake account: target={} payer={} target_lamports={} payer_lamports={}",
-        ctx.accounts.stake_account.key,
-        ctx.accounts.payer.key,
-        ctx.accounts.stake_account.lamports(),
-        ctx.accounts.payer.lamports()
-    );
+    let mut events = Vec::with_capacity(stakes.len());
+    for (stake, (deposit_fee, net_amount)) in stakes.iter().zip(splits) {
+        // Stakes without an index take the next one of the owner's directory
+        let index = match (stake.index, stake_position.as_ref()) {
+            (Some(index), _) => index,
+            (None, Some(position)) => position.next_index,
+            (None, None) => {
+                msg!("A stake without an index needs the owner's stake position directory");
+                return Err(StakePoolError::InvalidParameters.into());
+            }
+        };
 
-    create_account(
-        ctx.accounts.stake_account,
-        ctx.accounts.payer,
-        ctx.accounts.system_program,
-        StakeAccount::LEN,
-        &crate::ID,
-        Some(&[&seeds_refs]),
-    )?;
+        // Verify stake account PDA
+        let stake_account_seeds = StakeAccount::seeds(accounts.pool.key, accounts.owner.key, index);
+        let stake_seeds_refs: Vec<&[u8]> =
+            stake_account_seeds.iter().map(|s| s.as_slice()).collect();
+        let (stake_account_key, bump) = Pubkey::find_program_address(&stake_seeds_refs, &crate::ID);
 
-    // Transfer tokens with transfer fee support
-    let transfer_amount = transfer_tokens_with_fee(
-        ctx.accounts.user_token_account,
-        ctx.accounts.stake_vault,
-        ctx.accounts.stake_mint,
-        ctx.accounts.owner,
-        ctx.accounts.token_program,
-        amount,
-        &[],
-    )?;
+        assert_same_pubkeys("stake_account", stake.stake_account, &stake_account_key)?;
 
-    // Update pool total staked and rewards owed
-    pool_data.total_staked = pool_data
-        .total_staked
-        .checked_add(transfer_amount)
-        .ok_or(StakePoolError::NumericalOverflow)?;
+        // Create the new stake account
+        let mut seeds_with_bump = stake_account_seeds.clone();
+        seeds_with_bump.push(vec![bump]);
+        let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
 
-    pool_data.total_rewards_owed = pool_data
-        .total_rewards_owed
-        .checked_add(expected_rewards)
-        .ok_or(StakePoolError::NumericalOverflow)?;
+        // Diagnostic logging: print payer and target account lamports and pubkeys
+        msg!(
+            "Stake() - creating stake account: target={} payer={} target_lamports={} payer_lamports={}",
+            stake.stake_account.key,
+            accounts.payer.key,
+            stake.stake_account.lamports(),
+            accounts.payer.lamports()
+        );
 
-    // Initialize new stake account with the deposit
-    let stake_account_data = StakeAccount {
-        key: Key::StakeAccount,
-        pool: *ctx.accounts.pool.key,
-        owner: *ctx.accounts.owner.key,
-        index,
-        amount_staked: transfer_amount,
-        stake_timestamp: clock.unix_timestamp,
-        claimed_rewards: 0,
-        bump,
-    };
+        create_account(
+            stake.stake_account,
+            accounts.payer,
+            accounts.system_program,
+            StakeAccount::LEN,
+            &crate::ID,
+            Some(&[&seeds_refs]),
+        )?;
 
-    msg!(
-        "Staked {} tokens (actual: {}), index: {}, pool: {}",
-        amount,
-        transfer_amount,
-        index,
-        ctx.accounts.pool.key
-    );
+        // Transfer the deposit fee to the fee vault
+        let fee_received = if deposit_fee > 0 {
+            funding.deposit(&accounts, accounts.fee_vault, deposit_fee)?
+        } else {
+            0
+        };
 
-    // Save state first to ensure persistence before emitting event
-    pool_data.save(ctx.accounts.pool)?;
-    stake_account_data.save(ctx.accounts.stake_account)?;
+        // Transfer tokens with transfer fee support (or wrap lamports for native SOL pools)
+        let transfer_amount = funding.deposit(&accounts, accounts.stake_vault, net_amount)?;
 
-    // Emit event for off-chain indexing after successful state save
-    sol_log_data(&[
-        b"Stake",
-        ctx.accounts.pool.key.as_ref(),
-        ctx.accounts.owner.key.as_ref(),
-        &transfer_amount.to_le_bytes(),
-        &index.to_le_bytes(),
-    ]);
+        // Update pool total staked and rewards owed
+        pool_data.total_staked = pool_data
+            .total_staked
+            .checked_add(transfer_amount)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        pool_data.record_stake_opened()?;
+        mint_registry.record_stake(transfer_amount)?;
 
-    Ok(())
+        // Tokenized pools mint receipts 1:1 with the amount added to the position
+        if let Some(receipt) = &receipt {
+            let pool_seeds = StakePool::seeds(&pool_data.stake_mint, pool_data.pool_id);
+            let mut seeds_with_bump = pool_seeds.clone();
+            seeds_with_bump.push(vec![pool_data.bump]);
+            let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+            receipt.mint(accounts.pool, transfer_amount, &seeds_refs)?;
+        }
+
+        pool_data.commit_rewards(pool_data.expected_rewards(net_amount, clock.unix_timestamp)?)?;
+
+        // Initialize new stake account with the deposit
+        let mut stake_account_data = StakeAccount {
+            key: Key::StakeAccountV2,
+            pool: *accounts.pool.key,
+            owner: *accounts.owner.key,
+            index,
+            amount_staked: transfer_amount,
+            stake_timestamp: clock.unix_timestamp,
+            claimed_rewards: 0,
+            bump,
+            referrer,
+            early_unstaked: 0,
+            auto_relock,
+            carried_rewards: 0,
+            delegate: None,
+            pending_withdraw_amount: 0,
+            request_timestamp: None,
+            locked_reward_rate: pool_data.stake_reward_rate(net_amount, clock.unix_timestamp)?,
+            label: label.unwrap_or_default(),
+            pending_owner: None,
+            lockup_extension: 0,
+            vesting_amount: 0,
+            vesting_start: 0,
+            vesting_cliff: 0,
+            vesting_duration: 0,
+            pending_slash_bps: 0,
+            points_synced_at: clock.unix_timestamp,
+            reward_cohort: cohort,
+        };
+        if let StakeFunding::Grant {
+            vesting_cliff,
+            vesting_duration,
+            ..
+        } = funding
+        {
+            stake_account_data.vesting_amount = transfer_amount;
+            stake_account_data.vesting_start = clock.unix_timestamp;
+            stake_account_data.vesting_cliff = vesting_cliff;
+            stake_account_data.vesting_duration = vesting_duration;
+        }
+
+        msg!(
+            "Staked {} tokens (actual: {}, deposit fee: {}), index: {}, pool: {}",
+            stake.amount,
+            transfer_amount,
+            deposit_fee,
+            index,
+            accounts.pool.key
+        );
+
+        stake_account_data.save(stake.stake_account)?;
+        if let Some(position) = stake_position.as_mut() {
+            position.record(index)?;
+        }
+
+        events.push(StakeEvent {
+            pool: *accounts.pool.key,
+            owner: *accounts.owner.key,
+            index,
+            amount: transfer_amount,
+            fee: fee_received,
+            active_stake_count: pool_data.active_stake_count,
+            event_nonce: pool_data.next_event_nonce()?,
+        });
+    }
+
+    // Save state first to ensure persistence before emitting events
+    pool_data.save(accounts.pool)?;
+    mint_registry.save(accounts.mint_registry)?;
+    if let (Some(position), Some(position_account)) = (&stake_position, accounts.stake_position) {
+        position.save(position_account)?;
+    }
+    if let (Some(reward_cohort), Some(cohort_account)) = (&reward_cohort, accounts.reward_cohort) {
+        reward_cohort.save(cohort_account)?;
+    }
+
+    // Call the pool's hook once per new stake, after the state is saved
+    if let Some(hook) = &hook {
+        for (stake, event) in stakes.iter().zip(&events) {
+            hook.call(
+                StakePoolHookInstruction::AfterStake {
+                    amount: event.amount,
+                    index: event.index,
+                },
+                accounts.pool,
+                stake.stake_account,
+                accounts.owner,
+            )?;
+        }
+    }
+
+    // Emit events for off-chain indexing after successful state save
+    for event in events {
+        StakePoolEvent::Stake(event).emit()?;
+    }
+    emit_if_rewards_cap_reached(accounts.pool, &pool_data, clock.unix_timestamp)
 }
 
-pub fn unstake<'a>(
-    accounts: &'a [AccountInfo<'a>],
+fn process_unstake<'a>(
+    instruction: &str,
+    accounts: UnstakeAccountInfos<'a>,
+    payout: UnstakePayout<'a>,
     amount: u64,
     expected_reward_rate: Option<u64>,
 ) -> ProgramResult {
@@ -257,24 +1251,17 @@ pub fn unstake<'a>(
         return Err(StakePoolError::InvalidParameters.into());
     }
 
-    // Parse accounts using ShankContext-generated struct
-    let ctx = UnstakeAccounts::context(accounts)?;
-
     // Verify account discriminators before loading (Type Cosplay protection)
-    assert_account_key("pool", ctx.accounts.pool, Key::StakePool)?;
-    assert_account_key(
-        "stake_account",
-        ctx.accounts.stake_account,
-        Key::StakeAccount,
-    )?;
+    assert_account_key("pool", accounts.pool, Key::StakePoolV2)?;
+    assert_account_key("stake_account", accounts.stake_account, Key::StakeAccountV2)?;
 
     // Verify program ownership
-    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
-    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+    assert_program_owner("pool", accounts.pool, &crate::ID)?;
+    assert_program_owner("stake_account", accounts.stake_account, &crate::ID)?;
 
     // Load accounts
-    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
-    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+    let mut pool_data = StakePool::load(accounts.pool)?;
+    let mut stake_account_data = StakeAccount::load(accounts.stake_account)?;
 
     // Frontrunning protection: Verify expected reward rate if provided
     if let Some(expected_rate) = expected_reward_rate {
@@ -284,108 +1271,106 @@ pub fn unstake<'a>(
                 expected_rate,
                 pool_data.reward_rate
             );
-            return Err(StakePoolError::PoolParametersChanged.into());
+            return Err(StakePoolError::PoolParametersChanged.with_context(
+                instruction,
+                "reward_rate",
+                expected_rate,
+                pool_data.reward_rate,
+            ));
         }
     }
 
     // Guards
-    assert_signer("owner", ctx.accounts.owner)?;
-    assert_writable("pool", ctx.accounts.pool)?;
-    assert_writable("stake_account", ctx.accounts.stake_account)?;
-    assert_writable("user_token_account", ctx.accounts.user_token_account)?;
-    assert_writable("stake_vault", ctx.accounts.stake_vault)?;
-    assert_same_pubkeys("owner", ctx.accounts.owner, &stake_account_data.owner)?;
-    assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
-    assert_same_pubkeys(
+    assert_signer("owner", accounts.owner)?;
+    assert_writable("pool", accounts.pool)?;
+    assert_writable("stake_account", accounts.stake_account)?;
+    assert_writable("stake_vault", accounts.stake_vault)?;
+    assert_writable("fee_vault", accounts.fee_vault)?;
+    assert_writable("mint_registry", accounts.mint_registry)?;
+    assert_same_pubkeys("owner", accounts.owner, &stake_account_data.owner)?;
+    assert_same_pubkeys("pool", accounts.pool, &stake_account_data.pool)?;
+    assert_vault(
         "stake_vault",
-        ctx.accounts.stake_vault,
-        &pool_data.stake_vault,
+        accounts.stake_vault,
+        accounts.pool.key,
+        &pool_data,
+        PoolVault::Stake,
     )?;
-    assert_same_pubkeys("stake_mint", ctx.accounts.stake_mint, &pool_data.stake_mint)?;
+    assert_vault(
+        "fee_vault",
+        accounts.fee_vault,
+        accounts.pool.key,
+        &pool_data,
+        PoolVault::Fee,
+    )?;
+    assert_same_pubkeys("stake_mint", accounts.stake_mint, &pool_data.stake_mint)?;
 
     // Verify token accounts belong to correct mints
-    verify_stake_token_accounts(
-        ctx.accounts.user_token_account,
-        ctx.accounts.stake_vault,
-        &pool_data.stake_mint,
+    match payout {
+        UnstakePayout::Token(user_token_account) => {
+            assert_writable("user_token_account", user_token_account)?;
+            verify_stake_token_accounts(
+                user_token_account,
+                accounts.stake_vault,
+                &pool_data.stake_mint,
+            )?;
+        }
+        UnstakePayout::Lamports { unwrap_account, .. } => {
+            assert_native_sol_pool(&pool_data)?;
+            assert_writable("owner", accounts.owner)?;
+            assert_writable("unwrap_account", unwrap_account)?;
+            verify_token_account(accounts.stake_vault, &pool_data.stake_mint, None, None)?;
+        }
+    }
+    verify_token_account(accounts.fee_vault, &pool_data.stake_mint, None, None)?;
+    let mut mint_registry = load_mint_registry(accounts.mint_registry, &pool_data.stake_mint)?;
+    let receipt = ReceiptAccounts::resolve(
+        &pool_data,
+        accounts.receipt_mint,
+        accounts.user_receipt_account,
+        accounts.receipt_token_program,
+        accounts.owner.key,
     )?;
+    let hook = PoolHook::resolve(&pool_data, accounts.hook_program, accounts.hook_accounts)?;
+    if let Some(deny_list) = resolve_deny_list(&pool_data, accounts.deny_list)? {
+        let destination = match payout {
+            UnstakePayout::Token(user_token_account) => {
+                get_token_account_owner(user_token_account)?
+            }
+            UnstakePayout::Lamports { .. } => *accounts.owner.key,
+        };
+        deny_list.check_withdrawal(accounts.owner.key, &destination)?;
+    }
+
+    check_global_pause(accounts.program_authority, UserOperation::Withdrawal)?;
 
-    if stake_account_data.amount_staked < amount {
+    // Pools with a cooldown only release principal through RequestUnstake / WithdrawUnstaked
+    if pool_data.unstake_cooldown > 0 {
         msg!(
-            "Insufficient staked balance. Requested: {}, Available: {}",
-            amount,
-            stake_account_data.amount_staked
+            "Pool {} has a {} second unstake cooldown. Use RequestUnstake.",
+            accounts.pool.key,
+            pool_data.unstake_cooldown
         );
-        return Err(StakePoolError::InsufficientStakedBalance.into());
+        return Err(StakePoolError::UnstakeCooldownRequired.into());
     }
 
     // Get current time
-    let clock = Clock::from_account_info(ctx.accounts.clock)?;
-
-    // Check lockup period
-    let time_staked = clock
-        .unix_timestamp
-        .checked_sub(stake_account_data.stake_timestamp)
-        .ok_or(StakePoolError::NumericalOverflow)?;
-
-    let lockup_complete = time_staked >= pool_data.lockup_period;
+    let clock = Clock::from_account_info(accounts.clock)?;
 
-    // If enforce_lockup is true, prevent early withdrawals
-    if pool_data.enforce_lockup && !lockup_complete {
-        msg!(
-            "Lockup period not expired. Time staked: {}, Required: {}",
-            time_staked,
-            pool_data.lockup_period
-        );
-        return Err(StakePoolError::LockupNotExpired.into());
-    }
+    // Check the balance and lockup period
+    let lockup_complete =
+        pool_data.check_unstake_lockup(&stake_account_data, amount, clock.unix_timestamp)?;
 
-    // If lockup not enforced and not complete, warn about forfeiting rewards
-    if !pool_data.enforce_lockup && !lockup_complete {
+    // Warn about forfeiting the withdrawn part's rewards
+    if !lockup_complete {
         msg!("Warning: Unstaking before lockup period complete. Forfeiting proportional rewards.");
     }
 
-    // Calculate how much of the stake is being removed (as a fraction)
-    let total_staked_before = stake_account_data.amount_staked;
-    let remaining_stake = total_staked_before
-        .checked_sub(amount)
-        .ok_or(StakePoolError::NumericalOverflow)?;
+    // Calculate the unclaimed rewards released with the withdrawn part
+    let forfeited_rewards = pool_data.unstake_forfeiture(&stake_account_data, amount)?;
 
-    // Calculate total potential rewards for the original stake
-    let total_potential_rewards = if lockup_complete {
-        pool_data.calculate_rewards(
-            total_staked_before,
-            stake_account_data.stake_timestamp,
-            clock.unix_timestamp,
-        )?
-    } else {
-        0 // No rewards if lockup not complete
-    };
-
-    // Calculate proportional rewards being forfeited
-    let forfeited_rewards = if remaining_stake == 0 {
-        // Full unstake - forfeit all unclaimed rewards
-        total_potential_rewards
-            .checked_sub(stake_account_data.claimed_rewards)
-            .ok_or(StakePoolError::NumericalOverflow)?
-    } else {
-        // Partial unstake - forfeit proportional amount of unclaimed rewards
-        let unstake_fraction = (amount as u128)
-            .checked_mul(REWARD_SCALE)
-            .ok_or(StakePoolError::NumericalOverflow)?
-            .checked_div(total_staked_before as u128)
-            .ok_or(StakePoolError::NumericalOverflow)? as u64;
-
-        let unclaimed_rewards = total_potential_rewards
-            .checked_sub(stake_account_data.claimed_rewards)
-            .ok_or(StakePoolError::NumericalOverflow)?;
-
-        (unclaimed_rewards as u128)
-            .checked_mul(unstake_fraction as u128)
-            .ok_or(StakePoolError::NumericalOverflow)?
-            .checked_div(REWARD_SCALE)
-            .ok_or(StakePoolError::NumericalOverflow)? as u64
-    };
+    // Count the withdrawal against the pool's outflow limit
+    pool_data.record_unstake_outflow(amount, clock.unix_timestamp)?;
 
     // Transfer tokens (with PDA signer)
     let pool_seeds = StakePool::seeds(&pool_data.stake_mint, pool_data.pool_id);
@@ -393,26 +1378,42 @@ pub fn unstake<'a>(
     seeds_with_bump.push(vec![pool_data.bump]);
     let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
 
-    let actual_amount = transfer_tokens_with_fee(
-        ctx.accounts.stake_vault,
-        ctx.accounts.user_token_account,
-        ctx.accounts.stake_mint,
-        ctx.accounts.pool,
-        ctx.accounts.token_program,
-        amount,
-        &[&seeds_refs],
-    )?;
-
-    // Update balances with actual transferred amount
-    stake_account_data.amount_staked = stake_account_data
-        .amount_staked
-        .checked_sub(actual_amount)
+    // Withdrawal fee is routed to the fee vault; the owner receives the rest
+    let withdraw_fee = pool_data.withdraw_fee(amount)?;
+    let owner_amount = amount
+        .checked_sub(withdraw_fee)
         .ok_or(StakePoolError::NumericalOverflow)?;
 
+    let fee_received = if withdraw_fee > 0 {
+        transfer_tokens_with_fee(
+            accounts.stake_vault,
+            accounts.fee_vault,
+            accounts.stake_mint,
+            accounts.pool,
+            accounts.token_program,
+            withdraw_fee,
+            &[&seeds_refs],
+        )?
+    } else {
+        0
+    };
+
+    let actual_amount = payout.withdraw(&accounts, owner_amount, &seeds_refs)?;
+
+    // The vault is debited the full amount; transfer fees only reduce what the
+    // owner and the fee vault receive
+    stake_account_data.remove_principal(amount, forfeited_rewards)?;
+
     pool_data.total_staked = pool_data
         .total_staked
-        .checked_sub(actual_amount)
+        .checked_sub(amount)
         .ok_or(StakePoolError::NumericalOverflow)?;
+    mint_registry.record_unstake(amount);
+
+    // Receipts are burned for exactly what leaves the position
+    if let Some(receipt) = &receipt {
+        receipt.burn(accounts.owner, amount)?;
+    }
 
     // Update rewards owed to reflect forfeited rewards
     pool_data.total_rewards_owed = pool_data
@@ -420,33 +1421,61 @@ pub fn unstake<'a>(
         .checked_sub(forfeited_rewards)
         .ok_or(StakePoolError::NumericalOverflow)?;
 
+    if !lockup_complete {
+        stake_account_data.early_unstaked = stake_account_data
+            .early_unstaked
+            .checked_add(amount)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+    }
+
     // If fully unstaking, reset claimed rewards and timestamp
     // For partial unstakes, keep the original timestamp and adjust expectations
     if stake_account_data.amount_staked == 0 {
         stake_account_data.claimed_rewards = 0;
         stake_account_data.stake_timestamp = 0;
+        stake_account_data.early_unstaked = 0;
+        stake_account_data.carried_rewards = 0;
+        stake_account_data.lockup_extension = 0;
+        pool_data.record_stake_emptied();
         msg!("Full unstake - stake account reset");
     }
 
     msg!(
-        "Unstaked {} tokens (actual: {}), forfeited {} reward tokens",
+        "Unstaked {} tokens (actual: {}, withdraw fee: {}), forfeited {} reward tokens",
         amount,
         actual_amount,
+        withdraw_fee,
         forfeited_rewards
     );
 
     // Save state first to ensure persistence before emitting event
-    pool_data.save(ctx.accounts.pool)?;
-    stake_account_data.save(ctx.accounts.stake_account)?;
+    let event_nonce = pool_data.next_event_nonce()?;
+    pool_data.save(accounts.pool)?;
+    stake_account_data.save(accounts.stake_account)?;
+    mint_registry.save(accounts.mint_registry)?;
 
-    // Emit event for off-chain indexing after successful state save
-    sol_log_data(&[
-        b"Unstake",
-        ctx.accounts.pool.key.as_ref(),
-        ctx.accounts.owner.key.as_ref(),
-        &actual_amount.to_le_bytes(),
-        &forfeited_rewards.to_le_bytes(),
-    ]);
+    // Call the pool's hook after the state is saved
+    if let Some(hook) = &hook {
+        hook.call(
+            StakePoolHookInstruction::AfterUnstake {
+                amount: actual_amount,
+                remaining: stake_account_data.amount_staked,
+            },
+            accounts.pool,
+            accounts.stake_account,
+            accounts.owner,
+        )?;
+    }
 
-    Ok(())
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::Unstake(UnstakeEvent {
+        pool: *accounts.pool.key,
+        owner: *accounts.owner.key,
+        amount: actual_amount,
+        fee: fee_received,
+        forfeited_rewards,
+        active_stake_count: pool_data.active_stake_count,
+        event_nonce,
+    })
+    .emit()
 }
@@ -0,0 +1,351 @@
+//! Reward streams dripping a funder's tokens into a pool over time
+//!
+//! Instead of funding a lump sum, an allowed reward funder opens a stream PDA
+//! with a rate per second and an end time, delegating the whole stream from
+//! their token account to the PDA. Anyone can crank the stream to move what
+//! accrued since the last crank into the reward vault, where it is recorded as
+//! funded rewards and backs new stakes in the stake-time solvency check. The
+//! funder can close the stream at any time, revoking the delegation.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+use crate::assertions::*;
+use crate::error::StakePoolError;
+use crate::events::{
+    RewardStreamClosedEvent, RewardStreamCreatedEvent, RewardStreamedEvent, StakePoolEvent,
+};
+use crate::instruction::accounts::*;
+use crate::state::{Key, PoolVault, RewardStream, StakePool};
+use crate::utils::{
+    approve_tokens, close_account, create_account, revoke_tokens, transfer_tokens_with_fee,
+};
+
+use super::helpers::{
+    emit_keeper_bounty_paid, get_token_account_delegate, pay_keeper_bounty,
+    validate_current_timestamp, verify_token_account, verify_token_account_owner,
+};
+
+/// Load a reward stream after verifying its discriminator and owner
+fn load_reward_stream(account: &AccountInfo) -> Result<RewardStream, ProgramError> {
+    assert_account_key("reward_stream", account, Key::RewardStream)?;
+    assert_program_owner("reward_stream", account, &crate::ID)?;
+    assert_writable("reward_stream", account)?;
+    RewardStream::load(account)
+}
+
+/// Open a reward stream of `rate_per_second` until `end_ts` (same funders as FundRewards)
+///
+/// # Errors
+/// Returns error if:
+/// - The funder may not fund the pool's rewards
+/// - `rate_per_second` is zero or `end_ts` is not in the future
+/// - The funder token account is not the funder's reward-mint account
+/// - The stream is not the ["reward_stream", pool, funder] PDA or already exists
+pub fn create_reward_stream<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    rate_per_second: u64,
+    end_ts: i64,
+) -> ProgramResult {
+    let ctx = CreateRewardStreamAccounts::context(accounts)?;
+
+    // Verify pool account discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    let pool_data = StakePool::load(ctx.accounts.pool)?;
+
+    // Guards
+    assert_signer("funder", ctx.accounts.funder)?;
+    assert_writable("funder", ctx.accounts.funder)?;
+    assert_writable("funder_token_account", ctx.accounts.funder_token_account)?;
+    assert_writable("reward_stream", ctx.accounts.reward_stream)?;
+    assert_empty("reward_stream", ctx.accounts.reward_stream)?;
+    assert_same_pubkeys(
+        "reward_mint",
+        ctx.accounts.reward_mint,
+        &pool_data.reward_mint,
+    )?;
+
+    if !pool_data.is_reward_funder_allowed(ctx.accounts.funder.key) {
+        msg!(
+            "Funder {} is not in the pool's reward funder allowlist",
+            ctx.accounts.funder.key
+        );
+        return Err(StakePoolError::RewardFunderNotAllowed.into());
+    }
+
+    verify_token_account(
+        ctx.accounts.funder_token_account,
+        &pool_data.reward_mint,
+        None,
+        None,
+    )?;
+    verify_token_account_owner(
+        ctx.accounts.funder_token_account,
+        ctx.accounts.funder.key,
+        "funder_token_account",
+    )?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    validate_current_timestamp(current_time)?;
+
+    if rate_per_second == 0 {
+        msg!("Stream rate must be greater than zero");
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+    if end_ts <= current_time {
+        msg!(
+            "Stream end {} must be after the current time {}",
+            end_ts,
+            current_time
+        );
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+    let total_amount = RewardStream::total_amount(rate_per_second, current_time, end_ts)?;
+
+    let pool_key = ctx.accounts.pool.key;
+    let funder_key = ctx.accounts.funder.key;
+    let (reward_stream_key, bump) = RewardStream::find_pda(pool_key, funder_key);
+    assert_same_pubkeys(
+        "reward_stream",
+        ctx.accounts.reward_stream,
+        &reward_stream_key,
+    )?;
+
+    let mut seeds_with_bump = RewardStream::seeds(pool_key, funder_key);
+    seeds_with_bump.push(vec![bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    create_account(
+        ctx.accounts.reward_stream,
+        ctx.accounts.funder,
+        ctx.accounts.system_program,
+        RewardStream::LEN,
+        &crate::ID,
+        Some(&[&seeds_refs]),
+    )?;
+
+    // The stream draws from the funder's account as it is cranked
+    approve_tokens(
+        ctx.accounts.funder_token_account,
+        ctx.accounts.reward_mint,
+        ctx.accounts.reward_stream,
+        ctx.accounts.funder,
+        ctx.accounts.token_program,
+        total_amount,
+    )?;
+
+    let stream = RewardStream {
+        key: Key::RewardStream,
+        pool: *pool_key,
+        funder: *funder_key,
+        funder_token_account: *ctx.accounts.funder_token_account.key,
+        rate_per_second,
+        last_streamed_at: current_time,
+        end_ts,
+        total_streamed: 0,
+        bump,
+    };
+
+    msg!(
+        "Reward stream of {} tokens/s until {} opened for pool {} ({} tokens in total)",
+        rate_per_second,
+        end_ts,
+        pool_key,
+        total_amount
+    );
+
+    // Save state first to ensure persistence before emitting event
+    stream.save(ctx.accounts.reward_stream)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::RewardStreamCreated(RewardStreamCreatedEvent {
+        pool: *pool_key,
+        reward_stream: reward_stream_key,
+        funder: *funder_key,
+        rate_per_second,
+        end_ts,
+        total_amount,
+    })
+    .emit()
+}
+
+/// Move the rewards a stream accrued since its last crank into the reward vault
+///
+/// Permissionless. The received amount is recorded in `total_rewards_funded`.
+/// Succeeds without a transfer (or keeper bounty) when nothing has accrued.
+///
+/// # Errors
+/// Returns error if:
+/// - The stream belongs to another pool or funder token account
+/// - The stream's funder may no longer fund the pool's rewards
+/// - The reward vault does not match the pool's reward vault
+/// - The funder token account no longer covers the accrued amount
+pub fn crank_reward_stream<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = CrankRewardStreamAccounts::context(accounts)?;
+
+    // Verify pool account discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+    let mut stream = load_reward_stream(ctx.accounts.reward_stream)?;
+
+    // Guards
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("funder_token_account", ctx.accounts.funder_token_account)?;
+    assert_writable("reward_vault", ctx.accounts.reward_vault)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &stream.pool)?;
+    assert_same_pubkeys(
+        "funder_token_account",
+        ctx.accounts.funder_token_account,
+        &stream.funder_token_account,
+    )?;
+    assert_vault(
+        "reward_vault",
+        ctx.accounts.reward_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Reward,
+    )?;
+    assert_same_pubkeys(
+        "reward_mint",
+        ctx.accounts.reward_mint,
+        &pool_data.reward_mint,
+    )?;
+
+    // A funder dropped from the allowlist stops streaming too
+    if !pool_data.is_reward_funder_allowed(&stream.funder) {
+        msg!(
+            "Funder {} is not in the pool's reward funder allowlist",
+            stream.funder
+        );
+        return Err(StakePoolError::RewardFunderNotAllowed.into());
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    validate_current_timestamp(current_time)?;
+
+    let (amount, streamed_until) = stream.due_amount(current_time)?;
+    if amount == 0 {
+        msg!(
+            "Nothing accrued on reward stream {}",
+            ctx.accounts.reward_stream.key
+        );
+        return Ok(());
+    }
+
+    let mut seeds_with_bump = RewardStream::seeds(&stream.pool, &stream.funder);
+    seeds_with_bump.push(vec![stream.bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    let received = transfer_tokens_with_fee(
+        ctx.accounts.funder_token_account,
+        ctx.accounts.reward_vault,
+        ctx.accounts.reward_mint,
+        ctx.accounts.reward_stream,
+        ctx.accounts.token_program,
+        amount,
+        &[&seeds_refs],
+    )?;
+
+    pool_data.total_rewards_funded = pool_data
+        .total_rewards_funded
+        .checked_add(received)
+        .ok_or(StakePoolError::NumericalOverflow)?;
+    stream.last_streamed_at = streamed_until;
+    stream.total_streamed = stream
+        .total_streamed
+        .checked_add(amount)
+        .ok_or(StakePoolError::NumericalOverflow)?;
+
+    let bounty = pay_keeper_bounty(
+        ctx.accounts.pool,
+        &mut pool_data,
+        ctx.accounts.keeper,
+        current_time,
+    )?;
+
+    msg!(
+        "Streamed {} reward tokens into pool {} (received: {}, total funded: {})",
+        amount,
+        ctx.accounts.pool.key,
+        received,
+        pool_data.total_rewards_funded
+    );
+
+    // Save state first to ensure persistence before emitting event
+    pool_data.save(ctx.accounts.pool)?;
+    stream.save(ctx.accounts.reward_stream)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::RewardStreamed(RewardStreamedEvent {
+        pool: stream.pool,
+        reward_stream: *ctx.accounts.reward_stream.key,
+        amount,
+        received,
+        streamed_until,
+    })
+    .emit()?;
+    emit_keeper_bounty_paid(ctx.accounts.pool, ctx.accounts.keeper, bounty)
+}
+
+/// Close a reward stream, revoking its delegation (stream funder only)
+///
+/// Rewards accrued but not yet cranked are not streamed. The delegation is
+/// only revoked while it still points at the stream, so a delegation the
+/// funder has since granted elsewhere is left alone.
+///
+/// # Errors
+/// Returns error if:
+/// - The signer is not the stream's funder
+/// - The funder token account is not the stream's
+pub fn close_reward_stream<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = CloseRewardStreamAccounts::context(accounts)?;
+
+    let stream = load_reward_stream(ctx.accounts.reward_stream)?;
+
+    // Guards
+    assert_signer("funder", ctx.accounts.funder)?;
+    assert_writable("funder", ctx.accounts.funder)?;
+    assert_writable("funder_token_account", ctx.accounts.funder_token_account)?;
+    assert_same_pubkeys("funder", ctx.accounts.funder, &stream.funder)?;
+    assert_same_pubkeys(
+        "funder_token_account",
+        ctx.accounts.funder_token_account,
+        &stream.funder_token_account,
+    )?;
+
+    if get_token_account_delegate(ctx.accounts.funder_token_account)?
+        == Some(*ctx.accounts.reward_stream.key)
+    {
+        revoke_tokens(
+            ctx.accounts.funder_token_account,
+            ctx.accounts.funder,
+            ctx.accounts.token_program,
+        )?;
+    }
+
+    msg!(
+        "Reward stream {} closed after streaming {} tokens",
+        ctx.accounts.reward_stream.key,
+        stream.total_streamed
+    );
+
+    close_account(ctx.accounts.reward_stream, ctx.accounts.funder)?;
+
+    // Emit event for off-chain indexing after the account is closed
+    StakePoolEvent::RewardStreamClosed(RewardStreamClosedEvent {
+        pool: stream.pool,
+        reward_stream: *ctx.accounts.reward_stream.key,
+        funder: stream.funder,
+        total_streamed: stream.total_streamed,
+    })
+    .emit()
+}
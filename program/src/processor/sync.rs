@@ -0,0 +1,182 @@
+//! Permissionless solvency crank
+//!
+//! `sync_pool` compares the live vault balances with the pool's recorded totals
+//! so anyone (typically a keeper bot) can detect a drained or underfunded pool.
+//! An insolvent pool is paused to stop new commitments; unpausing stays with
+//! the program admins via `update_pool`. `get_pool_stats` reads the same
+//! balances without changing anything, for dashboards.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    sysvar::{clock::Clock, Sysvar},
+};
+
+use crate::assertions::*;
+use crate::events::{PoolSolvencyCheckedEvent, StakePoolEvent};
+use crate::instruction::accounts::*;
+use crate::return_data;
+use crate::state::{Key, PoolVault, StakePool};
+
+use super::helpers::{
+    emit_keeper_bounty_paid, get_token_account_balance, pay_keeper_bounty,
+    validate_current_timestamp, verify_token_account,
+};
+
+/// Check the pool's vault balances against `total_staked` and `total_rewards_owed`
+///
+/// Never fails on insolvency: the pause and the `PoolSolvencyChecked` event
+/// must persist, so the outcome is reported rather than returned as an error.
+/// The keeper bounty is paid either way, at most once per KEEPER_BOUNTY_INTERVAL.
+pub fn sync_pool<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = SyncPoolAccounts::context(accounts)?;
+
+    // Verify pool discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // Load pool
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+
+    // Guards
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_vault(
+        "stake_vault",
+        ctx.accounts.stake_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Stake,
+    )?;
+    assert_vault(
+        "reward_vault",
+        ctx.accounts.reward_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Reward,
+    )?;
+    verify_token_account(ctx.accounts.stake_vault, &pool_data.stake_mint, None, None)?;
+    verify_token_account(
+        ctx.accounts.reward_vault,
+        &pool_data.reward_mint,
+        None,
+        None,
+    )?;
+
+    let stake_vault_balance = get_token_account_balance(ctx.accounts.stake_vault)?;
+    let reward_vault_balance = get_token_account_balance(ctx.accounts.reward_vault)?;
+    let solvent = pool_data.is_solvent(stake_vault_balance, reward_vault_balance);
+    let mut paused = false;
+
+    if solvent {
+        msg!(
+            "Pool {} is solvent. Staked: {}/{}, Rewards: {}/{}",
+            ctx.accounts.pool.key,
+            pool_data.total_staked,
+            stake_vault_balance,
+            pool_data.reward_vault_owed(),
+            reward_vault_balance
+        );
+    } else {
+        msg!(
+            "Pool insolvency detected! Stake deficit: {}, Reward deficit: {}",
+            pool_data.total_staked.saturating_sub(stake_vault_balance),
+            pool_data
+                .reward_vault_owed()
+                .saturating_sub(reward_vault_balance)
+        );
+
+        if !pool_data.is_paused {
+            pool_data.is_paused = true;
+            paused = true;
+            msg!("Pool {} paused", ctx.accounts.pool.key);
+        }
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+    validate_current_timestamp(current_time)?;
+    let bounty = pay_keeper_bounty(
+        ctx.accounts.pool,
+        &mut pool_data,
+        ctx.accounts.keeper,
+        current_time,
+    )?;
+
+    if paused || bounty > 0 {
+        // Save state first to ensure persistence before emitting event
+        pool_data.save(ctx.accounts.pool)?;
+    }
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::PoolSolvencyChecked(PoolSolvencyCheckedEvent {
+        pool: *ctx.accounts.pool.key,
+        stake_vault_balance,
+        total_staked: pool_data.total_staked,
+        reward_vault_balance,
+        total_rewards_owed: pool_data.total_rewards_owed,
+        solvent,
+        paused: pool_data.is_paused,
+    })
+    .emit()?;
+    emit_keeper_bounty_paid(ctx.accounts.pool, ctx.accounts.keeper, bounty)
+}
+
+/// Report the pool's totals, live vault balances and coverage (read-only)
+///
+/// Returns a `PoolStats` via return data so a dashboard can render the pool's
+/// health from a single simulated call.
+pub fn get_pool_stats<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let ctx = GetPoolStatsAccounts::context(accounts)?;
+
+    // Verify pool discriminator before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+
+    // Load pool
+    let pool_data = StakePool::load(ctx.accounts.pool)?;
+
+    // Guards
+    assert_vault(
+        "stake_vault",
+        ctx.accounts.stake_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Stake,
+    )?;
+    assert_vault(
+        "reward_vault",
+        ctx.accounts.reward_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Reward,
+    )?;
+    verify_token_account(ctx.accounts.stake_vault, &pool_data.stake_mint, None, None)?;
+    verify_token_account(
+        ctx.accounts.reward_vault,
+        &pool_data.reward_mint,
+        None,
+        None,
+    )?;
+
+    let stats = pool_data.stats(
+        get_token_account_balance(ctx.accounts.stake_vault)?,
+        get_token_account_balance(ctx.accounts.reward_vault)?,
+    );
+
+    msg!(
+        "Pool stats: staked {}/{}, rewards {}/{}, active stakes {:?}, paused {}",
+        stats.total_staked,
+        stats.stake_vault_balance,
+        stats.total_rewards_owed,
+        stats.reward_vault_balance,
+        stats.active_stake_count,
+        stats.is_paused
+    );
+
+    return_data::set(&stats)
+}
@@ -0,0 +1,218 @@
+//! Stake position ownership transfers
+//!
+//! An owner rotating keys can hand a position to another wallet without
+//! unstaking. The owner nominates the new wallet with `nominate_stake_owner`,
+//! and the nominee completes the move with `accept_stake_ownership`. Since the
+//! owner is part of the stake account's seeds, accepting creates the new
+//! owner's stake account, copies the position into it and closes the previous
+//! one, all in the same instruction.
+
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
+
+use crate::assertions::*;
+use crate::error::StakePoolError;
+use crate::events::{StakeOwnerNominatedEvent, StakeOwnershipTransferredEvent, StakePoolEvent};
+use crate::instruction::accounts::*;
+use crate::state::{Key, StakeAccount, StakePool};
+use crate::utils::{close_account, create_account};
+
+use super::helpers::{load_or_create_stake_position, load_stake_position, resolve_deny_list};
+
+/// Nominate the wallet that may take over a stake position, or cancel the nomination (owner only)
+pub fn nominate_stake_owner<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    new_owner: Option<Pubkey>,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = NominateStakeOwnerAccounts::context(accounts)?;
+
+    // Verify account discriminators before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+
+    // Load accounts
+    let pool_data = StakePool::load(ctx.accounts.pool)?;
+    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+
+    // Guards
+    assert_signer("owner", ctx.accounts.owner)?;
+    assert_writable("stake_account", ctx.accounts.stake_account)?;
+    assert_same_pubkeys("owner", ctx.accounts.owner, &stake_account_data.owner)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
+
+    if new_owner.as_ref() == Some(ctx.accounts.owner.key) {
+        msg!(
+            "The stake account is already owned by {}",
+            ctx.accounts.owner.key
+        );
+        return Err(StakePoolError::InvalidParameters.into());
+    }
+
+    // Cancelling a nomination moves nothing, so only nominations are checked
+    if let Some(new_owner) = &new_owner {
+        if let Some(deny_list) = resolve_deny_list(&pool_data, ctx.accounts.deny_list)? {
+            deny_list.check("Stake owner", ctx.accounts.owner.key)?;
+            deny_list.check("Nominated owner", new_owner)?;
+        }
+    }
+
+    stake_account_data.pending_owner = new_owner;
+    stake_account_data.save(ctx.accounts.stake_account)?;
+
+    match new_owner {
+        Some(new_owner) => msg!(
+            "Stake account {} nominated for transfer to {}",
+            ctx.accounts.stake_account.key,
+            new_owner
+        ),
+        None => msg!(
+            "Transfer nomination of stake account {} cancelled",
+            ctx.accounts.stake_account.key
+        ),
+    }
+
+    // Emit event for off-chain indexing
+    StakePoolEvent::StakeOwnerNominated(StakeOwnerNominatedEvent {
+        stake_account: *ctx.accounts.stake_account.key,
+        owner: *ctx.accounts.owner.key,
+        pending_owner: new_owner,
+    })
+    .emit()
+}
+
+/// Move a nominated stake position into the new owner's stake account at `new_index`
+///
+/// # Errors
+/// Returns error if:
+/// - The signer is not the nominated owner
+/// - The pool mints receipt tokens (they stay with the previous owner)
+/// - The pool checks the deny list and it lists either owner
+/// - The new stake account is not the ["stake_account", pool, new_owner, new_index]
+///   PDA or already exists
+pub fn accept_stake_ownership<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    new_index: u64,
+) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = AcceptStakeOwnershipAccounts::context(accounts)?;
+
+    // Verify account discriminators before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+
+    // Load accounts
+    let pool_data = StakePool::load(ctx.accounts.pool)?;
+    let stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+
+    // Guards
+    assert_signer("new_owner", ctx.accounts.new_owner)?;
+    assert_writable("new_owner", ctx.accounts.new_owner)?;
+    assert_writable("stake_account", ctx.accounts.stake_account)?;
+    assert_writable("new_stake_account", ctx.accounts.new_stake_account)?;
+    assert_writable("previous_owner", ctx.accounts.previous_owner)?;
+    assert_empty("new_stake_account", ctx.accounts.new_stake_account)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
+    assert_same_pubkeys(
+        "previous_owner",
+        ctx.accounts.previous_owner,
+        &stake_account_data.owner,
+    )?;
+
+    // Receipt tokens are held by the previous owner's wallet and burned on unstake,
+    // so the position cannot move without them
+    if let Some(receipt_mint) = pool_data.receipt_mint {
+        msg!(
+            "Pool tokenizes positions with receipt mint {}; transfer the receipts instead",
+            receipt_mint
+        );
+        return Err(StakePoolError::ReceiptPositionNotTransferable.into());
+    }
+    if let Some(deny_list) = resolve_deny_list(&pool_data, ctx.accounts.deny_list)? {
+        deny_list.check("Stake owner", &stake_account_data.owner)?;
+        deny_list.check("New owner", ctx.accounts.new_owner.key)?;
+    }
+
+    let pool_key = ctx.accounts.pool.key;
+    let new_owner = ctx.accounts.new_owner.key;
+    let (new_stake_account_key, bump) = StakeAccount::find_pda(pool_key, new_owner, new_index);
+    assert_same_pubkeys(
+        "new_stake_account",
+        ctx.accounts.new_stake_account,
+        &new_stake_account_key,
+    )?;
+
+    let new_stake_account_data = stake_account_data.transfer_to(new_owner, new_index, bump)?;
+
+    let mut seeds_with_bump = StakeAccount::seeds(pool_key, new_owner, new_index);
+    seeds_with_bump.push(vec![bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    create_account(
+        ctx.accounts.new_stake_account,
+        ctx.accounts.new_owner,
+        ctx.accounts.system_program,
+        StakeAccount::LEN,
+        &crate::ID,
+        Some(&[&seeds_refs]),
+    )?;
+
+    // Move the index between the owners' stake position directories: the previous
+    // owner's, if it has one, releases it and the new owner's records it
+    if let Some(position_account) = ctx.accounts.previous_stake_position {
+        if !position_account.data_is_empty() {
+            let mut position =
+                load_stake_position(position_account, pool_key, &stake_account_data.owner)?;
+            position.release(stake_account_data.index);
+            position.save(position_account)?;
+        }
+    }
+    if let Some(position_account) = ctx.accounts.new_stake_position {
+        let mut position = load_or_create_stake_position(
+            position_account,
+            pool_key,
+            new_owner,
+            ctx.accounts.new_owner,
+            ctx.accounts.system_program,
+        )?;
+        position.record(new_index)?;
+        position.save(position_account)?;
+    }
+
+    // Save the new position before closing the previous account
+    new_stake_account_data.save(ctx.accounts.new_stake_account)?;
+    close_account(ctx.accounts.stake_account, ctx.accounts.previous_owner)?;
+
+    msg!(
+        "Stake account {} of {} transferred to {} as stake account {}",
+        ctx.accounts.stake_account.key,
+        stake_account_data.owner,
+        new_owner,
+        new_stake_account_key
+    );
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::StakeOwnershipTransferred(StakeOwnershipTransferredEvent {
+        pool: *pool_key,
+        previous_stake_account: *ctx.accounts.stake_account.key,
+        stake_account: new_stake_account_key,
+        previous_owner: stake_account_data.owner,
+        new_owner: *new_owner,
+    })
+    .emit()
+}
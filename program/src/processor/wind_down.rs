@@ -0,0 +1,219 @@
+//! Wind-down of ended pools
+//!
+//! Stakes nobody comes back for keep their principal in the stake vault after
+//! the pool has ended. `WIND_DOWN_DELAY` after the pool's end date anyone may
+//! call `wind_down_stake` to push such a stake's principal to its owner's
+//! associated token account and close the stake account, refunding its rent to
+//! the owner. The owner's earned rewards are paid or expired according to the
+//! pool's `wind_down_rewards`.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    sysvar::{clock::Clock, Sysvar},
+};
+use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
+
+use crate::assertions::*;
+use crate::error::StakePoolError;
+use crate::events::{StakePoolEvent, StakeWoundDownEvent};
+use crate::instruction::accounts::*;
+use crate::state::{Key, PoolVault, StakeAccount, StakePool, UserOperation};
+use crate::utils::{close_account, transfer_tokens_with_fee};
+
+use super::helpers::{
+    check_global_pause, get_token_account_balance, load_mint_registry, load_stake_position,
+    validate_current_timestamp, verify_reward_token_accounts, verify_stake_token_accounts,
+};
+
+/// Return an abandoned stake of an ended pool to its owner and close the stake account
+///
+/// Permissionless: the wind-down delay is the authorization. Funds only ever go to
+/// the owner's associated token accounts and the rent to the owner. Receipt tokens
+/// of pools that tokenize positions stay with their holder and no longer back a stake.
+pub fn wind_down_stake<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    // Parse accounts using ShankContext-generated struct
+    let ctx = WindDownStakeAccounts::context(accounts)?;
+
+    // Verify account discriminators before loading (Type Cosplay protection)
+    assert_account_key("pool", ctx.accounts.pool, Key::StakePoolV2)?;
+    assert_account_key(
+        "stake_account",
+        ctx.accounts.stake_account,
+        Key::StakeAccountV2,
+    )?;
+
+    // Verify program ownership
+    assert_program_owner("pool", ctx.accounts.pool, &crate::ID)?;
+    assert_program_owner("stake_account", ctx.accounts.stake_account, &crate::ID)?;
+
+    // Load accounts
+    let mut pool_data = StakePool::load(ctx.accounts.pool)?;
+    let mut stake_account_data = StakeAccount::load(ctx.accounts.stake_account)?;
+
+    // Guards
+    assert_writable("pool", ctx.accounts.pool)?;
+    assert_writable("stake_account", ctx.accounts.stake_account)?;
+    assert_writable("owner", ctx.accounts.owner)?;
+    assert_writable("owner_token_account", ctx.accounts.owner_token_account)?;
+    assert_writable("stake_vault", ctx.accounts.stake_vault)?;
+    assert_writable("reward_vault", ctx.accounts.reward_vault)?;
+    assert_writable("mint_registry", ctx.accounts.mint_registry)?;
+    assert_same_pubkeys("owner", ctx.accounts.owner, &stake_account_data.owner)?;
+    assert_same_pubkeys("pool", ctx.accounts.pool, &stake_account_data.pool)?;
+    assert_vault(
+        "stake_vault",
+        ctx.accounts.stake_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Stake,
+    )?;
+    assert_vault(
+        "reward_vault",
+        ctx.accounts.reward_vault,
+        ctx.accounts.pool.key,
+        &pool_data,
+        PoolVault::Reward,
+    )?;
+    assert_same_pubkeys("stake_mint", ctx.accounts.stake_mint, &pool_data.stake_mint)?;
+    assert_same_pubkeys(
+        "reward_mint",
+        ctx.accounts.reward_mint,
+        &pool_data.reward_mint,
+    )?;
+
+    // The caller is not the owner, so the token program must be the vaults' own
+    // and the principal can only go to the owner's associated token account
+    assert_program_owner(
+        "stake_vault",
+        ctx.accounts.stake_vault,
+        ctx.accounts.token_program.key,
+    )?;
+    assert_same_pubkeys(
+        "owner_token_account",
+        ctx.accounts.owner_token_account,
+        &get_associated_token_address_with_program_id(
+            ctx.accounts.owner.key,
+            &pool_data.stake_mint,
+            ctx.accounts.token_program.key,
+        ),
+    )?;
+    verify_stake_token_accounts(
+        ctx.accounts.owner_token_account,
+        ctx.accounts.stake_vault,
+        &pool_data.stake_mint,
+    )?;
+    let mut mint_registry = load_mint_registry(ctx.accounts.mint_registry, &pool_data.stake_mint)?;
+
+    check_global_pause(ctx.accounts.program_authority, UserOperation::Withdrawal)?;
+
+    let clock = Clock::get()?;
+    validate_current_timestamp(clock.unix_timestamp)?;
+
+    let wind_down = pool_data.wind_down_stake(&mut stake_account_data, clock.unix_timestamp)?;
+    mint_registry.record_unstake(wind_down.principal);
+
+    // Transfer tokens (with PDA signer)
+    let pool_seeds = StakePool::seeds(&pool_data.stake_mint, pool_data.pool_id);
+    let mut seeds_with_bump = pool_seeds.clone();
+    seeds_with_bump.push(vec![pool_data.bump]);
+    let seeds_refs: Vec<&[u8]> = seeds_with_bump.iter().map(|s| s.as_slice()).collect();
+
+    // No withdrawal fee: the owner did not ask for the withdrawal
+    let received = if wind_down.principal > 0 {
+        transfer_tokens_with_fee(
+            ctx.accounts.stake_vault,
+            ctx.accounts.owner_token_account,
+            ctx.accounts.stake_mint,
+            ctx.accounts.pool,
+            ctx.accounts.token_program,
+            wind_down.principal,
+            &[&seeds_refs],
+        )?
+    } else {
+        0
+    };
+
+    if wind_down.rewards_paid > 0 {
+        let owner_reward_account = ctx.accounts.owner_reward_account.ok_or_else(|| {
+            msg!("owner_reward_account is required to pay the stake's earned rewards");
+            ProgramError::NotEnoughAccountKeys
+        })?;
+        assert_writable("owner_reward_account", owner_reward_account)?;
+        assert_same_pubkeys(
+            "owner_reward_account",
+            owner_reward_account,
+            &get_associated_token_address_with_program_id(
+                ctx.accounts.owner.key,
+                &pool_data.reward_mint,
+                ctx.accounts.token_program.key,
+            ),
+        )?;
+        verify_reward_token_accounts(
+            owner_reward_account,
+            ctx.accounts.reward_vault,
+            &pool_data.reward_mint,
+        )?;
+
+        let reward_vault_balance = get_token_account_balance(ctx.accounts.reward_vault)?;
+        if reward_vault_balance < wind_down.rewards_paid {
+            msg!(
+                "Insufficient rewards in vault. Required: {}, Available: {}",
+                wind_down.rewards_paid,
+                reward_vault_balance
+            );
+            return Err(StakePoolError::InsufficientRewards.into());
+        }
+
+        transfer_tokens_with_fee(
+            ctx.accounts.reward_vault,
+            owner_reward_account,
+            ctx.accounts.reward_mint,
+            ctx.accounts.pool,
+            ctx.accounts.token_program,
+            wind_down.rewards_paid,
+            &[&seeds_refs],
+        )?;
+    }
+
+    // Remove the index from the owner's stake position directory, if it has one
+    if let Some(position_account) = ctx.accounts.stake_position {
+        if !position_account.data_is_empty() {
+            let mut position = load_stake_position(
+                position_account,
+                &stake_account_data.pool,
+                &stake_account_data.owner,
+            )?;
+            position.release(stake_account_data.index);
+            position.save(position_account)?;
+        }
+    }
+
+    msg!(
+        "Wound down stake account {}: returned {} tokens (actual: {}), rewards paid: {}, expired: {}",
+        ctx.accounts.stake_account.key,
+        wind_down.principal,
+        received,
+        wind_down.rewards_paid,
+        wind_down.rewards_expired
+    );
+
+    // Save state first to ensure persistence before emitting event
+    pool_data.save(ctx.accounts.pool)?;
+    mint_registry.save(ctx.accounts.mint_registry)?;
+    close_account(ctx.accounts.stake_account, ctx.accounts.owner)?;
+
+    // Emit event for off-chain indexing after successful state save
+    StakePoolEvent::StakeWoundDown(StakeWoundDownEvent {
+        pool: *ctx.accounts.pool.key,
+        stake_account: *ctx.accounts.stake_account.key,
+        owner: stake_account_data.owner,
+        principal: wind_down.principal,
+        received,
+        rewards_paid: wind_down.rewards_paid,
+        rewards_expired: wind_down.rewards_expired,
+    })
+    .emit()
+}
@@ -0,0 +1,154 @@
+//! Borsh-encoded payloads returned to clients via `set_return_data`
+//!
+//! Instructions that want to hand structured information back to wallets
+//! (e.g. through `simulateTransaction`) serialize one of these types and
+//! publish it with `solana_program::program::set_return_data`. Clients read
+//! the base64 `returnData` field of the simulation/transaction result and
+//! deserialize it with the same Borsh layout.
+//!
+//! The runtime strips trailing zero bytes from return data, so clients must
+//! pad the bytes back with zeros before deserializing (the Rust client's
+//! `return_data` module does this).
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{entrypoint::ProgramResult, program::set_return_data, pubkey::Pubkey};
+
+use crate::error::StakePoolError;
+
+/// Serialize `value` and publish it as the instruction's return data
+pub fn set<T: BorshSerialize>(value: &T) -> ProgramResult {
+    let data = borsh::to_vec(value).map_err(|_| StakePoolError::SerializationError)?;
+    set_return_data(&data);
+    Ok(())
+}
+
+/// Outcome of a `ClaimRewards` instruction
+///
+/// Claiming before the lockup period completes is not an error: the
+/// instruction succeeds without moving tokens and returns `LockupPending`
+/// so wallets can render a countdown instead of an empty success.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ClaimRewardsResult {
+    /// Rewards were transferred to the user
+    Claimed {
+        /// Amount of rewards committed by the protocol (deducted from owed rewards)
+        amount: u64,
+        /// Amount actually received by the user after transfer fees
+        received: u64,
+    },
+    /// Lockup period has not completed yet, nothing was transferred
+    LockupPending {
+        /// Unix timestamp at which the stake matures and rewards become claimable
+        matures_at: i64,
+        /// Rewards claimable at maturity
+        projected_rewards: u64,
+        /// Reward rate locked in by the stake (scaled by 1e9), which later pool
+        /// rate changes do not affect
+        reward_rate: u64,
+    },
+    /// Nothing to claim (no stake, or rewards already claimed)
+    NothingToClaim,
+}
+
+/// Rewards of a stake account as `GetPendingRewards` computes them
+///
+/// Produced with the same math `ClaimRewards` uses, so `claimable` is exactly
+/// what a claim at `current_time` would transfer.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PendingRewards {
+    /// Clock timestamp the values were computed at
+    pub current_time: i64,
+    /// Rewards a claim would pay now (before transfer fees)
+    pub claimable: u64,
+    /// Part of `claimable` paid to the stake's referrer
+    pub referral_share: u64,
+    /// Unix timestamp at which the current round's lockup completes
+    pub matures_at: i64,
+    /// Rewards claimable at `matures_at` (equals `claimable` once matured)
+    pub projected_rewards: u64,
+    /// Reward rate locked in by the stake (scaled by 1e9)
+    pub reward_rate: u64,
+}
+
+/// Health of a pool as `GetPoolStats` reports it
+///
+/// Coverage ratios are in basis points: 10_000 means the vault holds exactly
+/// what the pool owes from it. They are None when nothing is owed.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Stake recorded by the pool
+    pub total_staked: u64,
+    /// Live balance of the stake vault
+    pub stake_vault_balance: u64,
+    /// Rewards committed to stakers
+    pub total_rewards_owed: u64,
+    /// Live balance of the reward vault
+    pub reward_vault_balance: u64,
+    /// `stake_vault_balance / total_staked` in basis points
+    pub stake_coverage_bps: Option<u64>,
+    /// `reward_vault_balance / total_rewards_owed` in basis points
+    pub reward_coverage_bps: Option<u64>,
+    /// Whether both vaults cover what is owed from them (the check SyncPool applies)
+    pub solvent: bool,
+    /// Stake accounts still holding stake (None for pools created before the count was kept)
+    pub active_stake_count: Option<u64>,
+    /// Whether the pool is paused
+    pub is_paused: bool,
+}
+
+/// Creators allowed to initialize pools, returned by `GetAuthorizedCreators`
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AuthorizedCreators {
+    /// The main program authority (always authorized)
+    pub authority: Pubkey,
+    /// Additionally authorized creators, in slot order
+    pub creators: Vec<Pubkey>,
+    /// Authority nominated by a pending transfer, if any
+    pub pending_authority: Option<Pubkey>,
+}
+
+/// Answer of `CheckAuthorization`
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AuthorizationCheck {
+    /// The address checked
+    pub address: Pubkey,
+    /// Whether the address may create pools
+    pub authorized: bool,
+}
+
+/// An owner's stake indices in a pool, returned by `GetStakePosition`
+///
+/// Empty, with `next_index` 0, while the owner has no stake position directory.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct StakePositions {
+    /// One past the highest index recorded: the index to stake at next
+    pub next_index: u64,
+    /// Indices of the owner's open stake accounts, ascending
+    pub indices: Vec<u64>,
+}
+
+/// An owner's points in a pool, returned by `GetUserPoints`
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PointsBalance {
+    /// Clock timestamp the values were computed at
+    pub current_time: i64,
+    /// Points credited to the owner's `UserPoints` (0 before the first sync)
+    pub synced_points: u128,
+    /// Points the stake accounts passed have accrued since their last sync
+    pub pending_points: u128,
+}
+
+/// Outcome of `AuditPool`
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PoolAudit {
+    /// Stake accounts passed to the audit
+    pub audited_stakes: u64,
+    /// Sum of the unclaimed rewards the audited stakes hold reserved
+    pub recomputed_rewards_owed: u64,
+    /// The pool's `total_rewards_owed` before any repair
+    pub total_rewards_owed: u64,
+    /// Whether the audited stakes cover every active stake of the pool
+    pub complete: bool,
+    /// Whether `total_rewards_owed` was reset to the recomputed sum
+    pub repaired: bool,
+}
@@ -5,8 +5,34 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
-use crate::constants::REWARD_SCALE;
+use crate::constants::{
+    BPS_DENOMINATOR, DEVNET_REWARD_RATE_CHANGE_DELAY, KEEPER_BOUNTY_INTERVAL, MAX_DECIMALS_SHIFT,
+    MAX_LOCKUP_EXTENSION, MAX_RECOVERY_DELAY, MAX_REWARD_COHORT_DURATION,
+    MAX_REWARD_MULTIPLIER_BPS, MAX_REWARD_RATE, MAX_REWARD_RATE_CHANGE_DELAY, MAX_VESTING_DURATION,
+    MIN_LOCKUP_PERIOD, MIN_RECOVERY_DELAY, MIN_REWARD_COHORT_DURATION,
+    MIN_REWARD_RATE_CHANGE_DELAY, OUTFLOW_WINDOW, POINTS_SCALE, PROGRAM_VERSION,
+    REWARD_EXPIRY_PERIOD, REWARD_RATE_CHANGE_DELAY, REWARD_SCALE, SECONDS_PER_YEAR, SLASH_TIMELOCK,
+    WIND_DOWN_DELAY,
+};
 use crate::error::StakePoolError;
+use crate::return_data::{PendingRewards, PoolAudit, PoolStats};
+
+/// Length of the discriminator StakePool, StakeAccount and ProgramAuthority accounts start with
+pub const DISCRIMINATOR_LEN: usize = 8;
+
+/// Serialize `data` behind `discriminator` (empty for account types without one)
+fn serialize_account_data<T: BorshSerialize>(
+    discriminator: &[u8],
+    data: &T,
+    account_type: &str,
+) -> Result<Vec<u8>, ProgramError> {
+    let mut serialized = discriminator.to_vec();
+    data.serialize(&mut serialized).map_err(|error| {
+        msg!("{} serialization error: {}", account_type, error);
+        ProgramError::from(StakePoolError::SerializationError)
+    })?;
+    Ok(serialized)
+}
 
 /// Helper function to safely write serialized data to an account with size validation
 /// This prevents silent data truncation if new fields are added in future versions
@@ -14,12 +40,19 @@ fn save_account_data<T: BorshSerialize>(
     account: &AccountInfo,
     data: &T,
     account_type: &str,
+) -> ProgramResult {
+    save_prefixed_account_data(account, &[], data, account_type)
+}
+
+/// `save_account_data` for account types whose data starts with `discriminator`
+fn save_prefixed_account_data<T: BorshSerialize>(
+    account: &AccountInfo,
+    discriminator: &[u8],
+    data: &T,
+    account_type: &str,
 ) -> ProgramResult {
     // Serialize to a vec first to get the exact size
-    let serialized = borsh::to_vec(data).map_err(|error| {
-        msg!("{} serialization error: {}", account_type, error);
-        ProgramError::from(StakePoolError::SerializationError)
-    })?;
+    let serialized = serialize_account_data(discriminator, data, account_type)?;
 
     // Defensive check: Ensure account is large enough for serialized data
     let account_size = account.data_len();
@@ -52,6 +85,22 @@ fn validate_and_deserialize<T: BorshDeserialize>(
     account: &AccountInfo,
     account_type_name: &str,
 ) -> Result<T, ProgramError> {
+    validate_and_deserialize_padded(account, account_type_name, 0)
+}
+
+/// `validate_and_deserialize` for account types that grew in place: data shorter
+/// than `len` (written before the type last grew) reads as if zero-padded to `len`
+fn validate_and_deserialize_padded<T: BorshDeserialize>(
+    account: &AccountInfo,
+    account_type_name: &str,
+    len: usize,
+) -> Result<T, ProgramError> {
+    validate_account(account, account_type_name)?;
+    deserialize_padded(&account.data.borrow(), account_type_name, &[], len)
+}
+
+/// Check the account is owned by this program and holds data
+fn validate_account(account: &AccountInfo, account_type_name: &str) -> ProgramResult {
     // Validate account ownership
     if account.owner != &crate::ID {
         msg!(
@@ -68,16 +117,36 @@ fn validate_and_deserialize<T: BorshDeserialize>(
         return Err(ProgramError::UninitializedAccount);
     }
 
-    let data = account.data.borrow();
+    Ok(())
+}
 
+/// Deserialize account data that starts with `discriminator`; data shorter than
+/// `len` (discriminator included) reads as if zero-padded to `len`
+fn deserialize_padded<T: BorshDeserialize>(
+    data: &[u8],
+    account_type_name: &str,
+    discriminator: &[u8],
+    len: usize,
+) -> Result<T, ProgramError> {
     // Check minimum size (at least 1 byte for Key discriminator)
-    if data.is_empty() {
+    if data.len() <= discriminator.len() {
         msg!("{} account data too short", account_type_name);
         return Err(ProgramError::InvalidAccountData);
     }
 
+    if !data.starts_with(discriminator) {
+        msg!("Invalid {} discriminator", account_type_name);
+        return Err(StakePoolError::InvalidAccountDiscriminator.into());
+    }
+
     // Deserialize and validate Key discriminator
-    let mut bytes: &[u8] = &data;
+    let padded;
+    let mut bytes: &[u8] = if data.len() < len {
+        padded = [data, &vec![0; len - data.len()]].concat();
+        &padded[discriminator.len()..]
+    } else {
+        &data[discriminator.len()..]
+    };
     let deserialized = T::deserialize(&mut bytes).map_err(|error| {
         msg!("{} deserialization error: {}", account_type_name, error);
         StakePoolError::DeserializationError
@@ -90,12 +159,296 @@ fn validate_and_deserialize<T: BorshDeserialize>(
     Ok(deserialized)
 }
 
+/// Full reward for `amount_staked` at `reward_rate` (scaled by REWARD_SCALE)
+fn rewards_at_rate(amount_staked: u64, reward_rate: u64) -> Result<u64, ProgramError> {
+    // reward_rate is scaled by REWARD_SCALE (1e9, e.g., 100_000_000 = 10% of staked amount)
+    let rewards_u128 = (amount_staked as u128)
+        .checked_mul(reward_rate as u128)
+        .ok_or(StakePoolError::NumericalOverflow)?
+        .checked_div(REWARD_SCALE)
+        .ok_or(StakePoolError::NumericalOverflow)?;
+
+    // Safe cast from u128 to u64 with overflow check
+    u64::try_from(rewards_u128).map_err(|_| StakePoolError::NumericalOverflow.into())
+}
+
+/// `bps` basis points of `amount`, rounded down
+fn bps_of(amount: u64, bps: u16) -> Result<u64, ProgramError> {
+    let share = (amount as u128)
+        .checked_mul(bps as u128)
+        .ok_or(StakePoolError::NumericalOverflow)?
+        .checked_div(BPS_DENOMINATOR as u128)
+        .ok_or(StakePoolError::NumericalOverflow)?;
+
+    u64::try_from(share).map_err(|_| StakePoolError::NumericalOverflow.into())
+}
+
+/// Account key, stored as the first byte of every program account except
+/// StakePool, StakeAccount and ProgramAuthority, which store it right after
+/// their 8-byte discriminator (see `Key::discriminator`)
+///
+/// Layout changes of an account type get a new discriminator instead of reusing
+/// the old one, so accounts written by an earlier program version are recognized
+/// and can be upgraded with MigratePool rather than misread.
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug)]
 pub enum Key {
     Uninitialized,
-    StakePool,
-    StakeAccount,
-    ProgramAuthority,
+    /// StakePool in the original layout (see `StakePoolV1`)
+    StakePoolV1,
+    /// StakeAccount in the original layout (see `StakeAccountV1`)
+    StakeAccountV1,
+    /// ProgramAuthority with a fixed creator array (see `ProgramAuthorityV1`)
+    ProgramAuthorityV1,
+    MintRegistry,
+    StakePoolV2,
+    StakeAccountV2,
+    PoolRegistry,
+    PendingAction,
+    PoolAllowlist,
+    SlashProposal,
+    ProgramConfig,
+    ProgramAuthorityV2,
+    RewardStream,
+    StakePosition,
+    HookAllowlist,
+    UserPoints,
+    DenyList,
+    RewardCohort,
+}
+
+impl Key {
+    /// The discriminator of the previous layout of this account type, if any
+    pub fn legacy_version(&self) -> Option<Key> {
+        match self {
+            Key::StakePoolV2 => Some(Key::StakePoolV1),
+            Key::StakeAccountV2 => Some(Key::StakeAccountV1),
+            Key::ProgramAuthorityV2 => Some(Key::ProgramAuthorityV1),
+            _ => None,
+        }
+    }
+
+    /// The 8-byte discriminator accounts of this type start with, if any
+    ///
+    /// The first 8 bytes of `sha256("account:<Name>")`, as Anchor derives them.
+    /// None of them starts with a `Key` byte, so accounts written before their
+    /// type got a discriminator are told apart by their first byte.
+    pub fn discriminator(&self) -> Option<[u8; DISCRIMINATOR_LEN]> {
+        match self {
+            Key::StakePoolV2 => Some(StakePool::DISCRIMINATOR),
+            Key::StakeAccountV2 => Some(StakeAccount::DISCRIMINATOR),
+            Key::ProgramAuthorityV2 => Some(ProgramAuthority::DISCRIMINATOR),
+            _ => None,
+        }
+    }
+}
+
+/// Whether the data's first byte is `key`
+fn starts_with_key(data: &[u8], key: Key) -> bool {
+    data.first() == Some(&(key as u8))
+}
+
+/// Whether the account's first byte is `key`
+fn has_key(account: &AccountInfo, key: Key) -> bool {
+    account
+        .try_borrow_data()
+        .is_ok_and(|data| starts_with_key(&data, key))
+}
+
+/// Cluster a deployment serves, chosen when the ProgramAuthority is initialized
+///
+/// Lets a single audited binary serve both environments: dev-only instructions
+/// and shortened timelocks are enabled at runtime for Devnet only. Mainnet is
+/// the first variant so zeroed data never decodes as the permissive profile.
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub enum ClusterProfile {
+    Mainnet,
+    Devnet,
+}
+
+impl ClusterProfile {
+    /// Whether dev-only instructions (e.g. CloseProgramAuthority) are enabled
+    pub fn allows_dev_instructions(&self) -> bool {
+        matches!(self, ClusterProfile::Devnet)
+    }
+
+    /// Delay before a proposed reward rate change can be finalized,
+    /// also enforced as the cooldown between rate changes
+    pub fn reward_rate_change_delay(&self) -> i64 {
+        match self {
+            ClusterProfile::Mainnet => REWARD_RATE_CHANGE_DELAY,
+            ClusterProfile::Devnet => DEVNET_REWARD_RATE_CHANGE_DELAY,
+        }
+    }
+
+    /// Shortest reward rate change delay a pool may be created with
+    pub fn min_reward_rate_change_delay(&self) -> i64 {
+        match self {
+            ClusterProfile::Mainnet => MIN_REWARD_RATE_CHANGE_DELAY,
+            ClusterProfile::Devnet => DEVNET_REWARD_RATE_CHANGE_DELAY,
+        }
+    }
+
+    /// Fail unless `delay` is a reward rate change delay pools may be created with
+    pub fn validate_reward_rate_change_delay(&self, delay: i64) -> ProgramResult {
+        let min = self.min_reward_rate_change_delay();
+        if !(min..=MAX_REWARD_RATE_CHANGE_DELAY).contains(&delay) {
+            msg!(
+                "Reward rate change delay must be between {} and {} seconds, got {}",
+                min,
+                MAX_REWARD_RATE_CHANGE_DELAY,
+                delay
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        Ok(())
+    }
+}
+
+/// How a pool's `reward_rate` is expressed, chosen at initialization
+///
+/// Per-lockup is the first variant so pools created before annualized rates
+/// load as the model they were created with.
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub enum RewardRateMode {
+    /// Every round pays `reward_rate` once its lockup completes, whatever the lockup
+    #[default]
+    PerLockup,
+    /// `reward_rate` is an annual rate: a round pays it pro rata to its lockup
+    /// (`rate * lockup_period / 365 days`), so pools with different lockups compare
+    Annualized,
+}
+
+/// What WindDownStake does with the unclaimed rewards of the stakes it returns
+///
+/// Expire is the first variant so pools created before wind-downs keep their
+/// rewards for governance, like ExpireRewards.
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub enum WindDownRewards {
+    /// Earned rewards move to the pool's `expired_rewards`, claimable by governance
+    #[default]
+    Expire,
+    /// Earned rewards are paid to the owner's associated reward token account
+    Pay,
+}
+
+/// Outcome of returning a stake with WindDownStake
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WindDown {
+    /// Principal returned to the owner (staked and pending withdrawal)
+    pub principal: u64,
+    /// Earned rewards owed to the owner (`WindDownRewards::Pay`)
+    pub rewards_paid: u64,
+    /// Earned rewards moved to `expired_rewards` (`WindDownRewards::Expire`)
+    pub rewards_expired: u64,
+}
+
+/// Reward boost for stakes of at least `min_amount` tokens
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct RewardTier {
+    /// Smallest stake (after the deposit fee) the tier applies to
+    pub min_amount: u64,
+    /// Multiplier applied to the pool's reward rate (10_000 bps = 1x)
+    pub multiplier_bps: u16,
+}
+
+/// Recurring window in which a pool's rewards may be claimed
+///
+/// Time is divided into periods of `period` seconds counted from `start`, and
+/// claims are open during the first `duration` seconds of each period (e.g. the
+/// first day of every 30 days).
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct ClaimWindow {
+    /// Opening of one window; the others follow every `period` seconds
+    pub start: i64,
+    /// Seconds between window openings
+    pub period: i64,
+    /// Seconds each window stays open
+    pub duration: i64,
+}
+
+impl ClaimWindow {
+    /// Check that windows recur and stay open for part of each period
+    pub fn validate(&self) -> ProgramResult {
+        if self.period <= 0 || self.duration <= 0 || self.duration > self.period {
+            msg!(
+                "Invalid claim window: period {}, duration {}. Both must be positive and the duration at most the period.",
+                self.period,
+                self.duration
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        Ok(())
+    }
+
+    /// Opening of the next window after `current_time`, or None while a window is open
+    pub fn next_opening(&self, current_time: i64) -> Result<Option<i64>, ProgramError> {
+        let offset = current_time
+            .checked_sub(self.start)
+            .ok_or(StakePoolError::NumericalOverflow)?
+            .rem_euclid(self.period);
+        if offset < self.duration {
+            return Ok(None);
+        }
+
+        let next = current_time
+            .checked_add(self.period - offset)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        Ok(Some(next))
+    }
+}
+
+/// Most a pool may pay out in any outflow window (see `OUTFLOW_WINDOW`)
+///
+/// Caps how fast the vaults can be drained, e.g. by an exploit or a bank run:
+/// past a cap, unstakes or claims fail with `RateLimited` until the window resets.
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct OutflowLimit {
+    /// Most of the pool's stake at the window's opening that can be unstaked in it,
+    /// in basis points (0: unstakes are not limited)
+    pub max_unstake_bps: u16,
+    /// Most reward tokens that can be claimed in a window (0: claims are not limited)
+    pub max_claim_amount: u64,
+}
+
+impl OutflowLimit {
+    /// Check that the limit caps something and the unstake cap is at most 100%
+    pub fn validate(&self) -> ProgramResult {
+        if self.max_unstake_bps as u64 > BPS_DENOMINATOR
+            || (self.max_unstake_bps == 0 && self.max_claim_amount == 0)
+        {
+            msg!(
+                "Invalid outflow limit: max unstake {} bps, max claim {}. At least one must be set and the unstake cap at most {} bps.",
+                self.max_unstake_bps,
+                self.max_claim_amount,
+                BPS_DENOMINATOR
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        Ok(())
+    }
+}
+
+/// Outflows of a pool's current outflow window, counted while an `OutflowLimit` is set
+///
+/// A window opens with the first outflow after the previous one elapsed and lasts
+/// `OUTFLOW_WINDOW` seconds.
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct OutflowWindow {
+    /// When the window opened
+    pub start: i64,
+    /// The pool's total stake when the window opened, the base of the unstake cap
+    pub total_staked: u64,
+    /// Stake tokens unstaked in the window
+    pub unstaked: u64,
+    /// Reward tokens claimed in the window
+    pub claimed: u64,
+}
+
+impl OutflowWindow {
+    /// When the window resets
+    pub fn end(&self) -> i64 {
+        self.start.saturating_add(OUTFLOW_WINDOW)
+    }
 }
 
 /// The main stake pool configuration
@@ -168,29 +521,154 @@ pub struct StakePool {
     /// The first 1-2 bytes of the old reserved space will be misinterpreted as Option
     /// discriminators for the new fields, causing data corruption.
     ///
-    /// MIGRATION REQUIRED:
-    /// If you have existing pools deployed, you MUST:
-    /// 1. Drain all stakes and rewards from existing pools
-    /// 2. Close existing pool accounts
-    /// 3. Redeploy the new program version
-    /// 4. Recreate pools with new structure
-    ///
-    /// This is a fresh devnet deployment with no production pools, so the breaking
-    /// change is acceptable. DO NOT deploy this to a cluster with existing pools
-    /// without proper migration.
+    /// No pools were deployed with that pre-L-01 structure. Later layout changes
+    /// are versioned through the account discriminator instead: pools written in
+    /// the first deployed layout carry `Key::StakePoolV1` and are upgraded in
+    /// place by MigratePool (see `StakePoolV1`).
     pub pending_reward_rate: Option<u64>,
     /// Timestamp when pending reward rate change was proposed
-    /// Used to enforce REWARD_RATE_CHANGE_DELAY before finalizing
+    /// Used to enforce the pool's reward_rate_change_delay before finalizing
     /// Must always be in sync with pending_reward_rate (both Some or both None)
     pub reward_rate_change_timestamp: Option<i64>,
     /// Timestamp of the last successful reward rate change (finalization)
     /// Used to enforce cooldown period between rate changes to prevent authority
-    /// from bypassing the 7-day time-lock by immediately proposing another change
+    /// from bypassing the time-lock by immediately proposing another change
     pub last_rate_change: Option<i64>,
-    /// Reserved space for future use. Not currently used.
-    /// This field allows for future upgrades without breaking compatibility.
-    /// REDUCED from 32 bytes to 7 bytes to accommodate new cooldown field.
-    pub _reserved: [u8; 7],
+    /// Share of each claim (in basis points) paid to the stake's referrer, if any.
+    /// The referral share is carved out of the claimed amount, so it never
+    /// increases total_rewards_owed.
+    pub referral_bps: u16,
+    /// Share of a position (basis points) that may be unstaked before lockup completion,
+    /// even when enforce_lockup is set. Early withdrawals only forfeit the withdrawn
+    /// part's share of the reward; the rest of the position keeps its full reward.
+    pub early_unstake_bps: u16,
+    /// Bump of the stake vault PDA (["stake_vault", pool]) when the stake vault is a
+    /// program-derived token account. None for vaults created as regular keypair accounts.
+    /// When set, the vault address is re-derived and validated on every instruction
+    /// in addition to the stored pubkey comparison.
+    pub stake_vault_bump: Option<u8>,
+    /// Bump of the reward vault PDA (["reward_vault", pool]), see stake_vault_bump
+    pub reward_vault_bump: Option<u8>,
+    /// The pool's fee vault (stake mint) receiving deposit and withdrawal fees
+    pub fee_vault: Pubkey,
+    /// Fee (basis points) taken from each stake deposit
+    pub deposit_fee_bps: u16,
+    /// Fee (basis points) taken from each unstake
+    pub withdraw_fee_bps: u16,
+    /// Bump of the fee vault PDA (["fee_vault", pool]), see stake_vault_bump
+    pub fee_vault_bump: Option<u8>,
+    /// Reward tokens deposited through FundRewards (amounts received after transfer
+    /// fees), net of surplus withdrawn with DefundRewards and expired rewards
+    /// withdrawn with ClaimExpiredRewards
+    pub total_rewards_funded: u64,
+    /// Addresses allowed to call FundRewards. All None means anyone may fund.
+    pub reward_funders: [Option<Pubkey>; 4],
+    /// Token-2022 receipt mint (["receipt_mint", pool]) when positions are tokenized:
+    /// stakes mint receipt tokens 1:1 with the amount staked and unstakes burn them
+    pub receipt_mint: Option<Pubkey>,
+    /// Rewards of abandoned matured stakes moved out of total_rewards_owed by
+    /// ExpireRewards. They stay in the reward vault until governance withdraws
+    /// them with ClaimExpiredRewards and are not part of the DefundRewards surplus.
+    pub expired_rewards: u64,
+    /// Seconds between RequestUnstake and WithdrawUnstaked. While non-zero, Unstake
+    /// and UnstakeSol are disabled and withdrawals go through the two-step flow.
+    pub unstake_cooldown: i64,
+    /// Whether the pool has been appended to the pool registry (see `PoolRegistry`)
+    pub is_registered: bool,
+    /// Seconds a proposed reward rate change waits before it can be finalized, also
+    /// the cooldown between rate changes. Set at initialization; 0 (pools created
+    /// before the delay was configurable) uses the deployment's default delay.
+    pub reward_rate_change_delay: u32,
+    /// Proposed lockup_period, applied by FinalizeLockupChange after the pool's delay
+    pub pending_lockup_period: Option<i64>,
+    /// Proposed enforce_lockup, applied by FinalizeLockupChange after the pool's delay
+    pub pending_enforce_lockup: Option<bool>,
+    /// When the pending lockup change was proposed (Some while a change is pending)
+    pub lockup_change_timestamp: Option<i64>,
+    /// Lowest reward rate the pool committed to at initialization (None: no floor).
+    /// UpdatePool cannot propose a rate below it.
+    pub min_reward_rate_bound: Option<u64>,
+    /// Highest reward rate the pool committed to at initialization (None: MAX_REWARD_RATE).
+    /// UpdatePool cannot propose a rate above it.
+    pub max_reward_rate_bound: Option<u64>,
+    /// Stake accounts of the pool still holding stake: counted when Stake creates one and
+    /// no longer once unstaking empties it. None for pools created before the count was kept.
+    pub active_stake_count: Option<u64>,
+    /// Stake size tiers set at initialization, by ascending `min_amount`. A new round
+    /// locks in the reward rate boosted by the highest tier its stake reaches.
+    pub reward_tiers: [Option<RewardTier>; 4],
+    /// End of the early-bird bonus window (None: no bonus). Stakes created before it
+    /// lock in a reward rate further boosted by `bonus_multiplier_bps`.
+    pub bonus_end_ts: Option<i64>,
+    /// Reward rate multiplier of stakes created in the bonus window (10_000 bps = 1x)
+    pub bonus_multiplier_bps: u16,
+    /// Extra reward rate (scaled by REWARD_SCALE, per year of extension) a stake locks in
+    /// when ExtendLockup lengthens its lockup round. 0 disables ExtendLockup.
+    pub lockup_extension_rate: u64,
+    /// Whether only owners listed in the pool's allowlist pages may stake (see `PoolAllowlist`)
+    pub allowlist_required: bool,
+    /// Root of a merkle tree of allowed stake owners (see `crate::merkle`)
+    /// While set, only owners proving membership or listed in an allowlist page may stake.
+    pub allowlist_merkle_root: Option<[u8; 32]>,
+    /// Recurring window outside of which rewards cannot be claimed (None: claims are always open)
+    pub claim_window: Option<ClaimWindow>,
+    /// Caps on the unstakes and claims of any outflow window (None: outflows are not limited)
+    pub outflow_limit: Option<OutflowLimit>,
+    /// Outflows of the current window, checked against `outflow_limit`
+    pub outflow_window: OutflowWindow,
+    /// Manager of this pool only, e.g. a partner project (None: global admins only).
+    /// May pause and unpause the pool and fund its rewards, but not change its rates.
+    pub pool_manager: Option<Pubkey>,
+    /// Lamports paid to whoever runs a finalize, sync or stream crank (0: no bounty).
+    /// Paid out of the pool account's lamports above its rent-exempt minimum.
+    pub keeper_bounty: u64,
+    /// Unix timestamp of the last keeper bounty payout (see KEEPER_BOUNTY_INTERVAL)
+    pub last_keeper_bounty_at: i64,
+    /// Cap on `total_rewards_committed` (None: uncapped). Commitments past it fail, and
+    /// once no minimum stake fits under it the pool rejects new stakes as if it had ended.
+    pub max_total_rewards: Option<u64>,
+    /// Rewards reserved by stakes, relocks and lockup extensions over the pool's life
+    /// (claims do not reduce it). Pools created before the cap count from the upgrade on.
+    pub total_rewards_committed: u64,
+    /// Whether `reward_rate` is paid per lockup round or annualized (see `RewardRateMode`).
+    /// Fixed at initialization; new rounds lock in the rate it yields for the pool's lockup.
+    pub reward_rate_mode: RewardRateMode,
+    /// Reward mint decimals minus stake mint decimals, read from the mints at initialization.
+    /// New rounds lock in rates scaled by 10^shift, so a rate means the same share of a
+    /// whole stake token in whole reward tokens whatever the mints' decimals. 0 for pools
+    /// created before, which keep paying in base units.
+    pub reward_decimals_shift: i8,
+    /// Whether a partial unstake must leave at least `min_stake_amount` staked, so
+    /// positions are either withdrawn in full or stay above the minimum (no dust)
+    pub enforce_min_remaining_stake: bool,
+    /// What WindDownStake does with the unclaimed rewards of the stakes it returns
+    pub wind_down_rewards: WindDownRewards,
+    /// Program called after every Stake, Unstake and ClaimRewards of the pool (see
+    /// `crate::hook`). Only programs on the global `HookAllowlist` can be set.
+    pub hook_program: Option<Pubkey>,
+    /// Points accrued per staked base unit per second, scaled by POINTS_SCALE (0: no points).
+    /// SyncPoints credits a stake's points to its owner's `UserPoints`.
+    pub points_rate: u64,
+    /// Whether Stake, Unstake, WithdrawUnstaked and ClaimRewards check the global
+    /// `DenyList`; the entry points that cannot pass it reject the pool
+    pub deny_list_required: bool,
+    /// Whether Stake accepts a funder paying the deposit for an owner who does not sign,
+    /// e.g. employers staking for employees
+    pub allow_third_party_stake: bool,
+    /// Sequence number of the pool's last Stake, Unstake, Claim, ClaimBatch or
+    /// PoolUpdated event (see `next_event_nonce`). 0 before the first one.
+    pub event_nonce: u64,
+    /// Length in seconds of the pool's reward cohorts (0: every stake draws on the
+    /// reward vault). Stakes then draw their rewards from the vault of the cohort
+    /// they were opened in (see `RewardCohort`). Only changes while the pool is empty.
+    pub cohort_duration: i64,
+    /// Reward cohorts created by FundCohortRewards and not yet closed by
+    /// DefundCohortRewards. ClosePool needs none left.
+    pub open_reward_cohorts: u32,
+    /// Unused padding (2 bytes, always zero) closing the pool layout.
+    /// New fields are added before it and grow `LEN`; MigratePool and ResizePool
+    /// grow accounts allocated at an older size.
+    pub _reserved: [u8; 2],
 }
 
 /// Individual user stake account (one per deposit)
@@ -212,10 +690,90 @@ pub struct StakeAccount {
     pub claimed_rewards: u64,
     /// Bump seed for PDA derivation
     pub bump: u8,
+    /// Optional referrer who receives the pool's referral_bps share of each claim
+    pub referrer: Option<Pubkey>,
+    /// Principal withdrawn before lockup completion, counted against the pool's
+    /// early_unstake_bps allowance
+    pub early_unstaked: u64,
+    /// Whether the permissionless Relock crank may restart the lockup once it expires
+    pub auto_relock: bool,
+    /// Unclaimed rewards of completed lockup rounds, carried into the current round by Relock
+    pub carried_rewards: u64,
+    /// Optional operator allowed to claim rewards on the owner's behalf (to the owner only)
+    pub delegate: Option<Pubkey>,
+    /// Principal moved out of the position by RequestUnstake, released by WithdrawUnstaked.
+    /// It no longer earns rewards but still counts toward the pool's total_staked.
+    pub pending_withdraw_amount: u64,
+    /// Time of the latest RequestUnstake while a withdrawal is pending
+    pub request_timestamp: Option<i64>,
+    /// Pool reward_rate, boosted by the stake's reward tier, when the current round
+    /// started (stake or relock). Rewards are
+    /// reserved and paid at this rate, so later rate changes only affect new rounds.
+    pub locked_reward_rate: u64,
+    /// Free-form label chosen by the owner (e.g. UTF-8 "treasury-Q3", zero-padded).
+    /// All zeros means no label.
+    pub label: [u8; 32],
+    /// Wallet nominated by the owner to take over the position with AcceptStakeOwnership
+    pub pending_owner: Option<Pubkey>,
+    /// Seconds ExtendLockup added to the pool's lockup period for the current round
+    pub lockup_extension: i64,
+    /// Principal granted by VestingStake that vests over the schedule below.
+    /// 0 for regular stakes, which have no vesting.
+    pub vesting_amount: u64,
+    /// Time the vesting schedule started
+    pub vesting_start: i64,
+    /// Seconds after vesting_start before any principal vests
+    pub vesting_cliff: i64,
+    /// Seconds after vesting_start until all of vesting_amount has vested, linearly
+    pub vesting_duration: i64,
+    /// Share of the stake proposed for slashing by ProposeSlash, in basis points.
+    /// While non-zero the principal cannot leave the position or change owner.
+    pub pending_slash_bps: u16,
+    /// Time up to which the stake's points were credited to its owner (see
+    /// `StakePool::accrue_points`). 0 for stakes created before points.
+    pub points_synced_at: i64,
+    /// Cohort whose vault pays the stake's rewards (see `RewardCohort`).
+    /// 0 in pools without reward cohorts.
+    pub reward_cohort: u64,
+}
+
+/// One of the token vaults a pool records at initialization
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoolVault {
+    Stake,
+    Reward,
+    Fee,
+}
+
+impl PoolVault {
+    pub const ALL: [PoolVault; 3] = [PoolVault::Stake, PoolVault::Reward, PoolVault::Fee];
+
+    /// Account name of the vault in instruction account lists
+    pub fn name(self) -> &'static str {
+        match self {
+            PoolVault::Stake => "stake_vault",
+            PoolVault::Reward => "reward_vault",
+            PoolVault::Fee => "fee_vault",
+        }
+    }
+
+    /// Seed prefix of the vault PDA
+    pub fn seed(self) -> &'static [u8] {
+        match self {
+            PoolVault::Stake => StakePool::STAKE_VAULT_SEED,
+            PoolVault::Reward => StakePool::REWARD_VAULT_SEED,
+            PoolVault::Fee => StakePool::FEE_VAULT_SEED,
+        }
+    }
 }
 
 impl StakePool {
+    /// First 8 bytes of `sha256("account:StakePool")`
+    pub const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+        [0x79, 0x22, 0xce, 0x15, 0x4f, 0x7f, 0xff, 0x1c];
+
     // Size calculation:
+    // - discriminator: 8 bytes
     // - key (Key enum): 1 byte
     // - stake_mint (Pubkey): 32 bytes
     // - reward_mint (Pubkey): 32 bytes
@@ -234,23 +792,276 @@ impl StakePool {
     // - pending_reward_rate (Option<u64>): 1 byte when None, 9 bytes when Some
     // - reward_rate_change_timestamp (Option<i64>): 1 byte when None, 9 bytes when Some
     // - last_rate_change (Option<i64>): 1 byte when None, 9 bytes when Some
-    // - _reserved: 7 bytes
+    // - referral_bps (u16): 2 bytes
+    // - early_unstake_bps (u16): 2 bytes
+    // - stake_vault_bump (Option<u8>): 1 byte when None, 2 bytes when Some
+    // - reward_vault_bump (Option<u8>): 1 byte when None, 2 bytes when Some
+    // - fee_vault (Pubkey): 32 bytes
+    // - deposit_fee_bps (u16): 2 bytes
+    // - withdraw_fee_bps (u16): 2 bytes
+    // - fee_vault_bump (Option<u8>): 1 byte when None, 2 bytes when Some
+    // - total_rewards_funded (u64): 8 bytes
+    // - reward_funders (4 x Option<Pubkey>): 4 * 33 = 132 bytes
+    // - receipt_mint (Option<Pubkey>): 1 byte when None, 33 bytes when Some
+    // - expired_rewards (u64): 8 bytes
+    // - unstake_cooldown (i64): 8 bytes
+    // - is_registered (bool): 1 byte
+    // - reward_rate_change_delay (u32): 4 bytes
+    // - pending_lockup_period (Option<i64>): 1 byte when None, 9 bytes when Some
+    // - pending_enforce_lockup (Option<bool>): 1 byte when None, 2 bytes when Some
+    // - lockup_change_timestamp (Option<i64>): 1 byte when None, 9 bytes when Some
+    // - min_reward_rate_bound (Option<u64>): 1 byte when None, 9 bytes when Some
+    // - max_reward_rate_bound (Option<u64>): 1 byte when None, 9 bytes when Some
+    // - active_stake_count (Option<u64>): 1 byte when None, 9 bytes when Some
+    // - reward_tiers (4 x Option<RewardTier>): 4 * 11 = 44 bytes
+    // - bonus_end_ts (Option<i64>): 1 byte when None, 9 bytes when Some
+    // - bonus_multiplier_bps (u16): 2 bytes
+    // - lockup_extension_rate (u64): 8 bytes
+    // - allowlist_required (bool): 1 byte
+    // - allowlist_merkle_root (Option<[u8; 32]>): 1 byte when None, 33 bytes when Some
+    // - claim_window (Option<ClaimWindow>): 1 byte when None, 25 bytes when Some
+    // - outflow_limit (Option<OutflowLimit>): 1 byte when None, 11 bytes when Some
+    // - outflow_window (OutflowWindow): 32 bytes
+    // - pool_manager (Option<Pubkey>): 1 byte when None, 33 bytes when Some
+    // - keeper_bounty (u64): 8 bytes
+    // - last_keeper_bounty_at (i64): 8 bytes
+    // - max_total_rewards (Option<u64>): 1 byte when None, 9 bytes when Some
+    // - total_rewards_committed (u64): 8 bytes
+    // - reward_rate_mode (RewardRateMode enum): 1 byte
+    // - reward_decimals_shift (i8): 1 byte
+    // - enforce_min_remaining_stake (bool): 1 byte
+    // - wind_down_rewards (WindDownRewards enum): 1 byte
+    // - hook_program (Option<Pubkey>): 1 byte when None, 33 bytes when Some
+    // - points_rate (u64): 8 bytes
+    // - deny_list_required (bool): 1 byte
+    // - allow_third_party_stake (bool): 1 byte
+    // - event_nonce (u64): 8 bytes
+    // - cohort_duration (i64): 8 bytes
+    // - open_reward_cohorts (u32): 4 bytes
+    // - _reserved: 2 bytes
     //
     // We allocate for the maximum size (all Options as Some) to support future updates
     // Calculation breakdown:
     // Fixed: 1 (key) + 32 (stake_mint) + 32 (reward_mint) + 8 (pool_id) + 32 (stake_vault) + 32 (reward_vault)
     //        + 8 (total_staked) + 8 (total_rewards_owed) + 8 (reward_rate) + 8 (min_stake_amount)
-    //        + 8 (lockup_period) + 1 (is_paused) + 1 (enforce_lockup) + 1 (bump) = 180 bytes
-    // Options (all Some): 9 (pool_end_date) + 9 (pending_reward_rate) + 9 (reward_rate_change_timestamp) + 9 (last_rate_change) = 36 bytes
-    // Reserved: 7 bytes
-    // Total: 180 + 36 + 7 = 223 bytes
+    //        + 8 (lockup_period) + 1 (is_paused) + 1 (enforce_lockup) + 1 (bump) + 2 (referral_bps) + 2 (early_unstake_bps)
+    //        + 32 (fee_vault) + 2 (deposit_fee_bps) + 2 (withdraw_fee_bps) + 8 (total_rewards_funded)
+    //        + 8 (expired_rewards) + 8 (unstake_cooldown) + 1 (is_registered)
+    //        + 4 (reward_rate_change_delay) + 2 (bonus_multiplier_bps)
+    //        + 8 (lockup_extension_rate) + 1 (allowlist_required) + 32 (outflow_window)
+    //        + 8 (keeper_bounty) + 8 (last_keeper_bounty_at) + 8 (total_rewards_committed)
+    //        + 1 (reward_rate_mode) + 1 (reward_decimals_shift)
+    //        + 1 (enforce_min_remaining_stake) + 1 (wind_down_rewards)
+    //        + 8 (points_rate) + 1 (deny_list_required)
+    //        + 1 (allow_third_party_stake) + 8 (event_nonce) + 8 (cohort_duration)
+    //        + 4 (open_reward_cohorts) = 350 bytes
+    // Options (all Some): 9 (pool_end_date) + 9 (pending_reward_rate) + 9 (reward_rate_change_timestamp) + 9 (last_rate_change)
+    //        + 2 (stake_vault_bump) + 2 (reward_vault_bump) + 2 (fee_vault_bump) + 132 (reward_funders)
+    //        + 33 (receipt_mint) + 9 (pending_lockup_period) + 2 (pending_enforce_lockup)
+    //        + 9 (lockup_change_timestamp) + 9 (min_reward_rate_bound)
+    //        + 9 (max_reward_rate_bound) + 9 (active_stake_count) + 44 (reward_tiers)
+    //        + 9 (bonus_end_ts) + 33 (allowlist_merkle_root) + 25 (claim_window)
+    //        + 11 (outflow_limit) + 33 (pool_manager) + 9 (max_total_rewards)
+    //        + 33 (hook_program) = 451 bytes
+    // Reserved: 2 bytes
+    // Total: 8 (discriminator) + 350 + 451 + 2 = 811 bytes
+    //
+    // Pools created before the lockup time-lock were allocated 458 bytes, before
+    // the reward rate bounds 478 bytes, before the stake account count 496 bytes,
+    // before the reward tiers 505 bytes, before the bonus window 549 bytes, before
+    // the lockup extension rate 560 bytes, before the allowlist 568 bytes, before
+    // the merkle allowlist 569 bytes, before the claim window 602 bytes, before
+    // the outflow limit 627 bytes, before the pool manager 670 bytes, before the
+    // keeper bounty 703 bytes and before the discriminator 719 bytes (all of them
+    // without the discriminator, starting with the key), before the rewards cap
+    // 727 bytes, before the reward rate mode 744 bytes, before the decimals
+    // shift 745 bytes, before the minimum remaining stake 746 bytes, before the
+    // wind-down rewards policy 747 bytes, before the hook program 748 bytes,
+    // before the points rate 781 bytes, before the deny list 789 bytes, before
+    // third-party stakes 790 bytes, before the event nonce 791 bytes and before
+    // reward cohorts 799 bytes.
+    // Their missing fields load as None and they are rewritten at LEN by MigratePool.
     pub const LEN: usize = {
-        const FIXED_FIELDS: usize = 1 + 32 + 32 + 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 1;
-        const OPTIONS_MAX: usize = 9 + 9 + 9 + 9; // All Option<T> fields when Some
-        const RESERVED: usize = 7;
-        FIXED_FIELDS + OPTIONS_MAX + RESERVED
+        const FIXED_FIELDS: usize = 1
+            + 32
+            + 32
+            + 8
+            + 32
+            + 32
+            + 8
+            + 8
+            + 8
+            + 8
+            + 8
+            + 1
+            + 1
+            + 1
+            + 2
+            + 2
+            + 32
+            + 2
+            + 2
+            + 8
+            + 8
+            + 8
+            + 1
+            + 4
+            + 2
+            + 8
+            + 1
+            + 32
+            + 8
+            + 8
+            + 8
+            + 1
+            + 1
+            + 1
+            + 1
+            + 8
+            + 1
+            + 1
+            + 8
+            + 8
+            + 4;
+        const OPTIONS_MAX: usize = 9
+            + 9
+            + 9
+            + 9
+            + 2
+            + 2
+            + 2
+            + StakePool::MAX_REWARD_FUNDERS * 33
+            + 33
+            + 9
+            + 2
+            + 9
+            + 9
+            + 9
+            + 9
+            + StakePool::MAX_REWARD_TIERS * 11
+            + 9
+            + 33
+            + 25
+            + 11
+            + 33
+            + 9
+            + 33; // All Option<T> fields when Some
+        const RESERVED: usize = 2;
+        DISCRIMINATOR_LEN + FIXED_FIELDS + OPTIONS_MAX + RESERVED
     };
 
+    /// The pool's reward rate change delay, falling back to `profile`'s default
+    pub fn rate_change_delay(&self, profile: ClusterProfile) -> i64 {
+        match self.reward_rate_change_delay {
+            0 => profile.reward_rate_change_delay(),
+            delay => delay as i64,
+        }
+    }
+
+    /// Whether `rate` lies within the reward rate bounds `min_bound` / `max_bound`
+    pub fn reward_rate_within_bounds(
+        rate: u64,
+        min_bound: Option<u64>,
+        max_bound: Option<u64>,
+    ) -> bool {
+        min_bound.is_none_or(|min| rate >= min) && max_bound.is_none_or(|max| rate <= max)
+    }
+
+    /// Fail unless `rate` lies within the pool's committed reward rate bounds
+    pub fn validate_reward_rate_bounds(&self, rate: u64) -> ProgramResult {
+        if !Self::reward_rate_within_bounds(
+            rate,
+            self.min_reward_rate_bound,
+            self.max_reward_rate_bound,
+        ) {
+            msg!(
+                "Reward rate {} outside the pool's bounds (min: {:?}, max: {:?})",
+                rate,
+                self.min_reward_rate_bound,
+                self.max_reward_rate_bound
+            );
+            return Err(StakePoolError::RewardRateOutOfBounds.into());
+        }
+        Ok(())
+    }
+
+    /// Whether a lockup_period / enforce_lockup change is waiting to be finalized
+    pub fn has_pending_lockup_change(&self) -> bool {
+        self.lockup_change_timestamp.is_some()
+    }
+
+    /// Propose new lockup settings, applied by `finalize_lockup_change` after the delay
+    ///
+    /// Values equal to the current ones are not part of the proposal. When nothing
+    /// differs, any pending change is cancelled instead and `false` is returned.
+    pub fn propose_lockup_change(
+        &mut self,
+        lockup_period: Option<i64>,
+        enforce_lockup: Option<bool>,
+        current_time: i64,
+    ) -> Result<bool, ProgramError> {
+        if let Some(lockup) = lockup_period {
+            if lockup < 0 {
+                msg!("Lockup period cannot be negative: {}", lockup);
+                return Err(StakePoolError::InvalidParameters.into());
+            }
+        }
+
+        let lockup_period = lockup_period.filter(|lockup| *lockup != self.lockup_period);
+        let enforce_lockup = enforce_lockup.filter(|enforce| *enforce != self.enforce_lockup);
+        if lockup_period.is_none() && enforce_lockup.is_none() {
+            self.pending_lockup_period = None;
+            self.pending_enforce_lockup = None;
+            self.lockup_change_timestamp = None;
+            return Ok(false);
+        }
+
+        // A pending change must be finalized (or cancelled) first, so proposals
+        // cannot keep pushing the notice period back
+        if self.has_pending_lockup_change() {
+            msg!("Cannot propose a lockup change while one is already pending");
+            return Err(StakePoolError::PendingLockupChangeExists.into());
+        }
+
+        self.pending_lockup_period = lockup_period;
+        self.pending_enforce_lockup = enforce_lockup;
+        self.lockup_change_timestamp = Some(current_time);
+        Ok(true)
+    }
+
+    /// Apply the pending lockup change once `delay` seconds have passed since it was proposed
+    pub fn finalize_lockup_change(&mut self, current_time: i64, delay: i64) -> ProgramResult {
+        let change_timestamp = self
+            .lockup_change_timestamp
+            .ok_or(StakePoolError::NoPendingLockupChange)?;
+
+        // A pending change always carries at least one of the two settings
+        if self.pending_lockup_period.is_none() && self.pending_enforce_lockup.is_none() {
+            msg!("Data corruption: lockup change pending without new settings");
+            return Err(StakePoolError::DataCorruption.into());
+        }
+
+        let time_elapsed = current_time
+            .checked_sub(change_timestamp)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        if time_elapsed < delay {
+            msg!(
+                "Lockup change delay not elapsed. Time remaining: {} seconds",
+                delay.checked_sub(time_elapsed).unwrap_or(0)
+            );
+            return Err(StakePoolError::LockupChangeDelayNotElapsed.into());
+        }
+
+        if let Some(lockup) = self.pending_lockup_period.take() {
+            self.lockup_period = lockup;
+        }
+        if let Some(enforce) = self.pending_enforce_lockup.take() {
+            self.enforce_lockup = enforce;
+        }
+        self.lockup_change_timestamp = None;
+        Ok(())
+    }
+
     pub fn seeds(stake_mint: &Pubkey, pool_id: u64) -> Vec<Vec<u8>> {
         vec![
             b"stake_pool".to_vec(),
@@ -265,11 +1076,85 @@ impl StakePool {
         Pubkey::find_program_address(&seeds, &crate::ID)
     }
 
+    /// Maximum number of addresses in the reward funder allowlist
+    pub const MAX_REWARD_FUNDERS: usize = 4;
+
+    /// Maximum number of stake size reward tiers
+    pub const MAX_REWARD_TIERS: usize = 4;
+
+    /// Seed prefix of the stake vault PDA: ["stake_vault", pool]
+    pub const STAKE_VAULT_SEED: &'static [u8] = b"stake_vault";
+
+    /// Seed prefix of the reward vault PDA: ["reward_vault", pool]
+    pub const REWARD_VAULT_SEED: &'static [u8] = b"reward_vault";
+
+    /// Seed prefix of the fee vault PDA: ["fee_vault", pool]
+    pub const FEE_VAULT_SEED: &'static [u8] = b"fee_vault";
+
+    /// Seed prefix of the receipt mint PDA: ["receipt_mint", pool]
+    pub const RECEIPT_MINT_SEED: &'static [u8] = b"receipt_mint";
+
+    pub fn find_stake_vault_pda(pool: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::STAKE_VAULT_SEED, pool.as_ref()], &crate::ID)
+    }
+
+    pub fn find_reward_vault_pda(pool: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::REWARD_VAULT_SEED, pool.as_ref()], &crate::ID)
+    }
+
+    pub fn find_fee_vault_pda(pool: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::FEE_VAULT_SEED, pool.as_ref()], &crate::ID)
+    }
+
+    pub fn find_receipt_mint_pda(pool: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::RECEIPT_MINT_SEED, pool.as_ref()], &crate::ID)
+    }
+
+    /// Address of `vault` and, for program-derived vaults, its bump
+    pub fn vault(&self, vault: PoolVault) -> (Pubkey, Option<u8>) {
+        match vault {
+            PoolVault::Stake => (self.stake_vault, self.stake_vault_bump),
+            PoolVault::Reward => (self.reward_vault, self.reward_vault_bump),
+            PoolVault::Fee => (self.fee_vault, self.fee_vault_bump),
+        }
+    }
+
+    /// Whether the account still uses the V1 layout and needs MigratePool
+    pub fn is_legacy_layout(account: &AccountInfo) -> bool {
+        has_key(account, Key::StakePoolV1)
+    }
+
+    /// Whether the account uses the V2 layout without the discriminator and needs MigratePool
+    pub fn lacks_discriminator(account: &AccountInfo) -> bool {
+        has_key(account, Key::StakePoolV2)
+    }
+
+    /// Decode pool account data in any layout, upgrading older layouts in memory
+    pub fn from_account_data(data: &[u8]) -> Result<Self, ProgramError> {
+        if starts_with_key(data, Key::StakePoolV1) {
+            Ok(deserialize_padded::<StakePoolV1>(data, "StakePool", &[], 0)?.into())
+        } else if starts_with_key(data, Key::StakePoolV2) {
+            deserialize_padded(data, "StakePool", &[], Self::LEN - DISCRIMINATOR_LEN)
+        } else {
+            deserialize_padded(data, "StakePool", &Self::DISCRIMINATOR, Self::LEN)
+        }
+    }
+
+    /// Account data as `save` writes it: the discriminator, then the pool
+    pub fn to_account_data(&self) -> Result<Vec<u8>, ProgramError> {
+        serialize_account_data(&Self::DISCRIMINATOR, self, "StakePool")
+    }
+
+    /// Load a pool, upgrading older layouts in memory
+    ///
+    /// An upgraded pool cannot be saved back until MigratePool has grown the
+    /// account to `StakePool::LEN`.
     pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
-        let pool = validate_and_deserialize::<Self>(account, "StakePool")?;
+        validate_account(account, "StakePool")?;
+        let pool = Self::from_account_data(&account.data.borrow())?;
 
         // Verify discriminator matches expected type
-        if !matches!(pool.key, Key::StakePool) {
+        if !matches!(pool.key, Key::StakePoolV2) {
             msg!("Invalid StakePool discriminator");
             return Err(StakePoolError::InvalidAccountDiscriminator.into());
         }
@@ -302,14 +1187,19 @@ impl StakePool {
             validate_stored_timestamp(last_change, current_time)?;
         }
 
+        // Validate lockup_change_timestamp if present - set to current_time when proposed
+        if let Some(change_ts) = pool.lockup_change_timestamp {
+            validate_stored_timestamp(change_ts, current_time)?;
+        }
+
         Ok(pool)
     }
 
     pub fn save(&self, account: &AccountInfo) -> ProgramResult {
-        save_account_data(account, self, "StakePool")
+        save_prefixed_account_data(account, &Self::DISCRIMINATOR, self, "StakePool")
     }
 
-    /// Calculate rewards for a stake based on fixed reward rate
+    /// Calculate the current round's rewards for a stake at its locked reward rate
     ///
     /// # Security Fix [H-02]: Minimum Lockup Period
     /// Rewards are only earned if the lockup period is complete. The attack vector
@@ -318,12 +1208,14 @@ impl StakePool {
     ///
     /// # Reward Model
     /// - Binary distribution: 0% before lockup completes, 100% after
-    /// - Formula: (amount * reward_rate) / 1e9
+    /// - Formula: (amount * locked_reward_rate) / 1e9
     /// - Users must wait full lockup period before earning any rewards
+    /// - The rate is the pool's reward_rate when the round started, boosted by
+    ///   the reward tier the stake reached then (see `tiered_reward_rate`), so rate
+    ///   changes never affect in-flight stakes
     ///
     /// # Arguments
-    /// * `amount_staked` - Amount of tokens staked
-    /// * `stake_timestamp` - Unix timestamp when stake was created
+    /// * `stake` - The stake account (amount, start of the round and locked rate)
     /// * `current_time` - Current Unix timestamp
     ///
     /// # Returns
@@ -337,285 +1229,3845 @@ impl StakePool {
     /// - After 24 hours: 100 tokens (full reward)
     pub fn calculate_rewards(
         &self,
-        amount_staked: u64,
-        stake_timestamp: i64,
+        stake: &StakeAccount,
         current_time: i64,
     ) -> Result<u64, ProgramError> {
         // Check if lockup period is complete
         let time_staked = current_time
-            .checked_sub(stake_timestamp)
+            .checked_sub(stake.stake_timestamp)
             .ok_or(StakePoolError::NumericalOverflow)?;
 
-        if time_staked < self.lockup_period {
+        if time_staked < self.stake_lockup_period(stake)? {
             // No rewards if lockup not complete
             return Ok(0);
         }
 
-        // Calculate fixed rewards based on reward rate
-        // reward_rate is scaled by REWARD_SCALE (1e9, e.g., 100_000_000 = 10% of staked amount)
-        let rewards_u128 = (amount_staked as u128)
-            .checked_mul(self.reward_rate as u128)
-            .ok_or(StakePoolError::NumericalOverflow)?
-            .checked_div(REWARD_SCALE)
-            .ok_or(StakePoolError::NumericalOverflow)?;
-
-        // Safe cast from u128 to u64 with overflow check
-        let rewards = u64::try_from(rewards_u128).map_err(|_| StakePoolError::NumericalOverflow)?;
-
-        Ok(rewards)
+        stake.round_rewards()
     }
 
-    /// Check if the pool has sufficient rewards to cover all owed rewards.
+    /// Rewards earned by `stake` so far: rewards carried over from relocked rounds
+    /// plus the current round's reward (at its locked rate) once its lockup completes
     ///
-    /// This is a solvency check that verifies the reward vault has enough tokens
-    /// to satisfy all committed rewards. Should be called periodically for monitoring
-    /// and before accepting new stakes.
+    /// Includes rewards already claimed; subtract `claimed_rewards` for the claimable amount.
+    pub fn stake_rewards(
+        &self,
+        stake: &StakeAccount,
+        current_time: i64,
+    ) -> Result<u64, ProgramError> {
+        self.calculate_rewards(stake, current_time)?
+            .checked_add(stake.carried_rewards)
+            .ok_or_else(|| StakePoolError::NumericalOverflow.into())
+    }
+
+    /// Restart the lockup of a matured stake for another reward round
     ///
-    /// # Arguments
-    /// * `reward_vault_balance` - Current balance of the reward vault token account
+    /// The completed round's unclaimed rewards are carried over (they stay reserved
+    /// in `total_rewards_owed`) and the new round's full reward is reserved on top.
+    /// The caller must re-check reward vault solvency afterwards.
     ///
     /// # Returns
-    /// - `Ok(())` if pool is solvent (vault balance >= total_rewards_owed)
-    /// - `Err(InsufficientRewards)` if reward vault balance < total_rewards_owed
-    ///
-    /// # Example
-    /// ```ignore
-    /// let vault_balance = get_token_account_balance(reward_vault)?;
-    /// pool.verify_solvency(vault_balance)?;
-    /// ```
-    pub fn verify_solvency(&self, reward_vault_balance: u64) -> Result<(), ProgramError> {
-        if reward_vault_balance < self.total_rewards_owed {
+    /// The reward reserved for the new round
+    pub fn relock_stake(
+        &mut self,
+        stake: &mut StakeAccount,
+        current_time: i64,
+    ) -> Result<u64, ProgramError> {
+        if !stake.auto_relock {
+            msg!("Auto re-lock is disabled for this stake account");
+            return Err(StakePoolError::AutoRelockDisabled.into());
+        }
+        stake.check_no_reward_cohort()?;
+
+        if stake.amount_staked == 0 {
+            msg!("Nothing staked to re-lock");
+            return Err(StakePoolError::InsufficientStakedBalance.into());
+        }
+
+        let matures_at = self.stake_maturity(stake)?;
+        if current_time < matures_at {
             msg!(
-                "Pool insolvency detected! Owed: {}, Available: {}, Deficit: {}",
-                self.total_rewards_owed,
-                reward_vault_balance,
-                self.total_rewards_owed
-                    .checked_sub(reward_vault_balance)
-                    .unwrap_or(0)
+                "Lockup period not expired. Matures at: {}, Current time: {}",
+                matures_at,
+                current_time
             );
-            return Err(StakePoolError::InsufficientRewards.into());
+            return Err(StakePoolError::LockupNotExpired.into());
         }
-        Ok(())
-    }
-}
 
-impl StakeAccount {
-    pub const LEN: usize = 1 + 32 + 32 + 8 + 8 + 8 + 8 + 1;
+        // Carry the completed round's unclaimed rewards into the new round
+        let earned = self.stake_rewards(stake, current_time)?;
+        stake.carried_rewards = earned
+            .checked_sub(stake.claimed_rewards)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        stake.claimed_rewards = 0;
 
-    pub fn seeds(pool: &Pubkey, owner: &Pubkey, index: u64) -> Vec<Vec<u8>> {
-        vec![
-            b"stake_account".to_vec(),
-            pool.as_ref().to_vec(),
-            owner.as_ref().to_vec(),
-            index.to_le_bytes().to_vec(),
-        ]
-    }
+        // A new round starts a new position for the early unstake allowance
+        // and locks in the pool's current reward rate
+        stake.stake_timestamp = current_time;
+        stake.early_unstaked = 0;
+        stake.lockup_extension = 0;
+        stake.locked_reward_rate = self.tiered_reward_rate(stake.amount_staked)?;
 
-    pub fn find_pda(pool: &Pubkey, owner: &Pubkey, index: u64) -> (Pubkey, u8) {
-        let index_bytes = index.to_le_bytes();
-        let seeds: Vec<&[u8]> = vec![
-            b"stake_account",
-            pool.as_ref(),
-            owner.as_ref(),
-            &index_bytes,
-        ];
-        Pubkey::find_program_address(&seeds, &crate::ID)
+        let round_rewards = stake.round_rewards()?;
+        self.commit_rewards(round_rewards)?;
+
+        Ok(round_rewards)
     }
 
-    pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
-        let stake_account = validate_and_deserialize::<Self>(account, "StakeAccount")?;
+    /// Reserve `amount` rewards for a stake, counting them against `max_total_rewards`
+    ///
+    /// Fails with `RewardsCapExceeded` when the commitment would pass the cap.
+    pub fn commit_rewards(&mut self, amount: u64) -> ProgramResult {
+        let committed = self
+            .total_rewards_committed
+            .checked_add(amount)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        if let Some(cap) = self.max_total_rewards {
+            if committed > cap {
+                msg!(
+                    "Rewards cap exceeded: {} committed + {} new > {} cap",
+                    self.total_rewards_committed,
+                    amount,
+                    cap
+                );
+                return Err(StakePoolError::RewardsCapExceeded.into());
+            }
+        }
 
-        // Verify discriminator matches expected type
-        if !matches!(stake_account.key, Key::StakeAccount) {
+        self.total_rewards_owed = self
+            .total_rewards_owed
+            .checked_add(amount)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        self.total_rewards_committed = committed;
+        Ok(())
+    }
+
+    /// Rewards that may still be committed under `max_total_rewards` (None: uncapped)
+    pub fn rewards_headroom(&self) -> Option<u64> {
+        self.max_total_rewards
+            .map(|cap| cap.saturating_sub(self.total_rewards_committed))
+    }
+
+    /// Whether the rewards cap is exhausted: not even a minimum stake made at
+    /// `current_time` fits under it any more
+    pub fn is_rewards_cap_reached(&self, current_time: i64) -> Result<bool, ProgramError> {
+        match self.rewards_headroom() {
+            None => Ok(false),
+            Some(0) => Ok(true),
+            Some(headroom) => {
+                let smallest_stake = self.min_stake_amount.max(1);
+                Ok(headroom < self.expected_rewards(smallest_stake, current_time)?)
+            }
+        }
+    }
+
+    /// Fail with `PoolEnded` once the pool takes no new commitments: past its end
+    /// date, or with its rewards cap exhausted
+    pub fn check_not_ended(&self, current_time: i64) -> ProgramResult {
+        if let Some(end_date) = self.pool_end_date {
+            if current_time >= end_date {
+                msg!(
+                    "Pool has ended. End date: {}, Current time: {}",
+                    end_date,
+                    current_time
+                );
+                return Err(StakePoolError::PoolEnded.into());
+            }
+        }
+
+        if self.is_rewards_cap_reached(current_time)? {
+            msg!(
+                "Pool has ended: {} of its {:?} rewards cap are committed",
+                self.total_rewards_committed,
+                self.max_total_rewards
+            );
+            return Err(StakePoolError::PoolEnded.into());
+        }
+        Ok(())
+    }
+
+    /// Full reward for `amount_staked`, staked at `current_time`, once its lockup completes
+    ///
+    /// This is the amount reserved in `total_rewards_owed` when a stake is created,
+    /// at the rate it locks in (see `stake_reward_rate`). Existing stakes earn at their
+    /// `locked_reward_rate` instead (see `StakeAccount::round_rewards`).
+    pub fn expected_rewards(
+        &self,
+        amount_staked: u64,
+        current_time: i64,
+    ) -> Result<u64, ProgramError> {
+        rewards_at_rate(
+            amount_staked,
+            self.stake_reward_rate(amount_staked, current_time)?,
+        )
+    }
+
+    /// Reward rate a stake of `amount_staked` created at `current_time` locks in:
+    /// the tiered reward rate, boosted by the bonus multiplier inside the bonus window
+    pub fn stake_reward_rate(
+        &self,
+        amount_staked: u64,
+        current_time: i64,
+    ) -> Result<u64, ProgramError> {
+        let rate = self.tiered_reward_rate(amount_staked)?;
+        match self.bonus_end_ts {
+            Some(end) if current_time < end => {
+                let boosted = (rate as u128)
+                    .checked_mul(self.bonus_multiplier_bps as u128)
+                    .ok_or(StakePoolError::NumericalOverflow)?
+                    .checked_div(BPS_DENOMINATOR as u128)
+                    .ok_or(StakePoolError::NumericalOverflow)?;
+
+                u64::try_from(boosted).map_err(|_| StakePoolError::NumericalOverflow.into())
+            }
+            _ => Ok(rate),
+        }
+    }
+
+    /// Fail unless the pool's claim window is open at `current_time`
+    ///
+    /// The error log names the next time claims open.
+    pub fn check_claim_window(&self, current_time: i64) -> ProgramResult {
+        let Some(window) = self.claim_window else {
+            return Ok(());
+        };
+        if let Some(next_opening) = window.next_opening(current_time)? {
+            msg!(
+                "Claims are closed until the next claim window opens at {} (now {})",
+                next_opening,
+                current_time
+            );
+            return Err(StakePoolError::ClaimWindowClosed.into());
+        }
+        Ok(())
+    }
+
+    /// Copy the settings UpdatePool manages from `source` onto a pool being cloned from it
+    ///
+    /// Covers the unstake cooldown, lockup extension rate, reward funders, merkle
+    /// allowlist root, claim window, outflow limit, pool manager, keeper bounty, rewards
+    /// cap, minimum remaining stake, wind-down policy, points rate, deny list check,
+    /// third-party stakes and reward cohorts. Allowlist pages and receipt tokens belong
+    /// to the source pool and are not carried over, nor are its pause and its hook
+    /// (set with SetPoolHook against the hook allowlist).
+    pub fn copy_settings_from(&mut self, source: &StakePool) {
+        self.unstake_cooldown = source.unstake_cooldown;
+        self.lockup_extension_rate = source.lockup_extension_rate;
+        self.reward_funders = source.reward_funders;
+        self.allowlist_merkle_root = source.allowlist_merkle_root;
+        self.claim_window = source.claim_window;
+        self.outflow_limit = source.outflow_limit;
+        self.pool_manager = source.pool_manager;
+        self.keeper_bounty = source.keeper_bounty;
+        self.max_total_rewards = source.max_total_rewards;
+        self.enforce_min_remaining_stake = source.enforce_min_remaining_stake;
+        self.wind_down_rewards = source.wind_down_rewards;
+        self.points_rate = source.points_rate;
+        self.deny_list_required = source.deny_list_required;
+        self.allow_third_party_stake = source.allow_third_party_stake;
+        self.cohort_duration = source.cohort_duration;
+    }
+
+    /// Count `amount` stake tokens leaving the pool against its outflow limit
+    ///
+    /// Fails with `RateLimited` when the unstake would exceed the current window's cap.
+    pub fn record_unstake_outflow(&mut self, amount: u64, current_time: i64) -> ProgramResult {
+        let Some(limit) = self.outflow_limit else {
+            return Ok(());
+        };
+        if limit.max_unstake_bps == 0 {
+            return Ok(());
+        }
+        self.roll_outflow_window(current_time);
+
+        let cap = u64::try_from(
+            (self.outflow_window.total_staked as u128)
+                .checked_mul(limit.max_unstake_bps as u128)
+                .ok_or(StakePoolError::NumericalOverflow)?
+                / BPS_DENOMINATOR as u128,
+        )
+        .map_err(|_| StakePoolError::NumericalOverflow)?;
+        let unstaked = self
+            .outflow_window
+            .unstaked
+            .checked_add(amount)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        if unstaked > cap {
+            msg!(
+                "Unstaking {} exceeds the pool's outflow limit: {} of {} unstaked in the window, which resets at {}",
+                amount,
+                self.outflow_window.unstaked,
+                cap,
+                self.outflow_window.end()
+            );
+            return Err(StakePoolError::RateLimited.into());
+        }
+        self.outflow_window.unstaked = unstaked;
+        Ok(())
+    }
+
+    /// Count `amount` reward tokens leaving the pool against its outflow limit
+    ///
+    /// Fails with `RateLimited` when the claim would exceed the current window's cap.
+    pub fn record_claim_outflow(&mut self, amount: u64, current_time: i64) -> ProgramResult {
+        let Some(limit) = self.outflow_limit else {
+            return Ok(());
+        };
+        if limit.max_claim_amount == 0 {
+            return Ok(());
+        }
+        self.roll_outflow_window(current_time);
+
+        let claimed = self
+            .outflow_window
+            .claimed
+            .checked_add(amount)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        if claimed > limit.max_claim_amount {
+            msg!(
+                "Claiming {} exceeds the pool's outflow limit: {} of {} claimed in the window, which resets at {}",
+                amount,
+                self.outflow_window.claimed,
+                limit.max_claim_amount,
+                self.outflow_window.end()
+            );
+            return Err(StakePoolError::RateLimited.into());
+        }
+        self.outflow_window.claimed = claimed;
+        Ok(())
+    }
+
+    /// Open a new outflow window at `current_time` once the current one has elapsed
+    fn roll_outflow_window(&mut self, current_time: i64) {
+        if current_time >= self.outflow_window.end() {
+            self.outflow_window = OutflowWindow {
+                start: current_time,
+                total_staked: self.total_staked,
+                unstaked: 0,
+                claimed: 0,
+            };
+        }
+    }
+
+    /// Keeper bounty owed for a crank at `current_time`, out of `escrow` lamports
+    ///
+    /// Zero while no bounty is set or within KEEPER_BOUNTY_INTERVAL of the last
+    /// payout; a short escrow pays what is left of it.
+    pub fn keeper_bounty_due(&self, escrow: u64, current_time: i64) -> u64 {
+        let next_payout = self
+            .last_keeper_bounty_at
+            .saturating_add(KEEPER_BOUNTY_INTERVAL);
+        if self.keeper_bounty == 0 || current_time < next_payout {
+            return 0;
+        }
+        self.keeper_bounty.min(escrow)
+    }
+
+    /// Whether stakes must prove their owner is allowlisted, by page or merkle proof
+    pub fn requires_allowlist(&self) -> bool {
+        self.allowlist_required || self.allowlist_merkle_root.is_some()
+    }
+
+    /// Open an early-bird bonus window until `end` (or remove it when None)
+    ///
+    /// The window must end in the future and its multiplier lie between 1x and
+    /// MAX_REWARD_MULTIPLIER_BPS.
+    pub fn set_bonus_window(
+        &mut self,
+        end: Option<i64>,
+        multiplier_bps: u16,
+        current_time: i64,
+    ) -> ProgramResult {
+        if let Some(end) = end {
+            if end <= current_time {
+                msg!(
+                    "Bonus window must end in the future. End: {}, Current time: {}",
+                    end,
+                    current_time
+                );
+                return Err(StakePoolError::InvalidParameters.into());
+            }
+            if !(BPS_DENOMINATOR as u16..=MAX_REWARD_MULTIPLIER_BPS).contains(&multiplier_bps) {
+                msg!(
+                    "Invalid bonus multiplier: {} bps (min {} bps, max {} bps)",
+                    multiplier_bps,
+                    BPS_DENOMINATOR,
+                    MAX_REWARD_MULTIPLIER_BPS
+                );
+                return Err(StakePoolError::InvalidParameters.into());
+            }
+        }
+
+        self.bonus_end_ts = end;
+        self.bonus_multiplier_bps = if end.is_some() { multiplier_bps } else { 0 };
+        Ok(())
+    }
+
+    /// Multiplier (basis points) of the highest reward tier `amount_staked` reaches
+    /// (BPS_DENOMINATOR when it reaches none)
+    pub fn reward_multiplier_bps(&self, amount_staked: u64) -> u16 {
+        self.reward_tiers
+            .iter()
+            .flatten()
+            .filter(|tier| amount_staked >= tier.min_amount)
+            .map(|tier| tier.multiplier_bps)
+            .max()
+            .unwrap_or(BPS_DENOMINATOR as u16)
+    }
+
+    /// The pool's reward rate boosted by the reward tier of `amount_staked`
+    ///
+    /// New rounds (stake or relock) lock in this rate, so the boost is fixed by the
+    /// stake's size when the round starts.
+    ///
+    /// The rate is per round: an annualized pool's rate is prorated to its lockup
+    /// period in the same u128 computation, so only the final division rounds.
+    pub fn tiered_reward_rate(&self, amount_staked: u64) -> Result<u64, ProgramError> {
+        let boosted = (self.reward_rate as u128)
+            .checked_mul(self.reward_multiplier_bps(amount_staked) as u128)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        match self.reward_rate_mode {
+            RewardRateMode::PerLockup => self.reward_units(boosted, BPS_DENOMINATOR as u128),
+            RewardRateMode::Annualized => {
+                let lockup_period = u128::try_from(self.lockup_period)
+                    .map_err(|_| StakePoolError::NumericalOverflow)?;
+                self.reward_units(
+                    boosted
+                        .checked_mul(lockup_period)
+                        .ok_or(StakePoolError::NumericalOverflow)?,
+                    BPS_DENOMINATOR as u128 * SECONDS_PER_YEAR as u128,
+                )
+            }
+        }
+    }
+
+    /// `numerator / denominator`, a rate in stake token units, scaled to reward token
+    /// units by `reward_decimals_shift`
+    ///
+    /// The factor multiplies whichever side the shift calls for before the single
+    /// division, so the rate is rounded once.
+    fn reward_units(&self, numerator: u128, denominator: u128) -> Result<u64, ProgramError> {
+        let factor = 10u128
+            .checked_pow(self.reward_decimals_shift.unsigned_abs() as u32)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        let (numerator, denominator) = if self.reward_decimals_shift >= 0 {
+            (numerator.checked_mul(factor), Some(denominator))
+        } else {
+            (Some(numerator), denominator.checked_mul(factor))
+        };
+        let rate = numerator
+            .zip(denominator)
+            .and_then(|(numerator, denominator)| numerator.checked_div(denominator))
+            .ok_or(StakePoolError::NumericalOverflow)?;
+
+        u64::try_from(rate).map_err(|_| StakePoolError::NumericalOverflow.into())
+    }
+
+    /// `reward_decimals_shift` of a pool on mints with these decimals
+    ///
+    /// Fails with `InvalidParameters` past MAX_DECIMALS_SHIFT.
+    pub fn decimals_shift(stake_decimals: u8, reward_decimals: u8) -> Result<i8, ProgramError> {
+        if stake_decimals.abs_diff(reward_decimals) > MAX_DECIMALS_SHIFT {
+            msg!(
+                "Stake mint decimals ({}) and reward mint decimals ({}) differ by more than {}",
+                stake_decimals,
+                reward_decimals,
+                MAX_DECIMALS_SHIFT
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        Ok((reward_decimals as i16 - stake_decimals as i16) as i8)
+    }
+
+    /// The pool's reward rate as an annual rate (scaled by REWARD_SCALE), for display
+    ///
+    /// A per-lockup rate is scaled up by the number of lockup periods in a year, the
+    /// return of restaking every round. Reward tiers and bonuses are not included.
+    pub fn annual_reward_rate(&self) -> Result<u64, ProgramError> {
+        match self.reward_rate_mode {
+            RewardRateMode::Annualized => Ok(self.reward_rate),
+            RewardRateMode::PerLockup => {
+                let lockup_period = u128::try_from(self.lockup_period)
+                    .ok()
+                    .filter(|period| *period > 0)
+                    .ok_or(StakePoolError::InvalidParameters)?;
+                let rate = (self.reward_rate as u128)
+                    .checked_mul(SECONDS_PER_YEAR as u128)
+                    .ok_or(StakePoolError::NumericalOverflow)?
+                    / lockup_period;
+
+                u64::try_from(rate).map_err(|_| StakePoolError::NumericalOverflow.into())
+            }
+        }
+    }
+
+    /// Configure the pool's stake size reward tiers
+    ///
+    /// Tiers must have strictly ascending, non-zero minimum amounts and multipliers
+    /// between 1x and MAX_REWARD_MULTIPLIER_BPS that never decrease from one tier to
+    /// the next.
+    pub fn set_reward_tiers(&mut self, tiers: &[RewardTier]) -> Result<(), ProgramError> {
+        if tiers.len() > Self::MAX_REWARD_TIERS {
+            msg!(
+                "Too many reward tiers: {} (max {})",
+                tiers.len(),
+                Self::MAX_REWARD_TIERS
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+
+        let mut previous: Option<&RewardTier> = None;
+        for tier in tiers {
+            if tier.min_amount == 0
+                || !(BPS_DENOMINATOR as u16..=MAX_REWARD_MULTIPLIER_BPS)
+                    .contains(&tier.multiplier_bps)
+            {
+                msg!(
+                    "Invalid reward tier: min amount {}, multiplier {} bps (max {} bps)",
+                    tier.min_amount,
+                    tier.multiplier_bps,
+                    MAX_REWARD_MULTIPLIER_BPS
+                );
+                return Err(StakePoolError::InvalidParameters.into());
+            }
+            if let Some(previous) = previous {
+                if tier.min_amount <= previous.min_amount
+                    || tier.multiplier_bps < previous.multiplier_bps
+                {
+                    msg!("Reward tiers must be ordered by ascending min amount and multiplier");
+                    return Err(StakePoolError::InvalidParameters.into());
+                }
+            }
+            previous = Some(tier);
+        }
+
+        self.reward_tiers = [None; Self::MAX_REWARD_TIERS];
+        for (slot, tier) in self.reward_tiers.iter_mut().zip(tiers) {
+            *slot = Some(*tier);
+        }
+        Ok(())
+    }
+
+    /// Principal a stake may still withdraw before its lockup completes
+    ///
+    /// The allowance is `early_unstake_bps` of the original position (current stake
+    /// plus what was already withdrawn early), minus what was already withdrawn early.
+    pub fn early_unstake_allowance(&self, stake: &StakeAccount) -> Result<u64, ProgramError> {
+        let original_amount = stake
+            .amount_staked
+            .checked_add(stake.early_unstaked)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+
+        let allowance = bps_of(original_amount, self.early_unstake_bps)?;
+
+        Ok(allowance.saturating_sub(stake.early_unstaked))
+    }
+
+    /// Timestamp at which a stake created at `stake_timestamp` completes its lockup
+    ///
+    /// Rewards become claimable (see `calculate_rewards`) once the current time
+    /// reaches the returned value.
+    pub fn maturity_timestamp(&self, stake_timestamp: i64) -> Result<i64, ProgramError> {
+        stake_timestamp
+            .checked_add(self.lockup_period)
+            .ok_or_else(|| StakePoolError::NumericalOverflow.into())
+    }
+
+    /// Lockup of `stake`'s current round: the pool's lockup period plus its extension
+    pub fn stake_lockup_period(&self, stake: &StakeAccount) -> Result<i64, ProgramError> {
+        self.lockup_period
+            .checked_add(stake.lockup_extension)
+            .ok_or_else(|| StakePoolError::NumericalOverflow.into())
+    }
+
+    /// Timestamp at which `stake`'s current round completes its (possibly extended) lockup
+    pub fn stake_maturity(&self, stake: &StakeAccount) -> Result<i64, ProgramError> {
+        stake
+            .stake_timestamp
+            .checked_add(self.stake_lockup_period(stake)?)
+            .ok_or_else(|| StakePoolError::NumericalOverflow.into())
+    }
+
+    /// Extend the lockup of `stake`'s current round by `additional_seconds` (ExtendLockup)
+    ///
+    /// The stake locks in `lockup_extension_rate` pro rata to the extension on top of
+    /// its reward rate, so the bonus is paid with the round's reward once the extended
+    /// lockup completes, and shrinks with the stake like the rest of the round's reward.
+    /// The bonus is reserved in `total_rewards_owed`; the caller must re-check reward
+    /// vault solvency afterwards.
+    ///
+    /// # Returns
+    /// The bonus reward reserved for the stake
+    pub fn extend_lockup(
+        &mut self,
+        stake: &mut StakeAccount,
+        additional_seconds: i64,
+        current_time: i64,
+    ) -> Result<u64, ProgramError> {
+        if self.lockup_extension_rate == 0 {
+            msg!("The pool offers no bonus for lockup extensions");
+            return Err(StakePoolError::LockupExtensionDisabled.into());
+        }
+        stake.check_no_reward_cohort()?;
+
+        if stake.amount_staked == 0 {
+            msg!("Nothing staked to extend");
+            return Err(StakePoolError::InsufficientStakedBalance.into());
+        }
+
+        let matures_at = self.stake_maturity(stake)?;
+        if current_time >= matures_at {
+            msg!(
+                "Lockup already complete at {}; relock the stake instead",
+                matures_at
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+
+        let lockup_extension = stake
+            .lockup_extension
+            .checked_add(additional_seconds)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        if additional_seconds <= 0 || lockup_extension > MAX_LOCKUP_EXTENSION {
+            msg!(
+                "Invalid lockup extension: {} seconds (total {} seconds, max {} seconds)",
+                additional_seconds,
+                lockup_extension,
+                MAX_LOCKUP_EXTENSION
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+
+        let bonus_rate = self.reward_units(
+            (self.lockup_extension_rate as u128)
+                .checked_mul(additional_seconds as u128)
+                .ok_or(StakePoolError::NumericalOverflow)?,
+            SECONDS_PER_YEAR as u128,
+        )?;
+
+        let previous_rewards = stake.round_rewards()?;
+        stake.locked_reward_rate = stake
+            .locked_reward_rate
+            .checked_add(bonus_rate)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        stake.lockup_extension = lockup_extension;
+
+        let bonus = stake
+            .round_rewards()?
+            .checked_sub(previous_rewards)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        self.commit_rewards(bonus)?;
+
+        Ok(bonus)
+    }
+
+    /// Claimable and projected rewards of `stake` at `current_time`
+    pub fn pending_rewards(
+        &self,
+        stake: &StakeAccount,
+        current_time: i64,
+    ) -> Result<PendingRewards, ProgramError> {
+        let claimable = self
+            .stake_rewards(stake, current_time)?
+            .checked_sub(stake.claimed_rewards)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        let matures_at = self.stake_maturity(stake)?;
+        let projected_rewards = if current_time < matures_at {
+            self.stake_rewards(stake, matures_at)?
+                .checked_sub(stake.claimed_rewards)
+                .ok_or(StakePoolError::NumericalOverflow)?
+        } else {
+            claimable
+        };
+        let referral_share = match stake.referrer {
+            Some(_) => self.referral_share(claimable)?,
+            None => 0,
+        };
+
+        Ok(PendingRewards {
+            current_time,
+            claimable,
+            referral_share,
+            matures_at,
+            projected_rewards,
+            reward_rate: stake.locked_reward_rate,
+        })
+    }
+
+    /// Portion of a claim paid to the stake's referrer (`referral_bps` of `claim_amount`)
+    ///
+    /// Rounds down, so the owner keeps any remainder and the referrer share
+    /// never exceeds the configured basis points.
+    pub fn referral_share(&self, claim_amount: u64) -> Result<u64, ProgramError> {
+        bps_of(claim_amount, self.referral_bps)
+    }
+
+    /// Fee taken from a stake deposit of `amount` (`deposit_fee_bps`, rounded down)
+    pub fn deposit_fee(&self, amount: u64) -> Result<u64, ProgramError> {
+        bps_of(amount, self.deposit_fee_bps)
+    }
+
+    /// Fee taken from an unstake of `amount` (`withdraw_fee_bps`, rounded down)
+    pub fn withdraw_fee(&self, amount: u64) -> Result<u64, ProgramError> {
+        bps_of(amount, self.withdraw_fee_bps)
+    }
+
+    /// Check if the pool has sufficient rewards to cover all owed rewards.
+    ///
+    /// This is a solvency check that verifies the reward vault has enough tokens
+    /// to satisfy all committed rewards. Should be called periodically for monitoring
+    /// and before accepting new stakes.
+    ///
+    /// # Arguments
+    /// * `reward_vault_balance` - Current balance of the reward vault token account
+    ///
+    /// # Returns
+    /// - `Ok(())` if pool is solvent (vault balance >= total_rewards_owed)
+    /// - `Err(InsufficientRewards)` if reward vault balance < total_rewards_owed
+    ///
+    /// # Example
+    /// ```ignore
+    /// let vault_balance = get_token_account_balance(reward_vault)?;
+    /// pool.verify_solvency(vault_balance)?;
+    /// ```
+    pub fn verify_solvency(&self, reward_vault_balance: u64) -> Result<(), ProgramError> {
+        let owed = self.reward_vault_owed();
+        if reward_vault_balance < owed {
+            msg!(
+                "Pool insolvency detected! Owed: {}, Available: {}, Deficit: {}",
+                owed,
+                reward_vault_balance,
+                owed.saturating_sub(reward_vault_balance)
+            );
+            return Err(StakePoolError::InsufficientRewards.into());
+        }
+        Ok(())
+    }
+
+    /// Whether `funder` may call FundRewards (an empty allowlist allows anyone)
+    ///
+    /// The pool manager may always fund the pool.
+    pub fn is_reward_funder_allowed(&self, funder: &Pubkey) -> bool {
+        self.reward_funders.iter().all(Option::is_none)
+            || self.reward_funders.contains(&Some(*funder))
+            || self.pool_manager == Some(*funder)
+    }
+
+    /// Replace the reward funder allowlist (an empty list opens funding to anyone)
+    pub fn set_reward_funders(&mut self, funders: &[Pubkey]) -> Result<(), ProgramError> {
+        if funders.len() > Self::MAX_REWARD_FUNDERS {
+            msg!(
+                "Too many reward funders: {} (max {})",
+                funders.len(),
+                Self::MAX_REWARD_FUNDERS
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+
+        self.reward_funders = [None; Self::MAX_REWARD_FUNDERS];
+        for (slot, funder) in self.reward_funders.iter_mut().zip(funders) {
+            *slot = Some(*funder);
+        }
+        Ok(())
+    }
+
+    /// Reward vault tokens neither committed to stakers nor expired
+    /// (`balance - reward_vault_owed - expired_rewards`), the most DefundRewards may withdraw
+    pub fn reward_surplus(&self, reward_vault_balance: u64) -> u64 {
+        reward_vault_balance
+            .saturating_sub(self.reward_vault_owed())
+            .saturating_sub(self.expired_rewards)
+    }
+
+    /// Rewards the pool's reward vault must hold for the stakes
+    ///
+    /// `total_rewards_owed`, except in pools with reward cohorts, whose stakes
+    /// are paid by their cohort vaults (see `RewardCohort`).
+    pub fn reward_vault_owed(&self) -> u64 {
+        if self.cohort_duration > 0 {
+            0
+        } else {
+            self.total_rewards_owed
+        }
+    }
+
+    /// Cohort of a stake opened at `stake_timestamp` (0 in pools without reward cohorts)
+    pub fn reward_cohort_at(&self, stake_timestamp: i64) -> u64 {
+        if self.cohort_duration <= 0 {
+            return 0;
+        }
+        u64::try_from(stake_timestamp / self.cohort_duration).unwrap_or(0)
+    }
+
+    /// Set the length of the pool's reward cohorts (0 turns them off)
+    ///
+    /// Stakes keep the kind of vault they reserved their rewards in, so the
+    /// duration only changes while the pool holds no stake, owes no rewards and
+    /// has no cohort vault left open.
+    pub fn set_cohort_duration(&mut self, cohort_duration: i64) -> ProgramResult {
+        if cohort_duration != 0
+            && !(MIN_REWARD_COHORT_DURATION..=MAX_REWARD_COHORT_DURATION).contains(&cohort_duration)
+        {
+            msg!(
+                "Reward cohorts must last between {} and {} seconds, got {}",
+                MIN_REWARD_COHORT_DURATION,
+                MAX_REWARD_COHORT_DURATION,
+                cohort_duration
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        if self.total_staked != 0
+            || self.total_rewards_owed != 0
+            || self.active_stake_count.unwrap_or(0) != 0
+            || self.open_reward_cohorts != 0
+        {
+            msg!(
+                "Reward cohorts only change while the pool is empty. Staked: {}, Owed: {}, Open cohorts: {}",
+                self.total_staked,
+                self.total_rewards_owed,
+                self.open_reward_cohorts
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        self.cohort_duration = cohort_duration;
+        Ok(())
+    }
+
+    /// Advance `event_nonce` for the next Stake, Unstake, Claim, ClaimBatch or
+    /// PoolUpdated event of the pool, which carries the returned value
+    ///
+    /// The nonce is saved with the pool before the event is logged, so each
+    /// value appears in exactly one event of a successful transaction.
+    pub fn next_event_nonce(&mut self) -> Result<u64, ProgramError> {
+        self.event_nonce = self
+            .event_nonce
+            .checked_add(1)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        Ok(self.event_nonce)
+    }
+
+    /// Count a stake account created by Stake (pools keeping the count only)
+    pub fn record_stake_opened(&mut self) -> ProgramResult {
+        if let Some(count) = self.active_stake_count.as_mut() {
+            *count = count
+                .checked_add(1)
+                .ok_or(StakePoolError::NumericalOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// Stop counting a stake account whose whole stake was unstaked
+    ///
+    /// Saturates so an unstake can never fail on the count.
+    pub fn record_stake_emptied(&mut self) {
+        if let Some(count) = self.active_stake_count.as_mut() {
+            *count = count.saturating_sub(1);
+        }
+    }
+
+    /// Stake vault tokens not backing `total_staked` (`balance - total_staked`),
+    /// the amount SweepDust moves out
+    pub fn stake_surplus(&self, stake_vault_balance: u64) -> u64 {
+        stake_vault_balance.saturating_sub(self.total_staked)
+    }
+
+    /// Move the unclaimed rewards of an abandoned stake into `expired_rewards`
+    ///
+    /// A stake is abandoned once `REWARD_EXPIRY_PERIOD` has passed since its lockup
+    /// completed without its rewards being claimed. The rewards are marked as claimed
+    /// on the stake account, so its owner keeps the principal but can no longer claim
+    /// them, and their reservation leaves `total_rewards_owed`. A cohort stake's
+    /// rewards are forfeited to its cohort vault instead.
+    ///
+    /// # Returns
+    /// The amount moved to `expired_rewards`
+    pub fn expire_stake_rewards(
+        &mut self,
+        stake: &mut StakeAccount,
+        current_time: i64,
+    ) -> Result<u64, ProgramError> {
+        let expires_at = self
+            .stake_maturity(stake)?
+            .checked_add(REWARD_EXPIRY_PERIOD)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        if current_time < expires_at {
+            msg!(
+                "Rewards not expired. Expire at: {}, Current time: {}",
+                expires_at,
+                current_time
+            );
+            return Err(StakePoolError::RewardsNotExpired.into());
+        }
+
+        let earned = self.stake_rewards(stake, current_time)?;
+        let unclaimed = earned
+            .checked_sub(stake.claimed_rewards)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        if unclaimed == 0 {
+            msg!("Stake account has no unclaimed rewards to expire");
+            return Err(StakePoolError::NoExpiredRewards.into());
+        }
+
+        stake.claimed_rewards = earned;
+        self.total_rewards_owed = self
+            .total_rewards_owed
+            .checked_sub(unclaimed)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        // A cohort stake's rewards stay in its cohort vault, not the reward vault
+        if stake.reward_cohort == 0 {
+            self.expired_rewards = self
+                .expired_rewards
+                .checked_add(unclaimed)
+                .ok_or(StakePoolError::NumericalOverflow)?;
+        }
+
+        Ok(unclaimed)
+    }
+
+    /// Carve `amount` of a stake's principal into a new stake account at `index` (SplitStake)
+    ///
+    /// The new position keeps the stake's round (stake timestamp, locked rate and
+    /// lockup extension), so neither part loses lockup progress. Claimed and carried
+    /// rewards, the early unstake allowance used and vested principal are split in
+    /// proportion to the principal. Both parts must hold at least `min_stake_amount`;
+    /// a pending withdrawal stays with the original stake.
+    ///
+    /// # Returns
+    /// The new stake account
+    pub fn split_stake(
+        &mut self,
+        stake: &mut StakeAccount,
+        amount: u64,
+        index: u64,
+        bump: u8,
+    ) -> Result<StakeAccount, ProgramError> {
+        stake.check_no_pending_slash()?;
+        if amount == 0 || amount >= stake.amount_staked {
+            msg!(
+                "Split amount must be above 0 and below the {} staked",
+                stake.amount_staked
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        let remaining = stake.amount_staked - amount;
+        if amount.min(remaining) < self.min_stake_amount {
+            msg!(
+                "Both parts of a split need at least {} staked. Split: {}, remaining: {}",
+                self.min_stake_amount,
+                amount,
+                remaining
+            );
+            return Err(StakePoolError::AmountBelowMinimum.into());
+        }
+
+        let reserved = stake.unclaimed_rewards()?;
+        let share = |value: u64| -> Result<u64, ProgramError> {
+            let share = (value as u128)
+                .checked_mul(amount as u128)
+                .ok_or(StakePoolError::NumericalOverflow)?
+                / stake.amount_staked as u128;
+            Ok(share as u64)
+        };
+        let mut split = StakeAccount {
+            index,
+            bump,
+            amount_staked: amount,
+            claimed_rewards: share(stake.claimed_rewards)?,
+            carried_rewards: share(stake.carried_rewards)?,
+            early_unstaked: share(stake.early_unstaked)?,
+            vesting_amount: share(stake.vesting_amount)?,
+            pending_withdraw_amount: 0,
+            request_timestamp: None,
+            pending_owner: None,
+            ..stake.clone()
+        };
+
+        stake.amount_staked = remaining;
+        stake.claimed_rewards -= split.claimed_rewards;
+        stake.carried_rewards -= split.carried_rewards;
+        stake.early_unstaked -= split.early_unstaked;
+        stake.vesting_amount -= split.vesting_amount;
+
+        // Rounding the round rewards of both parts down can leave one having
+        // claimed slightly more than it earns: it counts as fully claimed
+        for part in [&mut *stake, &mut split] {
+            part.claimed_rewards = part.claimed_rewards.min(
+                part.round_rewards()?
+                    .checked_add(part.carried_rewards)
+                    .ok_or(StakePoolError::NumericalOverflow)?,
+            );
+        }
+
+        // The parts reserve what the stake did, up to that rounding
+        let split_reserved = stake
+            .unclaimed_rewards()?
+            .checked_add(split.unclaimed_rewards()?)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        self.total_rewards_owed = self
+            .total_rewards_owed
+            .checked_sub(reserved)
+            .and_then(|owed| owed.checked_add(split_reserved))
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        self.record_stake_opened()?;
+
+        Ok(split)
+    }
+
+    /// Fold `source` into `stake`, another stake account of the same owner (MergeStakes)
+    ///
+    /// The merged stake keeps the later of the two stake timestamps, so neither part
+    /// matures earlier than it would have. Both must share the locked reward rate and
+    /// lockup extension, and a matured stake cannot join one still locked, whose round
+    /// would hold back rewards it already earned. Claimed and carried rewards, the early
+    /// unstake allowance used and the principal add up; `stake` keeps its own referrer,
+    /// delegate, label, vesting and pending withdrawal. The caller closes `source`.
+    pub fn merge_stakes(
+        &mut self,
+        stake: &mut StakeAccount,
+        source: &StakeAccount,
+        current_time: i64,
+    ) -> ProgramResult {
+        stake.check_no_pending_slash()?;
+        source.check_no_pending_slash()?;
+        if source.pool != stake.pool || source.owner != stake.owner || source.index == stake.index {
+            msg!("Only two distinct stake accounts of the same owner and pool can be merged");
+            return Err(StakePoolError::StakesNotMergeable.into());
+        }
+        if source.locked_reward_rate != stake.locked_reward_rate
+            || source.lockup_extension != stake.lockup_extension
+        {
+            msg!(
+                "Stakes locked at different terms cannot be merged. Rates: {} and {}, lockup extensions: {} and {}",
+                stake.locked_reward_rate,
+                source.locked_reward_rate,
+                stake.lockup_extension,
+                source.lockup_extension
+            );
+            return Err(StakePoolError::StakesNotMergeable.into());
+        }
+        if source.reward_cohort != stake.reward_cohort {
+            msg!(
+                "Stakes of reward cohorts {} and {} cannot be merged",
+                stake.reward_cohort,
+                source.reward_cohort
+            );
+            return Err(StakePoolError::StakesNotMergeable.into());
+        }
+        if source.pending_withdraw_amount != 0 || source.vesting_amount != 0 {
+            msg!("The merged in stake must have no pending withdrawal or vesting grant");
+            return Err(StakePoolError::StakesNotMergeable.into());
+        }
+
+        let (earlier, later) = if source.stake_timestamp < stake.stake_timestamp {
+            (source, &*stake)
+        } else {
+            (&*stake, source)
+        };
+        let earlier_matured = current_time >= self.stake_maturity(earlier)?;
+        let later_matured = current_time >= self.stake_maturity(later)?;
+        if earlier_matured && !later_matured {
+            msg!(
+                "A matured stake cannot be merged into one locked until {}; claim or re-lock it first",
+                self.stake_maturity(later)?
+            );
+            return Err(StakePoolError::StakesNotMergeable.into());
+        }
+
+        let reserved = stake
+            .unclaimed_rewards()?
+            .checked_add(source.unclaimed_rewards()?)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        let points_since = |part: &StakeAccount| match part.points_synced_at {
+            0 => part.stake_timestamp,
+            synced_at => synced_at,
+        };
+        // Unsynced points are kept only from the later checkpoint on
+        let points_synced_at = points_since(stake).max(points_since(source));
+        let stake_timestamp = later.stake_timestamp;
+        // An empty side was uncounted when it emptied
+        let both_active = stake.amount_staked > 0 && source.amount_staked > 0;
+
+        let add = |a: u64, b: u64| -> Result<u64, ProgramError> {
+            a.checked_add(b)
+                .ok_or_else(|| StakePoolError::NumericalOverflow.into())
+        };
+        stake.amount_staked = add(stake.amount_staked, source.amount_staked)?;
+        stake.claimed_rewards = add(stake.claimed_rewards, source.claimed_rewards)?;
+        stake.carried_rewards = add(stake.carried_rewards, source.carried_rewards)?;
+        stake.early_unstaked = add(stake.early_unstaked, source.early_unstaked)?;
+        stake.stake_timestamp = stake_timestamp;
+        stake.points_synced_at = points_synced_at;
+
+        // Rounding the merged round's reward down once instead of twice can
+        // reserve one base unit more than the two stakes did
+        let merged_reserved = stake.unclaimed_rewards()?;
+        self.total_rewards_owed = self
+            .total_rewards_owed
+            .checked_sub(reserved)
+            .and_then(|owed| owed.checked_add(merged_reserved))
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        if both_active {
+            self.record_stake_emptied();
+        }
+
+        Ok(())
+    }
+
+    /// Points `stake` earned since its last sync, moving its checkpoint to `current_time`
+    ///
+    /// Points accrue on the stake's current principal at the pool's current
+    /// `points_rate`, from `points_synced_at` (or `stake_timestamp` for a stake
+    /// never synced). Principal withdrawn since the last sync earns nothing for
+    /// that time, so owners sync before unstaking.
+    pub fn accrue_points(
+        &self,
+        stake: &mut StakeAccount,
+        current_time: i64,
+    ) -> Result<u128, ProgramError> {
+        let since = match stake.points_synced_at {
+            0 => stake.stake_timestamp,
+            synced_at => synced_at,
+        };
+        let elapsed = current_time.saturating_sub(since).max(0) as u128;
+
+        let points = (stake.amount_staked as u128)
+            .checked_mul(elapsed)
+            .and_then(|token_seconds| token_seconds.checked_mul(self.points_rate as u128))
+            .ok_or(StakePoolError::NumericalOverflow)?
+            / POINTS_SCALE;
+
+        stake.points_synced_at = current_time.max(since);
+        Ok(points)
+    }
+
+    /// Empty an abandoned stake of an ended pool for WindDownStake
+    ///
+    /// Allowed once `WIND_DOWN_DELAY` has passed since the pool's end date. The
+    /// whole principal, staked and pending withdrawal, leaves `total_staked` and
+    /// every reward the stake holds reserved leaves `total_rewards_owed`: the part
+    /// it has earned is paid or expired according to `wind_down_rewards`, the rest
+    /// is forfeited as in an early unstake. Cohort stakes forfeit all of it to their
+    /// cohort vault. The caller closes the stake account.
+    pub fn wind_down_stake(
+        &mut self,
+        stake: &mut StakeAccount,
+        current_time: i64,
+    ) -> Result<WindDown, ProgramError> {
+        stake.check_no_pending_slash()?;
+        let Some(end_date) = self.pool_end_date else {
+            msg!("Pool has no end date to wind down after");
+            return Err(StakePoolError::WindDownNotStarted.into());
+        };
+        let starts_at = end_date
+            .checked_add(WIND_DOWN_DELAY)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        if current_time < starts_at {
+            msg!(
+                "Wind-down not started. Starts at: {}, Current time: {}",
+                starts_at,
+                current_time
+            );
+            return Err(StakePoolError::WindDownNotStarted.into());
+        }
+
+        let reserved = stake.unclaimed_rewards()?;
+        let earned = self
+            .stake_rewards(stake, current_time)?
+            .saturating_sub(stake.claimed_rewards)
+            .min(reserved);
+        let principal = stake
+            .amount_staked
+            .checked_add(stake.pending_withdraw_amount)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+
+        self.total_staked = self
+            .total_staked
+            .checked_sub(principal)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        self.total_rewards_owed = self
+            .total_rewards_owed
+            .checked_sub(reserved)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        if stake.amount_staked > 0 {
+            self.record_stake_emptied();
+        }
+
+        let wind_down = match self.wind_down_rewards {
+            // The cohort vault keeps a cohort stake's rewards, as when it is forfeited
+            _ if stake.reward_cohort != 0 => WindDown {
+                principal,
+                rewards_paid: 0,
+                rewards_expired: 0,
+            },
+            WindDownRewards::Expire => {
+                self.expired_rewards = self
+                    .expired_rewards
+                    .checked_add(earned)
+                    .ok_or(StakePoolError::NumericalOverflow)?;
+                WindDown {
+                    principal,
+                    rewards_paid: 0,
+                    rewards_expired: earned,
+                }
+            }
+            WindDownRewards::Pay => WindDown {
+                principal,
+                rewards_paid: earned,
+                rewards_expired: 0,
+            },
+        };
+
+        stake.amount_staked = 0;
+        stake.pending_withdraw_amount = 0;
+        stake.request_timestamp = None;
+        stake.claimed_rewards = 0;
+        stake.stake_timestamp = 0;
+        stake.early_unstaked = 0;
+        stake.carried_rewards = 0;
+        stake.lockup_extension = 0;
+
+        Ok(wind_down)
+    }
+
+    /// Check that `amount` may leave a stake at `current_time`
+    ///
+    /// With `enforce_lockup`, withdrawals before the lockup completes are limited to
+    /// the stake's early unstake allowance. Unvested principal of a VestingStake grant
+    /// must stay in the stake regardless of the lockup. With `enforce_min_remaining_stake`,
+    /// a partial withdrawal must leave at least `min_stake_amount` staked.
+    ///
+    /// # Returns
+    /// Whether the stake's lockup is complete
+    pub fn check_unstake_lockup(
+        &self,
+        stake: &StakeAccount,
+        amount: u64,
+        current_time: i64,
+    ) -> Result<bool, ProgramError> {
+        stake.check_no_pending_slash()?;
+        if stake.amount_staked < amount {
+            msg!(
+                "Insufficient staked balance. Requested: {}, Available: {}",
+                amount,
+                stake.amount_staked
+            );
+            return Err(StakePoolError::InsufficientStakedBalance.into());
+        }
+
+        let unvested = stake.unvested_amount(current_time)?;
+        let remaining = stake
+            .amount_staked
+            .checked_sub(amount)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        if remaining < unvested {
+            msg!(
+                "Principal not vested. Requested: {}, Staked: {}, Unvested: {}",
+                amount,
+                stake.amount_staked,
+                unvested
+            );
+            return Err(StakePoolError::PrincipalNotVested.into());
+        }
+        if self.enforce_min_remaining_stake && remaining > 0 && remaining < self.min_stake_amount {
+            msg!(
+                "Partial unstake would leave {} staked, below the minimum {}. Unstake {} or at most {}.",
+                remaining,
+                self.min_stake_amount,
+                stake.amount_staked,
+                stake.amount_staked.saturating_sub(self.min_stake_amount)
+            );
+            return Err(StakePoolError::RemainingStakeBelowMinimum.into());
+        }
+
+        let time_staked = current_time
+            .checked_sub(stake.stake_timestamp)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        let lockup_period = self.stake_lockup_period(stake)?;
+        let lockup_complete = time_staked >= lockup_period;
+
+        // If enforce_lockup is true, prevent early withdrawals beyond the pool's allowance
+        if self.enforce_lockup && !lockup_complete {
+            let allowance = self.early_unstake_allowance(stake)?;
+            if amount > allowance {
+                msg!(
+                    "Lockup period not expired. Time staked: {}, Required: {}, Early unstake allowance: {}",
+                    time_staked,
+                    lockup_period,
+                    allowance
+                );
+                return Err(StakePoolError::LockupNotExpired.into());
+            }
+        }
+
+        Ok(lockup_complete)
+    }
+
+    /// Unclaimed rewards released from `total_rewards_owed` when `amount` leaves a stake
+    ///
+    /// A full withdrawal forfeits all unclaimed rewards, a partial one the withdrawn
+    /// share of them. Before lockup completion the stake's reward is still reserved
+    /// in full, so the remainder keeps its share. Rewards carried over from relocked
+    /// rounds are reserved as well.
+    pub fn unstake_forfeiture(
+        &self,
+        stake: &StakeAccount,
+        amount: u64,
+    ) -> Result<u64, ProgramError> {
+        let unclaimed_rewards = stake.unclaimed_rewards()?;
+
+        if amount == stake.amount_staked {
+            return Ok(unclaimed_rewards);
+        }
+
+        let unstake_fraction = (amount as u128)
+            .checked_mul(REWARD_SCALE)
+            .ok_or(StakePoolError::NumericalOverflow)?
+            .checked_div(stake.amount_staked as u128)
+            .ok_or(StakePoolError::NumericalOverflow)? as u64;
+
+        Ok((unclaimed_rewards as u128)
+            .checked_mul(unstake_fraction as u128)
+            .ok_or(StakePoolError::NumericalOverflow)?
+            .checked_div(REWARD_SCALE)
+            .ok_or(StakePoolError::NumericalOverflow)? as u64)
+    }
+
+    /// Move `amount` of a stake's principal into its pending withdrawal (RequestUnstake)
+    ///
+    /// The amount stops earning immediately: its unclaimed rewards are forfeited as in
+    /// an unstake. It stays in `total_staked` until `WithdrawUnstaked` pays it out, and
+    /// a further request adds to the pending amount and restarts the cooldown.
+    ///
+    /// # Returns
+    /// The forfeited rewards
+    pub fn request_unstake(
+        &mut self,
+        stake: &mut StakeAccount,
+        amount: u64,
+        current_time: i64,
+    ) -> Result<u64, ProgramError> {
+        let lockup_complete = self.check_unstake_lockup(stake, amount, current_time)?;
+        let forfeited = self.unstake_forfeiture(stake, amount)?;
+
+        stake.remove_principal(amount, forfeited)?;
+        stake.pending_withdraw_amount = stake
+            .pending_withdraw_amount
+            .checked_add(amount)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        stake.request_timestamp = Some(current_time);
+        self.total_rewards_owed = self
+            .total_rewards_owed
+            .checked_sub(forfeited)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+
+        if !lockup_complete {
+            stake.early_unstaked = stake
+                .early_unstaked
+                .checked_add(amount)
+                .ok_or(StakePoolError::NumericalOverflow)?;
+        }
+        if stake.amount_staked == 0 {
+            stake.claimed_rewards = 0;
+            stake.stake_timestamp = 0;
+            stake.early_unstaked = 0;
+            stake.carried_rewards = 0;
+            stake.lockup_extension = 0;
+            self.record_stake_emptied();
+        }
+
+        Ok(forfeited)
+    }
+
+    /// Clear a stake's pending withdrawal once the cooldown has passed (WithdrawUnstaked)
+    ///
+    /// The pool's current `unstake_cooldown` applies, counted from the latest request.
+    ///
+    /// # Returns
+    /// The amount to pay out of the stake vault
+    pub fn complete_unstake_request(
+        &mut self,
+        stake: &mut StakeAccount,
+        current_time: i64,
+    ) -> Result<u64, ProgramError> {
+        stake.check_no_pending_slash()?;
+        let (Some(requested_at), amount @ 1..) =
+            (stake.request_timestamp, stake.pending_withdraw_amount)
+        else {
+            msg!("Stake account has no pending withdrawal");
+            return Err(StakePoolError::NoPendingWithdrawal.into());
+        };
+
+        let withdrawable_at = requested_at
+            .checked_add(self.unstake_cooldown)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        if current_time < withdrawable_at {
+            msg!(
+                "Unstake cooldown active. Withdrawable at: {}, Current time: {}",
+                withdrawable_at,
+                current_time
+            );
+            return Err(StakePoolError::UnstakeCooldownActive.into());
+        }
+
+        stake.pending_withdraw_amount = 0;
+        stake.request_timestamp = None;
+        self.total_staked = self
+            .total_staked
+            .checked_sub(amount)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+
+        Ok(amount)
+    }
+
+    /// Slash `slash_bps` of a stake's principal, staked and pending withdrawal (ExecuteSlash)
+    ///
+    /// The slashed part of the staked amount forfeits its unclaimed rewards as in
+    /// an unstake, and a vesting grant shrinks by the same share. A stake slashed
+    /// down to nothing is reset like a full unstake.
+    ///
+    /// # Returns
+    /// The slashed principal, which leaves `total_staked`, and the forfeited rewards
+    pub fn slash_stake(
+        &mut self,
+        stake: &mut StakeAccount,
+        slash_bps: u16,
+    ) -> Result<(u64, u64), ProgramError> {
+        let slashed_staked = bps_of(stake.amount_staked, slash_bps)?;
+        let slashed_pending = bps_of(stake.pending_withdraw_amount, slash_bps)?;
+        let forfeited = if slashed_staked > 0 {
+            self.unstake_forfeiture(stake, slashed_staked)?
+        } else {
+            0
+        };
+
+        stake.remove_principal(slashed_staked, forfeited)?;
+        stake.pending_withdraw_amount = stake
+            .pending_withdraw_amount
+            .checked_sub(slashed_pending)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        if stake.pending_withdraw_amount == 0 {
+            stake.request_timestamp = None;
+        }
+        stake.vesting_amount = stake
+            .vesting_amount
+            .checked_sub(bps_of(stake.vesting_amount, slash_bps)?)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+
+        let slashed = slashed_staked
+            .checked_add(slashed_pending)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        self.total_staked = self
+            .total_staked
+            .checked_sub(slashed)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        self.total_rewards_owed = self
+            .total_rewards_owed
+            .checked_sub(forfeited)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+
+        if slashed_staked > 0 && stake.amount_staked == 0 {
+            stake.claimed_rewards = 0;
+            stake.stake_timestamp = 0;
+            stake.early_unstaked = 0;
+            stake.carried_rewards = 0;
+            stake.lockup_extension = 0;
+            self.record_stake_emptied();
+        }
+
+        Ok((slashed, forfeited))
+    }
+
+    /// Whether the vault balances back both the recorded stake and the owed rewards
+    ///
+    /// `total_staked` is tracked in amounts actually received (net of Token-2022
+    /// transfer fees), and token account balances exclude fees withheld on the
+    /// vault, so the stake comparison is post-fee on both sides. A surplus in
+    /// either vault (donations, rounding dust) is fine; a shortfall is not.
+    pub fn is_solvent(&self, stake_vault_balance: u64, reward_vault_balance: u64) -> bool {
+        stake_vault_balance >= self.total_staked && reward_vault_balance >= self.reward_vault_owed()
+    }
+
+    /// `balance` as a share of `owed` in basis points, saturating at u64::MAX (None when nothing is owed)
+    pub fn coverage_bps(balance: u64, owed: u64) -> Option<u64> {
+        if owed == 0 {
+            return None;
+        }
+        let bps = (balance as u128) * (BPS_DENOMINATOR as u128) / (owed as u128);
+        Some(u64::try_from(bps).unwrap_or(u64::MAX))
+    }
+
+    /// The pool's health for `GetPoolStats`, given the live vault balances
+    pub fn stats(&self, stake_vault_balance: u64, reward_vault_balance: u64) -> PoolStats {
+        PoolStats {
+            total_staked: self.total_staked,
+            stake_vault_balance,
+            total_rewards_owed: self.total_rewards_owed,
+            reward_vault_balance,
+            stake_coverage_bps: Self::coverage_bps(stake_vault_balance, self.total_staked),
+            reward_coverage_bps: Self::coverage_bps(reward_vault_balance, self.reward_vault_owed()),
+            solvent: self.is_solvent(stake_vault_balance, reward_vault_balance),
+            active_stake_count: self.active_stake_count,
+            is_paused: self.is_paused,
+        }
+    }
+
+    /// Whether the pool can be decommissioned: paused, with nothing staked or owed
+    ///
+    /// Pausing first is the closure's first step; it stops new stakes from
+    /// landing between the admin's last check and the ClosePool transaction.
+    /// Pools keeping an active stake count must also have no stake account left
+    /// holding stake, and pools with reward cohorts no cohort vault left open.
+    pub fn is_closable(&self) -> bool {
+        self.is_paused
+            && self.total_staked == 0
+            && self.total_rewards_owed == 0
+            && self.active_stake_count.unwrap_or(0) == 0
+            && self.open_reward_cohorts == 0
+    }
+
+    /// Recompute `total_rewards_owed` from `stakes` for `AuditPool`
+    ///
+    /// The audit is complete when `stakes` hold exactly as many stakes with a
+    /// balance as the pool's active stake count, so pools created before the
+    /// count was kept can be audited but never repaired.
+    pub fn audit(&self, stakes: &[StakeAccount]) -> Result<PoolAudit, ProgramError> {
+        let mut recomputed_rewards_owed: u64 = 0;
+        let mut active_stakes: u64 = 0;
+        for stake in stakes {
+            recomputed_rewards_owed = recomputed_rewards_owed
+                .checked_add(stake.unclaimed_rewards()?)
+                .ok_or(StakePoolError::NumericalOverflow)?;
+            if stake.amount_staked > 0 {
+                active_stakes += 1;
+            }
+        }
+
+        Ok(PoolAudit {
+            audited_stakes: stakes.len() as u64,
+            recomputed_rewards_owed,
+            total_rewards_owed: self.total_rewards_owed,
+            complete: self.active_stake_count == Some(active_stakes),
+            repaired: false,
+        })
+    }
+}
+
+impl StakeAccount {
+    /// First 8 bytes of `sha256("account:StakeAccount")`
+    pub const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+        [0x50, 0x9e, 0x43, 0x7c, 0x32, 0xbd, 0xc0, 0xff];
+
+    // discriminator + key + pool + owner + index + amount_staked + stake_timestamp + claimed_rewards + bump
+    // + referrer (Option<Pubkey>, 33 bytes when Some) + early_unstaked + auto_relock
+    // + carried_rewards + delegate (Option<Pubkey>, 33 bytes when Some)
+    // + pending_withdraw_amount + request_timestamp (Option<i64>, 9 bytes when Some)
+    // + locked_reward_rate + label + pending_owner (Option<Pubkey>, 33 bytes when Some)
+    // + lockup_extension + vesting_amount + vesting_start + vesting_cliff
+    // + vesting_duration + pending_slash_bps + points_synced_at + reward_cohort
+    //
+    // Stake accounts created before the label, the pending owner, the lockup
+    // extension, vesting, slashing, points, reward cohorts or the discriminator
+    // were added are shorter. They load with an empty label, no pending owner, no
+    // extension, no vesting, no pending slash, no points checkpoint and cohort 0
+    // and are rewritten at LEN by MigratePool.
+    pub const LEN: usize = DISCRIMINATOR_LEN
+        + 1
+        + 32
+        + 32
+        + 8
+        + 8
+        + 8
+        + 8
+        + 1
+        + 33
+        + 8
+        + 1
+        + 8
+        + 33
+        + 8
+        + 9
+        + 8
+        + Self::LABEL_LEN
+        + 33
+        + 8
+        + 8
+        + 8
+        + 8
+        + 8
+        + 2
+        + 8
+        + 8;
+
+    /// Size of the owner's label
+    pub const LABEL_LEN: usize = 32;
+
+    pub fn seeds(pool: &Pubkey, owner: &Pubkey, index: u64) -> Vec<Vec<u8>> {
+        vec![
+            b"stake_account".to_vec(),
+            pool.as_ref().to_vec(),
+            owner.as_ref().to_vec(),
+            index.to_le_bytes().to_vec(),
+        ]
+    }
+
+    pub fn find_pda(pool: &Pubkey, owner: &Pubkey, index: u64) -> (Pubkey, u8) {
+        let index_bytes = index.to_le_bytes();
+        let seeds: Vec<&[u8]> = vec![
+            b"stake_account",
+            pool.as_ref(),
+            owner.as_ref(),
+            &index_bytes,
+        ];
+        Pubkey::find_program_address(&seeds, &crate::ID)
+    }
+
+    /// Seed prefix of the temporary wSOL account used to unwrap native SOL
+    /// unstakes: ["unwrap", stake_account]
+    pub const UNWRAP_SEED: &'static [u8] = b"unwrap";
+
+    pub fn find_unwrap_pda(stake_account: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[Self::UNWRAP_SEED, stake_account.as_ref()], &crate::ID)
+    }
+
+    /// Whether the account still uses the V1 layout and needs MigratePool
+    pub fn is_legacy_layout(account: &AccountInfo) -> bool {
+        has_key(account, Key::StakeAccountV1)
+    }
+
+    /// Whether the account uses the V2 layout without the discriminator and needs MigratePool
+    pub fn lacks_discriminator(account: &AccountInfo) -> bool {
+        has_key(account, Key::StakeAccountV2)
+    }
+
+    /// Decode stake account data in any layout, upgrading older layouts in memory
+    pub fn from_account_data(data: &[u8]) -> Result<Self, ProgramError> {
+        if starts_with_key(data, Key::StakeAccountV1) {
+            Ok(deserialize_padded::<StakeAccountV1>(data, "StakeAccount", &[], 0)?.into())
+        } else if starts_with_key(data, Key::StakeAccountV2) {
+            deserialize_padded(data, "StakeAccount", &[], Self::LEN - DISCRIMINATOR_LEN)
+        } else {
+            deserialize_padded(data, "StakeAccount", &Self::DISCRIMINATOR, Self::LEN)
+        }
+    }
+
+    /// Account data as `save` writes it: the discriminator, then the stake account
+    pub fn to_account_data(&self) -> Result<Vec<u8>, ProgramError> {
+        serialize_account_data(&Self::DISCRIMINATOR, self, "StakeAccount")
+    }
+
+    /// Full reward of the current round at its `locked_reward_rate`
+    ///
+    /// This is what the round reserved in the pool's `total_rewards_owed`, so
+    /// reward rate changes finalized after the round started do not affect it.
+    pub fn round_rewards(&self) -> Result<u64, ProgramError> {
+        rewards_at_rate(self.amount_staked, self.locked_reward_rate)
+    }
+
+    /// Rewards the stake still holds reserved in the pool's `total_rewards_owed`:
+    /// the current round's full reward plus carried over rewards, less what was claimed
+    pub fn unclaimed_rewards(&self) -> Result<u64, ProgramError> {
+        self.round_rewards()?
+            .checked_add(self.carried_rewards)
+            .ok_or(StakePoolError::NumericalOverflow)?
+            .checked_sub(self.claimed_rewards)
+            .ok_or_else(|| StakePoolError::NumericalOverflow.into())
+    }
+
+    /// Take `amount` out of the stake's principal, forfeiting `forfeited` of its
+    /// unclaimed rewards
+    ///
+    /// The rewards the stake holds reserved drop by exactly `forfeited`. The part of
+    /// the current round's reward the remaining principal no longer earns, but that
+    /// was already claimed or is not forfeited, moves to `carried_rewards`; otherwise
+    /// a stake that claimed its reward and then partially unstaked would have
+    /// claimed more than it earns and could never be emptied.
+    pub fn remove_principal(&mut self, amount: u64, forfeited: u64) -> ProgramResult {
+        let unclaimed = self
+            .unclaimed_rewards()?
+            .checked_sub(forfeited)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        self.amount_staked = self
+            .amount_staked
+            .checked_sub(amount)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        self.carried_rewards = unclaimed
+            .checked_add(self.claimed_rewards)
+            .ok_or(StakePoolError::NumericalOverflow)?
+            .saturating_sub(self.round_rewards()?);
+        Ok(())
+    }
+
+    /// Check a VestingStake schedule: a positive duration of at most
+    /// MAX_VESTING_DURATION, with a cliff no longer than the duration
+    pub fn validate_vesting_schedule(vesting_cliff: i64, vesting_duration: i64) -> ProgramResult {
+        if vesting_duration <= 0
+            || vesting_duration > MAX_VESTING_DURATION
+            || vesting_cliff < 0
+            || vesting_cliff > vesting_duration
+        {
+            msg!(
+                "Invalid vesting schedule: cliff {}, duration {}. Duration must be between 1 and {} seconds, the cliff at most the duration.",
+                vesting_cliff,
+                vesting_duration,
+                MAX_VESTING_DURATION
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        Ok(())
+    }
+
+    /// Part of the vesting grant that has not vested at `current_time`
+    ///
+    /// Nothing vests before the cliff; from then on the grant vests linearly
+    /// from `vesting_start` until `vesting_duration` has passed.
+    pub fn unvested_amount(&self, current_time: i64) -> Result<u64, ProgramError> {
+        if self.vesting_amount == 0 {
+            return Ok(0);
+        }
+
+        let elapsed = current_time
+            .checked_sub(self.vesting_start)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        if elapsed < self.vesting_cliff {
+            return Ok(self.vesting_amount);
+        }
+        if elapsed >= self.vesting_duration {
+            return Ok(0);
+        }
+
+        let vested = (self.vesting_amount as u128)
+            .checked_mul(elapsed as u128)
+            .ok_or(StakePoolError::NumericalOverflow)?
+            .checked_div(self.vesting_duration as u128)
+            .ok_or(StakePoolError::NumericalOverflow)? as u64;
+        self.vesting_amount
+            .checked_sub(vested)
+            .ok_or_else(|| StakePoolError::NumericalOverflow.into())
+    }
+
+    /// Fail while a slash of the stake is pending
+    ///
+    /// A proposed slash freezes the principal until it is executed or cancelled,
+    /// so the owner cannot withdraw or hand over the position during the timelock.
+    pub fn check_no_pending_slash(&self) -> ProgramResult {
+        if self.pending_slash_bps != 0 {
+            msg!(
+                "A slash of {} bps is pending on this stake",
+                self.pending_slash_bps
+            );
+            return Err(StakePoolError::StakeSlashPending.into());
+        }
+        Ok(())
+    }
+
+    /// Fail with `InvalidRewardCohort` for stakes of a reward cohort
+    ///
+    /// Their cohort vault reserved a fixed amount when they were opened, so
+    /// they cannot reserve more rewards by relocking or extending the lockup.
+    pub fn check_no_reward_cohort(&self) -> ProgramResult {
+        if self.reward_cohort != 0 {
+            msg!(
+                "Stakes of reward cohort {} cannot reserve more rewards",
+                self.reward_cohort
+            );
+            return Err(StakePoolError::InvalidRewardCohort.into());
+        }
+        Ok(())
+    }
+
+    /// Amount a claim of `requested` pays out of `unclaimed` rewards (all of them when `None`)
+    ///
+    /// The rest stays claimable later. A requested amount of zero or above
+    /// `unclaimed` is rejected.
+    pub fn claim_amount(unclaimed: u64, requested: Option<u64>) -> Result<u64, ProgramError> {
+        match requested {
+            None => Ok(unclaimed),
+            Some(0) => {
+                msg!("Claim amount must be greater than zero");
+                Err(StakePoolError::InvalidParameters.into())
+            }
+            Some(amount) if amount > unclaimed => {
+                msg!(
+                    "Claim amount {} exceeds unclaimed rewards {}",
+                    amount,
+                    unclaimed
+                );
+                Err(StakePoolError::ClaimExceedsUnclaimedRewards.into())
+            }
+            Some(amount) => Ok(amount),
+        }
+    }
+
+    /// Load a stake account, upgrading V1 accounts to the current layout in memory
+    ///
+    /// Like pools, upgraded V1 accounts are only saved back by MigratePool.
+    pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
+        validate_account(account, "StakeAccount")?;
+        let stake_account = Self::from_account_data(&account.data.borrow())?;
+
+        // Verify discriminator matches expected type
+        if !matches!(stake_account.key, Key::StakeAccountV2) {
             msg!("Invalid StakeAccount discriminator");
             return Err(StakePoolError::InvalidAccountDiscriminator.into());
         }
 
-        Ok(stake_account)
+        Ok(stake_account)
+    }
+
+    pub fn save(&self, account: &AccountInfo) -> ProgramResult {
+        save_prefixed_account_data(account, &Self::DISCRIMINATOR, self, "StakeAccount")
+    }
+
+    /// Whether `signer` may claim this stake's rewards (the owner or its delegate)
+    pub fn can_claim(&self, signer: &Pubkey) -> bool {
+        signer == &self.owner || self.delegate.as_ref() == Some(signer)
+    }
+
+    /// The position re-keyed to the nominated `new_owner` at stake index `index`
+    ///
+    /// The owner is part of the stake account's seeds, so accepting a transfer
+    /// moves the position into a new stake account with `bump`. Amounts, timestamps
+    /// and rewards carry over unchanged; the delegate chosen by the previous owner
+    /// and the nomination are cleared.
+    pub fn transfer_to(
+        &self,
+        new_owner: &Pubkey,
+        index: u64,
+        bump: u8,
+    ) -> Result<StakeAccount, ProgramError> {
+        self.check_no_pending_slash()?;
+        let pending_owner = self
+            .pending_owner
+            .ok_or(StakePoolError::NoPendingStakeOwner)?;
+        if new_owner != &pending_owner {
+            msg!(
+                "{} is not the nominated owner {} of the stake",
+                new_owner,
+                pending_owner
+            );
+            return Err(StakePoolError::InvalidPendingStakeOwner.into());
+        }
+
+        Ok(StakeAccount {
+            owner: *new_owner,
+            index,
+            bump,
+            delegate: None,
+            pending_owner: None,
+            ..self.clone()
+        })
+    }
+}
+
+/// StakePool in the first deployed layout (`Key::StakePoolV1`)
+///
+/// Only read by `StakePool::load`, which upgrades it to the current layout.
+/// Fields added since default to their disabled values; the fee vault and the
+/// vault bumps are filled in by MigratePool.
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug)]
+pub struct StakePoolV1 {
+    pub key: Key,
+    pub stake_mint: Pubkey,
+    pub reward_mint: Pubkey,
+    pub pool_id: u64,
+    pub stake_vault: Pubkey,
+    pub reward_vault: Pubkey,
+    pub total_staked: u64,
+    pub total_rewards_owed: u64,
+    pub reward_rate: u64,
+    pub min_stake_amount: u64,
+    pub lockup_period: i64,
+    pub is_paused: bool,
+    pub enforce_lockup: bool,
+    pub bump: u8,
+    pub pool_end_date: Option<i64>,
+    pub pending_reward_rate: Option<u64>,
+    pub reward_rate_change_timestamp: Option<i64>,
+    pub last_rate_change: Option<i64>,
+    pub _reserved: [u8; 7],
+}
+
+impl StakePoolV1 {
+    // Same fixed fields, options and reserved space as the V1 StakePool::LEN
+    pub const LEN: usize = 180 + 36 + 7;
+}
+
+impl From<StakePoolV1> for StakePool {
+    fn from(pool: StakePoolV1) -> Self {
+        Self {
+            key: Key::StakePoolV2,
+            stake_mint: pool.stake_mint,
+            reward_mint: pool.reward_mint,
+            pool_id: pool.pool_id,
+            stake_vault: pool.stake_vault,
+            reward_vault: pool.reward_vault,
+            total_staked: pool.total_staked,
+            total_rewards_owed: pool.total_rewards_owed,
+            reward_rate: pool.reward_rate,
+            min_stake_amount: pool.min_stake_amount,
+            lockup_period: pool.lockup_period,
+            is_paused: pool.is_paused,
+            enforce_lockup: pool.enforce_lockup,
+            bump: pool.bump,
+            pool_end_date: pool.pool_end_date,
+            pending_reward_rate: pool.pending_reward_rate,
+            reward_rate_change_timestamp: pool.reward_rate_change_timestamp,
+            last_rate_change: pool.last_rate_change,
+            referral_bps: 0,
+            early_unstake_bps: 0,
+            stake_vault_bump: None,
+            reward_vault_bump: None,
+            fee_vault: Pubkey::default(),
+            deposit_fee_bps: 0,
+            withdraw_fee_bps: 0,
+            fee_vault_bump: None,
+            // Funding was not tracked before V2
+            total_rewards_funded: 0,
+            reward_funders: [None; StakePool::MAX_REWARD_FUNDERS],
+            receipt_mint: None,
+            expired_rewards: 0,
+            unstake_cooldown: 0,
+            // V1 pools predate the registry and are registered with RegisterPool
+            is_registered: false,
+            reward_rate_change_delay: 0,
+            pending_lockup_period: None,
+            pending_enforce_lockup: None,
+            lockup_change_timestamp: None,
+            min_reward_rate_bound: None,
+            max_reward_rate_bound: None,
+            // V1 stakes were not counted
+            active_stake_count: None,
+            reward_tiers: [None; StakePool::MAX_REWARD_TIERS],
+            bonus_end_ts: None,
+            bonus_multiplier_bps: 0,
+            lockup_extension_rate: 0,
+            allowlist_required: false,
+            allowlist_merkle_root: None,
+            claim_window: None,
+            outflow_limit: None,
+            outflow_window: OutflowWindow::default(),
+            pool_manager: None,
+            keeper_bounty: 0,
+            last_keeper_bounty_at: 0,
+            max_total_rewards: None,
+            total_rewards_committed: 0,
+            reward_rate_mode: RewardRateMode::PerLockup,
+            reward_decimals_shift: 0,
+            enforce_min_remaining_stake: false,
+            wind_down_rewards: WindDownRewards::Expire,
+            hook_program: None,
+            points_rate: 0,
+            deny_list_required: false,
+            allow_third_party_stake: false,
+            event_nonce: 0,
+            cohort_duration: 0,
+            open_reward_cohorts: 0,
+            _reserved: [0; 2],
+        }
+    }
+}
+
+/// StakeAccount in the first deployed layout (`Key::StakeAccountV1`)
+///
+/// Only read by `StakeAccount::load`, which upgrades it to the current layout.
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug)]
+pub struct StakeAccountV1 {
+    pub key: Key,
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub index: u64,
+    pub amount_staked: u64,
+    pub stake_timestamp: i64,
+    pub claimed_rewards: u64,
+    pub bump: u8,
+}
+
+impl StakeAccountV1 {
+    pub const LEN: usize = 1 + 32 + 32 + 8 + 8 + 8 + 8 + 1;
+}
+
+impl From<StakeAccountV1> for StakeAccount {
+    fn from(stake: StakeAccountV1) -> Self {
+        Self {
+            key: Key::StakeAccountV2,
+            pool: stake.pool,
+            owner: stake.owner,
+            index: stake.index,
+            amount_staked: stake.amount_staked,
+            stake_timestamp: stake.stake_timestamp,
+            claimed_rewards: stake.claimed_rewards,
+            bump: stake.bump,
+            referrer: None,
+            early_unstaked: 0,
+            auto_relock: false,
+            carried_rewards: 0,
+            delegate: None,
+            pending_withdraw_amount: 0,
+            request_timestamp: None,
+            // V1 rewards followed the pool's current rate; MigratePool locks it in
+            locked_reward_rate: 0,
+            label: [0; StakeAccount::LABEL_LEN],
+            pending_owner: None,
+            lockup_extension: 0,
+            vesting_amount: 0,
+            vesting_start: 0,
+            vesting_cliff: 0,
+            vesting_duration: 0,
+            pending_slash_bps: 0,
+            points_synced_at: 0,
+            reward_cohort: 0,
+        }
+    }
+}
+
+/// Scoped administrative role, granted without full global admin rights
+///
+/// Roles are stored as a bit mask; `Role::mask` gives each role's bit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// Pause and unpause pools and flip the global pause switches (bit 0)
+    Pauser,
+    /// Propose reward rate changes (bit 1)
+    RateAdmin,
+    /// Defund surplus rewards and withdraw collected fees (bit 2)
+    Treasurer,
+}
+
+impl Role {
+    /// Mask of every defined role
+    pub const ALL: u8 = Role::Pauser.mask() | Role::RateAdmin.mask() | Role::Treasurer.mask();
+
+    /// The role's bit in a role mask
+    pub const fn mask(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// An address holding scoped roles on the ProgramAuthority
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct RoleHolder {
+    pub holder: Pubkey,
+    /// Bit mask of held roles (see `Role::mask`)
+    pub roles: u8,
+}
+
+/// An address authorized to create pools, with its pool count and limit
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct AuthorizedCreator {
+    pub creator: Pubkey,
+    /// Pools the creator has created
+    pub pools_created: u16,
+    /// Maximum pools the creator may create (None = unlimited)
+    pub pool_limit: Option<u16>,
+}
+
+impl AuthorizedCreator {
+    // Size calculation: creator (32) + pools_created (2) + pool_limit (Option<u16>, 3)
+    pub const LEN: usize = 32 + 2 + 3;
+}
+
+/// Program authority configuration for managing pool creation permissions
+/// This account controls who can create new stake pools
+///
+/// The authorized creators are stored last so the account grows at its tail:
+/// it is allocated with room for `DEFAULT_MAX_CREATORS` and reallocated when
+/// the list outgrows it, up to the configurable `max_creators`.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct ProgramAuthority {
+    pub key: Key,
+    /// The main authority who can manage the authorized creators list
+    pub authority: Pubkey,
+    /// Pending authority for two-step authority transfer (None if no transfer pending)
+    pub pending_authority: Option<Pubkey>,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    /// Cluster this deployment serves (gates dev-only instructions and timelocks)
+    pub cluster_profile: ClusterProfile,
+    /// Incident switch halting stake, unstake and claim operations in every pool
+    pub global_pause: bool,
+    /// Softer incident mode: in every pool only unstakes and withdrawals are allowed
+    pub withdrawals_only: bool,
+    /// Addresses holding scoped roles (pauser, rate admin, treasurer)
+    pub role_holders: [Option<RoleHolder>; 8],
+    /// Distinct global admin approvals a sensitive pool update needs
+    /// (0 or 1 = sensitive updates apply directly through UpdatePool)
+    pub approval_threshold: u8,
+    /// Key allowed to take over the authority if it is lost (None = no recovery)
+    pub recovery_authority: Option<Pubkey>,
+    /// Seconds between InitiateRecovery and the earliest CompleteRecovery
+    pub recovery_delay: i64,
+    /// Time the recovery authority initiated a takeover (None when none is in progress)
+    pub recovery_initiated_at: Option<i64>,
+    /// Most authorized creators the list may hold (at most `MAX_CREATORS`)
+    pub max_creators: u16,
+    /// Addresses authorized to create pools, in the order they were added
+    pub authorized_creators: Vec<AuthorizedCreator>,
+}
+
+/// User operations gated by the ProgramAuthority's global pause switches
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UserOperation {
+    /// Stake and StakeSol
+    Deposit,
+    /// Unstake, UnstakeSol, RequestUnstake and WithdrawUnstaked
+    Withdrawal,
+    /// ClaimRewards
+    Claim,
+}
+
+impl ProgramAuthority {
+    /// First 8 bytes of `sha256("account:ProgramAuthority")`
+    pub const DISCRIMINATOR: [u8; DISCRIMINATOR_LEN] =
+        [0x26, 0xc6, 0xbc, 0x3c, 0xab, 0xd2, 0xa9, 0x26];
+
+    // Size calculation:
+    // - discriminator: 8 bytes
+    // - key (Key enum): 1 byte
+    // - authority (Pubkey): 32 bytes
+    // - pending_authority (Option<Pubkey>): 1 byte when None, 33 bytes when Some
+    // - bump (u8): 1 byte
+    // - cluster_profile (ClusterProfile enum): 1 byte
+    // - global_pause (bool): 1 byte
+    // - withdrawals_only (bool): 1 byte
+    // - role_holders (8 x Option<RoleHolder>): 8 * 34 = 272 bytes (1 byte discriminator + 32 bytes pubkey + 1 byte roles)
+    // - approval_threshold (u8): 1 byte
+    // - recovery_authority (Option<Pubkey>): 1 byte when None, 33 bytes when Some
+    // - recovery_delay (i64): 8 bytes
+    // - recovery_initiated_at (Option<i64>): 1 byte when None, 9 bytes when Some
+    // - max_creators (u16): 2 bytes
+    // - authorized_creators (Vec<AuthorizedCreator>): 4 byte length + 37 bytes per creator
+    // Base: 8 + 1 + 32 + 33 + 1 + 1 + 1 + 1 + 272 + 1 + 33 + 8 + 9 + 2 + 4 = 407 bytes
+    pub const BASE_LEN: usize =
+        DISCRIMINATOR_LEN + 1 + 32 + 33 + 1 + 1 + 1 + 1 + (8 * 34) + 1 + 33 + 8 + 9 + 2 + 4;
+    /// Size allocated at initialization: room for `DEFAULT_MAX_CREATORS` creators (777 bytes)
+    pub const LEN: usize = Self::space(Self::DEFAULT_MAX_CREATORS as usize);
+    /// Creator capacity of a new program authority
+    pub const DEFAULT_MAX_CREATORS: u16 = 10;
+    /// Highest `max_creators` the authority can configure
+    /// Bounds the account size and the creator list scanned by every admin check.
+    pub const MAX_CREATORS: u16 = 100;
+    pub const MAX_ROLE_HOLDERS: usize = 8;
+
+    /// Account size holding `creators` authorized creators
+    pub const fn space(creators: usize) -> usize {
+        Self::BASE_LEN + creators * AuthorizedCreator::LEN
+    }
+
+    /// Account size the current creator list needs
+    pub fn required_len(&self) -> usize {
+        Self::space(self.authorized_creators.len())
+    }
+
+    pub fn seeds() -> Vec<Vec<u8>> {
+        vec![b"program_authority".to_vec()]
+    }
+
+    pub fn find_pda() -> (Pubkey, u8) {
+        let seeds: Vec<&[u8]> = vec![b"program_authority"];
+        Pubkey::find_program_address(&seeds, &crate::ID)
+    }
+
+    /// Whether the account holds a program authority in the V1 layout
+    pub fn is_legacy_layout(account: &AccountInfo) -> bool {
+        has_key(account, Key::ProgramAuthorityV1)
+    }
+
+    /// Whether the account holds a V2 program authority without the discriminator
+    pub fn lacks_discriminator(account: &AccountInfo) -> bool {
+        has_key(account, Key::ProgramAuthorityV2)
+    }
+
+    /// Decode program authority account data in any layout, upgrading older layouts in memory
+    pub fn from_account_data(data: &[u8]) -> Result<Self, ProgramError> {
+        if starts_with_key(data, Key::ProgramAuthorityV1) {
+            Ok(deserialize_padded::<ProgramAuthorityV1>(
+                data,
+                "ProgramAuthority",
+                &[],
+                ProgramAuthorityV1::LEN,
+            )?
+            .into())
+        } else if starts_with_key(data, Key::ProgramAuthorityV2) {
+            deserialize_padded(data, "ProgramAuthority", &[], 0)
+        } else {
+            deserialize_padded(data, "ProgramAuthority", &Self::DISCRIMINATOR, 0)
+        }
+    }
+
+    /// Account data as `save` writes it: the discriminator, then the program authority
+    pub fn to_account_data(&self) -> Result<Vec<u8>, ProgramError> {
+        serialize_account_data(&Self::DISCRIMINATOR, self, "ProgramAuthority")
+    }
+
+    /// Load the program authority, upgrading the V1 layout in memory
+    ///
+    /// V1 accounts written before the type last grew read as zero-padded;
+    /// MigrateProgramAuthority grows them if the current layout does not fit.
+    pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
+        validate_account(account, "ProgramAuthority")?;
+        let program_authority = Self::from_account_data(&account.data.borrow())?;
+
+        // Verify discriminator matches expected type
+        if !matches!(program_authority.key, Key::ProgramAuthorityV2) {
+            msg!("Invalid ProgramAuthority discriminator");
+            return Err(StakePoolError::InvalidAccountDiscriminator.into());
+        }
+
+        // Validate the creator list fits its configured capacity
+        program_authority.validate_creator_count()?;
+
+        Ok(program_authority)
+    }
+
+    pub fn save(&self, account: &AccountInfo) -> ProgramResult {
+        save_prefixed_account_data(account, &Self::DISCRIMINATOR, self, "ProgramAuthority")
+    }
+
+    /// Check if a given pubkey is authorized to create pools
+    pub fn is_authorized(&self, pubkey: &Pubkey) -> bool {
+        // Main authority is always authorized
+        if pubkey == &self.authority {
+            return true;
+        }
+
+        // Check authorized creators list
+        self.authorized_creators
+            .iter()
+            .any(|authorized| &authorized.creator == pubkey)
+    }
+
+    /// Number of authorized creators
+    pub fn creator_count(&self) -> usize {
+        self.authorized_creators.len()
+    }
+
+    /// Number of global admins: the main authority and the authorized creators
+    pub fn admin_count(&self) -> usize {
+        1 + self.creator_count()
+    }
+
+    /// Whether sensitive pool updates must go through the approval flow
+    pub fn requires_approval(&self) -> bool {
+        self.approval_threshold > 1
+    }
+
+    /// Set the approvals sensitive pool updates need
+    ///
+    /// The threshold cannot exceed the number of global admins, so it always
+    /// stays reachable when it is set, nor the approvals a pending action records.
+    pub fn set_approval_threshold(&mut self, threshold: u8) -> Result<(), ProgramError> {
+        if threshold as usize > self.admin_count() {
+            msg!(
+                "Approval threshold {} exceeds the {} global admins",
+                threshold,
+                self.admin_count()
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        if threshold as usize > PendingAction::MAX_APPROVALS {
+            msg!(
+                "Approval threshold {} exceeds the {} approvals a pending action records",
+                threshold,
+                PendingAction::MAX_APPROVALS
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        self.approval_threshold = threshold;
+        Ok(())
+    }
+
+    /// Set or remove the recovery authority and its delay
+    ///
+    /// Any takeover in progress is cancelled, so rotating a compromised
+    /// recovery key also stops what it initiated.
+    pub fn set_recovery(
+        &mut self,
+        recovery_authority: Option<Pubkey>,
+        recovery_delay: i64,
+    ) -> ProgramResult {
+        if let Some(recovery_authority) = recovery_authority {
+            if recovery_authority == self.authority {
+                msg!("Recovery authority cannot be the current authority");
+                return Err(StakePoolError::InvalidParameters.into());
+            }
+            if !(MIN_RECOVERY_DELAY..=MAX_RECOVERY_DELAY).contains(&recovery_delay) {
+                msg!(
+                    "Invalid recovery delay: {}. Must be between {} and {} seconds",
+                    recovery_delay,
+                    MIN_RECOVERY_DELAY,
+                    MAX_RECOVERY_DELAY
+                );
+                return Err(StakePoolError::InvalidParameters.into());
+            }
+        }
+
+        self.recovery_authority = recovery_authority;
+        self.recovery_delay = if recovery_authority.is_some() {
+            recovery_delay
+        } else {
+            0
+        };
+        self.recovery_initiated_at = None;
+        Ok(())
+    }
+
+    /// Start a takeover by the recovery authority `signer`
+    ///
+    /// # Returns
+    /// The time from which CompleteRecovery can hand it the authority
+    pub fn initiate_recovery(
+        &mut self,
+        signer: &Pubkey,
+        current_time: i64,
+    ) -> Result<i64, ProgramError> {
+        self.check_recovery_authority(signer)?;
+        if self.recovery_initiated_at.is_some() {
+            msg!("An authority recovery is already in progress");
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+
+        self.recovery_initiated_at = Some(current_time);
+        current_time
+            .checked_add(self.recovery_delay)
+            .ok_or_else(|| StakePoolError::NumericalOverflow.into())
+    }
+
+    /// Hand the authority to the recovery authority `signer` once the delay has passed
+    ///
+    /// The recovery key becomes the authority and the recovery configuration and
+    /// any nomination are cleared; the new authority sets a fresh recovery key.
+    ///
+    /// # Returns
+    /// The previous authority
+    pub fn complete_recovery(
+        &mut self,
+        signer: &Pubkey,
+        current_time: i64,
+    ) -> Result<Pubkey, ProgramError> {
+        self.check_recovery_authority(signer)?;
+        let initiated_at = self
+            .recovery_initiated_at
+            .ok_or(StakePoolError::NoRecoveryInProgress)?;
+        let completes_at = initiated_at
+            .checked_add(self.recovery_delay)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        if current_time < completes_at {
+            msg!(
+                "Recovery delay active. Completes at: {}, Current time: {}",
+                completes_at,
+                current_time
+            );
+            return Err(StakePoolError::RecoveryDelayActive.into());
+        }
+
+        let previous_authority = self.authority;
+        self.authority = *signer;
+        self.pending_authority = None;
+        self.recovery_authority = None;
+        self.recovery_delay = 0;
+        self.recovery_initiated_at = None;
+        Ok(previous_authority)
+    }
+
+    /// Fail unless `signer` is the configured recovery authority
+    fn check_recovery_authority(&self, signer: &Pubkey) -> ProgramResult {
+        if self.recovery_authority.as_ref() != Some(signer) {
+            msg!("Unauthorized: {} is not the recovery authority", signer);
+            return Err(StakePoolError::Unauthorized.into());
+        }
+        Ok(())
+    }
+
+    /// Roles held by `pubkey` (0 when it holds none)
+    pub fn roles_of(&self, pubkey: &Pubkey) -> u8 {
+        self.role_holders
+            .iter()
+            .flatten()
+            .find(|role_holder| &role_holder.holder == pubkey)
+            .map_or(0, |role_holder| role_holder.roles)
+    }
+
+    /// Check if `pubkey` holds every role in `roles`
+    ///
+    /// Global admins (the main authority and authorized creators) hold every role.
+    pub fn has_roles(&self, pubkey: &Pubkey, roles: u8) -> bool {
+        self.is_authorized(pubkey) || self.roles_of(pubkey) & roles == roles
+    }
+
+    /// Check if `pubkey` holds `role` (global admins hold every role)
+    pub fn has_role(&self, pubkey: &Pubkey, role: Role) -> bool {
+        self.has_roles(pubkey, role.mask())
+    }
+
+    /// Grant `holder` exactly the roles in `roles`, replacing its previous roles
+    ///
+    /// A mask of 0 revokes every role and frees the holder's slot.
+    pub fn set_roles(&mut self, holder: Pubkey, roles: u8) -> Result<(), ProgramError> {
+        if roles & !Role::ALL != 0 {
+            msg!("Unknown role bits: {:#010b}", roles & !Role::ALL);
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+
+        // Main authority holds every role, no need to grant explicitly
+        if holder == self.authority {
+            msg!("Main authority holds every role, cannot set roles explicitly");
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+
+        let existing = self
+            .role_holders
+            .iter()
+            .position(|slot| slot.is_some_and(|role_holder| role_holder.holder == holder));
+
+        let slot = match (existing, roles) {
+            (Some(index), _) => index,
+            (None, 0) => {
+                msg!("Address holds no roles: {}", holder);
+                return Err(StakePoolError::InvalidParameters.into());
+            }
+            (None, _) => self
+                .role_holders
+                .iter()
+                .position(Option::is_none)
+                .ok_or_else(|| {
+                    msg!("Maximum number of role holders reached");
+                    StakePoolError::MaxRoleHoldersReached
+                })?,
+        };
+
+        self.role_holders[slot] = (roles != 0).then_some(RoleHolder { holder, roles });
+        Ok(())
+    }
+
+    /// Fail if the global pause switches block `operation`
+    ///
+    /// `global_pause` blocks every user operation. `withdrawals_only` blocks new
+    /// deposits and claims so principal can still leave the pools.
+    pub fn check_not_paused(&self, operation: UserOperation) -> ProgramResult {
+        if self.global_pause {
+            msg!("Protocol is globally paused. {:?} is disabled.", operation);
+            return Err(StakePoolError::ProtocolPaused.into());
+        }
+        if self.withdrawals_only && operation != UserOperation::Withdrawal {
+            msg!(
+                "Protocol is in withdrawals-only mode. {:?} is disabled.",
+                operation
+            );
+            return Err(StakePoolError::WithdrawalsOnly.into());
+        }
+        Ok(())
+    }
+
+    /// Entry of `creator` in the authorized creators list
+    fn creator_entry(&mut self, creator: &Pubkey) -> Result<&mut AuthorizedCreator, ProgramError> {
+        self.authorized_creators
+            .iter_mut()
+            .find(|entry| &entry.creator == creator)
+            .ok_or_else(|| {
+                msg!("Creator not found in authorized list: {}", creator);
+                StakePoolError::CreatorNotFound.into()
+            })
+    }
+
+    /// Count a pool created by `creator`, failing once its pool limit is reached
+    ///
+    /// The main authority is neither limited nor tracked.
+    pub fn record_pool_created(&mut self, creator: &Pubkey) -> Result<(), ProgramError> {
+        if creator == &self.authority {
+            return Ok(());
+        }
+
+        let entry = self.creator_entry(creator)?;
+        if let Some(limit) = entry.pool_limit {
+            if entry.pools_created >= limit {
+                msg!(
+                    "Creator {} reached its pool limit: {}/{}",
+                    creator,
+                    entry.pools_created,
+                    limit
+                );
+                return Err(StakePoolError::CreatorPoolLimitReached.into());
+            }
+        }
+
+        entry.pools_created = entry
+            .pools_created
+            .checked_add(1)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        Ok(())
+    }
+
+    /// Set the maximum number of pools `creator` may create (None = unlimited)
+    ///
+    /// Returns the number of pools the creator has created so far. A limit
+    /// below that count stops further pool creation without affecting
+    /// existing pools.
+    pub fn set_creator_pool_limit(
+        &mut self,
+        creator: &Pubkey,
+        limit: Option<u16>,
+    ) -> Result<u16, ProgramError> {
+        if creator == &self.authority {
+            msg!("Main authority cannot be given a pool limit");
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+
+        let entry = self.creator_entry(creator)?;
+        entry.pool_limit = limit;
+        Ok(entry.pools_created)
+    }
+
+    /// Validate that the creator list fits `max_creators`
+    /// This prevents data corruption where the list outgrows its configured capacity
+    pub fn validate_creator_count(&self) -> Result<(), ProgramError> {
+        if self.creator_count() > self.max_creators as usize
+            || self.max_creators > Self::MAX_CREATORS
+        {
+            msg!(
+                "Creator count mismatch: {} creators, capacity {}",
+                self.creator_count(),
+                self.max_creators
+            );
+            return Err(StakePoolError::DataCorruption.into());
+        }
+
+        Ok(())
+    }
+
+    /// Check a repeated InitializeProgramAuthority against this program authority
+    ///
+    /// Re-running the setup with the authority and cluster profile it was
+    /// initialized with is a no-op, so deployment scripts can run it again.
+    /// Any other setup fails with `ExpectedEmptyAccount`, as it would otherwise
+    /// leave the existing configuration in place unnoticed.
+    pub fn check_reinitialization(
+        &self,
+        initial_authority: &Pubkey,
+        cluster_profile: ClusterProfile,
+    ) -> ProgramResult {
+        if self.authority != *initial_authority || self.cluster_profile != cluster_profile {
+            msg!(
+                "Program authority already initialized with authority {} ({:?} profile)",
+                self.authority,
+                self.cluster_profile
+            );
+            return Err(StakePoolError::ExpectedEmptyAccount.into());
+        }
+        Ok(())
+    }
+
+    /// Set how many authorized creators the list may hold
+    ///
+    /// The capacity cannot drop below the current creator count or exceed
+    /// `MAX_CREATORS`. The account itself grows as creators are added.
+    pub fn set_max_creators(&mut self, max_creators: u16) -> ProgramResult {
+        if max_creators > Self::MAX_CREATORS {
+            msg!(
+                "Creator capacity too high: {}. Maximum: {}",
+                max_creators,
+                Self::MAX_CREATORS
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        if (max_creators as usize) < self.creator_count() {
+            msg!(
+                "Creator capacity {} below the {} authorized creators",
+                max_creators,
+                self.creator_count()
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+
+        self.max_creators = max_creators;
+        Ok(())
+    }
+
+    /// Add a new authorized creator
+    pub fn add_creator(&mut self, creator: Pubkey) -> Result<(), ProgramError> {
+        // Main authority is always authorized, no need to add explicitly
+        if creator == self.authority {
+            msg!("Main authority is always authorized, cannot add explicitly");
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+
+        // Check if already exists
+        if self.is_authorized(&creator) {
+            msg!("Creator already authorized: {}", creator);
+            return Err(StakePoolError::CreatorAlreadyAuthorized.into());
+        }
+
+        if self.creator_count() >= self.max_creators as usize {
+            msg!("Maximum number of authorized creators reached");
+            return Err(StakePoolError::MaxAuthorizedCreatorsReached.into());
+        }
+
+        self.authorized_creators.push(AuthorizedCreator {
+            creator,
+            pools_created: 0,
+            pool_limit: None,
+        });
+        Ok(())
+    }
+
+    /// Remove an authorized creator
+    ///
+    /// The remaining creators keep their order, pool counts and limits.
+    pub fn remove_creator(&mut self, creator: &Pubkey) -> Result<(), ProgramError> {
+        // Cannot remove the main authority
+        if creator == &self.authority {
+            msg!("Cannot remove main authority from authorized creators");
+            return Err(StakePoolError::CannotRemoveMainAuthority.into());
+        }
+
+        let index = self
+            .authorized_creators
+            .iter()
+            .position(|entry| &entry.creator == creator)
+            .ok_or_else(|| {
+                msg!("Creator not found in authorized list: {}", creator);
+                StakePoolError::CreatorNotFound
+            })?;
+        self.authorized_creators.remove(index);
+        Ok(())
+    }
+}
+
+/// ProgramAuthority in the fixed creator array layout (`Key::ProgramAuthorityV1`)
+///
+/// Only read by `ProgramAuthority::load`, which upgrades it to the current
+/// layout with the default creator capacity. Accounts written before its
+/// trailing fields were added read them as zeros.
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug)]
+pub struct ProgramAuthorityV1 {
+    pub key: Key,
+    pub authority: Pubkey,
+    pub authorized_creators: [Option<Pubkey>; 10],
+    pub creator_count: u8,
+    pub pending_authority: Option<Pubkey>,
+    pub bump: u8,
+    pub cluster_profile: ClusterProfile,
+    pub global_pause: bool,
+    pub withdrawals_only: bool,
+    pub creator_pools_created: [u16; 10],
+    pub creator_pool_limits: [Option<u16>; 10],
+    pub role_holders: [Option<RoleHolder>; 8],
+    pub approval_threshold: u8,
+    pub recovery_authority: Option<Pubkey>,
+    pub recovery_delay: i64,
+    pub recovery_initiated_at: Option<i64>,
+}
+
+impl ProgramAuthorityV1 {
+    // 1 + 32 + (10 * 33) + 1 + 33 + 1 + 1 + 1 + 1 + (10 * 2) + (10 * 3) + (8 * 34) + 1 + 33 + 8 + 9
+    pub const LEN: usize = 774;
+}
+
+impl From<ProgramAuthorityV1> for ProgramAuthority {
+    fn from(authority: ProgramAuthorityV1) -> Self {
+        let authorized_creators = (0..authority.authorized_creators.len())
+            .filter_map(|slot| {
+                authority.authorized_creators[slot].map(|creator| AuthorizedCreator {
+                    creator,
+                    pools_created: authority.creator_pools_created[slot],
+                    pool_limit: authority.creator_pool_limits[slot],
+                })
+            })
+            .collect();
+
+        Self {
+            key: Key::ProgramAuthorityV2,
+            authority: authority.authority,
+            pending_authority: authority.pending_authority,
+            bump: authority.bump,
+            cluster_profile: authority.cluster_profile,
+            global_pause: authority.global_pause,
+            withdrawals_only: authority.withdrawals_only,
+            role_holders: authority.role_holders,
+            approval_threshold: authority.approval_threshold,
+            recovery_authority: authority.recovery_authority,
+            recovery_delay: authority.recovery_delay,
+            recovery_initiated_at: authority.recovery_initiated_at,
+            max_creators: Self::DEFAULT_MAX_CREATORS,
+            authorized_creators,
+        }
+    }
+}
+
+/// Per-stake-mint registry shared by every pool of that mint
+///
+/// Tracks the program's total exposure to a single token across all pools so
+/// risk management can bound it with a total value locked cap, however many
+/// pools creators spin up. Created with the mint's first pool.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct MintRegistry {
+    pub key: Key,
+    /// The stake mint this registry tracks
+    pub stake_mint: Pubkey,
+    /// Sum of `total_staked` over every pool of the stake mint
+    pub total_staked: u64,
+    /// Maximum `total_staked` accepted by new stakes (None = uncapped)
+    pub tvl_cap: Option<u64>,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl MintRegistry {
+    // Size calculation:
+    // - key (Key enum): 1 byte
+    // - stake_mint (Pubkey): 32 bytes
+    // - total_staked (u64): 8 bytes
+    // - tvl_cap (Option<u64>): 9 bytes
+    // - bump (u8): 1 byte
+    // Total: 1 + 32 + 8 + 9 + 1 = 51 bytes
+    pub const LEN: usize = 1 + 32 + 8 + 9 + 1;
+
+    pub fn seeds(stake_mint: &Pubkey) -> Vec<Vec<u8>> {
+        vec![b"mint_registry".to_vec(), stake_mint.to_bytes().to_vec()]
+    }
+
+    pub fn find_pda(stake_mint: &Pubkey) -> (Pubkey, u8) {
+        let seeds: Vec<&[u8]> = vec![b"mint_registry", stake_mint.as_ref()];
+        Pubkey::find_program_address(&seeds, &crate::ID)
+    }
+
+    pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
+        let registry = validate_and_deserialize::<Self>(account, "MintRegistry")?;
+
+        // Verify discriminator matches expected type
+        if !matches!(registry.key, Key::MintRegistry) {
+            msg!("Invalid MintRegistry discriminator");
+            return Err(StakePoolError::InvalidAccountDiscriminator.into());
+        }
+
+        Ok(registry)
+    }
+
+    pub fn save(&self, account: &AccountInfo) -> ProgramResult {
+        save_account_data(account, self, "MintRegistry")
+    }
+
+    /// Fail if staking `amount` more would take the mint past its TVL cap
+    pub fn check_capacity(&self, amount: u64) -> Result<(), ProgramError> {
+        let Some(cap) = self.tvl_cap else {
+            return Ok(());
+        };
+
+        let total_after = self
+            .total_staked
+            .checked_add(amount)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+
+        if total_after > cap {
+            msg!(
+                "TVL cap exceeded for mint {}: staked {}, new stake {}, cap {}",
+                self.stake_mint,
+                self.total_staked,
+                amount,
+                cap
+            );
+            return Err(StakePoolError::TvlCapExceeded.into());
+        }
+
+        Ok(())
+    }
+
+    /// Record tokens that entered one of the mint's stake vaults
+    pub fn record_stake(&mut self, amount: u64) -> Result<(), ProgramError> {
+        self.total_staked = self
+            .total_staked
+            .checked_add(amount)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        Ok(())
+    }
+
+    /// Record tokens that left one of the mint's stake vaults
+    ///
+    /// Saturates so stakes in pools that predate the registry can still be withdrawn.
+    pub fn record_unstake(&mut self, amount: u64) {
+        self.total_staked = self.total_staked.saturating_sub(amount);
+    }
+}
+
+/// A pool listed in the pool registry
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq)]
+pub struct PoolRegistryEntry {
+    pub pool: Pubkey,
+    pub stake_mint: Pubkey,
+    pub pool_id: u64,
+}
+
+/// One page of the program-wide pool registry
+///
+/// Lets wallets list every pool by reading the pages at
+/// ["pool_registry", page] in order, without a getProgramAccounts scan.
+/// Pages are filled in order: a page is only created once the previous one is
+/// full, so the first missing page ends the list. Closed pools keep their entry;
+/// wallets skip entries whose pool account no longer exists.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct PoolRegistry {
+    pub key: Key,
+    /// Page number, starting at 0
+    pub page: u32,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    /// Pools in registration order (at most PAGE_CAPACITY)
+    pub pools: Vec<PoolRegistryEntry>,
+}
+
+impl PoolRegistry {
+    /// Pools listed per page
+    pub const PAGE_CAPACITY: usize = 32;
+
+    // Size calculation:
+    // - key (Key enum): 1 byte
+    // - page (u32): 4 bytes
+    // - bump (u8): 1 byte
+    // - pools (Vec<PoolRegistryEntry>): 4 byte length + PAGE_CAPACITY * (32 + 32 + 8) bytes
+    // Total: 1 + 4 + 1 + 4 + 32 * 72 = 2314 bytes
+    pub const LEN: usize = 1 + 4 + 1 + 4 + Self::PAGE_CAPACITY * (32 + 32 + 8);
+
+    pub fn seeds(page: u32) -> Vec<Vec<u8>> {
+        vec![b"pool_registry".to_vec(), page.to_le_bytes().to_vec()]
+    }
+
+    pub fn find_pda(page: u32) -> (Pubkey, u8) {
+        let page_bytes = page.to_le_bytes();
+        let seeds: Vec<&[u8]> = vec![b"pool_registry", &page_bytes];
+        Pubkey::find_program_address(&seeds, &crate::ID)
+    }
+
+    pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
+        let registry = validate_and_deserialize::<Self>(account, "PoolRegistry")?;
+
+        // Verify discriminator matches expected type
+        if !matches!(registry.key, Key::PoolRegistry) {
+            msg!("Invalid PoolRegistry discriminator");
+            return Err(StakePoolError::InvalidAccountDiscriminator.into());
+        }
+
+        Ok(registry)
+    }
+
+    pub fn save(&self, account: &AccountInfo) -> ProgramResult {
+        save_account_data(account, self, "PoolRegistry")
+    }
+
+    /// Whether the page has no room left (the next pool goes to the next page)
+    pub fn is_full(&self) -> bool {
+        self.pools.len() >= Self::PAGE_CAPACITY
+    }
+
+    /// Append a pool, failing if the page is full
+    pub fn push(&mut self, entry: PoolRegistryEntry) -> ProgramResult {
+        if self.is_full() {
+            msg!(
+                "Pool registry page {} is full; use page {}",
+                self.page,
+                self.page.saturating_add(1)
+            );
+            return Err(StakePoolError::PoolRegistryPageFull.into());
+        }
+        self.pools.push(entry);
+        Ok(())
+    }
+}
+
+/// Pool parameter changes that need multi-admin approval once the
+/// ProgramAuthority's approval threshold is set (None leaves the parameter unchanged)
+#[derive(Clone, Copy, BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+pub struct SensitivePoolUpdate {
+    pub reward_rate: Option<u64>,
+    pub is_paused: Option<bool>,
+    pub pool_end_date: Option<Option<i64>>,
+}
+
+impl SensitivePoolUpdate {
+    /// Whether the update changes nothing
+    pub fn is_empty(&self) -> bool {
+        self.reward_rate.is_none() && self.is_paused.is_none() && self.pool_end_date.is_none()
+    }
+}
+
+/// A sensitive pool update awaiting approval by several global admins
+///
+/// Created by ProposePoolUpdate with the proposer's approval, approved by other
+/// admins with ApprovePoolUpdate and applied and closed by ExecutePoolUpdate once
+/// the ProgramAuthority's approval threshold is met. Approvals are counted at
+/// execution, so those of admins removed since then no longer count.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct PendingAction {
+    pub key: Key,
+    /// The pool the update applies to
+    pub pool: Pubkey,
+    /// The admin who proposed the update (receives the rent back)
+    pub proposer: Pubkey,
+    /// Proposer-chosen id, so several updates of a pool can be pending
+    pub action_id: u64,
+    /// The proposed parameter changes
+    pub update: SensitivePoolUpdate,
+    /// Admins who approved, in approval order (the proposer first)
+    pub approvals: [Option<Pubkey>; 11],
+    /// Unix timestamp of the proposal
+    pub created_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl PendingAction {
+    /// Approvals a pending action records, which bounds the approval threshold
+    /// (one per global admin of a program authority with the default creator capacity)
+    pub const MAX_APPROVALS: usize = ProgramAuthority::DEFAULT_MAX_CREATORS as usize + 1;
+
+    // Size calculation:
+    // - key (Key enum): 1 byte
+    // - pool (Pubkey): 32 bytes
+    // - proposer (Pubkey): 32 bytes
+    // - action_id (u64): 8 bytes
+    // - update (SensitivePoolUpdate): 9 + 2 + 10 = 21 bytes
+    // - approvals (11 x Option<Pubkey>): 11 * 33 = 363 bytes
+    // - created_at (i64): 8 bytes
+    // - bump (u8): 1 byte
+    // Total: 1 + 32 + 32 + 8 + 21 + 363 + 8 + 1 = 466 bytes
+    pub const LEN: usize = 1 + 32 + 32 + 8 + (9 + 2 + 10) + (Self::MAX_APPROVALS * 33) + 8 + 1;
+
+    pub fn seeds(pool: &Pubkey, action_id: u64) -> Vec<Vec<u8>> {
+        vec![
+            b"pending_action".to_vec(),
+            pool.to_bytes().to_vec(),
+            action_id.to_le_bytes().to_vec(),
+        ]
+    }
+
+    pub fn find_pda(pool: &Pubkey, action_id: u64) -> (Pubkey, u8) {
+        let action_id_bytes = action_id.to_le_bytes();
+        let seeds: Vec<&[u8]> = vec![b"pending_action", pool.as_ref(), &action_id_bytes];
+        Pubkey::find_program_address(&seeds, &crate::ID)
+    }
+
+    pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
+        let pending_action = validate_and_deserialize::<Self>(account, "PendingAction")?;
+
+        // Verify discriminator matches expected type
+        if !matches!(pending_action.key, Key::PendingAction) {
+            msg!("Invalid PendingAction discriminator");
+            return Err(StakePoolError::InvalidAccountDiscriminator.into());
+        }
+
+        Ok(pending_action)
+    }
+
+    pub fn save(&self, account: &AccountInfo) -> ProgramResult {
+        save_account_data(account, self, "PendingAction")
+    }
+
+    /// Record `approver`'s approval, returning the number of approvals recorded
+    pub fn approve(&mut self, approver: &Pubkey) -> Result<usize, ProgramError> {
+        if self.approvals.iter().flatten().any(|a| a == approver) {
+            msg!("{} already approved this update", approver);
+            return Err(StakePoolError::AlreadyApproved.into());
+        }
+
+        // Only reachable when admins that approved were since replaced
+        let slot = self
+            .approvals
+            .iter()
+            .position(Option::is_none)
+            .ok_or_else(|| {
+                msg!("No approval slot left; propose the update again");
+                StakePoolError::InvalidParameters
+            })?;
+        self.approvals[slot] = Some(*approver);
+        Ok(slot + 1)
+    }
+
+    /// Approvals from addresses that are still global admins
+    pub fn valid_approvals(&self, program_authority: &ProgramAuthority) -> usize {
+        self.approvals
+            .iter()
+            .flatten()
+            .filter(|approver| program_authority.is_authorized(approver))
+            .count()
+    }
+}
+
+/// One page of a pool's staker allowlist
+///
+/// While the pool's `allowlist_required` flag is set, `stake`, `stake_sol` and
+/// `stake_batch` only accept owners listed in one of the pool's pages at
+/// ["pool_allowlist", pool, page]; the staker passes the page listing them.
+/// Pages are managed by global admins with ManagePoolAllowlist and may be used
+/// in any order, so entries can be grouped (by cohort, say) as admins see fit.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct PoolAllowlist {
+    pub key: Key,
+    /// The pool the page belongs to
+    pub pool: Pubkey,
+    /// Page number
+    pub page: u32,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    /// Allowed stake owners (at most PAGE_CAPACITY)
+    pub members: Vec<Pubkey>,
+}
+
+impl PoolAllowlist {
+    /// Owners listed per page
+    pub const PAGE_CAPACITY: usize = 64;
+
+    // Size calculation:
+    // - key (Key enum): 1 byte
+    // - pool (Pubkey): 32 bytes
+    // - page (u32): 4 bytes
+    // - bump (u8): 1 byte
+    // - members (Vec<Pubkey>): 4 byte length + PAGE_CAPACITY * 32 bytes
+    // Total: 1 + 32 + 4 + 1 + 4 + 64 * 32 = 2090 bytes
+    pub const LEN: usize = 1 + 32 + 4 + 1 + 4 + Self::PAGE_CAPACITY * 32;
+
+    pub fn seeds(pool: &Pubkey, page: u32) -> Vec<Vec<u8>> {
+        vec![
+            b"pool_allowlist".to_vec(),
+            pool.to_bytes().to_vec(),
+            page.to_le_bytes().to_vec(),
+        ]
+    }
+
+    pub fn find_pda(pool: &Pubkey, page: u32) -> (Pubkey, u8) {
+        let page_bytes = page.to_le_bytes();
+        let seeds: Vec<&[u8]> = vec![b"pool_allowlist", pool.as_ref(), &page_bytes];
+        Pubkey::find_program_address(&seeds, &crate::ID)
+    }
+
+    pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
+        let allowlist = validate_and_deserialize::<Self>(account, "PoolAllowlist")?;
+
+        // Verify discriminator matches expected type
+        if !matches!(allowlist.key, Key::PoolAllowlist) {
+            msg!("Invalid PoolAllowlist discriminator");
+            return Err(StakePoolError::InvalidAccountDiscriminator.into());
+        }
+
+        Ok(allowlist)
+    }
+
+    pub fn save(&self, account: &AccountInfo) -> ProgramResult {
+        save_account_data(account, self, "PoolAllowlist")
+    }
+
+    /// Whether `owner` is listed on this page
+    pub fn contains(&self, owner: &Pubkey) -> bool {
+        self.members.contains(owner)
+    }
+
+    /// Remove `remove` then add `add`, skipping owners already in the wanted state
+    ///
+    /// Fails without changes if the additions do not fit in the page.
+    pub fn update(&mut self, add: &[Pubkey], remove: &[Pubkey]) -> ProgramResult {
+        let mut members = self.members.clone();
+        members.retain(|member| !remove.contains(member));
+        for owner in add {
+            if !members.contains(owner) {
+                members.push(*owner);
+            }
+        }
+
+        if members.len() > Self::PAGE_CAPACITY {
+            msg!(
+                "Pool allowlist page {} would hold {} owners. Maximum: {}",
+                self.page,
+                members.len(),
+                Self::PAGE_CAPACITY
+            );
+            return Err(StakePoolError::PoolAllowlistPageFull.into());
+        }
+
+        self.members = members;
+        Ok(())
+    }
+}
+
+/// A proposed slash of a stake account, executable once its timelock has passed
+///
+/// Created by ProposeSlash at ["slash_proposal", stake_account], which also
+/// freezes the stake's principal. ExecuteSlash moves `slash_bps` of the principal
+/// out of the stake vault after `executable_at`; CancelSlash drops the proposal.
+/// Either closes it and returns the rent to the proposer.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct SlashProposal {
+    pub key: Key,
+    /// The pool of the stake account
+    pub pool: Pubkey,
+    /// The stake account to slash
+    pub stake_account: Pubkey,
+    /// The admin who proposed the slash (receives the rent back)
+    pub proposer: Pubkey,
+    /// Share of the principal to slash, in basis points
+    pub slash_bps: u16,
+    /// Stake-mint token account receiving the slashed tokens, the pool's reward
+    /// vault when `None`
+    pub treasury: Option<Pubkey>,
+    /// Unix timestamp from which the slash can be executed
+    pub executable_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl SlashProposal {
+    // Size calculation:
+    // - key (Key enum): 1 byte
+    // - pool (Pubkey): 32 bytes
+    // - stake_account (Pubkey): 32 bytes
+    // - proposer (Pubkey): 32 bytes
+    // - slash_bps (u16): 2 bytes
+    // - treasury (Option<Pubkey>): 33 bytes
+    // - executable_at (i64): 8 bytes
+    // - bump (u8): 1 byte
+    // Total: 1 + 32 + 32 + 32 + 2 + 33 + 8 + 1 = 141 bytes
+    pub const LEN: usize = 1 + 32 + 32 + 32 + 2 + 33 + 8 + 1;
+
+    pub fn seeds(stake_account: &Pubkey) -> Vec<Vec<u8>> {
+        vec![
+            b"slash_proposal".to_vec(),
+            stake_account.to_bytes().to_vec(),
+        ]
+    }
+
+    pub fn find_pda(stake_account: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"slash_proposal", stake_account.as_ref()], &crate::ID)
+    }
+
+    pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
+        let proposal = validate_and_deserialize::<Self>(account, "SlashProposal")?;
+
+        // Verify discriminator matches expected type
+        if !matches!(proposal.key, Key::SlashProposal) {
+            msg!("Invalid SlashProposal discriminator");
+            return Err(StakePoolError::InvalidAccountDiscriminator.into());
+        }
+
+        Ok(proposal)
+    }
+
+    pub fn save(&self, account: &AccountInfo) -> ProgramResult {
+        save_account_data(account, self, "SlashProposal")
+    }
+
+    /// Timestamp from which a slash proposed at `current_time` can be executed
+    pub fn executable_after(current_time: i64) -> Result<i64, ProgramError> {
+        current_time
+            .checked_add(SLASH_TIMELOCK)
+            .ok_or_else(|| StakePoolError::NumericalOverflow.into())
+    }
+
+    /// Fail while the timelock has not passed at `current_time`
+    pub fn check_executable(&self, current_time: i64) -> ProgramResult {
+        if current_time < self.executable_at {
+            msg!(
+                "Slash timelock active. Executable at: {}, Current time: {}",
+                self.executable_at,
+                current_time
+            );
+            return Err(StakePoolError::SlashTimelockActive.into());
+        }
+        Ok(())
+    }
+}
+
+/// Deployment-wide configuration at ["program_config"]
+///
+/// Records the program version that last wrote it and the enabled feature
+/// flags for clients and indexers, and holds the limits pool creation and
+/// updates are validated against. The limits can only tighten the built-in
+/// MAX_REWARD_RATE and MIN_LOCKUP_PERIOD; until InitializeProgramConfig runs,
+/// processors use those built-in values (see `ProgramConfig::default`).
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct ProgramConfig {
+    pub key: Key,
+    /// Program version (major, minor, patch) that last initialized or updated the config
+    pub version: [u16; 3],
+    /// Enabled feature flags, one bit per feature
+    pub feature_flags: u64,
+    /// Highest reward rate a pool may be created or updated with
+    pub max_reward_rate: u64,
+    /// Shortest lockup period a pool may be created with
+    pub min_lockup_period: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Default for ProgramConfig {
+    /// The built-in limits, used while no config account is initialized
+    fn default() -> Self {
+        Self {
+            key: Key::ProgramConfig,
+            version: PROGRAM_VERSION,
+            feature_flags: 0,
+            max_reward_rate: MAX_REWARD_RATE,
+            min_lockup_period: MIN_LOCKUP_PERIOD,
+            bump: 0,
+        }
+    }
+}
+
+impl ProgramConfig {
+    // Size calculation:
+    // - key (Key enum): 1 byte
+    // - version ([u16; 3]): 6 bytes
+    // - feature_flags (u64): 8 bytes
+    // - max_reward_rate (u64): 8 bytes
+    // - min_lockup_period (i64): 8 bytes
+    // - bump (u8): 1 byte
+    // Total: 1 + 6 + 8 + 8 + 8 + 1 = 32 bytes
+    pub const LEN: usize = 1 + 6 + 8 + 8 + 8 + 1;
+
+    pub fn seeds() -> Vec<Vec<u8>> {
+        vec![b"program_config".to_vec()]
+    }
+
+    pub fn find_pda() -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"program_config"], &crate::ID)
+    }
+
+    pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
+        let config = validate_and_deserialize::<Self>(account, "ProgramConfig")?;
+
+        // Verify discriminator matches expected type
+        if !matches!(config.key, Key::ProgramConfig) {
+            msg!("Invalid ProgramConfig discriminator");
+            return Err(StakePoolError::InvalidAccountDiscriminator.into());
+        }
+
+        Ok(config)
+    }
+
+    pub fn save(&self, account: &AccountInfo) -> ProgramResult {
+        save_account_data(account, self, "ProgramConfig")
+    }
+
+    /// Whether every bit of `flag` is enabled
+    pub fn has_feature(&self, flag: u64) -> bool {
+        self.feature_flags & flag == flag
+    }
+
+    /// Set the pool limits, which may only tighten the built-in ones
+    pub fn set_limits(&mut self, max_reward_rate: u64, min_lockup_period: i64) -> ProgramResult {
+        if max_reward_rate > MAX_REWARD_RATE {
+            msg!(
+                "Max reward rate too high: {}. Maximum: {}",
+                max_reward_rate,
+                MAX_REWARD_RATE
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+        if min_lockup_period < MIN_LOCKUP_PERIOD {
+            msg!(
+                "Min lockup period too short: {} seconds. Minimum: {} seconds",
+                min_lockup_period,
+                MIN_LOCKUP_PERIOD
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+
+        self.max_reward_rate = max_reward_rate;
+        self.min_lockup_period = min_lockup_period;
+        Ok(())
+    }
+}
+
+/// A reward stream dripping a funder's tokens into a pool's reward vault
+///
+/// Created by CreateRewardStream at ["reward_stream", pool, funder]. The funder's
+/// token account delegates the whole stream to this PDA up front, and the
+/// permissionless CrankRewardStream moves whatever has accrued at
+/// `rate_per_second` since `last_streamed_at` (up to `end_ts`) into the reward
+/// vault, where it counts towards the stake-time solvency check.
+/// CloseRewardStream revokes the delegation and returns the rent to the funder.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct RewardStream {
+    pub key: Key,
+    /// The pool receiving the rewards
+    pub pool: Pubkey,
+    /// The funder who created the stream (receives the rent back)
+    pub funder: Pubkey,
+    /// Reward-mint token account the rewards are streamed from
+    pub funder_token_account: Pubkey,
+    /// Reward tokens streamed per second
+    pub rate_per_second: u64,
+    /// Unix timestamp up to which rewards have been streamed
+    pub last_streamed_at: i64,
+    /// Unix timestamp at which the stream stops accruing
+    pub end_ts: i64,
+    /// Total reward tokens streamed into the vault so far
+    pub total_streamed: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl RewardStream {
+    // Size calculation:
+    // - key (Key enum): 1 byte
+    // - pool (Pubkey): 32 bytes
+    // - funder (Pubkey): 32 bytes
+    // - funder_token_account (Pubkey): 32 bytes
+    // - rate_per_second (u64): 8 bytes
+    // - last_streamed_at (i64): 8 bytes
+    // - end_ts (i64): 8 bytes
+    // - total_streamed (u64): 8 bytes
+    // - bump (u8): 1 byte
+    // Total: 1 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 1 = 130 bytes
+    pub const LEN: usize = 1 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 1;
+
+    pub fn seeds(pool: &Pubkey, funder: &Pubkey) -> Vec<Vec<u8>> {
+        vec![
+            b"reward_stream".to_vec(),
+            pool.to_bytes().to_vec(),
+            funder.to_bytes().to_vec(),
+        ]
+    }
+
+    pub fn find_pda(pool: &Pubkey, funder: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"reward_stream", pool.as_ref(), funder.as_ref()],
+            &crate::ID,
+        )
+    }
+
+    pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
+        let stream = validate_and_deserialize::<Self>(account, "RewardStream")?;
+
+        // Verify discriminator matches expected type
+        if !matches!(stream.key, Key::RewardStream) {
+            msg!("Invalid RewardStream discriminator");
+            return Err(StakePoolError::InvalidAccountDiscriminator.into());
+        }
+
+        Ok(stream)
     }
 
     pub fn save(&self, account: &AccountInfo) -> ProgramResult {
-        save_account_data(account, self, "StakeAccount")
+        save_account_data(account, self, "RewardStream")
+    }
+
+    /// Total amount a stream running from `start` to `end_ts` at
+    /// `rate_per_second` delivers
+    pub fn total_amount(
+        rate_per_second: u64,
+        start: i64,
+        end_ts: i64,
+    ) -> Result<u64, ProgramError> {
+        let duration = end_ts.saturating_sub(start).max(0) as u64;
+        rate_per_second
+            .checked_mul(duration)
+            .ok_or_else(|| StakePoolError::NumericalOverflow.into())
+    }
+
+    /// Amount accrued since the last crank at `current_time`, and the timestamp
+    /// it accrued up to
+    pub fn due_amount(&self, current_time: i64) -> Result<(u64, i64), ProgramError> {
+        let until = current_time.min(self.end_ts).max(self.last_streamed_at);
+        let amount = Self::total_amount(self.rate_per_second, self.last_streamed_at, until)?;
+        Ok((amount, until))
+    }
+
+    /// Amount still to be streamed until `end_ts`
+    pub fn remaining_amount(&self) -> Result<u64, ProgramError> {
+        Self::total_amount(self.rate_per_second, self.last_streamed_at, self.end_ts)
     }
 }
 
-/// Program authority configuration for managing pool creation permissions
-/// This account controls who can create new stake pools
+/// Directory of an owner's stake indices in one pool
+///
+/// Created at ["stake_position", pool, owner] by the first stake that passes it,
+/// and kept up to date by the stake instructions and CloseStakeAccount given the
+/// account. `next_index` is one past the highest index ever recorded, so it is
+/// free as long as every stake of the owner went through the directory.
+/// GetStakePosition returns the directory as a `StakePositions`.
 #[repr(C)]
 #[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
-pub struct ProgramAuthority {
+pub struct StakePosition {
     pub key: Key,
-    /// The main authority who can manage the authorized creators list
-    pub authority: Pubkey,
-    /// List of addresses authorized to create pools
-    /// Using a fixed-size array for predictable memory layout
-    pub authorized_creators: [Option<Pubkey>; 10],
-    /// Number of active authorized creators (for iteration)
-    pub creator_count: u8,
-    /// Pending authority for two-step authority transfer (None if no transfer pending)
-    pub pending_authority: Option<Pubkey>,
+    /// The pool the stake accounts belong to
+    pub pool: Pubkey,
+    /// The owner of the stake accounts
+    pub owner: Pubkey,
+    /// One past the highest index recorded
+    pub next_index: u64,
     /// Bump seed for PDA derivation
     pub bump: u8,
+    /// Indices of the owner's open stake accounts, ascending (at most CAPACITY)
+    pub indices: Vec<u64>,
 }
 
-impl ProgramAuthority {
+impl StakePosition {
+    /// Open stake accounts a directory records
+    pub const CAPACITY: usize = 64;
+
+    // Size calculation:
+    // - key (Key enum): 1 byte
+    // - pool (Pubkey): 32 bytes
+    // - owner (Pubkey): 32 bytes
+    // - next_index (u64): 8 bytes
+    // - bump (u8): 1 byte
+    // - indices (Vec<u64>): 4 byte length + CAPACITY * 8 bytes
+    // Total: 1 + 32 + 32 + 8 + 1 + 4 + 64 * 8 = 590 bytes
+    pub const LEN: usize = 1 + 32 + 32 + 8 + 1 + 4 + Self::CAPACITY * 8;
+
+    pub fn seeds(pool: &Pubkey, owner: &Pubkey) -> Vec<Vec<u8>> {
+        vec![
+            b"stake_position".to_vec(),
+            pool.to_bytes().to_vec(),
+            owner.to_bytes().to_vec(),
+        ]
+    }
+
+    pub fn find_pda(pool: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"stake_position", pool.as_ref(), owner.as_ref()],
+            &crate::ID,
+        )
+    }
+
+    pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
+        let position = validate_and_deserialize::<Self>(account, "StakePosition")?;
+
+        // Verify discriminator matches expected type
+        if !matches!(position.key, Key::StakePosition) {
+            msg!("Invalid StakePosition discriminator");
+            return Err(StakePoolError::InvalidAccountDiscriminator.into());
+        }
+
+        Ok(position)
+    }
+
+    pub fn save(&self, account: &AccountInfo) -> ProgramResult {
+        save_account_data(account, self, "StakePosition")
+    }
+
+    /// Record the stake account at `index`, failing if the directory is full
+    pub fn record(&mut self, index: u64) -> ProgramResult {
+        if let Err(position) = self.indices.binary_search(&index) {
+            if self.indices.len() >= Self::CAPACITY {
+                msg!(
+                    "Stake position of {} is full ({} open stakes)",
+                    self.owner,
+                    Self::CAPACITY
+                );
+                return Err(StakePoolError::StakePositionFull.into());
+            }
+            self.indices.insert(position, index);
+        }
+        self.next_index = self.next_index.max(index.saturating_add(1));
+        Ok(())
+    }
+
+    /// Forget the stake account at `index` (closed); `next_index` is kept
+    pub fn release(&mut self, index: u64) {
+        if let Ok(position) = self.indices.binary_search(&index) {
+            self.indices.remove(position);
+        }
+    }
+}
+
+/// Programs pools may register as their hook, at ["hook_allowlist"]
+///
+/// Managed by global admins with ManageHookAllowlist. SetPoolHook only accepts
+/// listed programs; removing a program does not detach it from the pools
+/// already calling it (see `crate::hook`).
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct HookAllowlist {
+    pub key: Key,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    /// Allowed hook programs (at most CAPACITY)
+    pub programs: Vec<Pubkey>,
+}
+
+impl HookAllowlist {
+    /// Hook programs the allowlist holds
+    pub const CAPACITY: usize = 16;
+
     // Size calculation:
     // - key (Key enum): 1 byte
-    // - authority (Pubkey): 32 bytes
-    // - authorized_creators (10 x Option<Pubkey>): 10 * 33 = 330 bytes (1 byte discriminator + 32 bytes pubkey)
-    // - creator_count (u8): 1 byte
-    // - pending_authority (Option<Pubkey>): 1 byte when None, 33 bytes when Some
     // - bump (u8): 1 byte
-    // Total: 1 + 32 + 330 + 1 + 33 + 1 = 398 bytes
-    pub const LEN: usize = 1 + 32 + (10 * 33) + 1 + 33 + 1;
-    pub const MAX_CREATORS: usize = 10;
+    // - programs (Vec<Pubkey>): 4 byte length + CAPACITY * 32 bytes
+    // Total: 1 + 1 + 4 + 16 * 32 = 518 bytes
+    pub const LEN: usize = 1 + 1 + 4 + Self::CAPACITY * 32;
 
     pub fn seeds() -> Vec<Vec<u8>> {
-        vec![b"program_authority".to_vec()]
+        vec![b"hook_allowlist".to_vec()]
     }
 
     pub fn find_pda() -> (Pubkey, u8) {
-        let seeds: Vec<&[u8]> = vec![b"program_authority"];
-        Pubkey::find_program_address(&seeds, &crate::ID)
+        Pubkey::find_program_address(&[b"hook_allowlist"], &crate::ID)
     }
 
     pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
-        let program_authority = validate_and_deserialize::<Self>(account, "ProgramAuthority")?;
+        let allowlist = validate_and_deserialize::<Self>(account, "HookAllowlist")?;
 
         // Verify discriminator matches expected type
-        if !matches!(program_authority.key, Key::ProgramAuthority) {
-            msg!("Invalid ProgramAuthority discriminator");
+        if !matches!(allowlist.key, Key::HookAllowlist) {
+            msg!("Invalid HookAllowlist discriminator");
             return Err(StakePoolError::InvalidAccountDiscriminator.into());
         }
 
-        // Validate creator count matches actual array contents
-        program_authority.validate_creator_count()?;
-
-        Ok(program_authority)
+        Ok(allowlist)
     }
 
     pub fn save(&self, account: &AccountInfo) -> ProgramResult {
-        save_account_data(account, self, "ProgramAuthority")
+        save_account_data(account, self, "HookAllowlist")
     }
 
-    /// Check if a given pubkey is authorized to create pools
-    pub fn is_authorized(&self, pubkey: &Pubkey) -> bool {
-        // Main authority is always authorized
-        if pubkey == &self.authority {
-            return true;
+    /// Whether `program` may be registered as a pool's hook
+    pub fn contains(&self, program: &Pubkey) -> bool {
+        self.programs.contains(program)
+    }
+
+    /// Remove `remove` then add `add`, skipping programs already in the wanted state
+    ///
+    /// Fails without changes if the additions do not fit or include this program:
+    /// a pool calling itself back would re-enter its own instructions.
+    pub fn update(&mut self, add: &[Pubkey], remove: &[Pubkey]) -> ProgramResult {
+        if add.contains(&crate::ID) {
+            msg!("The stake pool program cannot be its own hook");
+            return Err(StakePoolError::HookProgramNotAllowed.into());
         }
 
-        // Check authorized creators list
-        for creator in &self.authorized_creators {
-            if let Some(authorized) = creator {
-                if authorized == pubkey {
-                    return true;
-                }
+        let mut programs = self.programs.clone();
+        programs.retain(|program| !remove.contains(program));
+        for program in add {
+            if !programs.contains(program) {
+                programs.push(*program);
             }
         }
 
-        false
+        if programs.len() > Self::CAPACITY {
+            msg!(
+                "Hook allowlist would hold {} programs. Maximum: {}",
+                programs.len(),
+                Self::CAPACITY
+            );
+            return Err(StakePoolError::InvalidParameters.into());
+        }
+
+        self.programs = programs;
+        Ok(())
     }
+}
 
-    /// Validate that creator_count matches the actual number of Some values in authorized_creators
-    /// This prevents data corruption where the count becomes out of sync with the array
-    pub fn validate_creator_count(&self) -> Result<(), ProgramError> {
-        let actual_count = self
-            .authorized_creators
-            .iter()
-            .filter(|c| c.is_some())
-            .count() as u8;
+/// Points an owner accrued in one pool, at ["user_points", pool, owner]
+///
+/// Created by the first SyncPoints of one of the owner's stakes (paid by the
+/// caller) and credited by every sync after it. Points are not tokens: no
+/// instruction transfers or spends them, they only weight off-chain
+/// distributions such as airdrops. GetUserPoints returns them as a `PointsBalance`.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct UserPoints {
+    pub key: Key,
+    /// The pool the points were accrued in
+    pub pool: Pubkey,
+    /// The owner of the stakes that accrued them
+    pub owner: Pubkey,
+    /// Points credited so far
+    pub points: u128,
+    /// Unix timestamp of the latest sync
+    pub last_synced_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
 
-        if actual_count != self.creator_count {
-            msg!(
-                "Creator count mismatch: stored={}, actual={}",
-                self.creator_count,
-                actual_count
-            );
-            return Err(StakePoolError::DataCorruption.into());
+impl UserPoints {
+    // Size calculation:
+    // - key (Key enum): 1 byte
+    // - pool (Pubkey): 32 bytes
+    // - owner (Pubkey): 32 bytes
+    // - points (u128): 16 bytes
+    // - last_synced_at (i64): 8 bytes
+    // - bump (u8): 1 byte
+    // Total: 1 + 32 + 32 + 16 + 8 + 1 = 90 bytes
+    pub const LEN: usize = 1 + 32 + 32 + 16 + 8 + 1;
+
+    pub fn seeds(pool: &Pubkey, owner: &Pubkey) -> Vec<Vec<u8>> {
+        vec![
+            b"user_points".to_vec(),
+            pool.to_bytes().to_vec(),
+            owner.to_bytes().to_vec(),
+        ]
+    }
+
+    pub fn find_pda(pool: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"user_points", pool.as_ref(), owner.as_ref()], &crate::ID)
+    }
+
+    pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
+        let points = validate_and_deserialize::<Self>(account, "UserPoints")?;
+
+        // Verify discriminator matches expected type
+        if !matches!(points.key, Key::UserPoints) {
+            msg!("Invalid UserPoints discriminator");
+            return Err(StakePoolError::InvalidAccountDiscriminator.into());
         }
 
+        Ok(points)
+    }
+
+    pub fn save(&self, account: &AccountInfo) -> ProgramResult {
+        save_account_data(account, self, "UserPoints")
+    }
+
+    /// Credit `points` synced at `current_time`
+    pub fn credit(&mut self, points: u128, current_time: i64) -> ProgramResult {
+        self.points = self
+            .points
+            .checked_add(points)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        self.last_synced_at = current_time;
         Ok(())
     }
+}
 
-    /// Compact the authorized_creators array by moving all Some values to the front
-    /// This prevents fragmentation and improves iteration efficiency
-    fn compact_creators(&mut self) {
-        let mut write_idx = 0;
-        for read_idx in 0..self.authorized_creators.len() {
-            if let Some(creator) = self.authorized_creators[read_idx] {
-                if write_idx != read_idx {
-                    self.authorized_creators[write_idx] = Some(creator);
-                    self.authorized_creators[read_idx] = None;
-                }
-                write_idx += 1;
-            }
+/// Addresses barred from the pools that require it, at ["deny_list"]
+///
+/// Managed by global admins with ManageDenyList. In pools with
+/// `deny_list_required`, listed addresses cannot stake, claim or receive
+/// principal. With `allow_listed_withdrawals`, owners listed after staking may
+/// still withdraw their principal to a token account they own.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct DenyList {
+    pub key: Key,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    /// Whether listed owners may still withdraw their principal to their own token accounts
+    pub allow_listed_withdrawals: bool,
+    /// Denied addresses, ascending (at most CAPACITY)
+    pub addresses: Vec<Pubkey>,
+}
+
+impl DenyList {
+    /// Addresses the deny list holds
+    pub const CAPACITY: usize = 256;
+
+    // Size calculation:
+    // - key (Key enum): 1 byte
+    // - bump (u8): 1 byte
+    // - allow_listed_withdrawals (bool): 1 byte
+    // - addresses (Vec<Pubkey>): 4 byte length + CAPACITY * 32 bytes
+    // Total: 1 + 1 + 1 + 4 + 256 * 32 = 8199 bytes
+    pub const LEN: usize = 1 + 1 + 1 + 4 + Self::CAPACITY * 32;
+
+    pub fn seeds() -> Vec<Vec<u8>> {
+        vec![b"deny_list".to_vec()]
+    }
+
+    pub fn find_pda() -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"deny_list"], &crate::ID)
+    }
+
+    /// The list before its account is created: nothing denied, withdrawals allowed
+    pub fn empty(bump: u8) -> Self {
+        Self {
+            key: Key::DenyList,
+            bump,
+            allow_listed_withdrawals: true,
+            addresses: Vec::new(),
         }
     }
 
-    /// Add a new authorized creator
-    pub fn add_creator(&mut self, creator: Pubkey) -> Result<(), ProgramError> {
-        // Main authority is always authorized, no need to add explicitly
-        if creator == self.authority {
-            msg!("Main authority is always authorized, cannot add explicitly");
-            return Err(StakePoolError::InvalidParameters.into());
+    pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
+        let deny_list = validate_and_deserialize::<Self>(account, "DenyList")?;
+
+        // Verify discriminator matches expected type
+        if !matches!(deny_list.key, Key::DenyList) {
+            msg!("Invalid DenyList discriminator");
+            return Err(StakePoolError::InvalidAccountDiscriminator.into());
         }
 
-        // Check if already exists
-        for existing_creator in &self.authorized_creators {
-            if let Some(authorized) = existing_creator {
-                if authorized == &creator {
-                    msg!("Creator already authorized: {}", creator);
-                    return Err(StakePoolError::CreatorAlreadyAuthorized.into());
-                }
+        Ok(deny_list)
+    }
+
+    pub fn save(&self, account: &AccountInfo) -> ProgramResult {
+        save_account_data(account, self, "DenyList")
+    }
+
+    /// Whether `address` is denied
+    pub fn contains(&self, address: &Pubkey) -> bool {
+        self.addresses.binary_search(address).is_ok()
+    }
+
+    /// Remove `remove` then add `add`, skipping addresses already in the wanted state
+    ///
+    /// Fails without changes if the additions do not fit.
+    pub fn update(&mut self, add: &[Pubkey], remove: &[Pubkey]) -> ProgramResult {
+        let mut addresses = self.addresses.clone();
+        addresses.retain(|address| !remove.contains(address));
+        for address in add {
+            if let Err(position) = addresses.binary_search(address) {
+                addresses.insert(position, *address);
             }
         }
 
-        // Find empty slot
-        for slot in &mut self.authorized_creators {
-            if slot.is_none() {
-                *slot = Some(creator);
-                self.creator_count = self
-                    .creator_count
-                    .checked_add(1)
-                    .ok_or(StakePoolError::NumericalOverflow)?;
-                return Ok(());
-            }
+        if addresses.len() > Self::CAPACITY {
+            msg!(
+                "Deny list would hold {} addresses. Maximum: {}",
+                addresses.len(),
+                Self::CAPACITY
+            );
+            return Err(StakePoolError::InvalidParameters.into());
         }
 
-        // No empty slots
-        msg!("Maximum number of authorized creators reached");
-        Err(StakePoolError::MaxAuthorizedCreatorsReached.into())
+        self.addresses = addresses;
+        Ok(())
     }
 
-    /// Remove an authorized creator
-    pub fn remove_creator(&mut self, creator: &Pubkey) -> Result<(), ProgramError> {
-        // Cannot remove the main authority
-        if creator == &self.authority {
-            msg!("Cannot remove main authority from authorized creators");
-            return Err(StakePoolError::CannotRemoveMainAuthority.into());
+    /// Fail if `address` (named `role` in the logs) is denied
+    pub fn check(&self, role: &str, address: &Pubkey) -> ProgramResult {
+        if self.contains(address) {
+            msg!("{} {} is on the deny list", role, address);
+            return Err(StakePoolError::AddressDenied.into());
+        }
+        Ok(())
+    }
+
+    /// Fail unless principal of `owner`'s stake may be paid to a token account of `destination`
+    ///
+    /// Listed destinations are refused, and so are listed owners unless
+    /// `allow_listed_withdrawals` lets them withdraw to their own token account.
+    pub fn check_withdrawal(&self, owner: &Pubkey, destination: &Pubkey) -> ProgramResult {
+        if self.allow_listed_withdrawals && destination == owner {
+            return Ok(());
         }
+        self.check("Stake owner", owner)?;
+        self.check("Destination owner", destination)
+    }
+}
 
-        // Find and remove
-        for slot in &mut self.authorized_creators {
-            if let Some(authorized) = slot {
-                if authorized == creator {
-                    *slot = None;
-                    self.creator_count = self
-                        .creator_count
-                        .checked_sub(1)
-                        .ok_or(StakePoolError::NumericalOverflow)?;
+/// Reward sub-vault of one cohort of a pool's stakers, at ["reward_cohort", pool, cohort]
+///
+/// Pools with a `cohort_duration` group their stakes by stake time: every stake
+/// opened in the same `cohort_duration` window reserves and claims its rewards in
+/// the cohort's own token vault, at ["cohort_vault", reward_cohort], so one
+/// cohort's rewards can never pay another's. Created by the first
+/// FundCohortRewards of the cohort (paid by the funder) and closed by
+/// DefundCohortRewards once the pool owes nothing and the vault is empty.
+#[repr(C)]
+#[derive(Clone, BorshSerialize, BorshDeserialize, Debug, ShankAccount)]
+pub struct RewardCohort {
+    pub key: Key,
+    /// The pool the cohort belongs to
+    pub pool: Pubkey,
+    /// Cohort number: stake timestamp / `cohort_duration`
+    pub cohort: u64,
+    /// Rewards received by the cohort vault through FundCohortRewards
+    pub rewards_funded: u64,
+    /// Rewards reserved for the cohort's stakes and not yet claimed
+    ///
+    /// Rewards a stake forfeits stay reserved here, since the pool only tracks its
+    /// total owed: they become withdrawable once the pool owes nothing.
+    pub rewards_owed: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+    /// Bump seed of the cohort vault
+    pub vault_bump: u8,
+}
 
-                    // Compact array to prevent fragmentation
-                    self.compact_creators();
+impl RewardCohort {
+    // Size calculation:
+    // - key (Key enum): 1 byte
+    // - pool (Pubkey): 32 bytes
+    // - cohort (u64): 8 bytes
+    // - rewards_funded (u64): 8 bytes
+    // - rewards_owed (u64): 8 bytes
+    // - bump (u8): 1 byte
+    // - vault_bump (u8): 1 byte
+    // Total: 1 + 32 + 8 + 8 + 8 + 1 + 1 = 59 bytes
+    pub const LEN: usize = 1 + 32 + 8 + 8 + 8 + 1 + 1;
 
-                    return Ok(());
-                }
-            }
+    pub fn seeds(pool: &Pubkey, cohort: u64) -> Vec<Vec<u8>> {
+        vec![
+            b"reward_cohort".to_vec(),
+            pool.to_bytes().to_vec(),
+            cohort.to_le_bytes().to_vec(),
+        ]
+    }
+
+    pub fn find_pda(pool: &Pubkey, cohort: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"reward_cohort", pool.as_ref(), &cohort.to_le_bytes()],
+            &crate::ID,
+        )
+    }
+
+    pub fn vault_seeds(reward_cohort: &Pubkey) -> Vec<Vec<u8>> {
+        vec![b"cohort_vault".to_vec(), reward_cohort.to_bytes().to_vec()]
+    }
+
+    pub fn find_vault_pda(reward_cohort: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"cohort_vault", reward_cohort.as_ref()], &crate::ID)
+    }
+
+    pub fn load(account: &AccountInfo) -> Result<Self, ProgramError> {
+        let cohort = validate_and_deserialize::<Self>(account, "RewardCohort")?;
+
+        // Verify discriminator matches expected type
+        if !matches!(cohort.key, Key::RewardCohort) {
+            msg!("Invalid RewardCohort discriminator");
+            return Err(StakePoolError::InvalidAccountDiscriminator.into());
+        }
+
+        Ok(cohort)
+    }
+
+    pub fn save(&self, account: &AccountInfo) -> ProgramResult {
+        save_account_data(account, self, "RewardCohort")
+    }
+
+    /// Reserve `amount` rewards for new stakes of the cohort
+    ///
+    /// Fails with `InsufficientRewards` when the cohort vault, holding
+    /// `vault_balance`, cannot cover them on top of what it already owes.
+    pub fn reserve(&mut self, amount: u64, vault_balance: u64) -> ProgramResult {
+        let required = self
+            .rewards_owed
+            .checked_add(amount)
+            .ok_or(StakePoolError::NumericalOverflow)?;
+        if vault_balance < required {
+            msg!(
+                "Insufficient rewards in cohort {}. Required (total): {}, Available: {}, Already owed: {}",
+                self.cohort,
+                required,
+                vault_balance,
+                self.rewards_owed
+            );
+            return Err(StakePoolError::InsufficientRewards.into());
         }
+        self.rewards_owed = required;
+        Ok(())
+    }
+
+    /// Release `amount` rewards paid out of the cohort vault
+    ///
+    /// Saturates so a claim can never fail on the cohort's bookkeeping.
+    pub fn release(&mut self, amount: u64) {
+        self.rewards_owed = self.rewards_owed.saturating_sub(amount);
+    }
 
-        // Not found
-        msg!("Creator not found in authorized list: {}", creator);
-        Err(StakePoolError::CreatorNotFound.into())
+    /// Cohort vault tokens DefundCohortRewards may withdraw
+    ///
+    /// The balance above `rewards_owed`, or the whole balance once the pool owes
+    /// nothing, which frees the rewards the cohort's stakes forfeited.
+    pub fn surplus(&self, vault_balance: u64, pool_owes_nothing: bool) -> u64 {
+        if pool_owes_nothing {
+            vault_balance
+        } else {
+            vault_balance.saturating_sub(self.rewards_owed)
+        }
     }
 }
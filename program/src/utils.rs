@@ -6,10 +6,14 @@ use solana_program::{
     program_error::ProgramError,
     pubkey::Pubkey,
     rent::Rent,
-    sysvar::Sysvar,
+    sysvar::{clock::Clock, Sysvar},
 };
 use solana_sdk_ids::system_program;
-use spl_token_2022::{extension::StateWithExtensions, instruction::transfer_checked, state::Mint};
+use spl_token_2022::{
+    extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions},
+    instruction::{approve_checked, revoke, sync_native, transfer_checked},
+    state::Mint,
+};
 
 use crate::error::StakePoolError;
 
@@ -157,7 +161,7 @@ pub fn create_account<'a>(
             );
             invoke_signed(
                 &assign_to_system_ix,
-                &[target_account.clone()],
+                std::slice::from_ref(target_account),
                 signer_seeds,
             )?;
         }
@@ -165,11 +169,19 @@ pub fn create_account<'a>(
         // Allocate space (we know current_data_len == 0 at this point)
         let allocate_ix =
             solana_program::system_instruction::allocate(target_account.key, size as u64);
-        invoke_signed(&allocate_ix, &[target_account.clone()], signer_seeds)?;
+        invoke_signed(
+            &allocate_ix,
+            std::slice::from_ref(target_account),
+            signer_seeds,
+        )?;
 
         // Assign to our program
         let assign_ix = solana_program::system_instruction::assign(target_account.key, owner);
-        invoke_signed(&assign_ix, &[target_account.clone()], signer_seeds)?;
+        invoke_signed(
+            &assign_ix,
+            std::slice::from_ref(target_account),
+            signer_seeds,
+        )?;
 
         return Ok(());
     }
@@ -191,11 +203,19 @@ pub fn create_account<'a>(
 
     // Then allocate space
     let allocate_ix = solana_program::system_instruction::allocate(target_account.key, size as u64);
-    invoke_signed(&allocate_ix, &[target_account.clone()], signer_seeds)?;
+    invoke_signed(
+        &allocate_ix,
+        std::slice::from_ref(target_account),
+        signer_seeds,
+    )?;
 
     // Finally assign ownership
     let assign_ix = solana_program::system_instruction::assign(target_account.key, owner);
-    invoke_signed(&assign_ix, &[target_account.clone()], signer_seeds)?;
+    invoke_signed(
+        &assign_ix,
+        std::slice::from_ref(target_account),
+        signer_seeds,
+    )?;
 
     Ok(())
 }
@@ -323,37 +343,114 @@ pub fn transfer_lamports_from_pdas<'a>(
     Ok(())
 }
 
+/// Wrap lamports into a native (wSOL) token account.
+///
+/// Transfers `lamports` from the system-owned `from` account into `native_account`
+/// and syncs the token amount with the account's lamport balance.
+pub fn wrap_sol<'a>(
+    from: &AccountInfo<'a>,
+    native_account: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    lamports: u64,
+) -> ProgramResult {
+    transfer_lamports(from, native_account, lamports, None)?;
+
+    let sync_ix = sync_native(token_program.key, native_account.key)?;
+    invoke(&sync_ix, std::slice::from_ref(native_account))
+}
+
+/// Approve `delegate` to transfer up to `amount` tokens out of `source`
+///
+/// `owner` must sign the enclosing transaction.
+pub fn approve_tokens<'a>(
+    source: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    delegate: &AccountInfo<'a>,
+    owner: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    amount: u64,
+) -> ProgramResult {
+    let mint_data = mint.try_borrow_data()?;
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint_data)?
+        .base
+        .decimals;
+    drop(mint_data);
+
+    let approve_ix = approve_checked(
+        token_program.key,
+        source.key,
+        mint.key,
+        delegate.key,
+        owner.key,
+        &[],
+        amount,
+        decimals,
+    )?;
+    invoke(
+        &approve_ix,
+        &[
+            source.clone(),
+            mint.clone(),
+            delegate.clone(),
+            owner.clone(),
+        ],
+    )
+}
+
+/// Revoke any delegation of `source`
+///
+/// `owner` must sign the enclosing transaction.
+pub fn revoke_tokens<'a>(
+    source: &AccountInfo<'a>,
+    owner: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    let revoke_ix = revoke(token_program.key, source.key, owner.key, &[])?;
+    invoke(&revoke_ix, &[source.clone(), owner.clone()])
+}
+
+/// Amount the recipient of a `transfer_checked` of `amount` receives at `epoch`
+///
+/// Token-2022 mints with the TransferFeeConfig extension withhold the fee of the
+/// current epoch in the destination account; other mints transfer the full amount.
+pub fn transfer_amount_after_fee(
+    mint: &StateWithExtensions<Mint>,
+    amount: u64,
+    epoch: u64,
+) -> Result<u64, ProgramError> {
+    let fee = match mint.get_extension::<TransferFeeConfig>() {
+        Ok(config) => config
+            .calculate_epoch_fee(epoch, amount)
+            .ok_or(StakePoolError::NumericalOverflow)?,
+        Err(_) => 0,
+    };
+    amount
+        .checked_sub(fee)
+        .ok_or_else(|| StakePoolError::NumericalOverflow.into())
+}
+
 /// Transfer tokens with support for Token-2022 transfer fees
-/// Returns the actual amount transferred (which may be less than requested if fees apply)
+/// Returns the actual amount received (which may be less than requested if fees apply)
 ///
 /// # Fee Calculation Method
-/// This function uses balance checking to determine actual transferred amounts.
-/// While this is generally reliable due to Solana's atomic transaction execution,
-/// there is a potential limitation: if the recipient account receives tokens from
-/// another source within the same transaction (via CPI), the calculated
-/// amount would be inflated.
+/// The amount the recipient should receive is computed from the mint's
+/// TransferFeeConfig for the current epoch, and the recipient's balance is read
+/// before and after the transfer to confirm it. Callers record the returned
+/// amount (e.g. in `total_staked`), so the protocol's accounting matches what
+/// actually landed in its vaults.
 ///
-/// However, this is not a practical concern in this protocol because:
-/// - For vault accounts: PDAs are owned by the protocol, so no external deposits are possible
-/// - For user accounts: The TransferHook extension is blocked, preventing CPI injection
-///   during transfers; users control their own accounts but cannot inject code
-///   into the protocol's transaction execution
-///
-/// # Why Balance Checking
-/// 1. Works for both Token and Token-2022 mints uniformly
-/// 2. Accounts for all fee scenarios without complex calculations
-/// 3. Solana's atomic transaction execution prevents concurrent modifications to the same accounts
-/// 4. The protocol design (vault accounts controlled by PDAs) prevents external deposits
+/// # Why Both
+/// 1. The fee calculation is what the token program itself applies, so it is exact
+/// 2. The balance check catches transfers that silently moved a different amount;
+///    any difference from the expected amount is rejected rather than recorded,
+///    including deposits from another source within the same transaction that
+///    would otherwise inflate the balance difference
 ///
 /// # Security Properties
 /// - Vault accounts are PDAs owned by the protocol (no external deposits possible)
-/// - User accounts as recipients are safe because:
-///   * TransferHook extension is blocked during pool initialization, preventing
-///     mid-transfer balance manipulation via custom transfer logic
-///   * Users cannot inject additional CPIs into the protocol's execution flow
-///   * Balance checking occurs within the same atomic transaction
+/// - TransferHook extension is blocked during pool initialization, preventing
+///   mid-transfer balance manipulation via custom transfer logic
 /// - Solana's transaction atomicity prevents concurrent modifications to the same accounts
-/// - Balance differences accurately reflect actual transfer results
 pub fn transfer_tokens_with_fee<'a>(
     from: &AccountInfo<'a>,
     to: &AccountInfo<'a>,
@@ -363,20 +460,15 @@ pub fn transfer_tokens_with_fee<'a>(
     amount: u64,
     signer_seeds: &[&[&[u8]]],
 ) -> Result<u64, ProgramError> {
-    // Safely unpack the mint account to get decimals
+    // Safely unpack the mint account to get decimals and the amount after fees
     let mint_data = mint.try_borrow_data()?;
     let mint_state = StateWithExtensions::<Mint>::unpack(&mint_data)?;
     let decimals = mint_state.base.decimals;
+    let expected_received = transfer_amount_after_fee(&mint_state, amount, Clock::get()?.epoch)?;
     drop(mint_data);
 
-    // Get the recipient's balance before transfer to calculate actual amount received
-    // NOTE: This requires unpacking the account twice (before and after transfer).
-    // While this adds overhead, it's necessary because:
-    // 1. Cannot hold borrow across CPI boundary (transfer instruction)
-    // 2. No way to know if fees apply without checking balance difference
-    // 3. Both Token and Token-2022 use same account structure, so optimization
-    //    would require mint inspection adding similar overhead
-    // 4. Balance checking is the only reliable way to handle all fee scenarios
+    // Get the recipient's balance before transfer to confirm the amount received
+    // (the borrow cannot be held across the transfer CPI)
     let to_data_before = to.try_borrow_data()?;
     let to_account_before =
         StateWithExtensions::<spl_token_2022::state::Account>::unpack(&to_data_before)?;
@@ -403,15 +495,12 @@ pub fn transfer_tokens_with_fee<'a>(
         invoke_signed(&transfer_ix, &accounts, signer_seeds)?;
     }
 
-    // Calculate the actual amount transferred by checking the balance difference
-    // This properly accounts for any transfer fees that may have been deducted
     let to_data_after = to.try_borrow_data()?;
     let to_account_after =
         StateWithExtensions::<spl_token_2022::state::Account>::unpack(&to_data_after)?;
     let balance_after = to_account_after.base.amount;
     drop(to_data_after);
 
-    // Ensure balance increased (or stayed the same for zero transfers)
     // If balance decreased, something unexpected happened - possibly concurrent modifications
     // or an unvalidated extension causing balance changes
     if balance_after < balance_before {
@@ -424,7 +513,7 @@ pub fn transfer_tokens_with_fee<'a>(
     }
 
     // Additional check: if amount > 0, balance must have increased
-    // This catches silent transfer failures that didn't error
+    // This catches silent transfer failures and fees that consume the whole transfer
     if amount > 0 && balance_after == balance_before {
         solana_program::msg!(
             "Error: Transfer of {} tokens resulted in no balance change. Before: {}, After: {}",
@@ -435,8 +524,17 @@ pub fn transfer_tokens_with_fee<'a>(
         return Err(StakePoolError::UnexpectedBalanceChange.into());
     }
 
-    // Safe: checks above guarantee balance_after >= balance_before and proper state change
+    // Safe: the check above guarantees balance_after >= balance_before
     let actual_transferred = balance_after - balance_before;
+    if actual_transferred != expected_received {
+        solana_program::msg!(
+            "Error: Transfer of {} tokens should deliver {} after fees but delivered {}",
+            amount,
+            expected_received,
+            actual_transferred
+        );
+        return Err(StakePoolError::UnexpectedBalanceChange.into());
+    }
 
     Ok(actual_transferred)
 }
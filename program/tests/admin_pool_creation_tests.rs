@@ -10,12 +10,16 @@
 // Test Status: ✅ Unit tests (state logic)
 // Note: Full integration tests are in TypeScript (example/src/)
 
+use borsh::BorshDeserialize;
 use num_traits::FromPrimitive;
 use solana_program::program_error::ProgramError;
 use solana_sdk::pubkey::Pubkey;
 use your_wallet_stake_pool::{
     error::StakePoolError,
-    state::{Key, ProgramAuthority},
+    state::{
+        AuthorizedCreator, ClusterProfile, Key, ProgramAuthority, ProgramAuthorityV1, Role,
+        RoleHolder,
+    },
 };
 
 /// Helper to convert ProgramError to StakePoolError
@@ -36,21 +40,26 @@ fn test_program_authority_initialization() {
     let authority = Pubkey::new_unique();
 
     let program_authority = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
 
-    assert!(matches!(program_authority.key, Key::ProgramAuthority));
+    assert!(matches!(program_authority.key, Key::ProgramAuthorityV2));
     assert_eq!(program_authority.authority, authority);
-    assert_eq!(program_authority.creator_count, 0);
-    assert!(program_authority
-        .authorized_creators
-        .iter()
-        .all(|c| c.is_none()));
+    assert_eq!(program_authority.creator_count(), 0);
+    assert!(program_authority.authorized_creators.is_empty());
 }
 
 #[test]
@@ -58,12 +67,20 @@ fn test_main_authority_is_authorized() {
     let authority = Pubkey::new_unique();
 
     let program_authority = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
 
     // Main authority should always be authorized
@@ -76,30 +93,89 @@ fn test_unauthorized_address_is_not_authorized() {
     let unauthorized = Pubkey::new_unique();
 
     let program_authority = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
 
     // Unauthorized address should not be authorized
     assert!(!program_authority.is_authorized(&unauthorized));
 }
 
+#[test]
+fn test_repeated_initialization_must_match_the_existing_authority() {
+    let authority = Pubkey::new_unique();
+
+    let program_authority = ProgramAuthority {
+        key: Key::ProgramAuthorityV2,
+        authority,
+        authorized_creators: Vec::new(),
+        pending_authority: None,
+        bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
+    };
+
+    // Same setup again: a no-op
+    program_authority
+        .check_reinitialization(&authority, ClusterProfile::Mainnet)
+        .unwrap();
+
+    // Another authority or cluster profile would leave the existing one in place unnoticed
+    let err = program_authority
+        .check_reinitialization(&Pubkey::new_unique(), ClusterProfile::Mainnet)
+        .unwrap_err();
+    assert_eq!(
+        to_stake_pool_error(err),
+        StakePoolError::ExpectedEmptyAccount
+    );
+    let err = program_authority
+        .check_reinitialization(&authority, ClusterProfile::Devnet)
+        .unwrap_err();
+    assert_eq!(
+        to_stake_pool_error(err),
+        StakePoolError::ExpectedEmptyAccount
+    );
+}
+
 #[test]
 fn test_add_authorized_creator_success() {
     let authority = Pubkey::new_unique();
     let creator = Pubkey::new_unique();
 
     let mut program_authority = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
 
     // Add creator
@@ -107,12 +183,12 @@ fn test_add_authorized_creator_success() {
     assert!(result.is_ok());
 
     // Verify creator was added
-    assert_eq!(program_authority.creator_count, 1);
+    assert_eq!(program_authority.creator_count(), 1);
     assert!(program_authority.is_authorized(&creator));
     assert!(program_authority
         .authorized_creators
         .iter()
-        .any(|c| c == &Some(creator)));
+        .any(|c| c.creator == creator));
 }
 
 #[test]
@@ -120,12 +196,20 @@ fn test_add_main_authority_fails() {
     let authority = Pubkey::new_unique();
 
     let mut program_authority = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
 
     // Try to add main authority - should fail
@@ -138,7 +222,7 @@ fn test_add_main_authority_fails() {
     }
 
     // Verify count is still 0 and main authority remains authorized via is_authorized()
-    assert_eq!(program_authority.creator_count, 0);
+    assert_eq!(program_authority.creator_count(), 0);
     assert!(program_authority.is_authorized(&authority));
 }
 
@@ -148,12 +232,20 @@ fn test_add_duplicate_creator_fails() {
     let creator = Pubkey::new_unique();
 
     let mut program_authority = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
 
     // Add creator once
@@ -174,16 +266,24 @@ fn test_add_max_creators_success() {
     let authority = Pubkey::new_unique();
 
     let mut program_authority = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
 
     // Add maximum creators
-    for i in 0..ProgramAuthority::MAX_CREATORS {
+    for i in 0..ProgramAuthority::DEFAULT_MAX_CREATORS {
         let creator = Pubkey::new_unique();
         let result = program_authority.add_creator(creator);
         assert!(result.is_ok(), "Failed to add creator {}", i);
@@ -191,8 +291,8 @@ fn test_add_max_creators_success() {
     }
 
     assert_eq!(
-        program_authority.creator_count as usize,
-        ProgramAuthority::MAX_CREATORS
+        program_authority.creator_count(),
+        ProgramAuthority::DEFAULT_MAX_CREATORS as usize
     );
 }
 
@@ -201,16 +301,24 @@ fn test_add_beyond_max_creators_fails() {
     let authority = Pubkey::new_unique();
 
     let mut program_authority = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
 
     // Fill up to max
-    for _ in 0..ProgramAuthority::MAX_CREATORS {
+    for _ in 0..ProgramAuthority::DEFAULT_MAX_CREATORS {
         let creator = Pubkey::new_unique();
         program_authority.add_creator(creator).unwrap();
     }
@@ -235,29 +343,34 @@ fn test_remove_authorized_creator_success() {
     let creator = Pubkey::new_unique();
 
     let mut program_authority = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
 
     // Add creator
     program_authority.add_creator(creator).unwrap();
-    assert_eq!(program_authority.creator_count, 1);
+    assert_eq!(program_authority.creator_count(), 1);
 
     // Remove creator
     let result = program_authority.remove_creator(&creator);
     assert!(result.is_ok());
 
     // Verify creator was removed
-    assert_eq!(program_authority.creator_count, 0);
+    assert_eq!(program_authority.creator_count(), 0);
     assert!(!program_authority.is_authorized(&creator));
-    assert!(program_authority
-        .authorized_creators
-        .iter()
-        .all(|c| c.is_none()));
+    assert!(program_authority.authorized_creators.is_empty());
 }
 
 #[test]
@@ -265,12 +378,20 @@ fn test_remove_main_authority_fails() {
     let authority = Pubkey::new_unique();
 
     let mut program_authority = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
 
     // Try to remove main authority - should fail
@@ -292,12 +413,20 @@ fn test_remove_nonexistent_creator_fails() {
     let nonexistent = Pubkey::new_unique();
 
     let mut program_authority = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
 
     // Try to remove creator that was never added
@@ -315,12 +444,20 @@ fn test_add_and_remove_multiple_creators() {
     let authority = Pubkey::new_unique();
 
     let mut program_authority = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
 
     // Add 5 creators
@@ -330,7 +467,7 @@ fn test_add_and_remove_multiple_creators() {
         program_authority.add_creator(*creator).unwrap();
     }
 
-    assert_eq!(program_authority.creator_count, 5);
+    assert_eq!(program_authority.creator_count(), 5);
 
     // Verify all are authorized
     for creator in &creators {
@@ -342,7 +479,7 @@ fn test_add_and_remove_multiple_creators() {
         program_authority.remove_creator(creator).unwrap();
     }
 
-    assert_eq!(program_authority.creator_count, 2);
+    assert_eq!(program_authority.creator_count(), 2);
 
     // Verify first 3 are not authorized
     for creator in &creators[0..3] {
@@ -373,21 +510,31 @@ fn test_pda_is_off_curve() {
 }
 
 #[test]
-fn test_authorized_creators_array_size() {
-    // Verify the max creators constant matches the array size
-    assert_eq!(ProgramAuthority::MAX_CREATORS, 10);
+fn test_authorized_creators_start_empty() {
+    // New authorities hold up to the default cap; SetMaxCreators raises it to the ceiling
+    assert_eq!(ProgramAuthority::DEFAULT_MAX_CREATORS, 10);
+    assert_eq!(ProgramAuthority::MAX_CREATORS, 100);
 
     let authority = Pubkey::new_unique();
     let program_authority = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
 
-    assert_eq!(program_authority.authorized_creators.len(), 10);
+    assert!(program_authority.authorized_creators.is_empty());
+    assert_eq!(program_authority.required_len(), ProgramAuthority::BASE_LEN);
 }
 
 #[test]
@@ -395,34 +542,42 @@ fn test_creator_count_accuracy() {
     let authority = Pubkey::new_unique();
 
     let mut program_authority = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
 
     // Add creators and verify count
     for i in 0..5 {
         let creator = Pubkey::new_unique();
         program_authority.add_creator(creator).unwrap();
-        assert_eq!(program_authority.creator_count, i + 1);
+        assert_eq!(program_authority.creator_count(), i + 1);
     }
 
     // Remove creators and verify count
     let creators_to_remove: Vec<Pubkey> = program_authority
         .authorized_creators
         .iter()
-        .filter_map(|c| *c)
+        .map(|c| c.creator)
         .collect();
 
     for (i, creator) in creators_to_remove.iter().enumerate() {
         program_authority.remove_creator(creator).unwrap();
         // Count should decrease: 5 -> 4 -> 3 -> 2 -> 1 -> 0
         assert_eq!(
-            program_authority.creator_count,
-            (creators_to_remove.len() - 1 - i) as u8
+            program_authority.creator_count(),
+            creators_to_remove.len() - 1 - i
         );
     }
 }
@@ -431,12 +586,20 @@ fn test_creator_count_accuracy() {
 fn test_serialization_size() {
     let authority = Pubkey::new_unique();
     let program_authority = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
 
     let serialized = borsh::to_vec(&program_authority).unwrap();
@@ -452,12 +615,20 @@ fn test_deserialization_roundtrip() {
     let creator2 = Pubkey::new_unique();
 
     let mut program_authority = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
 
     program_authority.add_creator(creator1).unwrap();
@@ -471,9 +642,12 @@ fn test_deserialization_roundtrip() {
     let deserialized: ProgramAuthority = ProgramAuthority::try_from_slice(&serialized).unwrap();
 
     // Verify all fields match
-    assert!(matches!(deserialized.key, Key::ProgramAuthority));
+    assert!(matches!(deserialized.key, Key::ProgramAuthorityV2));
     assert_eq!(deserialized.authority, program_authority.authority);
-    assert_eq!(deserialized.creator_count, program_authority.creator_count);
+    assert_eq!(
+        deserialized.creator_count(),
+        program_authority.creator_count()
+    );
     assert_eq!(deserialized.bump, program_authority.bump);
 
     // Verify creators match
@@ -482,17 +656,25 @@ fn test_deserialization_roundtrip() {
 }
 
 #[test]
-fn test_array_compaction_after_removal() {
+fn test_removal_keeps_creator_order() {
     let authority = Pubkey::new_unique();
     let creators: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
 
     let mut program_authority = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority,
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
 
     // Add 5 creators
@@ -504,16 +686,13 @@ fn test_array_compaction_after_removal() {
     program_authority.remove_creator(&creators[1]).unwrap();
     program_authority.remove_creator(&creators[3]).unwrap();
 
-    // After compaction, array should have no holes
-    // Should be: [creators[0], creators[2], creators[4], None, None, ...]
-    let mut found_none = false;
-    for slot in &program_authority.authorized_creators {
-        if slot.is_none() {
-            found_none = true;
-        } else if found_none {
-            panic!("Found Some after None - array not compacted!");
-        }
-    }
+    // Survivors keep their relative order
+    let remaining: Vec<Pubkey> = program_authority
+        .authorized_creators
+        .iter()
+        .map(|c| c.creator)
+        .collect();
+    assert_eq!(remaining, [creators[0], creators[2], creators[4]]);
 
     // Verify remaining creators are still authorized
     assert!(program_authority.is_authorized(&creators[0]));
@@ -523,24 +702,33 @@ fn test_array_compaction_after_removal() {
     assert!(program_authority.is_authorized(&creators[4]));
 
     // Verify count is correct
-    assert_eq!(program_authority.creator_count, 3);
+    assert_eq!(program_authority.creator_count(), 3);
 }
 
 #[test]
 fn test_program_authority_size_calculation() {
     // Verify LEN constant is correct
-    assert_eq!(ProgramAuthority::LEN, 398);
+    assert_eq!(ProgramAuthority::BASE_LEN, 407);
+    assert_eq!(ProgramAuthority::LEN, 777);
 
     // Test with minimal instance (all None)
     let minimal = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority: Pubkey::default(),
-        authorized_creators: [None; ProgramAuthority::MAX_CREATORS],
-        creator_count: 0,
+        authorized_creators: Vec::new(),
         pending_authority: None,
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
-    let serialized_minimal = borsh::to_vec(&minimal).unwrap();
+    let serialized_minimal = minimal.to_account_data().unwrap();
     assert!(
         serialized_minimal.len() <= ProgramAuthority::LEN,
         "Minimal serialized size {} exceeds LEN {}",
@@ -548,16 +736,34 @@ fn test_program_authority_size_calculation() {
         ProgramAuthority::LEN
     );
 
-    // Test with maximal instance (all Some)
+    // Test with maximal instance (all Some, default creator cap filled)
     let maximal = ProgramAuthority {
-        key: Key::ProgramAuthority,
+        key: Key::ProgramAuthorityV2,
         authority: Pubkey::new_unique(),
-        authorized_creators: [Some(Pubkey::new_unique()); ProgramAuthority::MAX_CREATORS],
-        creator_count: ProgramAuthority::MAX_CREATORS as u8,
+        authorized_creators: vec![
+            AuthorizedCreator {
+                creator: Pubkey::new_unique(),
+                pools_created: u16::MAX,
+                pool_limit: Some(u16::MAX),
+            };
+            ProgramAuthority::DEFAULT_MAX_CREATORS as usize
+        ],
         pending_authority: Some(Pubkey::new_unique()),
         bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [Some(RoleHolder {
+            holder: Pubkey::new_unique(),
+            roles: Role::ALL,
+        }); ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: ProgramAuthority::DEFAULT_MAX_CREATORS as u8 + 1,
+        recovery_authority: Some(Pubkey::new_unique()),
+        recovery_delay: i64::MAX,
+        recovery_initiated_at: Some(i64::MAX),
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
     };
-    let serialized_maximal = borsh::to_vec(&maximal).unwrap();
+    let serialized_maximal = maximal.to_account_data().unwrap();
     assert!(
         serialized_maximal.len() <= ProgramAuthority::LEN,
         "Maximal serialized size {} exceeds LEN {}",
@@ -571,6 +777,277 @@ fn test_program_authority_size_calculation() {
         ProgramAuthority::LEN,
         "Expected maximal serialized size to match LEN exactly"
     );
+    assert_eq!(maximal.required_len(), serialized_maximal.len());
+}
+
+#[test]
+fn test_max_creators_can_grow_the_list() {
+    let mut program_authority = authority_with_creators(Pubkey::new_unique(), &[]);
+    for _ in 0..ProgramAuthority::DEFAULT_MAX_CREATORS {
+        program_authority.add_creator(Pubkey::new_unique()).unwrap();
+    }
+
+    program_authority.set_max_creators(12).unwrap();
+    program_authority.add_creator(Pubkey::new_unique()).unwrap();
+    program_authority.add_creator(Pubkey::new_unique()).unwrap();
+    let result = program_authority.add_creator(Pubkey::new_unique());
+    assert_eq!(
+        to_stake_pool_error(result.unwrap_err()),
+        StakePoolError::MaxAuthorizedCreatorsReached
+    );
+
+    // The account outgrows the size allocated at initialization
+    assert_eq!(program_authority.creator_count(), 12);
+    assert_eq!(
+        program_authority.required_len(),
+        ProgramAuthority::space(12)
+    );
+    assert!(program_authority.required_len() > ProgramAuthority::LEN);
+    assert!(borsh::to_vec(&program_authority).unwrap().len() <= program_authority.required_len());
+}
+
+#[test]
+fn test_max_creators_is_bounded() {
+    let creators: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+    let mut program_authority = authority_with_creators(Pubkey::new_unique(), &creators);
+
+    // Neither below the current creators nor above the hard ceiling
+    for max_creators in [2, ProgramAuthority::MAX_CREATORS + 1] {
+        let result = program_authority.set_max_creators(max_creators);
+        assert_eq!(
+            to_stake_pool_error(result.unwrap_err()),
+            StakePoolError::InvalidParameters
+        );
+    }
+    program_authority.set_max_creators(3).unwrap();
+    program_authority
+        .set_max_creators(ProgramAuthority::MAX_CREATORS)
+        .unwrap();
+    assert_eq!(
+        program_authority.max_creators,
+        ProgramAuthority::MAX_CREATORS
+    );
+}
+
+#[test]
+fn test_legacy_creator_array_converts_to_list() {
+    let authority = Pubkey::new_unique();
+    let creators = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut authorized_creators = [None; 10];
+    authorized_creators[0] = Some(creators[0]);
+    authorized_creators[1] = Some(creators[1]);
+    let mut creator_pools_created = [0; 10];
+    creator_pools_created[1] = 4;
+    let mut creator_pool_limits = [None; 10];
+    creator_pool_limits[1] = Some(5);
+
+    let legacy = ProgramAuthorityV1 {
+        key: Key::ProgramAuthorityV1,
+        authority,
+        authorized_creators,
+        creator_count: 2,
+        pending_authority: None,
+        bump: 255,
+        cluster_profile: ClusterProfile::Devnet,
+        global_pause: false,
+        withdrawals_only: true,
+        creator_pools_created,
+        creator_pool_limits,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 2,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+    };
+
+    let program_authority = ProgramAuthority::from(legacy);
+    assert!(matches!(program_authority.key, Key::ProgramAuthorityV2));
+    assert_eq!(
+        program_authority.authorized_creators,
+        [
+            AuthorizedCreator {
+                creator: creators[0],
+                pools_created: 0,
+                pool_limit: None,
+            },
+            AuthorizedCreator {
+                creator: creators[1],
+                pools_created: 4,
+                pool_limit: Some(5),
+            },
+        ]
+    );
+    assert_eq!(
+        program_authority.max_creators,
+        ProgramAuthority::DEFAULT_MAX_CREATORS
+    );
+    assert_eq!(program_authority.cluster_profile, ClusterProfile::Devnet);
+    assert!(program_authority.withdrawals_only);
+    assert_eq!(program_authority.approval_threshold, 2);
+    assert!(program_authority.required_len() <= ProgramAuthorityV1::LEN);
+}
+
+// ============================================================================
+// Per-Creator Pool Limit Tests
+// ============================================================================
+
+fn authority_with_creators(authority: Pubkey, creators: &[Pubkey]) -> ProgramAuthority {
+    let mut program_authority = ProgramAuthority {
+        key: Key::ProgramAuthorityV2,
+        authority,
+        authorized_creators: Vec::new(),
+        pending_authority: None,
+        bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
+    };
+    for creator in creators {
+        program_authority.add_creator(*creator).unwrap();
+    }
+    program_authority
+}
+
+#[test]
+fn test_creator_pool_limit_is_enforced() {
+    let creator = Pubkey::new_unique();
+    let mut program_authority = authority_with_creators(Pubkey::new_unique(), &[creator]);
+
+    assert_eq!(
+        program_authority
+            .set_creator_pool_limit(&creator, Some(2))
+            .unwrap(),
+        0
+    );
+    program_authority.record_pool_created(&creator).unwrap();
+    program_authority.record_pool_created(&creator).unwrap();
+
+    let result = program_authority.record_pool_created(&creator);
+    assert_eq!(
+        to_stake_pool_error(result.unwrap_err()),
+        StakePoolError::CreatorPoolLimitReached
+    );
+    assert_eq!(program_authority.authorized_creators[0].pools_created, 2);
+
+    // Lifting the limit lets the creator continue
+    assert_eq!(
+        program_authority
+            .set_creator_pool_limit(&creator, None)
+            .unwrap(),
+        2
+    );
+    program_authority.record_pool_created(&creator).unwrap();
+    assert_eq!(program_authority.authorized_creators[0].pools_created, 3);
+}
+
+#[test]
+fn test_creators_are_tracked_without_a_limit() {
+    let creator = Pubkey::new_unique();
+    let mut program_authority = authority_with_creators(Pubkey::new_unique(), &[creator]);
+
+    for _ in 0..5 {
+        program_authority.record_pool_created(&creator).unwrap();
+    }
+    assert_eq!(program_authority.authorized_creators[0].pools_created, 5);
+
+    // A limit below the current count blocks further pools
+    program_authority
+        .set_creator_pool_limit(&creator, Some(1))
+        .unwrap();
+    let result = program_authority.record_pool_created(&creator);
+    assert_eq!(
+        to_stake_pool_error(result.unwrap_err()),
+        StakePoolError::CreatorPoolLimitReached
+    );
+}
+
+#[test]
+fn test_main_authority_has_no_pool_limit() {
+    let authority = Pubkey::new_unique();
+    let mut program_authority = authority_with_creators(authority, &[]);
+
+    program_authority.record_pool_created(&authority).unwrap();
+    assert!(program_authority.authorized_creators.is_empty());
+
+    let result = program_authority.set_creator_pool_limit(&authority, Some(1));
+    assert_eq!(
+        to_stake_pool_error(result.unwrap_err()),
+        StakePoolError::InvalidParameters
+    );
+
+    let result = program_authority.set_creator_pool_limit(&Pubkey::new_unique(), Some(1));
+    assert_eq!(
+        to_stake_pool_error(result.unwrap_err()),
+        StakePoolError::CreatorNotFound
+    );
+}
+
+#[test]
+fn test_pool_limits_follow_creators_through_removal() {
+    let creators: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+    let mut program_authority = authority_with_creators(Pubkey::new_unique(), &creators);
+
+    program_authority.record_pool_created(&creators[1]).unwrap();
+    program_authority.record_pool_created(&creators[2]).unwrap();
+    program_authority.record_pool_created(&creators[2]).unwrap();
+    program_authority
+        .set_creator_pool_limit(&creators[2], Some(2))
+        .unwrap();
+
+    // Removing the first creator keeps the others' counts and limits
+    program_authority.remove_creator(&creators[0]).unwrap();
+    assert_eq!(
+        program_authority.authorized_creators,
+        [
+            AuthorizedCreator {
+                creator: creators[1],
+                pools_created: 1,
+                pool_limit: None,
+            },
+            AuthorizedCreator {
+                creator: creators[2],
+                pools_created: 2,
+                pool_limit: Some(2),
+            },
+        ]
+    );
+
+    let result = program_authority.record_pool_created(&creators[2]);
+    assert_eq!(
+        to_stake_pool_error(result.unwrap_err()),
+        StakePoolError::CreatorPoolLimitReached
+    );
+
+    // A re-added creator starts from a clean entry
+    program_authority.remove_creator(&creators[2]).unwrap();
+    program_authority.add_creator(creators[2]).unwrap();
+    let entry = program_authority
+        .authorized_creators
+        .iter()
+        .find(|c| c.creator == creators[2])
+        .unwrap();
+    assert_eq!(entry.pools_created, 0);
+    assert_eq!(entry.pool_limit, None);
+}
+
+#[test]
+fn test_only_devnet_profile_allows_dev_instructions() {
+    assert!(ClusterProfile::Devnet.allows_dev_instructions());
+    assert!(!ClusterProfile::Mainnet.allows_dev_instructions());
+}
+
+#[test]
+fn test_zeroed_cluster_profile_decodes_as_mainnet() {
+    // The restrictive profile must be the default encoding
+    let profile = ClusterProfile::try_from_slice(&[0]).unwrap();
+
+    assert_eq!(profile, ClusterProfile::Mainnet);
 }
 
 // ============================================================================
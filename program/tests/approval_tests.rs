@@ -0,0 +1,145 @@
+// ============================================================================
+// Multi-Admin Approval Tests
+// ============================================================================
+// With an approval threshold above 1, reward rate, pause and end date changes
+// are proposed into a PendingAction, approved by distinct global admins and
+// executed once the approvals still held by admins reach the threshold.
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    error::StakePoolError,
+    processor::PoolUpdate,
+    state::{ClusterProfile, Key, PendingAction, ProgramAuthority, SensitivePoolUpdate},
+};
+
+fn program_authority(creators: &[Pubkey]) -> ProgramAuthority {
+    let mut authority = ProgramAuthority {
+        key: Key::ProgramAuthorityV2,
+        authority: Pubkey::new_unique(),
+        authorized_creators: Vec::new(),
+        pending_authority: None,
+        bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
+    };
+    for creator in creators {
+        authority.add_creator(*creator).unwrap();
+    }
+    authority
+}
+
+fn pending_action(proposer: Pubkey) -> PendingAction {
+    let mut approvals = [None; PendingAction::MAX_APPROVALS];
+    approvals[0] = Some(proposer);
+    PendingAction {
+        key: Key::PendingAction,
+        pool: Pubkey::new_unique(),
+        proposer,
+        action_id: 1,
+        update: SensitivePoolUpdate {
+            reward_rate: Some(50_000_000),
+            ..Default::default()
+        },
+        approvals,
+        created_at: 1_700_000_000,
+        bump: 254,
+    }
+}
+
+fn error(code: StakePoolError) -> ProgramError {
+    ProgramError::Custom(code as u32)
+}
+
+#[test]
+fn test_threshold_is_bounded_by_admin_count() {
+    let mut authority = program_authority(&[Pubkey::new_unique(), Pubkey::new_unique()]);
+    assert_eq!(authority.admin_count(), 3);
+
+    authority.set_approval_threshold(3).unwrap();
+    assert!(authority.requires_approval());
+    assert_eq!(
+        authority.set_approval_threshold(4).unwrap_err(),
+        error(StakePoolError::InvalidParameters)
+    );
+
+    // A threshold of 1 is a single admin, i.e. no approval flow
+    authority.set_approval_threshold(1).unwrap();
+    assert!(!authority.requires_approval());
+}
+
+#[test]
+fn test_each_admin_approves_once() {
+    let proposer = Pubkey::new_unique();
+    let approver = Pubkey::new_unique();
+    let mut action = pending_action(proposer);
+
+    assert_eq!(action.approve(&approver).unwrap(), 2);
+    for admin in [proposer, approver] {
+        assert_eq!(
+            action.approve(&admin).unwrap_err(),
+            error(StakePoolError::AlreadyApproved)
+        );
+    }
+}
+
+#[test]
+fn test_approvals_of_removed_admins_do_not_count() {
+    let creators = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut authority = program_authority(&creators);
+    let mut action = pending_action(authority.authority);
+    action.approve(&creators[0]).unwrap();
+    action.approve(&creators[1]).unwrap();
+    assert_eq!(action.valid_approvals(&authority), 3);
+
+    authority.remove_creator(&creators[0]).unwrap();
+    assert_eq!(action.valid_approvals(&authority), 2);
+}
+
+#[test]
+fn test_pending_action_fits_len_with_every_approval() {
+    let mut action = pending_action(Pubkey::new_unique());
+    action.approvals = [Some(Pubkey::new_unique()); PendingAction::MAX_APPROVALS];
+    action.update = SensitivePoolUpdate {
+        reward_rate: Some(u64::MAX),
+        is_paused: Some(true),
+        pool_end_date: Some(Some(i64::MAX)),
+    };
+
+    assert_eq!(borsh::to_vec(&action).unwrap().len(), PendingAction::LEN);
+}
+
+#[test]
+fn test_sensitive_parameters() {
+    assert!(SensitivePoolUpdate::default().is_empty());
+
+    for update in [
+        PoolUpdate {
+            reward_rate: Some(1),
+            ..Default::default()
+        },
+        PoolUpdate {
+            is_paused: Some(false),
+            ..Default::default()
+        },
+        PoolUpdate {
+            pool_end_date: Some(None),
+            ..Default::default()
+        },
+    ] {
+        assert!(update.is_sensitive());
+    }
+
+    let fees = PoolUpdate {
+        deposit_fee_bps: Some(10),
+        ..Default::default()
+    };
+    assert!(!fees.is_sensitive());
+}
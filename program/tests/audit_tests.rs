@@ -0,0 +1,96 @@
+// ============================================================================
+// Audit Tests
+// ============================================================================
+// AuditPool recomputes the rewards the stake accounts passed to it hold
+// reserved (current round plus carried over rewards, less claims) and compares
+// the sum with the pool's total_rewards_owed. A repair needs the audit to
+// cover every active stake of the pool.
+
+mod common;
+
+use solana_sdk::pubkey::Pubkey;
+
+use common::*;
+
+#[test]
+fn test_audit_matches_a_consistent_pool() {
+    let mut pool = sample_stake_pool();
+    let pool_key = Pubkey::new_unique();
+    let first = sample_stake_account(&pool_key, &Pubkey::new_unique(), 1_000);
+    let mut second = sample_stake_account(&pool_key, &Pubkey::new_unique(), 5_000);
+    second.carried_rewards = 40;
+    second.claimed_rewards = 30;
+    pool.total_rewards_owed = 100 + 500 + 40 - 30;
+    pool.active_stake_count = Some(2);
+
+    let audit = pool.audit(&[first, second]).unwrap();
+
+    assert_eq!(audit.audited_stakes, 2);
+    assert_eq!(audit.recomputed_rewards_owed, 610);
+    assert_eq!(audit.total_rewards_owed, 610);
+    assert!(audit.complete);
+    assert!(!audit.repaired);
+}
+
+#[test]
+fn test_audit_reports_a_drifted_counter() {
+    let mut pool = sample_stake_pool();
+    let pool_key = Pubkey::new_unique();
+    pool.total_rewards_owed = 250;
+    pool.active_stake_count = Some(1);
+
+    let audit = pool
+        .audit(&[sample_stake_account(
+            &pool_key,
+            &Pubkey::new_unique(),
+            1_000,
+        )])
+        .unwrap();
+
+    assert_eq!(audit.recomputed_rewards_owed, 100);
+    assert_eq!(audit.total_rewards_owed, 250);
+    assert!(audit.complete);
+}
+
+#[test]
+fn test_audit_missing_an_active_stake_is_incomplete() {
+    let mut pool = sample_stake_pool();
+    let pool_key = Pubkey::new_unique();
+    pool.active_stake_count = Some(2);
+
+    // An emptied stake account does not stand in for an active one
+    let emptied = sample_stake_account(&pool_key, &Pubkey::new_unique(), 0);
+    let active = sample_stake_account(&pool_key, &Pubkey::new_unique(), 1_000);
+    let audit = pool.audit(&[emptied, active]).unwrap();
+
+    assert_eq!(audit.audited_stakes, 2);
+    assert!(!audit.complete);
+}
+
+#[test]
+fn test_audit_without_an_active_stake_count_is_never_complete() {
+    let mut pool = sample_stake_pool();
+    pool.active_stake_count = None;
+
+    assert!(!pool.audit(&[]).unwrap().complete);
+
+    pool.active_stake_count = Some(0);
+    let audit = pool.audit(&[]).unwrap();
+    assert!(audit.complete);
+    assert_eq!(audit.recomputed_rewards_owed, 0);
+}
+
+#[test]
+fn test_unclaimed_rewards_match_a_full_unstake_forfeiture() {
+    let pool = sample_stake_pool();
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 2_000);
+    stake.carried_rewards = 75;
+    stake.claimed_rewards = 120;
+
+    assert_eq!(stake.unclaimed_rewards().unwrap(), 200 + 75 - 120);
+    assert_eq!(
+        pool.unstake_forfeiture(&stake, stake.amount_staked)
+            .unwrap(),
+        stake.unclaimed_rewards().unwrap()
+    );
+}
@@ -0,0 +1,108 @@
+// ============================================================================
+// Batch Pool Update Tests
+// ============================================================================
+// UpdatePoolsBatch applies one set of parameter changes to every pool passed
+// as a remaining account.
+
+use borsh::BorshSerialize;
+use solana_program::account_info::AccountInfo;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::instruction::{
+    accounts::UpdatePoolsBatchAccounts, StakePoolInstruction,
+};
+
+#[test]
+fn test_batch_pools_are_remaining_accounts() {
+    let keys: Vec<Pubkey> = (0..6).map(|_| Pubkey::new_unique()).collect();
+    let owner = Pubkey::new_unique();
+    let mut lamports = vec![0u64; keys.len()];
+    let mut data = vec![Vec::<u8>::new(); keys.len()];
+
+    let accounts: Vec<AccountInfo> = keys
+        .iter()
+        .zip(lamports.iter_mut())
+        .zip(data.iter_mut())
+        .enumerate()
+        .map(|(i, ((key, lamports), data))| {
+            AccountInfo::new(key, i == 0, i >= 3, lamports, data, &owner, false, 0)
+        })
+        .collect();
+
+    let ctx = UpdatePoolsBatchAccounts::context(&accounts).unwrap();
+
+    assert_eq!(ctx.accounts.admin.key, &keys[0]);
+    assert_eq!(ctx.accounts.program_authority.key, &keys[1]);
+    assert_eq!(ctx.accounts.program_config.key, &keys[2]);
+    assert_eq!(ctx.remaining_accounts.len(), 3);
+    assert_eq!(ctx.remaining_accounts[0].key, &keys[3]);
+    assert_eq!(ctx.remaining_accounts[2].key, &keys[5]);
+}
+
+#[test]
+fn test_batch_pause_instruction_matches_update_pool_encoding() {
+    let batch = StakePoolInstruction::UpdatePoolsBatch {
+        reward_rate: None,
+        min_stake_amount: None,
+        lockup_period: None,
+        is_paused: Some(true),
+        enforce_lockup: None,
+        pool_end_date: None,
+        referral_bps: None,
+        early_unstake_bps: None,
+        deposit_fee_bps: None,
+        withdraw_fee_bps: None,
+        unstake_cooldown: None,
+        bonus_end_ts: None,
+        bonus_multiplier_bps: None,
+        lockup_extension_rate: None,
+        allowlist_merkle_root: None,
+        claim_window: None,
+        outflow_limit: None,
+        pool_manager: None,
+        keeper_bounty: None,
+        max_total_rewards: None,
+        enforce_min_remaining_stake: None,
+        wind_down_rewards: None,
+        points_rate: None,
+        deny_list_required: None,
+        allow_third_party_stake: None,
+        cohort_duration: None,
+    }
+    .try_to_vec()
+    .unwrap();
+
+    let single = StakePoolInstruction::UpdatePool {
+        reward_rate: None,
+        min_stake_amount: None,
+        lockup_period: None,
+        is_paused: Some(true),
+        enforce_lockup: None,
+        pool_end_date: None,
+        referral_bps: None,
+        early_unstake_bps: None,
+        deposit_fee_bps: None,
+        withdraw_fee_bps: None,
+        unstake_cooldown: None,
+        bonus_end_ts: None,
+        bonus_multiplier_bps: None,
+        lockup_extension_rate: None,
+        allowlist_merkle_root: None,
+        claim_window: None,
+        outflow_limit: None,
+        pool_manager: None,
+        keeper_bounty: None,
+        max_total_rewards: None,
+        enforce_min_remaining_stake: None,
+        wind_down_rewards: None,
+        points_rate: None,
+        deny_list_required: None,
+        allow_third_party_stake: None,
+        cohort_duration: None,
+    }
+    .try_to_vec()
+    .unwrap();
+
+    // Same parameter payload, different instruction discriminator
+    assert_ne!(batch[0], single[0]);
+    assert_eq!(batch[1..], single[1..]);
+}
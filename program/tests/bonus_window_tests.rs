@@ -0,0 +1,168 @@
+// ============================================================================
+// Early-Bird Bonus Window Tests
+// ============================================================================
+// A pool may open a bonus window at initialization or with UpdatePool. Stakes
+// created before bonus_end_ts lock in the reward rate boosted by
+// bonus_multiplier_bps, and reserve their boosted reward in total_rewards_owed
+// so the reward vault solvency check covers it. Stakes created after the
+// window, and relocked rounds, earn the regular rate.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    constants::MAX_REWARD_MULTIPLIER_BPS,
+    error::StakePoolError,
+    processor::PoolUpdate,
+    state::{RewardTier, StakePool},
+};
+
+use common::*;
+
+const TEN_PERCENT: u64 = 100_000_000;
+const BONUS_END: i64 = SAMPLE_STAKE_TIMESTAMP + 86_400;
+
+/// A 10% pool paying 1.5x to stakes created in the day after SAMPLE_STAKE_TIMESTAMP
+fn bonus_pool() -> StakePool {
+    let mut pool = sample_stake_pool();
+    pool.reward_rate = TEN_PERCENT;
+    pool.set_bonus_window(Some(BONUS_END), 15_000, SAMPLE_STAKE_TIMESTAMP)
+        .unwrap();
+    pool
+}
+
+fn invalid() -> Result<(), ProgramError> {
+    Err(StakePoolError::InvalidParameters.into())
+}
+
+#[test]
+fn test_stakes_in_the_window_lock_in_the_bonus() {
+    let pool = bonus_pool();
+
+    assert_eq!(
+        pool.stake_reward_rate(1_000, SAMPLE_STAKE_TIMESTAMP)
+            .unwrap(),
+        150_000_000
+    );
+    assert_eq!(
+        pool.stake_reward_rate(1_000, BONUS_END - 1).unwrap(),
+        150_000_000
+    );
+    assert_eq!(
+        pool.stake_reward_rate(1_000, BONUS_END).unwrap(),
+        TEN_PERCENT
+    );
+}
+
+#[test]
+fn test_reserved_rewards_use_the_boosted_rate() {
+    let pool = bonus_pool();
+
+    // The solvency check at stake time covers the boosted reward
+    assert_eq!(
+        pool.expected_rewards(1_000_000, SAMPLE_STAKE_TIMESTAMP)
+            .unwrap(),
+        150_000
+    );
+    assert_eq!(
+        pool.expected_rewards(1_000_000, BONUS_END).unwrap(),
+        100_000
+    );
+}
+
+#[test]
+fn test_bonus_stacks_with_reward_tiers() {
+    let mut pool = bonus_pool();
+    pool.set_reward_tiers(&[RewardTier {
+        min_amount: 1_000_000,
+        multiplier_bps: 12_000,
+    }])
+    .unwrap();
+
+    // 10% * 1.2 * 1.5
+    assert_eq!(
+        pool.stake_reward_rate(1_000_000, SAMPLE_STAKE_TIMESTAMP)
+            .unwrap(),
+        180_000_000
+    );
+}
+
+#[test]
+fn test_relocked_rounds_do_not_get_the_bonus() {
+    let mut pool = bonus_pool();
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000_000);
+    stake.auto_relock = true;
+    stake.locked_reward_rate = pool
+        .stake_reward_rate(stake.amount_staked, stake.stake_timestamp)
+        .unwrap();
+    pool.total_rewards_owed = stake.round_rewards().unwrap();
+
+    // Shorten the lockup so the stake matures while the window is still open
+    pool.lockup_period = 3_600;
+    let matured = pool.maturity_timestamp(stake.stake_timestamp).unwrap();
+    assert!(matured < BONUS_END);
+
+    pool.relock_stake(&mut stake, matured).unwrap();
+    assert_eq!(stake.locked_reward_rate, TEN_PERCENT);
+}
+
+#[test]
+fn test_bonus_window_must_end_in_the_future() {
+    let mut pool = sample_stake_pool();
+
+    assert_eq!(
+        pool.set_bonus_window(Some(SAMPLE_STAKE_TIMESTAMP), 15_000, SAMPLE_STAKE_TIMESTAMP),
+        invalid()
+    );
+    assert_eq!(pool.bonus_end_ts, None);
+}
+
+#[test]
+fn test_bonus_multiplier_is_bounded() {
+    let mut pool = sample_stake_pool();
+
+    for multiplier_bps in [0, 9_999, MAX_REWARD_MULTIPLIER_BPS + 1] {
+        assert_eq!(
+            pool.set_bonus_window(Some(BONUS_END), multiplier_bps, SAMPLE_STAKE_TIMESTAMP),
+            invalid()
+        );
+    }
+    assert!(pool
+        .set_bonus_window(
+            Some(BONUS_END),
+            MAX_REWARD_MULTIPLIER_BPS,
+            SAMPLE_STAKE_TIMESTAMP
+        )
+        .is_ok());
+}
+
+#[test]
+fn test_removing_the_window_clears_the_multiplier() {
+    let mut pool = bonus_pool();
+
+    pool.set_bonus_window(None, 15_000, SAMPLE_STAKE_TIMESTAMP)
+        .unwrap();
+    assert_eq!(pool.bonus_end_ts, None);
+    assert_eq!(pool.bonus_multiplier_bps, 0);
+    assert_eq!(
+        pool.stake_reward_rate(1_000, SAMPLE_STAKE_TIMESTAMP)
+            .unwrap(),
+        TEN_PERCENT
+    );
+}
+
+#[test]
+fn test_bonus_window_updates_are_admin_only() {
+    let update = PoolUpdate {
+        bonus_end_ts: Some(Some(BONUS_END)),
+        ..Default::default()
+    };
+    assert_eq!(update.required_roles(), None);
+
+    let update = PoolUpdate {
+        bonus_multiplier_bps: Some(15_000),
+        ..Default::default()
+    };
+    assert_eq!(update.required_roles(), None);
+}
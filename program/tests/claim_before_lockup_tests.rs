@@ -0,0 +1,88 @@
+// ============================================================================
+// Claim Before Lockup Tests
+// ============================================================================
+// Claiming before the lockup completes succeeds without a transfer and returns
+// a `ClaimRewardsResult::LockupPending` payload via return data so wallets can
+// render a countdown.
+
+mod common;
+
+use borsh::BorshDeserialize;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::return_data::ClaimRewardsResult;
+
+use common::*;
+
+const STAKE_TIMESTAMP: i64 = 1_700_000_000;
+
+#[test]
+fn test_maturity_timestamp_is_stake_time_plus_lockup() {
+    let pool = sample_stake_pool();
+
+    let matures_at = pool.maturity_timestamp(STAKE_TIMESTAMP).unwrap();
+
+    assert_eq!(matures_at, STAKE_TIMESTAMP + pool.lockup_period);
+}
+
+#[test]
+fn test_maturity_timestamp_overflow_is_rejected() {
+    let mut pool = sample_stake_pool();
+    pool.lockup_period = i64::MAX;
+
+    assert!(pool.maturity_timestamp(STAKE_TIMESTAMP).is_err());
+}
+
+#[test]
+fn test_projected_rewards_match_rewards_at_maturity() {
+    let pool = sample_stake_pool();
+    let stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000_000);
+    let matures_at = pool.maturity_timestamp(stake.stake_timestamp).unwrap();
+
+    // Nothing is claimable one second before maturity...
+    let before = pool.calculate_rewards(&stake, matures_at - 1).unwrap();
+    assert_eq!(before, 0);
+
+    // ...and the projection equals the full reward at maturity (10%)
+    let projected = pool.calculate_rewards(&stake, matures_at).unwrap();
+    assert_eq!(projected, 100_000);
+}
+
+#[test]
+fn test_projection_uses_the_rate_locked_at_stake_time() {
+    let mut pool = sample_stake_pool();
+    let stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000_000);
+    let matures_at = pool.maturity_timestamp(stake.stake_timestamp).unwrap();
+
+    // A rate cut after staking does not lower what the stake was promised
+    pool.reward_rate /= 2;
+    assert_eq!(pool.calculate_rewards(&stake, matures_at).unwrap(), 100_000);
+}
+
+#[test]
+fn test_lockup_pending_result_roundtrip() {
+    let result = ClaimRewardsResult::LockupPending {
+        matures_at: STAKE_TIMESTAMP + 86400,
+        projected_rewards: 100_000,
+        reward_rate: 100_000_000,
+    };
+
+    let data = borsh::to_vec(&result).unwrap();
+
+    // Variant discriminator (1) + i64 + u64 + u64
+    assert_eq!(data.len(), 1 + 8 + 8 + 8);
+    assert_eq!(data[0], 1);
+    assert_eq!(ClaimRewardsResult::try_from_slice(&data).unwrap(), result);
+}
+
+#[test]
+fn test_claimed_result_roundtrip() {
+    let result = ClaimRewardsResult::Claimed {
+        amount: 100_000,
+        received: 99_000,
+    };
+
+    let data = borsh::to_vec(&result).unwrap();
+
+    assert_eq!(data[0], 0);
+    assert_eq!(ClaimRewardsResult::try_from_slice(&data).unwrap(), result);
+}
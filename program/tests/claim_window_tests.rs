@@ -0,0 +1,97 @@
+// ============================================================================
+// Claim Window Tests
+// ============================================================================
+// Pools may restrict ClaimRewards and ClaimRewardsBatch to a recurring claim
+// window set with UpdatePool, e.g. the first day of every 30 days, to smooth
+// reward vault outflows. Outside the window claims fail with
+// ClaimWindowClosed and the log names the next opening.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use your_wallet_stake_pool::{error::StakePoolError, processor::PoolUpdate, state::ClaimWindow};
+
+use common::*;
+
+const DAY: i64 = 86_400;
+const START: i64 = SAMPLE_STAKE_TIMESTAMP;
+
+/// Open for the first day of every 30 days from START
+fn monthly() -> ClaimWindow {
+    ClaimWindow {
+        start: START,
+        period: 30 * DAY,
+        duration: DAY,
+    }
+}
+
+fn closed() -> Result<(), ProgramError> {
+    Err(StakePoolError::ClaimWindowClosed.into())
+}
+
+#[test]
+fn test_windows_open_at_the_start_of_each_period() {
+    let window = monthly();
+
+    assert_eq!(window.next_opening(START).unwrap(), None);
+    assert_eq!(window.next_opening(START + DAY - 1).unwrap(), None);
+    assert_eq!(window.next_opening(START + 30 * DAY).unwrap(), None);
+    assert_eq!(window.next_opening(START + 61 * DAY - 1).unwrap(), None);
+}
+
+#[test]
+fn test_closed_windows_name_the_next_opening() {
+    let window = monthly();
+
+    assert_eq!(
+        window.next_opening(START + DAY).unwrap(),
+        Some(START + 30 * DAY)
+    );
+    assert_eq!(
+        window.next_opening(START + 45 * DAY).unwrap(),
+        Some(START + 60 * DAY)
+    );
+    // Periods also count back from the start
+    assert_eq!(window.next_opening(START - DAY).unwrap(), Some(START));
+    assert_eq!(window.next_opening(START - 30 * DAY).unwrap(), None);
+}
+
+#[test]
+fn test_pools_without_a_window_always_allow_claims() {
+    let pool = sample_stake_pool();
+    assert!(pool.check_claim_window(START + DAY).is_ok());
+}
+
+#[test]
+fn test_claims_outside_the_window_are_rejected() {
+    let mut pool = sample_stake_pool();
+    pool.claim_window = Some(monthly());
+
+    assert!(pool.check_claim_window(START + 30 * DAY + 3_600).is_ok());
+    assert_eq!(pool.check_claim_window(START + 2 * DAY), closed());
+}
+
+#[test]
+fn test_windows_must_recur_and_close() {
+    let invalid = || Err(StakePoolError::InvalidParameters.into());
+    let window = |period, duration| ClaimWindow {
+        start: START,
+        period,
+        duration,
+    };
+
+    assert!(window(DAY, DAY).validate().is_ok());
+    assert_eq!(window(0, 0).validate(), invalid());
+    assert_eq!(window(DAY, 0).validate(), invalid());
+    assert_eq!(window(DAY, DAY + 1).validate(), invalid());
+    assert_eq!(window(-DAY, -1).validate(), invalid());
+}
+
+#[test]
+fn test_claim_window_updates_are_admin_only() {
+    let update = PoolUpdate {
+        claim_window: Some(Some(monthly())),
+        ..Default::default()
+    };
+    assert_eq!(update.required_roles(), None);
+}
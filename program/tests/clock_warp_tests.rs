@@ -0,0 +1,300 @@
+// ============================================================================
+// Clock Warp Tests
+// ============================================================================
+// End-to-end runs of the time-dependent paths through LiteSVM, moving the
+// Clock sysvar with the client's test utilities instead of waiting: claims
+// before and after the lockup, early unstakes forfeiting rewards, the reward
+// rate time-lock and the pool end date.
+//
+// Run tests: cargo xtask test-all --test clock_warp_tests
+
+mod common;
+
+use litesvm::LiteSVM;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use your_wallet_stake_pool::{constants::REWARD_RATE_CHANGE_DELAY, error::StakePoolError};
+use your_wallet_stake_pool_client::{
+    instruction::{self, InitializePoolArgs, StakeArgs, UpdatePoolArgs},
+    test_utils::{self, warp_forward, warp_past_lockup, warp_to},
+    PoolKeys,
+};
+
+use common::*;
+
+const DAY: i64 = 86_400;
+const START_TIMESTAMP: i64 = 1_700_000_000;
+const LOCKUP_PERIOD: i64 = 30 * DAY;
+const STAKE_AMOUNT: u64 = 1_000_000;
+/// 10% of STAKE_AMOUNT, the reward of a full round at the pool's rate
+const ROUND_REWARDS: u64 = 100_000;
+
+/// A funded 30-day pool on Token-2022 and a staker holding stake tokens
+struct Fixture {
+    svm: LiteSVM,
+    authority: Keypair,
+    staker: Keypair,
+    keys: PoolKeys,
+    staker_stake_account: Pubkey,
+    staker_reward_account: Pubkey,
+}
+
+fn setup(args: InitializePoolArgs) -> Fixture {
+    let mut svm = LiteSVM::new();
+    test_utils::add_program(&mut svm, programs_dir().join("your_wallet_stake_pool.so"));
+    warp_to(&mut svm, START_TIMESTAMP);
+
+    let authority = test_utils::funded_keypair(&mut svm);
+    let staker = test_utils::funded_keypair(&mut svm);
+    let keys = test_utils::setup_pool(
+        &mut svm,
+        &authority,
+        InitializePoolArgs {
+            reward_rate: 100_000_000, // 10%
+            min_stake_amount: 1_000,
+            lockup_period: LOCKUP_PERIOD,
+            ..args
+        },
+    );
+    test_utils::fund_rewards(&mut svm, &authority, &keys, 1_000_000_000);
+
+    let staker_stake_account = test_utils::create_token_account(
+        &mut svm,
+        &authority,
+        &keys.stake_mint,
+        &staker.pubkey(),
+        2 * STAKE_AMOUNT,
+    );
+    let staker_reward_account = test_utils::create_token_account(
+        &mut svm,
+        &authority,
+        &keys.reward_mint,
+        &staker.pubkey(),
+        0,
+    );
+
+    Fixture {
+        svm,
+        authority,
+        staker,
+        keys,
+        staker_stake_account,
+        staker_reward_account,
+    }
+}
+
+impl Fixture {
+    /// Stake STAKE_AMOUNT into the staker's stake account `index`
+    fn stake(&mut self, index: u64) -> Pubkey {
+        let stake = self.stake_instruction(index);
+        test_utils::send(&mut self.svm, &[stake], &self.staker, &[]);
+        instruction::stake_account_address(&self.keys.pool, &self.staker.pubkey(), index)
+    }
+
+    fn stake_instruction(&self, index: u64) -> solana_sdk::instruction::Instruction {
+        instruction::stake(
+            &self.keys,
+            &self.staker.pubkey(),
+            &self.staker_stake_account,
+            &self.staker.pubkey(),
+            StakeArgs::new(STAKE_AMOUNT, index),
+        )
+    }
+
+    fn claim(&mut self, index: u64) {
+        test_utils::send(
+            &mut self.svm,
+            &[instruction::claim_rewards(
+                &self.keys,
+                &self.staker.pubkey(),
+                index,
+                &self.staker_reward_account,
+                None,
+            )],
+            &self.staker,
+            &[],
+        );
+    }
+
+    fn unstake(&mut self, index: u64, amount: u64) {
+        test_utils::send(
+            &mut self.svm,
+            &[instruction::unstake(
+                &self.keys,
+                &self.staker.pubkey(),
+                index,
+                &self.staker_stake_account,
+                amount,
+                None,
+                None,
+            )],
+            &self.staker,
+            &[],
+        );
+    }
+
+    fn reward_balance(&self) -> u64 {
+        test_utils::token_balance(&self.svm, &self.staker_reward_account)
+    }
+}
+
+#[test]
+fn test_claim_pays_the_round_once_the_lockup_has_expired() {
+    let mut fixture = setup(InitializePoolArgs::default());
+    let stake_account = fixture.stake(0);
+    assert_eq!(
+        test_utils::lockup_end(&fixture.svm, &stake_account),
+        START_TIMESTAMP + LOCKUP_PERIOD
+    );
+
+    // A second before the lockup ends the claim succeeds without a transfer
+    warp_to(&mut fixture.svm, START_TIMESTAMP + LOCKUP_PERIOD - 1);
+    fixture.claim(0);
+    assert_eq!(fixture.reward_balance(), 0);
+
+    warp_past_lockup(&mut fixture.svm, &stake_account);
+    fixture.claim(0);
+    assert_eq!(fixture.reward_balance(), ROUND_REWARDS);
+
+    let stake = test_utils::load_stake_account(&fixture.svm, &stake_account);
+    assert_eq!(stake.claimed_rewards, ROUND_REWARDS);
+    let pool = test_utils::load_stake_pool(&fixture.svm, &fixture.keys.pool);
+    assert_eq!(pool.total_rewards_owed, 0);
+}
+
+#[test]
+fn test_early_unstake_returns_the_principal_and_forfeits_the_rewards() {
+    let mut fixture = setup(InitializePoolArgs::default());
+    let stake_account = fixture.stake(0);
+    let pool = test_utils::load_stake_pool(&fixture.svm, &fixture.keys.pool);
+    assert_eq!(pool.total_rewards_owed, ROUND_REWARDS);
+
+    warp_forward(&mut fixture.svm, LOCKUP_PERIOD / 2);
+    fixture.unstake(0, STAKE_AMOUNT);
+
+    assert_eq!(
+        test_utils::token_balance(&fixture.svm, &fixture.staker_stake_account),
+        2 * STAKE_AMOUNT
+    );
+    let stake = test_utils::load_stake_account(&fixture.svm, &stake_account);
+    assert_eq!(stake.amount_staked, 0);
+    assert_eq!(stake.unclaimed_rewards().unwrap(), 0);
+    let pool = test_utils::load_stake_pool(&fixture.svm, &fixture.keys.pool);
+    assert_eq!(pool.total_rewards_owed, 0);
+
+    // Nothing is left to claim once the lockup would have ended
+    warp_to(&mut fixture.svm, START_TIMESTAMP + LOCKUP_PERIOD);
+    fixture.claim(0);
+    assert_eq!(fixture.reward_balance(), 0);
+}
+
+#[test]
+fn test_enforced_lockup_rejects_an_early_unstake_until_it_expires() {
+    let mut fixture = setup(InitializePoolArgs {
+        enforce_lockup: true,
+        ..InitializePoolArgs::default()
+    });
+    let stake_account = fixture.stake(0);
+    let unstake = instruction::unstake(
+        &fixture.keys,
+        &fixture.staker.pubkey(),
+        0,
+        &fixture.staker_stake_account,
+        STAKE_AMOUNT,
+        None,
+        None,
+    );
+
+    warp_to(&mut fixture.svm, START_TIMESTAMP + LOCKUP_PERIOD - 1);
+    assert_eq!(
+        test_utils::send_expecting_error(
+            &mut fixture.svm,
+            std::slice::from_ref(&unstake),
+            &fixture.staker,
+            &[]
+        ),
+        Some(StakePoolError::LockupNotExpired)
+    );
+
+    warp_past_lockup(&mut fixture.svm, &stake_account);
+    test_utils::send(&mut fixture.svm, &[unstake], &fixture.staker, &[]);
+    assert_eq!(
+        test_utils::token_balance(&fixture.svm, &fixture.staker_stake_account),
+        2 * STAKE_AMOUNT
+    );
+}
+
+#[test]
+fn test_reward_rate_change_finalizes_after_its_time_lock() {
+    let mut fixture = setup(InitializePoolArgs {
+        reward_rate_change_delay: Some(REWARD_RATE_CHANGE_DELAY as u32),
+        ..InitializePoolArgs::default()
+    });
+    test_utils::send(
+        &mut fixture.svm,
+        &[instruction::update_pool(
+            &fixture.keys.pool,
+            &fixture.authority.pubkey(),
+            UpdatePoolArgs {
+                reward_rate: Some(200_000_000),
+                ..UpdatePoolArgs::default()
+            },
+        )],
+        &fixture.authority,
+        &[],
+    );
+    let pool = test_utils::load_stake_pool(&fixture.svm, &fixture.keys.pool);
+    assert_eq!(pool.pending_reward_rate, Some(200_000_000));
+    assert_eq!(pool.reward_rate, 100_000_000);
+
+    let finalize = instruction::finalize_reward_rate_change(&fixture.keys.pool, None);
+    warp_forward(&mut fixture.svm, REWARD_RATE_CHANGE_DELAY - 1);
+    assert_eq!(
+        test_utils::send_expecting_error(
+            &mut fixture.svm,
+            std::slice::from_ref(&finalize),
+            &fixture.authority,
+            &[]
+        ),
+        Some(StakePoolError::RewardRateChangeDelayNotElapsed)
+    );
+
+    warp_forward(&mut fixture.svm, 1);
+    test_utils::send(&mut fixture.svm, &[finalize], &fixture.authority, &[]);
+    let pool = test_utils::load_stake_pool(&fixture.svm, &fixture.keys.pool);
+    assert_eq!(pool.reward_rate, 200_000_000);
+    assert_eq!(pool.pending_reward_rate, None);
+    assert_eq!(
+        pool.last_rate_change,
+        Some(START_TIMESTAMP + REWARD_RATE_CHANGE_DELAY)
+    );
+}
+
+#[test]
+fn test_pool_end_date_stops_new_stakes_but_not_withdrawals() {
+    let end_date = START_TIMESTAMP + 2 * LOCKUP_PERIOD;
+    let mut fixture = setup(InitializePoolArgs {
+        pool_end_date: Some(end_date),
+        ..InitializePoolArgs::default()
+    });
+    let stake_account = fixture.stake(0);
+
+    warp_to(&mut fixture.svm, end_date);
+    let stake = fixture.stake_instruction(1);
+    assert_eq!(
+        test_utils::send_expecting_error(&mut fixture.svm, &[stake], &fixture.staker, &[]),
+        Some(StakePoolError::PoolEnded)
+    );
+
+    // Stakes made before the end still mature and pay out
+    assert!(test_utils::lockup_end(&fixture.svm, &stake_account) < end_date);
+    fixture.claim(0);
+    fixture.unstake(0, STAKE_AMOUNT);
+    assert_eq!(fixture.reward_balance(), ROUND_REWARDS);
+    assert_eq!(
+        test_utils::token_balance(&fixture.svm, &fixture.staker_stake_account),
+        2 * STAKE_AMOUNT
+    );
+}
@@ -0,0 +1,232 @@
+// ============================================================================
+// Clone Pool Tests
+// ============================================================================
+// ClonePool creates a new pool, vaults included, with the parameters and
+// UpdatePool settings of an existing pool of the same mints, so recurring
+// campaigns are set up in one atomic instruction. Only the new pool's id and
+// campaign dates are given anew.
+
+#![allow(deprecated)]
+
+mod common;
+
+use litesvm::LiteSVM;
+use solana_program::{clock::Clock, program_pack::Pack};
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+use spl_token_2022::{instruction as token_instruction, state::Mint};
+use your_wallet_stake_pool::state::{
+    ClaimWindow, OutflowLimit, RewardTier, StakePool, WindDownRewards,
+};
+use your_wallet_stake_pool_client::{
+    instruction::{self, ClonePoolArgs, InitializePoolArgs, UpdatePoolArgs},
+    PoolKeys, ID,
+};
+
+use common::*;
+
+const START_TIMESTAMP: i64 = 1_700_000_000;
+const DAY: i64 = 86_400;
+
+fn claim_window() -> ClaimWindow {
+    ClaimWindow {
+        start: START_TIMESTAMP,
+        period: 30 * DAY,
+        duration: DAY,
+    }
+}
+
+fn outflow_limit() -> OutflowLimit {
+    OutflowLimit {
+        max_unstake_bps: 1_000,
+        max_claim_amount: 1_000_000,
+    }
+}
+
+#[test]
+fn test_clones_copy_the_settings_update_pool_manages() {
+    let funder = Pubkey::new_unique();
+    let mut source = sample_stake_pool();
+    source.unstake_cooldown = 3 * DAY;
+    source.lockup_extension_rate = 50_000_000;
+    source.reward_funders[0] = Some(funder);
+    source.allowlist_merkle_root = Some([7; 32]);
+    source.claim_window = Some(claim_window());
+    source.outflow_limit = Some(outflow_limit());
+    source.pool_manager = Some(funder);
+    source.keeper_bounty = 5_000;
+    source.max_total_rewards = Some(10_000_000);
+    source.enforce_min_remaining_stake = true;
+    source.wind_down_rewards = WindDownRewards::Pay;
+    source.points_rate = 3;
+    source.deny_list_required = true;
+    source.allow_third_party_stake = true;
+    source.cohort_duration = 7 * DAY;
+    // Pool-specific state stays with the source
+    source.is_paused = true;
+    source.allowlist_required = true;
+    source.receipt_mint = Some(Pubkey::new_unique());
+    source.hook_program = Some(Pubkey::new_unique());
+    source.total_staked = 1_000;
+    source.total_rewards_committed = 2_000;
+    source.open_reward_cohorts = 1;
+
+    let mut clone = sample_stake_pool();
+    clone.copy_settings_from(&source);
+
+    assert_eq!(clone.unstake_cooldown, 3 * DAY);
+    assert_eq!(clone.lockup_extension_rate, 50_000_000);
+    assert_eq!(clone.reward_funders[0], Some(funder));
+    assert_eq!(clone.allowlist_merkle_root, Some([7; 32]));
+    assert_eq!(clone.claim_window, Some(claim_window()));
+    assert_eq!(clone.outflow_limit, Some(outflow_limit()));
+    assert_eq!(clone.pool_manager, Some(funder));
+    assert_eq!(clone.keeper_bounty, 5_000);
+    assert_eq!(clone.max_total_rewards, Some(10_000_000));
+    assert!(clone.enforce_min_remaining_stake);
+    assert_eq!(clone.wind_down_rewards, WindDownRewards::Pay);
+    assert_eq!(clone.points_rate, 3);
+    assert!(clone.deny_list_required);
+    assert!(clone.allow_third_party_stake);
+    assert_eq!(clone.cohort_duration, 7 * DAY);
+    assert!(!clone.is_paused);
+    assert!(!clone.allowlist_required);
+    assert_eq!(clone.receipt_mint, None);
+    assert_eq!(clone.hook_program, None);
+    assert_eq!(clone.total_staked, 0);
+    assert_eq!(clone.total_rewards_committed, 0);
+    assert_eq!(clone.open_reward_cohorts, 0);
+}
+
+fn send(svm: &mut LiteSVM, instructions: &[Instruction], payer: &Keypair, signers: &[&Keypair]) {
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&payer.pubkey()),
+        &all_signers,
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx)
+        .unwrap_or_else(|e| panic!("Transaction failed: {:?}\n{:#?}", e.err, e.meta.logs));
+    svm.expire_blockhash();
+}
+
+fn create_mint(svm: &mut LiteSVM, payer: &Keypair) -> Pubkey {
+    let mint = Keypair::new();
+    let rent = svm.minimum_balance_for_rent_exemption(Mint::LEN);
+    let instructions = [
+        system_instruction::create_account(
+            &payer.pubkey(),
+            &mint.pubkey(),
+            rent,
+            Mint::LEN as u64,
+            &spl_token_2022::id(),
+        ),
+        token_instruction::initialize_mint(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            &payer.pubkey(),
+            None,
+            6,
+        )
+        .unwrap(),
+    ];
+    send(svm, &instructions, payer, &[&mint]);
+    mint.pubkey()
+}
+
+#[test]
+fn test_clone_pool_creates_a_pool_with_the_source_settings() {
+    let mut svm = LiteSVM::new();
+    svm.add_program(spl_token_2022::id(), &load_spl_token_program())
+        .unwrap();
+    svm.add_program(ID, &load_program()).unwrap();
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = START_TIMESTAMP;
+    svm.set_sysvar(&clock);
+
+    let payer = Keypair::new();
+    let authority = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 10_000_000_000).unwrap();
+    svm.airdrop(&authority.pubkey(), 1_000_000_000).unwrap();
+    initialize_program_authority(&mut svm, &payer, &authority);
+
+    let stake_mint = create_mint(&mut svm, &payer);
+    let reward_mint = create_mint(&mut svm, &payer);
+    let source = PoolKeys::with_pda_vaults(stake_mint, reward_mint, 0, spl_token_2022::id());
+    let tiers = vec![RewardTier {
+        min_amount: 1_000_000,
+        multiplier_bps: 12_000,
+    }];
+    send(
+        &mut svm,
+        &[
+            instruction::initialize_pool(
+                &source,
+                &payer.pubkey(),
+                InitializePoolArgs {
+                    reward_rate: 100_000_000,
+                    min_stake_amount: 1_000,
+                    lockup_period: 7 * DAY,
+                    referral_bps: 500,
+                    deposit_fee_bps: 100,
+                    reward_tiers: tiers.clone(),
+                    pool_end_date: Some(START_TIMESTAMP + 30 * DAY),
+                    ..InitializePoolArgs::default()
+                },
+            ),
+            instruction::update_pool(
+                &source.pool,
+                &payer.pubkey(),
+                UpdatePoolArgs {
+                    unstake_cooldown: Some(DAY),
+                    claim_window: Some(Some(claim_window())),
+                    outflow_limit: Some(Some(outflow_limit())),
+                    ..UpdatePoolArgs::default()
+                },
+            ),
+        ],
+        &payer,
+        &[],
+    );
+
+    let clone = PoolKeys::with_pda_vaults(stake_mint, reward_mint, 1, spl_token_2022::id());
+    send(
+        &mut svm,
+        &[instruction::clone_pool(
+            &source.pool,
+            &clone,
+            &payer.pubkey(),
+            ClonePoolArgs {
+                pool_id: 1,
+                pool_end_date: Some(START_TIMESTAMP + 60 * DAY),
+                bonus_end_ts: None,
+                registry_page: 0,
+            },
+        )],
+        &payer,
+        &[],
+    );
+
+    let pool: StakePool = load_stake_pool(&svm, &clone.pool);
+    assert_eq!(pool.pool_id, 1);
+    assert_eq!(pool.stake_vault, clone.stake_vault);
+    assert_eq!(pool.fee_vault, clone.fee_vault);
+    assert_eq!(pool.reward_rate, 100_000_000);
+    assert_eq!(pool.min_stake_amount, 1_000);
+    assert_eq!(pool.lockup_period, 7 * DAY);
+    assert_eq!(pool.referral_bps, 500);
+    assert_eq!(pool.deposit_fee_bps, 100);
+    assert_eq!(pool.reward_tiers[0], Some(tiers[0]));
+    assert_eq!(pool.pool_end_date, Some(START_TIMESTAMP + 60 * DAY));
+    assert_eq!(pool.unstake_cooldown, DAY);
+    assert_eq!(pool.claim_window, Some(claim_window()));
+    assert_eq!(pool.outflow_limit, Some(outflow_limit()));
+    assert!(svm.get_account(&clone.stake_vault).is_some());
+}
@@ -0,0 +1,54 @@
+// ============================================================================
+// Pool Closure Tests
+// ============================================================================
+// Closing a pool is a two-step process: the admin pauses it, then ClosePool
+// sweeps the vaults and reclaims rent once nothing is staked or owed.
+
+mod common;
+
+use your_wallet_stake_pool::state::StakePool;
+
+use common::*;
+
+fn paused_empty_pool() -> StakePool {
+    let mut pool = sample_stake_pool();
+    pool.is_paused = true;
+    pool.total_staked = 0;
+    pool.total_rewards_owed = 0;
+    pool
+}
+
+#[test]
+fn test_paused_empty_pool_is_closable() {
+    assert!(paused_empty_pool().is_closable());
+}
+
+#[test]
+fn test_pool_must_be_paused_before_closing() {
+    let mut pool = paused_empty_pool();
+    pool.is_paused = false;
+
+    assert!(!pool.is_closable());
+}
+
+#[test]
+fn test_pool_with_stake_or_owed_rewards_is_not_closable() {
+    let mut pool = paused_empty_pool();
+    pool.total_staked = 1;
+    assert!(!pool.is_closable());
+
+    let mut pool = paused_empty_pool();
+    pool.total_rewards_owed = 1;
+    assert!(!pool.is_closable());
+}
+
+#[test]
+fn test_pool_with_active_stake_accounts_is_not_closable() {
+    let mut pool = paused_empty_pool();
+    pool.active_stake_count = Some(1);
+    assert!(!pool.is_closable());
+
+    // Pools created before the counter existed rely on the totals alone
+    pool.active_stake_count = None;
+    assert!(pool.is_closable());
+}
@@ -3,10 +3,11 @@
 // ============================================================================
 // Shared utilities for LiteSVM tests
 
-use borsh::BorshDeserialize;
 use litesvm::LiteSVM;
 use solana_sdk::pubkey::Pubkey;
-use your_wallet_stake_pool::state::{StakeAccount, StakePool};
+use your_wallet_stake_pool::state::{
+    ClusterProfile, Key, OutflowWindow, RewardRateMode, StakeAccount, StakePool, WindDownRewards,
+};
 
 /// Program ID constant
 pub const PROGRAM_ID: &str = "8PtjrGvKNeZt2vCmRkSPGjss7TAFhvxux2N8r67UMKBx";
@@ -15,27 +16,43 @@ pub const PROGRAM_ID: &str = "8PtjrGvKNeZt2vCmRkSPGjss7TAFhvxux2N8r67UMKBx";
 // Program Loading
 // ============================================================================
 
+/// Directory holding the program binaries loaded into LiteSVM
+///
+/// `SBF_OUT_DIR` is set by `cargo test-sbf` and `cargo xtask test-all`; otherwise
+/// the workspace `target/deploy` written by `cargo xtask build-sbf` is used.
+#[allow(dead_code)]
+pub fn programs_dir() -> std::path::PathBuf {
+    std::env::var_os("SBF_OUT_DIR")
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| {
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/deploy")
+        })
+}
+
 /// Load the compiled program binary
-/// Tries multiple paths to handle different test execution contexts
+#[allow(dead_code)]
 pub fn load_program() -> Vec<u8> {
-    let paths = [
-        "target/sbpf-solana-solana/release/your_wallet_stake_pool.so",
-        "../target/sbpf-solana-solana/release/your_wallet_stake_pool.so",
-        "../../target/sbpf-solana-solana/release/your_wallet_stake_pool.so",
-        "target/deploy/your_wallet_stake_pool.so",
-        "../target/deploy/your_wallet_stake_pool.so",
-    ];
-
-    for path in &paths {
-        if let Ok(data) = std::fs::read(path) {
-            return data;
-        }
-    }
+    let path = programs_dir().join("your_wallet_stake_pool.so");
+    std::fs::read(&path).unwrap_or_else(|e| {
+        panic!(
+            "Failed to load program from {}: {}. Run `cargo xtask build-sbf` first.",
+            path.display(),
+            e
+        )
+    })
+}
 
-    panic!(
-        "Failed to load program. Run 'cargo build-sbf' first.\nTried paths: {:?}",
-        paths
-    );
+/// Load the SPL Token 2022 program binary dumped from mainnet
+#[allow(dead_code)]
+pub fn load_spl_token_program() -> Vec<u8> {
+    let path = programs_dir().join("spl_token_2022.so");
+    std::fs::read(&path).unwrap_or_else(|e| {
+        panic!(
+            "Failed to load SPL Token 2022 from {}: {}. Run `cargo xtask dump-token` first.",
+            path.display(),
+            e
+        )
+    })
 }
 
 // ============================================================================
@@ -43,6 +60,7 @@ pub fn load_program() -> Vec<u8> {
 // ============================================================================
 
 /// Derive the pool PDA address
+#[allow(dead_code)]
 pub fn get_pool_pda(stake_mint: &Pubkey, pool_id: u64) -> (Pubkey, u8) {
     let program_id = PROGRAM_ID.parse::<Pubkey>().unwrap();
     Pubkey::find_program_address(
@@ -80,6 +98,13 @@ pub fn get_reward_vault_pda(pool: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"reward_vault", pool.as_ref()], &program_id)
 }
 
+/// Derive the fee vault PDA address
+#[allow(dead_code)]
+pub fn get_fee_vault_pda(pool: &Pubkey) -> (Pubkey, u8) {
+    let program_id = PROGRAM_ID.parse::<Pubkey>().unwrap();
+    Pubkey::find_program_address(&[b"fee_vault", pool.as_ref()], &program_id)
+}
+
 /// Derive the program authority PDA address
 #[allow(dead_code)]
 pub fn get_program_authority_pda() -> (Pubkey, u8) {
@@ -87,6 +112,20 @@ pub fn get_program_authority_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[b"program_authority"], &program_id)
 }
 
+/// Derive the receipt mint PDA address
+#[allow(dead_code)]
+pub fn get_receipt_mint_pda(pool: &Pubkey) -> (Pubkey, u8) {
+    let program_id = PROGRAM_ID.parse::<Pubkey>().unwrap();
+    Pubkey::find_program_address(&[b"receipt_mint", pool.as_ref()], &program_id)
+}
+
+/// Derive the mint registry PDA address
+#[allow(dead_code)]
+pub fn get_mint_registry_pda(stake_mint: &Pubkey) -> (Pubkey, u8) {
+    let program_id = PROGRAM_ID.parse::<Pubkey>().unwrap();
+    Pubkey::find_program_address(&[b"mint_registry", stake_mint.as_ref()], &program_id)
+}
+
 /// Initialize the program authority account (required for pool creation)
 /// Returns the program authority PDA address
 #[allow(dead_code)]
@@ -114,9 +153,11 @@ pub fn initialize_program_authority(
             AccountMeta::new(payer.pubkey(), true),
             AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
         ],
-        data: StakePoolInstruction::InitializeProgramAuthority
-            .try_to_vec()
-            .unwrap(),
+        data: StakePoolInstruction::InitializeProgramAuthority {
+            cluster_profile: ClusterProfile::Mainnet,
+        }
+        .try_to_vec()
+        .unwrap(),
     };
 
     let tx = Transaction::new_signed_with_payer(
@@ -143,14 +184,12 @@ pub fn load_stake_pool(svm: &LiteSVM, pool_address: &Pubkey) -> StakePool {
         .get_account(pool_address)
         .expect("Pool account should exist");
 
-    // Use deserialize instead of try_from_slice to handle trailing zeros
     // The account may have extra space allocated but only partial data written
-    let mut data: &[u8] = &account.data;
-    StakePool::deserialize(&mut data).unwrap_or_else(|e| {
+    StakePool::from_account_data(&account.data).unwrap_or_else(|e| {
         eprintln!("Failed to deserialize pool:");
         eprintln!("  Account data length: {} bytes", account.data.len());
         eprintln!("  Error: {}", e);
-        if account.data.len() > 0 {
+        if !account.data.is_empty() {
             eprintln!(
                 "  First 32 bytes: {:?}",
                 &account.data[..account.data.len().min(32)]
@@ -167,9 +206,249 @@ pub fn load_stake_account(svm: &LiteSVM, stake_account_address: &Pubkey) -> Stak
         .get_account(stake_account_address)
         .expect("Stake account should exist");
 
-    // Use deserialize instead of try_from_slice to handle trailing zeros
-    let mut data: &[u8] = &account.data;
-    StakeAccount::deserialize(&mut data).expect("Failed to deserialize stake account")
+    StakeAccount::from_account_data(&account.data).expect("Failed to deserialize stake account")
+}
+
+// ============================================================================
+// State Fixtures
+// ============================================================================
+
+/// Build an in-memory StakePool with sensible defaults for pure logic tests
+/// (10% reward rate, 1 day lockup, no end date)
+#[allow(dead_code)]
+pub fn sample_stake_pool() -> StakePool {
+    StakePool {
+        key: Key::StakePoolV2,
+        stake_mint: Pubkey::new_unique(),
+        reward_mint: Pubkey::new_unique(),
+        pool_id: 0,
+        stake_vault: Pubkey::new_unique(),
+        reward_vault: Pubkey::new_unique(),
+        total_staked: 0,
+        total_rewards_owed: 0,
+        reward_rate: 100_000_000,
+        min_stake_amount: 0,
+        lockup_period: 86400,
+        is_paused: false,
+        enforce_lockup: false,
+        bump: 255,
+        pool_end_date: None,
+        pending_reward_rate: None,
+        reward_rate_change_timestamp: None,
+        last_rate_change: None,
+        referral_bps: 0,
+        early_unstake_bps: 0,
+        stake_vault_bump: None,
+        reward_vault_bump: None,
+        fee_vault: Pubkey::new_unique(),
+        deposit_fee_bps: 0,
+        withdraw_fee_bps: 0,
+        fee_vault_bump: None,
+        total_rewards_funded: 0,
+        reward_funders: [None; StakePool::MAX_REWARD_FUNDERS],
+        receipt_mint: None,
+        expired_rewards: 0,
+        unstake_cooldown: 0,
+        is_registered: false,
+        reward_rate_change_delay: 0,
+        pending_lockup_period: None,
+        pending_enforce_lockup: None,
+        lockup_change_timestamp: None,
+        min_reward_rate_bound: None,
+        max_reward_rate_bound: None,
+        active_stake_count: None,
+        reward_tiers: [None; StakePool::MAX_REWARD_TIERS],
+        bonus_end_ts: None,
+        bonus_multiplier_bps: 0,
+        lockup_extension_rate: 0,
+        allowlist_required: false,
+        allowlist_merkle_root: None,
+        claim_window: None,
+        outflow_limit: None,
+        outflow_window: OutflowWindow::default(),
+        pool_manager: None,
+        keeper_bounty: 0,
+        last_keeper_bounty_at: 0,
+        max_total_rewards: None,
+        total_rewards_committed: 0,
+        reward_rate_mode: RewardRateMode::PerLockup,
+        reward_decimals_shift: 0,
+        enforce_min_remaining_stake: false,
+        wind_down_rewards: WindDownRewards::Expire,
+        hook_program: None,
+        points_rate: 0,
+        deny_list_required: false,
+        allow_third_party_stake: false,
+        event_nonce: 0,
+        cohort_duration: 0,
+        open_reward_cohorts: 0,
+        _reserved: [0; 2],
+    }
+}
+
+/// Stake timestamp of the in-memory sample stake accounts
+#[allow(dead_code)]
+pub const SAMPLE_STAKE_TIMESTAMP: i64 = 1_700_000_000;
+
+/// Build an in-memory StakeAccount for the given pool and owner (index 0)
+#[allow(dead_code)]
+pub fn sample_stake_account(pool: &Pubkey, owner: &Pubkey, amount_staked: u64) -> StakeAccount {
+    StakeAccount {
+        key: Key::StakeAccountV2,
+        pool: *pool,
+        owner: *owner,
+        index: 0,
+        amount_staked,
+        stake_timestamp: SAMPLE_STAKE_TIMESTAMP,
+        claimed_rewards: 0,
+        bump: 255,
+        referrer: None,
+        early_unstaked: 0,
+        auto_relock: false,
+        carried_rewards: 0,
+        delegate: None,
+        pending_withdraw_amount: 0,
+        request_timestamp: None,
+        locked_reward_rate: 100_000_000,
+        label: [0; StakeAccount::LABEL_LEN],
+        pending_owner: None,
+        lockup_extension: 0,
+        vesting_amount: 0,
+        vesting_start: 0,
+        vesting_cliff: 0,
+        vesting_duration: 0,
+        pending_slash_bps: 0,
+        points_synced_at: 0,
+        reward_cohort: 0,
+    }
+}
+
+// ============================================================================
+// Account Fixtures
+// ============================================================================
+// Snapshots of real cluster accounts for reproducing production bugs locally.
+// Capture with `cargo xtask snapshot <address>..` (or `solana account <address>
+// --output json`) into tests/fixtures, then load them with set_fixture_account.
+
+/// An account snapshot in the `solana account --output json` layout
+#[allow(dead_code)]
+pub struct Fixture {
+    pub pubkey: Pubkey,
+    pub account: solana_sdk::account::Account,
+}
+
+/// Resolve a fixture name or path (relative paths are under tests/fixtures)
+#[allow(dead_code)]
+pub fn fixture_path(name: impl AsRef<std::path::Path>) -> std::path::PathBuf {
+    let name = name.as_ref();
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name);
+    if path.extension().is_none() {
+        path.with_extension("json")
+    } else {
+        path
+    }
+}
+
+/// Read a fixture file, e.g. `load_fixture("<pool address>")`
+#[allow(dead_code)]
+pub fn load_fixture(name: impl AsRef<std::path::Path>) -> Fixture {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    let path = fixture_path(name);
+    let contents = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Failed to read fixture {}: {}", path.display(), e));
+    let json: serde_json::Value = serde_json::from_str(&contents)
+        .unwrap_or_else(|e| panic!("Invalid fixture {}: {}", path.display(), e));
+
+    let account = &json["account"];
+    let data = account["data"][0]
+        .as_str()
+        .unwrap_or_else(|| panic!("Fixture {} has no base64 data", path.display()));
+
+    Fixture {
+        pubkey: json["pubkey"].as_str().unwrap().parse().unwrap(),
+        account: solana_sdk::account::Account {
+            lamports: account["lamports"].as_u64().unwrap(),
+            data: STANDARD.decode(data).unwrap(),
+            owner: account["owner"].as_str().unwrap().parse().unwrap(),
+            executable: account["executable"].as_bool().unwrap_or(false),
+            rent_epoch: account["rentEpoch"].as_u64().unwrap_or(u64::MAX),
+        },
+    }
+}
+
+/// Write an account held by LiteSVM as a fixture (e.g. to keep a reproduced state)
+#[allow(dead_code)]
+pub fn save_fixture(svm: &LiteSVM, pubkey: &Pubkey, path: impl AsRef<std::path::Path>) {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    let account = svm
+        .get_account(pubkey)
+        .expect("Fixture account should exist");
+    let json = serde_json::json!({
+        "pubkey": pubkey.to_string(),
+        "account": {
+            "lamports": account.lamports,
+            "data": [STANDARD.encode(&account.data), "base64"],
+            "owner": account.owner.to_string(),
+            "executable": account.executable,
+            "rentEpoch": account.rent_epoch,
+            "space": account.data.len(),
+        },
+    });
+
+    let path = fixture_path(path);
+    std::fs::write(&path, serde_json::to_string_pretty(&json).unwrap() + "\n")
+        .unwrap_or_else(|e| panic!("Failed to write fixture {}: {}", path.display(), e));
+}
+
+/// Whether `data` is one of this program's state accounts (discriminator and size)
+fn is_program_state(data: &[u8]) -> bool {
+    use your_wallet_stake_pool::state::{
+        MintRegistry, ProgramAuthority, StakeAccountV1, StakePoolV1, DISCRIMINATOR_LEN,
+    };
+
+    // Legacy layouts, and V2 accounts written before the discriminator, are
+    // included so snapshots of unmigrated accounts can exercise MigratePool locally
+    let len = match data.first() {
+        _ if data.starts_with(&StakePool::DISCRIMINATOR) => StakePool::LEN,
+        _ if data.starts_with(&StakeAccount::DISCRIMINATOR) => StakeAccount::LEN,
+        _ if data.starts_with(&ProgramAuthority::DISCRIMINATOR) => ProgramAuthority::LEN,
+        Some(&key) if key == Key::StakePoolV1 as u8 => StakePoolV1::LEN,
+        Some(&key) if key == Key::StakeAccountV1 as u8 => StakeAccountV1::LEN,
+        Some(&key) if key == Key::StakePoolV2 as u8 => StakePool::LEN - DISCRIMINATOR_LEN,
+        Some(&key) if key == Key::StakeAccountV2 as u8 => StakeAccount::LEN - DISCRIMINATOR_LEN,
+        Some(&key) if key == Key::ProgramAuthorityV2 as u8 => {
+            ProgramAuthority::LEN - DISCRIMINATOR_LEN
+        }
+        Some(&key) if key == Key::MintRegistry as u8 => MintRegistry::LEN,
+        _ => return false,
+    };
+    data.len() == len
+}
+
+/// Load a fixture into LiteSVM at its original address
+///
+/// Fixes what differs between the snapshot's cluster and the local setup:
+/// - program state accounts are reassigned to the local program ID, so snapshots
+///   from another deployment of the program load unchanged
+/// - lamports are topped up to the rent-exempt minimum for the data length
+#[allow(dead_code)]
+pub fn set_fixture_account(svm: &mut LiteSVM, fixture: &Fixture) -> Pubkey {
+    let mut account = fixture.account.clone();
+
+    if is_program_state(&account.data) {
+        account.owner = PROGRAM_ID.parse().unwrap();
+    }
+    account.lamports = account
+        .lamports
+        .max(svm.minimum_balance_for_rent_exemption(account.data.len()));
+
+    svm.set_account(fixture.pubkey, account)
+        .expect("Failed to set fixture account");
+    fixture.pubkey
 }
 
 // ============================================================================
@@ -0,0 +1,166 @@
+// ============================================================================
+// Compute Unit Regression Tests
+// ============================================================================
+// Runs stake, claim and unstake through LiteSVM and fails when one of them
+// consumes more compute units than its budget. Hot paths re-derive PDAs from
+// the bumps stored in state (`create_program_address`, one hash) instead of
+// searching for them (`find_program_address`, up to 255 hashes); a regression
+// shows up here as a budget overrun.
+//
+// Run tests: cargo xtask test-all --test compute_units_tests
+
+mod common;
+
+use litesvm::LiteSVM;
+use solana_sdk::{
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use your_wallet_stake_pool::constants::MIN_LOCKUP_PERIOD;
+use your_wallet_stake_pool_client::{
+    instruction::{self, InitializePoolArgs, StakeArgs},
+    test_utils::{self, warp_to},
+    PoolKeys, ID,
+};
+
+use common::*;
+
+/// Compute unit budgets of the hot paths; raise one only for a deliberate change
+const STAKE_CU_BUDGET: u64 = 80_000;
+const CLAIM_CU_BUDGET: u64 = 50_000;
+const UNSTAKE_CU_BUDGET: u64 = 60_000;
+
+const START_TIMESTAMP: i64 = 1_700_000_000;
+const STAKE_AMOUNT: u64 = 1_000_000_000;
+
+/// A funded pool on Token-2022 and a staker holding stake tokens
+struct Fixture {
+    svm: LiteSVM,
+    payer: Keypair,
+    staker: Keypair,
+    keys: PoolKeys,
+    staker_stake_account: Pubkey,
+    staker_reward_account: Pubkey,
+}
+
+/// Send `instructions` paid by `payer` and return the compute units consumed
+fn send(
+    svm: &mut LiteSVM,
+    instructions: &[Instruction],
+    payer: &Keypair,
+    signers: &[&Keypair],
+) -> u64 {
+    test_utils::send(svm, instructions, payer, signers).compute_units_consumed
+}
+
+fn assert_within_budget(operation: &str, consumed: u64, budget: u64) {
+    println!("{}: {} CUs (budget {})", operation, consumed, budget);
+    assert!(
+        consumed <= budget,
+        "{} consumed {} CUs, over its budget of {}",
+        operation,
+        consumed,
+        budget
+    );
+}
+
+fn setup() -> Fixture {
+    let mut svm = LiteSVM::new();
+    svm.add_program(spl_token_2022::id(), &load_spl_token_program())
+        .unwrap();
+    svm.add_program(ID, &load_program()).unwrap();
+    warp_to(&mut svm, START_TIMESTAMP);
+
+    let payer = test_utils::funded_keypair(&mut svm);
+    let staker = test_utils::funded_keypair(&mut svm);
+
+    let keys = test_utils::setup_pool(
+        &mut svm,
+        &payer,
+        InitializePoolArgs {
+            reward_rate: 100_000_000, // 10%
+            min_stake_amount: 1_000,
+            lockup_period: MIN_LOCKUP_PERIOD,
+            ..InitializePoolArgs::default()
+        },
+    );
+    test_utils::fund_rewards(&mut svm, &payer, &keys, STAKE_AMOUNT);
+
+    let staker_stake_account = test_utils::create_token_account(
+        &mut svm,
+        &payer,
+        &keys.stake_mint,
+        &staker.pubkey(),
+        STAKE_AMOUNT,
+    );
+    let staker_reward_account =
+        test_utils::create_token_account(&mut svm, &payer, &keys.reward_mint, &staker.pubkey(), 0);
+
+    Fixture {
+        svm,
+        payer,
+        staker,
+        keys,
+        staker_stake_account,
+        staker_reward_account,
+    }
+}
+
+#[test]
+fn test_hot_paths_stay_within_compute_budgets() {
+    let Fixture {
+        mut svm,
+        payer,
+        staker,
+        keys,
+        staker_stake_account,
+        staker_reward_account,
+    } = setup();
+
+    let consumed = send(
+        &mut svm,
+        &[instruction::stake(
+            &keys,
+            &staker.pubkey(),
+            &staker_stake_account,
+            &staker.pubkey(),
+            StakeArgs::new(STAKE_AMOUNT, 0),
+        )],
+        &payer,
+        &[&staker],
+    );
+    assert_within_budget("Stake", consumed, STAKE_CU_BUDGET);
+
+    warp_to(&mut svm, START_TIMESTAMP + MIN_LOCKUP_PERIOD + 1);
+
+    let consumed = send(
+        &mut svm,
+        &[instruction::claim_rewards(
+            &keys,
+            &staker.pubkey(),
+            0,
+            &staker_reward_account,
+            None,
+        )],
+        &payer,
+        &[&staker],
+    );
+    assert_within_budget("ClaimRewards", consumed, CLAIM_CU_BUDGET);
+
+    let consumed = send(
+        &mut svm,
+        &[instruction::unstake(
+            &keys,
+            &staker.pubkey(),
+            0,
+            &staker_stake_account,
+            STAKE_AMOUNT,
+            None,
+            None,
+        )],
+        &payer,
+        &[&staker],
+    );
+    assert_within_budget("Unstake", consumed, UNSTAKE_CU_BUDGET);
+}
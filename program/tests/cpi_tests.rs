@@ -0,0 +1,462 @@
+// ============================================================================
+// CPI Helper Tests
+// ============================================================================
+// The cpi module lets other programs invoke Stake, Unstake, ClaimRewards and
+// FundRewards. These tests capture the instruction each helper invokes and
+// check its accounts, signer/writable flags and data against what the
+// processor parses. The module is only built with the no-entrypoint feature.
+#![cfg(feature = "no-entrypoint")]
+
+use std::sync::Mutex;
+
+use borsh::BorshDeserialize;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program_stubs::{set_syscall_stubs, SyscallStubs},
+};
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    cpi::{self, CpiContext},
+    instruction::{
+        accounts::{ClaimRewardsAccounts, FundRewardsAccounts, StakeAccounts, UnstakeAccounts},
+        StakePoolInstruction,
+    },
+};
+
+/// An invoked instruction and its signer seeds
+type Invocation = (Instruction, Vec<Vec<Vec<u8>>>);
+
+/// Invocation captured by the syscall stub
+static INVOKED: Mutex<Option<Invocation>> = Mutex::new(None);
+/// Serializes tests, since the syscall stubs are process-wide
+static SERIAL: Mutex<()> = Mutex::new(());
+
+struct CaptureInvoke;
+
+impl SyscallStubs for CaptureInvoke {
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        _account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        let seeds = signers_seeds
+            .iter()
+            .map(|seeds| seeds.iter().map(|seed| seed.to_vec()).collect())
+            .collect();
+        *INVOKED.lock().unwrap() = Some((instruction.clone(), seeds));
+        Ok(())
+    }
+}
+
+/// Owned data behind the AccountInfos of a test
+struct TestAccount {
+    key: Pubkey,
+    lamports: u64,
+    data: Vec<u8>,
+    owner: Pubkey,
+}
+
+impl TestAccount {
+    fn new(key: Pubkey) -> Self {
+        Self {
+            key,
+            lamports: 0,
+            data: Vec::new(),
+            owner: Pubkey::default(),
+        }
+    }
+
+    fn info(&mut self) -> AccountInfo<'_> {
+        AccountInfo::new(
+            &self.key,
+            false,
+            false,
+            &mut self.lamports,
+            &mut self.data,
+            &self.owner,
+            false,
+            0,
+        )
+    }
+}
+
+/// `count` accounts with unique keys, followed by the stake pool program
+fn test_accounts(count: usize) -> Vec<TestAccount> {
+    let mut accounts: Vec<TestAccount> = (0..count)
+        .map(|_| TestAccount::new(Pubkey::new_unique()))
+        .collect();
+    let mut program = TestAccount::new(your_wallet_stake_pool::ID);
+    program.owner = solana_program::bpf_loader_upgradeable::ID;
+    accounts.push(program);
+    accounts
+}
+
+/// Run `call` with invocations captured, returning what it invoked
+fn capture(call: impl FnOnce() -> ProgramResult) -> Invocation {
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let previous = set_syscall_stubs(Box::new(CaptureInvoke));
+    let result = call();
+    set_syscall_stubs(previous);
+    result.unwrap();
+    INVOKED.lock().unwrap().take().unwrap()
+}
+
+fn meta(info: &AccountInfo, is_writable: bool, is_signer: bool) -> AccountMeta {
+    AccountMeta {
+        pubkey: *info.key,
+        is_signer,
+        is_writable,
+    }
+}
+
+#[test]
+fn test_stake_builds_stake_instruction() {
+    let mut accounts = test_accounts(13);
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+    let program = &infos[13];
+
+    let seeds: &[&[u8]] = &[b"vault", &[7]];
+    let (instruction, signer_seeds) = capture(|| {
+        cpi::stake(
+            CpiContext::new_with_signer(
+                program,
+                StakeAccounts {
+                    pool: &infos[0],
+                    stake_account: &infos[1],
+                    owner: &infos[2],
+                    user_token_account: &infos[3],
+                    stake_vault: &infos[4],
+                    reward_vault: &infos[5],
+                    stake_mint: &infos[6],
+                    token_program: &infos[7],
+                    payer: &infos[8],
+                    system_program: &infos[9],
+                    fee_vault: &infos[10],
+                    mint_registry: &infos[11],
+                    receipt_mint: None,
+                    user_receipt_account: None,
+                    receipt_token_program: None,
+                    program_authority: &infos[12],
+                    allowlist: None,
+                    memo_program: None,
+                    stake_position: None,
+                    funder: None,
+                    deny_list: None,
+                    reward_cohort: None,
+                    hook_program: None,
+                },
+                &[seeds],
+            ),
+            1_000,
+            Some(3),
+            Some(5),
+            None,
+            None,
+            true,
+            None,
+            None,
+            None,
+            Some(1_700_000_060),
+        )
+    });
+
+    assert_eq!(instruction.program_id, your_wallet_stake_pool::ID);
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            meta(&infos[0], true, false),
+            meta(&infos[1], true, false),
+            meta(&infos[2], false, true),
+            meta(&infos[3], true, false),
+            meta(&infos[4], true, false),
+            meta(&infos[5], false, false),
+            meta(&infos[6], false, false),
+            meta(&infos[7], false, false),
+            meta(&infos[8], true, true),
+            meta(&infos[9], false, false),
+            meta(&infos[10], true, false),
+            meta(&infos[11], true, false),
+            // Absent receipt accounts are passed as the program
+            meta(program, false, false),
+            meta(program, false, false),
+            meta(program, false, false),
+            meta(&infos[12], false, false),
+            meta(program, false, false),
+            meta(program, false, false),
+            meta(program, false, false),
+            meta(program, false, false),
+            meta(program, false, false),
+            meta(program, false, false),
+            meta(program, false, false),
+        ]
+    );
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&instruction.data).unwrap(),
+        StakePoolInstruction::Stake {
+            amount: 1_000,
+            index: Some(3),
+            expected_reward_rate: Some(5),
+            expected_lockup_period: None,
+            referrer: None,
+            auto_relock: true,
+            label: None,
+            allowlist_proof: None,
+            memo: None,
+            deadline: Some(1_700_000_060),
+        }
+    ));
+    assert_eq!(signer_seeds, vec![vec![b"vault".to_vec(), vec![7]]]);
+}
+
+#[test]
+fn test_stake_for_another_owner_is_signed_by_the_funder() {
+    let mut accounts = test_accounts(14);
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+    let program = &infos[14];
+
+    let (instruction, _) = capture(|| {
+        cpi::stake(
+            CpiContext::new(
+                program,
+                StakeAccounts {
+                    pool: &infos[0],
+                    stake_account: &infos[1],
+                    owner: &infos[2],
+                    user_token_account: &infos[3],
+                    stake_vault: &infos[4],
+                    reward_vault: &infos[5],
+                    stake_mint: &infos[6],
+                    token_program: &infos[7],
+                    payer: &infos[8],
+                    system_program: &infos[9],
+                    fee_vault: &infos[10],
+                    mint_registry: &infos[11],
+                    receipt_mint: None,
+                    user_receipt_account: None,
+                    receipt_token_program: None,
+                    program_authority: &infos[12],
+                    allowlist: None,
+                    memo_program: None,
+                    stake_position: None,
+                    funder: Some(&infos[13]),
+                    deny_list: None,
+                    reward_cohort: None,
+                    hook_program: None,
+                },
+            ),
+            1_000,
+            Some(0),
+            None,
+            None,
+            None,
+            false,
+            None,
+            None,
+            None,
+            None,
+        )
+    });
+
+    assert_eq!(instruction.accounts[2], meta(&infos[2], false, false));
+    assert_eq!(instruction.accounts[19], meta(&infos[13], false, true));
+}
+
+#[test]
+fn test_unstake_builds_unstake_instruction() {
+    let mut accounts = test_accounts(13);
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+    let program = &infos[13];
+
+    let (instruction, signer_seeds) = capture(|| {
+        cpi::unstake(
+            CpiContext::new(
+                program,
+                UnstakeAccounts {
+                    pool: &infos[0],
+                    stake_account: &infos[1],
+                    owner: &infos[2],
+                    user_token_account: &infos[3],
+                    stake_vault: &infos[4],
+                    stake_mint: &infos[5],
+                    token_program: &infos[6],
+                    clock: &infos[7],
+                    fee_vault: &infos[8],
+                    mint_registry: &infos[9],
+                    receipt_mint: Some(&infos[10]),
+                    user_receipt_account: Some(&infos[11]),
+                    receipt_token_program: Some(&infos[12]),
+                    program_authority: &infos[12],
+                    memo_program: Some(&infos[7]),
+                    deny_list: Some(&infos[0]),
+                    hook_program: Some(&infos[5]),
+                },
+            )
+            .with_remaining_accounts(&infos[10..12]),
+            400,
+            None,
+            Some("withdrawal #42".to_string()),
+            None,
+        )
+    });
+
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            meta(&infos[0], true, false),
+            meta(&infos[1], true, false),
+            meta(&infos[2], false, true),
+            meta(&infos[3], true, false),
+            meta(&infos[4], true, false),
+            meta(&infos[5], false, false),
+            meta(&infos[6], false, false),
+            meta(&infos[7], false, false),
+            meta(&infos[8], true, false),
+            meta(&infos[9], true, false),
+            meta(&infos[10], true, false),
+            meta(&infos[11], true, false),
+            meta(&infos[12], false, false),
+            meta(&infos[12], false, false),
+            meta(&infos[7], false, false),
+            meta(&infos[0], false, false),
+            // The hook program and its accounts come last
+            meta(&infos[5], false, false),
+            meta(&infos[10], false, false),
+            meta(&infos[11], false, false),
+        ]
+    );
+    match StakePoolInstruction::try_from_slice(&instruction.data).unwrap() {
+        StakePoolInstruction::Unstake {
+            amount,
+            expected_reward_rate,
+            memo,
+            deadline,
+        } => {
+            assert_eq!((amount, expected_reward_rate, deadline), (400, None, None));
+            assert_eq!(memo.as_deref(), Some("withdrawal #42"));
+        }
+        _ => panic!("expected Unstake instruction"),
+    }
+    assert!(signer_seeds.is_empty());
+}
+
+#[test]
+fn test_claim_rewards_builds_claim_instruction() {
+    let mut accounts = test_accounts(11);
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+    let program = &infos[11];
+
+    let claim_accounts = |create_reward_account: bool| ClaimRewardsAccounts {
+        pool: &infos[0],
+        stake_account: &infos[1],
+        owner: &infos[2],
+        user_reward_account: &infos[3],
+        reward_vault: &infos[4],
+        reward_mint: &infos[5],
+        token_program: &infos[6],
+        clock: &infos[7],
+        referrer_reward_account: None,
+        program_authority: &infos[8],
+        recipient: None,
+        associated_token_program: create_reward_account.then_some(&infos[9]),
+        system_program: create_reward_account.then_some(&infos[10]),
+        memo_program: None,
+        deny_list: None,
+        reward_cohort: None,
+        hook_program: None,
+    };
+
+    let (instruction, _) = capture(|| {
+        cpi::claim_rewards(
+            CpiContext::new(program, claim_accounts(false)),
+            Some(25),
+            None,
+            None,
+        )
+    });
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            meta(&infos[0], true, false),
+            meta(&infos[1], true, false),
+            meta(&infos[2], false, true),
+            meta(&infos[3], true, false),
+            meta(&infos[4], true, false),
+            meta(&infos[5], false, false),
+            meta(&infos[6], false, false),
+            meta(&infos[7], false, false),
+            meta(program, false, false),
+            meta(&infos[8], false, false),
+            meta(program, false, false),
+            meta(program, false, false),
+            meta(program, false, false),
+            meta(program, false, false),
+            meta(program, false, false),
+            meta(program, false, false),
+            meta(program, false, false),
+        ]
+    );
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&instruction.data).unwrap(),
+        StakePoolInstruction::ClaimRewards {
+            amount: Some(25),
+            memo: None,
+            deadline: None,
+        }
+    ));
+
+    // Creating the reward account makes the owner the writable payer
+    let (instruction, _) = capture(|| {
+        cpi::claim_rewards(
+            CpiContext::new(program, claim_accounts(true)),
+            None,
+            None,
+            None,
+        )
+    });
+    assert_eq!(instruction.accounts[2], meta(&infos[2], true, true));
+    assert_eq!(instruction.accounts[11], meta(&infos[9], false, false));
+    assert_eq!(instruction.accounts[12], meta(&infos[10], false, false));
+}
+
+#[test]
+fn test_fund_rewards_builds_fund_instruction() {
+    let mut accounts = test_accounts(6);
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+    let program = &infos[6];
+
+    let (instruction, _) = capture(|| {
+        cpi::fund_rewards(
+            CpiContext::new(
+                program,
+                FundRewardsAccounts {
+                    pool: &infos[0],
+                    funder: &infos[1],
+                    funder_token_account: &infos[2],
+                    reward_vault: &infos[3],
+                    reward_mint: &infos[4],
+                    token_program: &infos[5],
+                },
+            ),
+            9_000,
+        )
+    });
+
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            meta(&infos[0], true, false),
+            meta(&infos[1], false, true),
+            meta(&infos[2], true, false),
+            meta(&infos[3], true, false),
+            meta(&infos[4], false, false),
+            meta(&infos[5], false, false),
+        ]
+    );
+    assert!(matches!(
+        StakePoolInstruction::try_from_slice(&instruction.data).unwrap(),
+        StakePoolInstruction::FundRewards { amount: 9_000 }
+    ));
+}
@@ -0,0 +1,41 @@
+// ============================================================================
+// Transaction Deadline Tests
+// ============================================================================
+// Stake, Unstake and ClaimRewards take an optional deadline. A transaction
+// that lands after it fails before any account is loaded, so a signed
+// transaction held back by a validator cannot execute under terms its signer
+// no longer expects.
+
+use solana_program::program_error::ProgramError;
+use your_wallet_stake_pool::{error::StakePoolError, processor::helpers::check_deadline};
+
+const NOW: i64 = 1_700_000_000;
+
+#[test]
+fn test_no_deadline_never_expires() {
+    assert!(check_deadline("Stake", None, NOW).is_ok());
+    assert!(check_deadline("Stake", None, i64::MAX).is_ok());
+}
+
+#[test]
+fn test_deadline_is_inclusive() {
+    assert!(check_deadline("Unstake", Some(NOW + 60), NOW).is_ok());
+    assert!(check_deadline("Unstake", Some(NOW), NOW).is_ok());
+}
+
+#[test]
+fn test_late_transaction_is_rejected() {
+    assert_eq!(
+        check_deadline("ClaimRewards", Some(NOW), NOW + 1),
+        Err(ProgramError::Custom(
+            StakePoolError::DeadlineExceeded as u32
+        ))
+    );
+    // A deadline long past
+    assert_eq!(
+        check_deadline("Stake", Some(0), NOW),
+        Err(ProgramError::Custom(
+            StakePoolError::DeadlineExceeded as u32
+        ))
+    );
+}
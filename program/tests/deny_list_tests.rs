@@ -0,0 +1,270 @@
+// ============================================================================
+// Deny List Tests
+// ============================================================================
+// Pools with deny_list_required refuse stakes, claims, unstakes and ownership
+// transfers involving an address on the global deny list. Listed owners may still withdraw principal to
+// their own token accounts unless the admin turns allow_listed_withdrawals off.
+// ClaimRewardsBatch cannot pass the list and refuses such pools outright.
+
+mod common;
+
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, program_error::ProgramError,
+    program_stubs::SyscallStubs,
+};
+use solana_sdk::pubkey::Pubkey;
+use std::sync::Once;
+use your_wallet_stake_pool::{
+    error::StakePoolError,
+    instruction::StakePoolInstruction,
+    processor::process_instruction,
+    state::{ClusterProfile, DenyList, Key, ProgramAuthority, StakeAccount, StakePool},
+    ID,
+};
+
+use common::*;
+
+static INSTALL: Once = Once::new();
+
+/// Serves the clock to instructions run natively
+struct ClockStub;
+
+impl SyscallStubs for ClockStub {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            unix_timestamp: SAMPLE_STAKE_TIMESTAMP,
+            ..Clock::default()
+        };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        0
+    }
+}
+
+/// An account handed to the program: key, signer, owning program and data
+struct Ledger(Pubkey, bool, Pubkey, Vec<u8>);
+
+impl Ledger {
+    fn state(key: Pubkey, mut data: Vec<u8>, len: usize) -> Self {
+        data.resize(len, 0);
+        Self(key, false, ID, data)
+    }
+
+    fn wallet(key: Pubkey, signer: bool) -> Self {
+        Self(key, signer, Pubkey::default(), Vec::new())
+    }
+}
+
+/// Run `instruction` natively over `ledger`, every account writable
+fn run(ledger: &mut [Ledger], instruction: StakePoolInstruction) -> Result<(), ProgramError> {
+    INSTALL.call_once(|| {
+        solana_program::program_stubs::set_syscall_stubs(Box::new(ClockStub));
+    });
+    let mut lamports = vec![1_000_000_000u64; ledger.len()];
+    let infos: Vec<AccountInfo> = ledger
+        .iter_mut()
+        .zip(lamports.iter_mut())
+        .map(|(Ledger(key, signer, owner, data), lamports)| {
+            AccountInfo::new(key, *signer, true, lamports, data, owner, false, 0)
+        })
+        .collect();
+    process_instruction(&ID, &infos, &borsh::to_vec(&instruction).unwrap())
+}
+
+fn program_authority() -> Ledger {
+    let (key, bump) = get_program_authority_pda();
+    let authority = ProgramAuthority {
+        key: Key::ProgramAuthorityV2,
+        authority: Pubkey::new_unique(),
+        authorized_creators: Vec::new(),
+        pending_authority: None,
+        bump,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
+    };
+    Ledger::state(
+        key,
+        authority.to_account_data().unwrap(),
+        ProgramAuthority::LEN,
+    )
+}
+
+/// A pool checking the deny list and `owner`'s stake account 0 in it, nominated to `pending_owner`
+fn listed_pool(owner: &Pubkey, pending_owner: Option<Pubkey>) -> (StakePool, Ledger, Ledger) {
+    let pool_key = Pubkey::new_unique();
+    let mut pool = sample_stake_pool();
+    pool.deny_list_required = true;
+    let mut stake_account = sample_stake_account(&pool_key, owner, 1_000);
+    stake_account.pending_owner = pending_owner;
+    let pool_ledger = Ledger::state(pool_key, pool.to_account_data().unwrap(), StakePool::LEN);
+    let stake_ledger = Ledger::state(
+        StakeAccount::find_pda(&pool_key, owner, 0).0,
+        stake_account.to_account_data().unwrap(),
+        StakeAccount::LEN,
+    );
+    (pool, pool_ledger, stake_ledger)
+}
+
+/// The deny list PDA listing `addresses`
+fn deny_list(addresses: &[Pubkey]) -> Ledger {
+    let (key, bump) = DenyList::find_pda();
+    let mut deny_list = DenyList::empty(bump);
+    deny_list.update(addresses, &[]).unwrap();
+    Ledger::state(key, borsh::to_vec(&deny_list).unwrap(), DenyList::LEN)
+}
+
+fn nominate(
+    owner: &Pubkey,
+    new_owner: Option<Pubkey>,
+    listed: &[Pubkey],
+) -> Result<(), ProgramError> {
+    let (_, pool_ledger, stake_ledger) = listed_pool(owner, None);
+    let mut ledger = vec![
+        stake_ledger,
+        Ledger::wallet(*owner, true),
+        pool_ledger,
+        deny_list(listed),
+    ];
+    run(
+        &mut ledger,
+        StakePoolInstruction::NominateStakeOwner { new_owner },
+    )
+}
+
+fn accept(
+    previous_owner: &Pubkey,
+    new_owner: &Pubkey,
+    listed: &[Pubkey],
+) -> Result<(), ProgramError> {
+    let (_, pool_ledger, stake_ledger) = listed_pool(previous_owner, Some(*new_owner));
+    let pool_key = pool_ledger.0;
+    let mut ledger = vec![
+        pool_ledger,
+        stake_ledger,
+        Ledger::wallet(StakeAccount::find_pda(&pool_key, new_owner, 0).0, false),
+        Ledger::wallet(*new_owner, true),
+        Ledger::wallet(*previous_owner, false),
+        Ledger::wallet(Pubkey::default(), false),
+        // No stake position directories
+        Ledger::wallet(ID, false),
+        Ledger::wallet(ID, false),
+        deny_list(listed),
+    ];
+    run(
+        &mut ledger,
+        StakePoolInstruction::AcceptStakeOwnership { new_index: 0 },
+    )
+}
+
+fn assert_denied(result: Result<(), ProgramError>) {
+    assert_eq!(
+        result.unwrap_err(),
+        ProgramError::Custom(StakePoolError::AddressDenied as u32)
+    );
+}
+
+#[test]
+fn test_deny_list_keeps_addresses_sorted_and_unique() {
+    let mut deny_list = DenyList::empty(255);
+    let addresses: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+
+    deny_list
+        .update(
+            &[addresses[3], addresses[1], addresses[4], addresses[1]],
+            &[],
+        )
+        .unwrap();
+    deny_list.update(&[addresses[0]], &[addresses[4]]).unwrap();
+
+    assert_eq!(
+        deny_list.addresses,
+        vec![addresses[0], addresses[1], addresses[3]]
+    );
+    assert!(deny_list.contains(&addresses[3]));
+    assert!(!deny_list.contains(&addresses[4]));
+    assert_denied(deny_list.check("Owner", &addresses[0]));
+    deny_list.check("Owner", &addresses[2]).unwrap();
+}
+
+#[test]
+fn test_deny_list_capacity_fits_its_account() {
+    let mut deny_list = DenyList::empty(255);
+    let addresses: Vec<Pubkey> = (0..DenyList::CAPACITY)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    deny_list.update(&addresses, &[]).unwrap();
+    assert_eq!(borsh::to_vec(&deny_list).unwrap().len(), DenyList::LEN);
+
+    let err = deny_list.update(&[Pubkey::new_unique()], &[]).unwrap_err();
+    assert_eq!(
+        err,
+        ProgramError::Custom(StakePoolError::InvalidParameters as u32)
+    );
+    assert_eq!(deny_list.addresses.len(), DenyList::CAPACITY);
+}
+
+#[test]
+fn test_listed_owners_withdraw_only_to_themselves() {
+    let (listed, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut deny_list = DenyList::empty(255);
+    deny_list.update(&[listed], &[]).unwrap();
+    assert!(deny_list.allow_listed_withdrawals);
+
+    deny_list.check_withdrawal(&listed, &listed).unwrap();
+    assert_denied(deny_list.check_withdrawal(&listed, &other));
+    // Unlisted owners cannot withdraw to a listed address either
+    assert_denied(deny_list.check_withdrawal(&other, &listed));
+    deny_list.check_withdrawal(&other, &other).unwrap();
+
+    deny_list.allow_listed_withdrawals = false;
+    assert_denied(deny_list.check_withdrawal(&listed, &listed));
+}
+
+#[test]
+fn test_batch_claims_reject_pools_checking_the_deny_list() {
+    // Batches cannot pass the deny list, so a listed owner cannot use them to
+    // skip the per-owner check of ClaimRewards
+    let owner = Pubkey::new_unique();
+    let (pool, pool_ledger, stake_ledger) = listed_pool(&owner, None);
+    let mut ledger = vec![
+        pool_ledger,
+        Ledger::wallet(owner, true),
+        Ledger::wallet(Pubkey::new_unique(), false),
+        Ledger::wallet(pool.reward_vault, false),
+        Ledger::wallet(pool.reward_mint, false),
+        Ledger::wallet(Pubkey::new_unique(), false),
+        Ledger::wallet(Pubkey::new_unique(), false),
+        program_authority(),
+        stake_ledger,
+    ];
+
+    assert_eq!(
+        run(&mut ledger, StakePoolInstruction::ClaimRewardsBatch).unwrap_err(),
+        ProgramError::Custom(StakePoolError::DenyListRequired as u32)
+    );
+}
+
+#[test]
+fn test_listed_wallets_cannot_nominate_or_be_nominated() {
+    let (owner, nominee) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    assert_denied(nominate(&owner, Some(nominee), &[owner]));
+    assert_denied(nominate(&owner, Some(nominee), &[nominee]));
+    nominate(&owner, Some(nominee), &[]).unwrap();
+    // Cancelling a nomination moves nothing
+    nominate(&owner, None, &[owner]).unwrap();
+}
+
+#[test]
+fn test_listed_wallets_cannot_hand_over_or_accept_positions() {
+    let (previous_owner, new_owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    assert_denied(accept(&previous_owner, &new_owner, &[previous_owner]));
+    assert_denied(accept(&previous_owner, &new_owner, &[new_owner]));
+}
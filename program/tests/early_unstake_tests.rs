@@ -0,0 +1,75 @@
+// ============================================================================
+// Early Unstake Allowance Tests
+// ============================================================================
+// Pools can let stakers withdraw up to early_unstake_bps of a position before
+// the lockup completes, forfeiting only the withdrawn part's share of rewards.
+
+mod common;
+
+use solana_sdk::pubkey::Pubkey;
+
+use common::*;
+
+#[test]
+fn test_early_unstake_allowance_disabled_by_default() {
+    let pool = sample_stake_pool();
+    let stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000_000);
+
+    assert_eq!(pool.early_unstake_bps, 0);
+    assert_eq!(pool.early_unstake_allowance(&stake).unwrap(), 0);
+}
+
+#[test]
+fn test_early_unstake_allowance_is_bps_of_position() {
+    let mut pool = sample_stake_pool();
+    pool.early_unstake_bps = 2_500; // 25%
+    let stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000_000);
+
+    assert_eq!(pool.early_unstake_allowance(&stake).unwrap(), 250_000);
+}
+
+#[test]
+fn test_early_unstake_allowance_accounts_for_prior_withdrawals() {
+    let mut pool = sample_stake_pool();
+    pool.early_unstake_bps = 2_500; // 25%
+
+    // Original position of 1_000_000, 100_000 already withdrawn early
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 900_000);
+    stake.early_unstaked = 100_000;
+
+    assert_eq!(pool.early_unstake_allowance(&stake).unwrap(), 150_000);
+
+    // Allowance exhausted
+    stake.amount_staked = 750_000;
+    stake.early_unstaked = 250_000;
+    assert_eq!(pool.early_unstake_allowance(&stake).unwrap(), 0);
+}
+
+#[test]
+fn test_early_unstake_allowance_survives_lowered_bps() {
+    let mut pool = sample_stake_pool();
+    pool.early_unstake_bps = 1_000; // lowered to 10% after 25% was used
+
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 750_000);
+    stake.early_unstaked = 250_000;
+
+    assert_eq!(pool.early_unstake_allowance(&stake).unwrap(), 0);
+}
+
+#[test]
+fn test_early_withdrawal_keeps_remaining_reward_reservation() {
+    let pool = sample_stake_pool();
+
+    let full = pool
+        .expected_rewards(1_000_000, SAMPLE_STAKE_TIMESTAMP)
+        .unwrap();
+    let withdrawn_share = pool
+        .expected_rewards(250_000, SAMPLE_STAKE_TIMESTAMP)
+        .unwrap();
+    let remaining = pool
+        .expected_rewards(750_000, SAMPLE_STAKE_TIMESTAMP)
+        .unwrap();
+
+    assert!(full > 0);
+    assert_eq!(full - withdrawn_share, remaining);
+}
@@ -0,0 +1,176 @@
+// ============================================================================
+// Error Decoding Tests
+// ============================================================================
+// The dispatcher tags failures with the instruction name and decodes Custom
+// codes back into StakePoolError so log lines are unambiguous next to the
+// token program's overlapping Custom codes. Codes and names are pinned: clients
+// map them to messages, so they must never change between releases.
+
+use solana_program::program_error::ProgramError;
+use your_wallet_stake_pool::{error::StakePoolError, instruction::StakePoolInstruction};
+
+#[test]
+fn test_error_codes_round_trip() {
+    for error in [
+        StakePoolError::DeserializationError,
+        StakePoolError::Unauthorized,
+        StakePoolError::PoolNotClosable,
+    ] {
+        let ProgramError::Custom(code) = ProgramError::from(error.clone()) else {
+            panic!("stake pool errors are custom program errors");
+        };
+        assert_eq!(StakePoolError::from_code(code), Some(error));
+    }
+}
+
+/// Every error with the code it has been released with
+const RELEASED_CODES: &[(u32, StakePoolError)] = &[
+    (0, StakePoolError::DeserializationError),
+    (1, StakePoolError::SerializationError),
+    (2, StakePoolError::InvalidProgramOwner),
+    (3, StakePoolError::InvalidPda),
+    (4, StakePoolError::ExpectedEmptyAccount),
+    (5, StakePoolError::ExpectedNonEmptyAccount),
+    (6, StakePoolError::ExpectedSignerAccount),
+    (7, StakePoolError::ExpectedWritableAccount),
+    (8, StakePoolError::AccountMismatch),
+    (9, StakePoolError::InvalidAccountKey),
+    (10, StakePoolError::NumericalOverflow),
+    (11, StakePoolError::PoolPaused),
+    (12, StakePoolError::AmountBelowMinimum),
+    (13, StakePoolError::InsufficientStakedBalance),
+    (14, StakePoolError::LockupNotExpired),
+    (15, StakePoolError::InsufficientRewards),
+    (16, StakePoolError::Unauthorized),
+    (17, StakePoolError::InvalidTokenProgram),
+    (18, StakePoolError::InvalidMint),
+    (19, StakePoolError::InvalidAccountDiscriminator),
+    (20, StakePoolError::PoolParametersChanged),
+    (21, StakePoolError::NoPendingAuthority),
+    (22, StakePoolError::InvalidPendingAuthority),
+    (23, StakePoolError::PoolEnded),
+    (24, StakePoolError::InvalidParameters),
+    (25, StakePoolError::InvalidVaultOwner),
+    (26, StakePoolError::UnsafeTokenExtension),
+    (27, StakePoolError::UnexpectedBalanceChange),
+    (28, StakePoolError::MintHasFreezeAuthority),
+    (29, StakePoolError::RewardRateChangeDelayNotElapsed),
+    (30, StakePoolError::NoPendingRewardRateChange),
+    (31, StakePoolError::PendingRewardRateChangeExists),
+    (32, StakePoolError::InvalidTimestamp),
+    (33, StakePoolError::DataCorruption),
+    (34, StakePoolError::AccountSizeTooSmall),
+    (35, StakePoolError::UnauthorizedPoolCreator),
+    (36, StakePoolError::CreatorAlreadyAuthorized),
+    (37, StakePoolError::MaxAuthorizedCreatorsReached),
+    (38, StakePoolError::CannotRemoveMainAuthority),
+    (39, StakePoolError::CreatorNotFound),
+    (40, StakePoolError::InvalidReferrer),
+    (41, StakePoolError::InvalidTokenAccountOwner),
+    (42, StakePoolError::NotNativeSolPool),
+    (43, StakePoolError::AutoRelockDisabled),
+    (44, StakePoolError::DevOnlyInstruction),
+    (45, StakePoolError::RewardFunderNotAllowed),
+    (46, StakePoolError::DefundExceedsSurplus),
+    (47, StakePoolError::TvlCapExceeded),
+    (48, StakePoolError::PoolNotClosable),
+    (49, StakePoolError::ReceiptAccountsRequired),
+    (50, StakePoolError::RewardsNotExpired),
+    (51, StakePoolError::NoExpiredRewards),
+    (52, StakePoolError::UnstakeCooldownRequired),
+    (53, StakePoolError::UnstakeCooldownActive),
+    (54, StakePoolError::NoPendingWithdrawal),
+    (55, StakePoolError::ProtocolPaused),
+    (56, StakePoolError::WithdrawalsOnly),
+    (57, StakePoolError::AccountNeedsMigration),
+    (58, StakePoolError::PoolRegistryPageFull),
+    (59, StakePoolError::CreatorPoolLimitReached),
+    (60, StakePoolError::MaxRoleHoldersReached),
+    (61, StakePoolError::ApprovalRequired),
+    (62, StakePoolError::AlreadyApproved),
+    (63, StakePoolError::InsufficientApprovals),
+    (64, StakePoolError::PendingLockupChangeExists),
+    (65, StakePoolError::NoPendingLockupChange),
+    (66, StakePoolError::LockupChangeDelayNotElapsed),
+    (67, StakePoolError::RewardRateOutOfBounds),
+    (68, StakePoolError::ClaimExceedsUnclaimedRewards),
+    (69, StakePoolError::NoPendingStakeOwner),
+    (70, StakePoolError::InvalidPendingStakeOwner),
+    (71, StakePoolError::ReceiptPositionNotTransferable),
+    (72, StakePoolError::LockupExtensionDisabled),
+    (73, StakePoolError::NotAllowlisted),
+    (74, StakePoolError::PoolAllowlistPageFull),
+    (75, StakePoolError::PrincipalNotVested),
+    (76, StakePoolError::ClaimWindowClosed),
+    (77, StakePoolError::StakeSlashPending),
+    (78, StakePoolError::SlashTimelockActive),
+    (79, StakePoolError::NoRecoveryInProgress),
+    (80, StakePoolError::RecoveryDelayActive),
+    (81, StakePoolError::InvalidMemo),
+    (82, StakePoolError::VaultOrderMismatch),
+    (83, StakePoolError::RateLimited),
+    (84, StakePoolError::StakePositionFull),
+    (85, StakePoolError::RewardsCapExceeded),
+    (86, StakePoolError::IncompleteAudit),
+    (87, StakePoolError::RemainingStakeBelowMinimum),
+    (88, StakePoolError::WindDownNotStarted),
+    (89, StakePoolError::HookProgramNotAllowed),
+    (90, StakePoolError::HookProgramRequired),
+    (91, StakePoolError::PointsNotEnabled),
+    (92, StakePoolError::AddressDenied),
+    (93, StakePoolError::DenyListRequired),
+    (94, StakePoolError::ThirdPartyStakeDisabled),
+    (95, StakePoolError::StakesNotMergeable),
+    (96, StakePoolError::DeadlineExceeded),
+    (97, StakePoolError::InvalidRewardCohort),
+];
+
+#[test]
+fn test_error_codes_are_stable() {
+    for (code, error) in RELEASED_CODES {
+        assert_eq!(
+            ProgramError::from(error.clone()),
+            ProgramError::Custom(*code),
+            "{} changed its code",
+            error.to_str()
+        );
+        assert_eq!(StakePoolError::from_code(*code).as_ref(), Some(error));
+    }
+
+    // New errors are appended after the last released code
+    let next_code = RELEASED_CODES.len() as u32;
+    assert!(StakePoolError::from_code(next_code).is_none());
+}
+
+#[test]
+fn test_error_names_match_variants() {
+    for (_, error) in RELEASED_CODES {
+        assert_eq!(error.to_str(), format!("{:?}", error));
+    }
+}
+
+#[test]
+fn test_unknown_error_code_is_not_decoded() {
+    assert_eq!(StakePoolError::from_code(u32::MAX), None);
+}
+
+#[test]
+fn test_instruction_name_matches_variant() {
+    assert_eq!(
+        StakePoolInstruction::ClaimRewards {
+            amount: None,
+            memo: None,
+            deadline: None,
+        }
+        .name(),
+        "ClaimRewards"
+    );
+    assert_eq!(
+        StakePoolInstruction::FundRewards { amount: 1 }.name(),
+        "FundRewards"
+    );
+    assert_eq!(
+        StakePoolInstruction::SetMintTvlCap { tvl_cap: None }.name(),
+        "SetMintTvlCap"
+    );
+}
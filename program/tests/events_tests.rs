@@ -0,0 +1,295 @@
+// ============================================================================
+// Event Schema Tests
+// ============================================================================
+// Events are logged as a single Borsh-encoded StakePoolEvent. Indexers key on
+// the leading variant byte, so these tests pin the discriminators and check
+// that every payload shape round-trips.
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::error::StakePoolError;
+use your_wallet_stake_pool::events::{
+    ClaimBatchEvent, ContextValue, CreatorPoolLimitUpdatedEvent, ErrorContextEvent,
+    LockupChangeFinalizedEvent, LockupExtendedEvent, MintTvlCapUpdatedEvent,
+    PoolAllowlistUpdatedEvent, PoolParameter, PoolUpdatedEvent, RecoveryInitiatedEvent,
+    RewardFundersUpdatedEvent, SlashExecutedEvent, StakeClaim, StakeEvent,
+    StakeOwnershipTransferredEvent, StakePoolEvent, UnstakeEvent, VaultWithdrawalEvent,
+    VestingStakeCreatedEvent,
+};
+
+fn stake_event() -> StakePoolEvent {
+    StakePoolEvent::Stake(StakeEvent {
+        pool: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        index: 3,
+        amount: 990_000,
+        fee: 10_000,
+        active_stake_count: Some(7),
+        event_nonce: 42,
+    })
+}
+
+#[test]
+fn test_events_round_trip() {
+    let events = vec![
+        stake_event(),
+        StakePoolEvent::Unstake(UnstakeEvent {
+            pool: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            amount: 500_000,
+            fee: 0,
+            forfeited_rewards: 12_345,
+            active_stake_count: None,
+            event_nonce: 43,
+        }),
+        StakePoolEvent::PoolUpdated(PoolUpdatedEvent {
+            pool: Pubkey::new_unique(),
+            admin: Pubkey::new_unique(),
+            parameter: PoolParameter::PoolEndDate(None),
+            event_nonce: 44,
+        }),
+        StakePoolEvent::RewardFundersUpdated(RewardFundersUpdatedEvent {
+            pool: Pubkey::new_unique(),
+            admin: Pubkey::new_unique(),
+            funders: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+        }),
+        StakePoolEvent::MintTvlCapUpdated(MintTvlCapUpdatedEvent {
+            stake_mint: Pubkey::new_unique(),
+            admin: Pubkey::new_unique(),
+            tvl_cap: Some(u64::MAX),
+            total_staked: 42,
+        }),
+        StakePoolEvent::ClaimBatch(ClaimBatchEvent {
+            pool: Pubkey::new_unique(),
+            claimant: Pubkey::new_unique(),
+            claims: vec![
+                StakeClaim {
+                    stake_account: Pubkey::new_unique(),
+                    amount: 1_000,
+                },
+                StakeClaim {
+                    stake_account: Pubkey::new_unique(),
+                    amount: 2_500,
+                },
+            ],
+            amount: 3_500,
+            received: 3_465,
+            event_nonce: 45,
+        }),
+    ];
+
+    for event in events {
+        let data = borsh::to_vec(&event).unwrap();
+        assert_eq!(StakePoolEvent::decode(&data), Some(event));
+    }
+}
+
+#[test]
+fn test_event_discriminators_are_stable() {
+    let pool = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+
+    let discriminator = |event: &StakePoolEvent| borsh::to_vec(event).unwrap()[0];
+
+    assert_eq!(discriminator(&stake_event()), 1);
+    assert_eq!(
+        discriminator(&StakePoolEvent::PoolUpdated(PoolUpdatedEvent {
+            pool,
+            admin,
+            parameter: PoolParameter::Paused(true),
+            event_nonce: 1,
+        })),
+        19
+    );
+    assert_eq!(
+        discriminator(&StakePoolEvent::MintTvlCapUpdated(MintTvlCapUpdatedEvent {
+            stake_mint: pool,
+            admin,
+            tvl_cap: None,
+            total_staked: 0,
+        })),
+        30
+    );
+    assert_eq!(
+        discriminator(&StakePoolEvent::ClaimBatch(ClaimBatchEvent {
+            pool,
+            claimant: admin,
+            claims: Vec::new(),
+            amount: 0,
+            received: 0,
+            event_nonce: 1,
+        })),
+        35
+    );
+    assert_eq!(
+        discriminator(&StakePoolEvent::CreatorPoolLimitUpdated(
+            CreatorPoolLimitUpdatedEvent {
+                creator: pool,
+                authority: admin,
+                pool_limit: Some(5),
+                pools_created: 2,
+            }
+        )),
+        37
+    );
+    assert_eq!(
+        discriminator(&StakePoolEvent::LockupChangeFinalized(
+            LockupChangeFinalizedEvent {
+                pool,
+                lockup_period: 86400,
+                enforce_lockup: true,
+            }
+        )),
+        45
+    );
+    assert_eq!(
+        discriminator(&StakePoolEvent::StakeOwnershipTransferred(
+            StakeOwnershipTransferredEvent {
+                pool,
+                previous_stake_account: Pubkey::new_unique(),
+                stake_account: Pubkey::new_unique(),
+                previous_owner: admin,
+                new_owner: Pubkey::new_unique(),
+            }
+        )),
+        48
+    );
+    assert_eq!(
+        discriminator(&StakePoolEvent::DustSwept(VaultWithdrawalEvent {
+            pool,
+            admin,
+            destination: Pubkey::new_unique(),
+            amount: 3,
+        })),
+        49
+    );
+    assert_eq!(
+        discriminator(&StakePoolEvent::LockupExtended(LockupExtendedEvent {
+            pool,
+            stake_account: Pubkey::new_unique(),
+            owner: admin,
+            additional_seconds: 86_400,
+            matures_at: 1_700_000_000,
+            bonus_rewards: 5,
+        })),
+        50
+    );
+    assert_eq!(
+        discriminator(&StakePoolEvent::PoolAllowlistUpdated(
+            PoolAllowlistUpdatedEvent {
+                pool,
+                admin,
+                page: 0,
+                added: vec![Pubkey::new_unique()],
+                removed: vec![],
+                page_members: 1,
+                allowlist_required: true,
+            }
+        )),
+        51
+    );
+    assert_eq!(
+        discriminator(&StakePoolEvent::VestingStakeCreated(
+            VestingStakeCreatedEvent {
+                pool,
+                stake_account: Pubkey::new_unique(),
+                beneficiary: Pubkey::new_unique(),
+                admin,
+                vesting_amount: 1_000,
+                vesting_start: 1_700_000_000,
+                vesting_cliff: 100,
+                vesting_duration: 400,
+            }
+        )),
+        52
+    );
+    assert_eq!(
+        discriminator(&StakePoolEvent::SlashExecuted(SlashExecutedEvent {
+            pool,
+            stake_account: Pubkey::new_unique(),
+            executor: admin,
+            destination: Pubkey::new_unique(),
+            slashed_amount: 500,
+            received_amount: 495,
+            forfeited_rewards: 20,
+        })),
+        54
+    );
+    assert_eq!(
+        discriminator(&StakePoolEvent::RecoveryInitiated(RecoveryInitiatedEvent {
+            authority: admin,
+            recovery_authority: Pubkey::new_unique(),
+            completes_at: 1_702_592_000,
+        })),
+        57
+    );
+    assert_eq!(
+        discriminator(&StakePoolEvent::ErrorContext(ErrorContextEvent {
+            instruction: "Unstake".to_string(),
+            error: StakePoolError::PoolParametersChanged as u32,
+            field: "reward_rate".to_string(),
+            expected: ContextValue::Unsigned(100),
+            actual: ContextValue::Unsigned(200),
+        })),
+        70
+    );
+}
+
+#[test]
+fn test_error_context_returns_the_error() {
+    let error =
+        StakePoolError::AmountBelowMinimum.with_context("Stake", "amount", 1_000u64, 999u64);
+    assert_eq!(
+        error,
+        ProgramError::Custom(StakePoolError::AmountBelowMinimum as u32)
+    );
+
+    let context = ErrorContextEvent {
+        instruction: "Stake".to_string(),
+        error: StakePoolError::AmountBelowMinimum as u32,
+        field: "amount".to_string(),
+        expected: 1_000u64.into(),
+        actual: 999u64.into(),
+    };
+    assert_eq!(
+        context.stake_pool_error(),
+        Some(StakePoolError::AmountBelowMinimum)
+    );
+    let data = borsh::to_vec(&StakePoolEvent::ErrorContext(context.clone())).unwrap();
+    assert_eq!(
+        StakePoolEvent::decode(&data),
+        Some(StakePoolEvent::ErrorContext(context))
+    );
+}
+
+#[test]
+fn test_stake_event_layout() {
+    let event = stake_event();
+    let StakePoolEvent::Stake(stake) = &event else {
+        unreachable!()
+    };
+    let data = borsh::to_vec(&event).unwrap();
+
+    // discriminator, pool, owner, index, amount, fee, active_stake_count, event_nonce
+    assert_eq!(data.len(), 1 + 32 + 32 + 8 + 8 + 8 + 9 + 8);
+    assert_eq!(&data[1..33], stake.pool.as_ref());
+    assert_eq!(&data[65..73], &3u64.to_le_bytes());
+    assert_eq!(&data[81..89], &10_000u64.to_le_bytes());
+    assert_eq!(data[89], 1);
+    assert_eq!(&data[90..98], &7u64.to_le_bytes());
+    assert_eq!(&data[98..106], &42u64.to_le_bytes());
+}
+
+#[test]
+fn test_decode_rejects_unknown_or_truncated_data() {
+    let data = borsh::to_vec(&stake_event()).unwrap();
+
+    assert_eq!(StakePoolEvent::decode(&data[..data.len() - 1]), None);
+    assert_eq!(StakePoolEvent::decode(&[u8::MAX]), None);
+    assert_eq!(StakePoolEvent::decode(&[]), None);
+
+    // Trailing bytes are not a valid event either
+    let mut padded = data;
+    padded.push(0);
+    assert_eq!(StakePoolEvent::decode(&padded), None);
+}
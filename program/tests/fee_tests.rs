@@ -0,0 +1,87 @@
+// ============================================================================
+// Deposit / Withdrawal Fee Tests
+// ============================================================================
+// Pools can take deposit_fee_bps of each stake and withdraw_fee_bps of each
+// unstake; the fee goes to the pool's fee vault and only the net amount is
+// accounted as stake (or paid to the owner).
+
+mod common;
+
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{constants::MAX_FEE_BPS, state::StakePool};
+
+use common::*;
+
+#[test]
+fn test_fees_disabled_by_default() {
+    let pool = sample_stake_pool();
+
+    assert_eq!(pool.deposit_fee(1_000_000).unwrap(), 0);
+    assert_eq!(pool.withdraw_fee(1_000_000).unwrap(), 0);
+}
+
+#[test]
+fn test_deposit_fee_is_bps_of_amount() {
+    let mut pool = sample_stake_pool();
+    pool.deposit_fee_bps = 50; // 0.5%
+
+    assert_eq!(pool.deposit_fee(1_000_000).unwrap(), 5_000);
+}
+
+#[test]
+fn test_withdraw_fee_is_bps_of_amount() {
+    let mut pool = sample_stake_pool();
+    pool.withdraw_fee_bps = 125; // 1.25%
+
+    assert_eq!(pool.withdraw_fee(1_000_000).unwrap(), 12_500);
+}
+
+#[test]
+fn test_fees_round_down() {
+    let mut pool = sample_stake_pool();
+    pool.deposit_fee_bps = 30;
+    pool.withdraw_fee_bps = 30;
+
+    // 333 * 30 / 10_000 = 0.999 -> 0
+    assert_eq!(pool.deposit_fee(333).unwrap(), 0);
+    assert_eq!(pool.withdraw_fee(333).unwrap(), 0);
+}
+
+#[test]
+fn test_max_fee_never_consumes_amount() {
+    let mut pool = sample_stake_pool();
+    pool.deposit_fee_bps = MAX_FEE_BPS;
+    pool.withdraw_fee_bps = MAX_FEE_BPS;
+
+    let amount = u64::MAX;
+    assert_eq!(pool.deposit_fee(amount).unwrap(), amount / 10);
+    assert_eq!(pool.withdraw_fee(amount).unwrap(), amount / 10);
+}
+
+#[test]
+fn test_fee_vault_pda_is_distinct_from_other_vaults() {
+    let pool = Pubkey::new_unique();
+
+    let (fee_vault, _) = StakePool::find_fee_vault_pda(&pool);
+    let (stake_vault, _) = StakePool::find_stake_vault_pda(&pool);
+    let (reward_vault, _) = StakePool::find_reward_vault_pda(&pool);
+
+    assert_ne!(fee_vault, stake_vault);
+    assert_ne!(fee_vault, reward_vault);
+}
+
+#[test]
+fn test_stake_surplus_is_vault_balance_above_total_staked() {
+    let mut pool = sample_stake_pool();
+    pool.total_staked = 1_000_000;
+
+    // Transfer fee rounding left in the vault
+    assert_eq!(pool.stake_surplus(1_000_003), 3);
+    assert_eq!(pool.stake_surplus(1_000_000), 0);
+    // An underfunded vault has no dust to sweep (SyncPool reports the deficit)
+    assert_eq!(pool.stake_surplus(999_000), 0);
+
+    // Once the last staker exits, everything left is dust
+    pool.total_staked = 0;
+    assert_eq!(pool.stake_surplus(42), 42);
+}
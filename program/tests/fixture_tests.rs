@@ -0,0 +1,119 @@
+// ============================================================================
+// Account Fixture Tests
+// ============================================================================
+// Snapshots of cluster accounts (see tests/fixtures/README.md) must load into
+// LiteSVM with owner and lamport fixes applied, and round-trip through files.
+
+mod common;
+
+use litesvm::LiteSVM;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+use your_wallet_stake_pool::state::StakePool;
+
+use common::*;
+
+fn temp_fixture(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("{}-{}.json", name, Pubkey::new_unique()))
+}
+
+fn pool_account(owner: Pubkey, lamports: u64) -> Account {
+    let mut pool = sample_stake_pool();
+    pool.total_staked = 1_000_000;
+    let mut data = pool.to_account_data().unwrap();
+    data.resize(StakePool::LEN, 0);
+
+    Account {
+        lamports,
+        data,
+        owner,
+        executable: false,
+        rent_epoch: u64::MAX,
+    }
+}
+
+#[test]
+fn test_fixture_round_trip_reassigns_program_accounts() {
+    // Snapshot taken from another deployment of the program, under-funded
+    let other_deployment = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    let mut source = LiteSVM::new();
+    source
+        .set_account(address, pool_account(other_deployment, 1))
+        .unwrap();
+
+    let path = temp_fixture("pool");
+    save_fixture(&source, &address, &path);
+    let fixture = load_fixture(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    let mut svm = LiteSVM::new();
+    assert_eq!(set_fixture_account(&mut svm, &fixture), address);
+
+    let account = svm.get_account(&address).unwrap();
+    assert_eq!(account.owner, PROGRAM_ID.parse::<Pubkey>().unwrap());
+    assert_eq!(
+        account.lamports,
+        svm.minimum_balance_for_rent_exemption(StakePool::LEN)
+    );
+    let pool = StakePool::from_account_data(&account.data).unwrap();
+    assert_eq!(pool.total_staked, 1_000_000);
+}
+
+#[test]
+fn test_fixture_keeps_foreign_accounts_owner() {
+    let token_program = spl_token_2022::ID;
+    let address = Pubkey::new_unique();
+    let mut source = LiteSVM::new();
+    source
+        .set_account(
+            address,
+            Account {
+                lamports: 5_000_000,
+                data: vec![7; 165],
+                owner: token_program,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+    let path = temp_fixture("token");
+    save_fixture(&source, &address, &path);
+    let fixture = load_fixture(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    let mut svm = LiteSVM::new();
+    set_fixture_account(&mut svm, &fixture);
+
+    let account = svm.get_account(&address).unwrap();
+    assert_eq!(account.owner, token_program);
+    assert_eq!(account.lamports, 5_000_000);
+}
+
+#[test]
+fn test_fixture_reads_solana_cli_layout() {
+    let path = temp_fixture("cli");
+    std::fs::write(
+        &path,
+        r#"{
+  "pubkey": "8PtjrGvKNeZt2vCmRkSPGjss7TAFhvxux2N8r67UMKBx",
+  "account": {
+    "lamports": 1141440,
+    "data": ["AQID", "base64"],
+    "owner": "11111111111111111111111111111111",
+    "executable": false,
+    "rentEpoch": 18446744073709551615,
+    "space": 3
+  }
+}"#,
+    )
+    .unwrap();
+
+    let fixture = load_fixture(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(fixture.pubkey.to_string(), PROGRAM_ID);
+    assert_eq!(fixture.account.data, vec![1, 2, 3]);
+    assert_eq!(fixture.account.lamports, 1_141_440);
+    assert_eq!(fixture.account.rent_epoch, u64::MAX);
+}
@@ -0,0 +1,260 @@
+// ============================================================================
+// Property Tests (`fuzz` feature)
+// ============================================================================
+// Random inputs for the reward math and the bookkeeping around it, to catch
+// overflow and rounding bugs the example-based tests miss. Slow, so only built
+// with `cargo test -p your-wallet-stake-pool --features fuzz --test fuzz_tests`.
+// Set PROPTEST_CASES to run more cases than the default 256.
+//
+// Invariants:
+// - reward math either succeeds or fails with NumericalOverflow, never panics
+// - a stake's reserved rewards (`unclaimed_rewards`) always match what the pool
+//   keeps for it in `total_rewards_owed`, through claims, partial and full
+//   unstakes and slashes, so the counter never underflows
+// - a stake never claims more than it earned
+// - the authorized creator list matches the adds and removes applied to it
+
+#![cfg(feature = "fuzz")]
+
+mod common;
+
+use std::collections::HashSet;
+
+use proptest::prelude::*;
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    constants::{BPS_DENOMINATOR, REWARD_SCALE},
+    error::StakePoolError,
+    state::{ClusterProfile, Key, ProgramAuthority, StakeAccount, StakePool},
+};
+
+use common::*;
+
+/// A sample stake of the pool holding `amount` at the locked `rate`
+fn stake_with(pool_key: &Pubkey, amount: u64, rate: u64) -> StakeAccount {
+    let mut stake = sample_stake_account(pool_key, &Pubkey::new_unique(), amount);
+    stake.locked_reward_rate = rate;
+    stake
+}
+
+/// Basis points of a whole share
+const MAX_BPS: u16 = BPS_DENOMINATOR as u16;
+
+fn is_overflow(error: &ProgramError) -> bool {
+    *error == ProgramError::Custom(StakePoolError::NumericalOverflow as u32)
+}
+
+/// What a stake's current round earns, computed independently of the program
+fn expected_round_rewards(amount: u64, rate: u64) -> Option<u64> {
+    u64::try_from(amount as u128 * rate as u128 / REWARD_SCALE).ok()
+}
+
+/// Apply a ClaimRewards of everything claimable, as the processor does
+fn claim(pool: &mut StakePool, stake: &mut StakeAccount, now: i64) -> Result<(), ProgramError> {
+    let earned = pool.stake_rewards(stake, now)?;
+    let claimable = earned
+        .checked_sub(stake.claimed_rewards)
+        .ok_or(StakePoolError::NumericalOverflow)?;
+    stake.claimed_rewards = earned;
+    pool.total_rewards_owed = pool
+        .total_rewards_owed
+        .checked_sub(claimable)
+        .ok_or(StakePoolError::NumericalOverflow)?;
+    Ok(())
+}
+
+#[derive(Clone, Debug)]
+enum StakeStep {
+    Claim,
+    /// Unstake this share of the remaining principal (in bps, at least one token)
+    Unstake(u16),
+    Slash(u16),
+}
+
+fn stake_step() -> impl Strategy<Value = StakeStep> {
+    prop_oneof![
+        Just(StakeStep::Claim),
+        (1..=MAX_BPS).prop_map(StakeStep::Unstake),
+        (1..=MAX_BPS).prop_map(StakeStep::Slash),
+    ]
+}
+
+#[derive(Clone, Debug)]
+enum CreatorStep {
+    Add(usize),
+    Remove(usize),
+    SetMax(u16),
+}
+
+fn creator_step() -> impl Strategy<Value = CreatorStep> {
+    prop_oneof![
+        (0..24usize).prop_map(CreatorStep::Add),
+        (0..24usize).prop_map(CreatorStep::Remove),
+        (0..=ProgramAuthority::MAX_CREATORS + 1).prop_map(CreatorStep::SetMax),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn calculate_rewards_pays_the_full_round_once_locked_up(
+        amount in any::<u64>(),
+        rate in any::<u64>(),
+        lockup_period in 0..=(10 * 365 * 86_400i64),
+        elapsed in 0..=(20 * 365 * 86_400i64),
+    ) {
+        let mut pool = sample_stake_pool();
+        pool.lockup_period = lockup_period;
+        let stake = stake_with(&Pubkey::new_unique(), amount, rate);
+        let now = stake.stake_timestamp + elapsed;
+
+        match pool.calculate_rewards(&stake, now) {
+            Ok(rewards) if elapsed < lockup_period => prop_assert_eq!(rewards, 0),
+            Ok(rewards) => {
+                prop_assert_eq!(Some(rewards), expected_round_rewards(amount, rate));
+            }
+            Err(error) => {
+                prop_assert!(is_overflow(&error));
+                prop_assert!(elapsed >= lockup_period);
+                prop_assert_eq!(expected_round_rewards(amount, rate), None);
+            }
+        }
+    }
+
+    #[test]
+    fn rewards_owed_track_every_stake_through_claims_unstakes_and_slashes(
+        amount in 1..=1_000_000_000_000_000u64,
+        rate in 0..=(10 * REWARD_SCALE as u64),
+        carried in 0..=1_000_000_000u64,
+        other_owed in 0..=1_000_000_000u64,
+        matured in any::<bool>(),
+        steps in prop::collection::vec(stake_step(), 1..12),
+    ) {
+        let mut pool = sample_stake_pool();
+        pool.active_stake_count = Some(1);
+        pool.total_staked = amount;
+        let mut stake = stake_with(&Pubkey::new_unique(), amount, rate);
+        stake.carried_rewards = carried;
+        // Other stakes' reservations must never absorb this stake's rounding
+        pool.total_rewards_owed = other_owed + stake.unclaimed_rewards().unwrap();
+        let now = stake.stake_timestamp + if matured { pool.lockup_period } else { 0 };
+
+        for step in steps {
+            if stake.amount_staked == 0 {
+                break;
+            }
+            match step {
+                StakeStep::Claim => claim(&mut pool, &mut stake, now).unwrap(),
+                StakeStep::Unstake(bps) => {
+                    let share = (stake.amount_staked as u128 * bps as u128 / BPS_DENOMINATOR as u128) as u64;
+                    pool.request_unstake(&mut stake, share.max(1), now).unwrap();
+                }
+                StakeStep::Slash(bps) => {
+                    pool.slash_stake(&mut stake, bps).unwrap();
+                }
+            }
+
+            let unclaimed = stake.unclaimed_rewards().unwrap();
+            prop_assert_eq!(pool.total_rewards_owed, other_owed + unclaimed);
+            prop_assert!(stake.claimed_rewards <= stake.round_rewards().unwrap() + stake.carried_rewards);
+            prop_assert!(pool.stake_rewards(&stake, now).unwrap() >= stake.claimed_rewards);
+        }
+
+        // Emptying the stake releases exactly what it still had reserved
+        if stake.amount_staked > 0 {
+            let remaining = stake.amount_staked;
+            pool.request_unstake(&mut stake, remaining, now).unwrap();
+        }
+        prop_assert_eq!(pool.total_rewards_owed, other_owed);
+        prop_assert_eq!(stake.unclaimed_rewards().unwrap(), 0);
+    }
+
+    #[test]
+    fn partial_forfeiture_never_exceeds_the_unclaimed_rewards(
+        amount in 1..=u64::MAX / 2,
+        rate in 0..=(10 * REWARD_SCALE as u64),
+        carried in 0..=u32::MAX as u64,
+        withdrawn_bps in 0..=MAX_BPS,
+    ) {
+        let pool = sample_stake_pool();
+        let mut stake = stake_with(&Pubkey::new_unique(), amount, rate);
+        stake.carried_rewards = carried;
+        let Ok(unclaimed) = stake.unclaimed_rewards() else {
+            return Ok(());
+        };
+        let withdrawn = (amount as u128 * withdrawn_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+
+        let forfeited = pool.unstake_forfeiture(&stake, withdrawn).unwrap();
+        prop_assert!(forfeited <= unclaimed);
+        if withdrawn == amount {
+            prop_assert_eq!(forfeited, unclaimed);
+        }
+
+        stake.remove_principal(withdrawn, forfeited).unwrap();
+        prop_assert_eq!(stake.unclaimed_rewards().unwrap(), unclaimed - forfeited);
+    }
+
+    #[test]
+    fn creator_list_matches_the_adds_and_removes_applied(
+        steps in prop::collection::vec(creator_step(), 1..64),
+    ) {
+        let authority = Pubkey::new_unique();
+        let candidates: Vec<Pubkey> = (0..24).map(|_| Pubkey::new_unique()).collect();
+        let mut program_authority = ProgramAuthority {
+            key: Key::ProgramAuthorityV2,
+            authority,
+            authorized_creators: Vec::new(),
+            pending_authority: None,
+            bump: 255,
+            cluster_profile: ClusterProfile::Mainnet,
+            global_pause: false,
+            withdrawals_only: false,
+            role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+            approval_threshold: 0,
+            recovery_authority: None,
+            recovery_delay: 0,
+            recovery_initiated_at: None,
+            max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
+        };
+        let mut model: Vec<Pubkey> = Vec::new();
+
+        for step in steps {
+            match step {
+                CreatorStep::Add(i) => {
+                    let creator = candidates[i];
+                    let accepted = !model.contains(&creator)
+                        && model.len() < program_authority.max_creators as usize;
+                    prop_assert_eq!(program_authority.add_creator(creator).is_ok(), accepted);
+                    if accepted {
+                        model.push(creator);
+                    }
+                }
+                CreatorStep::Remove(i) => {
+                    let creator = candidates[i];
+                    let present = model.contains(&creator);
+                    prop_assert_eq!(program_authority.remove_creator(&creator).is_ok(), present);
+                    model.retain(|c| c != &creator);
+                }
+                CreatorStep::SetMax(max) => {
+                    let accepted = max <= ProgramAuthority::MAX_CREATORS && max as usize >= model.len();
+                    prop_assert_eq!(program_authority.set_max_creators(max).is_ok(), accepted);
+                }
+            }
+
+            let listed: Vec<Pubkey> = program_authority
+                .authorized_creators
+                .iter()
+                .map(|entry| entry.creator)
+                .collect();
+            prop_assert_eq!(&listed, &model);
+            prop_assert_eq!(listed.iter().collect::<HashSet<_>>().len(), listed.len());
+            prop_assert!(program_authority.validate_creator_count().is_ok());
+            prop_assert!(program_authority.is_authorized(&authority));
+            prop_assert!(program_authority.remove_creator(&authority).is_err());
+            prop_assert!(
+                program_authority.to_account_data().unwrap().len()
+                    <= program_authority.required_len()
+            );
+        }
+    }
+}
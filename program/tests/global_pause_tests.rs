@@ -0,0 +1,117 @@
+// ============================================================================
+// Global Pause Tests
+// ============================================================================
+// The ProgramAuthority carries two protocol-wide incident switches:
+// global_pause halts stake, unstake and claim operations in every pool, and
+// withdrawals_only blocks stakes and claims while principal can still leave.
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    error::StakePoolError,
+    state::{
+        AuthorizedCreator, ClusterProfile, Key, ProgramAuthority, Role, RoleHolder, UserOperation,
+    },
+};
+
+const OPERATIONS: [UserOperation; 3] = [
+    UserOperation::Deposit,
+    UserOperation::Withdrawal,
+    UserOperation::Claim,
+];
+
+fn program_authority(global_pause: bool, withdrawals_only: bool) -> ProgramAuthority {
+    ProgramAuthority {
+        key: Key::ProgramAuthorityV2,
+        authority: Pubkey::new_unique(),
+        authorized_creators: Vec::new(),
+        pending_authority: None,
+        bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause,
+        withdrawals_only,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
+    }
+}
+
+fn error(code: StakePoolError) -> ProgramError {
+    ProgramError::Custom(code as u32)
+}
+
+#[test]
+fn test_unpaused_protocol_allows_everything() {
+    let authority = program_authority(false, false);
+
+    for operation in OPERATIONS {
+        assert!(authority.check_not_paused(operation).is_ok());
+    }
+}
+
+#[test]
+fn test_global_pause_blocks_everything() {
+    // The full pause wins over withdrawals-only mode
+    for withdrawals_only in [false, true] {
+        let authority = program_authority(true, withdrawals_only);
+
+        for operation in OPERATIONS {
+            assert_eq!(
+                authority.check_not_paused(operation).unwrap_err(),
+                error(StakePoolError::ProtocolPaused)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_withdrawals_only_keeps_unstakes_open() {
+    let authority = program_authority(false, true);
+
+    assert!(authority
+        .check_not_paused(UserOperation::Withdrawal)
+        .is_ok());
+    assert_eq!(
+        authority
+            .check_not_paused(UserOperation::Deposit)
+            .unwrap_err(),
+        error(StakePoolError::WithdrawalsOnly)
+    );
+    assert_eq!(
+        authority
+            .check_not_paused(UserOperation::Claim)
+            .unwrap_err(),
+        error(StakePoolError::WithdrawalsOnly)
+    );
+}
+
+#[test]
+fn test_pause_switches_round_trip() {
+    let mut authority = program_authority(true, true);
+    authority.authorized_creators = vec![
+        AuthorizedCreator {
+            creator: Pubkey::new_unique(),
+            pools_created: u16::MAX,
+            pool_limit: Some(u16::MAX),
+        };
+        ProgramAuthority::DEFAULT_MAX_CREATORS as usize
+    ];
+    authority.pending_authority = Some(Pubkey::new_unique());
+    authority.role_holders = [Some(RoleHolder {
+        holder: Pubkey::new_unique(),
+        roles: Role::ALL,
+    }); ProgramAuthority::MAX_ROLE_HOLDERS];
+    authority.recovery_authority = Some(Pubkey::new_unique());
+    authority.recovery_initiated_at = Some(i64::MAX);
+
+    // The switches fit in LEN even when every optional slot and creator is filled
+    let data = authority.to_account_data().unwrap();
+    assert_eq!(data.len(), ProgramAuthority::LEN);
+
+    let decoded = ProgramAuthority::from_account_data(&data).unwrap();
+    assert!(decoded.global_pause);
+    assert!(decoded.withdrawals_only);
+}
@@ -0,0 +1,86 @@
+// ============================================================================
+// Hook Tests
+// ============================================================================
+// Pools may call an allowlisted program after every Stake, Unstake and
+// ClaimRewards. The allowlist never holds the pool program itself (which would
+// re-enter its own instructions), and hook calls carry a fixed discriminator so
+// hooks can tell them apart from their own instructions.
+
+use solana_program::{hash::hash, program_error::ProgramError};
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    error::StakePoolError,
+    hook::{StakePoolHookInstruction, HOOK_DISCRIMINATOR},
+    state::{HookAllowlist, Key},
+};
+
+fn empty_allowlist() -> HookAllowlist {
+    HookAllowlist {
+        key: Key::HookAllowlist,
+        bump: 255,
+        programs: Vec::new(),
+    }
+}
+
+#[test]
+fn test_hook_allowlist_adds_and_removes_programs() {
+    let mut allowlist = empty_allowlist();
+    let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    allowlist.update(&[first, second, first], &[]).unwrap();
+    assert_eq!(allowlist.programs, vec![first, second]);
+
+    allowlist.update(&[], &[first]).unwrap();
+    assert!(!allowlist.contains(&first));
+    assert!(allowlist.contains(&second));
+}
+
+#[test]
+fn test_pool_program_cannot_be_its_own_hook() {
+    let mut allowlist = empty_allowlist();
+
+    let err = allowlist
+        .update(&[Pubkey::new_unique(), your_wallet_stake_pool::ID], &[])
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ProgramError::Custom(StakePoolError::HookProgramNotAllowed as u32)
+    );
+    assert!(allowlist.programs.is_empty());
+}
+
+#[test]
+fn test_hook_allowlist_capacity_fits_its_account() {
+    let mut allowlist = empty_allowlist();
+    let programs: Vec<Pubkey> = (0..HookAllowlist::CAPACITY)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    allowlist.update(&programs, &[]).unwrap();
+    assert_eq!(borsh::to_vec(&allowlist).unwrap().len(), HookAllowlist::LEN);
+
+    let err = allowlist.update(&[Pubkey::new_unique()], &[]).unwrap_err();
+    assert_eq!(
+        err,
+        ProgramError::Custom(StakePoolError::InvalidParameters as u32)
+    );
+    assert_eq!(allowlist.programs, programs);
+}
+
+#[test]
+fn test_hook_instruction_data_round_trips() {
+    assert_eq!(
+        HOOK_DISCRIMINATOR,
+        hash(b"your-wallet-stake-pool:hook").to_bytes()[..8]
+    );
+
+    let operation = StakePoolHookInstruction::AfterUnstake {
+        amount: 400,
+        remaining: 600,
+    };
+    let data = operation.data().unwrap();
+    assert_eq!(data[..8], HOOK_DISCRIMINATOR);
+    assert_eq!(StakePoolHookInstruction::unpack(&data), Some(operation));
+
+    // Other instructions of the hook program are not mistaken for callbacks
+    assert_eq!(StakePoolHookInstruction::unpack(&data[1..]), None);
+}
@@ -0,0 +1,435 @@
+// ============================================================================
+// IDL Drift Tests
+// ============================================================================
+// The checked-in IDL (program/idl.json) is the input of the generated TypeScript
+// client in clients/js. These tests parse the program source with shank's own
+// parser and fail when the IDL no longer matches the instruction layouts,
+// account structs, error codes or events, so the TS bindings cannot silently
+// diverge.
+//
+// Shank does not know about events; `pnpm generate:idls` adds them in the
+// Anchor `events` format by running `export_idl_events` below. Each event also
+// records its one-byte `StakePoolEvent` discriminant.
+//
+// To fix a failure, regenerate the IDL and the clients:
+//   pnpm generate
+
+use serde_json::{json, Value};
+use shank_macro_impl::{
+    instruction::{Instruction, InstructionVariantFields},
+    parsed_struct::ParsedStruct,
+    syn::{self, Fields, Item, ItemEnum, Lit, Meta, NestedMeta, Type},
+    types::{Composite, RustType, TypeKind, Value as ValueKind},
+};
+use std::{convert::TryFrom, fs, path::Path};
+
+fn read_idl() -> Value {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("idl.json");
+    serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+fn parse_source(file: &str) -> syn::File {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join(file);
+    syn::parse_file(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+/// shank renders every name in lowerCamelCase (leading underscores dropped)
+fn camel_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, word) in name.split('_').filter(|w| !w.is_empty()).enumerate() {
+        if i == 0 {
+            out.push_str(word);
+        } else {
+            let mut chars = word.chars();
+            if let Some(first) = chars.next() {
+                out.extend(first.to_uppercase());
+                out.push_str(chars.as_str());
+            }
+        }
+    }
+    out
+}
+
+/// Render a Rust type the way shank writes it into the IDL
+fn idl_type(ty: &RustType) -> Value {
+    match &ty.kind {
+        TypeKind::Primitive(_) => json!(ty.ident.to_string()),
+        TypeKind::Value(ValueKind::Custom(name)) if name == "Pubkey" => json!("publicKey"),
+        TypeKind::Value(ValueKind::Custom(name)) => json!({ "defined": name }),
+        TypeKind::Value(_) => json!("string"),
+        TypeKind::Composite(Composite::Option, inner) => json!({ "option": idl_type(&inner[0]) }),
+        TypeKind::Composite(Composite::Vec, inner) => json!({ "vec": idl_type(&inner[0]) }),
+        TypeKind::Composite(Composite::Array(len), inner) => {
+            json!({ "array": [idl_type(&inner[0]), len] })
+        }
+        kind => panic!("type {:?} has no IDL mapping in this test", kind),
+    }
+}
+
+fn expected_instructions() -> Vec<Value> {
+    let file = parse_source("instruction.rs");
+    let item_enum = file
+        .items
+        .iter()
+        .find_map(|item| match item {
+            Item::Enum(e) if e.ident == "StakePoolInstruction" => Some(e),
+            _ => None,
+        })
+        .expect("StakePoolInstruction enum");
+    let instruction = Instruction::try_from_item_enum(item_enum, false)
+        .unwrap()
+        .expect("StakePoolInstruction derives ShankInstruction");
+
+    instruction
+        .variants
+        .iter()
+        .map(|variant| {
+            let accounts: Vec<Value> = variant
+                .accounts
+                .iter()
+                .map(|account| {
+                    let mut value = json!({
+                        "name": camel_case(&account.name),
+                        "isMut": account.writable,
+                        "isSigner": account.signer,
+                    });
+                    if account.optional_signer {
+                        value["isOptionalSigner"] = json!(true);
+                    }
+                    if account.optional {
+                        value["isOptional"] = json!(true);
+                    }
+                    if let Some(desc) = &account.desc {
+                        value["docs"] = json!([desc]);
+                    }
+                    value
+                })
+                .collect();
+            let args: Vec<Value> = match &variant.field_tys {
+                InstructionVariantFields::Named(fields) => fields
+                    .iter()
+                    .map(|(name, ty)| json!({ "name": camel_case(name), "type": idl_type(ty) }))
+                    .collect(),
+                InstructionVariantFields::Unnamed(fields) => fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, ty)| json!({ "name": format!("arg{}", i), "type": idl_type(ty) }))
+                    .collect(),
+            };
+
+            json!({
+                "name": variant.ident.to_string(),
+                "accounts": accounts,
+                "args": args,
+                "discriminant": { "type": "u8", "value": variant.discriminant },
+            })
+        })
+        .collect()
+}
+
+fn expected_accounts() -> Vec<Value> {
+    parse_source("state.rs")
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Struct(s) => Some(ParsedStruct::try_from(s).unwrap()),
+            _ => None,
+        })
+        .filter(|s| {
+            s.attrs
+                .iter()
+                .any(|attr| attr.tokens.to_string().contains("ShankAccount"))
+        })
+        .map(|s| {
+            let fields: Vec<Value> = s
+                .fields
+                .iter()
+                .map(|f| json!({ "name": camel_case(&f.ident.to_string()), "type": idl_type(&f.rust_type) }))
+                .collect();
+            json!({
+                "name": s.ident.to_string(),
+                "type": { "kind": "struct", "fields": fields },
+            })
+        })
+        .collect()
+}
+
+fn expected_errors() -> Vec<Value> {
+    let file = parse_source("error.rs");
+    let item_enum = file
+        .items
+        .iter()
+        .find_map(|item| match item {
+            Item::Enum(e) if e.ident == "StakePoolError" => Some(e),
+            _ => None,
+        })
+        .expect("StakePoolError enum");
+
+    item_enum
+        .variants
+        .iter()
+        .enumerate()
+        .map(|(code, variant)| {
+            let msg = variant
+                .attrs
+                .iter()
+                .filter(|attr| attr.path.is_ident("error"))
+                .find_map(|attr| match attr.parse_meta().ok()? {
+                    Meta::List(list) => match list.nested.first()? {
+                        NestedMeta::Lit(Lit::Str(s)) => Some(s.value()),
+                        _ => None,
+                    },
+                    _ => None,
+                })
+                .expect("every error variant has an #[error] message");
+            json!({ "code": code, "name": variant.ident.to_string(), "msg": msg })
+        })
+        .collect()
+}
+
+/// The single payload type of a tuple variant, e.g. `StakeEvent` of `Stake(StakeEvent)`
+fn payload_name(fields: &Fields) -> String {
+    match fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => match &fields.unnamed[0].ty {
+            Type::Path(path) => path.path.segments.last().unwrap().ident.to_string(),
+            ty => panic!("unsupported event payload {:?}", ty),
+        },
+        _ => panic!("StakePoolEvent variants wrap a single event struct"),
+    }
+}
+
+fn rust_type(ty: &Type) -> RustType {
+    RustType::try_from(ty).unwrap()
+}
+
+fn struct_fields(fields: &Fields) -> Vec<Value> {
+    fields
+        .iter()
+        .map(|f| {
+            json!({
+                "name": camel_case(&f.ident.as_ref().unwrap().to_string()),
+                "type": idl_type(&rust_type(&f.ty)),
+            })
+        })
+        .collect()
+}
+
+fn enum_type(item_enum: &ItemEnum) -> Value {
+    let variants: Vec<Value> = item_enum
+        .variants
+        .iter()
+        .map(|variant| {
+            let name = variant.ident.to_string();
+            match &variant.fields {
+                Fields::Unit => json!({ "name": name }),
+                Fields::Named(_) => {
+                    json!({ "name": name, "fields": struct_fields(&variant.fields) })
+                }
+                Fields::Unnamed(fields) => {
+                    let fields: Vec<Value> = fields
+                        .unnamed
+                        .iter()
+                        .map(|f| idl_type(&rust_type(&f.ty)))
+                        .collect();
+                    json!({ "name": name, "fields": fields })
+                }
+            }
+        })
+        .collect();
+    json!({
+        "name": item_enum.ident.to_string(),
+        "type": { "kind": "enum", "variants": variants },
+    })
+}
+
+/// Events in the Anchor IDL format, in `StakePoolEvent` discriminant order
+fn expected_events() -> Vec<Value> {
+    let file = parse_source("events.rs");
+    let event_enum = file
+        .items
+        .iter()
+        .find_map(|item| match item {
+            Item::Enum(e) if e.ident == "StakePoolEvent" => Some(e),
+            _ => None,
+        })
+        .expect("StakePoolEvent enum");
+
+    event_enum
+        .variants
+        .iter()
+        .enumerate()
+        .map(|(discriminant, variant)| {
+            let payload = payload_name(&variant.fields);
+            let item_struct = file
+                .items
+                .iter()
+                .find_map(|item| match item {
+                    Item::Struct(s) if s.ident == payload => Some(s),
+                    _ => None,
+                })
+                .unwrap_or_else(|| panic!("event struct {} in events.rs", payload));
+            let fields: Vec<Value> = struct_fields(&item_struct.fields)
+                .into_iter()
+                .map(|mut field| {
+                    field["index"] = json!(false);
+                    field
+                })
+                .collect();
+            json!({
+                "name": variant.ident.to_string(),
+                "fields": fields,
+                "discriminant": { "type": "u8", "value": discriminant },
+            })
+        })
+        .collect()
+}
+
+/// Types declared in events.rs that events reference, e.g. `PoolParameter`
+fn expected_event_types() -> Vec<Value> {
+    let file = parse_source("events.rs");
+    let payloads: Vec<String> = file
+        .items
+        .iter()
+        .find_map(|item| match item {
+            Item::Enum(e) if e.ident == "StakePoolEvent" => Some(e),
+            _ => None,
+        })
+        .expect("StakePoolEvent enum")
+        .variants
+        .iter()
+        .map(|variant| payload_name(&variant.fields))
+        .collect();
+
+    file.items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Struct(s) if !payloads.contains(&s.ident.to_string()) => Some(json!({
+                "name": s.ident.to_string(),
+                "type": { "kind": "struct", "fields": struct_fields(&s.fields) },
+            })),
+            Item::Enum(e) if e.ident != "StakePoolEvent" => Some(enum_type(e)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Every `{ "defined": name }` anywhere below `value`
+fn defined_names(value: &Value, names: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(name)) = map.get("defined") {
+                names.push(name.clone());
+            }
+            map.values().for_each(|v| defined_names(v, names));
+        }
+        Value::Array(items) => items.iter().for_each(|v| defined_names(v, names)),
+        _ => {}
+    }
+}
+
+/// Compare item by item so a failure names the first drifted entry
+fn assert_matches_idl(section: &str, expected: Vec<Value>) {
+    let idl = read_idl();
+    let actual = idl[section].as_array().unwrap();
+
+    for (expected, actual) in expected.iter().zip(actual.iter()) {
+        assert_eq!(
+            expected, actual,
+            "idl.json `{}` entry {} is out of date with the program source",
+            section, expected["name"]
+        );
+    }
+    assert_eq!(
+        expected.len(),
+        actual.len(),
+        "idl.json has {} `{}` entries, the program source has {}",
+        actual.len(),
+        section,
+        expected.len()
+    );
+}
+
+#[test]
+fn test_idl_instructions_match_program() {
+    assert_matches_idl("instructions", expected_instructions());
+}
+
+#[test]
+fn test_idl_accounts_match_program() {
+    assert_matches_idl("accounts", expected_accounts());
+}
+
+#[test]
+fn test_idl_errors_match_program() {
+    assert_matches_idl("errors", expected_errors());
+}
+
+#[test]
+fn test_idl_events_match_program() {
+    assert_matches_idl("events", expected_events());
+}
+
+#[test]
+fn test_idl_event_types_match_program() {
+    let idl = read_idl();
+    let types = idl["types"].as_array().unwrap();
+
+    for expected in expected_event_types() {
+        let actual = types
+            .iter()
+            .find(|t| t["name"] == expected["name"])
+            .unwrap_or_else(|| panic!("idl.json has no type {}", expected["name"]));
+        assert_eq!(
+            &expected, actual,
+            "idl.json type {} is out of date with the program source",
+            expected["name"]
+        );
+    }
+}
+
+#[test]
+fn test_idl_defines_every_referenced_type() {
+    let idl = read_idl();
+    let types: Vec<&str> = idl["types"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["name"].as_str().unwrap())
+        .collect();
+
+    let mut referenced = Vec::new();
+    defined_names(&idl, &mut referenced);
+    for name in referenced {
+        assert!(
+            types.contains(&name.as_str()),
+            "idl.json has no type {}",
+            name
+        );
+    }
+}
+
+#[test]
+fn test_embedded_idl_is_the_checked_in_idl() {
+    let embedded: Value = serde_json::from_str(your_wallet_stake_pool::IDL_JSON).unwrap();
+    assert_eq!(embedded, read_idl());
+}
+
+/// Write the events and event types to `$IDL_EVENTS_OUT` (run by `pnpm generate:idls`)
+#[test]
+#[ignore = "exports the IDL events for scripts/generate-idls.mjs"]
+fn export_idl_events() {
+    let out = std::env::var("IDL_EVENTS_OUT").expect("IDL_EVENTS_OUT is set");
+    let exported = json!({ "events": expected_events(), "types": expected_event_types() });
+    fs::write(out, serde_json::to_string(&exported).unwrap()).unwrap();
+}
+
+#[test]
+fn test_idl_version_matches_crate() {
+    let idl = read_idl();
+
+    assert_eq!(idl["version"], env!("CARGO_PKG_VERSION"));
+    assert_eq!(
+        idl["metadata"]["address"],
+        your_wallet_stake_pool::ID.to_string()
+    );
+}
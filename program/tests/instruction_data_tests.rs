@@ -0,0 +1,154 @@
+// ============================================================================
+// Instruction Data Tests
+// ============================================================================
+// process_instruction rejects instruction data that does not decode to exactly
+// one StakePoolInstruction (truncated payloads, unknown variants, trailing
+// bytes) with InvalidInstructionData, and variable-length arguments above
+// what any processor accepts with InvalidParameters, before any account is read.
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::constants::{MAX_MEMO_LEN, MAX_MERKLE_PROOF_LEN, MAX_STAKE_BATCH};
+use your_wallet_stake_pool::error::StakePoolError;
+use your_wallet_stake_pool::instruction::StakePoolInstruction;
+use your_wallet_stake_pool::processor::process_instruction;
+use your_wallet_stake_pool::state::{PoolAllowlist, ProgramAuthority, StakePool};
+
+fn process(data: &[u8]) -> Result<(), ProgramError> {
+    process_instruction(&your_wallet_stake_pool::ID, &[], data)
+}
+
+fn data(instruction: &StakePoolInstruction) -> Vec<u8> {
+    borsh::to_vec(instruction).unwrap()
+}
+
+#[test]
+fn test_well_formed_data_reaches_account_parsing() {
+    assert_eq!(
+        process(&data(&StakePoolInstruction::SyncPool)),
+        Err(ProgramError::NotEnoughAccountKeys)
+    );
+}
+
+#[test]
+fn test_trailing_bytes_are_rejected() {
+    let mut payload = data(&StakePoolInstruction::SyncPool);
+    payload.push(0);
+    assert_eq!(process(&payload), Err(ProgramError::InvalidInstructionData));
+
+    let mut payload = data(&StakePoolInstruction::FundRewards { amount: 5 });
+    payload.extend_from_slice(&[1, 2, 3]);
+    assert_eq!(process(&payload), Err(ProgramError::InvalidInstructionData));
+}
+
+#[test]
+fn test_truncated_and_unknown_payloads_are_rejected() {
+    let payload = data(&StakePoolInstruction::FundRewards { amount: 5 });
+    assert_eq!(
+        process(&payload[..payload.len() - 1]),
+        Err(ProgramError::InvalidInstructionData)
+    );
+    assert_eq!(process(&[]), Err(ProgramError::InvalidInstructionData));
+    assert_eq!(
+        process(&[u8::MAX]),
+        Err(ProgramError::InvalidInstructionData)
+    );
+}
+
+#[test]
+fn test_vec_length_prefix_beyond_the_data_is_rejected() {
+    // SetRewardFunders claiming 1,000 funders but carrying one
+    let mut payload = data(&StakePoolInstruction::SetRewardFunders {
+        funders: vec![Pubkey::new_unique()],
+    });
+    payload[1..5].copy_from_slice(&1_000u32.to_le_bytes());
+    assert_eq!(process(&payload), Err(ProgramError::InvalidInstructionData));
+}
+
+#[test]
+fn test_oversized_arguments_are_rejected_before_dispatch() {
+    let oversized = [
+        StakePoolInstruction::SetRewardFunders {
+            funders: vec![Pubkey::new_unique(); StakePool::MAX_REWARD_FUNDERS + 1],
+        },
+        StakePoolInstruction::ManageAuthorizedCreators {
+            add: vec![Pubkey::new_unique(); ProgramAuthority::MAX_CREATORS as usize + 1],
+            remove: vec![],
+        },
+        StakePoolInstruction::ManagePoolAllowlist {
+            page: 0,
+            add: vec![],
+            remove: vec![Pubkey::new_unique(); PoolAllowlist::PAGE_CAPACITY + 1],
+            allowlist_required: None,
+        },
+        StakePoolInstruction::StakeBatch {
+            amounts: vec![1; MAX_STAKE_BATCH + 1],
+            start_index: 0,
+            expected_reward_rate: None,
+            expected_lockup_period: None,
+            allowlist_proof: None,
+        },
+        StakePoolInstruction::ClaimRewards {
+            amount: None,
+            memo: Some("m".repeat(MAX_MEMO_LEN + 1)),
+            deadline: None,
+        },
+        StakePoolInstruction::StakeSol {
+            amount: 1,
+            index: 0,
+            expected_reward_rate: None,
+            expected_lockup_period: None,
+            referrer: None,
+            auto_relock: false,
+            label: None,
+            allowlist_proof: Some(vec![[0; 32]; MAX_MERKLE_PROOF_LEN + 1]),
+        },
+    ];
+
+    let too_long = Err(StakePoolError::InvalidParameters.into());
+    for instruction in &oversized {
+        assert_eq!(
+            instruction.check_lengths(),
+            too_long,
+            "{}",
+            instruction.name()
+        );
+        assert_eq!(
+            process(&data(instruction)),
+            too_long,
+            "{}",
+            instruction.name()
+        );
+    }
+}
+
+#[test]
+fn test_arguments_at_their_maximum_pass() {
+    let at_max = [
+        StakePoolInstruction::SetRewardFunders {
+            funders: vec![Pubkey::new_unique(); StakePool::MAX_REWARD_FUNDERS],
+        },
+        StakePoolInstruction::StakeBatch {
+            amounts: vec![1; MAX_STAKE_BATCH],
+            start_index: 0,
+            expected_reward_rate: None,
+            expected_lockup_period: None,
+            allowlist_proof: Some(vec![[0; 32]; MAX_MERKLE_PROOF_LEN]),
+        },
+        StakePoolInstruction::Unstake {
+            amount: 1,
+            expected_reward_rate: None,
+            memo: Some("m".repeat(MAX_MEMO_LEN)),
+            deadline: None,
+        },
+    ];
+
+    for instruction in &at_max {
+        assert_eq!(
+            instruction.check_lengths(),
+            Ok(()),
+            "{}",
+            instruction.name()
+        );
+    }
+}
@@ -27,6 +27,7 @@ use solana_sdk::{
     transaction::Transaction,
 };
 use your_wallet_stake_pool::instruction::StakePoolInstruction;
+use your_wallet_stake_pool::state::RewardRateMode;
 
 use common::*;
 
@@ -119,6 +120,17 @@ impl TestEnvironment {
                 lockup_period,
                 enforce_lockup,
                 pool_end_date,
+                referral_bps: 0,
+                early_unstake_bps: 0,
+                deposit_fee_bps: 0,
+                withdraw_fee_bps: 0,
+                reward_rate_change_delay: None,
+                min_reward_rate_bound: None,
+                max_reward_rate_bound: None,
+                reward_tiers: vec![],
+                bonus_end_ts: None,
+                bonus_multiplier_bps: 0,
+                reward_rate_mode: RewardRateMode::PerLockup,
             }
             .try_to_vec()
             .unwrap(),
@@ -179,6 +191,26 @@ fn test_update_pool_parameters() {
             is_paused: Some(false),
             enforce_lockup: None,
             pool_end_date: None,
+            referral_bps: None,
+            early_unstake_bps: None,
+            deposit_fee_bps: None,
+            withdraw_fee_bps: None,
+            unstake_cooldown: None,
+            bonus_end_ts: None,
+            bonus_multiplier_bps: None,
+            lockup_extension_rate: None,
+            allowlist_merkle_root: None,
+            claim_window: None,
+            outflow_limit: None,
+            pool_manager: None,
+            keeper_bounty: None,
+            max_total_rewards: None,
+            enforce_min_remaining_stake: None,
+            wind_down_rewards: None,
+            points_rate: None,
+            deny_list_required: None,
+            allow_third_party_stake: None,
+            cohort_duration: None,
         }
         .try_to_vec()
         .unwrap(),
@@ -197,7 +229,10 @@ fn test_update_pool_parameters() {
     let pool = load_stake_pool(&env.svm, &env.pool_pda);
     assert_eq!(pool.reward_rate, 200_000_000);
     assert_eq!(pool.min_stake_amount, 5_000_000);
-    assert_eq!(pool.lockup_period, 172800);
+
+    // Lockup changes wait for FinalizeLockupChange
+    assert_eq!(pool.lockup_period, 86400);
+    assert_eq!(pool.pending_lockup_period, Some(172800));
 }
 
 #[test]
@@ -0,0 +1,61 @@
+// ============================================================================
+// Keeper Bounty Tests
+// ============================================================================
+// Global admins may set a per-pool keeper bounty (UpdatePool). Finalize, sync
+// and stream cranks pay it to the keeper account passed with them, out of the
+// pool account's lamports above rent. Payouts are capped per crank and limited
+// to one per KEEPER_BOUNTY_INTERVAL so a repeatable crank cannot drain them.
+
+mod common;
+
+use your_wallet_stake_pool::constants::KEEPER_BOUNTY_INTERVAL;
+use your_wallet_stake_pool::processor::PoolUpdate;
+
+use common::*;
+
+const NOW: i64 = 1_700_000_000;
+
+#[test]
+fn test_no_bounty_is_due_while_unset() {
+    let pool = sample_stake_pool();
+    assert_eq!(pool.keeper_bounty, 0);
+    assert_eq!(pool.keeper_bounty_due(1_000_000, NOW), 0);
+}
+
+#[test]
+fn test_bounty_is_paid_at_most_once_per_interval() {
+    let mut pool = sample_stake_pool();
+    pool.keeper_bounty = 5_000;
+
+    assert_eq!(pool.keeper_bounty_due(1_000_000, NOW), 5_000);
+
+    pool.last_keeper_bounty_at = NOW;
+    assert_eq!(pool.keeper_bounty_due(1_000_000, NOW), 0);
+    assert_eq!(
+        pool.keeper_bounty_due(1_000_000, NOW + KEEPER_BOUNTY_INTERVAL - 1),
+        0
+    );
+    assert_eq!(
+        pool.keeper_bounty_due(1_000_000, NOW + KEEPER_BOUNTY_INTERVAL),
+        5_000
+    );
+}
+
+#[test]
+fn test_a_short_escrow_pays_what_is_left() {
+    let mut pool = sample_stake_pool();
+    pool.keeper_bounty = 5_000;
+
+    assert_eq!(pool.keeper_bounty_due(1_200, NOW), 1_200);
+    assert_eq!(pool.keeper_bounty_due(0, NOW), 0);
+}
+
+#[test]
+fn test_only_global_admins_set_the_bounty() {
+    let update = PoolUpdate {
+        keeper_bounty: Some(5_000),
+        ..Default::default()
+    };
+    assert_eq!(update.required_roles(), None);
+    assert!(!update.is_manager_update());
+}
@@ -0,0 +1,88 @@
+// ============================================================================
+// Locked Reward Rate Tests
+// ============================================================================
+// Each stake round locks the pool's reward_rate when it starts. Rewards are
+// reserved in total_rewards_owed at that rate and paid at that rate, so a
+// reward rate change finalized later never leaves total_rewards_owed stale.
+
+mod common;
+
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::state::{StakeAccount, StakePool};
+
+use common::*;
+
+const STAKED: u64 = 1_000_000;
+const TEN_PERCENT: u64 = 100_000_000;
+const TWENTY_PERCENT: u64 = 200_000_000;
+
+/// A pool with one 10% stake reserved in total_rewards_owed
+fn setup() -> (StakePool, StakeAccount, i64) {
+    let mut pool = sample_stake_pool();
+    pool.reward_rate = TEN_PERCENT;
+    pool.total_staked = STAKED;
+    pool.total_rewards_owed = pool
+        .expected_rewards(STAKED, SAMPLE_STAKE_TIMESTAMP)
+        .unwrap();
+
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), STAKED);
+    stake.locked_reward_rate = pool.reward_rate;
+    let matured = pool.maturity_timestamp(stake.stake_timestamp).unwrap();
+    (pool, stake, matured)
+}
+
+#[test]
+fn test_rate_increase_does_not_change_existing_rewards() {
+    let (mut pool, stake, matured) = setup();
+
+    pool.reward_rate = TWENTY_PERCENT;
+
+    // The stake is still paid what it reserved
+    assert_eq!(stake.round_rewards().unwrap(), 100_000);
+    assert_eq!(pool.stake_rewards(&stake, matured).unwrap(), 100_000);
+    assert_eq!(pool.total_rewards_owed, 100_000);
+
+    // New stakes reserve at the new rate
+    assert_eq!(
+        pool.expected_rewards(STAKED, SAMPLE_STAKE_TIMESTAMP)
+            .unwrap(),
+        200_000
+    );
+}
+
+#[test]
+fn test_rate_decrease_does_not_strand_reserved_rewards() {
+    let (mut pool, stake, _) = setup();
+
+    pool.reward_rate = 0;
+
+    // A full unstake releases exactly what the stake reserved
+    let forfeited = pool.unstake_forfeiture(&stake, STAKED).unwrap();
+    assert_eq!(forfeited, pool.total_rewards_owed);
+}
+
+#[test]
+fn test_rewards_are_zero_before_maturity() {
+    let (pool, stake, matured) = setup();
+
+    assert_eq!(pool.stake_rewards(&stake, matured - 1).unwrap(), 0);
+    assert_eq!(pool.stake_rewards(&stake, matured).unwrap(), 100_000);
+}
+
+#[test]
+fn test_relock_locks_the_current_rate() {
+    let (mut pool, mut stake, matured) = setup();
+    stake.auto_relock = true;
+    pool.reward_rate = TWENTY_PERCENT;
+
+    let reserved = pool.relock_stake(&mut stake, matured).unwrap();
+
+    // The completed round is carried at its own rate, the new one reserves at 20%
+    assert_eq!(stake.locked_reward_rate, TWENTY_PERCENT);
+    assert_eq!(stake.carried_rewards, 100_000);
+    assert_eq!(reserved, 200_000);
+    assert_eq!(pool.total_rewards_owed, 300_000);
+
+    let next_maturity = pool.maturity_timestamp(stake.stake_timestamp).unwrap();
+    assert_eq!(pool.stake_rewards(&stake, next_maturity).unwrap(), 300_000);
+}
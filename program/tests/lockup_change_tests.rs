@@ -0,0 +1,147 @@
+// ============================================================================
+// Lockup Change Time-Lock Tests
+// ============================================================================
+// lockup_period and enforce_lockup apply to existing stakes, so UpdatePool only
+// proposes new values. They take effect through FinalizeLockupChange once the
+// pool's rate change delay has passed, giving stakers notice to exit.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use your_wallet_stake_pool::{error::StakePoolError, state::StakePool};
+
+use common::*;
+
+const PROPOSED_AT: i64 = 1_700_000_000;
+const DELAY: i64 = 86_400;
+
+fn error(error: StakePoolError) -> ProgramError {
+    ProgramError::Custom(error as u32)
+}
+
+/// A pool with a pending change to a two-day enforced lockup
+fn pool_with_pending_change() -> StakePool {
+    let mut pool = sample_stake_pool();
+    pool.lockup_period = 86_400;
+    pool.enforce_lockup = false;
+    assert!(pool
+        .propose_lockup_change(Some(172_800), Some(true), PROPOSED_AT)
+        .unwrap());
+    pool
+}
+
+#[test]
+fn test_lockup_change_waits_for_the_delay() {
+    let mut pool = pool_with_pending_change();
+
+    // Existing stakes keep the current settings while the change is pending
+    assert_eq!(pool.lockup_period, 86_400);
+    assert!(!pool.enforce_lockup);
+    assert_eq!(pool.pending_lockup_period, Some(172_800));
+    assert_eq!(pool.pending_enforce_lockup, Some(true));
+
+    assert_eq!(
+        pool.finalize_lockup_change(PROPOSED_AT + DELAY - 1, DELAY)
+            .unwrap_err(),
+        error(StakePoolError::LockupChangeDelayNotElapsed)
+    );
+
+    pool.finalize_lockup_change(PROPOSED_AT + DELAY, DELAY)
+        .unwrap();
+    assert_eq!(pool.lockup_period, 172_800);
+    assert!(pool.enforce_lockup);
+    assert!(!pool.has_pending_lockup_change());
+    assert_eq!(pool.pending_lockup_period, None);
+    assert_eq!(pool.pending_enforce_lockup, None);
+
+    // Nothing left to finalize
+    assert_eq!(
+        pool.finalize_lockup_change(PROPOSED_AT + DELAY, DELAY)
+            .unwrap_err(),
+        error(StakePoolError::NoPendingLockupChange)
+    );
+}
+
+#[test]
+fn test_only_changed_settings_are_proposed() {
+    let mut pool = sample_stake_pool();
+    pool.enforce_lockup = true;
+
+    assert!(pool
+        .propose_lockup_change(Some(pool.lockup_period), Some(false), PROPOSED_AT)
+        .unwrap());
+    assert_eq!(pool.pending_lockup_period, None);
+    assert_eq!(pool.pending_enforce_lockup, Some(false));
+
+    pool.finalize_lockup_change(PROPOSED_AT + DELAY, DELAY)
+        .unwrap();
+    assert!(!pool.enforce_lockup);
+}
+
+#[test]
+fn test_second_proposal_is_rejected_while_pending() {
+    let mut pool = pool_with_pending_change();
+
+    assert_eq!(
+        pool.propose_lockup_change(Some(259_200), None, PROPOSED_AT + 1)
+            .unwrap_err(),
+        error(StakePoolError::PendingLockupChangeExists)
+    );
+    assert_eq!(pool.pending_lockup_period, Some(172_800));
+    assert_eq!(pool.lockup_change_timestamp, Some(PROPOSED_AT));
+}
+
+#[test]
+fn test_proposing_current_settings_cancels_pending_change() {
+    let mut pool = pool_with_pending_change();
+
+    assert!(!pool
+        .propose_lockup_change(Some(86_400), Some(false), PROPOSED_AT + 1)
+        .unwrap());
+    assert!(!pool.has_pending_lockup_change());
+    assert_eq!(pool.pending_lockup_period, None);
+    assert_eq!(pool.pending_enforce_lockup, None);
+
+    // Without a pending change it is a no-op
+    assert!(!pool
+        .propose_lockup_change(Some(86_400), None, PROPOSED_AT + 2)
+        .unwrap());
+    assert_eq!(pool.lockup_period, 86_400);
+}
+
+#[test]
+fn test_negative_lockup_is_rejected() {
+    let mut pool = sample_stake_pool();
+
+    assert_eq!(
+        pool.propose_lockup_change(Some(-1), None, PROPOSED_AT)
+            .unwrap_err(),
+        error(StakePoolError::InvalidParameters)
+    );
+    assert!(!pool.has_pending_lockup_change());
+}
+
+#[test]
+fn test_pools_allocated_before_the_lockup_fields_decode_without_pending_change() {
+    use borsh::BorshDeserialize;
+
+    // A fully populated pool in the 458-byte layout ends with its 2 reserved bytes
+    let mut pool = sample_stake_pool();
+    pool.pool_end_date = Some(1_900_000_000);
+    pool.pending_reward_rate = Some(1);
+    pool.reward_rate_change_timestamp = Some(PROPOSED_AT);
+    pool.last_rate_change = Some(PROPOSED_AT);
+    pool.stake_vault_bump = Some(255);
+    pool.reward_vault_bump = Some(255);
+    pool.fee_vault_bump = Some(255);
+    pool.reward_funders = [Some(pool.stake_mint); StakePool::MAX_REWARD_FUNDERS];
+    pool.receipt_mint = Some(pool.reward_mint);
+    let mut data = borsh::to_vec(&pool).unwrap();
+    data.truncate(458);
+
+    // Loading reads the missing tail as zeros
+    data.resize(StakePool::LEN, 0);
+    let decoded = StakePool::deserialize(&mut data.as_slice()).unwrap();
+    assert!(!decoded.has_pending_lockup_change());
+    assert_eq!(decoded.receipt_mint, pool.receipt_mint);
+}
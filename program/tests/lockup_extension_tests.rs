@@ -0,0 +1,142 @@
+// ============================================================================
+// Lockup Extension Tests
+// ============================================================================
+// Pools with a lockup_extension_rate let owners lengthen the lockup of a round
+// that has not matured yet with ExtendLockup. The stake locks in the extension
+// rate pro rata to the added time on top of its reward rate, so the bonus is
+// reserved in total_rewards_owed and paid with the round once the extended
+// lockup completes.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    constants::{MAX_LOCKUP_EXTENSION, SECONDS_PER_YEAR},
+    error::StakePoolError,
+    processor::PoolUpdate,
+    state::{StakeAccount, StakePool},
+};
+
+use common::*;
+
+const TEN_PERCENT: u64 = 100_000_000;
+
+/// A 10% pool paying 10% more per year of lockup extension, with one staked round
+fn extendable() -> (StakePool, StakeAccount) {
+    let mut pool = sample_stake_pool();
+    pool.reward_rate = TEN_PERCENT;
+    pool.lockup_extension_rate = TEN_PERCENT;
+
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000_000);
+    stake.locked_reward_rate = TEN_PERCENT;
+    pool.total_rewards_owed = stake.round_rewards().unwrap();
+    (pool, stake)
+}
+
+fn invalid() -> Result<u64, ProgramError> {
+    Err(StakePoolError::InvalidParameters.into())
+}
+
+#[test]
+fn test_extension_pushes_back_maturity() {
+    let (mut pool, mut stake) = extendable();
+    let now = stake.stake_timestamp;
+    let matured = pool.maturity_timestamp(now).unwrap();
+
+    pool.extend_lockup(&mut stake, SECONDS_PER_YEAR, now)
+        .unwrap();
+
+    assert_eq!(stake.lockup_extension, SECONDS_PER_YEAR);
+    assert_eq!(
+        pool.stake_maturity(&stake).unwrap(),
+        matured + SECONDS_PER_YEAR
+    );
+    // At the old maturity the round is still locked and has earned nothing
+    assert_eq!(pool.calculate_rewards(&stake, matured).unwrap(), 0);
+    assert!(!pool.check_unstake_lockup(&stake, 0, matured).unwrap());
+}
+
+#[test]
+fn test_extension_reserves_a_pro_rata_bonus() {
+    let (mut pool, mut stake) = extendable();
+    let now = stake.stake_timestamp;
+
+    // Half a year at 10% per year adds 5% on top of the round's 10%
+    let bonus = pool
+        .extend_lockup(&mut stake, SECONDS_PER_YEAR / 2, now)
+        .unwrap();
+
+    assert_eq!(bonus, 50_000);
+    assert_eq!(stake.locked_reward_rate, 150_000_000);
+    assert_eq!(pool.total_rewards_owed, 150_000);
+
+    let matured = pool.stake_maturity(&stake).unwrap();
+    assert_eq!(pool.calculate_rewards(&stake, matured).unwrap(), 150_000);
+}
+
+#[test]
+fn test_extensions_accumulate_up_to_the_maximum() {
+    let (mut pool, mut stake) = extendable();
+    let now = stake.stake_timestamp;
+
+    pool.extend_lockup(&mut stake, MAX_LOCKUP_EXTENSION - 1, now)
+        .unwrap();
+    assert_eq!(pool.extend_lockup(&mut stake, 2, now), invalid());
+    pool.extend_lockup(&mut stake, 1, now).unwrap();
+
+    assert_eq!(stake.lockup_extension, MAX_LOCKUP_EXTENSION);
+}
+
+#[test]
+fn test_matured_rounds_cannot_be_extended() {
+    let (mut pool, mut stake) = extendable();
+    let matured = pool.maturity_timestamp(stake.stake_timestamp).unwrap();
+
+    assert_eq!(pool.extend_lockup(&mut stake, 86_400, matured), invalid());
+    assert_eq!(stake.lockup_extension, 0);
+}
+
+#[test]
+fn test_extension_must_add_time() {
+    let (mut pool, mut stake) = extendable();
+    let now = stake.stake_timestamp;
+
+    assert_eq!(pool.extend_lockup(&mut stake, 0, now), invalid());
+    assert_eq!(pool.extend_lockup(&mut stake, -1, now), invalid());
+}
+
+#[test]
+fn test_pools_without_an_extension_rate_refuse_extensions() {
+    let (mut pool, mut stake) = extendable();
+    pool.lockup_extension_rate = 0;
+    let now = stake.stake_timestamp;
+
+    assert_eq!(
+        pool.extend_lockup(&mut stake, 86_400, now),
+        Err(StakePoolError::LockupExtensionDisabled.into())
+    );
+}
+
+#[test]
+fn test_relock_starts_an_unextended_round() {
+    let (mut pool, mut stake) = extendable();
+    stake.auto_relock = true;
+    let now = stake.stake_timestamp;
+    pool.extend_lockup(&mut stake, 86_400, now).unwrap();
+
+    let matured = pool.stake_maturity(&stake).unwrap();
+    pool.relock_stake(&mut stake, matured).unwrap();
+
+    assert_eq!(stake.lockup_extension, 0);
+    assert_eq!(stake.locked_reward_rate, TEN_PERCENT);
+}
+
+#[test]
+fn test_lockup_extension_rate_updates_are_admin_only() {
+    let update = PoolUpdate {
+        lockup_extension_rate: Some(TEN_PERCENT),
+        ..Default::default()
+    };
+    assert_eq!(update.required_roles(), None);
+}
@@ -0,0 +1,72 @@
+// ============================================================================
+// Memo Tests
+// ============================================================================
+// Stake, Unstake and ClaimRewards take an optional memo they attach to the
+// transaction via the SPL Memo program, signed by the owner. Memos are bounded
+// by MAX_MEMO_LEN and need the memo program account; without a memo nothing is
+// invoked.
+
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    constants::MAX_MEMO_LEN, error::StakePoolError, processor::helpers::write_memo, spl_memo,
+};
+
+/// Run `write_memo` signed by a fresh owner, passing a program account at `memo_program`
+fn write(memo: Option<&str>, memo_program: Option<Pubkey>) -> Result<(), ProgramError> {
+    let owner_key = Pubkey::new_unique();
+    let (mut owner_lamports, mut program_lamports) = (0, 0);
+    let (mut owner_data, mut program_data) = (Vec::new(), Vec::new());
+    let system_program = Pubkey::default();
+    let owner = AccountInfo::new(
+        &owner_key,
+        true,
+        false,
+        &mut owner_lamports,
+        &mut owner_data,
+        &system_program,
+        false,
+        0,
+    );
+    let program_key = memo_program.unwrap_or_default();
+    let program = AccountInfo::new(
+        &program_key,
+        false,
+        false,
+        &mut program_lamports,
+        &mut program_data,
+        &system_program,
+        true,
+        0,
+    );
+    write_memo(memo, memo_program.map(|_| &program), &owner)
+}
+
+fn invalid_memo() -> Result<(), ProgramError> {
+    Err(StakePoolError::InvalidMemo.into())
+}
+
+#[test]
+fn test_no_memo_needs_no_memo_program() {
+    assert_eq!(write(None, None), Ok(()));
+}
+
+#[test]
+fn test_memo_length_is_bounded() {
+    let too_long = "a".repeat(MAX_MEMO_LEN + 1);
+
+    assert_eq!(write(Some(""), Some(spl_memo::ID)), invalid_memo());
+    assert_eq!(write(Some(&too_long), Some(spl_memo::ID)), invalid_memo());
+}
+
+#[test]
+fn test_memo_needs_the_memo_program() {
+    assert_eq!(
+        write(Some("audit trail"), None),
+        Err(ProgramError::NotEnoughAccountKeys)
+    );
+    assert_eq!(
+        write(Some("audit trail"), Some(Pubkey::new_unique())),
+        Err(StakePoolError::AccountMismatch.into())
+    );
+}
@@ -0,0 +1,206 @@
+// ============================================================================
+// Merge Stakes Tests
+// ============================================================================
+// MergeStakes folds a source stake account into another of the same owner and
+// pool. The merged stake keeps the later stake timestamp, adds up principal and
+// rewards, and reserves what the two stakes did in total_rewards_owed.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    error::StakePoolError,
+    state::{StakeAccount, StakePool},
+};
+
+use common::*;
+
+// sample_stake_pool locks stakes for a day and pays 10% after
+const LOCKUP: i64 = 86400;
+
+/// A pool holding two stakes of one owner, the second made an hour after the first
+fn setup() -> (StakePool, StakeAccount, StakeAccount) {
+    let mut pool = sample_stake_pool();
+    let (pool_key, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let stake = sample_stake_account(&pool_key, &owner, 1_000_000);
+    let mut source = sample_stake_account(&pool_key, &owner, 500_000);
+    source.index = 1;
+    source.stake_timestamp += 3600;
+    pool.total_staked = 1_500_000;
+    pool.total_rewards_owed = 150_000;
+    pool.active_stake_count = Some(2);
+    (pool, stake, source)
+}
+
+fn assert_error(err: ProgramError, expected: StakePoolError) {
+    assert_eq!(err, ProgramError::Custom(expected as u32));
+}
+
+#[test]
+fn test_merge_adds_up_principal_and_keeps_the_later_timestamp() {
+    let (mut pool, mut stake, mut source) = setup();
+    source.carried_rewards = 7_000;
+    source.claimed_rewards = 2_000;
+    source.early_unstaked = 1_000;
+    pool.total_rewards_owed += 5_000;
+
+    pool.merge_stakes(&mut stake, &source, SAMPLE_STAKE_TIMESTAMP + 60)
+        .unwrap();
+
+    assert_eq!(stake.index, 0);
+    assert_eq!(stake.amount_staked, 1_500_000);
+    assert_eq!(stake.stake_timestamp, source.stake_timestamp);
+    assert_eq!(stake.carried_rewards, 7_000);
+    assert_eq!(stake.claimed_rewards, 2_000);
+    assert_eq!(stake.early_unstaked, 1_000);
+    assert_eq!(stake.unclaimed_rewards().unwrap(), 155_000);
+
+    assert_eq!(pool.total_staked, 1_500_000);
+    assert_eq!(pool.total_rewards_owed, 155_000);
+    assert_eq!(pool.active_stake_count, Some(1));
+}
+
+#[test]
+fn test_merge_keeps_the_later_timestamp_either_way() {
+    let (mut pool, mut source, mut stake) = setup();
+    source.index = 2;
+
+    pool.merge_stakes(&mut stake, &source, SAMPLE_STAKE_TIMESTAMP + 3600)
+        .unwrap();
+
+    assert_eq!(stake.index, 1);
+    assert_eq!(stake.stake_timestamp, SAMPLE_STAKE_TIMESTAMP + 3600);
+    assert_eq!(stake.amount_staked, 1_500_000);
+}
+
+#[test]
+fn test_merge_keeps_points_from_the_later_checkpoint() {
+    // Neither stake synced: points accrue from the later stake timestamp
+    let (mut pool, mut stake, source) = setup();
+    pool.merge_stakes(&mut stake, &source, SAMPLE_STAKE_TIMESTAMP + 7200)
+        .unwrap();
+    assert_eq!(stake.points_synced_at, source.stake_timestamp);
+
+    let (mut pool, mut stake, source) = setup();
+    stake.points_synced_at = SAMPLE_STAKE_TIMESTAMP + 7200;
+    pool.merge_stakes(&mut stake, &source, SAMPLE_STAKE_TIMESTAMP + 7200)
+        .unwrap();
+    assert_eq!(stake.points_synced_at, SAMPLE_STAKE_TIMESTAMP + 7200);
+}
+
+#[test]
+fn test_merge_conserves_reserved_rewards_up_to_rounding() {
+    let (mut pool, mut stake, mut source) = setup();
+    stake.amount_staked = 15;
+    source.amount_staked = 15;
+    let reserved = stake.unclaimed_rewards().unwrap() + source.unclaimed_rewards().unwrap();
+    pool.total_rewards_owed = reserved;
+
+    pool.merge_stakes(&mut stake, &source, SAMPLE_STAKE_TIMESTAMP + 3600)
+        .unwrap();
+
+    // 10% of 15 rounds down to 1 twice, while 10% of 30 is 3
+    assert_eq!(reserved, 2);
+    assert_eq!(pool.total_rewards_owed, stake.unclaimed_rewards().unwrap());
+    assert_eq!(pool.total_rewards_owed, 3);
+}
+
+#[test]
+fn test_merging_a_fully_unstaked_account_keeps_the_active_stake_count() {
+    let (mut pool, mut stake, mut source) = setup();
+    // A full unstake resets the source, which then no longer counts as active
+    source.amount_staked = 0;
+    source.stake_timestamp = 0;
+    pool.total_staked = 1_000_000;
+    pool.total_rewards_owed = 100_000;
+    pool.active_stake_count = Some(1);
+
+    pool.merge_stakes(&mut stake, &source, SAMPLE_STAKE_TIMESTAMP + LOCKUP)
+        .unwrap();
+
+    assert_eq!(stake.amount_staked, 1_000_000);
+    assert_eq!(pool.total_rewards_owed, 100_000);
+    assert_eq!(pool.active_stake_count, Some(1));
+}
+
+#[test]
+fn test_merge_rejects_the_same_index_or_another_owner() {
+    let (mut pool, mut stake, mut source) = setup();
+
+    let mut same = source.clone();
+    same.index = stake.index;
+    let err = pool
+        .merge_stakes(&mut stake, &same, SAMPLE_STAKE_TIMESTAMP + 3600)
+        .unwrap_err();
+    assert_error(err, StakePoolError::StakesNotMergeable);
+
+    source.owner = Pubkey::new_unique();
+    let err = pool
+        .merge_stakes(&mut stake, &source, SAMPLE_STAKE_TIMESTAMP + 3600)
+        .unwrap_err();
+    assert_error(err, StakePoolError::StakesNotMergeable);
+    assert_eq!(stake.amount_staked, 1_000_000);
+    assert_eq!(pool.active_stake_count, Some(2));
+}
+
+#[test]
+fn test_merge_requires_matching_terms() {
+    let (mut pool, mut stake, mut source) = setup();
+    source.locked_reward_rate = 50_000_000;
+    let err = pool
+        .merge_stakes(&mut stake, &source, SAMPLE_STAKE_TIMESTAMP + 3600)
+        .unwrap_err();
+    assert_error(err, StakePoolError::StakesNotMergeable);
+
+    let (mut pool, mut stake, mut source) = setup();
+    source.lockup_extension = 3600;
+    let err = pool
+        .merge_stakes(&mut stake, &source, SAMPLE_STAKE_TIMESTAMP + 3600)
+        .unwrap_err();
+    assert_error(err, StakePoolError::StakesNotMergeable);
+}
+
+#[test]
+fn test_merge_rejects_a_source_with_a_withdrawal_or_vesting_grant() {
+    let (mut pool, mut stake, mut source) = setup();
+    source.pending_withdraw_amount = 1_000;
+    let err = pool
+        .merge_stakes(&mut stake, &source, SAMPLE_STAKE_TIMESTAMP + 3600)
+        .unwrap_err();
+    assert_error(err, StakePoolError::StakesNotMergeable);
+
+    let (mut pool, mut stake, mut source) = setup();
+    source.vesting_amount = 1_000;
+    let err = pool
+        .merge_stakes(&mut stake, &source, SAMPLE_STAKE_TIMESTAMP + 3600)
+        .unwrap_err();
+    assert_error(err, StakePoolError::StakesNotMergeable);
+}
+
+#[test]
+fn test_merge_rejects_a_matured_stake_joining_a_locked_one() {
+    let (mut pool, mut stake, source) = setup();
+
+    // Only the first stake has matured
+    let err = pool
+        .merge_stakes(&mut stake, &source, SAMPLE_STAKE_TIMESTAMP + LOCKUP)
+        .unwrap_err();
+    assert_error(err, StakePoolError::StakesNotMergeable);
+
+    // Both have
+    pool.merge_stakes(&mut stake, &source, SAMPLE_STAKE_TIMESTAMP + LOCKUP + 3600)
+        .unwrap();
+    assert_eq!(stake.amount_staked, 1_500_000);
+}
+
+#[test]
+fn test_merge_is_blocked_by_a_pending_slash() {
+    let (mut pool, mut stake, mut source) = setup();
+    source.pending_slash_bps = 500;
+
+    let err = pool
+        .merge_stakes(&mut stake, &source, SAMPLE_STAKE_TIMESTAMP + 3600)
+        .unwrap_err();
+    assert_error(err, StakePoolError::StakeSlashPending);
+}
@@ -0,0 +1,132 @@
+// ============================================================================
+// Merkle Allowlist Tests
+// ============================================================================
+// Pools too large for allowlist pages store the root of a merkle tree of
+// allowed owners in allowlist_merkle_root, set with UpdatePool. Stake,
+// StakeSol and StakeBatch then accept an allowlist_proof of the owner's leaf,
+// verified on-chain with SHA-256, in place of an allowlist page.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    constants::MAX_MERKLE_PROOF_LEN,
+    error::StakePoolError,
+    merkle::{leaf_hash, node_hash, verify_proof},
+    processor::{helpers::check_pool_allowlist, PoolUpdate},
+    state::StakePool,
+};
+
+use common::*;
+
+/// Owners of a three leaf tree and its root: ((a, b), c)
+fn three_owner_tree() -> ([Pubkey; 3], [u8; 32]) {
+    let owners = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let [a, b, c] = owners.map(|owner| leaf_hash(&owner));
+    (owners, node_hash(&node_hash(&a, &b), &c))
+}
+
+fn merkle_pool(root: [u8; 32]) -> StakePool {
+    let mut pool = sample_stake_pool();
+    pool.allowlist_merkle_root = Some(root);
+    pool
+}
+
+fn check(pool: &StakePool, proof: Option<&[[u8; 32]]>, owner: &Pubkey) -> Result<(), ProgramError> {
+    check_pool_allowlist(pool, &Pubkey::new_unique(), None, proof, owner)
+}
+
+fn not_allowlisted() -> Result<(), ProgramError> {
+    Err(StakePoolError::NotAllowlisted.into())
+}
+
+#[test]
+fn test_node_hash_ignores_order() {
+    let (a, b) = ([1; 32], [2; 32]);
+    assert_eq!(node_hash(&a, &b), node_hash(&b, &a));
+}
+
+#[test]
+fn test_proofs_of_every_leaf_verify() {
+    let (owners, root) = three_owner_tree();
+    let [a, b, c] = owners.map(|owner| leaf_hash(&owner));
+
+    assert!(verify_proof(&root, &owners[0], &[b, c]));
+    assert!(verify_proof(&root, &owners[1], &[a, c]));
+    assert!(verify_proof(&root, &owners[2], &[node_hash(&a, &b)]));
+}
+
+#[test]
+fn test_proofs_of_other_owners_fail() {
+    let (owners, root) = three_owner_tree();
+    let [_, b, c] = owners.map(|owner| leaf_hash(&owner));
+
+    assert!(!verify_proof(&root, &Pubkey::new_unique(), &[b, c]));
+    assert!(!verify_proof(&root, &owners[0], &[c, b]));
+    assert!(!verify_proof(&root, &owners[0], &[b]));
+}
+
+#[test]
+fn test_inner_nodes_are_not_leaves() {
+    let (owners, root) = three_owner_tree();
+    let [a, b, c] = owners.map(|owner| leaf_hash(&owner));
+
+    // The (a, b) node hashed as a leaf never reproduces the root
+    let inner = Pubkey::new_from_array(node_hash(&a, &b));
+    assert!(!verify_proof(&root, &inner, &[c]));
+}
+
+#[test]
+fn test_proven_owners_may_stake() {
+    let (owners, root) = three_owner_tree();
+    let [a, _, c] = owners.map(|owner| leaf_hash(&owner));
+    let pool = merkle_pool(root);
+
+    assert!(check(&pool, Some(&[a, c]), &owners[1]).is_ok());
+    assert_eq!(
+        check(&pool, Some(&[a, c]), &Pubkey::new_unique()),
+        not_allowlisted()
+    );
+}
+
+#[test]
+fn test_merkle_root_requires_allowlisting() {
+    let (owners, root) = three_owner_tree();
+    let pool = merkle_pool(root);
+
+    assert!(!pool.allowlist_required);
+    assert!(pool.requires_allowlist());
+    assert_eq!(check(&pool, None, &owners[0]), not_allowlisted());
+}
+
+#[test]
+fn test_proofs_are_ignored_without_a_root() {
+    let pool = sample_stake_pool();
+    assert!(check(&pool, Some(&[[0; 32]]), &Pubkey::new_unique()).is_ok());
+}
+
+#[test]
+fn test_overlong_proofs_are_rejected() {
+    let (owners, root) = three_owner_tree();
+    let pool = merkle_pool(root);
+    let proof = vec![[0; 32]; MAX_MERKLE_PROOF_LEN + 1];
+
+    assert_eq!(
+        check(&pool, Some(&proof), &owners[0]),
+        Err(StakePoolError::InvalidParameters.into())
+    );
+}
+
+#[test]
+fn test_merkle_root_updates_are_admin_only() {
+    let update = PoolUpdate {
+        allowlist_merkle_root: Some(Some([7; 32])),
+        ..Default::default()
+    };
+    assert_eq!(update.required_roles(), None);
+}
@@ -0,0 +1,284 @@
+// ============================================================================
+// Account Migration Tests
+// ============================================================================
+// Pools and stake accounts written in the first deployed layout keep their
+// original discriminators (StakePoolV1 / StakeAccountV1). They are readable
+// and upgraded in memory by load(), rejected by every other instruction with
+// AccountNeedsMigration, and rewritten in the current layout by MigratePool.
+// V2 accounts written before the 8-byte discriminator are handled the same way.
+// ResizePool grows current pools ahead of a larger layout instead.
+
+use solana_program::{account_info::AccountInfo, hash::hash, program_error::ProgramError};
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    assertions::assert_account_key,
+    constants::MAX_POOL_ACCOUNT_LEN,
+    error::StakePoolError,
+    state::{
+        Key, ProgramAuthority, StakeAccount, StakeAccountV1, StakePool, StakePoolV1,
+        DISCRIMINATOR_LEN,
+    },
+};
+
+fn v1_pool() -> StakePoolV1 {
+    StakePoolV1 {
+        key: Key::StakePoolV1,
+        stake_mint: Pubkey::new_unique(),
+        reward_mint: Pubkey::new_unique(),
+        pool_id: 7,
+        stake_vault: Pubkey::new_unique(),
+        reward_vault: Pubkey::new_unique(),
+        total_staked: 5_000_000,
+        total_rewards_owed: 500_000,
+        reward_rate: 100_000_000,
+        min_stake_amount: 1_000,
+        lockup_period: 86400,
+        is_paused: false,
+        enforce_lockup: true,
+        bump: 254,
+        pool_end_date: Some(1_900_000_000),
+        pending_reward_rate: Some(200_000_000),
+        reward_rate_change_timestamp: Some(1_700_000_000),
+        last_rate_change: Some(1_690_000_000),
+        _reserved: [0; 7],
+    }
+}
+
+fn v1_stake_account(pool: &Pubkey) -> StakeAccountV1 {
+    StakeAccountV1 {
+        key: Key::StakeAccountV1,
+        pool: *pool,
+        owner: Pubkey::new_unique(),
+        index: 2,
+        amount_staked: 1_000_000,
+        stake_timestamp: 1_700_000_000,
+        claimed_rewards: 25_000,
+        bump: 253,
+    }
+}
+
+/// Account data as written by the V1 program: serialized and padded to the V1 size
+fn v1_data<T: borsh::BorshSerialize>(value: &T, len: usize) -> Vec<u8> {
+    let mut data = borsh::to_vec(value).unwrap();
+    assert!(data.len() <= len);
+    data.resize(len, 0);
+    data
+}
+
+#[test]
+fn test_discriminators_are_stable() {
+    // V1 accounts keep the bytes they were written with
+    assert_eq!(Key::StakePoolV1 as u8, 1);
+    assert_eq!(Key::StakeAccountV1 as u8, 2);
+    assert_eq!(Key::ProgramAuthorityV1 as u8, 3);
+    assert_eq!(Key::MintRegistry as u8, 4);
+    assert_eq!(Key::StakePoolV2 as u8, 5);
+    assert_eq!(Key::StakeAccountV2 as u8, 6);
+}
+
+#[test]
+fn test_v1_lens_fit_fully_populated_accounts() {
+    let pool = v1_pool();
+    assert_eq!(borsh::to_vec(&pool).unwrap().len(), StakePoolV1::LEN);
+
+    let stake = v1_stake_account(&Pubkey::new_unique());
+    assert_eq!(borsh::to_vec(&stake).unwrap().len(), StakeAccountV1::LEN);
+
+    // Migration only ever grows accounts
+    const {
+        assert!(StakePool::LEN > StakePoolV1::LEN);
+        assert!(StakeAccount::LEN > StakeAccountV1::LEN);
+    }
+}
+
+#[test]
+fn test_pool_upgrade_keeps_v1_fields() {
+    let v1 = v1_pool();
+    let pool = StakePool::from(v1.clone());
+
+    assert!(matches!(pool.key, Key::StakePoolV2));
+    assert_eq!(pool.stake_mint, v1.stake_mint);
+    assert_eq!(pool.reward_vault, v1.reward_vault);
+    assert_eq!(pool.pool_id, v1.pool_id);
+    assert_eq!(pool.total_staked, v1.total_staked);
+    assert_eq!(pool.total_rewards_owed, v1.total_rewards_owed);
+    assert_eq!(pool.pool_end_date, v1.pool_end_date);
+    assert_eq!(pool.pending_reward_rate, v1.pending_reward_rate);
+    assert_eq!(pool.last_rate_change, v1.last_rate_change);
+
+    // Features added since V1 start disabled
+    assert_eq!(pool.referral_bps, 0);
+    assert_eq!(pool.deposit_fee_bps, 0);
+    assert_eq!(pool.withdraw_fee_bps, 0);
+    assert_eq!(pool.unstake_cooldown, 0);
+    assert_eq!(pool.receipt_mint, None);
+    assert!(pool.reward_funders.iter().all(Option::is_none));
+
+    // The upgraded pool fits the current account size
+    assert!(borsh::to_vec(&pool).unwrap().len() <= StakePool::LEN);
+}
+
+#[test]
+fn test_stake_account_load_upgrades_v1_layout() {
+    let pool = Pubkey::new_unique();
+    let v1 = v1_stake_account(&pool);
+
+    let key = Pubkey::new_unique();
+    let mut lamports = 0;
+    let mut data = v1_data(&v1, StakeAccountV1::LEN);
+    let program_id = your_wallet_stake_pool::ID;
+    let account = AccountInfo::new(
+        &key,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &program_id,
+        false,
+        0,
+    );
+
+    assert!(StakeAccount::is_legacy_layout(&account));
+    let stake = StakeAccount::load(&account).unwrap();
+    assert!(matches!(stake.key, Key::StakeAccountV2));
+    assert_eq!(stake.pool, pool);
+    assert_eq!(stake.owner, v1.owner);
+    assert_eq!(stake.amount_staked, v1.amount_staked);
+    assert_eq!(stake.claimed_rewards, v1.claimed_rewards);
+    assert_eq!(stake.referrer, None);
+    assert_eq!(stake.pending_withdraw_amount, 0);
+
+    // Saving needs the account grown to the current size first
+    assert_eq!(
+        stake.save(&account).unwrap_err(),
+        ProgramError::Custom(StakePoolError::AccountSizeTooSmall as u32)
+    );
+}
+
+#[test]
+fn test_unmigrated_accounts_are_rejected_with_migration_error() {
+    let key = Pubkey::new_unique();
+    let mut lamports = 0;
+    let mut data = v1_data(&v1_pool(), StakePoolV1::LEN);
+    let program_id = your_wallet_stake_pool::ID;
+    let account = AccountInfo::new(
+        &key,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &program_id,
+        false,
+        0,
+    );
+
+    assert!(StakePool::is_legacy_layout(&account));
+    assert_eq!(
+        assert_account_key("pool", &account, Key::StakePoolV2).unwrap_err(),
+        ProgramError::Custom(StakePoolError::AccountNeedsMigration as u32)
+    );
+
+    // Other account types still fail the plain discriminator check
+    assert_eq!(
+        assert_account_key("stake_account", &account, Key::StakeAccountV2).unwrap_err(),
+        ProgramError::Custom(StakePoolError::InvalidAccountKey as u32)
+    );
+}
+
+#[test]
+fn test_account_discriminators_hash_the_account_names() {
+    for (name, discriminator) in [
+        ("StakePool", StakePool::DISCRIMINATOR),
+        ("StakeAccount", StakeAccount::DISCRIMINATOR),
+        ("ProgramAuthority", ProgramAuthority::DISCRIMINATOR),
+    ] {
+        let hash = hash(format!("account:{}", name).as_bytes());
+        assert_eq!(discriminator, hash.to_bytes()[..DISCRIMINATOR_LEN]);
+
+        // Accounts written before the discriminator are told apart by their first byte
+        assert!(discriminator[0] > Key::RewardStream as u8);
+    }
+}
+
+#[test]
+fn test_accounts_without_discriminator_need_migration() {
+    let stake = StakeAccount::from(v1_stake_account(&Pubkey::new_unique()));
+
+    let key = Pubkey::new_unique();
+    let mut lamports = 0;
+    let mut data = v1_data(&stake, StakeAccount::LEN - DISCRIMINATOR_LEN);
+    let program_id = your_wallet_stake_pool::ID;
+    let account = AccountInfo::new(
+        &key,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &program_id,
+        false,
+        0,
+    );
+
+    assert!(StakeAccount::lacks_discriminator(&account));
+    assert!(!StakeAccount::is_legacy_layout(&account));
+    assert_eq!(
+        assert_account_key("stake_account", &account, Key::StakeAccountV2).unwrap_err(),
+        ProgramError::Custom(StakePoolError::AccountNeedsMigration as u32)
+    );
+
+    // Still readable, for MigratePool to rewrite it
+    let loaded = StakeAccount::load(&account).unwrap();
+    assert_eq!(loaded.owner, stake.owner);
+    assert_eq!(loaded.amount_staked, stake.amount_staked);
+}
+
+#[test]
+fn test_saved_accounts_start_with_the_discriminator() {
+    let stake = StakeAccount::from(v1_stake_account(&Pubkey::new_unique()));
+
+    let key = Pubkey::new_unique();
+    let mut lamports = 0;
+    let mut data = vec![0; StakeAccount::LEN];
+    let program_id = your_wallet_stake_pool::ID;
+    let account = AccountInfo::new(
+        &key,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &program_id,
+        false,
+        0,
+    );
+
+    stake.save(&account).unwrap();
+    assert!(account
+        .data
+        .borrow()
+        .starts_with(&StakeAccount::DISCRIMINATOR));
+    assert!(!StakeAccount::lacks_discriminator(&account));
+    assert_account_key("stake_account", &account, Key::StakeAccountV2).unwrap();
+    assert_eq!(StakeAccount::load(&account).unwrap().owner, stake.owner);
+
+    // Another type's discriminator is not accepted
+    assert_eq!(
+        assert_account_key("pool", &account, Key::StakePoolV2).unwrap_err(),
+        ProgramError::Custom(StakePoolError::InvalidAccountKey as u32)
+    );
+}
+
+#[test]
+fn test_resized_pools_load_unchanged() {
+    const { assert!(StakePool::LEN < MAX_POOL_ACCOUNT_LEN) };
+
+    let pool = StakePool::from(v1_pool());
+    let mut data = pool.to_account_data().unwrap();
+    data.resize(MAX_POOL_ACCOUNT_LEN, 0);
+
+    // ResizePool only appends zeroed space, which loads ignore
+    let loaded = StakePool::from_account_data(&data).unwrap();
+    assert_eq!(
+        loaded.to_account_data().unwrap(),
+        pool.to_account_data().unwrap()
+    );
+}
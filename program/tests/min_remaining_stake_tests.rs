@@ -0,0 +1,94 @@
+// ============================================================================
+// Minimum Remaining Stake Tests
+// ============================================================================
+// With enforce_min_remaining_stake, a partial unstake (or unstake request) must
+// leave at least the pool's min_stake_amount staked, so positions cannot be
+// whittled down to dust that still counts in the pool's reward accounting.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    error::StakePoolError,
+    processor::PoolUpdate,
+    state::{StakeAccount, StakePool},
+};
+
+use common::*;
+
+const STAKED: u64 = 1_000_000;
+const MIN_STAKE: u64 = 100_000;
+
+fn setup(enforce: bool) -> (StakePool, StakeAccount, i64) {
+    let mut pool = sample_stake_pool();
+    pool.min_stake_amount = MIN_STAKE;
+    pool.enforce_min_remaining_stake = enforce;
+    pool.total_staked = STAKED;
+    pool.total_rewards_owed = STAKED / 10;
+    let stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), STAKED);
+    let matured = pool.maturity_timestamp(stake.stake_timestamp).unwrap();
+    (pool, stake, matured)
+}
+
+#[test]
+fn test_partial_unstake_leaving_dust_is_rejected() {
+    let (pool, stake, matured) = setup(true);
+
+    let err = pool
+        .check_unstake_lockup(&stake, STAKED - MIN_STAKE + 1, matured)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ProgramError::Custom(StakePoolError::RemainingStakeBelowMinimum as u32)
+    );
+}
+
+#[test]
+fn test_unstake_leaving_the_minimum_or_nothing_succeeds() {
+    let (pool, stake, matured) = setup(true);
+
+    assert!(pool
+        .check_unstake_lockup(&stake, STAKED - MIN_STAKE, matured)
+        .unwrap());
+    assert!(pool.check_unstake_lockup(&stake, STAKED, matured).unwrap());
+}
+
+#[test]
+fn test_dust_is_allowed_while_the_flag_is_off() {
+    let (pool, stake, matured) = setup(false);
+
+    assert!(pool
+        .check_unstake_lockup(&stake, STAKED - 1, matured)
+        .unwrap());
+}
+
+#[test]
+fn test_unstake_request_cannot_leave_dust() {
+    let (mut pool, mut stake, matured) = setup(true);
+
+    let err = pool
+        .request_unstake(&mut stake, STAKED - 1, matured)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ProgramError::Custom(StakePoolError::RemainingStakeBelowMinimum as u32)
+    );
+    assert_eq!(stake.amount_staked, STAKED);
+    assert_eq!(stake.pending_withdraw_amount, 0);
+
+    pool.request_unstake(&mut stake, STAKED - MIN_STAKE, matured)
+        .unwrap();
+    assert_eq!(stake.amount_staked, MIN_STAKE);
+}
+
+#[test]
+fn test_toggling_the_flag_is_admin_only() {
+    let update = PoolUpdate {
+        enforce_min_remaining_stake: Some(true),
+        ..PoolUpdate::default()
+    };
+
+    assert_eq!(update.required_roles(), None);
+    assert!(!update.is_sensitive());
+}
@@ -0,0 +1,86 @@
+// ============================================================================
+// Mint TVL Cap Tests
+// ============================================================================
+// Every pool of a stake mint shares one MintRegistry that tracks the total
+// staked across those pools. Stakes that would take the total past the
+// registry's TVL cap are rejected; unstakes always go through.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    error::StakePoolError,
+    state::{Key, MintRegistry},
+};
+
+use common::*;
+
+fn registry(total_staked: u64, tvl_cap: Option<u64>) -> MintRegistry {
+    MintRegistry {
+        key: Key::MintRegistry,
+        stake_mint: Pubkey::new_unique(),
+        total_staked,
+        tvl_cap,
+        bump: 255,
+    }
+}
+
+#[test]
+fn test_uncapped_registry_accepts_any_stake() {
+    let registry = registry(u64::MAX - 1, None);
+
+    assert!(registry.check_capacity(1).is_ok());
+}
+
+#[test]
+fn test_cap_allows_stake_up_to_limit() {
+    let registry = registry(600_000, Some(1_000_000));
+
+    assert!(registry.check_capacity(400_000).is_ok());
+
+    let err = registry.check_capacity(400_001).unwrap_err();
+    assert_eq!(
+        err,
+        ProgramError::Custom(StakePoolError::TvlCapExceeded as u32)
+    );
+}
+
+#[test]
+fn test_cap_below_current_total_blocks_only_new_stakes() {
+    let mut registry = registry(1_000_000, Some(500_000));
+
+    assert!(registry.check_capacity(1).is_err());
+
+    // Existing positions can still leave
+    registry.record_unstake(700_000);
+    assert_eq!(registry.total_staked, 300_000);
+    assert!(registry.check_capacity(200_000).is_ok());
+}
+
+#[test]
+fn test_record_stake_and_unstake_track_total() {
+    let mut registry = registry(0, None);
+
+    registry.record_stake(250_000).unwrap();
+    registry.record_stake(100_000).unwrap();
+    registry.record_unstake(50_000);
+    assert_eq!(registry.total_staked, 300_000);
+
+    // Stakes from pools that predate the registry are not tracked, so the
+    // total saturates instead of failing their withdrawal
+    registry.record_unstake(1_000_000);
+    assert_eq!(registry.total_staked, 0);
+}
+
+#[test]
+fn test_mint_registry_pda_and_len() {
+    let stake_mint = Pubkey::new_unique();
+    let (pda, bump) = MintRegistry::find_pda(&stake_mint);
+    let (expected, expected_bump) = get_mint_registry_pda(&stake_mint);
+
+    assert_pda_consistency(&pda, &expected, bump, expected_bump);
+
+    let data = borsh::to_vec(&registry(u64::MAX, Some(u64::MAX))).unwrap();
+    assert_eq!(data.len(), MintRegistry::LEN);
+}
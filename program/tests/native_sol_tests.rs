@@ -0,0 +1,39 @@
+// ============================================================================
+// Native SOL Pool Tests
+// ============================================================================
+// Pools whose stake mint is wrapped SOL accept lamports through StakeSol and
+// pay lamports back through UnstakeSol.
+
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    constants::NATIVE_MINT, processor::helpers::is_native_mint, state::StakeAccount,
+};
+
+#[test]
+fn test_native_mint_detection() {
+    assert!(is_native_mint(&NATIVE_MINT));
+    assert!(is_native_mint(&spl_token_2022::native_mint::ID));
+    assert!(!is_native_mint(&Pubkey::new_unique()));
+}
+
+#[test]
+fn test_native_mint_matches_spl_token() {
+    assert_eq!(
+        NATIVE_MINT.to_string(),
+        "So11111111111111111111111111111111111111112"
+    );
+}
+
+#[test]
+fn test_unwrap_pda_is_unique_per_stake_account() {
+    let pool = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let (stake_0, _) = StakeAccount::find_pda(&pool, &owner, 0);
+    let (stake_1, _) = StakeAccount::find_pda(&pool, &owner, 1);
+
+    let (unwrap_0, _) = StakeAccount::find_unwrap_pda(&stake_0);
+    let (unwrap_1, _) = StakeAccount::find_unwrap_pda(&stake_1);
+
+    assert_ne!(unwrap_0, unwrap_1);
+    assert_ne!(unwrap_0, stake_0);
+}
@@ -0,0 +1,566 @@
+// ============================================================================
+// Ordering Guarantee Tests
+// ============================================================================
+// Indexers and auditors rely on the order in which instructions emit events
+// and mutate state. These tests run fixed instruction sequences through the
+// processor natively (syscall stubs decode the `StakePoolEvent` logs and serve
+// the clock) and pin the guarantees documented in docs/ARCHITECTURE.md:
+//
+// 1. A failing instruction leaves every account byte untouched. Instructions
+//    emit their event after saving, so most emit nothing on failure;
+//    ManageAuthorizedCreators logs each creator as it goes, so indexers must
+//    skip failed transactions
+// 2. ManageAuthorizedCreators applies all removals, then all additions, each
+//    in argument order, with one event per creator in that same order
+// 3. Removing a creator keeps the list in order: survivors keep their
+//    relative order and new creators are appended after them
+// 4. Replaying the same sequence from the same state yields identical events
+//    and identical account data
+// 5. Stake, unstake, claim and pool update events carry the pool's event
+//    nonce, which increases by one per event and is saved with the pool
+
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, program_stubs::SyscallStubs,
+};
+use solana_sdk::pubkey::Pubkey;
+use std::sync::{
+    atomic::{AtomicI64, Ordering},
+    Mutex, Once,
+};
+use your_wallet_stake_pool::{
+    constants::REWARD_EXPIRY_PERIOD,
+    events::{
+        AuthorizedCreatorEvent, AutoRelockUpdatedEvent, PoolParameter, PoolUpdatedEvent,
+        RewardsExpiredEvent, StakeDelegateUpdatedEvent, StakePoolEvent,
+    },
+    instruction::StakePoolInstruction,
+    processor::process_instruction,
+    state::{
+        AuthorizedCreator, ClusterProfile, Key, ProgramAuthority, ProgramConfig, StakeAccount,
+        StakePool,
+    },
+    ID,
+};
+
+mod common;
+
+use common::*;
+
+/// Events recorded by the stubs; the stubs are process-wide, so tests run one at a time
+static EVENTS: Mutex<Vec<StakePoolEvent>> = Mutex::new(Vec::new());
+static SERIAL: Mutex<()> = Mutex::new(());
+static NOW: AtomicI64 = AtomicI64::new(0);
+static INSTALL: Once = Once::new();
+
+struct Recorder;
+
+impl SyscallStubs for Recorder {
+    fn sol_log_data(&self, fields: &[&[u8]]) {
+        assert_eq!(fields.len(), 1, "events are logged as a single field");
+        let event = StakePoolEvent::decode(fields[0]).expect("logged data is a StakePoolEvent");
+        EVENTS.lock().unwrap().push(event);
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            unix_timestamp: NOW.load(Ordering::SeqCst),
+            ..Clock::default()
+        };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        0
+    }
+}
+
+/// An account as the runtime would hand it to the program
+#[derive(Clone, PartialEq, Debug)]
+struct Ledger {
+    key: Pubkey,
+    owner: Pubkey,
+    lamports: u64,
+    data: Vec<u8>,
+    signer: bool,
+    writable: bool,
+}
+
+impl Ledger {
+    fn state(key: Pubkey, mut data: Vec<u8>, len: usize) -> Self {
+        data.resize(len, 0);
+        Self {
+            key,
+            owner: ID,
+            lamports: 1_000_000_000,
+            data,
+            signer: false,
+            writable: true,
+        }
+    }
+
+    fn signer(key: Pubkey) -> Self {
+        Self {
+            key,
+            owner: Pubkey::default(),
+            lamports: 1_000_000_000,
+            data: Vec::new(),
+            signer: true,
+            writable: false,
+        }
+    }
+
+    /// An omitted optional account
+    fn missing() -> Self {
+        Self {
+            key: ID,
+            owner: Pubkey::default(),
+            lamports: 0,
+            data: Vec::new(),
+            signer: false,
+            writable: false,
+        }
+    }
+}
+
+/// Run one instruction over `ledger` at time `now`, returning its result and events
+fn run(
+    ledger: &mut [Ledger],
+    instruction: StakePoolInstruction,
+    now: i64,
+) -> (ProgramResult, Vec<StakePoolEvent>) {
+    INSTALL.call_once(|| {
+        solana_program::program_stubs::set_syscall_stubs(Box::new(Recorder));
+    });
+    NOW.store(now, Ordering::SeqCst);
+    EVENTS.lock().unwrap().clear();
+
+    let data = borsh::to_vec(&instruction).unwrap();
+    let infos: Vec<AccountInfo> = ledger
+        .iter_mut()
+        .map(|account| {
+            AccountInfo::new(
+                &account.key,
+                account.signer,
+                account.writable,
+                &mut account.lamports,
+                &mut account.data,
+                &account.owner,
+                false,
+                0,
+            )
+        })
+        .collect();
+    let result = process_instruction(&ID, &infos, &data);
+
+    (result, std::mem::take(&mut *EVENTS.lock().unwrap()))
+}
+
+fn program_authority(authority: &Pubkey, creators: &[Pubkey]) -> ProgramAuthority {
+    ProgramAuthority {
+        key: Key::ProgramAuthorityV2,
+        authority: *authority,
+        authorized_creators: creators
+            .iter()
+            .map(|creator| AuthorizedCreator {
+                creator: *creator,
+                pools_created: 0,
+                pool_limit: None,
+            })
+            .collect(),
+        pending_authority: None,
+        bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
+    }
+}
+
+fn manage_creators_ledger(authority: &Pubkey, creators: &[Pubkey]) -> Vec<Ledger> {
+    vec![
+        Ledger::state(
+            get_program_authority_pda().0,
+            program_authority(authority, creators)
+                .to_account_data()
+                .unwrap(),
+            ProgramAuthority::LEN,
+        ),
+        Ledger::signer(*authority),
+        // No payer: the creators fit the account
+        Ledger::missing(),
+        Ledger::missing(),
+    ]
+}
+
+fn load_authority(ledger: &[Ledger]) -> ProgramAuthority {
+    ProgramAuthority::from_account_data(&ledger[0].data).unwrap()
+}
+
+fn creator_keys(authority: &ProgramAuthority) -> Vec<Pubkey> {
+    authority
+        .authorized_creators
+        .iter()
+        .map(|entry| entry.creator)
+        .collect()
+}
+
+/// A pool owing the full reward of two matured stake accounts of `owner`
+fn expiry_ledger(owner: &Pubkey) -> Vec<Ledger> {
+    let pool_key = Pubkey::new_unique();
+    let mut pool = sample_stake_pool();
+    let first = sample_stake_account(&pool_key, owner, 1_000_000);
+    let mut second = sample_stake_account(&pool_key, owner, 3_000_000);
+    second.index = 1;
+    pool.total_staked = 4_000_000;
+    pool.total_rewards_owed = 400_000;
+
+    vec![
+        Ledger::state(pool_key, pool.to_account_data().unwrap(), StakePool::LEN),
+        Ledger::state(
+            Pubkey::new_unique(),
+            first.to_account_data().unwrap(),
+            StakeAccount::LEN,
+        ),
+        Ledger::state(
+            Pubkey::new_unique(),
+            second.to_account_data().unwrap(),
+            StakeAccount::LEN,
+        ),
+        Ledger::signer(*owner),
+    ]
+}
+
+fn expiry_time() -> i64 {
+    let pool = sample_stake_pool();
+    pool.maturity_timestamp(1_700_000_000).unwrap() + REWARD_EXPIRY_PERIOD
+}
+
+fn load_pool(ledger: &[Ledger]) -> StakePool {
+    StakePool::from_account_data(&ledger[0].data).unwrap()
+}
+
+/// Expire both stake accounts in index order
+fn expire_both(ledger: &mut [Ledger], now: i64) -> Vec<StakePoolEvent> {
+    let mut events = Vec::new();
+    for stake in [1, 2] {
+        let mut accounts = vec![ledger[0].clone(), ledger[stake].clone()];
+        let (result, emitted) = run(&mut accounts, StakePoolInstruction::ExpireRewards, now);
+        result.unwrap();
+        ledger[0] = accounts[0].clone();
+        ledger[stake] = accounts[1].clone();
+        events.extend(emitted);
+    }
+    events
+}
+
+#[test]
+fn test_creator_removals_apply_before_additions_in_argument_order() {
+    let _serial = SERIAL.lock().unwrap();
+    let authority = Pubkey::new_unique();
+    let [a, b, c, d, e] = [(); 5].map(|_| Pubkey::new_unique());
+    let mut ledger = manage_creators_ledger(&authority, &[a, b, c]);
+
+    let (result, events) = run(
+        &mut ledger,
+        StakePoolInstruction::ManageAuthorizedCreators {
+            add: vec![e, d],
+            remove: vec![c, a],
+        },
+        1_700_000_000,
+    );
+    result.unwrap();
+
+    let creator = |creator| AuthorizedCreatorEvent { creator, authority };
+    assert_eq!(
+        events,
+        vec![
+            StakePoolEvent::AuthorizedCreatorRemoved(creator(c)),
+            StakePoolEvent::AuthorizedCreatorRemoved(creator(a)),
+            StakePoolEvent::AuthorizedCreatorAdded(creator(e)),
+            StakePoolEvent::AuthorizedCreatorAdded(creator(d)),
+        ]
+    );
+
+    let authority_data = load_authority(&ledger);
+    assert_eq!(authority_data.creator_count(), 3);
+    assert_eq!(creator_keys(&authority_data), [b, e, d]);
+}
+
+#[test]
+fn test_creator_removal_keeps_survivor_order() {
+    let _serial = SERIAL.lock().unwrap();
+    let authority = Pubkey::new_unique();
+    let creators = [(); 6].map(|_| Pubkey::new_unique());
+    let mut ledger = manage_creators_ledger(&authority, &creators);
+
+    // Remove from the middle, the front and the back of the list
+    let (result, _) = run(
+        &mut ledger,
+        StakePoolInstruction::ManageAuthorizedCreators {
+            add: vec![],
+            remove: vec![creators[2], creators[0], creators[5]],
+        },
+        1_700_000_000,
+    );
+    result.unwrap();
+
+    let authority_data = load_authority(&ledger);
+    authority_data.validate_creator_count().unwrap();
+    assert_eq!(
+        creator_keys(&authority_data),
+        [creators[1], creators[3], creators[4]]
+    );
+}
+
+#[test]
+fn test_growing_program_authority_needs_a_payer() {
+    let _serial = SERIAL.lock().unwrap();
+    let authority = Pubkey::new_unique();
+    let creators =
+        [(); ProgramAuthority::DEFAULT_MAX_CREATORS as usize].map(|_| Pubkey::new_unique());
+    let mut ledger = manage_creators_ledger(&authority, &creators);
+    let mut authority_data = load_authority(&ledger);
+    authority_data.set_max_creators(20).unwrap();
+    ledger[0] = Ledger::state(
+        ledger[0].key,
+        authority_data.to_account_data().unwrap(),
+        ProgramAuthority::LEN,
+    );
+    let before = ledger.clone();
+
+    // The eleventh creator does not fit the account allocated at initialization
+    let (result, _) = run(
+        &mut ledger,
+        StakePoolInstruction::ManageAuthorizedCreators {
+            add: vec![Pubkey::new_unique()],
+            remove: vec![],
+        },
+        1_700_000_000,
+    );
+    assert_eq!(result, Err(ProgramError::NotEnoughAccountKeys));
+    assert_eq!(ledger, before);
+}
+
+#[test]
+fn test_failed_instruction_keeps_state() {
+    let _serial = SERIAL.lock().unwrap();
+    let authority = Pubkey::new_unique();
+    let [a, b] = [(); 2].map(|_| Pubkey::new_unique());
+    let mut ledger = manage_creators_ledger(&authority, &[a]);
+    let before = ledger.clone();
+
+    // The first removal succeeds in memory, the second fails: nothing is saved or emitted
+    let (result, events) = run(
+        &mut ledger,
+        StakePoolInstruction::ManageAuthorizedCreators {
+            add: vec![],
+            remove: vec![a, b],
+        },
+        1_700_000_000,
+    );
+    assert!(result.is_err());
+    assert_eq!(ledger, before);
+    assert_eq!(
+        events,
+        vec![StakePoolEvent::AuthorizedCreatorRemoved(
+            AuthorizedCreatorEvent {
+                creator: a,
+                authority
+            }
+        )],
+        "steps before the failure still log, but the transaction fails as a whole"
+    );
+
+    let ledger = expiry_ledger(&Pubkey::new_unique());
+    let mut accounts = vec![ledger[0].clone(), ledger[1].clone()];
+    let before = accounts.clone();
+    let (result, events) = run(
+        &mut accounts,
+        StakePoolInstruction::ExpireRewards,
+        expiry_time() - 1,
+    );
+    assert!(result.is_err());
+    assert!(events.is_empty());
+    assert_eq!(accounts, before);
+}
+
+#[test]
+fn test_events_follow_instruction_order_and_counters_accumulate() {
+    let _serial = SERIAL.lock().unwrap();
+    let owner = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+    let mut ledger = expiry_ledger(&owner);
+    let now = expiry_time();
+
+    let mut events = Vec::new();
+    let mut accounts = vec![ledger[1].clone(), ledger[3].clone()];
+    let (result, emitted) = run(
+        &mut accounts,
+        StakePoolInstruction::SetStakeDelegate {
+            delegate: Some(delegate),
+        },
+        now,
+    );
+    result.unwrap();
+    ledger[1] = accounts[0].clone();
+    events.extend(emitted);
+
+    let mut accounts = vec![ledger[2].clone(), ledger[3].clone()];
+    let (result, emitted) = run(
+        &mut accounts,
+        StakePoolInstruction::SetAutoRelock { auto_relock: true },
+        now,
+    );
+    result.unwrap();
+    ledger[2] = accounts[0].clone();
+    events.extend(emitted);
+
+    events.extend(expire_both(&mut ledger, now));
+
+    // Each RewardsExpired names its stake account and amount, in execution order
+    let expired = |stake: usize, amount| {
+        StakePoolEvent::RewardsExpired(RewardsExpiredEvent {
+            pool: ledger[0].key,
+            stake_account: ledger[stake].key,
+            amount,
+        })
+    };
+    assert_eq!(
+        events,
+        vec![
+            StakePoolEvent::StakeDelegateUpdated(StakeDelegateUpdatedEvent {
+                stake_account: ledger[1].key,
+                delegate: Some(delegate),
+            }),
+            StakePoolEvent::AutoRelockUpdated(AutoRelockUpdatedEvent {
+                stake_account: ledger[2].key,
+                auto_relock: true,
+            }),
+            expired(1, 100_000),
+            expired(2, 300_000),
+        ]
+    );
+
+    let pool = load_pool(&ledger);
+    assert_eq!(pool.total_rewards_owed, 0);
+    assert_eq!(pool.expired_rewards, 400_000);
+}
+
+#[test]
+fn test_replaying_a_sequence_is_deterministic() {
+    let _serial = SERIAL.lock().unwrap();
+    let owner = Pubkey::new_unique();
+    let initial = expiry_ledger(&owner);
+    let now = expiry_time();
+
+    let mut first = initial.clone();
+    let first_events = expire_both(&mut first, now);
+    let mut second = initial.clone();
+    let second_events = expire_both(&mut second, now);
+
+    assert_eq!(first_events, second_events);
+    assert_eq!(first, second);
+}
+
+/// UpdatePool setting only the minimum stake and the pause flag
+fn update_pool(min_stake_amount: u64, is_paused: bool) -> StakePoolInstruction {
+    StakePoolInstruction::UpdatePool {
+        reward_rate: None,
+        min_stake_amount: Some(min_stake_amount),
+        lockup_period: None,
+        is_paused: Some(is_paused),
+        enforce_lockup: None,
+        pool_end_date: None,
+        referral_bps: None,
+        early_unstake_bps: None,
+        deposit_fee_bps: None,
+        withdraw_fee_bps: None,
+        unstake_cooldown: None,
+        bonus_end_ts: None,
+        bonus_multiplier_bps: None,
+        lockup_extension_rate: None,
+        allowlist_merkle_root: None,
+        claim_window: None,
+        outflow_limit: None,
+        pool_manager: None,
+        keeper_bounty: None,
+        max_total_rewards: None,
+        enforce_min_remaining_stake: None,
+        wind_down_rewards: None,
+        points_rate: None,
+        deny_list_required: None,
+        allow_third_party_stake: None,
+        cohort_duration: None,
+    }
+}
+
+#[test]
+fn test_pool_events_carry_consecutive_nonces() {
+    let _serial = SERIAL.lock().unwrap();
+    let admin = Pubkey::new_unique();
+    let pool_key = Pubkey::new_unique();
+    let mut ledger = vec![
+        Ledger::state(
+            pool_key,
+            sample_stake_pool().to_account_data().unwrap(),
+            StakePool::LEN,
+        ),
+        Ledger::signer(admin),
+        Ledger::state(
+            get_program_authority_pda().0,
+            program_authority(&admin, &[]).to_account_data().unwrap(),
+            ProgramAuthority::LEN,
+        ),
+        // Uninitialized program config: built-in limits
+        Ledger {
+            key: ProgramConfig::find_pda().0,
+            owner: Pubkey::default(),
+            lamports: 0,
+            data: Vec::new(),
+            signer: false,
+            writable: false,
+        },
+    ];
+
+    let mut events = Vec::new();
+    for (min_stake_amount, is_paused) in [(1_000, true), (2_000, false)] {
+        let (result, emitted) = run(
+            &mut ledger,
+            update_pool(min_stake_amount, is_paused),
+            1_700_000_000,
+        );
+        result.unwrap();
+        events.extend(emitted);
+    }
+
+    let updated = |parameter, event_nonce| {
+        StakePoolEvent::PoolUpdated(PoolUpdatedEvent {
+            pool: pool_key,
+            admin,
+            parameter,
+            event_nonce,
+        })
+    };
+    assert_eq!(
+        events,
+        vec![
+            updated(PoolParameter::MinStakeAmount(1_000), 1),
+            updated(PoolParameter::Paused(true), 2),
+            updated(PoolParameter::MinStakeAmount(2_000), 3),
+            updated(PoolParameter::Paused(false), 4),
+        ]
+    );
+    assert_eq!(load_pool(&ledger).event_nonce, 4);
+
+    // A failed update neither logs nor consumes a nonce
+    let before = ledger.clone();
+    ledger[1].signer = false;
+    let (result, events) = run(&mut ledger, update_pool(3_000, true), 1_700_000_000);
+    assert!(result.is_err());
+    assert!(events.is_empty());
+    ledger[1].signer = true;
+    assert_eq!(ledger, before);
+}
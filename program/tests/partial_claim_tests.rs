@@ -0,0 +1,64 @@
+// ============================================================================
+// Partial Claim Tests
+// ============================================================================
+// ClaimRewards takes an optional amount. A partial claim pays only that part of
+// the unclaimed rewards; the rest stays claimable by later claims.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{error::StakePoolError, state::StakeAccount};
+
+use common::*;
+
+#[test]
+fn test_claim_amount_defaults_to_all_unclaimed_rewards() {
+    assert_eq!(StakeAccount::claim_amount(100_000, None).unwrap(), 100_000);
+    assert_eq!(StakeAccount::claim_amount(0, None).unwrap(), 0);
+}
+
+#[test]
+fn test_claim_amount_accepts_a_portion() {
+    assert_eq!(
+        StakeAccount::claim_amount(100_000, Some(40_000)).unwrap(),
+        40_000
+    );
+    assert_eq!(
+        StakeAccount::claim_amount(100_000, Some(100_000)).unwrap(),
+        100_000
+    );
+}
+
+#[test]
+fn test_claim_amount_rejects_zero_and_excess() {
+    assert_eq!(
+        StakeAccount::claim_amount(100_000, Some(0)).unwrap_err(),
+        ProgramError::Custom(StakePoolError::InvalidParameters as u32)
+    );
+    assert_eq!(
+        StakeAccount::claim_amount(100_000, Some(100_001)).unwrap_err(),
+        ProgramError::Custom(StakePoolError::ClaimExceedsUnclaimedRewards as u32)
+    );
+}
+
+#[test]
+fn test_remainder_stays_claimable() {
+    let pool = sample_stake_pool();
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000_000);
+    stake.locked_reward_rate = pool.reward_rate;
+    let matured = pool.maturity_timestamp(stake.stake_timestamp).unwrap();
+    let total = pool.stake_rewards(&stake, matured).unwrap();
+    assert!(total > 1);
+
+    // First claim takes part of the rewards, as ClaimRewards records it
+    let first = StakeAccount::claim_amount(total, Some(total / 2)).unwrap();
+    stake.claimed_rewards += first;
+
+    let unclaimed = pool.stake_rewards(&stake, matured).unwrap() - stake.claimed_rewards;
+    assert_eq!(unclaimed, total - first);
+    assert_eq!(
+        StakeAccount::claim_amount(unclaimed, None).unwrap(),
+        unclaimed
+    );
+}
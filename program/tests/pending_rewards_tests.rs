@@ -0,0 +1,66 @@
+// ============================================================================
+// Pending Rewards View Tests
+// ============================================================================
+// GetPendingRewards returns what ClaimRewards would pay at the current clock,
+// plus the projection at maturity, so frontends never re-implement the math.
+
+mod common;
+
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    return_data::PendingRewards,
+    state::{StakeAccount, StakePool},
+};
+
+use common::*;
+
+const STAKED: u64 = 1_000_000;
+
+fn setup() -> (StakePool, StakeAccount, i64) {
+    let mut pool = sample_stake_pool();
+    pool.reward_rate = 100_000_000;
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), STAKED);
+    stake.locked_reward_rate = pool.reward_rate;
+    let matured = pool.maturity_timestamp(stake.stake_timestamp).unwrap();
+    (pool, stake, matured)
+}
+
+#[test]
+fn test_pending_before_maturity_projects_round_rewards() {
+    let (pool, stake, matured) = setup();
+
+    let pending = pool.pending_rewards(&stake, matured - 1).unwrap();
+    assert_eq!(
+        pending,
+        PendingRewards {
+            current_time: matured - 1,
+            claimable: 0,
+            referral_share: 0,
+            matures_at: matured,
+            projected_rewards: 100_000,
+            reward_rate: 100_000_000,
+        }
+    );
+}
+
+#[test]
+fn test_pending_after_maturity_matches_claim_math() {
+    let (pool, mut stake, matured) = setup();
+    stake.claimed_rewards = 40_000;
+
+    let pending = pool.pending_rewards(&stake, matured + 10).unwrap();
+    let expected = pool.stake_rewards(&stake, matured + 10).unwrap() - stake.claimed_rewards;
+    assert_eq!(pending.claimable, expected);
+    assert_eq!(pending.projected_rewards, pending.claimable);
+}
+
+#[test]
+fn test_pending_reports_referral_share() {
+    let (mut pool, mut stake, matured) = setup();
+    pool.referral_bps = 1_000;
+    stake.referrer = Some(Pubkey::new_unique());
+
+    let pending = pool.pending_rewards(&stake, matured).unwrap();
+    assert_eq!(pending.claimable, 100_000);
+    assert_eq!(pending.referral_share, 10_000);
+}
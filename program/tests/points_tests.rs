@@ -0,0 +1,83 @@
+// ============================================================================
+// Points Tests
+// ============================================================================
+// Pools with a points_rate accrue points per staked base unit per second.
+// SyncPoints credits what a stake earned since its last sync to the owner's
+// UserPoints and moves the stake's checkpoint, so no second counts twice.
+
+mod common;
+
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    constants::POINTS_SCALE,
+    state::{Key, UserPoints},
+};
+
+use common::*;
+
+const STAKED: u64 = 1_000_000;
+const DAY: i64 = 86_400;
+
+#[test]
+fn test_points_accrue_per_token_second_since_the_last_sync() {
+    let mut pool = sample_stake_pool();
+    // One point per 1,000 base units per second
+    pool.points_rate = (POINTS_SCALE / 1_000) as u64;
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), STAKED);
+    stake.points_synced_at = SAMPLE_STAKE_TIMESTAMP;
+
+    let points = pool
+        .accrue_points(&mut stake, SAMPLE_STAKE_TIMESTAMP + DAY)
+        .unwrap();
+    assert_eq!(points, (STAKED / 1_000) as u128 * DAY as u128);
+    assert_eq!(stake.points_synced_at, SAMPLE_STAKE_TIMESTAMP + DAY);
+
+    // Syncing again right away adds nothing
+    let points = pool
+        .accrue_points(&mut stake, SAMPLE_STAKE_TIMESTAMP + DAY)
+        .unwrap();
+    assert_eq!(points, 0);
+}
+
+#[test]
+fn test_stakes_never_synced_accrue_from_their_stake_time() {
+    let mut pool = sample_stake_pool();
+    pool.points_rate = POINTS_SCALE as u64;
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), STAKED);
+    assert_eq!(stake.points_synced_at, 0);
+
+    let points = pool
+        .accrue_points(&mut stake, SAMPLE_STAKE_TIMESTAMP + 10)
+        .unwrap();
+    assert_eq!(points, STAKED as u128 * 10);
+
+    // A clock behind the checkpoint neither accrues nor moves it back
+    let points = pool
+        .accrue_points(&mut stake, SAMPLE_STAKE_TIMESTAMP)
+        .unwrap();
+    assert_eq!(points, 0);
+    assert_eq!(stake.points_synced_at, SAMPLE_STAKE_TIMESTAMP + 10);
+}
+
+#[test]
+fn test_user_points_credit_and_fit_their_account() {
+    let mut user_points = UserPoints {
+        key: Key::UserPoints,
+        pool: Pubkey::new_unique(),
+        owner: Pubkey::new_unique(),
+        points: 0,
+        last_synced_at: 0,
+        bump: 255,
+    };
+
+    user_points.credit(500, SAMPLE_STAKE_TIMESTAMP).unwrap();
+    user_points
+        .credit(250, SAMPLE_STAKE_TIMESTAMP + DAY)
+        .unwrap();
+    assert_eq!(user_points.points, 750);
+    assert_eq!(user_points.last_synced_at, SAMPLE_STAKE_TIMESTAMP + DAY);
+
+    user_points.points = u128::MAX;
+    assert!(user_points.credit(1, SAMPLE_STAKE_TIMESTAMP).is_err());
+    assert_eq!(borsh::to_vec(&user_points).unwrap().len(), UserPoints::LEN);
+}
@@ -0,0 +1,160 @@
+// ============================================================================
+// Pool Allowlist Tests
+// ============================================================================
+// Private pools set allowlist_required and list their stakers in allowlist
+// pages at ["pool_allowlist", pool, page], managed by global admins with
+// ManagePoolAllowlist. Stake, StakeSol and StakeBatch then require the page
+// listing the owner; pools without the flag accept anyone.
+
+mod common;
+
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    error::StakePoolError,
+    processor::helpers::check_pool_allowlist,
+    state::{Key, PoolAllowlist},
+};
+
+use common::*;
+
+fn page(pool: &Pubkey, number: u32, members: Vec<Pubkey>) -> PoolAllowlist {
+    PoolAllowlist {
+        key: Key::PoolAllowlist,
+        pool: *pool,
+        page: number,
+        bump: PoolAllowlist::find_pda(pool, number).1,
+        members,
+    }
+}
+
+/// Run `check_pool_allowlist` for `owner` with `allowlist` stored at `address`
+fn check(
+    pool: &Pubkey,
+    allowlist_required: bool,
+    allowlist: Option<(Pubkey, &PoolAllowlist)>,
+    owner: &Pubkey,
+) -> Result<(), ProgramError> {
+    let mut pool_data = sample_stake_pool();
+    pool_data.allowlist_required = allowlist_required;
+
+    let program_id = your_wallet_stake_pool::ID;
+    let mut lamports = 0;
+    let Some((address, allowlist)) = allowlist else {
+        return check_pool_allowlist(&pool_data, pool, None, None, owner);
+    };
+    let mut data = borsh::to_vec(allowlist).unwrap();
+    data.resize(PoolAllowlist::LEN, 0);
+    let info = AccountInfo::new(
+        &address,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &program_id,
+        false,
+        0,
+    );
+    check_pool_allowlist(&pool_data, pool, Some(&info), None, owner)
+}
+
+fn not_allowlisted() -> Result<(), ProgramError> {
+    Err(StakePoolError::NotAllowlisted.into())
+}
+
+#[test]
+fn test_open_pools_accept_anyone() {
+    let pool = Pubkey::new_unique();
+    assert!(check(&pool, false, None, &Pubkey::new_unique()).is_ok());
+}
+
+#[test]
+fn test_listed_owners_may_stake() {
+    let pool = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let allowlist = page(&pool, 4, vec![Pubkey::new_unique(), owner]);
+    let address = PoolAllowlist::find_pda(&pool, 4).0;
+
+    assert!(check(&pool, true, Some((address, &allowlist)), &owner).is_ok());
+}
+
+#[test]
+fn test_unlisted_owners_are_rejected() {
+    let pool = Pubkey::new_unique();
+    let allowlist = page(&pool, 0, vec![Pubkey::new_unique()]);
+    let address = PoolAllowlist::find_pda(&pool, 0).0;
+
+    assert_eq!(
+        check(
+            &pool,
+            true,
+            Some((address, &allowlist)),
+            &Pubkey::new_unique()
+        ),
+        not_allowlisted()
+    );
+    // Omitting the page is no way around the allowlist
+    assert_eq!(
+        check(&pool, true, None, &Pubkey::new_unique()),
+        not_allowlisted()
+    );
+}
+
+#[test]
+fn test_pages_of_other_pools_are_rejected() {
+    let pool = Pubkey::new_unique();
+    let other_pool = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let allowlist = page(&other_pool, 0, vec![owner]);
+    let address = PoolAllowlist::find_pda(&other_pool, 0).0;
+
+    assert!(check(&pool, true, Some((address, &allowlist)), &owner).is_err());
+}
+
+#[test]
+fn test_updates_skip_owners_already_in_the_wanted_state() {
+    let pool = Pubkey::new_unique();
+    let (a, b, c) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let mut allowlist = page(&pool, 0, vec![a, b]);
+
+    allowlist
+        .update(&[b, c], &[a, Pubkey::new_unique()])
+        .unwrap();
+    assert_eq!(allowlist.members, vec![b, c]);
+}
+
+#[test]
+fn test_full_pages_reject_additions_without_changes() {
+    let pool = Pubkey::new_unique();
+    let members: Vec<Pubkey> = (0..PoolAllowlist::PAGE_CAPACITY)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    let mut allowlist = page(&pool, 0, members.clone());
+
+    assert_eq!(
+        allowlist.update(&[Pubkey::new_unique()], &[]),
+        Err(StakePoolError::PoolAllowlistPageFull.into())
+    );
+    assert_eq!(allowlist.members, members);
+
+    // Making room in the same update is fine
+    let newcomer = Pubkey::new_unique();
+    allowlist.update(&[newcomer], &[members[0]]).unwrap();
+    assert!(allowlist.contains(&newcomer));
+    assert!(!allowlist.contains(&members[0]));
+}
+
+#[test]
+fn test_full_page_fits_in_len() {
+    let pool = Pubkey::new_unique();
+    let members = (0..PoolAllowlist::PAGE_CAPACITY)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    let allowlist = page(&pool, u32::MAX, members);
+
+    assert_eq!(borsh::to_vec(&allowlist).unwrap().len(), PoolAllowlist::LEN);
+}
@@ -0,0 +1,68 @@
+// ============================================================================
+// Pool Manager Tests
+// ============================================================================
+// A pool may name a pool manager (set by global admins with UpdatePool), e.g.
+// a partner project. The manager may pause and unpause that one pool through
+// UpdatePool and fund its rewards, but cannot change its rates or any other
+// parameter.
+
+mod common;
+
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::processor::PoolUpdate;
+
+use common::*;
+
+#[test]
+fn test_managers_may_only_pause_and_unpause() {
+    let pause = PoolUpdate {
+        is_paused: Some(true),
+        ..Default::default()
+    };
+    assert!(pause.is_manager_update());
+
+    let rate = PoolUpdate {
+        reward_rate: Some(1),
+        ..Default::default()
+    };
+    assert!(!rate.is_manager_update());
+
+    let pause_and_rate = PoolUpdate {
+        is_paused: Some(false),
+        reward_rate: Some(1),
+        ..Default::default()
+    };
+    assert!(!pause_and_rate.is_manager_update());
+
+    let pause_and_min_stake = PoolUpdate {
+        is_paused: Some(false),
+        min_stake_amount: Some(1),
+        ..Default::default()
+    };
+    assert!(!pause_and_min_stake.is_manager_update());
+    assert!(!PoolUpdate::default().is_manager_update());
+}
+
+#[test]
+fn test_pool_manager_changes_are_admin_only() {
+    let update = PoolUpdate {
+        pool_manager: Some(Some(Pubkey::new_unique())),
+        ..Default::default()
+    };
+    assert_eq!(update.required_roles(), None);
+    assert!(!update.is_manager_update());
+}
+
+#[test]
+fn test_managers_may_fund_pools_with_a_funder_allowlist() {
+    let manager = Pubkey::new_unique();
+    let funder = Pubkey::new_unique();
+    let mut pool = sample_stake_pool();
+    pool.set_reward_funders(&[funder]).unwrap();
+
+    assert!(!pool.is_reward_funder_allowed(&manager));
+    pool.pool_manager = Some(manager);
+    assert!(pool.is_reward_funder_allowed(&manager));
+    assert!(pool.is_reward_funder_allowed(&funder));
+    assert!(!pool.is_reward_funder_allowed(&Pubkey::new_unique()));
+}
@@ -0,0 +1,165 @@
+// ============================================================================
+// Pool Registry Tests
+// ============================================================================
+// Every pool is listed in the program-wide pool registry, a chain of PDA pages
+// ["pool_registry", page] filled in order, so wallets can enumerate pools by
+// reading pages until the first missing one.
+
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    error::StakePoolError,
+    processor::helpers::append_to_pool_registry,
+    state::{Key, PoolRegistry, PoolRegistryEntry},
+};
+
+fn entry() -> PoolRegistryEntry {
+    PoolRegistryEntry {
+        pool: Pubkey::new_unique(),
+        stake_mint: Pubkey::new_unique(),
+        pool_id: 0,
+    }
+}
+
+fn page(page: u32, pools: usize) -> PoolRegistry {
+    PoolRegistry {
+        key: Key::PoolRegistry,
+        page,
+        bump: PoolRegistry::find_pda(page).1,
+        pools: (0..pools).map(|_| entry()).collect(),
+    }
+}
+
+/// Account data of `registry`, allocated at the full page size
+fn page_data(registry: &PoolRegistry) -> Vec<u8> {
+    let mut data = borsh::to_vec(registry).unwrap();
+    data.resize(PoolRegistry::LEN, 0);
+    data
+}
+
+fn error(code: StakePoolError) -> ProgramError {
+    ProgramError::Custom(code as u32)
+}
+
+#[test]
+fn test_full_page_fits_len() {
+    let registry = page(3, PoolRegistry::PAGE_CAPACITY);
+    assert!(registry.is_full());
+    assert_eq!(borsh::to_vec(&registry).unwrap().len(), PoolRegistry::LEN);
+}
+
+#[test]
+fn test_push_stops_at_page_capacity() {
+    let mut registry = page(0, PoolRegistry::PAGE_CAPACITY - 1);
+    assert!(registry.push(entry()).is_ok());
+    assert_eq!(
+        registry.push(entry()).unwrap_err(),
+        error(StakePoolError::PoolRegistryPageFull)
+    );
+    assert_eq!(registry.pools.len(), PoolRegistry::PAGE_CAPACITY);
+}
+
+#[test]
+fn test_pages_have_distinct_addresses() {
+    assert_ne!(PoolRegistry::find_pda(0).0, PoolRegistry::find_pda(1).0);
+}
+
+#[test]
+fn test_append_to_existing_page() {
+    let program_id = your_wallet_stake_pool::ID;
+    let system_program = solana_program::system_program::ID;
+    let (key, _) = PoolRegistry::find_pda(1);
+    let mut lamports = 0;
+    let mut data = page_data(&page(1, 2));
+    let registry_account = AccountInfo::new(
+        &key,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &program_id,
+        false,
+        0,
+    );
+    let payer_key = Pubkey::new_unique();
+    let mut payer_lamports = 0;
+    let mut payer_data = Vec::new();
+    let payer = AccountInfo::new(
+        &payer_key,
+        true,
+        true,
+        &mut payer_lamports,
+        &mut payer_data,
+        &system_program,
+        false,
+        0,
+    );
+
+    let listed = entry();
+    let page_number =
+        append_to_pool_registry(&registry_account, None, listed.clone(), &payer, &payer).unwrap();
+    assert_eq!(page_number, 1);
+
+    let registry = PoolRegistry::load(&registry_account).unwrap();
+    assert_eq!(registry.pools.len(), 3);
+    assert_eq!(registry.pools[2], listed);
+
+    // A full page sends the pool to the next one
+    let mut full_lamports = 0;
+    let mut full_data = page_data(&page(1, PoolRegistry::PAGE_CAPACITY));
+    let full_account = AccountInfo::new(
+        &key,
+        false,
+        true,
+        &mut full_lamports,
+        &mut full_data,
+        &program_id,
+        false,
+        0,
+    );
+    assert_eq!(
+        append_to_pool_registry(&full_account, None, entry(), &payer, &payer).unwrap_err(),
+        error(StakePoolError::PoolRegistryPageFull)
+    );
+}
+
+#[test]
+fn test_new_page_requires_full_previous_page() {
+    let program_id = your_wallet_stake_pool::ID;
+    let system_program = solana_program::system_program::ID;
+
+    let (previous_key, _) = PoolRegistry::find_pda(0);
+    let mut previous_lamports = 0;
+    let mut previous_data = page_data(&page(0, 5));
+    let previous = AccountInfo::new(
+        &previous_key,
+        false,
+        false,
+        &mut previous_lamports,
+        &mut previous_data,
+        &program_id,
+        false,
+        0,
+    );
+
+    let (key, _) = PoolRegistry::find_pda(1);
+    let mut lamports = 0;
+    let mut data = Vec::new();
+    let new_page = AccountInfo::new(
+        &key,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &system_program,
+        false,
+        0,
+    );
+
+    // Page 0 still has room, so page 1 may not be opened yet
+    assert_eq!(
+        append_to_pool_registry(&new_page, Some(&previous), entry(), &new_page, &new_page)
+            .unwrap_err(),
+        error(StakePoolError::InvalidParameters)
+    );
+}
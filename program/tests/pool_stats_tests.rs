@@ -0,0 +1,103 @@
+// ============================================================================
+// Pool Stats Tests
+// ============================================================================
+// GetPoolStats reports a pool's recorded totals next to its live vault
+// balances, the coverage of each in basis points, the number of stake
+// accounts still holding stake and the paused state, so dashboards need a
+// single simulated call.
+
+mod common;
+
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::state::StakePool;
+
+use common::*;
+
+#[test]
+fn test_stats_report_totals_and_coverage() {
+    let mut pool = sample_stake_pool();
+    pool.total_staked = 1_000_000;
+    pool.total_rewards_owed = 200_000;
+    pool.active_stake_count = Some(4);
+
+    let stats = pool.stats(1_000_000, 300_000);
+
+    assert_eq!(stats.total_staked, 1_000_000);
+    assert_eq!(stats.stake_vault_balance, 1_000_000);
+    assert_eq!(stats.total_rewards_owed, 200_000);
+    assert_eq!(stats.reward_vault_balance, 300_000);
+    assert_eq!(stats.stake_coverage_bps, Some(10_000));
+    assert_eq!(stats.reward_coverage_bps, Some(15_000));
+    assert!(stats.solvent);
+    assert_eq!(stats.active_stake_count, Some(4));
+    assert!(!stats.is_paused);
+}
+
+#[test]
+fn test_stats_flag_underfunded_rewards() {
+    let mut pool = sample_stake_pool();
+    pool.total_staked = 1_000_000;
+    pool.total_rewards_owed = 200_000;
+    pool.is_paused = true;
+
+    let stats = pool.stats(1_000_000, 50_000);
+
+    assert_eq!(stats.reward_coverage_bps, Some(2_500));
+    assert!(!stats.solvent);
+    assert!(stats.is_paused);
+}
+
+#[test]
+fn test_coverage_is_none_when_nothing_is_owed() {
+    assert_eq!(StakePool::coverage_bps(500, 0), None);
+    assert_eq!(StakePool::coverage_bps(0, 1), Some(0));
+    // Huge surpluses saturate instead of overflowing
+    assert_eq!(StakePool::coverage_bps(u64::MAX, 1), Some(u64::MAX));
+}
+
+#[test]
+fn test_active_stake_count_follows_stakes() {
+    let mut pool = sample_stake_pool();
+    pool.active_stake_count = Some(0);
+
+    pool.record_stake_opened().unwrap();
+    pool.record_stake_opened().unwrap();
+    assert_eq!(pool.active_stake_count, Some(2));
+
+    pool.record_stake_emptied();
+    assert_eq!(pool.active_stake_count, Some(1));
+
+    // Never underflows, so an unstake cannot fail on the count
+    pool.record_stake_emptied();
+    pool.record_stake_emptied();
+    assert_eq!(pool.active_stake_count, Some(0));
+}
+
+#[test]
+fn test_pools_without_count_stay_uncounted() {
+    let mut pool = sample_stake_pool();
+    pool.active_stake_count = None;
+
+    pool.record_stake_opened().unwrap();
+    pool.record_stake_emptied();
+
+    assert_eq!(pool.active_stake_count, None);
+}
+
+#[test]
+fn test_requesting_the_whole_stake_empties_the_stake_account() {
+    let mut pool = sample_stake_pool();
+    pool.total_staked = 1_000;
+    pool.total_rewards_owed = pool
+        .expected_rewards(1_000, SAMPLE_STAKE_TIMESTAMP)
+        .unwrap();
+    pool.active_stake_count = Some(2);
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000);
+    let matured = pool.maturity_timestamp(stake.stake_timestamp).unwrap();
+
+    pool.request_unstake(&mut stake, 400, matured).unwrap();
+    assert_eq!(pool.active_stake_count, Some(2));
+
+    pool.request_unstake(&mut stake, 600, matured).unwrap();
+    assert_eq!(pool.active_stake_count, Some(1));
+}
@@ -0,0 +1,109 @@
+// ============================================================================
+// Program Config Tests
+// ============================================================================
+// The ProgramConfig PDA records the program version and feature flags and holds
+// the pool limits InitializePool and pool updates validate against. Its limits
+// may only tighten the built-in MAX_REWARD_RATE and MIN_LOCKUP_PERIOD, which
+// apply until the config is initialized.
+
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    constants::{MAX_REWARD_RATE, MIN_LOCKUP_PERIOD, PROGRAM_VERSION},
+    error::StakePoolError,
+    processor::helpers::load_program_config,
+    state::{Key, ProgramConfig},
+};
+
+fn invalid_parameters() -> Result<(), ProgramError> {
+    Err(StakePoolError::InvalidParameters.into())
+}
+
+/// Run `load_program_config` on an account at `address` holding `data`
+fn load(address: Pubkey, mut data: Vec<u8>) -> Result<ProgramConfig, ProgramError> {
+    let program_id = your_wallet_stake_pool::ID;
+    let mut lamports = 0;
+    let info = AccountInfo::new(
+        &address,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &program_id,
+        false,
+        0,
+    );
+    load_program_config(&info)
+}
+
+#[test]
+fn test_program_version_matches_the_crate() {
+    let version = PROGRAM_VERSION.map(|part| part.to_string()).join(".");
+    assert_eq!(version, env!("CARGO_PKG_VERSION"));
+}
+
+#[test]
+fn test_limits_only_tighten_the_builtin_ones() {
+    let mut config = ProgramConfig::default();
+
+    assert_eq!(
+        config.set_limits(MAX_REWARD_RATE + 1, MIN_LOCKUP_PERIOD),
+        invalid_parameters()
+    );
+    assert_eq!(
+        config.set_limits(MAX_REWARD_RATE, MIN_LOCKUP_PERIOD - 1),
+        invalid_parameters()
+    );
+    assert_eq!(config.max_reward_rate, MAX_REWARD_RATE);
+    assert_eq!(config.min_lockup_period, MIN_LOCKUP_PERIOD);
+
+    config
+        .set_limits(MAX_REWARD_RATE / 10, MIN_LOCKUP_PERIOD * 7)
+        .unwrap();
+    assert_eq!(config.max_reward_rate, MAX_REWARD_RATE / 10);
+    assert_eq!(config.min_lockup_period, MIN_LOCKUP_PERIOD * 7);
+}
+
+#[test]
+fn test_feature_flags_need_every_bit() {
+    let config = ProgramConfig {
+        feature_flags: 0b0101,
+        ..ProgramConfig::default()
+    };
+
+    assert!(config.has_feature(0b0001));
+    assert!(config.has_feature(0b0101));
+    assert!(!config.has_feature(0b0010));
+    assert!(!config.has_feature(0b0011));
+}
+
+#[test]
+fn test_uninitialized_config_uses_builtin_limits() {
+    let config = load(ProgramConfig::find_pda().0, Vec::new()).unwrap();
+
+    assert_eq!(config.max_reward_rate, MAX_REWARD_RATE);
+    assert_eq!(config.min_lockup_period, MIN_LOCKUP_PERIOD);
+
+    // Only the config PDA may stand in for the missing config
+    assert!(load(Pubkey::new_unique(), Vec::new()).is_err());
+}
+
+#[test]
+fn test_initialized_config_is_loaded() {
+    let (address, bump) = ProgramConfig::find_pda();
+    let stored = ProgramConfig {
+        key: Key::ProgramConfig,
+        feature_flags: 1,
+        max_reward_rate: 200_000_000,
+        min_lockup_period: MIN_LOCKUP_PERIOD * 30,
+        bump,
+        ..ProgramConfig::default()
+    };
+    let data = borsh::to_vec(&stored).unwrap();
+    assert_eq!(data.len(), ProgramConfig::LEN);
+
+    let config = load(address, data).unwrap();
+    assert_eq!(config.max_reward_rate, 200_000_000);
+    assert_eq!(config.min_lockup_period, MIN_LOCKUP_PERIOD * 30);
+    assert!(config.has_feature(1));
+}
@@ -0,0 +1,120 @@
+// ============================================================================
+// Outflow Rate Limit Tests
+// ============================================================================
+// Pools may cap how much of their stake can be unstaked, and how many reward
+// tokens claimed, in any 24h outflow window (OutflowLimit, set with
+// UpdatePool). Past a cap Unstake, WithdrawUnstaked, ClaimRewards and
+// ClaimRewardsBatch fail with RateLimited until the window resets.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use your_wallet_stake_pool::{
+    constants::OUTFLOW_WINDOW,
+    error::StakePoolError,
+    processor::PoolUpdate,
+    state::{OutflowLimit, StakePool},
+};
+
+use common::*;
+
+const NOW: i64 = SAMPLE_STAKE_TIMESTAMP;
+const TOTAL_STAKED: u64 = 1_000_000;
+
+fn rate_limited() -> Result<(), ProgramError> {
+    Err(StakePoolError::RateLimited.into())
+}
+
+/// A pool holding TOTAL_STAKED with the given caps
+fn limited_pool(max_unstake_bps: u16, max_claim_amount: u64) -> StakePool {
+    let mut pool = sample_stake_pool();
+    pool.total_staked = TOTAL_STAKED;
+    pool.outflow_limit = Some(OutflowLimit {
+        max_unstake_bps,
+        max_claim_amount,
+    });
+    pool
+}
+
+#[test]
+fn test_pools_without_a_limit_are_not_rate_limited() {
+    let mut pool = sample_stake_pool();
+    pool.total_staked = TOTAL_STAKED;
+
+    assert!(pool.record_unstake_outflow(TOTAL_STAKED, NOW).is_ok());
+    assert!(pool.record_claim_outflow(u64::MAX, NOW).is_ok());
+    assert_eq!(pool.outflow_window.unstaked, 0);
+}
+
+#[test]
+fn test_unstakes_are_capped_by_the_stake_at_the_window_opening() {
+    // 10% of the 1_000_000 staked when the window opens
+    let mut pool = limited_pool(1_000, 0);
+
+    assert!(pool.record_unstake_outflow(60_000, NOW).is_ok());
+    pool.total_staked -= 60_000;
+    assert!(pool.record_unstake_outflow(40_000, NOW + 3_600).is_ok());
+    pool.total_staked -= 40_000;
+    assert_eq!(pool.record_unstake_outflow(1, NOW + 7_200), rate_limited());
+
+    assert_eq!(pool.outflow_window.start, NOW);
+    assert_eq!(pool.outflow_window.total_staked, TOTAL_STAKED);
+    assert_eq!(pool.outflow_window.unstaked, 100_000);
+    // Claims are not limited by an unstake-only cap
+    assert!(pool.record_claim_outflow(u64::MAX, NOW).is_ok());
+}
+
+#[test]
+fn test_claims_are_capped_per_window() {
+    let mut pool = limited_pool(0, 500);
+
+    assert!(pool.record_claim_outflow(300, NOW).is_ok());
+    assert_eq!(pool.record_claim_outflow(201, NOW + 60), rate_limited());
+    assert!(pool.record_claim_outflow(200, NOW + 60).is_ok());
+    assert_eq!(pool.outflow_window.claimed, 500);
+    // Unstakes are not limited by a claim-only cap
+    assert!(pool.record_unstake_outflow(TOTAL_STAKED, NOW).is_ok());
+}
+
+#[test]
+fn test_windows_reset_after_24_hours() {
+    let mut pool = limited_pool(1_000, 500);
+    assert!(pool.record_unstake_outflow(100_000, NOW).is_ok());
+    assert!(pool.record_claim_outflow(500, NOW).is_ok());
+    pool.total_staked -= 100_000;
+
+    let last_second = NOW + OUTFLOW_WINDOW - 1;
+    assert_eq!(pool.record_unstake_outflow(1, last_second), rate_limited());
+    assert_eq!(pool.record_claim_outflow(1, last_second), rate_limited());
+
+    // The next window's cap is based on the stake left when it opens
+    let reset = NOW + OUTFLOW_WINDOW;
+    assert!(pool.record_unstake_outflow(90_000, reset).is_ok());
+    assert_eq!(pool.record_unstake_outflow(1, reset), rate_limited());
+    assert!(pool.record_claim_outflow(500, reset).is_ok());
+    assert_eq!(pool.outflow_window.start, reset);
+    assert_eq!(pool.outflow_window.total_staked, 900_000);
+}
+
+#[test]
+fn test_limits_must_cap_something_within_100_percent() {
+    let invalid = || Err(StakePoolError::InvalidParameters.into());
+    let limit = |max_unstake_bps, max_claim_amount| OutflowLimit {
+        max_unstake_bps,
+        max_claim_amount,
+    };
+
+    assert!(limit(10_000, 0).validate().is_ok());
+    assert!(limit(0, 1).validate().is_ok());
+    assert_eq!(limit(0, 0).validate(), invalid());
+    assert_eq!(limit(10_001, 1).validate(), invalid());
+}
+
+#[test]
+fn test_outflow_limit_updates_are_admin_only() {
+    let update = PoolUpdate {
+        outflow_limit: Some(None),
+        ..Default::default()
+    };
+    assert_eq!(update.required_roles(), None);
+}
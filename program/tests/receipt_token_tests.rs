@@ -0,0 +1,43 @@
+// ============================================================================
+// Receipt Token Tests
+// ============================================================================
+// Pools can tokenize positions: once the admin enables it, every stake mints
+// receipt tokens 1:1 with the staked amount from a per-pool Token-2022 mint,
+// and every unstake burns them. Non-transferable receipts use the
+// NonTransferable mint extension.
+
+mod common;
+
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::state::StakePool;
+
+use common::*;
+
+#[test]
+fn test_receipt_mint_pda_derivation() {
+    let pool = Pubkey::new_unique();
+    let (pda, bump) = StakePool::find_receipt_mint_pda(&pool);
+    let (expected, expected_bump) = get_receipt_mint_pda(&pool);
+
+    assert_pda_consistency(&pda, &expected, bump, expected_bump);
+    assert_valid_pda(&pda);
+}
+
+#[test]
+fn test_receipt_mint_is_unique_per_pool() {
+    let (first, _) = StakePool::find_receipt_mint_pda(&Pubkey::new_unique());
+    let (second, _) = StakePool::find_receipt_mint_pda(&Pubkey::new_unique());
+
+    assert_ne!(first, second);
+}
+
+#[test]
+fn test_pools_start_without_receipt_mint() {
+    let mut pool = sample_stake_pool();
+    assert_eq!(pool.receipt_mint, None);
+
+    // Enabling receipts stores the mint, which fits inside StakePool::LEN
+    pool.receipt_mint = Some(StakePool::find_receipt_mint_pda(&Pubkey::new_unique()).0);
+    let data = borsh::to_vec(&pool).unwrap();
+    assert!(data.len() <= StakePool::LEN);
+}
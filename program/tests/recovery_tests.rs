@@ -0,0 +1,179 @@
+// ============================================================================
+// Authority Recovery Tests
+// ============================================================================
+// The program authority may name a recovery authority with a recovery delay.
+// The recovery key takes over a lost authority with InitiateRecovery and, once
+// the delay has passed, CompleteRecovery. A live authority cancels the takeover
+// with CancelAuthorityTransfer or by replacing the recovery key.
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    constants::{MAX_RECOVERY_DELAY, MIN_RECOVERY_DELAY},
+    error::StakePoolError,
+    state::{ClusterProfile, Key, ProgramAuthority},
+};
+
+const NOW: i64 = 1_700_000_000;
+const THIRTY_DAYS: i64 = 2_592_000;
+
+fn program_authority() -> ProgramAuthority {
+    ProgramAuthority {
+        key: Key::ProgramAuthorityV2,
+        authority: Pubkey::new_unique(),
+        authorized_creators: Vec::new(),
+        pending_authority: None,
+        bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
+    }
+}
+
+/// A program authority recoverable by the returned key after 30 days
+fn recoverable() -> (ProgramAuthority, Pubkey) {
+    let mut authority = program_authority();
+    let recovery = Pubkey::new_unique();
+    authority.set_recovery(Some(recovery), THIRTY_DAYS).unwrap();
+    (authority, recovery)
+}
+
+fn error(e: StakePoolError) -> ProgramError {
+    ProgramError::Custom(e as u32)
+}
+
+#[test]
+fn test_recovery_completes_after_the_delay() {
+    let (mut authority, recovery) = recoverable();
+    let lost = authority.authority;
+    authority.pending_authority = Some(Pubkey::new_unique());
+
+    let completes_at = authority.initiate_recovery(&recovery, NOW).unwrap();
+    assert_eq!(completes_at, NOW + THIRTY_DAYS);
+    assert_eq!(
+        authority
+            .complete_recovery(&recovery, completes_at - 1)
+            .unwrap_err(),
+        error(StakePoolError::RecoveryDelayActive)
+    );
+
+    assert_eq!(
+        authority
+            .complete_recovery(&recovery, completes_at)
+            .unwrap(),
+        lost
+    );
+    assert_eq!(authority.authority, recovery);
+    assert!(authority.is_authorized(&recovery));
+    // The new authority starts without a nomination or recovery key
+    assert_eq!(authority.pending_authority, None);
+    assert_eq!(authority.recovery_authority, None);
+    assert_eq!(authority.recovery_initiated_at, None);
+}
+
+#[test]
+fn test_only_the_recovery_authority_can_recover() {
+    let (mut authority, recovery) = recoverable();
+    let stranger = Pubkey::new_unique();
+
+    assert_eq!(
+        authority.initiate_recovery(&stranger, NOW).unwrap_err(),
+        error(StakePoolError::Unauthorized)
+    );
+    authority.initiate_recovery(&recovery, NOW).unwrap();
+    assert_eq!(
+        authority
+            .complete_recovery(&stranger, NOW + THIRTY_DAYS)
+            .unwrap_err(),
+        error(StakePoolError::Unauthorized)
+    );
+
+    // Without a recovery key nobody can initiate
+    let mut authority = program_authority();
+    assert_eq!(
+        authority.initiate_recovery(&recovery, NOW).unwrap_err(),
+        error(StakePoolError::Unauthorized)
+    );
+}
+
+#[test]
+fn test_recovery_must_be_initiated_first() {
+    let (mut authority, recovery) = recoverable();
+
+    assert_eq!(
+        authority
+            .complete_recovery(&recovery, NOW + THIRTY_DAYS)
+            .unwrap_err(),
+        error(StakePoolError::NoRecoveryInProgress)
+    );
+
+    // Initiating again would restart the delay
+    authority.initiate_recovery(&recovery, NOW).unwrap();
+    assert_eq!(
+        authority.initiate_recovery(&recovery, NOW + 1).unwrap_err(),
+        error(StakePoolError::InvalidParameters)
+    );
+    assert_eq!(authority.recovery_initiated_at, Some(NOW));
+}
+
+#[test]
+fn test_replacing_the_recovery_key_cancels_the_takeover() {
+    let (mut authority, recovery) = recoverable();
+    authority.initiate_recovery(&recovery, NOW).unwrap();
+
+    let replacement = Pubkey::new_unique();
+    authority
+        .set_recovery(Some(replacement), THIRTY_DAYS)
+        .unwrap();
+
+    assert_eq!(authority.recovery_initiated_at, None);
+    assert_eq!(
+        authority
+            .complete_recovery(&replacement, NOW + THIRTY_DAYS)
+            .unwrap_err(),
+        error(StakePoolError::NoRecoveryInProgress)
+    );
+}
+
+#[test]
+fn test_recovery_delay_is_bounded() {
+    let mut authority = program_authority();
+    let recovery = Pubkey::new_unique();
+
+    for delay in [0, MIN_RECOVERY_DELAY - 1, MAX_RECOVERY_DELAY + 1] {
+        assert_eq!(
+            authority.set_recovery(Some(recovery), delay).unwrap_err(),
+            error(StakePoolError::InvalidParameters)
+        );
+    }
+    authority
+        .set_recovery(Some(recovery), MIN_RECOVERY_DELAY)
+        .unwrap();
+    authority
+        .set_recovery(Some(recovery), MAX_RECOVERY_DELAY)
+        .unwrap();
+
+    // Removing recovery ignores the delay
+    authority.set_recovery(None, 0).unwrap();
+    assert_eq!(authority.recovery_authority, None);
+    assert_eq!(authority.recovery_delay, 0);
+}
+
+#[test]
+fn test_authority_cannot_be_its_own_recovery_key() {
+    let mut authority = program_authority();
+    let own_key = authority.authority;
+
+    assert_eq!(
+        authority
+            .set_recovery(Some(own_key), THIRTY_DAYS)
+            .unwrap_err(),
+        error(StakePoolError::InvalidParameters)
+    );
+}
@@ -0,0 +1,63 @@
+// ============================================================================
+// Referral Tests
+// ============================================================================
+// The pool's referral_bps share of each claim is carved out of the claimed
+// amount and paid to the referrer recorded on the stake account.
+
+mod common;
+
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{constants::MAX_REFERRAL_BPS, state::StakeAccount};
+
+use common::*;
+
+#[test]
+fn test_referral_share_is_bps_of_claim() {
+    let mut pool = sample_stake_pool();
+    pool.referral_bps = 500; // 5%
+
+    assert_eq!(pool.referral_share(100_000).unwrap(), 5_000);
+}
+
+#[test]
+fn test_referral_share_rounds_down() {
+    let mut pool = sample_stake_pool();
+    pool.referral_bps = 333; // 3.33%
+
+    // 99 * 333 / 10_000 = 3.2967 -> 3
+    assert_eq!(pool.referral_share(99).unwrap(), 3);
+}
+
+#[test]
+fn test_referral_share_disabled() {
+    let pool = sample_stake_pool();
+
+    assert_eq!(pool.referral_bps, 0);
+    assert_eq!(pool.referral_share(u64::MAX).unwrap(), 0);
+}
+
+#[test]
+fn test_referral_share_never_exceeds_claim() {
+    let mut pool = sample_stake_pool();
+    pool.referral_bps = MAX_REFERRAL_BPS;
+
+    let claim = u64::MAX;
+    let share = pool.referral_share(claim).unwrap();
+
+    assert!(share < claim);
+    assert_eq!(share, claim / 5);
+}
+
+#[test]
+fn test_stake_account_len_fits_referrer() {
+    let mut stake_account =
+        sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000);
+    stake_account.referrer = Some(Pubkey::new_unique());
+    stake_account.delegate = Some(Pubkey::new_unique());
+    stake_account.request_timestamp = Some(1_700_000_000);
+    stake_account.pending_owner = Some(Pubkey::new_unique());
+
+    let data = stake_account.to_account_data().unwrap();
+
+    assert_eq!(data.len(), StakeAccount::LEN);
+}
@@ -0,0 +1,124 @@
+// ============================================================================
+// Auto Re-lock Tests
+// ============================================================================
+// Stake accounts with auto_relock set can have their expired lockup restarted
+// by the permissionless Relock crank. Unclaimed rewards of the completed round
+// are carried over and the new round's reward is reserved in total_rewards_owed.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{error::StakePoolError, state::StakeAccount};
+
+use common::*;
+
+const STAKED: u64 = 1_000_000;
+const ROUND_REWARD: u64 = 100_000; // 10% of STAKED
+
+fn relockable_stake() -> StakeAccount {
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), STAKED);
+    stake.auto_relock = true;
+    stake
+}
+
+#[test]
+fn test_relock_requires_opt_in() {
+    let mut pool = sample_stake_pool();
+    let mut stake = relockable_stake();
+    stake.auto_relock = false;
+    let matured = stake.stake_timestamp + pool.lockup_period;
+
+    let err = pool.relock_stake(&mut stake, matured).unwrap_err();
+
+    assert_eq!(
+        err,
+        ProgramError::Custom(StakePoolError::AutoRelockDisabled as u32)
+    );
+}
+
+#[test]
+fn test_relock_requires_expired_lockup() {
+    let mut pool = sample_stake_pool();
+    let mut stake = relockable_stake();
+    let before_maturity = stake.stake_timestamp + pool.lockup_period - 1;
+
+    let err = pool.relock_stake(&mut stake, before_maturity).unwrap_err();
+
+    assert_eq!(
+        err,
+        ProgramError::Custom(StakePoolError::LockupNotExpired as u32)
+    );
+}
+
+#[test]
+fn test_relock_carries_unclaimed_rewards_and_reserves_new_round() {
+    let mut pool = sample_stake_pool();
+    pool.total_rewards_owed = ROUND_REWARD;
+    let mut stake = relockable_stake();
+    stake.early_unstaked = 10_000;
+    let now = stake.stake_timestamp + pool.lockup_period + 3_600;
+
+    let reserved = pool.relock_stake(&mut stake, now).unwrap();
+
+    assert_eq!(reserved, ROUND_REWARD);
+    assert_eq!(pool.total_rewards_owed, 2 * ROUND_REWARD);
+    assert_eq!(stake.carried_rewards, ROUND_REWARD);
+    assert_eq!(stake.claimed_rewards, 0);
+    assert_eq!(stake.stake_timestamp, now);
+    assert_eq!(stake.early_unstaked, 0);
+
+    // Carried rewards are claimable right away, the new round only after it matures
+    assert_eq!(pool.stake_rewards(&stake, now).unwrap(), ROUND_REWARD);
+    let next_maturity = pool.maturity_timestamp(now).unwrap();
+    assert_eq!(
+        pool.stake_rewards(&stake, next_maturity).unwrap(),
+        2 * ROUND_REWARD
+    );
+}
+
+#[test]
+fn test_relock_after_claim_carries_nothing() {
+    let mut pool = sample_stake_pool();
+    pool.total_rewards_owed = ROUND_REWARD;
+    let mut stake = relockable_stake();
+    let now = stake.stake_timestamp + pool.lockup_period;
+
+    // Owner claimed the matured round before the crank ran
+    stake.claimed_rewards = ROUND_REWARD;
+    pool.total_rewards_owed -= ROUND_REWARD;
+
+    pool.relock_stake(&mut stake, now).unwrap();
+
+    assert_eq!(stake.carried_rewards, 0);
+    assert_eq!(stake.claimed_rewards, 0);
+    assert_eq!(pool.total_rewards_owed, ROUND_REWARD);
+}
+
+#[test]
+fn test_relocked_reservation_fails_solvency_when_vault_short() {
+    let mut pool = sample_stake_pool();
+    pool.total_rewards_owed = ROUND_REWARD;
+    let mut stake = relockable_stake();
+    let now = stake.stake_timestamp + pool.lockup_period;
+
+    pool.relock_stake(&mut stake, now).unwrap();
+
+    // Vault funded for the first round only
+    assert!(pool.verify_solvency(ROUND_REWARD).is_err());
+    assert!(pool.verify_solvency(2 * ROUND_REWARD).is_ok());
+}
+
+#[test]
+fn test_stake_account_len_fits_relock_fields() {
+    let mut stake = relockable_stake();
+    stake.referrer = Some(Pubkey::new_unique());
+    stake.delegate = Some(Pubkey::new_unique());
+    stake.request_timestamp = Some(i64::MAX);
+    stake.carried_rewards = u64::MAX;
+    stake.pending_owner = Some(Pubkey::new_unique());
+
+    let data = stake.to_account_data().unwrap();
+
+    assert_eq!(data.len(), StakeAccount::LEN);
+}
@@ -0,0 +1,231 @@
+// ============================================================================
+// Reward Cohort Tests
+// ============================================================================
+// Pools with a cohort_duration pay each stake's rewards from the vault of the
+// cohort it was opened in. The pool's reward vault then owes nothing, and the
+// rewards cohort stakes forfeit stay in their cohort vault.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    constants::{
+        MAX_REWARD_COHORT_DURATION, MIN_REWARD_COHORT_DURATION, REWARD_EXPIRY_PERIOD,
+        WIND_DOWN_DELAY,
+    },
+    error::StakePoolError,
+    state::{Key, RewardCohort, StakeAccount, StakePool, WindDownRewards},
+};
+
+use common::*;
+
+const WEEK: i64 = 7 * 86400;
+
+/// A weekly cohort pool holding one stake of the current cohort
+fn setup() -> (StakePool, StakeAccount) {
+    let mut pool = sample_stake_pool();
+    pool.set_cohort_duration(WEEK).unwrap();
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000_000);
+    stake.reward_cohort = pool.reward_cohort_at(stake.stake_timestamp);
+    pool.total_staked = 1_000_000;
+    pool.total_rewards_owed = 100_000;
+    pool.open_reward_cohorts = 1;
+    (pool, stake)
+}
+
+fn sample_cohort(rewards_owed: u64) -> RewardCohort {
+    RewardCohort {
+        key: Key::RewardCohort,
+        pool: Pubkey::new_unique(),
+        cohort: 2810,
+        rewards_funded: 500_000,
+        rewards_owed,
+        bump: 255,
+        vault_bump: 254,
+    }
+}
+
+fn assert_error(err: ProgramError, expected: StakePoolError) {
+    assert_eq!(err, ProgramError::Custom(expected as u32));
+}
+
+#[test]
+fn test_stakes_fall_in_the_cohort_of_their_stake_time() {
+    let mut pool = sample_stake_pool();
+    assert_eq!(pool.reward_cohort_at(SAMPLE_STAKE_TIMESTAMP), 0);
+
+    pool.set_cohort_duration(WEEK).unwrap();
+    let cohort = pool.reward_cohort_at(SAMPLE_STAKE_TIMESTAMP);
+    assert_eq!(cohort, (SAMPLE_STAKE_TIMESTAMP / WEEK) as u64);
+    assert_eq!(pool.reward_cohort_at(cohort as i64 * WEEK), cohort);
+    assert_eq!(
+        pool.reward_cohort_at((cohort as i64 + 1) * WEEK - 1),
+        cohort
+    );
+    assert_eq!(
+        pool.reward_cohort_at((cohort as i64 + 1) * WEEK),
+        cohort + 1
+    );
+}
+
+#[test]
+fn test_cohort_duration_is_bounded() {
+    let mut pool = sample_stake_pool();
+
+    let err = pool
+        .set_cohort_duration(MIN_REWARD_COHORT_DURATION - 1)
+        .unwrap_err();
+    assert_error(err, StakePoolError::InvalidParameters);
+    assert_error(
+        pool.set_cohort_duration(-WEEK).unwrap_err(),
+        StakePoolError::InvalidParameters,
+    );
+
+    pool.set_cohort_duration(MIN_REWARD_COHORT_DURATION)
+        .unwrap();
+    assert_eq!(pool.cohort_duration, MIN_REWARD_COHORT_DURATION);
+
+    // Longer cohorts would put every stake in cohort 0, the pool's own reward vault
+    assert_error(
+        pool.set_cohort_duration(MAX_REWARD_COHORT_DURATION + 1)
+            .unwrap_err(),
+        StakePoolError::InvalidParameters,
+    );
+    pool.set_cohort_duration(MAX_REWARD_COHORT_DURATION)
+        .unwrap();
+    assert_ne!(pool.reward_cohort_at(SAMPLE_STAKE_TIMESTAMP), 0);
+    pool.set_cohort_duration(0).unwrap();
+    assert_eq!(pool.cohort_duration, 0);
+}
+
+#[test]
+fn test_cohort_duration_only_changes_while_the_pool_is_empty() {
+    let (mut pool, _) = setup();
+    assert_error(
+        pool.set_cohort_duration(0).unwrap_err(),
+        StakePoolError::InvalidParameters,
+    );
+
+    pool.total_staked = 0;
+    pool.total_rewards_owed = 0;
+    // A cohort vault left open still holds the old cohorts' tokens
+    assert_error(
+        pool.set_cohort_duration(0).unwrap_err(),
+        StakePoolError::InvalidParameters,
+    );
+
+    pool.open_reward_cohorts = 0;
+    pool.set_cohort_duration(0).unwrap();
+    assert_eq!(pool.cohort_duration, 0);
+}
+
+#[test]
+fn test_reward_vault_owes_nothing_in_cohort_pools() {
+    let (mut pool, _) = setup();
+    assert_eq!(pool.reward_vault_owed(), 0);
+    assert_eq!(pool.reward_surplus(40_000), 40_000);
+    assert!(pool.is_solvent(1_000_000, 0));
+
+    pool.cohort_duration = 0;
+    assert_eq!(pool.reward_vault_owed(), 100_000);
+    assert_eq!(pool.reward_surplus(140_000), 40_000);
+    assert!(!pool.is_solvent(1_000_000, 0));
+}
+
+#[test]
+fn test_cohort_reserves_only_what_its_vault_covers() {
+    let mut cohort = sample_cohort(300_000);
+
+    let err = cohort.reserve(200_001, 500_000).unwrap_err();
+    assert_error(err, StakePoolError::InsufficientRewards);
+    assert_eq!(cohort.rewards_owed, 300_000);
+
+    cohort.reserve(200_000, 500_000).unwrap();
+    assert_eq!(cohort.rewards_owed, 500_000);
+
+    cohort.release(120_000);
+    assert_eq!(cohort.rewards_owed, 380_000);
+    cohort.release(u64::MAX);
+    assert_eq!(cohort.rewards_owed, 0);
+}
+
+#[test]
+fn test_cohort_surplus_frees_forfeited_rewards_once_the_pool_owes_nothing() {
+    let cohort = sample_cohort(300_000);
+
+    assert_eq!(cohort.surplus(450_000, false), 150_000);
+    assert_eq!(cohort.surplus(250_000, false), 0);
+    assert_eq!(cohort.surplus(250_000, true), 250_000);
+}
+
+#[test]
+fn test_cohort_stakes_cannot_reserve_more_rewards() {
+    let (mut pool, mut stake) = setup();
+    stake.auto_relock = true;
+    pool.lockup_extension_rate = 50_000_000;
+    let matured = SAMPLE_STAKE_TIMESTAMP + pool.lockup_period;
+
+    let err = pool.relock_stake(&mut stake, matured).unwrap_err();
+    assert_error(err, StakePoolError::InvalidRewardCohort);
+    let err = pool
+        .extend_lockup(&mut stake, 86400, SAMPLE_STAKE_TIMESTAMP + 60)
+        .unwrap_err();
+    assert_error(err, StakePoolError::InvalidRewardCohort);
+    assert_eq!(pool.total_rewards_owed, 100_000);
+}
+
+#[test]
+fn test_expired_cohort_rewards_stay_in_the_cohort_vault() {
+    let (mut pool, mut stake) = setup();
+    let expired_at = SAMPLE_STAKE_TIMESTAMP + pool.lockup_period + REWARD_EXPIRY_PERIOD;
+
+    let expired = pool.expire_stake_rewards(&mut stake, expired_at).unwrap();
+
+    assert_eq!(expired, 100_000);
+    assert_eq!(stake.claimed_rewards, 100_000);
+    assert_eq!(pool.total_rewards_owed, 0);
+    assert_eq!(pool.expired_rewards, 0);
+}
+
+#[test]
+fn test_wind_down_forfeits_cohort_rewards() {
+    let (mut pool, mut stake) = setup();
+    pool.wind_down_rewards = WindDownRewards::Pay;
+    pool.pool_end_date = Some(SAMPLE_STAKE_TIMESTAMP + 2 * 86400);
+    let now = SAMPLE_STAKE_TIMESTAMP + 2 * 86400 + WIND_DOWN_DELAY;
+
+    let wind_down = pool.wind_down_stake(&mut stake, now).unwrap();
+
+    assert_eq!(wind_down.principal, 1_000_000);
+    assert_eq!(wind_down.rewards_paid, 0);
+    assert_eq!(wind_down.rewards_expired, 0);
+    assert_eq!(pool.total_staked, 0);
+    assert_eq!(pool.total_rewards_owed, 0);
+    assert_eq!(pool.expired_rewards, 0);
+}
+
+#[test]
+fn test_pool_with_open_cohorts_is_not_closable() {
+    let (mut pool, _) = setup();
+    pool.is_paused = true;
+    pool.total_staked = 0;
+    pool.total_rewards_owed = 0;
+    assert!(!pool.is_closable());
+
+    pool.open_reward_cohorts = 0;
+    assert!(pool.is_closable());
+}
+
+#[test]
+fn test_stakes_of_different_cohorts_cannot_be_merged() {
+    let (mut pool, mut stake) = setup();
+    let mut source = stake.clone();
+    source.index = 1;
+    source.reward_cohort += 1;
+
+    let err = pool
+        .merge_stakes(&mut stake, &source, SAMPLE_STAKE_TIMESTAMP + 60)
+        .unwrap_err();
+    assert_error(err, StakePoolError::StakesNotMergeable);
+}
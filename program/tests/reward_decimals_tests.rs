@@ -0,0 +1,116 @@
+// ============================================================================
+// Reward Decimals Tests
+// ============================================================================
+// A pool reads both mints' decimals at initialization and stores their
+// difference (reward_decimals_shift). New rounds lock in the reward rate scaled
+// by 10^shift, so 10% of one whole stake token pays a tenth of a whole reward
+// token however many decimals each mint has.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    constants::{MAX_DECIMALS_SHIFT, SECONDS_PER_YEAR},
+    error::StakePoolError,
+    state::{RewardRateMode, StakePool},
+};
+
+use common::*;
+
+const NOW: i64 = 1_700_000_000;
+
+/// A 10% pool on a stake mint and a reward mint with these decimals
+fn pool_with_decimals(stake_decimals: u8, reward_decimals: u8) -> StakePool {
+    let mut pool = sample_stake_pool();
+    pool.reward_decimals_shift =
+        StakePool::decimals_shift(stake_decimals, reward_decimals).unwrap();
+    pool
+}
+
+#[test]
+fn test_same_decimals_pay_in_base_units() {
+    let pool = pool_with_decimals(6, 6);
+
+    assert_eq!(pool.reward_decimals_shift, 0);
+    assert_eq!(pool.expected_rewards(1_000_000, NOW).unwrap(), 100_000);
+}
+
+#[test]
+fn test_reward_mint_with_more_decimals() {
+    let pool = pool_with_decimals(6, 9);
+    assert_eq!(pool.reward_decimals_shift, 3);
+
+    // 1 stake token (1e6 units) earns 0.1 reward token (1e8 units)
+    assert_eq!(pool.expected_rewards(1_000_000, NOW).unwrap(), 100_000_000);
+    assert_eq!(pool.tiered_reward_rate(1_000_000).unwrap(), 100_000_000_000);
+}
+
+#[test]
+fn test_reward_mint_with_fewer_decimals() {
+    let pool = pool_with_decimals(9, 6);
+    assert_eq!(pool.reward_decimals_shift, -3);
+
+    // 1 stake token (1e9 units) earns 0.1 reward token (1e5 units)
+    assert_eq!(pool.expected_rewards(1_000_000_000, NOW).unwrap(), 100_000);
+    assert_eq!(pool.tiered_reward_rate(1_000_000_000).unwrap(), 100_000);
+}
+
+#[test]
+fn test_stakes_lock_in_the_normalized_rate() {
+    let pool = pool_with_decimals(0, 9);
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 20);
+    stake.locked_reward_rate = pool.stake_reward_rate(20, NOW).unwrap();
+
+    // 10% of 20 whole tokens is 2 whole reward tokens
+    assert_eq!(stake.round_rewards().unwrap(), 2_000_000_000);
+    assert_eq!(
+        pool.calculate_rewards(&stake, stake.stake_timestamp + pool.lockup_period)
+            .unwrap(),
+        2_000_000_000
+    );
+}
+
+#[test]
+fn test_annualized_rates_are_normalized_too() {
+    let mut pool = pool_with_decimals(9, 6);
+    pool.reward_rate_mode = RewardRateMode::Annualized;
+    pool.lockup_period = SECONDS_PER_YEAR / 4;
+
+    // A quarter of 10% a year on 4 stake tokens is 0.1 reward token
+    assert_eq!(pool.expected_rewards(4_000_000_000, NOW).unwrap(), 100_000);
+}
+
+#[test]
+fn test_lockup_extension_bonus_is_normalized() {
+    let mut pool = pool_with_decimals(6, 9);
+    pool.lockup_extension_rate = 100_000_000; // 10% a year
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000_000);
+    stake.locked_reward_rate = pool.stake_reward_rate(1_000_000, NOW).unwrap();
+    let now = stake.stake_timestamp;
+
+    let bonus = pool
+        .extend_lockup(&mut stake, SECONDS_PER_YEAR, now)
+        .unwrap();
+
+    // A year at 10% more on 1 stake token is another 0.1 reward token
+    assert_eq!(bonus, 100_000_000);
+    assert_eq!(stake.round_rewards().unwrap(), 200_000_000);
+}
+
+#[test]
+fn test_decimals_too_far_apart_are_rejected() {
+    assert_eq!(
+        StakePool::decimals_shift(0, MAX_DECIMALS_SHIFT).unwrap(),
+        MAX_DECIMALS_SHIFT as i8
+    );
+    assert_eq!(
+        StakePool::decimals_shift(MAX_DECIMALS_SHIFT, 0).unwrap(),
+        -(MAX_DECIMALS_SHIFT as i8)
+    );
+    assert_eq!(
+        StakePool::decimals_shift(0, MAX_DECIMALS_SHIFT + 1).unwrap_err(),
+        ProgramError::Custom(StakePoolError::InvalidParameters as u32)
+    );
+    assert!(StakePool::decimals_shift(255, 0).is_err());
+}
@@ -0,0 +1,86 @@
+// ============================================================================
+// Reward Expiry Tests
+// ============================================================================
+// Rewards left unclaimed for REWARD_EXPIRY_PERIOD after a stake's lockup
+// completes can be moved out of total_rewards_owed into the pool's
+// expired_rewards, which governance withdraws with ClaimExpiredRewards.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{constants::REWARD_EXPIRY_PERIOD, error::StakePoolError};
+
+use common::*;
+
+const STAKED: u64 = 1_000_000;
+// sample_stake_pool pays 10% after its lockup
+const REWARDS: u64 = 100_000;
+
+fn expiry_time() -> i64 {
+    let pool = sample_stake_pool();
+    let stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), STAKED);
+    pool.maturity_timestamp(stake.stake_timestamp).unwrap() + REWARD_EXPIRY_PERIOD
+}
+
+#[test]
+fn test_rewards_cannot_expire_before_period() {
+    let mut pool = sample_stake_pool();
+    pool.total_rewards_owed = REWARDS;
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), STAKED);
+
+    let err = pool
+        .expire_stake_rewards(&mut stake, expiry_time() - 1)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ProgramError::Custom(StakePoolError::RewardsNotExpired as u32)
+    );
+    assert_eq!(pool.total_rewards_owed, REWARDS);
+    assert_eq!(pool.expired_rewards, 0);
+}
+
+#[test]
+fn test_expiry_moves_unclaimed_rewards_to_bucket() {
+    let mut pool = sample_stake_pool();
+    pool.total_rewards_owed = REWARDS + 50_000;
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), STAKED);
+    stake.carried_rewards = 20_000;
+    stake.claimed_rewards = 20_000;
+
+    let expired = pool
+        .expire_stake_rewards(&mut stake, expiry_time())
+        .unwrap();
+
+    assert_eq!(expired, REWARDS);
+    assert_eq!(pool.total_rewards_owed, 50_000);
+    assert_eq!(pool.expired_rewards, REWARDS);
+    // The owner keeps the principal but has nothing left to claim
+    assert_eq!(stake.amount_staked, STAKED);
+    assert_eq!(stake.claimed_rewards, REWARDS + 20_000);
+}
+
+#[test]
+fn test_expiry_requires_unclaimed_rewards() {
+    let mut pool = sample_stake_pool();
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), STAKED);
+    stake.claimed_rewards = REWARDS;
+
+    let err = pool
+        .expire_stake_rewards(&mut stake, expiry_time())
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ProgramError::Custom(StakePoolError::NoExpiredRewards as u32)
+    );
+}
+
+#[test]
+fn test_expired_rewards_are_not_defundable_surplus() {
+    let mut pool = sample_stake_pool();
+    pool.total_rewards_owed = 300_000;
+    pool.expired_rewards = 100_000;
+
+    assert_eq!(pool.reward_surplus(500_000), 100_000);
+    assert_eq!(pool.reward_surplus(350_000), 0);
+}
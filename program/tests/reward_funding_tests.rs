@@ -0,0 +1,62 @@
+// ============================================================================
+// Reward Funding Tests
+// ============================================================================
+// FundRewards can be restricted to an allowlist of funders and records the
+// amount funded; DefundRewards may only withdraw the reward vault's surplus
+// over total_rewards_owed.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{error::StakePoolError, state::StakePool};
+
+use common::*;
+
+#[test]
+fn test_empty_allowlist_allows_any_funder() {
+    let pool = sample_stake_pool();
+
+    assert!(pool.is_reward_funder_allowed(&Pubkey::new_unique()));
+}
+
+#[test]
+fn test_allowlist_restricts_funders() {
+    let mut pool = sample_stake_pool();
+    let funder = Pubkey::new_unique();
+    pool.set_reward_funders(&[funder]).unwrap();
+
+    assert!(pool.is_reward_funder_allowed(&funder));
+    assert!(!pool.is_reward_funder_allowed(&Pubkey::new_unique()));
+
+    // Clearing the list re-opens funding
+    pool.set_reward_funders(&[]).unwrap();
+    assert!(pool.is_reward_funder_allowed(&Pubkey::new_unique()));
+}
+
+#[test]
+fn test_allowlist_rejects_too_many_funders() {
+    let mut pool = sample_stake_pool();
+    let funders: Vec<Pubkey> = (0..=StakePool::MAX_REWARD_FUNDERS)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+
+    let err = pool.set_reward_funders(&funders).unwrap_err();
+
+    assert_eq!(
+        err,
+        ProgramError::Custom(StakePoolError::InvalidParameters as u32)
+    );
+    assert!(pool.reward_funders.iter().all(Option::is_none));
+}
+
+#[test]
+fn test_reward_surplus_excludes_owed_rewards() {
+    let mut pool = sample_stake_pool();
+    pool.total_rewards_owed = 100_000;
+
+    assert_eq!(pool.reward_surplus(150_000), 50_000);
+    assert_eq!(pool.reward_surplus(100_000), 0);
+    // An underfunded vault has no surplus rather than underflowing
+    assert_eq!(pool.reward_surplus(60_000), 0);
+}
@@ -0,0 +1,71 @@
+// ============================================================================
+// Reward Rate Bounds Tests
+// ============================================================================
+// A pool may commit at initialization to a reward rate range. UpdatePool
+// proposals outside it are rejected, giving stakers an on-chain guarantee of
+// the rates the pool can ever pay.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use your_wallet_stake_pool::{error::StakePoolError, state::StakePool};
+
+use common::*;
+
+const FIVE_PERCENT: u64 = 50_000_000;
+const TEN_PERCENT: u64 = 100_000_000;
+const TWENTY_PERCENT: u64 = 200_000_000;
+
+#[test]
+fn test_unbounded_pools_accept_any_rate() {
+    let pool = sample_stake_pool();
+    assert_eq!(pool.min_reward_rate_bound, None);
+    assert_eq!(pool.max_reward_rate_bound, None);
+
+    pool.validate_reward_rate_bounds(0).unwrap();
+    pool.validate_reward_rate_bounds(u64::MAX).unwrap();
+}
+
+#[test]
+fn test_rates_outside_the_bounds_are_rejected() {
+    let mut pool = sample_stake_pool();
+    pool.min_reward_rate_bound = Some(FIVE_PERCENT);
+    pool.max_reward_rate_bound = Some(TWENTY_PERCENT);
+
+    // The bounds themselves are inclusive
+    pool.validate_reward_rate_bounds(FIVE_PERCENT).unwrap();
+    pool.validate_reward_rate_bounds(TEN_PERCENT).unwrap();
+    pool.validate_reward_rate_bounds(TWENTY_PERCENT).unwrap();
+
+    for rate in [0, FIVE_PERCENT - 1, TWENTY_PERCENT + 1] {
+        assert_eq!(
+            pool.validate_reward_rate_bounds(rate).unwrap_err(),
+            ProgramError::Custom(StakePoolError::RewardRateOutOfBounds as u32)
+        );
+    }
+}
+
+#[test]
+fn test_one_sided_bounds() {
+    assert!(StakePool::reward_rate_within_bounds(
+        u64::MAX,
+        Some(TEN_PERCENT),
+        None
+    ));
+    assert!(!StakePool::reward_rate_within_bounds(
+        FIVE_PERCENT,
+        Some(TEN_PERCENT),
+        None
+    ));
+
+    assert!(StakePool::reward_rate_within_bounds(
+        0,
+        None,
+        Some(TEN_PERCENT)
+    ));
+    assert!(!StakePool::reward_rate_within_bounds(
+        TWENTY_PERCENT,
+        None,
+        Some(TEN_PERCENT)
+    ));
+}
@@ -8,8 +8,14 @@ use solana_sdk::{
 
 mod common;
 use common::*;
+use your_wallet_stake_pool::constants::{
+    DEVNET_REWARD_RATE_CHANGE_DELAY, MAX_REWARD_RATE_CHANGE_DELAY, MIN_REWARD_RATE_CHANGE_DELAY,
+    REWARD_RATE_CHANGE_DELAY,
+};
 use your_wallet_stake_pool::instruction::StakePoolInstruction;
-use your_wallet_stake_pool::state::StakePool;
+use your_wallet_stake_pool::state::{
+    ClusterProfile, OutflowWindow, RewardRateMode, StakePool, WindDownRewards,
+};
 
 /// Helper to create update_pool instruction
 fn create_update_pool_ix(
@@ -24,6 +30,26 @@ fn create_update_pool_ix(
         is_paused: None,
         enforce_lockup: None,
         pool_end_date: None,
+        referral_bps: None,
+        early_unstake_bps: None,
+        deposit_fee_bps: None,
+        withdraw_fee_bps: None,
+        unstake_cooldown: None,
+        bonus_end_ts: None,
+        bonus_multiplier_bps: None,
+        lockup_extension_rate: None,
+        allowlist_merkle_root: None,
+        claim_window: None,
+        outflow_limit: None,
+        pool_manager: None,
+        keeper_bounty: None,
+        max_total_rewards: None,
+        enforce_min_remaining_stake: None,
+        wind_down_rewards: None,
+        points_rate: None,
+        deny_list_required: None,
+        allow_third_party_stake: None,
+        cohort_duration: None,
     };
 
     Instruction {
@@ -42,7 +68,11 @@ fn create_finalize_reward_rate_change_ix(pool: &Pubkey) -> Instruction {
 
     Instruction {
         program_id: PROGRAM_ID.parse::<Pubkey>().unwrap(),
-        accounts: vec![AccountMeta::new(*pool, false)],
+        accounts: vec![
+            AccountMeta::new(*pool, false),
+            AccountMeta::new_readonly(get_program_authority_pda().0, false),
+            AccountMeta::new_readonly(PROGRAM_ID.parse::<Pubkey>().unwrap(), false),
+        ],
         data: borsh::to_vec(&data).unwrap(),
     }
 }
@@ -111,12 +141,72 @@ fn test_finalize_too_early_fails() {
 /// Test that REWARD_RATE_CHANGE_DELAY constant is 7 days
 #[test]
 fn test_delay_constant_is_seven_days() {
-    // The constant should be 604800 seconds (7 days)
-    const EXPECTED_DELAY: i64 = 604800;
+    assert_eq!(REWARD_RATE_CHANGE_DELAY, 7 * 24 * 60 * 60);
+    assert_eq!(
+        ClusterProfile::Mainnet.reward_rate_change_delay(),
+        REWARD_RATE_CHANGE_DELAY
+    );
+}
+
+/// Devnet-profile deployments use the shortened time-lock
+#[test]
+fn test_devnet_profile_shortens_delay() {
+    assert_eq!(
+        ClusterProfile::Devnet.reward_rate_change_delay(),
+        DEVNET_REWARD_RATE_CHANGE_DELAY
+    );
+    assert!(
+        ClusterProfile::Devnet.reward_rate_change_delay()
+            < ClusterProfile::Mainnet.reward_rate_change_delay()
+    );
+}
+
+/// Pools created without their own delay keep the deployment's default
+#[test]
+fn test_pool_delay_falls_back_to_profile_default() {
+    let mut pool = sample_stake_pool();
+    pool.reward_rate_change_delay = 0;
+    assert_eq!(
+        pool.rate_change_delay(ClusterProfile::Mainnet),
+        REWARD_RATE_CHANGE_DELAY
+    );
+    assert_eq!(
+        pool.rate_change_delay(ClusterProfile::Devnet),
+        DEVNET_REWARD_RATE_CHANGE_DELAY
+    );
 
-    // This verifies the constant is properly set
-    // In actual code, the constant is defined in processor/admin.rs
-    assert_eq!(EXPECTED_DELAY, 7 * 24 * 60 * 60);
+    // A pool's own delay applies on every profile
+    pool.reward_rate_change_delay = 2 * 24 * 60 * 60;
+    for profile in [ClusterProfile::Mainnet, ClusterProfile::Devnet] {
+        assert_eq!(pool.rate_change_delay(profile), 2 * 24 * 60 * 60);
+    }
+}
+
+/// Pool delays are bounded: at least 24h on Mainnet, at most 30 days
+#[test]
+fn test_pool_delay_bounds() {
+    let mainnet = ClusterProfile::Mainnet;
+    assert_eq!(MIN_REWARD_RATE_CHANGE_DELAY, 24 * 60 * 60);
+    assert!(mainnet
+        .validate_reward_rate_change_delay(MIN_REWARD_RATE_CHANGE_DELAY)
+        .is_ok());
+    assert!(mainnet
+        .validate_reward_rate_change_delay(MAX_REWARD_RATE_CHANGE_DELAY)
+        .is_ok());
+    assert!(mainnet
+        .validate_reward_rate_change_delay(MIN_REWARD_RATE_CHANGE_DELAY - 1)
+        .is_err());
+    assert!(mainnet
+        .validate_reward_rate_change_delay(MAX_REWARD_RATE_CHANGE_DELAY + 1)
+        .is_err());
+
+    // Devnet keeps its short testing delay as the floor
+    assert!(ClusterProfile::Devnet
+        .validate_reward_rate_change_delay(DEVNET_REWARD_RATE_CHANGE_DELAY)
+        .is_ok());
+    assert!(mainnet
+        .validate_reward_rate_change_delay(DEVNET_REWARD_RATE_CHANGE_DELAY)
+        .is_err());
 }
 
 /// Test structure validation - ensure new fields exist
@@ -124,7 +214,7 @@ fn test_delay_constant_is_seven_days() {
 fn test_stake_pool_structure_has_new_fields() {
     // Create a mock StakePool to verify the structure
     let pool = StakePool {
-        key: your_wallet_stake_pool::state::Key::StakePool,
+        key: your_wallet_stake_pool::state::Key::StakePoolV2,
         stake_mint: Pubkey::new_unique(),
         reward_mint: Pubkey::new_unique(),
         pool_id: 0,
@@ -142,13 +232,59 @@ fn test_stake_pool_structure_has_new_fields() {
         pending_reward_rate: Some(50_000_000),
         reward_rate_change_timestamp: Some(1700000000),
         last_rate_change: None,
-        _reserved: [0; 7],
+        referral_bps: 0,
+        early_unstake_bps: 0,
+        stake_vault_bump: None,
+        reward_vault_bump: None,
+        fee_vault: Pubkey::new_unique(),
+        deposit_fee_bps: 0,
+        withdraw_fee_bps: 0,
+        fee_vault_bump: None,
+        total_rewards_funded: 0,
+        reward_funders: [None; StakePool::MAX_REWARD_FUNDERS],
+        receipt_mint: None,
+        expired_rewards: 0,
+        unstake_cooldown: 0,
+        is_registered: false,
+        reward_rate_change_delay: 0,
+        pending_lockup_period: None,
+        pending_enforce_lockup: None,
+        lockup_change_timestamp: None,
+        min_reward_rate_bound: None,
+        max_reward_rate_bound: None,
+        active_stake_count: None,
+        reward_tiers: [None; StakePool::MAX_REWARD_TIERS],
+        bonus_end_ts: None,
+        bonus_multiplier_bps: 0,
+        lockup_extension_rate: 0,
+        allowlist_required: false,
+        allowlist_merkle_root: None,
+        claim_window: None,
+        outflow_limit: None,
+        outflow_window: OutflowWindow::default(),
+        pool_manager: None,
+        keeper_bounty: 0,
+        last_keeper_bounty_at: 0,
+        max_total_rewards: None,
+        total_rewards_committed: 0,
+        reward_rate_mode: RewardRateMode::PerLockup,
+        reward_decimals_shift: 0,
+        enforce_min_remaining_stake: false,
+        wind_down_rewards: WindDownRewards::Expire,
+        hook_program: None,
+        points_rate: 0,
+        deny_list_required: false,
+        allow_third_party_stake: false,
+        event_nonce: 0,
+        cohort_duration: 0,
+        open_reward_cohorts: 0,
+        _reserved: [0; 2],
     };
 
     // Verify new fields are accessible
     assert_eq!(pool.pending_reward_rate, Some(50_000_000));
     assert_eq!(pool.reward_rate_change_timestamp, Some(1700000000));
-    assert_eq!(pool._reserved.len(), 7); // Verify reduced from 16 to 7
+    assert_eq!(pool._reserved.len(), 2); // Verify reduced from 16 to 2
 }
 
 /// Test that instruction enum has FinalizeRewardRateChange variant
@@ -196,7 +332,7 @@ fn test_finalize_validates_rate_bounds() {
     // Create a StakePool with an invalid pending rate
     // This simulates the scenario where validation logic changed after proposal
     let pool = StakePool {
-        key: your_wallet_stake_pool::state::Key::StakePool,
+        key: your_wallet_stake_pool::state::Key::StakePoolV2,
         stake_mint: Pubkey::new_unique(),
         reward_mint: Pubkey::new_unique(),
         pool_id: 0,
@@ -214,7 +350,53 @@ fn test_finalize_validates_rate_bounds() {
         pending_reward_rate: Some(2_000_000_000_000), // Invalid: > 1_000_000_000_000
         reward_rate_change_timestamp: Some(1700000000),
         last_rate_change: None,
-        _reserved: [0; 7],
+        referral_bps: 0,
+        early_unstake_bps: 0,
+        stake_vault_bump: None,
+        reward_vault_bump: None,
+        fee_vault: Pubkey::new_unique(),
+        deposit_fee_bps: 0,
+        withdraw_fee_bps: 0,
+        fee_vault_bump: None,
+        total_rewards_funded: 0,
+        reward_funders: [None; StakePool::MAX_REWARD_FUNDERS],
+        receipt_mint: None,
+        expired_rewards: 0,
+        unstake_cooldown: 0,
+        is_registered: false,
+        reward_rate_change_delay: 0,
+        pending_lockup_period: None,
+        pending_enforce_lockup: None,
+        lockup_change_timestamp: None,
+        min_reward_rate_bound: None,
+        max_reward_rate_bound: None,
+        active_stake_count: None,
+        reward_tiers: [None; StakePool::MAX_REWARD_TIERS],
+        bonus_end_ts: None,
+        bonus_multiplier_bps: 0,
+        lockup_extension_rate: 0,
+        allowlist_required: false,
+        allowlist_merkle_root: None,
+        claim_window: None,
+        outflow_limit: None,
+        outflow_window: OutflowWindow::default(),
+        pool_manager: None,
+        keeper_bounty: 0,
+        last_keeper_bounty_at: 0,
+        max_total_rewards: None,
+        total_rewards_committed: 0,
+        reward_rate_mode: RewardRateMode::PerLockup,
+        reward_decimals_shift: 0,
+        enforce_min_remaining_stake: false,
+        wind_down_rewards: WindDownRewards::Expire,
+        hook_program: None,
+        points_rate: 0,
+        deny_list_required: false,
+        allow_third_party_stake: false,
+        event_nonce: 0,
+        cohort_duration: 0,
+        open_reward_cohorts: 0,
+        _reserved: [0; 2],
     };
 
     // Verify the pending rate exceeds the maximum
@@ -229,7 +411,7 @@ fn test_finalize_validates_rate_bounds() {
 fn test_propose_current_rate_cancels_pending() {
     // Create a StakePool with a pending rate change
     let pool = StakePool {
-        key: your_wallet_stake_pool::state::Key::StakePool,
+        key: your_wallet_stake_pool::state::Key::StakePoolV2,
         stake_mint: Pubkey::new_unique(),
         reward_mint: Pubkey::new_unique(),
         pool_id: 0,
@@ -247,7 +429,53 @@ fn test_propose_current_rate_cancels_pending() {
         pending_reward_rate: Some(50_000_000), // Pending different rate
         reward_rate_change_timestamp: Some(1700000000),
         last_rate_change: None,
-        _reserved: [0; 7],
+        referral_bps: 0,
+        early_unstake_bps: 0,
+        stake_vault_bump: None,
+        reward_vault_bump: None,
+        fee_vault: Pubkey::new_unique(),
+        deposit_fee_bps: 0,
+        withdraw_fee_bps: 0,
+        fee_vault_bump: None,
+        total_rewards_funded: 0,
+        reward_funders: [None; StakePool::MAX_REWARD_FUNDERS],
+        receipt_mint: None,
+        expired_rewards: 0,
+        unstake_cooldown: 0,
+        is_registered: false,
+        reward_rate_change_delay: 0,
+        pending_lockup_period: None,
+        pending_enforce_lockup: None,
+        lockup_change_timestamp: None,
+        min_reward_rate_bound: None,
+        max_reward_rate_bound: None,
+        active_stake_count: None,
+        reward_tiers: [None; StakePool::MAX_REWARD_TIERS],
+        bonus_end_ts: None,
+        bonus_multiplier_bps: 0,
+        lockup_extension_rate: 0,
+        allowlist_required: false,
+        allowlist_merkle_root: None,
+        claim_window: None,
+        outflow_limit: None,
+        outflow_window: OutflowWindow::default(),
+        pool_manager: None,
+        keeper_bounty: 0,
+        last_keeper_bounty_at: 0,
+        max_total_rewards: None,
+        total_rewards_committed: 0,
+        reward_rate_mode: RewardRateMode::PerLockup,
+        reward_decimals_shift: 0,
+        enforce_min_remaining_stake: false,
+        wind_down_rewards: WindDownRewards::Expire,
+        hook_program: None,
+        points_rate: 0,
+        deny_list_required: false,
+        allow_third_party_stake: false,
+        event_nonce: 0,
+        cohort_duration: 0,
+        open_reward_cohorts: 0,
+        _reserved: [0; 2],
     };
 
     // Verify there's a pending change different from current
@@ -263,7 +491,7 @@ fn test_propose_current_rate_cancels_pending() {
 fn test_propose_current_rate_no_pending() {
     // Create a StakePool with no pending change
     let pool = StakePool {
-        key: your_wallet_stake_pool::state::Key::StakePool,
+        key: your_wallet_stake_pool::state::Key::StakePoolV2,
         stake_mint: Pubkey::new_unique(),
         reward_mint: Pubkey::new_unique(),
         pool_id: 0,
@@ -281,7 +509,53 @@ fn test_propose_current_rate_no_pending() {
         pending_reward_rate: None, // No pending change
         reward_rate_change_timestamp: None,
         last_rate_change: None,
-        _reserved: [0; 7],
+        referral_bps: 0,
+        early_unstake_bps: 0,
+        stake_vault_bump: None,
+        reward_vault_bump: None,
+        fee_vault: Pubkey::new_unique(),
+        deposit_fee_bps: 0,
+        withdraw_fee_bps: 0,
+        fee_vault_bump: None,
+        total_rewards_funded: 0,
+        reward_funders: [None; StakePool::MAX_REWARD_FUNDERS],
+        receipt_mint: None,
+        expired_rewards: 0,
+        unstake_cooldown: 0,
+        is_registered: false,
+        reward_rate_change_delay: 0,
+        pending_lockup_period: None,
+        pending_enforce_lockup: None,
+        lockup_change_timestamp: None,
+        min_reward_rate_bound: None,
+        max_reward_rate_bound: None,
+        active_stake_count: None,
+        reward_tiers: [None; StakePool::MAX_REWARD_TIERS],
+        bonus_end_ts: None,
+        bonus_multiplier_bps: 0,
+        lockup_extension_rate: 0,
+        allowlist_required: false,
+        allowlist_merkle_root: None,
+        claim_window: None,
+        outflow_limit: None,
+        outflow_window: OutflowWindow::default(),
+        pool_manager: None,
+        keeper_bounty: 0,
+        last_keeper_bounty_at: 0,
+        max_total_rewards: None,
+        total_rewards_committed: 0,
+        reward_rate_mode: RewardRateMode::PerLockup,
+        reward_decimals_shift: 0,
+        enforce_min_remaining_stake: false,
+        wind_down_rewards: WindDownRewards::Expire,
+        hook_program: None,
+        points_rate: 0,
+        deny_list_required: false,
+        allow_third_party_stake: false,
+        event_nonce: 0,
+        cohort_duration: 0,
+        open_reward_cohorts: 0,
+        _reserved: [0; 2],
     };
 
     // Verify no pending change
@@ -336,7 +610,7 @@ fn test_future_timestamp_scenario() {
     // Create a StakePool with a timestamp in the future
     // This could indicate clock manipulation
     let pool = StakePool {
-        key: your_wallet_stake_pool::state::Key::StakePool,
+        key: your_wallet_stake_pool::state::Key::StakePoolV2,
         stake_mint: Pubkey::new_unique(),
         reward_mint: Pubkey::new_unique(),
         pool_id: 0,
@@ -354,7 +628,53 @@ fn test_future_timestamp_scenario() {
         pending_reward_rate: Some(50_000_000),
         reward_rate_change_timestamp: Some(9999999999), // Far future timestamp
         last_rate_change: None,
-        _reserved: [0; 7],
+        referral_bps: 0,
+        early_unstake_bps: 0,
+        stake_vault_bump: None,
+        reward_vault_bump: None,
+        fee_vault: Pubkey::new_unique(),
+        deposit_fee_bps: 0,
+        withdraw_fee_bps: 0,
+        fee_vault_bump: None,
+        total_rewards_funded: 0,
+        reward_funders: [None; StakePool::MAX_REWARD_FUNDERS],
+        receipt_mint: None,
+        expired_rewards: 0,
+        unstake_cooldown: 0,
+        is_registered: false,
+        reward_rate_change_delay: 0,
+        pending_lockup_period: None,
+        pending_enforce_lockup: None,
+        lockup_change_timestamp: None,
+        min_reward_rate_bound: None,
+        max_reward_rate_bound: None,
+        active_stake_count: None,
+        reward_tiers: [None; StakePool::MAX_REWARD_TIERS],
+        bonus_end_ts: None,
+        bonus_multiplier_bps: 0,
+        lockup_extension_rate: 0,
+        allowlist_required: false,
+        allowlist_merkle_root: None,
+        claim_window: None,
+        outflow_limit: None,
+        outflow_window: OutflowWindow::default(),
+        pool_manager: None,
+        keeper_bounty: 0,
+        last_keeper_bounty_at: 0,
+        max_total_rewards: None,
+        total_rewards_committed: 0,
+        reward_rate_mode: RewardRateMode::PerLockup,
+        reward_decimals_shift: 0,
+        enforce_min_remaining_stake: false,
+        wind_down_rewards: WindDownRewards::Expire,
+        hook_program: None,
+        points_rate: 0,
+        deny_list_required: false,
+        allow_third_party_stake: false,
+        event_nonce: 0,
+        cohort_duration: 0,
+        open_reward_cohorts: 0,
+        _reserved: [0; 2],
     };
 
     // Verify timestamp is far in the future
@@ -0,0 +1,107 @@
+// ============================================================================
+// Reward Rate Mode Tests
+// ============================================================================
+// A pool's reward_rate is either paid once per lockup round (the original
+// model) or an annual rate prorated to the lockup: a round pays
+// amount * rate * lockup_period / 365 days. New rounds lock in the per-round
+// rate the mode yields, so claims, forfeitures and audits are unchanged.
+
+mod common;
+
+use borsh::BorshDeserialize;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    constants::SECONDS_PER_YEAR,
+    state::{RewardRateMode, RewardTier, StakePool},
+};
+
+use common::*;
+
+const DAY: i64 = 86_400;
+const TEN_PERCENT: u64 = 100_000_000;
+const NOW: i64 = 1_700_000_000;
+
+fn annualized_pool(lockup_period: i64) -> StakePool {
+    let mut pool = sample_stake_pool();
+    pool.reward_rate = TEN_PERCENT;
+    pool.lockup_period = lockup_period;
+    pool.reward_rate_mode = RewardRateMode::Annualized;
+    pool
+}
+
+#[test]
+fn test_per_lockup_pays_the_full_rate_every_round() {
+    let mut pool = sample_stake_pool();
+    pool.lockup_period = 30 * DAY;
+
+    assert_eq!(pool.reward_rate_mode, RewardRateMode::PerLockup);
+    assert_eq!(pool.tiered_reward_rate(1_000).unwrap(), TEN_PERCENT);
+    assert_eq!(pool.expected_rewards(1_000_000, NOW).unwrap(), 100_000);
+}
+
+#[test]
+fn test_annualized_rate_is_prorated_to_the_lockup() {
+    let pool = annualized_pool(30 * DAY);
+
+    // 10% a year over 30 days: 100_000_000 * 30 / 365, rounded down once
+    assert_eq!(pool.tiered_reward_rate(1_000).unwrap(), 8_219_178);
+    assert_eq!(
+        pool.expected_rewards(1_000_000_000, NOW).unwrap(),
+        8_219_178
+    );
+
+    let yearly = annualized_pool(SECONDS_PER_YEAR);
+    assert_eq!(yearly.tiered_reward_rate(1_000).unwrap(), TEN_PERCENT);
+    assert_eq!(yearly.expected_rewards(1_000_000, NOW).unwrap(), 100_000);
+}
+
+#[test]
+fn test_annualized_stakes_lock_in_the_prorated_rate() {
+    let pool = annualized_pool(73 * DAY);
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 5_000);
+    stake.locked_reward_rate = pool.stake_reward_rate(5_000, NOW).unwrap();
+
+    // 73 days is a fifth of a year
+    assert_eq!(stake.locked_reward_rate, TEN_PERCENT / 5);
+    assert_eq!(stake.round_rewards().unwrap(), 100);
+    assert_eq!(
+        pool.calculate_rewards(&stake, stake.stake_timestamp + 73 * DAY)
+            .unwrap(),
+        100
+    );
+}
+
+#[test]
+fn test_annualized_tiers_round_only_once() {
+    let mut pool = annualized_pool(SECONDS_PER_YEAR * 2 / 3);
+    pool.reward_rate = 1;
+    pool.set_reward_tiers(&[RewardTier {
+        min_amount: 1,
+        multiplier_bps: 15_000,
+    }])
+    .unwrap();
+
+    // 1 * 1.5 * 2/3 is exactly 1; boosting and prorating separately would
+    // round 1.5 down first and end up at 0
+    assert_eq!(pool.tiered_reward_rate(1).unwrap(), 1);
+}
+
+#[test]
+fn test_annual_reward_rate_compares_pools_with_different_lockups() {
+    let mut per_lockup = sample_stake_pool();
+    per_lockup.lockup_period = SECONDS_PER_YEAR / 4;
+    assert_eq!(per_lockup.annual_reward_rate().unwrap(), 4 * TEN_PERCENT);
+
+    let annualized = annualized_pool(SECONDS_PER_YEAR / 4);
+    assert_eq!(annualized.annual_reward_rate().unwrap(), TEN_PERCENT);
+}
+
+#[test]
+fn test_pools_created_before_the_mode_load_as_per_lockup() {
+    // Older pools hold zeros where the mode is now read
+    assert_eq!(
+        RewardRateMode::try_from_slice(&[0]).unwrap(),
+        RewardRateMode::PerLockup
+    );
+    assert_eq!(RewardRateMode::default(), RewardRateMode::PerLockup);
+}
@@ -0,0 +1,62 @@
+// ============================================================================
+// Reward Stream Tests
+// ============================================================================
+// A reward funder may stream rewards at a fixed rate instead of funding a lump
+// sum. The stream PDA is delegated the whole amount up front, and cranking it
+// moves what accrued since the last crank (never past the stream's end) into
+// the reward vault.
+
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::state::{Key, RewardStream};
+
+fn stream(rate_per_second: u64, last_streamed_at: i64, end_ts: i64) -> RewardStream {
+    RewardStream {
+        key: Key::RewardStream,
+        pool: Pubkey::new_unique(),
+        funder: Pubkey::new_unique(),
+        funder_token_account: Pubkey::new_unique(),
+        rate_per_second,
+        last_streamed_at,
+        end_ts,
+        total_streamed: 0,
+        bump: 255,
+    }
+}
+
+#[test]
+fn test_reward_stream_len_matches_serialized_size() {
+    let data = borsh::to_vec(&stream(1, 0, 1)).unwrap();
+    assert_eq!(data.len(), RewardStream::LEN);
+}
+
+#[test]
+fn test_due_amount_accrues_since_the_last_crank() {
+    let stream = stream(25, 1_000, 2_000);
+
+    assert_eq!(stream.due_amount(1_000).unwrap(), (0, 1_000));
+    assert_eq!(stream.due_amount(1_100).unwrap(), (2_500, 1_100));
+    assert_eq!(stream.remaining_amount().unwrap(), 25_000);
+}
+
+#[test]
+fn test_due_amount_stops_at_the_stream_end() {
+    let mut stream = stream(25, 1_000, 2_000);
+
+    assert_eq!(stream.due_amount(5_000).unwrap(), (25_000, 2_000));
+
+    stream.last_streamed_at = 2_000;
+    assert_eq!(stream.due_amount(5_000).unwrap(), (0, 2_000));
+    assert_eq!(stream.remaining_amount().unwrap(), 0);
+}
+
+#[test]
+fn test_due_amount_ignores_a_clock_behind_the_last_crank() {
+    let stream = stream(25, 1_000, 2_000);
+    assert_eq!(stream.due_amount(900).unwrap(), (0, 1_000));
+}
+
+#[test]
+fn test_total_amount_rejects_overflow() {
+    assert!(RewardStream::total_amount(u64::MAX, 0, 2).is_err());
+    assert_eq!(RewardStream::total_amount(3, 10, 5).unwrap(), 0);
+}
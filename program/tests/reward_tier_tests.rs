@@ -0,0 +1,165 @@
+// ============================================================================
+// Reward Tier Tests
+// ============================================================================
+// Pools may boost the reward rate of large stakes with up to 4 stake size
+// tiers set at initialization. A round locks in the rate of the tier its stake
+// reaches when it starts, and that boosted rate is what the round reserves in
+// total_rewards_owed and pays out.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    constants::MAX_REWARD_MULTIPLIER_BPS,
+    error::StakePoolError,
+    state::{RewardTier, StakePool},
+};
+
+use common::*;
+
+const TEN_PERCENT: u64 = 100_000_000;
+
+fn tier(min_amount: u64, multiplier_bps: u16) -> RewardTier {
+    RewardTier {
+        min_amount,
+        multiplier_bps,
+    }
+}
+
+/// A 10% pool boosting stakes of 100k tokens by 1.2x and of 1M tokens by 1.5x
+fn tiered_pool() -> StakePool {
+    let mut pool = sample_stake_pool();
+    pool.reward_rate = TEN_PERCENT;
+    pool.set_reward_tiers(&[tier(100_000, 12_000), tier(1_000_000, 15_000)])
+        .unwrap();
+    pool
+}
+
+fn invalid() -> Result<(), ProgramError> {
+    Err(StakePoolError::InvalidParameters.into())
+}
+
+#[test]
+fn test_pool_without_tiers_pays_the_base_rate() {
+    let pool = sample_stake_pool();
+
+    assert_eq!(pool.reward_tiers, [None; StakePool::MAX_REWARD_TIERS]);
+    assert_eq!(pool.reward_multiplier_bps(u64::MAX), 10_000);
+    assert_eq!(pool.tiered_reward_rate(u64::MAX).unwrap(), pool.reward_rate);
+}
+
+#[test]
+fn test_stake_gets_the_highest_tier_it_reaches() {
+    let pool = tiered_pool();
+
+    assert_eq!(pool.reward_multiplier_bps(99_999), 10_000);
+    assert_eq!(pool.reward_multiplier_bps(100_000), 12_000);
+    assert_eq!(pool.reward_multiplier_bps(999_999), 12_000);
+    assert_eq!(pool.reward_multiplier_bps(1_000_000), 15_000);
+
+    assert_eq!(pool.tiered_reward_rate(50_000).unwrap(), TEN_PERCENT);
+    assert_eq!(pool.tiered_reward_rate(100_000).unwrap(), 120_000_000);
+    assert_eq!(pool.tiered_reward_rate(2_000_000).unwrap(), 150_000_000);
+}
+
+#[test]
+fn test_reserved_rewards_include_the_multiplier() {
+    let pool = tiered_pool();
+
+    assert_eq!(
+        pool.expected_rewards(50_000, SAMPLE_STAKE_TIMESTAMP)
+            .unwrap(),
+        5_000
+    );
+    assert_eq!(
+        pool.expected_rewards(100_000, SAMPLE_STAKE_TIMESTAMP)
+            .unwrap(),
+        12_000
+    );
+    assert_eq!(
+        pool.expected_rewards(1_000_000, SAMPLE_STAKE_TIMESTAMP)
+            .unwrap(),
+        150_000
+    );
+}
+
+#[test]
+fn test_round_pays_what_it_reserved() {
+    let mut pool = tiered_pool();
+    pool.total_rewards_owed = pool
+        .expected_rewards(200_000, SAMPLE_STAKE_TIMESTAMP)
+        .unwrap();
+
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 200_000);
+    stake.locked_reward_rate = pool.tiered_reward_rate(200_000).unwrap();
+    let matured = pool.maturity_timestamp(stake.stake_timestamp).unwrap();
+
+    assert_eq!(pool.calculate_rewards(&stake, matured).unwrap(), 24_000);
+    assert_eq!(
+        pool.unstake_forfeiture(&stake, 200_000).unwrap(),
+        pool.total_rewards_owed
+    );
+}
+
+#[test]
+fn test_relock_snapshots_the_tier_of_the_remaining_stake() {
+    let mut pool = tiered_pool();
+
+    // A 1M stake whose round started at 1.5x, partially unstaked down to 100k
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 100_000);
+    stake.auto_relock = true;
+    stake.locked_reward_rate = pool.tiered_reward_rate(1_000_000).unwrap();
+    pool.total_rewards_owed = stake.round_rewards().unwrap();
+    let matured = pool.maturity_timestamp(stake.stake_timestamp).unwrap();
+
+    // The next round locks in the 1.2x tier of what is still staked
+    let round_rewards = pool.relock_stake(&mut stake, matured).unwrap();
+    assert_eq!(stake.locked_reward_rate, 120_000_000);
+    assert_eq!(round_rewards, 12_000);
+    assert_eq!(pool.total_rewards_owed, 15_000 + 12_000);
+}
+
+#[test]
+fn test_multiplier_above_the_bound_is_rejected() {
+    let mut pool = sample_stake_pool();
+
+    assert_eq!(
+        pool.set_reward_tiers(&[tier(100, MAX_REWARD_MULTIPLIER_BPS + 1)]),
+        invalid()
+    );
+    assert!(pool
+        .set_reward_tiers(&[tier(100, MAX_REWARD_MULTIPLIER_BPS)])
+        .is_ok());
+}
+
+#[test]
+fn test_tiers_must_boost_and_ascend() {
+    let mut pool = sample_stake_pool();
+
+    // Multipliers below 1x would cut the base rate
+    assert_eq!(pool.set_reward_tiers(&[tier(100, 9_999)]), invalid());
+    // A zero threshold would boost every stake
+    assert_eq!(pool.set_reward_tiers(&[tier(0, 12_000)]), invalid());
+    // Thresholds must strictly ascend
+    assert_eq!(
+        pool.set_reward_tiers(&[tier(100, 12_000), tier(100, 13_000)]),
+        invalid()
+    );
+    // Larger stakes never get a smaller multiplier
+    assert_eq!(
+        pool.set_reward_tiers(&[tier(100, 13_000), tier(200, 12_000)]),
+        invalid()
+    );
+    assert_eq!(pool.reward_tiers, [None; StakePool::MAX_REWARD_TIERS]);
+}
+
+#[test]
+fn test_at_most_four_tiers() {
+    let mut pool = sample_stake_pool();
+    let tiers: Vec<RewardTier> = (1..=5).map(|i| tier(i * 100, 10_000)).collect();
+
+    assert_eq!(pool.set_reward_tiers(&tiers), invalid());
+    assert!(pool.set_reward_tiers(&tiers[..4]).is_ok());
+    assert_eq!(pool.reward_tiers[3], Some(tier(400, 10_000)));
+}
@@ -0,0 +1,117 @@
+// ============================================================================
+// Rewards Cap Tests
+// ============================================================================
+// Global admins may cap the rewards a pool commits over its life
+// (max_total_rewards). Stakes, relocks and lockup extensions that would commit
+// past the cap fail, and once not even a minimum stake fits under it the pool
+// counts as ended.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use your_wallet_stake_pool::error::StakePoolError;
+use your_wallet_stake_pool::processor::PoolUpdate;
+
+use common::*;
+
+const NOW: i64 = 1_700_000_000;
+
+fn error(code: StakePoolError) -> ProgramError {
+    ProgramError::Custom(code as u32)
+}
+
+#[test]
+fn test_uncapped_pool_commits_freely() {
+    let mut pool = sample_stake_pool();
+
+    pool.commit_rewards(u64::MAX / 2).unwrap();
+
+    assert_eq!(pool.rewards_headroom(), None);
+    assert_eq!(pool.total_rewards_owed, u64::MAX / 2);
+    assert_eq!(pool.total_rewards_committed, u64::MAX / 2);
+    assert!(!pool.is_rewards_cap_reached(NOW).unwrap());
+}
+
+#[test]
+fn test_commitments_past_the_cap_fail() {
+    let mut pool = sample_stake_pool();
+    pool.max_total_rewards = Some(1_000);
+
+    pool.commit_rewards(600).unwrap();
+    assert_eq!(pool.rewards_headroom(), Some(400));
+
+    assert_eq!(
+        pool.commit_rewards(401).unwrap_err(),
+        error(StakePoolError::RewardsCapExceeded)
+    );
+    assert_eq!(pool.total_rewards_owed, 600);
+    assert_eq!(pool.total_rewards_committed, 600);
+
+    pool.commit_rewards(400).unwrap();
+    assert_eq!(pool.rewards_headroom(), Some(0));
+}
+
+#[test]
+fn test_paid_out_rewards_still_count_against_the_cap() {
+    let mut pool = sample_stake_pool();
+    pool.max_total_rewards = Some(1_000);
+    pool.commit_rewards(1_000).unwrap();
+
+    // Claims lower what is owed, not what was committed
+    pool.total_rewards_owed = 0;
+
+    assert_eq!(pool.rewards_headroom(), Some(0));
+    assert!(pool.is_rewards_cap_reached(NOW).unwrap());
+}
+
+#[test]
+fn test_cap_is_reached_once_a_minimum_stake_no_longer_fits() {
+    let mut pool = sample_stake_pool();
+    pool.min_stake_amount = 1_000;
+    let minimum_reward = pool.expected_rewards(1_000, NOW).unwrap();
+    pool.max_total_rewards = Some(10 * minimum_reward);
+
+    pool.commit_rewards(9 * minimum_reward).unwrap();
+    assert!(!pool.is_rewards_cap_reached(NOW).unwrap());
+    pool.check_not_ended(NOW).unwrap();
+
+    pool.commit_rewards(1).unwrap();
+    assert!(pool.is_rewards_cap_reached(NOW).unwrap());
+    assert_eq!(
+        pool.check_not_ended(NOW).unwrap_err(),
+        error(StakePoolError::PoolEnded)
+    );
+}
+
+#[test]
+fn test_lifting_the_cap_reopens_the_pool() {
+    let mut pool = sample_stake_pool();
+    pool.max_total_rewards = Some(500);
+    pool.commit_rewards(500).unwrap();
+    assert!(pool.check_not_ended(NOW).is_err());
+
+    pool.max_total_rewards = None;
+    pool.check_not_ended(NOW).unwrap();
+}
+
+#[test]
+fn test_end_date_still_ends_the_pool() {
+    let mut pool = sample_stake_pool();
+    pool.pool_end_date = Some(NOW);
+
+    assert_eq!(
+        pool.check_not_ended(NOW).unwrap_err(),
+        error(StakePoolError::PoolEnded)
+    );
+    pool.check_not_ended(NOW - 1).unwrap();
+}
+
+#[test]
+fn test_only_global_admins_set_the_cap() {
+    let update = PoolUpdate {
+        max_total_rewards: Some(Some(1_000)),
+        ..Default::default()
+    };
+    assert_eq!(update.required_roles(), None);
+    assert!(!update.is_manager_update());
+}
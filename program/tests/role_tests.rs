@@ -0,0 +1,158 @@
+// ============================================================================
+// Role-Based Access Control Tests
+// ============================================================================
+// Besides the global admins (main authority and authorized creators), the
+// ProgramAuthority grants scoped roles: pausers may only pause, rate admins may
+// only propose reward rates and treasurers may only move surplus funds.
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    error::StakePoolError,
+    processor::PoolUpdate,
+    state::{ClusterProfile, Key, ProgramAuthority, Role},
+};
+
+fn program_authority() -> ProgramAuthority {
+    ProgramAuthority {
+        key: Key::ProgramAuthorityV2,
+        authority: Pubkey::new_unique(),
+        authorized_creators: Vec::new(),
+        pending_authority: None,
+        bump: 255,
+        cluster_profile: ClusterProfile::Mainnet,
+        global_pause: false,
+        withdrawals_only: false,
+        role_holders: [None; ProgramAuthority::MAX_ROLE_HOLDERS],
+        approval_threshold: 0,
+        recovery_authority: None,
+        recovery_delay: 0,
+        recovery_initiated_at: None,
+        max_creators: ProgramAuthority::DEFAULT_MAX_CREATORS,
+    }
+}
+
+fn error(code: StakePoolError) -> ProgramError {
+    ProgramError::Custom(code as u32)
+}
+
+#[test]
+fn test_role_masks_are_stable() {
+    // ManageRoles takes the raw mask, so the bits are part of the interface
+    assert_eq!(Role::Pauser.mask(), 0b001);
+    assert_eq!(Role::RateAdmin.mask(), 0b010);
+    assert_eq!(Role::Treasurer.mask(), 0b100);
+    assert_eq!(Role::ALL, 0b111);
+}
+
+#[test]
+fn test_role_holders_only_hold_granted_roles() {
+    let mut authority = program_authority();
+    let pauser = Pubkey::new_unique();
+    authority.set_roles(pauser, Role::Pauser.mask()).unwrap();
+
+    assert!(authority.has_role(&pauser, Role::Pauser));
+    assert!(!authority.has_role(&pauser, Role::RateAdmin));
+    assert!(!authority.has_role(&pauser, Role::Treasurer));
+
+    // Roles do not make the holder a global admin
+    assert!(!authority.is_authorized(&pauser));
+    assert!(!authority.has_role(&Pubkey::new_unique(), Role::Pauser));
+}
+
+#[test]
+fn test_global_admins_hold_every_role() {
+    let mut authority = program_authority();
+    let creator = Pubkey::new_unique();
+    authority.add_creator(creator).unwrap();
+
+    for admin in [authority.authority, creator] {
+        assert!(authority.has_roles(&admin, Role::ALL));
+    }
+}
+
+#[test]
+fn test_set_roles_replaces_and_revokes() {
+    let mut authority = program_authority();
+    let holder = Pubkey::new_unique();
+
+    authority
+        .set_roles(holder, Role::Pauser.mask() | Role::Treasurer.mask())
+        .unwrap();
+    authority.set_roles(holder, Role::RateAdmin.mask()).unwrap();
+    assert_eq!(authority.roles_of(&holder), Role::RateAdmin.mask());
+    assert_eq!(authority.role_holders.iter().flatten().count(), 1);
+
+    authority.set_roles(holder, 0).unwrap();
+    assert_eq!(authority.roles_of(&holder), 0);
+    assert!(authority.role_holders.iter().all(Option::is_none));
+
+    // Revoking from an address without roles is a mistake
+    assert_eq!(
+        authority.set_roles(holder, 0).unwrap_err(),
+        error(StakePoolError::InvalidParameters)
+    );
+}
+
+#[test]
+fn test_set_roles_rejects_invalid_grants() {
+    let mut authority = program_authority();
+
+    assert_eq!(
+        authority
+            .set_roles(Pubkey::new_unique(), 0b1000)
+            .unwrap_err(),
+        error(StakePoolError::InvalidParameters)
+    );
+    assert_eq!(
+        authority
+            .set_roles(authority.authority, Role::Pauser.mask())
+            .unwrap_err(),
+        error(StakePoolError::InvalidParameters)
+    );
+
+    for _ in 0..ProgramAuthority::MAX_ROLE_HOLDERS {
+        authority
+            .set_roles(Pubkey::new_unique(), Role::Pauser.mask())
+            .unwrap();
+    }
+    assert_eq!(
+        authority
+            .set_roles(Pubkey::new_unique(), Role::Pauser.mask())
+            .unwrap_err(),
+        error(StakePoolError::MaxRoleHoldersReached)
+    );
+}
+
+#[test]
+fn test_pool_updates_require_matching_roles() {
+    let pause = PoolUpdate {
+        is_paused: Some(true),
+        ..Default::default()
+    };
+    assert_eq!(pause.required_roles(), Some(Role::Pauser.mask()));
+
+    let rate = PoolUpdate {
+        reward_rate: Some(50_000_000),
+        ..Default::default()
+    };
+    assert_eq!(rate.required_roles(), Some(Role::RateAdmin.mask()));
+
+    let both = PoolUpdate {
+        is_paused: Some(false),
+        reward_rate: Some(50_000_000),
+        ..Default::default()
+    };
+    assert_eq!(
+        both.required_roles(),
+        Some(Role::Pauser.mask() | Role::RateAdmin.mask())
+    );
+
+    // Other parameters stay with global admins, even alongside role parameters
+    let fees = PoolUpdate {
+        is_paused: Some(true),
+        deposit_fee_bps: Some(100),
+        ..Default::default()
+    };
+    assert_eq!(fees.required_roles(), None);
+}
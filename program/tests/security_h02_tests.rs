@@ -117,13 +117,11 @@ fn calculate_test_rewards(
     const SCALE: u128 = 1_000_000_000;
 
     // Full rewards after lockup completes
-    let rewards = (amount_staked as u128)
+    (amount_staked as u128)
         .checked_mul(reward_rate as u128)
         .unwrap()
         .checked_div(SCALE)
-        .unwrap() as u64;
-
-    rewards
+        .unwrap() as u64
 }
 
 #[test]
@@ -9,9 +9,13 @@
 // - PermanentDelegate: Allows forcible token transfers from vaults
 // - MintCloseAuthority: Can close mint, rendering tokens worthless
 // - DefaultAccountState: Can freeze accounts preventing movement
+// - ConfidentialTransferMint / ConfidentialTransferFeeConfig: Encrypted balances
+//   bypass the vault accounting (see token_extension_tests.rs)
+// - InterestBearingConfig: UI amounts drift from the raw amounts the protocol records
 //
 // Supported Extensions:
-// - TransferFeeConfig: Properly supported via balance checking in transfer_tokens_with_fee()
+// - TransferFeeConfig: Properly supported; transfer_tokens_with_fee() computes the epoch fee and
+//   confirms it against the recipient balance
 //   Note: Transfer fees are deducted from user rewards (users pay the fees, not protocol)
 //
 // Security Impact: HIGH
@@ -9,7 +9,7 @@
 //
 // Security Fix: validate_no_freeze_authority() checks during pool initialization
 //
-// Run tests: cargo test-sbf --test security_m03_tests
+// Run tests: cargo xtask test-all --test security_m03_tests
 
 #![allow(deprecated)]
 
@@ -25,72 +25,12 @@ use solana_sdk::{
     signature::{Keypair, Signer},
     transaction::Transaction,
 };
-use spl_token_2022::{
-    instruction as token_instruction,
-    state::{Account as TokenAccount, Mint},
-};
+use spl_token_2022::{instruction as token_instruction, state::Mint};
 use your_wallet_stake_pool::instruction::StakePoolInstruction;
+use your_wallet_stake_pool::state::RewardRateMode;
 
 use common::*;
 
-// ============================================================================
-// Helper: Load SPL Token 2022 Program
-// ============================================================================
-
-fn load_spl_token_program() -> Vec<u8> {
-    const PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
-    const OUTPUT_PATH: &str = "/tmp/spl_token_2022.so";
-
-    let paths = [
-        OUTPUT_PATH,
-        "spl_token_2022.so",
-        "program/spl_token_2022.so",
-    ];
-
-    // Try to load from existing paths
-    for path in &paths {
-        if let Ok(data) = std::fs::read(path) {
-            println!("✅ Loaded SPL Token 2022 from: {}", path);
-            return data;
-        }
-    }
-
-    // Program not found - try to download it
-    println!("📦 SPL Token 2022 program not found, downloading...");
-
-    if download_spl_token_program(PROGRAM_ID, OUTPUT_PATH) {
-        if let Ok(data) = std::fs::read(OUTPUT_PATH) {
-            println!("✅ Downloaded SPL Token 2022 from: {}", OUTPUT_PATH);
-            return data;
-        }
-    }
-
-    eprintln!("\n❌ Failed to load SPL Token 2022 program!");
-    eprintln!(
-        "   Please run: solana program dump {} {}",
-        PROGRAM_ID, OUTPUT_PATH
-    );
-    panic!("SPL Token 2022 program required");
-}
-
-fn download_spl_token_program(program_id: &str, output_path: &str) -> bool {
-    use std::process::Command;
-
-    let solana_check = Command::new("solana").arg("--version").output();
-    if solana_check.is_err() {
-        return false;
-    }
-
-    let result = Command::new("solana")
-        .arg("program")
-        .arg("dump")
-        .arg(program_id)
-        .arg(output_path)
-        .output();
-
-    matches!(result, Ok(output) if output.status.success())
-}
-
 // ============================================================================
 // Helper: Create Token Mint with Freeze Authority
 // ============================================================================
@@ -135,46 +75,6 @@ fn create_mint_with_freeze_authority(
     mint.pubkey()
 }
 
-// ============================================================================
-// Helper: Create Token Account
-// ============================================================================
-
-fn create_token_account(
-    svm: &mut LiteSVM,
-    payer: &Keypair,
-    mint: &Pubkey,
-    owner: &Pubkey,
-) -> Pubkey {
-    let token_account = Keypair::new();
-    let rent = svm.minimum_balance_for_rent_exemption(TokenAccount::LEN);
-
-    let create_account_ix = system_instruction::create_account(
-        &payer.pubkey(),
-        &token_account.pubkey(),
-        rent,
-        TokenAccount::LEN as u64,
-        &spl_token_2022::id(),
-    );
-
-    let init_account_ix = token_instruction::initialize_account(
-        &spl_token_2022::id(),
-        &token_account.pubkey(),
-        mint,
-        owner,
-    )
-    .unwrap();
-
-    let tx = Transaction::new_signed_with_payer(
-        &[create_account_ix, init_account_ix],
-        Some(&payer.pubkey()),
-        &[payer, &token_account],
-        svm.latest_blockhash(),
-    );
-
-    svm.send_transaction(tx).unwrap();
-    token_account.pubkey()
-}
-
 // ============================================================================
 // Test: Pool Initialization Rejects Stake Mint with Freeze Authority
 // ============================================================================
@@ -228,9 +128,10 @@ fn test_initialize_pool_rejects_stake_mint_with_freeze_authority() {
     // Derive pool PDA
     let (pool_pda, _) = get_pool_pda(&stake_mint, 0);
 
-    // Create vault token accounts (owned by pool PDA)
-    let stake_vault = create_token_account(&mut svm, &payer, &stake_mint, &pool_pda);
-    let reward_vault = create_token_account(&mut svm, &payer, &reward_mint, &pool_pda);
+    // Vault PDAs (created by InitializePool, owned by the pool PDA)
+    let (stake_vault, _) = get_stake_vault_pda(&pool_pda);
+    let (reward_vault, _) = get_reward_vault_pda(&pool_pda);
+    let (fee_vault, _) = get_fee_vault_pda(&pool_pda);
 
     // Try to initialize pool with freezable stake mint
     let init_pool_ix = Instruction {
@@ -246,6 +147,8 @@ fn test_initialize_pool_rejects_stake_mint_with_freeze_authority() {
             AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
             AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
             AccountMeta::new_readonly(program_authority_pda, false),
+            AccountMeta::new(fee_vault, false),
+            AccountMeta::new(get_mint_registry_pda(&stake_mint).0, false),
         ],
         data: StakePoolInstruction::InitializePool {
             pool_id: 0,
@@ -254,6 +157,17 @@ fn test_initialize_pool_rejects_stake_mint_with_freeze_authority() {
             lockup_period: 86400,
             enforce_lockup: false,
             pool_end_date: None,
+            referral_bps: 0,
+            early_unstake_bps: 0,
+            deposit_fee_bps: 0,
+            withdraw_fee_bps: 0,
+            reward_rate_change_delay: None,
+            min_reward_rate_bound: None,
+            max_reward_rate_bound: None,
+            reward_tiers: vec![],
+            bonus_end_ts: None,
+            bonus_multiplier_bps: 0,
+            reward_rate_mode: RewardRateMode::PerLockup,
         }
         .try_to_vec()
         .unwrap(),
@@ -350,9 +264,10 @@ fn test_initialize_pool_rejects_reward_mint_with_freeze_authority() {
     // Derive pool PDA
     let (pool_pda, _) = get_pool_pda(&stake_mint, 0);
 
-    // Create vault token accounts
-    let stake_vault = create_token_account(&mut svm, &payer, &stake_mint, &pool_pda);
-    let reward_vault = create_token_account(&mut svm, &payer, &reward_mint, &pool_pda);
+    // Vault PDAs (created by InitializePool, owned by the pool PDA)
+    let (stake_vault, _) = get_stake_vault_pda(&pool_pda);
+    let (reward_vault, _) = get_reward_vault_pda(&pool_pda);
+    let (fee_vault, _) = get_fee_vault_pda(&pool_pda);
 
     // Try to initialize pool with freezable reward mint
     let init_pool_ix = Instruction {
@@ -368,6 +283,8 @@ fn test_initialize_pool_rejects_reward_mint_with_freeze_authority() {
             AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
             AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
             AccountMeta::new_readonly(program_authority_pda, false),
+            AccountMeta::new(fee_vault, false),
+            AccountMeta::new(get_mint_registry_pda(&stake_mint).0, false),
         ],
         data: StakePoolInstruction::InitializePool {
             pool_id: 0,
@@ -376,6 +293,17 @@ fn test_initialize_pool_rejects_reward_mint_with_freeze_authority() {
             lockup_period: 86400,
             enforce_lockup: false,
             pool_end_date: None,
+            referral_bps: 0,
+            early_unstake_bps: 0,
+            deposit_fee_bps: 0,
+            withdraw_fee_bps: 0,
+            reward_rate_change_delay: None,
+            min_reward_rate_bound: None,
+            max_reward_rate_bound: None,
+            reward_tiers: vec![],
+            bonus_end_ts: None,
+            bonus_multiplier_bps: 0,
+            reward_rate_mode: RewardRateMode::PerLockup,
         }
         .try_to_vec()
         .unwrap(),
@@ -489,9 +417,10 @@ fn test_initialize_pool_succeeds_without_freeze_authority() {
     // Derive pool PDA
     let (pool_pda, _) = get_pool_pda(&stake_mint, 0);
 
-    // Create vault token accounts
-    let stake_vault = create_token_account(&mut svm, &payer, &stake_mint, &pool_pda);
-    let reward_vault = create_token_account(&mut svm, &payer, &reward_mint, &pool_pda);
+    // Vault PDAs (created by InitializePool, owned by the pool PDA)
+    let (stake_vault, _) = get_stake_vault_pda(&pool_pda);
+    let (reward_vault, _) = get_reward_vault_pda(&pool_pda);
+    let (fee_vault, _) = get_fee_vault_pda(&pool_pda);
 
     // Initialize pool with safe mints
     let init_pool_ix = Instruction {
@@ -507,6 +436,8 @@ fn test_initialize_pool_succeeds_without_freeze_authority() {
             AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
             AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
             AccountMeta::new_readonly(program_authority_pda, false),
+            AccountMeta::new(fee_vault, false),
+            AccountMeta::new(get_mint_registry_pda(&stake_mint).0, false),
         ],
         data: StakePoolInstruction::InitializePool {
             pool_id: 0,
@@ -515,6 +446,17 @@ fn test_initialize_pool_succeeds_without_freeze_authority() {
             lockup_period: 86400,
             enforce_lockup: false,
             pool_end_date: None,
+            referral_bps: 0,
+            early_unstake_bps: 0,
+            deposit_fee_bps: 0,
+            withdraw_fee_bps: 0,
+            reward_rate_change_delay: None,
+            min_reward_rate_bound: None,
+            max_reward_rate_bound: None,
+            reward_tiers: vec![],
+            bonus_end_ts: None,
+            bonus_multiplier_bps: 0,
+            reward_rate_mode: RewardRateMode::PerLockup,
         }
         .try_to_vec()
         .unwrap(),
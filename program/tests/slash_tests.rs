@@ -0,0 +1,157 @@
+// ============================================================================
+// Slash Tests
+// ============================================================================
+// Global admins slash misbehaving stakers in two steps: ProposeSlash records a
+// SlashProposal and freezes the stake's principal, and ExecuteSlash moves the
+// slashed share out of the stake vault once SLASH_TIMELOCK has passed. The
+// slashed principal leaves total_staked and its unclaimed rewards are
+// forfeited from total_rewards_owed, like an unstake.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    constants::SLASH_TIMELOCK,
+    error::StakePoolError,
+    state::{Key, SlashProposal, StakeAccount, StakePool},
+};
+
+use common::*;
+
+const TEN_PERCENT: u64 = 100_000_000;
+
+/// A 10% pool with one 1_000_000 token stake whose round reward is reserved
+fn staked() -> (StakePool, StakeAccount) {
+    let mut pool = sample_stake_pool();
+    pool.reward_rate = TEN_PERCENT;
+
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000_000);
+    stake.locked_reward_rate = TEN_PERCENT;
+    pool.total_staked = stake.amount_staked;
+    pool.total_rewards_owed = stake.round_rewards().unwrap();
+    pool.active_stake_count = Some(1);
+    (pool, stake)
+}
+
+fn slash_pending<T>() -> Result<T, ProgramError> {
+    Err(StakePoolError::StakeSlashPending.into())
+}
+
+#[test]
+fn test_slash_removes_the_share_and_its_rewards() {
+    let (mut pool, mut stake) = staked();
+
+    let (slashed, forfeited) = pool.slash_stake(&mut stake, 2_500).unwrap();
+
+    assert_eq!(slashed, 250_000);
+    assert_eq!(forfeited, 25_000);
+    assert_eq!(stake.amount_staked, 750_000);
+    assert_eq!(pool.total_staked, 750_000);
+    assert_eq!(pool.total_rewards_owed, 75_000);
+    // The remainder keeps its round
+    assert_eq!(stake.stake_timestamp, SAMPLE_STAKE_TIMESTAMP);
+    assert_eq!(pool.active_stake_count, Some(1));
+}
+
+#[test]
+fn test_slash_covers_pending_withdrawals() {
+    let (mut pool, mut stake) = staked();
+    pool.unstake_cooldown = 86_400;
+    pool.request_unstake(&mut stake, 400_000, SAMPLE_STAKE_TIMESTAMP + 86_400)
+        .unwrap();
+
+    let (slashed, _) = pool.slash_stake(&mut stake, 5_000).unwrap();
+
+    assert_eq!(slashed, 300_000 + 200_000);
+    assert_eq!(stake.amount_staked, 300_000);
+    assert_eq!(stake.pending_withdraw_amount, 200_000);
+    assert_eq!(pool.total_staked, 500_000);
+}
+
+#[test]
+fn test_full_slash_empties_the_stake() {
+    let (mut pool, mut stake) = staked();
+    stake.claimed_rewards = 40_000;
+
+    let (slashed, forfeited) = pool.slash_stake(&mut stake, 10_000).unwrap();
+
+    assert_eq!(slashed, 1_000_000);
+    assert_eq!(forfeited, 60_000);
+    assert_eq!(stake.amount_staked, 0);
+    assert_eq!(stake.claimed_rewards, 0);
+    assert_eq!(stake.stake_timestamp, 0);
+    assert_eq!(pool.total_staked, 0);
+    assert_eq!(pool.total_rewards_owed, 40_000);
+    assert_eq!(pool.active_stake_count, Some(0));
+}
+
+#[test]
+fn test_slash_shrinks_the_vesting_grant() {
+    let (mut pool, mut stake) = staked();
+    stake.vesting_amount = 1_000_000;
+    stake.vesting_start = SAMPLE_STAKE_TIMESTAMP;
+    stake.vesting_duration = 400;
+
+    pool.slash_stake(&mut stake, 1_000).unwrap();
+
+    assert_eq!(stake.vesting_amount, 900_000);
+    assert_eq!(
+        stake.unvested_amount(SAMPLE_STAKE_TIMESTAMP).unwrap(),
+        stake.amount_staked
+    );
+}
+
+#[test]
+fn test_pending_slash_freezes_the_principal() {
+    let (mut pool, mut stake) = staked();
+    pool.lockup_period = 0;
+    stake.pending_slash_bps = 1_000;
+    let now = SAMPLE_STAKE_TIMESTAMP + 1;
+
+    assert_eq!(pool.check_unstake_lockup(&stake, 1, now), slash_pending());
+    assert_eq!(pool.request_unstake(&mut stake, 1, now), slash_pending());
+
+    stake.pending_withdraw_amount = 10;
+    stake.request_timestamp = Some(SAMPLE_STAKE_TIMESTAMP);
+    assert_eq!(
+        pool.complete_unstake_request(&mut stake, now),
+        slash_pending()
+    );
+
+    let new_owner = Pubkey::new_unique();
+    stake.pending_owner = Some(new_owner);
+    assert!(matches!(
+        stake.transfer_to(&new_owner, 0, 255),
+        Err(e) if e == StakePoolError::StakeSlashPending.into()
+    ));
+
+    stake.pending_slash_bps = 0;
+    assert!(pool.check_unstake_lockup(&stake, 1, now).is_ok());
+}
+
+#[test]
+fn test_proposal_waits_for_the_timelock() {
+    let proposed_at = SAMPLE_STAKE_TIMESTAMP;
+    let stake_account = Pubkey::new_unique();
+    let (_, bump) = SlashProposal::find_pda(&stake_account);
+    let proposal = SlashProposal {
+        key: Key::SlashProposal,
+        pool: Pubkey::new_unique(),
+        stake_account,
+        proposer: Pubkey::new_unique(),
+        slash_bps: 1_000,
+        treasury: Some(Pubkey::new_unique()),
+        executable_at: SlashProposal::executable_after(proposed_at).unwrap(),
+        bump,
+    };
+
+    assert_eq!(
+        proposal.check_executable(proposed_at + SLASH_TIMELOCK - 1),
+        Err(StakePoolError::SlashTimelockActive.into())
+    );
+    assert!(proposal
+        .check_executable(proposed_at + SLASH_TIMELOCK)
+        .is_ok());
+    assert_eq!(borsh::to_vec(&proposal).unwrap().len(), SlashProposal::LEN);
+}
@@ -3,10 +3,10 @@
 // ============================================================================
 // Full integration tests using SPL Token 2022 program in LiteSVM
 //
-// The tests will automatically download the SPL Token 2022 program if needed.
-// Manual setup: solana program dump TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb /tmp/spl_token_2022.so
+// The SPL Token 2022 program is loaded from target/deploy.
+// Setup: cargo xtask dump-token
 //
-// Run tests: cargo test --test spl_token_tests
+// Run tests: cargo xtask test-all --test spl_token_tests
 
 #![allow(deprecated)]
 
@@ -27,94 +27,10 @@ use spl_token_2022::{
     state::{Account as TokenAccount, Mint},
 };
 use your_wallet_stake_pool::instruction::StakePoolInstruction;
+use your_wallet_stake_pool::state::{RewardRateMode, WindDownRewards};
 
 use common::*;
 
-// ============================================================================
-// Helper: Load SPL Token 2022 Program
-// ============================================================================
-
-fn load_spl_token_program() -> Vec<u8> {
-    const PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
-    const OUTPUT_PATH: &str = "/tmp/spl_token_2022.so";
-
-    let paths = [
-        OUTPUT_PATH,
-        "spl_token_2022.so",
-        "program/spl_token_2022.so",
-    ];
-
-    // Try to load from existing paths
-    for path in &paths {
-        if let Ok(data) = std::fs::read(path) {
-            println!("✅ Loaded SPL Token 2022 from: {}", path);
-            return data;
-        }
-    }
-
-    // Program not found - try to download it automatically
-    println!("📦 SPL Token 2022 program not found, downloading...");
-
-    if download_spl_token_program(PROGRAM_ID, OUTPUT_PATH) {
-        if let Ok(data) = std::fs::read(OUTPUT_PATH) {
-            println!(
-                "✅ Downloaded and loaded SPL Token 2022 from: {}",
-                OUTPUT_PATH
-            );
-            return data;
-        }
-    }
-
-    eprintln!("\n❌ Failed to load SPL Token 2022 program!");
-    eprintln!("   Automatic download failed. Please run manually:");
-    eprintln!("   $ solana program dump {} {}", PROGRAM_ID, OUTPUT_PATH);
-    panic!("SPL Token 2022 program required");
-}
-
-/// Attempt to download SPL Token program using solana CLI
-fn download_spl_token_program(program_id: &str, output_path: &str) -> bool {
-    use std::process::Command;
-
-    // Check if solana CLI is available
-    let solana_check = Command::new("solana").arg("--version").output();
-
-    if solana_check.is_err() {
-        eprintln!("   ⚠️  solana CLI not found in PATH");
-        return false;
-    }
-
-    println!(
-        "   Running: solana program dump {} {}",
-        program_id, output_path
-    );
-
-    // Try to dump the program
-    let result = Command::new("solana")
-        .arg("program")
-        .arg("dump")
-        .arg(program_id)
-        .arg(output_path)
-        .output();
-
-    match result {
-        Ok(output) if output.status.success() => {
-            println!("   ✅ Download successful!");
-            true
-        }
-        Ok(output) => {
-            eprintln!(
-                "   ⚠️  Download failed: {}",
-                String::from_utf8_lossy(&output.stderr)
-            );
-            false
-        }
-        Err(e) => {
-            eprintln!("   ⚠️  Failed to execute solana command: {}", e);
-            false
-        }
-    }
-}
-
 // ============================================================================
 // Helper: Create Token Mint
 // ============================================================================
@@ -406,12 +322,10 @@ fn test_initialize_pool_with_real_tokens() {
     // Derive PDAs
     let (pool_pda, _) = get_pool_pda(&stake_mint, 0);
 
-    // Create vault token accounts (owned by pool PDA)
-    let stake_vault_account = create_token_account(&mut svm, &payer, &stake_mint, &pool_pda);
-    let reward_vault_account = create_token_account(&mut svm, &payer, &reward_mint, &pool_pda);
-
-    println!("✅ Created stake vault: {}", stake_vault_account);
-    println!("✅ Created reward vault: {}", reward_vault_account);
+    // Vault PDAs (created by InitializePool, owned by the pool PDA)
+    let (stake_vault_account, _) = get_stake_vault_pda(&pool_pda);
+    let (reward_vault_account, _) = get_reward_vault_pda(&pool_pda);
+    let (fee_vault_account, _) = get_fee_vault_pda(&pool_pda);
 
     // Initialize pool
     let init_pool_ix = Instruction {
@@ -427,6 +341,8 @@ fn test_initialize_pool_with_real_tokens() {
             AccountMeta::new_readonly(solana_sdk::system_program::id(), false),
             AccountMeta::new_readonly(solana_sdk::sysvar::rent::id(), false),
             AccountMeta::new_readonly(program_authority_pda, false),
+            AccountMeta::new(fee_vault_account, false),
+            AccountMeta::new(get_mint_registry_pda(&stake_mint).0, false),
         ],
         data: StakePoolInstruction::InitializePool {
             pool_id: 0,
@@ -435,6 +351,17 @@ fn test_initialize_pool_with_real_tokens() {
             lockup_period: 86400,
             enforce_lockup: false,
             pool_end_date: None,
+            referral_bps: 0,
+            early_unstake_bps: 0,
+            deposit_fee_bps: 0,
+            withdraw_fee_bps: 0,
+            reward_rate_change_delay: None,
+            min_reward_rate_bound: None,
+            max_reward_rate_bound: None,
+            reward_tiers: vec![],
+            bonus_end_ts: None,
+            bonus_multiplier_bps: 0,
+            reward_rate_mode: RewardRateMode::PerLockup,
         }
         .try_to_vec()
         .unwrap(),
@@ -466,16 +393,13 @@ fn test_initialize_pool_with_real_tokens() {
         println!("   Pool account owner: {}", pool_account.owner);
 
         // Try to deserialize pool
-        match load_stake_pool(&svm, &pool_pda) {
-            pool => {
-                assert_eq!(pool.stake_mint, stake_mint);
-                assert_eq!(pool.reward_mint, reward_mint);
-                println!("✅ Pool verification passed");
-                println!("   Reward rate: {}", pool.reward_rate);
-                println!("   Min stake: {}", pool.min_stake_amount);
-                println!("   Lockup period: {}", pool.lockup_period);
-            }
-        }
+        let pool = load_stake_pool(&svm, &pool_pda);
+        assert_eq!(pool.stake_mint, stake_mint);
+        assert_eq!(pool.reward_mint, reward_mint);
+        println!("✅ Pool verification passed");
+        println!("   Reward rate: {}", pool.reward_rate);
+        println!("   Min stake: {}", pool.min_stake_amount);
+        println!("   Lockup period: {}", pool.lockup_period);
     }
 }
 
@@ -486,11 +410,11 @@ fn test_initialize_pool_with_real_tokens() {
 #[test]
 fn test_stake_pool_serialized_size() {
     use borsh::BorshSerialize;
-    use your_wallet_stake_pool::state::{Key, StakePool};
+    use your_wallet_stake_pool::state::{Key, OutflowWindow, StakePool};
 
     // Create a StakePool instance with None optionals
     let pool = StakePool {
-        key: Key::StakePool,
+        key: Key::StakePoolV2,
         stake_mint: Pubkey::new_unique(),
         reward_mint: Pubkey::new_unique(),
         pool_id: 0,
@@ -508,7 +432,53 @@ fn test_stake_pool_serialized_size() {
         pending_reward_rate: None,
         reward_rate_change_timestamp: None,
         last_rate_change: None,
-        _reserved: [0; 7],
+        referral_bps: 0,
+        early_unstake_bps: 0,
+        stake_vault_bump: None,
+        reward_vault_bump: None,
+        fee_vault: Pubkey::new_unique(),
+        deposit_fee_bps: 0,
+        withdraw_fee_bps: 0,
+        fee_vault_bump: None,
+        total_rewards_funded: 0,
+        reward_funders: [None; StakePool::MAX_REWARD_FUNDERS],
+        receipt_mint: None,
+        expired_rewards: 0,
+        unstake_cooldown: 0,
+        is_registered: false,
+        reward_rate_change_delay: 0,
+        pending_lockup_period: None,
+        pending_enforce_lockup: None,
+        lockup_change_timestamp: None,
+        min_reward_rate_bound: None,
+        max_reward_rate_bound: None,
+        active_stake_count: None,
+        reward_tiers: [None; StakePool::MAX_REWARD_TIERS],
+        bonus_end_ts: None,
+        bonus_multiplier_bps: 0,
+        lockup_extension_rate: 0,
+        allowlist_required: false,
+        allowlist_merkle_root: None,
+        claim_window: None,
+        outflow_limit: None,
+        outflow_window: OutflowWindow::default(),
+        pool_manager: None,
+        keeper_bounty: 0,
+        last_keeper_bounty_at: 0,
+        max_total_rewards: None,
+        total_rewards_committed: 0,
+        reward_rate_mode: RewardRateMode::PerLockup,
+        reward_decimals_shift: 0,
+        enforce_min_remaining_stake: false,
+        wind_down_rewards: WindDownRewards::Expire,
+        hook_program: None,
+        points_rate: 0,
+        deny_list_required: false,
+        allow_third_party_stake: false,
+        event_nonce: 0,
+        cohort_duration: 0,
+        open_reward_cohorts: 0,
+        _reserved: [0; 2],
     };
 
     // Serialize it
@@ -527,7 +497,7 @@ fn test_stake_pool_serialized_size() {
 
     // Now test with Some values
     let pool_with_optionals = StakePool {
-        key: Key::StakePool,
+        key: Key::StakePoolV2,
         stake_mint: Pubkey::new_unique(),
         reward_mint: Pubkey::new_unique(),
         pool_id: 0,
@@ -545,7 +515,53 @@ fn test_stake_pool_serialized_size() {
         pending_reward_rate: Some(5_000_000_000),
         reward_rate_change_timestamp: Some(1700000000),
         last_rate_change: Some(1699000000),
-        _reserved: [0; 7],
+        referral_bps: 0,
+        early_unstake_bps: 0,
+        stake_vault_bump: None,
+        reward_vault_bump: None,
+        fee_vault: Pubkey::new_unique(),
+        deposit_fee_bps: 0,
+        withdraw_fee_bps: 0,
+        fee_vault_bump: None,
+        total_rewards_funded: 0,
+        reward_funders: [None; StakePool::MAX_REWARD_FUNDERS],
+        receipt_mint: None,
+        expired_rewards: 0,
+        unstake_cooldown: 0,
+        is_registered: false,
+        reward_rate_change_delay: 0,
+        pending_lockup_period: None,
+        pending_enforce_lockup: None,
+        lockup_change_timestamp: None,
+        min_reward_rate_bound: None,
+        max_reward_rate_bound: None,
+        active_stake_count: None,
+        reward_tiers: [None; StakePool::MAX_REWARD_TIERS],
+        bonus_end_ts: None,
+        bonus_multiplier_bps: 0,
+        lockup_extension_rate: 0,
+        allowlist_required: false,
+        allowlist_merkle_root: None,
+        claim_window: None,
+        outflow_limit: None,
+        outflow_window: OutflowWindow::default(),
+        pool_manager: None,
+        keeper_bounty: 0,
+        last_keeper_bounty_at: 0,
+        max_total_rewards: None,
+        total_rewards_committed: 0,
+        reward_rate_mode: RewardRateMode::PerLockup,
+        reward_decimals_shift: 0,
+        enforce_min_remaining_stake: false,
+        wind_down_rewards: WindDownRewards::Expire,
+        hook_program: None,
+        points_rate: 0,
+        deny_list_required: false,
+        allow_third_party_stake: false,
+        event_nonce: 0,
+        cohort_duration: 0,
+        open_reward_cohorts: 0,
+        _reserved: [0; 2],
     };
 
     let serialized_with_optionals = pool_with_optionals.try_to_vec().unwrap();
@@ -570,16 +586,16 @@ fn test_spl_token_integration_summary() {
     println!("\n================================================================");
     println!("   LiteSVM + SPL Token 2022 Integration - Summary");
     println!("================================================================");
-    println!("");
+    println!();
     println!(" Strategy:");
     println!("  1. Auto-download SPL Token 2022 program (if needed)");
     println!("  2. Load program into LiteSVM");
     println!("  3. Run full integration tests");
-    println!("");
+    println!();
     println!(" Setup:");
     println!("  - Automatic! Just run: cargo test --test spl_token_tests");
     println!("  - The program will be downloaded automatically if missing");
-    println!("");
+    println!();
     println!(" Test Coverage:");
     println!("  - SPL Token program loading (auto-download)");
     println!("  - Mint creation");
@@ -587,14 +603,14 @@ fn test_spl_token_integration_summary() {
     println!("  - Token minting");
     println!("  - Balance checking");
     println!("  - Pool initialization with real tokens");
-    println!("");
+    println!();
     println!(" Benefits:");
     println!("  - Fast execution (< 1 second)");
     println!("  - Full token operation testing");
     println!("  - No validator needed");
     println!("  - Zero-config setup (auto-download)");
     println!("  - Complete integration coverage");
-    println!("");
+    println!();
     println!("================================================================\n");
 }
 
@@ -667,6 +683,17 @@ fn test_initialize_pool_rejects_attacker_owned_vaults() {
             lockup_period: 86400,
             enforce_lockup: false,
             pool_end_date: None,
+            referral_bps: 0,
+            early_unstake_bps: 0,
+            deposit_fee_bps: 0,
+            withdraw_fee_bps: 0,
+            reward_rate_change_delay: None,
+            min_reward_rate_bound: None,
+            max_reward_rate_bound: None,
+            reward_tiers: vec![],
+            bonus_end_ts: None,
+            bonus_multiplier_bps: 0,
+            reward_rate_mode: RewardRateMode::PerLockup,
         }
         .try_to_vec()
         .unwrap(),
@@ -0,0 +1,148 @@
+// ============================================================================
+// Split Stake Tests
+// ============================================================================
+// SplitStake moves part of a stake's principal into a new stake account of the
+// same owner. The new part keeps the original round (stake timestamp, locked
+// rate), takes its share of the claimed rewards, and between them the parts
+// reserve what the original did in the pool's total_rewards_owed.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    error::StakePoolError,
+    state::{StakeAccount, StakePool},
+};
+
+use common::*;
+
+const STAKED: u64 = 1_000_000;
+// sample_stake_pool pays 10% after its lockup
+const REWARDS: u64 = 100_000;
+
+/// A pool holding the sample stake, with 40_000 of its rewards already claimed
+fn setup() -> (StakePool, StakeAccount) {
+    let mut pool = sample_stake_pool();
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), STAKED);
+    stake.claimed_rewards = 40_000;
+    pool.total_staked = STAKED;
+    pool.total_rewards_owed = REWARDS - stake.claimed_rewards;
+    pool.active_stake_count = Some(1);
+    (pool, stake)
+}
+
+fn assert_error(err: ProgramError, expected: StakePoolError) {
+    assert_eq!(err, ProgramError::Custom(expected as u32));
+}
+
+#[test]
+fn test_split_keeps_the_round_of_the_original_stake() {
+    let (mut pool, mut stake) = setup();
+    let original = stake.clone();
+
+    let split = pool.split_stake(&mut stake, 250_000, 4, 254).unwrap();
+
+    assert_eq!(split.index, 4);
+    assert_eq!(split.bump, 254);
+    assert_eq!(split.owner, original.owner);
+    assert_eq!(split.pool, original.pool);
+    assert_eq!(split.stake_timestamp, original.stake_timestamp);
+    assert_eq!(split.locked_reward_rate, original.locked_reward_rate);
+    assert_eq!(stake.stake_timestamp, original.stake_timestamp);
+    assert_eq!(stake.index, original.index);
+}
+
+#[test]
+fn test_split_divides_principal_and_claimed_rewards_proportionally() {
+    let (mut pool, mut stake) = setup();
+
+    let split = pool.split_stake(&mut stake, 250_000, 1, 255).unwrap();
+
+    assert_eq!(split.amount_staked, 250_000);
+    assert_eq!(stake.amount_staked, 750_000);
+    assert_eq!(split.claimed_rewards, 10_000);
+    assert_eq!(stake.claimed_rewards, 30_000);
+    assert_eq!(split.unclaimed_rewards().unwrap(), 15_000);
+    assert_eq!(stake.unclaimed_rewards().unwrap(), 45_000);
+
+    // The principal stays in the pool; only the stake count grows
+    assert_eq!(pool.total_staked, STAKED);
+    assert_eq!(pool.total_rewards_owed, 60_000);
+    assert_eq!(pool.active_stake_count, Some(2));
+}
+
+#[test]
+fn test_split_conserves_reserved_rewards_when_rounding() {
+    let (mut pool, mut stake) = setup();
+    stake.amount_staked = 1_000_003;
+    stake.claimed_rewards = 100_000;
+    pool.total_rewards_owed = stake.unclaimed_rewards().unwrap();
+
+    let split = pool.split_stake(&mut stake, 333_334, 1, 255).unwrap();
+
+    // Neither part claimed more than it earns
+    assert!(split.claimed_rewards <= split.round_rewards().unwrap());
+    assert!(stake.claimed_rewards <= stake.round_rewards().unwrap());
+    assert_eq!(
+        pool.total_rewards_owed,
+        split.unclaimed_rewards().unwrap() + stake.unclaimed_rewards().unwrap()
+    );
+}
+
+#[test]
+fn test_split_leaves_the_pending_withdrawal_with_the_original() {
+    let (mut pool, mut stake) = setup();
+    stake.pending_withdraw_amount = 5_000;
+    stake.request_timestamp = Some(SAMPLE_STAKE_TIMESTAMP);
+    stake.carried_rewards = 9_000;
+    stake.early_unstaked = 3_000;
+    pool.total_rewards_owed += stake.carried_rewards;
+
+    let split = pool.split_stake(&mut stake, 500_000, 1, 255).unwrap();
+
+    assert_eq!(split.pending_withdraw_amount, 0);
+    assert_eq!(split.request_timestamp, None);
+    assert_eq!(stake.pending_withdraw_amount, 5_000);
+    assert_eq!(split.carried_rewards, 4_500);
+    assert_eq!(stake.carried_rewards, 4_500);
+    assert_eq!(split.early_unstaked, 1_500);
+    assert_eq!(stake.early_unstaked, 1_500);
+}
+
+#[test]
+fn test_split_rejects_empty_or_whole_amounts() {
+    let (mut pool, mut stake) = setup();
+
+    for amount in [0, STAKED, STAKED + 1] {
+        let err = pool.split_stake(&mut stake, amount, 1, 255).unwrap_err();
+        assert_error(err, StakePoolError::InvalidParameters);
+    }
+    assert_eq!(stake.amount_staked, STAKED);
+    assert_eq!(pool.active_stake_count, Some(1));
+}
+
+#[test]
+fn test_split_requires_both_parts_to_meet_the_minimum() {
+    let (mut pool, mut stake) = setup();
+    pool.min_stake_amount = 300_000;
+
+    for amount in [200_000, 800_000] {
+        let err = pool.split_stake(&mut stake, amount, 1, 255).unwrap_err();
+        assert_error(err, StakePoolError::AmountBelowMinimum);
+    }
+
+    let split = pool.split_stake(&mut stake, 300_000, 1, 255).unwrap();
+    assert_eq!(split.amount_staked, 300_000);
+    assert_eq!(stake.amount_staked, 700_000);
+}
+
+#[test]
+fn test_split_is_blocked_by_a_pending_slash() {
+    let (mut pool, mut stake) = setup();
+    stake.pending_slash_bps = 1_000;
+
+    let err = pool.split_stake(&mut stake, 250_000, 1, 255).unwrap_err();
+    assert_error(err, StakePoolError::StakeSlashPending);
+    assert_eq!(stake.amount_staked, STAKED);
+}
@@ -0,0 +1,88 @@
+// ============================================================================
+// Stake Batch Tests
+// ============================================================================
+// StakeBatch creates up to MAX_STAKE_BATCH stake accounts in one instruction,
+// one per amount, at consecutive indices. The batch shape is validated before
+// any account is loaded.
+
+use borsh::BorshSerialize;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    constants::MAX_STAKE_BATCH, error::StakePoolError, instruction::StakePoolInstruction,
+    processor::process_instruction, ID,
+};
+
+/// Number of named accounts of StakeBatch
+const NAMED_ACCOUNTS: usize = 17;
+
+/// Run StakeBatch with `amounts` over blank accounts, `stake_accounts` of them remaining
+fn run(amounts: Vec<u64>, stake_accounts: usize) -> Result<(), ProgramError> {
+    let count = NAMED_ACCOUNTS + stake_accounts;
+    let keys: Vec<Pubkey> = (0..count).map(|_| Pubkey::new_unique()).collect();
+    let mut lamports = vec![0u64; count];
+    let mut data = vec![Vec::<u8>::new(); count];
+    let owner = Pubkey::default();
+    let infos: Vec<AccountInfo> = keys
+        .iter()
+        .zip(lamports.iter_mut())
+        .zip(data.iter_mut())
+        .map(|((key, lamports), data)| {
+            AccountInfo::new(key, true, true, lamports, data, &owner, false, 0)
+        })
+        .collect();
+
+    let ix_data = StakePoolInstruction::StakeBatch {
+        amounts,
+        start_index: 0,
+        expected_reward_rate: None,
+        expected_lockup_period: None,
+        allowlist_proof: None,
+    }
+    .try_to_vec()
+    .unwrap();
+
+    process_instruction(&ID, &infos, &ix_data)
+}
+
+fn error(code: StakePoolError) -> ProgramError {
+    ProgramError::Custom(code as u32)
+}
+
+#[test]
+fn test_empty_batch_is_rejected() {
+    assert_eq!(
+        run(vec![], 0).unwrap_err(),
+        error(StakePoolError::InvalidParameters)
+    );
+}
+
+#[test]
+fn test_oversized_batch_is_rejected() {
+    let size = MAX_STAKE_BATCH + 1;
+    assert_eq!(
+        run(vec![1_000; size], size).unwrap_err(),
+        error(StakePoolError::InvalidParameters)
+    );
+}
+
+#[test]
+fn test_every_amount_needs_a_stake_account() {
+    assert_eq!(
+        run(vec![1_000; 3], 2).unwrap_err(),
+        ProgramError::NotEnoughAccountKeys
+    );
+    assert_eq!(
+        run(vec![1_000; 2], 3).unwrap_err(),
+        ProgramError::NotEnoughAccountKeys
+    );
+}
+
+#[test]
+fn test_zero_amount_in_batch_is_rejected() {
+    // Checked before the pool is loaded, so blank accounts suffice
+    assert_eq!(
+        run(vec![1_000, 0], 2).unwrap_err(),
+        error(StakePoolError::InvalidParameters)
+    );
+}
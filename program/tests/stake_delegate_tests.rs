@@ -0,0 +1,36 @@
+// ============================================================================
+// Stake Delegate Tests
+// ============================================================================
+// A stake owner may name a delegate that can claim rewards on their behalf.
+// The delegate never gains the owner's other rights and can only claim into
+// a reward token account owned by the owner.
+
+mod common;
+
+use solana_sdk::pubkey::Pubkey;
+
+use common::*;
+
+#[test]
+fn test_owner_can_always_claim() {
+    let owner = Pubkey::new_unique();
+    let stake = sample_stake_account(&Pubkey::new_unique(), &owner, 1_000_000);
+
+    assert!(stake.can_claim(&owner));
+    assert!(!stake.can_claim(&Pubkey::new_unique()));
+}
+
+#[test]
+fn test_delegate_can_claim_until_cleared() {
+    let owner = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &owner, 1_000_000);
+
+    stake.delegate = Some(delegate);
+    assert!(stake.can_claim(&delegate));
+    assert!(stake.can_claim(&owner));
+    assert!(!stake.can_claim(&Pubkey::new_unique()));
+
+    stake.delegate = None;
+    assert!(!stake.can_claim(&delegate));
+}
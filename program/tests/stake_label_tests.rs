@@ -0,0 +1,81 @@
+// ============================================================================
+// Stake Label Tests
+// ============================================================================
+// Owners may tag a stake account with a 32-byte label, set at stake time or
+// with SetStakeLabel. Stake accounts allocated before the label existed are
+// 32 bytes shorter and read as unlabeled until MigratePool grows them.
+
+mod common;
+
+use borsh::BorshDeserialize;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{instruction::StakePoolInstruction, state::StakeAccount};
+
+use common::*;
+
+fn label(text: &str) -> [u8; StakeAccount::LABEL_LEN] {
+    let mut label = [0; StakeAccount::LABEL_LEN];
+    label[..text.len()].copy_from_slice(text.as_bytes());
+    label
+}
+
+#[test]
+fn test_len_fits_a_labeled_stake_account() {
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000);
+    stake.referrer = Some(Pubkey::new_unique());
+    stake.delegate = Some(Pubkey::new_unique());
+    stake.request_timestamp = Some(1_700_000_000);
+    stake.label = [u8::MAX; StakeAccount::LABEL_LEN];
+    stake.pending_owner = Some(Pubkey::new_unique());
+
+    assert_eq!(stake.to_account_data().unwrap().len(), StakeAccount::LEN);
+}
+
+#[test]
+fn test_label_is_stored_after_every_earlier_field() {
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000);
+    stake.delegate = Some(Pubkey::new_unique());
+    stake.label = label("treasury-Q3");
+
+    // Only the pending owner (None, one byte), the lockup extension, the vesting
+    // schedule, the pending slash, the points checkpoint and the reward cohort
+    // follow the label
+    let data = borsh::to_vec(&stake).unwrap();
+    let label_end = data.len() - 1 - 8 - 32 - 2 - 8 - 8;
+    assert_eq!(
+        &data[label_end - StakeAccount::LABEL_LEN..label_end],
+        &stake.label
+    );
+}
+
+#[test]
+fn test_accounts_allocated_before_the_label_read_as_unlabeled() {
+    // A fully populated account in the pre-label layout fills it exactly
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000);
+    stake.referrer = Some(Pubkey::new_unique());
+    stake.delegate = Some(Pubkey::new_unique());
+    stake.request_timestamp = Some(1_700_000_000);
+    let mut data = borsh::to_vec(&stake).unwrap();
+    data.truncate(StakeAccount::LEN - StakeAccount::LABEL_LEN - 33 - 8 - 32 - 2 - 8 - 8);
+
+    // Loading reads the missing tail as zeros
+    data.resize(StakeAccount::LEN, 0);
+    let decoded = StakeAccount::deserialize(&mut data.as_slice()).unwrap();
+    assert_eq!(decoded.label, [0; StakeAccount::LABEL_LEN]);
+    assert_eq!(decoded.delegate, stake.delegate);
+}
+
+#[test]
+fn test_set_stake_label_round_trips() {
+    let instruction = StakePoolInstruction::SetStakeLabel {
+        label: label("treasury-Q3"),
+    };
+    let data = borsh::to_vec(&instruction).unwrap();
+
+    match StakePoolInstruction::try_from_slice(&data).unwrap() {
+        StakePoolInstruction::SetStakeLabel { label: parsed } => {
+            assert_eq!(parsed, label("treasury-Q3"))
+        }
+        _ => panic!("expected SetStakeLabel"),
+    }
+}
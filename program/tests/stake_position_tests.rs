@@ -0,0 +1,124 @@
+// ============================================================================
+// Stake Position Tests
+// ============================================================================
+// Stakes that pass the owner's stake position directory ["stake_position", pool,
+// owner] record their index there, and CloseStakeAccount removes it, so clients
+// can pick the next free index without probing stake account PDAs.
+
+use solana_program::{
+    account_info::AccountInfo, program::MAX_RETURN_DATA, program_error::ProgramError,
+};
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    error::StakePoolError,
+    processor::helpers::load_stake_position,
+    return_data::StakePositions,
+    state::{Key, StakePosition},
+    ID,
+};
+
+fn position(pool: Pubkey, owner: Pubkey) -> StakePosition {
+    StakePosition {
+        key: Key::StakePosition,
+        pool,
+        owner,
+        next_index: 0,
+        bump: StakePosition::find_pda(&pool, &owner).1,
+        indices: Vec::new(),
+    }
+}
+
+/// Account data of `position`, allocated at the full directory size
+fn position_data(position: &StakePosition) -> Vec<u8> {
+    let mut data = borsh::to_vec(position).unwrap();
+    data.resize(StakePosition::LEN, 0);
+    data
+}
+
+fn error(code: StakePoolError) -> ProgramError {
+    ProgramError::Custom(code as u32)
+}
+
+#[test]
+fn test_full_directory_fits_len_and_return_data() {
+    let mut directory = position(Pubkey::new_unique(), Pubkey::new_unique());
+    for index in 0..StakePosition::CAPACITY as u64 {
+        directory.record(index).unwrap();
+    }
+    assert_eq!(borsh::to_vec(&directory).unwrap().len(), StakePosition::LEN);
+
+    let positions = StakePositions {
+        next_index: directory.next_index,
+        indices: directory.indices,
+    };
+    assert!(borsh::to_vec(&positions).unwrap().len() <= MAX_RETURN_DATA);
+}
+
+#[test]
+fn test_record_keeps_indices_sorted_and_advances_next_index() {
+    let mut directory = position(Pubkey::new_unique(), Pubkey::new_unique());
+
+    directory.record(5).unwrap();
+    directory.record(1).unwrap();
+    directory.record(3).unwrap();
+    // Recording an index twice leaves it listed once
+    directory.record(3).unwrap();
+
+    assert_eq!(directory.indices, vec![1, 3, 5]);
+    assert_eq!(directory.next_index, 6);
+}
+
+#[test]
+fn test_release_keeps_next_index() {
+    let mut directory = position(Pubkey::new_unique(), Pubkey::new_unique());
+    directory.record(0).unwrap();
+    directory.record(1).unwrap();
+
+    directory.release(1);
+    // Releasing an unknown index is a no-op
+    directory.release(9);
+
+    assert_eq!(directory.indices, vec![0]);
+    assert_eq!(directory.next_index, 2);
+}
+
+#[test]
+fn test_record_stops_at_capacity() {
+    let mut directory = position(Pubkey::new_unique(), Pubkey::new_unique());
+    for index in 0..StakePosition::CAPACITY as u64 {
+        directory.record(index).unwrap();
+    }
+
+    assert_eq!(
+        directory
+            .record(StakePosition::CAPACITY as u64)
+            .unwrap_err(),
+        error(StakePoolError::StakePositionFull)
+    );
+    // Indices already listed are still accepted
+    directory.record(0).unwrap();
+
+    directory.release(0);
+    directory.record(StakePosition::CAPACITY as u64).unwrap();
+    assert_eq!(directory.next_index, StakePosition::CAPACITY as u64 + 1);
+}
+
+#[test]
+fn test_load_requires_the_owners_pda() {
+    let pool = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut directory = position(pool, owner);
+    directory.record(2).unwrap();
+
+    let key = StakePosition::find_pda(&pool, &owner).0;
+    let mut lamports = 1_000_000;
+    let mut data = position_data(&directory);
+    let account = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &ID, false, 0);
+
+    let loaded = load_stake_position(&account, &pool, &owner).unwrap();
+    assert_eq!(loaded.indices, vec![2]);
+    assert_eq!(loaded.next_index, 3);
+
+    // Another owner's directory is rejected
+    assert!(load_stake_position(&account, &pool, &Pubkey::new_unique()).is_err());
+}
@@ -0,0 +1,114 @@
+// ============================================================================
+// Stake Ownership Transfer Tests
+// ============================================================================
+// An owner nominates a new wallet with NominateStakeOwner; the nominee accepts
+// with AcceptStakeOwnership, which moves the position into a stake account
+// derived from the new owner and closes the previous one. Stake accounts
+// allocated before the nomination existed read as having no pending owner.
+
+mod common;
+
+use borsh::BorshDeserialize;
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    error::StakePoolError, instruction::StakePoolInstruction, state::StakeAccount,
+};
+
+use common::*;
+
+fn nominated_stake(new_owner: &Pubkey) -> StakeAccount {
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000_000);
+    stake.claimed_rewards = 25_000;
+    stake.carried_rewards = 10_000;
+    stake.referrer = Some(Pubkey::new_unique());
+    stake.delegate = Some(Pubkey::new_unique());
+    stake.pending_withdraw_amount = 5_000;
+    stake.request_timestamp = Some(1_700_000_100);
+    stake.label[..8].copy_from_slice(b"treasury");
+    stake.pending_owner = Some(*new_owner);
+    stake
+}
+
+#[test]
+fn test_transfer_moves_the_position_to_the_new_owner() {
+    let new_owner = Pubkey::new_unique();
+    let stake = nominated_stake(&new_owner);
+    let (_, bump) = StakeAccount::find_pda(&stake.pool, &new_owner, 3);
+
+    let moved = stake.transfer_to(&new_owner, 3, bump).unwrap();
+
+    assert_eq!(moved.owner, new_owner);
+    assert_eq!(moved.index, 3);
+    assert_eq!(moved.bump, bump);
+    assert_eq!(moved.pool, stake.pool);
+
+    // Amounts, timestamps and rewards carry over unchanged
+    assert_eq!(moved.amount_staked, stake.amount_staked);
+    assert_eq!(moved.stake_timestamp, stake.stake_timestamp);
+    assert_eq!(moved.claimed_rewards, stake.claimed_rewards);
+    assert_eq!(moved.carried_rewards, stake.carried_rewards);
+    assert_eq!(moved.locked_reward_rate, stake.locked_reward_rate);
+    assert_eq!(moved.pending_withdraw_amount, stake.pending_withdraw_amount);
+    assert_eq!(moved.request_timestamp, stake.request_timestamp);
+    assert_eq!(moved.referrer, stake.referrer);
+    assert_eq!(moved.label, stake.label);
+
+    // The previous owner's delegate and the nomination do not carry over
+    assert_eq!(moved.delegate, None);
+    assert_eq!(moved.pending_owner, None);
+}
+
+#[test]
+fn test_transfer_requires_the_nominated_owner() {
+    let new_owner = Pubkey::new_unique();
+    let mut stake = nominated_stake(&new_owner);
+
+    assert_eq!(
+        stake
+            .transfer_to(&Pubkey::new_unique(), 0, 255)
+            .unwrap_err(),
+        ProgramError::Custom(StakePoolError::InvalidPendingStakeOwner as u32)
+    );
+
+    stake.pending_owner = None;
+    assert_eq!(
+        stake.transfer_to(&new_owner, 0, 255).unwrap_err(),
+        ProgramError::Custom(StakePoolError::NoPendingStakeOwner as u32)
+    );
+}
+
+#[test]
+fn test_accounts_allocated_before_nominations_have_no_pending_owner() {
+    let mut stake = nominated_stake(&Pubkey::new_unique());
+    stake.pending_owner = None;
+    let mut data = stake.to_account_data().unwrap();
+    assert_eq!(data.len(), StakeAccount::LEN - 32);
+    data.truncate(data.len() - 1 - 8 - 32 - 2 - 8 - 8);
+
+    // Loading reads the missing tail as zeros
+    let decoded = StakeAccount::from_account_data(&data).unwrap();
+    assert_eq!(decoded.pending_owner, None);
+    assert_eq!(decoded.label, stake.label);
+}
+
+#[test]
+fn test_transfer_instructions_round_trip() {
+    let new_owner = Pubkey::new_unique();
+    let data = borsh::to_vec(&StakePoolInstruction::NominateStakeOwner {
+        new_owner: Some(new_owner),
+    })
+    .unwrap();
+    match StakePoolInstruction::try_from_slice(&data).unwrap() {
+        StakePoolInstruction::NominateStakeOwner { new_owner: parsed } => {
+            assert_eq!(parsed, Some(new_owner))
+        }
+        _ => panic!("expected NominateStakeOwner"),
+    }
+
+    let data = borsh::to_vec(&StakePoolInstruction::AcceptStakeOwnership { new_index: 9 }).unwrap();
+    match StakePoolInstruction::try_from_slice(&data).unwrap() {
+        StakePoolInstruction::AcceptStakeOwnership { new_index } => assert_eq!(new_index, 9),
+        _ => panic!("expected AcceptStakeOwnership"),
+    }
+}
@@ -0,0 +1,47 @@
+// ============================================================================
+// Pool Solvency Sync Tests
+// ============================================================================
+// The permissionless SyncPool crank compares live vault balances with the
+// pool's recorded totals and pauses the pool when either vault falls short.
+
+mod common;
+
+use common::*;
+
+#[test]
+fn test_pool_solvent_when_vaults_match_totals() {
+    let mut pool = sample_stake_pool();
+    pool.total_staked = 1_000_000;
+    pool.total_rewards_owed = 100_000;
+
+    assert!(pool.is_solvent(1_000_000, 100_000));
+}
+
+#[test]
+fn test_pool_solvent_with_vault_surplus() {
+    let mut pool = sample_stake_pool();
+    pool.total_staked = 1_000_000;
+    pool.total_rewards_owed = 100_000;
+
+    // Donations and rounding dust never make a pool insolvent
+    assert!(pool.is_solvent(1_000_001, 250_000));
+}
+
+#[test]
+fn test_pool_insolvent_on_stake_vault_shortfall() {
+    let mut pool = sample_stake_pool();
+    pool.total_staked = 1_000_000;
+    pool.total_rewards_owed = 100_000;
+
+    assert!(!pool.is_solvent(999_999, 100_000));
+}
+
+#[test]
+fn test_pool_insolvent_on_reward_vault_shortfall() {
+    let mut pool = sample_stake_pool();
+    pool.total_staked = 1_000_000;
+    pool.total_rewards_owed = 100_000;
+
+    assert!(!pool.is_solvent(1_000_000, 99_999));
+    assert!(pool.verify_solvency(99_999).is_err());
+}
@@ -0,0 +1,145 @@
+// ============================================================================
+// Token-2022 Extension Policy Tests
+// ============================================================================
+// validate_token_extensions runs on the stake and reward mints at pool
+// initialization. Mints with extensions that break the vault accounting are
+// rejected with UnsafeTokenExtension, including confidential transfer mints
+// whose encrypted balances are not part of a token account's public amount and
+// interest-bearing mints whose displayed amounts drift from the raw amounts the
+// protocol accounts in.
+
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    error::StakePoolError,
+    processor::helpers::validate_token_extensions,
+    spl_token_2022::{
+        self,
+        extension::{
+            confidential_transfer::ConfidentialTransferMint,
+            confidential_transfer_fee::ConfidentialTransferFeeConfig,
+            interest_bearing_mint::InterestBearingConfig, transfer_fee::TransferFeeConfig,
+            transfer_hook::TransferHook, BaseStateWithExtensionsMut, ExtensionType,
+            StateWithExtensionsMut,
+        },
+        state::Mint,
+    },
+};
+
+/// Serialized Token-2022 mint data carrying the extensions initialized by `init`
+fn mint_data(
+    extensions: &[ExtensionType],
+    init: impl FnOnce(&mut StateWithExtensionsMut<Mint>),
+) -> Vec<u8> {
+    let len = ExtensionType::try_calculate_account_len::<Mint>(extensions).unwrap();
+    let mut data = vec![0u8; len];
+
+    let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+    init(&mut state);
+    state.base = Mint {
+        mint_authority: Some(Pubkey::new_unique()).into(),
+        supply: 0,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: None.into(),
+    };
+    state.pack_base();
+    if !extensions.is_empty() {
+        state.init_account_type().unwrap();
+    }
+    data
+}
+
+/// Mint data with a single default-initialized extension
+fn mint_with(extension: ExtensionType) -> Vec<u8> {
+    mint_data(&[extension], |state| match extension {
+        ExtensionType::TransferFeeConfig => {
+            state.init_extension::<TransferFeeConfig>(true).unwrap();
+        }
+        ExtensionType::ConfidentialTransferMint => {
+            state
+                .init_extension::<ConfidentialTransferMint>(true)
+                .unwrap();
+        }
+        ExtensionType::InterestBearingConfig => {
+            state.init_extension::<InterestBearingConfig>(true).unwrap();
+        }
+        ExtensionType::TransferHook => {
+            state.init_extension::<TransferHook>(true).unwrap();
+        }
+        _ => unimplemented!("{:?}", extension),
+    })
+}
+
+fn validate(mut data: Vec<u8>) -> Result<(), ProgramError> {
+    let key = Pubkey::new_unique();
+    let owner = spl_token_2022::ID;
+    let mut lamports = 0;
+    let mint = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    validate_token_extensions(&mint, "stake_mint")
+}
+
+#[test]
+fn test_mint_without_extensions_is_accepted() {
+    assert!(validate(mint_data(&[], |_| {})).is_ok());
+}
+
+#[test]
+fn test_transfer_fee_mint_is_accepted() {
+    assert!(validate(mint_with(ExtensionType::TransferFeeConfig)).is_ok());
+}
+
+#[test]
+fn test_confidential_transfer_mint_is_rejected() {
+    assert_eq!(
+        validate(mint_with(ExtensionType::ConfidentialTransferMint)),
+        Err(StakePoolError::UnsafeTokenExtension.into())
+    );
+}
+
+#[test]
+fn test_confidential_transfer_fee_mint_is_rejected() {
+    let data = mint_data(
+        &[
+            ExtensionType::TransferFeeConfig,
+            ExtensionType::ConfidentialTransferMint,
+            ExtensionType::ConfidentialTransferFeeConfig,
+        ],
+        |state| {
+            state.init_extension::<TransferFeeConfig>(true).unwrap();
+            state
+                .init_extension::<ConfidentialTransferFeeConfig>(true)
+                .unwrap();
+        },
+    );
+
+    assert_eq!(
+        validate(data),
+        Err(StakePoolError::UnsafeTokenExtension.into())
+    );
+}
+
+#[test]
+fn test_transfer_hook_mint_is_still_rejected() {
+    assert_eq!(
+        validate(mint_with(ExtensionType::TransferHook)),
+        Err(StakePoolError::UnsafeTokenExtension.into())
+    );
+}
+
+#[test]
+fn test_interest_bearing_mint_is_rejected() {
+    assert_eq!(
+        validate(mint_with(ExtensionType::InterestBearingConfig)),
+        Err(StakePoolError::UnsafeTokenExtension.into())
+    );
+}
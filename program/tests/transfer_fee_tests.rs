@@ -0,0 +1,304 @@
+// ============================================================================
+// Transfer Fee Accounting Tests
+// ============================================================================
+// transfer_tokens_with_fee returns what the recipient actually received. Stake
+// credits that amount; Unstake debits the stake vault the full requested amount,
+// fees only reducing what the owner and the fee vault receive. The expected
+// amount is computed from the mint's TransferFeeConfig for the current epoch.
+// The LiteSVM test at the end checks the stake vault against total_staked on a
+// real fee mint.
+//
+// Run tests: cargo xtask test-all --test transfer_fee_tests
+
+#![allow(deprecated)]
+
+mod common;
+
+use litesvm::LiteSVM;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+};
+use your_wallet_stake_pool::spl_token_2022::{
+    self,
+    extension::{
+        transfer_fee::{self, TransferFee, TransferFeeConfig},
+        BaseStateWithExtensionsMut, ExtensionType, StateWithExtensions, StateWithExtensionsMut,
+    },
+    instruction as token_instruction,
+    state::{Account as TokenAccount, Mint},
+};
+use your_wallet_stake_pool::utils::transfer_amount_after_fee;
+use your_wallet_stake_pool_client::{
+    instruction::{self, InitializePoolArgs, StakeArgs},
+    test_utils::{self, warp_to},
+    PoolKeys,
+};
+
+use common::*;
+
+/// A fee of `basis_points` capped at `maximum_fee`, in effect from `epoch`
+fn transfer_fee(epoch: u64, basis_points: u16, maximum_fee: u64) -> TransferFee {
+    TransferFee {
+        epoch: epoch.into(),
+        maximum_fee: maximum_fee.into(),
+        transfer_fee_basis_points: basis_points.into(),
+    }
+}
+
+/// Serialized mint data, with a TransferFeeConfig extension when fees are given
+fn mint_data(fees: Option<(TransferFee, TransferFee)>) -> Vec<u8> {
+    let extensions: &[ExtensionType] = if fees.is_some() {
+        &[ExtensionType::TransferFeeConfig]
+    } else {
+        &[]
+    };
+    let len = ExtensionType::try_calculate_account_len::<Mint>(extensions).unwrap();
+    let mut data = vec![0u8; len];
+
+    let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+    if let Some((older, newer)) = fees {
+        let config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+        config.older_transfer_fee = older;
+        config.newer_transfer_fee = newer;
+    }
+    state.base = Mint {
+        mint_authority: Some(Pubkey::new_unique()).into(),
+        supply: 1_000_000_000,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: None.into(),
+    };
+    state.pack_base();
+    if fees.is_some() {
+        state.init_account_type().unwrap();
+    }
+    data
+}
+
+fn amount_after_fee(data: &[u8], amount: u64, epoch: u64) -> u64 {
+    let mint = StateWithExtensions::<Mint>::unpack(data).unwrap();
+    transfer_amount_after_fee(&mint, amount, epoch).unwrap()
+}
+
+#[test]
+fn test_mint_without_fee_transfers_full_amount() {
+    let data = mint_data(None);
+
+    assert_eq!(amount_after_fee(&data, 1_000_000, 0), 1_000_000);
+}
+
+#[test]
+fn test_fee_is_deducted_from_received_amount() {
+    // 1% fee, capped well above the transferred amount
+    let fee = transfer_fee(0, 100, 1_000_000);
+    let data = mint_data(Some((fee, fee)));
+
+    assert_eq!(amount_after_fee(&data, 1_000_000, 0), 990_000);
+    // Fees round up, so small transfers still pay at least one token
+    assert_eq!(amount_after_fee(&data, 150, 0), 148);
+}
+
+#[test]
+fn test_fee_is_capped_at_maximum() {
+    let fee = transfer_fee(0, 100, 5_000);
+    let data = mint_data(Some((fee, fee)));
+
+    assert_eq!(amount_after_fee(&data, 1_000_000, 0), 995_000);
+}
+
+#[test]
+fn test_fee_follows_the_current_epoch() {
+    // 1% until epoch 10, 5% from then on
+    let older = transfer_fee(0, 100, u64::MAX);
+    let newer = transfer_fee(10, 500, u64::MAX);
+    let data = mint_data(Some((older, newer)));
+
+    assert_eq!(amount_after_fee(&data, 1_000_000, 9), 990_000);
+    assert_eq!(amount_after_fee(&data, 1_000_000, 10), 950_000);
+}
+
+#[test]
+fn test_zero_fee_config_transfers_full_amount() {
+    let fee = transfer_fee(0, 0, 0);
+    let data = mint_data(Some((fee, fee)));
+
+    assert_eq!(amount_after_fee(&data, 1_000_000, 0), 1_000_000);
+}
+
+// ============================================================================
+// LiteSVM: Stake and Unstake on a Fee Mint
+// ============================================================================
+
+/// Create a Token-2022 mint charging `basis_points` on every transfer
+fn create_fee_mint(svm: &mut LiteSVM, authority: &Keypair, basis_points: u16) -> Pubkey {
+    let mint = Keypair::new();
+    let len = ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::TransferFeeConfig])
+        .unwrap();
+    let instructions = [
+        system_instruction::create_account(
+            &authority.pubkey(),
+            &mint.pubkey(),
+            svm.minimum_balance_for_rent_exemption(len),
+            len as u64,
+            &spl_token_2022::id(),
+        ),
+        transfer_fee::instruction::initialize_transfer_fee_config(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            Some(&authority.pubkey()),
+            Some(&authority.pubkey()),
+            basis_points,
+            u64::MAX,
+        )
+        .unwrap(),
+        token_instruction::initialize_mint2(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            &authority.pubkey(),
+            None,
+            test_utils::MINT_DECIMALS,
+        )
+        .unwrap(),
+    ];
+    test_utils::send(svm, &instructions, authority, &[&mint]);
+    mint.pubkey()
+}
+
+/// Create a token account of fee mint `mint` for `owner` holding `amount` newly minted tokens
+fn create_fee_token_account(
+    svm: &mut LiteSVM,
+    mint_authority: &Keypair,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let token_account = Keypair::new();
+    let len = ExtensionType::try_calculate_account_len::<TokenAccount>(&[
+        ExtensionType::TransferFeeAmount,
+    ])
+    .unwrap();
+    let instructions = [
+        system_instruction::create_account(
+            &mint_authority.pubkey(),
+            &token_account.pubkey(),
+            svm.minimum_balance_for_rent_exemption(len),
+            len as u64,
+            &spl_token_2022::id(),
+        ),
+        token_instruction::initialize_account3(
+            &spl_token_2022::id(),
+            &token_account.pubkey(),
+            mint,
+            owner,
+        )
+        .unwrap(),
+        token_instruction::mint_to(
+            &spl_token_2022::id(),
+            mint,
+            &token_account.pubkey(),
+            &mint_authority.pubkey(),
+            &[],
+            amount,
+        )
+        .unwrap(),
+    ];
+    test_utils::send(svm, &instructions, mint_authority, &[&token_account]);
+    token_account.pubkey()
+}
+
+/// The stake vault balance and the pool's total_staked
+fn vault_and_total_staked(svm: &LiteSVM, keys: &PoolKeys) -> (u64, u64) {
+    let vault = svm.get_account(&keys.stake_vault).unwrap();
+    let balance = StateWithExtensions::<TokenAccount>::unpack(&vault.data)
+        .unwrap()
+        .base
+        .amount;
+    (
+        balance,
+        test_utils::load_stake_pool(svm, &keys.pool).total_staked,
+    )
+}
+
+#[test]
+fn test_stake_vault_backs_total_staked_through_stake_and_unstake() {
+    let mut svm = LiteSVM::new();
+    test_utils::add_program(&mut svm, programs_dir().join("your_wallet_stake_pool.so"));
+    warp_to(&mut svm, 1_700_000_000);
+
+    let authority = test_utils::funded_keypair(&mut svm);
+    let staker = test_utils::funded_keypair(&mut svm);
+    test_utils::initialize_program_authority(
+        &mut svm,
+        &authority,
+        your_wallet_stake_pool::state::ClusterProfile::Devnet,
+    );
+
+    // 1% on every transfer, plus a 1% withdrawal fee routed to the fee vault
+    let stake_mint = create_fee_mint(&mut svm, &authority, 100);
+    let reward_mint = test_utils::create_mint(&mut svm, &authority, test_utils::MINT_DECIMALS);
+    let keys = PoolKeys::with_pda_vaults(stake_mint, reward_mint, 0, spl_token_2022::id());
+    test_utils::send(
+        &mut svm,
+        &[instruction::initialize_pool(
+            &keys,
+            &authority.pubkey(),
+            InitializePoolArgs {
+                reward_rate: 100_000_000, // 10%
+                min_stake_amount: 1_000,
+                lockup_period: 86_400,
+                withdraw_fee_bps: 100,
+                ..Default::default()
+            },
+        )],
+        &authority,
+        &[],
+    );
+    test_utils::fund_rewards(&mut svm, &authority, &keys, 1_000_000_000);
+    let staker_account = create_fee_token_account(
+        &mut svm,
+        &authority,
+        &stake_mint,
+        &staker.pubkey(),
+        1_000_000,
+    );
+
+    test_utils::send(
+        &mut svm,
+        &[instruction::stake(
+            &keys,
+            &staker.pubkey(),
+            &staker_account,
+            &staker.pubkey(),
+            StakeArgs::new(1_000_000, 0),
+        )],
+        &staker,
+        &[],
+    );
+    // The pool is credited what the vault received after the transfer fee
+    assert_eq!(vault_and_total_staked(&svm, &keys), (990_000, 990_000));
+
+    let unstake = |amount| {
+        instruction::unstake(
+            &keys,
+            &staker.pubkey(),
+            0,
+            &staker_account,
+            amount,
+            None,
+            None,
+        )
+    };
+    test_utils::send(&mut svm, &[unstake(400_000)], &staker, &[]);
+    assert_eq!(vault_and_total_staked(&svm, &keys), (590_000, 590_000));
+
+    // The last staker withdraws everything the pool recorded
+    test_utils::send(&mut svm, &[unstake(590_000)], &staker, &[]);
+    assert_eq!(vault_and_total_staked(&svm, &keys), (0, 0));
+    let stake_account = instruction::stake_account_address(&keys.pool, &staker.pubkey(), 0);
+    assert_eq!(
+        test_utils::load_stake_account(&svm, &stake_account).amount_staked,
+        0
+    );
+}
@@ -20,7 +20,7 @@ use solana_sdk::{
     pubkey::Pubkey,
     signature::{Keypair, Signer},
 };
-use your_wallet_stake_pool::state::Key;
+use your_wallet_stake_pool::state::{ClaimWindow, Key, OutflowLimit, RewardTier, StakePool};
 
 use common::*;
 
@@ -140,6 +140,68 @@ fn test_vault_pda_derivation() {
     println!("✅ Vault PDA derivation correct");
 }
 
+#[test]
+fn test_vault_pda_matches_program_derivation() {
+    let pool = Keypair::new().pubkey();
+
+    // Client-side derivation must agree with the seeds the program validates against
+    let (stake_vault, stake_bump) = get_stake_vault_pda(&pool);
+    let (reward_vault, reward_bump) = get_reward_vault_pda(&pool);
+    assert_eq!(
+        StakePool::find_stake_vault_pda(&pool),
+        (stake_vault, stake_bump)
+    );
+    assert_eq!(
+        StakePool::find_reward_vault_pda(&pool),
+        (reward_vault, reward_bump)
+    );
+
+    println!("✅ Vault PDA seeds consistent with program");
+}
+
+#[test]
+fn test_pool_len_fits_vault_bumps() {
+    let mut pool = sample_stake_pool();
+    pool.pool_end_date = Some(i64::MAX);
+    pool.pending_reward_rate = Some(u64::MAX);
+    pool.reward_rate_change_timestamp = Some(i64::MAX);
+    pool.last_rate_change = Some(i64::MAX);
+    pool.stake_vault_bump = Some(255);
+    pool.reward_vault_bump = Some(255);
+    pool.fee_vault_bump = Some(255);
+    pool.reward_funders = [Some(Pubkey::new_unique()); StakePool::MAX_REWARD_FUNDERS];
+    pool.receipt_mint = Some(Pubkey::new_unique());
+    pool.pending_lockup_period = Some(i64::MAX);
+    pool.pending_enforce_lockup = Some(true);
+    pool.lockup_change_timestamp = Some(i64::MAX);
+    pool.min_reward_rate_bound = Some(u64::MAX);
+    pool.max_reward_rate_bound = Some(u64::MAX);
+    pool.active_stake_count = Some(u64::MAX);
+    pool.reward_tiers = [Some(RewardTier {
+        min_amount: u64::MAX,
+        multiplier_bps: u16::MAX,
+    }); StakePool::MAX_REWARD_TIERS];
+    pool.bonus_end_ts = Some(i64::MAX);
+    pool.allowlist_merkle_root = Some([u8::MAX; 32]);
+    pool.claim_window = Some(ClaimWindow {
+        start: i64::MAX,
+        period: i64::MAX,
+        duration: i64::MAX,
+    });
+    pool.outflow_limit = Some(OutflowLimit {
+        max_unstake_bps: u16::MAX,
+        max_claim_amount: u64::MAX,
+    });
+    pool.pool_manager = Some(Pubkey::new_unique());
+    pool.max_total_rewards = Some(u64::MAX);
+    pool.hook_program = Some(Pubkey::new_unique());
+
+    let data = pool.to_account_data().unwrap();
+    assert_eq!(data.len(), StakePool::LEN);
+
+    println!("✅ StakePool::LEN covers all optional fields");
+}
+
 // ============================================================================
 // Module 3: Account Validation
 // ============================================================================
@@ -172,8 +234,10 @@ fn test_validate_pool_existence() {
 #[test]
 fn test_account_discriminators() {
     // Verify account discriminators (Type Cosplay protection)
-    assert_eq!(Key::StakePool as u8, 1);
-    assert_eq!(Key::StakeAccount as u8, 2);
+    assert_eq!(Key::StakePoolV1 as u8, 1);
+    assert_eq!(Key::StakeAccountV1 as u8, 2);
+    assert_eq!(Key::StakePoolV2 as u8, 5);
+    assert_eq!(Key::StakeAccountV2 as u8, 6);
 
     println!("✅ Account discriminators correct");
 }
@@ -0,0 +1,197 @@
+// ============================================================================
+// Unstake Cooldown Tests
+// ============================================================================
+// Pools with an unstake_cooldown release principal in two steps: RequestUnstake
+// moves it into the stake account's pending withdrawal (forfeiting its unclaimed
+// rewards) and WithdrawUnstaked pays it out once the cooldown has passed.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    error::StakePoolError,
+    state::{StakeAccount, StakePool},
+};
+
+use common::*;
+
+const STAKED: u64 = 1_000_000;
+// sample_stake_pool pays 10% after its lockup
+const REWARDS: u64 = 100_000;
+const COOLDOWN: i64 = 48 * 3600;
+
+fn setup() -> (StakePool, StakeAccount, i64) {
+    let mut pool = sample_stake_pool();
+    pool.unstake_cooldown = COOLDOWN;
+    pool.total_staked = STAKED;
+    pool.total_rewards_owed = REWARDS;
+    let stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), STAKED);
+    let matured = pool.maturity_timestamp(stake.stake_timestamp).unwrap();
+    (pool, stake, matured)
+}
+
+fn assert_error(err: ProgramError, expected: StakePoolError) {
+    assert_eq!(err, ProgramError::Custom(expected as u32));
+}
+
+#[test]
+fn test_request_moves_principal_to_pending() {
+    let (mut pool, mut stake, matured) = setup();
+
+    let forfeited = pool
+        .request_unstake(&mut stake, STAKED / 4, matured)
+        .unwrap();
+
+    // The requested quarter stops earning: its share of the rewards is released
+    assert_eq!(forfeited, REWARDS / 4);
+    assert_eq!(pool.total_rewards_owed, REWARDS - REWARDS / 4);
+    assert_eq!(stake.amount_staked, STAKED - STAKED / 4);
+    assert_eq!(stake.pending_withdraw_amount, STAKED / 4);
+    assert_eq!(stake.request_timestamp, Some(matured));
+    // The tokens are still in the vault until withdrawn
+    assert_eq!(pool.total_staked, STAKED);
+}
+
+#[test]
+fn test_withdraw_waits_for_cooldown() {
+    let (mut pool, mut stake, matured) = setup();
+    pool.request_unstake(&mut stake, STAKED, matured).unwrap();
+
+    let err = pool
+        .complete_unstake_request(&mut stake, matured + COOLDOWN - 1)
+        .unwrap_err();
+    assert_error(err, StakePoolError::UnstakeCooldownActive);
+    assert_eq!(stake.pending_withdraw_amount, STAKED);
+
+    let amount = pool
+        .complete_unstake_request(&mut stake, matured + COOLDOWN)
+        .unwrap();
+    assert_eq!(amount, STAKED);
+    assert_eq!(pool.total_staked, 0);
+    assert_eq!(pool.total_rewards_owed, 0);
+    assert_eq!(stake.pending_withdraw_amount, 0);
+    assert_eq!(stake.request_timestamp, None);
+}
+
+#[test]
+fn test_withdraw_without_request_fails() {
+    let (mut pool, mut stake, matured) = setup();
+
+    let err = pool
+        .complete_unstake_request(&mut stake, matured)
+        .unwrap_err();
+    assert_error(err, StakePoolError::NoPendingWithdrawal);
+
+    // Withdrawing twice is the same as never requesting
+    pool.request_unstake(&mut stake, 1_000, matured).unwrap();
+    pool.complete_unstake_request(&mut stake, matured + COOLDOWN)
+        .unwrap();
+    let err = pool
+        .complete_unstake_request(&mut stake, matured + COOLDOWN)
+        .unwrap_err();
+    assert_error(err, StakePoolError::NoPendingWithdrawal);
+}
+
+#[test]
+fn test_new_request_adds_to_pending_and_restarts_cooldown() {
+    let (mut pool, mut stake, matured) = setup();
+
+    pool.request_unstake(&mut stake, 100_000, matured).unwrap();
+    pool.request_unstake(&mut stake, 200_000, matured + COOLDOWN / 2)
+        .unwrap();
+    assert_eq!(stake.pending_withdraw_amount, 300_000);
+
+    // The first request's cooldown no longer applies
+    let err = pool
+        .complete_unstake_request(&mut stake, matured + COOLDOWN)
+        .unwrap_err();
+    assert_error(err, StakePoolError::UnstakeCooldownActive);
+
+    let amount = pool
+        .complete_unstake_request(&mut stake, matured + COOLDOWN / 2 + COOLDOWN)
+        .unwrap();
+    assert_eq!(amount, 300_000);
+    assert_eq!(pool.total_staked, STAKED - 300_000);
+}
+
+#[test]
+fn test_request_respects_lockup_and_balance() {
+    let (mut pool, mut stake, matured) = setup();
+    pool.enforce_lockup = true;
+    pool.early_unstake_bps = 1_000; // 10% may leave early
+
+    let err = pool
+        .request_unstake(&mut stake, STAKED / 10 + 1, matured - 1)
+        .unwrap_err();
+    assert_error(err, StakePoolError::LockupNotExpired);
+
+    pool.request_unstake(&mut stake, STAKED / 10, matured - 1)
+        .unwrap();
+    assert_eq!(stake.early_unstaked, STAKED / 10);
+
+    let err = pool
+        .request_unstake(&mut stake, STAKED, matured)
+        .unwrap_err();
+    assert_error(err, StakePoolError::InsufficientStakedBalance);
+}
+
+#[test]
+fn test_full_request_resets_position() {
+    let (mut pool, mut stake, matured) = setup();
+    stake.claimed_rewards = 40_000;
+
+    let forfeited = pool.request_unstake(&mut stake, STAKED, matured).unwrap();
+
+    assert_eq!(forfeited, REWARDS - 40_000);
+    assert_eq!(stake.amount_staked, 0);
+    assert_eq!(stake.claimed_rewards, 0);
+    assert_eq!(stake.stake_timestamp, 0);
+    assert_eq!(stake.pending_withdraw_amount, STAKED);
+}
+
+#[test]
+fn test_partial_request_after_a_claim_keeps_the_stake_withdrawable() {
+    let (mut pool, mut stake, matured) = setup();
+    stake.claimed_rewards = REWARDS;
+    pool.total_rewards_owed = 0;
+
+    // The claimed reward exceeds what the remaining half earns on its own
+    assert_eq!(
+        pool.request_unstake(&mut stake, STAKED / 2, matured)
+            .unwrap(),
+        0
+    );
+    assert_eq!(stake.unclaimed_rewards().unwrap(), 0);
+    assert_eq!(
+        pool.request_unstake(&mut stake, STAKED / 2, matured)
+            .unwrap(),
+        0
+    );
+    assert_eq!(pool.total_rewards_owed, 0);
+    assert_eq!(stake.pending_withdraw_amount, STAKED);
+}
+
+#[test]
+fn test_disabling_cooldown_releases_pending_immediately() {
+    let (mut pool, mut stake, matured) = setup();
+    pool.request_unstake(&mut stake, STAKED, matured).unwrap();
+
+    pool.unstake_cooldown = 0;
+    assert_eq!(
+        pool.complete_unstake_request(&mut stake, matured).unwrap(),
+        STAKED
+    );
+}
+
+#[test]
+fn test_stake_account_len_fits_pending_withdrawal() {
+    let (_, mut stake, _) = setup();
+    stake.referrer = Some(Pubkey::new_unique());
+    stake.delegate = Some(Pubkey::new_unique());
+    stake.pending_withdraw_amount = u64::MAX;
+    stake.request_timestamp = Some(i64::MAX);
+    stake.pending_owner = Some(Pubkey::new_unique());
+
+    assert_eq!(stake.to_account_data().unwrap().len(), StakeAccount::LEN);
+}
@@ -0,0 +1,94 @@
+// ============================================================================
+// Vault Order Tests
+// ============================================================================
+// Processors resolve each vault slot from the addresses stored on the pool.
+// A vault of the pool passed in another vault's slot fails with
+// VaultOrderMismatch, so a misordered account list is told apart from a
+// foreign account (AccountMismatch).
+
+mod common;
+
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    assertions::assert_vault,
+    error::StakePoolError,
+    state::{PoolVault, StakePool},
+};
+
+use common::*;
+
+/// A sample pool at `pool` whose vaults live at their PDAs
+fn pool_with_pda_vaults(pool: &Pubkey) -> StakePool {
+    let (stake_vault, stake_vault_bump) = StakePool::find_stake_vault_pda(pool);
+    let (reward_vault, reward_vault_bump) = StakePool::find_reward_vault_pda(pool);
+    let (fee_vault, fee_vault_bump) = StakePool::find_fee_vault_pda(pool);
+    StakePool {
+        stake_vault,
+        stake_vault_bump: Some(stake_vault_bump),
+        reward_vault,
+        reward_vault_bump: Some(reward_vault_bump),
+        fee_vault,
+        fee_vault_bump: Some(fee_vault_bump),
+        ..sample_stake_pool()
+    }
+}
+
+/// Run `assert_vault` for the `vault` slot holding the account at `address`
+fn check(
+    pool: &Pubkey,
+    pool_data: &StakePool,
+    vault: PoolVault,
+    address: Pubkey,
+) -> Result<(), ProgramError> {
+    let owner = spl_token_2022::id();
+    let mut lamports = 0;
+    let mut data = Vec::new();
+    let info = AccountInfo::new(
+        &address,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    assert_vault(vault.name(), &info, pool, pool_data, vault)
+}
+
+#[test]
+fn test_each_slot_accepts_its_vault() {
+    let pool = Pubkey::new_unique();
+    let pool_data = pool_with_pda_vaults(&pool);
+
+    for vault in PoolVault::ALL {
+        assert!(check(&pool, &pool_data, vault, pool_data.vault(vault).0).is_ok());
+    }
+}
+
+#[test]
+fn test_swapped_vaults_fail_with_vault_order_mismatch() {
+    let pool = Pubkey::new_unique();
+    let pool_data = pool_with_pda_vaults(&pool);
+
+    assert_eq!(
+        check(&pool, &pool_data, PoolVault::Stake, pool_data.reward_vault),
+        Err(StakePoolError::VaultOrderMismatch.into())
+    );
+    assert_eq!(
+        check(&pool, &pool_data, PoolVault::Fee, pool_data.stake_vault),
+        Err(StakePoolError::VaultOrderMismatch.into())
+    );
+}
+
+#[test]
+fn test_foreign_account_fails_with_account_mismatch() {
+    let pool = Pubkey::new_unique();
+    let pool_data = pool_with_pda_vaults(&pool);
+
+    assert_eq!(
+        check(&pool, &pool_data, PoolVault::Reward, Pubkey::new_unique()),
+        Err(StakePoolError::AccountMismatch.into())
+    );
+}
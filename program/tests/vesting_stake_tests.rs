@@ -0,0 +1,143 @@
+// ============================================================================
+// Vesting Stake Tests
+// ============================================================================
+// Global admins grant team allocations with VestingStake: a stake funded by the
+// admin and owned by a beneficiary whose principal vests linearly over
+// vesting_duration after a vesting_cliff. It earns rewards like any stake, but
+// check_unstake_lockup keeps the unvested principal staked, which gates both
+// Unstake and RequestUnstake.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    constants::MAX_VESTING_DURATION,
+    error::StakePoolError,
+    state::{StakeAccount, StakePool},
+};
+
+use common::*;
+
+const START: i64 = SAMPLE_STAKE_TIMESTAMP;
+const CLIFF: i64 = 100;
+const DURATION: i64 = 400;
+
+/// A pool without lockup and a 1_000 token grant vesting over 400 seconds after a 100 second cliff
+fn vesting() -> (StakePool, StakeAccount) {
+    let mut pool = sample_stake_pool();
+    pool.lockup_period = 0;
+
+    let mut stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000);
+    stake.vesting_amount = 1_000;
+    stake.vesting_start = START;
+    stake.vesting_cliff = CLIFF;
+    stake.vesting_duration = DURATION;
+    (pool, stake)
+}
+
+fn not_vested() -> Result<bool, ProgramError> {
+    Err(StakePoolError::PrincipalNotVested.into())
+}
+
+#[test]
+fn test_nothing_vests_before_the_cliff() {
+    let (pool, stake) = vesting();
+
+    assert_eq!(stake.unvested_amount(START).unwrap(), 1_000);
+    assert_eq!(stake.unvested_amount(START + CLIFF - 1).unwrap(), 1_000);
+    assert_eq!(
+        pool.check_unstake_lockup(&stake, 1, START + CLIFF - 1),
+        not_vested()
+    );
+}
+
+#[test]
+fn test_principal_vests_linearly_from_the_start() {
+    let (_, stake) = vesting();
+
+    // At the cliff a quarter of the duration has passed
+    assert_eq!(stake.unvested_amount(START + CLIFF).unwrap(), 750);
+    assert_eq!(stake.unvested_amount(START + DURATION / 2).unwrap(), 500);
+    assert_eq!(stake.unvested_amount(START + DURATION - 1).unwrap(), 3);
+    assert_eq!(stake.unvested_amount(START + DURATION).unwrap(), 0);
+}
+
+#[test]
+fn test_only_vested_principal_can_be_unstaked() {
+    let (pool, mut stake) = vesting();
+    let halfway = START + DURATION / 2;
+
+    assert!(pool.check_unstake_lockup(&stake, 500, halfway).is_ok());
+    assert_eq!(
+        pool.check_unstake_lockup(&stake, 501, halfway),
+        not_vested()
+    );
+
+    // What was unstaked counts against the vested part
+    stake.amount_staked = 700;
+    assert!(pool.check_unstake_lockup(&stake, 200, halfway).is_ok());
+    assert_eq!(
+        pool.check_unstake_lockup(&stake, 201, halfway),
+        not_vested()
+    );
+
+    // Once fully vested the rest is free
+    assert!(pool
+        .check_unstake_lockup(&stake, 700, START + DURATION)
+        .is_ok());
+}
+
+#[test]
+fn test_request_unstake_is_gated_too() {
+    let (mut pool, mut stake) = vesting();
+
+    assert_eq!(
+        pool.request_unstake(&mut stake, 1, START).unwrap_err(),
+        StakePoolError::PrincipalNotVested.into()
+    );
+    assert_eq!(stake.amount_staked, 1_000);
+}
+
+#[test]
+fn test_regular_stakes_do_not_vest() {
+    let (pool, mut stake) = vesting();
+    stake.vesting_amount = 0;
+
+    assert_eq!(stake.unvested_amount(START).unwrap(), 0);
+    assert!(pool.check_unstake_lockup(&stake, 1_000, START).is_ok());
+}
+
+#[test]
+fn test_vesting_schedules_are_validated() {
+    let invalid = || Err(StakePoolError::InvalidParameters.into());
+
+    assert!(StakeAccount::validate_vesting_schedule(0, 1).is_ok());
+    assert!(StakeAccount::validate_vesting_schedule(DURATION, DURATION).is_ok());
+    assert!(StakeAccount::validate_vesting_schedule(0, MAX_VESTING_DURATION).is_ok());
+
+    assert_eq!(StakeAccount::validate_vesting_schedule(0, 0), invalid());
+    assert_eq!(
+        StakeAccount::validate_vesting_schedule(-1, DURATION),
+        invalid()
+    );
+    assert_eq!(
+        StakeAccount::validate_vesting_schedule(DURATION + 1, DURATION),
+        invalid()
+    );
+    assert_eq!(
+        StakeAccount::validate_vesting_schedule(0, MAX_VESTING_DURATION + 1),
+        invalid()
+    );
+}
+
+#[test]
+fn test_ownership_transfers_keep_the_schedule() {
+    let (_, mut stake) = vesting();
+    let new_owner = Pubkey::new_unique();
+    stake.pending_owner = Some(new_owner);
+
+    let transferred = stake.transfer_to(&new_owner, 0, 255).unwrap();
+    assert_eq!(transferred.vesting_amount, 1_000);
+    assert_eq!(transferred.unvested_amount(START).unwrap(), 1_000);
+}
@@ -0,0 +1,119 @@
+// ============================================================================
+// Wind-Down Tests
+// ============================================================================
+// WIND_DOWN_DELAY after a pool's end date, WindDownStake returns what is left
+// of a stake to its owner and closes the account. The whole principal leaves
+// total_staked and the stake's reserved rewards leave total_rewards_owed; the
+// earned part is paid or expired according to the pool's wind_down_rewards.
+
+mod common;
+
+use solana_program::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+use your_wallet_stake_pool::{
+    constants::WIND_DOWN_DELAY,
+    error::StakePoolError,
+    state::{StakeAccount, StakePool, WindDown, WindDownRewards},
+};
+
+use common::*;
+
+const STAKED: u64 = 1_000_000;
+// sample_stake_pool pays 10% after its lockup
+const REWARDS: u64 = 100_000;
+
+/// A pool that ended once the sample stake matured, with that stake still in it
+fn setup() -> (StakePool, StakeAccount, i64) {
+    let mut pool = sample_stake_pool();
+    let stake = sample_stake_account(&Pubkey::new_unique(), &Pubkey::new_unique(), STAKED);
+    let end_date = pool.maturity_timestamp(stake.stake_timestamp).unwrap();
+    pool.pool_end_date = Some(end_date);
+    pool.total_staked = STAKED;
+    pool.total_rewards_owed = REWARDS;
+    pool.active_stake_count = Some(1);
+    (pool, stake, end_date + WIND_DOWN_DELAY)
+}
+
+fn assert_error(err: ProgramError, expected: StakePoolError) {
+    assert_eq!(err, ProgramError::Custom(expected as u32));
+}
+
+#[test]
+fn test_wind_down_waits_for_the_delay_after_the_end_date() {
+    let (mut pool, mut stake, starts_at) = setup();
+
+    let err = pool.wind_down_stake(&mut stake, starts_at - 1).unwrap_err();
+    assert_error(err, StakePoolError::WindDownNotStarted);
+    assert_eq!(stake.amount_staked, STAKED);
+
+    pool.pool_end_date = None;
+    let err = pool.wind_down_stake(&mut stake, i64::MAX / 2).unwrap_err();
+    assert_error(err, StakePoolError::WindDownNotStarted);
+}
+
+#[test]
+fn test_wind_down_expires_earned_rewards_by_default() {
+    let (mut pool, mut stake, starts_at) = setup();
+    assert_eq!(pool.wind_down_rewards, WindDownRewards::Expire);
+
+    let wind_down = pool.wind_down_stake(&mut stake, starts_at).unwrap();
+
+    assert_eq!(
+        wind_down,
+        WindDown {
+            principal: STAKED,
+            rewards_paid: 0,
+            rewards_expired: REWARDS,
+        }
+    );
+    assert_eq!(pool.total_staked, 0);
+    assert_eq!(pool.total_rewards_owed, 0);
+    assert_eq!(pool.expired_rewards, REWARDS);
+    assert_eq!(pool.active_stake_count, Some(0));
+    assert_eq!(stake.amount_staked, 0);
+    assert_eq!(stake.unclaimed_rewards().unwrap(), 0);
+}
+
+#[test]
+fn test_wind_down_can_pay_the_rewards_left_unclaimed() {
+    let (mut pool, mut stake, starts_at) = setup();
+    pool.wind_down_rewards = WindDownRewards::Pay;
+    stake.claimed_rewards = REWARDS / 4;
+    pool.total_rewards_owed = REWARDS - REWARDS / 4;
+
+    let wind_down = pool.wind_down_stake(&mut stake, starts_at).unwrap();
+
+    assert_eq!(wind_down.rewards_paid, REWARDS - REWARDS / 4);
+    assert_eq!(wind_down.rewards_expired, 0);
+    assert_eq!(pool.total_rewards_owed, 0);
+    assert_eq!(pool.expired_rewards, 0);
+}
+
+#[test]
+fn test_wind_down_forfeits_rewards_of_an_unfinished_round() {
+    let (mut pool, mut stake, starts_at) = setup();
+    pool.wind_down_rewards = WindDownRewards::Pay;
+    // Extended well past the wind-down start, the round has not completed yet
+    stake.lockup_extension = 2 * WIND_DOWN_DELAY;
+
+    let wind_down = pool.wind_down_stake(&mut stake, starts_at).unwrap();
+
+    assert_eq!(wind_down.principal, STAKED);
+    assert_eq!(wind_down.rewards_paid, 0);
+    assert_eq!(pool.total_rewards_owed, 0);
+}
+
+#[test]
+fn test_wind_down_returns_a_pending_withdrawal_too() {
+    let (mut pool, mut stake, starts_at) = setup();
+    pool.request_unstake(&mut stake, STAKED / 2, starts_at - 1)
+        .unwrap();
+
+    let wind_down = pool.wind_down_stake(&mut stake, starts_at).unwrap();
+
+    assert_eq!(wind_down.principal, STAKED);
+    assert_eq!(pool.total_staked, 0);
+    assert_eq!(pool.total_rewards_owed, 0);
+    assert_eq!(stake.pending_withdraw_amount, 0);
+    assert_eq!(stake.request_timestamp, None);
+}
@@ -5,7 +5,8 @@ import { rootNodeFromAnchor } from '@codama/nodes-from-anchor';
 import { renderVisitor as renderJavaScriptVisitor } from '@codama/renderers-js';
 import { getAllProgramIdls } from './utils.mjs';
 
-// Instanciate Codama.
+// Instanciate Codama from the checked-in IDL (kept in sync with the program
+// source by program/tests/idl_tests.rs).
 const [idl, ...additionalIdls] = getAllProgramIdls().map((idl) =>
   rootNodeFromAnchor(require(idl))
 );
@@ -18,51 +19,6 @@ codama.update(
   })
 );
 
-// Update accounts.
-codama.update(
-  c.updateAccountsVisitor({
-    counter: {
-      seeds: [
-        c.constantPdaSeedNodeFromString('utf8', 'counter'),
-        c.variablePdaSeedNode(
-          'authority',
-          c.publicKeyTypeNode(),
-          'The authority of the counter account'
-        ),
-      ],
-    },
-  })
-);
-
-// Update instructions.
-codama.update(
-  c.updateInstructionsVisitor({
-    create: {
-      byteDeltas: [c.instructionByteDeltaNode(c.accountLinkNode('counter'))],
-      accounts: {
-        counter: { defaultValue: c.pdaValueNode('counter') },
-        payer: { defaultValue: c.accountValueNode('authority') },
-      },
-    },
-    increment: {
-      accounts: {
-        counter: { defaultValue: c.pdaValueNode('counter') },
-      },
-      arguments: {
-        amount: { defaultValue: c.noneValueNode() },
-      },
-    },
-  })
-);
-
-// Set account discriminators.
-const key = (name) => ({ field: 'key', value: c.enumValueNode('Key', name) });
-codama.update(
-  c.setAccountDiscriminatorFromFieldVisitor({
-    counter: key('counter'),
-  })
-);
-
 // Render JavaScript.
 const jsClient = path.join(__dirname, '..', 'clients', 'js');
 codama.accept(
@@ -5,12 +5,12 @@ import { getCargo, getProgramFolders } from './utils.mjs';
 
 const binaryInstallDir = path.join(__dirname, '..', '.cargo');
 
-getProgramFolders().forEach((folder) => {
+for (const folder of getProgramFolders()) {
   const cargo = getCargo(folder);
   const isShank = Object.keys(cargo.dependencies).includes('shank');
   const programDir = path.join(__dirname, '..', folder);
 
-  generateIdl({
+  await generateIdl({
     generator: isShank ? 'shank' : 'anchor',
     programName: cargo.package.name.replace(/-/g, '_'),
     programId: cargo.package.metadata.solana['program-id'],
@@ -19,4 +19,33 @@ getProgramFolders().forEach((folder) => {
     programDir,
     binaryInstallDir,
   });
-});
+
+  if (isShank) {
+    await addEvents(programDir, cargo.package.name);
+  }
+}
+
+// Shank does not know about events: add them in the Anchor `events` format,
+// with the types they use, as exported from the program source by the IDL
+// drift tests (program/tests/idl_tests.rs).
+async function addEvents(programDir, packageName) {
+  const exported = path.join(os.tmpdir(), `${packageName}-idl-events.json`);
+  process.env.IDL_EVENTS_OUT = exported;
+  await $`cargo test -p ${packageName} --test idl_tests -- --ignored export_idl_events`;
+  const { events, types } = await fs.readJson(exported);
+
+  const idlPath = path.join(programDir, 'idl.json');
+  const { metadata, ...idl } = await fs.readJson(idlPath);
+  for (const type of types) {
+    const index = idl.types.findIndex(({ name }) => name === type.name);
+    if (index === -1) {
+      idl.types.push(type);
+    } else {
+      idl.types[index] = type;
+    }
+  }
+  await fs.writeFile(
+    idlPath,
+    JSON.stringify({ ...idl, events, metadata }, null, 2)
+  );
+}
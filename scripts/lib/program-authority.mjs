@@ -133,7 +133,7 @@ export async function initializeProgramAuthority({
   cluster = 'devnet',
 }) {
   const clientPath = join(workspaceRoot, 'clients/js/dist/src/index.js');
-  const { getInitializeProgramAuthorityInstruction } = await import(clientPath);
+  const { getInitializeProgramAuthorityInstruction, ClusterProfile } = await import(clientPath);
   
   const clusterUrl = getClusterUrl(cluster);
   
@@ -149,6 +149,8 @@ export async function initializeProgramAuthority({
     initialAuthority: authority.address,
     payer: authority.address,
     systemProgram: address('11111111111111111111111111111111'),
+    // Dev-only instructions and shorter timelocks are enabled on devnet only
+    clusterProfile: cluster === 'devnet' ? ClusterProfile.Devnet : ClusterProfile.Mainnet,
   });
   
   // Get recent blockhash
@@ -0,0 +1,346 @@
+//! Developer workflow tasks, run with `cargo xtask <command>`
+//!
+//! - `build-sbf [args..]`: build the program to `target/deploy/your_wallet_stake_pool.so`
+//! - `dump-token [--url <rpc>]`: dump the Token-2022 program to `target/deploy/spl_token_2022.so`
+//! - `test-all [args..]`: build, dump Token-2022 when missing, then run the workspace tests
+//! - `snapshot [--url <rpc>] [--out-dir <dir>] <address>..`: save accounts as test fixtures
+//!
+//! Solana tools are looked up in `$SOLANA_BIN_DIR`, then `PATH`, then the default
+//! Solana install location, so the workflow does not depend on the shell setup.
+//! Without the `solana` CLI, `dump-token` fetches the program over JSON-RPC.
+
+use std::{
+    env,
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Value};
+
+const PROGRAM_MANIFEST: &str = "program/Cargo.toml";
+const PROGRAM_SO: &str = "your_wallet_stake_pool.so";
+const TOKEN_2022_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+const TOKEN_2022_SO: &str = "spl_token_2022.so";
+const DEFAULT_RPC_URL: &str = "https://api.mainnet-beta.solana.com";
+const DEVNET_RPC_URL: &str = "https://api.devnet.solana.com";
+const FIXTURES_DIR: &str = "program/tests/fixtures";
+
+/// Size of the upgradeable loader's ProgramData header preceding the ELF
+/// (enum tag, deployment slot, optional upgrade authority)
+const PROGRAMDATA_METADATA_LEN: usize = 4 + 8 + 1 + 32;
+
+type Result<T> = std::result::Result<T, String>;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (command, rest) = match args.split_first() {
+        Some((command, rest)) => (command.as_str(), rest),
+        None => {
+            print_usage();
+            process::exit(1);
+        }
+    };
+
+    let result = match command {
+        "build-sbf" => build_sbf(rest),
+        "dump-token" => dump_token(rest),
+        "test-all" => test_all(rest),
+        "snapshot" => snapshot(rest),
+        "help" | "--help" | "-h" => {
+            print_usage();
+            Ok(())
+        }
+        other => Err(format!("unknown command `{}`", other)),
+    };
+
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+fn print_usage() {
+    eprintln!(
+        "Usage: cargo xtask <command>\n\n\
+         Commands:\n  \
+         build-sbf [args..]        Build the program to target/deploy/{}\n  \
+         dump-token [--url <rpc>]  Dump Token-2022 to target/deploy/{}\n  \
+         test-all [args..]         Build, dump Token-2022 if missing, run workspace tests\n  \
+         snapshot [--url <rpc>] [--out-dir <dir>] <address>..\n                            \
+         Save accounts (default: devnet) as fixtures in {}",
+        PROGRAM_SO, TOKEN_2022_SO, FIXTURES_DIR
+    );
+}
+
+fn build_sbf(args: &[String]) -> Result<()> {
+    let cargo_build_sbf = find_solana_tool("cargo-build-sbf")?;
+    let deploy_dir = deploy_dir();
+
+    run(Command::new(cargo_build_sbf)
+        .current_dir(workspace_root())
+        .arg("--manifest-path")
+        .arg(PROGRAM_MANIFEST)
+        .arg("--sbf-out-dir")
+        .arg(&deploy_dir)
+        .args(args))?;
+
+    println!("Built {}", deploy_dir.join(PROGRAM_SO).display());
+    Ok(())
+}
+
+fn dump_token(args: &[String]) -> Result<()> {
+    let url = match args {
+        [] => DEFAULT_RPC_URL.to_string(),
+        [flag, url] if flag == "--url" || flag == "-u" => url.clone(),
+        _ => return Err("usage: cargo xtask dump-token [--url <rpc>]".to_string()),
+    };
+
+    let deploy_dir = deploy_dir();
+    fs::create_dir_all(&deploy_dir)
+        .map_err(|e| format!("cannot create {}: {}", deploy_dir.display(), e))?;
+    let output = deploy_dir.join(TOKEN_2022_SO);
+
+    match find_solana_tool("solana") {
+        Ok(solana) => run(Command::new(solana)
+            .args(["program", "dump", "--url", &url, TOKEN_2022_ID])
+            .arg(&output))?,
+        Err(_) => {
+            println!(
+                "solana CLI not found, fetching {} over JSON-RPC",
+                TOKEN_2022_ID
+            );
+            let elf = fetch_program_elf(&url, TOKEN_2022_ID)?;
+            fs::write(&output, elf)
+                .map_err(|e| format!("cannot write {}: {}", output.display(), e))?;
+        }
+    }
+
+    println!("Dumped Token-2022 to {}", output.display());
+    Ok(())
+}
+
+fn test_all(args: &[String]) -> Result<()> {
+    build_sbf(&[])?;
+
+    let deploy_dir = deploy_dir();
+    if !deploy_dir.join(TOKEN_2022_SO).exists() {
+        dump_token(&[])?;
+    }
+
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    run(Command::new(cargo)
+        .current_dir(workspace_root())
+        .env("SBF_OUT_DIR", &deploy_dir)
+        .args(["test", "--workspace"])
+        .args(args))
+}
+
+/// Save accounts as fixture files loadable by the LiteSVM tests
+///
+/// Files use the `solana account --output json` layout, so fixtures captured with
+/// the CLI and with this command are interchangeable.
+fn snapshot(args: &[String]) -> Result<()> {
+    let mut url = DEVNET_RPC_URL.to_string();
+    let mut out_dir = workspace_root().join(FIXTURES_DIR);
+    let mut addresses = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--url" | "-u" => url = args.next().ok_or("--url needs a value")?.clone(),
+            "--out-dir" => out_dir = PathBuf::from(args.next().ok_or("--out-dir needs a value")?),
+            address => addresses.push(address.to_string()),
+        }
+    }
+    if addresses.is_empty() {
+        return Err(
+            "usage: cargo xtask snapshot [--url <rpc>] [--out-dir <dir>] <address>..".to_string(),
+        );
+    }
+
+    fs::create_dir_all(&out_dir)
+        .map_err(|e| format!("cannot create {}: {}", out_dir.display(), e))?;
+
+    for address in &addresses {
+        let account = account_info(&url, address)?;
+        let fixture = json!({ "pubkey": address, "account": account });
+        let path = out_dir.join(format!("{}.json", address));
+        let contents = serde_json::to_string_pretty(&fixture)
+            .map_err(|e| format!("cannot encode {}: {}", address, e))?;
+        fs::write(&path, contents + "\n")
+            .map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+        println!("Saved {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lives in the workspace root")
+        .to_path_buf()
+}
+
+/// Directory the tests load program binaries from (see `SBF_OUT_DIR` in program/tests/common)
+fn deploy_dir() -> PathBuf {
+    let target_dir = env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| workspace_root().join("target"));
+    target_dir.join("deploy")
+}
+
+fn find_solana_tool(name: &str) -> Result<PathBuf> {
+    let mut candidates = Vec::new();
+
+    if let Some(dir) = env::var_os("SOLANA_BIN_DIR") {
+        candidates.push(PathBuf::from(dir).join(name));
+    }
+    if let Some(path) = env::var_os("PATH") {
+        candidates.extend(env::split_paths(&path).map(|dir| dir.join(name)));
+    }
+    if let Some(home) = env::var_os("HOME") {
+        candidates.push(
+            PathBuf::from(home)
+                .join(".local/share/solana/install/active_release/bin")
+                .join(name),
+        );
+    }
+
+    candidates
+        .into_iter()
+        .find(|path| path.is_file())
+        .ok_or_else(|| {
+            format!(
+                "`{}` not found in $SOLANA_BIN_DIR, PATH or the default Solana install. \
+             Install the Solana CLI {} or point SOLANA_BIN_DIR at its bin directory.",
+                name,
+                pinned_solana_version().unwrap_or_else(|| "release".to_string())
+            )
+        })
+}
+
+/// Solana CLI version pinned in the workspace manifest (`[workspace.metadata.cli]`)
+fn pinned_solana_version() -> Option<String> {
+    let manifest = fs::read_to_string(workspace_root().join("Cargo.toml")).ok()?;
+    manifest
+        .lines()
+        .skip_while(|line| line.trim() != "[workspace.metadata.cli]")
+        .find_map(|line| line.trim().strip_prefix("solana = "))
+        .map(|version| version.trim_matches('"').to_string())
+}
+
+/// Fetch an upgradeable program's ELF the way `solana program dump` does
+fn fetch_program_elf(url: &str, program_id: &str) -> Result<Vec<u8>> {
+    let program = account_data(url, program_id)?;
+    // UpgradeableLoaderState::Program { programdata_address }
+    if program.len() < 36 || program[..4] != [2, 0, 0, 0] {
+        return Err(format!("{} is not an upgradeable program", program_id));
+    }
+    let programdata_address = bs58_encode(&program[4..36]);
+
+    let programdata = account_data(url, &programdata_address)?;
+    if programdata.len() <= PROGRAMDATA_METADATA_LEN || programdata[..4] != [3, 0, 0, 0] {
+        return Err(format!(
+            "{} is not a program data account",
+            programdata_address
+        ));
+    }
+
+    Ok(programdata[PROGRAMDATA_METADATA_LEN..].to_vec())
+}
+
+fn account_data(url: &str, address: &str) -> Result<Vec<u8>> {
+    let account = account_info(url, address)?;
+    let data = account["data"][0]
+        .as_str()
+        .ok_or_else(|| format!("account {} has no base64 data: {}", address, account))?;
+
+    STANDARD
+        .decode(data)
+        .map_err(|e| format!("invalid account data for {}: {}", address, e))
+}
+
+/// `getAccountInfo` result value (lamports, base64 data, owner, executable, rentEpoch, space)
+fn account_info(url: &str, address: &str) -> Result<Value> {
+    let request = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "getAccountInfo",
+        "params": [address, { "encoding": "base64" }],
+    });
+
+    let output = Command::new("curl")
+        .args([
+            "-sS",
+            "-X",
+            "POST",
+            "-H",
+            "Content-Type: application/json",
+            "-d",
+        ])
+        .arg(request.to_string())
+        .arg(url)
+        .output()
+        .map_err(|e| format!("cannot run curl: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "RPC request to {} failed: {}",
+            url,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    let mut response: Value = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("invalid RPC response: {}", e))?;
+    let account = response["result"]["value"].take();
+    if !account.is_object() {
+        return Err(format!("account {} not found: {}", address, response));
+    }
+
+    Ok(account)
+}
+
+fn bs58_encode(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    let mut digits: Vec<u8> = Vec::new();
+    for &byte in bytes {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let leading_zeros = bytes.iter().take_while(|&&b| b == 0).count();
+    std::iter::repeat_n(b'1', leading_zeros)
+        .chain(digits.iter().rev().map(|&d| ALPHABET[d as usize]))
+        .map(char::from)
+        .collect()
+}
+
+fn run(command: &mut Command) -> Result<()> {
+    let program = command.get_program().to_owned();
+    let status = command
+        .status()
+        .map_err(|e| format!("cannot run {}: {}", display(&program), e))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("{} exited with {}", display(&program), status))
+    }
+}
+
+fn display(program: &OsStr) -> String {
+    program.to_string_lossy().into_owned()
+}